@@ -149,6 +149,9 @@ fn configure_validation(builder: Builder) -> Builder {
             ("OptimizersConfigDiff.deleted_threshold", "range(min = 0.0, max = 1.0)"),
             ("OptimizersConfigDiff.vacuum_min_vector_number", "range(min = 100)"),
             ("OptimizersConfigDiff.max_segment_size", "range(min = 1)"),
+            ("OptimizersConfigDiff.requantization", ""),
+            ("RequantizationConfig.max_distortion", "range(min = 0.0, max = 1.0)"),
+            ("RequantizationConfig.check_interval_sec", "range(min = 1)"),
             ("VectorsConfig.config", ""),
             ("VectorsConfigDiff.config", ""),
             ("VectorParams.size", "range(min = 1, max = 65536)"),
@@ -169,6 +172,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("StrictModeConfig.read_rate_limit", "range(min = 1)"),
             ("StrictModeConfig.write_rate_limit", "range(min = 1)"),
             ("StrictModeConfig.max_resident_memory_percent", "range(min = 1, max = 100)"),
+            ("StrictModeConfig.filter_max_selectivity", "range(min = 0.0, max = 1.0)"),
             ("StrictModeConfig.multivector_config", ""),
             ("StrictModeConfig.sparse_config", ""),
             ("StrictModeSparseConfig.sparse_config", ""),
@@ -251,7 +255,11 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchPointGroups.timeout", "range(min = 1)"),
             ("SearchParams.quantization", ""),
             ("SearchParams.acorn", ""),
+            ("SearchParams.segment_limit_factor", "range(min = 1.0)"),
             ("QuantizationSearchParams.oversampling", "range(min = 1.0)"),
+            ("QuantizationSearchParams.fallback", ""),
+            ("QuantizationFallbackParams.max_distortion", "range(min = 0.0, max = 1.0)"),
+            ("QuantizationFallbackParams.max_oversampling", "range(min = 1.0)"),
             ("ScrollPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("ScrollPoints.filter", ""),
             ("ScrollPoints.limit", "range(min = 1)"),
@@ -285,6 +293,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CountPoints.filter", ""),
             ("GeoPolygon.exterior", "custom(function = \"crate::grpc::validate::validate_geo_polygon_exterior\")"),
             ("GeoPolygon.interiors", "custom(function = \"crate::grpc::validate::validate_geo_polygon_interiors\")"),
+            ("GeoPolygon.tolerance", "range(min = 0.0)"),
             ("Filter.should", ""),
             ("Filter.must", ""),
             ("Filter.must_not", ""),
@@ -328,6 +337,10 @@ fn configure_validation(builder: Builder) -> Builder {
             ("Mmr.diversity", "range(min = 0.0, max = 1.0)"),
             ("Mmr.candidates_limit", "range(max = 16_384)"),
             ("Rrf.k", "range(min = 1)"),
+            ("RecencyBoost.key", "length(min = 1)"),
+            ("ProximityBoost.key", "length(min = 1)"),
+            ("WeightedSample.key", "length(min = 1)"),
+            ("WeightedSample.default_weight", "range(min = 0.0)"),
             ("Query.variant", ""),
             ("PrefetchQuery.prefetch", ""),
             ("PrefetchQuery.query", ""),
@@ -341,6 +354,10 @@ fn configure_validation(builder: Builder) -> Builder {
             ("QueryPoints.filter", ""),
             ("QueryPoints.params", ""),
             ("QueryPoints.timeout", "range(min = 1)"),
+            ("QueryPoints.facets", ""),
+            ("QueryPoints.soft_timeout_ms", "range(min = 1)"),
+            ("QueryFacet.key", "length(min = 1)"),
+            ("QueryFacet.limit", "range(min = 1, max = 100)"),
             ("QueryBatchPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("QueryBatchPoints.query_points", ""),
             ("QueryBatchPoints.timeout", "range(min = 1)"),
@@ -407,6 +424,10 @@ fn configure_validation(builder: Builder) -> Builder {
             ("QueryBatchPointsInternal.timeout", "range(min = 1)"),
             ("FacetCountsInternal.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("FacetCountsInternal.timeout", "range(min = 1)"),
+            ("ValueSamplesInternal.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("ValueSamplesInternal.timeout", "range(min = 1)"),
+            ("PointVersionsInternal.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("PointVersionsInternal.timeout", "range(min = 1)"),
         ], &[])
         // Service: raft_service.proto
         .validates(&[
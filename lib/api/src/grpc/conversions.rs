@@ -15,10 +15,11 @@ use segment::data_types::index::{
     KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
 };
 use segment::data_types::modifier::Modifier;
+use segment::data_types::quantiles::ValueSample;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedMultiDenseVector, VectorInternal};
 use segment::data_types::{facets as segment_facets, vectors as segment_vectors};
 use segment::index::query_optimization::rescore_formula::parsed_formula::{
-    DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula,
+    DatetimeExpression, DecayKind, ParsedExpression, ParsedFormula, TextScore,
 };
 use segment::types::{
     DateTimePayloadType, FloatPayloadType, VectorStorageDatatype, default_quantization_ignore_value,
@@ -29,15 +30,16 @@ use tonic::Status;
 use uuid::Uuid;
 
 use super::qdrant::{
-    BinaryQuantization, BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange,
-    Direction, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal, FieldType,
+    ArithmeticDiv, ArithmeticExpression, ArithmeticExpressions, BinaryQuantization,
+    BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange, Direction,
+    ExpressionCondition, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal, FieldType,
     FloatIndexParams, GeoIndexParams, GeoLineString, GroupId, HardwareUsage, HasVectorCondition,
     KeywordIndexParams, LookupLocation, MaxOptimizationThreads, MultiVectorComparator,
     MultiVectorConfig, OrderBy, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint,
     SearchMatrixPair, SearchPointGroups, SearchPoints, ShardKeySelector, StartFrom,
     StrictModeMultivector, StrictModeMultivectorConfig, StrictModeSparse, StrictModeSparseConfig,
-    TurboQuantBitSize, TurboQuantization, UuidIndexParams, VectorsOutput, WithLookup, raw_query,
-    start_from,
+    TurboQuantBitSize, TurboQuantization, UpdatedAfterCondition, UuidIndexParams, UuidRange,
+    ValueSampleInternal, VectorsOutput, WithLookup, arithmetic_expression, raw_query, start_from,
 };
 use super::stemming_algorithm::StemmingParams;
 use super::{Expression, Formula, RecoQuery, SnowballParams, StemmingAlgorithm, Usage};
@@ -49,21 +51,24 @@ use crate::grpc::qdrant::point_id::PointIdOptions;
 use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
     AcornSearchParams, CollectionDescription, CollectionOperationResponse, Condition, Distance,
-    FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, HasIdCondition,
-    HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition, IsNullCondition,
-    ListCollectionsResponse, ListShardKeysResponse, Match, MinShould, NamedVectors,
-    NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams,
-    PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct, PointsOperationResponse,
+    FieldCondition, Filter, GeoBoundingBox, GeoNearPath, GeoPoint, GeoPolygon, GeoRadius,
+    HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition,
+    IsNullCondition, ListCollectionsResponse, ListShardKeysResponse, Match, MinShould,
+    MinShouldMatch, NamedVectors, NestedCondition, NullElements, OversizedValuePolicy,
+    PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo,
+    PayloadSchemaType, PointId, PointProvenance, PointStruct, PointsOperationResponse,
     PointsOperationResponseInternal, ProductQuantization, QuantizationConfig,
-    QuantizationSearchParams, QuantizationType, RepeatedIntegers, RepeatedStrings,
-    ScalarQuantization, ScoredPoint, SearchParams, ShardKey, ShardKeyDescription, StopwordsSet,
-    StrictModeConfig, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
-    ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector, shard_key,
-    with_vectors_selector,
+    QuantizationFallbackParams, QuantizationSearchParams, QuantizationType, RepeatedIntegers,
+    RepeatedStrings, ScalarOutliers, ScalarQuantization, ScoreStage, ScoreStageKind, ScoredPoint,
+    SearchParams, ShardKey, ShardKeyDescription, StopwordsSet, StrictModeConfig,
+    TextIndexCompression, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
+    ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector, min_should_match,
+    shard_key, with_vectors_selector,
 };
 use crate::grpc::{
     self, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, DecayParamsExpression,
     DivExpression, GeoDistance, MultExpression, PowExpression, SumExpression,
+    TextScore as GrpcTextScore,
 };
 use crate::rest::models::{CollectionsResponse, ShardKeysResponse, VersionInfo};
 use crate::rest::schema as rest;
@@ -191,6 +196,27 @@ impl From<segment::data_types::index::TokenizerType> for TokenizerType {
     }
 }
 
+impl From<segment::data_types::index::TextIndexCompression> for TextIndexCompression {
+    fn from(compression: segment::data_types::index::TextIndexCompression) -> Self {
+        match compression {
+            segment::data_types::index::TextIndexCompression::Lz4 => TextIndexCompression::Lz4,
+            segment::data_types::index::TextIndexCompression::Zstd => TextIndexCompression::Zstd,
+        }
+    }
+}
+
+impl From<segment::data_types::index::OversizedValuePolicy> for OversizedValuePolicy {
+    fn from(policy: segment::data_types::index::OversizedValuePolicy) -> Self {
+        match policy {
+            segment::data_types::index::OversizedValuePolicy::Skip => OversizedValuePolicy::Skip,
+            segment::data_types::index::OversizedValuePolicy::Truncate => {
+                OversizedValuePolicy::Truncate
+            }
+            segment::data_types::index::OversizedValuePolicy::Error => OversizedValuePolicy::Error,
+        }
+    }
+}
+
 impl From<segment::data_types::index::KeywordIndexParams> for PayloadIndexParams {
     fn from(params: segment::data_types::index::KeywordIndexParams) -> Self {
         let segment::data_types::index::KeywordIndexParams {
@@ -198,12 +224,21 @@ impl From<segment::data_types::index::KeywordIndexParams> for PayloadIndexParams
             is_tenant,
             on_disk,
             enable_hnsw,
+            deduplicate_values,
+            max_value_bytes,
+            oversized_value_policy,
+            case_insensitive,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::KeywordIndexParams(KeywordIndexParams {
                 is_tenant,
                 on_disk,
                 enable_hnsw,
+                deduplicate_values,
+                max_value_bytes: max_value_bytes.map(|x| x as u64),
+                oversized_value_policy: oversized_value_policy
+                    .map(|p| OversizedValuePolicy::from(p) as i32),
+                case_insensitive,
             })),
         }
     }
@@ -238,12 +273,14 @@ impl From<segment::data_types::index::FloatIndexParams> for PayloadIndexParams {
             on_disk,
             is_principal,
             enable_hnsw,
+            precision,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::FloatIndexParams(FloatIndexParams {
                 on_disk,
                 is_principal,
                 enable_hnsw,
+                precision: precision.map(OrderedFloat::into_inner),
             })),
         }
     }
@@ -255,11 +292,13 @@ impl From<segment::data_types::index::GeoIndexParams> for PayloadIndexParams {
             r#type: _,
             on_disk,
             enable_hnsw,
+            h3_resolution,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::GeoIndexParams(GeoIndexParams {
                 on_disk,
                 enable_hnsw,
+                h3_resolution: h3_resolution.map(u32::from),
             })),
         }
     }
@@ -279,6 +318,15 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
             stopwords,
             stemmer,
             enable_hnsw,
+            compression,
+            index_unstemmed,
+            max_value_bytes,
+            oversized_value_policy,
+            auto_stopwords_threshold,
+            strip_html,
+            decode_html_entities,
+            strip_html_queries,
+            forward_index,
         } = params;
         let tokenizer = TokenizerType::from(tokenizer);
 
@@ -299,6 +347,16 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
                 stopwords: stopwords_set,
                 stemmer: stemming_algo,
                 enable_hnsw,
+                compression: compression.map(|c| TextIndexCompression::from(c) as i32),
+                index_unstemmed,
+                max_value_bytes: max_value_bytes.map(|x| x as u64),
+                oversized_value_policy: oversized_value_policy
+                    .map(|p| OversizedValuePolicy::from(p) as i32),
+                auto_stopwords_threshold,
+                strip_html,
+                decode_html_entities,
+                strip_html_queries,
+                forward_index,
             })),
         }
     }
@@ -310,11 +368,13 @@ impl From<segment::data_types::index::BoolIndexParams> for PayloadIndexParams {
             r#type: _,
             on_disk,
             enable_hnsw,
+            max_positions,
         } = params;
         PayloadIndexParams {
             index_params: Some(IndexParams::BoolIndexParams(BoolIndexParams {
                 on_disk,
                 enable_hnsw,
+                max_positions: max_positions.map(|positions| positions as u64),
             })),
         }
     }
@@ -410,6 +470,8 @@ impl From<segment::data_types::index::StopwordsInterface> for StopwordsSet {
                 StopwordsSet {
                     languages: vec![lang_str],
                     custom: vec![],
+                    auto: None,
+                    exclude: vec![],
                 }
             }
             segment::data_types::index::StopwordsInterface::Set(set) => {
@@ -425,8 +487,24 @@ impl From<segment::data_types::index::StopwordsInterface> for StopwordsSet {
                     vec![]
                 };
 
-                StopwordsSet { languages, custom }
+                let exclude = set
+                    .exclude
+                    .map(|exclude| exclude.into_iter().collect())
+                    .unwrap_or_default();
+
+                StopwordsSet {
+                    languages,
+                    custom,
+                    auto: None,
+                    exclude,
+                }
             }
+            segment::data_types::index::StopwordsInterface::Auto(_) => StopwordsSet {
+                languages: vec![],
+                custom: vec![],
+                auto: Some(true),
+                exclude: vec![],
+            },
         }
     }
 }
@@ -465,6 +543,54 @@ impl TryFrom<TokenizerType> for segment::data_types::index::TokenizerType {
     }
 }
 
+impl TryFrom<TextIndexCompression> for segment::data_types::index::TextIndexCompression {
+    type Error = Status;
+    fn try_from(compression: TextIndexCompression) -> Result<Self, Self::Error> {
+        match compression {
+            TextIndexCompression::UnknownTextIndexCompression => {
+                Err(Status::invalid_argument("unknown text index compression"))
+            }
+            TextIndexCompression::Lz4 => Ok(segment::data_types::index::TextIndexCompression::Lz4),
+            TextIndexCompression::Zstd => {
+                Ok(segment::data_types::index::TextIndexCompression::Zstd)
+            }
+        }
+    }
+}
+
+impl TryFrom<OversizedValuePolicy> for segment::data_types::index::OversizedValuePolicy {
+    type Error = Status;
+    fn try_from(policy: OversizedValuePolicy) -> Result<Self, Self::Error> {
+        match policy {
+            OversizedValuePolicy::UnknownOversizedValuePolicy => {
+                Err(Status::invalid_argument("unknown oversized value policy"))
+            }
+            OversizedValuePolicy::Skip => {
+                Ok(segment::data_types::index::OversizedValuePolicy::Skip)
+            }
+            OversizedValuePolicy::Truncate => {
+                Ok(segment::data_types::index::OversizedValuePolicy::Truncate)
+            }
+            OversizedValuePolicy::Error => {
+                Ok(segment::data_types::index::OversizedValuePolicy::Error)
+            }
+        }
+    }
+}
+
+/// Convert an optional oversized value policy of gRPC index params
+fn oversized_value_policy_from_grpc(
+    policy: Option<i32>,
+) -> Result<Option<segment::data_types::index::OversizedValuePolicy>, Status> {
+    policy
+        .map(|p| {
+            OversizedValuePolicy::try_from(p)
+                .map_err(|_| Status::invalid_argument("unknown oversized value policy"))?
+                .try_into()
+        })
+        .transpose()
+}
+
 impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
     fn from(params: segment::types::PayloadSchemaParams) -> Self {
         match params {
@@ -487,12 +613,20 @@ impl TryFrom<KeywordIndexParams> for segment::data_types::index::KeywordIndexPar
             is_tenant,
             on_disk,
             enable_hnsw,
+            deduplicate_values,
+            max_value_bytes,
+            oversized_value_policy,
+            case_insensitive,
         } = params;
         Ok(segment::data_types::index::KeywordIndexParams {
             r#type: KeywordIndexType::Keyword,
             is_tenant,
             on_disk,
             enable_hnsw,
+            deduplicate_values,
+            max_value_bytes: max_value_bytes.map(|x| x as usize),
+            oversized_value_policy: oversized_value_policy_from_grpc(oversized_value_policy)?,
+            case_insensitive,
         })
     }
 }
@@ -525,12 +659,14 @@ impl TryFrom<FloatIndexParams> for segment::data_types::index::FloatIndexParams
             on_disk,
             is_principal,
             enable_hnsw,
+            precision,
         } = params;
         Ok(segment::data_types::index::FloatIndexParams {
             r#type: FloatIndexType::Float,
             on_disk,
             is_principal,
             enable_hnsw,
+            precision: precision.map(OrderedFloat::from),
         })
     }
 }
@@ -541,11 +677,17 @@ impl TryFrom<GeoIndexParams> for segment::data_types::index::GeoIndexParams {
         let GeoIndexParams {
             on_disk,
             enable_hnsw,
+            h3_resolution,
         } = params;
+        let h3_resolution = h3_resolution
+            .map(u8::try_from)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("h3_resolution is out of range"))?;
         Ok(segment::data_types::index::GeoIndexParams {
             r#type: GeoIndexType::Geo,
             on_disk,
             enable_hnsw,
+            h3_resolution,
         })
     }
 }
@@ -554,7 +696,23 @@ impl TryFrom<StopwordsSet> for segment::data_types::index::StopwordsInterface {
     type Error = Status;
 
     fn try_from(value: StopwordsSet) -> Result<Self, Self::Error> {
-        let StopwordsSet { languages, custom } = value;
+        let StopwordsSet {
+            languages,
+            custom,
+            auto,
+            exclude,
+        } = value;
+
+        if auto.unwrap_or(false) {
+            if !languages.is_empty() || !custom.is_empty() || !exclude.is_empty() {
+                return Err(Status::invalid_argument(
+                    "auto stopwords can't be combined with languages and custom stopwords",
+                ));
+            }
+            return Ok(segment::data_types::index::StopwordsInterface::Auto(
+                segment::data_types::index::StopwordsDetection::Auto,
+            ));
+        }
 
         let result_languages = if languages.is_empty() {
             None
@@ -572,6 +730,7 @@ impl TryFrom<StopwordsSet> for segment::data_types::index::StopwordsInterface {
             segment::data_types::index::StopwordsSet {
                 languages: result_languages,
                 custom: (!custom.is_empty()).then_some(custom.into_iter().collect()),
+                exclude: (!exclude.is_empty()).then_some(exclude.into_iter().collect()),
             },
         ))
     }
@@ -591,6 +750,15 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             stopwords,
             stemmer,
             enable_hnsw,
+            compression,
+            index_unstemmed,
+            max_value_bytes,
+            oversized_value_policy,
+            auto_stopwords_threshold,
+            strip_html,
+            decode_html_entities,
+            strip_html_queries,
+            forward_index,
         } = params;
 
         // Convert stopwords if present
@@ -607,6 +775,14 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             .map(segment::data_types::index::StemmingAlgorithm::try_from)
             .transpose()?;
 
+        let compression = compression
+            .map(|c| {
+                TextIndexCompression::try_from(c)
+                    .map_err(|_| Status::invalid_argument("unknown text index compression"))?
+                    .try_into()
+            })
+            .transpose()?;
+
         Ok(segment::data_types::index::TextIndexParams {
             r#type: TextIndexType::Text,
             tokenizer: TokenizerType::try_from(tokenizer)
@@ -621,6 +797,15 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             stopwords: stopwords_converted,
             stemmer,
             enable_hnsw,
+            compression,
+            index_unstemmed,
+            max_value_bytes: max_value_bytes.map(|x| x as usize),
+            oversized_value_policy: oversized_value_policy_from_grpc(oversized_value_policy)?,
+            auto_stopwords_threshold,
+            strip_html,
+            decode_html_entities,
+            strip_html_queries,
+            forward_index,
         })
     }
 }
@@ -651,11 +836,13 @@ impl TryFrom<BoolIndexParams> for segment::data_types::index::BoolIndexParams {
         let BoolIndexParams {
             on_disk,
             enable_hnsw,
+            max_positions,
         } = params;
         Ok(segment::data_types::index::BoolIndexParams {
             r#type: BoolIndexType::Bool,
             on_disk,
             enable_hnsw,
+            max_positions: max_positions.map(|positions| positions as usize),
         })
     }
 }
@@ -856,11 +1043,13 @@ impl From<QuantizationSearchParams> for segment::types::QuantizationSearchParams
             ignore,
             rescore,
             oversampling,
+            fallback,
         } = params;
         Self {
             ignore: ignore.unwrap_or(default_quantization_ignore_value()),
             rescore,
             oversampling,
+            fallback: fallback.map(Into::into),
         }
     }
 }
@@ -871,11 +1060,43 @@ impl From<segment::types::QuantizationSearchParams> for QuantizationSearchParams
             ignore,
             rescore,
             oversampling,
+            fallback,
         } = params;
         Self {
             ignore: Some(ignore),
             rescore,
             oversampling,
+            fallback: fallback.map(Into::into),
+        }
+    }
+}
+
+impl From<QuantizationFallbackParams> for segment::types::QuantizationFallbackParams {
+    fn from(params: QuantizationFallbackParams) -> Self {
+        let QuantizationFallbackParams {
+            enable,
+            max_distortion,
+            max_oversampling,
+        } = params;
+        Self {
+            enable: enable.unwrap_or(false),
+            max_distortion: max_distortion.map(OrderedFloat),
+            max_oversampling: max_oversampling.map(OrderedFloat),
+        }
+    }
+}
+
+impl From<segment::types::QuantizationFallbackParams> for QuantizationFallbackParams {
+    fn from(params: segment::types::QuantizationFallbackParams) -> Self {
+        let segment::types::QuantizationFallbackParams {
+            enable,
+            max_distortion,
+            max_oversampling,
+        } = params;
+        Self {
+            enable: Some(enable),
+            max_distortion: max_distortion.map(|OrderedFloat(x)| x),
+            max_oversampling: max_oversampling.map(|OrderedFloat(x)| x),
         }
     }
 }
@@ -914,6 +1135,9 @@ impl From<SearchParams> for segment::types::SearchParams {
             quantization,
             indexed_only,
             acorn,
+            missing_vectors,
+            segment_limit_factor,
+            allow_full_scan,
         } = params;
         Self {
             hnsw_ef: hnsw_ef.map(|x| x as usize),
@@ -921,6 +1145,15 @@ impl From<SearchParams> for segment::types::SearchParams {
             quantization: quantization.map(QuantizationSearchParams::into),
             indexed_only: indexed_only.unwrap_or(false),
             acorn: acorn.map(segment::types::AcornSearchParams::from),
+            missing_vectors: missing_vectors
+                .and_then(|missing_vectors| grpc::MissingVectors::try_from(missing_vectors).ok())
+                .and_then(|missing_vectors| match missing_vectors {
+                    grpc::MissingVectors::UnknownMissingVectors => None,
+                    grpc::MissingVectors::Exclude => Some(segment::types::MissingVectors::Exclude),
+                    grpc::MissingVectors::Include => Some(segment::types::MissingVectors::Include),
+                }),
+            segment_limit_factor: segment_limit_factor.map(OrderedFloat),
+            allow_full_scan: allow_full_scan.unwrap_or(false),
         }
     }
 }
@@ -933,6 +1166,9 @@ impl From<segment::types::SearchParams> for SearchParams {
             quantization,
             indexed_only,
             acorn,
+            missing_vectors,
+            segment_limit_factor,
+            allow_full_scan,
         } = params;
         Self {
             hnsw_ef: hnsw_ef.map(|x| x as u64),
@@ -940,6 +1176,15 @@ impl From<segment::types::SearchParams> for SearchParams {
             quantization: quantization.map(Into::into),
             indexed_only: Some(indexed_only),
             acorn: acorn.map(AcornSearchParams::from),
+            missing_vectors: missing_vectors.map(|missing_vectors| {
+                let missing_vectors = match missing_vectors {
+                    segment::types::MissingVectors::Exclude => grpc::MissingVectors::Exclude,
+                    segment::types::MissingVectors::Include => grpc::MissingVectors::Include,
+                };
+                missing_vectors as i32
+            }),
+            segment_limit_factor: segment_limit_factor.map(|OrderedFloat(x)| x),
+            allow_full_scan: Some(allow_full_scan),
         }
     }
 }
@@ -996,6 +1241,7 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vector,
             shard_key,
             order_value,
+            score: _,
         } = record;
         let retrieved_point = Self {
             id: Some(PointId::from(id)),
@@ -1058,6 +1304,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            provenance,
+            score_stages,
         } = point;
         Self {
             id: Some(PointId::from(id)),
@@ -1067,10 +1315,105 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            provenance: provenance.map(PointProvenance::from),
+            score_stages: score_stages.into_iter().map(ScoreStage::from).collect(),
+        }
+    }
+}
+
+impl From<segment::types::ScoredPointProvenance> for PointProvenance {
+    fn from(provenance: segment::types::ScoredPointProvenance) -> Self {
+        let segment::types::ScoredPointProvenance {
+            segment_uuid,
+            appendable,
+            shard_id,
+            peer_id,
+        } = provenance;
+        Self {
+            segment_uuid: segment_uuid.to_string(),
+            appendable,
+            shard_id,
+            peer_id,
+        }
+    }
+}
+
+impl TryFrom<PointProvenance> for segment::types::ScoredPointProvenance {
+    type Error = Status;
+
+    fn try_from(provenance: PointProvenance) -> Result<Self, Self::Error> {
+        let PointProvenance {
+            segment_uuid,
+            appendable,
+            shard_id,
+            peer_id,
+        } = provenance;
+        let segment_uuid = Uuid::parse_str(&segment_uuid).map_err(|_err| {
+            Status::invalid_argument(format!("Unable to parse UUID: {segment_uuid}"))
+        })?;
+        Ok(Self {
+            segment_uuid,
+            appendable,
+            shard_id,
+            peer_id,
+        })
+    }
+}
+
+impl From<segment::types::ScoreStage> for ScoreStage {
+    fn from(stage: segment::types::ScoreStage) -> Self {
+        let segment::types::ScoreStage { kind, score } = stage;
+        let (kind, boost_rule, boost_multiplier) = match kind {
+            segment::types::ScoreStageKind::Vector => (ScoreStageKind::Vector, None, None),
+            segment::types::ScoreStageKind::Formula => (ScoreStageKind::Formula, None, None),
+            segment::types::ScoreStageKind::Fusion => (ScoreStageKind::Fusion, None, None),
+            segment::types::ScoreStageKind::Mmr => (ScoreStageKind::Mmr, None, None),
+            segment::types::ScoreStageKind::Boost { rule, multiplier } => {
+                (ScoreStageKind::Boost, Some(rule), Some(multiplier))
+            }
+        };
+        Self {
+            kind: kind as i32,
+            score,
+            boost_rule,
+            boost_multiplier,
         }
     }
 }
 
+impl TryFrom<ScoreStage> for segment::types::ScoreStage {
+    type Error = Status;
+
+    fn try_from(stage: ScoreStage) -> Result<Self, Self::Error> {
+        let ScoreStage {
+            kind,
+            score,
+            boost_rule,
+            boost_multiplier,
+        } = stage;
+        let kind = match ScoreStageKind::try_from(kind) {
+            Ok(ScoreStageKind::Vector) => segment::types::ScoreStageKind::Vector,
+            Ok(ScoreStageKind::Formula) => segment::types::ScoreStageKind::Formula,
+            Ok(ScoreStageKind::Fusion) => segment::types::ScoreStageKind::Fusion,
+            Ok(ScoreStageKind::Mmr) => segment::types::ScoreStageKind::Mmr,
+            Ok(ScoreStageKind::Boost) => {
+                let (Some(rule), Some(multiplier)) = (boost_rule, boost_multiplier) else {
+                    return Err(Status::invalid_argument(
+                        "Boost score stage must have a rule and a multiplier",
+                    ));
+                };
+                segment::types::ScoreStageKind::Boost { rule, multiplier }
+            }
+            Err(_) => {
+                return Err(Status::invalid_argument(format!(
+                    "Unknown score stage kind: {kind}"
+                )));
+            }
+        };
+        Ok(Self { kind, score })
+    }
+}
+
 impl TryFrom<rest::ScoredPoint> for ScoredPoint {
     type Error = OperationError;
     fn try_from(point: rest::ScoredPoint) -> Result<Self, Self::Error> {
@@ -1091,6 +1434,8 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            provenance: None,
+            score_stages: Vec::new(),
         })
     }
 }
@@ -1186,6 +1531,12 @@ impl From<segment::types::ScalarQuantization> for ScalarQuantization {
             },
             quantile: config.quantile,
             always_ram: config.always_ram,
+            outliers: config.outliers.map(|outliers| match outliers {
+                segment::types::ScalarOutliers::Clamp => ScalarOutliers::Clamp as i32,
+                segment::types::ScalarOutliers::FullPrecision => {
+                    ScalarOutliers::FullPrecision as i32
+                }
+            }),
         }
     }
 }
@@ -1198,7 +1549,17 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
             r#type,
             quantile,
             always_ram,
+            outliers,
         } = value;
+        let outliers = outliers
+            .map(|outliers| match ScalarOutliers::try_from(outliers) {
+                Ok(ScalarOutliers::Clamp) => Ok(segment::types::ScalarOutliers::Clamp),
+                Ok(ScalarOutliers::FullPrecision) => {
+                    Ok(segment::types::ScalarOutliers::FullPrecision)
+                }
+                Err(_) => Err(Status::invalid_argument("Unknown scalar outliers handling")),
+            })
+            .transpose()?;
         Ok(segment::types::ScalarQuantization {
             scalar: segment::types::ScalarQuantizationConfig {
                 r#type: match QuantizationType::try_from(r#type).ok() {
@@ -1209,6 +1570,7 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
                 },
                 quantile,
                 always_ram,
+                outliers,
             },
         })
     }
@@ -1661,6 +2023,14 @@ pub fn grpc_condition_into_condition(
                 has_vector: has_vector.has_vector,
             },
         )),
+        ConditionOneOf::UpdatedAfter(updated_after) => Some(
+            segment::types::Condition::UpdatedAfter(segment::types::UpdatedAfterCondition {
+                updated_after: updated_after.updated_after,
+            }),
+        ),
+        ConditionOneOf::Expression(expression) => Some(segment::types::Condition::Expression(
+            expression.try_into()?,
+        )),
     };
 
     Ok(condition)
@@ -1695,12 +2065,136 @@ impl From<segment::types::Condition> for Condition {
                     has_vector: has_vector.has_vector,
                 }))
             }
+            segment::types::Condition::UpdatedAfter(updated_after) => {
+                Some(ConditionOneOf::UpdatedAfter(UpdatedAfterCondition {
+                    updated_after: updated_after.updated_after,
+                }))
+            }
+            segment::types::Condition::Expression(expression) => Some(ConditionOneOf::Expression(
+                ExpressionCondition::from(expression),
+            )),
         };
 
         Self { condition_one_of }
     }
 }
 
+impl TryFrom<ExpressionCondition> for segment::types::ExpressionCondition {
+    type Error = Status;
+
+    fn try_from(value: ExpressionCondition) -> Result<Self, Self::Error> {
+        let ExpressionCondition { expression, range } = value;
+        let expression = expression
+            .ok_or_else(|| Status::invalid_argument("Expression condition must have expression"))?;
+        let range = range
+            .ok_or_else(|| Status::invalid_argument("Expression condition must have range"))?;
+        Ok(Self {
+            expression: expression.try_into()?,
+            range: range.into(),
+        })
+    }
+}
+
+impl From<segment::types::ExpressionCondition> for ExpressionCondition {
+    fn from(value: segment::types::ExpressionCondition) -> Self {
+        let segment::types::ExpressionCondition { expression, range } = value;
+        Self {
+            expression: Some(expression.into()),
+            range: Some(range.into()),
+        }
+    }
+}
+
+impl TryFrom<ArithmeticExpression> for segment::types::ArithmeticExpression {
+    type Error = Status;
+
+    fn try_from(value: ArithmeticExpression) -> Result<Self, Self::Error> {
+        use arithmetic_expression::Variant;
+
+        let ArithmeticExpression { variant } = value;
+        let variant = variant
+            .ok_or_else(|| Status::invalid_argument("Arithmetic expression must have variant"))?;
+        let expression = match variant {
+            Variant::Constant(constant) => Self::Constant(OrderedFloat(constant)),
+            Variant::Key(key) => Self::Key(json::json_path_from_proto(&key)?),
+            Variant::Sum(ArithmeticExpressions { expressions }) => {
+                Self::Sum(segment::types::ArithmeticSum {
+                    sum: try_arithmetic_operands_from_grpc(expressions, "sum")?,
+                })
+            }
+            Variant::Mult(ArithmeticExpressions { expressions }) => {
+                Self::Mult(segment::types::ArithmeticMult {
+                    mult: try_arithmetic_operands_from_grpc(expressions, "mult")?,
+                })
+            }
+            Variant::Neg(neg) => Self::Neg(segment::types::ArithmeticNeg {
+                neg: Box::new((*neg).try_into()?),
+            }),
+            Variant::Div(div) => {
+                let ArithmeticDiv { left, right } = *div;
+                let (Some(left), Some(right)) = (left, right) else {
+                    return Err(Status::invalid_argument(
+                        "Arithmetic div must have left and right operands",
+                    ));
+                };
+                Self::Div(segment::types::ArithmeticDiv {
+                    div: segment::types::ArithmeticDivParams {
+                        left: Box::new((*left).try_into()?),
+                        right: Box::new((*right).try_into()?),
+                    },
+                })
+            }
+        };
+        Ok(expression)
+    }
+}
+
+fn try_arithmetic_operands_from_grpc(
+    expressions: Vec<ArithmeticExpression>,
+    operation: &str,
+) -> Result<Vec<segment::types::ArithmeticExpression>, Status> {
+    if expressions.is_empty() {
+        return Err(Status::invalid_argument(format!(
+            "Arithmetic {operation} must have operands"
+        )));
+    }
+    expressions.into_iter().map(TryInto::try_into).collect()
+}
+
+impl From<segment::types::ArithmeticExpression> for ArithmeticExpression {
+    fn from(value: segment::types::ArithmeticExpression) -> Self {
+        use arithmetic_expression::Variant;
+        use segment::types::ArithmeticExpression as SegmentExpression;
+
+        let variant = match value {
+            SegmentExpression::Constant(constant) => Variant::Constant(constant.0),
+            SegmentExpression::Key(key) => Variant::Key(key.to_string()),
+            SegmentExpression::Sum(segment::types::ArithmeticSum { sum }) => {
+                Variant::Sum(ArithmeticExpressions {
+                    expressions: sum.into_iter().map(Self::from).collect(),
+                })
+            }
+            SegmentExpression::Mult(segment::types::ArithmeticMult { mult }) => {
+                Variant::Mult(ArithmeticExpressions {
+                    expressions: mult.into_iter().map(Self::from).collect(),
+                })
+            }
+            SegmentExpression::Neg(segment::types::ArithmeticNeg { neg }) => {
+                Variant::Neg(Box::new(Self::from(*neg)))
+            }
+            SegmentExpression::Div(segment::types::ArithmeticDiv { div }) => {
+                Variant::Div(Box::new(ArithmeticDiv {
+                    left: Some(Box::new(Self::from(*div.left))),
+                    right: Some(Box::new(Self::from(*div.right))),
+                }))
+            }
+        };
+        Self {
+            variant: Some(variant),
+        }
+    }
+}
+
 impl TryFrom<NestedCondition> for segment::types::Nested {
     type Error = Status;
 
@@ -1754,20 +2248,57 @@ impl TryFrom<IsNullCondition> for segment::types::IsNullCondition {
     type Error = Status;
 
     fn try_from(value: IsNullCondition) -> Result<Self, Status> {
-        let IsNullCondition { key } = value;
+        let IsNullCondition { key, null_elements } = value;
+        let null_elements = null_elements
+            .map(|null_elements| {
+                NullElements::try_from(null_elements)
+                    .map_err(|_| Status::invalid_argument("unknown null elements"))?
+                    .try_into()
+            })
+            .transpose()?;
         Ok(segment::types::IsNullCondition {
             is_null: segment::types::PayloadField {
                 key: json::json_path_from_proto(&key)?,
             },
+            null_elements,
         })
     }
 }
 
 impl From<segment::types::IsNullCondition> for IsNullCondition {
     fn from(value: segment::types::IsNullCondition) -> Self {
-        let segment::types::IsNullCondition { is_null } = value;
+        let segment::types::IsNullCondition {
+            is_null,
+            null_elements,
+        } = value;
         Self {
             key: is_null.key.to_string(),
+            null_elements: null_elements.map(|n| NullElements::from(n) as i32),
+        }
+    }
+}
+
+impl TryFrom<NullElements> for segment::types::NullElements {
+    type Error = Status;
+
+    fn try_from(value: NullElements) -> Result<Self, Self::Error> {
+        match value {
+            NullElements::UnknownNullElements => {
+                Err(Status::invalid_argument("unknown null elements"))
+            }
+            NullElements::Ignore => Ok(segment::types::NullElements::Ignore),
+            NullElements::Include => Ok(segment::types::NullElements::Include),
+            NullElements::Only => Ok(segment::types::NullElements::Only),
+        }
+    }
+}
+
+impl From<segment::types::NullElements> for NullElements {
+    fn from(value: segment::types::NullElements) -> Self {
+        match value {
+            segment::types::NullElements::Ignore => NullElements::Ignore,
+            segment::types::NullElements::Include => NullElements::Include,
+            segment::types::NullElements::Only => NullElements::Only,
         }
     }
 }
@@ -1808,12 +2339,15 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             datetime_range,
             is_empty,
             is_null,
+            geo_near_path,
+            uuid_range,
         } = value;
 
         let geo_bounding_box =
             geo_bounding_box.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_radius = geo_radius.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
         let geo_polygon = geo_polygon.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
+        let geo_near_path = geo_near_path.map_or_else(|| Ok(None), |g| g.try_into().map(Some))?;
 
         let mut range = range.map(Range::into);
         if range.is_none() {
@@ -1821,6 +2355,11 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
                 .map(segment::types::RangeInterface::try_from)
                 .transpose()?;
         }
+        if range.is_none() {
+            range = uuid_range
+                .map(segment::types::RangeInterface::try_from)
+                .transpose()?;
+        }
 
         Ok(Self {
             key: json::json_path_from_proto(&key)?,
@@ -1829,6 +2368,7 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_near_path,
             values_count: values_count.map(ValuesCount::into),
             is_empty,
             is_null,
@@ -1845,15 +2385,21 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_near_path,
             values_count,
             is_empty,
             is_null,
         } = value;
 
-        let (range, datetime_range) = match range {
-            Some(segment::types::RangeInterface::Float(range)) => (Some(Range::from(range)), None),
-            Some(segment::types::RangeInterface::DateTime(range)) => (None, Some(range.into())),
-            None => (None, None),
+        let (range, datetime_range, uuid_range) = match range {
+            Some(segment::types::RangeInterface::Float(range)) => {
+                (Some(Range::from(range)), None, None)
+            }
+            Some(segment::types::RangeInterface::DateTime(range)) => {
+                (None, Some(range.into()), None)
+            }
+            Some(segment::types::RangeInterface::Uuid(range)) => (None, None, Some(range.into())),
+            None => (None, None, None),
         };
 
         Self {
@@ -1867,6 +2413,8 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             datetime_range,
             is_empty,
             is_null,
+            geo_near_path: geo_near_path.map(Into::into),
+            uuid_range,
         }
     }
 }
@@ -1936,9 +2484,11 @@ impl TryFrom<GeoPolygon> for segment::types::GeoPolygon {
             GeoPolygon {
                 exterior: Some(e),
                 interiors,
+                tolerance,
             } => Ok(Self {
                 exterior: e.into(),
                 interiors: Some(interiors.into_iter().map(GeoLineString::into).collect()),
+                tolerance: tolerance.map(OrderedFloat),
             }),
             _ => Err(Status::invalid_argument(
                 "Malformed GeoPolygon type - field `exterior` is required",
@@ -1952,6 +2502,7 @@ impl From<segment::types::GeoPolygon> for GeoPolygon {
         let segment::types::GeoPolygon {
             exterior,
             interiors,
+            tolerance,
         } = value;
         Self {
             exterior: Some(exterior.into()),
@@ -1960,6 +2511,31 @@ impl From<segment::types::GeoPolygon> for GeoPolygon {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            tolerance: tolerance.map(|OrderedFloat(tolerance)| tolerance),
+        }
+    }
+}
+
+impl TryFrom<GeoNearPath> for segment::types::GeoNearPath {
+    type Error = Status;
+
+    fn try_from(value: GeoNearPath) -> Result<Self, Self::Error> {
+        let GeoNearPath { path, distance } = value;
+        let shadow = segment::types::GeoNearPathShadow {
+            path: path.into_iter().map(GeoPoint::into).collect(),
+            distance: OrderedFloat(FloatPayloadType::from(distance)),
+        };
+        Self::try_from(shadow)
+            .map_err(|err| Status::invalid_argument(format!("Malformed GeoNearPath type: {err}")))
+    }
+}
+
+impl From<segment::types::GeoNearPath> for GeoNearPath {
+    fn from(value: segment::types::GeoNearPath) -> Self {
+        let segment::types::GeoNearPath { path, distance } = value;
+        Self {
+            path: path.into_iter().map(Into::into).collect(),
+            distance: distance.0 as f32,
         }
     }
 }
@@ -2048,26 +2624,70 @@ impl From<segment::types::Range<DateTimePayloadType>> for DatetimeRange {
     }
 }
 
+impl TryFrom<UuidRange> for segment::types::RangeInterface {
+    type Error = Status;
+
+    fn try_from(value: UuidRange) -> Result<Self, Self::Error> {
+        let UuidRange { lt, gt, gte, lte } = value;
+        let parse = |uuid: String| {
+            Uuid::parse_str(&uuid)
+                .map_err(|_err| Status::invalid_argument(format!("Unable to parse UUID: {uuid}")))
+        };
+        Ok(Self::Uuid(segment::types::Range {
+            lt: lt.map(parse).transpose()?,
+            gt: gt.map(parse).transpose()?,
+            gte: gte.map(parse).transpose()?,
+            lte: lte.map(parse).transpose()?,
+        }))
+    }
+}
+
+impl From<segment::types::Range<Uuid>> for UuidRange {
+    fn from(value: segment::types::Range<Uuid>) -> Self {
+        let segment::types::Range { lt, gt, gte, lte } = value;
+        Self {
+            lt: lt.map(|uuid| uuid.to_string()),
+            gt: gt.map(|uuid| uuid.to_string()),
+            gte: gte.map(|uuid| uuid.to_string()),
+            lte: lte.map(|uuid| uuid.to_string()),
+        }
+    }
+}
+
 impl From<ValuesCount> for segment::types::ValuesCount {
     fn from(value: ValuesCount) -> Self {
-        let ValuesCount { lt, gt, gte, lte } = value;
+        let ValuesCount {
+            lt,
+            gt,
+            gte,
+            lte,
+            distinct,
+        } = value;
         Self {
             lt: lt.map(|x| x as usize),
             gt: gt.map(|x| x as usize),
             gte: gte.map(|x| x as usize),
             lte: lte.map(|x| x as usize),
+            distinct,
         }
     }
 }
 
 impl From<segment::types::ValuesCount> for ValuesCount {
     fn from(value: segment::types::ValuesCount) -> Self {
-        let segment::types::ValuesCount { lt, gt, gte, lte } = value;
+        let segment::types::ValuesCount {
+            lt,
+            gt,
+            gte,
+            lte,
+            distinct,
+        } = value;
         Self {
             lt: lt.map(|x| x as u64),
             gt: gt.map(|x| x as u64),
             gte: gte.map(|x| x as u64),
             lte: lte.map(|x| x as u64),
+            distinct,
         }
     }
 }
@@ -2076,14 +2696,30 @@ impl TryFrom<Match> for segment::types::Match {
     type Error = Status;
 
     fn try_from(value: Match) -> Result<Self, Self::Error> {
-        let Match { match_value } = value;
+        let Match {
+            match_value,
+            min_should_match,
+            language,
+            unstemmed,
+            slop,
+        } = value;
+        let language = language
+            .map(|lang| segment::data_types::index::Language::from_str(&lang))
+            .transpose()
+            .map_err(|e| Status::invalid_argument(format!("unknown language: {e}")))?;
         match match_value {
             Some(mv) => Ok(match mv {
                 MatchValue::Keyword(kw) => kw.into(),
                 MatchValue::Integer(int) => int.into(),
                 MatchValue::Boolean(flag) => flag.into(),
-                MatchValue::Text(text) => segment::types::Match::Text(text.into()),
-                MatchValue::Phrase(phrase) => segment::types::Match::Phrase(phrase.into()),
+                MatchValue::Text(text) => segment::types::Match::Text(segment::types::MatchText {
+                    text,
+                    language,
+                    unstemmed,
+                }),
+                MatchValue::Phrase(phrase) => {
+                    segment::types::Match::Phrase(segment::types::MatchPhrase { phrase, slop })
+                }
                 MatchValue::Keywords(kwds) => kwds.strings.into(),
                 MatchValue::Integers(ints) => ints.integers.into(),
                 MatchValue::ExceptIntegers(kwds) => {
@@ -2092,8 +2728,22 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::ExceptKeywords(ints) => {
                     segment::types::Match::Except(ints.strings.into())
                 }
+                MatchValue::ExactKeywords(kwds) => {
+                    segment::types::Match::ExactSet(kwds.strings.into())
+                }
+                MatchValue::ExactIntegers(ints) => {
+                    segment::types::Match::ExactSet(ints.integers.into())
+                }
                 MatchValue::TextAny(text_any) => {
-                    segment::types::Match::TextAny(segment::types::MatchTextAny { text_any })
+                    segment::types::Match::TextAny(segment::types::MatchTextAny {
+                        text_any,
+                        min_should_match: min_should_match.map(TryInto::try_into).transpose()?,
+                    })
+                }
+                MatchValue::KeywordPrefix(prefix) => {
+                    segment::types::Match::KeywordPrefix(segment::types::MatchKeywordPrefix {
+                        prefix,
+                    })
                 }
             }),
             _ => Err(Status::invalid_argument("Malformed Match condition")),
@@ -2103,16 +2753,30 @@ impl TryFrom<Match> for segment::types::Match {
 
 impl From<segment::types::Match> for Match {
     fn from(value: segment::types::Match) -> Self {
+        let mut min_should_match = None;
+        let mut language = None;
+        let mut unstemmed = None;
+        let mut slop = None;
         let match_value = match value {
             segment::types::Match::Value(value) => match value.value {
                 segment::types::ValueVariants::String(kw) => MatchValue::Keyword(kw),
                 segment::types::ValueVariants::Integer(int) => MatchValue::Integer(int),
                 segment::types::ValueVariants::Bool(flag) => MatchValue::Boolean(flag),
             },
-            segment::types::Match::Text(segment::types::MatchText { text }) => {
+            segment::types::Match::Text(segment::types::MatchText {
+                text,
+                language: text_language,
+                unstemmed: text_unstemmed,
+            }) => {
+                language = text_language.map(|lang| lang.to_string());
+                unstemmed = text_unstemmed;
                 MatchValue::Text(text)
             }
-            segment::types::Match::Phrase(segment::types::MatchPhrase { phrase }) => {
+            segment::types::Match::Phrase(segment::types::MatchPhrase {
+                phrase,
+                slop: phrase_slop,
+            }) => {
+                slop = phrase_slop;
                 MatchValue::Phrase(phrase)
             }
             segment::types::Match::Any(any) => match any.any {
@@ -2135,16 +2799,74 @@ impl From<segment::types::Match> for Match {
                     MatchValue::ExceptIntegers(RepeatedIntegers { integers })
                 }
             },
-            segment::types::Match::TextAny(segment::types::MatchTextAny { text_any }) => {
+            segment::types::Match::ExactSet(exact_set) => match exact_set.exact_set {
+                segment::types::AnyVariants::Strings(strings) => {
+                    let strings = strings.into_iter().collect();
+                    MatchValue::ExactKeywords(RepeatedStrings { strings })
+                }
+                segment::types::AnyVariants::Integers(integers) => {
+                    let integers = integers.into_iter().collect();
+                    MatchValue::ExactIntegers(RepeatedIntegers { integers })
+                }
+            },
+            segment::types::Match::TextAny(segment::types::MatchTextAny {
+                text_any,
+                min_should_match: text_any_min_should_match,
+            }) => {
+                min_should_match = text_any_min_should_match.map(MinShouldMatch::from);
                 MatchValue::TextAny(text_any)
             }
+            segment::types::Match::KeywordPrefix(keyword_prefix) => {
+                MatchValue::KeywordPrefix(keyword_prefix.prefix)
+            }
         };
         Self {
             match_value: Some(match_value),
+            min_should_match,
+            language,
+            unstemmed,
+            slop,
         }
     }
 }
 
+impl TryFrom<MinShouldMatch> for segment::types::MinShouldMatch {
+    type Error = Status;
+
+    fn try_from(value: MinShouldMatch) -> Result<Self, Self::Error> {
+        let MinShouldMatch { value } = value;
+        match value {
+            Some(min_should_match::Value::Count(count)) => {
+                Ok(segment::types::MinShouldMatch::Count(count as usize))
+            }
+            Some(min_should_match::Value::Percent(percent)) => {
+                if percent > segment::types::MinShouldMatch::MAX_PERCENT {
+                    return Err(Status::invalid_argument(
+                        "MinShouldMatch percent must be in range 0..=100",
+                    ));
+                }
+                let percentage = segment::types::MinShouldMatchPercentage { percent };
+                Ok(segment::types::MinShouldMatch::Percentage(percentage))
+            }
+            None => Err(Status::invalid_argument("Malformed MinShouldMatch")),
+        }
+    }
+}
+
+impl From<segment::types::MinShouldMatch> for MinShouldMatch {
+    fn from(value: segment::types::MinShouldMatch) -> Self {
+        let value = match value {
+            segment::types::MinShouldMatch::Count(count) => {
+                min_should_match::Value::Count(count as u64)
+            }
+            segment::types::MinShouldMatch::Percentage(percentage) => {
+                min_should_match::Value::Percent(percentage.percent)
+            }
+        };
+        Self { value: Some(value) }
+    }
+}
+
 impl From<Direction> for segment::data_types::order_by::Direction {
     fn from(value: Direction) -> Self {
         match value {
@@ -2356,6 +3078,8 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            filter_max_selectivity,
+            filter_selectivity_warn_only,
             multivector_config,
             sparse_config,
             max_payload_index_count,
@@ -2380,6 +3104,8 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
             max_points_count: max_points_count.map(|i| i as usize),
             filter_max_conditions: filter_max_conditions.map(|i| i as usize),
             condition_max_size: condition_max_size.map(|i| i as usize),
+            filter_max_selectivity: filter_max_selectivity.map(f64::from),
+            filter_selectivity_warn_only,
             multivector_config: multivector_config
                 .map(segment::types::StrictModeMultivectorConfig::from),
             sparse_config: sparse_config.map(segment::types::StrictModeSparseConfig::from),
@@ -2485,6 +3211,8 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            filter_max_selectivity,
+            filter_selectivity_warn_only,
             multivector_config,
             sparse_config,
             max_payload_index_count,
@@ -2507,6 +3235,8 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             max_collection_payload_size_bytes: max_collection_payload_size_bytes.map(|i| i as u64),
             filter_max_conditions: filter_max_conditions.map(|i| i as u64),
             condition_max_size: condition_max_size.map(|i| i as u64),
+            filter_max_selectivity: filter_max_selectivity.map(|i| i as f32),
+            filter_selectivity_warn_only,
             multivector_config: multivector_config.map(StrictModeMultivectorConfig::from),
             sparse_config: sparse_config.map(StrictModeSparseConfig::from),
             max_points_count: max_points_count.map(|i| i as u64),
@@ -2536,6 +3266,8 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            filter_max_selectivity,
+            filter_selectivity_warn_only,
             multivector_config,
             sparse_config,
             max_payload_index_count,
@@ -2560,6 +3292,8 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
             max_points_count: max_points_count.map(|i| i as usize),
             filter_max_conditions: filter_max_conditions.map(|i| i as usize),
             condition_max_size: condition_max_size.map(|i| i as usize),
+            filter_max_selectivity: filter_max_selectivity.map(f64::from),
+            filter_selectivity_warn_only,
             multivector_config: multivector_config
                 .map(segment::types::StrictModeMultivectorConfigOutput::from),
             sparse_config: sparse_config.map(segment::types::StrictModeSparseConfigOutput::from),
@@ -3217,6 +3951,26 @@ impl From<segment_facets::FacetValueHit> for FacetHitInternal {
     }
 }
 
+impl From<ValueSampleInternal> for ValueSample {
+    fn from(sample: ValueSampleInternal) -> Self {
+        let ValueSampleInternal { value, count } = sample;
+        Self {
+            value,
+            count: count as usize,
+        }
+    }
+}
+
+impl From<ValueSample> for ValueSampleInternal {
+    fn from(sample: ValueSample) -> Self {
+        let ValueSample { value, count } = sample;
+        Self {
+            value,
+            count: count as u64,
+        }
+    }
+}
+
 impl From<segment_facets::FacetValueHit> for FacetHit {
     fn from(hit: segment_facets::FacetValueHit) -> Self {
         let segment_facets::FacetValueHit { value, count } = hit;
@@ -3379,10 +4133,11 @@ impl Formula {
             formula,
             payload_vars: _, // they are already in the expression
             conditions,
+            text_scores,
             defaults,
         } = value;
 
-        let expression = unparse_expression(formula, &conditions);
+        let expression = unparse_expression(formula, &conditions, &text_scores);
 
         let defaults = defaults
             .into_iter()
@@ -3399,6 +4154,7 @@ impl Formula {
 fn unparse_expression(
     expression: ParsedExpression,
     conditions: &Vec<segment::types::Condition>,
+    text_scores: &[TextScore],
 ) -> Expression {
     use segment::index::query_optimization::rescore_formula::parsed_formula::VariableId;
 
@@ -3417,6 +4173,18 @@ fn unparse_expression(
             origin: Some(GeoPoint::from(origin)),
             to: key.to_string(),
         }),
+        ParsedExpression::TextScore(text_score_idx) => {
+            let TextScore {
+                key,
+                text,
+                aggregation,
+            } = text_scores[text_score_idx].clone();
+            Variant::TextScore(GrpcTextScore {
+                key: key.to_string(),
+                text,
+                aggregation: Some(grpc::TextScoreAggregation::from(aggregation) as i32),
+            })
+        }
         ParsedExpression::Datetime(dt_expr) => match dt_expr {
             DatetimeExpression::Constant(date_time_wrapper) => {
                 Variant::Datetime(date_time_wrapper.to_string())
@@ -3428,43 +4196,53 @@ fn unparse_expression(
         ParsedExpression::Mult(exprs) => Variant::Mult(MultExpression {
             mult: exprs
                 .into_iter()
-                .map(|expr| unparse_expression(expr, conditions))
+                .map(|expr| unparse_expression(expr, conditions, text_scores))
                 .collect(),
         }),
         ParsedExpression::Sum(exprs) => Variant::Sum(SumExpression {
             sum: exprs
                 .into_iter()
-                .map(|expr| unparse_expression(expr, conditions))
+                .map(|expr| unparse_expression(expr, conditions, text_scores))
                 .collect(),
         }),
         ParsedExpression::Neg(expr) => {
-            Variant::Neg(Box::new(unparse_expression(*expr, conditions)))
+            Variant::Neg(Box::new(unparse_expression(*expr, conditions, text_scores)))
         }
         ParsedExpression::Div {
             left,
             right,
             by_zero_default,
         } => Variant::Div(Box::new(DivExpression {
-            left: Some(Box::new(unparse_expression(*left, conditions))),
-            right: Some(Box::new(unparse_expression(*right, conditions))),
+            left: Some(Box::new(unparse_expression(*left, conditions, text_scores))),
+            right: Some(Box::new(unparse_expression(
+                *right,
+                conditions,
+                text_scores,
+            ))),
             by_zero_default: by_zero_default.map(|v| v.0 as f32),
         })),
         ParsedExpression::Sqrt(expr) => {
-            Variant::Sqrt(Box::new(unparse_expression(*expr, conditions)))
+            Variant::Sqrt(Box::new(unparse_expression(*expr, conditions, text_scores)))
         }
         ParsedExpression::Pow { base, exponent } => Variant::Pow(Box::new(PowExpression {
-            base: Some(Box::new(unparse_expression(*base, conditions))),
-            exponent: Some(Box::new(unparse_expression(*exponent, conditions))),
+            base: Some(Box::new(unparse_expression(*base, conditions, text_scores))),
+            exponent: Some(Box::new(unparse_expression(
+                *exponent,
+                conditions,
+                text_scores,
+            ))),
         })),
         ParsedExpression::Exp(expr) => {
-            Variant::Exp(Box::new(unparse_expression(*expr, conditions)))
+            Variant::Exp(Box::new(unparse_expression(*expr, conditions, text_scores)))
         }
         ParsedExpression::Log10(expr) => {
-            Variant::Log10(Box::new(unparse_expression(*expr, conditions)))
+            Variant::Log10(Box::new(unparse_expression(*expr, conditions, text_scores)))
+        }
+        ParsedExpression::Ln(expr) => {
+            Variant::Ln(Box::new(unparse_expression(*expr, conditions, text_scores)))
         }
-        ParsedExpression::Ln(expr) => Variant::Ln(Box::new(unparse_expression(*expr, conditions))),
         ParsedExpression::Abs(expr) => {
-            Variant::Abs(Box::new(unparse_expression(*expr, conditions)))
+            Variant::Abs(Box::new(unparse_expression(*expr, conditions, text_scores)))
         }
         ParsedExpression::Decay {
             kind,
@@ -3474,8 +4252,8 @@ fn unparse_expression(
         } => {
             let (midpoint, scale) = ParsedExpression::decay_lambda_to_params(lambda.0, kind);
             let params = DecayParamsExpression {
-                x: Some(Box::new(unparse_expression(*x, conditions))),
-                target: target.map(|t| Box::new(unparse_expression(*t, conditions))),
+                x: Some(Box::new(unparse_expression(*x, conditions, text_scores))),
+                target: target.map(|t| Box::new(unparse_expression(*t, conditions, text_scores))),
                 midpoint: Some(midpoint),
                 scale: Some(scale),
             };
@@ -3492,6 +4270,24 @@ fn unparse_expression(
     }
 }
 
+impl From<grpc::TextScoreAggregation> for segment::types::TextScoreAggregation {
+    fn from(value: grpc::TextScoreAggregation) -> Self {
+        match value {
+            grpc::TextScoreAggregation::Max => segment::types::TextScoreAggregation::Max,
+            grpc::TextScoreAggregation::Sum => segment::types::TextScoreAggregation::Sum,
+        }
+    }
+}
+
+impl From<segment::types::TextScoreAggregation> for grpc::TextScoreAggregation {
+    fn from(value: segment::types::TextScoreAggregation) -> Self {
+        match value {
+            segment::types::TextScoreAggregation::Max => grpc::TextScoreAggregation::Max,
+            segment::types::TextScoreAggregation::Sum => grpc::TextScoreAggregation::Sum,
+        }
+    }
+}
+
 impl From<grpc::Modifier> for Modifier {
     fn from(value: grpc::Modifier) -> Self {
         match value {
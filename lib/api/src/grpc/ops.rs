@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::grpc::{HardwareUsage, InferenceUsage, ModelUsage, Usage};
+use crate::grpc::{HardwareUsage, InferenceUsage, ModelUsage, QueryStats, Usage};
 
 impl HardwareUsage {
     pub fn add(&mut self, other: Self) {
@@ -80,6 +80,64 @@ impl Usage {
     }
 }
 
+impl QueryStats {
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            segment_limit,
+            partial,
+            searched_segments,
+            total_segments,
+            quantization_fallbacks,
+        } = self;
+
+        segment_limit.is_none()
+            && partial.is_none()
+            && searched_segments.is_none()
+            && total_segments.is_none()
+            && quantization_fallbacks.is_none()
+    }
+
+    pub fn into_non_empty(self) -> Option<Self> {
+        (!self.is_empty()).then_some(self)
+    }
+
+    /// Record the statistics of a remote shard into the statistics of the request
+    pub fn accumulate_into(self, stats: &common::query_stats::QueryStats) {
+        let Self {
+            segment_limit,
+            partial: _, // Derived from the segment counts
+            searched_segments,
+            total_segments,
+            quantization_fallbacks,
+        } = self;
+
+        if let Some(segment_limit) = segment_limit {
+            stats.record_segment_limit(segment_limit as usize);
+        }
+        if let (Some(searched), Some(total)) = (searched_segments, total_segments) {
+            stats.record_searched_segments(searched as usize, total as usize);
+        }
+        if let Some(quantization_fallbacks) = quantization_fallbacks {
+            stats.record_quantization_fallbacks(quantization_fallbacks as usize);
+        }
+    }
+}
+
+impl From<&common::query_stats::QueryStats> for QueryStats {
+    fn from(stats: &common::query_stats::QueryStats) -> Self {
+        let searched_segments = stats.searched_segments();
+        Self {
+            segment_limit: stats.segment_limit().map(|limit| limit as u64),
+            partial: searched_segments.map(|segments| segments.is_partial()),
+            searched_segments: searched_segments.map(|segments| segments.searched as u64),
+            total_segments: searched_segments.map(|segments| segments.total as u64),
+            quantization_fallbacks: stats
+                .quantization_fallbacks()
+                .map(|searches| searches as u64),
+        }
+    }
+}
+
 impl InferenceUsage {
     pub fn new() -> Self {
         Self {
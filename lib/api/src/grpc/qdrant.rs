@@ -167,7 +167,7 @@ pub struct MinShould {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Condition {
-    #[prost(oneof = "condition::ConditionOneOf", tags = "1, 2, 3, 4, 5, 6, 7")]
+    #[prost(oneof = "condition::ConditionOneOf", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
     #[validate(nested)]
     pub condition_one_of: ::core::option::Option<condition::ConditionOneOf>,
 }
@@ -191,6 +191,10 @@ pub mod condition {
         Nested(super::NestedCondition),
         #[prost(message, tag = "7")]
         HasVector(super::HasVectorCondition),
+        #[prost(message, tag = "8")]
+        UpdatedAfter(super::UpdatedAfterCondition),
+        #[prost(message, tag = "9")]
+        Expression(super::ExpressionCondition),
     }
 }
 #[derive(serde::Serialize)]
@@ -206,6 +210,9 @@ pub struct IsEmptyCondition {
 pub struct IsNullCondition {
     #[prost(string, tag = "1")]
     pub key: ::prost::alloc::string::String,
+    /// How to treat arrays with null elements. Default: `Ignore` - only null values match
+    #[prost(enumeration = "NullElements", optional, tag = "2")]
+    pub null_elements: ::core::option::Option<i32>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -221,6 +228,73 @@ pub struct HasVectorCondition {
     #[prost(string, tag = "1")]
     pub has_vector: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatedAfterCondition {
+    /// Match points, which were updated after this version of the shard replica
+    #[prost(uint64, tag = "1")]
+    pub updated_after: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpressionCondition {
+    /// Arithmetic expression over numeric payload fields
+    #[prost(message, optional, tag = "1")]
+    pub expression: ::core::option::Option<ArithmeticExpression>,
+    /// Match points, for which the result of the expression is in this range
+    #[prost(message, optional, tag = "2")]
+    pub range: ::core::option::Option<Range>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArithmeticExpression {
+    #[prost(oneof = "arithmetic_expression::Variant", tags = "1, 2, 3, 4, 5, 6")]
+    pub variant: ::core::option::Option<arithmetic_expression::Variant>,
+}
+/// Nested message and enum types in `ArithmeticExpression`.
+pub mod arithmetic_expression {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        #[prost(double, tag = "1")]
+        Constant(f64),
+        /// Payload key with numeric values
+        #[prost(string, tag = "2")]
+        Key(::prost::alloc::string::String),
+        /// Sum
+        #[prost(message, tag = "3")]
+        Sum(super::ArithmeticExpressions),
+        /// Multiply
+        #[prost(message, tag = "4")]
+        Mult(super::ArithmeticExpressions),
+        /// Negate
+        #[prost(message, tag = "5")]
+        Neg(::prost::alloc::boxed::Box<super::ArithmeticExpression>),
+        /// Divide
+        #[prost(message, tag = "6")]
+        Div(::prost::alloc::boxed::Box<super::ArithmeticDiv>),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArithmeticExpressions {
+    #[prost(message, repeated, tag = "1")]
+    pub expressions: ::prost::alloc::vec::Vec<ArithmeticExpression>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArithmeticDiv {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub left: ::core::option::Option<::prost::alloc::boxed::Box<ArithmeticExpression>>,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub right: ::core::option::Option<::prost::alloc::boxed::Box<ArithmeticExpression>>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -267,12 +341,32 @@ pub struct FieldCondition {
     /// Check if field is null
     #[prost(bool, optional, tag = "10")]
     pub is_null: ::core::option::Option<bool>,
+    /// Check if geo point is within a given distance of a path
+    #[prost(message, optional, tag = "11")]
+    pub geo_near_path: ::core::option::Option<GeoNearPath>,
+    /// Check if UUID is within a given range, UUIDs are compared by their 128-bit value
+    #[prost(message, optional, tag = "12")]
+    pub uuid_range: ::core::option::Option<UuidRange>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Match {
-    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    /// Minimal number of query tokens, which must be present in the document. Only for `text_any`.
+    #[prost(message, optional, tag = "11")]
+    pub min_should_match: ::core::option::Option<MinShouldMatch>,
+    /// Language of the query, its stopwords are removed from the query tokens. Only for `text`.
+    #[prost(string, optional, tag = "12")]
+    pub language: ::core::option::Option<::prost::alloc::string::String>,
+    /// Match the original form of the query tokens instead of the stemmed one. Only for `text`.
+    /// Requires `index_unstemmed` for the field. Default: false.
+    #[prost(bool, optional, tag = "15")]
+    pub unstemmed: ::core::option::Option<bool>,
+    /// Max number of other tokens between the tokens of the phrase, in total. Only for `phrase`.
+    /// Default: 0, the tokens must be adjacent.
+    #[prost(uint32, optional, tag = "16")]
+    pub slop: ::core::option::Option<u32>,
+    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 13, 14, 17")]
     pub match_value: ::core::option::Option<r#match::MatchValue>,
 }
 /// Nested message and enum types in `Match`.
@@ -311,6 +405,36 @@ pub mod r#match {
         /// Match any word in the text
         #[prost(string, tag = "10")]
         TextAny(::prost::alloc::string::String),
+        /// Match exactly this set of keywords, no more and no less
+        #[prost(message, tag = "13")]
+        ExactKeywords(super::RepeatedStrings),
+        /// Match exactly this set of integers, no more and no less
+        #[prost(message, tag = "14")]
+        ExactIntegers(super::RepeatedIntegers),
+        /// Match keywords starting with this prefix
+        #[prost(string, tag = "17")]
+        KeywordPrefix(::prost::alloc::string::String),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MinShouldMatch {
+    #[prost(oneof = "min_should_match::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<min_should_match::Value>,
+}
+/// Nested message and enum types in `MinShouldMatch`.
+pub mod min_should_match {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        /// Absolute number of query tokens
+        #[prost(uint64, tag = "1")]
+        Count(u64),
+        /// Percentage of the query tokens, from 0 to 100, rounded down
+        #[prost(uint32, tag = "2")]
+        Percent(u32),
     }
 }
 #[derive(serde::Serialize)]
@@ -361,6 +485,19 @@ pub struct DatetimeRange {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UuidRange {
+    #[prost(string, optional, tag = "1")]
+    pub lt: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub gt: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub gte: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub lte: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GeoBoundingBox {
     /// north-west corner
     #[prost(message, optional, tag = "1")]
@@ -408,6 +545,24 @@ pub struct GeoPolygon {
         custom(function = "crate::grpc::validate::validate_geo_polygon_interiors")
     )]
     pub interiors: ::prost::alloc::vec::Vec<GeoLineString>,
+    /// Simplify the lines to this tolerance in meters before matching points.
+    /// Points closer than `tolerance` to the boundary might be matched incorrectly.
+    /// Default: 0 - no simplification, exact matching
+    #[prost(double, optional, tag = "3")]
+    #[validate(range(min = 0.0))]
+    pub tolerance: ::core::option::Option<f64>,
+}
+/// For a valid GeoNearPath, the path must consist of a minimum of 2 points.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GeoNearPath {
+    /// Ordered sequence of GeoPoints representing the path
+    #[prost(message, repeated, tag = "1")]
+    pub path: ::prost::alloc::vec::Vec<GeoPoint>,
+    /// Maximal distance from the path, in meters
+    #[prost(float, tag = "2")]
+    pub distance: f32,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -421,6 +576,45 @@ pub struct ValuesCount {
     pub gte: ::core::option::Option<u64>,
     #[prost(uint64, optional, tag = "4")]
     pub lte: ::core::option::Option<u64>,
+    /// If true, only distinct values are counted. Default: false
+    #[prost(bool, optional, tag = "5")]
+    pub distinct: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NullElements {
+    UnknownNullElements = 0,
+    /// Only null values match, arrays don't match regardless of their elements
+    Ignore = 1,
+    /// Null values match, as well as arrays with at least one null element
+    Include = 2,
+    /// Only arrays with at least one null element match, null values don't
+    Only = 3,
+}
+impl NullElements {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NullElements::UnknownNullElements => "UnknownNullElements",
+            NullElements::Ignore => "Ignore",
+            NullElements::Include => "Include",
+            NullElements::Only => "Only",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UnknownNullElements" => Some(Self::UnknownNullElements),
+            "Ignore" => Some(Self::Ignore),
+            "Include" => Some(Self::Include),
+            "Only" => Some(Self::Only),
+            _ => None,
+        }
+    }
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -862,6 +1056,29 @@ pub struct OptimizersConfigDiff {
     /// Default is disabled.
     #[prost(bool, optional, tag = "10")]
     pub prevent_unoptimized: ::core::option::Option<bool>,
+    /// Re-quantize segments, which quantized ranking drifted away from the exact one.
+    /// Default is disabled.
+    #[prost(message, optional, tag = "11")]
+    #[validate(nested)]
+    pub requantization: ::core::option::Option<RequantizationConfig>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequantizationConfig {
+    /// Enable re-quantization. Default: false
+    #[prost(bool, tag = "1")]
+    pub enable: bool,
+    /// Maximum average fraction of the rescored results, which were not in the top by quantized
+    /// score, before the segment is re-quantized. Default: 0.3
+    #[prost(double, optional, tag = "2")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub max_distortion: ::core::option::Option<f64>,
+    /// Interval between checks of the distortion, in seconds. Default: 600
+    #[prost(uint64, optional, tag = "3")]
+    #[validate(range(min = 1))]
+    pub check_interval_sec: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -878,6 +1095,9 @@ pub struct ScalarQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "3")]
     pub always_ram: ::core::option::Option<bool>,
+    /// How to handle vectors with values outside of the quantile range. Default is `Clamp`
+    #[prost(enumeration = "ScalarOutliers", optional, tag = "4")]
+    pub outliers: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1092,6 +1312,15 @@ pub struct StrictModeConfig {
     /// Max size of a condition, eg. items in `MatchAny`.
     #[prost(uint64, optional, tag = "15")]
     pub condition_max_size: ::core::option::Option<u64>,
+    /// Max estimated selectivity of filters in search requests, in \[0, 1\].
+    /// Requests with a less selective filter are rejected, unless they set `allow_full_scan`.
+    /// Requests without a filter match all points.
+    #[prost(float, optional, tag = "22")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub filter_max_selectivity: ::core::option::Option<f32>,
+    /// Only log a warning instead of rejecting requests exceeding `filter_max_selectivity`.
+    #[prost(bool, optional, tag = "23")]
+    pub filter_selectivity_warn_only: ::core::option::Option<bool>,
     /// Multivector strict mode configuration
     #[prost(message, optional, tag = "16")]
     #[validate(nested)]
@@ -1273,6 +1502,32 @@ pub struct UpdateCollection {
     /// already stored metadata
     #[prost(map = "string, message", tag = "10")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Boost rules to add, replace, or remove
+    #[prost(message, optional, tag = "11")]
+    pub boost_rules: ::core::option::Option<BoostRulesDiff>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BoostRule {
+    /// Points matching this filter are boosted
+    #[prost(message, optional, tag = "1")]
+    pub filter: ::core::option::Option<Filter>,
+    /// Scores of matching points are multiplied by this value, must be greater than 0.
+    /// Negative scores, and distances where a smaller score is better, are divided by it instead.
+    #[prost(double, tag = "2")]
+    pub multiplier: f64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BoostRulesDiff {
+    /// Rules to add, by their names. Rules with the same names are replaced
+    #[prost(map = "string, message", tag = "1")]
+    pub upsert: ::std::collections::HashMap<::prost::alloc::string::String, BoostRule>,
+    /// Names of rules to remove
+    #[prost(string, repeated, tag = "2")]
+    pub delete: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1336,6 +1591,30 @@ pub struct CollectionParams {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "11")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// Score threshold of the queries, which don't set a `score_threshold`
+    #[prost(float, optional, tag = "12")]
+    pub default_score_threshold: ::core::option::Option<f32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScoreThresholdDiff {
+    #[prost(oneof = "score_threshold_diff::Threshold", tags = "1, 2")]
+    pub threshold: ::core::option::Option<score_threshold_diff::Threshold>,
+}
+/// Nested message and enum types in `ScoreThresholdDiff`.
+pub mod score_threshold_diff {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Threshold {
+        /// New default score threshold
+        #[prost(float, tag = "1")]
+        Value(f32),
+        /// Remove the default score threshold
+        #[prost(message, tag = "2")]
+        Disabled(super::Disabled),
+    }
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1359,6 +1638,9 @@ pub struct CollectionParamsDiff {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "5")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// Score threshold of the queries, which don't set a `score_threshold`
+    #[prost(message, optional, tag = "6")]
+    pub default_score_threshold: ::core::option::Option<ScoreThresholdDiff>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1385,6 +1667,9 @@ pub struct CollectionConfig {
     /// Arbitrary JSON metadata for the collection
     #[prost(map = "string, message", tag = "7")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Boost rules applied to the scores of query results
+    #[prost(map = "string, message", tag = "8")]
+    pub boost_rules: ::std::collections::HashMap<::prost::alloc::string::String, BoostRule>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1401,6 +1686,18 @@ pub struct KeywordIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If true - store repeated keywords within a point only once. Default: false.
+    #[prost(bool, optional, tag = "4")]
+    pub deduplicate_values: ::core::option::Option<bool>,
+    /// Max size of a single keyword in bytes. Default: no limit.
+    #[prost(uint64, optional, tag = "5")]
+    pub max_value_bytes: ::core::option::Option<u64>,
+    /// How to index keywords larger than `max_value_bytes`. Default: Skip.
+    #[prost(enumeration = "OversizedValuePolicy", optional, tag = "6")]
+    pub oversized_value_policy: ::core::option::Option<i32>,
+    /// If true - match keywords ignoring their case. Default: false.
+    #[prost(bool, optional, tag = "7")]
+    pub case_insensitive: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1442,6 +1739,11 @@ pub struct FloatIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If set, values are stored as 32 bit integer multiples of this precision,
+    /// rounded to the nearest one. Range filters are applied to the rounded values.
+    /// Values more than 2^31 multiples away from zero are rejected. Must be greater than 0.
+    #[prost(double, optional, tag = "4")]
+    pub precision: ::core::option::Option<f64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1455,6 +1757,10 @@ pub struct GeoIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "2")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If set, additionally index the points by the H3 cells of this resolution (0 - 15).
+    /// Faceting the field then counts the points per cell.
+    #[prost(uint32, optional, tag = "3")]
+    pub h3_resolution: ::core::option::Option<u32>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1466,6 +1772,12 @@ pub struct StopwordsSet {
     /// List of custom stopwords
     #[prost(string, repeated, tag = "2")]
     pub custom: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// If true, detect the language of the indexed texts and use its stopwords. Can't be combined with languages and custom stopwords
+    #[prost(bool, optional, tag = "3")]
+    pub auto: ::core::option::Option<bool>,
+    /// List of words to keep, even though they are in the stopwords of the languages
+    #[prost(string, repeated, tag = "4")]
+    pub exclude: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1504,6 +1816,37 @@ pub struct TextIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "10")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// Compress posting lists of the index stored on disk.
+    /// Reduces disk and page cache usage, but makes queries slower. Default: no compression.
+    #[prost(enumeration = "TextIndexCompression", optional, tag = "11")]
+    pub compression: ::core::option::Option<i32>,
+    /// If true, index the original form of tokens alongside the stemmed one.
+    /// Allows to match the original form with `unstemmed` text queries. Default: false.
+    #[prost(bool, optional, tag = "12")]
+    pub index_unstemmed: ::core::option::Option<bool>,
+    /// Max size of a single text value in bytes. Default: no limit.
+    #[prost(uint64, optional, tag = "13")]
+    pub max_value_bytes: ::core::option::Option<u64>,
+    /// How to index text values larger than `max_value_bytes`. Default: Skip.
+    #[prost(enumeration = "OversizedValuePolicy", optional, tag = "14")]
+    pub oversized_value_policy: ::core::option::Option<i32>,
+    /// Treat tokens contained in at least this percentage of the indexed points as stopwords,
+    /// and remove them from text queries. Not supported by the prefix tokenizer. Default: disabled.
+    #[prost(uint32, optional, tag = "15")]
+    pub auto_stopwords_threshold: ::core::option::Option<u32>,
+    /// If true, strip HTML tags from text values before tokenization. Default: false.
+    #[prost(bool, optional, tag = "16")]
+    pub strip_html: ::core::option::Option<bool>,
+    /// If true, decode HTML entities in the text left after stripping HTML. Default: true.
+    #[prost(bool, optional, tag = "17")]
+    pub decode_html_entities: ::core::option::Option<bool>,
+    /// If true, strip HTML from text queries too. Default: false.
+    #[prost(bool, optional, tag = "18")]
+    pub strip_html_queries: ::core::option::Option<bool>,
+    /// If true, store the tokens of every point in the index, in addition to the posting lists.
+    /// Allows to retrieve the tokens of a point. Default: false.
+    #[prost(bool, optional, tag = "19")]
+    pub forward_index: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1543,6 +1886,10 @@ pub struct BoolIndexParams {
     /// Default: true.
     #[prost(bool, optional, tag = "2")]
     pub enable_hnsw: ::core::option::Option<bool>,
+    /// If set, additionally index each of the first `max_positions` elements
+    /// of a boolean array on its own, e.g. `permissions\[2\]`.
+    #[prost(uint64, optional, tag = "3")]
+    pub max_positions: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1831,6 +2178,9 @@ pub struct LocalShardInfo {
     /// User-defined shard key
     #[prost(message, optional, tag = "4")]
     pub shard_key: ::core::option::Option<ShardKey>,
+    /// Version of the last operation applied to this replica
+    #[prost(uint64, optional, tag = "5")]
+    pub max_version: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -2379,6 +2729,35 @@ impl QuantizationType {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum ScalarOutliers {
+    /// Clamp the values into the range, such vectors are scored less accurately
+    Clamp = 0,
+    /// Keep the vectors distorted the most by clamping in full precision, at most 1% of them, and score them exactly
+    FullPrecision = 1,
+}
+impl ScalarOutliers {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ScalarOutliers::Clamp => "Clamp",
+            ScalarOutliers::FullPrecision => "FullPrecision",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Clamp" => Some(Self::Clamp),
+            "FullPrecision" => Some(Self::FullPrecision),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum CompressionRatio {
     X4 = 0,
     X8 = 1,
@@ -2543,6 +2922,74 @@ impl TokenizerType {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum OversizedValuePolicy {
+    UnknownOversizedValuePolicy = 0,
+    /// Don't index the value
+    Skip = 1,
+    /// Index the beginning of the value, text values are truncated at a token boundary
+    Truncate = 2,
+    /// Reject the value, fails the update of the point or the creation of the index
+    Error = 3,
+}
+impl OversizedValuePolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OversizedValuePolicy::UnknownOversizedValuePolicy => "UnknownOversizedValuePolicy",
+            OversizedValuePolicy::Skip => "Skip",
+            OversizedValuePolicy::Truncate => "Truncate",
+            OversizedValuePolicy::Error => "Error",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UnknownOversizedValuePolicy" => Some(Self::UnknownOversizedValuePolicy),
+            "Skip" => Some(Self::Skip),
+            "Truncate" => Some(Self::Truncate),
+            "Error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TextIndexCompression {
+    UnknownTextIndexCompression = 0,
+    /// Fast compression and decompression, moderate compression ratio
+    Lz4 = 1,
+    /// Higher compression ratio, slower decompression
+    Zstd = 2,
+}
+impl TextIndexCompression {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TextIndexCompression::UnknownTextIndexCompression => "UnknownTextIndexCompression",
+            TextIndexCompression::Lz4 => "Lz4",
+            TextIndexCompression::Zstd => "Zstd",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UnknownTextIndexCompression" => Some(Self::UnknownTextIndexCompression),
+            "Lz4" => Some(Self::Lz4),
+            "Zstd" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ReplicaState {
     /// Active and sound
     Active = 0,
@@ -5271,6 +5718,13 @@ pub struct UpsertPoints {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[prost(enumeration = "UpdateMode", optional, tag = "8")]
     pub update_mode: ::core::option::Option<i32>,
+    /// If set, all points must exist with exactly this version, otherwise the operation fails with a conflict.
+    /// Only supported in collections with `replication_factor` 1, as versions differ between replicas
+    #[prost(uint64, optional, tag = "9")]
+    pub expected_version: ::core::option::Option<u64>,
+    /// How to handle points with the same id in this request: last_wins (default), first_wins, error
+    #[prost(enumeration = "DuplicateIdsPolicy", optional, tag = "10")]
+    pub duplicate_ids: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5818,6 +6272,36 @@ pub struct QuantizationSearchParams {
     #[prost(double, optional, tag = "3")]
     #[validate(range(min = 1.0))]
     pub oversampling: ::core::option::Option<f64>,
+    /// Retry of the search with larger oversampling, if quantized scores are too inaccurate.
+    #[prost(message, optional, tag = "4")]
+    #[validate(nested)]
+    pub fallback: ::core::option::Option<QuantizationFallbackParams>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QuantizationFallbackParams {
+    /// If true, the search is retried with doubled oversampling, while too many of the rescored
+    /// top results are missing in the top results by quantized score.
+    ///
+    /// Improves recall for collections with high quantization distortion, at cost of latency.
+    /// Only applies if rescoring is enabled.
+    #[prost(bool, optional, tag = "1")]
+    pub enable: ::core::option::Option<bool>,
+    /// Maximum distortion of the quantized ranking, before the search is retried.
+    ///
+    /// Distortion is the fraction of the rescored top results,
+    /// which are not among the top results by quantized score.
+    ///
+    /// 0.0 to retry on any difference, 1.0 for never. Default is 0.3.
+    #[prost(double, optional, tag = "2")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub max_distortion: ::core::option::Option<f64>,
+    /// Maximum oversampling of the retries, the search is not retried beyond it. Default is 8.0.
+    #[prost(double, optional, tag = "3")]
+    #[validate(range(min = 1.0))]
+    pub max_oversampling: ::core::option::Option<f64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5867,6 +6351,20 @@ pub struct SearchParams {
     #[prost(message, optional, tag = "5")]
     #[validate(nested)]
     pub acorn: ::core::option::Option<AcornSearchParams>,
+    /// How to treat points, which don't have the searched vector. Default: `Exclude`
+    #[prost(enumeration = "MissingVectors", optional, tag = "6")]
+    pub missing_vectors: ::core::option::Option<i32>,
+    /// Limit the number of results each segment of a shard contributes to the merge
+    /// to `segment_limit_factor * limit / number of segments`, rounded up.
+    /// Bounds the merge work at cost of recall. The limit is reported in `QueryStats`.
+    /// Default: no limit
+    #[prost(double, optional, tag = "7")]
+    #[validate(range(min = 1.0))]
+    pub segment_limit_factor: ::core::option::Option<f64>,
+    /// Acknowledge, that the filter of the request might match a large part of the collection.
+    /// Allows the request, if strict mode rejects filters exceeding `filter_max_selectivity`.
+    #[prost(bool, optional, tag = "8")]
+    pub allow_full_scan: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6560,6 +7058,26 @@ pub struct NaiveFeedbackStrategy {
     #[prost(float, tag = "3")]
     pub c: f32,
 }
+/// Sample points with probability proportional to a numeric payload field.
+/// Points with the largest `ln(u) / weight` keys are sampled,
+/// where `u` is a seeded uniform random number.
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WeightedSample {
+    /// Payload field with the weight of the point. Requires a range index.
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1))]
+    pub key: ::prost::alloc::string::String,
+    /// Seed of the sample. The same seed gives the same sample. Defaults to a random seed.
+    #[prost(uint64, optional, tag = "2")]
+    pub seed: ::core::option::Option<u64>,
+    /// Weight of the points without the field, zero weight points are never sampled. Defaults to 0.
+    #[prost(double, optional, tag = "3")]
+    #[validate(range(min = 0.0))]
+    pub default_weight: ::core::option::Option<f64>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6645,6 +7163,9 @@ pub mod expression {
         /// Linear decay
         #[prost(message, tag = "19")]
         LinDecay(::prost::alloc::boxed::Box<super::DecayParamsExpression>),
+        /// Relevance of a text field to the text
+        #[prost(message, tag = "20")]
+        TextScore(super::TextScore),
     }
 }
 #[derive(serde::Serialize)]
@@ -6656,6 +7177,21 @@ pub struct GeoDistance {
     #[prost(string, tag = "2")]
     pub to: ::prost::alloc::string::String,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextScore {
+    /// Payload field with a full-text index
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Text to score the values of the field against.
+    /// Each value is scored as the fraction of the tokens of the text present in it.
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+    /// How the scores of the values of an array are combined. Defaults to `Max`.
+    #[prost(enumeration = "TextScoreAggregation", optional, tag = "3")]
+    pub aggregation: ::core::option::Option<i32>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6723,20 +7259,70 @@ pub struct DecayParamsExpression {
     #[prost(float, optional, tag = "4")]
     pub midpoint: ::core::option::Option<f32>,
 }
+/// Boost the score of the prefetched points by their recency: `score * (1 + decay(age))`.
+/// Points without a datetime value are not boosted.
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NearestInputWithMmr {
-    /// The vector to search for nearest neighbors.
-    #[prost(message, optional, tag = "1")]
-    #[validate(nested)]
-    pub nearest: ::core::option::Option<VectorInput>,
-    /// Perform MMR (Maximal Marginal Relevance) reranking after search,
-    /// using the same vector in this query to calculate relevance.
-    #[prost(message, optional, tag = "2")]
-    #[validate(nested)]
-    pub mmr: ::core::option::Option<Mmr>,
+pub struct RecencyBoost {
+    /// Payload field with the datetime of the point
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1))]
+    pub key: ::prost::alloc::string::String,
+    /// Age of the point, in seconds, at which the boost decays to `midpoint`.
+    /// Must be a non-zero positive number.
+    #[prost(float, tag = "2")]
+    pub scale: f32,
+    /// Decay function of the age. Defaults to `Exp`.
+    #[prost(enumeration = "DecayFunction", optional, tag = "3")]
+    pub function: ::core::option::Option<i32>,
+    /// The boost at the age of `scale`. Should be between 0 and 1. Defaults to 0.5.
+    #[prost(float, optional, tag = "4")]
+    pub midpoint: ::core::option::Option<f32>,
+}
+/// Boost the score of the prefetched points by their proximity to the origin:
+/// `score * (1 + decay(distance))`.
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProximityBoost {
+    /// Payload field with the geo point of the point
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1))]
+    pub key: ::prost::alloc::string::String,
+    /// The geo point to measure the distance from
+    #[prost(message, optional, tag = "2")]
+    pub origin: ::core::option::Option<GeoPoint>,
+    /// Distance from the origin, in meters, at which the boost decays to `midpoint`.
+    /// Must be a non-zero positive number.
+    #[prost(float, tag = "3")]
+    pub scale: f32,
+    /// Decay function of the distance. Defaults to `Exp`.
+    #[prost(enumeration = "DecayFunction", optional, tag = "4")]
+    pub function: ::core::option::Option<i32>,
+    /// The boost at the distance of `scale`. Should be between 0 and 1. Defaults to 0.5.
+    #[prost(float, optional, tag = "5")]
+    pub midpoint: ::core::option::Option<f32>,
+    /// The boost of points without a geo point. Defaults to 0 - such points are not boosted.
+    #[prost(float, optional, tag = "6")]
+    pub missing_boost: ::core::option::Option<f32>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NearestInputWithMmr {
+    /// The vector to search for nearest neighbors.
+    #[prost(message, optional, tag = "1")]
+    #[validate(nested)]
+    pub nearest: ::core::option::Option<VectorInput>,
+    /// Perform MMR (Maximal Marginal Relevance) reranking after search,
+    /// using the same vector in this query to calculate relevance.
+    #[prost(message, optional, tag = "2")]
+    #[validate(nested)]
+    pub mmr: ::core::option::Option<Mmr>,
 }
 /// Maximal Marginal Relevance (MMR) algorithm for re-ranking the points.
 #[derive(validator::Validate)]
@@ -6784,7 +7370,7 @@ pub struct Rrf {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
-    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
     #[validate(nested)]
     pub variant: ::core::option::Option<query::Variant>,
 }
@@ -6807,6 +7393,10 @@ pub mod query {
         #[prost(message, tag = "4")]
         Context(super::ContextInput),
         /// Order the points by a payload field.
+        /// Vectors are not scored. If `using` names a vector, only the points having this vector
+        /// are ordered. Without `using`, all points matching the filter are ordered, also the ones
+        /// without the default vector.
+        /// Over prefetches, the order of the payload field takes precedence over their scores.
         #[prost(message, tag = "5")]
         OrderBy(super::OrderBy),
         /// Fuse the results of multiple prefetches.
@@ -6827,6 +7417,15 @@ pub mod query {
         /// Search with feedback from some oracle.
         #[prost(message, tag = "11")]
         RelevanceFeedback(super::RelevanceFeedbackInput),
+        /// Score boosting by recency of a datetime payload field
+        #[prost(message, tag = "12")]
+        Recency(super::RecencyBoost),
+        /// Sample points with probability proportional to a numeric payload field.
+        #[prost(message, tag = "13")]
+        WeightedSample(super::WeightedSample),
+        /// Score boosting by proximity of a geo payload field to a point
+        #[prost(message, tag = "14")]
+        Proximity(super::ProximityBoost),
     }
 }
 #[derive(validator::Validate)]
@@ -6932,6 +7531,42 @@ pub struct QueryPoints {
     #[prost(uint64, optional, tag = "15")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// Payload fields to count the values of, returned alongside the points.
+    /// Values are counted over all points matching the filter, regardless of limit and offset.
+    /// Not supported in batch requests.
+    #[prost(message, repeated, tag = "16")]
+    #[validate(nested)]
+    pub facets: ::prost::alloc::vec::Vec<QueryFacet>,
+    /// Whether to apply the boost rules of the collection to the scores of the results.
+    /// Default is true.
+    #[prost(bool, optional, tag = "17")]
+    pub apply_boost_rules: ::core::option::Option<bool>,
+    /// Whether to use the `default_score_threshold` of the collection, if `score_threshold` is
+    /// not set. Only applies to nearest neighbour and average vector recommendation queries.
+    /// Set to false to return results of any score. Default is true.
+    #[prost(bool, optional, tag = "18")]
+    pub apply_default_score_threshold: ::core::option::Option<bool>,
+    /// Milliseconds after which the query returns the best results of the segments searched until
+    /// then, instead of searching all of them. The response reports the number of searched segments.
+    #[prost(uint64, optional, tag = "19")]
+    #[validate(range(min = 1))]
+    pub soft_timeout_ms: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFacet {
+    /// Payload key to count the values of. Requires a keyword, integer, uuid or bool index,
+    /// or a geo index with `h3_resolution` to count the points per H3 cell.
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1))]
+    pub key: ::prost::alloc::string::String,
+    /// Max number of values to return, most frequent first. Default is 10, at most 100.
+    /// Values with the same count are ordered by value ascending.
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(range(min = 1, max = 100))]
+    pub limit: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -7049,7 +7684,7 @@ pub struct FacetCounts {
     #[prost(message, optional, tag = "3")]
     #[validate(nested)]
     pub filter: ::core::option::Option<Filter>,
-    /// Max number of facets. Default is 10.
+    /// Max number of facets. Default is 10. Values with the same count are ordered by value ascending.
     #[prost(uint64, optional, tag = "4")]
     pub limit: ::core::option::Option<u64>,
     /// If true, return exact counts, slower but useful for debugging purposes. Default is false.
@@ -7206,6 +7841,13 @@ pub mod points_update_operation {
         /// Mode of the upsert operation: insert_only, upsert (default), update_only
         #[prost(enumeration = "super::UpdateMode", optional, tag = "4")]
         pub update_mode: ::core::option::Option<i32>,
+        /// If set, all points must exist with exactly this version, otherwise the operation fails with a conflict.
+        /// Only supported in collections with `replication_factor` 1, as versions differ between replicas
+        #[prost(uint64, optional, tag = "5")]
+        pub expected_version: ::core::option::Option<u64>,
+        /// How to handle points with the same id in this request: last_wins (default), first_wins, error
+        #[prost(enumeration = "super::DuplicateIdsPolicy", optional, tag = "6")]
+        pub duplicate_ids: ::core::option::Option<i32>,
     }
     #[derive(serde::Serialize)]
     #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7427,6 +8069,43 @@ pub struct ScoredPoint {
     /// Order by value
     #[prost(message, optional, tag = "8")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Segment, shard and peer, which scored the point. Internal, only set if requested
+    #[prost(message, optional, tag = "9")]
+    pub provenance: ::core::option::Option<PointProvenance>,
+    /// Stages, which composed the score, in order. Internal, only set if requested
+    #[prost(message, repeated, tag = "10")]
+    pub score_stages: ::prost::alloc::vec::Vec<ScoreStage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PointProvenance {
+    /// Segment, which scored the point
+    #[prost(string, tag = "1")]
+    pub segment_uuid: ::prost::alloc::string::String,
+    /// Whether the segment is appendable, i.e. receives new points
+    #[prost(bool, tag = "2")]
+    pub appendable: bool,
+    #[prost(uint32, optional, tag = "3")]
+    pub shard_id: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag = "4")]
+    pub peer_id: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScoreStage {
+    #[prost(enumeration = "ScoreStageKind", tag = "1")]
+    pub kind: i32,
+    /// Score of the point after the stage
+    #[prost(float, tag = "2")]
+    pub score: f32,
+    /// Name of the boost rule, for boost stages
+    #[prost(string, optional, tag = "3")]
+    pub boost_rule: ::core::option::Option<::prost::alloc::string::String>,
+    /// Multiplier of the boost rule, for boost stages
+    #[prost(double, optional, tag = "4")]
+    pub boost_multiplier: ::core::option::Option<f64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7489,6 +8168,37 @@ pub struct SearchResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchStreamResponse {
+    #[prost(oneof = "search_stream_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<search_stream_response::Response>,
+}
+/// Nested message and enum types in `SearchStreamResponse`.
+pub mod search_stream_response {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        /// Next point of the result, points are sent in the final ranked order
+        #[prost(message, tag = "1")]
+        Point(super::ScoredPoint),
+        /// Sent as the last message of the stream, after all the points
+        #[prost(message, tag = "2")]
+        Summary(super::SearchStreamSummary),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchStreamSummary {
+    /// Time spent to process
+    #[prost(double, tag = "1")]
+    pub time: f64,
+    #[prost(message, optional, tag = "2")]
+    pub usage: ::core::option::Option<Usage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryResponse {
     #[prost(message, repeated, tag = "1")]
     pub result: ::prost::alloc::vec::Vec<ScoredPoint>,
@@ -7497,6 +8207,47 @@ pub struct QueryResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// Value counts of the requested facets, in the order of the request
+    #[prost(message, repeated, tag = "4")]
+    pub facets: ::prost::alloc::vec::Vec<QueryFacetResult>,
+    /// Statistics of the execution of the query
+    #[prost(message, optional, tag = "5")]
+    pub stats: ::core::option::Option<QueryStats>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryFacetResult {
+    /// Payload key of the facet
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub hits: ::prost::alloc::vec::Vec<FacetHit>,
+    /// Sum of the counts of the values, which are not in `hits`
+    #[prost(uint64, tag = "3")]
+    pub other: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryStats {
+    /// Number of results each segment contributed at most to the merge, if limited by
+    /// `segment_limit_factor`. The largest limit of all shards
+    #[prost(uint64, optional, tag = "1")]
+    pub segment_limit: ::core::option::Option<u64>,
+    /// Whether the soft timeout stopped the search before all segments were searched
+    #[prost(bool, optional, tag = "2")]
+    pub partial: ::core::option::Option<bool>,
+    /// Segments of all shards, which were searched before the soft timeout
+    #[prost(uint64, optional, tag = "3")]
+    pub searched_segments: ::core::option::Option<u64>,
+    /// Segments of all shards to search, if a soft timeout was set
+    #[prost(uint64, optional, tag = "4")]
+    pub total_segments: ::core::option::Option<u64>,
+    /// Segment searches, which were retried with larger oversampling,
+    /// because quantized and rescored rankings disagreed too much
+    #[prost(uint64, optional, tag = "5")]
+    pub quantization_fallbacks: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7699,6 +8450,9 @@ pub struct FacetResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// Sum of the counts of the values, which are not in `hits`
+    #[prost(uint64, tag = "4")]
+    pub other: u64,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7881,6 +8635,40 @@ impl UpdateMode {
         }
     }
 }
+/// Defines how points with the same id in a single upsert request are handled
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DuplicateIdsPolicy {
+    /// Default policy - only the last point with the id is applied
+    LastWins = 0,
+    /// Only the first point with the id is applied
+    FirstWins = 1,
+    /// Reject the whole request, if any id occurs more than once
+    Error = 2,
+}
+impl DuplicateIdsPolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DuplicateIdsPolicy::LastWins => "LastWins",
+            DuplicateIdsPolicy::FirstWins => "FirstWins",
+            DuplicateIdsPolicy::Error => "Error",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LastWins" => Some(Self::LastWins),
+            "FirstWins" => Some(Self::FirstWins),
+            "Error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -7962,6 +8750,39 @@ impl FieldType {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum MissingVectors {
+    UnknownMissingVectors = 0,
+    /// Points without the vector are not returned
+    Exclude = 1,
+    /// Points without the vector, which match the filter, are returned after all points with
+    /// the vector, with the worst possible score
+    Include = 2,
+}
+impl MissingVectors {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MissingVectors::UnknownMissingVectors => "UnknownMissingVectors",
+            MissingVectors::Exclude => "MissingVectorsExclude",
+            MissingVectors::Include => "MissingVectorsInclude",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UnknownMissingVectors" => Some(Self::UnknownMissingVectors),
+            "MissingVectorsExclude" => Some(Self::Exclude),
+            "MissingVectorsInclude" => Some(Self::Include),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum Direction {
     Asc = 0,
     Desc = 1,
@@ -8085,6 +8906,68 @@ impl Sample {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum DecayFunction {
+    /// Exponential decay
+    Exp = 0,
+    /// Linear decay
+    Lin = 1,
+    /// Gaussian decay
+    Gauss = 2,
+}
+impl DecayFunction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DecayFunction::Exp => "Exp",
+            DecayFunction::Lin => "Lin",
+            DecayFunction::Gauss => "Gauss",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Exp" => Some(Self::Exp),
+            "Lin" => Some(Self::Lin),
+            "Gauss" => Some(Self::Gauss),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TextScoreAggregation {
+    /// Score of the best matching value
+    Max = 0,
+    /// Sum of the scores of all values
+    Sum = 1,
+}
+impl TextScoreAggregation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TextScoreAggregation::Max => "Max",
+            TextScoreAggregation::Sum => "Sum",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Max" => Some(Self::Max),
+            "Sum" => Some(Self::Sum),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum UpdateStatus {
     UnknownUpdateStatus = 0,
     /// Update is received, but not processed yet
@@ -8122,6 +9005,47 @@ impl UpdateStatus {
         }
     }
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScoreStageKind {
+    /// Scored against the query vector(s)
+    Vector = 0,
+    /// Scored by a formula
+    Formula = 1,
+    /// Fusion of the prefetches
+    Fusion = 2,
+    /// Reordered by maximal marginal relevance
+    Mmr = 3,
+    /// Multiplied by a boost rule of the collection
+    Boost = 4,
+}
+impl ScoreStageKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ScoreStageKind::Vector => "ScoreStageKindVector",
+            ScoreStageKind::Formula => "ScoreStageKindFormula",
+            ScoreStageKind::Fusion => "ScoreStageKindFusion",
+            ScoreStageKind::Mmr => "ScoreStageKindMmr",
+            ScoreStageKind::Boost => "ScoreStageKindBoost",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ScoreStageKindVector" => Some(Self::Vector),
+            "ScoreStageKindFormula" => Some(Self::Formula),
+            "ScoreStageKindFusion" => Some(Self::Fusion),
+            "ScoreStageKindMmr" => Some(Self::Mmr),
+            "ScoreStageKindBoost" => Some(Self::Boost),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod points_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -8274,7 +9198,8 @@ pub mod points_client {
             req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "Get"));
             self.inner.unary(req, path, codec).await
         }
-        /// Update named vectors for point
+        /// Update named vectors for point, keep unspecified vectors and the payload intact.
+        /// Vectors, which the point didn't have before, are added. Fails if the point doesn't exist.
         pub async fn update_vectors(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdatePointVectors>,
@@ -8553,6 +9478,32 @@ pub mod points_client {
             self.inner.unary(req, path, codec).await
         }
         /// Retrieve closest points based on vector similarity and given filtering
+        /// conditions, streaming the points one by one in the final ranked order
+        pub async fn search_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchPoints>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SearchStreamResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/SearchStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "SearchStream"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Retrieve closest points based on vector similarity and given filtering
         /// conditions
         pub async fn search_batch(
             &mut self,
@@ -8994,7 +9945,8 @@ pub mod points_server {
             &self,
             request: tonic::Request<super::GetPoints>,
         ) -> std::result::Result<tonic::Response<super::GetResponse>, tonic::Status>;
-        /// Update named vectors for point
+        /// Update named vectors for point, keep unspecified vectors and the payload intact.
+        /// Vectors, which the point didn't have before, are added. Fails if the point doesn't exist.
         async fn update_vectors(
             &self,
             request: tonic::Request<super::UpdatePointVectors>,
@@ -9080,6 +10032,21 @@ pub mod points_server {
             &self,
             request: tonic::Request<super::SearchPoints>,
         ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status>;
+        /// Server streaming response type for the SearchStream method.
+        type SearchStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SearchStreamResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Retrieve closest points based on vector similarity and given filtering
+        /// conditions, streaming the points one by one in the final ranked order
+        async fn search_stream(
+            &self,
+            request: tonic::Request<super::SearchPoints>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SearchStreamStream>,
+            tonic::Status,
+        >;
         /// Retrieve closest points based on vector similarity and given filtering
         /// conditions
         async fn search_batch(
@@ -9939,6 +10906,53 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/SearchStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchStreamSvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::ServerStreamingService<super::SearchPoints>
+                    for SearchStreamSvc<T> {
+                        type Response = super::SearchStreamResponse;
+                        type ResponseStream = T::SearchStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::search_stream(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SearchStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/SearchBatch" => {
                     #[allow(non_camel_case_types)]
                     struct SearchBatchSvc<T: Points>(pub Arc<T>);
@@ -11385,6 +12399,15 @@ pub struct QueryShardPoints {
     pub with_payload: ::core::option::Option<WithPayloadSelector>,
     #[prost(message, optional, tag = "10")]
     pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Return the segment, shard and peer, which scored each of the points
+    #[prost(bool, tag = "11")]
+    pub with_provenance: bool,
+    /// Record the stages, which composed the score of each of the points
+    #[prost(bool, tag = "12")]
+    pub with_explanation: bool,
+    /// Return the results of the segments searched until then, instead of searching all of them
+    #[prost(uint64, optional, tag = "13")]
+    pub soft_timeout_ms: ::core::option::Option<u64>,
 }
 /// Nested message and enum types in `QueryShardPoints`.
 pub mod query_shard_points {
@@ -11393,7 +12416,7 @@ pub mod query_shard_points {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Query {
-        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7")]
+        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
         pub score: ::core::option::Option<query::Score>,
     }
     /// Nested message and enum types in `Query`.
@@ -11423,6 +12446,9 @@ pub mod query_shard_points {
             /// Parameterized RRF fusion
             #[prost(message, tag = "7")]
             Rrf(super::super::Rrf),
+            /// Weighted random sample
+            #[prost(message, tag = "8")]
+            WeightedSample(super::super::WeightedSample),
         }
     }
     #[derive(serde::Serialize)]
@@ -11491,6 +12517,8 @@ pub struct QueryBatchResponseInternal {
     pub hardware_usage: ::core::option::Option<HardwareUsage>,
     #[prost(message, optional, tag = "6")]
     pub inference_usage: ::core::option::Option<InferenceUsage>,
+    #[prost(message, optional, tag = "7")]
+    pub stats: ::core::option::Option<QueryStats>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
@@ -11560,6 +12588,92 @@ pub struct FacetResponseInternal {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<HardwareUsage>,
+    /// Sum of the counts of the values, which are not in `hits`
+    #[prost(uint64, tag = "4")]
+    pub other_count: u64,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueSamplesInternal {
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Numeric payload field to sample
+    #[prost(string, tag = "2")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub shard_id: u32,
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueSampleInternal {
+    /// Sampled value
+    #[prost(double, tag = "1")]
+    pub value: f64,
+    /// Number of values, the sample stands for
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValueSamplesResponseInternal {
+    #[prost(message, repeated, tag = "1")]
+    pub samples: ::prost::alloc::vec::Vec<ValueSampleInternal>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
+#[derive(validator::Validate)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PointVersionsInternal {
+    #[prost(string, tag = "1")]
+    #[validate(
+        length(min = 1, max = 255),
+        custom(function = "common::validation::validate_collection_name_legacy")
+    )]
+    pub collection_name: ::prost::alloc::string::String,
+    /// Ids of the points to look up
+    #[prost(message, repeated, tag = "2")]
+    pub ids: ::prost::alloc::vec::Vec<PointId>,
+    #[prost(uint32, tag = "3")]
+    pub shard_id: u32,
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PointVersionInternal {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PointId>,
+    /// Latest version of the point
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PointVersionsResponseInternal {
+    #[prost(message, repeated, tag = "1")]
+    pub versions: ::prost::alloc::vec::Vec<PointVersionInternal>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+    #[prost(message, optional, tag = "3")]
+    pub usage: ::core::option::Option<HardwareUsage>,
 }
 /// Controls how an update operation waits for completion.
 /// When present, fully overrides the `wait` boolean from the wrapped public message.
@@ -12197,6 +13311,56 @@ pub mod points_internal_client {
                 .insert(GrpcMethod::new("qdrant.PointsInternal", "Facet"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn value_samples(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValueSamplesInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValueSamplesResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/ValueSamples",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "ValueSamples"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn point_versions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PointVersionsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointVersionsResponseInternal>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.PointsInternal/PointVersions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.PointsInternal", "PointVersions"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -12344,6 +13508,20 @@ pub mod points_internal_server {
             tonic::Response<super::FacetResponseInternal>,
             tonic::Status,
         >;
+        async fn value_samples(
+            &self,
+            request: tonic::Request<super::ValueSamplesInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValueSamplesResponseInternal>,
+            tonic::Status,
+        >;
+        async fn point_versions(
+            &self,
+            request: tonic::Request<super::PointVersionsInternal>,
+        ) -> std::result::Result<
+            tonic::Response<super::PointVersionsResponseInternal>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PointsInternalServer<T: PointsInternal> {
@@ -13402,6 +14580,98 @@ pub mod points_internal_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.PointsInternal/ValueSamples" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValueSamplesSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::ValueSamplesInternal>
+                    for ValueSamplesSvc<T> {
+                        type Response = super::ValueSamplesResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValueSamplesInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::value_samples(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ValueSamplesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/qdrant.PointsInternal/PointVersions" => {
+                    #[allow(non_camel_case_types)]
+                    struct PointVersionsSvc<T: PointsInternal>(pub Arc<T>);
+                    impl<
+                        T: PointsInternal,
+                    > tonic::server::UnaryService<super::PointVersionsInternal>
+                    for PointVersionsSvc<T> {
+                        type Response = super::PointVersionsResponseInternal;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PointVersionsInternal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PointsInternal>::point_versions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PointVersionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -13644,6 +14914,9 @@ pub struct ReplicaSetTelemetry {
     /// Snapshot operations telemetry
     #[prost(message, optional, tag = "6")]
     pub partial_snapshot: ::core::option::Option<PartialSnapshotTelemetry>,
+    /// Number of reads, which sent an additional request to another replica after `read_fan_out_delay_ms`
+    #[prost(uint64, optional, tag = "7")]
+    pub delayed_fan_outs: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -34,6 +34,19 @@ const MAX_CONNECTIONS_PER_CHANNEL: usize = 1024;
 pub const DEFAULT_RETRIES: usize = 2;
 const DEFAULT_BACKOFF: Duration = Duration::from_millis(100);
 
+/// Metadata key set on statuses of requests rejected by a peer, because the target shard is
+/// temporarily unavailable there.
+///
+/// Only describes the peer which answered the request. Peers don't pass the marker on, when
+/// the rejection comes from yet another peer, so each rejection is retried by one hop only.
+pub const SHARD_UNAVAILABLE_METADATA_KEY: &str = "qdrant-shard-unavailable";
+
+/// Metadata key set on statuses of requests rejected by a peer, because they conflict with the
+/// current state of the points, e.g. with their versions.
+///
+/// Tells the conflict apart from other `Aborted` statuses, which are not produced by qdrant.
+pub const VERSION_CONFLICT_METADATA_KEY: &str = "qdrant-version-conflict";
+
 /// How long to wait for response from server, before checking health of the server
 const SMART_CONNECT_INTERVAL: Duration = Duration::from_secs(1);
 
@@ -57,6 +70,94 @@ enum RetryAction {
     RetryOnce(Status),
     RetryWithBackoff(Status),
     RetryImmediately(Status),
+    RetryShardUnavailable(Status, Duration),
+}
+
+/// Extra retries of idempotent requests rejected by a peer, because the target shard is
+/// temporarily unavailable, e.g. in the middle of a failover
+///
+/// Once these are used up, such rejections are retried like any other unavailable peer.
+#[derive(Copy, Clone, Debug)]
+pub struct ShardUnavailableRetry {
+    /// Max number of extra retries, 0 to only retry like any other unavailable peer
+    pub retries: usize,
+    /// Backoff before the first retry, doubled for each next one
+    pub backoff: Duration,
+}
+
+impl Default for ShardUnavailableRetry {
+    fn default() -> Self {
+        Self {
+            retries: 0,
+            backoff: DEFAULT_BACKOFF,
+        }
+    }
+}
+
+impl ShardUnavailableRetry {
+    /// Backoff before the next retry, after `attempt` retries were made already
+    ///
+    /// Returns `None` if no retries are left.
+    fn backoff(&self, attempt: usize) -> Option<Duration> {
+        (attempt < self.retries).then(|| {
+            self.backoff
+                .saturating_mul(2u32.saturating_pow(attempt as u32))
+        })
+    }
+}
+
+/// Whether the peer rejected the request, because the target shard is temporarily unavailable
+pub fn is_shard_unavailable(status: &Status) -> bool {
+    status.code() == Code::Unavailable
+        && status
+            .metadata()
+            .contains_key(SHARD_UNAVAILABLE_METADATA_KEY)
+}
+
+/// Whether the peer rejected the request, because it conflicts with the current state of the points
+pub fn is_version_conflict(status: &Status) -> bool {
+    status.code() == Code::Aborted
+        && status
+            .metadata()
+            .contains_key(VERSION_CONFLICT_METADATA_KEY)
+}
+
+/// How to handle the error status returned by the peer for the request
+///
+/// `shard_unavailable_backoff` is the backoff of the next extra retry of shard unavailable
+/// rejections, `None` if there are no extra retries left.
+fn request_error_action(
+    status: Status,
+    idempotent: bool,
+    shard_unavailable_backoff: Option<Duration>,
+) -> RetryAction {
+    if is_shard_unavailable(&status) {
+        if !idempotent {
+            // The peer might have applied the request partially, so we can't repeat it
+            return RetryAction::Fail(status);
+        }
+        if let Some(backoff) = shard_unavailable_backoff {
+            return RetryAction::RetryShardUnavailable(status, backoff);
+        }
+        // No extra retries left, retry like any other unavailable peer
+    }
+
+    match status.code() {
+        Code::Cancelled | Code::Unavailable => {
+            // Possible situations:
+            // - Server is frozen and will never respond.
+            // - Server is overloaded and will respond in the future.
+            RetryAction::RetryWithBackoff(status)
+        }
+        Code::Internal => {
+            // Something is broken, but let's retry anyway, but only once.
+            RetryAction::RetryOnce(status)
+        }
+        _ => {
+            // No special handling, just fail already.
+            RetryAction::Fail(status)
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -121,6 +222,7 @@ pub struct TransportChannelPool {
     connection_timeout: Duration,
     tls_config: Option<ClientTlsConfig>,
     api_key: Option<String>,
+    shard_unavailable_retry: ShardUnavailableRetry,
 }
 
 impl Default for TransportChannelPool {
@@ -132,6 +234,7 @@ impl Default for TransportChannelPool {
             connection_timeout: DEFAULT_CONNECT_TIMEOUT,
             tls_config: None,
             api_key: None,
+            shard_unavailable_retry: ShardUnavailableRetry::default(),
         }
     }
 }
@@ -143,6 +246,7 @@ impl TransportChannelPool {
         pool_size: usize,
         tls_config: Option<ClientTlsConfig>,
         api_key: Option<String>,
+        shard_unavailable_retry: ShardUnavailableRetry,
     ) -> Self {
         Self {
             uri_to_pool: Default::default(),
@@ -151,6 +255,7 @@ impl TransportChannelPool {
             pool_size: NonZeroUsize::new(pool_size).unwrap(),
             tls_config,
             api_key,
+            shard_unavailable_retry,
         }
     }
 
@@ -311,9 +416,28 @@ impl TransportChannelPool {
         f: impl Fn(InterceptedService<Channel, PoolInterceptor>) -> O,
         timeout: Option<Duration>,
         retries: usize,
+    ) -> Result<T, RequestError<Status>> {
+        self.with_channel_retries(uri, f, timeout, retries, true)
+            .await
+    }
+
+    /// Use channel to `uri`, retrying failed requests up to `retries` times.
+    ///
+    /// Rejections of `idempotent` requests, because the target shard is temporarily unavailable,
+    /// are first retried with the extra budget of [`ShardUnavailableRetry`], then like any other
+    /// unavailable peer. Other requests are not retried on such rejections, as the peer might
+    /// have applied them partially.
+    async fn with_channel_retries<T, O: Future<Output = Result<T, Status>>>(
+        &self,
+        uri: &Uri,
+        f: impl Fn(InterceptedService<Channel, PoolInterceptor>) -> O,
+        timeout: Option<Duration>,
+        retries: usize,
+        idempotent: bool,
     ) -> Result<T, RequestError<Status>> {
         let mut retries_left = retries;
         let mut attempt = 0;
+        let mut shard_unavailable_attempt = 0;
 
         // Actual timeout must be slightly larger than request timeout, so that in case
         // of timeout even, we have a headroom to properly handle and report it.
@@ -356,24 +480,12 @@ impl TransportChannelPool {
                         }
                     }
                 }
-                RequestFailure::RequestError(status) => {
-                    match status.code() {
-                        Code::Cancelled | Code::Unavailable => {
-                            // Possible situations:
-                            // - Server is frozen and will never respond.
-                            // - Server is overloaded and will respond in the future.
-                            RetryAction::RetryWithBackoff(status)
-                        }
-                        Code::Internal => {
-                            // Something is broken, but let's retry anyway, but only once.
-                            RetryAction::RetryOnce(status)
-                        }
-                        _ => {
-                            // No special handling, just fail already.
-                            RetryAction::Fail(status)
-                        }
-                    }
-                }
+                RequestFailure::RequestError(status) => request_error_action(
+                    status,
+                    idempotent,
+                    self.shard_unavailable_retry
+                        .backoff(shard_unavailable_attempt),
+                ),
                 RequestFailure::RequestConnection(error) => {
                     // Can't establish connection to the server during the request.
                     // Possible situation:
@@ -407,6 +519,18 @@ impl TransportChannelPool {
                     }
                     (Duration::ZERO, fallback_status)
                 }
+                RetryAction::RetryShardUnavailable(status, backoff) => {
+                    // Extra retry budget, independent of the retries of failed requests
+                    let backoff = backoff + Duration::from_millis(rng().random_range(0..100));
+
+                    if backoff > max_timeout {
+                        return Err(RequestError::FromClosure(status));
+                    }
+
+                    shard_unavailable_attempt += 1;
+                    tokio::time::sleep(backoff).await;
+                    continue;
+                }
             };
 
             attempt += 1;
@@ -430,8 +554,115 @@ impl TransportChannelPool {
             .await
     }
 
+    /// Like [`Self::with_channel`], for requests which are not safe to repeat, like updates.
+    ///
+    /// Such requests are not retried, when the peer rejects them because the target shard is
+    /// temporarily unavailable.
+    pub async fn with_channel_non_idempotent<T, O: Future<Output = Result<T, Status>>>(
+        &self,
+        uri: &Uri,
+        f: impl Fn(InterceptedService<Channel, PoolInterceptor>) -> O,
+    ) -> Result<T, RequestError<Status>> {
+        self.with_channel_retries(uri, f, None, DEFAULT_RETRIES, false)
+            .await
+    }
+
     /// Default time to wait for a request to complete.
     pub fn request_timeout(&self) -> Duration {
         self.grpc_timeout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tonic::metadata::MetadataValue;
+
+    use super::*;
+
+    fn shard_unavailable_status() -> Status {
+        let mut status = Status::unavailable("Shard 0 is not active");
+        status.metadata_mut().insert(
+            SHARD_UNAVAILABLE_METADATA_KEY,
+            MetadataValue::from_static("true"),
+        );
+        status
+    }
+
+    #[test]
+    fn test_shard_unavailable_action() {
+        assert!(is_shard_unavailable(&shard_unavailable_status()));
+        assert!(!is_shard_unavailable(&Status::unavailable("Peer is down")));
+
+        assert!(matches!(
+            request_error_action(shard_unavailable_status(), true, Some(DEFAULT_BACKOFF)),
+            RetryAction::RetryShardUnavailable(_, DEFAULT_BACKOFF),
+        ));
+        assert!(matches!(
+            request_error_action(shard_unavailable_status(), true, None),
+            RetryAction::RetryWithBackoff(_),
+        ));
+        for backoff in [Some(DEFAULT_BACKOFF), None] {
+            assert!(matches!(
+                request_error_action(shard_unavailable_status(), false, backoff),
+                RetryAction::Fail(_),
+            ));
+        }
+
+        // Unreachable or overloaded peers are retried as before
+        for idempotent in [true, false] {
+            assert!(matches!(
+                request_error_action(Status::unavailable("Peer is down"), idempotent, None),
+                RetryAction::RetryWithBackoff(_),
+            ));
+        }
+    }
+
+    #[test]
+    fn test_shard_unavailable_default_retries() {
+        // Without extra retries, idempotent requests are still retried with backoff
+        let retry = ShardUnavailableRetry::default();
+        assert!(matches!(
+            request_error_action(shard_unavailable_status(), true, retry.backoff(0)),
+            RetryAction::RetryWithBackoff(_),
+        ));
+        assert!(matches!(
+            request_error_action(shard_unavailable_status(), false, retry.backoff(0)),
+            RetryAction::Fail(_),
+        ));
+    }
+
+    #[test]
+    fn test_version_conflict() {
+        let mut status = Status::aborted("point 1 has version 2, expected 1");
+        assert!(!is_version_conflict(&status));
+
+        status.metadata_mut().insert(
+            VERSION_CONFLICT_METADATA_KEY,
+            MetadataValue::from_static("true"),
+        );
+        assert!(is_version_conflict(&status));
+
+        // The marker only applies to aborted requests
+        let mut status = Status::internal("Something is broken");
+        status.metadata_mut().insert(
+            VERSION_CONFLICT_METADATA_KEY,
+            MetadataValue::from_static("true"),
+        );
+        assert!(!is_version_conflict(&status));
+    }
+
+    #[test]
+    fn test_shard_unavailable_backoff() {
+        // No extra retries by default
+        assert_eq!(ShardUnavailableRetry::default().backoff(0), None);
+
+        let retry = ShardUnavailableRetry {
+            retries: 3,
+            backoff: Duration::from_millis(100),
+        };
+        assert_eq!(retry.backoff(0), Some(Duration::from_millis(100)));
+        assert_eq!(retry.backoff(1), Some(Duration::from_millis(200)));
+        assert_eq!(retry.backoff(2), Some(Duration::from_millis(400)));
+        assert_eq!(retry.backoff(3), None);
+    }
+}
@@ -2,7 +2,10 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use common::validation::{validate_range_generic, validate_shard_different_peers};
-use segment::data_types::index::validate_integer_index_params;
+use segment::data_types::index::{
+    validate_bool_index_params, validate_float_index_params, validate_geo_index_params,
+    validate_integer_index_params,
+};
 use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::qdrant as grpc;
@@ -225,6 +228,9 @@ impl Validate for grpc::condition::ConditionOneOf {
             ConditionOneOf::HasId(_) => Ok(()),
             ConditionOneOf::IsNull(_) => Ok(()),
             ConditionOneOf::HasVector(_) => Ok(()),
+            ConditionOneOf::UpdatedAfter(_) => Ok(()),
+            // Checked on conversion into the internal condition
+            ConditionOneOf::Expression(_) => Ok(()),
         }
     }
 }
@@ -260,17 +266,21 @@ impl Validate for grpc::FieldCondition {
             geo_bounding_box,
             geo_radius,
             geo_polygon,
+            geo_near_path,
             values_count,
             is_empty,
             is_null,
+            uuid_range,
         } = self;
 
         let all_fields_none = r#match.is_none()
             && range.is_none()
             && datetime_range.is_none()
+            && uuid_range.is_none()
             && geo_bounding_box.is_none()
             && geo_radius.is_none()
             && geo_polygon.is_none()
+            && geo_near_path.is_none()
             && values_count.is_none()
             && is_empty.is_none()
             && is_null.is_none();
@@ -382,6 +392,9 @@ impl Validate for super::qdrant::query::Variant {
             grpc::query::Variant::Formula(q) => q.validate(),
             grpc::query::Variant::Rrf(q) => q.validate(),
             grpc::query::Variant::RelevanceFeedback(q) => q.validate(),
+            grpc::query::Variant::Recency(q) => q.validate(),
+            grpc::query::Variant::Proximity(q) => q.validate(),
+            grpc::query::Variant::WeightedSample(q) => q.validate(),
             grpc::query::Variant::Sample(_)
             | grpc::query::Variant::Fusion(_)
             | grpc::query::Variant::OrderBy(_) => Ok(()),
@@ -414,6 +427,7 @@ impl Validate for super::qdrant::expression::Variant {
             grpc::expression::Variant::GeoDistance(_) => Ok(()),
             grpc::expression::Variant::Datetime(_) => Ok(()),
             grpc::expression::Variant::DatetimeKey(_) => Ok(()),
+            grpc::expression::Variant::TextScore(_) => Ok(()),
             grpc::expression::Variant::Mult(mult_expression) => mult_expression.validate(),
             grpc::expression::Variant::Sum(sum_expression) => sum_expression.validate(),
             grpc::expression::Variant::Div(div_expression) => div_expression.validate(),
@@ -503,10 +517,16 @@ impl Validate for super::qdrant::payload_index_params::IndexParams {
             grpc::payload_index_params::IndexParams::IntegerIndexParams(integer_index_params) => {
                 integer_index_params.validate()
             }
-            grpc::payload_index_params::IndexParams::FloatIndexParams(_) => Ok(()),
-            grpc::payload_index_params::IndexParams::GeoIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::FloatIndexParams(float_index_params) => {
+                float_index_params.validate()
+            }
+            grpc::payload_index_params::IndexParams::GeoIndexParams(geo_index_params) => {
+                geo_index_params.validate()
+            }
             grpc::payload_index_params::IndexParams::TextIndexParams(_) => Ok(()),
-            grpc::payload_index_params::IndexParams::BoolIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::BoolIndexParams(bool_index_params) => {
+                bool_index_params.validate()
+            }
             grpc::payload_index_params::IndexParams::DatetimeIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::UuidIndexParams(_) => Ok(()),
         }
@@ -526,6 +546,40 @@ impl Validate for super::qdrant::IntegerIndexParams {
     }
 }
 
+impl Validate for super::qdrant::BoolIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let super::qdrant::BoolIndexParams {
+            on_disk: _,
+            enable_hnsw: _,
+            max_positions,
+        } = &self;
+        validate_bool_index_params(*max_positions)
+    }
+}
+
+impl Validate for super::qdrant::FloatIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let super::qdrant::FloatIndexParams {
+            on_disk: _,
+            is_principal: _,
+            enable_hnsw: _,
+            precision,
+        } = &self;
+        validate_float_index_params(*precision)
+    }
+}
+
+impl Validate for super::qdrant::GeoIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let super::qdrant::GeoIndexParams {
+            on_disk: _,
+            enable_hnsw: _,
+            h3_resolution,
+        } = &self;
+        validate_geo_index_params(*h3_resolution)
+    }
+}
+
 impl Validate for super::qdrant::points_selector::PointsSelectorOneOf {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
@@ -671,6 +725,7 @@ mod tests {
         let bad_polygon = GeoPolygon {
             exterior: Some(GeoLineString { points: vec![] }),
             interiors: vec![],
+            tolerance: None,
         };
         assert!(
             bad_polygon.validate().is_err(),
@@ -686,6 +741,7 @@ mod tests {
                 ],
             }),
             interiors: vec![],
+            tolerance: None,
         };
         assert!(
             bad_polygon.validate().is_err(),
@@ -702,6 +758,7 @@ mod tests {
                 ],
             }),
             interiors: vec![],
+            tolerance: None,
         };
 
         assert!(
@@ -726,6 +783,7 @@ mod tests {
                     GeoPoint { lat: 2., lon: 2. },
                 ],
             }],
+            tolerance: None,
         };
 
         assert!(
@@ -743,6 +801,7 @@ mod tests {
                 ],
             }),
             interiors: vec![],
+            tolerance: None,
         };
         assert!(
             good_polygon.validate().is_ok(),
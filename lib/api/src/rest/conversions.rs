@@ -1,12 +1,17 @@
 use std::collections::HashMap;
 
+use segment::data_types::text_query::{QueryStopwords, SnippetParams, TextCondition};
 use segment::data_types::vectors::{DenseVector, VectorInternal, VectorStructInternal};
+use segment::json_path::JsonPath;
+use segment::types::Filter;
 use uuid::Uuid;
 
 use super::schema::{ScoredPoint, Vector};
 use super::{
-    FacetRequestInternal, FacetResponse, FacetValue, FacetValueHit, NearestQuery, Query,
-    QueryInterface, VectorOutput, VectorStructOutput,
+    AppliedBoost, FacetRequestInternal, FacetResponse, FacetValue, FacetValueHit, NearestQuery,
+    PointProvenance, Query, QueryFacet, QueryFacetResponse, QueryInterface, QuerySnippets,
+    QueryStats, ScoreExplanation, ScoreStage, ScoreStageKind, TextConditionStopwords, VectorOutput,
+    VectorStructOutput,
 };
 use crate::grpc;
 use crate::rest::NamedVectorStruct;
@@ -75,6 +80,59 @@ impl From<Vector> for VectorInternal {
     }
 }
 
+impl PointProvenance {
+    /// Provenance of the point, if it was scored by a segment
+    pub fn from_scored_point(point: &segment::types::ScoredPoint) -> Option<Self> {
+        let segment::types::ScoredPointProvenance {
+            segment_uuid,
+            appendable,
+            shard_id,
+            peer_id,
+        } = point.provenance?;
+        Some(Self {
+            id: point.id,
+            version: point.version,
+            segment_uuid,
+            appendable,
+            shard_id,
+            peer_id,
+        })
+    }
+}
+
+impl ScoreExplanation {
+    /// Explanation of the score of the point, from its recorded score stages
+    pub fn from_scored_point(point: &segment::types::ScoredPoint) -> Self {
+        Self {
+            id: point.id,
+            stages: point
+                .score_stages
+                .iter()
+                .cloned()
+                .map(ScoreStage::from)
+                .collect(),
+            score: point.score,
+        }
+    }
+}
+
+impl From<segment::types::ScoreStage> for ScoreStage {
+    fn from(stage: segment::types::ScoreStage) -> Self {
+        let segment::types::ScoreStage { kind, score } = stage;
+        let (kind, boost) = match kind {
+            segment::types::ScoreStageKind::Vector => (ScoreStageKind::Vector, None),
+            segment::types::ScoreStageKind::Formula => (ScoreStageKind::Formula, None),
+            segment::types::ScoreStageKind::Fusion => (ScoreStageKind::Fusion, None),
+            segment::types::ScoreStageKind::Mmr => (ScoreStageKind::Mmr, None),
+            segment::types::ScoreStageKind::Boost { rule, multiplier } => (
+                ScoreStageKind::Boost,
+                Some(AppliedBoost { rule, multiplier }),
+            ),
+        };
+        Self { kind, boost, score }
+    }
+}
+
 impl From<segment::types::ScoredPoint> for ScoredPoint {
     fn from(value: segment::types::ScoredPoint) -> Self {
         let segment::types::ScoredPoint {
@@ -85,6 +143,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            provenance: _,   // Reported under the `debug` key of the query response
+            score_stages: _, // Reported under the `debug` key of the query response
         } = value;
         ScoredPoint {
             id,
@@ -163,9 +223,78 @@ impl From<segment::data_types::facets::FacetValueHit> for FacetValueHit {
 
 impl From<segment::data_types::facets::FacetResponse> for FacetResponse {
     fn from(value: segment::data_types::facets::FacetResponse) -> Self {
-        let segment::data_types::facets::FacetResponse { hits } = value;
+        let segment::data_types::facets::FacetResponse { hits, other_count } = value;
         Self {
             hits: hits.into_iter().map(From::from).collect(),
+            other: other_count,
+        }
+    }
+}
+
+impl QueryFacet {
+    /// Parameters to count the values of this facet, over all points matching the query `filter`
+    pub fn into_facet_params(
+        self,
+        filter: Option<Filter>,
+    ) -> segment::data_types::facets::FacetParams {
+        let QueryFacet { key, limit } = self;
+        segment::data_types::facets::FacetParams {
+            key,
+            limit: limit.unwrap_or(segment::data_types::facets::FacetParams::DEFAULT_LIMIT),
+            filter,
+            exact: segment::data_types::facets::FacetParams::DEFAULT_EXACT,
+        }
+    }
+}
+
+impl QuerySnippets {
+    /// Parameters to pick the excerpts, with the defaults for the missing options
+    pub fn snippet_params(&self) -> SnippetParams {
+        SnippetParams {
+            length: self.length.unwrap_or(SnippetParams::DEFAULT_LENGTH),
+            count: self.count.unwrap_or(SnippetParams::DEFAULT_COUNT),
+        }
+    }
+}
+
+impl From<(JsonPath, segment::data_types::facets::FacetResponse)> for QueryFacetResponse {
+    fn from((key, response): (JsonPath, segment::data_types::facets::FacetResponse)) -> Self {
+        let FacetResponse { hits, other } = FacetResponse::from(response);
+        Self { key, hits, other }
+    }
+}
+
+impl From<(TextCondition, QueryStopwords)> for TextConditionStopwords {
+    fn from((condition, stopwords): (TextCondition, QueryStopwords)) -> Self {
+        let TextCondition {
+            key,
+            text,
+            language: _,
+        } = condition;
+        let QueryStopwords { removed, is_empty } = stopwords;
+        let warning = is_empty.then(|| {
+            "All query tokens were removed as stopwords, the condition matches no points"
+                .to_string()
+        });
+        Self {
+            key,
+            text,
+            removed: removed.into_iter().collect(),
+            is_empty,
+            warning,
+        }
+    }
+}
+
+impl From<&common::query_stats::QueryStats> for QueryStats {
+    fn from(stats: &common::query_stats::QueryStats) -> Self {
+        let searched_segments = stats.searched_segments();
+        Self {
+            segment_limit: stats.segment_limit(),
+            partial: searched_segments.map(|segments| segments.is_partial()),
+            searched_segments: searched_segments.map(|segments| segments.searched),
+            total_segments: searched_segments.map(|segments| segments.total),
+            quantization_fallbacks: stats.quantization_fallbacks(),
         }
     }
 }
@@ -37,6 +37,37 @@ pub enum ApiStatus {
     Error(String),
     Accepted,
     AlreadyInProgress,
+    /// Same as [`Self::Error`], with a machine-readable code of the error
+    #[serde(untagged)]
+    ErrorWithCode(ApiError),
+}
+
+/// Description of an error, with a machine-readable code of the error
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ApiError {
+    pub error: String,
+    pub code: ErrorCode,
+}
+
+/// Machine-readable code of the error, for handling errors without parsing their description
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    BadInput,
+    NotFound,
+    ServiceError,
+    BadRequest,
+    Locked,
+    Timeout,
+    AlreadyExists,
+    ChecksumMismatch,
+    Forbidden,
+    PreconditionFailed,
+    InferenceError,
+    RateLimitExceeded,
+    ShardUnavailable,
+    EmptyPartialSnapshot,
+    Conflict,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -9,11 +9,12 @@ use schemars::JsonSchema;
 use segment::common::utils::MaybeOneOrMany;
 use segment::data_types::index::{StemmingAlgorithm, StopwordsInterface, TokenizerType};
 use segment::data_types::order_by::OrderByInterface;
+use segment::data_types::text_query::TextSnippet;
 use segment::data_types::vectors::{DenseVector, MultiDenseVector};
 use segment::json_path::JsonPath;
 use segment::types::{
     Condition, Filter, GeoPoint, IntPayloadType, Payload, PointIdType, SearchParams, ShardKey,
-    VectorNameBuf, WithPayloadInterface, WithVector,
+    TextScoreAggregation, VectorNameBuf, WithPayloadInterface, WithVector,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -478,6 +479,10 @@ pub struct Record {
     pub shard_key: Option<segment::types::ShardKey>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Similarity to the `score_with` vector of the scroll request, if requested.
+    /// Missing if the point has no such vector.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<ScoreType>,
 }
 
 /// Vector data separator for named and unnamed modes
@@ -570,8 +575,14 @@ pub struct QueryRequestInternal {
     pub params: Option<SearchParams>,
 
     /// Return points with scores better than this threshold.
+    /// If not set, the `default_score_threshold` of the collection is used.
     pub score_threshold: Option<ScoreType>,
 
+    /// Whether to use the `default_score_threshold` of the collection, if `score_threshold` is
+    /// not set. Only applies to nearest neighbour and average vector recommendation queries.
+    /// Set to false to return results of any score. Default is true.
+    pub apply_default_score_threshold: Option<bool>,
+
     /// Max number of points to return. Default is 10.
     #[validate(range(min = 1))]
     pub limit: Option<usize>,
@@ -590,6 +601,97 @@ pub struct QueryRequestInternal {
     /// Note: the other collection vectors should have the same vector size as the 'using' vector in the current collection
     #[serde(default)]
     pub lookup_from: Option<LookupLocation>,
+
+    /// Payload fields to count the values of, returned alongside the points.
+    /// Values are counted over all points matching the `filter`,
+    /// regardless of `limit` and `offset`.
+    /// Not supported in batch requests.
+    #[validate(nested)]
+    pub facets: Option<Vec<QueryFacet>>,
+
+    /// Whether to apply the boost rules of the collection to the scores of the results.
+    /// Default is true.
+    pub apply_boost_rules: Option<bool>,
+
+    /// Debug: return the segment, shard and peer, which scored each of the returned points,
+    /// under the `debug` key. Not supported in batch requests. Default is false.
+    pub with_provenance: Option<bool>,
+
+    /// Debug: return how the score of each of the returned points is composed, under the `debug`
+    /// key. Not supported in batch requests. Default is false.
+    pub with_explanation: Option<bool>,
+
+    /// Debug: return the query tokens of the full-text conditions of the `filter`, which are
+    /// removed as stopwords, under the `debug` key. Not supported in batch requests.
+    /// Default is false.
+    pub with_stopwords: Option<bool>,
+
+    /// Milliseconds after which the query returns the best results of the segments searched until
+    /// then, instead of searching all of them. The `stats` of the response report the number of
+    /// searched segments. Default is to search all segments.
+    #[validate(range(min = 1))]
+    pub soft_timeout_ms: Option<u64>,
+
+    /// Return excerpts of a text field of each of the returned points around the matches of a
+    /// text, under the `snippets` key. Not supported in batch requests.
+    #[validate(nested)]
+    pub snippets: Option<QuerySnippets>,
+
+    /// Return the nearest neighbors of the top returned points, by their stored `using` vector,
+    /// under the `neighbors` key. Not supported in batch requests.
+    #[validate(nested)]
+    pub neighbors: Option<QueryNeighbors>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct QuerySnippets {
+    /// Payload key of the text field to take the excerpts from. Requires a full-text index.
+    pub key: JsonPath,
+
+    /// Text to match. Default is the text of the first full-text condition on the `key` in the
+    /// `filter` or the prefetches.
+    pub text: Option<String>,
+
+    /// Target length of an excerpt, in chars. Default is 200.
+    #[validate(range(min = 1))]
+    pub length: Option<usize>,
+
+    /// Max number of excerpts per point, best first. Default is 1.
+    #[validate(range(min = 1))]
+    pub count: Option<usize>,
+}
+
+/// Max number of returned points, which can be expanded with their nearest neighbors
+pub const MAX_QUERY_NEIGHBORS_SEEDS: usize = 16;
+
+/// Max number of nearest neighbors of a single returned point
+pub const MAX_QUERY_NEIGHBORS_LIMIT: usize = 32;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct QueryNeighbors {
+    /// Max number of neighbors of each expanded point, best first, at most 32.
+    /// A point is never its own neighbor.
+    #[validate(range(min = 1, max = MAX_QUERY_NEIGHBORS_LIMIT))]
+    pub limit: usize,
+
+    /// Number of the top returned points to expand. Default is all of them, at most 16.
+    #[validate(range(min = 1, max = MAX_QUERY_NEIGHBORS_SEEDS))]
+    pub seeds: Option<usize>,
+}
+
+/// Max number of values of a single facet in query results
+pub const MAX_QUERY_FACET_LIMIT: usize = 100;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct QueryFacet {
+    /// Payload key to count the values of. Requires a keyword, integer, uuid or bool index,
+    /// or a geo index with `h3_resolution` to count the points per H3 cell.
+    pub key: JsonPath,
+
+    /// Max number of values to return, most frequent first. Default is 10, at most 100.
+    /// Values with the same count are ordered by value ascending.
+    #[validate(range(min = 1, max = MAX_QUERY_FACET_LIMIT))]
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -609,6 +711,226 @@ pub struct QueryRequestBatch {
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct QueryResponse {
     pub points: Vec<ScoredPoint>,
+    /// Value counts of the requested facets, in the order of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<QueryFacetResponse>>,
+    /// Excerpts of the text field of the returned points, if requested, in the order of the points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippets: Option<Vec<PointSnippets>>,
+    /// Nearest neighbors of the top returned points, if requested, in the order of the points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neighbors: Option<Vec<PointNeighbors>>,
+    /// Debug information, if requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug: Option<QueryDebugInfo>,
+    /// Statistics of the execution of the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<QueryStats>,
+}
+
+/// Excerpts of the text field of a returned point
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PointSnippets {
+    pub id: PointIdType,
+    /// Excerpts, best first. Empty if the point has no matching text value in the field.
+    pub snippets: Vec<TextSnippet>,
+}
+
+/// Nearest neighbors of a returned point
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PointNeighbors {
+    pub id: PointIdType,
+    /// Neighbors, best first, scored against the stored vector of the point.
+    /// They match the `filter` of the query, and have the same payload and vector options.
+    pub neighbors: Vec<ScoredPoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct FederatedQueryRequest {
+    /// Names of the collections to run the query on.
+    /// All collections must have the same vectors, with the same size, distance and modifier.
+    #[validate(length(min = 1))]
+    pub collections: Vec<String>,
+
+    /// Query to run on each of the collections. Facets and debug options are not supported.
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub internal: QueryRequestInternal,
+
+    /// If true, collections which fail to respond are listed in `errors`,
+    /// and the results of the other collections are returned.
+    /// If false, the whole request fails. Default is false.
+    pub allow_partial_results: Option<bool>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FederatedQueryResponse {
+    /// Points of all collections, ranked by score
+    pub points: Vec<FederatedScoredPoint>,
+    /// Collections which failed to respond, only present if partial results are allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<FederatedQueryError>>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FederatedScoredPoint {
+    /// Name of the collection the point comes from
+    pub collection: String,
+    #[serde(flatten)]
+    pub point: ScoredPoint,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FederatedQueryError {
+    pub collection: String,
+    pub error: String,
+}
+
+#[derive(Debug, Default, Serialize, JsonSchema)]
+pub struct QueryStats {
+    /// Number of results each segment contributed at most to the merge of the results of its
+    /// shard, if limited by `segment_limit_factor`. The largest limit of all shards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_limit: Option<usize>,
+    /// Whether the soft timeout stopped the search before all segments were searched, so better
+    /// points may be missing in the results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial: Option<bool>,
+    /// Segments of all shards, which were searched before the soft timeout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub searched_segments: Option<usize>,
+    /// Segments of all shards to search, if a soft timeout was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_segments: Option<usize>,
+    /// Segment searches, which were retried with larger oversampling, because quantized and
+    /// rescored rankings disagreed too much. See `quantization.fallback` of search params.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantization_fallbacks: Option<usize>,
+}
+
+impl QueryStats {
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            segment_limit,
+            partial,
+            searched_segments,
+            total_segments,
+            quantization_fallbacks,
+        } = self;
+
+        segment_limit.is_none()
+            && partial.is_none()
+            && searched_segments.is_none()
+            && total_segments.is_none()
+            && quantization_fallbacks.is_none()
+    }
+
+    pub fn into_non_empty(self) -> Option<Self> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct QueryDebugInfo {
+    /// Segments, which scored the returned points, in the order of the points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Vec<PointProvenance>>,
+    /// Composition of the scores of the returned points, in the order of the points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Vec<ScoreExplanation>>,
+    /// Query tokens removed as stopwords, in the order of the full-text conditions of the filter.
+    /// Conditions on fields without a full-text index are not listed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<Vec<TextConditionStopwords>>,
+}
+
+/// Query tokens removed as stopwords from a full-text condition of the filter
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TextConditionStopwords {
+    /// Full path of the field of the condition
+    pub key: JsonPath,
+    /// Text of the condition
+    pub text: String,
+    /// Removed tokens, sorted
+    pub removed: Vec<String>,
+    /// Whether no query tokens are left after the removal, so the condition matches no points
+    pub is_empty: bool,
+    /// Warning, if no query tokens are left after the removal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// Segment, which scored a returned point.
+///
+/// Points, which are not scored by a segment, like points ordered by a payload field, are not
+/// listed.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct PointProvenance {
+    pub id: PointIdType,
+    /// Version of the point in the segment
+    pub version: segment::types::SeqNumberType,
+    pub segment_uuid: uuid::Uuid,
+    /// Whether the segment is appendable, i.e. receives new points
+    pub appendable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_id: Option<u64>,
+}
+
+/// Composition of the score of a returned point, recorded while the point was scored
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ScoreExplanation {
+    pub id: PointIdType,
+    /// Stages, which composed the score, in the order they were applied.
+    /// Stages rescoring prefetched points follow the stages of the prefetch, which found the point.
+    /// If several prefetches found it, the stages of the first of them are listed.
+    /// Orders and samples discard the scores, so their points have no stages.
+    pub stages: Vec<ScoreStage>,
+    /// Final score of the point, as returned
+    pub score: f32,
+}
+
+/// Stage of the scoring of a returned point
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ScoreStage {
+    pub kind: ScoreStageKind,
+    /// Boost rule of the collection applied at this stage, for `boost` stages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boost: Option<AppliedBoost>,
+    /// Score of the point after this stage
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreStageKind {
+    /// Scored against the query vector(s)
+    Vector,
+    /// Scored by a formula, like recency or proximity boosting
+    Formula,
+    /// Fusion of the prefetches
+    Fusion,
+    /// Reordered by maximal marginal relevance
+    Mmr,
+    /// Multiplied by a boost rule of the collection
+    Boost,
+}
+
+/// Boost rule of the collection, applied to the score of a point
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AppliedBoost {
+    /// Name of the boost rule
+    pub rule: String,
+    pub multiplier: f64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct QueryFacetResponse {
+    pub key: JsonPath,
+    pub hits: Vec<FacetValueHit>,
+    /// Sum of the counts of the values, which are not in `hits`
+    pub other: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -634,7 +956,7 @@ pub enum Query {
     /// Return points that live in positive areas.
     Context(ContextQuery),
 
-    /// Order the points by a payload field.
+    /// Order the points by a payload field, see [`OrderByQuery`].
     OrderBy(OrderByQuery),
 
     /// Fuse the results of multiple prefetches.
@@ -646,9 +968,18 @@ pub enum Query {
     /// Score boosting via an arbitrary formula
     Formula(FormulaQuery),
 
+    /// Score boosting by recency of a datetime payload field
+    Recency(RecencyQuery),
+
+    /// Score boosting by proximity of a geo payload field to a point
+    Proximity(ProximityQuery),
+
     /// Sample points from the collection, non-deterministically.
     Sample(SampleQuery),
 
+    /// Sample points with probability proportional to a numeric payload field.
+    WeightedSample(WeightedSampleQuery),
+
     /// Use feedback from an oracle to improve the results
     RelevanceFeedback(RelevanceFeedbackQuery),
 }
@@ -687,6 +1018,11 @@ pub struct ContextQuery {
     pub context: ContextInput,
 }
 
+/// Order the points by a payload field, vectors are not scored.
+///
+/// If `using` names a vector, only the points having this vector are ordered. Without `using`,
+/// all points matching the filter are ordered, also the ones without the default vector.
+/// Over prefetches, the order of the payload field takes precedence over their scores.
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct OrderByQuery {
@@ -716,6 +1052,71 @@ pub struct FormulaQuery {
     pub defaults: HashMap<String, Value>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct RecencyQuery {
+    #[validate(nested)]
+    pub recency: RecencyBoost,
+}
+
+/// Boost the score of the prefetched points by their recency:
+/// `score * (1 + decay(age))`.
+///
+/// Brand-new points get their score doubled, the boost decays with the age of the point.
+/// Points without a datetime value are not boosted.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct RecencyBoost {
+    /// Payload field with the datetime of the point. Preferably indexed with a `datetime` index.
+    pub key: JsonPath,
+    /// Decay function of the age. Defaults to `exp`.
+    pub function: Option<DecayFunction>,
+    /// Age of the point, in seconds, at which the boost decays to `midpoint`. Must be a non-zero positive number.
+    pub scale: f32,
+    /// The boost at the age of `scale`. Should be between 0 and 1. Defaults to 0.5.
+    pub midpoint: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct ProximityQuery {
+    #[validate(nested)]
+    pub proximity: ProximityBoost,
+}
+
+/// Boost the score of the prefetched points by their proximity to the origin:
+/// `score * (1 + decay(distance))`.
+///
+/// Points at the origin get their score doubled, the boost decays with the distance of the point.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct ProximityBoost {
+    /// Payload field with the geo point of the point. Preferably indexed with a `geo` index.
+    pub key: JsonPath,
+    /// The geo point to measure the distance from
+    pub origin: GeoPoint,
+    /// Decay function of the distance. Defaults to `exp`.
+    pub function: Option<DecayFunction>,
+    /// Distance from the origin, in meters, at which the boost decays to `midpoint`. Must be a non-zero positive number.
+    pub scale: f32,
+    /// The boost at the distance of `scale`. Should be between 0 and 1. Defaults to 0.5.
+    pub midpoint: Option<f32>,
+    /// The boost of points without a geo point. Defaults to 0 - such points are not boosted.
+    pub missing_boost: Option<f32>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DecayFunction {
+    /// Linear decay
+    Lin,
+    /// Exponential decay
+    #[default]
+    Exp,
+    /// Gaussian decay
+    Gauss,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct SampleQuery {
@@ -723,6 +1124,33 @@ pub struct SampleQuery {
     pub sample: Sample,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct WeightedSampleQuery {
+    #[validate(nested)]
+    pub weighted_sample: WeightedSample,
+}
+
+/// Weighted random sample, with probability proportional to a numeric payload field.
+///
+/// Uses A-Res weighted reservoir sampling: every point gets a random key `u^(1/weight)`, and the
+/// points with the largest keys are sampled. Scores of the sampled points are their keys, in the
+/// log space: `ln(u) / weight`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct WeightedSample {
+    /// Payload field with the weights of the points. Requires a range index.
+    /// If a point has multiple values, the largest one is used.
+    pub key: JsonPath,
+    /// Seed of the sample. The same seed gives the same sample, as long as the data doesn't change.
+    /// Defaults to a random seed.
+    pub seed: Option<u64>,
+    /// Weight of the points without the field. Points with zero weight are never sampled.
+    /// Defaults to 0.
+    #[validate(range(min = 0.0))]
+    pub default_weight: Option<f64>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct RelevanceFeedbackQuery {
@@ -915,6 +1343,7 @@ pub enum Expression {
     GeoDistance(GeoDistance),
     Datetime(DatetimeExpression),
     DatetimeKey(DatetimeKeyExpression),
+    TextScore(TextScoreExpression),
     Mult(MultExpression),
     Sum(SumExpression),
     Neg(NegExpression),
@@ -953,6 +1382,22 @@ pub struct DatetimeKeyExpression {
     pub datetime_key: JsonPath,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TextScoreExpression {
+    pub text_score: TextScoreParams,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TextScoreParams {
+    /// Payload field with a full-text index
+    pub key: JsonPath,
+    /// Text to score the values of the field against. Each value is scored as the fraction of
+    /// the tokens of the text present in it.
+    pub text: String,
+    /// How the scores of the values of an array are combined. Default: max
+    pub aggregation: Option<TextScoreAggregation>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct MultExpression {
     #[validate(nested)]
@@ -1336,12 +1781,70 @@ pub struct SearchMatrixPairsResponse {
     pub pairs: Vec<SearchMatrixPair>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct SimilarityMatrixRequest {
+    /// Ids of the points to score against each other. At most 500 points.
+    #[validate(length(min = 1, max = 500))]
+    pub ids: Vec<PointIdType>,
+    /// Define which vector name to use for scoring. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct SimilarityMatrixResponse {
+    /// Ids of the scored points, in the order of the rows and columns of the matrix.
+    /// Points which don't exist or don't have the vector are left out.
+    pub ids: Vec<PointIdType>,
+    /// Square matrix of scores, `scores[i][j]` is the score of point `ids[j]` against `ids[i]`
+    pub scores: Vec<Vec<ScoreType>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct KDistanceRequest {
+    /// Look for the neighbors of this vector
+    #[validate(nested)]
+    pub vector: NamedVectorStruct,
+    /// Rank of the neighbor to return the score of, 1 is the nearest one
+    #[validate(range(min = 1))]
+    pub k: usize,
+    /// Look only for points which satisfies this conditions
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// Additional search params
+    #[validate(nested)]
+    pub params: Option<SearchParams>,
+    /// If true, also return the id of the k-th nearest point. Default is false.
+    #[serde(default)]
+    pub with_id: bool,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct KDistanceResponse {
+    /// Score of the k-th nearest point. Null if fewer than `k` points match.
+    pub score: Option<ScoreType>,
+    /// Id of the k-th nearest point, if requested with `with_id`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<PointIdType>,
+}
+
 #[derive(Debug, JsonSchema, Serialize, Deserialize, Validate)]
 pub struct FacetRequestInternal {
     /// Payload key to use for faceting.
     pub key: JsonPath,
 
     /// Max number of hits to return. Default is 10.
+    /// Values with the same count are ordered by value ascending.
+    /// Counts of the values, which are not returned, are summed into `other`.
     #[validate(range(min = 1))]
     pub limit: Option<usize>,
 
@@ -1379,6 +1882,21 @@ pub struct FacetValueHit {
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct FacetResponse {
     pub hits: Vec<FacetValueHit>,
+    /// Sum of the counts of the values, which are not in `hits`
+    pub other: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct QuantilesRequest {
+    /// Numeric payload key to split into quantiles.
+    /// Requires an integer or float index with range support.
+    pub key: JsonPath,
+
+    /// Number of buckets with about the same number of values each. Default is 10.
+    #[validate(range(min = 1, max = 1000))]
+    pub buckets: Option<usize>,
+
+    pub shard_key: Option<ShardKeySelector>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate)]
@@ -1411,6 +1929,23 @@ pub enum UpdateMode {
     UpdateOnly,
 }
 
+/// Defines how points with the same id in a single upsert request are handled
+///
+/// * `last_wins` - default, only the last point with the id is applied
+/// * `first_wins` - only the first point with the id is applied
+/// * `error` - reject the whole request, if any id occurs more than once
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateIdsPolicy {
+    // Default policy - only the last point with the id is applied
+    #[default]
+    LastWins,
+    // Only the first point with the id is applied
+    FirstWins,
+    // Reject the whole request
+    Error,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Validate, JsonSchema)]
 pub struct PointsBatch {
     #[validate(nested)]
@@ -1427,6 +1962,13 @@ pub struct PointsBatch {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+    /// If set, all points must exist with exactly this version, otherwise the whole operation fails with a conflict.
+    /// Only supported in collections with `replication_factor` 1, as versions differ between replicas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<segment::types::SeqNumberType>,
+    /// How to handle points with the same id in this request: last_wins (default), first_wins, error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_ids: Option<DuplicateIdsPolicy>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -1465,6 +2007,13 @@ pub struct PointsList {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+    /// If set, all points must exist with exactly this version, otherwise the whole operation fails with a conflict.
+    /// Only supported in collections with `replication_factor` 1, as versions differ between replicas.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<segment::types::SeqNumberType>,
+    /// How to handle points with the same id in this request: last_wins (default), first_wins, error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_ids: Option<DuplicateIdsPolicy>,
 }
 
 impl<'de> serde::Deserialize<'de> for PointInsertOperations {
@@ -40,8 +40,11 @@ impl Validate for Query {
             Query::Fusion(fusion) => fusion.validate(),
             Query::Rrf(rrf) => rrf.validate(),
             Query::Formula(formula) => formula.validate(),
+            Query::Recency(recency) => recency.validate(),
+            Query::Proximity(proximity) => proximity.validate(),
             Query::OrderBy(order_by) => order_by.validate(),
             Query::Sample(sample) => sample.validate(),
+            Query::WeightedSample(weighted_sample) => weighted_sample.validate(),
             Query::RelevanceFeedback(feedback) => feedback.validate(),
         }
     }
@@ -258,6 +261,7 @@ impl Validate for Expression {
             Expression::GeoDistance(_) => Ok(()),
             Expression::Datetime(_) => Ok(()),
             Expression::DatetimeKey(_) => Ok(()),
+            Expression::TextScore(_) => Ok(()),
             Expression::Mult(mult_expression) => mult_expression.validate(),
             Expression::Sum(sum_expression) => sum_expression.validate(),
             Expression::Neg(neg_expression) => neg_expression.validate(),
@@ -65,6 +65,7 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
             flush_interval_sec: 30,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            requantization: None,
         },
         wal_config,
         hnsw_config: Default::default(),
@@ -72,6 +73,7 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        boost_rules: Default::default(),
     };
 
     let optimizers_config = collection_config.optimizer_config.clone();
@@ -182,6 +184,9 @@ fn batch_search_bench(c: &mut Criterion) {
                             with_payload: WithPayloadInterface::Bool(true),
                             with_vector: WithVector::Bool(false),
                             score_threshold: None,
+                            with_provenance: false,
+                            with_explanation: false,
+                            soft_timeout: None,
                         };
                         searches.push(search_query);
                     }
@@ -283,6 +288,9 @@ fn batch_rrf_query_bench(c: &mut Criterion) {
                             with_payload: WithPayloadInterface::Bool(true),
                             with_vector: WithVector::Bool(false),
                             score_threshold: None,
+                            with_provenance: false,
+                            with_explanation: false,
+                            soft_timeout: None,
                         };
                         searches.push(search_query);
                     }
@@ -340,6 +348,9 @@ fn batch_rescore_bench(c: &mut Criterion) {
                             with_payload: WithPayloadInterface::Bool(true),
                             with_vector: WithVector::Bool(false),
                             score_threshold: None,
+                            with_provenance: false,
+                            with_explanation: false,
+                            soft_timeout: None,
                         };
                         searches.push(search_query);
                     }
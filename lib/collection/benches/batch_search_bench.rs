@@ -83,6 +83,7 @@ fn batch_search_bench(c: &mut Criterion) {
             flush_interval_sec: 30,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            requantization: None,
         },
         wal_config,
         hnsw_config: Default::default(),
@@ -90,6 +91,7 @@ fn batch_search_bench(c: &mut Criterion) {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        boost_rules: Default::default(),
     };
 
     let optimizers_config = collection_config.optimizer_config.clone();
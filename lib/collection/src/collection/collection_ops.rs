@@ -10,6 +10,7 @@ use shard::count::CountRequestInternal;
 use shard::operations::optimization::{OptimizationsRequestOptions, OptimizationsResponse};
 
 use super::Collection;
+use crate::operations::boost_rules::BoostRulesDiff;
 use crate::operations::config_diff::*;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
@@ -171,6 +172,17 @@ impl Collection {
         Ok(())
     }
 
+    /// Updates boost rules of the collection:
+    /// Saves new rules on disk
+    pub async fn update_boost_rules(&self, diff: BoostRulesDiff) -> CollectionResult<()> {
+        {
+            let mut config = self.collection_config.write().await;
+            diff.apply_to(&mut config.boost_rules);
+        }
+        self.collection_config.read().await.save(&self.path)?;
+        Ok(())
+    }
+
     /// Updates the strict mode configuration and saves it to disk.
     ///
     /// Order matters: rate limiters on each shard are updated *before* the new
@@ -430,11 +442,13 @@ impl Collection {
                     .unwrap_or_default();
 
                 let points_count = count_result.map(|x| x.count).unwrap_or(0);
+                let max_version = replica_set.local_max_segment_version().await;
                 local_shards.push(LocalShardInfo {
                     shard_id,
                     points_count,
                     state,
                     shard_key: shard_to_key.get(&shard_id).cloned(),
+                    max_version,
                 })
             }
             for (peer_id, state) in replica_set.peers() {
@@ -1,21 +1,22 @@
 use std::time::Duration;
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use api::rest::{
     SearchMatrixOffsetsResponse, SearchMatrixPair, SearchMatrixPairsResponse,
-    SearchMatrixRequestInternal,
+    SearchMatrixRequestInternal, SimilarityMatrixResponse,
 };
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::types::ScoreType;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::types::{
-    Condition, Filter, HasIdCondition, HasVectorCondition, PointIdType, ScoredPoint, VectorNameBuf,
-    WithPayloadInterface, WithVector,
+    Condition, Filter, HasIdCondition, HasVectorCondition, PointIdType, QuantizationSearchParams,
+    ScoredPoint, SearchParams, VectorNameBuf, WithPayloadInterface, WithVector,
 };
 
 use crate::collection::Collection;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
-use crate::operations::types::CollectionResult;
+use crate::operations::types::{CollectionError, CollectionResult, PointRequestInternal};
 use crate::operations::universal_query::collection_query::{
     CollectionQueryRequest, Query, VectorInputInternal, VectorQuery,
 };
@@ -60,6 +61,31 @@ impl From<SearchMatrixRequestInternal> for CollectionSearchMatrixRequest {
     }
 }
 
+/// Internal representation of the similarity matrix request over the given points
+pub struct CollectionSimilarityMatrixRequest {
+    pub ids: Vec<PointIdType>,
+    pub using: VectorNameBuf,
+}
+
+impl CollectionSimilarityMatrixRequest {
+    /// The number of scores grows quadratically with the number of points
+    pub const MAX_IDS: usize = 500;
+}
+
+#[derive(Debug, Default)]
+pub struct CollectionSimilarityMatrixResponse {
+    pub ids: Vec<PointIdType>,
+    /// `scores[i][j]` is the score of `ids[j]` against `ids[i]`
+    pub scores: Vec<Vec<ScoreType>>,
+}
+
+impl From<CollectionSimilarityMatrixResponse> for SimilarityMatrixResponse {
+    fn from(response: CollectionSimilarityMatrixResponse) -> Self {
+        let CollectionSimilarityMatrixResponse { ids, scores } = response;
+        Self { ids, scores }
+    }
+}
+
 impl From<CollectionSearchMatrixResponse> for SearchMatrixOffsetsResponse {
     fn from(response: CollectionSearchMatrixResponse) -> Self {
         let CollectionSearchMatrixResponse {
@@ -185,6 +211,9 @@ impl Collection {
             params: None,
             with_vector: WithVector::Selector(vec![using.clone()]), // retrieve the vector
             with_payload: Default::default(),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         };
 
         let mut sampled_points = self
@@ -241,6 +270,12 @@ impl Collection {
                 with_vector: WithVector::Bool(false),
                 with_payload: WithPayloadInterface::Bool(false),
                 lookup_from: None,
+                // Distances between points, not a ranking
+                apply_boost_rules: false,
+                apply_default_score_threshold: false,
+                with_provenance: false,
+                with_explanation: false,
+                soft_timeout: None,
             };
 
             queries.push((query_request, shard_selection.clone()));
@@ -283,6 +318,177 @@ impl Collection {
             nearests: nearest,
         })
     }
+
+    /// Score each of the given points against all the others, in a square matrix
+    pub async fn similarity_matrix(
+        &self,
+        request: CollectionSimilarityMatrixRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionSimilarityMatrixResponse> {
+        let start = std::time::Instant::now();
+        let CollectionSimilarityMatrixRequest { mut ids, using } = request;
+
+        // deduplicate, keeping the order of the request
+        let mut seen = AHashSet::with_capacity(ids.len());
+        ids.retain(|id| seen.insert(*id));
+
+        if ids.len() > CollectionSimilarityMatrixRequest::MAX_IDS {
+            return Err(CollectionError::bad_request(format!(
+                "Similarity matrix is limited to {} points, got {}",
+                CollectionSimilarityMatrixRequest::MAX_IDS,
+                ids.len(),
+            )));
+        }
+
+        self.collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(&using)?;
+
+        let records = self
+            .retrieve(
+                PointRequestInternal {
+                    ids: ids.clone(),
+                    with_payload: Some(WithPayloadInterface::Bool(false)),
+                    with_vector: WithVector::Selector(vec![using.clone()]),
+                },
+                read_consistency,
+                &shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        let mut vector_by_id: AHashMap<_, _> = records
+            .into_iter()
+            .filter_map(|record| {
+                let vector = record.vector.as_ref()?.get(&using)?.to_owned();
+                Some((record.id, vector))
+            })
+            .collect();
+
+        // points without the vector can't be scored
+        ids.retain(|id| vector_by_id.contains_key(id));
+
+        if ids.is_empty() {
+            return Ok(CollectionSimilarityMatrixResponse::default());
+        }
+
+        // score against the requested points only, all of them in each query
+        let filter = Filter::new_must(Condition::HasId(HasIdCondition::from(
+            ids.iter().copied().collect::<AHashSet<_>>(),
+        )));
+
+        // exact scores of the original vectors, the matrix is not a ranking
+        let params = SearchParams {
+            exact: true,
+            quantization: Some(QuantizationSearchParams {
+                ignore: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let queries = ids
+            .iter()
+            .map(|id| {
+                let vector = vector_by_id
+                    .remove(id)
+                    .expect("vector of the point is present");
+                let query =
+                    Query::Vector(VectorQuery::Nearest(VectorInputInternal::Vector(vector)));
+
+                let query_request = CollectionQueryRequest {
+                    prefetch: vec![],
+                    query: Some(query),
+                    using: using.clone(),
+                    filter: Some(filter.clone()),
+                    score_threshold: None,
+                    limit: ids.len(),
+                    offset: 0,
+                    params: Some(params),
+                    with_vector: WithVector::Bool(false),
+                    with_payload: WithPayloadInterface::Bool(false),
+                    lookup_from: None,
+                    apply_boost_rules: false,
+                    apply_default_score_threshold: false,
+                    with_provenance: false,
+                    with_explanation: false,
+                    soft_timeout: None,
+                };
+
+                (query_request, shard_selection.clone())
+            })
+            .collect();
+
+        // update timeout
+        let timeout = timeout.map(|timeout| timeout.saturating_sub(start.elapsed()));
+
+        // We know by construction that lookup_from is not used in the queries
+        // so can use placeholder closure here
+        let collection_by_name = |_name: String| async move { None };
+
+        let rows = self
+            .query_batch(
+                queries,
+                collection_by_name,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        let rows: Vec<AHashMap<_, _>> = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|point| (point.id, point.score))
+                    .collect()
+            })
+            .collect();
+
+        Ok(square_matrix(ids, rows))
+    }
+}
+
+/// Arrange the scores of each row by the order of the `ids`.
+///
+/// Points deleted in the middle of the request are missing in some rows, they are left out of
+/// the matrix entirely to keep it square. The row of a deleted point misses the point itself, so
+/// such rows are dropped first. Only the remaining rows are then checked to contain all of the
+/// remaining points, until every row is complete.
+fn square_matrix(
+    ids: Vec<PointIdType>,
+    rows: Vec<AHashMap<PointIdType, ScoreType>>,
+) -> CollectionSimilarityMatrixResponse {
+    let mut kept: Vec<usize> = (0..ids.len())
+        .filter(|&i| rows[i].contains_key(&ids[i]))
+        .collect();
+
+    loop {
+        let complete: Vec<usize> = kept
+            .iter()
+            .copied()
+            .filter(|&i| kept.iter().all(|&j| rows[i].contains_key(&ids[j])))
+            .collect();
+
+        if complete.len() == kept.len() {
+            break;
+        }
+        kept = complete;
+    }
+
+    let scores = kept
+        .iter()
+        .map(|&i| kept.iter().map(|&j| rows[i][&ids[j]]).collect())
+        .collect();
+    let ids = kept.iter().map(|&i| ids[i]).collect();
+
+    CollectionSimilarityMatrixResponse { ids, scores }
 }
 
 #[cfg(test)]
@@ -300,6 +506,8 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
         }
     }
 
@@ -333,6 +541,53 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_similarity_square_matrix() {
+        let ids: Vec<PointIdType> = vec![1.into(), 2.into(), 3.into()];
+        let row = |scores: &[(u64, ScoreType)]| {
+            scores
+                .iter()
+                .map(|&(id, score)| (PointIdType::from(id), score))
+                .collect::<AHashMap<_, _>>()
+        };
+
+        // point 3 got deleted while scoring, after the first row
+        let rows = vec![
+            row(&[(1, 1.0), (2, 0.5), (3, 0.1)]),
+            row(&[(2, 1.0), (1, 0.5)]),
+            row(&[(1, 0.5)]),
+        ];
+        let response = square_matrix(ids.clone(), rows);
+        assert_eq!(response.ids, ids[..2].to_vec());
+        assert_eq!(response.scores, vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+
+        // point 2 got deleted before the last row, the incomplete row of point 3 is dropped
+        let rows = vec![
+            row(&[(1, 1.0), (2, 0.5), (3, 0.1)]),
+            row(&[(2, 1.0), (1, 0.5), (3, 0.2)]),
+            row(&[(3, 1.0), (1, 0.1)]),
+        ];
+        let response = square_matrix(ids.clone(), rows);
+        assert_eq!(response.ids, vec![1.into(), 2.into()]);
+        assert_eq!(response.scores, vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+
+        let rows = vec![
+            row(&[(1, 1.0), (2, 0.5), (3, 0.1)]),
+            row(&[(2, 1.0), (1, 0.5), (3, 0.2)]),
+            row(&[(3, 1.0), (2, 0.2), (1, 0.1)]),
+        ];
+        let response = square_matrix(ids.clone(), rows);
+        assert_eq!(response.ids, ids);
+        assert_eq!(
+            response.scores,
+            vec![
+                vec![1.0, 0.5, 0.1],
+                vec![0.5, 1.0, 0.2],
+                vec![0.1, 0.2, 1.0],
+            ],
+        );
+    }
+
     #[test]
     fn test_matrix_offsets_response_conversion() {
         let response = fixture_response();
@@ -46,12 +46,17 @@ impl Collection {
 
         // Collect results from all shards into a single map
         let mut aggregated_results: HashMap<FacetValue, usize> = HashMap::new();
+        // Values, which shards didn't return individually
+        let mut shards_other_count = 0;
         while let Some(response) = shards_reads_f.try_next().await? {
             for hit in response.hits {
                 *aggregated_results.entry(hit.value).or_insert(0) += hit.count;
             }
+            shards_other_count += response.other_count;
         }
 
-        Ok(FacetResponse::top_hits(aggregated_results, limit))
+        let mut response = FacetResponse::top_hits(aggregated_results, limit);
+        response.other_count += shards_other_count;
+        Ok(response)
     }
 }
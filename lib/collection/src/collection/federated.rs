@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroU64;
+
+use itertools::{Either, Itertools};
+use segment::data_types::modifier::Modifier;
+use segment::types::{Distance, MultiVectorConfig, Order, ScoredPoint, VectorName};
+use segment::utils::scored_point_ties::ScoredPointTies;
+
+use super::Collection;
+use super::query::boosted_order;
+use crate::config::CollectionParams;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::universal_query::collection_query::CollectionQueryRequest;
+
+/// Parts of the vector config, which affect the scores of the query results
+#[derive(Debug, PartialEq)]
+enum VectorSchema {
+    Dense {
+        size: NonZeroU64,
+        distance: Distance,
+        multivector_config: Option<MultiVectorConfig>,
+    },
+    Sparse {
+        modifier: Modifier,
+    },
+}
+
+fn vector_schema(params: &CollectionParams) -> BTreeMap<&VectorName, VectorSchema> {
+    let dense = params.vectors.params_iter().map(|(name, params)| {
+        let schema = VectorSchema::Dense {
+            size: params.size,
+            distance: params.distance,
+            multivector_config: params.multivector_config,
+        };
+        (name, schema)
+    });
+
+    let sparse = params
+        .sparse_vectors
+        .iter()
+        .flatten()
+        .map(|(name, params)| {
+            let schema = VectorSchema::Sparse {
+                modifier: params.modifier.unwrap_or_default(),
+            };
+            (name.as_str(), schema)
+        });
+
+    dense.chain(sparse).collect()
+}
+
+impl Collection {
+    /// Order of the results of the `request`, when they are merged with the results of the same
+    /// request over other collections.
+    ///
+    /// Only queries which rank the points by score can be merged.
+    pub async fn federated_query_order(
+        &self,
+        request: &CollectionQueryRequest,
+    ) -> CollectionResult<Order> {
+        let collection_params = self.collection_config.read().await.params.clone();
+        boosted_order(request, &collection_params)?.ok_or_else(|| {
+            CollectionError::bad_request(
+                "Federated query requires a query, which ranks the points by score. \
+                 MMR, order_by, sample and queries without a query are not supported",
+            )
+        })
+    }
+
+    /// Check that the scores of the `other` collection are comparable with the scores of this
+    /// one, i.e. both have the same vectors with the same size, distance and modifier.
+    pub async fn check_federated_compatibility(&self, other: &Collection) -> CollectionResult<()> {
+        let this_params = self.collection_config.read().await.params.clone();
+        let other_params = other.collection_config.read().await.params.clone();
+
+        let this_schema = vector_schema(&this_params);
+        let other_schema = vector_schema(&other_params);
+        if this_schema == other_schema {
+            return Ok(());
+        }
+
+        let mismatch = this_schema
+            .iter()
+            .find(|(name, schema)| other_schema.get(*name) != Some(*schema))
+            .map(|(name, _)| *name)
+            .or_else(|| {
+                other_schema
+                    .keys()
+                    .find(|name| !this_schema.contains_key(*name))
+                    .copied()
+            })
+            .unwrap_or_default();
+
+        Err(CollectionError::bad_request(format!(
+            "Collection `{}` is not compatible with collection `{}`: \
+             vector `{mismatch}` is configured differently or missing",
+            other.name(),
+            self.name(),
+        )))
+    }
+}
+
+/// Merge the results of the same query over several collections into a single ranked list.
+///
+/// Each of the `results` must be sorted in the `order` and contain at least `offset + limit`
+/// points, if there are enough. Merged points are tagged with the index of their results.
+pub fn merge_federated_results(
+    results: Vec<Vec<ScoredPoint>>,
+    order: Order,
+    offset: usize,
+    limit: usize,
+) -> Vec<(usize, ScoredPoint)> {
+    let tagged = results
+        .into_iter()
+        .enumerate()
+        .map(|(source, points)| points.into_iter().map(move |point| (source, point)));
+
+    match order {
+        Order::LargeBetter => {
+            Either::Left(tagged.kmerge_by(|(_, a), (_, b)| ScoredPointTies(a) > ScoredPointTies(b)))
+        }
+        Order::SmallBetter => Either::Right(
+            tagged.kmerge_by(|(_, a), (_, b)| ScoredPointTies(a) < ScoredPointTies(b)),
+        ),
+    }
+    .skip(offset)
+    .take(limit)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::types::ExtendedPointId;
+
+    use super::*;
+
+    fn point(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: ExtendedPointId::NumId(id),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_merge_federated_results() {
+        let results = vec![
+            vec![point(1, 0.9), point(2, 0.5), point(3, 0.1)],
+            vec![point(1, 0.8), point(4, 0.6)],
+        ];
+
+        let merged = merge_federated_results(results, Order::LargeBetter, 1, 3);
+        let merged = merged
+            .into_iter()
+            .map(|(source, point)| (source, point.score))
+            .collect_vec();
+        assert_eq!(merged, [(1, 0.8), (1, 0.6), (0, 0.5)]);
+
+        let results = vec![vec![point(1, 0.1), point(2, 0.7)], vec![point(3, 0.3)]];
+        let merged = merge_federated_results(results, Order::SmallBetter, 0, 10);
+        let merged = merged
+            .into_iter()
+            .map(|(source, point)| (source, point.score))
+            .collect_vec();
+        assert_eq!(merged, [(0, 0.1), (1, 0.3), (0, 0.7)]);
+    }
+}
@@ -2,9 +2,11 @@ mod clean;
 mod collection_ops;
 pub mod distance_matrix;
 mod facet;
+pub mod federated;
 pub mod mmr;
 pub mod payload_index_schema;
 mod point_ops;
+mod quantiles;
 pub mod query;
 mod resharding;
 mod search;
@@ -23,10 +25,18 @@ use std::time::Duration;
 
 use clean::ShardCleanTasks;
 use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::save_on_disk::SaveOnDisk;
 use common::storage_version::StorageVersion;
-use segment::types::{SeqNumberType, ShardKey};
+use segment::data_types::index::Language;
+use segment::data_types::text_query::{
+    QueryStopwords, SnippetParams, TextSnippet, TextValueMatches,
+};
+use segment::types::{Filter, PayloadKeyType, PointIdType, SeqNumberType, ShardKey, VectorName};
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
 use semver::Version;
+use shard::count::CountRequestInternal;
 use shard::operations::optimization::{OptimizationsRequestOptions, OptimizationsResponse};
 use tokio::runtime::Handle;
 use tokio::sync::{Mutex, RwLock};
@@ -42,9 +52,13 @@ use crate::common::is_ready::IsReady;
 use crate::config::{CollectionConfigInternal, ShardingMethod};
 use crate::operations::OperationWithClockTag;
 use crate::operations::config_diff::{DiffConfig, OptimizersConfigDiff};
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::shared_storage_config::SharedStorageConfig;
-use crate::operations::types::{CollectionError, CollectionResult, NodeType, OptimizersStatus};
+use crate::operations::types::{
+    CollectionError, CollectionResult, NodeType, OptimizersStatus, ShardStatus, WarmupResponse,
+};
 use crate::optimizers_builder::OptimizersConfig;
+use crate::profiling::index_advisor::UnindexedFilterStats;
 use crate::shards::channel_service::ChannelService;
 use crate::shards::collection_shard_distribution::CollectionShardDistribution;
 use crate::shards::local_shard::clock_map::RecoveryPoint;
@@ -91,6 +105,8 @@ pub struct Collection {
     collection_stats_cache: CollectionSizeStatsCache,
     // Background tasks to clean shards
     shard_clean_tasks: ShardCleanTasks,
+    // Fields filtered without a payload index, for index recommendations
+    unindexed_filter_stats: UnindexedFilterStats,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -200,6 +216,7 @@ impl Collection {
             optimizer_resource_budget,
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
+            unindexed_filter_stats: Default::default(),
         })
     }
 
@@ -319,6 +336,7 @@ impl Collection {
             optimizer_resource_budget,
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
+            unindexed_filter_stats: Default::default(),
         }
     }
 
@@ -581,6 +599,256 @@ impl Collection {
         replica_set.get_wal_entries(count).await
     }
 
+    /// Quantized representation of the named vector of the point, read from the local replicas
+    /// of the shards on this peer.
+    ///
+    /// Returns `None` if the vector is not quantized.
+    pub async fn point_quantized_vector(
+        &self,
+        point_id: PointIdType,
+        vector_name: &VectorName,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<QuantizedVectorRecord>> {
+        let shard_holder = self.shards_holder.read().await;
+        for replica_set in shard_holder.all_shards() {
+            if !replica_set.has_local_shard().await {
+                continue;
+            }
+            match replica_set
+                .point_quantized_vector(point_id, vector_name, hw_measurement_acc)
+                .await
+            {
+                // The point is stored in another shard
+                Err(CollectionError::PointNotFound { .. }) => continue,
+                result => return result,
+            }
+        }
+        Err(CollectionError::PointNotFound {
+            missed_point_id: point_id,
+        })
+    }
+
+    /// Tokens stored for the point in the full-text index of the field, read from the local
+    /// replicas of the shards on this peer.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn point_text_tokens(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let shard_holder = self.shards_holder.read().await;
+        for replica_set in shard_holder.all_shards() {
+            if !replica_set.has_local_shard().await {
+                continue;
+            }
+            match replica_set
+                .point_text_tokens(point_id, key, hw_measurement_acc)
+                .await
+            {
+                // The point is stored in another shard
+                Err(CollectionError::PointNotFound { .. }) => continue,
+                result => return result,
+            }
+        }
+        Err(CollectionError::PointNotFound {
+            missed_point_id: point_id,
+        })
+    }
+
+    /// Occurrences of the query tokens of the `text` in the values of the field of the point,
+    /// read from the local replica of the shard.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn get_shard_point_text_match_offsets(
+        &self,
+        shard_id: ShardId,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        text: &str,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextValueMatches>>> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let Some(replica_set) = shard_holder.get_shard(shard_id) else {
+            return Err(CollectionError::not_found(format!("Shard {shard_id}")));
+        };
+
+        replica_set
+            .point_text_match_offsets(point_id, key, text, hw_measurement_acc)
+            .await
+    }
+
+    /// Excerpts of the values of the field of the point around the matches of the `text`, read
+    /// from the local replica of the shard.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn get_shard_point_text_snippets(
+        &self,
+        shard_id: ShardId,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextSnippet>>> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let Some(replica_set) = shard_holder.get_shard(shard_id) else {
+            return Err(CollectionError::not_found(format!("Shard {shard_id}")));
+        };
+
+        replica_set
+            .point_text_snippets(point_id, key, text, params, hw_measurement_acc)
+            .await
+    }
+
+    /// Excerpts of the values of the field of each of the points around the matches of the
+    /// `text`, read from the local replicas of the selected shards.
+    ///
+    /// Points of shards without a local replica, and points without a full-text index of the
+    /// field, are left out.
+    pub async fn points_text_snippets(
+        &self,
+        point_ids: &[PointIdType],
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        shard_selection: &ShardSelectorInternal,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<HashMap<PointIdType, Vec<TextSnippet>>> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let mut points_snippets = HashMap::new();
+        for (replica_set, _shard_key) in shard_holder.select_shards(shard_selection)? {
+            if !replica_set.has_local_shard().await {
+                continue;
+            }
+            let shard_snippets = replica_set
+                .points_text_snippets(point_ids, key, text, params, hw_measurement_acc)
+                .await?;
+            points_snippets.extend(shard_snippets);
+        }
+        Ok(points_snippets)
+    }
+
+    /// Stopwords detected in the full-text index of the field, read from the local replica of
+    /// the shard.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn get_shard_text_auto_stopwords(
+        &self,
+        shard_id: ShardId,
+        key: &PayloadKeyType,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let Some(replica_set) = shard_holder.get_shard(shard_id) else {
+            return Err(CollectionError::not_found(format!("Shard {shard_id}")));
+        };
+
+        replica_set.text_auto_stopwords(key).await
+    }
+
+    /// Query tokens removed as stopwords, when querying the full-text index of the field with
+    /// the `text`, read from the local replica of the shard.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn get_shard_text_query_stopwords(
+        &self,
+        shard_id: ShardId,
+        key: &PayloadKeyType,
+        text: &str,
+        language: Option<Language>,
+    ) -> CollectionResult<Option<QueryStopwords>> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let Some(replica_set) = shard_holder.get_shard(shard_id) else {
+            return Err(CollectionError::not_found(format!("Shard {shard_id}")));
+        };
+
+        replica_set.text_query_stopwords(key, text, language).await
+    }
+
+    /// Query tokens removed as stopwords, when querying the full-text index of the field with
+    /// the `text`, read from the local replicas of the selected shards.
+    /// Shards without a local replica are skipped.
+    ///
+    /// Returns `None` if the field has no full-text index in any of them.
+    pub async fn text_query_stopwords(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        language: Option<Language>,
+        shard_selection: &ShardSelectorInternal,
+    ) -> CollectionResult<Option<QueryStopwords>> {
+        let shard_holder = self.shards_holder.read().await;
+
+        let mut stopwords: Option<QueryStopwords> = None;
+        for (replica_set, _shard_key) in shard_holder.select_shards(shard_selection)? {
+            if !replica_set.has_local_shard().await {
+                continue;
+            }
+            if let Some(shard_stopwords) = replica_set
+                .text_query_stopwords(key, text, language)
+                .await?
+            {
+                stopwords.get_or_insert_default().merge(shard_stopwords);
+            }
+        }
+        Ok(stopwords)
+    }
+
+    /// Populate pages of the payload field indexes of the local shards, which fit into the
+    /// `budget`.
+    ///
+    /// Lookup structures of the indexes, like dictionaries, are populated for all fields before
+    /// the rest of the data. Fields are populated in the order of `fields`, or of their keys if
+    /// not given.
+    pub async fn warmup_payload_indexes(
+        &self,
+        fields: Option<Vec<PayloadKeyType>>,
+        mut budget: PopulateBudget,
+    ) -> CollectionResult<WarmupResponse> {
+        let fields = match fields {
+            Some(fields) => fields,
+            None => {
+                let mut fields: Vec<_> = self
+                    .payload_index_schema
+                    .read()
+                    .schema
+                    .keys()
+                    .cloned()
+                    .collect();
+                fields.sort_unstable();
+                fields
+            }
+        };
+
+        let shard_holder = self.shards_holder.read().await;
+        for stage in PopulateStage::ALL {
+            budget.set_stage(stage);
+            for key in &fields {
+                for replica_set in shard_holder.all_shards() {
+                    budget = replica_set.populate_payload_indexes(key, budget).await?;
+                }
+            }
+        }
+        Ok(WarmupResponse::from(budget.report()))
+    }
+
+    /// Approximate RAM usage in bytes of the payload field indexes kept in memory by the local
+    /// shards.
+    pub async fn payload_indexes_ram_bytes(&self) -> usize {
+        let shard_holder = self.shards_holder.read().await;
+        let mut ram_bytes = 0;
+        for replica_set in shard_holder.all_shards() {
+            ram_bytes += replica_set.payload_indexes_ram_bytes().await;
+        }
+        ram_bytes
+    }
+
     /// Get optimizations info from the local shard only.
     ///
     /// Used by the internal gRPC handler to serve requests from remote peers.
@@ -916,6 +1184,33 @@ impl Collection {
         })
     }
 
+    /// Check if all replicas of this peer are ready to serve requests with loaded indexes.
+    ///
+    /// A replica is ready if it is healthy, and its shard has no failed operations and no running
+    /// or pending optimizations. Optimizations pending after a restart keep the replica unready
+    /// until optimizers are triggered, e.g. by the next update.
+    /// Only the current state is checked, nothing is loaded or optimized by this call.
+    pub async fn is_local_replicas_ready(&self) -> bool {
+        let shards_holder = self.shards_holder.read().await;
+
+        for replica_set in shards_holder.all_shards() {
+            let Some(state) = replica_set.peer_state(replica_set.this_peer_id()) else {
+                continue;
+            };
+
+            if !state.is_healthy() {
+                return false;
+            }
+
+            match replica_set.local_shard_status().await {
+                None | Some(ShardStatus::Green) => {}
+                Some(ShardStatus::Yellow | ShardStatus::Grey | ShardStatus::Red) => return false,
+            }
+        }
+
+        true
+    }
+
     pub async fn effective_optimizers_config(&self) -> CollectionResult<OptimizersConfig> {
         let config = self.collection_config.read().await;
 
@@ -958,6 +1253,41 @@ impl Collection {
             .get_or_update_cache(|| Self::estimate_collection_size_stats(&self.shards_holder))
             .await
     }
+
+    /// Estimated fraction of the points, which match the `filter`, based on the cardinality
+    /// estimation in all shards, local or remote. No filter matches all points.
+    /// Returns `None` if the collection has no points.
+    pub async fn estimate_filter_ratio(
+        &self,
+        filter: Option<&Filter>,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<f64>> {
+        let estimate = |filter: Option<&Filter>| {
+            let request = CountRequestInternal {
+                filter: filter.cloned(),
+                exact: false,
+            };
+            self.count(
+                request,
+                None,
+                &ShardSelectorInternal::All,
+                None,
+                hw_measurement_acc.clone(),
+            )
+        };
+
+        let total = estimate(None).await?.count;
+        if total == 0 {
+            return Ok(None);
+        }
+
+        let matching = match filter {
+            Some(filter) => estimate(Some(filter)).await?.count,
+            None => total,
+        };
+
+        Ok(Some(matching.min(total) as f64 / total as f64))
+    }
 }
 
 struct CollectionVersion;
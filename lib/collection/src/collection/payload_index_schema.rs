@@ -2,16 +2,22 @@ use std::path::{Path, PathBuf};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
+use segment::index::field_index::full_text_index::tokenizers::Tokenizer;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, PayloadFieldSchema};
+use segment::types::{Filter, PayloadFieldSchema, PayloadSchemaParams};
 use shard::files::PAYLOAD_INDEX_CONFIG_FILE;
 pub use shard::payload_index_schema::PayloadIndexSchema;
 
 use crate::collection::Collection;
-use crate::operations::types::{CollectionResult, UpdateResult};
+use crate::operations::types::{
+    CollectionError, CollectionResult, FilterValidationResult, TokenizeResponse, UpdateResult,
+};
 use crate::operations::universal_query::formula::ExpressionInternal;
 use crate::operations::{CollectionUpdateOperations, CreateIndex, FieldIndexOperations};
 use crate::problems::unindexed_field;
+use crate::profiling::index_advisor::{
+    AutoCreatedIndexTelemetry, IndexRecommendation, index_advisor_config,
+};
 use crate::shards::shard_trait::WaitUntil;
 
 impl Collection {
@@ -112,6 +118,87 @@ impl Collection {
     ) -> Option<(JsonPath, Vec<PayloadFieldSchema>)> {
         one_unindexed_expression_key(&self.payload_index_schema.read(), expr)
     }
+
+    /// Checks `filter` conditions against the payload index schema, without executing it.
+    pub fn validate_filter(&self, filter: &Filter) -> FilterValidationResult {
+        let diagnostics =
+            unindexed_field::validate_filter(filter, &self.payload_index_schema.read().schema);
+        FilterValidationResult { diagnostics }
+    }
+
+    /// Tokens of the `text`, as produced by the configured full-text index of the field.
+    ///
+    /// Stopwords of the language detected from the indexed texts are not removed, as the
+    /// language is detected per segment.
+    pub fn tokenize_text(&self, key: &JsonPath, text: &str) -> CollectionResult<TokenizeResponse> {
+        let Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(params))) =
+            self.payload_key_index_schema(key)
+        else {
+            return Err(CollectionError::bad_input(format!(
+                "Field `{key}` has no full-text index",
+            )));
+        };
+
+        let tokens = Tokenizer::new_from_text_index_params(&params).tokenize_debug(text);
+        Ok(TokenizeResponse { tokens })
+    }
+
+    /// Counts the fields of client `filters`, which have no suitable payload index.
+    ///
+    /// Does nothing if the index advisor is disabled.
+    pub(crate) async fn record_unindexed_filters<'a>(
+        &self,
+        filters: impl IntoIterator<Item = Option<&'a Filter>>,
+    ) {
+        if index_advisor_config().is_none() {
+            return;
+        }
+
+        let points_count = self.estimated_points_count().await;
+        let payload_index_schema = self.payload_index_schema.read();
+        for filter in filters.into_iter().flatten() {
+            self.unindexed_filter_stats
+                .record(filter, &payload_index_schema.schema, points_count);
+        }
+    }
+
+    /// Payload indexes recommended for the fields, which are frequently filtered without a
+    /// suitable index.
+    ///
+    /// Empty if the index advisor is disabled.
+    pub async fn index_recommendations(&self) -> Vec<IndexRecommendation> {
+        let Some(config) = index_advisor_config() else {
+            return Vec::new();
+        };
+
+        let points_count = self.estimated_points_count().await;
+        let payload_index_schema = self.payload_index_schema.read();
+        self.unindexed_filter_stats.recommendations(
+            &payload_index_schema.schema,
+            points_count,
+            config,
+        )
+    }
+
+    /// Remember that the index of the `recommendation` was created automatically, to be reported
+    /// in telemetry
+    pub fn record_auto_created_index(&self, recommendation: &IndexRecommendation, on_disk: bool) {
+        self.unindexed_filter_stats
+            .record_auto_created(recommendation, on_disk);
+    }
+
+    /// Indexes created automatically from the recommendations, in the order of creation
+    pub fn auto_created_indexes(&self) -> Vec<AutoCreatedIndexTelemetry> {
+        self.unindexed_filter_stats.auto_created()
+    }
+
+    /// Cached number of points in the local shards, might be outdated
+    async fn estimated_points_count(&self) -> usize {
+        match self.estimated_collection_stats().await {
+            Ok(Some(stats)) => stats.get_points_count(),
+            Ok(None) | Err(_) => 0,
+        }
+    }
 }
 
 enum PotentiallyUnindexed<'a> {
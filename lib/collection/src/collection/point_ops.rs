@@ -8,7 +8,7 @@ use futures::stream::FuturesUnordered;
 use futures::{StreamExt as _, TryFutureExt, TryStreamExt as _, future};
 use itertools::Itertools;
 use segment::data_types::order_by::{Direction, OrderBy};
-use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
+use segment::types::{PointIdType, ShardKey, WithPayload, WithPayloadInterface};
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
@@ -150,6 +150,23 @@ impl Collection {
         shard_keys_selection: Option<ShardKey>,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<UpdateResult> {
+        // Point versions are op_nums of the WAL of each replica, which differ between replicas.
+        // The same expected version could match on some replicas and conflict on others.
+        if operation.expected_version().is_some() {
+            let replication_factor = self
+                .collection_config
+                .read()
+                .await
+                .params
+                .replication_factor;
+            if replication_factor.get() > 1 {
+                return Err(CollectionError::bad_request(format!(
+                    "expected_version is not supported in collections with replication_factor \
+                     {replication_factor}, versions of points differ between replicas",
+                )));
+            }
+        }
+
         let shard_holder = self.shards_holder.clone().read_owned().await;
         let start_time = std::time::Instant::now();
 
@@ -515,4 +532,91 @@ impl Collection {
 
         Ok(points)
     }
+
+    /// Whether each of the points with `ids` exists, in the same order.
+    ///
+    /// Only point versions are looked up in the id trackers, payloads and vectors are not read.
+    pub async fn check_points_exist(
+        &self,
+        ids: Vec<PointIdType>,
+        with_version: bool,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<PointExistence>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids = Arc::new(ids);
+
+        let shard_holder = self.shards_holder.read().await;
+        let target_shards = shard_holder.select_shards(shard_selection)?;
+        let mut all_shard_versions_requests = target_shards
+            .into_iter()
+            .map(|(shard, _shard_key)| {
+                shard.point_versions(
+                    ids.clone(),
+                    read_consistency,
+                    timeout,
+                    shard_selection.is_shard_id(),
+                    hw_measurement_acc.clone(),
+                )
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        // pre-allocate hashmap with capped capacity to protect from malevolent input
+        let mut point_versions = HashMap::with_capacity(ids.len().min(1024));
+        while let Some(response) = all_shard_versions_requests.try_next().await? {
+            for PointVersion { id, version } in response {
+                let latest_version = point_versions.entry(id).or_insert(version);
+                *latest_version = version.max(*latest_version);
+            }
+        }
+
+        let existence = ids
+            .iter()
+            .map(|&id| {
+                let version = point_versions.get(&id).copied();
+                PointExistence {
+                    id,
+                    exists: version.is_some(),
+                    version: version.filter(|_| with_version),
+                }
+            })
+            .collect();
+
+        Ok(existence)
+    }
+
+    /// Latest versions of the points with `ids` in a single local shard
+    pub async fn point_versions_internal(
+        &self,
+        ids: Vec<PointIdType>,
+        shard_id: ShardId,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        let ids = Arc::new(ids);
+        let shard_selection = ShardSelectorInternal::ShardId(shard_id);
+
+        let shard_holder = self.shards_holder.read().await;
+        let target_shards = shard_holder.select_shards(&shard_selection)?;
+
+        let mut versions = Vec::new();
+        for (shard, _shard_key) in target_shards {
+            let shard_versions = shard
+                .point_versions(
+                    ids.clone(),
+                    None,
+                    timeout,
+                    shard_selection.is_shard_id(),
+                    hw_measurement_acc.clone(),
+                )
+                .await?;
+            versions.extend(shard_versions);
+        }
+
+        Ok(versions)
+    }
 }
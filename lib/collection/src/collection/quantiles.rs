@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use futures::stream::FuturesUnordered;
+use segment::data_types::quantiles::{QuantilesParams, QuantilesResponse, ValueSamples};
+use segment::types::{PayloadFieldSchema, PayloadKeyType, PayloadSchemaParams, PayloadSchemaType};
+
+use super::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl Collection {
+    /// Split the values of a numeric field into about evenly populated buckets.
+    ///
+    /// Boundaries are derived from the samples of the sorted values in the range indexes of all
+    /// shards, without scanning the values. Counts are approximate.
+    pub async fn quantiles(
+        &self,
+        request: QuantilesParams,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<QuantilesResponse> {
+        let has_range_index = self
+            .payload_key_index_schema(&request.key)
+            .is_some_and(|schema| is_numeric_range_index(&schema));
+        if !has_range_index {
+            return Err(CollectionError::bad_request(format!(
+                "No integer or float range index for quantiles key: `{}`. \
+                 Please create one to split the values into quantiles",
+                request.key,
+            )));
+        }
+
+        let samples = self
+            .value_samples(&request.key, shard_selection, read_consistency, timeout)
+            .await?;
+
+        Ok(QuantilesResponse::from_samples(samples, request.buckets))
+    }
+
+    /// Values of the numeric field sampled from the range indexes of the selected shards
+    pub async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        let shard_holder = self.shards_holder.read().await;
+        let target_shards = shard_holder.select_shards(&shard_selection)?;
+
+        let mut shards_reads_f = target_shards
+            .iter()
+            .map(|(shard, _shard_key)| {
+                shard.value_samples(
+                    key,
+                    read_consistency,
+                    shard_selection.is_shard_id(),
+                    timeout,
+                )
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut samples = ValueSamples::default();
+        while let Some(shard_samples) = shards_reads_f.try_next().await? {
+            samples.samples.extend(shard_samples.samples);
+        }
+
+        Ok(samples)
+    }
+}
+
+fn is_numeric_range_index(schema: &PayloadFieldSchema) -> bool {
+    match schema {
+        PayloadFieldSchema::FieldType(schema_type) => matches!(
+            schema_type,
+            PayloadSchemaType::Integer | PayloadSchemaType::Float
+        ),
+        PayloadFieldSchema::FieldParams(PayloadSchemaParams::Integer(params)) => {
+            params.range != Some(false)
+        }
+        PayloadFieldSchema::FieldParams(PayloadSchemaParams::Float(_)) => true,
+        PayloadFieldSchema::FieldParams(_) => false,
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
@@ -5,12 +6,15 @@ use std::time::Duration;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::{TryFutureExt, future};
 use itertools::{Either, Itertools};
+use ordered_float::OrderedFloat;
 use rand::RngExt;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
 use segment::data_types::vectors::VectorStructInternal;
-use segment::types::{Order, ScoredPoint, WithPayloadInterface, WithVector};
+use segment::types::{Order, PointIdType, ScoredPoint, WithPayloadInterface, WithVector};
 use segment::utils::scored_point_ties::ScoredPointTies;
+use shard::query::score_stages::{record_rescore_stage, take_score_stages};
+use shard::scroll::ScrollRequestInternal;
 use tokio::time::Instant;
 
 use super::Collection;
@@ -22,10 +26,14 @@ use crate::common::fetch_vectors::{
 };
 use crate::common::retrieve_request_trait::RetrieveRequest;
 use crate::common::transpose_iterator::transposed_iter;
+use crate::config::CollectionParams;
+use crate::operations::boost_rules::{BoostRules, boost_scores};
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
-use crate::operations::universal_query::collection_query::CollectionQueryRequest;
+use crate::operations::universal_query::collection_query::{
+    CollectionQueryRequest, Query, VectorQuery,
+};
 use crate::operations::universal_query::shard_query::{
     self, FusionInternal, MmrInternal, ScoringQuery, ShardQueryRequest, ShardQueryResponse,
 };
@@ -34,6 +42,73 @@ use crate::operations::universal_query::shard_query::{
 /// Should be adjusted based on usage statistics.
 pub(super) const PAYLOAD_TRANSFERS_FACTOR_THRESHOLD: usize = 10;
 
+/// Query, whose results are reordered by the boost rules of the collection
+struct BoostedQuery {
+    offset: usize,
+    limit: usize,
+    order: Order,
+    shard_selection: ShardSelectorInternal,
+    /// Record the applied rules in the score stages of the points
+    with_explanation: bool,
+}
+
+impl BoostedQuery {
+    /// Prepare the `request` to be boosted, if its results are ordered by score.
+    ///
+    /// Boosting can move points across the offset, so the request is changed to return the points
+    /// before the offset as well. The requested page is taken after boosting.
+    fn prepare(
+        request: &mut ShardQueryRequest,
+        shard_selection: ShardSelectorInternal,
+        collection_params: &CollectionParams,
+    ) -> CollectionResult<Option<Self>> {
+        // Orders by payload values or ids, samples, and diverse results are not boosted
+        let is_scored = matches!(
+            request.query,
+            Some(ScoringQuery::Vector(_) | ScoringQuery::Fusion(_) | ScoringQuery::Formula(_)),
+        );
+        if !is_scored {
+            return Ok(None);
+        }
+        let order = shard_query::query_result_order(request.query.as_ref(), collection_params)?;
+        let Some(order) = order else {
+            return Ok(None);
+        };
+
+        let boosted = Self {
+            offset: request.offset,
+            limit: request.limit,
+            order,
+            shard_selection,
+            with_explanation: request.with_explanation,
+        };
+        request.limit = request.limit.saturating_add(request.offset);
+        request.offset = 0;
+        Ok(Some(boosted))
+    }
+}
+
+/// Order of the results of the `request`, if they are boosted by the boost rules.
+///
+/// Mirrors [`BoostedQuery::prepare`], for the request before it is converted into a shard request.
+pub(super) fn boosted_order(
+    request: &CollectionQueryRequest,
+    collection_params: &CollectionParams,
+) -> CollectionResult<Option<Order>> {
+    let order = match &request.query {
+        Some(Query::Vector(VectorQuery::Nearest(_) | VectorQuery::RecommendAverageVector(_))) => {
+            collection_params
+                .get_distance(&request.using)?
+                .distance_order()
+        }
+        // MMR cannot be reordered
+        Some(Query::Vector(VectorQuery::NearestWithMmr(_))) => return Ok(None),
+        Some(Query::Vector(_) | Query::Fusion(_) | Query::Formula(_)) => Order::LargeBetter,
+        Some(Query::OrderBy(_) | Query::Sample(_)) | None => return Ok(None),
+    };
+    Ok(Some(order))
+}
+
 struct IntermediateQueryInfo<'a> {
     scoring_query: Option<&'a ScoringQuery>,
     /// Limit + offset
@@ -171,8 +246,10 @@ impl Collection {
             is_auto_sharding,
         );
 
+        let this_peer_id = self.this_peer_id;
         let all_searches = target_shards.iter().map(|(shard, shard_key)| {
             let shard_key = shard_key.cloned();
+            let shard_id = shard.shard_id;
             let request_clone = Arc::clone(&batch_request);
             shard
                 .query_batch(
@@ -183,14 +260,16 @@ impl Collection {
                     hw_measurement_acc.clone(),
                 )
                 .and_then(move |mut shard_responses| async move {
-                    if shard_key.is_none() {
-                        return Ok(shard_responses);
+                    for point in shard_responses.iter_mut().flatten().flatten() {
+                        if shard_key.is_some() {
+                            point.shard_key.clone_from(&shard_key);
+                        }
+                        // Points scored by a remote replica are already marked by its peer
+                        if let Some(provenance) = &mut point.provenance {
+                            provenance.shard_id.get_or_insert(shard_id);
+                            provenance.peer_id.get_or_insert(this_peer_id);
+                        }
                     }
-                    shard_responses
-                        .iter_mut()
-                        .flatten()
-                        .flatten()
-                        .for_each(|point| point.shard_key.clone_from(&shard_key));
 
                     Ok(shard_responses)
                 })
@@ -331,7 +410,11 @@ impl Collection {
                     .await?;
 
                 let filter_refs = request.filter_refs();
-                self.post_process_if_slow_request(instant.elapsed(), filter_refs);
+                self.post_process_if_slow_request(instant.elapsed(), filter_refs.clone());
+
+                if !shard_selection.is_shard_id() {
+                    self.record_unindexed_filters(filter_refs).await;
+                }
 
                 Ok::<_, CollectionError>(result)
             });
@@ -363,8 +446,17 @@ impl Collection {
             params: _,
             with_vector,
             with_payload: _,
+            with_provenance: _,
+            with_explanation,
+            soft_timeout: _,
         } = request;
 
+        // Results of all shards are fused or diversified here, continue the stages recorded by
+        // the shards, see `LocalShard::recurse_prefetch`
+        let is_merged = matches!(query, Some(ScoringQuery::Fusion(_) | ScoringQuery::Mmr(_)));
+        let source_stages =
+            (*with_explanation && is_merged).then(|| take_score_stages(&mut intermediates));
+
         let result = match query.as_ref() {
             Some(ScoringQuery::Fusion(fusion)) => {
                 // If the root query is a Fusion, the returned results correspond to each the prefetches.
@@ -434,7 +526,12 @@ impl Collection {
             }
         };
 
-        let result: Vec<ScoredPoint> = result.into_iter().skip(*offset).take(*limit).collect();
+        let mut result: Vec<ScoredPoint> = result.into_iter().skip(*offset).take(*limit).collect();
+
+        if let Some(source_stages) = source_stages {
+            let stage_kind = query.as_ref().and_then(ScoringQuery::score_stage_kind);
+            record_rescore_stage(&mut result, source_stages, stage_kind);
+        }
 
         Ok(result)
     }
@@ -485,6 +582,14 @@ impl Collection {
             }
         }
 
+        let (boost_rules, collection_params) = {
+            let config = self.collection_config.read().await;
+            (config.boost_rules.clone(), config.params.clone())
+        };
+
+        // Boosted queries, in the order of the batch
+        let mut boosted_queries = Vec::with_capacity(requests_batch.len());
+
         let futures = batch_requests::<
             (CollectionQueryRequest, ShardSelectorInternal),
             ShardSelectorInternal,
@@ -493,11 +598,35 @@ impl Collection {
         >(
             requests_batch,
             |(_req, shard)| shard,
-            |(req, _), acc| {
-                req.try_into_shard_request(&self.id, &ids_to_vectors)
-                    .map(|shard_req| {
-                        acc.push(shard_req);
-                    })
+            |(mut req, shard), acc| {
+                // Resolved before the scorers are built, like a threshold set by the query.
+                // Only nearest neighbours are scored by their similarity to the query. Scores of
+                // MMR, recommendations by best or summed scores, discovery, context, feedback,
+                // fusion, formulas, ordering or sampling are no similarities.
+                let is_similarity_query = matches!(
+                    req.query,
+                    Some(Query::Vector(
+                        VectorQuery::Nearest(_) | VectorQuery::RecommendAverageVector(_)
+                    ))
+                );
+                if req.score_threshold.is_none()
+                    && req.apply_default_score_threshold
+                    && is_similarity_query
+                {
+                    req.score_threshold = collection_params
+                        .default_score_threshold
+                        .map(OrderedFloat::into_inner);
+                }
+                let apply_boost_rules = req.apply_boost_rules && !boost_rules.is_empty();
+                let mut shard_req = req.try_into_shard_request(&self.id, &ids_to_vectors)?;
+                let boosted_query = if apply_boost_rules {
+                    BoostedQuery::prepare(&mut shard_req, shard, &collection_params)?
+                } else {
+                    None
+                };
+                boosted_queries.push(boosted_query);
+                acc.push(shard_req);
+                Ok(())
             },
             |shard_selection, shard_requests, futures| {
                 if shard_requests.is_empty() {
@@ -516,13 +645,123 @@ impl Collection {
             },
         )?;
 
-        let results = future::try_join_all(futures)
+        let results: Vec<_> = future::try_join_all(futures)
             .await?
             .into_iter()
             .flatten()
             .collect();
 
-        Ok(results)
+        if boosted_queries.iter().all(Option::is_none) {
+            return Ok(results);
+        }
+
+        // update timeout
+        let timeout = timeout.map(|timeout| timeout.saturating_sub(start.elapsed()));
+
+        let boosted_results = results
+            .into_iter()
+            .zip(boosted_queries)
+            .map(|(points, boosted)| {
+                let boost_rules = &boost_rules;
+                let hw_measurement_acc = hw_measurement_acc.clone();
+                async move {
+                    let Some(boosted) = boosted else {
+                        return Ok(points);
+                    };
+                    self.boost_query_results(
+                        points,
+                        boosted,
+                        boost_rules,
+                        read_consistency,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await
+                }
+            });
+        future::try_join_all(boosted_results).await
+    }
+
+    /// Multiply the scores of the `points` by the boost rules they match, and take the page
+    /// requested by the boosted query.
+    async fn boost_query_results(
+        &self,
+        points: Vec<ScoredPoint>,
+        boosted: BoostedQuery,
+        boost_rules: &BoostRules,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let BoostedQuery {
+            offset,
+            limit,
+            order,
+            shard_selection,
+            with_explanation,
+        } = boosted;
+
+        if points.is_empty() {
+            return Ok(points);
+        }
+
+        let ids: Vec<_> = points.iter().map(|point| point.id).collect();
+        let matched_rules = self
+            .matched_boost_rules(
+                &ids,
+                boost_rules,
+                read_consistency,
+                &shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        let boosted = boost_scores(points, &matched_rules, order, with_explanation);
+        Ok(boosted.into_iter().skip(offset).take(limit).collect())
+    }
+
+    /// Names and multipliers of the boost rules matched by each of the points with `ids`, in the
+    /// order of the rule names. Points matching no rules are omitted.
+    async fn matched_boost_rules(
+        &self,
+        ids: &[PointIdType],
+        boost_rules: &BoostRules,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<HashMap<PointIdType, Vec<(String, f64)>>> {
+        // Find which of the points match each rule
+        let matched_futures = boost_rules.iter().map(|(name, rule)| {
+            let request = ScrollRequestInternal {
+                offset: None,
+                limit: Some(ids.len()),
+                filter: Some(rule.filter.clone().with_point_ids(ids.iter().copied())),
+                with_payload: Some(WithPayloadInterface::Bool(false)),
+                with_vector: WithVector::Bool(false),
+                order_by: None,
+            };
+            self.scroll_by(
+                request,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .map_ok(move |matched| (name, rule.multiplier.into_inner(), matched.points))
+        });
+
+        let mut matched_rules: HashMap<_, Vec<_>> = HashMap::new();
+        for (name, multiplier, matched) in future::try_join_all(matched_futures).await? {
+            for record in matched {
+                matched_rules
+                    .entry(record.id)
+                    .or_default()
+                    .push((name.clone(), multiplier));
+            }
+        }
+        Ok(matched_rules)
     }
 
     /// To be called on the remote instance. Only used for the internal service.
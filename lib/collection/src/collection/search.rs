@@ -205,7 +205,11 @@ impl Collection {
 
         let filters_refs = request.searches.iter().map(|req| req.filter.as_ref());
 
-        self.post_process_if_slow_request(instant.elapsed(), filters_refs);
+        self.post_process_if_slow_request(instant.elapsed(), filters_refs.clone());
+
+        if !shard_selection.is_shard_id() {
+            self.record_unindexed_filters(filters_refs).await;
+        }
 
         result
     }
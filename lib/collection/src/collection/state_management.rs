@@ -154,6 +154,7 @@ impl Collection {
                 strict_mode_config,
                 uuid: _,
                 metadata,
+                boost_rules,
             } = &new_config;
 
             let is_core_config_updated = params != &config.params
@@ -161,7 +162,8 @@ impl Collection {
                 || optimizer_config != &config.optimizer_config
                 || quantization_config != &config.quantization_config;
 
-            let is_metadata_updated = metadata != &config.metadata;
+            let is_metadata_updated =
+                metadata != &config.metadata || boost_rules != &config.boost_rules;
 
             let is_wal_config_updated = wal_config != &config.wal_config;
             let is_strict_mode_config_updated = strict_mode_config != &config.strict_mode_config;
@@ -34,6 +34,7 @@ impl Collection {
         };
 
         let shard_clean_tasks = self.clean_local_shards_statuses();
+        let auto_created_indexes = self.auto_created_indexes();
 
         Ok(CollectionTelemetry {
             id: self.name().to_string(),
@@ -45,6 +46,9 @@ impl Collection {
             transfers,
             resharding,
             shard_clean_tasks: (!shard_clean_tasks.is_empty()).then_some(shard_clean_tasks),
+            auto_created_indexes: (!auto_created_indexes.is_empty())
+                .then_some(auto_created_indexes),
+            hardware_usage: None,
         })
     }
 }
@@ -418,6 +418,7 @@ mod tests {
                     r#type: ScalarType::Int8,
                     quantile: Some(0.99),
                     always_ram: Some(true),
+                    outliers: None,
                 },
             });
 
@@ -444,6 +445,7 @@ mod tests {
                     r#type: ScalarType::Int8,
                     quantile: Some(0.91),
                     always_ram: None,
+                    outliers: None,
                 },
             });
 
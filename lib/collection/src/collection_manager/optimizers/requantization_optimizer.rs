@@ -0,0 +1,201 @@
+/// Optimizer which looks for segments with drifted quantization
+///
+/// Quantization parameters are fit to the data at the time the segment is built. As the data
+/// distribution shifts, the quantized ranking diverges from the exact one. This optimizer rebuilds
+/// the segments with the highest distortion, which fits the quantization to the current data.
+pub use shard::optimizers::requantization_optimizer::RequantizationOptimizer;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use itertools::Itertools;
+    use rand::Rng;
+    use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector};
+    use segment::types::{
+        BinaryQuantization, BinaryQuantizationConfig, Distance, HnswConfig, HnswGlobalConfig,
+        QuantizationConfig, QuantizationSearchParams, ScalarQuantization, ScalarQuantizationConfig,
+        ScalarType, SearchParams, WithPayload, WithVector,
+    };
+    use shard::operations::optimization::OptimizerThresholds;
+    use shard::optimizers::segment_optimizer::SegmentOptimizer;
+    use shard::segment_holder::locked::LockedSegmentHolder;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::collection_manager::fixtures::random_segment;
+    use crate::collection_manager::holders::segment_holder::SegmentHolder;
+    use crate::collection_manager::optimizers::indexing_optimizer::IndexingOptimizer;
+    use crate::config::CollectionParams;
+    use crate::operations::types::VectorsConfig;
+    use crate::operations::vector_params_builder::VectorParamsBuilder;
+    use crate::optimizers_builder::build_segment_optimizer_config;
+
+    const DIM: usize = 4;
+
+    /// Run rescored searches with oversampling in all segments, observing distortion
+    fn search(holder: &LockedSegmentHolder, count: usize) {
+        let params = SearchParams {
+            quantization: Some(QuantizationSearchParams {
+                rescore: Some(true),
+                oversampling: Some(2.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut rng = rand::rng();
+        for _ in 0..count {
+            let query: Vec<f32> = (0..DIM).map(|_| rng.random_range(0.0..1.0)).collect();
+            let query = QueryVector::from(query);
+            for (_, segment) in holder.read().iter_original() {
+                segment
+                    .read()
+                    .search(
+                        DEFAULT_VECTOR_NAME,
+                        &query,
+                        &WithPayload::default(),
+                        &WithVector::Bool(false),
+                        None,
+                        10,
+                        Some(&params),
+                    )
+                    .unwrap();
+            }
+        }
+    }
+
+    /// Build an indexed segment with the quantization, and an optimizer rebuilding it on distortion
+    fn indexed_segment(
+        dir: &Path,
+        temp_dir: &Path,
+        quantization_config: QuantizationConfig,
+    ) -> (LockedSegmentHolder, RequantizationOptimizer) {
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+            deferred_internal_id: None,
+        };
+
+        let mut holder = SegmentHolder::default();
+        let segment_id = holder.add_new(random_segment(dir, 100, 1000, DIM));
+        let locked_holder = LockedSegmentHolder::new(holder);
+
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(
+                VectorParamsBuilder::new(DIM as u64, Distance::Dot).build(),
+            ),
+            ..CollectionParams::empty()
+        };
+        let hnsw_config = HnswConfig {
+            m: 16,
+            ef_construct: 100,
+            full_scan_threshold: 10,
+            max_indexing_threads: 0,
+            on_disk: None,
+            payload_m: None,
+            inline_storage: None,
+        };
+        let segment_config = build_segment_optimizer_config(
+            &collection_params,
+            &hnsw_config,
+            &Some(quantization_config),
+        );
+
+        let indexing_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config,
+            dir.to_owned(),
+            temp_dir.to_owned(),
+            segment_config.clone(),
+            HnswGlobalConfig::default(),
+        );
+        let requantization_optimizer = RequantizationOptimizer::new(
+            0.1,
+            Duration::from_secs(600),
+            thresholds_config,
+            dir.to_owned(),
+            temp_dir.to_owned(),
+            segment_config,
+            HnswGlobalConfig::default(),
+        );
+
+        let changed = indexing_optimizer.optimize_for_test(locked_holder.clone(), vec![segment_id]);
+        assert!(changed > 0, "optimizer should have indexed this segment");
+
+        (locked_holder, requantization_optimizer)
+    }
+
+    #[test]
+    fn test_requantization_on_distortion() {
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        // Vectors have only positive components, and the top ones are all clamped to the same
+        // values by such a narrow quantile, so they can't be ranked by quantized scores
+        let quantization_config = QuantizationConfig::Scalar(ScalarQuantization {
+            scalar: ScalarQuantizationConfig {
+                r#type: ScalarType::Int8,
+                quantile: Some(0.5),
+                always_ram: Some(true),
+                outliers: None,
+            },
+        });
+        let (locked_holder, requantization_optimizer) =
+            indexed_segment(dir.path(), temp_dir.path(), quantization_config);
+
+        // No distortion is observed without searches
+        let suggested_to_optimize =
+            requantization_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert!(suggested_to_optimize.is_empty());
+
+        // Too few searches to trust the distortion
+        search(&locked_holder, 10);
+        let suggested_to_optimize =
+            requantization_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert!(suggested_to_optimize.is_empty());
+
+        search(&locked_holder, 200);
+        let suggested_to_optimize = requantization_optimizer
+            .plan_optimizations_for_test(&locked_holder)
+            .into_iter()
+            .exactly_one()
+            .unwrap();
+        requantization_optimizer.optimize_for_test(locked_holder.clone(), suggested_to_optimize);
+
+        // Rebuilt segment observes distortion anew
+        let suggested_to_optimize =
+            requantization_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert!(suggested_to_optimize.is_empty());
+
+        // The data didn't drift, so the rebuilt segment is as distorted as before, and rebuilding
+        // it again wouldn't help
+        search(&locked_holder, 200);
+        let suggested_to_optimize =
+            requantization_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert!(suggested_to_optimize.is_empty());
+    }
+
+    #[test]
+    fn test_no_requantization_of_binary_quantization() {
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        // Vectors have only positive components, so binary quantization can't rank them at all,
+        // but it doesn't depend on the data, so rebuilding wouldn't change anything
+        let quantization_config = QuantizationConfig::Binary(BinaryQuantization {
+            binary: BinaryQuantizationConfig {
+                always_ram: Some(true),
+                encoding: None,
+                query_encoding: None,
+            },
+        });
+        let (locked_holder, requantization_optimizer) =
+            indexed_segment(dir.path(), temp_dir.path(), quantization_config);
+
+        search(&locked_holder, 200);
+        let suggested_to_optimize =
+            requantization_optimizer.plan_optimizations_for_test(&locked_holder);
+        assert!(suggested_to_optimize.is_empty());
+    }
+}
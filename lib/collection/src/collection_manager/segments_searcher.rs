@@ -1,12 +1,13 @@
 use std::collections::BTreeSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 use ahash::AHashMap;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::{DeferredBehavior, ScoreType};
 use futures::stream::FuturesUnordered;
-use futures::{FutureExt, TryStreamExt};
+use futures::{FutureExt, StreamExt, TryStreamExt};
 use itertools::Itertools;
 use ordered_float::Float;
 use segment::common::operation_error::OperationError;
@@ -14,15 +15,15 @@ use segment::data_types::modifier::Modifier;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use segment::data_types::vectors::QueryVector;
 use segment::types::{
-    Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, VectorName,
-    WithPayload, WithPayloadInterface, WithVector,
+    Filter, Indexes, PointIdType, ScoredPoint, SearchParams, SegmentConfig, SeqNumberType,
+    VectorName, WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::common::stopping_guard::StoppingGuard;
 use shard::optimizers::config::DEFAULT_INDEXING_THRESHOLD_KB;
 use shard::query::query_context::{fill_query_context, init_query_context};
 use shard::query::query_enum::QueryEnum;
 use shard::retrieve::record_internal::RecordInternal;
-use shard::retrieve::retrieve_blocking::retrieve_blocking;
+use shard::retrieve::retrieve_blocking::{point_versions_blocking, retrieve_blocking};
 use shard::search::CoreSearchRequestBatch;
 use shard::search_result_aggregator::BatchResultAggregator;
 use shard::segment_holder::locked::LockedSegmentHolder;
@@ -45,6 +46,23 @@ type BatchSearchResult = Vec<SegmentBatchSearchResult>;
 // Result of batch search in one segment
 type SegmentSearchExecutedResult = CollectionResult<(SegmentBatchSearchResult, Vec<bool>)>;
 
+/// Result of [`SegmentsSearcher::search_with_soft_timeout`]
+#[derive(Debug)]
+pub struct PartialSearchResult {
+    /// Best results found in the processed segments, per batched request
+    pub results: Vec<Vec<ScoredPoint>>,
+    /// Number of segments which were fully searched before the deadline
+    pub processed_segments: usize,
+    pub total_segments: usize,
+}
+
+impl PartialSearchResult {
+    /// Whether some segments were not searched, so results may be missing better points
+    pub fn is_partial(&self) -> bool {
+        self.processed_segments < self.total_segments
+    }
+}
+
 /// Simple implementation of segment manager
 ///  - rebuild segment for memory optimization purposes
 #[derive(Default)]
@@ -245,40 +263,31 @@ impl SegmentsSearcher {
                 && segments.len() > 1
                 && query_context_arc.available_point_count() > 0;
 
+            let num_segments = segments.len();
+            let query_stats = query_context_arc.hardware_usage_accumulator().query_stats();
+            for request in &batch_request.searches {
+                let limit = request.limit + request.offset;
+                if let Some(segment_limit) =
+                    segment_limit(limit, request.params.as_ref(), num_segments)
+                {
+                    query_stats.record_segment_limit(segment_limit);
+                }
+            }
+
             segments
                 .into_iter()
                 .map(|segment| {
-                    let query_context_arc_segment = query_context_arc.clone();
                     // update timeout
                     let timeout = timeout.saturating_sub(start.elapsed());
-                    let search = runtime_handle.spawn_blocking({
-                        let (segment, batch_request) = (segment.clone(), batch_request.clone());
-                        let cpu_utilization = query_context_arc_segment
-                            .hardware_usage_accumulator()
-                            .cpu_utilization();
-                        move || {
-                            cpu_utilization.measure(|| {
-                                let segment_query_context =
-                                    query_context_arc_segment.get_segment_query_context();
-
-                                search_in_segment(
-                                    segment,
-                                    batch_request,
-                                    use_sampling,
-                                    &segment_query_context,
-                                    timeout,
-                                )
-                            })
-                        }
-                    });
-
-                    // We MUST wrap the search handle in AbortOnDropHandle to ensure that we skip
-                    // all searches for futures that are already dropped. Not using this allows
-                    // users to create a humongous queue of search tasks, even though the searches
-                    // are already invalidated.
-                    // See: <https://github.com/qdrant/qdrant/pull/7530>
-                    let search = AbortOnDropHandle::new(search);
-
+                    let search = Self::spawn_search_in_segment(
+                        segment.clone(),
+                        batch_request.clone(),
+                        use_sampling,
+                        num_segments,
+                        query_context_arc.clone(),
+                        runtime_handle,
+                        timeout,
+                    );
                     (segment, search)
                 })
                 .unzip()
@@ -312,6 +321,7 @@ impl SegmentsSearcher {
                 for (segment_id, batch_ids) in searches_to_rerun.iter() {
                     let query_context_arc_segment = query_context_arc.clone();
                     let segment = locked_segments[*segment_id].clone();
+                    let num_segments = locked_segments.len();
                     let partial_batch_request = Arc::new(CoreSearchRequestBatch {
                         searches: batch_ids
                             .iter()
@@ -332,6 +342,7 @@ impl SegmentsSearcher {
                                 segment,
                                 partial_batch_request,
                                 false,
+                                num_segments,
                                 &segment_query_context,
                                 timeout,
                             )
@@ -375,6 +386,128 @@ impl SegmentsSearcher {
         Ok(top_scores)
     }
 
+    fn spawn_search_in_segment(
+        segment: LockedSegment,
+        batch_request: Arc<CoreSearchRequestBatch>,
+        use_sampling: bool,
+        num_segments: usize,
+        query_context: Arc<QueryContext>,
+        runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+    ) -> AbortOnDropHandle<SegmentSearchExecutedResult> {
+        let cpu_utilization = query_context.hardware_usage_accumulator().cpu_utilization();
+        let search = runtime_handle.spawn_blocking(move || {
+            cpu_utilization.measure(|| {
+                let segment_query_context = query_context.get_segment_query_context();
+
+                search_in_segment(
+                    segment,
+                    batch_request,
+                    use_sampling,
+                    num_segments,
+                    &segment_query_context,
+                    timeout,
+                )
+            })
+        });
+
+        // We MUST wrap the search handle in AbortOnDropHandle to ensure that we skip
+        // all searches for futures that are already dropped. Not using this allows
+        // users to create a humongous queue of search tasks, even though the searches
+        // are already invalidated.
+        // See: <https://github.com/qdrant/qdrant/pull/7530>
+        AbortOnDropHandle::new(search)
+    }
+
+    /// Same as [`Self::search`], but stops waiting for segments once `soft_timeout` is reached.
+    ///
+    /// Instead of failing, results of the segments which finished in time are returned.
+    /// Segments still running at the deadline are stopped through `is_stopped`, which must be
+    /// the stopping flag of `query_context`.
+    ///
+    /// Sampling is not used, results of the processed segments are exact.
+    /// The hard `timeout` still applies to every segment.
+    /// The searched segments are recorded in the [`QueryStats`] of the request.
+    ///
+    /// [`QueryStats`]: common::query_stats::QueryStats
+    pub async fn search_with_soft_timeout(
+        segments: LockedSegmentHolder,
+        batch_request: Arc<CoreSearchRequestBatch>,
+        runtime_handle: &AdaptiveSearchHandle,
+        query_context: QueryContext,
+        timeout: Duration,
+        soft_timeout: Duration,
+        is_stopped: &AtomicBool,
+    ) -> CollectionResult<PartialSearchResult> {
+        let deadline = tokio::time::Instant::now() + soft_timeout;
+        let query_context_arc = Arc::new(query_context);
+
+        let segments: Vec<_> = {
+            let Some(segments_lock) = segments.try_read_for(timeout) else {
+                return Err(CollectionError::timeout(timeout, "search"));
+            };
+            segments_lock
+                .non_appendable_then_appendable_segments()
+                .collect()
+        };
+        let total_segments = segments.len();
+
+        let mut searches: FuturesUnordered<_> = segments
+            .into_iter()
+            .map(|segment| {
+                Self::spawn_search_in_segment(
+                    segment,
+                    batch_request.clone(),
+                    false,
+                    total_segments,
+                    query_context_arc.clone(),
+                    runtime_handle,
+                    timeout,
+                )
+            })
+            .collect();
+
+        let mut result_aggregator = BatchResultAggregator::new(
+            batch_request
+                .searches
+                .iter()
+                .map(|request| request.limit + request.offset),
+        );
+        let mut processed_segments = 0;
+
+        loop {
+            match tokio::time::timeout_at(deadline, searches.next()).await {
+                Ok(Some(search_result)) => {
+                    let (segment_result, _further_results) = search_result??;
+                    result_aggregator.update_point_versions(segment_result.iter().flatten());
+                    for (batch_id, query_res) in segment_result.into_iter().enumerate() {
+                        result_aggregator.update_batch_results(batch_id, query_res);
+                    }
+                    processed_segments += 1;
+                }
+                Ok(None) => break,
+                Err(_) => {
+                    log::debug!("Soft search timeout reached: {soft_timeout:?}");
+                    // Stop scoring in the segments which are still running,
+                    // the pending ones are aborted on drop
+                    is_stopped.store(true, Ordering::Relaxed);
+                    break;
+                }
+            }
+        }
+
+        query_context_arc
+            .hardware_usage_accumulator()
+            .query_stats()
+            .record_searched_segments(processed_segments, total_segments);
+
+        Ok(PartialSearchResult {
+            results: result_aggregator.into_topk(),
+            processed_segments,
+            total_segments,
+        })
+    }
+
     /// Retrieve records for the given points ids from the segments
     /// - if payload is enabled, payload will be fetched
     /// - if vector is enabled, vector will be fetched
@@ -417,6 +550,29 @@ impl SegmentsSearcher {
         Ok(AbortOnDropHandle::new(points).await??)
     }
 
+    /// Latest versions of the given points in the segments
+    ///
+    /// Only id trackers are read, payloads and vectors are not fetched.
+    ///
+    /// If an id is not found in the segments, it won't be included in the output.
+    pub async fn point_versions(
+        segments: LockedSegmentHolder,
+        points: &[PointIdType],
+        runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        deferred_behavior: DeferredBehavior,
+    ) -> CollectionResult<AHashMap<PointIdType, SeqNumberType>> {
+        let stopping_guard = StoppingGuard::new();
+        let versions = runtime_handle.spawn_blocking({
+            let points = points.to_vec();
+            let is_stopped = stopping_guard.get_is_stopped();
+            move || {
+                point_versions_blocking(segments, &points, timeout, &is_stopped, deferred_behavior)
+            }
+        });
+        Ok(AbortOnDropHandle::new(versions).await??)
+    }
+
     pub async fn read_filtered(
         segments: LockedSegmentHolder,
         filter: Option<&Filter>,
@@ -565,6 +721,8 @@ struct BatchSearchParams<'a> {
     pub with_vector: WithVector,
     pub top: usize,
     pub params: Option<&'a SearchParams>,
+    /// Maximal number of results of the segment, see [`SearchParams::segment_limit_factor`]
+    pub segment_limit: Option<usize>,
 }
 
 /// Returns suggested search sampling size for a given number of points and required limit.
@@ -592,6 +750,18 @@ fn sampling_limit(
     effective
 }
 
+/// Number of results a segment contributes at most to the merge of `num_segments` segments,
+/// if limited by [`SearchParams::segment_limit_factor`].
+fn segment_limit(
+    limit: usize,
+    params: Option<&SearchParams>,
+    num_segments: usize,
+) -> Option<usize> {
+    let factor = params?.segment_limit_factor?.0;
+    let segment_limit = (factor * limit as f64 / num_segments as f64).ceil() as usize;
+    Some(segment_limit.max(1).min(limit))
+}
+
 /// Determines the effective ef limit value for the given parameters.
 fn effective_limit(limit: usize, ef_limit: usize, poisson_sampling: usize) -> usize {
     // Prefer the highest of poisson_sampling/ef_limit, but never be higher than limit
@@ -605,6 +775,7 @@ fn effective_limit(limit: usize, ef_limit: usize, poisson_sampling: usize) -> us
 /// * `segment` - Locked segment to search in
 /// * `request` - Batch of search requests
 /// * `use_sampling` - If true, try to use probabilistic sampling
+/// * `num_segments` - Number of segments, which results are merged
 /// * `query_context` - Additional context for the search
 ///
 /// # Returns
@@ -616,6 +787,7 @@ fn search_in_segment(
     segment: LockedSegment,
     request: Arc<CoreSearchRequestBatch>,
     use_sampling: bool,
+    num_segments: usize,
     segment_query_context: &SegmentQueryContext,
     timeout: Duration,
 ) -> CollectionResult<(Vec<Vec<ScoredPoint>>, Vec<bool>)> {
@@ -638,14 +810,16 @@ fn search_in_segment(
             .as_ref()
             .unwrap_or(&WithPayloadInterface::Bool(false));
 
+        let top = search_query.limit + search_query.offset;
         let params = BatchSearchParams {
             search_type: search_query.query.as_ref().into(),
             vector_name: search_query.query.get_vector_name(),
             filter: search_query.filter.as_ref(),
             with_payload: WithPayload::from(with_payload_interface),
             with_vector: search_query.with_vector.clone().unwrap_or_default(),
-            top: search_query.limit + search_query.offset,
+            top,
             params: search_query.params.as_ref(),
+            segment_limit: segment_limit(top, search_query.params.as_ref(), num_segments),
         };
 
         let query = search_query.query.clone().into();
@@ -722,6 +896,9 @@ fn execute_batch_search(
     } else {
         search_params.top
     };
+    let top = search_params
+        .segment_limit
+        .map_or(top, |segment_limit| top.min(segment_limit));
 
     let vectors_batch = &vectors_batch.iter().collect_vec();
     let res = read_segment.search_batch(
@@ -737,9 +914,13 @@ fn execute_batch_search(
 
     drop(read_segment);
 
+    // Segments, which returned as many results as the segment limit, are not searched further,
+    // so that the merge stays bounded
     let further_results = res
         .iter()
-        .map(|batch_result| batch_result.len() == top)
+        .map(|batch_result| {
+            batch_result.len() == top && search_params.segment_limit != Some(batch_result.len())
+        })
         .collect();
 
     Ok((res, further_results))
@@ -766,6 +947,7 @@ mod tests {
     use ahash::AHashSet;
     use api::rest::SearchRequestInternal;
     use common::counter::hardware_counter::HardwareCounterCell;
+    use ordered_float::OrderedFloat;
     use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
     use segment::fixtures::index_fixtures::random_vector;
     use segment::index::VectorIndexEnum;
@@ -866,6 +1048,86 @@ mod tests {
         assert!(result[1].id == 3.into() || result[1].id == 11.into());
     }
 
+    #[tokio::test]
+    async fn test_segments_search_with_soft_timeout() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+        let segment_holder = build_test_holder(dir.path());
+
+        let req = CoreSearchRequest {
+            query: vec![1.0, 1.0, 1.0, 1.0].into(),
+            with_payload: None,
+            with_vector: None,
+            filter: None,
+            params: None,
+            limit: 5,
+            score_threshold: None,
+            offset: 0,
+        };
+        let batch_request = Arc::new(CoreSearchRequestBatch {
+            searches: vec![req],
+        });
+
+        let search = |soft_timeout| {
+            let is_stopped = Arc::new(AtomicBool::new(false));
+            let hw_acc = HwMeasurementAcc::new();
+            let query_context = QueryContext::new(DEFAULT_INDEXING_THRESHOLD_KB, hw_acc.clone())
+                .with_is_stopped(is_stopped.clone());
+            let segment_holder = segment_holder.clone();
+            let batch_request = batch_request.clone();
+            async move {
+                let result = SegmentsSearcher::search_with_soft_timeout(
+                    segment_holder,
+                    batch_request,
+                    &AdaptiveSearchHandle::current_for_tests(),
+                    query_context,
+                    TEST_TIMEOUT,
+                    soft_timeout,
+                    &is_stopped,
+                )
+                .await
+                .unwrap();
+                (result, hw_acc.query_stats())
+            }
+        };
+
+        // Enough time to search all segments
+        let (result, stats) = search(TEST_TIMEOUT).await;
+        assert!(!result.is_partial());
+        assert_eq!(result.processed_segments, 2);
+        assert_eq!(result.total_segments, 2);
+        assert!(!stats.searched_segments().unwrap().is_partial());
+
+        let top = &result.results[0];
+        assert_eq!(top.len(), 5);
+        assert!(top[0].id == 3.into() || top[0].id == 11.into());
+        assert!(top[1].id == 3.into() || top[1].id == 11.into());
+
+        // The segment with points 11 to 15 can't be searched while it is locked for writing
+        let blocked_segment = segment_holder
+            .read()
+            .iter()
+            .map(|(_, segment)| segment.clone())
+            .find(|segment| segment.get().read().has_point(11.into()))
+            .unwrap();
+        let write_guard = blocked_segment.get().write();
+        let (result, stats) = search(Duration::from_millis(100)).await;
+        drop(write_guard);
+
+        assert!(result.is_partial());
+        assert_eq!(result.processed_segments, 1);
+        assert_eq!(result.total_segments, 2);
+        let searched_segments = stats.searched_segments().unwrap();
+        assert!(searched_segments.is_partial());
+        assert_eq!(searched_segments.searched, 1);
+
+        // Best results of the other segment are returned
+        let top = &result.results[0];
+        assert_eq!(top.len(), 5);
+        assert_eq!(top[0].id, 3.into());
+        assert!(top.iter().all(|point| point.id != 11.into()));
+    }
+
     #[tokio::test]
     async fn test_segments_search_sampling() {
         let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -1011,6 +1273,21 @@ mod tests {
         assert_eq!(sampling_limit(1000000, None, 464530, 35103551), 1000000);
     }
 
+    #[test]
+    fn test_segment_limit() {
+        let params = |factor| SearchParams {
+            segment_limit_factor: Some(OrderedFloat(factor)),
+            ..Default::default()
+        };
+
+        assert_eq!(segment_limit(100, None, 8), None);
+        assert_eq!(segment_limit(100, Some(&SearchParams::default()), 8), None);
+        assert_eq!(segment_limit(100, Some(&params(2.0)), 8), Some(25));
+        assert_eq!(segment_limit(100, Some(&params(1.0)), 3), Some(34));
+        assert_eq!(segment_limit(100, Some(&params(8.0)), 4), Some(100));
+        assert_eq!(segment_limit(1, Some(&params(1.0)), 10), Some(1));
+    }
+
     /// Tests whether calculating the effective ef limit value is correct.
     ///
     /// Because there was confusion about what the effective value should be for some input
@@ -14,7 +14,7 @@ use segment::json_path::JsonPath;
 use segment::payload_json;
 use segment::types::{ExtendedPointId, PayloadContainer, PointIdType, WithPayload, WithVector};
 use shard::retrieve::record_internal::RecordInternal;
-use shard::retrieve::retrieve_blocking::retrieve_blocking;
+use shard::retrieve::retrieve_blocking::{point_versions_blocking, retrieve_blocking};
 use shard::segment_holder::locked::LockedSegmentHolder;
 use shard::update::{delete_points, set_payload, upsert_points};
 use tempfile::Builder;
@@ -359,6 +359,47 @@ fn test_delete_all_point_versions() {
     assert!(retrieved.is_empty());
 }
 
+#[test]
+fn test_point_versions() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment1 = empty_segment(dir.path());
+    let mut segment2 = empty_segment(dir.path());
+
+    let vector = vec![0.0, 1.0, 2.0, 3.0];
+
+    // Point 1 is in both segments, with the latest version in the first one
+    segment1
+        .upsert_point(30, 1.into(), only_default_vector(&vector), &hw_counter)
+        .unwrap();
+    segment2
+        .upsert_point(20, 1.into(), only_default_vector(&vector), &hw_counter)
+        .unwrap();
+    segment2
+        .upsert_point(25, 2.into(), only_default_vector(&vector), &hw_counter)
+        .unwrap();
+
+    let mut holder = SegmentHolder::default();
+    holder.add_new(segment1);
+    holder.add_new(segment2);
+    let segments = LockedSegmentHolder::new(holder);
+
+    let versions = point_versions_blocking(
+        segments,
+        &[1.into(), 2.into(), 3.into(), 1.into()],
+        TEST_TIMEOUT,
+        &AtomicBool::new(false),
+        DeferredBehavior::Exclude,
+    )
+    .unwrap();
+
+    assert_eq!(
+        versions,
+        AHashMap::from([(PointIdType::from(1), 30), (PointIdType::from(2), 25)]),
+    );
+}
+
 #[test]
 fn test_proxy_shared_updates() {
     // Testing that multiple proxies that share point with the same id but different versions
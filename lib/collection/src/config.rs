@@ -7,20 +7,22 @@ use atomicwrites::AtomicFile;
 use atomicwrites::OverwriteBehavior::AllowOverwrite;
 use common::types::PointOffsetType;
 use fs_err::File;
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
-    Distance, HnswConfig, Indexes, Payload, PayloadStorageType, QuantizationConfig, SegmentConfig,
-    SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName, VectorNameBuf,
-    VectorStorageDatatype, VectorStorageType,
+    Distance, HnswConfig, Indexes, Payload, PayloadStorageType, QuantizationConfig, ScoreType,
+    SegmentConfig, SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName,
+    VectorNameBuf, VectorStorageDatatype, VectorStorageType,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 use wal::WalOptions;
 
+use crate::operations::boost_rules::BoostRules;
 use crate::operations::config_diff::{DiffConfig, QuantizationConfigDiff};
 use crate::operations::types::{
     CollectionError, CollectionResult, CollectionWarning, Datatype, SparseVectorParams,
@@ -125,6 +127,13 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub read_fan_out_delay_ms: Option<u64>,
+    /// Score threshold of the nearest neighbour and average vector recommendation queries to this
+    /// collection, which don't set a `score_threshold`. A `score_threshold` of the query takes
+    /// precedence over it, and queries can opt out with `apply_default_score_threshold: false`.
+    /// Default is not set, results are not limited by score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub default_score_threshold: Option<OrderedFloat<ScoreType>>,
     /// If true - point's payload will not be stored in memory.
     /// It will be read from the disk every time it is requested.
     /// This setting saves RAM by (slightly) increasing the response time.
@@ -153,6 +162,7 @@ impl CollectionParams {
             write_consistency_factor: _, // May be changed
             read_fan_out_factor: _, // May be changed
             read_fan_out_delay_ms: _, // May be changed,
+            default_score_threshold: _, // May be changed
             on_disk_payload: _, // May be changed
             sparse_vectors: _, // Sets may differ via named vector CRUD
         } = other;
@@ -252,6 +262,10 @@ pub struct CollectionConfigInternal {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Boost rules applied to the scores of query results
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[validate(nested)]
+    pub boost_rules: BoostRules,
 }
 
 impl CollectionConfigInternal {
@@ -339,6 +353,7 @@ impl CollectionParams {
             write_consistency_factor: default_write_consistency_factor(),
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            default_score_threshold: None,
             on_disk_payload: default_on_disk_payload(),
             sparse_vectors: None,
         }
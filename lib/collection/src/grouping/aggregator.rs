@@ -212,6 +212,8 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
         }
     }
 
@@ -224,6 +226,8 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
         }
     }
 
@@ -294,6 +294,12 @@ impl From<CollectionQueryGroupsRequest> for GroupRequest {
             with_vector,
             with_payload,
             lookup_from,
+            // Groups are filled by repeated queries, which don't see the boosted order
+            apply_boost_rules: false,
+            apply_default_score_threshold: false,
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         };
 
         GroupRequest {
@@ -568,6 +574,8 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
         }
     }
 
@@ -0,0 +1,214 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use segment::types::{Filter, Order, PointIdType, ScoreStage, ScoreStageKind, ScoredPoint};
+use segment::utils::scored_point_ties::ScoredPointTies;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+/// Boost rules of a collection, by their names
+pub type BoostRules = BTreeMap<String, BoostRule>;
+
+/// Score multiplier for points matching a filter.
+///
+/// Boost rules of a collection are applied to the results of every query, unless the query
+/// disables them with `apply_boost_rules: false`. Points matching several rules are boosted by
+/// the product of their multipliers.
+///
+/// Boosting reorders the results found by the query itself, it never adds points which the query
+/// wouldn't return otherwise.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct BoostRule {
+    /// Points matching this filter are boosted
+    #[validate(nested)]
+    pub filter: Filter,
+    /// Scores of matching points are multiplied by this value, values below 1.0 demote points.
+    /// Must be greater than 0.
+    /// The score is divided by it instead where multiplying would move it the wrong way: for
+    /// negative scores, and for positive distances, where a smaller score is better.
+    #[validate(custom(function = "validate_multiplier"))]
+    pub multiplier: OrderedFloat<f64>,
+}
+
+/// Zero multipliers would erase scores, and divide distances by zero
+fn validate_multiplier(multiplier: &OrderedFloat<f64>) -> Result<(), ValidationError> {
+    if multiplier.into_inner() > 0.0 {
+        return Ok(());
+    }
+    let mut err = ValidationError::new("range");
+    err.add_param(Cow::from("exclusive_min"), &0.0);
+    Err(err)
+}
+
+/// Changes of the boost rules of a collection
+#[derive(
+    Debug, Default, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct BoostRulesDiff {
+    /// Rules to add, by their names. Rules with the same names are replaced.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[validate(nested)]
+    pub upsert: BoostRules,
+    /// Names of rules to remove
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delete: Vec<String>,
+}
+
+impl BoostRulesDiff {
+    /// Apply the changes to the `rules`, removed rules are removed before added ones are added
+    pub fn apply_to(self, rules: &mut BoostRules) {
+        let Self { upsert, delete } = self;
+        for name in delete {
+            rules.remove(&name);
+        }
+        rules.extend(upsert);
+    }
+}
+
+/// Apply the multipliers of the `matched_rules`, names and multipliers of the rules matched by
+/// each point, to the scores of the points, and sort the points by their new scores.
+///
+/// With `record_stages`, a score stage is recorded for every applied rule.
+pub fn boost_scores(
+    mut points: Vec<ScoredPoint>,
+    matched_rules: &HashMap<PointIdType, Vec<(String, f64)>>,
+    order: Order,
+    record_stages: bool,
+) -> Vec<ScoredPoint> {
+    for point in &mut points {
+        let Some(rules) = matched_rules.get(&point.id) else {
+            continue;
+        };
+        let mut score = f64::from(point.score);
+        for (rule, multiplier) in rules {
+            score = boost_score(score, *multiplier, order);
+            if record_stages {
+                point.score_stages.push(ScoreStage {
+                    kind: ScoreStageKind::Boost {
+                        rule: rule.clone(),
+                        multiplier: *multiplier,
+                    },
+                    score: score as f32,
+                });
+            }
+        }
+        point.score = score as f32;
+    }
+
+    match order {
+        Order::LargeBetter => {
+            points.sort_unstable_by(|a, b| ScoredPointTies(b).cmp(&ScoredPointTies(a)))
+        }
+        Order::SmallBetter => {
+            points.sort_unstable_by(|a, b| ScoredPointTies(a).cmp(&ScoredPointTies(b)))
+        }
+    }
+    points
+}
+
+/// Boost the `score` by the `multiplier`, moving it towards better scores for multipliers above 1
+fn boost_score(score: f64, multiplier: f64, order: Order) -> f64 {
+    match (order, score < 0.0) {
+        (Order::LargeBetter, false) | (Order::SmallBetter, true) => score * multiplier,
+        (Order::LargeBetter, true) | (Order::SmallBetter, false) => score / multiplier,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
+        }
+    }
+
+    fn ids(points: &[ScoredPoint]) -> Vec<PointIdType> {
+        points.iter().map(|point| point.id).collect()
+    }
+
+    fn boost_stage(rule: &str, multiplier: f64, score: f32) -> ScoreStage {
+        ScoreStage {
+            kind: ScoreStageKind::Boost {
+                rule: rule.to_string(),
+                multiplier,
+            },
+            score,
+        }
+    }
+
+    #[test]
+    fn test_boost_scores() {
+        let points = vec![point(1, 0.9), point(2, 0.8), point(3, 0.5)];
+        let matched_rules = HashMap::from([
+            (
+                3.into(),
+                vec![("a".to_string(), 2.0), ("b".to_string(), 1.5)],
+            ),
+            (1.into(), vec![("c".to_string(), 0.5)]),
+        ]);
+
+        let boosted = boost_scores(points, &matched_rules, Order::LargeBetter, false);
+        assert_eq!(ids(&boosted), vec![3.into(), 2.into(), 1.into()]);
+        assert_eq!(boosted[0].score, 1.5);
+        assert_eq!(boosted[2].score, 0.45);
+        assert!(boosted.iter().all(|point| point.score_stages.is_empty()));
+
+        // Smaller distances are better, boosted points get closer
+        let points = vec![point(1, 1.5), point(2, 2.0), point(3, 3.0)];
+        let boosted = boost_scores(points, &matched_rules, Order::SmallBetter, true);
+        assert_eq!(ids(&boosted), vec![3.into(), 2.into(), 1.into()]);
+        assert_eq!(boosted[0].score, 1.0);
+        assert_eq!(boosted[2].score, 3.0);
+
+        // Every rule is recorded with the score after applying it
+        assert_eq!(
+            boosted[0].score_stages,
+            vec![boost_stage("a", 2.0, 1.5), boost_stage("b", 1.5, 1.0)],
+        );
+        assert!(boosted[1].score_stages.is_empty());
+        assert_eq!(boosted[2].score_stages, vec![boost_stage("c", 0.5, 3.0)]);
+    }
+
+    #[test]
+    fn test_boost_negative_scores() {
+        let points = vec![point(1, -0.5), point(2, -0.8)];
+        let matched_rules = HashMap::from([(2.into(), vec![("a".to_string(), 2.0)])]);
+
+        // Boosted negative scores get closer to zero
+        let boosted = boost_scores(points, &matched_rules, Order::LargeBetter, false);
+        assert_eq!(ids(&boosted), vec![2.into(), 1.into()]);
+        assert_eq!(boosted[0].score, -0.4);
+
+        // Smaller is better, boosted negative scores get further from zero
+        let points = vec![point(1, -0.5), point(2, -0.3)];
+        let boosted = boost_scores(points, &matched_rules, Order::SmallBetter, false);
+        assert_eq!(ids(&boosted), vec![2.into(), 1.into()]);
+        assert_eq!(boosted[0].score, -0.6);
+    }
+
+    #[test]
+    fn test_multiplier_must_be_positive() {
+        let rule = |multiplier| BoostRule {
+            filter: Filter::default(),
+            multiplier: OrderedFloat(multiplier),
+        };
+        assert!(rule(0.5).validate().is_ok());
+        assert!(rule(0.0).validate().is_err());
+        assert!(rule(-1.0).validate().is_err());
+        assert!(rule(f64::NAN).validate().is_err());
+    }
+}
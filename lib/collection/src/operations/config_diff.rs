@@ -5,16 +5,17 @@
 use std::num::NonZeroU32;
 
 use api::rest::MaxOptimizationThreads;
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use segment::types::{
-    BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization, StrictModeConfig,
-    TurboQuantization,
+    BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization, ScoreType,
+    StrictModeConfig, TurboQuantization,
 };
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationErrors};
 
 use crate::config::{CollectionParams, WalConfig};
-use crate::optimizers_builder::OptimizersConfig;
+use crate::optimizers_builder::{OptimizersConfig, RequantizationConfig};
 
 pub trait DiffConfig<Diff>: Clone {
     /// Update this config with field from `diff`
@@ -97,6 +98,9 @@ pub struct CollectionParamsDiff {
     pub read_fan_out_factor: Option<u32>,
     ///  Delay in milliseconds before sending read requests to remote nodes
     pub read_fan_out_delay_ms: Option<u64>,
+    /// Score threshold of the queries, which don't set a `score_threshold`.
+    /// `Disabled` removes the threshold.
+    pub default_score_threshold: Option<ScoreThresholdDiff>,
     /// If true - point's payload will not be stored in memory.
     /// It will be read from the disk every time it is requested.
     /// This setting saves RAM by (slightly) increasing the response time.
@@ -169,6 +173,12 @@ pub struct OptimizersConfigDiff {
     /// Default is disabled.
     #[serde(default)]
     pub prevent_unoptimized: Option<bool>,
+
+    /// Re-quantize segments, which quantized ranking drifted away from the exact one.
+    /// Default is disabled.
+    #[serde(default)]
+    #[validate(nested)]
+    pub requantization: Option<RequantizationConfig>,
 }
 
 impl std::hash::Hash for OptimizersConfigDiff {
@@ -184,6 +194,7 @@ impl std::hash::Hash for OptimizersConfigDiff {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            requantization,
         } = self;
 
         deleted_threshold.map(f64::to_le_bytes).hash(state);
@@ -195,6 +206,7 @@ impl std::hash::Hash for OptimizersConfigDiff {
         flush_interval_sec.hash(state);
         max_optimization_threads.hash(state);
         prevent_unoptimized.hash(state);
+        requantization.hash(state);
     }
 }
 
@@ -260,6 +272,7 @@ impl DiffConfig<OptimizersConfigDiff> for OptimizersConfig {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            requantization,
         } = diff;
 
         OptimizersConfig {
@@ -274,6 +287,7 @@ impl DiffConfig<OptimizersConfigDiff> for OptimizersConfig {
             max_optimization_threads: max_optimization_threads
                 .map_or(self.max_optimization_threads, From::from),
             prevent_unoptimized: prevent_unoptimized.or(self.prevent_unoptimized),
+            requantization: requantization.or(self.requantization),
         }
     }
 }
@@ -301,6 +315,7 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
             write_consistency_factor,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            default_score_threshold,
             on_disk_payload,
         } = diff;
 
@@ -310,6 +325,11 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
                 .unwrap_or(self.write_consistency_factor),
             read_fan_out_factor: read_fan_out_factor.or(self.read_fan_out_factor),
             read_fan_out_delay_ms: read_fan_out_delay_ms.or(self.read_fan_out_delay_ms),
+            default_score_threshold: match default_score_threshold {
+                None => self.default_score_threshold,
+                Some(ScoreThresholdDiff::Threshold(threshold)) => Some(*threshold),
+                Some(ScoreThresholdDiff::Disabled(_)) => None,
+            },
             on_disk_payload: on_disk_payload.unwrap_or(self.on_disk_payload),
             shard_number: self.shard_number,
             sharding_method: self.sharding_method,
@@ -338,6 +358,8 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            filter_max_selectivity,
+            filter_selectivity_warn_only,
             multivector_config,
             sparse_config,
             max_payload_index_count,
@@ -367,6 +389,9 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
             max_points_count: max_points_count.or(self.max_points_count),
             filter_max_conditions: filter_max_conditions.or(self.filter_max_conditions),
             condition_max_size: condition_max_size.or(self.condition_max_size),
+            filter_max_selectivity: filter_max_selectivity.or(self.filter_max_selectivity),
+            filter_selectivity_warn_only: filter_selectivity_warn_only
+                .or(self.filter_selectivity_warn_only),
             multivector_config: multivector_config
                 .as_ref()
                 .or(self.multivector_config.as_ref())
@@ -429,6 +454,7 @@ impl From<CollectionParams> for CollectionParamsDiff {
             write_consistency_factor,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            default_score_threshold,
             on_disk_payload,
             shard_number: _,
             sharding_method: _,
@@ -441,6 +467,7 @@ impl From<CollectionParams> for CollectionParamsDiff {
             write_consistency_factor: Some(write_consistency_factor),
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            default_score_threshold: default_score_threshold.map(ScoreThresholdDiff::Threshold),
             on_disk_payload: Some(on_disk_payload),
         }
     }
@@ -459,6 +486,7 @@ impl From<OptimizersConfig> for OptimizersConfigDiff {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            requantization,
         } = config;
 
         Self {
@@ -472,6 +500,7 @@ impl From<OptimizersConfig> for OptimizersConfigDiff {
             flush_interval_sec: Some(flush_interval_sec),
             max_optimization_threads: max_optimization_threads.map(MaxOptimizationThreads::Threads),
             prevent_unoptimized,
+            requantization,
         }
     }
 }
@@ -481,6 +510,14 @@ pub enum Disabled {
     Disabled,
 }
 
+/// Change of the default score threshold of a collection
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum ScoreThresholdDiff {
+    Threshold(OrderedFloat<ScoreType>),
+    Disabled(Disabled),
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[serde(untagged)]
@@ -533,6 +570,7 @@ mod tests {
             write_consistency_factor: Some(NonZeroU32::new(2).unwrap()),
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            default_score_threshold: None,
             on_disk_payload: None,
         };
 
@@ -543,6 +581,27 @@ mod tests {
         assert!(new_params.on_disk_payload);
     }
 
+    #[test]
+    fn test_update_default_score_threshold() {
+        let params = CollectionParams::empty();
+
+        let set: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "default_score_threshold": 0.5 }"#).unwrap();
+        let params = params.update(&set);
+        assert_eq!(params.default_score_threshold, Some(OrderedFloat(0.5)));
+
+        // Other changes keep the threshold
+        let unchanged: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "on_disk_payload": false }"#).unwrap();
+        let params = params.update(&unchanged);
+        assert_eq!(params.default_score_threshold, Some(OrderedFloat(0.5)));
+
+        let unset: CollectionParamsDiff =
+            serde_json::from_str(r#"{ "default_score_threshold": "Disabled" }"#).unwrap();
+        let params = params.update(&unset);
+        assert_eq!(params.default_score_threshold, None);
+    }
+
     #[test]
     fn test_hnsw_update() {
         let base_config = HnswConfig::default();
@@ -563,6 +622,7 @@ mod tests {
             flush_interval_sec: 30,
             max_optimization_threads: Some(1),
             prevent_unoptimized: None,
+            requantization: None,
         };
         let update: OptimizersConfigDiff =
             serde_json::from_str(r#"{ "indexing_threshold": 10000 }"#).unwrap();
@@ -588,6 +648,7 @@ mod tests {
             flush_interval_sec: 30,
             max_optimization_threads: Some(1),
             prevent_unoptimized: None,
+            requantization: None,
         };
 
         let update: OptimizersConfigDiff = serde_json::from_str(json_diff).unwrap();
@@ -20,6 +20,10 @@ use validator::{Validate, ValidationError as ValidatorError, ValidationErrors};
 ///
 /// * `all` - send requests to all nodes and return points which present on all of them
 ///
+/// Higher consistency costs latency, as the slowest of the queried replicas defines the response
+/// time. If not enough replicas of a shard are active to satisfy the consistency, the request
+/// fails with a shard unavailable error.
+///
 /// Default value is `Factor(1)`
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
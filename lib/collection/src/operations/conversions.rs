@@ -9,6 +9,7 @@ use api::grpc::conversions::{
 };
 use api::grpc::qdrant as grpc;
 use api::grpc::qdrant::quantization_config_diff::Quantization;
+use api::grpc::qdrant::score_threshold_diff::Threshold;
 use api::grpc::qdrant::update_collection_cluster_setup_request::{
     Operation as ClusterOperationsPb, Operation,
 };
@@ -17,6 +18,7 @@ use api::rest::{
     BaseGroupRequest, LookupLocation, MaxOptimizationThreads, ShardKeyWithFallback, schema as rest,
 };
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use segment::common::operation_error::OperationError;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
@@ -42,6 +44,7 @@ use crate::config::{
 use crate::lookup::WithLookup;
 use crate::lookup::types::WithLookupInterface;
 use crate::operations::ClockTag;
+use crate::operations::boost_rules::{BoostRule, BoostRulesDiff};
 use crate::operations::cluster_ops::{
     AbortShardTransfer, AbortTransferOperation, ClusterOperations, CreateShardingKey,
     CreateShardingKeyOperation, DropReplicaOperation, DropShardingKey, DropShardingKeyOperation,
@@ -49,8 +52,8 @@ use crate::operations::cluster_ops::{
     RestartTransfer, RestartTransferOperation,
 };
 use crate::operations::config_diff::{
-    CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
-    WalConfigDiff,
+    CollectionParamsDiff, Disabled, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
+    ScoreThresholdDiff, WalConfigDiff,
 };
 use crate::operations::point_ops::{FilterSelector, PointIdsList, PointsSelector, WriteOrdering};
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
@@ -60,7 +63,7 @@ use crate::operations::types::{
     ShardTransferInfo, UpdateQueueInfo, UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
 };
 use crate::operations::universal_query::collection_query::FeedbackStrategy;
-use crate::optimizers_builder::OptimizersConfig;
+use crate::optimizers_builder::{OptimizersConfig, RequantizationConfig};
 use crate::shards::remote_shard::CollectionCoreSearchRequest;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::transfer::ShardTransferMethod;
@@ -304,6 +307,7 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
             read_fan_out_factor,
             on_disk_payload,
             read_fan_out_delay_ms,
+            default_score_threshold,
         } = value;
         Ok(Self {
             replication_factor: replication_factor
@@ -321,11 +325,29 @@ impl TryFrom<api::grpc::qdrant::CollectionParamsDiff> for CollectionParamsDiff {
                 .transpose()?,
             read_fan_out_factor,
             read_fan_out_delay_ms,
+            default_score_threshold: default_score_threshold
+                .map(ScoreThresholdDiff::try_from)
+                .transpose()?,
             on_disk_payload,
         })
     }
 }
 
+impl TryFrom<api::grpc::qdrant::ScoreThresholdDiff> for ScoreThresholdDiff {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::ScoreThresholdDiff) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::ScoreThresholdDiff { threshold } = value;
+        match threshold {
+            None => Err(Status::invalid_argument(
+                "Default score threshold is not specified",
+            )),
+            Some(Threshold::Value(threshold)) => Ok(Self::Threshold(OrderedFloat(threshold))),
+            Some(Threshold::Disabled(_)) => Ok(Self::Disabled(Disabled::Disabled)),
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
     type Error = Status;
 
@@ -341,6 +363,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
             deprecated_max_optimization_threads,
             max_optimization_threads,
             prevent_unoptimized,
+            requantization,
         } = value;
         Ok(Self {
             deleted_threshold,
@@ -358,6 +381,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfigDiff {
                     .map(TryFrom::try_from)
                     .transpose()?),
             prevent_unoptimized,
+            requantization: requantization.map(RequantizationConfig::from),
         })
     }
 }
@@ -404,6 +428,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             quantization_config,
             strict_mode_config,
             metadata,
+            boost_rules,
         } = config;
 
         let OptimizersConfig {
@@ -417,6 +442,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             flush_interval_sec,
             max_optimization_threads,
             prevent_unoptimized,
+            requantization,
         } = optimizer_config;
 
         let HnswConfig {
@@ -434,6 +460,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             shard_number,
             replication_factor,
             read_fan_out_delay_ms,
+            default_score_threshold,
             on_disk_payload,
             write_consistency_factor,
             read_fan_out_factor,
@@ -502,6 +529,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                         }
                     }),
                     read_fan_out_delay_ms,
+                    default_score_threshold: default_score_threshold.map(OrderedFloat::into_inner),
                 }),
                 hnsw_config: Some(api::grpc::qdrant::HnswConfigDiff {
                     m: Some(m as u64),
@@ -523,6 +551,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     deprecated_max_optimization_threads: max_optimization_threads.map(|x| x as u64),
                     max_optimization_threads: Some(From::from(max_optimization_threads)),
                     prevent_unoptimized,
+                    requantization: requantization.map(From::from),
                 }),
                 wal_config: wal_config.map(|wal_config| {
                     let WalConfig {
@@ -543,6 +572,10 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                 metadata: metadata
                     .map(api::conversions::json::payload_to_proto)
                     .unwrap_or_default(),
+                boost_rules: boost_rules
+                    .into_iter()
+                    .map(|(name, rule)| (name, rule.into()))
+                    .collect(),
             }),
             payload_schema: payload_schema
                 .into_iter()
@@ -635,6 +668,7 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfig {
             deprecated_max_optimization_threads,
             max_optimization_threads,
             prevent_unoptimized,
+            requantization,
         } = optimizer_config;
 
         let converted_max_optimization_threads: Option<usize> =
@@ -657,10 +691,41 @@ impl TryFrom<api::grpc::qdrant::OptimizersConfigDiff> for OptimizersConfig {
             flush_interval_sec: flush_interval_sec.unwrap_or_default(),
             max_optimization_threads: converted_max_optimization_threads,
             prevent_unoptimized,
+            requantization: requantization.map(RequantizationConfig::from),
         })
     }
 }
 
+impl From<api::grpc::qdrant::RequantizationConfig> for RequantizationConfig {
+    fn from(value: api::grpc::qdrant::RequantizationConfig) -> Self {
+        let api::grpc::qdrant::RequantizationConfig {
+            enable,
+            max_distortion,
+            check_interval_sec,
+        } = value;
+        Self {
+            enable,
+            max_distortion,
+            check_interval_sec,
+        }
+    }
+}
+
+impl From<RequantizationConfig> for api::grpc::qdrant::RequantizationConfig {
+    fn from(value: RequantizationConfig) -> Self {
+        let RequantizationConfig {
+            enable,
+            max_distortion,
+            check_interval_sec,
+        } = value;
+        Self {
+            enable,
+            max_distortion,
+            check_interval_sec,
+        }
+    }
+}
+
 impl From<api::grpc::qdrant::WalConfigDiff> for WalConfig {
     fn from(wal_config: api::grpc::qdrant::WalConfigDiff) -> Self {
         let api::grpc::qdrant::WalConfigDiff {
@@ -1449,12 +1514,14 @@ impl From<LocalShardInfo> for api::grpc::qdrant::LocalShardInfo {
             points_count,
             state,
             shard_key,
+            max_version,
         } = value;
         Self {
             shard_id,
             points_count: points_count as u64,
             state: state as i32,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
+            max_version,
         }
     }
 }
@@ -1859,6 +1926,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
             quantization_config,
             strict_mode_config,
             metadata,
+            boost_rules,
         } = config;
         Ok(Self {
             params: match params {
@@ -1874,6 +1942,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         sharding_method,
                         sparse_vectors_config,
                         read_fan_out_delay_ms,
+                        default_score_threshold,
                     } = params;
                     CollectionParams {
                         vectors: match vectors_config {
@@ -1931,6 +2000,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             .map(sharding_method_from_proto)
                             .transpose()?,
                         read_fan_out_delay_ms,
+                        default_score_threshold: default_score_threshold.map(OrderedFloat),
                     }
                 }
             },
@@ -1959,6 +2029,55 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
             } else {
                 Some(api::conversions::json::proto_to_payloads(metadata)?)
             },
+            boost_rules: boost_rules
+                .into_iter()
+                .map(|(name, rule)| Ok((name, rule.try_into()?)))
+                .collect::<Result<_, Status>>()?,
+        })
+    }
+}
+
+impl From<BoostRule> for grpc::BoostRule {
+    fn from(rule: BoostRule) -> Self {
+        let BoostRule { filter, multiplier } = rule;
+        Self {
+            filter: Some(filter.into()),
+            multiplier: multiplier.into_inner(),
+        }
+    }
+}
+
+impl TryFrom<grpc::BoostRule> for BoostRule {
+    type Error = Status;
+
+    fn try_from(rule: grpc::BoostRule) -> Result<Self, Self::Error> {
+        let grpc::BoostRule { filter, multiplier } = rule;
+        let filter = filter
+            .ok_or_else(|| Status::invalid_argument("Boost rule must have a filter"))?
+            .try_into()?;
+        if multiplier.is_nan() || multiplier <= 0.0 {
+            return Err(Status::invalid_argument(format!(
+                "Boost rule multiplier must be greater than 0, got {multiplier}"
+            )));
+        }
+        Ok(Self {
+            filter,
+            multiplier: OrderedFloat(multiplier),
+        })
+    }
+}
+
+impl TryFrom<grpc::BoostRulesDiff> for BoostRulesDiff {
+    type Error = Status;
+
+    fn try_from(diff: grpc::BoostRulesDiff) -> Result<Self, Self::Error> {
+        let grpc::BoostRulesDiff { upsert, delete } = diff;
+        Ok(Self {
+            upsert: upsert
+                .into_iter()
+                .map(|(name, rule)| Ok((name, rule.try_into()?)))
+                .collect::<Result<_, Status>>()?,
+            delete,
         })
     }
 }
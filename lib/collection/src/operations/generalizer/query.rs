@@ -31,6 +31,9 @@ impl Generalizer for ShardQueryRequest {
             params,
             with_vector,
             with_payload,
+            with_provenance,
+            with_explanation,
+            soft_timeout,
         } = self;
 
         ShardQueryRequest {
@@ -43,6 +46,9 @@ impl Generalizer for ShardQueryRequest {
             params: *params,
             with_vector: with_vector.clone(),
             with_payload: with_payload.clone(),
+            with_provenance: *with_provenance,
+            with_explanation: *with_explanation,
+            soft_timeout: *soft_timeout,
         }
     }
 }
@@ -113,12 +113,14 @@ impl Generalizer for ConditionalInsertOperationInternal {
             points_op,
             condition,
             update_mode,
+            expected_version,
         } = self;
 
         Self {
             condition: condition.clone(),
             points_op: points_op.remove_details(),
             update_mode: *update_mode,
+            expected_version: *expected_version,
         }
     }
 }
@@ -1,3 +1,4 @@
+pub mod boost_rules;
 pub mod cluster_ops;
 pub mod config_diff;
 pub mod consistency_params;
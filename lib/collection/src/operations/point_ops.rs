@@ -102,6 +102,7 @@ impl SplitByShard for ConditionalInsertOperationInternal {
             points_op,
             condition,
             update_mode,
+            expected_version,
         } = self;
 
         let points_op = points_op.split_by_shard(ring);
@@ -116,6 +117,7 @@ impl SplitByShard for ConditionalInsertOperationInternal {
                                 points_op: upsert_operation,
                                 condition: condition.clone(),
                                 update_mode,
+                                expected_version,
                             },
                         )
                     })
@@ -125,6 +127,7 @@ impl SplitByShard for ConditionalInsertOperationInternal {
                 points_op: upsert_operation,
                 condition,
                 update_mode,
+                expected_version,
             }),
         }
     }
@@ -399,6 +402,8 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            expected_version: None,
+            duplicate_ids: None,
         });
         assert!(batch.validate().is_err());
 
@@ -411,6 +416,8 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            expected_version: None,
+            duplicate_ids: None,
         });
         assert!(batch.validate().is_ok());
 
@@ -423,6 +430,8 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            expected_version: None,
+            duplicate_ids: None,
         });
         assert!(batch.validate().is_err());
     }
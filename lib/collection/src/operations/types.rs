@@ -6,12 +6,13 @@ use std::iter;
 use std::num::NonZeroU64;
 use std::time::{Duration, SystemTimeError};
 
-use api::grpc::transport_channel_pool::RequestError;
+use api::grpc::transport_channel_pool::{RequestError, is_version_conflict};
 use api::rest::{
-    BaseGroupRequest, LookupLocation, RecommendStrategy, SearchGroupsRequestInternal,
-    SearchRequestInternal, ShardKeySelector, VectorStructOutput,
+    BaseGroupRequest, LookupLocation, NamedVectorStruct, RecommendStrategy,
+    SearchGroupsRequestInternal, SearchRequestInternal, ShardKeySelector, VectorStructOutput,
 };
 use common::ext::OptionExt;
+use common::populate_budget::PopulateReport;
 use common::rate_limiting::{RateLimitError, RetryError};
 use common::types::ScoreType;
 use common::validation::validate_range_generic;
@@ -29,6 +30,7 @@ use segment::types::{
     SparseVectorStorageType, StrictModeConfigOutput, VectorName, VectorNameBuf,
     VectorStorageDatatype, WithPayloadInterface, WithVector,
 };
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
 use semver::Version;
 use serde::{self, Deserialize, Serialize};
 use serde_json::{Error as JsonError, Map, Value};
@@ -49,9 +51,11 @@ use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::ClockTag;
 use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::boost_rules::BoostRules;
 use crate::operations::cluster_ops::ReshardingDirection;
 use crate::operations::config_diff::{HnswConfigDiff, QuantizationConfigDiff};
 use crate::optimizers_builder::OptimizersConfig;
+use crate::problems::unindexed_field::FilterConditionDiagnostic;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::resharding::ReshardingStage;
 use crate::shards::shard::{PeerId, ShardId};
@@ -108,6 +112,103 @@ pub struct CollectionExistence {
     pub exists: bool,
 }
 
+/// Filter to check against the payload indexes of a collection
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct ValidateFilterRequest {
+    /// Filter to validate, it is not executed
+    #[validate(nested)]
+    pub filter: Filter,
+}
+
+/// Per-condition diagnostics of a filter validated against the collection payload indexes.
+/// Empty if every indexable condition can be served by an existing index.
+#[derive(Debug, Serialize, JsonSchema, Clone)]
+pub struct FilterValidationResult {
+    pub diagnostics: Vec<FilterConditionDiagnostic>,
+}
+
+/// Text to tokenize with the full-text index of a field
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenizeRequest {
+    /// Text to tokenize, as if it was a payload value
+    pub text: String,
+}
+
+/// Tokens produced by the full-text index of a field
+#[derive(Debug, Serialize, JsonSchema, Clone)]
+pub struct TokenizeResponse {
+    pub tokens: Vec<String>,
+}
+
+/// Quantized representation of a vector of a point, as used by the scorers of quantized search
+#[derive(Debug, Serialize, JsonSchema, Clone)]
+pub struct QuantizedVectorResponse {
+    /// Quantization the vector is encoded with
+    pub quantization: QuantizationConfig,
+    /// Dimensionality of the original vector
+    pub dim: usize,
+    /// Encoded vector. Layout depends on the quantization method:
+    ///
+    /// - Scalar (`int8`): little-endian `f32` offset correction, followed by one `u8` code per
+    ///   dimension. Codes are padded with zeros to a multiple of 16 dimensions.
+    /// - Product: one `u8` centroid index per chunk of the original vector.
+    /// - Binary: bits packed into little-endian `u128` words. One bit per dimension for
+    ///   `one_bit` encoding, two bits per dimension for `two_bits`, three bits per two dimensions
+    ///   for `one_and_half_bits`.
+    /// - TurboQuant: packed codes of the rotated vector, `bits` per dimension with the dimension
+    ///   padded to a whole number of bytes, followed by per-vector extras depending on distance
+    ///   and mode.
+    pub bytes: Vec<u8>,
+}
+
+impl From<QuantizedVectorRecord> for QuantizedVectorResponse {
+    fn from(record: QuantizedVectorRecord) -> Self {
+        let QuantizedVectorRecord { config, bytes } = record;
+        Self {
+            quantization: config.quantization_config,
+            dim: config.vector_parameters.dim,
+            bytes,
+        }
+    }
+}
+
+/// Load the payload field indexes of the collection on this peer into the page cache
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct WarmupRequest {
+    /// Max number of bytes to load. The storage, which doesn't fit, is loaded partially.
+    /// Default is unlimited.
+    pub budget_bytes: Option<usize>,
+    /// Payload fields to load the indexes of, most important first. Lookup structures of the
+    /// indexes, like dictionaries, are loaded for all fields before the rest of the data.
+    /// Default is all indexed fields, in the order of their keys.
+    pub fields: Option<Vec<PayloadKeyType>>,
+}
+
+/// Amount of the payload field indexes loaded into the page cache
+#[derive(Debug, Serialize, JsonSchema, Clone)]
+pub struct WarmupResponse {
+    /// Bytes loaded into the page cache
+    pub warmed_bytes: usize,
+    /// Bytes of the indexes of the fields, including the loaded ones
+    pub total_bytes: usize,
+}
+
+impl From<PopulateReport> for WarmupResponse {
+    fn from(report: PopulateReport) -> Self {
+        let PopulateReport {
+            warmed_bytes,
+            total_bytes,
+        } = report;
+        Self {
+            warmed_bytes,
+            total_bytes,
+        }
+    }
+}
+
 /// Current state of the collection
 #[derive(
     Debug, Default, Serialize, JsonSchema, Anonymize, PartialEq, Eq, PartialOrd, Ord, Clone,
@@ -177,6 +278,9 @@ pub struct CollectionConfig {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Boost rules applied to the scores of query results
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub boost_rules: BoostRules,
 }
 
 impl From<CollectionConfigInternal> for CollectionConfig {
@@ -191,6 +295,7 @@ impl From<CollectionConfigInternal> for CollectionConfig {
             // Internal UUID to identify unique collections in consensus snapshots
             uuid: _,
             metadata,
+            boost_rules,
         } = config;
 
         CollectionConfig {
@@ -201,6 +306,7 @@ impl From<CollectionConfigInternal> for CollectionConfig {
             quantization_config,
             strict_mode_config: strict_mode_config.map(StrictModeConfigOutput::from),
             metadata,
+            boost_rules,
         }
     }
 }
@@ -411,6 +517,10 @@ pub struct LocalShardInfo {
     pub points_count: usize,
     /// Is replica active
     pub state: ReplicaState,
+    /// Version of the last operation applied to this replica.
+    /// Use it in the `updated_after` condition to find points updated since this request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_version: Option<SeqNumberType>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -489,6 +599,11 @@ pub struct ScrollRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Return the similarity of each of the points to this vector as its `score`.
+    /// The order of the points is not affected.
+    #[validate(nested)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_with: Option<NamedVectorStruct>,
 }
 
 fn points_example() -> Vec<api::rest::Record> {
@@ -507,6 +622,7 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.875, 0.140625, 0.897_6])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            score: None,
         },
         api::rest::Record {
             id: PointIdType::NumId(41),
@@ -514,6 +630,7 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.75, 0.640625, 0.8945])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            score: None,
         },
     ]
 }
@@ -579,6 +696,40 @@ pub struct PointRequestInternal {
     pub with_vector: WithVector,
 }
 
+/// Check which points exist, without retrieving their payloads or vectors
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct PointsExistenceRequest {
+    /// Ids of the points to check
+    pub ids: Vec<PointIdType>,
+    /// Return the version of each existing point. Default is false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_version: Option<bool>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+/// Whether a requested point exists
+#[derive(Debug, Serialize, JsonSchema, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PointExistence {
+    /// Id of the requested point
+    pub id: PointIdType,
+    /// Whether the point exists in the collection
+    pub exists: bool,
+    /// Version of the point, if it exists and the version was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<SeqNumberType>,
+}
+
+/// Latest version of an existing point in a shard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointVersion {
+    pub id: PointIdType,
+    pub version: SeqNumberType,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum RecommendExample {
@@ -945,6 +1096,8 @@ pub enum CollectionError {
     },
     #[error("Shard temporarily unavailable: {description}")]
     ShardUnavailable { description: String },
+    #[error("{description}")]
+    Conflict { description: String },
 }
 
 impl CollectionError {
@@ -1051,6 +1204,12 @@ impl CollectionError {
         }
     }
 
+    pub fn conflict(description: impl Into<String>) -> Self {
+        Self::Conflict {
+            description: description.into(),
+        }
+    }
+
     /// Returns true if the error is transient and the operation can be retried.
     /// Returns false if the error is not transient and the operation should fail on all replicas.
     pub fn is_transient(&self) -> bool {
@@ -1073,6 +1232,7 @@ impl CollectionError {
             Self::StrictMode { .. } => false,
             Self::InferenceError { .. } => false,
             Self::RateLimitExceeded { .. } => false,
+            Self::Conflict { .. } => false,
         }
     }
 
@@ -1145,9 +1305,11 @@ impl From<OperationError> for CollectionError {
                 description: "Conversion between multi and regular vectors failed".to_string(),
             },
             OperationError::MissingRangeIndexForOrderBy { .. } => Self::bad_input(err.to_string()),
+            OperationError::MissingRangeIndexForSample { .. } => Self::bad_input(err.to_string()),
             OperationError::MissingMapIndexForFacet { .. } => Self::bad_input(err.to_string()),
             OperationError::VariableTypeError { .. } => Self::bad_input(err.to_string()),
             OperationError::NonFiniteNumber { .. } => Self::bad_input(err.to_string()),
+            OperationError::VersionConflict { .. } => Self::conflict(err.to_string()),
         }
     }
 }
@@ -1239,6 +1401,9 @@ impl From<tonic::Status> for CollectionError {
                     retry_after,
                 }
             }
+            tonic::Code::Aborted if is_version_conflict(&err) => Self::conflict(err.message()),
+            // Shard unavailable rejections of other peers are not converted back, so this peer
+            // doesn't mark its own response, and its callers don't retry the request yet again
             tonic::Code::Ok
             | tonic::Code::Unknown
             | tonic::Code::PermissionDenied
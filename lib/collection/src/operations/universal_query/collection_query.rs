@@ -1,15 +1,18 @@
+use std::time::Duration;
+
 use ahash::AHashSet;
 use api::rest::LookupLocation;
 use common::types::ScoreType;
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::text_query::TextCondition;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal, VectorRef};
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::json_path::JsonPath;
 use segment::types::{
-    Condition, ExtendedPointId, Filter, HasIdCondition, PointIdType, SearchParams, VectorName,
-    VectorNameBuf, WithPayloadInterface, WithVector,
+    Condition, ExtendedPointId, Filter, HasIdCondition, HasVectorCondition, PointIdType,
+    SearchParams, VectorName, VectorNameBuf, WithPayloadInterface, WithVector,
 };
 use segment::vector_storage::query::{
     ContextPair, ContextQuery, DiscoverQuery, FeedbackItem, NaiveFeedbackCoefficients, RecoQuery,
@@ -44,6 +47,16 @@ pub struct CollectionQueryRequest {
     pub with_vector: WithVector,
     pub with_payload: WithPayloadInterface,
     pub lookup_from: Option<LookupLocation>,
+    /// Apply the boost rules of the collection to the scores of the results
+    pub apply_boost_rules: bool,
+    /// Use the default score threshold of the collection, if `score_threshold` is not set
+    pub apply_default_score_threshold: bool,
+    /// Keep the segment, shard and peer, which scored each of the points
+    pub with_provenance: bool,
+    /// Record the stages, which composed the score of each of the points
+    pub with_explanation: bool,
+    /// Return the results of the segments searched until then, instead of searching all of them
+    pub soft_timeout: Option<Duration>,
 }
 
 impl CollectionQueryRequest {
@@ -56,6 +69,41 @@ impl CollectionQueryRequest {
     pub const DEFAULT_WITH_VECTOR: WithVector = WithVector::Bool(false);
 
     pub const DEFAULT_WITH_PAYLOAD: WithPayloadInterface = WithPayloadInterface::Bool(false);
+
+    pub const DEFAULT_APPLY_BOOST_RULES: bool = true;
+
+    pub const DEFAULT_APPLY_DEFAULT_SCORE_THRESHOLD: bool = true;
+
+    pub const DEFAULT_WITH_PROVENANCE: bool = false;
+
+    pub const DEFAULT_WITH_EXPLANATION: bool = false;
+
+    /// Request of the nearest neighbors of the stored point `seed`, by its `using` vector.
+    ///
+    /// Keeps the filter, the search params and the output options of this request. The seed is
+    /// excluded from the results, as any other point referenced by a query.
+    pub fn nearest_neighbors_of(&self, seed: PointIdType, limit: usize) -> Self {
+        Self {
+            prefetch: Vec::new(),
+            query: Some(Query::Vector(VectorQuery::Nearest(
+                VectorInputInternal::Id(seed),
+            ))),
+            using: self.using.clone(),
+            filter: self.filter.clone(),
+            score_threshold: None,
+            limit,
+            offset: 0,
+            params: self.params,
+            with_vector: self.with_vector.clone(),
+            with_payload: self.with_payload.clone(),
+            lookup_from: None,
+            apply_boost_rules: false,
+            apply_default_score_threshold: false,
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: self.soft_timeout,
+        }
+    }
 }
 
 /// Lightweight representation of a query request to implement the [`RetrieveRequest`] trait.
@@ -534,6 +582,25 @@ fn exclude_referenced_ids(ids: Vec<ExtendedPointId>, filter: Option<Filter>) ->
     Some(id_filter.merge_owned(filter.unwrap_or_default()))
 }
 
+/// Order-by queries are not scored against the `using` vector, it only filters the points.
+///
+/// Points without this vector are excluded, the order of the results is defined by the
+/// payload field alone. The default vector name is not used as a filter.
+fn order_by_vector_filter(
+    query: &Option<Query>,
+    using: &VectorName,
+    filter: Option<Filter>,
+) -> Option<Filter> {
+    if !matches!(query, Some(Query::OrderBy(_))) || using == DEFAULT_VECTOR_NAME {
+        return filter;
+    }
+
+    let vector_filter = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+        using.to_owned(),
+    )));
+    Some(vector_filter.merge_owned(filter.unwrap_or_default()))
+}
+
 impl CollectionPrefetch {
     fn get_lookup_collection(&self) -> Option<&String> {
         self.lookup_from.as_ref().map(|x| &x.collection)
@@ -569,6 +636,18 @@ impl CollectionPrefetch {
         refs
     }
 
+    fn text_conditions(&self) -> Vec<TextCondition> {
+        let mut conditions = self
+            .filter
+            .as_ref()
+            .map(TextCondition::collect_from)
+            .unwrap_or_default();
+        for prefetch in &self.prefetch {
+            conditions.extend(prefetch.text_conditions());
+        }
+        conditions
+    }
+
     fn try_into_shard_prefetch(
         self,
         ids_to_vectors: &ReferencedVectors,
@@ -584,6 +663,8 @@ impl CollectionPrefetch {
         let lookup_collection = self.get_lookup_collection().cloned();
         let using = self.using.clone();
 
+        let filter = order_by_vector_filter(&self.query, &self.using, self.filter);
+
         let query = self
             .query
             .map(|query| {
@@ -606,7 +687,7 @@ impl CollectionPrefetch {
         Ok(ShardPrefetch {
             prefetches,
             query,
-            filter: self.filter,
+            filter,
             score_threshold: self.score_threshold,
             limit: self.limit,
             params: self.params,
@@ -642,6 +723,19 @@ impl CollectionPrefetch {
 }
 
 impl CollectionQueryRequest {
+    /// Full-text conditions of the filters of the request and its prefetches, without repeats
+    pub fn text_conditions(&self) -> Vec<TextCondition> {
+        let mut conditions = self
+            .filter
+            .as_ref()
+            .map(TextCondition::collect_from)
+            .unwrap_or_default();
+        for prefetch in &self.prefetch {
+            conditions.extend(prefetch.text_conditions());
+        }
+        conditions.into_iter().unique().collect()
+    }
+
     fn get_lookup_collection(&self) -> Option<&String> {
         self.lookup_from.as_ref().map(|x| &x.collection)
     }
@@ -706,6 +800,7 @@ impl CollectionQueryRequest {
         let referenced_point_ids = self.get_referenced_point_ids_on_collection(collection_name);
 
         let filter = exclude_referenced_ids(referenced_point_ids, self.filter);
+        let filter = order_by_vector_filter(&self.query, &self.using, filter);
 
         let query = self
             .query
@@ -736,6 +831,9 @@ impl CollectionQueryRequest {
             params: self.params,
             with_vector: self.with_vector,
             with_payload: self.with_payload,
+            with_provenance: self.with_provenance,
+            with_explanation: self.with_explanation,
+            soft_timeout: self.soft_timeout,
         })
     }
 
@@ -33,6 +33,8 @@ pub fn query_result_order(
             ScoringQuery::OrderBy(order_by) => Some(Order::from(order_by.direction())),
             // Random sample does not require ordering
             ScoringQuery::Sample(SampleInternal::Random) => None,
+            // Larger sampling keys are sampled first
+            ScoringQuery::Sample(SampleInternal::Weighted(_)) => Some(Order::LargeBetter),
             // MMR cannot be reordered
             ScoringQuery::Mmr(_) => None,
         },
@@ -24,6 +24,10 @@ impl StrictModeVerification for DiscoverRequestInternal {
     fn request_search_params(&self) -> Option<&SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
 
 impl StrictModeVerification for DiscoverRequestBatch {
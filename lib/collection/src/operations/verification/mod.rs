@@ -10,6 +10,7 @@ mod update;
 
 use std::fmt::Display;
 
+use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
 use segment::json_path::JsonPath;
 use segment::types::{Filter, SearchParams, StrictModeConfig};
@@ -72,6 +73,12 @@ pub trait StrictModeVerification {
 
     fn request_search_params(&self) -> Option<&SearchParams>;
 
+    /// Whether the request acknowledges, that its read filter might match a large part of the
+    /// collection. Implement this for requests, which are subject to `filter_max_selectivity`.
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        None
+    }
+
     /// Checks the 'exact' parameter.
     fn check_request_exact(&self, strict_mode_config: &StrictModeConfig) -> CollectionResult<()> {
         check_bool_opt(
@@ -157,6 +164,21 @@ pub trait StrictModeVerification {
         Ok(())
     }
 
+    /// Checks that the read filter is selective enough, unless the request allows a full scan.
+    /// A request without a filter matches all points.
+    #[allow(async_fn_in_trait)]
+    async fn check_request_filter_selectivity(
+        &self,
+        collection: &Collection,
+        strict_mode_config: &StrictModeConfig,
+    ) -> CollectionResult<()> {
+        if self.request_allow_full_scan() != Some(false) {
+            return Ok(());
+        }
+
+        check_filter_selectivity(self.indexed_filter_read(), collection, strict_mode_config).await
+    }
+
     /// Does the verification of all configured parameters. Only implement this function if you know what
     /// you are doing. In most cases implementing `check_custom` is sufficient.
     #[allow(async_fn_in_trait)]
@@ -168,6 +190,8 @@ pub trait StrictModeVerification {
         self.check_custom(collection, strict_mode_config).await?;
         self.check_request_query_limit(strict_mode_config)?;
         self.check_request_filter(collection, strict_mode_config)?;
+        self.check_request_filter_selectivity(collection, strict_mode_config)
+            .await?;
         self.check_request_exact(strict_mode_config)?;
         self.check_search_params(collection, strict_mode_config)
             .await?;
@@ -210,6 +234,46 @@ fn check_filter_limits(
     Ok(())
 }
 
+/// Reject the `filter`, if it is estimated to match a larger fraction of the collection than
+/// allowed by `filter_max_selectivity`. Only logs a warning in the warn-only mode.
+async fn check_filter_selectivity(
+    filter: Option<&Filter>,
+    collection: &Collection,
+    strict_mode_config: &StrictModeConfig,
+) -> CollectionResult<()> {
+    let Some(max_selectivity) = strict_mode_config.filter_max_selectivity else {
+        return Ok(());
+    };
+
+    let hw_measurement_acc = HwMeasurementAcc::disposable();
+    let Some(selectivity) = collection
+        .estimate_filter_ratio(filter, &hw_measurement_acc)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    if selectivity <= max_selectivity {
+        return Ok(());
+    }
+
+    let error = CollectionError::strict_mode(
+        format!(
+            "Filter is not selective enough: estimated to match {:.1}% of the points, exceeding the configured limit of {:.1}%",
+            selectivity * 100.0,
+            max_selectivity * 100.0,
+        ),
+        "Use a more selective filter or set `allow_full_scan` in the search params.",
+    );
+
+    if strict_mode_config.filter_selectivity_warn_only == Some(true) {
+        log::warn!("Collection {}: {error}", collection.name());
+        return Ok(());
+    }
+
+    Err(error)
+}
+
 pub fn check_timeout(
     timeout: usize,
     strict_mode_config: &StrictModeConfig,
@@ -594,6 +658,8 @@ mod test {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            expected_version: None,
+            duplicate_ids: None,
         });
         assert!(insert.consumes_memory());
 
@@ -643,6 +709,8 @@ mod test {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            expected_version: None,
+            duplicate_ids: None,
         });
         assert_strict_mode_error(request, collection).await;
 
@@ -658,6 +726,8 @@ mod test {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            expected_version: None,
+            duplicate_ids: None,
         });
         assert_strict_mode_success(request, collection).await;
     }
@@ -755,6 +825,7 @@ mod test {
             strict_mode_config: Some(strict_mode_config.clone()),
             uuid: None,
             metadata: None,
+            boost_rules: Default::default(),
         };
 
         let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
@@ -182,6 +182,10 @@ impl StrictModeVerification for CollectionQueryRequest {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
 
 impl StrictModeVerification for CollectionPrefetch {
@@ -231,6 +235,10 @@ impl StrictModeVerification for CollectionPrefetch {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
 
 impl StrictModeVerification for CollectionQueryGroupsRequest {
@@ -278,4 +286,8 @@ impl StrictModeVerification for CollectionQueryGroupsRequest {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
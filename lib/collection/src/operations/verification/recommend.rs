@@ -23,6 +23,10 @@ impl StrictModeVerification for RecommendRequestInternal {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
 
 impl StrictModeVerification for RecommendGroupsRequestInternal {
@@ -45,4 +49,8 @@ impl StrictModeVerification for RecommendGroupsRequestInternal {
     fn request_search_params(&self) -> Option<&segment::types::SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
@@ -22,6 +22,10 @@ impl StrictModeVerification for SearchRequestInternal {
         self.params.as_ref()
     }
 
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
+
     fn request_exact(&self) -> Option<bool> {
         None
     }
@@ -47,6 +51,10 @@ impl StrictModeVerification for CoreSearchRequest {
     fn request_search_params(&self) -> Option<&SearchParams> {
         self.params.as_ref()
     }
+
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
 }
 
 impl StrictModeVerification for SearchRequestBatch {
@@ -119,6 +127,10 @@ impl StrictModeVerification for SearchGroupsRequestInternal {
         self.params.as_ref()
     }
 
+    fn request_allow_full_scan(&self) -> Option<bool> {
+        Some(self.params.is_some_and(|params| params.allow_full_scan))
+    }
+
     fn indexed_filter_write(&self) -> Option<&Filter> {
         None
     }
@@ -199,12 +199,16 @@ impl StrictModeVerification for PointInsertOperations {
                 shard_key: _,
                 update_filter: _,
                 update_mode: _,
+                expected_version: _,
+                duplicate_ids: _,
             }) => None,
             PointInsertOperations::PointsList(PointsList {
                 points: _,
                 shard_key: _,
                 update_filter: _,
                 update_mode: _,
+                expected_version: _,
+                duplicate_ids: _,
             }) => None,
         }
     }
@@ -1,6 +1,7 @@
 use std::num::NonZeroUsize;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common::types::PointOffsetType;
 use fs_err as fs;
@@ -11,10 +12,11 @@ use serde::{Deserialize, Serialize};
 use shard::files::SEGMENTS_PATH;
 use shard::operations::optimization::OptimizerThresholds;
 use shard::optimizers::config::{
-    DEFAULT_DELETED_THRESHOLD, DEFAULT_VACUUM_MIN_VECTOR_NUMBER, DenseVectorOptimizerInput,
-    SegmentOptimizerConfig, SparseVectorOptimizerInput, TEMP_SEGMENTS_PATH,
-    get_deferred_points_threshold_bytes, get_indexing_threshold_kb, get_max_segment_size_kb,
-    get_number_segments,
+    DEFAULT_DELETED_THRESHOLD, DEFAULT_REQUANTIZATION_CHECK_INTERVAL_SEC,
+    DEFAULT_REQUANTIZATION_MAX_DISTORTION, DEFAULT_VACUUM_MIN_VECTOR_NUMBER,
+    DenseVectorOptimizerInput, SegmentOptimizerConfig, SparseVectorOptimizerInput,
+    TEMP_SEGMENTS_PATH, get_deferred_points_threshold_bytes, get_indexing_threshold_kb,
+    get_max_segment_size_kb, get_number_segments,
 };
 use shard::optimizers::segment_optimizer::max_num_indexing_threads;
 use validator::Validate;
@@ -22,6 +24,7 @@ use validator::Validate;
 use crate::collection_manager::optimizers::config_mismatch_optimizer::ConfigMismatchOptimizer;
 use crate::collection_manager::optimizers::indexing_optimizer::IndexingOptimizer;
 use crate::collection_manager::optimizers::merge_optimizer::MergeOptimizer;
+use crate::collection_manager::optimizers::requantization_optimizer::RequantizationOptimizer;
 use crate::collection_manager::optimizers::vacuum_optimizer::VacuumOptimizer;
 use crate::config::CollectionParams;
 use crate::operations::config_diff::DiffConfig;
@@ -98,6 +101,52 @@ pub struct OptimizersConfig {
     /// Default is disabled.
     #[serde(default)]
     pub prevent_unoptimized: Option<bool>,
+
+    /// Re-quantize segments, which quantized ranking drifted away from the exact one.
+    /// Default is disabled.
+    #[serde(default)]
+    #[validate(nested)]
+    pub requantization: Option<RequantizationConfig>,
+}
+
+/// Rebuild of segments, which quantization no longer fits their data.
+///
+/// Distortion of the quantized ranking is observed in searches with rescoring and oversampling.
+/// Once its average in a segment exceeds `max_distortion`, the segment is rebuilt in background,
+/// which fits the quantization to the current data. Binary quantization doesn't depend on the
+/// data, so it is never rebuilt. A rebuilt segment is only rebuilt again once its distortion grows
+/// over the one which triggered its rebuild.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, Copy, PartialEq,
+)]
+#[anonymize(false)]
+pub struct RequantizationConfig {
+    /// Enable re-quantization. Default: false
+    #[serde(default)]
+    pub enable: bool,
+    /// Maximum average fraction of the rescored results, which were not in the top by quantized
+    /// score, before the segment is re-quantized. Default: 0.3
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub max_distortion: Option<f64>,
+    /// Interval between checks of the distortion, in seconds. Default: 600
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub check_interval_sec: Option<u64>,
+}
+
+impl std::hash::Hash for RequantizationConfig {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            enable,
+            max_distortion,
+            check_interval_sec,
+        } = self;
+
+        enable.hash(state);
+        max_distortion.map(f64::to_le_bytes).hash(state);
+        check_interval_sec.hash(state);
+    }
 }
 
 fn default_deleted_threshold() -> f64 {
@@ -122,6 +171,7 @@ impl OptimizersConfig {
             flush_interval_sec: 60,
             max_optimization_threads: Some(0),
             prevent_unoptimized: None,
+            requantization: None,
         }
     }
 
@@ -268,7 +318,7 @@ pub fn build_optimizers(
         ),
     );
 
-    Arc::new(vec![
+    let mut optimizers: Vec<Arc<Optimizer>> = vec![
         Arc::new(MergeOptimizer::new(
             optimizers_config.get_number_segments(),
             threshold_config,
@@ -295,12 +345,34 @@ pub fn build_optimizers(
             hnsw_global_config.clone(),
         )),
         Arc::new(ConfigMismatchOptimizer::new(
+            threshold_config,
+            segments_path.clone(),
+            temp_segments_path.clone(),
+            segment_config.clone(),
+            *hnsw_config,
+            hnsw_global_config.clone(),
+        )),
+    ];
+
+    if let Some(requantization) = optimizers_config.requantization
+        && requantization.enable
+    {
+        optimizers.push(Arc::new(RequantizationOptimizer::new(
+            requantization
+                .max_distortion
+                .unwrap_or(DEFAULT_REQUANTIZATION_MAX_DISTORTION),
+            Duration::from_secs(
+                requantization
+                    .check_interval_sec
+                    .unwrap_or(DEFAULT_REQUANTIZATION_CHECK_INTERVAL_SEC),
+            ),
             threshold_config,
             segments_path,
             temp_segments_path,
             segment_config,
-            *hnsw_config,
             hnsw_global_config.clone(),
-        )),
-    ])
+        )));
+    }
+
+    Arc::new(optimizers)
 }
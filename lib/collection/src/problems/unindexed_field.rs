@@ -7,6 +7,7 @@ use http::header::CONTENT_TYPE;
 use http::{HeaderMap, HeaderValue, Method, Uri};
 use issues::{Action, Code, ImmediateSolution, Issue, Solution};
 use itertools::Itertools;
+use schemars::JsonSchema;
 use segment::common::operation_error::OperationError;
 use segment::data_types::index::{TextIndexParams, TextIndexType};
 use segment::index::query_optimization::rescore_formula::parsed_formula::VariableId;
@@ -15,6 +16,7 @@ use segment::types::{
     AnyVariants, Condition, FieldCondition, Filter, Match, MatchValue, PayloadFieldSchema,
     PayloadKeyType, PayloadSchemaParams, PayloadSchemaType, RangeInterface, UuidPayloadType,
 };
+use serde::Serialize;
 use strum::{EnumIter, IntoEnumIterator as _};
 
 use crate::operations::universal_query::formula::ExpressionInternal;
@@ -218,6 +220,7 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
         geo_bounding_box,
         geo_radius,
         geo_polygon,
+        geo_near_path,
         values_count,
         is_empty,
         is_null,
@@ -232,7 +235,11 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
             Match::Phrase(_match_text) => vec![FieldIndexType::TextPhrase],
             Match::Any(match_any) => infer_index_from_any_variants(&match_any.any),
             Match::Except(match_except) => infer_index_from_any_variants(&match_except.except),
+            Match::ExactSet(match_exact_set) => {
+                infer_index_from_any_variants(&match_exact_set.exact_set)
+            }
             Match::TextAny(_match_text_any) => vec![FieldIndexType::Text],
+            Match::KeywordPrefix(_match_prefix) => vec![FieldIndexType::KeywordMatch],
         })
     }
     if let Some(range_interface) = range {
@@ -244,9 +251,16 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
                 required_indexes.push(FieldIndexType::FloatRange);
                 required_indexes.push(FieldIndexType::IntRange);
             }
+            RangeInterface::Uuid(_) => {
+                required_indexes.push(FieldIndexType::UuidRange);
+            }
         }
     }
-    if geo_bounding_box.is_some() || geo_radius.is_some() || geo_polygon.is_some() {
+    if geo_bounding_box.is_some()
+        || geo_radius.is_some()
+        || geo_polygon.is_some()
+        || geo_near_path.is_some()
+    {
         required_indexes.push(FieldIndexType::Geo);
     }
     if values_count.is_some() || is_empty.is_some() || is_null.is_some() {
@@ -257,6 +271,122 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
     required_indexes
 }
 
+/// Key and acceptable indexes of a leaf condition.
+///
+/// Returns `None` for compound conditions (`Filter`, `Nested`) and for conditions which
+/// don't need any payload index.
+fn infer_index_from_leaf_condition(
+    condition: &Condition,
+) -> Option<(&JsonPath, Vec<FieldIndexType>)> {
+    match condition {
+        Condition::Field(field_condition) => Some((
+            &field_condition.key,
+            infer_index_from_field_condition(field_condition),
+        )),
+        // Any index will suffice to get the satellite null index
+        Condition::IsEmpty(is_empty) => Some((&is_empty.is_empty.key, all_indexes().collect())),
+        Condition::IsNull(is_null) => Some((&is_null.is_null.key, all_indexes().collect())),
+        // Handled by the caller
+        Condition::Filter(_) | Condition::Nested(_) => None,
+        // No index needed
+        Condition::HasId(_) => None,
+        Condition::CustomIdChecker(_) => None,
+        Condition::HasVector(_) => None,
+        Condition::UpdatedAfter(_) => None,
+        // Uses several keys, any numeric index of them narrows down the candidates
+        Condition::Expression(_) => None,
+    }
+}
+
+/// Problem found for a single filter condition by [`validate_filter`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum FilterConditionProblem {
+    /// There is no payload index on the field
+    NotIndexed,
+    /// The field is indexed, but the index can't serve this condition
+    IncompatibleIndex { indexed: PayloadSchemaType },
+}
+
+/// Diagnostic for a single filter condition, produced by [`validate_filter`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct FilterConditionDiagnostic {
+    /// Full payload key of the condition, including nested prefixes
+    pub key: JsonPath,
+    pub problem: FilterConditionProblem,
+    /// Index types which would be able to serve the condition
+    pub acceptable_schemas: Vec<PayloadSchemaType>,
+}
+
+/// Checks every condition of the `filter` against the `payload_schema`, without executing it.
+///
+/// Returns one diagnostic per condition which can't be served by a payload index.
+/// Uses the same capability table as the unindexed field detection.
+pub fn validate_filter(
+    filter: &Filter,
+    payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+) -> Vec<FilterConditionDiagnostic> {
+    let mut diagnostics = Vec::new();
+    validate_filter_rec(None, filter, payload_schema, &mut diagnostics);
+    diagnostics
+}
+
+fn validate_filter_rec(
+    nested_prefix: Option<&JsonPath>,
+    filter: &Filter,
+    payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    diagnostics: &mut Vec<FilterConditionDiagnostic>,
+) {
+    for condition in filter.iter_conditions() {
+        match condition {
+            Condition::Filter(filter) => {
+                validate_filter_rec(nested_prefix, filter, payload_schema, diagnostics);
+                continue;
+            }
+            Condition::Nested(nested) => {
+                let prefix = JsonPath::extend_or_new(nested_prefix, &nested.raw_key().array_key());
+                validate_filter_rec(Some(&prefix), nested.filter(), payload_schema, diagnostics);
+                continue;
+            }
+            _ => {}
+        }
+
+        let Some((key, required_indexes)) = infer_index_from_leaf_condition(condition) else {
+            continue;
+        };
+
+        let full_key = JsonPath::extend_or_new(nested_prefix, key);
+
+        let problem = match payload_schema.get(&full_key) {
+            Some(index_info) => {
+                let capabilities = schema_capabilities(index_info);
+                if required_indexes
+                    .iter()
+                    .any(|required| capabilities.contains(required))
+                {
+                    continue;
+                }
+                FilterConditionProblem::IncompatibleIndex {
+                    indexed: index_info.kind(),
+                }
+            }
+            None => FilterConditionProblem::NotIndexed,
+        };
+
+        let acceptable_schemas = required_indexes
+            .into_iter()
+            .map(|index| PayloadFieldSchema::from(index).kind())
+            .unique()
+            .collect();
+
+        diagnostics.push(FilterConditionDiagnostic {
+            key: full_key,
+            problem,
+            acceptable_schemas,
+        });
+    }
+}
+
 pub struct IssueExtractor<'a> {
     extractor: Extractor<'a>,
     collection_name: String,
@@ -297,6 +427,15 @@ impl<'a> IssueExtractor<'a> {
     }
 }
 
+/// Fields of the filter without a suitable index in the `payload_schema`, with the index schemas
+/// which would serve the conditions on them
+pub fn unindexed_filter_fields(
+    filter: &Filter,
+    payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+) -> HashMap<PayloadKeyType, Vec<PayloadFieldSchema>> {
+    Extractor::new_eager(filter, payload_schema).unindexed_schema
+}
+
 pub struct Extractor<'a> {
     payload_schema: &'a HashMap<PayloadKeyType, PayloadFieldSchema>,
     unindexed_schema: HashMap<PayloadKeyType, Vec<PayloadFieldSchema>>,
@@ -349,14 +488,7 @@ impl<'a> Extractor<'a> {
     }
 
     fn update_from_condition(&mut self, nested_prefix: Option<&JsonPath>, condition: &Condition) {
-        let key;
-        let required_index;
-
         match condition {
-            Condition::Field(field_condition) => {
-                key = &field_condition.key;
-                required_index = infer_index_from_field_condition(field_condition);
-            }
             Condition::Filter(filter) => {
                 self.update_from_filter(nested_prefix, filter);
                 return;
@@ -371,19 +503,11 @@ impl<'a> Extractor<'a> {
                 );
                 return;
             }
-            // Any index will suffice to get the satellite null index
-            Condition::IsEmpty(is_empty) => {
-                key = &is_empty.is_empty.key;
-                required_index = all_indexes().collect();
-            }
-            Condition::IsNull(is_null) => {
-                key = &is_null.is_null.key;
-                required_index = all_indexes().collect();
-            }
-            // No index needed
-            Condition::HasId(_) => return,
-            Condition::CustomIdChecker(_) => return,
-            Condition::HasVector(_) => return,
+            _ => {}
+        }
+
+        let Some((key, required_index)) = infer_index_from_leaf_condition(condition) else {
+            return;
         };
 
         let full_key = JsonPath::extend_or_new(nested_prefix, key);
@@ -447,6 +571,14 @@ impl<'a> Extractor<'a> {
                 required_index = vec![FieldIndexType::Geo];
             }
             ExpressionInternal::Datetime(_) => return,
+            ExpressionInternal::TextScore {
+                key: text_key,
+                text: _,
+                aggregation: _,
+            } => {
+                key = text_key.clone();
+                required_index = vec![FieldIndexType::Text];
+            }
             ExpressionInternal::DatetimeKey(variable) => {
                 key = variable.clone();
                 required_index = vec![FieldIndexType::DatetimeRange];
@@ -629,6 +761,7 @@ impl From<FieldIndexType> for PayloadFieldSchema {
 
 #[cfg(test)]
 mod tests {
+    use ordered_float::OrderedFloat;
     use segment::data_types::index::IntegerIndexParams;
 
     use super::*;
@@ -645,4 +778,66 @@ mod tests {
         assert!(index_types.contains(&FieldIndexType::IntMatch));
         assert!(index_types.contains(&FieldIndexType::IntRange));
     }
+
+    #[test]
+    fn validate_filter_diagnostics() {
+        let indexed_keyword: JsonPath = "city".parse().unwrap();
+        let indexed_text: JsonPath = "description".parse().unwrap();
+        let unindexed: JsonPath = "price".parse().unwrap();
+
+        let payload_schema = HashMap::from([
+            (
+                indexed_keyword.clone(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+            ),
+            (
+                indexed_text.clone(),
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Text),
+            ),
+        ]);
+
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            indexed_keyword.clone(),
+            Match::new_value(segment::types::ValueVariants::String("Berlin".to_string())),
+        )));
+        assert!(validate_filter(&filter, &payload_schema).is_empty());
+
+        let filter = Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![
+                Condition::Field(FieldCondition::new_match(
+                    indexed_text.clone(),
+                    Match::new_value(segment::types::ValueVariants::Integer(42)),
+                )),
+                Condition::Field(FieldCondition::new_range(
+                    unindexed.clone(),
+                    segment::types::Range {
+                        gte: Some(OrderedFloat(1.0)),
+                        ..Default::default()
+                    },
+                )),
+            ]),
+            must_not: None,
+        };
+
+        let diagnostics = validate_filter(&filter, &payload_schema);
+        assert_eq!(
+            diagnostics,
+            vec![
+                FilterConditionDiagnostic {
+                    key: indexed_text,
+                    problem: FilterConditionProblem::IncompatibleIndex {
+                        indexed: PayloadSchemaType::Text,
+                    },
+                    acceptable_schemas: vec![PayloadSchemaType::Integer],
+                },
+                FilterConditionDiagnostic {
+                    key: unindexed,
+                    problem: FilterConditionProblem::NotIndexed,
+                    acceptable_schemas: vec![PayloadSchemaType::Float, PayloadSchemaType::Integer],
+                },
+            ],
+        );
+    }
 }
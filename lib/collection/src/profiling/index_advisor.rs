@@ -0,0 +1,405 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use parking_lot::Mutex;
+use schemars::JsonSchema;
+use segment::common::anonymize::Anonymize;
+use segment::types::{
+    Filter, PayloadFieldSchema, PayloadKeyType, PayloadSchemaParams, PayloadSchemaType,
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::problems::unindexed_field::unindexed_filter_fields;
+
+static INDEX_ADVISOR_CONFIG: OnceLock<IndexAdvisorConfig> = OnceLock::new();
+
+#[derive(Debug, Deserialize, Validate, Clone)]
+pub struct IndexAdvisorConfig {
+    /// Track fields filtered without a payload index, and recommend indexes for them.
+    /// Default: false
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Filters on an unindexed field, after which an index is recommended for it. Default: 100
+    #[serde(default = "default_min_filter_count")]
+    #[validate(range(min = 1))]
+    pub min_filter_count: usize,
+
+    /// Automatically create recommended indexes. Default: false
+    #[serde(default)]
+    pub auto_create: bool,
+
+    /// Estimated RAM in megabytes, which recommended indexes may use in total.
+    /// Indexes exceeding the budget are recommended, and created, on disk. Default: 512
+    #[serde(default = "default_memory_budget_mb")]
+    pub memory_budget_mb: usize,
+
+    /// Interval of creating recommended indexes, in seconds. Default: 60
+    #[serde(default = "default_auto_create_interval_sec")]
+    #[validate(range(min = 1))]
+    pub auto_create_interval_sec: u64,
+
+    /// Max number of indexes to create automatically in each collection. Default: 8
+    #[serde(default = "default_max_auto_created_indexes")]
+    pub max_auto_created_indexes: usize,
+}
+
+const fn default_min_filter_count() -> usize {
+    100
+}
+
+const fn default_memory_budget_mb() -> usize {
+    512
+}
+
+const fn default_auto_create_interval_sec() -> u64 {
+    60
+}
+
+const fn default_max_auto_created_indexes() -> usize {
+    8
+}
+
+impl Default for IndexAdvisorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_filter_count: default_min_filter_count(),
+            auto_create: false,
+            memory_budget_mb: default_memory_budget_mb(),
+            auto_create_interval_sec: default_auto_create_interval_sec(),
+            max_auto_created_indexes: default_max_auto_created_indexes(),
+        }
+    }
+}
+
+impl IndexAdvisorConfig {
+    pub fn memory_budget_bytes(&self) -> usize {
+        self.memory_budget_mb.saturating_mul(1024 * 1024)
+    }
+}
+
+/// This function initializes the index advisor.
+///
+/// It should be called once during the application startup, the advisor is disabled otherwise.
+pub fn init_index_advisor(config: IndexAdvisorConfig) {
+    if config.enabled {
+        log::info!(
+            "Index advisor enabled, min filter count: {}, auto create: {}",
+            config.min_filter_count,
+            config.auto_create,
+        );
+    }
+
+    if INDEX_ADVISOR_CONFIG.set(config).is_err() {
+        log::warn!("Index advisor is already initialized");
+    }
+}
+
+/// Returns the index advisor configuration, if the advisor is enabled
+pub fn index_advisor_config() -> Option<&'static IndexAdvisorConfig> {
+    INDEX_ADVISOR_CONFIG.get().filter(|config| config.enabled)
+}
+
+/// Payload index recommended for a field, which is frequently filtered without a suitable index
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct IndexRecommendation {
+    /// Name of the filtered field
+    pub field_name: PayloadKeyType,
+    /// Schema of the recommended index, to be used for creating it
+    pub field_schema: PayloadFieldSchema,
+    /// Type of the recommended index
+    pub index_type: PayloadSchemaType,
+    /// Whether the index is recommended to be stored on disk, instead of RAM
+    pub on_disk: bool,
+    /// Number of filters on the field without a suitable index, since the collection was loaded
+    pub filter_count: usize,
+    /// Estimated benefit: number of point payloads, which these filters had to check
+    /// without the index. It is an upper bound, other conditions of the filters may have
+    /// narrowed the checked points.
+    pub estimated_payload_checks: usize,
+    /// Rough estimation of RAM used by the index, when stored in RAM
+    pub estimated_ram_bytes: usize,
+}
+
+/// Payload index created automatically from a recommendation of the index advisor
+#[derive(Debug, Clone, Serialize, JsonSchema, Anonymize)]
+pub struct AutoCreatedIndexTelemetry {
+    pub field_name: PayloadKeyType,
+    #[anonymize(false)]
+    pub index_type: PayloadSchemaType,
+    #[anonymize(false)]
+    pub on_disk: bool,
+    /// Number of filters on the field without a suitable index, before the index was created
+    pub filter_count: usize,
+    /// Rough estimation of RAM used by the index, when stored in RAM
+    pub estimated_ram_bytes: usize,
+}
+
+/// Fields filtered without a suitable payload index, in the queries to a collection
+#[derive(Debug, Default)]
+pub struct UnindexedFilterStats {
+    fields: Mutex<HashMap<PayloadKeyType, FieldFilterStats>>,
+    /// Indexes created automatically for the fields, in the order of creation
+    auto_created: Mutex<Vec<AutoCreatedIndexTelemetry>>,
+}
+
+#[derive(Debug, Default)]
+struct FieldFilterStats {
+    filter_count: usize,
+    /// Index schemas, which would have served the filters, with the number of filters they
+    /// would have served, in the order of their first appearance
+    schemas: Vec<(PayloadFieldSchema, usize)>,
+    /// Sum of the point counts of the collection, at the times of the filters
+    payload_checks: usize,
+}
+
+impl UnindexedFilterStats {
+    /// Count the fields of the `filter`, which have no suitable index in the `payload_schema`
+    pub fn record(
+        &self,
+        filter: &Filter,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+        points_count: usize,
+    ) {
+        let unindexed = unindexed_filter_fields(filter, payload_schema);
+        if unindexed.is_empty() {
+            return;
+        }
+
+        let mut fields = self.fields.lock();
+        for (key, schemas) in unindexed {
+            let stats = fields.entry(key).or_default();
+            stats.filter_count += 1;
+            stats.payload_checks = stats.payload_checks.saturating_add(points_count);
+
+            let mut counted = Vec::with_capacity(schemas.len());
+            // UUID index only supports UUID values, keyword index is suggested for them anyway
+            let schemas = schemas
+                .into_iter()
+                .filter(|schema| schema.kind() != PayloadSchemaType::Uuid);
+            for schema in schemas {
+                if counted.contains(&schema) {
+                    continue;
+                }
+                match stats.schemas.iter_mut().find(|(known, _)| *known == schema) {
+                    Some((_, count)) => *count += 1,
+                    None => stats.schemas.push((schema.clone(), 1)),
+                }
+                counted.push(schema);
+            }
+        }
+    }
+
+    /// Recommended indexes for the fields filtered at least `min_filter_count` times, which are
+    /// still not indexed in the `payload_schema`. The most beneficial recommendations come first.
+    pub fn recommendations(
+        &self,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+        points_count: usize,
+        config: &IndexAdvisorConfig,
+    ) -> Vec<IndexRecommendation> {
+        let fields = self.fields.lock();
+
+        let mut recommendations: Vec<_> = fields
+            .iter()
+            .filter(|(key, stats)| {
+                stats.filter_count >= config.min_filter_count && !payload_schema.contains_key(*key)
+            })
+            .filter_map(|(key, stats)| {
+                let schema = stats.best_schema()?;
+                let index_type = schema.kind();
+
+                let estimated_ram_bytes =
+                    points_count.saturating_mul(estimated_bytes_per_point(index_type));
+                let on_disk = estimated_ram_bytes > config.memory_budget_bytes();
+                let field_schema = if on_disk {
+                    schema_on_disk(schema)
+                } else {
+                    schema.clone()
+                };
+
+                Some(IndexRecommendation {
+                    field_name: key.clone(),
+                    field_schema,
+                    index_type,
+                    on_disk,
+                    filter_count: stats.filter_count,
+                    estimated_payload_checks: stats.payload_checks,
+                    estimated_ram_bytes,
+                })
+            })
+            .collect();
+
+        recommendations.sort_by(|a, b| {
+            b.estimated_payload_checks
+                .cmp(&a.estimated_payload_checks)
+                .then_with(|| a.field_name.cmp(&b.field_name))
+        });
+        recommendations
+    }
+
+    /// Remember that the index of the `recommendation` was created automatically
+    pub fn record_auto_created(&self, recommendation: &IndexRecommendation, on_disk: bool) {
+        self.auto_created.lock().push(AutoCreatedIndexTelemetry {
+            field_name: recommendation.field_name.clone(),
+            index_type: recommendation.index_type,
+            on_disk,
+            filter_count: recommendation.filter_count,
+            estimated_ram_bytes: recommendation.estimated_ram_bytes,
+        });
+    }
+
+    /// Indexes created automatically, in the order of creation
+    pub fn auto_created(&self) -> Vec<AutoCreatedIndexTelemetry> {
+        self.auto_created.lock().clone()
+    }
+}
+
+impl FieldFilterStats {
+    /// Schema serving the most filters, the earliest seen one on ties
+    fn best_schema(&self) -> Option<&PayloadFieldSchema> {
+        let mut best: Option<&(PayloadFieldSchema, usize)> = None;
+        for entry in &self.schemas {
+            if best.is_none_or(|(_, best_count)| entry.1 > *best_count) {
+                best = Some(entry);
+            }
+        }
+        best.map(|(schema, _)| schema)
+    }
+}
+
+/// Rough size of a single point in the index of this type, in RAM
+fn estimated_bytes_per_point(index_type: PayloadSchemaType) -> usize {
+    match index_type {
+        PayloadSchemaType::Bool => 8,
+        PayloadSchemaType::Integer | PayloadSchemaType::Float | PayloadSchemaType::Datetime => 24,
+        PayloadSchemaType::Uuid => 32,
+        PayloadSchemaType::Keyword | PayloadSchemaType::Geo => 48,
+        PayloadSchemaType::Text => 256,
+    }
+}
+
+/// Same index schema, with the index stored on disk
+pub fn schema_on_disk(schema: &PayloadFieldSchema) -> PayloadFieldSchema {
+    let mut params = schema.expand().into_owned();
+    let on_disk = match &mut params {
+        PayloadSchemaParams::Keyword(params) => &mut params.on_disk,
+        PayloadSchemaParams::Integer(params) => &mut params.on_disk,
+        PayloadSchemaParams::Float(params) => &mut params.on_disk,
+        PayloadSchemaParams::Geo(params) => &mut params.on_disk,
+        PayloadSchemaParams::Text(params) => &mut params.on_disk,
+        PayloadSchemaParams::Bool(params) => &mut params.on_disk,
+        PayloadSchemaParams::Datetime(params) => &mut params.on_disk,
+        PayloadSchemaParams::Uuid(params) => &mut params.on_disk,
+    };
+    *on_disk = Some(true);
+    PayloadFieldSchema::FieldParams(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+    use segment::json_path::JsonPath;
+    use segment::types::{Condition, FieldCondition, Match, Range, ValueVariants};
+
+    use super::*;
+
+    fn config(min_filter_count: usize, memory_budget_mb: usize) -> IndexAdvisorConfig {
+        IndexAdvisorConfig {
+            enabled: true,
+            min_filter_count,
+            memory_budget_mb,
+            ..Default::default()
+        }
+    }
+
+    fn match_filter(key: &str) -> Filter {
+        Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new(key),
+            Match::new_value(ValueVariants::String("value".to_string())),
+        )))
+    }
+
+    fn range_filter(key: &str) -> Filter {
+        Filter::new_must(Condition::Field(FieldCondition::new_range(
+            JsonPath::new(key),
+            Range {
+                gte: Some(OrderedFloat(1.0)),
+                ..Default::default()
+            },
+        )))
+    }
+
+    #[test]
+    fn test_recommendations() {
+        let stats = UnindexedFilterStats::default();
+        let mut payload_schema = HashMap::new();
+        payload_schema.insert(
+            JsonPath::new("indexed"),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+        );
+
+        for _ in 0..3 {
+            stats.record(&match_filter("city"), &payload_schema, 100);
+            stats.record(&match_filter("indexed"), &payload_schema, 100);
+        }
+        stats.record(&range_filter("price"), &payload_schema, 1000);
+
+        // Not filtered often enough
+        assert!(
+            stats
+                .recommendations(&payload_schema, 1000, &config(4, 512))
+                .is_empty()
+        );
+
+        let recommendations = stats.recommendations(&payload_schema, 1000, &config(1, 512));
+        let fields: Vec<_> = recommendations
+            .iter()
+            .map(|recommendation| recommendation.field_name.to_string())
+            .collect();
+        assert_eq!(fields, vec!["price", "city"]);
+
+        let price = &recommendations[0];
+        assert_eq!(price.index_type, PayloadSchemaType::Float);
+        assert_eq!(price.estimated_payload_checks, 1000);
+        assert!(!price.on_disk);
+
+        let city = &recommendations[1];
+        assert_eq!(city.index_type, PayloadSchemaType::Keyword);
+        assert_eq!(city.filter_count, 3);
+        assert_eq!(city.estimated_payload_checks, 300);
+
+        // Indexes exceeding the memory budget are recommended on disk
+        let recommendations = stats.recommendations(&payload_schema, 100_000_000, &config(1, 1));
+        assert!(recommendations.iter().all(|recommendation| {
+            recommendation.on_disk && recommendation.field_schema.is_on_disk()
+        }));
+
+        // Indexed fields are not recommended anymore
+        payload_schema.insert(
+            JsonPath::new("city"),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+        );
+        let recommendations = stats.recommendations(&payload_schema, 1000, &config(1, 512));
+        assert_eq!(recommendations.len(), 1);
+    }
+
+    #[test]
+    fn test_auto_created() {
+        let stats = UnindexedFilterStats::default();
+        let payload_schema = HashMap::new();
+        stats.record(&match_filter("city"), &payload_schema, 100);
+
+        let recommendations = stats.recommendations(&payload_schema, 100, &config(1, 512));
+        stats.record_auto_created(&recommendations[0], true);
+
+        let auto_created = stats.auto_created();
+        assert_eq!(auto_created.len(), 1);
+        assert_eq!(auto_created[0].field_name, JsonPath::new("city"));
+        assert_eq!(auto_created[0].index_type, PayloadSchemaType::Keyword);
+        assert_eq!(auto_created[0].filter_count, 1);
+        assert!(auto_created[0].on_disk);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod index_advisor;
 pub mod interface;
+pub mod slow_query_log;
 mod slow_requests_collector;
 pub mod slow_requests_log;
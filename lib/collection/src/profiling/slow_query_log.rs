@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::counter::hardware_counter::HardwareCounterCell;
+use itertools::Itertools;
+use segment::common::operation_error::OperationResult;
+use segment::index::field_index::{CardinalityEstimation, EstimationMerge, PrimaryCondition};
+use segment::index::query_optimization::condition_cost::PlannedCondition;
+use segment::types::{Condition, Filter, PayloadFieldSchema, PayloadKeyType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shard::segment_holder::locked::LockedSegmentHolder;
+use validator::Validate;
+
+/// Log target of slow query entries, to separate them from the rest of the log
+pub const SLOW_QUERY_LOG_TARGET: &str = "qdrant::slow_query";
+
+/// Placeholder for payload values of redacted filters
+const REDACTED: &str = "<redacted>";
+
+/// Object keys of a serialized filter, whose values are never redacted.
+/// They name payload fields and vectors, or define the structure of the filter.
+const NON_SENSITIVE_KEYS: [&str; 3] = ["key", "has_vector", "min_count"];
+
+static SLOW_QUERY_LOG_CONFIG: OnceLock<SlowQueryLogConfig> = OnceLock::new();
+
+#[derive(Debug, Deserialize, Validate, Clone)]
+pub struct SlowQueryLogConfig {
+    /// Enable structured logging of slow queries. Default: false
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Queries taking longer than this on a shard are logged. Default: 1000
+    #[serde(default = "default_threshold_ms")]
+    pub threshold_ms: u64,
+
+    /// Fraction of slow queries to log, from 0.0 to 1.0. Default: 1.0
+    #[serde(default = "default_sample_rate")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub sample_rate: f64,
+
+    /// Replace payload values in logged filters with a placeholder.
+    /// Field names and the filter structure are kept. Default: false
+    #[serde(default)]
+    pub redact_filter_values: bool,
+}
+
+const fn default_threshold_ms() -> u64 {
+    1000
+}
+
+const fn default_sample_rate() -> f64 {
+    1.0
+}
+
+impl Default for SlowQueryLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold_ms: default_threshold_ms(),
+            sample_rate: default_sample_rate(),
+            redact_filter_values: false,
+        }
+    }
+}
+
+impl SlowQueryLogConfig {
+    fn threshold(&self) -> Duration {
+        Duration::from_millis(self.threshold_ms)
+    }
+}
+
+/// This function initializes the slow query log.
+///
+/// It should be called once during the application startup, the log is disabled otherwise.
+pub fn init_slow_query_log(config: SlowQueryLogConfig) {
+    if config.enabled {
+        log::info!(
+            "Slow query log enabled, threshold: {}ms, sample rate: {}",
+            config.threshold_ms,
+            config.sample_rate,
+        );
+    }
+
+    if SLOW_QUERY_LOG_CONFIG.set(config).is_err() {
+        log::warn!("Slow query log is already initialized");
+    }
+}
+
+/// Returns the slow query log configuration, if a query of this `duration` should be logged.
+///
+/// Queries below the threshold are never logged, queries above it are sampled.
+pub fn slow_query_log_config(duration: Duration) -> Option<&'static SlowQueryLogConfig> {
+    let config = SLOW_QUERY_LOG_CONFIG.get()?;
+
+    if !config.enabled || duration < config.threshold() {
+        return None;
+    }
+
+    if config.sample_rate < 1.0 && rand::random::<f64>() >= config.sample_rate {
+        return None;
+    }
+
+    Some(config)
+}
+
+/// Single line of the slow query log
+#[derive(Serialize)]
+pub struct SlowQueryLogEntry {
+    collection_name: String,
+    request_name: &'static str,
+    duration_ms: u128,
+    filters: Vec<FilterPlan>,
+    hardware: HardwareUsage,
+}
+
+/// How a single filter of the query was executed
+#[derive(Serialize)]
+pub struct FilterPlan {
+    filter: Value,
+    /// Fields, whose payload index selects the candidate points in the largest segment, and
+    /// their index schema
+    indexed_fields: HashMap<PayloadKeyType, PayloadFieldSchema>,
+    /// Filtered fields without a payload index, which require a full scan of the payload
+    unindexed_fields: Vec<PayloadKeyType>,
+    cardinality: CardinalityPlan,
+    /// Order in which points are checked against the `must` conditions, in the largest segment
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    must_order: Vec<PlannedCondition>,
+}
+
+#[derive(Serialize)]
+struct CardinalityPlan {
+    estimated_min: usize,
+    estimated_exp: usize,
+    estimated_max: usize,
+    /// Actual number of matched points, only known for some requests, like exact count
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct HardwareUsage {
+    cpu: usize,
+    payload_io_read: usize,
+    payload_index_io_read: usize,
+    vector_io_read: usize,
+}
+
+impl FilterPlan {
+    fn new(
+        filter: &Filter,
+        payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+        estimation: CardinalityEstimation,
+        primary_clauses: &[PrimaryCondition],
+        actual: Option<usize>,
+        must_order: Vec<PlannedCondition>,
+        config: &SlowQueryLogConfig,
+    ) -> Self {
+        let indexed_fields = primary_clauses
+            .iter()
+            .filter_map(|clause| match clause {
+                PrimaryCondition::Condition(condition) => {
+                    let schema = payload_schema.get(&condition.key)?;
+                    Some((condition.key.clone(), schema.clone()))
+                }
+                PrimaryCondition::Ids(_) | PrimaryCondition::HasVector(_) => None,
+            })
+            .collect();
+
+        let mut unindexed_fields = Vec::new();
+        for key in filtered_keys(filter) {
+            if !payload_schema.contains_key(&key) && !unindexed_fields.contains(&key) {
+                unindexed_fields.push(key);
+            }
+        }
+
+        let mut filter = serde_json::to_value(filter).unwrap_or_default();
+        if config.redact_filter_values {
+            redact_values(&mut filter);
+        }
+
+        let CardinalityEstimation {
+            primary_clauses: _,
+            min,
+            exp,
+            max,
+        } = estimation;
+
+        Self {
+            filter,
+            indexed_fields,
+            unindexed_fields,
+            cardinality: CardinalityPlan {
+                estimated_min: min,
+                estimated_exp: exp,
+                estimated_max: max,
+                actual,
+            },
+            must_order,
+        }
+    }
+}
+
+impl SlowQueryLogEntry {
+    pub fn new(
+        collection_name: String,
+        request_name: &'static str,
+        duration: Duration,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> Self {
+        Self {
+            collection_name,
+            request_name,
+            duration_ms: duration.as_millis(),
+            filters: Vec::new(),
+            hardware: HardwareUsage {
+                cpu: hw_measurement_acc.get_cpu(),
+                payload_io_read: hw_measurement_acc.get_payload_io_read(),
+                payload_index_io_read: hw_measurement_acc.get_payload_index_io_read(),
+                vector_io_read: hw_measurement_acc.get_vector_io_read(),
+            },
+        }
+    }
+
+    pub fn with_filters(mut self, filters: Vec<FilterPlan>) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Write the entry into the log as a single line of JSON
+    pub fn log(&self) {
+        match serde_json::to_string(self) {
+            Ok(line) => log::warn!(target: SLOW_QUERY_LOG_TARGET, "{line}"),
+            Err(err) => log::warn!("Failed to serialize slow query log entry: {err}"),
+        }
+    }
+}
+
+/// Plan the `filters` of a logged query over the segments of the shard.
+///
+/// Filters are paired with the actual number of points they matched, if it is known. Segments
+/// may plan a filter differently, depending on their indexes and the cardinality of conditions,
+/// so the plan is taken from the largest segment, which takes most of the time to check. The
+/// cardinality is estimated over all segments.
+///
+/// Takes about as long as the query itself, so it must not run on the request path.
+pub fn plan_filters(
+    segments: &LockedSegmentHolder,
+    filters: &[(Filter, Option<usize>)],
+    payload_schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    config: &SlowQueryLogConfig,
+) -> Vec<FilterPlan> {
+    // Planning is not a part of the request, don't account it
+    let hw_counter = HardwareCounterCell::disposable();
+
+    // Collect the segments first so we don't lock the segment holder during the operations.
+    let segments = segments
+        .read()
+        .iter()
+        .map(|i| i.1.clone())
+        .collect::<Vec<_>>();
+    let largest_segment = segments
+        .iter()
+        .position_max_by_key(|segment| segment.get().read().available_point_count());
+
+    let plan_filter = |filter: &Filter, actual: Option<usize>| -> OperationResult<FilterPlan> {
+        let estimations = segments
+            .iter()
+            .map(|segment| {
+                segment
+                    .get()
+                    .read()
+                    .estimate_point_count(Some(filter), &hw_counter)
+            })
+            .collect::<OperationResult<Vec<_>>>()?;
+        let (primary_clauses, must_order) = match largest_segment {
+            Some(largest_segment) => (
+                estimations[largest_segment].primary_clauses.clone(),
+                segments[largest_segment]
+                    .get()
+                    .read()
+                    .plan_must_conditions(filter, &hw_counter)?,
+            ),
+            None => (Vec::new(), Vec::new()),
+        };
+
+        Ok(FilterPlan::new(
+            filter,
+            payload_schema,
+            estimations.into_iter().merge_independent(),
+            &primary_clauses,
+            actual,
+            must_order,
+            config,
+        ))
+    };
+
+    filters
+        .iter()
+        .filter_map(|(filter, actual)| match plan_filter(filter, *actual) {
+            Ok(plan) => Some(plan),
+            Err(err) => {
+                log::debug!("Failed to plan filter for slow query log: {err}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// All payload fields, which are used in the filter, including nested filters
+fn filtered_keys(filter: &Filter) -> Vec<PayloadKeyType> {
+    let mut keys = Vec::new();
+    let mut stack: Vec<&Condition> = filter.iter_conditions().collect();
+    while let Some(condition) = stack.pop() {
+        match condition {
+            Condition::Filter(filter) => stack.extend(filter.iter_conditions()),
+            Condition::Nested(nested) => {
+                let array_key = nested.array_key();
+                keys.extend(
+                    filtered_keys(nested.filter())
+                        .into_iter()
+                        .map(|key| array_key.extend(&key)),
+                );
+            }
+            condition => keys.extend(condition.targeted_key()),
+        }
+    }
+    keys
+}
+
+/// Replace all scalar values of a serialized filter with a placeholder,
+/// except for the ones under [`NON_SENSITIVE_KEYS`].
+fn redact_values(value: &mut Value) {
+    match value {
+        Value::Object(map) => map
+            .iter_mut()
+            .filter(|(key, _)| !NON_SENSITIVE_KEYS.contains(&key.as_str()))
+            .for_each(|(_, value)| redact_values(value)),
+        Value::Array(values) => values.iter_mut().for_each(redact_values),
+        Value::Null => {}
+        Value::Bool(_) | Value::Number(_) | Value::String(_) => {
+            *value = Value::String(REDACTED.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::json_path::JsonPath;
+    use segment::types::{FieldCondition, Match, Nested, NestedCondition, ValueVariants};
+    use serde_json::json;
+
+    use super::*;
+
+    fn key(key: &str) -> JsonPath {
+        key.parse().unwrap()
+    }
+
+    #[test]
+    fn test_redact_filter_values() {
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            key("city"),
+            Match::new_value(ValueVariants::String("Berlin".to_string())),
+        )));
+
+        let mut value = serde_json::to_value(&filter).unwrap();
+        redact_values(&mut value);
+
+        assert_eq!(
+            value,
+            json!({
+                "must": [{ "key": "city", "match": { "value": "<redacted>" } }]
+            }),
+        );
+    }
+
+    #[test]
+    fn test_filtered_keys() {
+        let nested = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            key("name"),
+            Match::new_value(ValueVariants::String("Bob".to_string())),
+        )));
+        let filter = Filter {
+            must: Some(vec![
+                Condition::Field(FieldCondition::new_match(
+                    key("city"),
+                    Match::new_value(ValueVariants::String("Berlin".to_string())),
+                )),
+                Condition::Nested(NestedCondition::new(Nested {
+                    key: key("people"),
+                    filter: nested,
+                })),
+            ]),
+            ..Default::default()
+        };
+
+        let mut keys = filtered_keys(&filter);
+        keys.sort_by_key(|key| key.to_string());
+        assert_eq!(keys, vec![key("city"), key("people[].name")]);
+    }
+}
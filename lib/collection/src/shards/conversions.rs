@@ -94,6 +94,8 @@ pub fn internal_upsert_points(
             update_filter: None,
             timeout: wait_timeout,
             update_mode: None, // Default mode (Upsert)
+            expected_version: None,
+            duplicate_ids: None, // Ids are unique after the API layer
         }),
     })
 }
@@ -113,6 +115,7 @@ pub fn internal_conditional_upsert_points(
         points_op: point_insert_operations,
         condition,
         update_mode,
+        expected_version,
     } = point_condition_upsert_operations;
 
     let grpc_update_mode = update_mode.map(|mode| match mode {
@@ -140,6 +143,8 @@ pub fn internal_conditional_upsert_points(
             update_filter: Some(api::grpc::Filter::from(condition)),
             timeout: wait_timeout,
             update_mode: grpc_update_mode,
+            expected_version,
+            duplicate_ids: None, // Ids are unique after the API layer
         }),
     })
 }
@@ -555,6 +560,8 @@ pub fn try_scored_point_from_grpc(
         vectors,
         shard_key,
         order_value,
+        provenance,
+        score_stages,
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("scored point does not have an ID"))?
@@ -580,5 +587,10 @@ pub fn try_scored_point_from_grpc(
         vector,
         shard_key: convert_shard_key_from_grpc_opt(shard_key),
         order_value: order_value.map(TryFrom::try_from).transpose()?,
+        provenance: provenance.map(TryFrom::try_from).transpose()?,
+        score_stages: score_stages
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<_, _>>()?,
     })
 }
@@ -5,10 +5,11 @@ use async_trait::async_trait;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::DeferredBehavior;
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::quantiles::ValueSamples;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, SizeStats, StrictModeConfig, WithPayload,
-    WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, PayloadKeyType, PointIdType, ScoredPoint, SizeStats, StrictModeConfig,
+    WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::count::CountRequestInternal;
 use shard::operations::CollectionUpdateOperations;
@@ -21,7 +22,7 @@ use crate::common::adaptive_handle::AdaptiveSearchHandle;
 use crate::operations::OperationWithClockTag;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CountResult, OptimizersStatus,
-    PointRequestInternal, ShardStatus, UpdateResult, UpdateStatus,
+    PointRequestInternal, PointVersion, ShardStatus, UpdateResult, UpdateStatus,
 };
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use crate::shards::shard_trait::{ShardOperation, WaitUntil};
@@ -190,6 +191,17 @@ impl ShardOperation for DummyShard {
         self.dummy("retrieve")
     }
 
+    async fn point_versions(
+        &self,
+        _: Arc<Vec<PointIdType>>,
+        _: &AdaptiveSearchHandle,
+        _: Option<Duration>,
+        _: HwMeasurementAcc,
+        _: DeferredBehavior,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        self.dummy("point_versions")
+    }
+
     async fn query_batch(
         &self,
         _requests: Arc<Vec<ShardQueryRequest>>,
@@ -210,5 +222,14 @@ impl ShardOperation for DummyShard {
         self.dummy("facet")
     }
 
+    async fn value_samples(
+        &self,
+        _: &PayloadKeyType,
+        _search_runtime_handle: &AdaptiveSearchHandle,
+        _: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        self.dummy("value_samples")
+    }
+
     async fn stop_gracefully(self) {}
 }
@@ -9,10 +9,11 @@ use common::tar_ext;
 use common::types::{DeferredBehavior, TelemetryDetail};
 use parking_lot::Mutex as ParkingMutex;
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::quantiles::ValueSamples;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
-    ExtendedPointId, Filter, PointIdType, ScoredPoint, SizeStats, SnapshotFormat, StrictModeConfig,
-    WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, PayloadKeyType, PointIdType, ScoredPoint, SizeStats, SnapshotFormat,
+    StrictModeConfig, WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -32,7 +33,7 @@ use crate::operations::point_ops::{
 };
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CountResult, OptimizersStatus,
-    PointRequestInternal, UpdateResult, UpdateStatus,
+    PointRequestInternal, PointVersion, UpdateResult, UpdateStatus,
 };
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use crate::operations::{
@@ -713,6 +714,26 @@ impl ShardOperation for ForwardProxyShard {
             .await
     }
 
+    async fn point_versions(
+        &self,
+        ids: Arc<Vec<PointIdType>>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+        deferred_behavior: DeferredBehavior,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .point_versions(
+                ids,
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+                deferred_behavior,
+            )
+            .await
+    }
+
     async fn query_batch(
         &self,
         requests: Arc<Vec<ShardQueryRequest>>,
@@ -739,6 +760,18 @@ impl ShardOperation for ForwardProxyShard {
             .await
     }
 
+    async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .value_samples(key, search_runtime_handle, timeout)
+            .await
+    }
+
     async fn stop_gracefully(self) {
         self.wrapped_shard.stop_gracefully().await
     }
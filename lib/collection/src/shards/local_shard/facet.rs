@@ -9,7 +9,7 @@ use futures::future;
 use futures::future::try_join_all;
 use itertools::{Itertools, process_results};
 use segment::data_types::facets::{FacetParams, FacetValue, FacetValueHit};
-use segment::types::{Condition, FieldCondition, Filter, Match};
+use segment::types::{Condition, FieldCondition, Filter, Match, PayloadSchemaType};
 use shard::common::stopping_guard::StoppingGuard;
 use tokio::time::error::Elapsed;
 use tokio_util::task::AbortOnDropHandle;
@@ -108,6 +108,21 @@ impl LocalShard {
         // To do this we will perform exact counts for each of the values in the field.
         let instant = std::time::Instant::now();
 
+        // H3 cells of a geo field are no payload values, they can't be matched by a condition
+        let is_geo_field = self
+            .payload_index_schema
+            .read()
+            .schema
+            .get(&request.key)
+            .is_some_and(|schema| schema.kind() == PayloadSchemaType::Geo);
+        if is_geo_field {
+            return Err(CollectionError::bad_request(format!(
+                "Exact facet counts are not supported for geo field \"{}\", \
+                 use approximate counts of H3 cells instead",
+                request.key,
+            )));
+        }
+
         // Get unique values for the field
         let unique_values = self
             .unique_values(
@@ -2,6 +2,7 @@ pub mod clock_map;
 pub mod disk_usage_watcher;
 pub(super) mod facet;
 pub(super) mod formula_rescore;
+pub(super) mod quantiles;
 pub(super) mod query;
 pub(super) mod scroll;
 pub(super) mod search;
@@ -32,6 +33,7 @@ use common::budget::ResourceBudget;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::defaults::log_load_timing;
+use common::populate_budget::PopulateBudget;
 use common::rate_limiting::RateLimiter;
 use common::save_on_disk::SaveOnDisk;
 use common::types::DeferredBehavior;
@@ -44,13 +46,19 @@ use indicatif::{ProgressBar, ProgressStyle};
 use itertools::Itertools;
 use parking_lot::Mutex as ParkingMutex;
 use segment::common::operation_error::OperationResult;
+use segment::data_types::index::Language;
+use segment::data_types::text_query::{
+    QueryStopwords, SnippetParams, TextSnippet, TextValueMatches,
+};
 use segment::entry::ReadSegmentEntry as _;
 use segment::index::field_index::{CardinalityEstimation, EstimationMerge};
 use segment::segment_constructor::{build_segment, load_segment, normalize_segment_dir};
 use segment::types::{
-    Filter, PayloadIndexInfo, PayloadKeyType, PointIdType, SegmentConfig, SegmentType,
-    SeqNumberType, StrictModeConfig,
+    Filter, PayloadIndexInfo, PayloadKeyType, PayloadSchemaType, PointIdType, SegmentConfig,
+    SegmentType, SeqNumberType, StrictModeConfig, VectorName,
 };
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
+use shard::auto_stopwords::AutoStopwords;
 use shard::files::{NEWEST_CLOCKS_PATH, OLDEST_CLOCKS_PATH, ShardDataFiles};
 use shard::operations::CollectionUpdateOperations;
 use shard::operations::optimization::{OptimizationSegmentInfo, PendingOptimization};
@@ -139,6 +147,9 @@ pub struct LocalShard {
 
     /// Persist the applied op_num sequence number
     applied_seq_handler: Arc<AppliedSeqHandler>,
+
+    /// Stopwords detected for the full-text indexes of the shard
+    auto_stopwords: Arc<SaveOnDisk<AutoStopwords>>,
 }
 
 /// Shard holds information about segments and WAL.
@@ -160,6 +171,7 @@ impl LocalShard {
             newest_clocks_path: newest_clocks_path_from,
             oldest_clocks_path: oldest_clocks_path_from,
             applied_seq_path: applied_seq_path_from,
+            auto_stopwords_path: auto_stopwords_path_from,
         } = shard_data_files_from;
 
         let ShardDataFiles {
@@ -168,6 +180,7 @@ impl LocalShard {
             newest_clocks_path: newest_clocks_path_to,
             oldest_clocks_path: oldest_clocks_path_to,
             applied_seq_path: applied_seq_path_to,
+            auto_stopwords_path: auto_stopwords_path_to,
         } = shard_data_files_to;
 
         move_dir(wal_from, wal_to).await?;
@@ -185,6 +198,10 @@ impl LocalShard {
             move_file(applied_seq_path_from, applied_seq_path_to).await?;
         }
 
+        if auto_stopwords_path_from.exists() {
+            move_file(auto_stopwords_path_from, auto_stopwords_path_to).await?;
+        }
+
         Ok(())
     }
 
@@ -205,6 +222,7 @@ impl LocalShard {
             newest_clocks_path,
             oldest_clocks_path,
             applied_seq_path,
+            auto_stopwords_path,
         } = shard_data_files;
 
         if wal_path.exists() {
@@ -227,13 +245,17 @@ impl LocalShard {
             tokio_fs::remove_file(applied_seq_path).await?;
         }
 
+        if auto_stopwords_path.exists() {
+            tokio_fs::remove_file(auto_stopwords_path).await?;
+        }
+
         Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
     pub async fn new(
         collection_name: String,
-        segment_holder: SegmentHolder,
+        mut segment_holder: SegmentHolder,
         collection_config: Arc<TokioRwLock<CollectionConfigInternal>>,
         shared_storage_config: Arc<SharedStorageConfig>,
         payload_index_schema: Arc<SaveOnDisk<PayloadIndexSchema>>,
@@ -242,9 +264,11 @@ impl LocalShard {
         optimizer_resource_budget: ResourceBudget,
         shard_path: &Path,
         clocks: LocalShardClocks,
+        auto_stopwords: Arc<SaveOnDisk<AutoStopwords>>,
         update_runtime: Handle,
         search_runtime: AdaptiveSearchHandle,
     ) -> Self {
+        auto_stopwords.read().assign(&mut segment_holder);
         let segment_holder = LockedSegmentHolder::new(segment_holder);
         let config = collection_config.read().await;
         let locked_wal = Arc::new(Mutex::new(wal));
@@ -290,6 +314,7 @@ impl LocalShard {
             scroll_read_lock.clone(),
             update_tracker.clone(),
             applied_seq_handler.clone(),
+            auto_stopwords.clone(),
         );
 
         let (update_sender, update_receiver) =
@@ -333,6 +358,7 @@ impl LocalShard {
             is_gracefully_stopped: false,
             update_operation_lock: scroll_read_lock,
             applied_seq_handler,
+            auto_stopwords,
         }
     }
 
@@ -486,6 +512,9 @@ impl LocalShard {
         drop(collection_config_read); // release `shared_config` from borrow checker
 
         let clocks = LocalShardClocks::load(shard_path)?;
+        let auto_stopwords = Arc::new(SaveOnDisk::load_or_init_default(
+            shard::files::auto_stopwords_path(shard_path),
+        )?);
 
         // Always make sure we have any appendable segments, needed for update operations
         if !segment_holder.has_appendable_segment() {
@@ -517,6 +546,7 @@ impl LocalShard {
             optimizer_resource_budget,
             shard_path,
             clocks,
+            auto_stopwords,
             update_runtime,
             search_runtime,
         )
@@ -669,6 +699,10 @@ impl LocalShard {
 
         drop(config); // release `shared_config` from borrow checker
 
+        let auto_stopwords = Arc::new(SaveOnDisk::load_or_init_default(
+            shard::files::auto_stopwords_path(shard_path),
+        )?);
+
         let local_shard = LocalShard::new(
             collection_id,
             segment_holder,
@@ -680,6 +714,7 @@ impl LocalShard {
             optimizer_resource_budget,
             shard_path,
             LocalShardClocks::default(),
+            auto_stopwords,
             update_runtime,
             search_runtime,
         )
@@ -962,6 +997,289 @@ impl LocalShard {
         Ok(cardinality)
     }
 
+    /// Quantized representation of the named vector of the point, as used by the scorers.
+    ///
+    /// Read from the segment with the latest version of the point. Returns `None` if the vector
+    /// is not quantized in that segment.
+    pub async fn point_quantized_vector(
+        &self,
+        point_id: PointIdType,
+        vector_name: &VectorName,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<QuantizedVectorRecord>> {
+        let segments = self.segments.clone();
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+        let vector_name = vector_name.to_owned();
+        let vector = tokio::task::spawn_blocking(move || -> CollectionResult<_> {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            let segment = latest_point_segment(&segments, point_id)?;
+            let vector =
+                segment
+                    .get()
+                    .read()
+                    .quantized_vector(&vector_name, point_id, &hw_counter)?;
+            Ok(vector)
+        });
+        AbortOnDropHandle::new(vector).await?
+    }
+
+    /// Tokens stored for the point in the full-text index of the field, in the index order.
+    ///
+    /// Read from the segment with the latest version of the point. Returns `None` if the field
+    /// has no full-text index in that segment.
+    pub async fn point_text_tokens(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let segments = self.segments.clone();
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+        let key = key.clone();
+        let tokens = tokio::task::spawn_blocking(move || -> CollectionResult<_> {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            let segment = latest_point_segment(&segments, point_id)?;
+            let tokens = segment
+                .get()
+                .read()
+                .point_text_tokens(point_id, &key, &hw_counter)?;
+            Ok(tokens)
+        });
+        AbortOnDropHandle::new(tokens).await?
+    }
+
+    /// Occurrences of the query tokens of the `text` in the values of the field of the point,
+    /// tokenized with its full-text index.
+    ///
+    /// Read from the segment with the latest version of the point. Returns `None` if the field
+    /// has no full-text index in that segment.
+    pub async fn point_text_match_offsets(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        text: &str,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextValueMatches>>> {
+        let segments = self.segments.clone();
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+        let key = key.clone();
+        let text = text.to_string();
+        let matches = tokio::task::spawn_blocking(move || -> CollectionResult<_> {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            let segment = latest_point_segment(&segments, point_id)?;
+            let matches = segment.get().read().point_text_match_offsets(
+                point_id,
+                &key,
+                &text,
+                &hw_counter,
+            )?;
+            Ok(matches)
+        });
+        AbortOnDropHandle::new(matches).await?
+    }
+
+    /// Excerpts of the values of the field of the point around the matches of the `text`, with
+    /// the query tokens highlighted.
+    ///
+    /// Read from the segment with the latest version of the point. Returns `None` if the field
+    /// has no full-text index in that segment.
+    pub async fn point_text_snippets(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextSnippet>>> {
+        let segments = self.segments.clone();
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+        let key = key.clone();
+        let text = text.to_string();
+        let snippets = tokio::task::spawn_blocking(move || -> CollectionResult<_> {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            let segment = latest_point_segment(&segments, point_id)?;
+            let snippets = segment.get().read().point_text_snippets(
+                point_id,
+                &key,
+                &text,
+                params,
+                &hw_counter,
+            )?;
+            Ok(snippets)
+        });
+        AbortOnDropHandle::new(snippets).await?
+    }
+
+    /// Same as [`Self::point_text_snippets`] for each of the points, in the order of
+    /// `point_ids`. Points not stored in the shard, or without a full-text index of the field in
+    /// their segment, are left out.
+    pub async fn points_text_snippets(
+        &self,
+        point_ids: &[PointIdType],
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Vec<(PointIdType, Vec<TextSnippet>)>> {
+        let segments = self.segments.clone();
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+        let point_ids = point_ids.to_vec();
+        let key = key.clone();
+        let text = text.to_string();
+        let snippets = tokio::task::spawn_blocking(move || -> CollectionResult<_> {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            let mut points_snippets = Vec::new();
+            for point_id in point_ids {
+                let segment = match latest_point_segment(&segments, point_id) {
+                    Ok(segment) => segment,
+                    Err(CollectionError::PointNotFound { .. }) => continue,
+                    Err(err) => return Err(err),
+                };
+                let snippets = segment.get().read().point_text_snippets(
+                    point_id,
+                    &key,
+                    &text,
+                    params,
+                    &hw_counter,
+                )?;
+                if let Some(snippets) = snippets {
+                    points_snippets.push((point_id, snippets));
+                }
+            }
+            Ok(points_snippets)
+        });
+        AbortOnDropHandle::new(snippets).await?
+    }
+
+    /// Stopwords detected for the full-text index of the field in the shard, sorted.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn text_auto_stopwords(
+        &self,
+        key: &PayloadKeyType,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let is_text_index = self
+            .payload_index_schema
+            .read()
+            .schema
+            .get(key)
+            .is_some_and(|schema| schema.kind() == PayloadSchemaType::Text);
+        if !is_text_index {
+            return Ok(None);
+        }
+        Ok(Some(self.auto_stopwords.read().stopwords(key)))
+    }
+
+    /// Query tokens removed as stopwords in any of the segments, when querying the full-text index
+    /// of the field with the `text`.
+    ///
+    /// Returns `None` if the field has no full-text index in any of the segments.
+    pub async fn text_query_stopwords(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        language: Option<Language>,
+    ) -> CollectionResult<Option<QueryStopwords>> {
+        let segments = self.segments.clone();
+        let key = key.clone();
+        let text = text.to_string();
+        let stopwords = tokio::task::spawn_blocking(move || {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            let mut stopwords: Option<QueryStopwords> = None;
+            for segment in segments {
+                let segment_stopwords = segment
+                    .get()
+                    .read()
+                    .text_query_stopwords(&key, &text, language);
+                if let Some(segment_stopwords) = segment_stopwords {
+                    stopwords.get_or_insert_default().merge(segment_stopwords);
+                }
+            }
+            stopwords
+        });
+        Ok(AbortOnDropHandle::new(stopwords).await?)
+    }
+
+    /// Populate pages of the indexes of the payload field in all segments, which fit into the
+    /// `budget`. Returns the remaining budget.
+    pub async fn populate_payload_indexes(
+        &self,
+        key: &PayloadKeyType,
+        mut budget: PopulateBudget,
+    ) -> CollectionResult<PopulateBudget> {
+        let segments = self.segments.clone();
+        let key = key.clone();
+        let populate = tokio::task::spawn_blocking(move || -> CollectionResult<_> {
+            // Collect the segments first so we don't lock the segment holder during the operations.
+            let segments = segments
+                .read()
+                .iter()
+                .map(|i| i.1.clone())
+                .collect::<Vec<_>>();
+
+            for segment in segments {
+                segment
+                    .get()
+                    .read()
+                    .populate_payload_indexes(&key, &mut budget)?;
+            }
+            Ok(budget)
+        });
+        AbortOnDropHandle::new(populate).await?
+    }
+
+    /// Approximate RAM usage in bytes of the payload field indexes kept in memory, over all
+    /// segments.
+    pub fn payload_indexes_ram_bytes(&self) -> usize {
+        let segments = self
+            .segments
+            .read()
+            .iter()
+            .map(|i| i.1.clone())
+            .collect::<Vec<_>>();
+
+        segments
+            .iter()
+            .map(|segment| segment.get().read().payload_indexes_ram_bytes())
+            .sum()
+    }
+
     pub async fn read_filtered<'a>(
         &'a self,
         filter: Option<&'a Filter>,
@@ -1102,6 +1420,17 @@ impl LocalShard {
         (ShardStatus::Green, OptimizersStatus::Ok)
     }
 
+    /// Version of the last operation applied to this shard.
+    /// Points updated after it can be found with the `updated_after` condition.
+    pub async fn max_segment_version(&self) -> CollectionResult<SeqNumberType> {
+        let segments = self.segments.clone();
+        tokio::task::spawn_blocking(move || segments.read().max_segment_version())
+            .await
+            .map_err(|e| {
+                CollectionError::service_error(format!("Failed to get max segment version: {e}"))
+            })
+    }
+
     pub async fn memory_report(&self) -> CollectionResult<CollectionMemoryReport> {
         let segments = self.segments.clone();
         tokio::task::spawn_blocking(move || {
@@ -1420,6 +1749,24 @@ fn deduplicate_points_async(
     }
 }
 
+/// Segment with the latest version of the point, among the `segments`
+fn latest_point_segment(
+    segments: &[LockedSegment],
+    point_id: PointIdType,
+) -> CollectionResult<LockedSegment> {
+    segments
+        .iter()
+        .filter_map(|segment| {
+            let version = segment.get().read().point_version(point_id)?;
+            Some((version, segment))
+        })
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, segment)| segment.clone())
+        .ok_or(CollectionError::PointNotFound {
+            missed_point_id: point_id,
+        })
+}
+
 /// Convenience struct for combining clock maps belonging to a shard
 ///
 /// Holds a clock map for tracking the highest clocks and the cutoff clocks.
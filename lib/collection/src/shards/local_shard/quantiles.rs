@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use futures::future::try_join_all;
+use segment::data_types::quantiles::ValueSamples;
+use segment::types::PayloadKeyType;
+use tokio::time::error::Elapsed;
+use tokio_util::task::AbortOnDropHandle;
+
+use super::LocalShard;
+use crate::collection_manager::holders::segment_holder::LockedSegment;
+use crate::common::adaptive_handle::AdaptiveSearchHandle;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+impl LocalShard {
+    /// Values of the numeric field sampled from the indexes of all segments.
+    ///
+    /// Samples of the segments are not merged: the same value can be sampled in several of them.
+    pub async fn segments_value_samples(
+        &self,
+        key: &PayloadKeyType,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+    ) -> CollectionResult<ValueSamples> {
+        let spawn_read = |segment: LockedSegment| {
+            let key = key.clone();
+            let task = search_runtime_handle.spawn_blocking(move || {
+                segment.get().read().value_samples(&key).unwrap_or_default()
+            });
+            AbortOnDropHandle::new(task)
+        };
+
+        let all_reads = {
+            let segments_lock = self.segments.read();
+
+            tokio::time::timeout(
+                timeout,
+                try_join_all(
+                    segments_lock
+                        .non_appendable_then_appendable_segments()
+                        .map(spawn_read),
+                ),
+            )
+        }
+        .await
+        .map_err(|_: Elapsed| CollectionError::timeout(timeout, "value samples"))??;
+
+        let samples = all_reads
+            .into_iter()
+            .flat_map(|segment_samples| segment_samples.samples)
+            .collect();
+
+        Ok(ValueSamples { samples })
+    }
+}
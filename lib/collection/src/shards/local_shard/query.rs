@@ -11,8 +11,11 @@ use ordered_float::OrderedFloat;
 use parking_lot::Mutex;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
-use segment::types::{Filter, HasIdCondition, ScoredPoint, WithPayloadInterface, WithVector};
+use segment::types::{
+    Filter, HasIdCondition, ScoreStageKind, ScoredPoint, WithPayloadInterface, WithVector,
+};
 use shard::query::planned_query::RescoreStages;
+use shard::query::score_stages::{push_score_stage, record_rescore_stage, take_score_stages};
 use shard::search::CoreSearchRequestBatch;
 
 use super::LocalShard;
@@ -65,14 +68,33 @@ impl LocalShard {
         hw_counter_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let start_time = std::time::Instant::now();
-        let searches_f = self.do_search(
-            Arc::new(CoreSearchRequestBatch {
-                searches: request.searches,
-            }),
-            search_runtime_handle,
-            timeout,
-            hw_counter_acc.clone(),
-        );
+        let searches = Arc::new(CoreSearchRequestBatch {
+            searches: request.searches,
+        });
+        let soft_timeout = request.soft_timeout;
+        let searches_f = async {
+            match soft_timeout {
+                None => {
+                    self.do_search(
+                        searches,
+                        search_runtime_handle,
+                        timeout,
+                        hw_counter_acc.clone(),
+                    )
+                    .await
+                }
+                Some(soft_timeout) => self
+                    .do_search_with_soft_timeout(
+                        searches,
+                        search_runtime_handle,
+                        timeout,
+                        soft_timeout,
+                        hw_counter_acc.clone(),
+                    )
+                    .await
+                    .map(|partial| partial.results),
+            }
+        };
 
         let scrolls_f = self.query_scroll_batch(
             Arc::new(request.scrolls),
@@ -175,6 +197,7 @@ impl LocalShard {
             merge_plan,
             with_payload,
             with_vector,
+            with_explanation,
         } = root_plan;
 
         // resolve merging plan
@@ -185,6 +208,7 @@ impl LocalShard {
                 search_runtime_handle,
                 timeout,
                 0,
+                with_explanation,
                 hw_measurement_acc.clone(),
             )
             .await?;
@@ -207,6 +231,7 @@ impl LocalShard {
         search_runtime_handle: &'a AdaptiveSearchHandle,
         timeout: Duration,
         depth: usize,
+        with_explanation: bool,
         hw_counter_acc: HwMeasurementAcc,
     ) -> BoxFuture<'a, CollectionResult<Vec<Vec<ScoredPoint>>>> {
         async move {
@@ -223,7 +248,11 @@ impl LocalShard {
             for source in plan_sources {
                 match source {
                     Source::SearchesIdx(idx) => {
-                        sources.push(prefetch_holder.get(FetchedSource::Search(idx))?)
+                        let mut points = prefetch_holder.get(FetchedSource::Search(idx))?;
+                        if with_explanation {
+                            push_score_stage(&mut points, &ScoreStageKind::Vector);
+                        }
+                        sources.push(points)
                     }
                     Source::ScrollsIdx(idx) => {
                         sources.push(prefetch_holder.get(FetchedSource::Scroll(idx))?)
@@ -236,6 +265,7 @@ impl LocalShard {
                                 search_runtime_handle,
                                 timeout,
                                 depth + 1,
+                                with_explanation,
                                 hw_counter_acc.clone(),
                             )
                             .await?
@@ -255,7 +285,9 @@ impl LocalShard {
                 } = rescore_stages;
 
                 let rescored = if let Some(rescore_params) = shard_level {
-                    let rescored = self
+                    let stage_kind = rescore_params.rescore.score_stage_kind();
+                    let source_stages = with_explanation.then(|| take_score_stages(&mut sources));
+                    let mut rescored = self
                         .rescore(
                             sources,
                             rescore_params,
@@ -264,6 +296,9 @@ impl LocalShard {
                             hw_counter_acc,
                         )
                         .await?;
+                    if let Some(source_stages) = source_stages {
+                        record_rescore_stage(&mut rescored, source_stages, stage_kind);
+                    }
                     vec![rescored]
                 } else {
                     // This re-scoring method requires full knowledge of all sources across all shards,
@@ -378,35 +413,38 @@ impl LocalShard {
                 )
                 .await
             }
-            ScoringQuery::Sample(sample) => match sample {
-                SampleInternal::Random => {
-                    // create single scroll request for rescoring query
-                    let filter = filter_with_sources_ids(sources.into_iter());
-
-                    // Note: score_threshold is not used in this case, as all results will have same score and order_value
-                    let scroll_request = QueryScrollRequestInternal {
-                        limit,
-                        filter: Some(filter),
-                        with_payload: false.into(),
-                        with_vector: false.into(),
-                        scroll_order: ScrollOrder::Random,
-                    };
-
-                    self.query_scroll_batch(
-                        Arc::new(vec![scroll_request]),
-                        search_runtime_handle,
-                        timeout,
-                        hw_counter_acc.clone(),
+            ScoringQuery::Sample(sample) => {
+                // create single scroll request for rescoring query
+                let filter = filter_with_sources_ids(sources.into_iter());
+
+                // Note: score_threshold is not used in this case, as random samples have same
+                // scores, and sampling keys of weighted samples are meaningless as a threshold
+                let scroll_order = match sample {
+                    SampleInternal::Random => ScrollOrder::Random,
+                    SampleInternal::Weighted(weighted) => ScrollOrder::WeightedRandom(weighted),
+                };
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order,
+                };
+
+                self.query_scroll_batch(
+                    Arc::new(vec![scroll_request]),
+                    search_runtime_handle,
+                    timeout,
+                    hw_counter_acc.clone(),
+                )
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    CollectionError::service_error(
+                        "Rescoring with sample query didn't return expected batch of results",
                     )
-                    .await?
-                    .pop()
-                    .ok_or_else(|| {
-                        CollectionError::service_error(
-                            "Rescoring with order-by query didn't return expected batch of results",
-                        )
-                    })
-                }
-            },
+                })
+            }
             ScoringQuery::Mmr(mmr) => {
                 self.mmr_rescore(
                     sources,
@@ -1,10 +1,11 @@
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::DeferredBehavior;
+use common::types::{DeferredBehavior, ScoreType};
 use futures::future::try_join_all;
 use itertools::Itertools as _;
 use rand::RngExt;
@@ -12,8 +13,10 @@ use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use segment::common::operation_error::OperationResult;
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, ScoredPoint, SeqNumberType, WithPayload, WithPayloadInterface,
+    WithVector,
 };
 use shard::common::stopping_guard::StoppingGuard;
 use shard::retrieve::record_internal::RecordInternal;
@@ -120,6 +123,21 @@ impl LocalShard {
                 )
                 .await?
             }
+            ScrollOrder::WeightedRandom(sample) => {
+                // Sampling keys are the scores, points are not converted from records
+                return self
+                    .scroll_weighted_randomly(
+                        limit,
+                        with_payload,
+                        with_vector,
+                        filter.as_ref(),
+                        sample,
+                        search_runtime_handle,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await;
+            }
         };
 
         let point_results = record_results
@@ -132,6 +150,8 @@ impl LocalShard {
                 vector: record.vector,
                 shard_key: record.shard_key,
                 order_value: record.order_value,
+                provenance: None,
+                score_stages: Vec::new(),
             })
             .collect();
 
@@ -489,4 +509,149 @@ impl LocalShard {
 
         Ok(records_map.into_values().collect())
     }
+    /// Weighted random sample of points, see [`WeightedSample`].
+    ///
+    /// Samples of all segments are merged by their sampling keys, which become the scores.
+    #[allow(clippy::too_many_arguments)]
+    async fn scroll_weighted_randomly(
+        &self,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        sample: &WeightedSample,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let start = Instant::now();
+        let stopping_guard = StoppingGuard::new();
+        let segments = self.segments.clone();
+
+        let update_operation_lock = self.update_operation_lock.read().await;
+        let (non_appendable, appendable) = {
+            let Some(segments_guard) = segments.try_read_for(timeout) else {
+                return Err(CollectionError::timeout(
+                    timeout,
+                    "scroll_weighted_randomly",
+                ));
+            };
+            segments_guard.split_segments()
+        };
+
+        let read_sample = |segment: LockedSegment, hw_counter: &HardwareCounterCell| {
+            let is_stopped = stopping_guard.get_is_stopped();
+            let filter = filter.cloned();
+            let sample = sample.clone();
+
+            let hw_counter = hw_counter.fork();
+            let cpu_utilization = hw_counter.cpu_utilization();
+            let task = search_runtime_handle.spawn_blocking(move || -> OperationResult<_> {
+                let work = || -> OperationResult<_> {
+                    let get_segment = segment.get();
+                    let read_segment = get_segment.read();
+                    let sampled = read_segment.read_weighted_random_filtered(
+                        limit,
+                        filter.as_ref(),
+                        &sample,
+                        &is_stopped,
+                        &hw_counter,
+                    )?;
+                    Ok(sampled
+                        .into_iter()
+                        .map(|(key, point_id)| {
+                            let version = read_segment.point_version(point_id).unwrap_or_default();
+                            (key, point_id, version)
+                        })
+                        .collect::<Vec<_>>())
+                };
+                match cpu_utilization {
+                    Some(cu) => cu.measure(work),
+                    None => work(),
+                }
+            });
+            AbortOnDropHandle::new(task)
+        };
+
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        let all_reads = tokio::time::timeout(
+            timeout,
+            try_join_all(
+                non_appendable
+                    .into_iter()
+                    .chain(appendable)
+                    .map(|segment| read_sample(segment, &hw_counter)),
+            ),
+        )
+        .await
+        .map_err(|_| CollectionError::timeout(timeout, "scroll_weighted_randomly"))??;
+
+        // A point can be present in multiple segments, keep the sample of its latest version only
+        let mut latest: HashMap<ExtendedPointId, (SeqNumberType, ScoreType)> = HashMap::new();
+        for read in all_reads {
+            for (key, point_id, version) in read? {
+                match latest.entry(point_id) {
+                    Entry::Occupied(mut entry) => {
+                        if version > entry.get().0 {
+                            entry.insert((version, key));
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert((version, key));
+                    }
+                }
+            }
+        }
+
+        let mut sampled: Vec<_> = latest
+            .into_iter()
+            .map(|(point_id, (version, key))| (key, point_id, version))
+            .collect();
+        sampled.sort_unstable_by(|(key_a, ..), (key_b, ..)| key_b.total_cmp(key_a));
+        sampled.truncate(limit);
+
+        let selected_points: Vec<_> = sampled.iter().map(|(_, point_id, _)| *point_id).collect();
+
+        let with_payload = WithPayload::from(with_payload_interface);
+        // update timeout
+        let timeout = timeout.saturating_sub(start.elapsed());
+        let mut records_map = tokio::time::timeout(
+            timeout,
+            SegmentsSearcher::retrieve(
+                segments,
+                &selected_points,
+                &with_payload,
+                with_vector,
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+                DeferredBehavior::Exclude,
+            ),
+        )
+        .await
+        .map_err(|_| CollectionError::timeout(timeout, "retrieve"))??;
+
+        drop(update_operation_lock);
+
+        let points = sampled
+            .into_iter()
+            .filter_map(|(score, point_id, version)| {
+                let record = records_map.remove(&point_id)?;
+                Some(ScoredPoint {
+                    id: point_id,
+                    version,
+                    score,
+                    payload: record.payload,
+                    vector: record.vector,
+                    shard_key: record.shard_key,
+                    order_value: None,
+                    provenance: None,
+                    score_stages: Vec::new(),
+                })
+            })
+            .collect();
+
+        Ok(points)
+    }
 }
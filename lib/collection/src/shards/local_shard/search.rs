@@ -8,8 +8,9 @@ use shard::query::query_enum::QueryEnum;
 use shard::search::CoreSearchRequestBatch;
 
 use super::LocalShard;
-use crate::collection_manager::segments_searcher::SegmentsSearcher;
+use crate::collection_manager::segments_searcher::{PartialSearchResult, SegmentsSearcher};
 use crate::common::adaptive_handle::AdaptiveSearchHandle;
+use crate::config::CollectionParams;
 use crate::operations::types::{CollectionError, CollectionResult};
 
 // Chunk requests for parallelism in certain scenarios
@@ -96,6 +97,78 @@ impl LocalShard {
         Ok(results)
     }
 
+    /// Search with a soft deadline, returning results found so far instead of a timeout error.
+    ///
+    /// See [`SegmentsSearcher::search_with_soft_timeout`].
+    pub async fn do_search_with_soft_timeout(
+        &self,
+        core_request: Arc<CoreSearchRequestBatch>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        soft_timeout: Duration,
+        hw_counter_acc: HwMeasurementAcc,
+    ) -> CollectionResult<PartialSearchResult> {
+        if core_request.searches.is_empty() {
+            return Ok(PartialSearchResult {
+                results: vec![],
+                processed_segments: 0,
+                total_segments: 0,
+            });
+        }
+
+        let start = std::time::Instant::now();
+        let is_stopped_guard = StoppingGuard::new();
+
+        let (query_context, collection_params) = {
+            let collection_config = self.collection_config.read().await;
+            let query_context_opt = SegmentsSearcher::prepare_query_context(
+                self.segments.clone(),
+                &core_request,
+                &collection_config,
+                timeout,
+                search_runtime_handle,
+                &is_stopped_guard,
+                hw_counter_acc,
+            )
+            .await?;
+
+            let Some(query_context) = query_context_opt else {
+                // No segments to search
+                return Ok(PartialSearchResult {
+                    results: vec![],
+                    processed_segments: 0,
+                    total_segments: 0,
+                });
+            };
+
+            (query_context, collection_config.params.clone())
+        };
+
+        let timeout = timeout.saturating_sub(start.elapsed());
+        let soft_timeout = soft_timeout.saturating_sub(start.elapsed());
+        let is_stopped = is_stopped_guard.get_is_stopped();
+
+        let search_request = SegmentsSearcher::search_with_soft_timeout(
+            self.segments.clone(),
+            core_request.clone(),
+            search_runtime_handle,
+            query_context,
+            timeout,
+            soft_timeout,
+            &is_stopped,
+        );
+
+        let mut res = tokio::time::timeout(timeout, search_request)
+            .await
+            .map_err(|_| {
+                log::debug!("Search timeout reached: {timeout:?}");
+                CollectionError::timeout(timeout, "Search")
+            })??;
+
+        res.results = postprocess_results(res.results, &core_request, &collection_params);
+        Ok(res)
+    }
+
     async fn do_search_impl(
         &self,
         core_request: Arc<CoreSearchRequestBatch>,
@@ -146,38 +219,47 @@ impl LocalShard {
                 CollectionError::timeout(timeout, "Search")
             })??;
 
-        let top_results = res
-            .into_iter()
-            .zip(core_request.searches.iter())
-            .map(|(vector_res, req)| {
-                let vector_name = req.query.get_vector_name();
-                let distance = collection_params.get_distance(vector_name).unwrap();
-                let processed_res = vector_res.into_iter().map(|mut scored_point| {
-                    match req.query {
-                        QueryEnum::Nearest(_) => {
-                            scored_point.score = distance.postprocess_score(scored_point.score);
-                        }
-                        // Don't post-process if we are dealing with custom scoring
-                        QueryEnum::RecommendBestScore(_)
-                        | QueryEnum::RecommendSumScores(_)
-                        | QueryEnum::Discover(_)
-                        | QueryEnum::Context(_)
-                        | QueryEnum::FeedbackNaive(_) => {}
-                    };
-                    scored_point
-                });
-
-                if let Some(threshold) = req.score_threshold {
-                    processed_res
-                        .take_while(|scored_point| {
-                            distance.check_threshold(scored_point.score, threshold)
-                        })
-                        .collect()
-                } else {
-                    processed_res.collect()
-                }
-            })
-            .collect();
+        let top_results = postprocess_results(res, &core_request, &collection_params);
         Ok(top_results)
     }
 }
+
+/// Post-process scores and apply score thresholds of the requests
+fn postprocess_results(
+    results: Vec<Vec<ScoredPoint>>,
+    core_request: &CoreSearchRequestBatch,
+    collection_params: &CollectionParams,
+) -> Vec<Vec<ScoredPoint>> {
+    results
+        .into_iter()
+        .zip(core_request.searches.iter())
+        .map(|(vector_res, req)| {
+            let vector_name = req.query.get_vector_name();
+            let distance = collection_params.get_distance(vector_name).unwrap();
+            let processed_res = vector_res.into_iter().map(|mut scored_point| {
+                match req.query {
+                    QueryEnum::Nearest(_) => {
+                        scored_point.score = distance.postprocess_score(scored_point.score);
+                    }
+                    // Don't post-process if we are dealing with custom scoring
+                    QueryEnum::RecommendBestScore(_)
+                    | QueryEnum::RecommendSumScores(_)
+                    | QueryEnum::Discover(_)
+                    | QueryEnum::Context(_)
+                    | QueryEnum::FeedbackNaive(_) => {}
+                };
+                scored_point
+            });
+
+            if let Some(threshold) = req.score_threshold {
+                processed_res
+                    .take_while(|scored_point| {
+                        distance.check_threshold(scored_point.score, threshold)
+                    })
+                    .collect()
+            } else {
+                processed_res.collect()
+            }
+        })
+        .collect()
+}
@@ -6,8 +6,10 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::DeferredBehavior;
 use segment::data_types::facets::{FacetParams, FacetResponse};
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::quantiles::ValueSamples;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, PayloadKeyType, PointIdType, ScoredPoint, WithPayload,
+    WithPayloadInterface, WithVector,
 };
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -21,15 +23,17 @@ use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::common::adaptive_handle::AdaptiveSearchHandle;
 use crate::operations::OperationWithClockTag;
 use crate::operations::generalizer::Generalizer;
+use crate::operations::loggable::Loggable as _;
 use crate::operations::shared_storage_config::DEFAULT_UPDATE_QUEUE_RAM_BUFFER;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CountResult, PointRequestInternal,
-    UpdateResult, UpdateStatus,
+    PointVersion, UpdateResult, UpdateStatus,
 };
 use crate::operations::universal_query::planned_query::PlannedQuery;
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use crate::operations::verification::operation_rate_cost::{BASE_COST, filter_rate_cost};
 use crate::profiling::interface::log_request_to_collector;
+use crate::profiling::slow_query_log::{SlowQueryLogEntry, plan_filters, slow_query_log_config};
 use crate::shards::local_shard::LocalShard;
 use crate::shards::shard_trait::{ShardOperation, WaitUntil};
 use crate::update_handler::{OperationData, UpdateSignal};
@@ -123,6 +127,41 @@ impl LocalShard {
             clock_tag,
         })
     }
+
+    /// Write the request into the slow query log, if it took long enough.
+    ///
+    /// Filters are paired with the actual number of points they matched, if it is known.
+    /// Filters of the logged requests are planned in the background, so neither fast nor slow
+    /// requests wait for it.
+    fn log_slow_query<'a>(
+        &self,
+        request_name: &'static str,
+        duration: Duration,
+        filters: impl IntoIterator<Item = (Option<&'a Filter>, Option<usize>)>,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) {
+        let Some(config) = slow_query_log_config(duration) else {
+            return;
+        };
+
+        let entry = SlowQueryLogEntry::new(
+            self.collection_name.clone(),
+            request_name,
+            duration,
+            hw_measurement_acc,
+        );
+        let filters: Vec<_> = filters
+            .into_iter()
+            .filter_map(|(filter, actual)| Some((filter?.clone(), actual)))
+            .collect();
+        let segments = self.segments.clone();
+        let payload_schema = self.payload_index_schema.read().schema.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let plans = plan_filters(&segments, &filters, &payload_schema, config);
+            entry.with_filters(plans).log();
+        });
+    }
 }
 
 /// Wait for an update previously dispatched via [`LocalShard::submit_update`].
@@ -262,7 +301,7 @@ impl ShardOperation for LocalShard {
                     filter.as_ref(),
                     search_runtime_handle,
                     timeout,
-                    hw_measurement_acc,
+                    hw_measurement_acc.clone(),
                     DeferredBehavior::Exclude,
                 )
                 .await
@@ -276,7 +315,7 @@ impl ShardOperation for LocalShard {
                     search_runtime_handle,
                     &order_by,
                     timeout,
-                    hw_measurement_acc,
+                    hw_measurement_acc.clone(),
                     DeferredBehavior::Exclude,
                 )
                 .await
@@ -290,6 +329,12 @@ impl ShardOperation for LocalShard {
         } else {
             None
         };
+        self.log_slow_query(
+            request.request_name(),
+            elapsed,
+            [(filter.as_ref(), None)],
+            &hw_measurement_acc,
+        );
         log_request_to_collector(&self.collection_name, elapsed, cpu_usage_ratio, || request);
         result
     }
@@ -339,8 +384,17 @@ impl ShardOperation for LocalShard {
             request.searches.iter().map(|s| s.search_rate_cost()).sum()
         })?;
         let timeout = self.timeout_or_default_search_timeout(timeout);
-        self.do_search(request, search_runtime_handle, timeout, hw_measurement_acc)
-            .await
+        let mut results = self
+            .do_search(request, search_runtime_handle, timeout, hw_measurement_acc)
+            .await?;
+
+        // Provenance is only returned by the queries requesting it
+        results
+            .iter_mut()
+            .flatten()
+            .for_each(|point| point.provenance = None);
+
+        Ok(results)
     }
 
     /// This call is rate limited by the read rate limiter.
@@ -369,7 +423,7 @@ impl ShardOperation for LocalShard {
                 self.read_filtered(
                     request.filter.as_ref(),
                     search_runtime_handle,
-                    hw_measurement_acc,
+                    hw_measurement_acc.clone(),
                     Some(timeout),
                     deferred_behavior,
                 ),
@@ -392,6 +446,17 @@ impl ShardOperation for LocalShard {
         } else {
             None
         };
+        let actual = if request.exact {
+            result.as_ref().ok().copied()
+        } else {
+            None
+        };
+        self.log_slow_query(
+            request.request_name(),
+            elapsed,
+            [(request.filter.as_ref(), actual)],
+            &hw_measurement_acc,
+        );
         log_request_to_collector(&self.collection_name, elapsed, cpu_usage_ratio, || request);
         result.map(|total_count| CountResult { count: total_count })
     }
@@ -453,6 +518,43 @@ impl ShardOperation for LocalShard {
         result
     }
 
+    /// This call is rate limited by the read rate limiter.
+    async fn point_versions(
+        &self,
+        ids: Arc<Vec<PointIdType>>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+        deferred_behavior: DeferredBehavior,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        // Check read rate limiter before proceeding
+        self.check_read_rate_limiter(&hw_measurement_acc, "point_versions", || ids.len())?;
+        let timeout = self.timeout_or_default_search_timeout(timeout);
+
+        let versions = tokio::time::timeout(
+            timeout,
+            SegmentsSearcher::point_versions(
+                self.segments.clone(),
+                &ids,
+                search_runtime_handle,
+                timeout,
+                deferred_behavior,
+            ),
+        )
+        .await
+        .map_err(|_elapsed| CollectionError::timeout(timeout, "point_versions"))??;
+
+        let ordered_versions = ids
+            .iter()
+            .filter_map(|&id| {
+                let version = *versions.get(&id)?;
+                Some(PointVersion { id, version })
+            })
+            .collect();
+
+        Ok(ordered_versions)
+    }
+
     /// This call is rate limited by the read rate limiter.
     async fn query_batch(
         &self,
@@ -480,9 +582,21 @@ impl ShardOperation for LocalShard {
                 planned_query,
                 search_runtime_handle,
                 timeout,
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             )
-            .await;
+            .await
+            .map(|mut responses| {
+                // Segments mark every point they score, keep the marks only where requested
+                for (response, request) in responses.iter_mut().zip(requests.iter()) {
+                    if !request.with_provenance {
+                        response
+                            .iter_mut()
+                            .flatten()
+                            .for_each(|point| point.provenance = None);
+                    }
+                }
+                responses
+            });
 
         let elapsed = start_time.elapsed();
         let cpu_ratio = cpu_utilization.ratio();
@@ -491,6 +605,16 @@ impl ShardOperation for LocalShard {
         } else {
             None
         };
+        let filters = requests
+            .iter()
+            .flat_map(|request| request.filter_refs())
+            .map(|filter| (filter, None));
+        self.log_slow_query(
+            requests.request_name(),
+            elapsed,
+            filters,
+            &hw_measurement_acc,
+        );
         log_request_to_collector(&self.collection_name, elapsed, cpu_usage_ratio, || {
             requests.remove_details()
         });
@@ -523,7 +647,7 @@ impl ShardOperation for LocalShard {
                 request.clone(),
                 search_runtime_handle,
                 timeout,
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             )
             .await
         } else {
@@ -531,7 +655,7 @@ impl ShardOperation for LocalShard {
                 request.clone(),
                 search_runtime_handle,
                 timeout,
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             )
             .await
         };
@@ -542,8 +666,29 @@ impl ShardOperation for LocalShard {
         } else {
             None
         };
+        self.log_slow_query(
+            request.request_name(),
+            elapsed,
+            [(request.filter.as_ref(), None)],
+            &hw_measurement_acc,
+        );
         log_request_to_collector(&self.collection_name, elapsed, cpu_usage_ratio, || request);
-        result.map(|hits| FacetResponse { hits })
+        // All values are returned, they are limited when merged across shards
+        result.map(|hits| FacetResponse {
+            hits,
+            other_count: 0,
+        })
+    }
+
+    async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        let timeout = self.timeout_or_default_search_timeout(timeout);
+        self.segments_value_samples(key, search_runtime_handle, timeout)
+            .await
     }
 
     /// Finishes ongoing update tasks
@@ -12,7 +12,7 @@ use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::manifest::SegmentManifest;
 use segment::entry::StorageSegmentEntry;
 use segment::types::{SegmentConfig, SnapshotFormat};
-use shard::files::{APPLIED_SEQ_FILE, SEGMENTS_PATH, WAL_PATH};
+use shard::files::{APPLIED_SEQ_FILE, AUTO_STOPWORDS_FILE, SEGMENTS_PATH, WAL_PATH};
 use shard::locked_segment::LockedSegment;
 use shard::operations::OperationWithClockTag;
 use shard::payload_index_schema::PayloadIndexSchema;
@@ -111,6 +111,7 @@ impl LocalShard {
                 let wal_guard = wal.blocking_lock_owned();
 
                 LocalShardClocks::archive_data(&shard_path, &tar)?;
+                Self::snapshot_auto_stopwords(&shard_path, &tar)?;
 
                 // Staging delay
                 #[cfg(feature = "staging")]
@@ -228,6 +229,23 @@ impl LocalShard {
         Ok(())
     }
 
+    /// snapshot the stopwords detected for the full-text indexes
+    fn snapshot_auto_stopwords(
+        shard_path: &Path,
+        tar: &tar_ext::BuilderExt,
+    ) -> CollectionResult<()> {
+        let auto_stopwords_path = shard::files::auto_stopwords_path(shard_path);
+        if auto_stopwords_path.exists() {
+            tar.blocking_append_file(&auto_stopwords_path, Path::new(AUTO_STOPWORDS_FILE))
+                .map_err(|err| {
+                    CollectionError::service_error(format!(
+                        "Error while archiving auto_stopwords: {err}"
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+
     /// snapshot the applied_seq file
     fn snapshot_applied_seq(
         applied_seq_path: PathBuf,
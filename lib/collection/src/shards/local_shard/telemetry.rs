@@ -76,6 +76,18 @@ impl LocalShard {
                     .get_statistics(detail)
             })
             .fold(Default::default(), |total, stats| total + stats);
+        let requantizations = self
+            .optimizers
+            .load()
+            .iter()
+            .find(|optimizer| optimizer.name() == "requantization")
+            .map(|optimizer| {
+                optimizer
+                    .get_telemetry_counter()
+                    .lock()
+                    .get_statistics(detail)
+            })
+            .unwrap_or_default();
 
         let status = self
             .get_optimization_status(timeout.saturating_sub(start.elapsed()))
@@ -107,6 +119,7 @@ impl LocalShard {
             optimizations: Some(OptimizerTelemetry {
                 status,
                 optimizations,
+                requantizations,
                 log: (detail.level >= DetailsLevel::Level4)
                     .then(|| self.optimizers_log.lock().to_telemetry()),
             }),
@@ -10,10 +10,11 @@ use common::tar_ext;
 use common::types::{DeferredBehavior, TelemetryDetail};
 use parking_lot::Mutex as ParkingMutex;
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::quantiles::ValueSamples;
 use segment::index::field_index::CardinalityEstimation;
 use segment::types::{
-    ExtendedPointId, Filter, PointIdType, ScoredPoint, SizeStats, SnapshotFormat, StrictModeConfig,
-    WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, PayloadKeyType, PointIdType, ScoredPoint, SizeStats, SnapshotFormat,
+    StrictModeConfig, WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -33,7 +34,7 @@ use crate::operations::operation_effect::{
 };
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CountResult, OptimizersStatus,
-    PointRequestInternal, UpdateResult,
+    PointRequestInternal, PointVersion, UpdateResult,
 };
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use crate::shards::local_shard::LocalShard;
@@ -372,6 +373,26 @@ impl ShardOperation for ProxyShard {
             .await
     }
 
+    async fn point_versions(
+        &self,
+        ids: Arc<Vec<PointIdType>>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+        deferred_behavior: DeferredBehavior,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .point_versions(
+                ids,
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+                deferred_behavior,
+            )
+            .await
+    }
+
     /// Forward read-only `query` to `wrapped_shard`
     async fn query_batch(
         &self,
@@ -399,6 +420,18 @@ impl ShardOperation for ProxyShard {
             .await
     }
 
+    async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        let local_shard = &self.wrapped_shard;
+        local_shard
+            .value_samples(key, search_runtime_handle, timeout)
+            .await
+    }
+
     async fn stop_gracefully(self) {
         let local_shard = self.wrapped_shard;
         local_shard.stop_gracefully().await;
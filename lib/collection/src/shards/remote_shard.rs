@@ -12,10 +12,11 @@ use api::grpc::qdrant::{
     CollectionOperationResponse, CoreSearchBatchPointsInternal, CountPoints, CountPointsInternal,
     CountResponse, FacetCountsInternal, GetCollectionInfoRequest, GetCollectionInfoRequestInternal,
     GetPoints, GetPointsInternal, GetShardOptimizationsRequest, GetShardRecoveryPointRequest,
-    HealthCheckRequest, InitiateShardTransferRequest, QueryBatchPointsInternal,
-    QueryBatchResponseInternal, QueryShardPoints, RecoverShardSnapshotRequest,
-    RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal, SearchBatchResponse,
-    ShardSnapshotLocation, UpdateShardCutoffPointRequest, WaitForShardStateRequest,
+    HealthCheckRequest, InitiateShardTransferRequest, PointVersionsInternal,
+    QueryBatchPointsInternal, QueryBatchResponseInternal, QueryShardPoints,
+    RecoverShardSnapshotRequest, RecoverSnapshotResponse, ScrollPoints, ScrollPointsInternal,
+    SearchBatchResponse, ShardSnapshotLocation, UpdateShardCutoffPointRequest,
+    ValueSamplesInternal, WaitForShardStateRequest,
 };
 use api::grpc::transport_channel_pool::{MAX_GRPC_CHANNEL_TIMEOUT, PoolInterceptor};
 use api::grpc::update_operation::Update;
@@ -30,8 +31,10 @@ use segment::common::operation_time_statistics::{
 };
 use segment::data_types::facets::{FacetParams, FacetResponse, FacetValueHit};
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::quantiles::{ValueSample, ValueSamples};
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    ExtendedPointId, Filter, PayloadKeyType, PointIdType, ScoredPoint, WithPayload,
+    WithPayloadInterface, WithVector,
 };
 use semver::Version;
 use shard::count::CountRequestInternal;
@@ -56,7 +59,7 @@ use crate::operations::point_ops::{PointOperations, WriteOrdering};
 use crate::operations::snapshot_ops::SnapshotPriority;
 use crate::operations::types::{
     CollectionError, CollectionInfo, CollectionResult, CoreSearchRequest, CountResult,
-    PointRequestInternal, UpdateResult,
+    PointRequestInternal, PointVersion, UpdateResult,
 };
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use crate::operations::vector_ops::VectorOperations;
@@ -151,6 +154,24 @@ impl RemoteShard {
             .map_err(|err| err.into())
     }
 
+    /// Like [`Self::with_points_client`], but doesn't retry updates on shard unavailable
+    /// rejections, as they are not safe to repeat.
+    async fn with_points_client_update<T, O: Future<Output = Result<T, Status>>>(
+        &self,
+        f: impl Fn(PointsInternalClient<InterceptedService<Channel, PoolInterceptor>>) -> O,
+    ) -> CollectionResult<T> {
+        let current_address = self.current_address()?;
+        self.channel_service
+            .channel_pool
+            .with_channel_non_idempotent(&current_address, |channel| {
+                let client = PointsInternalClient::new(channel);
+                let client = client.max_decoding_message_size(usize::MAX);
+                f(client)
+            })
+            .await
+            .map_err(|err| err.into())
+    }
+
     async fn with_collections_client<T, O: Future<Output = Result<T, Status>>>(
         &self,
         f: impl Fn(CollectionsInternalClient<InterceptedService<Channel, PoolInterceptor>>) -> O,
@@ -492,7 +513,7 @@ impl RemoteShard {
         };
 
         let point_operation_response = self
-            .with_points_client(|mut client| async move {
+            .with_points_client_update(|mut client| async move {
                 client
                     .update_batch(tonic::Request::new(batch_request.clone()))
                     .await
@@ -575,7 +596,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client.upsert(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -591,7 +612,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client.upsert(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -607,7 +628,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client.delete(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -623,7 +644,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client.delete(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -639,7 +660,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client.sync(tonic::Request::new(request.clone())).await
                     })
                     .await?
@@ -657,7 +678,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     )?;
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .update_vectors(tonic::Request::new(request.clone()))
                             .await
@@ -676,7 +697,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .delete_vectors(tonic::Request::new(request.clone()))
                             .await
@@ -695,7 +716,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .delete_vectors(tonic::Request::new(request.clone()))
                             .await
@@ -715,7 +736,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .set_payload(tonic::Request::new(request.clone()))
                             .await
@@ -733,7 +754,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .delete_payload(tonic::Request::new(request.clone()))
                             .await
@@ -751,7 +772,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .clear_payload(tonic::Request::new(request.clone()))
                             .await
@@ -769,7 +790,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .clear_payload(tonic::Request::new(request.clone()))
                             .await
@@ -787,7 +808,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .overwrite_payload(tonic::Request::new(request.clone()))
                             .await
@@ -808,7 +829,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .create_field_index(tonic::Request::new(request.clone()))
                             .await
@@ -826,7 +847,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .delete_field_index(tonic::Request::new(request.clone()))
                             .await
@@ -847,7 +868,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .create_vector_name(tonic::Request::new(request.clone()))
                             .await
@@ -865,7 +886,7 @@ impl RemoteShard {
                         timeout,
                         ordering,
                     );
-                    self.with_points_client(|mut client| async move {
+                    self.with_points_client_update(|mut client| async move {
                         client
                             .delete_vector_name(tonic::Request::new(request.clone()))
                             .await
@@ -1426,6 +1447,56 @@ impl ShardOperation for RemoteShard {
         result.map_err(|e| e.into())
     }
 
+    async fn point_versions(
+        &self,
+        ids: Arc<Vec<PointIdType>>,
+        _search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+        // TODO(deferred): Find a solution for this parameter, and don't simply ignore it.
+        _deferred_behavior: DeferredBehavior,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        let processed_timeout = Self::process_read_timeout(timeout, "point versions")?;
+        let versions_request = &PointVersionsInternal {
+            collection_name: self.collection_id.clone(),
+            ids: ids.iter().copied().map(ExtendedPointId::into).collect(),
+            shard_id: self.id,
+            timeout: processed_timeout.map(|t| t.as_secs()),
+        };
+
+        let versions_response = self
+            .with_points_client(|mut client| async move {
+                let mut request = tonic::Request::new(versions_request.clone());
+                if let Some(timeout) = processed_timeout {
+                    request.set_timeout(timeout);
+                }
+                client.point_versions(request).await
+            })
+            .await?
+            .into_inner();
+
+        if let Some(hw_usage) = versions_response.usage {
+            hw_measurement_acc.accumulate_request(hw_usage);
+        }
+
+        let result: Result<Vec<PointVersion>, Status> = versions_response
+            .versions
+            .into_iter()
+            .map(|point| {
+                let id = point
+                    .id
+                    .ok_or_else(|| Status::invalid_argument("Point version without id"))?
+                    .try_into()?;
+                Ok(PointVersion {
+                    id,
+                    version: point.version,
+                })
+            })
+            .collect();
+
+        result.map_err(|e| e.into())
+    }
+
     async fn query_batch(
         &self,
         requests: Arc<Vec<ShardQueryRequest>>,
@@ -1469,12 +1540,17 @@ impl ShardOperation for RemoteShard {
             time: _,
             hardware_usage,
             inference_usage: _, // Remote shards don't have inference usage, so we can ignore it
+            stats,
         } = batch_response;
 
         if let Some(hw_usage) = hardware_usage {
             hw_measurement_acc.accumulate_request(hw_usage);
         }
 
+        if let Some(stats) = stats {
+            stats.accumulate_into(&hw_measurement_acc.query_stats());
+        }
+
         let result = results
             .into_iter()
             .zip(requests.iter())
@@ -1551,13 +1627,57 @@ impl ShardOperation for RemoteShard {
             .map(FacetValueHit::try_from)
             .try_collect()?;
 
-        let result = FacetResponse { hits };
+        let result = FacetResponse {
+            hits,
+            other_count: response.other_count as usize,
+        };
 
         timer.set_success(true);
 
         Ok(result)
     }
 
+    async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        _search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        let processed_timeout = Self::process_read_timeout(timeout, "value samples")?;
+        let mut timer = ScopeDurationMeasurer::new(&self.telemetry_search_durations);
+        timer.set_success(false);
+
+        let response = self
+            .with_points_client(|mut client| async move {
+                let request = ValueSamplesInternal {
+                    collection_name: self.collection_id.clone(),
+                    key: key.to_string(),
+                    shard_id: self.id,
+                    timeout: processed_timeout.map(|t| t.as_secs()),
+                };
+
+                let mut request = tonic::Request::new(request);
+
+                if let Some(timeout) = timeout {
+                    request.set_timeout(timeout);
+                }
+
+                client.value_samples(request).await
+            })
+            .await?
+            .into_inner();
+
+        let samples = response
+            .samples
+            .into_iter()
+            .map(ValueSample::from)
+            .collect();
+
+        timer.set_success(true);
+
+        Ok(ValueSamples { samples })
+    }
+
     async fn stop_gracefully(self) {
         // No background operations to stop on RemoteShard
     }
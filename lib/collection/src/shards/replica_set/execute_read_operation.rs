@@ -1,6 +1,7 @@
 use std::cmp;
 use std::fmt::Write as _;
 use std::ops::Deref as _;
+use std::sync::atomic::Ordering;
 
 use futures::future::{self, BoxFuture};
 use futures::stream::FuturesUnordered;
@@ -94,10 +95,12 @@ impl ShardReplicaSet {
         };
 
         if active_count + initializing_count < required_successful_results {
-            return Err(CollectionError::service_error(format!(
-                "The replica set for shard {} on peer {} does not have enough active replicas",
+            return Err(CollectionError::shard_unavailable(format!(
+                "The replica set for shard {} on peer {} does not have enough active replicas: \
+                 read consistency requires {required_successful_results}, but only {} available",
                 self.shard_id,
                 self.this_peer_id(),
+                active_count + initializing_count,
             )));
         }
 
@@ -301,7 +304,10 @@ impl ShardReplicaSet {
 
                 _ = &mut fan_out_delay_sleep, if !is_fan_out_delay_resolved => {
                     is_fan_out_delay_resolved = true;
-                    pending_operations.extend(operations.next());
+                    if let Some(operation) = operations.next() {
+                        self.delayed_fan_outs.fetch_add(1, Ordering::Relaxed);
+                        pending_operations.push(operation);
+                    }
                     continue;
                 }
             }
@@ -13,14 +13,23 @@ use std::collections::{HashMap, HashSet};
 use std::ops::Deref as _;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
 use std::time::Duration;
 
 use common::budget::ResourceBudget;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::populate_budget::PopulateBudget;
 use common::save_on_disk::SaveOnDisk;
 use common::types::DeferredBehavior;
 use replica_set_state::{ReplicaSetState, ReplicaState};
-use segment::types::{ExtendedPointId, Filter, SeqNumberType, ShardKey, StrictModeConfig};
+use segment::data_types::index::Language;
+use segment::data_types::text_query::{
+    QueryStopwords, SnippetParams, TextSnippet, TextValueMatches,
+};
+use segment::types::{
+    ExtendedPointId, Filter, PayloadKeyType, SeqNumberType, ShardKey, StrictModeConfig, VectorName,
+};
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
 use serde::{Deserialize, Serialize};
 use shard::operations::optimization::{
     OptimizationsRequestOptions, OptimizationsResponse, OptimizationsSummary,
@@ -125,6 +134,9 @@ pub struct ShardReplicaSet {
     /// Local clock set, used to tag new operations on this shard.
     clock_set: Mutex<ClockSet>,
     pub partial_snapshot_meta: PartialSnapshotMeta,
+    /// Number of reads, which sent an additional request to another replica after
+    /// `read_fan_out_delay_ms`, since the start.
+    delayed_fan_outs: AtomicUsize,
 }
 
 pub type AbortShardTransfer = Arc<dyn Fn(ShardTransfer, &str) + Send + Sync>;
@@ -224,6 +236,7 @@ impl ShardReplicaSet {
             write_ordering_lock: Mutex::new(()),
             clock_set: Default::default(),
             partial_snapshot_meta: PartialSnapshotMeta::default(),
+            delayed_fan_outs: AtomicUsize::new(0),
         })
     }
 
@@ -355,6 +368,7 @@ impl ShardReplicaSet {
             write_ordering_lock: Mutex::new(()),
             clock_set: Default::default(),
             partial_snapshot_meta: PartialSnapshotMeta::default(),
+            delayed_fan_outs: AtomicUsize::new(0),
         };
 
         // `active_remote_shards` includes `Active` and `ReshardingScaleDown` replicas!
@@ -1260,6 +1274,147 @@ impl ShardReplicaSet {
         local.get_wal_entries(count).await
     }
 
+    pub(crate) async fn point_quantized_vector(
+        &self,
+        point_id: ExtendedPointId,
+        vector_name: &VectorName,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<QuantizedVectorRecord>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local
+            .point_quantized_vector(point_id, vector_name, hw_measurement_acc)
+            .await
+    }
+
+    pub(crate) async fn point_text_tokens(
+        &self,
+        point_id: ExtendedPointId,
+        key: &PayloadKeyType,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local
+            .point_text_tokens(point_id, key, hw_measurement_acc)
+            .await
+    }
+
+    pub(crate) async fn point_text_match_offsets(
+        &self,
+        point_id: ExtendedPointId,
+        key: &PayloadKeyType,
+        text: &str,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextValueMatches>>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local
+            .point_text_match_offsets(point_id, key, text, hw_measurement_acc)
+            .await
+    }
+
+    pub(crate) async fn point_text_snippets(
+        &self,
+        point_id: ExtendedPointId,
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextSnippet>>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local
+            .point_text_snippets(point_id, key, text, params, hw_measurement_acc)
+            .await
+    }
+
+    pub(crate) async fn points_text_snippets(
+        &self,
+        point_ids: &[ExtendedPointId],
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Vec<(ExtendedPointId, Vec<TextSnippet>)>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local
+            .points_text_snippets(point_ids, key, text, params, hw_measurement_acc)
+            .await
+    }
+
+    pub(crate) async fn text_auto_stopwords(
+        &self,
+        key: &PayloadKeyType,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local.text_auto_stopwords(key).await
+    }
+
+    pub(crate) async fn text_query_stopwords(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        language: Option<Language>,
+    ) -> CollectionResult<Option<QueryStopwords>> {
+        let local = self.local.read().await;
+
+        let Some(local) = local.as_ref() else {
+            return Err(CollectionError::not_found("Peer does not have local shard"));
+        };
+
+        local.text_query_stopwords(key, text, language).await
+    }
+
+    /// Populate pages of the indexes of the payload field of the local shard, which fit into the
+    /// `budget`. Returns the remaining budget.
+    pub(crate) async fn populate_payload_indexes(
+        &self,
+        key: &PayloadKeyType,
+        budget: PopulateBudget,
+    ) -> CollectionResult<PopulateBudget> {
+        match self.local.read().await.as_ref() {
+            Some(local) => local.populate_payload_indexes(key, budget).await,
+            None => Ok(budget),
+        }
+    }
+
+    /// Approximate RAM usage in bytes of the payload field indexes kept in memory by the local
+    /// shard.
+    pub(crate) async fn payload_indexes_ram_bytes(&self) -> usize {
+        self.local
+            .read()
+            .await
+            .as_ref()
+            .map_or(0, |local| local.payload_indexes_ram_bytes())
+    }
+
     pub(crate) fn get_snapshots_storage_manager(&self) -> CollectionResult<SnapshotStorageManager> {
         SnapshotStorageManager::new(&self.shared_storage_config.snapshots_config)
     }
@@ -5,6 +5,7 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::DeferredBehavior;
 use futures::FutureExt as _;
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::quantiles::ValueSamples;
 use segment::types::*;
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -281,4 +282,57 @@ impl ShardReplicaSet {
         )
         .await
     }
+
+    pub async fn point_versions(
+        &self,
+        ids: Arc<Vec<PointIdType>>,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        local_only: bool,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<PointVersion>> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let ids = ids.clone();
+                let search_runtime = self.search_runtime.clone();
+
+                let hw_acc = hw_measurement_acc.clone();
+
+                async move {
+                    shard
+                        .point_versions(
+                            ids,
+                            &search_runtime,
+                            timeout,
+                            hw_acc,
+                            DeferredBehavior::Exclude,
+                        )
+                        .await
+                }
+                .boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
+
+    pub async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        read_consistency: Option<ReadConsistency>,
+        local_only: bool,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples> {
+        self.execute_and_resolve_read_operation(
+            |shard| {
+                let search_runtime = self.search_runtime.clone();
+
+                async move { shard.value_samples(key, &search_runtime, timeout).await }.boxed()
+            },
+            read_consistency,
+            local_only,
+        )
+        .await
+    }
 }
@@ -1,10 +1,11 @@
 use std::ops::Deref as _;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use common::types::TelemetryDetail;
-use segment::types::SizeStats;
+use segment::types::{SeqNumberType, SizeStats};
 
-use crate::operations::types::{CollectionResult, OptimizersStatus};
+use crate::operations::types::{CollectionResult, OptimizersStatus, ShardStatus};
 use crate::shards::replica_set::ShardReplicaSet;
 use crate::shards::telemetry::{PartialSnapshotTelemetry, ReplicaSetTelemetry};
 
@@ -41,6 +42,7 @@ impl ShardReplicaSet {
                 is_recovering: self.partial_snapshot_meta.is_recovery_lock_taken(),
                 recovery_timestamp: self.partial_snapshot_meta.recovery_timestamp(),
             }),
+            delayed_fan_outs: Some(self.delayed_fan_outs.load(Ordering::Relaxed)),
         })
     }
 
@@ -57,6 +59,24 @@ impl ShardReplicaSet {
         Some(local.get_optimization_status(timeout).await)
     }
 
+    /// Status of the local shard, `None` if there is no local shard or it is a dummy shard.
+    pub(crate) async fn local_shard_status(&self) -> Option<ShardStatus> {
+        let local_shard = self.local.read().await;
+        let local = local_shard.as_ref()?.local_shard()?;
+        Some(local.local_shard_status().await.0)
+    }
+
+    /// Version of the last operation applied to the local replica, if it is a local shard
+    pub(crate) async fn local_max_segment_version(&self) -> Option<SeqNumberType> {
+        let local_shard = self.local.read().await;
+        let local = local_shard.as_ref()?.local_shard()?;
+        local
+            .max_segment_version()
+            .await
+            .inspect_err(|err| log::error!("{err}"))
+            .ok()
+    }
+
     pub(crate) async fn get_size_stats(&self, timeout: Duration) -> CollectionResult<SizeStats> {
         let local_shard = self.local.read().await;
 
@@ -870,9 +870,11 @@ mod tests {
     use std::collections::HashSet;
     use std::num::NonZeroU32;
     use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use common::budget::ResourceBudget;
     use common::save_on_disk::SaveOnDisk;
+    use common::types::{DetailsLevel, TelemetryDetail};
     use segment::types::Distance;
     use tempfile::{Builder, TempDir};
     use tokio::runtime::Handle;
@@ -972,6 +974,70 @@ mod tests {
         assert_eq!(rs.highest_alive_replica_peer_id(), Some(4));
     }
 
+    #[tokio::test]
+    async fn test_delayed_fan_out_telemetry() {
+        let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+        let rs = new_shard_replica_set(&collection_dir).await;
+
+        // Only remote replicas are readable, read from one of them unless it is slow
+        rs.set_replica_state(2, ReplicaState::Active).await.unwrap();
+        rs.set_replica_state(3, ReplicaState::Active).await.unwrap();
+        {
+            let mut config = rs.collection_config.write().await;
+            config.params.read_fan_out_factor = Some(0);
+            config.params.read_fan_out_delay_ms = Some(10);
+        }
+
+        let delayed_fan_outs = async || {
+            rs.get_telemetry_data(
+                TelemetryDetail::new(DetailsLevel::Level0, false),
+                Duration::from_secs(10),
+            )
+            .await
+            .unwrap()
+            .delayed_fan_outs
+        };
+        assert_eq!(delayed_fan_outs().await, Some(0));
+
+        // The first replica responds before the delay, no other replica is requested
+        let calls = AtomicUsize::new(0);
+        let result = rs
+            .execute_read_operation(
+                |_shard| {
+                    let call = calls.fetch_add(1, Ordering::Relaxed);
+                    async move { Ok(call) }.boxed()
+                },
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result, 0);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        assert_eq!(delayed_fan_outs().await, Some(0));
+
+        // The first replica is too slow, the second one is requested after the delay and wins
+        let calls = AtomicUsize::new(0);
+        let result = rs
+            .execute_read_operation(
+                |_shard| {
+                    let call = calls.fetch_add(1, Ordering::Relaxed);
+                    async move {
+                        if call == 0 {
+                            tokio::time::sleep(Duration::from_secs(10)).await;
+                        }
+                        Ok(call)
+                    }
+                    .boxed()
+                },
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result, 1);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(delayed_fan_outs().await, Some(1));
+    }
+
     const TEST_OPTIMIZERS_CONFIG: OptimizersConfig = OptimizersConfig {
         deleted_threshold: 0.9,
         vacuum_min_vector_number: 1000,
@@ -983,6 +1049,7 @@ mod tests {
         flush_interval_sec: 30,
         max_optimization_threads: Some(2),
         prevent_unoptimized: None,
+        requantization: None,
     };
 
     async fn new_shard_replica_set(collection_dir: &TempDir) -> ShardReplicaSet {
@@ -1012,6 +1079,7 @@ mod tests {
             strict_mode_config: None,
             uuid: None,
             metadata: None,
+            boost_rules: Default::default(),
         };
 
         let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
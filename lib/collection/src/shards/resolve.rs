@@ -5,12 +5,13 @@ use std::rc::Rc;
 
 use itertools::Itertools;
 use segment::data_types::facets::{FacetResponse, FacetValue};
+use segment::data_types::quantiles::ValueSamples;
 use segment::types::{Payload, ScoredPoint};
 use shard::retrieve::record_internal::RecordInternal;
 use tinyvec::TinyVec;
 
 use crate::common::transpose_iterator::transposed_iter;
-use crate::operations::types::CountResult;
+use crate::operations::types::{CountResult, PointVersion};
 use crate::operations::universal_query::shard_query::ShardQueryResponse;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -81,7 +82,7 @@ impl Resolve for FacetResponse {
 
         let resolved_counts: HashMap<_, _> = responses
             .iter()
-            .flat_map(|FacetResponse { hits }| hits)
+            .flat_map(|FacetResponse { hits, .. }| hits)
             // Collect all hits into a Hashmap of {value -> Vec<CountResult>}
             .fold(
                 HashMap::new(),
@@ -107,7 +108,15 @@ impl Resolve for FacetResponse {
             })
             .collect();
 
-        let filtered_iters = responses.into_iter().map(|FacetResponse { hits }| {
+        let other_counts = responses
+            .iter()
+            .map(|response| CountResult {
+                count: response.other_count,
+            })
+            .collect();
+        let other_count = CountResult::resolve(other_counts, condition).count;
+
+        let filtered_iters = responses.into_iter().map(|FacetResponse { hits, .. }| {
             hits.into_iter().filter_map(|mut hit| {
                 resolved_counts.get(&hit.value).map(|&count| {
                     // Use the resolved count
@@ -129,16 +138,42 @@ impl Resolve for FacetResponse {
 
         FacetResponse {
             hits: resolved_hits,
+            other_count,
         }
     }
 }
 
+impl Resolve for ValueSamples {
+    /// Samples of replicas are not comparable value by value, segments of replicas are laid out
+    /// differently. Use the samples of the replica with the resolved total count instead.
+    fn resolve(responses: Vec<Self>, condition: ResolveCondition) -> Self {
+        let counts = responses
+            .iter()
+            .map(|samples| CountResult {
+                count: samples.total_count(),
+            })
+            .collect();
+        let resolved_count = CountResult::resolve(counts, condition).count;
+
+        responses
+            .into_iter()
+            .find(|samples| samples.total_count() == resolved_count)
+            .unwrap_or_default()
+    }
+}
+
 impl Resolve for Vec<RecordInternal> {
     fn resolve(records: Vec<Self>, condition: ResolveCondition) -> Self {
         Resolver::resolve(records, |record| record.id, record_eq, condition)
     }
 }
 
+impl Resolve for Vec<PointVersion> {
+    fn resolve(versions: Vec<Self>, condition: ResolveCondition) -> Self {
+        Resolver::resolve(versions, |point| point.id, PartialEq::eq, condition)
+    }
+}
+
 impl Resolve for Vec<Vec<ScoredPoint>> {
     fn resolve(batches: Vec<Self>, condition: ResolveCondition) -> Self {
         // batches: <replica_id, <batch_id, ScoredPoints>>
@@ -548,6 +583,8 @@ mod test {
             vector: None,
             shard_key: None,
             order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
         }
     }
 
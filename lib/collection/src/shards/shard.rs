@@ -5,12 +5,21 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::populate_budget::PopulateBudget;
 use common::tar_ext;
 use common::types::TelemetryDetail;
 use futures::future::Either;
 use parking_lot::Mutex as ParkingMutex;
+use segment::data_types::index::Language;
+use segment::data_types::text_query::{
+    QueryStopwords, SnippetParams, TextSnippet, TextValueMatches,
+};
 use segment::index::field_index::CardinalityEstimation;
-use segment::types::{Filter, SeqNumberType, SizeStats, SnapshotFormat, StrictModeConfig};
+use segment::types::{
+    Filter, PayloadKeyType, PointIdType, SeqNumberType, SizeStats, SnapshotFormat,
+    StrictModeConfig, VectorName,
+};
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
 use shard::snapshots::snapshot_manifest::SnapshotManifest;
 use tokio::sync::oneshot;
 
@@ -438,6 +447,150 @@ impl Shard {
         local.get_wal_entries(count).await
     }
 
+    pub async fn point_quantized_vector(
+        &self,
+        point_id: PointIdType,
+        vector_name: &VectorName,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<QuantizedVectorRecord>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading quantized vectors not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local
+            .point_quantized_vector(point_id, vector_name, hw_measurement_acc)
+            .await
+    }
+
+    pub async fn point_text_tokens(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading text tokens not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local
+            .point_text_tokens(point_id, key, hw_measurement_acc)
+            .await
+    }
+
+    pub async fn point_text_match_offsets(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        text: &str,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextValueMatches>>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading text matches not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local
+            .point_text_match_offsets(point_id, key, text, hw_measurement_acc)
+            .await
+    }
+
+    pub async fn point_text_snippets(
+        &self,
+        point_id: PointIdType,
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Option<Vec<TextSnippet>>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading text snippets not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local
+            .point_text_snippets(point_id, key, text, params, hw_measurement_acc)
+            .await
+    }
+
+    pub async fn points_text_snippets(
+        &self,
+        point_ids: &[PointIdType],
+        key: &PayloadKeyType,
+        text: &str,
+        params: SnippetParams,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<Vec<(PointIdType, Vec<TextSnippet>)>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading text snippets not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local
+            .points_text_snippets(point_ids, key, text, params, hw_measurement_acc)
+            .await
+    }
+
+    pub async fn text_auto_stopwords(
+        &self,
+        key: &PayloadKeyType,
+    ) -> CollectionResult<Option<Vec<String>>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading text stopwords not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local.text_auto_stopwords(key).await
+    }
+
+    pub async fn text_query_stopwords(
+        &self,
+        key: &PayloadKeyType,
+        text: &str,
+        language: Option<Language>,
+    ) -> CollectionResult<Option<QueryStopwords>> {
+        let Some(local) = self.local_shard() else {
+            return Err(CollectionError::service_error(format!(
+                "Reading text stopwords not supported on {}",
+                self.variant_name(),
+            )));
+        };
+
+        local.text_query_stopwords(key, text, language).await
+    }
+
+    /// Populate pages of the indexes of the payload field, which fit into the `budget`.
+    /// Returns the remaining budget.
+    pub async fn populate_payload_indexes(
+        &self,
+        key: &PayloadKeyType,
+        budget: PopulateBudget,
+    ) -> CollectionResult<PopulateBudget> {
+        match self.local_shard() {
+            Some(local) => local.populate_payload_indexes(key, budget).await,
+            None => Ok(budget),
+        }
+    }
+
+    /// Approximate RAM usage in bytes of the payload field indexes kept in memory.
+    pub fn payload_indexes_ram_bytes(&self) -> usize {
+        self.local_shard()
+            .map_or(0, |local| local.payload_indexes_ram_bytes())
+    }
+
     pub async fn set_extended_wal_retention(&self) {
         match self {
             Shard::Local(local) => local.set_extended_wal_retention().await,
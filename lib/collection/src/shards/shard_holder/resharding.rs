@@ -644,6 +644,7 @@ impl OperationsByMode {
                                 points_op: operation,
                                 condition: Filter::new(), // Always true condition
                                 update_mode: Some(UpdateMode::UpdateOnly),
+                                expected_version: None,
                             },
                         ),
                     )]
@@ -655,6 +656,7 @@ impl OperationsByMode {
                                 points_op: operation.points_op,
                                 condition: operation.condition,
                                 update_mode: Some(UpdateMode::UpdateOnly),
+                                expected_version: operation.expected_version,
                             },
                         ),
                     )]
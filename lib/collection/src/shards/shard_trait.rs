@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::DeferredBehavior;
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::quantiles::ValueSamples;
 use segment::types::*;
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -150,6 +151,18 @@ pub trait ShardOperation {
         deferred_behavior: DeferredBehavior,
     ) -> CollectionResult<Vec<RecordInternal>>;
 
+    /// Latest versions of the points with the given ids, in the same order.
+    ///
+    /// Points which are not found are not included in the output.
+    async fn point_versions(
+        &self,
+        ids: Arc<Vec<PointIdType>>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+        deferred_behavior: DeferredBehavior,
+    ) -> CollectionResult<Vec<PointVersion>>;
+
     async fn query_batch(
         &self,
         requests: Arc<Vec<ShardQueryRequest>>,
@@ -166,6 +179,14 @@ pub trait ShardOperation {
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<FacetResponse>;
 
+    /// Values of the numeric field sampled from the integer or float indexes of all segments
+    async fn value_samples(
+        &self,
+        key: &PayloadKeyType,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+    ) -> CollectionResult<ValueSamples>;
+
     /// Signal `Stop` to all background operations gracefully
     /// and wait till they are finished.
     async fn stop_gracefully(self);
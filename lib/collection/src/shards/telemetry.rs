@@ -23,6 +23,10 @@ pub struct ReplicaSetTelemetry {
     pub replicate_states: HashMap<PeerId, ReplicaState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub partial_snapshot: Option<PartialSnapshotTelemetry>,
+    /// Number of reads, which sent an additional request to another replica after
+    /// `read_fan_out_delay_ms`, because no replica responded in time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delayed_fan_outs: Option<usize>,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -81,6 +85,9 @@ pub struct LocalShardTelemetry {
 pub struct OptimizerTelemetry {
     pub status: OptimizersStatus,
     pub optimizations: OperationDurationStatistics,
+    /// Rebuilds of segments, which quantized ranking drifted away from the exact one
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub requantizations: OperationDurationStatistics,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log: Option<Vec<TrackerTelemetry>>,
 }
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use api::rest::models::HardwareUsage;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::data_types::tiny_map::TinyMap;
@@ -12,6 +13,7 @@ use uuid::Uuid;
 use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
 use crate::operations::types::{OptimizersStatus, ReshardingInfo, ShardStatus, ShardTransferInfo};
 use crate::optimizers_builder::OptimizersConfig;
+use crate::profiling::index_advisor::AutoCreatedIndexTelemetry;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::shard::ShardId;
 use crate::shards::telemetry::{
@@ -41,6 +43,14 @@ pub struct CollectionTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub shard_clean_tasks: Option<HashMap<ShardId, ShardCleanStatusTelemetry>>,
+
+    /// Payload indexes created automatically by the index advisor of this peer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_created_indexes: Option<Vec<AutoCreatedIndexTelemetry>>,
+
+    /// Hardware resources spent on this collection by this peer since the previous telemetry read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hardware_usage: Option<HardwareUsage>,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -162,6 +172,8 @@ impl From<CollectionConfigInternal> for CollectionConfigTelemetry {
             strict_mode_config,
             uuid,
             metadata,
+            // Filters of the rules may contain user data, not reported in telemetry
+            boost_rules: _,
         } = config;
         CollectionConfigTelemetry {
             params,
@@ -433,6 +445,8 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                auto_created_indexes: None, // Not provided in internal service
+                hardware_usage: None,       // Not provided in internal service
             })
         }
     }
@@ -447,6 +461,8 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                auto_created_indexes: _,
+                hardware_usage: _,
             } = value;
 
             grpc::CollectionTelemetry {
@@ -488,6 +504,7 @@ mod internal_conversions {
                 remote,
                 replicate_states,
                 partial_snapshot,
+                delayed_fan_outs,
             } = value;
 
             grpc::ReplicaSetTelemetry {
@@ -503,6 +520,7 @@ mod internal_conversions {
                     .map(|(k, v)| (k, grpc::ReplicaState::from(v) as i32))
                     .collect(),
                 partial_snapshot: partial_snapshot.map(grpc::PartialSnapshotTelemetry::from),
+                delayed_fan_outs: delayed_fan_outs.map(|count| count as u64),
             }
         }
     }
@@ -612,6 +630,7 @@ mod internal_conversions {
                 remote,
                 replica_states,
                 partial_snapshot,
+                delayed_fan_outs,
             } = value;
 
             let replicate_states = replica_states
@@ -642,6 +661,7 @@ mod internal_conversions {
                 partial_snapshot: partial_snapshot
                     .map(PartialSnapshotTelemetry::try_from)
                     .transpose()?,
+                delayed_fan_outs: delayed_fan_outs.map(|count| count as usize),
             })
         }
     }
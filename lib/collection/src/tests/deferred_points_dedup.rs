@@ -78,6 +78,7 @@ async fn build_shard() -> (LocalShard, TempDir) {
         flush_interval_sec: 0,
         max_optimization_threads: Some(2),
         prevent_unoptimized: Some(true),
+        requantization: None,
     };
 
     let config = CollectionConfigInternal {
@@ -89,6 +90,7 @@ async fn build_shard() -> (LocalShard, TempDir) {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        boost_rules: Default::default(),
     };
 
     let payload_index_schema_file = collection_dir.path().join("payload.json");
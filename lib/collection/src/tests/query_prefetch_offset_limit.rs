@@ -63,6 +63,7 @@ async fn fixture() -> Collection {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        boost_rules: Default::default(),
     };
 
     let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
@@ -165,6 +166,9 @@ async fn test_limit_offset_with_prefetch() {
                     with_payload: WithPayloadInterface::Bool(false),
                     with_vector: WithVector::Bool(false),
                     score_threshold: None,
+                    with_provenance: false,
+                    with_explanation: false,
+                    soft_timeout: None,
                 },
                 None,
                 ShardSelectorInternal::All,
@@ -222,6 +226,9 @@ async fn test_limit_offset_with_prefetch() {
                     with_payload: WithPayloadInterface::Bool(false),
                     with_vector: WithVector::Bool(false),
                     score_threshold: None,
+                    with_provenance: false,
+                    with_explanation: false,
+                    soft_timeout: None,
                 },
                 None,
                 ShardSelectorInternal::All,
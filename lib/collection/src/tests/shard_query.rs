@@ -1,11 +1,12 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use common::budget::ResourceBudget;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
 use segment::common::reciprocal_rank_fusion::DEFAULT_RRF_K;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
-use segment::types::{PointIdType, WithPayloadInterface, WithVector};
+use segment::types::{PointIdType, ScoreStageKind, WithPayloadInterface, WithVector};
 use shard::query::query_enum::QueryEnum;
 use tempfile::Builder;
 use tokio::runtime::Handle;
@@ -77,6 +78,9 @@ async fn test_shard_query_rrf_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -116,6 +120,9 @@ async fn test_shard_query_rrf_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -166,6 +173,9 @@ async fn test_shard_query_rrf_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -213,6 +223,9 @@ async fn test_shard_query_rrf_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -301,6 +314,9 @@ async fn test_shard_query_vector_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -328,6 +344,9 @@ async fn test_shard_query_vector_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -358,6 +377,9 @@ async fn test_shard_query_vector_rescoring() {
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -435,6 +457,9 @@ async fn test_shard_query_payload_vector() {
         params: None,
         with_vector: WithVector::Bool(true), // requesting vector
         with_payload: WithPayloadInterface::Bool(true), // requesting payload
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let hw_acc = HwMeasurementAcc::new();
@@ -455,3 +480,318 @@ async fn test_shard_query_payload_vector() {
         assert!(scored_point.payload.is_some());
     });
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_shard_query_provenance() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+
+    let config = create_collection_config();
+
+    let collection_name = "test".to_string();
+
+    let update_runtime = Handle::current();
+    let current_runtime: AdaptiveSearchHandle = AdaptiveSearchHandle::current_for_tests();
+
+    let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
+    let payload_index_schema_file = payload_index_schema_dir.path().join("payload-schema.json");
+    let payload_index_schema =
+        Arc::new(SaveOnDisk::load_or_init_default(payload_index_schema_file).unwrap());
+
+    let shard = LocalShard::build(
+        0,
+        collection_name.clone(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        Arc::new(Default::default()),
+        payload_index_schema,
+        update_runtime.clone(),
+        current_runtime.clone(),
+        ResourceBudget::default(),
+        config.optimizer_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let upsert_ops = upsert_operation();
+
+    shard
+        .update(
+            upsert_ops.into(),
+            WaitUntil::Visible,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let nearest_query = QueryEnum::Nearest(NamedQuery::new(
+        VectorInternal::Dense(vec![1.0, 2.0, 3.0, 4.0]),
+        DEFAULT_VECTOR_NAME,
+    ));
+
+    // RRF fusion of two prefetches, so provenance has to survive the merge
+    let nearest_query_prefetch = ShardPrefetch {
+        prefetches: vec![],
+        query: Some(ScoringQuery::Vector(nearest_query)),
+        limit: 3,
+        params: None,
+        filter: None,
+        score_threshold: None,
+    };
+    let query = ShardQueryRequest {
+        prefetches: vec![nearest_query_prefetch.clone(), nearest_query_prefetch],
+        query: Some(ScoringQuery::Fusion(FusionInternal::Rrf {
+            k: DEFAULT_RRF_K,
+            weights: None,
+        })),
+        filter: None,
+        score_threshold: None,
+        limit: 2,
+        offset: 0,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: true,
+        with_explanation: false,
+        soft_timeout: None,
+    };
+    let without_provenance = ShardQueryRequest {
+        with_provenance: false,
+        ..query.clone()
+    };
+
+    let hw_acc = HwMeasurementAcc::new();
+    let mut responses = shard
+        .query_batch(
+            Arc::new(vec![query, without_provenance]),
+            &current_runtime,
+            None,
+            hw_acc,
+        )
+        .await
+        .unwrap();
+
+    let without_provenance = responses.pop().unwrap();
+    let with_provenance = responses.pop().unwrap();
+
+    assert_eq!(with_provenance[0].len(), 2);
+    for point in &with_provenance[0] {
+        let provenance = point.provenance.unwrap();
+        // Shard and peer are filled in by the collection
+        assert_eq!(provenance.shard_id, None);
+        assert_eq!(provenance.peer_id, None);
+    }
+
+    assert_eq!(without_provenance[0].len(), 2);
+    for point in &without_provenance[0] {
+        assert_eq!(point.provenance, None);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_shard_query_explanation() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+
+    let config = create_collection_config();
+
+    let collection_name = "test".to_string();
+
+    let update_runtime = Handle::current();
+    let current_runtime: AdaptiveSearchHandle = AdaptiveSearchHandle::current_for_tests();
+
+    let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
+    let payload_index_schema_file = payload_index_schema_dir.path().join("payload-schema.json");
+    let payload_index_schema =
+        Arc::new(SaveOnDisk::load_or_init_default(payload_index_schema_file).unwrap());
+
+    let shard = LocalShard::build(
+        0,
+        collection_name.clone(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        Arc::new(Default::default()),
+        payload_index_schema,
+        update_runtime.clone(),
+        current_runtime.clone(),
+        ResourceBudget::default(),
+        config.optimizer_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let upsert_ops = upsert_operation();
+
+    shard
+        .update(
+            upsert_ops.into(),
+            WaitUntil::Visible,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let nearest_query = QueryEnum::Nearest(NamedQuery::new(
+        VectorInternal::Dense(vec![1.0, 2.0, 3.0, 4.0]),
+        DEFAULT_VECTOR_NAME,
+    ));
+    let other_nearest_query = QueryEnum::Nearest(NamedQuery::new(
+        VectorInternal::Dense(vec![4.0, 3.0, 2.0, 1.0]),
+        DEFAULT_VECTOR_NAME,
+    ));
+
+    // Prefetched points are rescored by another vector in the shard
+    let query = ShardQueryRequest {
+        prefetches: vec![ShardPrefetch {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Vector(nearest_query)),
+            limit: 3,
+            params: None,
+            filter: None,
+            score_threshold: None,
+        }],
+        query: Some(ScoringQuery::Vector(other_nearest_query)),
+        filter: None,
+        score_threshold: None,
+        limit: 2,
+        offset: 0,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: true,
+        soft_timeout: None,
+    };
+    let without_explanation = ShardQueryRequest {
+        with_explanation: false,
+        ..query.clone()
+    };
+
+    let hw_acc = HwMeasurementAcc::new();
+    let mut responses = shard
+        .query_batch(
+            Arc::new(vec![query, without_explanation]),
+            &current_runtime,
+            None,
+            hw_acc,
+        )
+        .await
+        .unwrap();
+
+    let without_explanation = responses.pop().unwrap();
+    let with_explanation = responses.pop().unwrap();
+
+    assert_eq!(with_explanation[0].len(), 2);
+    for point in &with_explanation[0] {
+        let kinds: Vec<_> = point.score_stages.iter().map(|stage| &stage.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![&ScoreStageKind::Vector, &ScoreStageKind::Vector]
+        );
+        // The last stage produced the score of the point
+        assert_eq!(point.score_stages[1].score, point.score);
+    }
+
+    assert_eq!(without_explanation[0].len(), 2);
+    for point in &without_explanation[0] {
+        assert!(point.score_stages.is_empty());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_shard_query_soft_timeout() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+
+    let config = create_collection_config();
+
+    let collection_name = "test".to_string();
+
+    let update_runtime = Handle::current();
+    let current_runtime: AdaptiveSearchHandle = AdaptiveSearchHandle::current_for_tests();
+
+    let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
+    let payload_index_schema_file = payload_index_schema_dir.path().join("payload-schema.json");
+    let payload_index_schema =
+        Arc::new(SaveOnDisk::load_or_init_default(payload_index_schema_file).unwrap());
+
+    let shard = LocalShard::build(
+        0,
+        collection_name.clone(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        Arc::new(Default::default()),
+        payload_index_schema,
+        update_runtime.clone(),
+        current_runtime.clone(),
+        ResourceBudget::default(),
+        config.optimizer_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let upsert_ops = upsert_operation();
+
+    shard
+        .update(
+            upsert_ops.into(),
+            WaitUntil::Visible,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let nearest_query = QueryEnum::Nearest(NamedQuery::new(
+        VectorInternal::Dense(vec![1.0, 2.0, 3.0, 4.0]),
+        DEFAULT_VECTOR_NAME,
+    ));
+
+    let query = ShardQueryRequest {
+        prefetches: vec![],
+        query: Some(ScoringQuery::Vector(nearest_query)),
+        filter: None,
+        score_threshold: None,
+        limit: 3,
+        offset: 0,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
+    };
+    let with_soft_timeout = ShardQueryRequest {
+        soft_timeout: Some(Duration::from_secs(60)),
+        ..query.clone()
+    };
+
+    let hw_acc = HwMeasurementAcc::new();
+    let expected = shard
+        .query_batch(
+            Arc::new(vec![query]),
+            &current_runtime,
+            None,
+            hw_acc.clone(),
+        )
+        .await
+        .unwrap();
+    // Only searches with a soft timeout report the searched segments
+    assert_eq!(hw_acc.query_stats().searched_segments(), None);
+
+    let hw_acc = HwMeasurementAcc::new();
+    let responses = shard
+        .query_batch(
+            Arc::new(vec![with_soft_timeout]),
+            &current_runtime,
+            None,
+            hw_acc.clone(),
+        )
+        .await
+        .unwrap();
+
+    // All segments were searched in time, so the results are complete
+    assert_eq!(responses, expected);
+    let searched_segments = hw_acc.query_stats().searched_segments().unwrap();
+    assert!(!searched_segments.is_partial());
+    assert!(searched_segments.total > 0);
+}
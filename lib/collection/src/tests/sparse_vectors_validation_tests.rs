@@ -71,6 +71,8 @@ fn validate_error_sparse_vector_points_list() {
         shard_key: None,
         update_filter: None,
         update_mode: None,
+        expected_version: None,
+        duplicate_ids: None,
     });
 }
 
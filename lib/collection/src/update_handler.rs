@@ -8,6 +8,7 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
 use parking_lot::Mutex;
 use segment::types::SeqNumberType;
+use shard::auto_stopwords::AutoStopwords;
 use shard::operations::CollectionUpdateOperations;
 use shard::segment_holder::locked::LockedSegmentHolder;
 use tokio::runtime::Handle;
@@ -135,6 +136,9 @@ pub struct UpdateHandler {
 
     /// Persist the applied op_num sequence number
     applied_seq_handler: Arc<AppliedSeqHandler>,
+
+    /// Stopwords detected for the full-text indexes of the shard
+    auto_stopwords: Arc<SaveOnDisk<AutoStopwords>>,
 }
 
 impl UpdateHandler {
@@ -158,6 +162,7 @@ impl UpdateHandler {
         scroll_read_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
         applied_seq_handler: Arc<AppliedSeqHandler>,
+        auto_stopwords: Arc<SaveOnDisk<AutoStopwords>>,
     ) -> Self {
         UpdateHandler {
             collection_name,
@@ -186,6 +191,7 @@ impl UpdateHandler {
             scroll_read_lock,
             update_tracker,
             applied_seq_handler,
+            auto_stopwords,
         }
     }
 
@@ -209,6 +215,7 @@ impl UpdateHandler {
                 self.max_optimization_threads,
                 self.has_triggered_optimizers.clone(),
                 self.payload_index_schema.clone(),
+                self.auto_stopwords.clone(),
                 self.scroll_read_lock.clone(),
                 self.update_tracker.clone(),
                 optimization_finished_sender,
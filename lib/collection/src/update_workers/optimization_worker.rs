@@ -2,7 +2,7 @@ use std::panic::AssertUnwindSafe;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use common::budget::ResourceBudget;
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -11,6 +11,7 @@ use common::save_on_disk::SaveOnDisk;
 use itertools::Itertools;
 use parking_lot::Mutex;
 use segment::common::operation_error::{OperationError, OperationResult};
+use shard::auto_stopwords::{AutoStopwords, refresh_auto_stopwords};
 use shard::operations::optimization::OptimizerThresholds;
 use shard::optimizers::config::SegmentOptimizerConfig;
 use shard::payload_index_schema::PayloadIndexSchema;
@@ -55,6 +56,7 @@ impl UpdateWorkers {
         max_handles: Option<usize>,
         has_triggered_optimizers: Arc<AtomicBool>,
         payload_index_schema: Arc<SaveOnDisk<PayloadIndexSchema>>,
+        auto_stopwords: Arc<SaveOnDisk<AutoStopwords>>,
         update_operation_lock: Arc<tokio::sync::RwLock<()>>,
         update_tracker: UpdateTracker,
         optimization_finished_sender: watch::Sender<()>,
@@ -71,11 +73,21 @@ impl UpdateWorkers {
         // Asynchronous task to trigger optimizers once CPU budget is available again
         let mut resource_available_trigger: Option<JoinHandle<()>> = None;
 
+        // Some optimizers plan based on statistics collected by reads, trigger them periodically
+        // even if there are no updates
+        let check_interval = optimizers
+            .iter()
+            .filter_map(|optimizer| optimizer.check_interval())
+            .min();
+        let mut last_triggered = Instant::now();
+
         loop {
             let result = timeout(OPTIMIZER_CLEANUP_INTERVAL, receiver.recv()).await;
 
             let cleaned_any =
                 Self::cleanup_optimization_handles(optimization_handles.clone()).await;
+            let is_check_due =
+                check_interval.is_some_and(|interval| last_triggered.elapsed() >= interval);
 
             // Either continue below here with the worker, or reloop/break
             // Decision logic doing one of three things:
@@ -100,6 +112,8 @@ impl UpdateWorkers {
                     );
                     true
                 }
+                // Hit optimizer cleanup interval, optimizers are due for a periodic check: do 1
+                Err(Elapsed { .. }) if is_check_due => false,
                 // Hit optimizer cleanup interval, did not clean up a task: do 2
                 Err(Elapsed { .. }) => continue,
                 // Channel closed or received stop signal: do 3
@@ -107,6 +121,7 @@ impl UpdateWorkers {
             };
 
             has_triggered_optimizers.store(true, Ordering::Relaxed);
+            last_triggered = Instant::now();
 
             // Ensure we have at least one appendable segment with enough capacity
             // Source required parameters from first optimizer
@@ -122,6 +137,13 @@ impl UpdateWorkers {
                 panic!("Failed to ensure there are appendable segments with capacity: {err}");
             }
 
+            Self::refresh_auto_stopwords(
+                auto_stopwords.clone(),
+                segments.clone(),
+                payload_index_schema.clone(),
+            )
+            .await;
+
             // If not forcing, wait on next signal if we have too many handles
             if !ignore_max_handles && optimization_handles.lock().await.len() >= max_handles {
                 continue;
@@ -479,6 +501,30 @@ impl UpdateWorkers {
         Ok(())
     }
 
+    /// Detect the stopwords of the full-text indexes of the shard, if outdated
+    ///
+    /// Detection scans the vocabularies of the indexes, so it runs on a blocking thread.
+    async fn refresh_auto_stopwords(
+        auto_stopwords: Arc<SaveOnDisk<AutoStopwords>>,
+        segments: LockedSegmentHolder,
+        payload_index_schema: Arc<SaveOnDisk<PayloadIndexSchema>>,
+    ) {
+        let result = task::spawn_blocking(move || {
+            refresh_auto_stopwords(
+                &auto_stopwords,
+                &segments,
+                &payload_index_schema.read().schema,
+            )
+        })
+        .await;
+
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => log::error!("Failed to detect stopwords of full-text indexes: {err}"),
+            Err(err) => log::error!("Stopwords detection of full-text indexes panicked: {err}"),
+        }
+    }
+
     /// Trigger optimizers when CPU budget is available
     fn trigger_optimizers_on_resource_budget(
         optimizer_resource_budget: ResourceBudget,
@@ -1,7 +1,14 @@
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::counter::hardware_data::HardwareData;
 use criterion::{Criterion, criterion_group, criterion_main};
 
+/// Number of points scored in a single search, each counted as one vector read
+const SCORED_POINTS: usize = 10_000;
+
+/// Number of scored points per flush of the counter in the periodically flushed benchmark
+const FLUSH_INTERVAL: usize = 1_000;
+
 fn bench_hw_counter(c: &mut Criterion) {
     c.bench_function("Disposable Hw Cell", |b| {
         b.iter(|| {
@@ -16,5 +23,50 @@ fn bench_hw_counter(c: &mut Criterion) {
     });
 }
 
-criterion_group!(hw_counter, bench_hw_counter);
+/// Counting of vector reads in a scoring hot loop, with every increment going to the shared
+/// accumulator versus batched in a counter cell
+fn bench_hw_counter_scoring(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hw-counter-scoring");
+
+    group.bench_function("unbatched", |b| {
+        let accumulator = HwMeasurementAcc::new();
+        b.iter(|| {
+            for _ in 0..SCORED_POINTS {
+                accumulator.accumulate(HardwareData {
+                    vector_io_read: 1,
+                    ..Default::default()
+                });
+            }
+        });
+    });
+
+    group.bench_function("batched", |b| {
+        let accumulator = HwMeasurementAcc::new();
+        b.iter(|| {
+            let mut counter = accumulator.get_counter_cell();
+            counter.set_vector_io_read_multiplier(128);
+            for _ in 0..SCORED_POINTS {
+                counter.vector_io_read().incr();
+            }
+        });
+    });
+
+    group.bench_function("batched-flush", |b| {
+        let accumulator = HwMeasurementAcc::new();
+        b.iter(|| {
+            let mut counter = accumulator.get_counter_cell();
+            counter.set_vector_io_read_multiplier(128);
+            for i in 0..SCORED_POINTS {
+                counter.vector_io_read().incr();
+                if (i + 1) % FLUSH_INTERVAL == 0 {
+                    counter.flush();
+                }
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(hw_counter, bench_hw_counter, bench_hw_counter_scoring);
 criterion_main!(hw_counter);
@@ -4,6 +4,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use super::hardware_counter::HardwareCounterCell;
 use super::hardware_data::HardwareData;
 use crate::cpu_utilization::CpuUtilization;
+use crate::query_stats::QueryStats;
 
 /// Data structure, that routes hardware measurement counters to specific location.
 /// Shared drain MUST NOT create its own counters, but only hold a reference to the existing one,
@@ -48,6 +49,28 @@ impl HwSharedDrain {
         self.vector_io_read_counter.load(Ordering::Relaxed)
     }
 
+    pub fn hw_data(&self) -> HardwareData {
+        let HwSharedDrain {
+            cpu_counter,
+            payload_io_read_counter,
+            payload_io_write_counter,
+            payload_index_io_read_counter,
+            payload_index_io_write_counter,
+            vector_io_read_counter,
+            vector_io_write_counter,
+        } = self;
+
+        HardwareData {
+            cpu: cpu_counter.load(Ordering::Relaxed),
+            payload_io_read: payload_io_read_counter.load(Ordering::Relaxed),
+            payload_io_write: payload_io_write_counter.load(Ordering::Relaxed),
+            vector_io_read: vector_io_read_counter.load(Ordering::Relaxed),
+            vector_io_write: vector_io_write_counter.load(Ordering::Relaxed),
+            payload_index_io_read: payload_index_io_read_counter.load(Ordering::Relaxed),
+            payload_index_io_write: payload_index_io_write_counter.load(Ordering::Relaxed),
+        }
+    }
+
     /// Accumulates all values from `src` into this HwSharedDrain.
     fn accumulate_from_hw_data(&self, src: HardwareData) {
         let HwSharedDrain {
@@ -93,6 +116,7 @@ pub struct HwMeasurementAcc {
     /// If this is set to true, the accumulator will not accumulate any values.
     disposable: bool,
     cpu_utilization: CpuUtilization,
+    query_stats: QueryStats,
 }
 
 impl HwMeasurementAcc {
@@ -103,6 +127,7 @@ impl HwMeasurementAcc {
             metrics_drain: Arc::new(HwSharedDrain::default()),
             disposable: false,
             cpu_utilization: CpuUtilization::new(),
+            query_stats: QueryStats::new(),
         }
     }
 
@@ -116,6 +141,7 @@ impl HwMeasurementAcc {
             metrics_drain: Arc::new(HwSharedDrain::default()),
             disposable: true,
             cpu_utilization: CpuUtilization::new(),
+            query_stats: QueryStats::new(),
         }
     }
 
@@ -139,6 +165,7 @@ impl HwMeasurementAcc {
             metrics_drain,
             disposable: false,
             cpu_utilization: CpuUtilization::new(),
+            query_stats: QueryStats::new(),
         }
     }
 
@@ -146,6 +173,11 @@ impl HwMeasurementAcc {
         self.cpu_utilization.clone()
     }
 
+    /// Statistics of the request, which are reported in its response
+    pub fn query_stats(&self) -> QueryStats {
+        self.query_stats.clone()
+    }
+
     pub fn accumulate<T: Into<HardwareData>>(&self, src: T) {
         let src = src.into();
         self.request_drain.accumulate_from_hw_data(src);
@@ -189,25 +221,7 @@ impl HwMeasurementAcc {
     }
 
     pub fn hw_data(&self) -> HardwareData {
-        let HwSharedDrain {
-            cpu_counter,
-            payload_io_read_counter,
-            payload_io_write_counter,
-            payload_index_io_read_counter,
-            payload_index_io_write_counter,
-            vector_io_read_counter,
-            vector_io_write_counter,
-        } = self.request_drain.as_ref();
-
-        HardwareData {
-            cpu: cpu_counter.load(Ordering::Relaxed),
-            payload_io_read: payload_io_read_counter.load(Ordering::Relaxed),
-            payload_io_write: payload_io_write_counter.load(Ordering::Relaxed),
-            vector_io_read: vector_io_read_counter.load(Ordering::Relaxed),
-            vector_io_write: vector_io_write_counter.load(Ordering::Relaxed),
-            payload_index_io_read: payload_index_io_read_counter.load(Ordering::Relaxed),
-            payload_index_io_write: payload_index_io_write_counter.load(Ordering::Relaxed),
-        }
+        self.request_drain.hw_data()
     }
 }
 
@@ -225,6 +239,7 @@ impl Clone for HwMeasurementAcc {
             metrics_drain: self.metrics_drain.clone(),
             disposable: self.disposable,
             cpu_utilization: self.cpu_utilization.clone(),
+            query_stats: self.query_stats.clone(),
         }
     }
 }
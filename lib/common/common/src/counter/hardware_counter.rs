@@ -2,6 +2,7 @@ use super::counter_cell::CounterCell;
 use super::hardware_accumulator::HwMeasurementAcc;
 use super::hardware_data::HardwareData;
 use crate::cpu_utilization::CpuUtilization;
+use crate::query_stats::QueryStats;
 
 /// Collection of different types of hardware measurements.
 ///
@@ -92,6 +93,11 @@ impl HardwareCounterCell {
         self.accumulator.as_ref().map(|a| a.cpu_utilization())
     }
 
+    /// Statistics of the request, which are reported in its response
+    pub fn query_stats(&self) -> Option<QueryStats> {
+        self.accumulator.as_ref().map(|a| a.query_stats())
+    }
+
     pub fn new_accumulator(&self) -> HwMeasurementAcc {
         self.accumulator
             .clone()
@@ -187,6 +193,47 @@ impl HardwareCounterCell {
         }
     }
 
+    /// Moves the measurements made so far into the accumulator, leaving the counters at 0.
+    ///
+    /// Increments only touch the local counters of this cell, which are otherwise merged into the
+    /// shared accumulator on drop. Flush to report the progress of long running operations at
+    /// their own boundaries, e.g. per batch of scored points.
+    /// Multipliers are applied to the flushed values, so the totals are the same as without flush.
+    /// Does nothing if there is no accumulator to flush to.
+    pub fn flush(&self) {
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.accumulate(self.take_hw_data());
+        }
+    }
+
+    /// Same as [`Self::get_hw_data`], but also resets the counters to 0
+    fn take_hw_data(&self) -> HardwareData {
+        let hw_data = self.get_hw_data();
+
+        let HardwareCounterCell {
+            vector_io_read_multiplier: _,
+            cpu_multiplier: _,
+            cpu_counter,
+            payload_io_read_counter,
+            payload_io_write_counter,
+            payload_index_io_read_counter,
+            payload_index_io_write_counter,
+            vector_io_read_counter,
+            vector_io_write_counter,
+            accumulator: _,
+        } = self;
+
+        cpu_counter.clear();
+        payload_io_read_counter.clear();
+        payload_io_write_counter.clear();
+        payload_index_io_read_counter.clear();
+        payload_index_io_write_counter.clear();
+        vector_io_read_counter.clear();
+        vector_io_write_counter.clear();
+
+        hw_data
+    }
+
     fn merge_to_accumulator(&self) {
         if let Some(accumulator) = &self.accumulator {
             accumulator.accumulate(self.get_hw_data());
@@ -263,4 +310,28 @@ mod test {
 
         assert_eq!(accumulator.get_cpu(), 69);
     }
+
+    #[test]
+    fn test_hw_counter_flush() {
+        let accumulator = HwMeasurementAcc::new();
+
+        {
+            let mut counter = accumulator.get_counter_cell();
+            counter.set_vector_io_read_multiplier(4);
+
+            counter.vector_io_read().incr_delta(3);
+            counter.payload_io_read_counter().incr_delta(5);
+            counter.flush();
+
+            assert_eq!(accumulator.get_vector_io_read(), 12);
+            assert_eq!(accumulator.get_payload_io_read(), 5);
+            assert_eq!(counter.vector_io_read().get(), 0);
+
+            counter.vector_io_read().incr_delta(2);
+        }
+
+        // The rest is merged on drop, without counting the flushed values twice
+        assert_eq!(accumulator.get_vector_io_read(), 20);
+        assert_eq!(accumulator.get_payload_io_read(), 5);
+    }
 }
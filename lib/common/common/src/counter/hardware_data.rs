@@ -12,6 +12,25 @@ pub struct HardwareData {
     pub payload_index_io_write: usize,
 }
 
+impl HardwareData {
+    /// Difference of the values, clamped at zero
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            cpu: self.cpu.saturating_sub(rhs.cpu),
+            payload_io_read: self.payload_io_read.saturating_sub(rhs.payload_io_read),
+            payload_io_write: self.payload_io_write.saturating_sub(rhs.payload_io_write),
+            vector_io_read: self.vector_io_read.saturating_sub(rhs.vector_io_read),
+            vector_io_write: self.vector_io_write.saturating_sub(rhs.vector_io_write),
+            payload_index_io_read: self
+                .payload_index_io_read
+                .saturating_sub(rhs.payload_index_io_read),
+            payload_index_io_write: self
+                .payload_index_io_write
+                .saturating_sub(rhs.payload_index_io_write),
+        }
+    }
+}
+
 impl Add for HardwareData {
     type Output = HardwareData;
 
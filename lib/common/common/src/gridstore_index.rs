@@ -0,0 +1,49 @@
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+/// Global write tuning of gridstore-backed payload indexes, initialized once at startup.
+static GRIDSTORE_INDEX_CONFIG: OnceLock<GridstoreIndexConfig> = OnceLock::new();
+
+/// Write amplification control for mutable payload indexes stored in gridstore.
+///
+/// Configured via `storage.performance.gridstore_index` in the configuration file.
+///
+/// Gridstore never overwrites a value in place: every update writes the value into newly
+/// allocated blocks, and releases the old ones on the next flush. Frequently updated payload
+/// fields therefore write the same points over and over again.
+///
+/// * Smaller pages keep the files of small indexes small, larger pages mean fewer files and
+///   fewer page allocations for large indexes.
+/// * Coalescing keeps updated values in memory and writes only the latest version of each
+///   point. It lowers write amplification for update-heavy workloads, at the cost of memory
+///   for buffered values and of more work (latency) on flush.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Default)]
+pub struct GridstoreIndexConfig {
+    /// Page size in bytes for newly created indexes, rounded up to whole regions.
+    /// Existing indexes keep their page size.
+    /// If not set - page size depends on the type of indexed values, 4 to 8 MiB.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_size_bytes: Option<usize>,
+
+    /// Maximum number of updated values to keep in memory, before writing them into pages.
+    /// Buffered values are also written on every flush.
+    /// If not set or 0 - values are written immediately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_coalescing_window: Option<usize>,
+}
+
+/// Initializes the global gridstore index config. Must only be called once at
+/// startup; subsequent calls are ignored with a warning.
+pub fn init_gridstore_index_config(config: GridstoreIndexConfig) {
+    if GRIDSTORE_INDEX_CONFIG.set(config).is_err() {
+        log::warn!("Gridstore index config already initialized!");
+    }
+}
+
+/// Returns the globally configured gridstore index config.
+///
+/// Returns the default config if the global has not been initialized (e.g. from unit tests).
+pub fn gridstore_index_config() -> GridstoreIndexConfig {
+    GRIDSTORE_INDEX_CONFIG.get().copied().unwrap_or_default()
+}
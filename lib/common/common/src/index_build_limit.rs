@@ -0,0 +1,137 @@
+use std::num::NonZeroUsize;
+use std::sync::OnceLock;
+
+use parking_lot::{Condvar, Mutex};
+
+/// Global limit of concurrent payload field index builds, initialized once at startup.
+static INDEX_BUILD_LIMIT: OnceLock<IndexBuildLimit> = OnceLock::new();
+
+/// Limits how many payload field indexes are built at the same time, across all collections.
+///
+/// Configured via `storage.performance.max_concurrent_field_index_builds` in the configuration
+/// file.
+///
+/// Creating indexes on many fields, or in many collections at once, runs a builder in the update
+/// worker of every affected shard. All of them read the payload of every point concurrently,
+/// which can saturate IO and CPU, and starve search requests. Builds beyond the limit wait in a
+/// queue, until one of the running builds finishes.
+#[derive(Debug)]
+pub struct IndexBuildLimit {
+    max_concurrent: Option<NonZeroUsize>,
+    state: Mutex<IndexBuildState>,
+    finished: Condvar,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct IndexBuildState {
+    /// Number of index builds in progress
+    pub running: usize,
+    /// Number of index builds waiting for one of the running builds to finish
+    pub queued: usize,
+}
+
+/// A permit to build a field index, releases its slot when dropped.
+#[derive(Debug)]
+#[must_use = "Dropping this permit will immediately allow another index build"]
+pub struct IndexBuildPermit {
+    limit: &'static IndexBuildLimit,
+}
+
+impl IndexBuildLimit {
+    /// Create a limit of `max_concurrent` builds. If not set - builds are not limited.
+    pub fn new(max_concurrent: Option<NonZeroUsize>) -> Self {
+        Self {
+            max_concurrent,
+            state: Mutex::new(IndexBuildState::default()),
+            finished: Condvar::new(),
+        }
+    }
+
+    fn acquire(&'static self) -> IndexBuildPermit {
+        let mut state = self.state.lock();
+
+        if let Some(max_concurrent) = self.max_concurrent {
+            state.queued += 1;
+            while state.running >= max_concurrent.get() {
+                self.finished.wait(&mut state);
+            }
+            state.queued -= 1;
+        }
+
+        state.running += 1;
+        IndexBuildPermit { limit: self }
+    }
+
+    pub fn state(&self) -> IndexBuildState {
+        *self.state.lock()
+    }
+}
+
+impl Drop for IndexBuildPermit {
+    fn drop(&mut self) {
+        let mut state = self.limit.state.lock();
+        state.running -= 1;
+        drop(state);
+        self.limit.finished.notify_one();
+    }
+}
+
+/// Initializes the global limit of concurrent field index builds. Must only be called once at
+/// startup; subsequent calls are ignored with a warning.
+///
+/// Zero or `None` means builds are not limited.
+pub fn init_index_build_limit(max_concurrent: Option<usize>) {
+    let limit = IndexBuildLimit::new(max_concurrent.and_then(NonZeroUsize::new));
+    if INDEX_BUILD_LIMIT.set(limit).is_err() {
+        log::warn!("Index build limit already initialized!");
+    }
+}
+
+fn index_build_limit() -> &'static IndexBuildLimit {
+    INDEX_BUILD_LIMIT.get_or_init(|| IndexBuildLimit::new(None))
+}
+
+/// Blocks until a field index can be built within the global limit.
+///
+/// The build should hold the returned permit until it is finished.
+pub fn acquire_index_build_permit() -> IndexBuildPermit {
+    index_build_limit().acquire()
+}
+
+/// Number of running and queued field index builds
+pub fn index_build_state() -> IndexBuildState {
+    index_build_limit().state()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_index_build_limit() {
+        let limit: &'static IndexBuildLimit =
+            Box::leak(Box::new(IndexBuildLimit::new(NonZeroUsize::new(1))));
+        let running_and_queued = || {
+            let IndexBuildState { running, queued } = limit.state();
+            (running, queued)
+        };
+
+        let permit = limit.acquire();
+        assert_eq!(running_and_queued(), (1, 0));
+
+        let waiting = std::thread::spawn(move || {
+            let _permit = limit.acquire();
+        });
+
+        while limit.state().queued == 0 {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(running_and_queued(), (1, 1));
+
+        drop(permit);
+        waiting.join().unwrap();
+        assert_eq!(running_and_queued(), (0, 0));
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+
+/// In-place reconfigurations of payload field indexes in progress, across all collections.
+static RECONFIGURATIONS: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+/// Unique id of every reconfiguration, to remove its entry once finished
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug)]
+struct Entry {
+    id: usize,
+    progress: IndexReconfigurationProgress,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct IndexReconfigurationProgress {
+    /// Field, which index is reconfigured
+    pub field_name: String,
+    /// Number of segments of the shard, which are already processed
+    pub processed_segments: usize,
+    /// Number of segments of the shard
+    pub total_segments: usize,
+}
+
+/// Reports progress of reconfiguration of the field index in one shard, removes it when dropped.
+#[derive(Debug)]
+#[must_use = "Dropping this tracker will immediately remove the reconfiguration from progress"]
+pub struct IndexReconfigurationTracker {
+    id: usize,
+}
+
+impl IndexReconfigurationTracker {
+    /// Start reporting the reconfiguration of the field index in the shard of `total_segments`
+    pub fn start(field_name: impl Into<String>, total_segments: usize) -> Self {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        RECONFIGURATIONS.lock().push(Entry {
+            id,
+            progress: IndexReconfigurationProgress {
+                field_name: field_name.into(),
+                processed_segments: 0,
+                total_segments,
+            },
+        });
+        Self { id }
+    }
+
+    /// Report the number of segments of the shard, which are already processed
+    pub fn set_processed_segments(&self, processed_segments: usize) {
+        let mut reconfigurations = RECONFIGURATIONS.lock();
+        if let Some(entry) = reconfigurations
+            .iter_mut()
+            .find(|entry| entry.id == self.id)
+        {
+            entry.progress.processed_segments = processed_segments;
+        }
+    }
+}
+
+impl Drop for IndexReconfigurationTracker {
+    fn drop(&mut self) {
+        RECONFIGURATIONS.lock().retain(|entry| entry.id != self.id);
+    }
+}
+
+/// Progress of all reconfigurations in progress, in the order they were started
+pub fn index_reconfigurations() -> Vec<IndexReconfigurationProgress> {
+    RECONFIGURATIONS
+        .lock()
+        .iter()
+        .map(|entry| entry.progress.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_reconfiguration_progress() {
+        let field_progress = |field_name: &str| {
+            index_reconfigurations()
+                .into_iter()
+                .find(|progress| progress.field_name == field_name)
+                .map(|progress| (progress.processed_segments, progress.total_segments))
+        };
+
+        let tracker = IndexReconfigurationTracker::start("test_reconfigured_field", 3);
+        assert_eq!(field_progress("test_reconfigured_field"), Some((0, 3)));
+
+        tracker.set_processed_segments(2);
+        assert_eq!(field_progress("test_reconfigured_field"), Some((2, 3)));
+
+        drop(tracker);
+        assert_eq!(field_progress("test_reconfigured_field"), None);
+    }
+}
@@ -18,6 +18,9 @@ pub mod fixed_length_priority_queue;
 pub mod flags;
 pub mod fs;
 pub mod generic_consts;
+pub mod gridstore_index;
+pub mod index_build_limit;
+pub mod index_reconfiguration_progress;
 pub mod is_alive_lock;
 pub mod iterator_ext;
 pub mod load_concurrency;
@@ -29,9 +32,11 @@ pub mod mmap;
 pub mod num_traits;
 pub mod panic;
 pub mod persisted_hashmap;
+pub mod populate_budget;
 pub mod process_counter;
 pub mod process_cpu_usage;
 pub mod progress_tracker;
+pub mod query_stats;
 pub mod rate_limiting;
 pub mod save_on_disk;
 pub mod scope_tracker;
@@ -11,6 +11,7 @@ use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
 use super::{BucketOffset, Header, Key, ValuesLen};
 use crate::mmap::{AdviceSetting, Madviseable, open_read_mmap};
+use crate::populate_budget::{PopulateBudget, PopulateStage};
 
 /// On-disk hash map backed by a memory-mapped file.
 ///
@@ -209,6 +210,15 @@ impl<K: Key + ?Sized, V: Sized + FromBytes + Immutable + IntoBytes + KnownLayout
         Ok(())
     }
 
+    /// Populate the pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(
+        &self,
+        stage: PopulateStage,
+        budget: &mut PopulateBudget,
+    ) -> io::Result<()> {
+        budget.populate_with(stage, &self.mmap[..], || self.populate())
+    }
+
     /// Hint to the OS that pages backing this mmap can be reclaimed.
     pub fn clear_cache(&self) -> io::Result<()> {
         let Self {
@@ -0,0 +1,221 @@
+use serde::Serialize;
+
+use crate::generic_consts::Sequential;
+use crate::low_memory::low_memory_mode;
+use crate::universal_io::{self, ReadRange, UniversalRead};
+
+/// Part of the storages of an index, in the order of usefulness for queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopulateStage {
+    /// Lookup structures used by every query on the index, like dictionaries
+    Lookup,
+    /// Rest of the data, like posting lists and values of the points
+    Data,
+}
+
+impl PopulateStage {
+    /// All stages, most useful first
+    pub const ALL: [Self; 2] = [Self::Lookup, Self::Data];
+}
+
+/// Limit of bytes, which a warmup may load into the page cache.
+///
+/// A warmup makes a pass over the storages for each [`PopulateStage`], offering the storages of
+/// the current stage to the budget in the order of their usefulness. Storages are populated as a
+/// whole while they fit into the remaining budget. The storage, which doesn't fit, is populated
+/// from its start with the rest of the budget.
+#[derive(Debug, Clone)]
+pub struct PopulateBudget {
+    /// Bytes left to populate, `None` if unlimited
+    remaining_bytes: Option<usize>,
+    /// Storages of other stages are skipped in the current pass
+    stage: PopulateStage,
+    report: PopulateReport,
+}
+
+/// Amount of data populated by a warmup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct PopulateReport {
+    /// Bytes loaded into the page cache
+    pub warmed_bytes: usize,
+    /// Bytes of all storages offered to the budget, including the warmed ones
+    pub total_bytes: usize,
+}
+
+impl PopulateBudget {
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            remaining_bytes: Some(max_bytes),
+            stage: PopulateStage::Lookup,
+            report: PopulateReport::default(),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self {
+            remaining_bytes: None,
+            stage: PopulateStage::Lookup,
+            report: PopulateReport::default(),
+        }
+    }
+
+    pub fn report(&self) -> PopulateReport {
+        self.report
+    }
+
+    /// Start the pass over the storages of the `stage`
+    pub fn set_stage(&mut self, stage: PopulateStage) {
+        self.stage = stage;
+    }
+
+    /// Offer a storage of the `stage` of `size_bytes` to the budget.
+    ///
+    /// Returns the number of bytes to populate from the start of the storage: all of them if the
+    /// storage fits into the budget, the rest of the budget otherwise. Storages of other stages
+    /// than the current one are not populated.
+    pub fn take(&mut self, stage: PopulateStage, size_bytes: usize) -> usize {
+        if stage != self.stage {
+            return 0;
+        }
+
+        self.report.total_bytes = self.report.total_bytes.saturating_add(size_bytes);
+
+        let granted_bytes = match &mut self.remaining_bytes {
+            None => size_bytes,
+            Some(remaining_bytes) => {
+                let granted_bytes = size_bytes.min(*remaining_bytes);
+                *remaining_bytes -= granted_bytes;
+                granted_bytes
+            }
+        };
+
+        self.report.warmed_bytes = self.report.warmed_bytes.saturating_add(granted_bytes);
+        granted_bytes
+    }
+
+    /// Populate the in-memory mapped `items` of a storage of the `stage` within the budget.
+    ///
+    /// Calls `populate` if all of them fit, and reads the pages of a part of them otherwise.
+    pub fn populate_with<T: Copy, E>(
+        &mut self,
+        stage: PopulateStage,
+        items: &[T],
+        populate: impl FnOnce() -> Result<(), E>,
+    ) -> Result<(), E> {
+        let size_bytes = size_of_val(items);
+        let granted_bytes = self.take(stage, size_bytes);
+        if granted_bytes == 0 {
+            return Ok(());
+        }
+
+        if granted_bytes == size_bytes {
+            populate()
+        } else {
+            touch_items(&items[..granted_bytes / size_of::<T>()]);
+            Ok(())
+        }
+    }
+
+    /// Populate the `storage` of the `stage` within the budget.
+    ///
+    /// Populates the whole storage if it fits, and reads a part of it from the start otherwise.
+    pub fn populate<T: Copy + 'static>(
+        &mut self,
+        stage: PopulateStage,
+        storage: &impl UniversalRead<T>,
+    ) -> universal_io::Result<()> {
+        let size_bytes = (storage.len()? as usize).saturating_mul(size_of::<T>());
+        let granted_bytes = self.take(stage, size_bytes);
+        if granted_bytes == 0 {
+            return Ok(());
+        }
+
+        if granted_bytes == size_bytes {
+            storage.populate()
+        } else {
+            let range = ReadRange::new(0, (granted_bytes / size_of::<T>()) as u64);
+            touch_items(&storage.read::<Sequential>(range)?);
+            Ok(())
+        }
+    }
+}
+
+/// Read an item every 512 bytes, to load the pages of the `items` into the page cache.
+fn touch_items<T: Copy>(items: &[T]) {
+    // Low-memory mode `no_populate` suppresses prefault globally, as in `Madviseable::populate`
+    if low_memory_mode().skip_populate() {
+        return;
+    }
+
+    let step = (512 / size_of::<T>()).max(1);
+    for item in items.iter().step_by(step) {
+        std::hint::black_box(*item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_populate_budget() {
+        let mut budget = PopulateBudget::new(100);
+        assert_eq!(budget.take(PopulateStage::Lookup, 60), 60);
+        // The storage, which doesn't fit, gets the rest of the budget
+        assert_eq!(budget.take(PopulateStage::Lookup, 50), 40);
+        assert_eq!(budget.take(PopulateStage::Lookup, 1), 0);
+        assert_eq!(
+            budget.report(),
+            PopulateReport {
+                warmed_bytes: 100,
+                total_bytes: 111,
+            },
+        );
+
+        // Storages of other stages are skipped, and not counted
+        let mut budget = PopulateBudget::new(100);
+        assert_eq!(budget.take(PopulateStage::Data, 10), 0);
+        assert_eq!(budget.take(PopulateStage::Lookup, 10), 10);
+        budget.set_stage(PopulateStage::Data);
+        assert_eq!(budget.take(PopulateStage::Lookup, 10), 0);
+        assert_eq!(budget.take(PopulateStage::Data, 150), 90);
+        assert_eq!(
+            budget.report(),
+            PopulateReport {
+                warmed_bytes: 100,
+                total_bytes: 160,
+            },
+        );
+
+        let mut budget = PopulateBudget::unlimited();
+        assert_eq!(budget.take(PopulateStage::Lookup, usize::MAX), usize::MAX);
+        assert_eq!(budget.take(PopulateStage::Lookup, 1), 1);
+        assert_eq!(budget.report().warmed_bytes, usize::MAX);
+    }
+
+    #[test]
+    fn test_populate_with_partially() {
+        let items = [1u64; 100];
+
+        let mut budget = PopulateBudget::new(400);
+        let mut populated = false;
+        budget
+            .populate_with(PopulateStage::Lookup, &items, || {
+                populated = true;
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+        // Only a part of the items fits, they are read instead
+        assert!(!populated);
+        assert_eq!(budget.report().warmed_bytes, 400);
+
+        let mut budget = PopulateBudget::new(800);
+        budget
+            .populate_with(PopulateStage::Lookup, &items, || {
+                populated = true;
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+        assert!(populated);
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Statistics of the execution of a single request, collected on all shards of the request.
+///
+/// Cheap to clone — clones share the same inner values via a single `Arc`.
+/// Reported in the response of the request, if it has any values.
+#[derive(Debug, Clone, Default)]
+pub struct QueryStats {
+    inner: Arc<QueryStatsInner>,
+}
+
+#[derive(Debug, Default)]
+struct QueryStatsInner {
+    /// Largest number of results, which a segment contributes at most to the merge, 0 if unlimited
+    segment_limit: AtomicUsize,
+    /// Segments of all shards, which were searched before the soft timeout
+    searched_segments: AtomicUsize,
+    /// Segments of all shards, which were to be searched with a soft timeout, 0 if not used
+    total_segments: AtomicUsize,
+    /// Segment searches, which were retried with larger oversampling of quantized search
+    quantization_fallbacks: AtomicUsize,
+}
+
+impl QueryStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the number of results, which each segment of a shard contributes at most to the
+    /// merge. Shards have different numbers of segments, so the largest limit is kept.
+    pub fn record_segment_limit(&self, segment_limit: usize) {
+        self.inner
+            .segment_limit
+            .fetch_max(segment_limit, Ordering::Relaxed);
+    }
+
+    pub fn segment_limit(&self) -> Option<usize> {
+        match self.inner.segment_limit.load(Ordering::Relaxed) {
+            0 => None,
+            segment_limit => Some(segment_limit),
+        }
+    }
+
+    /// Record the segments of a shard, which were searched before the soft timeout, out of all
+    /// segments of the shard. Summed over all shards.
+    pub fn record_searched_segments(&self, searched_segments: usize, total_segments: usize) {
+        self.inner
+            .searched_segments
+            .fetch_add(searched_segments, Ordering::Relaxed);
+        self.inner
+            .total_segments
+            .fetch_add(total_segments, Ordering::Relaxed);
+    }
+
+    /// Segments searched before the soft timeout and all segments, if a soft timeout was used
+    pub fn searched_segments(&self) -> Option<SearchedSegments> {
+        match self.inner.total_segments.load(Ordering::Relaxed) {
+            0 => None,
+            total => Some(SearchedSegments {
+                searched: self.inner.searched_segments.load(Ordering::Relaxed),
+                total,
+            }),
+        }
+    }
+
+    /// Record segment searches, which were retried with larger oversampling, because quantized
+    /// and rescored rankings disagreed too much. Summed over all shards.
+    pub fn record_quantization_fallbacks(&self, searches: usize) {
+        self.inner
+            .quantization_fallbacks
+            .fetch_add(searches, Ordering::Relaxed);
+    }
+
+    /// Segment searches retried by the quantization fallback, if there were any
+    pub fn quantization_fallbacks(&self) -> Option<usize> {
+        match self.inner.quantization_fallbacks.load(Ordering::Relaxed) {
+            0 => None,
+            searches => Some(searches),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchedSegments {
+    pub searched: usize,
+    pub total: usize,
+}
+
+impl SearchedSegments {
+    /// Whether some segments were not searched, so better points may be missing in the results
+    pub fn is_partial(&self) -> bool {
+        self.searched < self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_limit() {
+        let stats = QueryStats::new();
+        assert_eq!(stats.segment_limit(), None);
+
+        let shard_stats = stats.clone();
+        shard_stats.record_segment_limit(20);
+        stats.record_segment_limit(10);
+        assert_eq!(stats.segment_limit(), Some(20));
+    }
+
+    #[test]
+    fn test_searched_segments() {
+        let stats = QueryStats::new();
+        assert_eq!(stats.searched_segments(), None);
+
+        stats.clone().record_searched_segments(3, 3);
+        let searched = stats.searched_segments().unwrap();
+        assert_eq!(
+            searched,
+            SearchedSegments {
+                searched: 3,
+                total: 3
+            }
+        );
+        assert!(!searched.is_partial());
+
+        stats.clone().record_searched_segments(1, 4);
+        let searched = stats.searched_segments().unwrap();
+        assert_eq!(
+            searched,
+            SearchedSegments {
+                searched: 4,
+                total: 7
+            }
+        );
+        assert!(searched.is_partial());
+    }
+
+    #[test]
+    fn test_quantization_fallbacks() {
+        let stats = QueryStats::new();
+        assert_eq!(stats.quantization_fallbacks(), None);
+
+        stats.clone().record_quantization_fallbacks(1);
+        stats.clone().record_quantization_fallbacks(2);
+        assert_eq!(stats.quantization_fallbacks(), Some(3));
+    }
+}
@@ -144,6 +144,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                 "hello".try_into().unwrap(),
                 Match::TextAny(MatchTextAny {
                     text_any: "world".to_string(),
+                    min_should_match: None,
                 }),
             )),
             Condition::Field(FieldCondition::new_range(
@@ -7,7 +7,7 @@ IDE autocompletion and type checking.
 
 from enum import Enum
 from optparse import Option
-from typing import Any, Dict, List, Optional, Set, Tuple, Union
+from typing import Any, Dict, List, Literal, Optional, Set, Tuple, Union
 from uuid import UUID
 
 # Type aliases
@@ -18,20 +18,32 @@ Payload = Dict[str, Any]
 JsonPath = str
 WithPayloadType = Union[bool, List[str], "PayloadSelector"]
 WithVectorType = Union[bool, List[str]]
-ScoringQueryType = Union["Query", "Fusion", "OrderBy", "Formula", "Sample", "Mmr"]
+ScoringQueryType = Union[
+    "Query", "Fusion", "OrderBy", "Formula", "Sample", "WeightedSample", "Mmr"
+]
 ConditionType = Union[
     "FieldCondition",
     "IsEmptyCondition",
     "IsNullCondition",
     "HasIdCondition",
     "HasVectorCondition",
+    "UpdatedAfterCondition",
+    "ExpressionCondition",
     "NestedCondition",
     "Filter",
 ]
 MatchType = Union[
-    "MatchValue", "MatchText", "MatchTextAny", "MatchPhrase", "MatchAny", "MatchExcept"
+    "MatchValue",
+    "MatchText",
+    "MatchTextAny",
+    "MatchPhrase",
+    "MatchAny",
+    "MatchExcept",
+    "MatchExactSet",
+    "MatchKeywordPrefix",
 ]
-RangeType = Union["RangeFloat", "RangeDateTime"]
+MinShouldMatchType = Union[int, "MinShouldMatchPercentage"]
+RangeType = Union["RangeFloat", "RangeDateTime", "RangeUuid"]
 QuantizationConfigType = Union[
     "ScalarQuantizationConfig",
     "ProductQuantizationConfig",
@@ -96,6 +108,7 @@ class EdgeShard:
     def optimize(self) -> bool:
         """
         Run segment optimizers in-process, blocking until no more optimizations are planned.
+        Then detects the stopwords of the full-text indexes with `auto_stopwords_threshold`, if outdated.
 
         Returns:
             True if any segments were optimized, False if already optimal.
@@ -554,6 +567,7 @@ class ScalarQuantizationConfig:
         type: "ScalarType",
         quantile: Optional[float] = None,
         always_ram: Optional[bool] = None,
+        outliers: Optional["ScalarOutliers"] = None,
     ) -> None:
         """
         Create a ScalarQuantizationConfig.
@@ -562,6 +576,7 @@ class ScalarQuantizationConfig:
             type: Scalar type (e.g., Int8).
             quantile: Quantile for normalization.
             always_ram: Whether to keep in RAM.
+            outliers: Handling of vectors with values outside of the quantile range.
         """
         ...
 
@@ -580,6 +595,11 @@ class ScalarQuantizationConfig:
         """Always RAM flag."""
         ...
 
+    @property
+    def outliers(self) -> Optional["ScalarOutliers"]:
+        """Handling of outlier vectors."""
+        ...
+
 class ProductQuantizationConfig:
     """Configuration for product quantization."""
 
@@ -704,6 +724,12 @@ class ScalarType(Enum):
 
     Int8 = ...
 
+class ScalarOutliers(Enum):
+    """Handling of vectors with values outside of the scalar quantization range."""
+
+    Clamp = ...
+    FullPrecision = ...
+
 class CompressionRatio(Enum):
     """Product quantization compression ratios."""
 
@@ -770,6 +796,12 @@ class DecayKind(Enum):
     Gauss = ...
     Exp = ...
 
+class TextScoreAggregation(Enum):
+    """Aggregations of the text scores of the values of an array."""
+
+    Max = ...
+    Sum = ...
+
 class PayloadSchemaType(Enum):
     """Payload field schema types."""
 
@@ -986,6 +1018,10 @@ class KeywordIndexParams:
         is_tenant: Optional[bool] = None,
         on_disk: Optional[bool] = None,
         enable_hnsw: Optional[bool] = None,
+        deduplicate_values: Optional[bool] = None,
+        max_value_bytes: Optional[int] = None,
+        oversized_value_policy: Optional["OversizedValuePolicy"] = None,
+        case_insensitive: Optional[bool] = None,
     ) -> None:
         """
         Create KeywordIndexParams.
@@ -994,6 +1030,10 @@ class KeywordIndexParams:
             is_tenant: Whether this field is used for tenant separation.
             on_disk: Whether to store index on disk.
             enable_hnsw: Whether to enable HNSW index for this field.
+            deduplicate_values: Whether to store repeated keywords within a point only once.
+            max_value_bytes: Max size of a single keyword in bytes.
+            oversized_value_policy: How to index keywords larger than `max_value_bytes`.
+            case_insensitive: Whether to match keywords ignoring their case.
         """
         ...
 
@@ -1012,6 +1052,33 @@ class KeywordIndexParams:
         """Whether to enable HNSW index."""
         ...
 
+    @property
+    def deduplicate_values(self) -> Optional[bool]:
+        """Whether repeated keywords within a point are stored only once."""
+        ...
+
+    @property
+    def max_value_bytes(self) -> Optional[int]:
+        """Max size of a single keyword in bytes."""
+        ...
+
+    @property
+    def oversized_value_policy(self) -> Optional["OversizedValuePolicy"]:
+        """How to index keywords larger than `max_value_bytes`."""
+        ...
+
+    @property
+    def case_insensitive(self) -> Optional[bool]:
+        """Whether keywords are matched ignoring their case."""
+        ...
+
+class OversizedValuePolicy(Enum):
+    """How to index values larger than `max_value_bytes` of the field index."""
+
+    Skip = ...
+    Truncate = ...
+    Error = ...
+
 class IntegerIndexParams:
     """Index parameters for integer fields."""
 
@@ -1032,6 +1099,7 @@ class IntegerIndexParams:
             is_principal: Whether this field is a principal identifier.
             on_disk: Whether to store index on disk.
             enable_hnsw: Whether to enable HNSW index for this field.
+            compression: Compression of posting lists stored on disk.
         """
         ...
 
@@ -1068,6 +1136,7 @@ class FloatIndexParams:
         is_principal: Optional[bool] = None,
         on_disk: Optional[bool] = None,
         enable_hnsw: Optional[bool] = None,
+        precision: Optional[float] = None,
     ) -> None:
         """
         Create FloatIndexParams.
@@ -1076,6 +1145,7 @@ class FloatIndexParams:
             is_principal: Whether this field is a principal identifier.
             on_disk: Whether to store index on disk.
             enable_hnsw: Whether to enable HNSW index for this field.
+            precision: Precision to store the values at, as integer multiples of it.
         """
         ...
 
@@ -1094,6 +1164,11 @@ class FloatIndexParams:
         """Whether to enable HNSW index."""
         ...
 
+    @property
+    def precision(self) -> Optional[float]:
+        """Precision to store the values at, as integer multiples of it."""
+        ...
+
 class GeoIndexParams:
     """Index parameters for geo fields."""
 
@@ -1101,6 +1176,7 @@ class GeoIndexParams:
         self,
         on_disk: Optional[bool] = None,
         enable_hnsw: Optional[bool] = None,
+        h3_resolution: Optional[int] = None,
     ) -> None:
         """
         Create GeoIndexParams.
@@ -1108,6 +1184,7 @@ class GeoIndexParams:
         Args:
             on_disk: Whether to store index on disk.
             enable_hnsw: Whether to enable HNSW index for this field.
+            h3_resolution: Resolution (0 - 15) of H3 cells to additionally index points by.
         """
         ...
 
@@ -1121,6 +1198,11 @@ class GeoIndexParams:
         """Whether to enable HNSW index."""
         ...
 
+    @property
+    def h3_resolution(self) -> Optional[int]:
+        """Resolution of H3 cells points are additionally indexed by."""
+        ...
+
 class BoolIndexParams:
     """Index parameters for boolean fields."""
 
@@ -1128,6 +1210,7 @@ class BoolIndexParams:
         self,
         on_disk: Optional[bool] = None,
         enable_hnsw: Optional[bool] = None,
+        max_positions: Optional[int] = None,
     ) -> None:
         """
         Create BoolIndexParams.
@@ -1135,6 +1218,7 @@ class BoolIndexParams:
         Args:
             on_disk: Whether to store index on disk.
             enable_hnsw: Whether to enable HNSW index for this field.
+            max_positions: Number of leading array positions to index separately.
         """
         ...
 
@@ -1148,6 +1232,11 @@ class BoolIndexParams:
         """Whether to enable HNSW index."""
         ...
 
+    @property
+    def max_positions(self) -> Optional[int]:
+        """Number of leading array positions indexed separately."""
+        ...
+
 class DatetimeIndexParams:
     """Index parameters for datetime fields."""
 
@@ -1231,6 +1320,15 @@ class TextIndexParams:
         on_disk: Optional[bool] = None,
         stemmer: Optional["StemmingAlgorithm"] = None,
         enable_hnsw: Optional[bool] = None,
+        compression: Optional["TextIndexCompression"] = None,
+        index_unstemmed: Optional[bool] = None,
+        max_value_bytes: Optional[int] = None,
+        oversized_value_policy: Optional["OversizedValuePolicy"] = None,
+        auto_stopwords_threshold: Optional[int] = None,
+        strip_html: Optional[bool] = None,
+        decode_html_entities: Optional[bool] = None,
+        strip_html_queries: Optional[bool] = None,
+        forward_index: Optional[bool] = None,
     ) -> None:
         """
         Create TextIndexParams.
@@ -1246,6 +1344,14 @@ class TextIndexParams:
             on_disk: Whether to store index on disk.
             stemmer: Stemming algorithm.
             enable_hnsw: Whether to enable HNSW index for this field.
+            index_unstemmed: Index the original form of tokens alongside the stemmed one.
+            max_value_bytes: Max size of a single text value in bytes.
+            oversized_value_policy: How to index text values larger than `max_value_bytes`.
+            auto_stopwords_threshold: Percentage of points containing a token to treat it as a stopword.
+            strip_html: Strip HTML tags from text values before tokenization.
+            decode_html_entities: Decode HTML entities in the text left after stripping HTML.
+            strip_html_queries: Strip HTML from text queries too.
+            forward_index: Store the tokens of every point in the index.
         """
         ...
 
@@ -1299,6 +1405,57 @@ class TextIndexParams:
         """Whether to enable HNSW index."""
         ...
 
+    @property
+    def compression(self) -> Optional["TextIndexCompression"]:
+        """Compression of posting lists stored on disk."""
+        ...
+
+    @property
+    def index_unstemmed(self) -> Optional[bool]:
+        """Whether to index the original form of tokens."""
+        ...
+
+    @property
+    def max_value_bytes(self) -> Optional[int]:
+        """Max size of a single text value in bytes."""
+        ...
+
+    @property
+    def oversized_value_policy(self) -> Optional["OversizedValuePolicy"]:
+        """How to index text values larger than `max_value_bytes`."""
+        ...
+
+    @property
+    def auto_stopwords_threshold(self) -> Optional[int]:
+        """Percentage of points containing a token to treat it as a stopword."""
+        ...
+
+    @property
+    def strip_html(self) -> Optional[bool]:
+        """Whether to strip HTML tags from text values before tokenization."""
+        ...
+
+    @property
+    def decode_html_entities(self) -> Optional[bool]:
+        """Whether to decode HTML entities in the text left after stripping HTML."""
+        ...
+
+    @property
+    def strip_html_queries(self) -> Optional[bool]:
+        """Whether to strip HTML from text queries too."""
+        ...
+
+    @property
+    def forward_index(self) -> Optional[bool]:
+        """Whether to store the tokens of every point in the index."""
+        ...
+
+class TextIndexCompression(Enum):
+    """Compression algorithms for text index posting lists."""
+
+    Lz4 = ...
+    Zstd = ...
+
 class TokenizerType(Enum):
     """Text tokenizer types."""
 
@@ -1307,8 +1464,8 @@ class TokenizerType(Enum):
     Word = ...
     Multilingual = ...
 
-Stopwords = Union["Language", "StopwordsSet"]
-"""Stopwords configuration - either a language or a custom set."""
+Stopwords = Union["Language", "StopwordsSet", Literal["auto"]]
+"""Stopwords configuration - either a language, a custom set, or "auto" to detect the language of indexed texts."""
 
 class Language(Enum):
     """Predefined stopword languages."""
@@ -1351,6 +1508,7 @@ class StopwordsSet:
         self,
         languages: Optional[Set["Language"]] = None,
         custom: Optional[Set[str]] = None,
+        exclude: Optional[Set[str]] = None,
     ) -> None:
         """
         Create a StopwordsSet.
@@ -1358,6 +1516,7 @@ class StopwordsSet:
         Args:
             languages: Predefined language stopwords to include.
             custom: Custom stopwords to add.
+            exclude: Words to keep, even though they are in the language stopwords.
         """
         ...
 
@@ -1371,6 +1530,11 @@ class StopwordsSet:
         """Custom stopwords."""
         ...
 
+    @property
+    def exclude(self) -> Optional[Set[str]]:
+        """Words kept, even though they are in the language stopwords."""
+        ...
+
 StemmingAlgorithm = Union["SnowballParams"]
 
 class SnowballParams:
@@ -1761,6 +1925,11 @@ class FacetResponse:
         """Facet hits."""
         ...
 
+    @property
+    def other_count(self) -> int:
+        """Sum of the counts of the values, which are not in hits."""
+        ...
+
     def __len__(self) -> int:
         """Number of hits."""
         ...
@@ -1779,6 +1948,9 @@ class SearchParams:
         quantization: Optional["QuantizationSearchParams"] = None,
         indexed_only: bool = False,
         acorn: Optional["AcornSearchParams"] = None,
+        missing_vectors: Optional["MissingVectors"] = None,
+        segment_limit_factor: Optional[float] = None,
+        allow_full_scan: bool = False,
     ) -> None:
         """
         Create SearchParams.
@@ -1789,6 +1961,9 @@ class SearchParams:
             quantization: Quantization search parameters.
             indexed_only: Whether to search only indexed vectors.
             acorn: Acorn search parameters.
+            missing_vectors: How to treat points without the searched vector.
+            segment_limit_factor: Limit of the results of each segment, relative to its share of the limit.
+            allow_full_scan: Whether the filter may match a large part of the collection.
         """
         ...
 
@@ -1817,6 +1992,29 @@ class SearchParams:
         """Acorn parameters."""
         ...
 
+    @property
+    def missing_vectors(self) -> Optional["MissingVectors"]:
+        """Treatment of points without the searched vector."""
+        ...
+
+    @property
+    def segment_limit_factor(self) -> Optional[float]:
+        """Per-segment result limit factor."""
+        ...
+
+    @property
+    def allow_full_scan(self) -> bool:
+        """Full scan acknowledgement flag."""
+        ...
+
+class MissingVectors(Enum):
+    """Treatment of points without the searched vector."""
+
+    Exclude = ...
+    """Points without the vector are never returned."""
+    Include = ...
+    """Points without the vector are returned last, with the worst possible score."""
+
 class QuantizationSearchParams:
     """Parameters for quantization during search."""
 
@@ -1825,6 +2023,7 @@ class QuantizationSearchParams:
         ignore: bool = False,
         rescore: Optional[bool] = None,
         oversampling: Optional[float] = None,
+        fallback: Optional["QuantizationFallbackParams"] = None,
     ) -> None:
         """
         Create QuantizationSearchParams.
@@ -1833,6 +2032,7 @@ class QuantizationSearchParams:
             ignore: Whether to ignore quantization.
             rescore: Whether to rescore with original vectors.
             oversampling: Oversampling factor.
+            fallback: Retry with larger oversampling if quantized scores are too inaccurate.
         """
         ...
 
@@ -1851,6 +2051,45 @@ class QuantizationSearchParams:
         """Oversampling factor."""
         ...
 
+    @property
+    def fallback(self) -> Optional["QuantizationFallbackParams"]:
+        """Fallback parameters."""
+        ...
+
+class QuantizationFallbackParams:
+    """Parameters for retrying quantized search with larger oversampling."""
+
+    def __init__(
+        self,
+        enable: bool = False,
+        max_distortion: Optional[float] = None,
+        max_oversampling: Optional[float] = None,
+    ) -> None:
+        """
+        Create QuantizationFallbackParams.
+
+        Args:
+            enable: Whether to retry the search if quantized and rescored rankings disagree.
+            max_distortion: Maximum fraction of rescored top results missing in the quantized top.
+            max_oversampling: Maximum oversampling of the retries.
+        """
+        ...
+
+    @property
+    def enable(self) -> bool:
+        """Enable flag."""
+        ...
+
+    @property
+    def max_distortion(self) -> Optional[float]:
+        """Maximum distortion."""
+        ...
+
+    @property
+    def max_oversampling(self) -> Optional[float]:
+        """Maximum oversampling."""
+        ...
+
 class AcornSearchParams:
     """Parameters for Acorn filtered search."""
 
@@ -1985,6 +2224,44 @@ class OrderBy:
         """Starting value."""
         ...
 
+class WeightedSample:
+    """
+    Weighted random sample, with probability proportional to a numeric payload field.
+
+    Uses A-Res weighted reservoir sampling. Scores of the sampled points are their sampling keys.
+    """
+
+    def __init__(
+        self,
+        key: JsonPath,
+        seed: int,
+        default_weight: Optional[float] = None,
+    ) -> None:
+        """
+        Create a WeightedSample.
+
+        Args:
+            key: Numeric payload field with the weights, requires a range index.
+            seed: Seed of the sample, the same seed gives the same sample.
+            default_weight: Weight of points without the field. Default: 0, never sampled.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Field key."""
+        ...
+
+    @property
+    def seed(self) -> int:
+        """Seed of the sample."""
+        ...
+
+    @property
+    def default_weight(self) -> float:
+        """Weight of points without the field."""
+        ...
+
 class Mmr:
     """Maximal Marginal Relevance for result diversification."""
 
@@ -2266,6 +2543,15 @@ class Expression(Enum):
         """Create a datetime field expression."""
         ...
 
+    @staticmethod
+    def TextScore(
+        key: JsonPath,
+        text: str,
+        aggregation: Optional["TextScoreAggregation"] = None,
+    ) -> "Expression":
+        """Create a relevance expression of a full-text indexed field to the text."""
+        ...
+
     @staticmethod
     def Mult(exprs: List["Expression"]) -> "Expression":
         """Create a multiplication expression."""
@@ -2414,6 +2700,7 @@ class FieldCondition:
         geo_bounding_box: Optional["GeoBoundingBox"] = None,
         geo_radius: Optional["GeoRadius"] = None,
         geo_polygon: Optional["GeoPolygon"] = None,
+        geo_near_path: Optional["GeoNearPath"] = None,
         values_count: Optional["ValuesCount"] = None,
         is_empty: Optional[bool] = None,
         is_null: Optional[bool] = None,
@@ -2428,6 +2715,7 @@ class FieldCondition:
             geo_bounding_box: Geo bounding box condition.
             geo_radius: Geo radius condition.
             geo_polygon: Geo polygon condition.
+            geo_near_path: Geo path proximity condition.
             values_count: Values count condition.
             is_empty: Check if empty.
             is_null: Check if null.
@@ -2464,6 +2752,11 @@ class FieldCondition:
         """Geo polygon."""
         ...
 
+    @property
+    def geo_near_path(self) -> Optional["GeoNearPath"]:
+        """Geo path proximity."""
+        ...
+
     @property
     def values_count(self) -> Optional["ValuesCount"]:
         """Values count."""
@@ -2499,12 +2792,13 @@ class IsEmptyCondition:
 class IsNullCondition:
     """Check if a field is null."""
 
-    def __init__(self, key: JsonPath) -> None:
+    def __init__(self, key: JsonPath, null_elements: Optional[NullElements] = None) -> None:
         """
         Create an IsNullCondition.
 
         Args:
             key: Payload field path.
+            null_elements: How to treat arrays with null elements. Default: Ignore.
         """
         ...
 
@@ -2513,6 +2807,18 @@ class IsNullCondition:
         """Field key."""
         ...
 
+    @property
+    def null_elements(self) -> Optional[NullElements]:
+        """How to treat arrays with null elements."""
+        ...
+
+class NullElements(Enum):
+    """Treatment of null elements of arrays in IsNullCondition."""
+
+    Ignore = ...
+    Include = ...
+    Only = ...
+
 class HasIdCondition:
     """Check if point ID is in a set."""
 
@@ -2547,6 +2853,80 @@ class HasVectorCondition:
         """Vector name."""
         ...
 
+class UpdatedAfterCondition:
+    """Check if point was updated after a specific version of the shard."""
+
+    def __init__(self, version: int) -> None:
+        """
+        Create an UpdatedAfterCondition.
+
+        Args:
+            version: Points with a higher version match. Deleted points never match.
+        """
+        ...
+
+    @property
+    def version(self) -> int:
+        """Version, after which points were updated."""
+        ...
+
+class ExpressionCondition:
+    """Check if the result of an arithmetic expression over payload fields is in a range."""
+
+    def __init__(self, expression: "ArithmeticExpression", range: "RangeFloat") -> None:
+        """
+        Create an ExpressionCondition.
+
+        Args:
+            expression: Expression over numeric payload fields.
+            range: Range the result must be in. Points with missing fields, or with a
+                non-finite result, e.g. on division by zero, never match.
+        """
+        ...
+
+    @property
+    def expression(self) -> "ArithmeticExpression":
+        """Expression over numeric payload fields."""
+        ...
+
+    @property
+    def range(self) -> "RangeFloat":
+        """Range the result must be in."""
+        ...
+
+class ArithmeticExpression(Enum):
+    """Arithmetic expression types for expression conditions."""
+
+    @staticmethod
+    def Constant(val: float) -> "ArithmeticExpression":
+        """Create a constant expression."""
+        ...
+
+    @staticmethod
+    def Key(key: JsonPath) -> "ArithmeticExpression":
+        """Create a numeric payload field expression."""
+        ...
+
+    @staticmethod
+    def Sum(exprs: List["ArithmeticExpression"]) -> "ArithmeticExpression":
+        """Create a sum expression."""
+        ...
+
+    @staticmethod
+    def Mult(exprs: List["ArithmeticExpression"]) -> "ArithmeticExpression":
+        """Create a multiplication expression."""
+        ...
+
+    @staticmethod
+    def Neg(expr: "ArithmeticExpression") -> "ArithmeticExpression":
+        """Create a negation expression."""
+        ...
+
+    @staticmethod
+    def Div(left: "ArithmeticExpression", right: "ArithmeticExpression") -> "ArithmeticExpression":
+        """Create a division expression."""
+        ...
+
 class NestedCondition:
     """Condition on nested objects."""
 
@@ -2594,12 +2974,19 @@ class MatchValue:
 class MatchText:
     """Full-text match."""
 
-    def __init__(self, text: str) -> None:
+    def __init__(
+        self,
+        text: str,
+        language: Optional["Language"] = None,
+        unstemmed: Optional[bool] = None,
+    ) -> None:
         """
         Create a MatchText.
 
         Args:
             text: Text to search for.
+            language: Language of the query, its stopwords are removed from the query.
+            unstemmed: Match the original form of the query words instead of the stemmed one.
         """
         ...
 
@@ -2608,15 +2995,30 @@ class MatchText:
         """Text."""
         ...
 
+    @property
+    def language(self) -> Optional["Language"]:
+        """Query language."""
+        ...
+
+    @property
+    def unstemmed(self) -> Optional[bool]:
+        """Match the original form of the query words."""
+        ...
+
 class MatchTextAny:
     """Match any of the words in text."""
 
-    def __init__(self, text_any: str) -> None:
+    def __init__(
+        self,
+        text_any: str,
+        min_should_match: Optional[MinShouldMatchType] = None,
+    ) -> None:
         """
         Create a MatchTextAny.
 
         Args:
             text_any: Space-separated words to match any of.
+            min_should_match: Minimum number (or percentage) of words that must match.
         """
         ...
 
@@ -2625,15 +3027,38 @@ class MatchTextAny:
         """Text."""
         ...
 
+    @property
+    def min_should_match(self) -> Optional[MinShouldMatchType]:
+        """Minimum number of words that must match."""
+        ...
+
+class MinShouldMatchPercentage:
+    """Minimum percentage of query words that must match."""
+
+    def __init__(self, percent: int) -> None:
+        """
+        Create a MinShouldMatchPercentage.
+
+        Args:
+            percent: Percentage of query words, from 0 to 100.
+        """
+        ...
+
+    @property
+    def percent(self) -> int:
+        """Percentage."""
+        ...
+
 class MatchPhrase:
     """Match exact phrase."""
 
-    def __init__(self, phrase: str) -> None:
+    def __init__(self, phrase: str, slop: Optional[int] = None) -> None:
         """
         Create a MatchPhrase.
 
         Args:
             phrase: Phrase to match.
+            slop: Max number of other words between the words of the phrase, in total.
         """
         ...
 
@@ -2642,6 +3067,11 @@ class MatchPhrase:
         """Phrase."""
         ...
 
+    @property
+    def slop(self) -> Optional[int]:
+        """Max number of other words between the words of the phrase."""
+        ...
+
 class MatchAny:
     """Match any of the values."""
 
@@ -2676,6 +3106,40 @@ class MatchExcept:
         """Excluded values."""
         ...
 
+class MatchExactSet:
+    """Match exactly this set of values, no more and no less. Duplicates are ignored."""
+
+    def __init__(self, exact_set: Union[List[str], List[int]]) -> None:
+        """
+        Create a MatchExactSet.
+
+        Args:
+            exact_set: Set of values, which must all be present, and nothing else.
+        """
+        ...
+
+    @property
+    def value(self) -> Union[List[str], List[int]]:
+        """Values of the set."""
+        ...
+
+class MatchKeywordPrefix:
+    """Match keywords starting with the prefix. Case-sensitive."""
+
+    def __init__(self, prefix: str) -> None:
+        """
+        Create a MatchKeywordPrefix.
+
+        Args:
+            prefix: Prefix of the keyword to match.
+        """
+        ...
+
+    @property
+    def prefix(self) -> str:
+        """Prefix to match."""
+        ...
+
 # ============================================================================
 # Range Conditions
 # ============================================================================
@@ -2762,6 +3226,47 @@ class RangeDateTime:
         """Less than."""
         ...
 
+class RangeUuid:
+    """Range condition for UUID values, compared by their 128-bit value."""
+
+    def __init__(
+        self,
+        gte: Optional[UUID] = None,
+        gt: Optional[UUID] = None,
+        lte: Optional[UUID] = None,
+        lt: Optional[UUID] = None,
+    ) -> None:
+        """
+        Create a RangeUuid.
+
+        Args:
+            gte: Greater than or equal.
+            gt: Greater than.
+            lte: Less than or equal.
+            lt: Less than.
+        """
+        ...
+
+    @property
+    def gte(self) -> Optional[UUID]:
+        """Greater than or equal."""
+        ...
+
+    @property
+    def gt(self) -> Optional[UUID]:
+        """Greater than."""
+        ...
+
+    @property
+    def lte(self) -> Optional[UUID]:
+        """Less than or equal."""
+        ...
+
+    @property
+    def lt(self) -> Optional[UUID]:
+        """Less than."""
+        ...
+
 class ValuesCount:
     """Condition on count of values in array field."""
 
@@ -2771,6 +3276,7 @@ class ValuesCount:
         gt: Optional[int] = None,
         lte: Optional[int] = None,
         gte: Optional[int] = None,
+        distinct: Optional[bool] = None,
     ) -> None:
         """
         Create a ValuesCount.
@@ -2780,6 +3286,7 @@ class ValuesCount:
             gt: Greater than.
             lte: Less than or equal.
             gte: Greater than or equal.
+            distinct: Count only distinct values.
         """
         ...
 
@@ -2803,6 +3310,11 @@ class ValuesCount:
         """Greater than or equal."""
         ...
 
+    @property
+    def distinct(self) -> Optional[bool]:
+        """Count only distinct values."""
+        ...
+
 # ============================================================================
 # Geo Types
 # ============================================================================
@@ -2883,6 +3395,7 @@ class GeoPolygon:
         self,
         exterior: List[GeoPoint],
         interiors: Optional[List[List[GeoPoint]]] = None,
+        tolerance: Optional[float] = None,
     ) -> None:
         """
         Create a GeoPolygon.
@@ -2890,6 +3403,7 @@ class GeoPolygon:
         Args:
             exterior: Exterior ring points.
             interiors: Optional interior rings (holes).
+            tolerance: Optional tolerance in meters to simplify the rings to before matching.
         """
         ...
 
@@ -2903,6 +3417,34 @@ class GeoPolygon:
         """Interior rings (holes)."""
         ...
 
+    @property
+    def tolerance(self) -> Optional[float]:
+        """Simplification tolerance in meters."""
+        ...
+
+class GeoNearPath:
+    """Area within a distance of a geographic path."""
+
+    def __init__(self, path: List[GeoPoint], distance: float) -> None:
+        """
+        Create a GeoNearPath.
+
+        Args:
+            path: Path points, at least 2.
+            distance: Maximal distance from the path in meters.
+        """
+        ...
+
+    @property
+    def path(self) -> List[GeoPoint]:
+        """Path points."""
+        ...
+
+    @property
+    def distance(self) -> float:
+        """Distance in meters."""
+        ...
+
 # ============================================================================
 # Payload Selector
 # ============================================================================
@@ -94,12 +94,18 @@ pub struct PyScalarQuantizationConfig(ScalarQuantizationConfig);
 #[pymethods]
 impl PyScalarQuantizationConfig {
     #[new]
-    #[pyo3(signature = (r#type, quantile = None, always_ram = None))]
-    pub fn new(r#type: PyScalarType, quantile: Option<f32>, always_ram: Option<bool>) -> Self {
+    #[pyo3(signature = (r#type, quantile = None, always_ram = None, outliers = None))]
+    pub fn new(
+        r#type: PyScalarType,
+        quantile: Option<f32>,
+        always_ram: Option<bool>,
+        outliers: Option<PyScalarOutliers>,
+    ) -> Self {
         Self(ScalarQuantizationConfig {
             r#type: ScalarType::from(r#type),
             quantile,
             always_ram,
+            outliers: outliers.map(ScalarOutliers::from),
         })
     }
 
@@ -118,6 +124,11 @@ impl PyScalarQuantizationConfig {
         self.0.always_ram
     }
 
+    #[getter]
+    pub fn outliers(&self) -> Option<PyScalarOutliers> {
+        self.0.outliers.map(PyScalarOutliers::from)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -130,6 +141,7 @@ impl PyScalarQuantizationConfig {
             r#type: _,
             quantile: _,
             always_ram: _,
+            outliers: _,
         } = self.0;
     }
 }
@@ -173,6 +185,49 @@ impl From<PyScalarType> for ScalarType {
     }
 }
 
+#[pyclass(name = "ScalarOutliers", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyScalarOutliers {
+    Clamp,
+    FullPrecision,
+}
+
+#[pymethods]
+impl PyScalarOutliers {
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Repr for PyScalarOutliers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Clamp => "Clamp",
+            Self::FullPrecision => "FullPrecision",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<ScalarOutliers> for PyScalarOutliers {
+    fn from(outliers: ScalarOutliers) -> Self {
+        match outliers {
+            ScalarOutliers::Clamp => PyScalarOutliers::Clamp,
+            ScalarOutliers::FullPrecision => PyScalarOutliers::FullPrecision,
+        }
+    }
+}
+
+impl From<PyScalarOutliers> for ScalarOutliers {
+    fn from(outliers: PyScalarOutliers) -> Self {
+        match outliers {
+            PyScalarOutliers::Clamp => ScalarOutliers::Clamp,
+            PyScalarOutliers::FullPrecision => ScalarOutliers::FullPrecision,
+        }
+    }
+}
+
 #[pyclass(name = "ProductQuantizationConfig", from_py_object)]
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
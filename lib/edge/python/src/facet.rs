@@ -98,6 +98,11 @@ impl PyFacetResponse {
         PyFacetHit::wrap_vec(self.0.hits.clone())
     }
 
+    #[getter]
+    pub fn other_count(&self) -> usize {
+        self.0.other_count
+    }
+
     fn __len__(&self) -> usize {
         self.0.hits.len()
     }
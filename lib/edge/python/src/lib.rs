@@ -38,7 +38,7 @@ mod qdrant_edge {
     use super::config::quantization::{
         PyBinaryQuantizationConfig, PyBinaryQuantizationEncoding,
         PyBinaryQuantizationQueryEncoding, PyCompressionRatio, PyProductQuantizationConfig,
-        PyScalarQuantizationConfig, PyScalarType, PyTurboQuantBitSize,
+        PyScalarOutliers, PyScalarQuantizationConfig, PyScalarType, PyTurboQuantBitSize,
         PyTurboQuantQuantizationConfig,
     };
     #[pymodule_export]
@@ -57,28 +57,34 @@ mod qdrant_edge {
     #[pymodule_export]
     use super::query::{
         PyDirection, PyFusion, PyMmr, PyOrderBy, PyPrefetch, PyQueryRequest, PySample,
+        PyWeightedSample,
     };
     #[pymodule_export]
     use super::scroll::PyScrollRequest;
     #[pymodule_export]
     use super::search::{
-        PyAcornSearchParams, PyQuantizationSearchParams, PySearchParams, PySearchRequest,
+        PyAcornSearchParams, PyMissingVectors, PyQuantizationFallbackParams,
+        PyQuantizationSearchParams, PySearchParams, PySearchRequest,
     };
     #[pymodule_export]
     use super::types::filter::{
-        PyFieldCondition, PyFilter, PyGeoBoundingBox, PyGeoPoint, PyGeoPolygon, PyGeoRadius,
-        PyHasIdCondition, PyHasVectorCondition, PyIsEmptyCondition, PyIsNullCondition, PyMatchAny,
-        PyMatchExcept, PyMatchPhrase, PyMatchText, PyMatchTextAny, PyMatchValue, PyMinShould,
-        PyNestedCondition, PyRangeDateTime, PyRangeFloat, PyValuesCount,
+        PyArithmeticExpressionInterface, PyExpressionCondition, PyFieldCondition, PyFilter,
+        PyGeoBoundingBox, PyGeoNearPath, PyGeoPoint, PyGeoPolygon, PyGeoRadius, PyHasIdCondition,
+        PyHasVectorCondition, PyIsEmptyCondition, PyIsNullCondition, PyMatchAny, PyMatchExactSet,
+        PyMatchExcept, PyMatchKeywordPrefix, PyMatchPhrase, PyMatchText, PyMatchTextAny,
+        PyMatchValue, PyMinShould, PyMinShouldMatchPercentage, PyNestedCondition, PyNullElements,
+        PyRangeDateTime, PyRangeFloat, PyRangeUuid, PyUpdatedAfterCondition, PyValuesCount,
     };
     #[pymodule_export]
-    use super::types::formula::{PyDecayKind, PyExpressionInterface, PyFormula};
+    use super::types::formula::{
+        PyDecayKind, PyExpressionInterface, PyFormula, PyTextScoreAggregation,
+    };
     #[pymodule_export]
     use super::types::payload_schema::{
         PyBoolIndexParams, PyDatetimeIndexParams, PyFloatIndexParams, PyGeoIndexParams,
-        PyIntegerIndexParams, PyKeywordIndexParams, PyLanguage, PyPayloadSchemaType,
-        PySnowballLanguage, PySnowballParams, PyStopwordsSet, PyTextIndexParams, PyTokenizerType,
-        PyUuidIndexParams,
+        PyIntegerIndexParams, PyKeywordIndexParams, PyLanguage, PyOversizedValuePolicy,
+        PyPayloadSchemaType, PySnowballLanguage, PySnowballParams, PyStopwordsSet,
+        PyTextIndexCompression, PyTextIndexParams, PyTokenizerType, PyUuidIndexParams,
     };
     #[pymodule_export]
     use super::types::query::{
@@ -8,6 +8,7 @@ use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use segment::data_types::order_by::{Direction, OrderBy, OrderByInterface, StartFrom};
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorInternal};
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::json_path::JsonPath;
 use shard::query::query_enum::QueryEnum;
@@ -59,6 +60,9 @@ impl PyQueryRequest {
             filter: filter.map(Filter::from),
             score_threshold: score_threshold.map(OrderedFloat),
             params: params.map(SearchParams::from),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         })
     }
 
@@ -127,6 +131,9 @@ impl PyQueryRequest {
             params: _,
             with_vector: _,
             with_payload: _,
+            with_provenance: _,  // not relevant for Qdrant Edge
+            with_explanation: _, // not relevant for Qdrant Edge
+            soft_timeout: _,     // not relevant for Qdrant Edge
         } = self.0;
     }
 }
@@ -242,6 +249,7 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             OrderBy(PyOrderBy),
             Formula(PyFormula),
             Sample(PySample),
+            WeightedSample(PyWeightedSample),
             Mmr(PyMmr),
         }
 
@@ -262,6 +270,9 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             Helper::OrderBy(order_by) => ScoringQuery::OrderBy(OrderBy::from(order_by)),
             Helper::Formula(formula) => ScoringQuery::Formula(ParsedFormula::from(formula)),
             Helper::Sample(sample) => ScoringQuery::Sample(SampleInternal::from(sample)),
+            Helper::WeightedSample(sample) => {
+                ScoringQuery::Sample(SampleInternal::Weighted(WeightedSample::from(sample)))
+            }
             Helper::Mmr(mmr) => ScoringQuery::Mmr(MmrInternal::from(mmr)),
         };
 
@@ -280,7 +291,10 @@ impl<'py> IntoPyObject<'py> for PyScoringQuery {
             ScoringQuery::Fusion(fusion) => PyFusion::from(fusion).into_bound_py_any(py),
             ScoringQuery::OrderBy(order_by) => PyOrderBy(order_by).into_bound_py_any(py),
             ScoringQuery::Formula(formula) => PyFormula(formula).into_bound_py_any(py),
-            ScoringQuery::Sample(sample) => PySample::from(sample).into_bound_py_any(py),
+            ScoringQuery::Sample(SampleInternal::Random) => PySample::Random.into_bound_py_any(py),
+            ScoringQuery::Sample(SampleInternal::Weighted(sample)) => {
+                PyWeightedSample(sample).into_bound_py_any(py)
+            }
             ScoringQuery::Mmr(mmr) => PyMmr(mmr).into_bound_py_any(py),
         }
     }
@@ -303,7 +317,10 @@ impl Repr for PyScoringQuery {
             ScoringQuery::Fusion(fusion) => PyFusion::from(fusion.clone()).fmt(f),
             ScoringQuery::OrderBy(order_by) => PyOrderBy::wrap_ref(order_by).fmt(f),
             ScoringQuery::Formula(_formula) => f.unimplemented(), // TODO!
-            ScoringQuery::Sample(sample) => PySample::from(*sample).fmt(f),
+            ScoringQuery::Sample(SampleInternal::Random) => PySample::Random.fmt(f),
+            ScoringQuery::Sample(SampleInternal::Weighted(sample)) => {
+                PyWeightedSample::wrap_ref(sample).fmt(f)
+            }
             ScoringQuery::Mmr(mmr) => PyMmr::wrap_ref(mmr).fmt(f),
         }
     }
@@ -571,19 +588,67 @@ impl Repr for PySample {
     }
 }
 
-impl From<SampleInternal> for PySample {
-    fn from(sample: SampleInternal) -> Self {
+impl From<PySample> for SampleInternal {
+    fn from(sample: PySample) -> Self {
         match sample {
-            SampleInternal::Random => PySample::Random,
+            PySample::Random => SampleInternal::Random,
         }
     }
 }
 
-impl From<PySample> for SampleInternal {
-    fn from(sample: PySample) -> Self {
-        match sample {
-            PySample::Random => SampleInternal::Random,
+#[pyclass(name = "WeightedSample", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyWeightedSample(WeightedSample);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyWeightedSample {
+    #[new]
+    #[pyo3(signature = (key, seed, default_weight = None))]
+    pub fn new(key: PyJsonPath, seed: u64, default_weight: Option<f64>) -> PyResult<Self> {
+        let default_weight = default_weight.unwrap_or_default();
+        if default_weight.is_nan() || default_weight < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "default_weight must be a non-negative number, got {default_weight}"
+            )));
         }
+
+        Ok(Self(WeightedSample {
+            key: JsonPath::from(key),
+            seed,
+            default_weight: OrderedFloat(default_weight),
+        }))
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    #[getter]
+    pub fn seed(&self) -> u64 {
+        self.0.seed
+    }
+
+    #[getter]
+    pub fn default_weight(&self) -> f64 {
+        self.0.default_weight.into_inner()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyWeightedSample {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let WeightedSample {
+            key: _,
+            seed: _,
+            default_weight: _,
+        } = self.0;
     }
 }
 
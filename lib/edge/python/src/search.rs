@@ -1,3 +1,5 @@
+use std::fmt;
+
 use bytemuck::TransparentWrapper;
 use derive_more::Into;
 use ordered_float::OrderedFloat;
@@ -124,13 +126,20 @@ impl PySearchParams {
         quantization = None,
         indexed_only = false,
         acorn = None,
+        missing_vectors = None,
+        segment_limit_factor = None,
+        allow_full_scan = false,
     ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         hnsw_ef: Option<usize>,
         exact: bool,
         quantization: Option<PyQuantizationSearchParams>,
         indexed_only: bool,
         acorn: Option<PyAcornSearchParams>,
+        missing_vectors: Option<PyMissingVectors>,
+        segment_limit_factor: Option<f64>,
+        allow_full_scan: bool,
     ) -> Self {
         Self(SearchParams {
             hnsw_ef,
@@ -138,6 +147,9 @@ impl PySearchParams {
             quantization: quantization.map(QuantizationSearchParams::from),
             indexed_only,
             acorn: acorn.map(AcornSearchParams::from),
+            missing_vectors: missing_vectors.map(MissingVectors::from),
+            segment_limit_factor: segment_limit_factor.map(OrderedFloat),
+            allow_full_scan,
         })
     }
 
@@ -166,6 +178,23 @@ impl PySearchParams {
         self.0.acorn.map(PyAcornSearchParams)
     }
 
+    #[getter]
+    pub fn missing_vectors(&self) -> Option<PyMissingVectors> {
+        self.0.missing_vectors.map(PyMissingVectors::from)
+    }
+
+    #[getter]
+    pub fn segment_limit_factor(&self) -> Option<f64> {
+        self.0
+            .segment_limit_factor
+            .map(|OrderedFloat(factor)| factor)
+    }
+
+    #[getter]
+    pub fn allow_full_scan(&self) -> bool {
+        self.0.allow_full_scan
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -180,10 +209,56 @@ impl PySearchParams {
             quantization: _,
             indexed_only: _,
             acorn: _,
+            missing_vectors: _,
+            segment_limit_factor: _,
+            allow_full_scan: _,
         } = self.0;
     }
 }
 
+#[pyclass(name = "MissingVectors", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyMissingVectors {
+    Exclude,
+    Include,
+}
+
+#[pymethods]
+impl PyMissingVectors {
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Repr for PyMissingVectors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Exclude => "Exclude",
+            Self::Include => "Include",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<MissingVectors> for PyMissingVectors {
+    fn from(missing_vectors: MissingVectors) -> Self {
+        match missing_vectors {
+            MissingVectors::Exclude => PyMissingVectors::Exclude,
+            MissingVectors::Include => PyMissingVectors::Include,
+        }
+    }
+}
+
+impl From<PyMissingVectors> for MissingVectors {
+    fn from(missing_vectors: PyMissingVectors) -> Self {
+        match missing_vectors {
+            PyMissingVectors::Exclude => MissingVectors::Exclude,
+            PyMissingVectors::Include => MissingVectors::Include,
+        }
+    }
+}
+
 #[pyclass(name = "QuantizationSearchParams", from_py_object)]
 #[derive(Copy, Clone, Debug, Into)]
 pub struct PyQuantizationSearchParams(QuantizationSearchParams);
@@ -192,12 +267,18 @@ pub struct PyQuantizationSearchParams(QuantizationSearchParams);
 #[pymethods]
 impl PyQuantizationSearchParams {
     #[new]
-    #[pyo3(signature = (ignore = false, rescore = None, oversampling = None))]
-    pub fn new(ignore: bool, rescore: Option<bool>, oversampling: Option<f64>) -> Self {
+    #[pyo3(signature = (ignore = false, rescore = None, oversampling = None, fallback = None))]
+    pub fn new(
+        ignore: bool,
+        rescore: Option<bool>,
+        oversampling: Option<f64>,
+        fallback: Option<PyQuantizationFallbackParams>,
+    ) -> Self {
         Self(QuantizationSearchParams {
             ignore,
             rescore,
             oversampling,
+            fallback: fallback.map(QuantizationFallbackParams::from),
         })
     }
 
@@ -216,6 +297,11 @@ impl PyQuantizationSearchParams {
         self.0.oversampling
     }
 
+    #[getter]
+    pub fn fallback(&self) -> Option<PyQuantizationFallbackParams> {
+        self.0.fallback.map(PyQuantizationFallbackParams)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -228,6 +314,59 @@ impl PyQuantizationSearchParams {
             ignore: _,
             rescore: _,
             oversampling: _,
+            fallback: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "QuantizationFallbackParams", from_py_object)]
+#[derive(Copy, Clone, Debug, Into)]
+pub struct PyQuantizationFallbackParams(QuantizationFallbackParams);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyQuantizationFallbackParams {
+    #[new]
+    #[pyo3(signature = (enable = false, max_distortion = None, max_oversampling = None))]
+    pub fn new(enable: bool, max_distortion: Option<f64>, max_oversampling: Option<f64>) -> Self {
+        Self(QuantizationFallbackParams {
+            enable,
+            max_distortion: max_distortion.map(OrderedFloat),
+            max_oversampling: max_oversampling.map(OrderedFloat),
+        })
+    }
+
+    #[getter]
+    pub fn enable(&self) -> bool {
+        self.0.enable
+    }
+
+    #[getter]
+    pub fn max_distortion(&self) -> Option<f64> {
+        self.0
+            .max_distortion
+            .map(|distortion| distortion.into_inner())
+    }
+
+    #[getter]
+    pub fn max_oversampling(&self) -> Option<f64> {
+        self.0
+            .max_oversampling
+            .map(|oversampling| oversampling.into_inner())
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyQuantizationFallbackParams {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let QuantizationFallbackParams {
+            enable: _,
+            max_distortion: _,
+            max_oversampling: _,
         } = self.0;
     }
 }
@@ -27,6 +27,8 @@ impl FromPyObject<'_, '_> for PyCondition {
             IsNull(PyIsNullCondition),
             HasId(PyHasIdCondition),
             HasVector(PyHasVectorCondition),
+            UpdatedAfter(PyUpdatedAfterCondition),
+            Expression(PyExpressionCondition),
             Nested(PyNestedCondition),
             Filter(PyFilter),
         }
@@ -37,6 +39,8 @@ impl FromPyObject<'_, '_> for PyCondition {
             Helper::IsNull(is_null) => Condition::IsNull(is_null.into()),
             Helper::HasId(has_id) => Condition::HasId(has_id.into()),
             Helper::HasVector(has_vector) => Condition::HasVector(has_vector.into()),
+            Helper::UpdatedAfter(updated_after) => Condition::UpdatedAfter(updated_after.into()),
+            Helper::Expression(expression) => Condition::Expression(expression.into()),
             Helper::Nested(nested) => Condition::Nested(nested.into()),
             Helper::Filter(filter) => Condition::Filter(filter.into()),
         };
@@ -59,6 +63,12 @@ impl<'py> IntoPyObject<'py> for PyCondition {
             Condition::HasVector(has_vector) => {
                 PyHasVectorCondition(has_vector).into_bound_py_any(py)
             }
+            Condition::UpdatedAfter(updated_after) => {
+                PyUpdatedAfterCondition(updated_after).into_bound_py_any(py)
+            }
+            Condition::Expression(expression) => {
+                PyExpressionCondition(expression).into_bound_py_any(py)
+            }
             Condition::Nested(nested) => PyNestedCondition(nested).into_bound_py_any(py),
             Condition::Filter(filter) => PyFilter(filter).into_bound_py_any(py),
             Condition::CustomIdChecker(_) => {
@@ -86,6 +96,10 @@ impl Repr for PyCondition {
             Condition::IsNull(is_null) => PyIsNullCondition::wrap_ref(is_null).fmt(f),
             Condition::HasId(has_id) => PyHasIdCondition::wrap_ref(has_id).fmt(f),
             Condition::HasVector(has_vector) => PyHasVectorCondition::wrap_ref(has_vector).fmt(f),
+            Condition::UpdatedAfter(updated_after) => {
+                PyUpdatedAfterCondition::wrap_ref(updated_after).fmt(f)
+            }
+            Condition::Expression(expression) => PyExpressionCondition::wrap_ref(expression).fmt(f),
             Condition::Nested(nested) => PyNestedCondition::wrap_ref(nested).fmt(f),
             Condition::Filter(filter) => PyFilter::wrap_ref(filter).fmt(f),
             Condition::CustomIdChecker(_) => {
@@ -140,11 +154,13 @@ pub struct PyIsNullCondition(pub IsNullCondition);
 #[pymethods]
 impl PyIsNullCondition {
     #[new]
-    pub fn new(key: PyJsonPath) -> Self {
+    #[pyo3(signature = (key, null_elements = None))]
+    pub fn new(key: PyJsonPath, null_elements: Option<PyNullElements>) -> Self {
         Self(IsNullCondition {
             is_null: PayloadField {
                 key: JsonPath::from(key),
             },
+            null_elements: null_elements.map(NullElements::from),
         })
     }
 
@@ -153,6 +169,11 @@ impl PyIsNullCondition {
         PyJsonPath::wrap_ref(&self.0.is_null.key)
     }
 
+    #[getter]
+    pub fn null_elements(&self) -> Option<PyNullElements> {
+        self.0.null_elements.map(PyNullElements::from)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -163,10 +184,51 @@ impl PyIsNullCondition {
         // Every field should have a getter method
         let IsNullCondition {
             is_null: PayloadField { key: _ },
+            null_elements: _,
         } = self.0;
     }
 }
 
+#[pyclass(name = "NullElements", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyNullElements {
+    Ignore,
+    Include,
+    Only,
+}
+
+impl Repr for PyNullElements {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Ignore => "Ignore",
+            Self::Include => "Include",
+            Self::Only => "Only",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<NullElements> for PyNullElements {
+    fn from(null_elements: NullElements) -> Self {
+        match null_elements {
+            NullElements::Ignore => PyNullElements::Ignore,
+            NullElements::Include => PyNullElements::Include,
+            NullElements::Only => PyNullElements::Only,
+        }
+    }
+}
+
+impl From<PyNullElements> for NullElements {
+    fn from(null_elements: PyNullElements) -> Self {
+        match null_elements {
+            PyNullElements::Ignore => NullElements::Ignore,
+            PyNullElements::Include => NullElements::Include,
+            PyNullElements::Only => NullElements::Only,
+        }
+    }
+}
+
 #[pyclass(name = "HasIdCondition", from_py_object)]
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
@@ -230,3 +292,37 @@ impl PyHasVectorCondition {
         } = self.0;
     }
 }
+
+#[pyclass(name = "UpdatedAfterCondition", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyUpdatedAfterCondition(pub UpdatedAfterCondition);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyUpdatedAfterCondition {
+    #[new]
+    pub fn new(version: SeqNumberType) -> Self {
+        Self(UpdatedAfterCondition {
+            updated_after: version,
+        })
+    }
+
+    #[getter]
+    pub fn version(&self) -> SeqNumberType {
+        self.0.updated_after
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyUpdatedAfterCondition {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let UpdatedAfterCondition {
+            updated_after: _version,
+        } = self.0;
+    }
+}
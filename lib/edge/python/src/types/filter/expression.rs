@@ -0,0 +1,223 @@
+use std::fmt;
+
+use bytemuck::{TransparentWrapper, TransparentWrapperAlloc as _};
+use derive_more::Into;
+use ordered_float::OrderedFloat;
+use pyo3::prelude::*;
+use segment::types::*;
+
+use crate::repr::*;
+use crate::*;
+
+#[pyclass(name = "ExpressionCondition", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyExpressionCondition(pub ExpressionCondition);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyExpressionCondition {
+    #[new]
+    pub fn new(expression: PyArithmeticExpression, range: PyRangeFloat) -> Self {
+        Self(ExpressionCondition {
+            expression: expression.0,
+            range: range.0,
+        })
+    }
+
+    #[getter]
+    pub fn expression(&self) -> PyArithmeticExpression {
+        PyArithmeticExpression(self.0.expression.clone())
+    }
+
+    #[getter]
+    pub fn range(&self) -> PyRangeFloat {
+        PyRangeFloat(self.0.range)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyExpressionCondition {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let ExpressionCondition {
+            expression: _,
+            range: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "ArithmeticExpression", from_py_object)]
+#[derive(Clone, Debug)]
+pub enum PyArithmeticExpressionInterface {
+    Constant {
+        val: f64,
+    },
+
+    Key {
+        key: PyJsonPath,
+    },
+
+    Sum {
+        exprs: Vec<PyArithmeticExpression>,
+    },
+
+    Mult {
+        exprs: Vec<PyArithmeticExpression>,
+    },
+
+    Neg {
+        expr: Boxed<PyArithmeticExpression>,
+    },
+
+    Div {
+        left: Boxed<PyArithmeticExpression>,
+        right: Boxed<PyArithmeticExpression>,
+    },
+}
+
+impl Repr for PyArithmeticExpressionInterface {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (repr, fields): (_, &[(_, &dyn Repr)]) = match self {
+            PyArithmeticExpressionInterface::Constant { val } => ("Constant", &[("val", val)]),
+            PyArithmeticExpressionInterface::Key { key } => ("Key", &[("key", key)]),
+            PyArithmeticExpressionInterface::Sum { exprs } => ("Sum", &[("exprs", exprs)]),
+            PyArithmeticExpressionInterface::Mult { exprs } => ("Mult", &[("exprs", exprs)]),
+            PyArithmeticExpressionInterface::Neg { expr } => ("Neg", &[("expr", expr)]),
+
+            PyArithmeticExpressionInterface::Div { left, right } => {
+                ("Div", &[("left", left), ("right", right)])
+            }
+        };
+
+        f.complex_enum::<Self>(repr, fields)
+    }
+}
+
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyArithmeticExpression(ArithmeticExpression);
+
+impl FromPyObject<'_, '_> for PyArithmeticExpression {
+    type Error = PyErr;
+
+    fn extract(helper: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        let expr = match helper.extract()? {
+            PyArithmeticExpressionInterface::Constant { val } => {
+                ArithmeticExpression::Constant(OrderedFloat(val))
+            }
+
+            PyArithmeticExpressionInterface::Key { key } => ArithmeticExpression::Key(key.into()),
+
+            PyArithmeticExpressionInterface::Sum { exprs } => {
+                ArithmeticExpression::Sum(ArithmeticSum {
+                    sum: PyArithmeticExpression::peel_vec(exprs),
+                })
+            }
+
+            PyArithmeticExpressionInterface::Mult { exprs } => {
+                ArithmeticExpression::Mult(ArithmeticMult {
+                    mult: PyArithmeticExpression::peel_vec(exprs),
+                })
+            }
+
+            PyArithmeticExpressionInterface::Neg { expr } => {
+                ArithmeticExpression::Neg(ArithmeticNeg {
+                    neg: expr.into_box(),
+                })
+            }
+
+            PyArithmeticExpressionInterface::Div { left, right } => {
+                ArithmeticExpression::Div(ArithmeticDiv {
+                    div: ArithmeticDivParams {
+                        left: left.into_box(),
+                        right: right.into_box(),
+                    },
+                })
+            }
+        };
+
+        Ok(Self(expr))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for PyArithmeticExpression {
+    type Target = PyArithmeticExpressionInterface;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        let helper = match self.0 {
+            ArithmeticExpression::Constant(val) => {
+                PyArithmeticExpressionInterface::Constant { val: val.0 }
+            }
+
+            ArithmeticExpression::Key(key) => PyArithmeticExpressionInterface::Key {
+                key: PyJsonPath(key),
+            },
+
+            ArithmeticExpression::Sum(ArithmeticSum { sum }) => {
+                PyArithmeticExpressionInterface::Sum {
+                    exprs: PyArithmeticExpression::wrap_vec(sum),
+                }
+            }
+
+            ArithmeticExpression::Mult(ArithmeticMult { mult }) => {
+                PyArithmeticExpressionInterface::Mult {
+                    exprs: PyArithmeticExpression::wrap_vec(mult),
+                }
+            }
+
+            ArithmeticExpression::Neg(ArithmeticNeg { neg }) => {
+                PyArithmeticExpressionInterface::Neg {
+                    expr: Boxed::from_box(neg),
+                }
+            }
+
+            ArithmeticExpression::Div(ArithmeticDiv { div }) => {
+                PyArithmeticExpressionInterface::Div {
+                    left: Boxed::from_box(div.left),
+                    right: Boxed::from_box(div.right),
+                }
+            }
+        };
+
+        Bound::new(py, helper)
+    }
+}
+
+impl Repr for PyArithmeticExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (repr, fields): (_, &[(_, &dyn Repr)]) = match &self.0 {
+            ArithmeticExpression::Constant(val) => ("Constant", &[("val", &val.0)]),
+            ArithmeticExpression::Key(key) => ("Key", &[("key", PyJsonPath::wrap_ref(key))]),
+
+            ArithmeticExpression::Sum(ArithmeticSum { sum }) => (
+                "Sum",
+                &[("exprs", &PyArithmeticExpression::wrap_slice(sum))],
+            ),
+
+            ArithmeticExpression::Mult(ArithmeticMult { mult }) => (
+                "Mult",
+                &[("exprs", &PyArithmeticExpression::wrap_slice(mult))],
+            ),
+
+            ArithmeticExpression::Neg(ArithmeticNeg { neg }) => {
+                ("Neg", &[("expr", PyArithmeticExpression::wrap_ref(neg))])
+            }
+
+            ArithmeticExpression::Div(ArithmeticDiv { div }) => (
+                "Div",
+                &[
+                    ("left", PyArithmeticExpression::wrap_ref(&div.left)),
+                    ("right", PyArithmeticExpression::wrap_ref(&div.right)),
+                ],
+            ),
+        };
+
+        f.complex_enum::<PyArithmeticExpressionInterface>(repr, fields)
+    }
+}
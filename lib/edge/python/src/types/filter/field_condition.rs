@@ -23,6 +23,7 @@ impl PyFieldCondition {
         geo_bounding_box=None,
         geo_radius=None,
         geo_polygon=None,
+        geo_near_path=None,
         values_count=None,
         is_empty=None,
         is_null=None,
@@ -35,6 +36,7 @@ impl PyFieldCondition {
         geo_bounding_box: Option<PyGeoBoundingBox>,
         geo_radius: Option<PyGeoRadius>,
         geo_polygon: Option<PyGeoPolygon>,
+        geo_near_path: Option<PyGeoNearPath>,
         values_count: Option<PyValuesCount>,
         is_empty: Option<bool>,
         is_null: Option<bool>,
@@ -46,6 +48,7 @@ impl PyFieldCondition {
             geo_bounding_box: geo_bounding_box.map(GeoBoundingBox::from),
             geo_radius: geo_radius.map(GeoRadius::from),
             geo_polygon: geo_polygon.map(GeoPolygon::from),
+            geo_near_path: geo_near_path.map(GeoNearPath::from),
             values_count: values_count.map(ValuesCount::from),
             is_empty,
             is_null,
@@ -82,6 +85,11 @@ impl PyFieldCondition {
         self.0.geo_polygon.clone().map(PyGeoPolygon)
     }
 
+    #[getter]
+    pub fn geo_near_path(&self) -> Option<PyGeoNearPath> {
+        self.0.geo_near_path.clone().map(PyGeoNearPath)
+    }
+
     #[getter]
     pub fn values_count(&self) -> Option<PyValuesCount> {
         self.0.values_count.map(PyValuesCount)
@@ -108,6 +116,7 @@ impl PyFieldCondition {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
+            geo_near_path: _,
             values_count: _,
             is_empty: _,
             is_null: _,
@@ -145,14 +145,16 @@ pub struct PyGeoPolygon(pub GeoPolygon);
 #[pymethods]
 impl PyGeoPolygon {
     #[new]
-    #[pyo3(signature = (exterior, interiors=None))]
+    #[pyo3(signature = (exterior, interiors=None, tolerance=None))]
     pub fn new(
         exterior: PyGeoLineString,
         interiors: Option<Vec<PyGeoLineString>>,
+        tolerance: Option<f64>,
     ) -> Result<Self, PyErr> {
         let shadow = GeoPolygonShadow {
             exterior: GeoLineString::from(exterior),
             interiors: interiors.map(PyGeoLineString::peel_vec),
+            tolerance: tolerance.map(OrderedFloat),
         };
 
         let polygon =
@@ -174,6 +176,11 @@ impl PyGeoPolygon {
             .map(|interiors| PyGeoLineString::wrap_slice(interiors))
     }
 
+    #[getter]
+    pub fn tolerance(&self) -> Option<f64> {
+        self.0.tolerance.map(|OrderedFloat(tolerance)| tolerance)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -185,6 +192,52 @@ impl PyGeoPolygon {
         let GeoPolygon {
             exterior: _,
             interiors: _,
+            tolerance: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "GeoNearPath", from_py_object)]
+#[derive(Clone, Debug, Into)]
+pub struct PyGeoNearPath(pub GeoNearPath);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyGeoNearPath {
+    #[new]
+    pub fn new(path: Vec<PyGeoPoint>, distance: f64) -> Result<Self, PyErr> {
+        let shadow = GeoNearPathShadow {
+            path: PyGeoPoint::peel_vec(path),
+            distance: OrderedFloat(distance),
+        };
+
+        let near_path =
+            GeoNearPath::try_from(shadow).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(Self(near_path))
+    }
+
+    #[getter]
+    pub fn path(&self) -> &[PyGeoPoint] {
+        PyGeoPoint::wrap_slice(&self.0.path)
+    }
+
+    #[getter]
+    pub fn distance(&self) -> f64 {
+        self.0.distance.into_inner()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyGeoNearPath {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let GeoNearPath {
+            path: _,
+            distance: _,
         } = self.0;
     }
 }
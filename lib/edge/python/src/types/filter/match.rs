@@ -6,9 +6,11 @@ use derive_more::Into;
 use pyo3::IntoPyObjectExt as _;
 use pyo3::prelude::*;
 use pyo3::types::PyList;
+use segment::data_types::index::Language;
 use segment::types::*;
 
 use crate::repr::*;
+use crate::types::PyLanguage;
 
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
@@ -26,6 +28,8 @@ impl FromPyObject<'_, '_> for PyMatch {
             Phrase(PyMatchPhrase),
             Any(PyMatchAny),
             Except(PyMatchExcept),
+            ExactSet(PyMatchExactSet),
+            KeywordPrefix(PyMatchKeywordPrefix),
         }
 
         fn _variants(filter: Match) {
@@ -36,6 +40,8 @@ impl FromPyObject<'_, '_> for PyMatch {
                 Match::Phrase(_) => {}
                 Match::Any(_) => {}
                 Match::Except(_) => {}
+                Match::ExactSet(_) => {}
+                Match::KeywordPrefix(_) => {}
             }
         }
 
@@ -46,6 +52,8 @@ impl FromPyObject<'_, '_> for PyMatch {
             Helper::Phrase(phrase) => Match::Phrase(MatchPhrase::from(phrase)),
             Helper::Any(any) => Match::Any(MatchAny::from(any)),
             Helper::Except(except) => Match::Except(MatchExcept::from(except)),
+            Helper::ExactSet(exact_set) => Match::ExactSet(MatchExactSet::from(exact_set)),
+            Helper::KeywordPrefix(prefix) => Match::KeywordPrefix(MatchKeywordPrefix::from(prefix)),
         };
 
         Ok(Self(filter))
@@ -65,6 +73,8 @@ impl<'py> IntoPyObject<'py> for PyMatch {
             Match::Phrase(phrase) => PyMatchPhrase(phrase).into_bound_py_any(py),
             Match::Any(any) => PyMatchAny(any).into_bound_py_any(py),
             Match::Except(except) => PyMatchExcept(except).into_bound_py_any(py),
+            Match::ExactSet(exact_set) => PyMatchExactSet(exact_set).into_bound_py_any(py),
+            Match::KeywordPrefix(prefix) => PyMatchKeywordPrefix(prefix).into_bound_py_any(py),
         }
     }
 }
@@ -78,6 +88,8 @@ impl Repr for PyMatch {
             Match::Phrase(phrase) => PyMatchPhrase::wrap_ref(phrase).fmt(f),
             Match::Any(any) => PyMatchAny::wrap_ref(any).fmt(f),
             Match::Except(except) => PyMatchExcept::wrap_ref(except).fmt(f),
+            Match::ExactSet(exact_set) => PyMatchExactSet::wrap_ref(exact_set).fmt(f),
+            Match::KeywordPrefix(prefix) => PyMatchKeywordPrefix::wrap_ref(prefix).fmt(f),
         }
     }
 }
@@ -186,8 +198,13 @@ pub struct PyMatchText(pub MatchText);
 #[pymethods]
 impl PyMatchText {
     #[new]
-    pub fn new(text: String) -> Self {
-        Self(MatchText { text })
+    #[pyo3(signature = (text, language = None, unstemmed = None))]
+    pub fn new(text: String, language: Option<PyLanguage>, unstemmed: Option<bool>) -> Self {
+        Self(MatchText {
+            text,
+            language: language.map(Language::from),
+            unstemmed,
+        })
     }
 
     #[getter]
@@ -195,6 +212,16 @@ impl PyMatchText {
         &self.0.text
     }
 
+    #[getter]
+    pub fn language(&self) -> Option<PyLanguage> {
+        self.0.language.map(PyLanguage::from)
+    }
+
+    #[getter]
+    pub fn unstemmed(&self) -> Option<bool> {
+        self.0.unstemmed
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -203,7 +230,11 @@ impl PyMatchText {
 impl PyMatchText {
     fn _getters(self) {
         // Every field should have a getter method
-        let MatchText { text: _ } = self.0;
+        let MatchText {
+            text: _,
+            language: _,
+            unstemmed: _,
+        } = self.0;
     }
 }
 
@@ -216,8 +247,12 @@ pub struct PyMatchTextAny(pub MatchTextAny);
 #[pymethods]
 impl PyMatchTextAny {
     #[new]
-    pub fn new(text_any: String) -> Self {
-        Self(MatchTextAny { text_any })
+    #[pyo3(signature = (text_any, min_should_match = None))]
+    pub fn new(text_any: String, min_should_match: Option<PyMinShouldMatch>) -> Self {
+        Self(MatchTextAny {
+            text_any,
+            min_should_match: min_should_match.map(MinShouldMatch::from),
+        })
     }
 
     #[getter]
@@ -225,6 +260,11 @@ impl PyMatchTextAny {
         &self.0.text_any
     }
 
+    #[getter]
+    pub fn min_should_match(&self) -> Option<PyMinShouldMatch> {
+        self.0.min_should_match.map(PyMinShouldMatch)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -233,7 +273,98 @@ impl PyMatchTextAny {
 impl PyMatchTextAny {
     fn _getters(self) {
         // Every field should have a getter method
-        let MatchTextAny { text_any: _ } = self.0;
+        let MatchTextAny {
+            text_any: _,
+            min_should_match: _,
+        } = self.0;
+    }
+}
+
+#[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMinShouldMatch(MinShouldMatch);
+
+impl FromPyObject<'_, '_> for PyMinShouldMatch {
+    type Error = PyErr;
+
+    fn extract(value: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        #[derive(FromPyObject)]
+        enum Helper {
+            Count(usize),
+            Percentage(PyMinShouldMatchPercentage),
+        }
+
+        fn _variants(value: MinShouldMatch) {
+            match value {
+                MinShouldMatch::Count(_) => {}
+                MinShouldMatch::Percentage(_) => {}
+            }
+        }
+
+        let value = match value.extract()? {
+            Helper::Count(count) => MinShouldMatch::Count(count),
+            Helper::Percentage(percentage) => {
+                MinShouldMatch::Percentage(MinShouldMatchPercentage::from(percentage))
+            }
+        };
+
+        Ok(Self(value))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for PyMinShouldMatch {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        match self.0 {
+            MinShouldMatch::Count(count) => count.into_bound_py_any(py),
+            MinShouldMatch::Percentage(percentage) => {
+                PyMinShouldMatchPercentage(percentage).into_bound_py_any(py)
+            }
+        }
+    }
+}
+
+impl Repr for PyMinShouldMatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            MinShouldMatch::Count(count) => count.fmt(f),
+            MinShouldMatch::Percentage(percentage) => {
+                PyMinShouldMatchPercentage::wrap_ref(percentage).fmt(f)
+            }
+        }
+    }
+}
+
+#[pyclass(name = "MinShouldMatchPercentage", from_py_object)]
+#[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMinShouldMatchPercentage(pub MinShouldMatchPercentage);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMinShouldMatchPercentage {
+    #[new]
+    pub fn new(percent: u32) -> Self {
+        Self(MinShouldMatchPercentage { percent })
+    }
+
+    #[getter]
+    pub fn percent(&self) -> u32 {
+        self.0.percent
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMinShouldMatchPercentage {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MinShouldMatchPercentage { percent: _ } = self.0;
     }
 }
 
@@ -246,8 +377,9 @@ pub struct PyMatchPhrase(pub MatchPhrase);
 #[pymethods]
 impl PyMatchPhrase {
     #[new]
-    pub fn new(phrase: String) -> Self {
-        Self(MatchPhrase { phrase })
+    #[pyo3(signature = (phrase, slop = None))]
+    pub fn new(phrase: String, slop: Option<u32>) -> Self {
+        Self(MatchPhrase { phrase, slop })
     }
 
     #[getter]
@@ -255,6 +387,11 @@ impl PyMatchPhrase {
         &self.0.phrase
     }
 
+    #[getter]
+    pub fn slop(&self) -> Option<u32> {
+        self.0.slop
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -263,7 +400,7 @@ impl PyMatchPhrase {
 impl PyMatchPhrase {
     fn _getters(self) {
         // Every field should have a getter method
-        let MatchPhrase { phrase: _ } = self.0;
+        let MatchPhrase { phrase: _, slop: _ } = self.0;
     }
 }
 
@@ -331,6 +468,68 @@ impl PyMatchExcept {
     }
 }
 
+#[pyclass(name = "MatchExactSet", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMatchExactSet(pub MatchExactSet);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMatchExactSet {
+    #[new]
+    pub fn new(exact_set: PyAnyVariants) -> Self {
+        Self(MatchExactSet {
+            exact_set: AnyVariants::from(exact_set),
+        })
+    }
+
+    #[getter]
+    pub fn value(&self) -> &PyAnyVariants {
+        PyAnyVariants::wrap_ref(&self.0.exact_set)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMatchExactSet {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MatchExactSet { exact_set: _value } = self.0;
+    }
+}
+
+#[pyclass(name = "MatchKeywordPrefix", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyMatchKeywordPrefix(pub MatchKeywordPrefix);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyMatchKeywordPrefix {
+    #[new]
+    pub fn new(prefix: String) -> Self {
+        Self(MatchKeywordPrefix { prefix })
+    }
+
+    #[getter]
+    pub fn prefix(&self) -> &str {
+        &self.0.prefix
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyMatchKeywordPrefix {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let MatchKeywordPrefix { prefix: _ } = self.0;
+    }
+}
+
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
 pub struct PyAnyVariants(AnyVariants);
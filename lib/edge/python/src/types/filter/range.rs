@@ -12,6 +12,7 @@ use crate::repr::*;
 pub enum PyRange {
     Float(PyRangeFloat),
     DateTime(PyRangeDateTime),
+    Uuid(PyRangeUuid),
 }
 
 impl Repr for PyRange {
@@ -19,6 +20,7 @@ impl Repr for PyRange {
         match self {
             PyRange::Float(float) => float.fmt(f),
             PyRange::DateTime(date_time) => date_time.fmt(f),
+            PyRange::Uuid(uuid) => uuid.fmt(f),
         }
     }
 }
@@ -28,6 +30,7 @@ impl From<RangeInterface> for PyRange {
         match range {
             RangeInterface::Float(float) => PyRange::Float(PyRangeFloat(float)),
             RangeInterface::DateTime(date_time) => PyRange::DateTime(PyRangeDateTime(date_time)),
+            RangeInterface::Uuid(uuid) => PyRange::Uuid(PyRangeUuid(uuid)),
         }
     }
 }
@@ -37,6 +40,7 @@ impl From<PyRange> for RangeInterface {
         match range {
             PyRange::Float(float) => RangeInterface::Float(float.0),
             PyRange::DateTime(date_time) => RangeInterface::DateTime(date_time.0),
+            PyRange::Uuid(uuid) => RangeInterface::Uuid(uuid.0),
         }
     }
 }
@@ -153,6 +157,57 @@ impl PyRangeDateTime {
     }
 }
 
+#[pyclass(name = "RangeUuid", from_py_object)]
+#[derive(Copy, Clone, Debug, Into)]
+pub struct PyRangeUuid(pub Range<UuidPayloadType>);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyRangeUuid {
+    #[new]
+    #[pyo3(signature = (gte=None, gt=None, lte=None, lt=None))]
+    pub fn new(
+        gte: Option<UuidPayloadType>,
+        gt: Option<UuidPayloadType>,
+        lte: Option<UuidPayloadType>,
+        lt: Option<UuidPayloadType>,
+    ) -> Self {
+        Self(Range { gte, gt, lte, lt })
+    }
+
+    #[getter]
+    pub fn gte(&self) -> Option<UuidPayloadType> {
+        self.0.gte
+    }
+
+    #[getter]
+    pub fn gt(&self) -> Option<UuidPayloadType> {
+        self.0.gt
+    }
+
+    #[getter]
+    pub fn lte(&self) -> Option<UuidPayloadType> {
+        self.0.lte
+    }
+
+    #[getter]
+    pub fn lt(&self) -> Option<UuidPayloadType> {
+        self.0.lt
+    }
+}
+
+impl PyRangeUuid {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let Range {
+            gte: _,
+            gt: _,
+            lte: _,
+            lt: _,
+        } = self.0;
+    }
+}
+
 fn parse_datetime_opt(date_time: Option<&str>) -> PyResult<Option<DateTimeWrapper>> {
     date_time.map(parse_datetime).transpose()
 }
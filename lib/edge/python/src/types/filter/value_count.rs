@@ -12,14 +12,21 @@ pub struct PyValuesCount(pub ValuesCount);
 #[pymethods]
 impl PyValuesCount {
     #[new]
-    #[pyo3(signature = (lt=None, gt=None, lte=None, gte=None))]
+    #[pyo3(signature = (lt=None, gt=None, lte=None, gte=None, distinct=None))]
     pub fn new(
         lt: Option<usize>,
         gt: Option<usize>,
         lte: Option<usize>,
         gte: Option<usize>,
+        distinct: Option<bool>,
     ) -> Self {
-        Self(ValuesCount { lt, gt, lte, gte })
+        Self(ValuesCount {
+            lt,
+            gt,
+            lte,
+            gte,
+            distinct,
+        })
     }
 
     #[getter]
@@ -42,6 +49,11 @@ impl PyValuesCount {
         self.0.gte
     }
 
+    #[getter]
+    pub fn distinct(&self) -> Option<bool> {
+        self.0.distinct
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -55,6 +67,7 @@ impl PyValuesCount {
             gt: _,
             lte: _,
             gte: _,
+            distinct: _,
         } = self.0;
     }
 }
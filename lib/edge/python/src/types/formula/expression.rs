@@ -37,6 +37,16 @@ impl FromPyObject<'_, '_> for PyExpression {
                 ExpressionInternal::DatetimeKey(path.into())
             }
 
+            PyExpressionInterface::TextScore {
+                key,
+                text,
+                aggregation,
+            } => ExpressionInternal::TextScore {
+                key: key.into(),
+                text,
+                aggregation: aggregation.map(Into::into).unwrap_or_default(),
+            },
+
             PyExpressionInterface::Mult { exprs } => {
                 ExpressionInternal::Mult(PyExpression::peel_vec(exprs))
             }
@@ -115,6 +125,16 @@ impl<'py> IntoPyObject<'py> for PyExpression {
                 path: PyJsonPath(path),
             },
 
+            ExpressionInternal::TextScore {
+                key,
+                text,
+                aggregation,
+            } => PyExpressionInterface::TextScore {
+                key: PyJsonPath(key),
+                text,
+                aggregation: Some(aggregation.into()),
+            },
+
             ExpressionInternal::Mult(exprs) => PyExpressionInterface::Mult {
                 exprs: PyExpression::wrap_vec(exprs),
             },
@@ -205,6 +225,19 @@ impl Repr for PyExpression {
                 ("DatetimeKey", &[("path", PyJsonPath::wrap_ref(path))])
             }
 
+            ExpressionInternal::TextScore {
+                key,
+                text,
+                aggregation,
+            } => (
+                "TextScore",
+                &[
+                    ("key", PyJsonPath::wrap_ref(key)),
+                    ("text", text),
+                    ("aggregation", &PyTextScoreAggregation::from(*aggregation)),
+                ],
+            ),
+
             ExpressionInternal::Mult(exprs) => {
                 ("Mult", &[("exprs", &PyExpression::wrap_slice(exprs))])
             }
@@ -34,6 +34,12 @@ pub enum PyExpressionInterface {
         path: PyJsonPath,
     },
 
+    TextScore {
+        key: PyJsonPath,
+        text: String,
+        aggregation: Option<PyTextScoreAggregation>,
+    },
+
     Mult {
         exprs: Vec<PyExpression>,
     },
@@ -102,6 +108,16 @@ impl Repr for PyExpressionInterface {
             }
 
             PyExpressionInterface::DatetimeKey { path } => ("DatetimeKey", &[("path", path)]),
+
+            PyExpressionInterface::TextScore {
+                key,
+                text,
+                aggregation,
+            } => (
+                "TextScore",
+                &[("key", key), ("text", text), ("aggregation", aggregation)],
+            ),
+
             PyExpressionInterface::Mult { exprs } => ("Mult", &[("exprs", exprs)]),
             PyExpressionInterface::Sum { exprs } => ("Sum", &[("exprs", exprs)]),
             PyExpressionInterface::Neg { expr } => ("Neg", &[("expr", expr)]),
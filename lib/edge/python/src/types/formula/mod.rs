@@ -11,6 +11,7 @@ use pyo3::prelude::*;
 use segment::index::query_optimization::rescore_formula::parsed_formula::{
     DecayKind, ParsedFormula,
 };
+use segment::types::TextScoreAggregation;
 use shard::query::formula::{ExpressionInternal, FormulaInternal};
 
 pub use self::expression::*;
@@ -97,3 +98,48 @@ impl From<PyDecayKind> for DecayKind {
         }
     }
 }
+
+#[pyclass(name = "TextScoreAggregation", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyTextScoreAggregation {
+    /// Score of the best matching value
+    Max,
+    /// Sum of the scores of all values
+    Sum,
+}
+
+#[pymethods]
+impl PyTextScoreAggregation {
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl Repr for PyTextScoreAggregation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            PyTextScoreAggregation::Max => "Max",
+            PyTextScoreAggregation::Sum => "Sum",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<TextScoreAggregation> for PyTextScoreAggregation {
+    fn from(aggregation: TextScoreAggregation) -> Self {
+        match aggregation {
+            TextScoreAggregation::Max => PyTextScoreAggregation::Max,
+            TextScoreAggregation::Sum => PyTextScoreAggregation::Sum,
+        }
+    }
+}
+
+impl From<PyTextScoreAggregation> for TextScoreAggregation {
+    fn from(aggregation: PyTextScoreAggregation) -> Self {
+        match aggregation {
+            PyTextScoreAggregation::Max => TextScoreAggregation::Max,
+            PyTextScoreAggregation::Sum => TextScoreAggregation::Sum,
+        }
+    }
+}
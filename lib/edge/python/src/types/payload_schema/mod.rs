@@ -4,6 +4,7 @@ use std::fmt;
 
 use bytemuck::TransparentWrapper;
 use derive_more::Into;
+use ordered_float::OrderedFloat;
 use pyo3::IntoPyObjectExt as _;
 use pyo3::prelude::*;
 use segment::data_types::index::*;
@@ -208,13 +209,25 @@ pub struct PyKeywordIndexParams(KeywordIndexParams);
 #[pymethods]
 impl PyKeywordIndexParams {
     #[new]
-    #[pyo3(signature = (is_tenant = None, on_disk = None, enable_hnsw = None))]
-    pub fn new(is_tenant: Option<bool>, on_disk: Option<bool>, enable_hnsw: Option<bool>) -> Self {
+    #[pyo3(signature = (is_tenant = None, on_disk = None, enable_hnsw = None, deduplicate_values = None, max_value_bytes = None, oversized_value_policy = None, case_insensitive = None))]
+    pub fn new(
+        is_tenant: Option<bool>,
+        on_disk: Option<bool>,
+        enable_hnsw: Option<bool>,
+        deduplicate_values: Option<bool>,
+        max_value_bytes: Option<usize>,
+        oversized_value_policy: Option<PyOversizedValuePolicy>,
+        case_insensitive: Option<bool>,
+    ) -> Self {
         Self(KeywordIndexParams {
             r#type: Default::default(),
             is_tenant,
             on_disk,
             enable_hnsw,
+            deduplicate_values,
+            max_value_bytes,
+            oversized_value_policy: oversized_value_policy.map(OversizedValuePolicy::from),
+            case_insensitive,
         })
     }
 
@@ -232,6 +245,28 @@ impl PyKeywordIndexParams {
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn deduplicate_values(&self) -> Option<bool> {
+        self.0.deduplicate_values
+    }
+
+    #[getter]
+    pub fn max_value_bytes(&self) -> Option<usize> {
+        self.0.max_value_bytes
+    }
+
+    #[getter]
+    pub fn oversized_value_policy(&self) -> Option<PyOversizedValuePolicy> {
+        self.0
+            .oversized_value_policy
+            .map(PyOversizedValuePolicy::from)
+    }
+
+    #[getter]
+    pub fn case_insensitive(&self) -> Option<bool> {
+        self.0.case_insensitive
+    }
 }
 
 impl PyKeywordIndexParams {
@@ -242,10 +277,54 @@ impl PyKeywordIndexParams {
             is_tenant: _,
             on_disk: _,
             enable_hnsw: _,
+            deduplicate_values: _,
+            max_value_bytes: _,
+            oversized_value_policy: _,
+            case_insensitive: _,
         } = self.0;
     }
 }
 
+#[pyclass(name = "OversizedValuePolicy", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyOversizedValuePolicy {
+    Skip,
+    Truncate,
+    Error,
+}
+
+impl Repr for PyOversizedValuePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Skip => "Skip",
+            Self::Truncate => "Truncate",
+            Self::Error => "Error",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<OversizedValuePolicy> for PyOversizedValuePolicy {
+    fn from(policy: OversizedValuePolicy) -> Self {
+        match policy {
+            OversizedValuePolicy::Skip => PyOversizedValuePolicy::Skip,
+            OversizedValuePolicy::Truncate => PyOversizedValuePolicy::Truncate,
+            OversizedValuePolicy::Error => PyOversizedValuePolicy::Error,
+        }
+    }
+}
+
+impl From<PyOversizedValuePolicy> for OversizedValuePolicy {
+    fn from(policy: PyOversizedValuePolicy) -> Self {
+        match policy {
+            PyOversizedValuePolicy::Skip => OversizedValuePolicy::Skip,
+            PyOversizedValuePolicy::Truncate => OversizedValuePolicy::Truncate,
+            PyOversizedValuePolicy::Error => OversizedValuePolicy::Error,
+        }
+    }
+}
+
 #[pyclass(name = "IntegerIndexParams", from_py_object)]
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
@@ -322,17 +401,19 @@ pub struct PyFloatIndexParams(FloatIndexParams);
 #[pymethods]
 impl PyFloatIndexParams {
     #[new]
-    #[pyo3(signature = (is_principal = None, on_disk = None, enable_hnsw = None))]
+    #[pyo3(signature = (is_principal = None, on_disk = None, enable_hnsw = None, precision = None))]
     pub fn new(
         is_principal: Option<bool>,
         on_disk: Option<bool>,
         enable_hnsw: Option<bool>,
+        precision: Option<f64>,
     ) -> Self {
         Self(FloatIndexParams {
             r#type: Default::default(),
             is_principal,
             on_disk,
             enable_hnsw,
+            precision: precision.map(OrderedFloat),
         })
     }
 
@@ -350,6 +431,11 @@ impl PyFloatIndexParams {
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn precision(&self) -> Option<f64> {
+        self.0.precision.map(|OrderedFloat(precision)| precision)
+    }
 }
 
 impl PyFloatIndexParams {
@@ -360,6 +446,7 @@ impl PyFloatIndexParams {
             is_principal: _,
             on_disk: _,
             enable_hnsw: _,
+            precision: _,
         } = self.0;
     }
 }
@@ -373,12 +460,17 @@ pub struct PyGeoIndexParams(GeoIndexParams);
 #[pymethods]
 impl PyGeoIndexParams {
     #[new]
-    #[pyo3(signature = (on_disk = None, enable_hnsw = None))]
-    pub fn new(on_disk: Option<bool>, enable_hnsw: Option<bool>) -> Self {
+    #[pyo3(signature = (on_disk = None, enable_hnsw = None, h3_resolution = None))]
+    pub fn new(
+        on_disk: Option<bool>,
+        enable_hnsw: Option<bool>,
+        h3_resolution: Option<u8>,
+    ) -> Self {
         Self(GeoIndexParams {
             r#type: Default::default(),
             on_disk,
             enable_hnsw,
+            h3_resolution,
         })
     }
 
@@ -391,6 +483,11 @@ impl PyGeoIndexParams {
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn h3_resolution(&self) -> Option<u8> {
+        self.0.h3_resolution
+    }
 }
 
 impl PyGeoIndexParams {
@@ -400,6 +497,7 @@ impl PyGeoIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             on_disk: _,
             enable_hnsw: _,
+            h3_resolution: _,
         } = self.0;
     }
 }
@@ -413,12 +511,17 @@ pub struct PyBoolIndexParams(BoolIndexParams);
 #[pymethods]
 impl PyBoolIndexParams {
     #[new]
-    #[pyo3(signature = (on_disk = None, enable_hnsw = None))]
-    pub fn new(on_disk: Option<bool>, enable_hnsw: Option<bool>) -> Self {
+    #[pyo3(signature = (on_disk = None, enable_hnsw = None, max_positions = None))]
+    pub fn new(
+        on_disk: Option<bool>,
+        enable_hnsw: Option<bool>,
+        max_positions: Option<usize>,
+    ) -> Self {
         Self(BoolIndexParams {
             r#type: Default::default(),
             on_disk,
             enable_hnsw,
+            max_positions,
         })
     }
 
@@ -431,6 +534,11 @@ impl PyBoolIndexParams {
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn max_positions(&self) -> Option<usize> {
+        self.0.max_positions
+    }
 }
 
 impl PyBoolIndexParams {
@@ -440,6 +548,7 @@ impl PyBoolIndexParams {
             r#type: _, // not relevant for Qdrant Edge
             on_disk: _,
             enable_hnsw: _,
+            max_positions: _,
         } = self.0;
     }
 }
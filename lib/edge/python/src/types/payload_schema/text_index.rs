@@ -4,9 +4,11 @@ use std::fmt;
 use bytemuck::TransparentWrapper;
 use derive_more::Into;
 use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use segment::data_types::index::*;
 
+use super::PyOversizedValuePolicy;
 use crate::repr::*;
 
 #[pyclass(name = "TextIndexParams", from_py_object)]
@@ -19,7 +21,7 @@ pub struct PyTextIndexParams(pub TextIndexParams);
 impl PyTextIndexParams {
     #[expect(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (tokenizer = None, min_token_len = None, max_token_len = None, lowercase = None, ascii_folding = None, phrase_matching = None, stopwords = None, on_disk = None, stemmer = None, enable_hnsw = None))]
+    #[pyo3(signature = (tokenizer = None, min_token_len = None, max_token_len = None, lowercase = None, ascii_folding = None, phrase_matching = None, stopwords = None, on_disk = None, stemmer = None, enable_hnsw = None, compression = None, index_unstemmed = None, max_value_bytes = None, oversized_value_policy = None, auto_stopwords_threshold = None, strip_html = None, decode_html_entities = None, strip_html_queries = None, forward_index = None))]
     pub fn new(
         tokenizer: Option<PyTokenizerType>,
         min_token_len: Option<usize>,
@@ -31,6 +33,15 @@ impl PyTextIndexParams {
         on_disk: Option<bool>,
         stemmer: Option<PyStemmingAlgorithm>,
         enable_hnsw: Option<bool>,
+        compression: Option<PyTextIndexCompression>,
+        index_unstemmed: Option<bool>,
+        max_value_bytes: Option<usize>,
+        oversized_value_policy: Option<PyOversizedValuePolicy>,
+        auto_stopwords_threshold: Option<u32>,
+        strip_html: Option<bool>,
+        decode_html_entities: Option<bool>,
+        strip_html_queries: Option<bool>,
+        forward_index: Option<bool>,
     ) -> Self {
         Self(TextIndexParams {
             r#type: Default::default(),
@@ -44,6 +55,15 @@ impl PyTextIndexParams {
             on_disk,
             stemmer: stemmer.map(StemmingAlgorithm::from),
             enable_hnsw,
+            compression: compression.map(TextIndexCompression::from),
+            index_unstemmed,
+            max_value_bytes,
+            oversized_value_policy: oversized_value_policy.map(OversizedValuePolicy::from),
+            auto_stopwords_threshold,
+            strip_html,
+            decode_html_entities,
+            strip_html_queries,
+            forward_index,
         })
     }
 
@@ -96,6 +116,53 @@ impl PyTextIndexParams {
     pub fn enable_hnsw(&self) -> Option<bool> {
         self.0.enable_hnsw
     }
+
+    #[getter]
+    pub fn compression(&self) -> Option<PyTextIndexCompression> {
+        self.0.compression.map(PyTextIndexCompression::from)
+    }
+
+    #[getter]
+    pub fn index_unstemmed(&self) -> Option<bool> {
+        self.0.index_unstemmed
+    }
+
+    #[getter]
+    pub fn max_value_bytes(&self) -> Option<usize> {
+        self.0.max_value_bytes
+    }
+
+    #[getter]
+    pub fn oversized_value_policy(&self) -> Option<PyOversizedValuePolicy> {
+        self.0
+            .oversized_value_policy
+            .map(PyOversizedValuePolicy::from)
+    }
+
+    #[getter]
+    pub fn auto_stopwords_threshold(&self) -> Option<u32> {
+        self.0.auto_stopwords_threshold
+    }
+
+    #[getter]
+    pub fn strip_html(&self) -> Option<bool> {
+        self.0.strip_html
+    }
+
+    #[getter]
+    pub fn decode_html_entities(&self) -> Option<bool> {
+        self.0.decode_html_entities
+    }
+
+    #[getter]
+    pub fn strip_html_queries(&self) -> Option<bool> {
+        self.0.strip_html_queries
+    }
+
+    #[getter]
+    pub fn forward_index(&self) -> Option<bool> {
+        self.0.forward_index
+    }
 }
 
 impl PyTextIndexParams {
@@ -113,6 +180,15 @@ impl PyTextIndexParams {
             on_disk: _,
             stemmer: _,
             enable_hnsw: _,
+            compression: _,
+            index_unstemmed: _,
+            max_value_bytes: _,
+            oversized_value_policy: _,
+            auto_stopwords_threshold: _,
+            strip_html: _,
+            decode_html_entities: _,
+            strip_html_queries: _,
+            forward_index: _,
         } = self.0;
     }
 }
@@ -161,10 +237,49 @@ impl From<PyTokenizerType> for TokenizerType {
     }
 }
 
+#[pyclass(name = "TextIndexCompression", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyTextIndexCompression {
+    Lz4,
+    Zstd,
+}
+
+impl Repr for PyTextIndexCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Lz4 => "Lz4",
+            Self::Zstd => "Zstd",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<TextIndexCompression> for PyTextIndexCompression {
+    fn from(compression: TextIndexCompression) -> Self {
+        match compression {
+            TextIndexCompression::Lz4 => PyTextIndexCompression::Lz4,
+            TextIndexCompression::Zstd => PyTextIndexCompression::Zstd,
+        }
+    }
+}
+
+impl From<PyTextIndexCompression> for TextIndexCompression {
+    fn from(compression: PyTextIndexCompression) -> Self {
+        match compression {
+            PyTextIndexCompression::Lz4 => TextIndexCompression::Lz4,
+            PyTextIndexCompression::Zstd => TextIndexCompression::Zstd,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
 pub struct PyStopwords(StopwordsInterface);
 
+/// Stopwords of the detected language of the indexed texts
+const STOPWORDS_AUTO: &str = "auto";
+
 impl FromPyObject<'_, '_> for PyStopwords {
     type Error = PyErr;
 
@@ -173,18 +288,28 @@ impl FromPyObject<'_, '_> for PyStopwords {
         enum Helper {
             Language(PyLanguage),
             Set(PyStopwordsSet),
+            Auto(String),
         }
 
         fn _variants(stopwords: StopwordsInterface) {
             match stopwords {
                 StopwordsInterface::Language(_) => {}
                 StopwordsInterface::Set(_) => {}
+                StopwordsInterface::Auto(_) => {}
             }
         }
 
         let stopwords = match stopwords.extract()? {
             Helper::Language(lang) => StopwordsInterface::Language(lang.into()),
             Helper::Set(set) => StopwordsInterface::Set(set.into()),
+            Helper::Auto(auto) if auto == STOPWORDS_AUTO => {
+                StopwordsInterface::Auto(StopwordsDetection::Auto)
+            }
+            Helper::Auto(other) => {
+                return Err(PyValueError::new_err(format!(
+                    "invalid stopwords {other:?}, expected {STOPWORDS_AUTO:?}"
+                )));
+            }
         };
 
         Ok(Self(stopwords))
@@ -200,6 +325,7 @@ impl<'py> IntoPyObject<'py> for PyStopwords {
         match self.0 {
             StopwordsInterface::Language(lang) => PyLanguage::from(lang).into_bound_py_any(py),
             StopwordsInterface::Set(set) => PyStopwordsSet(set).into_bound_py_any(py),
+            StopwordsInterface::Auto(_) => STOPWORDS_AUTO.into_bound_py_any(py),
         }
     }
 }
@@ -219,6 +345,7 @@ impl Repr for PyStopwords {
         match &self.0 {
             StopwordsInterface::Language(lang) => PyLanguage::from(*lang).fmt(f),
             StopwordsInterface::Set(set) => PyStopwordsSet::wrap_ref(set).fmt(f),
+            StopwordsInterface::Auto(_) => STOPWORDS_AUTO.fmt(f),
         }
     }
 }
@@ -380,11 +507,16 @@ pub struct PyStopwordsSet(StopwordsSet);
 #[pymethods]
 impl PyStopwordsSet {
     #[new]
-    #[pyo3(signature = (languages = None, custom = None))]
-    pub fn new(languages: Option<BTreeSet<PyLanguage>>, custom: Option<BTreeSet<String>>) -> Self {
+    #[pyo3(signature = (languages = None, custom = None, exclude = None))]
+    pub fn new(
+        languages: Option<BTreeSet<PyLanguage>>,
+        custom: Option<BTreeSet<String>>,
+        exclude: Option<BTreeSet<String>>,
+    ) -> Self {
         Self(StopwordsSet {
             languages: languages.map(|langs| langs.into_iter().map(Language::from).collect()),
             custom,
+            exclude,
         })
     }
 
@@ -400,6 +532,11 @@ impl PyStopwordsSet {
     pub fn custom(&self) -> Option<&BTreeSet<String>> {
         self.0.custom.as_ref()
     }
+
+    #[getter]
+    pub fn exclude(&self) -> Option<&BTreeSet<String>> {
+        self.0.exclude.as_ref()
+    }
 }
 
 impl PyStopwordsSet {
@@ -408,6 +545,7 @@ impl PyStopwordsSet {
         let StopwordsSet {
             languages: _,
             custom: _,
+            exclude: _,
         } = self.0;
     }
 }
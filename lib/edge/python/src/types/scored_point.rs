@@ -61,6 +61,8 @@ impl PyScoredPoint {
             payload: _,
             shard_key: _, // not relevant for Qdrant Edge
             order_value: _,
+            provenance: _,   // not relevant for Qdrant Edge
+            score_stages: _, // not relevant for Qdrant Edge
         } = self.0;
     }
 }
@@ -34,6 +34,7 @@ impl PyUpdateOperation {
                     points_op: points,
                     condition: Filter::from(condition),
                     update_mode: mode,
+                    expected_version: None,
                 },
             ),
             (None, Some(mode)) => point_ops::PointOperations::UpsertPointsConditional(
@@ -41,6 +42,7 @@ impl PyUpdateOperation {
                     points_op: points,
                     condition: Filter::default(),
                     update_mode: Some(mode),
+                    expected_version: None,
                 },
             ),
             // Default case: regular upsert
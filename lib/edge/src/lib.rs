@@ -29,6 +29,7 @@ use parking_lot::Mutex;
 pub use reexports::*;
 use segment::entry::ReadSegmentEntry as _;
 use segment::segment_constructor::{load_segment, normalize_segment_dir};
+use shard::auto_stopwords::AutoStopwords;
 use shard::files::{PAYLOAD_INDEX_CONFIG_FILE, SEGMENTS_PATH};
 use shard::operations::CollectionUpdateOperations;
 use shard::segment_holder::SegmentHolder;
@@ -44,6 +45,7 @@ pub struct EdgeShard {
     config: SaveOnDisk<EdgeConfig>,
     wal: Mutex<SerdeWal<CollectionUpdateOperations>>,
     segments: LockedSegmentHolder,
+    auto_stopwords: SaveOnDisk<AutoStopwords>,
 }
 
 const WAL_PATH: &str = "wal";
@@ -65,6 +67,9 @@ impl EdgeShard {
         let mut segments = SegmentHolder::default();
         ensure_appendable_segment(&mut segments, path, &segments_path, &config)?;
 
+        let auto_stopwords = load_auto_stopwords(path)?;
+        auto_stopwords.read().assign(&mut segments);
+
         let config_path = path.join(EDGE_CONFIG_FILE);
         let config = SaveOnDisk::new(&config_path, config)
             .map_err(|e| OperationError::service_error(e.to_string()))?;
@@ -74,6 +79,7 @@ impl EdgeShard {
             config,
             wal: parking_lot::Mutex::new(wal),
             segments: LockedSegmentHolder::new(segments),
+            auto_stopwords,
         })
     }
 
@@ -106,6 +112,9 @@ impl EdgeShard {
             OperationError::service_error("edge config is not provided and no segments were loaded")
         })?;
 
+        let auto_stopwords = load_auto_stopwords(path)?;
+        auto_stopwords.read().assign(&mut segments);
+
         let config_path = path.join(EDGE_CONFIG_FILE);
         let config = SaveOnDisk::new(&config_path, config)
             .map_err(|e| OperationError::service_error(e.to_string()))?;
@@ -115,6 +124,7 @@ impl EdgeShard {
             config,
             wal: parking_lot::Mutex::new(wal),
             segments: LockedSegmentHolder::new(segments),
+            auto_stopwords,
         })
     }
 
@@ -321,6 +331,16 @@ fn load_segments(
     Ok(segments)
 }
 
+fn load_auto_stopwords(path: &Path) -> OperationResult<SaveOnDisk<AutoStopwords>> {
+    let auto_stopwords_path = shard::files::auto_stopwords_path(path);
+    SaveOnDisk::load_or_init_default(&auto_stopwords_path).map_err(|err| {
+        OperationError::service_error(format!(
+            "failed to load detected stopwords file {}: {err}",
+            auto_stopwords_path.display(),
+        ))
+    })
+}
+
 fn ensure_appendable_segment(
     segments: &mut SegmentHolder,
     path: &Path,
@@ -1,10 +1,13 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
 use common::budget::ResourceBudget;
 use common::progress_tracker::new_progress_tracker;
 use segment::common::operation_error::{OperationError, OperationResult};
+use segment::entry::ReadSegmentEntry as _;
 use segment::types::HnswGlobalConfig;
+use shard::auto_stopwords::refresh_auto_stopwords;
 use shard::optimizers::config::{
     DEFAULT_DELETED_THRESHOLD, DEFAULT_VACUUM_MIN_VECTOR_NUMBER, TEMP_SEGMENTS_PATH,
 };
@@ -35,7 +38,7 @@ impl EdgeShard {
             };
 
             if planned.is_empty() {
-                return Ok(optimized_any);
+                break;
             }
 
             let mut optimized_in_iteration = false;
@@ -72,9 +75,24 @@ impl EdgeShard {
 
             // Avoid repeating the same plan forever if no optimizer made effective progress.
             if !optimized_in_iteration {
-                return Ok(optimized_any);
+                break;
             }
         }
+
+        self.refresh_auto_stopwords()?;
+
+        Ok(optimized_any)
+    }
+
+    /// Detect the stopwords of the full-text indexes, if outdated.
+    ///
+    /// Edge has no payload index schema, the indexes are taken from the segments.
+    fn refresh_auto_stopwords(&self) -> OperationResult<()> {
+        let mut schema = HashMap::new();
+        for (_, segment) in self.segments.read().iter() {
+            schema.extend(segment.get().read().get_indexed_fields());
+        }
+        refresh_auto_stopwords(&self.auto_stopwords, &self.segments, &schema)
     }
 
     fn build_blocking_optimizers(&self) -> Vec<Arc<Optimizer>> {
@@ -33,6 +33,7 @@ impl EdgeShard {
             root_plans,
             searches,
             scrolls,
+            soft_timeout: _, // Qdrant Edge always searches all segments
         } = planned_query;
 
         let mut search_results = Vec::new();
@@ -80,6 +81,7 @@ impl EdgeShard {
             merge_plan,
             with_payload,
             with_vector,
+            with_explanation: _, // Scores are not explained in Qdrant Edge
         } = root_plan;
 
         let results = self.recurse_prefetch(
@@ -252,23 +254,26 @@ impl EdgeShard {
                 hw_counter_acc,
             ),
 
-            ScoringQuery::Sample(sample) => match sample {
-                SampleInternal::Random => {
-                    // create single scroll request for rescoring query
-                    let filter = filter_by_point_ids(&sources);
-
-                    // Note: score_threshold is not used in this case, as all results will have same score and order_value
-                    let scroll_request = QueryScrollRequestInternal {
-                        limit,
-                        filter: Some(filter),
-                        with_payload: false.into(),
-                        with_vector: false.into(),
-                        scroll_order: ScrollOrder::Random,
-                    };
-
-                    self.query_scroll(&scroll_request)
-                }
-            },
+            ScoringQuery::Sample(sample) => {
+                // create single scroll request for rescoring query
+                let filter = filter_by_point_ids(&sources);
+
+                // Note: score_threshold is not used in this case, as random samples have same
+                // scores, and sampling keys of weighted samples are meaningless as a threshold
+                let scroll_order = match sample {
+                    SampleInternal::Random => ScrollOrder::Random,
+                    SampleInternal::Weighted(weighted) => ScrollOrder::WeightedRandom(weighted),
+                };
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order,
+                };
+
+                self.query_scroll(&scroll_request)
+            }
 
             ScoringQuery::Mmr(mmr) => self.mmr_rescore(sources, mmr, limit, hw_counter_acc),
         }
@@ -3,8 +3,8 @@ mod reexports_from_qdrant_crates {
     pub use segment::data_types::facets::{FacetHit, FacetResponse, FacetValue, FacetValueHit};
     pub use segment::data_types::index::{
         BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
-        KeywordIndexParams, Language, SnowballLanguage, SnowballParams, StopwordsSet,
-        TextIndexParams, TokenizerType, UuidIndexParams,
+        KeywordIndexParams, Language, OversizedValuePolicy, SnowballLanguage, SnowballParams,
+        StopwordsSet, TextIndexCompression, TextIndexParams, TokenizerType, UuidIndexParams,
     };
     pub use segment::data_types::modifier::Modifier;
     pub use segment::data_types::order_by::{
@@ -13,20 +13,25 @@ mod reexports_from_qdrant_crates {
     pub use segment::data_types::vectors::{
         DEFAULT_VECTOR_NAME, NamedQuery, TypedMultiDenseVector,
     };
+    pub use segment::data_types::weighted_sample::WeightedSample;
     pub use segment::index::query_optimization::rescore_formula::parsed_formula::DecayKind;
     pub use segment::json_path::JsonPath;
     pub use segment::types::{
-        AcornSearchParams, AnyVariants, BinaryQuantizationConfig, BinaryQuantizationEncoding,
-        BinaryQuantizationQueryEncoding, CompressionRatio, Condition, DateTimeWrapper, Distance,
-        ExtendedPointId as PointId, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon,
-        GeoRadius, HasIdCondition, HasVectorCondition, HnswConfig as HnswIndexConfig,
-        IsEmptyCondition, IsNullCondition, Match, MatchAny, MatchExcept, MatchPhrase, MatchText,
-        MatchTextAny, MatchValue, MinShould, MultiVectorComparator, MultiVectorConfig, Nested,
-        NestedCondition, Payload, PayloadFieldSchema, PayloadIndexInfo, PayloadSchemaParams,
-        PayloadSchemaType, PayloadSelector, PayloadSelectorExclude, PayloadSelectorInclude,
-        ProductQuantizationConfig, QuantizationConfig, QuantizationSearchParams, Range,
+        AcornSearchParams, AnyVariants, ArithmeticDiv, ArithmeticDivParams, ArithmeticExpression,
+        ArithmeticMult, ArithmeticNeg, ArithmeticSum, BinaryQuantizationConfig,
+        BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, CompressionRatio, Condition,
+        DateTimeWrapper, Distance, ExpressionCondition, ExtendedPointId as PointId, FieldCondition,
+        Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, HasIdCondition,
+        HasVectorCondition, HnswConfig as HnswIndexConfig, IsEmptyCondition, IsNullCondition,
+        Match, MatchAny, MatchExactSet, MatchExcept, MatchKeywordPrefix, MatchPhrase, MatchText,
+        MatchTextAny, MatchValue, MinShould, MissingVectors, MultiVectorComparator,
+        MultiVectorConfig, Nested, NestedCondition, NullElements, Payload, PayloadFieldSchema,
+        PayloadIndexInfo, PayloadSchemaParams, PayloadSchemaType, PayloadSelector,
+        PayloadSelectorExclude, PayloadSelectorInclude, ProductQuantizationConfig,
+        QuantizationConfig, QuantizationFallbackParams, QuantizationSearchParams, Range,
         RangeInterface, ScalarQuantizationConfig, ScalarType, ScoredPoint, SearchParams,
-        ValueVariants, ValuesCount, VectorStorageDatatype, WithPayloadInterface, WithVector,
+        UpdatedAfterCondition, ValueVariants, ValuesCount, VectorStorageDatatype,
+        WithPayloadInterface, WithVector,
     };
     pub use segment::vector_storage::query::{
         ContextPair, ContextQuery, DiscoverQuery, FeedbackItem,
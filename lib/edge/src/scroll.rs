@@ -1,14 +1,16 @@
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use common::types::DeferredBehavior;
+use common::types::{DeferredBehavior, ScoreType};
 use itertools::Itertools as _;
 use rand::RngExt;
 use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::types::*;
 use shard::query::scroll::{QueryScrollRequestInternal, ScrollOrder};
 use shard::retrieve::record_internal::RecordInternal;
@@ -110,6 +112,17 @@ impl EdgeShard {
                 filter.as_ref(),
                 HwMeasurementAcc::disposable_edge(),
             )?,
+            ScrollOrder::WeightedRandom(sample) => {
+                // Sampling keys are the scores, points are not converted from records
+                return self.scroll_weighted_randomly(
+                    *limit,
+                    with_payload,
+                    with_vector,
+                    filter.as_ref(),
+                    sample,
+                    HwMeasurementAcc::disposable_edge(),
+                );
+            }
         };
 
         let point_results = records
@@ -122,6 +135,8 @@ impl EdgeShard {
                 vector: record.vector,
                 shard_key: record.shard_key,
                 order_value: record.order_value,
+                provenance: None,
+                score_stages: Vec::new(),
             })
             .collect();
 
@@ -331,4 +346,83 @@ impl EdgeShard {
 
         Ok(random_points)
     }
+    fn scroll_weighted_randomly(
+        &self,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        sample: &WeightedSample,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        let (non_appendable, appendable) = self.segments.read().split_segments();
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        // A point can be present in multiple segments, keep the sample of its latest version only
+        let mut latest: HashMap<PointIdType, (SeqNumberType, ScoreType)> = HashMap::new();
+        for segment in non_appendable.into_iter().chain(appendable) {
+            let get_segment = segment.get();
+            let read_segment = get_segment.read();
+            let segment_sampled = read_segment.read_weighted_random_filtered(
+                limit,
+                filter,
+                sample,
+                &AtomicBool::new(false),
+                &hw_counter,
+            )?;
+            for (key, point_id) in segment_sampled {
+                let version = read_segment.point_version(point_id).unwrap_or_default();
+                match latest.entry(point_id) {
+                    Entry::Occupied(mut entry) => {
+                        if version > entry.get().0 {
+                            entry.insert((version, key));
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert((version, key));
+                    }
+                }
+            }
+        }
+
+        let mut sampled: Vec<_> = latest
+            .into_iter()
+            .map(|(point_id, (version, key))| (key, point_id, version))
+            .collect();
+        sampled.sort_unstable_by(|(key_a, ..), (key_b, ..)| key_b.total_cmp(key_a));
+        sampled.truncate(limit);
+
+        let point_ids: Vec<_> = sampled.iter().map(|(_, point_id, _)| *point_id).collect();
+
+        let mut records = retrieve_blocking(
+            self.segments.clone(),
+            &point_ids,
+            &WithPayload::from(with_payload_interface),
+            with_vector,
+            DEFAULT_EDGE_TIMEOUT,
+            &AtomicBool::new(false),
+            hw_measurement_acc,
+            DeferredBehavior::Exclude,
+        )?;
+
+        let points = sampled
+            .into_iter()
+            .filter_map(|(score, point_id, version)| {
+                let record = records.remove(&point_id)?;
+                Some(ScoredPoint {
+                    id: point_id,
+                    version,
+                    score,
+                    payload: record.payload,
+                    vector: record.vector,
+                    shard_key: record.shard_key,
+                    order_value: None,
+                    provenance: None,
+                    score_stages: Vec::new(),
+                })
+            })
+            .collect();
+
+        Ok(points)
+    }
 }
@@ -80,5 +80,6 @@ macro_rules! impl_blob_vec_zerocopy {
 }
 
 impl_blob_vec_zerocopy!(i64);
+impl_blob_vec_zerocopy!(i32);
 impl_blob_vec_zerocopy!(u128);
 impl_blob_vec_zerocopy!(f64);
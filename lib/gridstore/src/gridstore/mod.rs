@@ -2,7 +2,10 @@ mod reader;
 #[cfg(test)]
 mod tests;
 pub(crate) mod view;
+mod write_buffer;
 
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::ops::ControlFlow;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -17,10 +20,12 @@ use common::mmap::create_and_ensure_length;
 use common::universal_io::MmapFile;
 use fs_err as fs;
 use itertools::Itertools;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use reader::CONFIG_FILENAME;
 pub use reader::GridstoreReader;
 pub use view::GridstoreView;
+use write_buffer::WriteBuffer;
+pub use write_buffer::WriteStats;
 
 use crate::bitmask::MmapBitmask;
 use crate::blob::Blob;
@@ -49,6 +54,12 @@ pub struct Gridstore<V> {
     pub(super) _value_type: std::marker::PhantomData<V>,
     /// Lock to prevent concurrent flushes and used for waiting for ongoing flushes to finish.
     is_alive_flush_lock: IsAliveLock,
+    /// Maximum number of buffered values, 0 disables buffering.
+    /// The buffer is always empty when buffering is disabled, so reads don't lock it then.
+    write_coalescing_window: usize,
+    /// Updated values, not yet written into pages.
+    /// Also serializes allocation of blocks, see [`Self::set_write_coalescing_window`].
+    write_buffer: Mutex<WriteBuffer>,
 }
 
 impl<V: Blob> Gridstore<V> {
@@ -119,6 +130,8 @@ impl<V: Blob> Gridstore<V> {
             _value_type: std::marker::PhantomData,
             bitmask: Arc::new(RwLock::new(bitmask)),
             is_alive_flush_lock: IsAliveLock::new(),
+            write_coalescing_window: 0,
+            write_buffer: Mutex::new(WriteBuffer::default()),
         };
 
         let new_page_id = storage.next_page_id();
@@ -157,67 +170,11 @@ impl<V: Blob> Gridstore<V> {
             base_path,
             _value_type: std::marker::PhantomData,
             is_alive_flush_lock: IsAliveLock::new(),
+            write_coalescing_window: 0,
+            write_buffer: Mutex::new(WriteBuffer::default()),
         })
     }
 
-    /// Create a new page and return its id.
-    #[allow(clippy::needless_pass_by_ref_mut)]
-    fn create_new_page(&mut self) -> Result<u32> {
-        let new_page_id = self.next_page_id();
-        let path = page_path(&self.base_path, new_page_id);
-        create_and_ensure_length(&path, self.config.page_size_bytes)?;
-        self.pages.write().attach_page(&path)?;
-
-        self.bitmask.write().cover_new_page()?;
-
-        Ok(new_page_id)
-    }
-
-    fn find_or_create_available_blocks(
-        &mut self,
-        num_blocks: u32,
-    ) -> Result<(PageId, BlockOffset)> {
-        debug_assert!(num_blocks > 0, "num_blocks must be greater than 0");
-
-        let bitmask_guard = self.bitmask.read();
-        if let Some((page_id, block_offset)) = bitmask_guard.find_available_blocks(num_blocks)? {
-            return Ok((page_id, block_offset));
-        }
-        let trailing_free_blocks = bitmask_guard.trailing_free_blocks()?;
-
-        drop(bitmask_guard);
-
-        let missing_blocks = num_blocks.saturating_sub(trailing_free_blocks) as usize;
-
-        let num_pages =
-            (missing_blocks * self.config.block_size_bytes).div_ceil(self.config.page_size_bytes);
-        for _ in 0..num_pages {
-            self.create_new_page()?;
-        }
-
-        let available = self
-            .bitmask
-            .read()
-            .find_available_blocks(num_blocks)?
-            .expect("New page has just been created");
-
-        Ok(available)
-    }
-
-    /// Write value into a new cell, considering that it can span more than one page.
-    #[allow(clippy::needless_pass_by_ref_mut)]
-    fn write_into_pages(
-        &mut self,
-        value: &[u8],
-        start_page_id: PageId,
-        block_offset: BlockOffset,
-    ) -> Result<()> {
-        let pointer = ValuePointer::new(start_page_id, block_offset, value.len() as u32);
-        self.pages
-            .write()
-            .write_to_pages(pointer, value, &self.config)
-    }
-
     /// Put a value in the storage.
     ///
     /// Returns true if the value existed previously and was updated, false if it was newly inserted.
@@ -281,32 +238,76 @@ impl<V: Blob> Gridstore<V> {
 
         hw_counter.incr_delta(value_size);
 
-        let required_blocks = Self::blocks_for_value(value_size, self.config.block_size_bytes);
-        let (start_page_id, block_offset) =
-            self.find_or_create_available_blocks(required_blocks)?;
+        let mut write_buffer = self.write_buffer.lock();
+        let WriteBuffer { values, stats } = &mut *write_buffer;
+        stats.requested_values += 1;
+        stats.requested_bytes += value_size as u64;
 
-        self.write_into_pages(&comp_value, start_page_id, block_offset)?;
+        if self.write_coalescing_window == 0 {
+            return self.write_value(stats, point_offset, &comp_value);
+        }
 
-        self.bitmask
-            .write()
-            .mark_blocks(start_page_id, block_offset, required_blocks, true)?;
+        let is_buffered = values.insert(point_offset, comp_value).is_some();
+        let is_update = is_buffered || self.tracker.read().has_pointer(point_offset)?;
 
-        let mut tracker_guard = self.tracker.write();
-        let is_update = tracker_guard.has_pointer(point_offset)?;
-        tracker_guard.set(
-            point_offset,
-            ValuePointer::new(start_page_id, block_offset, value_size as u32),
-        );
+        if values.len() >= self.write_coalescing_window {
+            self.drain_write_buffer_locked(&mut write_buffer)?;
+        }
 
         Ok(is_update)
     }
 
+    /// Buffer up to `window` updated values in memory, before writing them into pages.
+    ///
+    /// Every write allocates new blocks, so a point updated repeatedly between flushes has its
+    /// value written repeatedly. Buffered values are written only once, when the buffer is full
+    /// or on flush. Larger windows reduce write amplification of update-heavy workloads, at the
+    /// cost of memory for buffered values and longer flushes. Reads return buffered values,
+    /// but never write them out.
+    ///
+    /// 0 disables buffering, which is the default. The window is not persisted.
+    pub fn set_write_coalescing_window(&mut self, window: usize) -> Result<()> {
+        self.write_coalescing_window = window;
+        let mut write_buffer = self.write_buffer.lock();
+        if write_buffer.values.len() >= window {
+            self.drain_write_buffer_locked(&mut write_buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Counters of written data, since the storage was opened.
+    pub fn write_stats(&self) -> WriteStats {
+        self.write_buffer.lock().stats
+    }
+
+    /// Decompress and deserialize a buffered value.
+    fn buffered_value(&self, comp_value: &[u8]) -> V {
+        self.with_view(|view| V::from_bytes(&view.decompress(Cow::Borrowed(comp_value))))
+    }
+
+    /// Buffered value of the point, without locking the buffer if buffering is disabled.
+    fn get_buffered_value(&self, point_offset: PointOffset) -> Option<V> {
+        if self.write_coalescing_window == 0 {
+            return None;
+        }
+        let write_buffer = self.write_buffer.lock();
+        let comp_value = write_buffer.values.get(&point_offset)?;
+        Some(self.buffered_value(comp_value))
+    }
+
     /// Delete a value from the storage.
     ///
     /// Returns None if the point_offset, page, or value was not found.
     /// Returns the deleted value otherwise.
     pub fn delete_value(&mut self, point_offset: PointOffset) -> Result<Option<V>> {
-        let Some(pointer) = self.tracker.write().unset(point_offset)? else {
+        let buffered = self.write_buffer.get_mut().values.remove(&point_offset);
+        let pointer = self.tracker.write().unset(point_offset)?;
+
+        if let Some(comp_value) = buffered {
+            return Ok(Some(self.buffered_value(&comp_value)));
+        }
+
+        let Some(pointer) = pointer else {
             return Ok(None);
         };
 
@@ -324,6 +325,8 @@ impl<V: Blob> Gridstore<V> {
     pub fn clear(&mut self) -> Result<()> {
         let create_options = StorageOptions::from(&self.config);
         let base_path = self.base_path.clone();
+        let window = self.write_coalescing_window;
+        let WriteBuffer { values: _, stats } = std::mem::take(self.write_buffer.get_mut());
 
         self.is_alive_flush_lock.blocking_mark_dead();
 
@@ -341,6 +344,9 @@ impl<V: Blob> Gridstore<V> {
         })?;
         *self = Self::new(base_path, create_options)?;
 
+        self.write_coalescing_window = window;
+        self.write_buffer.get_mut().stats = stats;
+
         Ok(())
     }
 
@@ -372,13 +378,16 @@ impl<V: Blob> Gridstore<V> {
         point_offset: PointOffset,
         hw_counter: &HardwareCounterCell,
     ) -> Result<Option<V>> {
+        if let Some(value) = self.get_buffered_value(point_offset) {
+            return Ok(Some(value));
+        }
         self.with_view(|view| view.get_value::<P>(point_offset, hw_counter))
     }
 
     pub fn for_each_in_batch<P, F, E>(
         &self,
         offsets: &[PointOffset],
-        callback: F,
+        mut callback: F,
         hw_counter: &HardwareCounterCell,
     ) -> std::result::Result<(), E>
     where
@@ -386,7 +395,31 @@ impl<V: Blob> Gridstore<V> {
         F: FnMut(usize, Option<V>) -> std::result::Result<(), E>,
         E: From<GridstoreError>,
     {
-        self.with_view(|view| view.for_each_in_batch::<P, F, E>(offsets, callback, hw_counter))
+        if self.write_coalescing_window == 0 {
+            return self.with_view(|view| {
+                view.for_each_in_batch::<P, F, E>(offsets, callback, hw_counter)
+            });
+        }
+
+        // Buffered values replace the ones in pages
+        let mut buffered: AHashMap<usize, V> = {
+            let write_buffer = self.write_buffer.lock();
+            offsets
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, point_offset)| {
+                    let comp_value = write_buffer.values.get(point_offset)?;
+                    Some((idx, self.buffered_value(comp_value)))
+                })
+                .collect()
+        };
+        self.with_view(|view| {
+            view.for_each_in_batch::<P, _, E>(
+                offsets,
+                |idx, value| callback(idx, buffered.remove(&idx).or(value)),
+                hw_counter,
+            )
+        })
     }
 
     #[cfg(test)]
@@ -395,7 +428,18 @@ impl<V: Blob> Gridstore<V> {
     }
 
     pub fn max_point_offset(&self) -> PointOffset {
-        self.tracker.read().pointer_count()
+        let pointer_count = self.tracker.read().pointer_count();
+        if self.write_coalescing_window == 0 {
+            return pointer_count;
+        }
+        let buffered_count = self
+            .write_buffer
+            .lock()
+            .values
+            .keys()
+            .max()
+            .map_or(0, |point_offset| point_offset + 1);
+        pointer_count.max(buffered_count)
     }
 
     pub fn iter<F, E>(
@@ -409,6 +453,36 @@ impl<V: Blob> Gridstore<V> {
     {
         const BATCH_SIZE: usize = 128;
 
+        // Buffered values, merged into the values of pages in point order
+        let mut buffered: BTreeMap<PointOffset, V> = if self.write_coalescing_window == 0 {
+            BTreeMap::new()
+        } else {
+            self.write_buffer
+                .lock()
+                .values
+                .iter()
+                .map(|(point_offset, comp_value)| (*point_offset, self.buffered_value(comp_value)))
+                .collect()
+        };
+        let mut is_stopped = false;
+
+        let mut merged_callback =
+            |point_offset: PointOffset, value: V| -> std::result::Result<bool, E> {
+                while let Some(entry) = buffered.first_entry() {
+                    if *entry.key() >= point_offset {
+                        break;
+                    }
+                    let (buffered_offset, buffered_value) = entry.remove_entry();
+                    if !callback(buffered_offset, buffered_value)? {
+                        is_stopped = true;
+                        return Ok(false);
+                    }
+                }
+                let value = buffered.remove(&point_offset).unwrap_or(value);
+                is_stopped = !callback(point_offset, value)?;
+                Ok(!is_stopped)
+            };
+
         let mut from_offset = 0;
         // Iterate in batches to allow releasing read locks, see:
         // <https://github.com/qdrant/qdrant/pull/7983>
@@ -417,13 +491,24 @@ impl<V: Blob> Gridstore<V> {
                 from_offset,
                 PointOffset::MAX,
                 BATCH_SIZE,
-                &mut callback,
+                &mut merged_callback,
                 hw_counter,
             )
         })? {
             from_offset = next_offset;
         }
 
+        if is_stopped {
+            return Ok(());
+        }
+
+        // Buffered values of points after the last one in pages
+        for (point_offset, value) in buffered {
+            if !callback(point_offset, value)? {
+                break;
+            }
+        }
+
         Ok(())
     }
 }
@@ -438,8 +523,125 @@ impl<V> Gridstore<V> {
         value_size.div_ceil(block_size).try_into().unwrap()
     }
 
+    /// Create a new page and return its id.
+    fn create_new_page(&self) -> Result<u32> {
+        let new_page_id = self.next_page_id();
+        let path = page_path(&self.base_path, new_page_id);
+        create_and_ensure_length(&path, self.config.page_size_bytes)?;
+        self.pages.write().attach_page(&path)?;
+
+        self.bitmask.write().cover_new_page()?;
+
+        Ok(new_page_id)
+    }
+
+    fn find_or_create_available_blocks(&self, num_blocks: u32) -> Result<(PageId, BlockOffset)> {
+        debug_assert!(num_blocks > 0, "num_blocks must be greater than 0");
+
+        let bitmask_guard = self.bitmask.read();
+        if let Some((page_id, block_offset)) = bitmask_guard.find_available_blocks(num_blocks)? {
+            return Ok((page_id, block_offset));
+        }
+        let trailing_free_blocks = bitmask_guard.trailing_free_blocks()?;
+
+        drop(bitmask_guard);
+
+        let missing_blocks = num_blocks.saturating_sub(trailing_free_blocks) as usize;
+
+        let num_pages =
+            (missing_blocks * self.config.block_size_bytes).div_ceil(self.config.page_size_bytes);
+        for _ in 0..num_pages {
+            self.create_new_page()?;
+        }
+
+        let available = self
+            .bitmask
+            .read()
+            .find_available_blocks(num_blocks)?
+            .expect("New page has just been created");
+
+        Ok(available)
+    }
+
+    /// Write value into a new cell, considering that it can span more than one page.
+    fn write_into_pages(
+        &self,
+        value: &[u8],
+        start_page_id: PageId,
+        block_offset: BlockOffset,
+    ) -> Result<()> {
+        let pointer = ValuePointer::new(start_page_id, block_offset, value.len() as u32);
+        self.pages
+            .write()
+            .write_to_pages(pointer, value, &self.config)
+    }
+
+    /// Write a compressed value into newly allocated blocks, and point to it.
+    ///
+    /// Returns true if the point had a value written before.
+    /// The write buffer lock must be held, it serializes allocation of blocks.
+    fn write_value(
+        &self,
+        stats: &mut WriteStats,
+        point_offset: PointOffset,
+        comp_value: &[u8],
+    ) -> Result<bool> {
+        let value_size = comp_value.len();
+
+        let required_blocks = Self::blocks_for_value(value_size, self.config.block_size_bytes);
+        let (start_page_id, block_offset) =
+            self.find_or_create_available_blocks(required_blocks)?;
+
+        self.write_into_pages(comp_value, start_page_id, block_offset)?;
+
+        self.bitmask
+            .write()
+            .mark_blocks(start_page_id, block_offset, required_blocks, true)?;
+
+        stats.written_values += 1;
+        stats.written_bytes += (required_blocks as usize * self.config.block_size_bytes) as u64;
+
+        let mut tracker_guard = self.tracker.write();
+        let is_update = tracker_guard.has_pointer(point_offset)?;
+        tracker_guard.set(
+            point_offset,
+            ValuePointer::new(start_page_id, block_offset, value_size as u32),
+        );
+
+        Ok(is_update)
+    }
+
+    /// Write all buffered values into pages.
+    fn drain_write_buffer(&self) -> Result<()> {
+        if self.write_coalescing_window == 0 {
+            return Ok(());
+        }
+        let mut write_buffer = self.write_buffer.lock();
+        self.drain_write_buffer_locked(&mut write_buffer)
+    }
+
+    fn drain_write_buffer_locked(&self, write_buffer: &mut WriteBuffer) -> Result<()> {
+        let WriteBuffer { values, stats } = write_buffer;
+
+        // Write in point order, to keep values of neighbouring points close to each other
+        let buffered = values
+            .drain()
+            .sorted_unstable_by_key(|(point_offset, _)| *point_offset);
+        for (point_offset, comp_value) in buffered {
+            self.write_value(stats, point_offset, &comp_value)?;
+        }
+
+        Ok(())
+    }
+
     /// Create flusher that durably persists all pending changes when invoked.
+    ///
+    /// Buffered values are written into pages right away, so they are persisted too.
     pub fn flusher(&self) -> Flusher {
+        if let Err(err) = self.drain_write_buffer() {
+            return Box::new(move || Err(err));
+        }
+
         let pending_updates = self.tracker.read().pending_updates.clone();
 
         let pages = Arc::downgrade(&self.pages);
@@ -538,6 +740,8 @@ impl<V> Gridstore<V> {
             base_path: _,
             _value_type,
             is_alive_flush_lock: _,
+            write_coalescing_window: _,
+            write_buffer: _,
         } = self;
         pages.read().clear_cache()?;
         bitmask.read().clear_cache()?;
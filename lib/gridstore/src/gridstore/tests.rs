@@ -616,6 +616,136 @@ fn test_storage_persistence_basic() {
     assert_eq!(stored_payload.unwrap(), payload);
 }
 
+#[test]
+fn test_write_coalescing() {
+    let dir = Builder::new().prefix("test-storage").tempdir().unwrap();
+    let path = dir.path().to_path_buf();
+
+    let hw_counter = HardwareCounterCell::new();
+    let hw_counter_ref = hw_counter.ref_payload_io_write_counter();
+
+    let payload = |value: usize| {
+        let mut payload = Payload::default();
+        payload
+            .0
+            .insert("key".to_string(), serde_json::Value::from(value));
+        payload
+    };
+
+    let iter_values = |storage: &Gridstore<Payload>| {
+        let mut values = Vec::new();
+        storage
+            .iter::<_, GridstoreError>(
+                |point_offset, payload| {
+                    values.push((point_offset, payload));
+                    Ok(true)
+                },
+                hw_counter_ref,
+            )
+            .unwrap();
+        values
+    };
+
+    {
+        let mut storage = Gridstore::new(path.clone(), Default::default()).unwrap();
+        storage.set_write_coalescing_window(4).unwrap();
+
+        // Repeated updates of the same points are buffered, only the latest value is kept
+        assert!(!storage.put_value(0, &payload(0), hw_counter_ref).unwrap());
+        assert!(storage.put_value(0, &payload(1), hw_counter_ref).unwrap());
+        assert!(!storage.put_value(1, &payload(2), hw_counter_ref).unwrap());
+        assert!(storage.put_value(1, &payload(3), hw_counter_ref).unwrap());
+        assert_eq!(storage.write_stats().written_values, 0);
+        assert_eq!(storage.max_point_offset(), 2);
+
+        // Buffered values are readable
+        let stored_payload = storage.get_value::<Random>(0, &hw_counter).unwrap();
+        assert_eq!(stored_payload, Some(payload(1)));
+        assert_eq!(
+            iter_values(&storage),
+            vec![(0, payload(1)), (1, payload(3))],
+        );
+        let mut batch_results = vec![None; 3];
+        storage
+            .for_each_in_batch::<Random, _, GridstoreError>(
+                &[1, 0, 7],
+                |idx, value| {
+                    batch_results[idx] = value;
+                    Ok(())
+                },
+                &hw_counter,
+            )
+            .unwrap();
+        assert_eq!(
+            batch_results,
+            vec![Some(payload(3)), Some(payload(1)), None]
+        );
+
+        // Reads never write buffered values out
+        assert_eq!(storage.write_stats().written_values, 0);
+
+        // Deleting a buffered value drops it from the buffer
+        let deleted = storage.delete_value(1).unwrap();
+        assert_eq!(deleted, Some(payload(3)));
+        assert!(
+            storage
+                .get_value::<Random>(1, &hw_counter)
+                .unwrap()
+                .is_none()
+        );
+
+        // Filling the window writes buffered values out
+        for point_offset in 2..5 {
+            storage
+                .put_value(
+                    point_offset,
+                    &payload(point_offset as usize),
+                    hw_counter_ref,
+                )
+                .unwrap();
+        }
+        assert_eq!(storage.write_stats().written_values, 4);
+
+        storage.put_value(0, &payload(5), hw_counter_ref).unwrap();
+
+        // Iteration merges buffered values with the ones in pages
+        assert_eq!(
+            iter_values(&storage),
+            vec![
+                (0, payload(5)),
+                (2, payload(2)),
+                (3, payload(3)),
+                (4, payload(4)),
+            ],
+        );
+        assert_eq!(storage.write_stats().written_values, 4);
+
+        // Flush writes out the remaining buffered values
+        storage.flusher()().unwrap();
+
+        let stats = storage.write_stats();
+        assert_eq!(stats.requested_values, 8);
+        assert_eq!(stats.written_values, 5);
+        assert_eq!(stats.written_bytes, 5 * DEFAULT_BLOCK_SIZE_BYTES as u64);
+    }
+
+    // reopen storage
+    let storage = Gridstore::<Payload>::open(path).unwrap();
+    let expected = [
+        Some(payload(5)),
+        None,
+        Some(payload(2)),
+        Some(payload(3)),
+        Some(payload(4)),
+    ];
+    for (point_offset, expected) in expected.into_iter().enumerate() {
+        let stored_payload = storage
+            .get_value::<Random>(point_offset as PointOffset, &hw_counter)
+            .unwrap();
+        assert_eq!(stored_payload, expected);
+    }
+}
+
 #[test]
 #[ignore = "this test is too slow for ci, and has similar coverage to the hashmap tests"]
 fn test_with_real_hm_data() {
@@ -0,0 +1,34 @@
+use ahash::AHashMap;
+
+use crate::tracker::PointOffset;
+
+/// Updated values, which are not yet written into pages.
+#[derive(Debug, Default)]
+pub(super) struct WriteBuffer {
+    /// Compressed values by point offset, only the latest update of each point is kept.
+    pub values: AHashMap<PointOffset, Vec<u8>>,
+    pub stats: WriteStats,
+}
+
+/// Counters of data written into the pages, to observe write amplification.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WriteStats {
+    /// Number of values passed to `put_value`
+    pub requested_values: u64,
+    /// Size of values passed to `put_value`, after compression
+    pub requested_bytes: u64,
+    /// Number of values written into pages
+    pub written_values: u64,
+    /// Size of blocks allocated for the written values
+    pub written_bytes: u64,
+}
+
+impl WriteStats {
+    /// Ratio of bytes written into pages to bytes requested to be written.
+    ///
+    /// Values above 1.0 come from padding values to whole blocks, values below 1.0 mean that
+    /// updates of the same points were coalesced in the write buffer.
+    pub fn write_amplification(&self) -> Option<f64> {
+        (self.requested_bytes > 0).then(|| self.written_bytes as f64 / self.requested_bytes as f64)
+    }
+}
@@ -9,7 +9,7 @@ mod tracker;
 
 pub use blob::Blob;
 use common::universal_io::MmapFile;
-pub use gridstore::{Gridstore, GridstoreReader, GridstoreView};
+pub use gridstore::{Gridstore, GridstoreReader, GridstoreView, WriteStats};
 
 use crate::error::GridstoreError;
 
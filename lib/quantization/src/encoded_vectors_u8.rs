@@ -546,6 +546,13 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
         }
     }
 
+    /// Range of values `(min, max)` encoded without clamping
+    pub fn value_range(&self) -> (f32, f32) {
+        match &self.metadata {
+            Metadata::Int8(meta) => (meta.offset, meta.offset + 127.0 * meta.alpha),
+        }
+    }
+
     pub fn get_actual_dim(vector_parameters: &VectorParameters) -> usize {
         vector_parameters.dim + (ALIGNMENT - vector_parameters.dim % ALIGNMENT) % ALIGNMENT
     }
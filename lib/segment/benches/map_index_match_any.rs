@@ -0,0 +1,92 @@
+#[cfg(not(target_os = "windows"))]
+mod prof;
+
+use std::path::Path;
+
+use common::bitvec::BitVec;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use segment::index::field_index::map_index::MapIndex;
+use segment::index::field_index::{FieldIndexBuilderTrait, PayloadFieldIndex};
+use segment::types::{AnyVariants, FieldCondition, IntPayloadType, Match};
+use serde_json::Value;
+use tempfile::Builder;
+
+const NUM_POINTS: usize = 100_000;
+const MATCH_ANY_SIZE: usize = 10_000;
+
+fn build_index(path: &Path, unique_values: usize) -> MapIndex<IntPayloadType> {
+    let hw_counter = HardwareCounterCell::new();
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let deleted_points = BitVec::repeat(false, NUM_POINTS);
+    let mut builder = MapIndex::builder_mmap(path, false, false, &deleted_points);
+    builder.init().unwrap();
+    for id in 0..NUM_POINTS as PointOffsetType {
+        let value = Value::from(rng.random_range(0..unique_values as IntPayloadType));
+        builder.add_point(id, &[&value], &hw_counter).unwrap();
+    }
+    builder.finalize().unwrap()
+}
+
+/// `match any` with a large set, against indexes with fewer and more unique values than the set
+fn map_index_match_any(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map-index-match-any");
+
+    let hw_counter = HardwareCounterCell::new();
+
+    // Every other value of the index matches
+    let integers = (0..MATCH_ANY_SIZE as IntPayloadType)
+        .map(|i| i * 2)
+        .collect();
+    let condition = FieldCondition::new_match(
+        "value".parse().unwrap(),
+        Match::new_any(AnyVariants::Integers(integers)),
+    );
+
+    for unique_values in [1_000, 100_000] {
+        let dir = Builder::new().prefix("map_index").tempdir().unwrap();
+        let index = build_index(dir.path(), unique_values);
+
+        group.bench_function(format!("filter-{unique_values}-values"), |b| {
+            b.iter(|| {
+                index
+                    .filter(&condition, &hw_counter)
+                    .unwrap()
+                    .unwrap()
+                    .count()
+            })
+        });
+
+        group.bench_function(format!("estimate-{unique_values}-values"), |b| {
+            b.iter(|| {
+                index
+                    .estimate_cardinality(&condition, &hw_counter)
+                    .unwrap()
+                    .unwrap()
+                    .exp
+            })
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(not(target_os = "windows"))]
+criterion_group! {
+    name = benches;
+    config = Criterion::default().with_profiler(prof::FlamegraphProfiler::new(100));
+    targets = map_index_match_any
+}
+
+#[cfg(target_os = "windows")]
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = map_index_match_any
+}
+
+criterion_main!(benches);
@@ -57,6 +57,7 @@ use segment::spaces::metric_uint::sse2::euclid::sse_euclid_similarity_bytes;
 #[cfg(target_arch = "x86_64")]
 use segment::spaces::metric_uint::sse2::manhattan::sse_manhattan_similarity_bytes;
 use segment::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use segment::vector_storage::dense::vector_norms::VectorNorms;
 
 const DIM: usize = 1024;
 const COUNT: usize = 100_000;
@@ -165,6 +166,28 @@ fn byte_metrics_bench(c: &mut Criterion) {
         });
     });
 
+    let norms_1: Vec<f32> = random_vectors_1
+        .iter()
+        .map(|v| VectorNorms::norm(v))
+        .collect();
+    let norms_2: Vec<f32> = random_vectors_2
+        .iter()
+        .map(|v| VectorNorms::norm(v))
+        .collect();
+
+    group.bench_function("byte-cosine-cached-norms", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % COUNT;
+            <CosineMetric as Metric<VectorElementTypeByte>>::similarity_with_norms(
+                &random_vectors_1[i],
+                &random_vectors_2[i],
+                norms_1[i],
+                norms_2[i],
+            )
+        });
+    });
+
     group.bench_function("byte-euclid", |b| {
         let mut i = 0;
         b.iter(|| {
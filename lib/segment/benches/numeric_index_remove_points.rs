@@ -0,0 +1,101 @@
+#[cfg(not(target_os = "windows"))]
+mod prof;
+
+use std::path::Path;
+
+use common::bitvec::BitVec;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use rand::prelude::StdRng;
+use rand::{RngExt, SeedableRng};
+use segment::index::field_index::numeric_index::NumericIndex;
+use segment::index::field_index::{FieldIndex, FieldIndexBuilderTrait};
+use segment::types::FloatPayloadType;
+use serde_json::Value;
+use tempfile::Builder;
+
+const NUM_POINTS: usize = 1_000_000;
+const NUM_DELETED: usize = 100_000;
+
+fn build_index(path: &Path, rng: &mut StdRng) {
+    let hw_counter = HardwareCounterCell::new();
+    let deleted_points = BitVec::repeat(false, NUM_POINTS);
+
+    let mut builder = NumericIndex::<FloatPayloadType, FloatPayloadType>::builder_mmap(
+        path,
+        false,
+        &deleted_points,
+    );
+    builder.init().unwrap();
+    for id in 0..NUM_POINTS as PointOffsetType {
+        let value = Value::from(rng.random_range(0.0..1000.0));
+        builder.add_point(id, &[&value], &hw_counter).unwrap();
+    }
+    builder.finalize().unwrap();
+}
+
+/// Deletions are not persisted, so every iteration removes the points from a freshly loaded index
+fn open_index(path: &Path) -> FieldIndex {
+    let deleted_points = BitVec::repeat(false, NUM_POINTS);
+    let index = NumericIndex::new_mmap(path, false, &deleted_points)
+        .unwrap()
+        .unwrap();
+    FieldIndex::FloatIndex(index)
+}
+
+fn numeric_index_remove_points(c: &mut Criterion) {
+    let mut group = c.benchmark_group("numeric-index-remove-points");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_DELETED as u64));
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("numeric_index").tempdir().unwrap();
+    build_index(dir.path(), &mut rng);
+
+    let ids: Vec<PointOffsetType> = (0..NUM_DELETED)
+        .map(|_| rng.random_range(0..NUM_POINTS as PointOffsetType))
+        .collect();
+
+    group.bench_function("remove-point", |b| {
+        b.iter_batched(
+            || open_index(dir.path()),
+            |mut index| {
+                for &id in &ids {
+                    index.remove_point(id).unwrap();
+                }
+                index
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("remove-points", |b| {
+        b.iter_batched(
+            || open_index(dir.path()),
+            |mut index| {
+                index.remove_points(&ids).unwrap();
+                index
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+#[cfg(not(target_os = "windows"))]
+criterion_group! {
+    name = benches;
+    config = Criterion::default().with_profiler(prof::FlamegraphProfiler::new(100));
+    targets = numeric_index_remove_points
+}
+
+#[cfg(target_os = "windows")]
+criterion_group! {
+    name = benches;
+    config = Criterion::default();
+    targets = numeric_index_remove_points
+}
+
+criterion_main!(benches);
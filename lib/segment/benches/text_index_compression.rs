@@ -0,0 +1,105 @@
+#[cfg(not(target_os = "windows"))]
+mod prof;
+
+use std::path::Path;
+
+use common::bitvec::BitVec;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use criterion::{Criterion, criterion_group, criterion_main};
+use fs_err as fs;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use segment::data_types::index::{TextIndexCompression, TextIndexParams};
+use segment::fixtures::payload_fixtures::random_keyword;
+use segment::index::field_index::full_text_index::text_index::FullTextIndex;
+use segment::index::field_index::{FieldIndexBuilderTrait, PayloadFieldIndex};
+use segment::types::{FieldCondition, Match};
+use serde_json::Value;
+use tempfile::Builder;
+
+const NUM_POINTS: usize = 100_000;
+const KEYWORDS_PER_DOCUMENT: usize = 10;
+const NUM_QUERIES: usize = 1_000;
+
+fn random_document(rng: &mut StdRng) -> Value {
+    let document = (0..KEYWORDS_PER_DOCUMENT)
+        .map(|_| random_keyword(rng))
+        .collect::<Vec<_>>()
+        .join(" ");
+    Value::String(document)
+}
+
+fn build_index(path: &Path, compression: Option<TextIndexCompression>) -> FullTextIndex {
+    let config = TextIndexParams {
+        on_disk: Some(true),
+        compression,
+        ..Default::default()
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let mut builder = FullTextIndex::builder_mmap(path.to_path_buf(), config, true, &BitVec::new());
+    builder.init().unwrap();
+    for id in 0..NUM_POINTS as PointOffsetType {
+        let document = random_document(&mut rng);
+        builder.add_point(id, &[&document], &hw_counter).unwrap();
+    }
+    builder.finalize().unwrap()
+}
+
+fn text_index_compression(c: &mut Criterion) {
+    let mut group = c.benchmark_group("text-index-compression");
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut rng = StdRng::seed_from_u64(43);
+    let conditions: Vec<_> = (0..NUM_QUERIES)
+        .map(|_| {
+            FieldCondition::new_match(
+                "text".parse().unwrap(),
+                Match::new_text(&random_keyword(&mut rng)),
+            )
+        })
+        .collect();
+
+    for (name, compression) in [
+        ("none", None),
+        ("lz4", Some(TextIndexCompression::Lz4)),
+        ("zstd", Some(TextIndexCompression::Zstd)),
+    ] {
+        let dir = Builder::new().prefix("text_index").tempdir().unwrap();
+        let index = build_index(dir.path(), compression);
+
+        let size_bytes: u64 = index
+            .files()
+            .iter()
+            .map(|file| fs::metadata(file).unwrap().len())
+            .sum();
+        eprintln!("{name}: index size {size_bytes} bytes");
+
+        let mut query_idx = 0;
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let condition = &conditions[query_idx % conditions.len()];
+                query_idx += 1;
+                index
+                    .filter(condition, &hw_counter)
+                    .unwrap()
+                    .unwrap()
+                    .count()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().with_profiler(prof::FlamegraphProfiler::new(100));
+    targets = text_index_compression
+}
+
+criterion_main!(benches);
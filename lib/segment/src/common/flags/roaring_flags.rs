@@ -120,6 +120,22 @@ where
         }
     }
 
+    /// Set the flags at the given indices to false, updating the bitmap at once.
+    pub fn clear_many(&mut self, indices: &[PointOffsetType]) {
+        for &index in indices {
+            self.storage.buffer_set(index, false);
+        }
+        self.clear_many_immutable(indices);
+    }
+
+    /// Set the flags at the given indices to false without changing the underlying storage.
+    pub fn clear_many_immutable(&mut self, indices: &[PointOffsetType]) {
+        if let Some(&max_index) = indices.iter().max() {
+            self.len = self.len.max(max_index as usize + 1);
+        }
+        self.bitmap -= indices.iter().copied().collect::<RoaringBitmap>();
+    }
+
     pub fn clear_cache(&self) -> OperationResult<()> {
         let Self {
             storage,
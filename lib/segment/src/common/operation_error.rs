@@ -62,6 +62,10 @@ pub enum OperationError {
         "No range index for `order_by` key: `{key}`. Please create one to use `order_by`. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Range conditions"
     )]
     MissingRangeIndexForOrderBy { key: String },
+    #[error(
+        "No range index for weighted sample key: `{key}`. Please create one to use weighted sampling. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Range conditions"
+    )]
+    MissingRangeIndexForSample { key: String },
     #[error(
         "No appropriate index for faceting: `{key}`. Please create one to facet on this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Match conditions"
     )]
@@ -76,6 +80,8 @@ pub enum OperationError {
     },
     #[error("The expression {expression} produced a non-finite number")]
     NonFiniteNumber { expression: String },
+    #[error("Version conflict: {description}")]
+    VersionConflict { description: String },
 }
 
 impl OperationError {
@@ -114,6 +120,12 @@ impl OperationError {
         }
     }
 
+    pub fn version_conflict(description: impl Into<String>) -> Self {
+        Self::VersionConflict {
+            description: description.into(),
+        }
+    }
+
     pub fn vector_name_not_exists(vector_name: impl Into<String>) -> Self {
         Self::VectorNameNotExists {
             received_name: vector_name.into(),
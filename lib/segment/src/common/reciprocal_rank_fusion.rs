@@ -112,6 +112,8 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            provenance: None,
+            score_stages: Vec::new(),
         }
     }
 
@@ -9,7 +9,7 @@ use smallvec::SmallVec;
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::vectors::VectorInternal;
 use crate::index::field_index::FieldIndex;
-use crate::types::{PayloadKeyType, VectorNameBuf};
+use crate::types::{NullElements, PayloadKeyType, VectorNameBuf};
 
 pub type IndexesMap = HashMap<PayloadKeyType, Vec<FieldIndex>>;
 
@@ -24,11 +24,21 @@ pub fn check_is_empty<'a>(values: impl IntoIterator<Item = &'a Value>) -> bool {
     })
 }
 
-pub fn check_is_null<'a>(values: impl IntoIterator<Item = &'a Value>) -> bool {
-    values.into_iter().any(|x| x.is_null())
+pub fn check_is_null<'a>(
+    values: impl IntoIterator<Item = &'a Value>,
+    null_elements: NullElements,
+) -> bool {
+    values.into_iter().any(|x| match x {
+        Value::Null => null_elements != NullElements::Only,
+        Value::Array(array) => {
+            null_elements != NullElements::Ignore && array.iter().any(Value::is_null)
+        }
+        _ => false,
+    })
     // { "a": [ { "b": null }, { "b": 1 } ] } => true
     // { "a": [ { "b": 1 }, { "b": null } ] } => true
     // { "a": [ { "b": 1 }, { "b": 2 } ] } => false
+    // { "a": [ { "b": [1, null] } ] } => true with `NullElements::Include` or `Only`
 }
 
 pub fn rev_range(a: usize, b: usize) -> impl Iterator<Item = usize> {
@@ -189,6 +189,7 @@ mod tests {
                                 r#type: Default::default(),
                                 quantile: Some(0.99),
                                 always_ram: Some(true),
+                                outliers: None,
                             },
                         })),
                         on_disk: None,
@@ -265,6 +266,7 @@ mod tests {
                                 r#type: Default::default(),
                                 quantile: Some(0.99),
                                 always_ram: Some(true),
+                                outliers: None,
                             },
                         })),
                         on_disk: None,
@@ -289,6 +291,7 @@ mod tests {
                     r#type: Default::default(),
                     quantile: Some(0.95),
                     always_ram: Some(true),
+                    outliers: None,
                 },
             })),
         };
@@ -109,20 +109,32 @@ pub struct FacetHit<T: FacetValueTrait> {
 #[derive(Clone, Debug, Default)]
 pub struct FacetResponse {
     pub hits: Vec<FacetValueHit>,
+    /// Sum of the counts of the values, which are not in `hits`
+    pub other_count: usize,
 }
 
 impl FacetResponse {
     /// Convert a count map to top `limit` hits sorted by count descending.
+    /// Values with the same count are ordered by value ascending, also at the `limit` boundary.
+    ///
+    /// Counts of the values, which don't fit into the `limit`, are summed into `other_count`.
     ///
     /// Shared utility used by Edge and Collection facet implementations.
     pub fn top_hits(counts: HashMap<FacetValue, usize>, limit: usize) -> Self {
-        let hits = counts
+        let total_count: usize = counts.values().sum();
+
+        let hits: Vec<_> = counts
             .into_iter()
             .map(|(value, count)| FacetValueHit { value, count })
             .k_largest(limit)
             .collect();
 
-        Self { hits }
+        let hits_count: usize = hits.iter().map(|hit| hit.count).sum();
+
+        Self {
+            hits,
+            other_count: total_count - hits_count,
+        }
     }
 }
 
@@ -151,3 +163,37 @@ impl From<FacetValue> for ValueVariants {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_hits() {
+        let counts = HashMap::from([
+            (FacetValue::Keyword("a".to_string()), 3),
+            (FacetValue::Keyword("b".to_string()), 5),
+            (FacetValue::Keyword("c".to_string()), 3),
+            (FacetValue::Keyword("d".to_string()), 3),
+            (FacetValue::Keyword("e".to_string()), 1),
+        ]);
+
+        let response = FacetResponse::top_hits(counts, 3);
+
+        // Ties at the limit boundary are broken by value ascending
+        let hits = response
+            .hits
+            .iter()
+            .map(|hit| (hit.value.clone(), hit.count))
+            .collect_vec();
+        assert_eq!(
+            hits,
+            [
+                (FacetValue::Keyword("b".to_string()), 5),
+                (FacetValue::Keyword("a".to_string()), 3),
+                (FacetValue::Keyword("c".to_string()), 3),
+            ],
+        );
+        assert_eq!(response.other_count, 4);
+    }
+}
@@ -2,10 +2,25 @@ use std::collections::BTreeSet;
 use std::fmt;
 use std::str::FromStr;
 
+use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError, ValidationErrors};
 
+/// How to index string values, which exceed the `max_value_bytes` of the field index.
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OversizedValuePolicy {
+    /// Don't index the value. The point is still indexed with its other values, if any.
+    #[default]
+    Skip,
+    /// Index the beginning of the value, up to `max_value_bytes`.
+    /// Text values are truncated at a token boundary.
+    Truncate,
+    /// Reject the value. Fails the update of the point, or the creation of the index.
+    Error,
+}
+
 // Keyword
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -34,6 +49,26 @@ pub struct KeywordIndexParams {
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// If true, repeated occurrences of the same keyword within a point are stored once,
+    /// so the values count of the point reflects distinct keywords.
+    /// If false, every occurrence is counted. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduplicate_values: Option<bool>,
+
+    /// Max size of a single keyword in bytes. Larger keywords are handled according to
+    /// `oversized_value_policy`. Default: no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value_bytes: Option<usize>,
+
+    /// How to index keywords larger than `max_value_bytes`. Default: skip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oversized_value_policy: Option<OversizedValuePolicy>,
+
+    /// If true, keywords are matched ignoring their case, so "Active" matches "active".
+    /// Keywords are stored as is, so facets keep the original values. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case_insensitive: Option<bool>,
 }
 
 // Integer
@@ -162,6 +197,43 @@ pub struct FloatIndexParams {
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// If set, values are stored as 32 bit integer multiples of this precision, which takes half
+    /// of the memory, for fields which only need a coarse resolution.
+    /// Values are rounded to the nearest multiple, and range filters are applied to the rounded
+    /// values, so points with values within half of the precision outside of the range bounds may
+    /// match, and points within half of the precision inside of the bounds may not. Values more
+    /// than 2^31 multiples of the precision away from zero are rejected. The precision is stored
+    /// with the index, changing it rebuilds the index. Must be greater than 0.
+    /// Default: not set, values are stored as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<OrderedFloat<f64>>,
+}
+
+impl Validate for FloatIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let FloatIndexParams {
+            r#type: _,
+            is_principal: _,
+            on_disk: _,
+            enable_hnsw: _,
+            precision,
+        } = &self;
+        validate_float_index_params(precision.map(OrderedFloat::into_inner))
+    }
+}
+
+pub fn validate_float_index_params(precision: Option<f64>) -> Result<(), ValidationErrors> {
+    if let Some(precision) = precision
+        && !(precision.is_finite() && precision > 0.0)
+    {
+        let mut errors = ValidationErrors::new();
+        let mut error = ValidationError::new("range");
+        error.message = Some("'precision' must be a finite number greater than 0".into());
+        errors.add("precision", error);
+        return Err(errors);
+    }
+    Ok(())
 }
 
 // Geo
@@ -188,6 +260,41 @@ pub struct GeoIndexParams {
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// If set, additionally index the points by the H3 cells of this resolution, which contain
+    /// their locations. Faceting the field then counts the points per cell.
+    /// Must be between 0 (coarsest) and 15 (finest). Default: not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h3_resolution: Option<u8>,
+}
+
+/// Finest resolution of H3 cells
+pub const MAX_H3_RESOLUTION: u32 = 15;
+
+impl Validate for GeoIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let GeoIndexParams {
+            r#type: _,
+            on_disk: _,
+            enable_hnsw: _,
+            h3_resolution,
+        } = &self;
+        validate_geo_index_params(h3_resolution.map(u32::from))
+    }
+}
+
+pub fn validate_geo_index_params(h3_resolution: Option<u32>) -> Result<(), ValidationErrors> {
+    if let Some(h3_resolution) = h3_resolution
+        && h3_resolution > MAX_H3_RESOLUTION
+    {
+        let mut errors = ValidationErrors::new();
+        let mut error = ValidationError::new("range");
+        error.message =
+            Some(format!("'h3_resolution' must be between 0 and {MAX_H3_RESOLUTION}").into());
+        errors.add("h3_resolution", error);
+        return Err(errors);
+    }
+    Ok(())
 }
 
 // Text
@@ -209,6 +316,16 @@ pub enum TokenizerType {
     Multilingual,
 }
 
+/// Compression of posting lists of the text index stored on disk.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TextIndexCompression {
+    /// Fast compression and decompression, moderate compression ratio.
+    Lz4,
+    /// Higher compression ratio, slower decompression. Suited for rarely queried fields.
+    Zstd,
+}
+
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
 #[serde(rename_all = "snake_case")]
 pub struct TextIndexParams {
@@ -238,7 +355,8 @@ pub struct TextIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub phrase_matching: Option<bool>,
 
-    /// Ignore this set of tokens. Can select from predefined languages and/or provide a custom set.
+    /// Ignore this set of tokens. Can select from predefined languages and/or provide a custom set,
+    /// or `auto` to use the stopwords of the detected language of the indexed texts.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stopwords: Option<StopwordsInterface>,
 
@@ -255,6 +373,59 @@ pub struct TextIndexParams {
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// Compress posting lists of the index stored on disk.
+    /// Reduces disk and page cache usage, but posting lists have to be decompressed on every
+    /// access, which makes queries slower. Default: no compression.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<TextIndexCompression>,
+
+    /// If true, index the original form of tokens alongside the stemmed one.
+    /// Allows to match the original form with `unstemmed` text queries, but roughly doubles the
+    /// size of the dictionary. Requires a stemmer, not supported by the prefix tokenizer.
+    /// Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_unstemmed: Option<bool>,
+
+    /// Max size of a single text value in bytes. Larger values are handled according to
+    /// `oversized_value_policy`. Protects index builds from pathologically large documents.
+    /// Default: no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value_bytes: Option<usize>,
+
+    /// How to index text values larger than `max_value_bytes`. Default: skip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oversized_value_policy: Option<OversizedValuePolicy>,
+
+    /// Treat tokens contained in at least this percentage of the indexed points as stopwords,
+    /// and remove them from text queries. Detected per shard over all of its segments, once it has
+    /// at least 100 indexed points, and persisted. Re-detected when the threshold changes, or when
+    /// the number of indexed points changes by more than 10%.
+    /// Not supported by the prefix tokenizer. Default: disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_stopwords_threshold: Option<u32>,
+
+    /// If true, strip HTML tags from text values before tokenization, and index only the visible
+    /// text. Content of comments, `<script>` and `<style>` elements is removed too.
+    /// Malformed markup is stripped on a best-effort basis. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strip_html: Option<bool>,
+
+    /// If true, decode HTML entities (e.g., "&amp;" -> "&") in the text left after stripping HTML.
+    /// Only used with `strip_html`. Default: true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decode_html_entities: Option<bool>,
+
+    /// If true, strip HTML from text queries too, not only from indexed values.
+    /// Only used with `strip_html`. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strip_html_queries: Option<bool>,
+
+    /// If true, store the tokens of every point in the index, in addition to the posting lists.
+    /// Allows to retrieve the tokens, which are searchable for a point. Increases the size of the
+    /// index on disk, but is not loaded into RAM. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward_index: Option<bool>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -367,6 +538,21 @@ impl FromStr for SnowballLanguage {
 pub enum StopwordsInterface {
     Language(Language),
     Set(StopwordsSet),
+    /// Detect the language of the indexed texts, and use its stopwords
+    Auto(StopwordsDetection),
+}
+
+/// Detect the language of the indexed texts from a sample of the first indexed documents.
+///
+/// Detected per segment, when its index is built by optimization. The detected language is stored
+/// with the index, so the same stopwords are used after reload. If the language can't be detected
+/// confidently, or has no pre-defined stopwords, no stopwords are used.
+/// Mutable indexes of not yet optimized segments don't use stopwords.
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StopwordsDetection {
+    #[default]
+    Auto,
 }
 
 impl StopwordsInterface {
@@ -375,6 +561,7 @@ impl StopwordsInterface {
         StopwordsInterface::Set(StopwordsSet {
             languages: None,
             custom: Some(custom.iter().map(|s| (*s).to_string()).collect()),
+            exclude: None,
         })
     }
 
@@ -383,6 +570,7 @@ impl StopwordsInterface {
         StopwordsInterface::Set(StopwordsSet {
             languages: Some(languages.iter().cloned().collect()),
             custom: Some(custom.iter().map(|s| (*s).to_string()).collect()),
+            exclude: None,
         })
     }
 }
@@ -481,6 +669,12 @@ pub struct StopwordsSet {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom: Option<BTreeSet<String>>,
+
+    /// Words to keep, even though they are in the stopwords of the languages.
+    /// Doesn't affect custom stopwords.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<BTreeSet<String>>,
 }
 
 // Bool
@@ -507,6 +701,53 @@ pub struct BoolIndexParams {
     /// Default: true.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_hnsw: Option<bool>,
+
+    /// If set, additionally index each of the first `max_positions` elements of a boolean
+    /// array on its own, so conditions like `permissions[2]` can use an index.
+    /// Positions beyond this limit are still filterable, but without an index.
+    /// Default: not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_positions: Option<usize>,
+}
+
+/// Upper limit for `max_positions` of a bool index, each position is indexed separately
+pub const MAX_BOOL_INDEX_POSITIONS: u64 = 256;
+
+impl Validate for BoolIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let BoolIndexParams {
+            r#type: _,
+            on_disk: _,
+            enable_hnsw: _,
+            max_positions,
+        } = &self;
+        validate_bool_index_params(max_positions.map(|positions| positions as u64))
+    }
+}
+
+pub fn validate_bool_index_params(max_positions: Option<u64>) -> Result<(), ValidationErrors> {
+    if let Some(max_positions) = max_positions
+        && !(1..=MAX_BOOL_INDEX_POSITIONS).contains(&max_positions)
+    {
+        let mut errors = ValidationErrors::new();
+        let mut error = ValidationError::new("range");
+        error.message = Some(
+            format!("'max_positions' must be between 1 and {MAX_BOOL_INDEX_POSITIONS}").into(),
+        );
+        errors.add("max_positions", error);
+        return Err(errors);
+    }
+    Ok(())
+}
+
+impl BoolIndexParams {
+    /// Params of the plain index built for a single position of a positional bool index.
+    pub fn element_params(&self) -> Self {
+        Self {
+            max_positions: None,
+            ..self.clone()
+        }
+    }
 }
 
 // Datetime
@@ -564,6 +805,16 @@ mod tests {
         assert_eq!(deserialized, stopwords);
     }
 
+    #[test]
+    fn test_stopwords_option_auto_serialization() {
+        let stopwords = StopwordsInterface::Auto(StopwordsDetection::Auto);
+        let json = serde_json::to_string(&stopwords).unwrap();
+        assert_eq!(json, r#""auto""#);
+
+        let deserialized: StopwordsInterface = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, stopwords);
+    }
+
     #[test]
     fn test_deserialize_stopwords_from_json_examples() {
         let json1 = r#"{"custom": ["as", "the", "a"]}"#;
@@ -8,8 +8,11 @@ pub mod modifier;
 pub mod named_vectors;
 pub mod order_by;
 pub mod primitive;
+pub mod quantiles;
 pub mod query_context;
 pub mod segment_record;
+pub mod text_query;
 pub mod tiny_map;
 pub mod vector_name_config;
 pub mod vectors;
+pub mod weighted_sample;
@@ -179,6 +179,13 @@ impl std::hash::Hash for OrderValue {
 impl OrderValue {
     const MAX: Self = Self::Float(f64::NAN);
     const MIN: Self = Self::Float(f64::MIN);
+
+    pub fn to_f64(self) -> f64 {
+        match self {
+            OrderValue::Int(value) => value as f64,
+            OrderValue::Float(value) => value,
+        }
+    }
 }
 
 impl From<OrderValue> for serde_json::Value {
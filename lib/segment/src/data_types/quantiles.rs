@@ -0,0 +1,193 @@
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::json_path::JsonPath;
+
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize, Validate, Hash)]
+pub struct QuantilesParams {
+    /// Numeric payload field to split, requires an integer or float index with range support
+    pub key: JsonPath,
+    /// Number of evenly populated buckets to split the values of the field into
+    #[validate(range(min = 1, max = 1000))]
+    pub buckets: usize,
+}
+
+impl QuantilesParams {
+    pub const DEFAULT_BUCKETS: usize = 10;
+}
+
+/// Value sampled from the sorted values of a numeric index.
+///
+/// Stands for itself and the values after it, up to the next sample of the same index.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ValueSample {
+    pub value: f64,
+    /// Number of values the sample stands for, including the sampled value itself
+    pub count: usize,
+}
+
+/// Samples of the values of a numeric field, from any number of segments
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValueSamples {
+    pub samples: Vec<ValueSample>,
+}
+
+impl ValueSamples {
+    pub fn total_count(&self) -> usize {
+        self.samples.iter().map(|sample| sample.count).sum()
+    }
+}
+
+/// Range of values `from <= value < to`, the last bucket also includes `to`
+#[derive(Clone, Debug, PartialEq, JsonSchema, Serialize)]
+pub struct QuantileBucket {
+    pub from: f64,
+    pub to: f64,
+    /// Approximate number of values in the range.
+    /// Points with multiple values are counted once per value.
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, JsonSchema, Serialize)]
+pub struct QuantilesResponse {
+    /// Buckets with about the same number of values each, sorted by value
+    pub buckets: Vec<QuantileBucket>,
+}
+
+impl QuantilesResponse {
+    /// Split the sampled values into `buckets` evenly populated buckets.
+    ///
+    /// Bucket boundaries are sampled values, so there are fewer buckets if there are fewer
+    /// distinct samples, and counts are as approximate as the samples.
+    pub fn from_samples(samples: ValueSamples, buckets: usize) -> Self {
+        // Samples of the same value from different segments are merged
+        let samples = samples
+            .samples
+            .into_iter()
+            .filter(|sample| sample.value.is_finite() && sample.count > 0)
+            .sorted_unstable_by_key(|sample| OrderedFloat(sample.value))
+            .coalesce(|prev, next| {
+                if prev.value == next.value {
+                    Ok(ValueSample {
+                        value: prev.value,
+                        count: prev.count + next.count,
+                    })
+                } else {
+                    Err((prev, next))
+                }
+            })
+            .collect_vec();
+
+        let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+            return Self::default();
+        };
+        let max_value = last.value;
+
+        let total_count: usize = samples.iter().map(|sample| sample.count).sum();
+        let buckets_count = buckets.max(1);
+
+        let mut result = Vec::with_capacity(buckets_count.min(samples.len()));
+        let mut bucket = QuantileBucket {
+            from: first.value,
+            to: max_value,
+            count: 0,
+        };
+        let mut reached_count = 0;
+
+        for sample in samples {
+            // Close the bucket, once it reached its share of the values
+            let bucket_end = total_count * (result.len() + 1) / buckets_count;
+            if bucket.count > 0 && reached_count >= bucket_end {
+                bucket.to = sample.value;
+                result.push(bucket);
+                bucket = QuantileBucket {
+                    from: sample.value,
+                    to: max_value,
+                    count: 0,
+                };
+            }
+
+            bucket.count += sample.count;
+            reached_count += sample.count;
+        }
+        result.push(bucket);
+
+        Self { buckets: result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(values: &[(f64, usize)]) -> ValueSamples {
+        ValueSamples {
+            samples: values
+                .iter()
+                .map(|&(value, count)| ValueSample { value, count })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_evenly_populated_buckets() {
+        let samples = samples(&(0..100).map(|i| (f64::from(i), 10)).collect_vec());
+
+        let response = QuantilesResponse::from_samples(samples, 4);
+
+        assert_eq!(response.buckets.len(), 4);
+        for (i, bucket) in response.buckets.iter().enumerate() {
+            assert_eq!(bucket.from, 25.0 * i as f64);
+            assert_eq!(bucket.count, 250);
+        }
+        let to = response
+            .buckets
+            .iter()
+            .map(|bucket| bucket.to)
+            .collect_vec();
+        assert_eq!(to, vec![25.0, 50.0, 75.0, 99.0]);
+    }
+
+    #[test]
+    fn test_skewed_samples_of_several_segments() {
+        // Most values are below 10, a few are spread up to 1000
+        let samples = samples(&[(0.0, 40), (1000.0, 1), (5.0, 40), (0.0, 10), (500.0, 9)]);
+
+        let response = QuantilesResponse::from_samples(samples, 2);
+
+        assert_eq!(
+            response.buckets,
+            vec![
+                QuantileBucket {
+                    from: 0.0,
+                    to: 5.0,
+                    count: 50,
+                },
+                QuantileBucket {
+                    from: 5.0,
+                    to: 1000.0,
+                    count: 50,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_fewer_samples_than_buckets() {
+        let response = QuantilesResponse::from_samples(samples(&[(7.0, 3)]), 10);
+        assert_eq!(
+            response.buckets,
+            vec![QuantileBucket {
+                from: 7.0,
+                to: 7.0,
+                count: 3,
+            }],
+        );
+
+        let response = QuantilesResponse::from_samples(ValueSamples::default(), 10);
+        assert!(response.buckets.is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeSet;
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::data_types::index::Language;
+use crate::json_path::JsonPath;
+use crate::types::{Condition, Filter, Match, MatchPhrase, MatchText, MatchTextAny};
+
+/// Query tokens removed as stopwords, when parsing a full-text query
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct QueryStopwords {
+    /// Removed tokens, sorted
+    pub removed: BTreeSet<String>,
+    /// Whether no query tokens are left after the removal, so the query matches no points
+    pub is_empty: bool,
+}
+
+impl QueryStopwords {
+    /// Combine with the stopwords removed in another segment.
+    ///
+    /// Detected stopwords are shared by all segments of a shard, but the language of the
+    /// stopwords may be detected per segment, so the query may be left empty in some segments
+    /// only.
+    pub fn merge(&mut self, other: Self) {
+        let Self { removed, is_empty } = other;
+        self.removed.extend(removed);
+        self.is_empty |= is_empty;
+    }
+}
+
+/// Full-text condition of a filter
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextCondition {
+    /// Full path of the field, including the keys of nested conditions
+    pub key: JsonPath,
+    pub text: String,
+    pub language: Option<Language>,
+}
+
+impl TextCondition {
+    /// Full-text conditions of the `filter`, including those of nested filters, in the order of
+    /// the filter.
+    pub fn collect_from(filter: &Filter) -> Vec<Self> {
+        fn collect(
+            filter: &Filter,
+            nested_path: Option<&JsonPath>,
+            output: &mut Vec<TextCondition>,
+        ) {
+            for condition in filter.iter_conditions() {
+                match condition {
+                    Condition::Field(field_condition) => {
+                        let (text, language) = match &field_condition.r#match {
+                            Some(Match::Text(MatchText { text, language, .. })) => {
+                                (text, *language)
+                            }
+                            Some(Match::TextAny(MatchTextAny { text_any, .. })) => (text_any, None),
+                            Some(Match::Phrase(MatchPhrase { phrase, .. })) => (phrase, None),
+                            _ => continue,
+                        };
+                        output.push(TextCondition {
+                            key: JsonPath::extend_or_new(nested_path, &field_condition.key),
+                            text: text.clone(),
+                            language,
+                        });
+                    }
+                    Condition::Filter(filter) => collect(filter, nested_path, output),
+                    Condition::Nested(nested) => {
+                        let full_path = JsonPath::extend_or_new(nested_path, &nested.array_key());
+                        collect(nested.filter(), Some(&full_path), output);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut conditions = Vec::new();
+        collect(filter, None, &mut conditions);
+        conditions
+    }
+}
+
+/// Occurrence of a query token in a text value
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TextTokenMatch {
+    /// Query token, as produced by the tokenizer
+    pub token: String,
+    /// Byte offset of the start of the word, which produced the token
+    pub start: usize,
+    /// Byte offset of the end of the word, exclusive
+    pub end: usize,
+    /// Same as `start`, in chars
+    pub char_start: usize,
+    /// Same as `end`, in chars
+    pub char_end: usize,
+}
+
+/// Occurrences of the query tokens in one of the text values of a field
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TextValueMatches {
+    /// Position of the value in the array of the field, 0 for a single value
+    pub value_index: usize,
+    /// Occurrences in the order of the text
+    pub matches: Vec<TextTokenMatch>,
+}
+
+/// Parameters of the excerpts of text values around the matches of a full-text query
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnippetParams {
+    /// Target length of an excerpt, in chars
+    pub length: usize,
+    /// Maximum number of excerpts
+    pub count: usize,
+}
+
+impl SnippetParams {
+    pub const DEFAULT_LENGTH: usize = 200;
+    pub const DEFAULT_COUNT: usize = 1;
+}
+
+impl Default for SnippetParams {
+    fn default() -> Self {
+        Self {
+            length: Self::DEFAULT_LENGTH,
+            count: Self::DEFAULT_COUNT,
+        }
+    }
+}
+
+/// Occurrence of a query token in an excerpt
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct TextHighlight {
+    /// Query token, as produced by the tokenizer
+    pub token: String,
+    /// Char offset of the start of the word in the excerpt
+    pub start: usize,
+    /// Char offset of the end of the word in the excerpt, exclusive
+    pub end: usize,
+}
+
+/// Excerpt of one of the text values of a field around the occurrences of query tokens
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct TextSnippet {
+    /// Position of the value in the array of the field, 0 for a single value
+    pub value_index: usize,
+    /// Char offset of the start of the excerpt in the value
+    pub char_start: usize,
+    /// Text of the excerpt
+    pub text: String,
+    /// Occurrences in the order of the text
+    pub highlights: Vec<TextHighlight>,
+}
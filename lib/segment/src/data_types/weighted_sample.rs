@@ -0,0 +1,60 @@
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use crate::json_path::JsonPath;
+use crate::types::{ExtendedPointId, PointIdType};
+
+/// Weighted random sample of points, by the values of a numeric payload field.
+///
+/// Implements A-Res weighted reservoir sampling (Efraimidis & Spirakis): every point gets a random
+/// key `u^(1/weight)`, where `u` is uniform in `(0, 1)`, and the points with the largest keys are
+/// sampled. Keys are computed in the log space, as `ln(u) / weight`, to stay precise for small
+/// weights.
+///
+/// `u` is derived from the seed and the external id of the point, so the sample is
+/// deterministic for the same seed and data, regardless of how points are spread over segments
+/// and shards. It also makes samples mergeable: the top keys of all segments are the top keys of
+/// the whole collection.
+#[derive(Clone, Debug, PartialEq, Hash, Serialize)]
+pub struct WeightedSample {
+    /// Numeric payload field with the weights of points, requires a range index.
+    /// If a point has multiple values, the largest one is used.
+    pub key: JsonPath,
+    pub seed: u64,
+    /// Weight of points without a value
+    pub default_weight: OrderedFloat<f64>,
+}
+
+impl WeightedSample {
+    /// A-Res key of the point, larger keys are sampled first.
+    ///
+    /// Returns `None` for non-positive weights, such points are never sampled.
+    pub fn sampling_key(&self, point_id: PointIdType, weight: Option<f64>) -> Option<f64> {
+        let weight = weight.unwrap_or(self.default_weight.0);
+        if weight <= 0.0 || !weight.is_finite() {
+            return None;
+        }
+
+        let id = match point_id {
+            ExtendedPointId::NumId(num) => num,
+            ExtendedPointId::Uuid(uuid) => {
+                let (high, low) = uuid.as_u64_pair();
+                splitmix64(high) ^ low
+            }
+        };
+        let hash = splitmix64(splitmix64(self.seed) ^ id);
+
+        // Top 53 bits as a uniform value in (0, 1), zero is excluded
+        let uniform = ((hash >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+        Some(uniform.ln() / weight)
+    }
+}
+
+/// Finalizer of the SplitMix64 generator, a fast and well distributed 64-bit mix.
+/// Stable across platforms and releases, unlike the hashers of the standard library.
+const fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
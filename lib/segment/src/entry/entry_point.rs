@@ -3,23 +3,29 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::{DeferredBehavior, TelemetryDetail};
+use common::populate_budget::PopulateBudget;
+use common::types::{DeferredBehavior, ScoreType, TelemetryDetail};
 use uuid::Uuid;
 
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
 use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::index::Language;
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::quantiles::ValueSamples;
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use crate::data_types::segment_record::SegmentRecord;
+use crate::data_types::text_query::{QueryStopwords, SnippetParams, TextSnippet, TextValueMatches};
 use crate::data_types::vector_name_config::VectorNameConfig;
 use crate::data_types::vectors::{QueryVector, VectorInternal};
+use crate::data_types::weighted_sample::WeightedSample;
 use crate::entry::snapshot_entry::SnapshotEntry;
 use crate::index::field_index::{CardinalityEstimation, FieldIndex};
+use crate::index::query_optimization::condition_cost::PlannedCondition;
 use crate::json_path::JsonPath;
 use crate::telemetry::SegmentTelemetry;
 use crate::types::{
@@ -27,6 +33,7 @@ use crate::types::{
     PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType,
     VectorName, VectorNameBuf, WithPayload, WithVector,
 };
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
 
 /// Define all operations on segment that do not require mutable access.
 ///
@@ -140,6 +147,20 @@ pub trait ReadSegmentEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<PointIdType>>;
 
+    /// Return a weighted random sample of points which satisfy filtering condition,
+    /// together with their sampling keys, largest first.
+    ///
+    /// Will fail if there is no index for the sample key.
+    /// Cancelled by `is_stopped` flag.
+    fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        sample: &WeightedSample,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>>;
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType>;
 
@@ -172,6 +193,109 @@ pub trait ReadSegmentEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<CardinalityEstimation>;
 
+    /// Order in which points of this segment are checked against `must` conditions of the filter.
+    fn plan_must_conditions(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<PlannedCondition>>;
+
+    /// Quantized representation of the named vector of the point, as used by the scorers.
+    ///
+    /// Returns `None` if the vector is deleted, or if the vector is not quantized in this segment.
+    fn quantized_vector(
+        &self,
+        vector_name: &VectorName,
+        point_id: PointIdType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<QuantizedVectorRecord>>;
+
+    /// Tokens stored for the point in the full-text index of the field, in the index order.
+    ///
+    /// Returns `None` if the field has no full-text index in this segment.
+    fn point_text_tokens(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<String>>>;
+
+    /// Occurrences of the query tokens of the `text` in the values of the field of the point,
+    /// tokenized with its full-text index.
+    ///
+    /// Returns `None` if the field has no full-text index in this segment.
+    fn point_text_match_offsets(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        text: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextValueMatches>>>;
+
+    /// Excerpts of the values of the field of the point around the matches of the `text`, with
+    /// the query tokens highlighted.
+    ///
+    /// Returns `None` if the field has no full-text index in this segment.
+    fn point_text_snippets(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        text: &str,
+        params: SnippetParams,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextSnippet>>>;
+
+    /// Values of the field sampled from its integer or float index, for splitting them into
+    /// quantiles.
+    ///
+    /// Returns `None` if the field has no such index in this segment.
+    fn value_samples(&self, key: &JsonPath) -> Option<ValueSamples>;
+
+    /// Number of points in the full-text index of the field, `0` if the field has no full-text
+    /// index in this segment.
+    fn text_index_points_count(&self, key: &JsonPath) -> usize;
+
+    /// Tokens contained in at least `min_fraction` of the points in the full-text index of the
+    /// field, as candidates for the stopwords of the shard.
+    ///
+    /// Empty if the field has no full-text index in this segment.
+    fn text_frequent_tokens(
+        &self,
+        key: &JsonPath,
+        min_fraction: f64,
+    ) -> OperationResult<Vec<String>>;
+
+    /// Number of points containing each of the `tokens` in the full-text index of the field.
+    ///
+    /// All `0` if the field has no full-text index in this segment.
+    fn text_tokens_points_count(
+        &self,
+        key: &JsonPath,
+        tokens: &[String],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<usize>>;
+
+    /// Query tokens removed as stopwords, when querying the full-text index of the field with
+    /// the `text`.
+    ///
+    /// Returns `None` if the field has no full-text index in this segment.
+    fn text_query_stopwords(
+        &self,
+        key: &JsonPath,
+        text: &str,
+        language: Option<Language>,
+    ) -> Option<QueryStopwords>;
+
+    /// Populate pages of the indexes of the payload field, which fit into the `budget`.
+    fn populate_payload_indexes(
+        &self,
+        key: &PayloadKeyType,
+        budget: &mut PopulateBudget,
+    ) -> OperationResult<()>;
+
+    /// Approximate RAM usage in bytes of the payload field indexes kept in memory.
+    fn payload_indexes_ram_bytes(&self) -> usize;
+
     fn vector_names(&self) -> HashSet<VectorNameBuf>;
 
     /// Whether this segment is completely empty in terms of points
@@ -298,6 +422,22 @@ pub trait NonAppendableSegmentEntry: StorageSegmentEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<bool>;
 
+    /// Same as [`Self::delete_point`] for each of the points, returns the number of deleted ones
+    fn delete_points(
+        &mut self,
+        op_num: SeqNumberType,
+        point_ids: &[PointIdType],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<usize> {
+        let mut deleted = 0;
+        for &point_id in point_ids {
+            if self.delete_point(op_num, point_id, hw_counter)? {
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+
     /// Delete field index, if exists
     fn delete_field_index(
         &mut self,
@@ -331,6 +471,44 @@ pub trait NonAppendableSegmentEntry: StorageSegmentEntry {
         field_index: Vec<FieldIndex>,
     ) -> OperationResult<bool>;
 
+    /// Build a replacement for the field index of the key, keeping the current index intact.
+    ///
+    /// The current index keeps serving queries until the replacement is passed to
+    /// [`NonAppendableSegmentEntry::swap_field_index`].
+    fn build_replacement_field_index(
+        &self,
+        op_num: SeqNumberType,
+        key: PayloadKeyTypeRef,
+        field_schema: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<BuildFieldIndexResult>;
+
+    /// Atomically replace the field index of the key with a replacement built by
+    /// [`NonAppendableSegmentEntry::build_replacement_field_index`].
+    /// On failure the current index stays intact. Returns whether it was actually swapped or not.
+    fn swap_field_index(
+        &mut self,
+        op_num: SeqNumberType,
+        key: PayloadKeyType,
+        field_schema: PayloadFieldSchema,
+        field_index: Vec<FieldIndex>,
+    ) -> OperationResult<bool>;
+
+    /// Switch the field index of the key between on-disk and in-memory mode, without rebuilding it.
+    ///
+    /// Returns `false` if the schema or the storage of the index doesn't allow it, in which case
+    /// the index has to be rebuilt with [`NonAppendableSegmentEntry::build_replacement_field_index`].
+    fn set_field_index_on_disk(
+        &mut self,
+        op_num: SeqNumberType,
+        key: PayloadKeyTypeRef,
+        field_schema: &PayloadFieldSchema,
+    ) -> OperationResult<bool>;
+
+    /// Assign the stopwords detected by the shard to the full-text index of the field, including
+    /// an index of the field built later. Not persisted, the shard assigns them again on load.
+    fn set_text_auto_stopwords(&mut self, key: &JsonPath, stopwords: Arc<AHashSet<String>>);
+
     /// Create index for a payload field, if not exists
     fn create_field_index(
         &mut self,
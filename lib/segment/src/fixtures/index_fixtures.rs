@@ -50,6 +50,7 @@ impl TestRawScorerProducer {
                     r#type: Default::default(),
                     quantile: None,
                     always_ram: Some(true),
+                    outliers: None,
                 }
                 .into(),
                 QuantizedVectorsStorageType::Immutable,
@@ -174,6 +174,7 @@ pub fn random_uncommon_condition<R: Rng + ?Sized>(rnd_gen: &mut R) -> Condition
                 gt: None,
                 gte: Some(3),
                 lte: None,
+                distinct: None,
             },
         )),
         1 => Condition::Field(FieldCondition::new_values_count(
@@ -183,6 +184,7 @@ pub fn random_uncommon_condition<R: Rng + ?Sized>(rnd_gen: &mut R) -> Condition
                 gt: None,
                 gte: None,
                 lte: Some(2),
+                distinct: None,
             },
         )),
         2 => Condition::HasId(HasIdCondition {
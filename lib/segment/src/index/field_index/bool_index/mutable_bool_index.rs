@@ -218,6 +218,11 @@ impl MutableBoolIndex {
             points_count: self.indexed_count,
             points_values_count: (self.trues_count + self.falses_count),
             histogram_bucket_size: None,
+            write_amplification: None,
+            oversized_values: None,
+            stopwords_language: None,
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
             index_type: "mmap_bool",
         }
     }
@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
+use common::bitvec::{BitSlice, BitSliceExt};
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
 use common::types::PointOffsetType;
+use itertools::Itertools;
 
 use super::bool_index::BoolIndex;
+use super::geo_index::GeoMapIndex;
 use super::map_index::MapIndex;
 use crate::common::operation_error::OperationResult;
-use crate::data_types::facets::{FacetHit, FacetValueRef};
+use crate::data_types::facets::{FacetHit, FacetValueHit, FacetValueRef};
 use crate::types::{IntPayloadType, UuidIntType};
 
 pub trait FacetIndex {
@@ -74,6 +80,51 @@ pub enum FacetIndexEnum<'a> {
     Int(&'a MapIndex<IntPayloadType>),
     Uuid(&'a MapIndex<UuidIntType>),
     Bool(&'a BoolIndex),
+    /// Only geo indexes with H3 cells, see [`GeoMapIndex::h3_cells`]
+    Geo(&'a GeoMapIndex),
+}
+
+impl FacetIndexEnum<'_> {
+    /// Top `top_k` values by the number of their points among `filter_ids`, best first.
+    ///
+    /// The total number of points of a value bounds the number of its filtered points, so the
+    /// points of values, which can't beat the current k-th count, are not intersected at all.
+    pub fn facet_counts(
+        &self,
+        filter_ids: &BitSlice,
+        top_k: usize,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<FacetValueHit>> {
+        if top_k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut upper_bounds = HashMap::new();
+        self.for_each_count_per_value(None, |hit| {
+            upper_bounds.insert(hit.value.to_owned(), hit.count);
+            Ok(())
+        })?;
+
+        let mut top = FixedLengthPriorityQueue::<FacetValueHit>::new(top_k);
+        self.for_each_value_map(hw_counter, |value, points| {
+            let value = value.to_owned();
+            let upper_bound = upper_bounds.get(&value).copied().unwrap_or(usize::MAX);
+            if top.is_full() && top.top().is_some_and(|kth| upper_bound < kth.count) {
+                return Ok(());
+            }
+
+            let count = points
+                .dedup()
+                .filter(|&point_id| filter_ids.get_bit(point_id as usize).unwrap_or(false))
+                .count();
+            if count > 0 {
+                top.push(FacetHit { value, count });
+            }
+            Ok(())
+        })?;
+
+        Ok(top.into_sorted_vec())
+    }
 }
 
 impl<'a> FacetIndex for FacetIndexEnum<'a> {
@@ -96,6 +147,9 @@ impl<'a> FacetIndex for FacetIndexEnum<'a> {
             FacetIndexEnum::Bool(index) => {
                 FacetIndex::for_points_values(*index, points, hw_counter, f)
             }
+            FacetIndexEnum::Geo(index) => {
+                FacetIndex::for_points_values(*index, points, hw_counter, f)
+            }
         }
     }
 
@@ -108,6 +162,7 @@ impl<'a> FacetIndex for FacetIndexEnum<'a> {
             FacetIndexEnum::Int(index) => FacetIndex::for_each_value(*index, f),
             FacetIndexEnum::Uuid(index) => FacetIndex::for_each_value(*index, f),
             FacetIndexEnum::Bool(index) => FacetIndex::for_each_value(*index, f),
+            FacetIndexEnum::Geo(index) => FacetIndex::for_each_value(*index, f),
         }
     }
 
@@ -124,6 +179,7 @@ impl<'a> FacetIndex for FacetIndexEnum<'a> {
             FacetIndexEnum::Int(index) => FacetIndex::for_each_value_map(*index, hw_counter, f),
             FacetIndexEnum::Uuid(index) => FacetIndex::for_each_value_map(*index, hw_counter, f),
             FacetIndexEnum::Bool(index) => FacetIndex::for_each_value_map(*index, hw_counter, f),
+            FacetIndexEnum::Geo(index) => FacetIndex::for_each_value_map(*index, hw_counter, f),
         }
     }
 
@@ -145,6 +201,9 @@ impl<'a> FacetIndex for FacetIndexEnum<'a> {
             FacetIndexEnum::Bool(index) => {
                 FacetIndex::for_each_count_per_value(*index, deferred_internal_id, f)
             }
+            FacetIndexEnum::Geo(index) => {
+                FacetIndex::for_each_count_per_value(*index, deferred_internal_id, f)
+            }
         }
     }
 }
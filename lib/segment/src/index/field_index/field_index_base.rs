@@ -1,8 +1,12 @@
 use std::fmt::Formatter;
 use std::path::PathBuf;
 
+use common::bitvec::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::PointOffsetType;
+use common::populate_budget::PopulateBudget;
+use common::types::{DetailsLevel, PointOffsetType, TelemetryDetail};
+#[cfg(unix)]
+use common::universal_io::MmapFile;
 use itertools::Either;
 use serde_json::Value;
 
@@ -17,6 +21,9 @@ use super::full_text_index::text_index::{
 use super::geo_index::{GeoMapIndexGridstoreBuilder, GeoMapIndexMmapBuilder};
 use super::map_index::{MapIndex, MapIndexGridstoreBuilder, MapIndexMmapBuilder};
 use super::null_index::immutable_null_index::ImmutableNullIndexBuilder;
+use super::numeric_index::scaled_float_index::{
+    ScaledFloatIndex, ScaledFloatIndexGridstoreBuilder, ScaledFloatIndexMmapBuilder,
+};
 use super::numeric_index::{
     NumericIndex, NumericIndexGridstoreBuilder, NumericIndexMmapBuilder, StreamRange,
 };
@@ -34,7 +41,7 @@ use crate::index::payload_config::{
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
     DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchPhrase,
-    MatchText, MatchTextAny, PayloadKeyType, RangeInterface, UuidIntType, UuidPayloadType,
+    MatchText, MatchTextAny, PayloadKeyType, Range, RangeInterface, UuidIntType, UuidPayloadType,
 };
 
 pub trait PayloadFieldIndex {
@@ -67,6 +74,20 @@ pub trait PayloadFieldIndex {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<CardinalityEstimation>>;
 
+    /// Return estimations of amount of points for each of the given conditions.
+    /// Same as calling [`Self::estimate_cardinality`] for each condition, but indexes may share
+    /// the setup of the estimation across conditions.
+    fn estimate_cardinality_batch(
+        &self,
+        conditions: &[&FieldCondition],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<Option<CardinalityEstimation>>> {
+        conditions
+            .iter()
+            .map(|condition| self.estimate_cardinality(condition, hw_counter))
+            .collect()
+    }
+
     /// Iterate conditions for payload blocks with minimum size of `threshold`
     /// Required for building HNSW index
     fn for_each_payload_block(
@@ -126,6 +147,16 @@ pub trait ValueIndexer {
 
     /// remove a point from the index
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()>;
+
+    /// Remove many points from the index at once.
+    ///
+    /// Indexes, which can batch the updates, override this to avoid removing points one by one.
+    fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        for &id in ids {
+            self.remove_point(id)?;
+        }
+        Ok(())
+    }
 }
 
 /// Common interface for all possible types of field indexes
@@ -136,6 +167,7 @@ pub enum FieldIndex {
     IntMapIndex(MapIndex<IntPayloadType>),
     KeywordIndex(MapIndex<str>),
     FloatIndex(NumericIndex<FloatPayloadType, FloatPayloadType>),
+    ScaledFloatIndex(ScaledFloatIndex),
     GeoIndex(GeoMapIndex),
     FullTextIndex(FullTextIndex),
     BoolIndex(BoolIndex),
@@ -152,6 +184,7 @@ impl std::fmt::Debug for FieldIndex {
             FieldIndex::IntMapIndex(_index) => write!(f, "IntMapIndex"),
             FieldIndex::KeywordIndex(_index) => write!(f, "KeywordIndex"),
             FieldIndex::FloatIndex(_index) => write!(f, "FloatIndex"),
+            FieldIndex::ScaledFloatIndex(_index) => write!(f, "ScaledFloatIndex"),
             FieldIndex::GeoIndex(_index) => write!(f, "GeoIndex"),
             FieldIndex::BoolIndex(_index) => write!(f, "BoolIndex"),
             FieldIndex::FullTextIndex(_index) => write!(f, "FullTextIndex"),
@@ -182,30 +215,44 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(_) => None,
             FieldIndex::KeywordIndex(_) => None,
             FieldIndex::FloatIndex(_) => None,
+            FieldIndex::ScaledFloatIndex(_) => None,
             FieldIndex::GeoIndex(_) => None,
             FieldIndex::BoolIndex(_) => None,
             FieldIndex::FullTextIndex(index) => match &condition.r#match {
-                Some(Match::Text(MatchText { text })) => Some(index.check_payload_match(
-                    payload_value,
+                Some(Match::Text(MatchText {
                     text,
-                    PayloadMatchQueryType::Text,
-                    hw_counter,
-                )?),
-                Some(Match::Phrase(MatchPhrase { phrase })) => Some(index.check_payload_match(
+                    language,
+                    unstemmed,
+                })) => Some(index.check_payload_match(
                     payload_value,
-                    phrase,
-                    PayloadMatchQueryType::Phrase,
+                    text,
+                    PayloadMatchQueryType::Text {
+                        language: *language,
+                        unstemmed: unstemmed.unwrap_or(false),
+                    },
                     hw_counter,
                 )?),
-                Some(Match::TextAny(MatchTextAny { text_any })) => {
+                Some(Match::Phrase(MatchPhrase { phrase, slop })) => {
                     Some(index.check_payload_match(
                         payload_value,
-                        text_any,
-                        PayloadMatchQueryType::TextAny,
+                        phrase,
+                        PayloadMatchQueryType::Phrase {
+                            slop: slop.unwrap_or(0),
+                        },
                         hw_counter,
                     )?)
                 }
+                Some(Match::TextAny(MatchTextAny {
+                    text_any,
+                    min_should_match,
+                })) => Some(index.check_payload_match(
+                    payload_value,
+                    text_any,
+                    PayloadMatchQueryType::TextAny(*min_should_match),
+                    hw_counter,
+                )?),
                 Some(Match::Value(_) | Match::Any(_) | Match::Except(_)) | None => None,
+                Some(Match::ExactSet(_) | Match::KeywordPrefix(_)) => None,
             },
             FieldIndex::UuidIndex(_) => None,
             FieldIndex::UuidMapIndex(_) => None,
@@ -220,6 +267,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(payload_field_index) => payload_field_index,
             FieldIndex::KeywordIndex(payload_field_index) => payload_field_index,
             FieldIndex::FloatIndex(payload_field_index) => payload_field_index.inner(),
+            FieldIndex::ScaledFloatIndex(payload_field_index) => payload_field_index,
             FieldIndex::GeoIndex(payload_field_index) => payload_field_index,
             FieldIndex::BoolIndex(payload_field_index) => payload_field_index,
             FieldIndex::FullTextIndex(payload_field_index) => payload_field_index,
@@ -236,6 +284,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.wipe(),
             FieldIndex::KeywordIndex(index) => index.wipe(),
             FieldIndex::FloatIndex(index) => index.wipe(),
+            FieldIndex::ScaledFloatIndex(index) => index.wipe(),
             FieldIndex::GeoIndex(index) => index.wipe(),
             FieldIndex::BoolIndex(index) => index.wipe(),
             FieldIndex::FullTextIndex(index) => index.wipe(),
@@ -278,6 +327,15 @@ impl FieldIndex {
             .estimate_cardinality(condition, hw_counter)
     }
 
+    pub fn estimate_cardinality_batch(
+        &self,
+        conditions: &[&FieldCondition],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<Option<CardinalityEstimation>>> {
+        self.get_payload_field_index()
+            .estimate_cardinality_batch(conditions, hw_counter)
+    }
+
     pub fn for_each_payload_block(
         &self,
         threshold: usize,
@@ -310,6 +368,9 @@ impl FieldIndex {
             FieldIndex::FloatIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
+            FieldIndex::ScaledFloatIndex(payload_field_index) => {
+                payload_field_index.add_point(id, payload, hw_counter)
+            }
             FieldIndex::GeoIndex(payload_field_index) => {
                 payload_field_index.add_point(id, payload, hw_counter)
             }
@@ -338,6 +399,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.remove_point(point_id),
             FieldIndex::KeywordIndex(index) => index.remove_point(point_id),
             FieldIndex::FloatIndex(index) => index.mut_inner().remove_point(point_id),
+            FieldIndex::ScaledFloatIndex(index) => index.remove_point(point_id),
             FieldIndex::GeoIndex(index) => index.remove_point(point_id),
             FieldIndex::BoolIndex(index) => index.remove_point(point_id),
             FieldIndex::FullTextIndex(index) => index.remove_point(point_id),
@@ -347,19 +409,49 @@ impl FieldIndex {
         }
     }
 
-    pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
+    /// Remove many points at once, which is faster than one by one for some indexes
+    pub fn remove_points(&mut self, point_ids: &[PointOffsetType]) -> OperationResult<()> {
         match self {
+            FieldIndex::IntIndex(index) => index.mut_inner().remove_points(point_ids),
+            FieldIndex::DatetimeIndex(index) => index.mut_inner().remove_points(point_ids),
+            FieldIndex::IntMapIndex(index) => index.remove_points(point_ids),
+            FieldIndex::KeywordIndex(index) => index.remove_points(point_ids),
+            FieldIndex::FloatIndex(index) => index.mut_inner().remove_points(point_ids),
+            FieldIndex::ScaledFloatIndex(index) => index.remove_points(point_ids),
+            FieldIndex::GeoIndex(index) => index.remove_points(point_ids),
+            FieldIndex::BoolIndex(index) => index.remove_points(point_ids),
+            FieldIndex::FullTextIndex(index) => index.remove_points(point_ids),
+            FieldIndex::UuidIndex(index) => index.remove_points(point_ids),
+            FieldIndex::UuidMapIndex(index) => index.remove_points(point_ids),
+            FieldIndex::NullIndex(index) => index.remove_points(point_ids),
+        }
+    }
+
+    /// Whether this is the special null index, which complements every other index
+    pub fn is_null_index(&self) -> bool {
+        matches!(self, FieldIndex::NullIndex(_))
+    }
+
+    /// Memory usage is only reported for segment level details, as it probes the index files.
+    pub fn get_telemetry_data(&self, detail: TelemetryDetail) -> PayloadIndexTelemetry {
+        let telemetry = match self {
             FieldIndex::IntIndex(index) => index.get_telemetry_data(),
             FieldIndex::DatetimeIndex(index) => index.get_telemetry_data(),
             FieldIndex::IntMapIndex(index) => index.get_telemetry_data(),
             FieldIndex::KeywordIndex(index) => index.get_telemetry_data(),
             FieldIndex::FloatIndex(index) => index.get_telemetry_data(),
+            FieldIndex::ScaledFloatIndex(index) => index.get_telemetry_data(),
             FieldIndex::GeoIndex(index) => index.get_telemetry_data(),
             FieldIndex::BoolIndex(index) => index.get_telemetry_data(),
             FieldIndex::FullTextIndex(index) => index.get_telemetry_data(),
             FieldIndex::UuidIndex(index) => index.get_telemetry_data(),
             FieldIndex::UuidMapIndex(index) => index.get_telemetry_data(),
             FieldIndex::NullIndex(index) => index.get_telemetry_data(),
+        };
+        if detail.level >= DetailsLevel::Level4 {
+            telemetry.set_memory_usage_bytes(self.memory_usage_bytes())
+        } else {
+            telemetry
         }
     }
 
@@ -370,6 +462,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.values_count(point_id),
             FieldIndex::KeywordIndex(index) => index.values_count(point_id),
             FieldIndex::FloatIndex(index) => index.values_count(point_id),
+            FieldIndex::ScaledFloatIndex(index) => index.values_count(point_id),
             FieldIndex::GeoIndex(index) => index.values_count(point_id),
             FieldIndex::BoolIndex(index) => index.values_count(point_id),
             FieldIndex::FullTextIndex(index) => index.values_count(point_id),
@@ -386,6 +479,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.values_is_empty(point_id),
             FieldIndex::KeywordIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FloatIndex(index) => index.values_is_empty(point_id),
+            FieldIndex::ScaledFloatIndex(index) => index.values_is_empty(point_id),
             FieldIndex::GeoIndex(index) => index.values_is_empty(point_id),
             FieldIndex::BoolIndex(index) => index.values_is_empty(point_id),
             FieldIndex::FullTextIndex(index) => index.values_is_empty(point_id),
@@ -400,6 +494,7 @@ impl FieldIndex {
             FieldIndex::IntIndex(index) => Some(NumericFieldIndex::IntIndex(index.inner())),
             FieldIndex::DatetimeIndex(index) => Some(NumericFieldIndex::IntIndex(index.inner())),
             FieldIndex::FloatIndex(index) => Some(NumericFieldIndex::FloatIndex(index.inner())),
+            FieldIndex::ScaledFloatIndex(index) => Some(NumericFieldIndex::ScaledFloatIndex(index)),
             FieldIndex::IntMapIndex(_)
             | FieldIndex::KeywordIndex(_)
             | FieldIndex::GeoIndex(_)
@@ -417,11 +512,12 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => Some(FacetIndexEnum::Int(index)),
             FieldIndex::UuidMapIndex(index) => Some(FacetIndexEnum::Uuid(index)),
             FieldIndex::BoolIndex(index) => Some(FacetIndexEnum::Bool(index)),
+            FieldIndex::GeoIndex(index) => index.h3_cells().map(|_| FacetIndexEnum::Geo(index)),
             FieldIndex::UuidIndex(_)
             | FieldIndex::IntIndex(_)
             | FieldIndex::DatetimeIndex(_)
             | FieldIndex::FloatIndex(_)
-            | FieldIndex::GeoIndex(_)
+            | FieldIndex::ScaledFloatIndex(_)
             | FieldIndex::FullTextIndex(_)
             | FieldIndex::NullIndex(_) => None,
         }
@@ -435,6 +531,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.ram_usage_bytes(),
             FieldIndex::KeywordIndex(index) => index.ram_usage_bytes(),
             FieldIndex::FloatIndex(index) => index.inner().ram_usage_bytes(),
+            FieldIndex::ScaledFloatIndex(index) => index.ram_usage_bytes(),
             FieldIndex::GeoIndex(index) => index.ram_usage_bytes(),
             FieldIndex::BoolIndex(index) => index.ram_usage_bytes(),
             FieldIndex::FullTextIndex(index) => index.ram_usage_bytes(),
@@ -444,6 +541,32 @@ impl FieldIndex {
         }
     }
 
+    /// Approximate memory usage in bytes, including the pages of the index files in the page cache.
+    ///
+    /// Pages are counted for all indexes, as indexes loaded into RAM keep their files mapped and
+    /// populated too. Residency is probed on a best-effort basis, files which fail to be probed
+    /// are skipped.
+    pub fn memory_usage_bytes(&self) -> usize {
+        let ram_bytes = self.ram_usage_bytes();
+
+        #[cfg(unix)]
+        let resident_bytes: u64 = self
+            .files()
+            .iter()
+            .filter_map(|file| match MmapFile::probe_memory_stats(file) {
+                Ok((_disk_bytes, resident_bytes)) => Some(resident_bytes),
+                Err(err) => {
+                    log::debug!("Failed to probe memory stats for {}: {err}", file.display());
+                    None
+                }
+            })
+            .sum();
+        #[cfg(not(unix))]
+        let resident_bytes: u64 = 0;
+
+        ram_bytes + resident_bytes as usize
+    }
+
     pub fn is_on_disk(&self) -> bool {
         match self {
             FieldIndex::IntIndex(index) => index.is_on_disk(),
@@ -451,6 +574,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.is_on_disk(),
             FieldIndex::KeywordIndex(index) => index.is_on_disk(),
             FieldIndex::FloatIndex(index) => index.is_on_disk(),
+            FieldIndex::ScaledFloatIndex(index) => index.is_on_disk(),
             FieldIndex::GeoIndex(index) => index.is_on_disk(),
             FieldIndex::BoolIndex(index) => index.is_on_disk(),
             FieldIndex::FullTextIndex(index) => index.is_on_disk(),
@@ -469,6 +593,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.populate(),
             FieldIndex::KeywordIndex(index) => index.populate(),
             FieldIndex::FloatIndex(index) => index.populate(),
+            FieldIndex::ScaledFloatIndex(index) => index.populate(),
             FieldIndex::GeoIndex(index) => index.populate(),
             FieldIndex::BoolIndex(index) => index.populate(),
             FieldIndex::FullTextIndex(index) => index.populate(),
@@ -478,6 +603,25 @@ impl FieldIndex {
         }
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    /// Block until the pages are populated.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        match self {
+            FieldIndex::IntIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::DatetimeIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::IntMapIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::KeywordIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::FloatIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::ScaledFloatIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::GeoIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::FullTextIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::UuidIndex(index) => index.populate_with_budget(budget),
+            FieldIndex::UuidMapIndex(index) => index.populate_with_budget(budget),
+            // Flags are kept in memory
+            FieldIndex::BoolIndex(_) | FieldIndex::NullIndex(_) => Ok(()),
+        }
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
@@ -486,6 +630,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.clear_cache(),
             FieldIndex::KeywordIndex(index) => index.clear_cache(),
             FieldIndex::FloatIndex(index) => index.clear_cache(),
+            FieldIndex::ScaledFloatIndex(index) => index.clear_cache(),
             FieldIndex::GeoIndex(index) => index.clear_cache(),
             FieldIndex::BoolIndex(index) => index.clear_cache(),
             FieldIndex::FullTextIndex(index) => index.clear_cache(),
@@ -502,6 +647,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(_) => PayloadIndexType::IntMapIndex,
             FieldIndex::KeywordIndex(_) => PayloadIndexType::KeywordIndex,
             FieldIndex::FloatIndex(_) => PayloadIndexType::FloatIndex,
+            FieldIndex::ScaledFloatIndex(_) => PayloadIndexType::ScaledFloatIndex,
             FieldIndex::GeoIndex(_) => PayloadIndexType::GeoIndex,
             FieldIndex::FullTextIndex(_) => PayloadIndexType::FullTextIndex,
             FieldIndex::BoolIndex(_) => PayloadIndexType::BoolIndex,
@@ -524,6 +670,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.get_mutability_type(),
             FieldIndex::KeywordIndex(index) => index.get_mutability_type(),
             FieldIndex::FloatIndex(index) => index.get_mutability_type(),
+            FieldIndex::ScaledFloatIndex(index) => index.get_mutability_type(),
             FieldIndex::GeoIndex(index) => index.get_mutability_type(),
             FieldIndex::FullTextIndex(index) => index.get_mutability_type(),
             FieldIndex::BoolIndex(index) => index.get_mutability_type(),
@@ -540,6 +687,7 @@ impl FieldIndex {
             FieldIndex::IntMapIndex(index) => index.get_storage_type(),
             FieldIndex::KeywordIndex(index) => index.get_storage_type(),
             FieldIndex::FloatIndex(index) => index.get_storage_type(),
+            FieldIndex::ScaledFloatIndex(index) => index.get_storage_type(),
             FieldIndex::GeoIndex(index) => index.get_storage_type(),
             FieldIndex::FullTextIndex(index) => index.get_storage_type(),
             FieldIndex::BoolIndex(index) => index.get_storage_type(),
@@ -591,6 +739,8 @@ pub enum FieldIndexBuilder {
     KeywordGridstoreIndex(MapIndexGridstoreBuilder<str>),
     FloatMmapIndex(NumericIndexMmapBuilder<FloatPayloadType, FloatPayloadType>),
     FloatGridstoreIndex(NumericIndexGridstoreBuilder<FloatPayloadType, FloatPayloadType>),
+    ScaledFloatMmapIndex(ScaledFloatIndexMmapBuilder),
+    ScaledFloatGridstoreIndex(ScaledFloatIndexGridstoreBuilder),
     GeoMmapIndex(GeoMapIndexMmapBuilder),
     GeoGridstoreIndex(GeoMapIndexGridstoreBuilder),
     FullTextMmapIndex(FullTextMmapIndexBuilder),
@@ -603,6 +753,16 @@ pub enum FieldIndexBuilder {
     ImmutableNullIndex(ImmutableNullIndexBuilder),
 }
 
+impl FieldIndexBuilder {
+    /// Whether this builds the special null index, which complements every other index
+    pub fn is_null_index(&self) -> bool {
+        matches!(
+            self,
+            Self::MutableNullIndex(_) | Self::ImmutableNullIndex(_)
+        )
+    }
+}
+
 impl FieldIndexBuilderTrait for FieldIndexBuilder {
     type FieldIndexType = FieldIndex;
 
@@ -618,6 +778,8 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::KeywordGridstoreIndex(index) => index.init(),
             Self::FloatMmapIndex(index) => index.init(),
             Self::FloatGridstoreIndex(index) => index.init(),
+            Self::ScaledFloatMmapIndex(index) => index.init(),
+            Self::ScaledFloatGridstoreIndex(index) => index.init(),
             Self::GeoMmapIndex(index) => index.init(),
             Self::GeoGridstoreIndex(index) => index.init(),
             Self::BoolMmapIndex(index) => index.init(),
@@ -648,6 +810,8 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::KeywordGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
             Self::FloatMmapIndex(index) => index.add_point(id, payload, hw_counter),
             Self::FloatGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
+            Self::ScaledFloatMmapIndex(index) => index.add_point(id, payload, hw_counter),
+            Self::ScaledFloatGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
             Self::GeoMmapIndex(index) => index.add_point(id, payload, hw_counter),
             Self::GeoGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
             Self::BoolGridstoreIndex(index) => index.add_point(id, payload, hw_counter),
@@ -677,6 +841,10 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
             Self::KeywordGridstoreIndex(index) => FieldIndex::KeywordIndex(index.finalize()?),
             Self::FloatMmapIndex(index) => FieldIndex::FloatIndex(index.finalize()?),
             Self::FloatGridstoreIndex(index) => FieldIndex::FloatIndex(index.finalize()?),
+            Self::ScaledFloatMmapIndex(index) => FieldIndex::ScaledFloatIndex(index.finalize()?),
+            Self::ScaledFloatGridstoreIndex(index) => {
+                FieldIndex::ScaledFloatIndex(index.finalize()?)
+            }
             Self::GeoMmapIndex(index) => FieldIndex::GeoIndex(index.finalize()?),
             Self::GeoGridstoreIndex(index) => FieldIndex::GeoIndex(index.finalize()?),
             Self::BoolGridstoreIndex(index) => {
@@ -700,6 +868,7 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
 pub enum NumericFieldIndex<'a> {
     IntIndex(&'a NumericIndexInner<IntPayloadType>),
     FloatIndex(&'a NumericIndexInner<FloatPayloadType>),
+    ScaledFloatIndex(&'a ScaledFloatIndex),
 }
 
 impl<'a> StreamRange<OrderValue> for NumericFieldIndex<'a> {
@@ -713,11 +882,16 @@ impl<'a> StreamRange<OrderValue> for NumericFieldIndex<'a> {
                     .stream_range(range)?
                     .map(|(v, p)| (OrderValue::from(v), p)),
             ),
-            NumericFieldIndex::FloatIndex(index) => Either::Right(
+            NumericFieldIndex::FloatIndex(index) => Either::Right(Either::Left(
                 index
                     .stream_range(range)?
                     .map(|(v, p)| (OrderValue::from(v), p)),
-            ),
+            )),
+            NumericFieldIndex::ScaledFloatIndex(index) => Either::Right(Either::Right(
+                index
+                    .stream_range(range)?
+                    .map(|(v, p)| (OrderValue::from(v), p)),
+            )),
         })
     }
 }
@@ -735,15 +909,77 @@ impl<'a> NumericFieldIndex<'a> {
                     .flatten()
                     .map(OrderValue::Int),
             ),
-            NumericFieldIndex::FloatIndex(index) => Either::Right(
+            NumericFieldIndex::FloatIndex(index) => Either::Right(Either::Left(
                 index
                     .get_values(idx)
                     .into_iter()
                     .flatten()
                     .map(OrderValue::Float),
-            ),
+            )),
+            NumericFieldIndex::ScaledFloatIndex(index) => Either::Right(Either::Right(
+                index
+                    .get_values(idx)
+                    .into_iter()
+                    .flatten()
+                    .map(OrderValue::Float),
+            )),
         }
     }
+
+    /// Min, max, sum and count of the values of the points in `ids`, or of all indexed points if
+    /// `ids` is not given.
+    pub fn aggregate(&self, ids: Option<&BitSlice>) -> OperationResult<NumericAggregate> {
+        let mut aggregate = NumericAggregate::default();
+        match ids {
+            Some(ids) => {
+                for idx in ids.iter_ones() {
+                    for value in self.get_ordering_values(idx as PointOffsetType) {
+                        aggregate.add(value);
+                    }
+                }
+            }
+            None => {
+                // Values are streamed in sorted order, so min and max are at the ends
+                let full_range = RangeInterface::Float(Range::default());
+                let mut values = StreamRange::stream_range(self, &full_range)?.map(|(v, _)| v);
+                let first = values.next();
+                let last = values.next_back();
+                aggregate.min = first;
+                aggregate.max = last.or(first);
+                for value in first.into_iter().chain(values).chain(last) {
+                    aggregate.sum += value.to_f64();
+                    aggregate.count += 1;
+                }
+            }
+        }
+        Ok(aggregate)
+    }
+}
+
+/// Aggregate of the values in a numeric index
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NumericAggregate {
+    /// `None` if there are no values
+    pub min: Option<OrderValue>,
+    /// `None` if there are no values
+    pub max: Option<OrderValue>,
+    pub sum: f64,
+    /// Number of values, points with multiple values are counted once per value
+    pub count: usize,
+}
+
+impl NumericAggregate {
+    fn add(&mut self, value: OrderValue) {
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        self.sum += value.to_f64();
+        self.count += 1;
+    }
+
+    /// Average of the values, `None` if there are no values
+    pub fn avg(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
 }
 
 /// Read-only abstraction over a per-key numeric index.
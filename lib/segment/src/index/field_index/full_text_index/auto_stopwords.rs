@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use ahash::{AHashMap, AHashSet};
+
+use super::inverted_index::{ARRAY_BOUNDARY_SENTINEL, InvertedIndex};
+use crate::common::operation_error::OperationResult;
+
+/// Tokens contained in a large share of the points of the shard, removed from text queries.
+///
+/// The index doesn't detect them itself. The shard detects the stopwords from the frequencies of
+/// the tokens in all of its segments, and assigns the same set to the index of every segment, so
+/// a query token is either removed in all segments or in none of them. Queries hold the index for
+/// reading, so the stopwords never change within a query.
+#[derive(Debug, Default)]
+pub struct AutoStopwords {
+    stopwords: Arc<AHashSet<String>>,
+}
+
+impl AutoStopwords {
+    pub fn set(&mut self, stopwords: Arc<AHashSet<String>>) {
+        self.stopwords = stopwords;
+    }
+
+    /// Remove the stopwords from the tokens of a query
+    pub fn remove_from<V>(&self, tokens: &mut AHashMap<Cow<'_, str>, V>) {
+        if !self.stopwords.is_empty() {
+            tokens.retain(|token, _| !self.stopwords.contains(token.as_ref()));
+        }
+    }
+
+    pub fn contains(&self, token: &str) -> bool {
+        self.stopwords.contains(token)
+    }
+
+    /// Currently assigned stopwords, sorted
+    pub fn stopwords(&self) -> Vec<String> {
+        let mut stopwords: Vec<_> = self.stopwords.iter().cloned().collect();
+        stopwords.sort_unstable();
+        stopwords
+    }
+}
+
+/// Tokens of the `index` contained in at least `min_fraction` of its points, the candidates for
+/// stopwords of the shard.
+pub fn frequent_tokens(
+    index: &impl InvertedIndex,
+    min_fraction: f64,
+) -> OperationResult<Vec<String>> {
+    let points_count = index.points_count();
+    if points_count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let min_postings_len = (points_count as f64 * min_fraction).ceil() as usize;
+    let mut tokens = Vec::new();
+    for item in index.vocab_with_postings_len_iter() {
+        let (token, postings_len) = item?;
+        if postings_len >= min_postings_len && token != ARRAY_BOUNDARY_SENTINEL {
+            tokens.push(token.to_string());
+        }
+    }
+    Ok(tokens)
+}
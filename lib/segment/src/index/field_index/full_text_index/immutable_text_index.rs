@@ -1,7 +1,9 @@
 use std::path::PathBuf;
 
+use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 
+use super::auto_stopwords::AutoStopwords;
 use super::inverted_index::InvertedIndex;
 use super::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
 use super::mmap_text_index::MmapFullTextIndex;
@@ -11,6 +13,7 @@ use crate::index::payload_config::StorageType;
 
 pub struct ImmutableFullTextIndex {
     pub(super) inverted_index: ImmutableInvertedIndex,
+    pub(super) auto_stopwords: AutoStopwords,
     // Backing storage, source of state, persists deletions
     pub(super) storage: Storage,
     /// Snapshot of approximate RAM usage at construction time.
@@ -24,8 +27,10 @@ pub(super) enum Storage {
 
 impl ImmutableFullTextIndex {
     /// Open and load immutable full text index from mmap storage
-    pub fn open_mmap(index: MmapFullTextIndex) -> OperationResult<Self> {
+    pub fn open_mmap(mut index: MmapFullTextIndex) -> OperationResult<Self> {
         let inverted_index = ImmutableInvertedIndex::try_from(&index.inverted_index)?;
+        // Keep the stopwords assigned to the mmap index
+        let auto_stopwords = std::mem::take(&mut index.auto_stopwords);
 
         // Index is now loaded into memory, clear cache of backing mmap storage
         if let Err(err) = index.clear_cache() {
@@ -34,6 +39,7 @@ impl ImmutableFullTextIndex {
 
         let mut result = Self {
             inverted_index,
+            auto_stopwords,
             storage: Storage::Mmap(Box::new(index)),
             cached_ram_usage_bytes: 0,
         };
@@ -98,6 +104,18 @@ impl ImmutableFullTextIndex {
         }
     }
 
+    /// Tokens stored for the point in the forward index of the backing storage, `None` if it has
+    /// no forward index. The in-memory index doesn't keep them, they are not used by queries.
+    pub fn point_tokens(
+        &self,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<&str>>> {
+        match &self.storage {
+            Storage::Mmap(index) => index.inverted_index.point_tokens(point_id, hw_counter),
+        }
+    }
+
     /// Approximate RAM usage in bytes (cached at construction).
     pub fn ram_usage_bytes(&self) -> usize {
         self.cached_ram_usage_bytes
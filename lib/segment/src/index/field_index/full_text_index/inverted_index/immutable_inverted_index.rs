@@ -16,7 +16,7 @@ use super::positions::Positions;
 use super::postings_iterator::{
     intersect_compressed_postings_iterator, merge_compressed_postings_iterator,
 };
-use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
+use super::{Document, InvertedIndex, ParsedQuery, PhraseQuery, TokenId, TokenSet};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::full_text_index::inverted_index::postings_iterator::{
     check_compressed_postings_phrase, intersect_compressed_postings_phrase_iterator,
@@ -94,10 +94,11 @@ impl ImmutableInvertedIndex {
         }
     }
 
-    /// Iterate over point ids whose documents contain at least one of the given tokens
+    /// Iterate over point ids whose documents contain at least `min_match` of the given tokens
     fn filter_has_any<'a>(
         &'a self,
         tokens: TokenSet,
+        min_match: usize,
     ) -> impl Iterator<Item = PointOffsetType> + 'a {
         // in case of immutable index, deleted documents are still in the postings
         let is_active = move |idx| {
@@ -109,6 +110,7 @@ impl ImmutableInvertedIndex {
         fn merge<'a, V: PostingValue>(
             postings: &'a [PostingList<V>],
             tokens: TokenSet,
+            min_match: usize,
             is_active: impl Fn(PointOffsetType) -> bool + 'a,
         ) -> impl Iterator<Item = PointOffsetType> + 'a {
             let postings: Vec<_> = tokens
@@ -117,18 +119,22 @@ impl ImmutableInvertedIndex {
                 .filter_map(|&token_id| postings.get(token_id as usize).map(PostingList::view))
                 .collect();
 
-            // Query must not be empty
-            if postings.is_empty() {
+            // Query must not be empty, and must have enough known tokens
+            if postings.is_empty() || postings.len() < min_match {
                 return Either::Left(std::iter::empty());
             };
 
-            Either::Right(merge_compressed_postings_iterator(postings, is_active))
+            Either::Right(merge_compressed_postings_iterator(
+                postings, min_match, is_active,
+            ))
         }
 
         match &self.postings {
-            ImmutablePostings::Ids(postings) => Either::Left(merge(postings, tokens, is_active)),
+            ImmutablePostings::Ids(postings) => {
+                Either::Left(merge(postings, tokens, min_match, is_active))
+            }
             ImmutablePostings::WithPositions(postings) => {
-                Either::Right(merge(postings, tokens, is_active))
+                Either::Right(merge(postings, tokens, min_match, is_active))
             }
         }
     }
@@ -163,7 +169,12 @@ impl ImmutableInvertedIndex {
         }
     }
 
-    fn check_has_any(&self, tokens: &TokenSet, point_id: PointOffsetType) -> bool {
+    fn check_has_any(
+        &self,
+        tokens: &TokenSet,
+        min_match: usize,
+        point_id: PointOffsetType,
+    ) -> bool {
         if tokens.is_empty() {
             return false;
         }
@@ -176,25 +187,34 @@ impl ImmutableInvertedIndex {
         fn check_any<V: PostingValue>(
             postings: &[PostingList<V>],
             tokens: &TokenSet,
+            min_match: usize,
             point_id: PointOffsetType,
         ) -> bool {
-            // Check that at least one token is in document
-            tokens.tokens().iter().any(|token_id| {
-                let posting_list = &postings[*token_id as usize];
-                posting_list.visitor().contains(point_id)
-            })
+            // Check that at least `min_match` tokens are in document
+            tokens
+                .tokens()
+                .iter()
+                .filter(|token_id| {
+                    let posting_list = &postings[**token_id as usize];
+                    posting_list.visitor().contains(point_id)
+                })
+                .take(min_match)
+                .count()
+                >= min_match
         }
 
         match &self.postings {
-            ImmutablePostings::Ids(postings) => check_any(postings, tokens, point_id),
-            ImmutablePostings::WithPositions(postings) => check_any(postings, tokens, point_id),
+            ImmutablePostings::Ids(postings) => check_any(postings, tokens, min_match, point_id),
+            ImmutablePostings::WithPositions(postings) => {
+                check_any(postings, tokens, min_match, point_id)
+            }
         }
     }
 
     /// Iterate over point ids whose documents contain all given tokens in the same order they are provided
     pub fn filter_has_phrase<'a>(
         &'a self,
-        phrase: Document,
+        phrase: PhraseQuery,
     ) -> impl Iterator<Item = PointOffsetType> + 'a {
         // in case of mmap immutable index, deleted points are still in the postings
         let is_active = move |idx| {
@@ -208,7 +228,8 @@ impl ImmutableInvertedIndex {
                 // Deduplicate phrase tokens: repeated tokens (e.g. "zn zn") must
                 // not fetch the same posting list twice, otherwise positions get
                 // added twice in `phrase_in_all_postings`.
-                let unique_tokens = phrase.to_token_set();
+                // The boundary sentinel is fetched as well, if the phrase has a slop.
+                let unique_tokens = phrase.posting_tokens();
                 if let Some(selected_postings) = get_all_or_none(postings, unique_tokens.tokens()) {
                     Either::Right(intersect_compressed_postings_phrase_iterator(
                         phrase,
@@ -225,7 +246,7 @@ impl ImmutableInvertedIndex {
     }
 
     /// Checks if the point document contains all given tokens in the same order they are provided
-    pub fn check_has_phrase(&self, phrase: &Document, point_id: PointOffsetType) -> bool {
+    pub fn check_has_phrase(&self, phrase: &PhraseQuery, point_id: PointOffsetType) -> bool {
         // in case of mmap immutable index, deleted points are still in the postings
         if self
             .point_to_tokens_count
@@ -237,7 +258,7 @@ impl ImmutableInvertedIndex {
 
         match &self.postings {
             ImmutablePostings::WithPositions(postings) => {
-                let unique_tokens = phrase.to_token_set();
+                let unique_tokens = phrase.posting_tokens();
                 let Some(selected_postings) = get_all_or_none(postings, unique_tokens.tokens())
                 else {
                     return false;
@@ -295,7 +316,10 @@ impl InvertedIndex for ImmutableInvertedIndex {
         match query {
             ParsedQuery::AllTokens(tokens) => Ok(Box::new(self.filter_has_all(tokens))),
             ParsedQuery::Phrase(tokens) => Ok(Box::new(self.filter_has_phrase(tokens))),
-            ParsedQuery::AnyTokens(tokens) => Ok(Box::new(self.filter_has_any(tokens))),
+            ParsedQuery::AnyTokens(tokens) => Ok(Box::new(self.filter_has_any(tokens, 1))),
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                Ok(Box::new(self.filter_has_any(tokens, min_match)))
+            }
         }
     }
 
@@ -325,7 +349,10 @@ impl InvertedIndex for ImmutableInvertedIndex {
         let matched = match parsed_query {
             ParsedQuery::AllTokens(tokens) => self.check_has_subset(tokens, point_id),
             ParsedQuery::Phrase(phrase) => self.check_has_phrase(phrase, point_id),
-            ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, point_id),
+            ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, 1, point_id),
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                self.check_has_any(tokens, *min_match, point_id)
+            }
         };
         Ok(matched)
     }
@@ -365,6 +392,7 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
             vocab,
             point_to_tokens,
             point_to_doc,
+            id_to_token: _,
             points_count,
         } = index;
 
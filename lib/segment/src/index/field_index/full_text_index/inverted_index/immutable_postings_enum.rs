@@ -1,4 +1,3 @@
-#[cfg(test)]
 use common::types::PointOffsetType;
 use posting_list::PostingList;
 
@@ -45,7 +44,6 @@ impl ImmutablePostings {
         }
     }
 
-    #[cfg(test)]
     pub fn iter_ids(
         &self,
         token_id: TokenId,
@@ -0,0 +1,92 @@
+use std::borrow::Cow;
+use std::io;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::index::TextIndexCompression;
+
+/// Zstd compression level for posting lists.
+/// Higher levels barely improve the ratio of bit-packed postings, but slow down the build.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Compression of individual posting lists in the postings file.
+///
+/// Stored as a single byte in the [`PostingsHeader`](super::types::PostingsHeader), files
+/// written before compression was introduced have zeros there and read as [`Self::None`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PostingsCompression {
+    #[default]
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl PostingsCompression {
+    pub fn from_code(code: u8) -> OperationResult<Self> {
+        match code {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Lz4),
+            2 => Ok(Self::Zstd),
+            _ => Err(OperationError::service_error(format!(
+                "Unknown posting list compression: {code}"
+            ))),
+        }
+    }
+
+    pub fn code(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Lz4 => 1,
+            Self::Zstd => 2,
+        }
+    }
+
+    pub fn is_compressed(self) -> bool {
+        self != Self::None
+    }
+
+    pub fn compress(self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Lz4 => Ok(lz4_flex::compress(data)),
+            Self::Zstd => zstd::bulk::compress(data, ZSTD_LEVEL),
+        }
+    }
+
+    /// Decompress a posting list, which is expected to be `decompressed_len` bytes long.
+    ///
+    /// Uncompressed data is passed through as is, without copying.
+    pub fn decompress<'a>(
+        self,
+        data: Cow<'a, [u8]>,
+        decompressed_len: usize,
+    ) -> OperationResult<Cow<'a, [u8]>> {
+        let decompressed = match self {
+            Self::None => return Ok(data),
+            Self::Lz4 => lz4_flex::decompress(&data, decompressed_len).map_err(|err| {
+                OperationError::service_error(format!("Failed to decompress posting list: {err}"))
+            })?,
+            Self::Zstd => zstd::bulk::decompress(&data, decompressed_len).map_err(|err| {
+                OperationError::service_error(format!("Failed to decompress posting list: {err}"))
+            })?,
+        };
+
+        if decompressed.len() != decompressed_len {
+            return Err(OperationError::service_error(format!(
+                "Decompressed posting list has {} bytes, expected {decompressed_len}",
+                decompressed.len(),
+            )));
+        }
+
+        Ok(Cow::Owned(decompressed))
+    }
+}
+
+impl From<Option<TextIndexCompression>> for PostingsCompression {
+    fn from(compression: Option<TextIndexCompression>) -> Self {
+        match compression {
+            None => Self::None,
+            Some(TextIndexCompression::Lz4) => Self::Lz4,
+            Some(TextIndexCompression::Zstd) => Self::Zstd,
+        }
+    }
+}
@@ -7,6 +7,7 @@ use fs_err::File;
 use posting_list::{PostingList, PostingListComponents};
 use zerocopy::IntoBytes;
 
+use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::compression::PostingsCompression;
 use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::types::{
     ALIGNMENT, PostingListHeader, PostingsHeader, ZerocopyPostingValue,
 };
@@ -20,8 +21,9 @@ use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_in
 /// offset 0
 /// ┌────────────────────────────────────────────────┐
 /// │ PostingsHeader                                 │  size_of::<PostingsHeader>()
-/// │   posting_count: usize    (one per term)       │  = 8 + 32 = 40 bytes
-/// │   _reserved: [u8; 32]                          │
+/// │   posting_count: usize    (one per term)       │  = 8 + 1 + 31 = 40 bytes
+/// │   compression: u8         (0 = none)           │
+/// │   _reserved: [u8; 31]                          │
 /// ├────────────────────────────────────────────────┤
 /// │ PostingListHeader[0]   ── token_id 0           │  each
 /// │ PostingListHeader[1]   ── token_id 1           │  size_of::<PostingListHeader>()
@@ -71,9 +73,17 @@ use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_in
 /// - `V = Positions` — phrase index; per-doc token positions live in
 ///   `var_size_data`, with each `PostingChunk` / `RemainderPosting` carrying
 ///   a `Sized` handle (offset/len) into that blob.
+///
+/// With `compression` enabled, every `CompressedPostingList` is compressed as a
+/// whole, and `PostingsHeader.compression` records the algorithm. Header fields
+/// keep describing the decompressed layout, while `header.offset` points to the
+/// compressed bytes. One extra `PostingListHeader[N]` follows the per-token
+/// headers, its `offset` marks the end of the last compressed posting list, so
+/// the stored size of posting `i` is `header[i + 1].offset - header[i].offset`.
 pub fn create_postings_file<V: ZerocopyPostingValue>(
     path: PathBuf,
     compressed_postings: &[PostingList<V>],
+    compression: PostingsCompression,
 ) -> io::Result<()> {
     // Create a new empty file, where we will write the compressed posting lists and the header
     let (file, temp_path) = tempfile::Builder::new()
@@ -83,17 +93,35 @@ pub fn create_postings_file<V: ZerocopyPostingValue>(
     let file = File::from_parts::<&Path>(file, temp_path.as_ref());
     let mut bufw = io::BufWriter::new(&file);
 
+    // Block-compressed posting lists have to be prepared upfront, headers need their sizes
+    let compressed_blocks = compression
+        .is_compressed()
+        .then(|| {
+            compressed_postings
+                .iter()
+                .map(|compressed_posting| {
+                    let mut block = Vec::new();
+                    write_posting(&mut block, compressed_posting)?;
+                    compression.compress(&block)
+                })
+                .collect::<io::Result<Vec<_>>>()
+        })
+        .transpose()?;
+
     let postings_header = PostingsHeader {
         posting_count: compressed_postings.len(),
-        _reserved: [0; 32],
+        compression: compression.code(),
+        _reserved: [0; 31],
     };
 
     bufw.write_all(postings_header.as_bytes())?;
 
-    let postings_lists_headers_size = compressed_postings.len() * size_of::<PostingListHeader>();
+    // Compressed files have an extra header, which marks the end of the last posting list
+    let headers_count = compressed_postings.len() + usize::from(compressed_blocks.is_some());
+    let postings_lists_headers_size = headers_count * size_of::<PostingListHeader>();
     let mut posting_offset = size_of::<PostingsHeader>() + postings_lists_headers_size;
 
-    for compressed_posting in compressed_postings {
+    for (i, compressed_posting) in compressed_postings.iter().enumerate() {
         let view = compressed_posting.view();
         let PostingListComponents {
             id_data,
@@ -120,41 +148,28 @@ pub fn create_postings_file<V: ZerocopyPostingValue>(
 
         bufw.write_all(posting_list_header.as_bytes())?;
 
-        posting_offset += posting_list_header.posting_size::<V>();
+        posting_offset += match &compressed_blocks {
+            Some(blocks) => blocks[i].len(),
+            None => posting_list_header.posting_size::<V>(),
+        };
     }
 
-    for compressed_posting in compressed_postings {
-        let view = compressed_posting.view();
-        let PostingListComponents {
-            id_data,
-            chunks,
-            var_size_data,
-            remainders,
-            last_id,
-        } = view.components();
-
-        bufw.write_all(
-            last_id
-                .expect("posting must have at least one element")
-                .as_bytes(),
-        )?;
-
-        for chunk in chunks {
-            bufw.write_all(chunk.as_bytes())?;
+    match compressed_blocks {
+        Some(blocks) => {
+            let end_header = PostingListHeader {
+                offset: posting_offset as u64,
+                ..Default::default()
+            };
+            bufw.write_all(end_header.as_bytes())?;
+
+            for block in blocks {
+                bufw.write_all(&block)?;
+            }
         }
-
-        bufw.write_all(id_data)?;
-
-        if !var_size_data.is_empty() {
-            bufw.write_all(var_size_data)?;
-        }
-
-        // alignment padding so the next `RemainderPosting` is 4-byte aligned
-        let data_len = id_data.len() + var_size_data.len();
-        bufw.write_zeros(data_len.next_multiple_of(ALIGNMENT) - data_len)?;
-
-        for element in remainders {
-            bufw.write_all(element.as_bytes())?;
+        None => {
+            for compressed_posting in compressed_postings {
+                write_posting(&mut bufw, compressed_posting)?;
+            }
         }
     }
 
@@ -167,3 +182,44 @@ pub fn create_postings_file<V: ZerocopyPostingValue>(
 
     Ok(())
 }
+
+/// Write a single `CompressedPostingList` in the layout documented in [`create_postings_file`].
+fn write_posting<V: ZerocopyPostingValue>(
+    writer: &mut impl Write,
+    compressed_posting: &PostingList<V>,
+) -> io::Result<()> {
+    let view = compressed_posting.view();
+    let PostingListComponents {
+        id_data,
+        chunks,
+        var_size_data,
+        remainders,
+        last_id,
+    } = view.components();
+
+    writer.write_all(
+        last_id
+            .expect("posting must have at least one element")
+            .as_bytes(),
+    )?;
+
+    for chunk in chunks {
+        writer.write_all(chunk.as_bytes())?;
+    }
+
+    writer.write_all(id_data)?;
+
+    if !var_size_data.is_empty() {
+        writer.write_all(var_size_data)?;
+    }
+
+    // alignment padding so the next `RemainderPosting` is 4-byte aligned
+    let data_len = id_data.len() + var_size_data.len();
+    writer.write_zeros(data_len.next_multiple_of(ALIGNMENT) - data_len)?;
+
+    for element in remainders {
+        writer.write_all(element.as_bytes())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+use std::path::{Path, PathBuf};
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::mmap::{self, AdviceSetting, MmapSlice};
+use common::types::PointOffsetType;
+use itertools::Itertools;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::index::field_index::full_text_index::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
+use crate::index::field_index::full_text_index::inverted_index::{
+    ARRAY_BOUNDARY_SENTINEL, TokenId,
+};
+
+const POINT_TOKENS_OFFSETS_FILE: &str = "point_tokens_offsets.dat";
+const POINT_TOKENS_FILE: &str = "point_tokens.dat";
+const TOKENS_OFFSETS_FILE: &str = "tokens_offsets.dat";
+const TOKENS_FILE: &str = "tokens.dat";
+
+/// Opt-in forward index of the mmap inverted index: sorted token ids of every point, and the
+/// tokens by their ids.
+///
+/// Queries don't use it, so it is never populated, and all reads are counted as disk IO.
+pub(super) struct MmapForwardIndex {
+    /// Start of the ids of every point in `point_tokens`, followed by the end of the last point
+    point_tokens_offsets: MmapSlice<u64>,
+    point_tokens: MmapSlice<TokenId>,
+    /// Start of every token in `tokens`, by token id, followed by the end of the last token
+    tokens_offsets: MmapSlice<u64>,
+    tokens: MmapSlice<u8>,
+}
+
+impl MmapForwardIndex {
+    pub fn create(path: &Path, inverted_index: &ImmutableInvertedIndex) -> OperationResult<()> {
+        let ImmutableInvertedIndex {
+            postings,
+            vocab,
+            point_to_tokens_count,
+            points_count: _,
+        } = inverted_index;
+
+        // Posting lists are visited in the order of token ids, so tokens of every point are sorted
+        let mut point_tokens = vec![Vec::new(); point_to_tokens_count.len()];
+        for token_id in 0..postings.len() as TokenId {
+            for point_id in postings.iter_ids(token_id).into_iter().flatten() {
+                if let Some(tokens) = point_tokens.get_mut(point_id as usize) {
+                    tokens.push(token_id);
+                }
+            }
+        }
+
+        create_offsets(
+            &path.join(POINT_TOKENS_OFFSETS_FILE),
+            point_tokens.iter().map(Vec::len),
+        )?;
+        MmapSlice::create(
+            &path.join(POINT_TOKENS_FILE),
+            point_tokens.into_iter().flatten().collect_vec().into_iter(),
+        )?;
+
+        // Token ids of the immutable index are dense
+        let mut tokens = vec![""; vocab.len()];
+        for (token, &token_id) in vocab {
+            tokens[token_id as usize] = token.as_str();
+        }
+        create_offsets(
+            &path.join(TOKENS_OFFSETS_FILE),
+            tokens.iter().map(|t| t.len()),
+        )?;
+        MmapSlice::create(
+            &path.join(TOKENS_FILE),
+            tokens
+                .iter()
+                .flat_map(|t| t.bytes())
+                .collect_vec()
+                .into_iter(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Open the forward index, `None` if it was not created with the inverted index
+    pub fn open(path: &Path) -> OperationResult<Option<Self>> {
+        if !path.join(POINT_TOKENS_FILE).is_file() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            point_tokens_offsets: open_slice(&path.join(POINT_TOKENS_OFFSETS_FILE))?,
+            point_tokens: open_slice(&path.join(POINT_TOKENS_FILE))?,
+            tokens_offsets: open_slice(&path.join(TOKENS_OFFSETS_FILE))?,
+            tokens: open_slice(&path.join(TOKENS_FILE))?,
+        }))
+    }
+
+    pub fn files(path: &Path) -> Vec<PathBuf> {
+        vec![
+            path.join(POINT_TOKENS_OFFSETS_FILE),
+            path.join(POINT_TOKENS_FILE),
+            path.join(TOKENS_OFFSETS_FILE),
+            path.join(TOKENS_FILE),
+        ]
+    }
+
+    /// Tokens of the point, in the index order, without the array boundary sentinel
+    pub fn point_tokens(
+        &self,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<&str>> {
+        let io_counter = hw_counter.payload_index_io_read_counter();
+
+        let Some(range) = offsets_range(&self.point_tokens_offsets, point_id as usize) else {
+            return Ok(Vec::new());
+        };
+        io_counter.incr_delta(2 * size_of::<u64>());
+        let token_ids = self.point_tokens.get(range).ok_or_else(|| {
+            OperationError::service_error("Forward index of the text index is corrupted")
+        })?;
+        io_counter.incr_delta(size_of_val(token_ids));
+
+        token_ids
+            .iter()
+            .map(|&token_id| {
+                let token = self.token(token_id)?;
+                io_counter.incr_delta(2 * size_of::<u64>() + token.len());
+                Ok(token)
+            })
+            .filter_ok(|token| *token != ARRAY_BOUNDARY_SENTINEL)
+            .collect()
+    }
+
+    fn token(&self, token_id: TokenId) -> OperationResult<&str> {
+        let bytes = offsets_range(&self.tokens_offsets, token_id as usize)
+            .and_then(|range| self.tokens.get(range))
+            .ok_or_else(|| {
+                OperationError::service_error(format!(
+                    "Token {token_id} is missing in the forward index of the text index",
+                ))
+            })?;
+        std::str::from_utf8(bytes).map_err(|err| {
+            OperationError::service_error(format!(
+                "Token {token_id} in the forward index of the text index is not valid UTF-8: {err}",
+            ))
+        })
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        let Self {
+            point_tokens_offsets,
+            point_tokens,
+            tokens_offsets,
+            tokens,
+        } = self;
+        point_tokens_offsets.clear_cache()?;
+        point_tokens.clear_cache()?;
+        tokens_offsets.clear_cache()?;
+        tokens.clear_cache()?;
+        Ok(())
+    }
+}
+
+/// Write the starts of the items of `lengths`, followed by the end of the last item
+fn create_offsets(path: &Path, lengths: impl Iterator<Item = usize>) -> OperationResult<()> {
+    let offsets = std::iter::once(0)
+        .chain(lengths.scan(0u64, |offset, len| {
+            *offset += len as u64;
+            Some(*offset)
+        }))
+        .collect_vec();
+    MmapSlice::create(path, offsets.into_iter())?;
+    Ok(())
+}
+
+fn offsets_range(offsets: &[u64], idx: usize) -> Option<std::ops::Range<usize>> {
+    let start = *offsets.get(idx)?;
+    let end = *offsets.get(idx + 1)?;
+    Some(start as usize..end as usize)
+}
+
+fn open_slice<T>(path: &Path) -> OperationResult<MmapSlice<T>> {
+    let slice =
+        unsafe { MmapSlice::try_from(mmap::open_write_mmap(path, AdviceSetting::Global, false)?)? };
+    Ok(slice)
+}
@@ -1,3 +1,4 @@
+use common::populate_budget::PopulateBudget;
 #[cfg(test)]
 use common::types::PointOffsetType;
 use common::universal_io::MmapFile;
@@ -20,6 +21,13 @@ impl MmapPostingsEnum {
         }
     }
 
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        match self {
+            MmapPostingsEnum::Ids(postings) => postings.populate_with_budget(budget),
+            MmapPostingsEnum::WithPositions(postings) => postings.populate_with_budget(budget),
+        }
+    }
+
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
             MmapPostingsEnum::Ids(postings) => postings.clear_cache(),
@@ -7,13 +7,16 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::fs::clear_disk_cache;
 use common::mmap::{self, Advice, AdviceSetting, MmapSlice, create_and_ensure_length};
 use common::persisted_hashmap::{MmapHashMap, READ_ENTRY_OVERHEAD, serialize_hashmap};
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::stored_bitslice::MmapBitSlice;
 use common::types::PointOffsetType;
 use common::universal_io::{MmapFile, OpenOptions};
 use types::ZerocopyPostingValue;
 use uio_postings::UniversalPostings;
 
+use self::compression::PostingsCompression;
 use self::create_postings::create_postings_file;
+use self::forward_index::MmapForwardIndex;
 use super::immutable_inverted_index::ImmutableInvertedIndex;
 use super::immutable_postings_enum::ImmutablePostings;
 use super::mmap_inverted_index::mmap_postings_enum::MmapPostingsEnum;
@@ -21,15 +24,18 @@ use super::positions::Positions;
 use super::postings_iterator::{
     intersect_compressed_postings_iterator, merge_compressed_postings_iterator,
 };
-use super::{InvertedIndex, ParsedQuery, TokenId, TokenSet};
+use super::{InvertedIndex, ParsedQuery, PhraseQuery, TokenId, TokenSet};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::index::TextIndexCompression;
 use crate::index::field_index::full_text_index::inverted_index::Document;
 use crate::index::field_index::full_text_index::inverted_index::postings_iterator::{
     check_compressed_postings_phrase, intersect_compressed_postings_phrase_iterator,
 };
 
+mod compression;
 mod create_postings;
+mod forward_index;
 pub mod mmap_postings_enum;
 mod raw_posting_list;
 pub(in crate::index::field_index::full_text_index) mod types;
@@ -43,9 +49,9 @@ const DELETED_POINTS_FILE: &str = "deleted_points.dat";
 /// Mmap-backed immutable full-text inverted index.
 ///
 /// On-disk state (`postings.dat`, `vocab.dat`, `point_to_tokens_count.dat`,
-/// `deleted_points.dat`) is written once during [`Self::create`] and not
-/// mutated afterwards: `deleted_points.dat` records only the points whose
-/// document was empty at build time.
+/// `deleted_points.dat`, and the files of the opt-in forward index) is written
+/// once during [`Self::create`] and not mutated afterwards: `deleted_points.dat`
+/// records only the points whose document was empty at build time.
 ///
 /// Runtime deletions live in the in-memory `Storage::deleted_points` bitvec.
 /// They are **not persisted** — [`Self::flusher`] is a no-op and [`Self::remove`]
@@ -65,6 +71,8 @@ pub(in crate::index::field_index::full_text_index) struct Storage {
     pub(in crate::index::field_index::full_text_index) vocab: MmapHashMap<str, TokenId>,
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: MmapSlice<usize>,
     pub(in crate::index::field_index::full_text_index) deleted_points: BitVec,
+    /// Tokens of every point, only if enabled in the index params
+    forward_index: Option<MmapForwardIndex>,
 }
 
 impl Storage {
@@ -74,6 +82,7 @@ impl Storage {
             vocab: _,
             point_to_tokens_count: _,
             deleted_points,
+            forward_index: _,
         } = self;
 
         deleted_points.capacity().div_ceil(u8::BITS as usize)
@@ -81,7 +90,12 @@ impl Storage {
 }
 
 impl MmapInvertedIndex {
-    pub fn create(path: PathBuf, inverted_index: &ImmutableInvertedIndex) -> OperationResult<()> {
+    pub fn create(
+        path: PathBuf,
+        inverted_index: &ImmutableInvertedIndex,
+        compression: Option<TextIndexCompression>,
+        forward_index: bool,
+    ) -> OperationResult<()> {
         let ImmutableInvertedIndex {
             postings,
             vocab,
@@ -96,10 +110,13 @@ impl MmapInvertedIndex {
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
 
+        let compression = PostingsCompression::from(compression);
         match postings {
-            ImmutablePostings::Ids(postings) => create_postings_file(postings_path, postings)?,
+            ImmutablePostings::Ids(postings) => {
+                create_postings_file(postings_path, postings, compression)?
+            }
             ImmutablePostings::WithPositions(postings) => {
-                create_postings_file(postings_path, postings)?
+                create_postings_file(postings_path, postings, compression)?
             }
         }
 
@@ -137,6 +154,10 @@ impl MmapInvertedIndex {
 
         MmapSlice::create(&point_to_tokens_count_path, point_to_tokens_count_iter)?;
 
+        if forward_index {
+            MmapForwardIndex::create(&path, inverted_index)?;
+        }
+
         Ok(())
     }
 
@@ -203,6 +224,8 @@ impl MmapInvertedIndex {
         let num_deleted_points = deleted.count_ones();
         let points_count = point_to_tokens_count.len() - num_deleted_points;
 
+        let forward_index = MmapForwardIndex::open(&path)?;
+
         Ok(Some(Self {
             path,
             storage: Storage {
@@ -210,6 +233,7 @@ impl MmapInvertedIndex {
                 vocab,
                 point_to_tokens_count,
                 deleted_points: deleted,
+                forward_index,
             },
             active_points_count: points_count,
             is_on_disk: !populate,
@@ -269,31 +293,40 @@ impl MmapInvertedIndex {
         }
     }
 
-    /// Iterate over point ids whose documents contain at least one of the given tokens
-    fn filter_has_any(&self, tokens: TokenSet) -> OperationResult<Vec<PointOffsetType>> {
+    /// Iterate over point ids whose documents contain at least `min_match` of the given tokens
+    fn filter_has_any(
+        &self,
+        tokens: TokenSet,
+        min_match: usize,
+    ) -> OperationResult<Vec<PointOffsetType>> {
         // in case of immutable index, deleted documents are still in the postings
         let is_active = move |idx| self.is_active(idx);
 
         fn merge<V: ZerocopyPostingValue>(
             postings: &UniversalPostings<V, MmapFile>,
             tokens: TokenSet,
+            min_match: usize,
             is_active: impl Fn(PointOffsetType) -> bool,
         ) -> OperationResult<Vec<PointOffsetType>> {
             postings.with_existing_postings(tokens.tokens(), |posting_readers| {
-                if posting_readers.is_empty() {
+                if posting_readers.is_empty() || posting_readers.len() < min_match {
                     return Ok(Vec::new());
                 }
                 let posting_readers = posting_readers
                     .into_iter()
                     .map(|(_token_id, posting_list_view)| posting_list_view)
                     .collect();
-                Ok(merge_compressed_postings_iterator(posting_readers, is_active).collect())
+                let merged =
+                    merge_compressed_postings_iterator(posting_readers, min_match, is_active);
+                Ok(merged.collect())
             })
         }
 
         match &self.storage.postings {
-            MmapPostingsEnum::Ids(postings) => merge(postings, tokens, is_active),
-            MmapPostingsEnum::WithPositions(postings) => merge(postings, tokens, is_active),
+            MmapPostingsEnum::Ids(postings) => merge(postings, tokens, min_match, is_active),
+            MmapPostingsEnum::WithPositions(postings) => {
+                merge(postings, tokens, min_match, is_active)
+            }
         }
     }
 
@@ -334,7 +367,12 @@ impl MmapInvertedIndex {
         }
     }
 
-    fn check_has_any(&self, tokens: &TokenSet, point_id: PointOffsetType) -> OperationResult<bool> {
+    fn check_has_any(
+        &self,
+        tokens: &TokenSet,
+        min_match: usize,
+        point_id: PointOffsetType,
+    ) -> OperationResult<bool> {
         if tokens.is_empty() {
             return Ok(false);
         }
@@ -347,23 +385,29 @@ impl MmapInvertedIndex {
         fn check_any<V: ZerocopyPostingValue>(
             postings: &UniversalPostings<V, MmapFile>,
             tokens: &TokenSet,
+            min_match: usize,
             point_id: PointOffsetType,
         ) -> OperationResult<bool> {
             postings.with_existing_postings(tokens.tokens(), |all_postings| {
-                Ok(all_postings
+                let matched = all_postings
                     .into_iter()
-                    .any(|(_token_id, posting)| posting.visitor().contains(point_id)))
+                    .filter(|(_token_id, posting)| posting.visitor().contains(point_id))
+                    .take(min_match)
+                    .count();
+                Ok(matched >= min_match)
             })
         }
 
         match &self.storage.postings {
-            MmapPostingsEnum::Ids(postings) => check_any(postings, tokens, point_id),
-            MmapPostingsEnum::WithPositions(postings) => check_any(postings, tokens, point_id),
+            MmapPostingsEnum::Ids(postings) => check_any(postings, tokens, min_match, point_id),
+            MmapPostingsEnum::WithPositions(postings) => {
+                check_any(postings, tokens, min_match, point_id)
+            }
         }
     }
 
     /// Iterate over point ids whose documents contain all given tokens in the same order they are provided
-    pub fn filter_has_phrase(&self, phrase: Document) -> OperationResult<Vec<PointOffsetType>> {
+    pub fn filter_has_phrase(&self, phrase: PhraseQuery) -> OperationResult<Vec<PointOffsetType>> {
         // in case of mmap immutable index, deleted points are still in the postings
         let is_active = move |idx| self.is_active(idx);
 
@@ -372,7 +416,8 @@ impl MmapInvertedIndex {
                 // Deduplicate phrase tokens: repeated tokens (e.g. "zn zn") must
                 // not fetch the same posting list twice, otherwise positions get
                 // added twice in `phrase_in_all_postings`.
-                let unique_tokens = phrase.to_token_set();
+                // The boundary sentinel is fetched as well, if the phrase has a slop.
+                let unique_tokens = phrase.posting_tokens();
                 let result = postings.with_all_or_none_postings(
                     unique_tokens.tokens(),
                     |selected_postings| {
@@ -394,7 +439,7 @@ impl MmapInvertedIndex {
 
     pub fn check_has_phrase(
         &self,
-        phrase: &Document,
+        phrase: &PhraseQuery,
         point_id: PointOffsetType,
     ) -> OperationResult<bool> {
         // in case of mmap immutable index, deleted points are still in the postings
@@ -404,7 +449,7 @@ impl MmapInvertedIndex {
 
         match &self.storage.postings {
             MmapPostingsEnum::WithPositions(postings) => {
-                let unique_tokens = phrase.to_token_set();
+                let unique_tokens = phrase.posting_tokens();
                 let result = postings.with_all_or_none_postings(
                     unique_tokens.tokens(),
                     |selected_postings| {
@@ -424,21 +469,20 @@ impl MmapInvertedIndex {
     }
 
     pub fn files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             self.path.join(POSTINGS_FILE),
             self.path.join(VOCAB_FILE),
             self.path.join(POINT_TO_TOKENS_COUNT_FILE),
             self.path.join(DELETED_POINTS_FILE),
-        ]
+        ];
+        if self.storage.forward_index.is_some() {
+            files.extend(MmapForwardIndex::files(&self.path));
+        }
+        files
     }
 
     pub fn immutable_files(&self) -> Vec<PathBuf> {
-        vec![
-            self.path.join(POSTINGS_FILE),
-            self.path.join(VOCAB_FILE),
-            self.path.join(POINT_TO_TOKENS_COUNT_FILE),
-            self.path.join(DELETED_POINTS_FILE),
-        ]
+        self.files()
     }
 
     /// No-op flusher: the on-disk state is build-time only. See the type-level
@@ -465,6 +509,25 @@ impl MmapInvertedIndex {
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    /// The vocabulary is a lookup structure, used by every query on the index.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        let Storage {
+            postings,
+            vocab,
+            point_to_tokens_count,
+            deleted_points: _,
+            // Not used by queries, never populated
+            forward_index: _,
+        } = &self.storage;
+        vocab.populate_with_budget(PopulateStage::Lookup, budget)?;
+        postings.populate_with_budget(budget)?;
+        budget.populate_with(PopulateStage::Data, &point_to_tokens_count[..], || {
+            point_to_tokens_count.populate()
+        })?;
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         let Self {
@@ -478,13 +541,34 @@ impl MmapInvertedIndex {
             vocab,
             point_to_tokens_count,
             deleted_points: _,
+            forward_index,
         } = storage;
         postings.clear_cache()?;
         vocab.clear_cache()?;
         point_to_tokens_count.clear_cache()?;
+        if let Some(forward_index) = forward_index {
+            forward_index.clear_cache()?;
+        }
         clear_disk_cache(&path.join(DELETED_POINTS_FILE))?;
         Ok(())
     }
+
+    /// Tokens stored for the point in the forward index, in the index order.
+    ///
+    /// Returns `None` if the index was built without the forward index.
+    pub fn point_tokens(
+        &self,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<&str>>> {
+        let Some(forward_index) = &self.storage.forward_index else {
+            return Ok(None);
+        };
+        if self.values_is_empty(point_id) {
+            return Ok(Some(Vec::new()));
+        }
+        forward_index.point_tokens(point_id, hw_counter).map(Some)
+    }
 }
 
 impl InvertedIndex for MmapInvertedIndex {
@@ -536,7 +620,10 @@ impl InvertedIndex for MmapInvertedIndex {
         let ids = match query {
             ParsedQuery::AllTokens(tokens) => self.filter_has_all(tokens)?,
             ParsedQuery::Phrase(phrase) => self.filter_has_phrase(phrase)?,
-            ParsedQuery::AnyTokens(tokens) => self.filter_has_any(tokens)?,
+            ParsedQuery::AnyTokens(tokens) => self.filter_has_any(tokens, 1)?,
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                self.filter_has_any(tokens, min_match)?
+            }
         };
         Ok(Box::new(ids.into_iter()))
     }
@@ -571,7 +658,10 @@ impl InvertedIndex for MmapInvertedIndex {
         match parsed_query {
             ParsedQuery::AllTokens(tokens) => self.check_has_subset(tokens, point_id),
             ParsedQuery::Phrase(phrase) => self.check_has_phrase(phrase, point_id),
-            ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, point_id),
+            ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, 1, point_id),
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                self.check_has_any(tokens, *min_match, point_id)
+            }
         }
     }
 
@@ -25,7 +25,9 @@ impl ZerocopyPostingValue for Positions {}
 pub(in crate::index::field_index::full_text_index) struct PostingsHeader {
     /// Number of posting lists. One posting list per term
     pub posting_count: usize,
-    pub _reserved: [u8; 32],
+    /// Code of [`PostingsCompression`](super::compression::PostingsCompression)
+    pub compression: u8,
+    pub _reserved: [u8; 31],
 }
 
 /// This data structure should contain all the necessary information to
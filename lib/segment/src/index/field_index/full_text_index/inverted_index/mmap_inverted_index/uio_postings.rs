@@ -3,12 +3,14 @@ use std::marker::PhantomData;
 use std::path::PathBuf;
 
 use common::generic_consts::{Random, Sequential};
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::universal_io::{OpenOptions, ReadRange, UniversalIoError, UniversalRead};
 use posting_list::{PostingList, PostingListView};
 use zerocopy::FromBytes;
 
 use crate::common::operation_error::OperationResult;
 use crate::index::field_index::full_text_index::inverted_index::TokenId;
+use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::compression::PostingsCompression;
 use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::raw_posting_list::RawPostingList;
 use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::types::{
     PostingListHeader, PostingsHeader, ZerocopyPostingValue,
@@ -23,14 +25,24 @@ use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_in
 /// `size_of::<PostingsHeader>() + token_id * size_of::<PostingListHeader>()`.
 /// Each [`PostingListHeader`] then points (via absolute `offset`) into the
 /// posting-data region.
+///
+/// Compressed posting lists are decompressed into memory on every access,
+/// their stored size is taken from the header of the next token.
 pub struct UniversalPostings<V: ZerocopyPostingValue, S: UniversalRead<u8>> {
     _path: PathBuf,
     storage: S,
     header: PostingsHeader,
+    compression: PostingsCompression,
     _value_type: PhantomData<V>,
 }
 
-type HeaderResult = Result<(TokenId, PostingListHeader), UniversalIoError>;
+/// Header of a posting list, along with the range of its stored bytes.
+struct StoredPostingHeader {
+    header: PostingListHeader,
+    range: ReadRange,
+}
+
+type HeaderResult = Result<(TokenId, StoredPostingHeader), UniversalIoError>;
 
 /// Output of [`UniversalPostings::headers_iter`]: a lazy iterator over the
 /// headers that are in range, paired with the pre-filtered list of token ids
@@ -52,11 +64,13 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
         })?;
 
         let (header, _) = PostingsHeader::read_from_prefix(header_bytes.as_ref())?;
+        let compression = PostingsCompression::from_code(header.compression)?;
 
         Ok(Self {
             _path: path,
             storage,
             header,
+            compression,
             _value_type: PhantomData,
         })
     }
@@ -69,6 +83,12 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
         Ok(())
     }
 
+    /// Populate the part of the storage, which fits into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        budget.populate::<u8>(PopulateStage::Data, &self.storage)?;
+        Ok(())
+    }
+
     /// Hint the storage backend to drop any RAM cache backing this file.
     /// For mmap-backed storage this is `madvise(MADV_PAGEOUT)`.
     pub fn clear_cache(&self) -> OperationResult<()> {
@@ -84,6 +104,14 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
     fn headers_iter(&self, token_ids: &[TokenId]) -> Result<HeadersBatch<'_>, UniversalIoError> {
         let header_length = size_of::<PostingListHeader>() as u64;
         let posting_count = self.header.posting_count;
+        let compression = self.compression;
+
+        // Compressed posting list ends where the next one starts, so read the next header too
+        let read_length = if compression.is_compressed() {
+            2 * header_length
+        } else {
+            header_length
+        };
 
         let mut valid_ranges: Vec<(TokenId, ReadRange)> = Vec::with_capacity(token_ids.len());
         let mut filtered_out: Vec<TokenId> = Vec::new();
@@ -100,7 +128,7 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
                 token_id,
                 ReadRange {
                     byte_offset: header_offset,
-                    length: header_length,
+                    length: read_length,
                 },
             ));
         }
@@ -108,10 +136,20 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
         let valid_iter = self
             .storage
             .read_iter::<Random, _>(valid_ranges)?
-            .map(|res| {
+            .map(move |res| {
                 let (token_id, bytes) = res?;
-                let (header, _) = PostingListHeader::read_from_prefix(bytes.as_ref())?;
-                Ok((token_id, header))
+                let (header, rest) = PostingListHeader::read_from_prefix(bytes.as_ref())?;
+                let length = if compression.is_compressed() {
+                    let (next_header, _) = PostingListHeader::read_from_prefix(rest)?;
+                    next_header.offset.saturating_sub(header.offset)
+                } else {
+                    header.posting_size::<V>() as u64
+                };
+                let range = ReadRange {
+                    byte_offset: header.offset,
+                    length,
+                };
+                Ok((token_id, StoredPostingHeader { header, range }))
             });
 
         Ok(HeadersBatch {
@@ -120,7 +158,7 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
         })
     }
 
-    fn get_header(&self, token_id: TokenId) -> OperationResult<Option<PostingListHeader>> {
+    fn get_header(&self, token_id: TokenId) -> OperationResult<Option<StoredPostingHeader>> {
         let HeadersBatch { mut iter, .. } = self.headers_iter(&[token_id])?;
         let Some(entry) = iter.next() else {
             return Ok(None);
@@ -144,13 +182,13 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
     #[cfg(test)]
     fn raw_posting<'a>(
         &'a self,
-        header: PostingListHeader,
-    ) -> Result<RawPostingList<'a>, UniversalIoError> {
-        let read_range = ReadRange {
-            byte_offset: header.offset,
-            length: header.posting_size::<V>() as u64,
-        };
-        let bytes = self.storage.read::<Sequential>(read_range)?;
+        stored: StoredPostingHeader,
+    ) -> OperationResult<RawPostingList<'a>> {
+        let StoredPostingHeader { header, range } = stored;
+        let bytes = self.storage.read::<Sequential>(range)?;
+        let bytes = self
+            .compression
+            .decompress(bytes, header.posting_size::<V>())?;
         let result = RawPostingList::new(bytes, header);
         Ok(result)
     }
@@ -168,7 +206,7 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
     /// Number of elements in the posting list for `token_id`. Reads only the
     /// per-token header, not the posting bytes.
     pub fn posting_len(&self, token_id: TokenId) -> OperationResult<Option<usize>> {
-        Ok(self.get_header(token_id)?.map(|h| h.posting_len()))
+        Ok(self.get_header(token_id)?.map(|h| h.header.posting_len()))
     }
 
     /// Read the posting lists for every header yielded by `header_iter` and
@@ -184,11 +222,7 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
         let header_err: Cell<Option<UniversalIoError>> = Cell::new(None);
 
         let range_iter = header_iter.filter_map(|header_res| match header_res {
-            Ok((token_id, header)) => {
-                let range = ReadRange {
-                    byte_offset: header.offset,
-                    length: header.posting_size::<V>() as u64,
-                };
+            Ok((token_id, StoredPostingHeader { header, range })) => {
                 Some(((token_id, header), range))
             }
             Err(err) => {
@@ -202,6 +236,9 @@ impl<V: ZerocopyPostingValue, S: UniversalRead<u8>> UniversalPostings<V, S> {
 
         for entry in self.storage.read_iter::<Sequential, _>(range_iter)? {
             let ((token_id, header), bytes) = entry?;
+            let bytes = self
+                .compression
+                .decompress(bytes, header.posting_size::<V>())?;
             raw_postings.push((token_id, RawPostingList::new(bytes, header)));
         }
 
@@ -78,6 +78,21 @@ impl TokenSet {
         }
         subset.0.iter().any(|token| self.contains(token))
     }
+
+    /// Checks if the current set contains at least `min_match` of the given tokens.
+    /// Returns false if the subset is empty
+    pub fn has_at_least(&self, subset: &TokenSet, min_match: usize) -> bool {
+        if subset.is_empty() {
+            return false;
+        }
+        subset
+            .0
+            .iter()
+            .filter(|token| self.contains(token))
+            .take(min_match)
+            .count()
+            >= min_match
+    }
 }
 
 impl FromIterator<TokenId> for TokenSet {
@@ -128,10 +143,16 @@ impl Document {
     ///
     /// Returns false if the phrase is empty.
     /// Boundary sentinels naturally prevent matches across array elements
-    /// because the query never contains them.
-    pub fn has_phrase(&self, phrase: &Document) -> bool {
+    /// because the query never contains them. With a slop, they end the match explicitly.
+    pub fn has_phrase(&self, phrase: &PhraseQuery) -> bool {
         let doc = self.0.as_slice();
-        let phrase = phrase.0.as_slice();
+
+        if phrase.slop > 0 {
+            let positions = doc.iter().enumerate();
+            return phrase.has_sloppy_match(positions.map(|(pos, token)| (pos as u32, *token)));
+        }
+
+        let phrase = phrase.tokens.0.as_slice();
 
         if doc.is_empty() || phrase.is_empty() {
             return false;
@@ -159,6 +180,91 @@ impl FromIterator<TokenId> for Document {
     }
 }
 
+/// Tokens of a phrase, which must be present in the document in the same order
+#[derive(Debug, Clone)]
+pub struct PhraseQuery {
+    tokens: Document,
+    /// Max number of other tokens between the tokens of the phrase, in total
+    slop: u32,
+    /// Token of the array boundary sentinel, if it's in the vocabulary.
+    /// Only used with a slop, as adjacent tokens can't be separated by a boundary anyway.
+    boundary: Option<TokenId>,
+}
+
+impl PhraseQuery {
+    pub fn new(tokens: Document, slop: u32, boundary: Option<TokenId>) -> Self {
+        Self {
+            tokens,
+            slop,
+            boundary,
+        }
+    }
+
+    pub fn tokens(&self) -> &Document {
+        &self.tokens
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Boundary sentinel, which must not be between the matched tokens
+    pub fn sloppy_boundary(&self) -> Option<TokenId> {
+        self.boundary.filter(|_| self.slop > 0)
+    }
+
+    /// Distinct tokens to read postings of, including the boundary sentinel if needed
+    pub fn posting_tokens(&self) -> TokenSet {
+        let tokens = self.tokens.tokens().iter().copied();
+        tokens.chain(self.sloppy_boundary()).collect()
+    }
+
+    /// Checks if the tokens of the document, with increasing positions, contain the phrase
+    /// with at most `slop` other positions between its tokens.
+    ///
+    /// Picking the next phrase token at the earliest position gives the shortest match from each
+    /// start, so no backtracking is needed.
+    fn has_sloppy_match(&self, mut doc: impl Iterator<Item = (u32, TokenId)> + Clone) -> bool {
+        let Some((first, rest)) = self.tokens.tokens().split_first() else {
+            return false;
+        };
+        let max_span = (rest.len() as u32).saturating_add(self.slop);
+
+        while let Some((start, token)) = doc.next() {
+            if token != *first {
+                continue;
+            }
+
+            let mut matched = 0;
+            for (position, token) in doc.clone() {
+                if matched == rest.len()
+                    || position - start > max_span
+                    || Some(token) == self.boundary
+                {
+                    break;
+                }
+                if token == rest[matched] {
+                    matched += 1;
+                }
+            }
+            if matched == rest.len() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl From<Document> for PhraseQuery {
+    fn from(tokens: Document) -> Self {
+        Self::new(tokens, 0, None)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ParsedQuery {
     /// All these tokens must be present in the document, regardless of order.
@@ -169,8 +275,11 @@ pub enum ParsedQuery {
     /// At least one of these tokens must be present in the document.
     AnyTokens(TokenSet),
 
+    /// At least `min_match` of these tokens must be present in the document.
+    MinTokens { tokens: TokenSet, min_match: usize },
+
     /// All these tokens must be present in the document, in the same order as this query.
-    Phrase(Document),
+    Phrase(PhraseQuery),
 }
 
 pub trait InvertedIndex {
@@ -248,6 +357,9 @@ pub trait InvertedIndex {
             ParsedQuery::AnyTokens(tokens) => {
                 self.estimate_has_any_cardinality(tokens, condition, hw_counter)
             }
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                self.estimate_has_at_least_cardinality(tokens, *min_match, condition, hw_counter)
+            }
         }
     }
 
@@ -336,9 +448,66 @@ pub trait InvertedIndex {
         })
     }
 
+    fn estimate_has_at_least_cardinality(
+        &self,
+        tokens: &TokenSet,
+        min_match: usize,
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<CardinalityEstimation> {
+        if min_match <= 1 {
+            return self.estimate_has_any_cardinality(tokens, condition, hw_counter);
+        }
+
+        let points_count = self.points_count();
+
+        let mut posting_lengths: Vec<usize> = tokens
+            .tokens()
+            .iter()
+            .filter_map(|&vocab_idx| self.get_posting_len(vocab_idx, hw_counter).transpose())
+            .collect::<OperationResult<Vec<usize>>>()?;
+
+        if posting_lengths.len() < min_match || points_count == 0 {
+            // Not enough known tokens -> no matches
+            return Ok(CardinalityEstimation::exact(0)
+                .with_primary_clause(PrimaryCondition::Condition(Box::new(condition.clone()))));
+        }
+
+        // A matching point must contain at least one of any `n - min_match + 1` tokens,
+        // so it is contained in the union of the smallest of them.
+        posting_lengths.sort_unstable();
+        let sum: usize = posting_lengths.iter().sum();
+        let smallest_sum: usize = posting_lengths[..posting_lengths.len() - min_match + 1]
+            .iter()
+            .sum();
+        let max = smallest_sum.min(sum / min_match).min(points_count);
+
+        // Assuming independent tokens, compute the probability of a point to contain
+        // at least `min_match` tokens. `matched[j]` is the probability of exactly `j` matches,
+        // with the last element accumulating `min_match` matches or more.
+        let mut matched = vec![0.0; min_match + 1];
+        matched[0] = 1.0;
+        for &posting_len in &posting_lengths {
+            let probability = posting_len as f64 / points_count as f64;
+            matched[min_match] += matched[min_match - 1] * probability;
+            for j in (1..min_match).rev() {
+                matched[j] = matched[j] * (1.0 - probability) + matched[j - 1] * probability;
+            }
+            matched[0] *= 1.0 - probability;
+        }
+        let exp = ((matched[min_match] * points_count as f64) as usize).min(max);
+
+        Ok(CardinalityEstimation {
+            primary_clauses: vec![PrimaryCondition::Condition(Box::new(condition.clone()))],
+            min: 0,
+            exp,
+            max,
+        })
+    }
+
     fn estimate_has_phrase_cardinality(
         &self,
-        phrase: &Document,
+        phrase: &PhraseQuery,
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<CardinalityEstimation> {
@@ -348,7 +517,7 @@ pub trait InvertedIndex {
         }
 
         // Start with same cardinality estimation as has_subset
-        let tokenset = phrase.to_token_set();
+        let tokenset = phrase.tokens().to_token_set();
         let subset_estimation =
             self.estimate_has_subset_cardinality(&tokenset, condition, hw_counter)?;
 
@@ -418,6 +587,7 @@ mod tests {
     use rstest::rstest;
 
     use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
+    use crate::data_types::index::TextIndexCompression;
     use crate::index::field_index::full_text_index::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
     use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::MmapInvertedIndex;
     use crate::index::field_index::full_text_index::inverted_index::mutable_inverted_index::MutableInvertedIndex;
@@ -476,7 +646,7 @@ mod tests {
         deleted_count: u32,
         with_positions: bool,
     ) -> MutableInvertedIndex {
-        let mut index = MutableInvertedIndex::new(with_positions);
+        let mut index = MutableInvertedIndex::new(with_positions, true);
 
         let hw_counter = HardwareCounterCell::new();
 
@@ -536,6 +706,68 @@ mod tests {
         });
     }
 
+    #[rstest]
+    fn test_point_tokens(#[values(false, true)] phrase_matching: bool) {
+        let mutable = mutable_inverted_index(100, 20, phrase_matching);
+        let immutable = ImmutableInvertedIndex::from(mutable.clone());
+
+        let open_mmap = |forward_index: bool| {
+            let mmap_dir = tempfile::tempdir().unwrap();
+            MmapInvertedIndex::create(mmap_dir.path().into(), &immutable, None, forward_index)
+                .unwrap();
+            let mmap = MmapInvertedIndex::open(
+                mmap_dir.path().into(),
+                false,
+                phrase_matching,
+                &BitVec::new(),
+            )
+            .unwrap()
+            .unwrap();
+            (mmap_dir, mmap)
+        };
+        let (_mmap_dir, mmap) = open_mmap(true);
+        let (_plain_dir, plain_mmap) = open_mmap(false);
+
+        let hw_counter = HardwareCounterCell::new();
+        for point_id in 0..100 {
+            let mutable_tokens = mutable.point_tokens(point_id).unwrap();
+            let mmap_tokens = mmap.point_tokens(point_id, &hw_counter).unwrap().unwrap();
+
+            // Tokens are in the order of token ids
+            let mutable_ids = mutable_tokens
+                .iter()
+                .map(|token| mutable.vocab[*token])
+                .collect::<Vec<_>>();
+            let expected_ids = mutable.point_to_tokens[point_id as usize]
+                .as_ref()
+                .map_or(&[][..], TokenSet::tokens);
+            assert_eq!(mutable_ids, expected_ids);
+
+            let mmap_ids = mmap_tokens
+                .iter()
+                .map(|token| immutable.vocab[*token])
+                .collect::<Vec<_>>();
+            assert!(mmap_ids.is_sorted());
+
+            // Token ids of immutable indexes differ, but the tokens are the same
+            assert_eq!(mutable_tokens.len(), mmap_tokens.len());
+            assert!(mutable_tokens.iter().all(|t| mmap_tokens.contains(t)));
+
+            // Without the forward index, the tokens can't be retrieved
+            assert!(
+                plain_mmap
+                    .point_tokens(point_id, &hw_counter)
+                    .unwrap()
+                    .is_none()
+            );
+        }
+        // Reads of the forward index are accounted
+        assert!(hw_counter.payload_index_io_read_counter().get() > 0);
+
+        let plain_mutable = MutableInvertedIndex::new(phrase_matching, false);
+        assert!(plain_mutable.point_tokens(0).is_none());
+    }
+
     #[rstest]
     #[case(2000, 400)]
     #[case(2000, 2000)]
@@ -558,7 +790,7 @@ mod tests {
 
         let hw_counter = HardwareCounterCell::new();
 
-        MmapInvertedIndex::create(mmap_dir.path().into(), &immutable).unwrap();
+        MmapInvertedIndex::create(mmap_dir.path().into(), &immutable, None, false).unwrap();
         let empty_deleted = BitVec::new();
         let mmap = MmapInvertedIndex::open(
             mmap_dir.path().into(),
@@ -625,7 +857,15 @@ mod tests {
     }
 
     #[rstest]
-    fn test_mmap_index_congruence(#[values(false, true)] phrase_matching: bool) {
+    fn test_mmap_index_congruence(
+        #[values(false, true)] phrase_matching: bool,
+        #[values(
+            None,
+            Some(TextIndexCompression::Lz4),
+            Some(TextIndexCompression::Zstd)
+        )]
+        compression: Option<TextIndexCompression>,
+    ) {
         let indexed_count = 10000;
         let deleted_count = 500;
 
@@ -635,7 +875,7 @@ mod tests {
         let mut mut_index = mutable_inverted_index(indexed_count, deleted_count, phrase_matching);
 
         let immutable = ImmutableInvertedIndex::from(mut_index.clone());
-        MmapInvertedIndex::create(mmap_dir.path().into(), &immutable).unwrap();
+        MmapInvertedIndex::create(mmap_dir.path().into(), &immutable, compression, false).unwrap();
         let empty_deleted = BitVec::new();
         let mut mmap_index = MmapInvertedIndex::open(
             mmap_dir.path().into(),
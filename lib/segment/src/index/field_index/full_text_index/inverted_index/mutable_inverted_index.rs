@@ -6,7 +6,9 @@ use itertools::Either;
 
 use super::posting_list::PostingList;
 use super::postings_iterator::{intersect_postings_iterator, merge_postings_iterator};
-use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
+use super::{
+    ARRAY_BOUNDARY_SENTINEL, Document, InvertedIndex, ParsedQuery, PhraseQuery, TokenId, TokenSet,
+};
 use crate::common::operation_error::OperationResult;
 
 #[cfg_attr(test, derive(Clone))]
@@ -19,17 +21,23 @@ pub struct MutableInvertedIndex {
     ///
     /// Must be enabled explicitly.
     pub point_to_doc: Option<Vec<Option<Document>>>,
+    /// Optional tokens by their ids, to retrieve the tokens of a point.
+    ///
+    /// Must be enabled explicitly.
+    pub(super) id_to_token: Option<Vec<String>>,
     pub(super) points_count: usize,
 }
 
 impl MutableInvertedIndex {
-    /// Create a new inverted index with or without positional information.
-    pub fn new(with_positions: bool) -> Self {
+    /// Create a new inverted index with or without positional information, and with or without
+    /// the reverse vocabulary for retrieving the tokens of points.
+    pub fn new(with_positions: bool, with_forward_index: bool) -> Self {
         Self {
             postings: Vec::new(),
             vocab: HashMap::new(),
             point_to_tokens: Vec::new(),
             point_to_doc: with_positions.then_some(Vec::new()),
+            id_to_token: with_forward_index.then_some(Vec::new()),
             points_count: 0,
         }
     }
@@ -67,7 +75,12 @@ impl MutableInvertedIndex {
         Either::Right(intersect_postings_iterator(postings))
     }
 
-    fn filter_has_any(&self, tokens: TokenSet) -> impl Iterator<Item = PointOffsetType> + '_ {
+    /// Iterate over point ids whose documents contain at least `min_match` of the given tokens
+    fn filter_has_any(
+        &self,
+        tokens: TokenSet,
+        min_match: usize,
+    ) -> impl Iterator<Item = PointOffsetType> + '_ {
         let postings_opt: Vec<_> = tokens
             .tokens()
             .iter()
@@ -78,17 +91,17 @@ impl MutableInvertedIndex {
             })
             .collect();
 
-        if postings_opt.is_empty() {
-            // Empty request -> no matches
+        if postings_opt.is_empty() || postings_opt.len() < min_match {
+            // Empty request or not enough known tokens -> no matches
             return Either::Left(std::iter::empty());
         }
 
-        Either::Right(merge_postings_iterator(postings_opt))
+        Either::Right(merge_postings_iterator(postings_opt, min_match))
     }
 
     pub fn filter_has_phrase(
         &self,
-        phrase: Document,
+        phrase: PhraseQuery,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
         let Some(point_to_doc) = self.point_to_doc.as_ref() else {
             // Return empty iterator when not enabled
@@ -96,7 +109,7 @@ impl MutableInvertedIndex {
         };
 
         let iter = self
-            .filter_has_all(phrase.to_token_set())
+            .filter_has_all(phrase.tokens().to_token_set())
             .filter(move |id| {
                 let doc = point_to_doc[*id as usize]
                     .as_ref()
@@ -114,6 +127,19 @@ impl InvertedIndex for MutableInvertedIndex {
         &mut self.vocab
     }
 
+    fn register_token<S: AsRef<str>>(&mut self, token_str: S) -> TokenId {
+        if let Some(&idx) = self.vocab.get(token_str.as_ref()) {
+            return idx;
+        }
+        let next_token_id = self.vocab.len() as TokenId;
+        self.vocab
+            .insert(token_str.as_ref().to_string(), next_token_id);
+        if let Some(id_to_token) = &mut self.id_to_token {
+            id_to_token.push(token_str.as_ref().to_string());
+        }
+        next_token_id
+    }
+
     fn index_tokens(
         &mut self,
         point_id: PointOffsetType,
@@ -218,7 +244,10 @@ impl InvertedIndex for MutableInvertedIndex {
         match query {
             ParsedQuery::AllTokens(tokens) => Ok(Box::new(self.filter_has_all(tokens))),
             ParsedQuery::Phrase(phrase) => Ok(Box::new(self.filter_has_phrase(phrase))),
-            ParsedQuery::AnyTokens(tokens) => Ok(Box::new(self.filter_has_any(tokens))),
+            ParsedQuery::AnyTokens(tokens) => Ok(Box::new(self.filter_has_any(tokens, 1))),
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                Ok(Box::new(self.filter_has_any(tokens, min_match)))
+            }
         }
     }
 
@@ -254,13 +283,13 @@ impl InvertedIndex for MutableInvertedIndex {
                 // Check that all tokens are in document
                 doc.has_subset(query)
             }
-            ParsedQuery::Phrase(document) => {
+            ParsedQuery::Phrase(phrase) => {
                 let Some(doc) = self.get_document(point_id) else {
                     return Ok(false);
                 };
 
                 // Check that all tokens are in document, in order
-                doc.has_phrase(document)
+                doc.has_phrase(phrase)
             }
             ParsedQuery::AnyTokens(query) => {
                 let Some(doc) = self.get_tokens(point_id) else {
@@ -270,6 +299,14 @@ impl InvertedIndex for MutableInvertedIndex {
                 // Check that at least one token is in document
                 doc.has_any(query)
             }
+            ParsedQuery::MinTokens { tokens, min_match } => {
+                let Some(doc) = self.get_tokens(point_id) else {
+                    return Ok(false);
+                };
+
+                // Check that enough tokens are in document
+                doc.has_at_least(tokens, *min_match)
+            }
         };
         Ok(matched)
     }
@@ -299,6 +336,24 @@ impl InvertedIndex for MutableInvertedIndex {
 }
 
 impl MutableInvertedIndex {
+    /// Tokens stored for the point, in the index order.
+    ///
+    /// Returns `None` if the index keeps no reverse vocabulary.
+    pub fn point_tokens(&self, point_id: PointOffsetType) -> Option<Vec<&str>> {
+        let id_to_token = self.id_to_token.as_ref()?;
+        let Some(token_set) = self.get_tokens(point_id) else {
+            return Some(Vec::new());
+        };
+        let tokens = token_set
+            .tokens()
+            .iter()
+            .filter_map(|&token_id| id_to_token.get(token_id as usize))
+            .map(String::as_str)
+            .filter(|token| *token != ARRAY_BOUNDARY_SENTINEL)
+            .collect();
+        Some(tokens)
+    }
+
     /// Approximate RAM usage in bytes.
     pub fn ram_usage_bytes(&self) -> usize {
         let Self {
@@ -306,6 +361,7 @@ impl MutableInvertedIndex {
             vocab,
             point_to_tokens,
             point_to_doc,
+            id_to_token,
             points_count: _,
         } = self;
 
@@ -336,6 +392,13 @@ impl MutableInvertedIndex {
                         .sum::<usize>()
             })
             .unwrap_or(0);
-        postings_bytes + vocab_base_bytes + vocab_heap_bytes + ptt_bytes + ptd_bytes
+        let itt_bytes: usize = id_to_token
+            .as_ref()
+            .map(|v| {
+                v.capacity() * std::mem::size_of::<String>()
+                    + v.iter().map(|s| s.capacity()).sum::<usize>()
+            })
+            .unwrap_or(0);
+        postings_bytes + vocab_base_bytes + vocab_heap_bytes + ptt_bytes + ptd_bytes + itt_bytes
     }
 }
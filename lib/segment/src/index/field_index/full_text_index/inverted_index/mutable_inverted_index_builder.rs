@@ -3,14 +3,15 @@ use common::types::PointOffsetType;
 use super::InvertedIndex;
 use super::mutable_inverted_index::MutableInvertedIndex;
 use crate::index::field_index::full_text_index::inverted_index::{Document, TokenSet};
+use crate::index::field_index::full_text_index::tokenizers::is_unstemmed_token;
 
 pub struct MutableInvertedIndexBuilder {
     index: MutableInvertedIndex,
 }
 
 impl MutableInvertedIndexBuilder {
-    pub fn new(phrase_matching: bool) -> Self {
-        let index = MutableInvertedIndex::new(phrase_matching);
+    pub fn new(phrase_matching: bool, forward_index: bool) -> Self {
+        let index = MutableInvertedIndex::new(phrase_matching, forward_index);
         Self { index }
     }
 
@@ -29,11 +30,18 @@ impl MutableInvertedIndexBuilder {
             }
         }
 
-        let tokens = self.index.register_tokens(str_tokens);
+        let str_tokens: Vec<String> = str_tokens.into_iter().collect();
+        let tokens = self.index.register_tokens(&str_tokens);
 
-        // insert as whole document
+        // insert as whole document, original forms of stemmed tokens are not a part of it
         if let Some(point_to_doc) = self.index.point_to_doc.as_mut() {
-            point_to_doc[idx as usize] = Some(Document::new(tokens.clone()));
+            let document_tokens = tokens
+                .iter()
+                .zip(&str_tokens)
+                .filter(|(_, str_token)| !is_unstemmed_token(str_token))
+                .map(|(token, _)| *token)
+                .collect();
+            point_to_doc[idx as usize] = Some(Document::new(document_tokens));
         }
 
         // insert as tokenset
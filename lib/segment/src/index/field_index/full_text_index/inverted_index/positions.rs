@@ -1,7 +1,7 @@
 use posting_list::{PostingValue, UnsizedHandler, UnsizedValue};
 use zerocopy::{FromBytes, IntoBytes};
 
-use crate::index::field_index::full_text_index::inverted_index::{Document, TokenId};
+use crate::index::field_index::full_text_index::inverted_index::{PhraseQuery, TokenId};
 
 /// Represents a list of positions of a token in a document.
 #[derive(Default, Clone, Debug)]
@@ -71,8 +71,18 @@ impl PartialDocument {
     }
 
     /// Returns true if any sequential window of tokens match the given phrase.
-    pub fn has_phrase(&self, phrase: &Document) -> bool {
-        match phrase.tokens() {
+    ///
+    /// With a slop, the positions of the window may have gaps within the slop.
+    pub fn has_phrase(&self, phrase: &PhraseQuery) -> bool {
+        if phrase.slop > 0 {
+            let positions = self
+                .0
+                .iter()
+                .map(|tok_pos| (tok_pos.position, tok_pos.token_id));
+            return phrase.has_sloppy_match(positions);
+        }
+
+        match phrase.tokens().tokens() {
             // no tokens in query -> no match
             [] => false,
 
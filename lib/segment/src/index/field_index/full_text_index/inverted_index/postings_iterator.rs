@@ -6,7 +6,7 @@ use super::posting_list::PostingList;
 use crate::index::field_index::full_text_index::inverted_index::positions::{
     PartialDocument, Positions, TokenPosition,
 };
-use crate::index::field_index::full_text_index::inverted_index::{Document, TokenId};
+use crate::index::field_index::full_text_index::inverted_index::{PhraseQuery, TokenId};
 
 pub fn intersect_postings_iterator<'a>(
     mut postings: Vec<&'a PostingList>,
@@ -24,14 +24,17 @@ pub fn intersect_postings_iterator<'a>(
         .filter(move |doc_id| postings.iter().all(|posting| posting.contains(*doc_id)))
 }
 
+/// Iterate over points, which are present in at least `min_match` of the postings
 pub fn merge_postings_iterator<'a>(
     postings: Vec<&'a PostingList>,
+    min_match: usize,
 ) -> impl Iterator<Item = PointOffsetType> + 'a {
     postings
         .into_iter()
         .map(PostingList::iter)
         .kmerge_by(|a, b| a < b)
-        .dedup()
+        .dedup_with_count()
+        .filter_map(move |(count, id)| (count >= min_match).then_some(id))
 }
 
 pub fn intersect_compressed_postings_iterator<'a, V: PostingValue + 'a>(
@@ -70,8 +73,10 @@ pub fn intersect_compressed_postings_iterator<'a, V: PostingValue + 'a>(
         })
 }
 
+/// Iterate over active points, which are present in at least `min_match` of the postings
 pub fn merge_compressed_postings_iterator<'a, V: PostingValue + 'a>(
     postings: Vec<PostingListView<'a, V>>,
+    min_match: usize,
     is_active: impl Fn(PointOffsetType) -> bool + 'a,
 ) -> impl Iterator<Item = PointOffsetType> + 'a {
     postings
@@ -80,13 +85,16 @@ pub fn merge_compressed_postings_iterator<'a, V: PostingValue + 'a>(
         //                         We are discarding them here, thus unnecessarily reading them from the tails of the posting lists.
         .map(|view| view.into_iter().map(|elem| elem.id))
         .kmerge_by(|a, b| a < b)
-        .dedup()
-        .filter(move |id| is_active(*id))
+        // Each posting contains a point once, so the count is the number of matched tokens
+        .dedup_with_count()
+        .filter_map(move |(count, id)| (count >= min_match && is_active(id)).then_some(id))
 }
 
 /// Returns an iterator over the points that match the given phrase query.
+///
+/// `postings` are expected for [`PhraseQuery::posting_tokens`].
 pub fn intersect_compressed_postings_phrase_iterator<'a>(
-    phrase: Document,
+    phrase: PhraseQuery,
     mut postings: Vec<(TokenId, PostingListView<'a, Positions>)>,
     is_active: impl Fn(PointOffsetType) -> bool + 'a,
 ) -> impl Iterator<Item = PointOffsetType> + 'a {
@@ -95,6 +103,8 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
         return Either::Left(std::iter::empty());
     }
 
+    let mut boundary_iterator = take_boundary_iterator(&phrase, &mut postings);
+
     let smallest_posting_idx = postings
         .iter()
         .enumerate()
@@ -122,6 +132,7 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
                 &phrase,
                 initial_tokens_positions,
                 &mut posting_iterators,
+                &mut boundary_iterator,
             )
         })
         .map(|elem| elem.id);
@@ -129,6 +140,20 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
     Either::Right(has_phrase_iter)
 }
 
+/// Separates the posting of the boundary sentinel, which is not intersected with the others,
+/// because documents without arrays don't have it.
+fn take_boundary_iterator<'a>(
+    phrase: &PhraseQuery,
+    postings: &mut Vec<(TokenId, PostingListView<'a, Positions>)>,
+) -> Option<(TokenId, PostingIterator<'a, Positions>)> {
+    let boundary = phrase.sloppy_boundary()?;
+    let idx = postings
+        .iter()
+        .position(|(token_id, _posting)| *token_id == boundary)?;
+    let (token_id, posting) = postings.remove(idx);
+    Some((token_id, posting.into_iter()))
+}
+
 /// Reconstructs a partial document from the posting lists (which contain positions)
 ///
 /// Returns true if the document contains the entire phrase, in the same order.
@@ -136,11 +161,13 @@ pub fn intersect_compressed_postings_phrase_iterator<'a>(
 /// # Arguments
 ///
 /// - `initial_tokens_positions` - must be prepopulated if iterating over a posting not included in the `posting_iterators`.
+/// - `boundary_iterator` - positions of the boundary sentinel in the document are added, if any.
 fn phrase_in_all_postings<'a>(
     id: PointOffsetType,
-    phrase: &Document,
+    phrase: &PhraseQuery,
     initial_tokens_positions: Vec<TokenPosition>,
     posting_iterators: &mut Vec<(TokenId, PostingIterator<'a, Positions>)>,
+    boundary_iterator: &mut Option<(TokenId, PostingIterator<'a, Positions>)>,
 ) -> bool {
     let mut tokens_positions = initial_tokens_positions;
     for (token_id, posting_iterator) in posting_iterators.iter_mut() {
@@ -159,20 +186,34 @@ fn phrase_in_all_postings<'a>(
         debug_assert!(!other.value.is_empty());
         tokens_positions.extend(other.value.to_token_positions(*token_id))
     }
+    if let Some((token_id, boundary_iterator)) = boundary_iterator
+        && let Some(boundary) = boundary_iterator.advance_until_greater_or_equal(id)
+        && boundary.id == id
+    {
+        tokens_positions.extend(boundary.value.to_token_positions(*token_id));
+    }
     PartialDocument::new(tokens_positions).has_phrase(phrase)
 }
 
+/// `token_to_posting` are expected for [`PhraseQuery::posting_tokens`].
 pub fn check_compressed_postings_phrase(
-    phrase: &Document,
+    phrase: &PhraseQuery,
     point_id: PointOffsetType,
-    token_to_posting: Vec<(TokenId, PostingListView<'_, Positions>)>,
+    mut token_to_posting: Vec<(TokenId, PostingListView<'_, Positions>)>,
 ) -> bool {
+    let mut boundary_iterator = take_boundary_iterator(phrase, &mut token_to_posting);
     let mut posting_iterators = token_to_posting
         .into_iter()
         .map(|(token_id, posting)| (token_id, posting.into_iter()))
         .collect::<Vec<_>>();
 
-    phrase_in_all_postings(point_id, phrase, Vec::new(), &mut posting_iterators)
+    phrase_in_all_postings(
+        point_id,
+        phrase,
+        Vec::new(),
+        &mut posting_iterators,
+        &mut boundary_iterator,
+    )
 }
 
 #[cfg(test)]
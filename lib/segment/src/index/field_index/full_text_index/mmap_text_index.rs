@@ -2,25 +2,41 @@ use std::path::PathBuf;
 
 use common::bitvec::{BitSlice, BitVec};
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::fs::{atomic_save_json, read_json};
+use common::populate_budget::PopulateBudget;
 use common::types::PointOffsetType;
 use fs_err as fs;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::auto_stopwords::AutoStopwords;
 use super::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
 use super::inverted_index::mmap_inverted_index::MmapInvertedIndex;
 use super::inverted_index::mutable_inverted_index::MutableInvertedIndex;
 use super::inverted_index::{ARRAY_BOUNDARY_SENTINEL, Document, InvertedIndex, TokenSet};
+use super::stopwords_language::LanguageSample;
 use super::text_index::FullTextIndex;
 use super::tokenizers::Tokenizer;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::index::TextIndexParams;
+use crate::data_types::index::{Language, StopwordsInterface, TextIndexParams};
 use crate::index::field_index::full_text_index::immutable_text_index::ImmutableFullTextIndex;
 use crate::index::field_index::{FieldIndexBuilderTrait, ValueIndexer};
 
+const CONFIG_PATH: &str = "mmap_field_index_config.json";
+
+/// Written once during the build, only if the stopwords language is detected automatically
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MmapFullTextIndexConfig {
+    /// Detected language of the stopwords, `None` if detection failed
+    stopwords_language: Option<Language>,
+}
+
 pub struct MmapFullTextIndex {
     pub(super) inverted_index: MmapInvertedIndex,
     pub(super) tokenizer: Tokenizer,
+    pub(super) auto_stopwords: AutoStopwords,
+    config: Option<MmapFullTextIndexConfig>,
 }
 
 impl MmapFullTextIndex {
@@ -33,26 +49,60 @@ impl MmapFullTextIndex {
         let populate = !is_on_disk;
 
         let has_positions = config.phrase_matching == Some(true);
-        let tokenizer = Tokenizer::new_from_text_index_params(&config);
+        let mut tokenizer = Tokenizer::new_from_text_index_params(&config);
 
-        let inverted_index =
-            MmapInvertedIndex::open(path, populate, has_positions, deleted_points)?;
-        Ok(inverted_index.map(|inverted_index| Self {
+        let config_path = path.join(CONFIG_PATH);
+        let Some(inverted_index) =
+            MmapInvertedIndex::open(path, populate, has_positions, deleted_points)?
+        else {
+            return Ok(None);
+        };
+
+        // Use the stopwords language detected during the build, don't detect it again
+        let index_config: Option<MmapFullTextIndexConfig> = config_path
+            .is_file()
+            .then(|| read_json(&config_path))
+            .transpose()?;
+        if let Some(language) = index_config
+            .as_ref()
+            .and_then(|index_config| index_config.stopwords_language.as_ref())
+        {
+            tokenizer = tokenizer.with_language_stopwords(language);
+        }
+
+        Ok(Some(Self {
             inverted_index,
             tokenizer,
+            auto_stopwords: AutoStopwords::default(),
+            config: index_config,
         }))
     }
 
+    /// Language of the stopwords, detected when the index was built
+    pub fn stopwords_language(&self) -> Option<Language> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.stopwords_language)
+    }
+
     pub(crate) fn ram_usage_bytes(&self) -> usize {
         self.inverted_index.ram_usage_bytes()
     }
 
     pub fn files(&self) -> Vec<PathBuf> {
-        self.inverted_index.files()
+        let mut files = self.inverted_index.files();
+        if self.config.is_some() {
+            files.push(self.path().join(CONFIG_PATH));
+        }
+        files
     }
 
     pub fn immutable_files(&self) -> Vec<PathBuf> {
-        self.inverted_index.immutable_files()
+        let mut files = self.inverted_index.immutable_files();
+        if self.config.is_some() {
+            files.push(self.path().join(CONFIG_PATH));
+        }
+        files
     }
 
     fn path(&self) -> &PathBuf {
@@ -90,6 +140,12 @@ impl MmapFullTextIndex {
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        self.inverted_index.populate_with_budget(budget)?;
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         self.inverted_index.clear_cache()?;
@@ -104,6 +160,9 @@ pub struct FullTextMmapIndexBuilder {
     is_on_disk: bool,
     tokenizer: Tokenizer,
     deleted_points: BitVec,
+    /// First documents, not indexed until the stopwords language is detected from them
+    language_sample: Option<LanguageSample>,
+    stopwords_language: Option<Language>,
 }
 
 impl FullTextMmapIndexBuilder {
@@ -115,42 +174,53 @@ impl FullTextMmapIndexBuilder {
     ) -> Self {
         let with_positions = config.phrase_matching.unwrap_or_default();
         let tokenizer = Tokenizer::new_from_text_index_params(&config);
+        let language_sample = matches!(config.stopwords, Some(StopwordsInterface::Auto(_)))
+            .then(LanguageSample::default);
         Self {
             path,
-            mutable_index: MutableInvertedIndex::new(with_positions),
+            // The build only needs the token ids of the points for the forward index
+            mutable_index: MutableInvertedIndex::new(with_positions, false),
             config,
             is_on_disk,
             tokenizer,
             deleted_points: deleted_points.to_owned(),
+            language_sample,
+            stopwords_language: None,
         }
     }
-}
 
-impl ValueIndexer for FullTextMmapIndexBuilder {
-    type ValueType = String;
+    /// Detect the stopwords language from the sampled documents, and index them with its stopwords
+    fn finish_language_detection(
+        &mut self,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let Some(sample) = self.language_sample.take() else {
+            return Ok(());
+        };
 
-    fn get_value(value: &Value) -> Option<String> {
-        match value {
-            Value::String(s) => Some(s.clone()),
-            _ => None,
+        self.stopwords_language = sample.detect();
+        if let Some(language) = &self.stopwords_language {
+            self.tokenizer = self.tokenizer.with_language_stopwords(language);
+        }
+
+        for (id, values) in sample.into_documents() {
+            self.index_values(id, &values, hw_counter)?;
         }
+        Ok(())
     }
 
-    fn add_many(
+    fn index_values(
         &mut self,
         id: PointOffsetType,
-        values: Vec<Self::ValueType>,
+        values: &[String],
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        if values.is_empty() {
-            return Ok(());
-        }
-
         let phrase_matching = self.mutable_index.point_to_doc.is_some();
         let insert_boundaries = phrase_matching && values.len() > 1;
 
         let mut str_tokens: Vec<std::borrow::Cow<str>> =
             Vec::with_capacity((values.len() * 2).saturating_sub(1));
+        let mut unstemmed_str_tokens: Vec<std::borrow::Cow<str>> = Vec::new();
         for (i, value) in values.iter().enumerate() {
             if insert_boundaries && i > 0 {
                 str_tokens.push(std::borrow::Cow::Borrowed(ARRAY_BOUNDARY_SENTINEL));
@@ -158,9 +228,13 @@ impl ValueIndexer for FullTextMmapIndexBuilder {
             self.tokenizer.tokenize_doc(value, |token| {
                 str_tokens.push(token);
             });
+            self.tokenizer.tokenize_unstemmed_doc(value, |token| {
+                unstemmed_str_tokens.push(token);
+            });
         }
 
         let tokens = self.mutable_index.register_tokens(&str_tokens);
+        let unstemmed_tokens = self.mutable_index.register_tokens(&unstemmed_str_tokens);
 
         if phrase_matching {
             let document = Document::new(tokens.clone());
@@ -168,13 +242,48 @@ impl ValueIndexer for FullTextMmapIndexBuilder {
                 .index_document(id, document, hw_counter)?;
         }
 
-        let token_set = TokenSet::from_iter(tokens);
+        let token_set = TokenSet::from_iter(tokens.into_iter().chain(unstemmed_tokens));
         self.mutable_index.index_tokens(id, token_set, hw_counter)?;
 
         Ok(())
     }
+}
+
+impl ValueIndexer for FullTextMmapIndexBuilder {
+    type ValueType = String;
+
+    fn get_value(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    fn add_many(
+        &mut self,
+        id: PointOffsetType,
+        values: Vec<Self::ValueType>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(sample) = &mut self.language_sample {
+            sample.add(id, values);
+            if sample.is_complete() {
+                self.finish_language_detection(hw_counter)?;
+            }
+            return Ok(());
+        }
+
+        self.index_values(id, &values, hw_counter)
+    }
 
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        if let Some(sample) = &mut self.language_sample {
+            sample.remove(id);
+        }
         self.mutable_index.remove(id);
 
         Ok(())
@@ -197,7 +306,17 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
         ValueIndexer::add_point(self, id, payload, hw_counter)
     }
 
-    fn finalize(self) -> OperationResult<Self::FieldIndexType> {
+    fn finalize(mut self) -> OperationResult<Self::FieldIndexType> {
+        let index_config = if matches!(self.config.stopwords, Some(StopwordsInterface::Auto(_))) {
+            // If there are fewer documents than the sample size, detect from all of them
+            self.finish_language_detection(&HardwareCounterCell::disposable())?;
+            Some(MmapFullTextIndexConfig {
+                stopwords_language: self.stopwords_language,
+            })
+        } else {
+            None
+        };
+
         let Self {
             path,
             mutable_index,
@@ -205,13 +324,23 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
             is_on_disk,
             tokenizer,
             deleted_points,
+            language_sample: _,
+            stopwords_language: _,
         } = self;
 
         let immutable = ImmutableInvertedIndex::from(mutable_index);
 
         fs::create_dir_all(path.as_path())?;
 
-        MmapInvertedIndex::create(path.clone(), &immutable)?;
+        MmapInvertedIndex::create(
+            path.clone(),
+            &immutable,
+            config.compression,
+            config.forward_index.unwrap_or_default(),
+        )?;
+        if let Some(index_config) = &index_config {
+            atomic_save_json(&path.join(CONFIG_PATH), index_config)?;
+        }
 
         let populate = !is_on_disk;
         let has_positions = config.phrase_matching.unwrap_or_default();
@@ -227,6 +356,8 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
         let mmap_index = MmapFullTextIndex {
             inverted_index,
             tokenizer,
+            auto_stopwords: AutoStopwords::default(),
+            config: index_config,
         };
 
         let text_index = if is_on_disk {
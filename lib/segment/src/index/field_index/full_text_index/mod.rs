@@ -1,8 +1,11 @@
+mod auto_stopwords;
 mod immutable_text_index;
 mod inverted_index;
 pub mod mmap_text_index;
 mod mutable_text_index;
+pub mod snippets;
 pub mod stop_words;
+mod stopwords_language;
 pub mod text_index;
 pub mod tokenizers;
 
@@ -7,6 +7,7 @@ use gridstore::Gridstore;
 use gridstore::config::StorageOptions;
 use itertools::Itertools;
 
+use super::auto_stopwords::AutoStopwords;
 use super::inverted_index::mutable_inverted_index::MutableInvertedIndex;
 use super::inverted_index::mutable_inverted_index_builder::MutableInvertedIndexBuilder;
 use super::inverted_index::{ARRAY_BOUNDARY_SENTINEL, Document, InvertedIndex, TokenSet};
@@ -30,6 +31,7 @@ pub struct MutableFullTextIndex {
     pub(super) config: TextIndexParams,
     pub(super) storage: Storage,
     pub(super) tokenizer: Tokenizer,
+    pub(super) auto_stopwords: AutoStopwords,
 }
 
 pub(super) enum Storage {
@@ -65,12 +67,13 @@ impl MutableFullTextIndex {
         };
 
         let phrase_matching = config.phrase_matching.unwrap_or_default();
+        let forward_index = config.forward_index.unwrap_or_default();
         let tokenizer = Tokenizer::new_from_text_index_params(&config);
 
         let hw_counter = HardwareCounterCell::disposable();
         let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
 
-        let mut builder = MutableInvertedIndexBuilder::new(phrase_matching);
+        let mut builder = MutableInvertedIndexBuilder::new(phrase_matching, forward_index);
 
         store
             .iter::<_, OperationError>(
@@ -92,6 +95,7 @@ impl MutableFullTextIndex {
             config,
             storage: Storage::Gridstore(store),
             tokenizer,
+            auto_stopwords: AutoStopwords::default(),
         }))
     }
 
@@ -163,6 +167,7 @@ impl MutableFullTextIndex {
 
         let mut str_tokens: Vec<Cow<str>> =
             Vec::with_capacity((values.len() * 2).saturating_sub(1));
+        let mut unstemmed_str_tokens: Vec<Cow<str>> = Vec::new();
         for (i, value) in values.iter().enumerate() {
             if insert_boundaries && i > 0 {
                 str_tokens.push(Cow::Borrowed(ARRAY_BOUNDARY_SENTINEL));
@@ -170,9 +175,13 @@ impl MutableFullTextIndex {
             self.tokenizer.tokenize_doc(value, |token| {
                 str_tokens.push(token);
             });
+            self.tokenizer.tokenize_unstemmed_doc(value, |token| {
+                unstemmed_str_tokens.push(token);
+            });
         }
 
         let tokens = self.inverted_index.register_tokens(&str_tokens);
+        let unstemmed_tokens = self.inverted_index.register_tokens(&unstemmed_str_tokens);
 
         if phrase_matching {
             let document = Document::new(tokens.clone());
@@ -180,10 +189,13 @@ impl MutableFullTextIndex {
                 .index_document(idx, document, hw_counter)?;
         }
 
-        let token_set = TokenSet::from_iter(tokens);
+        let token_set = TokenSet::from_iter(tokens.into_iter().chain(unstemmed_tokens));
         self.inverted_index
             .index_tokens(idx, token_set, hw_counter)?;
 
+        // Original tokens go after the document, they are not a part of the phrase
+        str_tokens.extend(unstemmed_str_tokens);
+
         let tokens_to_store = if phrase_matching {
             // store ordered tokens
             str_tokens
@@ -331,6 +343,15 @@ mod tests {
             stemmer: None,
             ascii_folding: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         {
@@ -0,0 +1,270 @@
+// Excerpts of text values around the occurrences of query tokens, for highlighting matches.
+
+use ahash::AHashSet;
+
+use crate::data_types::text_query::{
+    SnippetParams, TextHighlight, TextSnippet, TextTokenMatch, TextValueMatches,
+};
+
+/// Consecutive occurrences of query tokens in a value, which fit into an excerpt
+struct Window {
+    /// Position of the first occurrence, among not yet highlighted ones
+    first: usize,
+    /// Position of the last occurrence, inclusive
+    last: usize,
+    /// Number of distinct tokens, then number of occurrences
+    score: (usize, usize),
+}
+
+/// Pick the best excerpts of the text `values` around the occurrences of query tokens.
+///
+/// An excerpt is better if it contains more distinct query tokens, and then more occurrences.
+/// Excerpts are picked from all values, so for an array field the best one may come from any of
+/// its elements. Each occurrence is highlighted in one excerpt at most.
+///
+/// Excerpts are snapped to word boundaries, and returned from the best one.
+pub fn build_snippets(
+    values: &[(String, TextValueMatches)],
+    params: SnippetParams,
+) -> Vec<TextSnippet> {
+    let SnippetParams { length, count } = params;
+
+    // Occurrences of each value, which are not highlighted yet
+    let mut remaining: Vec<Vec<&TextTokenMatch>> = values
+        .iter()
+        .map(|(_, value)| value.matches.iter().collect())
+        .collect();
+
+    let mut snippets = Vec::new();
+    while snippets.len() < count {
+        let mut best: Option<(usize, Window)> = None;
+        for (value, matches) in remaining.iter().enumerate() {
+            if let Some(window) = best_window(matches, length)
+                && best
+                    .as_ref()
+                    .is_none_or(|(_, best)| window.score > best.score)
+            {
+                best = Some((value, window));
+            }
+        }
+        let Some((value, window)) = best else {
+            break;
+        };
+
+        let (text, TextValueMatches { value_index, .. }) = &values[value];
+        let chars: Vec<char> = text.chars().collect();
+
+        let matches = &remaining[value][window.first..=window.last];
+        let span_start = matches[0].char_start;
+        let span_end = matches
+            .iter()
+            .map(|m| m.char_end)
+            .max()
+            .unwrap_or(span_start);
+        let (start, end) = excerpt_range(&chars, span_start, span_end, length);
+
+        let (highlighted, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut remaining[value])
+            .into_iter()
+            .partition(|m| m.char_start >= start && m.char_end <= end);
+        remaining[value] = rest;
+
+        snippets.push(TextSnippet {
+            value_index: *value_index,
+            char_start: start,
+            text: chars[start..end].iter().collect(),
+            highlights: highlighted
+                .into_iter()
+                .map(|m| TextHighlight {
+                    token: m.token.clone(),
+                    start: m.char_start - start,
+                    end: m.char_end - start,
+                })
+                .collect(),
+        });
+    }
+
+    snippets
+}
+
+/// Best window of consecutive `matches`, which spans at most `length` chars.
+///
+/// A single occurrence makes a window, even if it's longer.
+fn best_window(matches: &[&TextTokenMatch], length: usize) -> Option<Window> {
+    let mut best: Option<Window> = None;
+    for first in 0..matches.len() {
+        let start = matches[first].char_start;
+        let last = first
+            + matches[first + 1..]
+                .iter()
+                .take_while(|m| m.char_end.saturating_sub(start) <= length)
+                .count();
+
+        let distinct = matches[first..=last]
+            .iter()
+            .map(|m| m.token.as_str())
+            .collect::<AHashSet<_>>()
+            .len();
+        let window = Window {
+            first,
+            last,
+            score: (distinct, last - first + 1),
+        };
+
+        if best.as_ref().is_none_or(|best| window.score > best.score) {
+            best = Some(window);
+        }
+    }
+    best
+}
+
+/// Chars of the excerpt of `length` around the occurrences at `span_start..span_end`.
+///
+/// The excerpt is snapped to word boundaries, without cutting the occurrences.
+fn excerpt_range(
+    chars: &[char],
+    span_start: usize,
+    span_end: usize,
+    length: usize,
+) -> (usize, usize) {
+    let slack = length.saturating_sub(span_end - span_start);
+    let mut start = span_start.saturating_sub(slack / 2);
+    let mut end = (start + length).max(span_end).min(chars.len());
+    // Near the end of the value, use the rest of the slack before the occurrences
+    start = start.min(end.saturating_sub(length));
+
+    if start > 0
+        && !chars[start - 1].is_whitespace()
+        && let Some(offset) = chars[start..span_start]
+            .iter()
+            .position(|c| c.is_whitespace())
+    {
+        start += offset + 1;
+    }
+    if end < chars.len()
+        && !chars[end].is_whitespace()
+        && let Some(offset) = chars[span_end..end].iter().rposition(|c| c.is_whitespace())
+    {
+        end = span_end + offset;
+    }
+
+    while start < span_start && chars[start].is_whitespace() {
+        start += 1;
+    }
+    while end > span_end && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text value with the occurrences of the `tokens`, which are whole words of the `text`
+    fn value(value_index: usize, text: &str, tokens: &[&str]) -> (String, TextValueMatches) {
+        let mut matches = Vec::new();
+        let mut char_start = 0;
+        for word in text.split(' ') {
+            let char_end = char_start + word.chars().count();
+            if tokens.contains(&word) {
+                matches.push(TextTokenMatch {
+                    token: word.to_string(),
+                    start: char_start,
+                    end: char_end,
+                    char_start,
+                    char_end,
+                });
+            }
+            char_start = char_end + 1;
+        }
+        (
+            text.to_string(),
+            TextValueMatches {
+                value_index,
+                matches,
+            },
+        )
+    }
+
+    fn highlights(snippet: &TextSnippet) -> Vec<&str> {
+        let chars: Vec<char> = snippet.text.chars().collect();
+        snippet
+            .highlights
+            .iter()
+            .map(|highlight| {
+                let text: String = chars[highlight.start..highlight.end].iter().collect();
+                assert_eq!(text, highlight.token);
+                highlight.token.as_str()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_snippet_around_matches() {
+        let values = [value(
+            0,
+            "one two three four five six seven eight nine ten",
+            &["three", "nine"],
+        )];
+
+        // Both occurrences fit into one excerpt
+        let snippets = build_snippets(&values, SnippetParams::default());
+        assert_eq!(snippets.len(), 1);
+        assert_eq!(snippets[0].char_start, 0);
+        assert_eq!(snippets[0].text, values[0].0);
+        assert_eq!(highlights(&snippets[0]), ["three", "nine"]);
+
+        // Short excerpts are snapped to word boundaries
+        let params = SnippetParams {
+            length: 12,
+            count: 5,
+        };
+        let snippets = build_snippets(&values, params);
+        let excerpts = snippets
+            .iter()
+            .map(|snippet| (snippet.char_start, snippet.text.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(excerpts, [(8, "three"), (40, "nine ten")]);
+        assert_eq!(highlights(&snippets[1]), ["nine"]);
+
+        // Occurrences longer than the excerpt are not cut
+        let values = [value(
+            0,
+            "supercalifragilistic word",
+            &["supercalifragilistic"],
+        )];
+        let params = SnippetParams {
+            length: 5,
+            count: 1,
+        };
+        let snippets = build_snippets(&values, params);
+        assert_eq!(snippets[0].text, "supercalifragilistic");
+    }
+
+    #[test]
+    fn test_best_snippet_across_values() {
+        let values = [
+            value(0, "fox fox fox", &["fox"]),
+            value(1, "no matches here", &[]),
+            value(2, "quick brown fox", &["quick", "fox"]),
+        ];
+        let params = SnippetParams {
+            length: 100,
+            count: 3,
+        };
+
+        // Distinct tokens are preferred over repeated occurrences
+        let snippets = build_snippets(&values, params);
+        let excerpts = snippets
+            .iter()
+            .map(|snippet| (snippet.value_index, highlights(snippet)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            excerpts,
+            [(2, vec!["quick", "fox"]), (0, vec!["fox", "fox", "fox"])],
+        );
+
+        assert!(build_snippets(&values[1..2], params).is_empty());
+    }
+}
@@ -87,6 +87,12 @@ impl StopwordsFilter {
                         }
                     }
 
+                    if let Some(exclude) = set.exclude.as_ref() {
+                        for word in exclude {
+                            this.remove_stopword(word, lowercase);
+                        }
+                    }
+
                     if let Some(custom) = set.custom.as_ref() {
                         // If custom stopwords are provided, add them
                         for word in custom {
@@ -94,17 +100,33 @@ impl StopwordsFilter {
                         }
                     }
                 }
+                // Stopwords of the language are added once it's detected, see `with_language`
+                StopwordsInterface::Auto(_) => {}
             }
         }
 
         this
     }
 
+    /// Copy of this filter, extended with stopwords of the given `language`
+    pub fn with_language(&self, language: &Language, lowercase: bool) -> Self {
+        let mut this = self.clone();
+        this.add_language_stopwords(language, lowercase);
+        this
+    }
+
     /// Check if a token is a stopword
     pub fn is_stopword(&self, token: &str) -> bool {
         self.stopwords.contains(token)
     }
 
+    /// All stopwords, sorted
+    pub fn stopwords(&self) -> Vec<String> {
+        let mut stopwords: Vec<_> = self.stopwords.iter().cloned().collect();
+        stopwords.sort_unstable();
+        stopwords
+    }
+
     fn add_stopword(&mut self, word: &str, lowercase: bool) {
         if lowercase {
             self.stopwords.insert(word.to_lowercase());
@@ -113,6 +135,14 @@ impl StopwordsFilter {
         }
     }
 
+    fn remove_stopword(&mut self, word: &str, lowercase: bool) {
+        if lowercase {
+            self.stopwords.remove(&word.to_lowercase());
+        } else {
+            self.stopwords.remove(word);
+        }
+    }
+
     /// Add stopwords for a specific language
     fn add_language_stopwords(&mut self, language: &Language, lowercase: bool) {
         let stopwords_array = match language {
@@ -157,6 +187,7 @@ impl StopwordsFilter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::data_types::index::StopwordsSet;
 
     #[test]
     fn test_empty_stopwords() {
@@ -202,6 +233,45 @@ mod tests {
         assert!(!filter.is_stopword("programming"));
     }
 
+    #[test]
+    fn test_multiple_languages_stopwords() {
+        let languages = [Language::English, Language::French, Language::Hungarian];
+        let option = Some(StopwordsInterface::new_set(&languages, &[]));
+        let filter = StopwordsFilter::new(&option, true);
+
+        assert!(filter.is_stopword("the"));
+        assert!(filter.is_stopword("et"));
+        assert!(filter.is_stopword("és"));
+        assert!(!filter.is_stopword("qdrant"));
+
+        // Words shared by the languages, like "a", are stored once
+        let lists = [ENGLISH_STOPWORDS, FRENCH_STOPWORDS, HUNGARIAN_STOPWORDS];
+        let distinct_words: AHashSet<_> = lists
+            .into_iter()
+            .flatten()
+            .map(|word| word.to_lowercase())
+            .collect();
+        assert!(distinct_words.contains("a"));
+        assert_eq!(filter.stopwords, distinct_words);
+    }
+
+    #[test]
+    fn test_excluded_stopwords() {
+        let option = Some(StopwordsInterface::Set(StopwordsSet {
+            languages: Some([Language::Dutch].into()),
+            custom: Some(["click".to_string(), "http".to_string()].into()),
+            exclude: Some(["Van".to_string(), "click".to_string()].into()),
+        }));
+        let filter = StopwordsFilter::new(&option, true);
+
+        assert!(filter.is_stopword("de"));
+        assert!(!filter.is_stopword("van"));
+
+        // Custom stopwords are not excluded
+        assert!(filter.is_stopword("click"));
+        assert!(filter.is_stopword("http"));
+    }
+
     #[test]
     fn test_case_sensitivity() {
         let option = Some(StopwordsInterface::new_custom(&["Hello", "World"]));
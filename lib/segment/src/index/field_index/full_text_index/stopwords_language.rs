@@ -0,0 +1,152 @@
+use common::types::PointOffsetType;
+use whatlang::Lang;
+
+use crate::data_types::index::Language;
+
+/// Number of the first indexed documents, sampled to detect the language of stopwords
+const SAMPLE_DOCUMENTS_COUNT: usize = 100;
+
+/// Max size of the sampled text in bytes, longer samples are cut.
+/// Classification quality doesn't improve much beyond this size.
+const MAX_SAMPLE_BYTES: usize = 64 * 1024;
+
+/// Min confidence of the detection to use the stopwords of the detected language.
+/// Mixed language samples are detected with low confidence.
+const MIN_CONFIDENCE: f64 = 0.5;
+
+/// The first indexed documents, buffered until the language of their stopwords is detected.
+///
+/// Documents can only be tokenized once the stopwords are known.
+#[derive(Debug, Default)]
+pub struct LanguageSample {
+    documents: Vec<(PointOffsetType, Vec<String>)>,
+}
+
+impl LanguageSample {
+    pub fn add(&mut self, id: PointOffsetType, values: Vec<String>) {
+        self.documents.push((id, values));
+    }
+
+    pub fn remove(&mut self, id: PointOffsetType) {
+        self.documents.retain(|(document_id, _)| *document_id != id);
+    }
+
+    /// Whether enough documents are sampled to detect the language
+    pub fn is_complete(&self) -> bool {
+        self.documents.len() >= SAMPLE_DOCUMENTS_COUNT
+    }
+
+    /// Detect the language of the sampled documents with a trigram classifier.
+    ///
+    /// Returns `None` if the confidence is too low, or there are no stopwords for the language.
+    pub fn detect(&self) -> Option<Language> {
+        let mut text = String::new();
+        for value in self.documents.iter().flat_map(|(_, values)| values) {
+            let remaining = MAX_SAMPLE_BYTES.saturating_sub(text.len());
+            if remaining == 0 {
+                break;
+            }
+            text.push_str(&value[..value.floor_char_boundary(remaining)]);
+            text.push('\n');
+        }
+
+        let info = whatlang::detect(&text)?;
+        if info.confidence() < MIN_CONFIDENCE {
+            return None;
+        }
+        stopwords_language(info.lang())
+    }
+
+    pub fn into_documents(self) -> Vec<(PointOffsetType, Vec<String>)> {
+        self.documents
+    }
+}
+
+/// Language with pre-defined stopwords, matching the detected one
+fn stopwords_language(lang: Lang) -> Option<Language> {
+    let language = match lang {
+        Lang::Ara => Language::Arabic,
+        Lang::Aze => Language::Azerbaijani,
+        Lang::Ben => Language::Bengali,
+        Lang::Cat => Language::Catalan,
+        Lang::Cmn => Language::Chinese,
+        Lang::Dan => Language::Danish,
+        Lang::Nld => Language::Dutch,
+        Lang::Eng => Language::English,
+        Lang::Fin => Language::Finnish,
+        Lang::Fra => Language::French,
+        Lang::Deu => Language::German,
+        Lang::Ell => Language::Greek,
+        Lang::Heb => Language::Hebrew,
+        Lang::Hun => Language::Hungarian,
+        Lang::Ind => Language::Indonesian,
+        Lang::Ita => Language::Italian,
+        Lang::Jpn => Language::Japanese,
+        Lang::Nep => Language::Nepali,
+        Lang::Nob => Language::Norwegian,
+        Lang::Por => Language::Portuguese,
+        Lang::Ron => Language::Romanian,
+        Lang::Rus => Language::Russian,
+        Lang::Slv => Language::Slovene,
+        Lang::Spa => Language::Spanish,
+        Lang::Swe => Language::Swedish,
+        Lang::Tur => Language::Turkish,
+        _ => return None,
+    };
+    Some(language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(texts: &[&str]) -> LanguageSample {
+        let mut sample = LanguageSample::default();
+        for (id, text) in texts.iter().enumerate() {
+            sample.add(id as PointOffsetType, vec![text.to_string()]);
+        }
+        sample
+    }
+
+    #[test]
+    fn test_detect_language() {
+        let english = sample(&[
+            "The quick brown fox jumps over the lazy dog, and then it runs into the forest.",
+            "It was the best of times, it was the worst of times, it was the age of wisdom.",
+        ]);
+        assert_eq!(english.detect(), Some(Language::English));
+
+        let french = sample(&[
+            "Le petit prince est un livre qui raconte l'histoire d'un enfant venu d'ailleurs.",
+            "Il était une fois une reine qui vivait dans un château au bord de la mer.",
+        ]);
+        assert_eq!(french.detect(), Some(Language::French));
+
+        // Nothing to detect from
+        assert_eq!(sample(&["12345", "67 89"]).detect(), None);
+        assert_eq!(sample(&[]).detect(), None);
+    }
+
+    #[test]
+    fn test_sample_documents() {
+        let mut sample = LanguageSample::default();
+        for id in 0..SAMPLE_DOCUMENTS_COUNT as PointOffsetType {
+            assert!(!sample.is_complete());
+            sample.add(id, vec!["text".to_string()]);
+        }
+        assert!(sample.is_complete());
+
+        sample.remove(0);
+        assert!(!sample.is_complete());
+
+        let ids: Vec<_> = sample
+            .into_documents()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(
+            ids,
+            (1..SAMPLE_DOCUMENTS_COUNT as PointOffsetType).collect::<Vec<_>>()
+        );
+    }
+}
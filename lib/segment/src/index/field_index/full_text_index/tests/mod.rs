@@ -1,5 +1,8 @@
 mod test_congruence;
 
+use std::sync::Arc;
+
+use ahash::AHashSet;
 use common::bitvec::BitVec;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
@@ -167,6 +170,15 @@ fn test_prefix_search() {
         stemmer: None,
         ascii_folding: None,
         enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
     };
 
     let mut index =
@@ -187,7 +199,7 @@ fn test_prefix_search() {
     let res: Vec<_> = index.query("ROBO", &hw_counter).unwrap().collect();
 
     let query = index
-        .parse_text_query("ROBO", &hw_counter)
+        .parse_text_query("ROBO", None, false, &hw_counter)
         .unwrap()
         .unwrap();
 
@@ -202,12 +214,97 @@ fn test_prefix_search() {
 
     assert!(
         index
-            .parse_text_query("q231", &hw_counter)
+            .parse_text_query("q231", None, false, &hw_counter)
             .unwrap()
             .is_none()
     );
 }
 
+#[test]
+fn test_prefix_search_matches_prefix_scan() {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let min_gram = 2;
+    let max_gram = 5;
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Prefix,
+        min_token_len: Some(min_gram),
+        max_token_len: Some(max_gram),
+        lowercase: Some(true),
+        phrase_matching: None,
+        stopwords: None,
+        on_disk: None,
+        stemmer: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
+    };
+
+    let mut index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let texts = movie_titles();
+    let words: Vec<Vec<String>> = texts
+        .iter()
+        .map(|text| {
+            text.to_lowercase()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .collect();
+
+    for (i, text) in texts.iter().enumerate() {
+        index
+            .add_many(i as PointOffsetType, vec![text.clone()], &hw_counter)
+            .unwrap();
+    }
+
+    let mut prefixes: Vec<String> = words
+        .iter()
+        .flatten()
+        .flat_map(|word| {
+            let chars: Vec<char> = word.chars().collect();
+            (min_gram..=chars.len().min(max_gram + 2))
+                .map(move |len| chars[..len].iter().collect::<String>())
+        })
+        .collect();
+    prefixes.sort();
+    prefixes.dedup();
+
+    for prefix in prefixes {
+        let mut res: Vec<_> = index.query(&prefix, &hw_counter).unwrap().collect();
+        res.sort_unstable();
+
+        // Grams are bounded by the max length, so longer queries match by their longest gram
+        let matched_prefix: String = prefix.chars().take(max_gram).collect();
+        let expected: Vec<_> = words
+            .iter()
+            .enumerate()
+            .filter(|(_, doc_words)| {
+                doc_words
+                    .iter()
+                    .any(|word| word.starts_with(&matched_prefix))
+            })
+            .map(|(i, _)| i as PointOffsetType)
+            .collect();
+
+        assert_eq!(res, expected, "prefix: {prefix}");
+    }
+}
+
 #[test]
 fn test_phrase_matching() {
     let hw_counter = HardwareCounterCell::default();
@@ -226,6 +323,15 @@ fn test_phrase_matching() {
         stemmer: None,
         ascii_folding: None,
         enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
     };
 
     let mut mutable_index =
@@ -265,7 +371,7 @@ fn test_phrase_matching() {
     let check_matching = |index: FullTextIndex| {
         // Test regular text matching (should match documents containing all tokens regardless of order)
         let text_query = index
-            .parse_text_query("quick brown fox", &hw_counter)
+            .parse_text_query("quick brown fox", None, false, &hw_counter)
             .unwrap()
             .unwrap();
         assert!(index.check_match(&text_query, 0).unwrap());
@@ -359,6 +465,15 @@ fn test_ascii_folding_in_full_text_index_word() {
         stemmer: None,
         ascii_folding: Some(true),
         enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
     };
     let config_disabled = TextIndexParams {
         ascii_folding: Some(false),
@@ -398,7 +513,7 @@ fn test_ascii_folding_in_full_text_index_word() {
 
     // ASCII-only queries should match only when folding is enabled
     let query_enabled = index_enabled
-        .parse_text_query("acao", &hw_counter)
+        .parse_text_query("acao", None, false, &hw_counter)
         .unwrap()
         .unwrap();
     assert!(index_enabled.check_match(&query_enabled, 0).unwrap());
@@ -410,7 +525,7 @@ fn test_ascii_folding_in_full_text_index_word() {
     assert!(results_enabled.contains(&0));
 
     let query_disabled_opt = index_disabled
-        .parse_text_query("acao", &hw_counter)
+        .parse_text_query("acao", None, false, &hw_counter)
         .unwrap();
     // Query might still parse, but should not match anything
     if let Some(query_disabled) = query_disabled_opt {
@@ -423,7 +538,7 @@ fn test_ascii_folding_in_full_text_index_word() {
 
     // Non-folded query must work in both
     let query_acento = index_enabled
-        .parse_text_query("ação", &hw_counter)
+        .parse_text_query("ação", None, false, &hw_counter)
         .unwrap()
         .unwrap();
     assert!(index_enabled.check_match(&query_acento, 0).unwrap());
@@ -434,7 +549,7 @@ fn test_ascii_folding_in_full_text_index_word() {
     assert!(results_acento.contains(&0));
 
     let query_acento2 = index_disabled
-        .parse_text_query("ação", &hw_counter)
+        .parse_text_query("ação", None, false, &hw_counter)
         .unwrap()
         .unwrap();
     let results_acento2: Vec<_> = index_disabled
@@ -471,6 +586,15 @@ fn test_special_check_condition_match_text_any() {
         stemmer: None,
         ascii_folding: None,
         enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
     };
 
     let mut index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
@@ -496,11 +620,13 @@ fn test_special_check_condition_match_text_any() {
         key: JsonPath::new("title"),
         r#match: Some(Match::TextAny(MatchTextAny {
             text_any: "good cheap".to_string(),
+            min_should_match: None,
         })),
         range: None,
         geo_bounding_box: None,
         geo_radius: None,
         geo_polygon: None,
+        geo_near_path: None,
         values_count: None,
         is_empty: None,
         is_null: None,
@@ -539,3 +665,596 @@ fn test_special_check_condition_match_text_any() {
         "MatchTextAny must not match 'neutral text' for query 'good cheap'"
     );
 }
+
+#[test]
+fn test_text_any_min_should_match() {
+    use crate::types::{MinShouldMatch, MinShouldMatchPercentage};
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
+    };
+
+    let mut index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    index
+        .add_many(0, vec!["good cheap hardware".to_string()], &hw_counter)
+        .unwrap();
+    index
+        .add_many(1, vec!["cheap hardware".to_string()], &hw_counter)
+        .unwrap();
+    index
+        .add_many(2, vec!["good software".to_string()], &hw_counter)
+        .unwrap();
+
+    let query_points = |min_should_match: Option<MinShouldMatch>| -> Vec<PointOffsetType> {
+        let Some(query) = index
+            .parse_text_any_query("good cheap hardware", min_should_match, &hw_counter)
+            .unwrap()
+        else {
+            return vec![];
+        };
+        let mut points: Vec<_> = index.filter_query(query, &hw_counter).unwrap().collect();
+        points.sort_unstable();
+        points
+    };
+
+    assert_eq!(query_points(None), vec![0, 1, 2]);
+    assert_eq!(query_points(Some(MinShouldMatch::Count(1))), vec![0, 1, 2]);
+    assert_eq!(query_points(Some(MinShouldMatch::Count(2))), vec![0, 1]);
+    assert_eq!(query_points(Some(MinShouldMatch::Count(3))), vec![0]);
+    assert_eq!(
+        query_points(Some(MinShouldMatch::Count(4))),
+        Vec::<PointOffsetType>::new()
+    );
+
+    let percentage = |percent| MinShouldMatch::Percentage(MinShouldMatchPercentage { percent });
+    // 50% of 3 tokens is rounded down to 1
+    assert_eq!(query_points(Some(percentage(50))), vec![0, 1, 2]);
+    assert_eq!(query_points(Some(percentage(70))), vec![0, 1]);
+    assert_eq!(query_points(Some(percentage(100))), vec![0]);
+
+    for point_id in 0..3 {
+        let query = index
+            .parse_text_any_query(
+                "good cheap hardware",
+                Some(MinShouldMatch::Count(2)),
+                &hw_counter,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            index.check_match(&query, point_id).unwrap(),
+            point_id != 2,
+            "point {point_id} must match only if it has at least 2 query tokens",
+        );
+    }
+}
+
+#[test]
+fn test_text_query_language_stopwords() {
+    use crate::data_types::index::Language;
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
+    };
+
+    let mut index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    index
+        .add_many(0, vec!["chat noir".to_string()], &hw_counter)
+        .unwrap();
+    index
+        .add_many(1, vec!["le chat".to_string()], &hw_counter)
+        .unwrap();
+
+    let query_points = |language: Option<Language>| -> Vec<PointOffsetType> {
+        let Some(query) = index
+            .parse_text_query("le chat", language, false, &hw_counter)
+            .unwrap()
+        else {
+            return vec![];
+        };
+        let mut points: Vec<_> = index.filter_query(query, &hw_counter).unwrap().collect();
+        points.sort_unstable();
+        points
+    };
+
+    // Field is indexed without stopwords, so "le" is a regular token
+    assert_eq!(query_points(None), vec![1]);
+    // French stopwords are removed from the query, only "chat" must match
+    assert_eq!(query_points(Some(Language::French)), vec![0, 1]);
+    // "le" is not an English stopword
+    assert_eq!(query_points(Some(Language::English)), vec![1]);
+}
+
+#[test]
+fn test_text_score_aggregation() {
+    use serde_json::json;
+
+    use crate::types::TextScoreAggregation;
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        ascii_folding: None,
+        enable_hnsw: None,
+        compression: None,
+        index_unstemmed: None,
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
+    };
+
+    let mut index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let reviews = json!(["great battery life", "battery died", "nice screen"]);
+    index.add_point(0, &[&reviews], &hw_counter).unwrap();
+
+    let query = index
+        .parse_score_query("battery life", &hw_counter)
+        .unwrap();
+    let score = |value: &serde_json::Value, aggregation| {
+        index
+            .score_values(&query, [value], aggregation, &hw_counter)
+            .unwrap()
+    };
+
+    // Best element matches both query tokens
+    assert_eq!(score(&reviews, TextScoreAggregation::Max), 1.0);
+    // 2/2 + 1/2 + 0/2
+    assert_eq!(score(&reviews, TextScoreAggregation::Sum), 1.5);
+
+    // Single value behaves as an array of one element
+    let single = json!("battery died");
+    assert_eq!(score(&single, TextScoreAggregation::Max), 0.5);
+    assert_eq!(score(&single, TextScoreAggregation::Sum), 0.5);
+
+    // Unseen query tokens still count towards the total
+    let query = index
+        .parse_score_query("battery unknown", &hw_counter)
+        .unwrap();
+    let max_score = index
+        .score_values(&query, [&reviews], TextScoreAggregation::Max, &hw_counter)
+        .unwrap();
+    assert_eq!(max_score, 0.5);
+
+    // Values of an array are scored separately, also if they are retrieved one by one
+    let values = reviews.as_array().unwrap();
+    let query = index
+        .parse_score_query("battery life", &hw_counter)
+        .unwrap();
+    let sum_score = index
+        .score_values(&query, values, TextScoreAggregation::Sum, &hw_counter)
+        .unwrap();
+    assert_eq!(sum_score, 1.5);
+}
+
+#[test]
+fn test_match_text_unstemmed() {
+    use crate::data_types::index::{Snowball, SnowballLanguage, SnowballParams, StemmingAlgorithm};
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: Some(true),
+        stopwords: None,
+        stemmer: Some(StemmingAlgorithm::Snowball(SnowballParams {
+            r#type: Snowball::Snowball,
+            language: SnowballLanguage::English,
+        })),
+        ascii_folding: None,
+        enable_hnsw: None,
+        compression: None,
+        index_unstemmed: Some(true),
+        max_value_bytes: None,
+        oversized_value_policy: None,
+        auto_stopwords_threshold: None,
+        strip_html: None,
+        decode_html_entities: None,
+        strip_html_queries: None,
+        forward_index: None,
+    };
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().to_path_buf(), config.clone())
+            .make_empty()
+            .unwrap();
+
+    let empty_deleted = BitVec::new();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().to_path_buf(), config, true, &empty_deleted);
+    mmap_builder.init().unwrap();
+
+    let documents = ["running shoes", "run fast", "he runs daily"];
+    for (point_id, text) in documents.iter().enumerate() {
+        let point_id = point_id as PointOffsetType;
+        mutable_index
+            .add_many(point_id, vec![text.to_string()], &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, vec![text.to_string()], &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+
+    let check_matching = |index: FullTextIndex| {
+        let query_points = |text: &str, unstemmed: bool| -> Vec<PointOffsetType> {
+            let Some(query) = index
+                .parse_text_query(text, None, unstemmed, &hw_counter)
+                .unwrap()
+            else {
+                return vec![];
+            };
+            let mut points: Vec<_> = index.filter_query(query, &hw_counter).unwrap().collect();
+            points.sort_unstable();
+            points
+        };
+
+        // Stemmed queries match all forms of the word
+        assert_eq!(query_points("running", false), vec![0, 1, 2]);
+        assert_eq!(query_points("run", false), vec![0, 1, 2]);
+
+        // Unstemmed queries match only the original form
+        assert_eq!(query_points("running", true), vec![0]);
+        assert_eq!(query_points("run", true), vec![1]);
+        assert_eq!(query_points("Runs Daily", true), vec![2]);
+
+        let query = index
+            .parse_text_query("running", None, true, &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert!(index.check_match(&query, 0).unwrap());
+        assert!(!index.check_match(&query, 1).unwrap());
+
+        // Original tokens are not a part of the phrase
+        let phrase_query = index
+            .parse_phrase_query("run fast", &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert!(index.check_match(&phrase_query, 1).unwrap());
+        assert!(!index.check_match(&phrase_query, 0).unwrap());
+    };
+
+    check_matching(mutable_index);
+    check_matching(mmap_index);
+}
+
+#[test]
+fn test_auto_stopwords() {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        auto_stopwords_threshold: Some(50),
+        ..Default::default()
+    };
+
+    let mut index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    for i in 0..150 {
+        let text = format!("common word{i}");
+        index
+            .add_many(i as PointOffsetType, vec![text], &hw_counter)
+            .unwrap();
+    }
+    for i in 150..160 {
+        index
+            .add_many(i as PointOffsetType, vec!["rare".to_string()], &hw_counter)
+            .unwrap();
+    }
+
+    // The index only reports candidates, the shard decides on the stopwords
+    assert_eq!(
+        index.frequent_tokens(0.5).unwrap(),
+        vec!["common".to_string()]
+    );
+    let tokens = ["common", "rare", "unseen"].map(String::from);
+    assert_eq!(
+        index.tokens_points_count(&tokens, &hw_counter).unwrap(),
+        vec![150, 10, 0],
+    );
+    assert!(index.detected_stopwords().is_empty());
+    assert_eq!(index.query("common", &hw_counter).unwrap().count(), 150);
+
+    index.set_detected_stopwords(Arc::new(AHashSet::from(["common".to_string()])));
+    assert_eq!(index.detected_stopwords(), vec!["common".to_string()]);
+
+    let query = index
+        .parse_text_any_query("common word7", None, &hw_counter)
+        .unwrap()
+        .unwrap();
+    let res: Vec<_> = index.filter_query(query, &hw_counter).unwrap().collect();
+    assert_eq!(res, vec![7]);
+
+    let res: Vec<_> = index.query("common word7", &hw_counter).unwrap().collect();
+    assert_eq!(res, vec![7]);
+    assert_eq!(index.query("rare", &hw_counter).unwrap().count(), 10);
+
+    // Assigned stopwords don't change on updates
+    for i in 160..400 {
+        index
+            .add_many(i as PointOffsetType, vec!["rare".to_string()], &hw_counter)
+            .unwrap();
+    }
+    assert_eq!(index.detected_stopwords(), vec!["common".to_string()]);
+}
+
+#[test]
+fn test_detected_stopwords_language() {
+    use crate::data_types::index::{Language, StopwordsDetection, StopwordsInterface};
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        stopwords: Some(StopwordsInterface::Auto(StopwordsDetection::Auto)),
+        forward_index: Some(true),
+        ..Default::default()
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+    let empty_deleted = BitVec::new();
+    let mut mmap_builder = FullTextIndex::builder_mmap(
+        temp_dir.path().to_path_buf(),
+        config.clone(),
+        true,
+        &empty_deleted,
+    );
+    mmap_builder.init().unwrap();
+
+    // More documents than sampled, so some of them are indexed after the detection
+    let titles = movie_titles();
+    for i in 0..150 {
+        let text = format!("The story of {}", titles[i % titles.len()]);
+        mmap_builder
+            .add_many(i as PointOffsetType, vec![text], &hw_counter)
+            .unwrap();
+    }
+
+    let index = mmap_builder.finalize().unwrap();
+    assert_eq!(index.stopwords_language(), Some(Language::English));
+    for point_id in [0, 149] {
+        let tokens = index.point_tokens(point_id, &hw_counter).unwrap();
+        assert!(tokens.contains(&"story".to_string()));
+        assert!(!tokens.contains(&"the".to_string()));
+        assert!(!tokens.contains(&"of".to_string()));
+    }
+    drop(index);
+
+    // Detected language is used after reload
+    let index =
+        FullTextIndex::new_mmap(temp_dir.path().to_path_buf(), config, true, &empty_deleted)
+            .unwrap()
+            .unwrap();
+    assert_eq!(index.stopwords_language(), Some(Language::English));
+    let query = index
+        .parse_text_query("the story", None, false, &hw_counter)
+        .unwrap()
+        .unwrap();
+    assert_eq!(index.filter_query(query, &hw_counter).unwrap().count(), 150);
+}
+
+#[test]
+fn test_effective_stopwords() {
+    use crate::data_types::index::{Language, StopwordsInterface, StopwordsSet};
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        stopwords: Some(StopwordsInterface::Set(StopwordsSet {
+            languages: Some([Language::French].into()),
+            custom: Some(["http".to_string()].into()),
+            exclude: Some(["le".to_string()].into()),
+        })),
+        ..Default::default()
+    };
+
+    let index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let stopwords = index.effective_stopwords();
+    assert!(stopwords.is_sorted());
+    assert!(stopwords.contains(&"http".to_string()));
+    assert!(stopwords.contains(&"la".to_string()));
+    assert!(!stopwords.contains(&"le".to_string()));
+}
+
+#[test]
+fn test_text_query_stopwords() {
+    use crate::data_types::index::{Language, StopwordsInterface};
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        stopwords: Some(StopwordsInterface::new_custom(&["the", "of"])),
+        ..Default::default()
+    };
+
+    let index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let stopwords = index.text_query_stopwords("The end of time", None);
+    assert_eq!(
+        stopwords.removed.into_iter().collect::<Vec<_>>(),
+        vec!["of".to_string(), "the".to_string()],
+    );
+    assert!(!stopwords.is_empty);
+
+    // Nothing but stopwords is left of the query
+    let stopwords = index.text_query_stopwords("the and", Some(Language::English));
+    assert_eq!(
+        stopwords.removed.into_iter().collect::<Vec<_>>(),
+        vec!["and".to_string(), "the".to_string()],
+    );
+    assert!(stopwords.is_empty);
+}
+
+#[test]
+fn test_text_match_offsets() {
+    use serde_json::json;
+
+    use crate::data_types::index::StopwordsInterface;
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        stopwords: Some(StopwordsInterface::new_custom(&["the"])),
+        ..Default::default()
+    };
+
+    let index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let first = json!(["The Dust of Death", 42, "Ödön and the dust"]);
+    let second = json!("Death of death");
+    let matches = index.text_match_offsets(&[&first, &second], "the dust, DEATH");
+
+    let offsets = matches
+        .iter()
+        .map(|value| {
+            let matches = value
+                .matches
+                .iter()
+                .map(|m| (m.token.as_str(), m.start..m.end, m.char_start..m.char_end))
+                .collect::<Vec<_>>();
+            (value.value_index, matches)
+        })
+        .collect::<Vec<_>>();
+
+    // Non-text values are skipped in numbering, stopwords are not reported
+    assert_eq!(
+        offsets,
+        vec![
+            (0, vec![("dust", 4..8, 4..8), ("death", 12..17, 12..17)]),
+            (1, vec![("dust", 15..19, 13..17)]),
+            (2, vec![("death", 0..5, 0..5), ("death", 9..14, 9..14)]),
+        ],
+    );
+
+    assert!(index.text_match_offsets(&[&second], "nothing").is_empty());
+}
+
+#[test]
+fn test_text_match_offsets_multibyte() {
+    use serde_json::json;
+
+    use crate::data_types::index::{Language, StopwordsInterface};
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        stopwords: Some(StopwordsInterface::Language(Language::French)),
+        ..Default::default()
+    };
+
+    let index = FullTextIndex::new_gridstore(temp_dir.path().to_path_buf(), config, true)
+        .unwrap()
+        .unwrap();
+
+    let text = "Le Château était à côté de l'Église";
+    let matches = index.text_match_offsets(&[&json!(text)], "château était côté église");
+    let offsets = matches[0]
+        .matches
+        .iter()
+        .map(|m| {
+            (
+                &text[m.start..m.end],
+                m.start..m.end,
+                m.char_start..m.char_end,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Offsets point to the original form of the words, accented stopwords are not reported
+    assert_eq!(
+        offsets,
+        vec![
+            ("Château", 3..11, 3..10),
+            ("côté", 22..28, 19..23),
+            ("Église", 34..41, 29..35),
+        ],
+    );
+}
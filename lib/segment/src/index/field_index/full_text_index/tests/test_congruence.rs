@@ -19,7 +19,7 @@ use crate::index::field_index::full_text_index::inverted_index::{
 use crate::index::field_index::full_text_index::mmap_text_index::FullTextMmapIndexBuilder;
 use crate::index::field_index::full_text_index::mutable_text_index::MutableFullTextIndex;
 use crate::index::field_index::full_text_index::text_index::{
-    FullTextGridstoreIndexBuilder, FullTextIndex,
+    FullTextGridstoreIndexBuilder, FullTextIndex, PayloadMatchQueryType,
 };
 use crate::index::field_index::{FieldIndexBuilderTrait, ValueIndexer};
 use crate::json_path::JsonPath;
@@ -228,7 +228,7 @@ pub fn parse_query(query: &[String], is_phrase: bool, index: &FullTextIndex) ->
     let tokens = resolve_tokens(index, query, &hw_counter).into_iter();
     match is_phrase {
         false => ParsedQuery::AllTokens(tokens.collect::<Option<TokenSet>>().unwrap()),
-        true => ParsedQuery::Phrase(tokens.collect::<Option<Document>>().unwrap()),
+        true => ParsedQuery::Phrase(tokens.collect::<Option<Document>>().unwrap().into()),
     }
 }
 
@@ -611,3 +611,71 @@ fn test_phrase_matching_single_element_array(
     results.sort();
     assert_eq!(results, vec![1, 2, 3]);
 }
+
+#[rstest]
+fn test_sloppy_phrase_matching(
+    #[values(IndexType::MutableGridstore, IndexType::ImmMmap, IndexType::ImmRamMmap)]
+    index_type: IndexType,
+) {
+    let hw = HardwareCounterCell::new();
+    let (mut builder, _temp_dir, _db) = create_builder(index_type, true);
+
+    let payloads = [
+        serde_json::json!("quick fox"),
+        serde_json::json!("quick brown fox"),
+        serde_json::json!("quick big brown fox"),
+        serde_json::json!("quick brown red big fox"),
+        // Out of order
+        serde_json::json!("fox brown quick"),
+        // Separate array elements
+        serde_json::json!(["quick", "fox"]),
+    ];
+    for (id, payload) in payloads.iter().enumerate() {
+        builder
+            .add_point(id as PointOffsetType, &[payload], &hw)
+            .unwrap();
+    }
+    let index = builder.finalize().unwrap();
+
+    let check = |phrase: &str, slop: u32, expected: &[PointOffsetType]| {
+        let query = index
+            .parse_sloppy_phrase_query(phrase, slop, &hw)
+            .unwrap()
+            .unwrap();
+
+        let mut results: Vec<_> = index.filter_query(query.clone(), &hw).unwrap().collect();
+        results.sort();
+        assert_eq!(results, expected, "phrase: {phrase}, slop: {slop}");
+
+        for id in 0..payloads.len() as PointOffsetType {
+            let is_match = index.check_match(&query, id).unwrap();
+            assert_eq!(is_match, expected.contains(&id), "id: {id}, slop: {slop}");
+        }
+    };
+
+    // Zero slop is an exact phrase
+    check("quick fox", 0, &[0]);
+    check("quick fox", 1, &[0, 1]);
+    // Exactly `slop` tokens in between
+    check("quick fox", 2, &[0, 1, 2]);
+    check("quick fox", 3, &[0, 1, 2, 3]);
+    // Order of the tokens and array boundaries are respected with any slop
+    check("quick fox", 100, &[0, 1, 2, 3]);
+    check("fox quick", 100, &[4]);
+    // Slop is shared by all gaps of the phrase
+    check("quick brown fox", 0, &[1]);
+    check("quick brown fox", 1, &[1, 2]);
+    check("quick big fox", 1, &[2]);
+    check("quick red fox", 1, &[]);
+    check("quick red fox", 2, &[3]);
+
+    let value = serde_json::json!("quick brown fox");
+    let check_payload = |slop| {
+        let query_type = PayloadMatchQueryType::Phrase { slop };
+        index
+            .check_payload_match(&value, "quick fox", query_type, &hw)
+            .unwrap()
+    };
+    assert!(!check_payload(0));
+    assert!(check_payload(1));
+}
@@ -1,22 +1,32 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
+use std::sync::Arc;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use common::bitvec::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::iterator_ext::IteratorExt;
+use common::populate_budget::PopulateBudget;
 use common::types::PointOffsetType;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::auto_stopwords::{AutoStopwords, frequent_tokens};
 use super::immutable_text_index::{ImmutableFullTextIndex, Storage};
-use super::inverted_index::{InvertedIndex, ParsedQuery, TokenId, TokenSet};
+use super::inverted_index::{
+    ARRAY_BOUNDARY_SENTINEL, InvertedIndex, ParsedQuery, PhraseQuery, TokenId, TokenSet,
+};
 use super::mmap_text_index::{FullTextMmapIndexBuilder, MmapFullTextIndex};
 use super::mutable_text_index::MutableFullTextIndex;
+use super::snippets::build_snippets;
 use super::tokenizers::Tokenizer;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::index::TextIndexParams;
+use crate::data_types::index::{Language, TextIndexParams};
+use crate::data_types::text_query::{
+    QueryStopwords, SnippetParams, TextSnippet, TextTokenMatch, TextValueMatches,
+};
 use crate::index::field_index::full_text_index::inverted_index::Document;
 use crate::index::field_index::full_text_index::tokenizers::TokenizerTextKind;
 use crate::index::field_index::{
@@ -25,16 +35,33 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, Match, MatchPhrase, MatchText, MatchTextAny, PayloadKeyType};
+use crate::types::{
+    FieldCondition, Match, MatchPhrase, MatchText, MatchTextAny, MinShouldMatch, PayloadKeyType,
+    TextScoreAggregation,
+};
 
 /// Selects how a text query is parsed and matched against the payload.
 pub enum PayloadMatchQueryType {
     /// All query tokens must be present in the document (any order).
-    Text,
-    /// All query tokens must be present in exact order.
-    Phrase,
-    /// At least one query token must be present.
-    TextAny,
+    /// Stopwords of the `language` are removed from the query, if specified.
+    /// If `unstemmed`, the original form of the tokens must be present.
+    Text {
+        language: Option<Language>,
+        unstemmed: bool,
+    },
+    /// All query tokens must be present in the same order,
+    /// with at most `slop` other tokens between them.
+    Phrase { slop: u32 },
+    /// At least one query token must be present, or as many as `min_should_match` requires.
+    TextAny(Option<MinShouldMatch>),
+}
+
+/// Query tokens of a text score, resolved in the vocabulary of the index.
+#[derive(Debug, Clone)]
+pub struct TextScoreQuery {
+    /// Number of query tokens, unseen ones can't be present in any value but still count
+    tokens_count: usize,
+    seen_tokens: TokenSet,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -114,7 +141,7 @@ impl FullTextIndex {
         FullTextGridstoreIndexBuilder::new(dir, config)
     }
 
-    pub(super) fn points_count(&self) -> usize {
+    pub fn points_count(&self) -> usize {
         match self {
             Self::Mutable(index) => index.inverted_index.points_count(),
             Self::Immutable(index) => index.inverted_index.points_count(),
@@ -147,6 +174,125 @@ impl FullTextIndex {
         }
     }
 
+    /// Distinct tokens stored in the index for the point, in the order of their token ids.
+    ///
+    /// Requires the `forward_index` in the index params.
+    pub fn point_tokens(
+        &self,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<String>> {
+        let tokens = match self {
+            Self::Mutable(index) => index.inverted_index.point_tokens(point_id),
+            Self::Immutable(index) => index.point_tokens(point_id, hw_counter)?,
+            Self::Mmap(index) => index.inverted_index.point_tokens(point_id, hw_counter)?,
+        };
+        let tokens = tokens.ok_or_else(|| {
+            OperationError::validation_error(
+                "Text index keeps no tokens of points, enable `forward_index` in its params",
+            )
+        })?;
+        Ok(tokens.into_iter().map(str::to_string).collect())
+    }
+
+    fn auto_stopwords(&self) -> &AutoStopwords {
+        match self {
+            Self::Mutable(index) => &index.auto_stopwords,
+            Self::Immutable(index) => &index.auto_stopwords,
+            Self::Mmap(index) => &index.auto_stopwords,
+        }
+    }
+
+    fn auto_stopwords_mut(&mut self) -> &mut AutoStopwords {
+        match self {
+            Self::Mutable(index) => &mut index.auto_stopwords,
+            Self::Immutable(index) => &mut index.auto_stopwords,
+            Self::Mmap(index) => &mut index.auto_stopwords,
+        }
+    }
+
+    /// Stopwords detected by the shard from the frequencies of the tokens and assigned to the
+    /// index, sorted
+    pub fn detected_stopwords(&self) -> Vec<String> {
+        self.auto_stopwords().stopwords()
+    }
+
+    /// Assign the stopwords detected by the shard, removed from the text queries from now on
+    pub fn set_detected_stopwords(&mut self, stopwords: Arc<AHashSet<String>>) {
+        self.auto_stopwords_mut().set(stopwords);
+    }
+
+    /// Tokens contained in at least `min_fraction` of the points of the index
+    pub fn frequent_tokens(&self, min_fraction: f64) -> OperationResult<Vec<String>> {
+        match self {
+            Self::Mutable(index) => frequent_tokens(&index.inverted_index, min_fraction),
+            Self::Immutable(index) => frequent_tokens(&index.inverted_index, min_fraction),
+            Self::Mmap(index) => frequent_tokens(&index.inverted_index, min_fraction),
+        }
+    }
+
+    /// Number of points containing each of the `tokens`, `0` for unseen tokens
+    pub fn tokens_points_count(
+        &self,
+        tokens: &[String],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<usize>> {
+        let mut token_ids = vec![None; tokens.len()];
+        self.for_each_token_id(
+            tokens.iter().map(String::as_str).enumerate(),
+            hw_counter,
+            |i, token_id| token_ids[i] = token_id,
+        )?;
+
+        token_ids
+            .into_iter()
+            .map(|token_id| {
+                let Some(token_id) = token_id else {
+                    return Ok(0);
+                };
+                let postings_len = match self {
+                    Self::Mutable(index) => {
+                        index.inverted_index.get_posting_len(token_id, hw_counter)?
+                    }
+                    Self::Immutable(index) => {
+                        index.inverted_index.get_posting_len(token_id, hw_counter)?
+                    }
+                    Self::Mmap(index) => {
+                        index.inverted_index.get_posting_len(token_id, hw_counter)?
+                    }
+                };
+                Ok(postings_len.unwrap_or(0))
+            })
+            .collect()
+    }
+
+    /// Stopwords removed from indexed texts and queries, as configured for the index, sorted.
+    ///
+    /// Includes the stopwords of the detected language, but not the stopwords detected from
+    /// the token frequencies, see [`Self::detected_stopwords`].
+    pub fn effective_stopwords(&self) -> Vec<String> {
+        self.get_tokenizer().stopwords()
+    }
+
+    /// Tokens of the `text`, as produced by the tokenizer of the index for documents.
+    ///
+    /// Runs the same pipeline as indexing: splitting, lowercasing, folding, stemming and removal
+    /// of the configured stopwords, including the stopwords of the detected language.
+    pub fn tokenize_debug(&self, text: &str) -> Vec<String> {
+        self.get_tokenizer().tokenize_debug(text)
+    }
+
+    /// Language of the stopwords, detected from the indexed texts
+    pub fn stopwords_language(&self) -> Option<Language> {
+        match self {
+            Self::Mutable(_) => None,
+            Self::Immutable(index) => match &index.storage {
+                Storage::Mmap(mmap_index) => mmap_index.stopwords_language(),
+            },
+            Self::Mmap(index) => index.stopwords_language(),
+        }
+    }
+
     fn get_tokenizer(&self) -> &Tokenizer {
         match self {
             Self::Mutable(index) => &index.tokenizer,
@@ -257,6 +403,11 @@ impl FullTextIndex {
             points_values_count: self.points_count(),
             points_count: self.points_count(),
             histogram_bucket_size: None,
+            write_amplification: None,
+            oversized_values: None,
+            stopwords_language: self.stopwords_language(),
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
         }
     }
 
@@ -267,36 +418,192 @@ impl FullTextIndex {
         phrase: &str,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<ParsedQuery>> {
-        let document = self.parse_document(phrase, hw_counter)?;
-        Ok(document.map(ParsedQuery::Phrase))
+        self.parse_sloppy_phrase_query(phrase, 0, hw_counter)
+    }
+
+    /// Same as [`Self::parse_phrase_query`], but allows up to `slop` other tokens between the
+    /// tokens of the phrase.
+    pub fn parse_sloppy_phrase_query(
+        &self,
+        phrase: &str,
+        slop: u32,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<ParsedQuery>> {
+        let Some(document) = self.parse_document(phrase, hw_counter)? else {
+            return Ok(None);
+        };
+
+        let mut boundary = None;
+        if slop > 0 {
+            let sentinel = std::iter::once(((), ARRAY_BOUNDARY_SENTINEL));
+            self.for_each_token_id(sentinel, hw_counter, |(), token_id| boundary = token_id)?;
+        }
+
+        let phrase = PhraseQuery::new(document, slop, boundary);
+        Ok(Some(ParsedQuery::Phrase(phrase)))
     }
 
     /// Parse as [`TokenizerTextKind::Query`] and return [`ParsedQuery::AllTokens`].
+    /// Detected stopwords are removed from the query.
+    /// If `language` is specified, its stopwords are removed from the query as well.
+    /// If `unstemmed`, parse as [`TokenizerTextKind::UnstemmedQuery`] instead.
     /// Returns [`None`] if there are any unseen tokens.
     pub fn parse_text_query(
         &self,
         text: &str,
+        language: Option<Language>,
+        unstemmed: bool,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<ParsedQuery>> {
-        let tokenset: Option<TokenSet> = self
-            .resolve_tokens(TokenizerTextKind::Query, text, hw_counter)?
-            .into_values()
-            .collect::<Option<TokenSet>>();
+        let kind = if unstemmed {
+            TokenizerTextKind::UnstemmedQuery
+        } else {
+            TokenizerTextKind::Query
+        };
+        let mut token_map = match language {
+            Some(language) => {
+                let tokenizer = self.get_tokenizer().with_language_stopwords(&language);
+                self.resolve_tokens_with(&tokenizer, kind, text, hw_counter)?
+            }
+            None => self.resolve_tokens(kind, text, hw_counter)?,
+        };
+        self.auto_stopwords().remove_from(&mut token_map);
+        let tokenset: Option<TokenSet> = token_map.into_values().collect::<Option<TokenSet>>();
         Ok(tokenset.map(ParsedQuery::AllTokens))
     }
 
-    /// Parse as [`TokenizerTextKind::Query`] and return [`ParsedQuery::AnyTokens`].
-    /// Unseen tokens are ignored. Never returns [`None`].
+    /// Query tokens removed as stopwords by [`Self::parse_text_query`]: configured stopwords,
+    /// stopwords of the `language` if specified, and detected stopwords.
+    pub fn text_query_stopwords(&self, text: &str, language: Option<Language>) -> QueryStopwords {
+        let language_tokenizer =
+            language.map(|language| self.get_tokenizer().with_language_stopwords(&language));
+        let tokenizer = language_tokenizer
+            .as_ref()
+            .unwrap_or_else(|| self.get_tokenizer());
+
+        let mut kept_tokens = AHashSet::new();
+        tokenizer.tokenize(TokenizerTextKind::Query, text, |token| {
+            kept_tokens.insert(token);
+        });
+
+        let mut removed = BTreeSet::new();
+        let auto_stopwords = self.auto_stopwords();
+        kept_tokens.retain(|token| {
+            let is_stopword = auto_stopwords.contains(token);
+            if is_stopword {
+                removed.insert(token.to_string());
+            }
+            !is_stopword
+        });
+
+        let unfiltered_tokenizer = self.get_tokenizer().without_stopwords();
+        unfiltered_tokenizer.tokenize(TokenizerTextKind::Query, text, |token| {
+            if !kept_tokens.contains(token.as_ref()) {
+                removed.insert(token.into_owned());
+            }
+        });
+
+        QueryStopwords {
+            removed,
+            is_empty: kept_tokens.is_empty(),
+        }
+    }
+
+    /// Occurrences of the query tokens in the text values of the `payload`, for highlighting
+    /// the matches of the `text`.
+    ///
+    /// Offsets are computed by tokenizing the values again, they are not stored in the index.
+    /// Each value of an array is reported separately, numbered like in the index. Values
+    /// without occurrences are skipped. Stopwords are not reported, as they don't take part
+    /// in matching.
+    pub fn text_match_offsets(&self, payload: &[&Value], text: &str) -> Vec<TextValueMatches> {
+        self.matched_values(payload, text)
+            .into_iter()
+            .map(|(_, matches)| matches)
+            .collect()
+    }
+
+    /// Excerpts of the text values of the `payload` around the matches of the `text`, with the
+    /// occurrences of the query tokens highlighted.
+    ///
+    /// See [`build_snippets`] for how excerpts are picked.
+    pub fn text_snippets(
+        &self,
+        payload: &[&Value],
+        text: &str,
+        params: SnippetParams,
+    ) -> Vec<TextSnippet> {
+        build_snippets(&self.matched_values(payload, text), params)
+    }
+
+    /// Text values of the `payload` with occurrences of the query tokens of the `text`, along
+    /// with the occurrences.
+    fn matched_values(&self, payload: &[&Value], text: &str) -> Vec<(String, TextValueMatches)> {
+        let tokenizer = self.get_tokenizer();
+
+        let mut query_tokens = AHashSet::new();
+        tokenizer.tokenize_query(text, |token| {
+            query_tokens.insert(token);
+        });
+        let auto_stopwords = self.auto_stopwords();
+        query_tokens.retain(|token| !auto_stopwords.contains(token));
+
+        payload
+            .iter()
+            .flat_map(|value| FullTextIndex::get_values(value))
+            .enumerate()
+            .filter_map(|(value_index, value)| {
+                let mut matches = Vec::new();
+                tokenizer.tokenize_doc_with_offsets(&value, |token, range| {
+                    if query_tokens.contains(token.as_ref()) {
+                        matches.push(TextTokenMatch {
+                            token: token.into_owned(),
+                            char_start: value[..range.start].chars().count(),
+                            char_end: value[..range.end].chars().count(),
+                            start: range.start,
+                            end: range.end,
+                        });
+                    }
+                });
+                let matches = TextValueMatches {
+                    value_index,
+                    matches,
+                };
+                (!matches.matches.is_empty()).then_some((value, matches))
+            })
+            .collect()
+    }
+
+    /// Parse as [`TokenizerTextKind::Query`] and return [`ParsedQuery::AnyTokens`], or
+    /// [`ParsedQuery::MinTokens`] if more than one token must match.
+    ///
+    /// Unseen tokens are ignored, but still count towards the percentage of `min_should_match`.
+    /// Detected stopwords are removed from the query, and don't count towards it.
+    /// Returns [`None`] if there are fewer seen tokens than required to match.
     pub fn parse_text_any_query(
         &self,
         text: &str,
+        min_should_match: Option<MinShouldMatch>,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<ParsedQuery>> {
-        let tokenset = self.parse_tokenset(TokenizerTextKind::Query, text, hw_counter)?;
-        Ok(Some(ParsedQuery::AnyTokens(tokenset)))
+        let mut token_map = self.resolve_tokens(TokenizerTextKind::Query, text, hw_counter)?;
+        self.auto_stopwords().remove_from(&mut token_map);
+        let min_match = min_should_match.map_or(1, |min| min.min_tokens(token_map.len()));
+        let tokens: TokenSet = token_map.into_values().flatten().collect();
+
+        if min_match <= 1 {
+            return Ok(Some(ParsedQuery::AnyTokens(tokens)));
+        }
+
+        if tokens.len() < min_match {
+            return Ok(None);
+        }
+
+        Ok(Some(ParsedQuery::MinTokens { tokens, min_match }))
     }
 
     /// Parse as provided [`TokenizerTextKind`] and return [`TokenSet`].
+    /// Documents include the original form of the tokens, if it is indexed.
     /// Unseen tokens are ignored.
     fn parse_tokenset(
         &self,
@@ -304,8 +611,17 @@ impl FullTextIndex {
         text: &str,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<TokenSet> {
+        let unstemmed_token_ids = match kind {
+            TokenizerTextKind::Document => {
+                self.resolve_tokens(TokenizerTextKind::UnstemmedDocument, text, hw_counter)?
+            }
+            _ => AHashMap::new(),
+        };
         let token_ids = self.resolve_tokens(kind, text, hw_counter)?.into_values();
-        Ok(token_ids.flatten().collect())
+        Ok(token_ids
+            .chain(unstemmed_token_ids.into_values())
+            .flatten()
+            .collect())
     }
 
     /// Tokenize the `text` and return a map of token -> token_id.
@@ -315,9 +631,20 @@ impl FullTextIndex {
         kind: TokenizerTextKind,
         text: &'a str,
         hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<AHashMap<Cow<'a, str>, Option<TokenId>>> {
+        self.resolve_tokens_with(self.get_tokenizer(), kind, text, hw_counter)
+    }
+
+    /// Same as [`Self::resolve_tokens`], but tokenizes the `text` with the provided `tokenizer`.
+    fn resolve_tokens_with<'a>(
+        &self,
+        tokenizer: &Tokenizer,
+        kind: TokenizerTextKind,
+        text: &'a str,
+        hw_counter: &HardwareCounterCell,
     ) -> OperationResult<AHashMap<Cow<'a, str>, Option<TokenId>>> {
         let mut token_map = AHashMap::new();
-        self.get_tokenizer().tokenize(kind, text, |token| {
+        tokenizer.tokenize(kind, text, |token| {
             token_map.insert(token, None);
         });
         let iter = token_map
@@ -358,7 +685,7 @@ impl FullTextIndex {
         query: &'a str,
         hw_counter: &'a HardwareCounterCell,
     ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
-        let Some(parsed_query) = self.parse_text_query(query, hw_counter)? else {
+        let Some(parsed_query) = self.parse_text_query(query, None, false, hw_counter)? else {
             return Ok(Box::new(std::iter::empty()));
         };
         self.filter_query(parsed_query, hw_counter)
@@ -368,9 +695,9 @@ impl FullTextIndex {
     /// full-text index tokenizer.
     ///
     /// `query_type` selects the parsing / matching strategy:
-    /// - `Text`    — all query tokens must appear in the document
-    /// - `Phrase`  — all query tokens must appear in exact order
-    /// - `TextAny` — at least one query token must appear
+    /// - `Text`    — all query tokens (except `language` stopwords) must appear in the document
+    /// - `Phrase`  — all query tokens must appear in the same order, within the `slop`
+    /// - `TextAny` — at least one query token (or `min_should_match` tokens) must appear
     pub fn check_payload_match(
         &self,
         payload_value: &serde_json::Value,
@@ -379,9 +706,16 @@ impl FullTextIndex {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<bool> {
         let query_opt = match query_type {
-            PayloadMatchQueryType::Text => self.parse_text_query(text, hw_counter)?,
-            PayloadMatchQueryType::Phrase => self.parse_phrase_query(text, hw_counter)?,
-            PayloadMatchQueryType::TextAny => self.parse_text_any_query(text, hw_counter)?,
+            PayloadMatchQueryType::Text {
+                language,
+                unstemmed,
+            } => self.parse_text_query(text, language, unstemmed, hw_counter)?,
+            PayloadMatchQueryType::Phrase { slop } => {
+                self.parse_sloppy_phrase_query(text, slop, hw_counter)?
+            }
+            PayloadMatchQueryType::TextAny(min_should_match) => {
+                self.parse_text_any_query(text, min_should_match, hw_counter)?
+            }
         };
 
         let Some(query) = query_opt else {
@@ -405,9 +739,64 @@ impl FullTextIndex {
                         self.parse_tokenset(TokenizerTextKind::Document, value, hw_counter)?;
                     Ok(tokenset.has_any(query))
                 }
+                ParsedQuery::MinTokens { tokens, min_match } => {
+                    let tokenset =
+                        self.parse_tokenset(TokenizerTextKind::Document, value, hw_counter)?;
+                    Ok(tokenset.has_at_least(tokens, *min_match))
+                }
             })
     }
 
+    /// Tokenize the `text` of a [`TextScoreQuery`] once, to score the values of many points.
+    pub fn parse_score_query(
+        &self,
+        text: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<TextScoreQuery> {
+        let mut token_map = self.resolve_tokens(TokenizerTextKind::Query, text, hw_counter)?;
+        self.auto_stopwords().remove_from(&mut token_map);
+
+        Ok(TextScoreQuery {
+            tokens_count: token_map.len(),
+            seen_tokens: token_map.into_values().flatten().collect(),
+        })
+    }
+
+    /// Scores the payload values of a point against the `query`.
+    ///
+    /// Each value of an array is scored separately, as the fraction of query tokens present
+    /// in it. Scores of the values are then combined according to `aggregation`.
+    /// A single (non-array) value is scored as an array of one element.
+    pub fn score_values<'v>(
+        &self,
+        query: &TextScoreQuery,
+        values: impl IntoIterator<Item = &'v Value>,
+        aggregation: TextScoreAggregation,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<f32> {
+        if query.tokens_count == 0 {
+            return Ok(0.0);
+        }
+
+        let scores = values
+            .into_iter()
+            .flat_map(FullTextIndex::get_values)
+            .map(|value| {
+                let tokenset =
+                    self.parse_tokenset(TokenizerTextKind::Document, &value, hw_counter)?;
+                let matched = query
+                    .seen_tokens
+                    .tokens()
+                    .iter()
+                    .filter(|token| tokenset.contains(token))
+                    .count();
+                Ok(matched as f32 / query.tokens_count as f32)
+            })
+            .collect::<OperationResult<Vec<_>>>()?;
+
+        Ok(aggregation.aggregate(scores))
+    }
+
     /// Approximate RAM usage in bytes for in-memory structures.
     pub fn ram_usage_bytes(&self) -> usize {
         match self {
@@ -436,6 +825,16 @@ impl FullTextIndex {
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        match self {
+            FullTextIndex::Mutable(_) => {}   // Not a mmap
+            FullTextIndex::Immutable(_) => {} // Not a mmap
+            FullTextIndex::Mmap(index) => index.populate_with_budget(budget)?,
+        }
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
@@ -547,12 +946,25 @@ impl PayloadFieldIndex for FullTextIndex {
         };
 
         let parsed_query_opt = match r#match {
-            Match::Text(MatchText { text }) => self.parse_text_query(text, hw_counter),
-            Match::Phrase(MatchPhrase { phrase }) => self.parse_phrase_query(phrase, hw_counter),
-            Match::TextAny(MatchTextAny { text_any }) => {
-                self.parse_text_any_query(text_any, hw_counter)
+            Match::Text(MatchText {
+                text,
+                language,
+                unstemmed,
+            }) => self.parse_text_query(text, *language, unstemmed.unwrap_or(false), hw_counter),
+            Match::Phrase(MatchPhrase { phrase, slop }) => {
+                self.parse_sloppy_phrase_query(phrase, slop.unwrap_or(0), hw_counter)
+            }
+            Match::TextAny(MatchTextAny {
+                text_any,
+                min_should_match,
+            }) => self.parse_text_any_query(text_any, *min_should_match, hw_counter),
+            Match::Value(_)
+            | Match::Any(_)
+            | Match::Except(_)
+            | Match::ExactSet(_)
+            | Match::KeywordPrefix(_) => {
+                return Ok(None);
             }
-            Match::Value(_) | Match::Any(_) | Match::Except(_) => return Ok(None),
         }?;
 
         let Some(parsed_query) = parsed_query_opt else {
@@ -572,12 +984,25 @@ impl PayloadFieldIndex for FullTextIndex {
         };
 
         let parsed_query_opt = match r#match {
-            Match::Text(MatchText { text }) => self.parse_text_query(text, hw_counter),
-            Match::Phrase(MatchPhrase { phrase }) => self.parse_phrase_query(phrase, hw_counter),
-            Match::TextAny(MatchTextAny { text_any }) => {
-                self.parse_text_any_query(text_any, hw_counter)
+            Match::Text(MatchText {
+                text,
+                language,
+                unstemmed,
+            }) => self.parse_text_query(text, *language, unstemmed.unwrap_or(false), hw_counter),
+            Match::Phrase(MatchPhrase { phrase, slop }) => {
+                self.parse_sloppy_phrase_query(phrase, slop.unwrap_or(0), hw_counter)
+            }
+            Match::TextAny(MatchTextAny {
+                text_any,
+                min_should_match,
+            }) => self.parse_text_any_query(text_any, *min_should_match, hw_counter),
+            Match::Value(_)
+            | Match::Any(_)
+            | Match::Except(_)
+            | Match::ExactSet(_)
+            | Match::KeywordPrefix(_) => {
+                return Ok(None);
             }
-            Match::Value(_) | Match::Any(_) | Match::Except(_) => return Ok(None),
         }?;
 
         let Some(parsed_query) = parsed_query_opt else {
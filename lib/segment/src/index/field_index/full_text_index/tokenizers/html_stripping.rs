@@ -0,0 +1,253 @@
+// HTML stripping, applied to the text before tokenization.
+// Keeps only the visible text, never fails on malformed markup.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Elements, which content is not visible text
+const INVISIBLE_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Longest entity to decode, including `&` and `;`. E.g. `&#x10FFFF;`
+const MAX_ENTITY_LEN: usize = 10;
+
+/// Strip HTML markup from the `text`, and decode entities in the remaining text if requested.
+///
+/// Tags are replaced with a whitespace, so words separated only by markup are not glued together.
+/// Comments and the content of `<script>` and `<style>` elements are removed entirely.
+///
+/// Malformed markup is handled gracefully: `<`, which doesn't start a tag, is kept as text,
+/// and an unterminated tag or comment drops the rest of the text.
+pub fn strip_html(text: &str, decode_entities: bool) -> Cow<'_, str> {
+    if !text.contains('<') && !(decode_entities && text.contains('&')) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    for (i, range) in visible_ranges(text).into_iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        push_text(&mut out, &text[range], decode_entities);
+    }
+
+    Cow::Owned(out)
+}
+
+/// Byte ranges of the `text`, which are left by [`strip_html`], in order.
+///
+/// Entities are not decoded, the ranges point to the original `text`.
+pub fn visible_ranges(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut ranges = Vec::new();
+    let mut text_start = 0;
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        if bytes[pos] == b'<' && starts_tag(bytes.get(pos + 1).copied()) {
+            ranges.push(text_start..pos);
+            pos = markup_end(text, pos);
+            text_start = pos;
+        } else {
+            pos += 1;
+        }
+    }
+    ranges.push(text_start..bytes.len());
+
+    ranges
+}
+
+fn starts_tag(next: Option<u8>) -> bool {
+    next.is_some_and(|b| b.is_ascii_alphabetic() || matches!(b, b'/' | b'!' | b'?'))
+}
+
+/// Position right after the markup starting at `start`
+fn markup_end(text: &str, start: usize) -> usize {
+    let bytes = text.as_bytes();
+
+    if text[start..].starts_with("<!--") {
+        return text[start + 4..]
+            .find("-->")
+            .map_or(bytes.len(), |end| start + 4 + end + 3);
+    }
+
+    let end = tag_end(bytes, start + 1);
+
+    let name_len = bytes[start + 1..]
+        .iter()
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    let name = &text[start + 1..start + 1 + name_len];
+    let is_invisible = INVISIBLE_ELEMENTS
+        .iter()
+        .any(|element| element.eq_ignore_ascii_case(name));
+    if !is_invisible {
+        return end;
+    }
+
+    // Skip the content up to the end of the closing tag
+    let closing_tag = format!("</{name}");
+    match find_ignore_ascii_case(bytes, closing_tag.as_bytes(), end) {
+        Some(closing_start) => tag_end(bytes, closing_start + 1),
+        None => bytes.len(),
+    }
+}
+
+/// Position right after the `>` closing the tag, ignoring `>` in quoted attribute values
+fn tag_end(bytes: &[u8], from: usize) -> usize {
+    let mut quote = None;
+    let mut after_equals = false;
+    for (offset, &b) in bytes[from..].iter().enumerate() {
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None if b == b'>' => return from + offset + 1,
+            // Quotes only open attribute values, stray ones are ignored
+            None if after_equals && (b == b'"' || b == b'\'') => quote = Some(b),
+            None => {}
+        }
+        if !b.is_ascii_whitespace() {
+            after_equals = b == b'=';
+        }
+    }
+    bytes.len()
+}
+
+fn find_ignore_ascii_case(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+        .map(|position| from + position)
+}
+
+fn push_text(out: &mut String, text: &str, decode_entities: bool) {
+    if !decode_entities {
+        out.push_str(text);
+        return;
+    }
+
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let candidate = &rest[amp..];
+        match decode_entity(candidate) {
+            Some((ch, len)) => {
+                out.push(ch);
+                rest = &candidate[len..];
+            }
+            // Not an entity, keep as is
+            None => {
+                out.push('&');
+                rest = &candidate[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+}
+
+/// Decode the entity at the start of the `text`.
+///
+/// Returns the decoded character and the length of the entity in bytes.
+fn decode_entity(text: &str) -> Option<(char, usize)> {
+    let end = text.bytes().take(MAX_ENTITY_LEN).position(|b| b == b';')?;
+    let name = &text[1..end];
+
+    let ch = match name.strip_prefix('#') {
+        Some(code) => {
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+        None => match name {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => ' ',
+            _ => return None,
+        },
+    };
+
+    Some((ch, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text_is_borrowed() {
+        let text = "no markup & no entities";
+        assert!(matches!(strip_html(text, false), Cow::Borrowed(_)));
+        assert!(matches!(strip_html("no markup", true), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_strip_tags() {
+        let text = r#"<p class="intro">Hello <b>world</b>!</p><br/>Bye"#;
+        assert_eq!(
+            strip_html(text, false)
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            ["Hello", "world", "!", "Bye"],
+        );
+    }
+
+    #[test]
+    fn test_strip_invisible_content() {
+        let text = "a<!-- hidden -->b<script>var x = '</p>';</script>c<STYLE>p {}</STYLE >d";
+        assert_eq!(
+            strip_html(text, false)
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            ["a", "b", "c", "d"],
+        );
+    }
+
+    #[test]
+    fn test_quoted_attributes() {
+        let text = r#"<a title="a > b" href='x>y'>link</a> <p don't>text</p>"#;
+        assert_eq!(
+            strip_html(text, false)
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            ["link", "text"],
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        let text = "Tom &amp; Jerry&nbsp;&lt;3 &#169; &#x1F600; &unknown; & &#xZZ;";
+        assert_eq!(
+            strip_html(text, true),
+            "Tom & Jerry <3 © 😀 &unknown; & &#xZZ;"
+        );
+        assert_eq!(strip_html(text, false), text);
+    }
+
+    #[test]
+    fn test_malformed_markup() {
+        assert_eq!(strip_html("1 < 2 and 3 <> 4", true), "1 < 2 and 3 <> 4");
+        assert_eq!(strip_html("text <div unterminated", false), "text  ");
+        assert_eq!(strip_html("text <!-- unterminated", false), "text  ");
+        assert_eq!(strip_html("a <script>never closed", false), "a  ");
+        assert_eq!(strip_html("ünï<b>cödé</b>", false), "ünï cödé ");
+        assert_eq!(strip_html("&#xD800; &amp", true), "&#xD800; &amp");
+    }
+
+    #[test]
+    fn test_visible_ranges() {
+        let text = "<p>Hello <b>world</b></p><script>x</script>&amp; bye";
+        let visible = visible_ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(visible, ["Hello ", "world", "&amp; bye"]);
+
+        assert_eq!(visible_ranges("plain"), [0..5]);
+    }
+}
@@ -1,16 +1,19 @@
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::Arc;
 mod ascii_folding;
+mod html_stripping;
 mod japanese;
 mod multilingual;
 mod stemmer;
 pub mod tokens_processor;
 
+use itertools::Either;
 use multilingual::MultilingualTokenizer;
 pub use stemmer::Stemmer;
 pub use tokens_processor::TokensProcessor;
 
-use crate::data_types::index::{TextIndexParams, TokenizerType};
+use crate::data_types::index::{Language, TextIndexParams, TokenizerType};
 use crate::index::field_index::full_text_index::inverted_index::ARRAY_BOUNDARY_SENTINEL;
 use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
 
@@ -154,15 +157,55 @@ fn truncate_cow_ref<'a>(inp: &Cow<'a, str>, len: usize) -> Cow<'a, str> {
     }
 }
 
+/// Prefix of original tokens, which are indexed alongside the stemmed ones.
+///
+/// Keeps them apart from stemmed tokens, which happen to be equal to some original word.
+const UNSTEMMED_TOKEN_PREFIX: char = '\x01';
+
+/// Check if the token is an original form, indexed with [`TokenizerTextKind::UnstemmedDocument`].
+pub fn is_unstemmed_token(token: &str) -> bool {
+    token.starts_with(UNSTEMMED_TOKEN_PREFIX)
+}
+
+fn mark_unstemmed(token: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(format!("{UNSTEMMED_TOKEN_PREFIX}{token}"))
+}
+
+/// Stripping of HTML markup from the text before tokenization
+#[derive(Debug, Clone, Copy)]
+struct HtmlStripping {
+    decode_entities: bool,
+    /// Strip queries too, not only documents
+    strip_queries: bool,
+}
+
 #[derive(Debug)]
 pub struct Tokenizer {
     tokenizer_type: TokenizerType,
     tokens_processor: TokensProcessor,
+    /// Processor without stemming, if original tokens are indexed alongside the stemmed ones
+    unstemmed_processor: Option<TokensProcessor>,
+    html_stripping: Option<HtmlStripping>,
 }
 
 pub enum TokenizerTextKind {
     Query,
     Document,
+    /// Query matched against the original form of the tokens.
+    /// Same as [`Self::Query`], if original tokens are not indexed.
+    UnstemmedQuery,
+    /// Original form of the document tokens, which are indexed in addition to [`Self::Document`].
+    /// Produces no tokens, if original tokens are not indexed.
+    UnstemmedDocument,
+}
+
+impl TokenizerTextKind {
+    fn is_document(&self) -> bool {
+        match self {
+            Self::Document | Self::UnstemmedDocument => true,
+            Self::Query | Self::UnstemmedQuery => false,
+        }
+    }
 }
 
 impl Tokenizer {
@@ -179,6 +222,15 @@ impl Tokenizer {
             stopwords,
             stemmer,
             enable_hnsw: _,
+            compression: _,
+            index_unstemmed,
+            max_value_bytes: _,
+            oversized_value_policy: _,
+            auto_stopwords_threshold: _,
+            strip_html,
+            decode_html_entities,
+            strip_html_queries,
+            forward_index: _,
         } = params;
 
         let lowercase = lowercase.unwrap_or(true);
@@ -194,13 +246,63 @@ impl Tokenizer {
             *max_token_len,
         );
 
-        Self::new(*tokenizer, tokens_processor)
+        let index_unstemmed = index_unstemmed.unwrap_or(false)
+            && tokens_processor.has_stemmer()
+            && *tokenizer != TokenizerType::Prefix;
+        let unstemmed_processor = index_unstemmed.then(|| tokens_processor.without_stemmer());
+
+        let html_stripping = strip_html.unwrap_or(false).then(|| HtmlStripping {
+            decode_entities: decode_html_entities.unwrap_or(true),
+            strip_queries: strip_html_queries.unwrap_or(false),
+        });
+
+        Self {
+            tokenizer_type: *tokenizer,
+            tokens_processor,
+            unstemmed_processor,
+            html_stripping,
+        }
     }
 
     pub fn new(tokenizer_type: TokenizerType, tokens_processor: TokensProcessor) -> Self {
         Self {
             tokenizer_type,
             tokens_processor,
+            unstemmed_processor: None,
+            html_stripping: None,
+        }
+    }
+
+    /// Copy of this tokenizer, which additionally removes stopwords of the given `language`.
+    ///
+    /// Intended for query-time stopword removal, when the language is only known per request.
+    pub fn with_language_stopwords(&self, language: &Language) -> Self {
+        Self {
+            tokenizer_type: self.tokenizer_type,
+            tokens_processor: self.tokens_processor.with_language_stopwords(language),
+            unstemmed_processor: self
+                .unstemmed_processor
+                .as_ref()
+                .map(|processor| processor.with_language_stopwords(language)),
+            html_stripping: self.html_stripping,
+        }
+    }
+
+    /// Stopwords removed by this tokenizer, sorted
+    pub fn stopwords(&self) -> Vec<String> {
+        self.tokens_processor.stopwords()
+    }
+
+    /// Copy of this tokenizer, which keeps stopwords.
+    pub fn without_stopwords(&self) -> Self {
+        Self {
+            tokenizer_type: self.tokenizer_type,
+            tokens_processor: self.tokens_processor.without_stopwords(),
+            unstemmed_processor: self
+                .unstemmed_processor
+                .as_ref()
+                .map(TokensProcessor::without_stopwords),
+            html_stripping: self.html_stripping,
         }
     }
 
@@ -208,20 +310,74 @@ impl Tokenizer {
         &self,
         kind: TokenizerTextKind,
         text: &'a str,
-        callback: C,
+        mut callback: C,
+    ) {
+        let stripped = self
+            .html_stripping
+            .filter(|stripping| stripping.strip_queries || kind.is_document())
+            .map(|stripping| html_stripping::strip_html(text, stripping.decode_entities));
+
+        match stripped {
+            // Tokens can't outlive the stripped text, so they have to be owned
+            Some(Cow::Owned(stripped)) => self.tokenize_text(kind, &stripped, |token| {
+                callback(Cow::Owned(token.into_owned()))
+            }),
+            Some(Cow::Borrowed(stripped)) => self.tokenize_text(kind, stripped, callback),
+            None => self.tokenize_text(kind, text, callback),
+        }
+    }
+
+    fn tokenize_text<'a, C: FnMut(Cow<'a, str>)>(
+        &self,
+        kind: TokenizerTextKind,
+        text: &'a str,
+        mut callback: C,
     ) {
         let Self {
             tokenizer_type,
-            tokens_processor: tp,
+            tokens_processor,
+            unstemmed_processor,
+            html_stripping: _,
         } = self;
+        match (kind, unstemmed_processor) {
+            (TokenizerTextKind::Query, _) | (TokenizerTextKind::UnstemmedQuery, None) => {
+                Self::tokenize_with(*tokenizer_type, true, text, tokens_processor, callback)
+            }
+            (TokenizerTextKind::Document, _) => {
+                Self::tokenize_with(*tokenizer_type, false, text, tokens_processor, callback)
+            }
+            (TokenizerTextKind::UnstemmedQuery, Some(tp)) => {
+                Self::tokenize_with(*tokenizer_type, true, text, tp, |token| {
+                    callback(mark_unstemmed(token))
+                })
+            }
+            (TokenizerTextKind::UnstemmedDocument, Some(tp)) => {
+                Self::tokenize_with(*tokenizer_type, false, text, tp, |token| {
+                    callback(mark_unstemmed(token))
+                })
+            }
+            (TokenizerTextKind::UnstemmedDocument, None) => {}
+        }
+    }
+
+    fn tokenize_with<'a, C: FnMut(Cow<'a, str>)>(
+        tokenizer_type: TokenizerType,
+        is_query: bool,
+        text: &'a str,
+        tp: &TokensProcessor,
+        callback: C,
+    ) {
         match tokenizer_type {
             TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(text, tp, callback),
             TokenizerType::Word => WordTokenizer::tokenize(text, tp, callback),
             TokenizerType::Multilingual => MultilingualTokenizer::tokenize(text, tp, callback),
-            TokenizerType::Prefix => match kind {
-                TokenizerTextKind::Document => PrefixTokenizer::tokenize(text, tp, callback),
-                TokenizerTextKind::Query => PrefixTokenizer::tokenize_query(text, tp, callback),
-            },
+            TokenizerType::Prefix => {
+                if is_query {
+                    PrefixTokenizer::tokenize_query(text, tp, callback)
+                } else {
+                    PrefixTokenizer::tokenize(text, tp, callback)
+                }
+            }
         }
     }
 
@@ -229,6 +385,56 @@ impl Tokenizer {
         self.tokenize(TokenizerTextKind::Document, text, callback);
     }
 
+    /// Same as [`Self::tokenize_doc`], but also passes the byte range of the word in the `text`,
+    /// which produced the token.
+    ///
+    /// The multilingual tokenizer passes the range of the whole whitespace-separated chunk.
+    /// If HTML stripping is enabled, markup is skipped but entities are not decoded, so the
+    /// ranges point to the original `text`.
+    pub fn tokenize_doc_with_offsets<'a, C: FnMut(Cow<'a, str>, Range<usize>)>(
+        &self,
+        text: &'a str,
+        mut callback: C,
+    ) {
+        let visible_ranges = match self.html_stripping {
+            Some(_) => html_stripping::visible_ranges(text),
+            None => vec![0..text.len()],
+        };
+
+        for visible_range in visible_ranges {
+            let visible = &text[visible_range.clone()];
+            let words = match self.tokenizer_type {
+                TokenizerType::Whitespace | TokenizerType::Multilingual => {
+                    Either::Left(visible.split_whitespace())
+                }
+                TokenizerType::Word | TokenizerType::Prefix => Either::Right(
+                    visible
+                        .split(|c| !char::is_alphanumeric(c))
+                        .filter(|word| !word.is_empty()),
+                ),
+            };
+
+            for word in words {
+                // Words are subslices of the visible text
+                let offset = word.as_ptr() as usize - visible.as_ptr() as usize;
+                let start = visible_range.start + offset;
+                let range = start..start + word.len();
+                self.tokenize_text(TokenizerTextKind::Document, word, |token| {
+                    callback(token, range.clone())
+                });
+            }
+        }
+    }
+
+    /// Original form of the document tokens, if they are indexed alongside the stemmed ones.
+    pub fn tokenize_unstemmed_doc<'a, C: FnMut(Cow<'a, str>)>(
+        &'a self,
+        text: &'a str,
+        callback: C,
+    ) {
+        self.tokenize(TokenizerTextKind::UnstemmedDocument, text, callback);
+    }
+
     pub fn tokenize_query<'a, C: FnMut(Cow<'a, str>)>(&'a self, text: &'a str, mut callback: C) {
         self.tokenize(TokenizerTextKind::Query, text, |token| {
             if token != ARRAY_BOUNDARY_SENTINEL {
@@ -236,6 +442,16 @@ impl Tokenizer {
             }
         });
     }
+
+    /// Document tokens of the `text`, in the order they are produced, for previewing the
+    /// tokenization.
+    pub fn tokenize_debug(&self, text: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        self.tokenize(TokenizerTextKind::Document, text, |token| {
+            tokens.push(token.into_owned())
+        });
+        tokens
+    }
 }
 
 #[cfg(test)]
@@ -424,6 +640,15 @@ mod tests {
             stopwords: None,
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -457,6 +682,15 @@ mod tests {
             stopwords: Some(StopwordsInterface::Language(Language::English)),
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -501,6 +735,15 @@ mod tests {
                 stopwords: Some(StopwordsInterface::Language(Language::English)),
                 stemmer: None,
                 enable_hnsw: None,
+                compression: None,
+                index_unstemmed: None,
+                max_value_bytes: None,
+                oversized_value_policy: None,
+                auto_stopwords_threshold: None,
+                strip_html: None,
+                decode_html_entities: None,
+                strip_html_queries: None,
+                forward_index: None,
             };
 
             let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -538,6 +781,15 @@ mod tests {
             )),
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -575,6 +827,15 @@ mod tests {
             stopwords: Some(StopwordsInterface::new_custom(&["as", "the", "a"])),
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -615,6 +876,15 @@ mod tests {
             stopwords: Some(StopwordsInterface::Language(Language::English)),
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -635,6 +905,24 @@ mod tests {
         assert!(tokens.contains(&Cow::Borrowed("dog")));
     }
 
+    #[test]
+    fn test_tokenize_debug() {
+        let params = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            lowercase: Some(true),
+            stopwords: Some(StopwordsInterface::Language(Language::Hungarian)),
+            ..Default::default()
+        };
+        let tokenizer = Tokenizer::new_from_text_index_params(&params);
+
+        // The Hungarian stopwords list has the legacy encoding with "õ", not "ő"
+        assert_eq!(
+            tokenizer.tokenize_debug("Elõször a ház, először a kert"),
+            vec!["ház", "először", "kert"],
+        );
+    }
+
     #[test]
     fn test_tokenizer_with_languages_english_spanish_custom_aaa() {
         let text = "The quick brown fox jumps over the lazy dog I'd y de";
@@ -655,6 +943,15 @@ mod tests {
             )),
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -698,6 +995,15 @@ mod tests {
             stopwords: Some(StopwordsInterface::new_custom(&["the", "The", "LAZY"])),
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -747,6 +1053,15 @@ mod tests {
             stopwords: None,
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
         let tokenizer_disabled = Tokenizer::new_from_text_index_params(&params_disabled);
         let mut tokens_disabled = Vec::new();
@@ -766,6 +1081,15 @@ mod tests {
             stopwords: None,
             stemmer: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
         let tokenizer_enabled = Tokenizer::new_from_text_index_params(&params_enabled);
         let mut tokens_enabled = Vec::new();
@@ -838,4 +1162,110 @@ mod tests {
         PrefixTokenizer::tokenize(input, &tokens_processor, |i| out.push(i.to_string()));
         assert_eq!(out, vec!["int", "inte", "pro", "proc", "liv", "live"]);
     }
+
+    #[test]
+    fn test_strip_html_word_tokenizer() {
+        let html = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <title>Quick fox</title>
+  <style>div.article { color: red; }</style>
+</head>
+<body>
+  <div class="article">
+    <a href="https://example.com/page?id=1&amp;ref=2">Brown &amp; lazy</a><br>dog
+  </div>
+  <!-- <div>hidden</div> -->
+  <script>trackVisit("div", { href: location.href });</script>
+</body>
+</html>"#;
+
+        let make_params = |strip_html, strip_html_queries| TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            min_token_len: None,
+            max_token_len: None,
+            lowercase: Some(true),
+            ascii_folding: None,
+            on_disk: None,
+            phrase_matching: None,
+            stopwords: None,
+            stemmer: None,
+            enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: Some(strip_html),
+            decode_html_entities: None,
+            strip_html_queries: Some(strip_html_queries),
+            forward_index: None,
+        };
+
+        let tokenizer = Tokenizer::new_from_text_index_params(&make_params(true, false));
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_doc(html, |token| tokens.push(token.to_string()));
+        assert_eq!(tokens, ["quick", "fox", "brown", "lazy", "dog"]);
+
+        // Markup is tokenized as is, if stripping is disabled
+        let tokenizer_disabled = Tokenizer::new_from_text_index_params(&make_params(false, false));
+        let mut tokens_disabled = Vec::new();
+        tokenizer_disabled.tokenize_doc(html, |token| tokens_disabled.push(token.to_string()));
+        assert!(tokens_disabled.contains(&"div".to_string()));
+        assert!(tokens_disabled.contains(&"href".to_string()));
+
+        // Queries are not stripped, unless requested
+        let query = "<b>lazy</b> dog";
+        let mut query_tokens = Vec::new();
+        tokenizer.tokenize_query(query, |token| query_tokens.push(token.to_string()));
+        assert_eq!(query_tokens, ["b", "lazy", "b", "dog"]);
+
+        let tokenizer = Tokenizer::new_from_text_index_params(&make_params(true, true));
+        query_tokens.clear();
+        tokenizer.tokenize_query(query, |token| query_tokens.push(token.to_string()));
+        assert_eq!(query_tokens, ["lazy", "dog"]);
+    }
+
+    #[test]
+    fn test_tokenize_doc_with_offsets() {
+        let params = TextIndexParams {
+            tokenizer: TokenizerType::Word,
+            strip_html: Some(true),
+            ..Default::default()
+        };
+        let tokenizer = Tokenizer::new_from_text_index_params(&params);
+
+        let text = "<p>Über <b>Fox</b>es</p>:dog";
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_doc_with_offsets(text, |token, range| {
+            tokens.push((token.to_string(), &text[range]))
+        });
+        assert_eq!(
+            tokens,
+            [
+                ("über".to_string(), "Über"),
+                ("fox".to_string(), "Fox"),
+                ("es".to_string(), "es"),
+                ("dog".to_string(), "dog"),
+            ],
+        );
+
+        // Prefix tokens point to the whole word
+        let params = TextIndexParams {
+            tokenizer: TokenizerType::Prefix,
+            min_token_len: Some(2),
+            max_token_len: Some(3),
+            ..Default::default()
+        };
+        let tokenizer = Tokenizer::new_from_text_index_params(&params);
+        let mut tokens = Vec::new();
+        tokenizer.tokenize_doc_with_offsets("a hello", |token, range| {
+            tokens.push((token.to_string(), range))
+        });
+        assert_eq!(
+            tokens,
+            [("he".to_string(), 2..7), ("hel".to_string(), 2..7)]
+        );
+    }
 }
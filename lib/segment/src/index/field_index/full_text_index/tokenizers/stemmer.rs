@@ -6,6 +6,7 @@ use rust_stemmers::Algorithm;
 
 use crate::data_types::index::{SnowballLanguage, SnowballParams, StemmingAlgorithm};
 
+#[derive(Clone)]
 pub enum Stemmer {
     Snowball(Arc<rust_stemmers::Stemmer>),
 }
@@ -2,9 +2,10 @@ use std::borrow::Cow;
 use std::sync::Arc;
 
 use super::stemmer::Stemmer;
+use crate::data_types::index::Language;
 use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TokensProcessor {
     pub lowercase: bool,
     pub ascii_folding: bool,
@@ -43,6 +44,26 @@ impl TokensProcessor {
         stemmer.stem(input)
     }
 
+    pub fn has_stemmer(&self) -> bool {
+        self.stemmer.is_some()
+    }
+
+    /// Copy of this processor, which keeps tokens in their original, unstemmed form.
+    pub fn without_stemmer(&self) -> Self {
+        Self {
+            stemmer: None,
+            ..self.clone()
+        }
+    }
+
+    /// Copy of this processor, which keeps stopwords.
+    pub fn without_stopwords(&self) -> Self {
+        Self {
+            stopwords_filter: Arc::default(),
+            ..self.clone()
+        }
+    }
+
     /// Applies ASCII folding if enabled. Converts accented characters to their ASCII equivalents.
     pub fn fold_if_enabled<'a>(&self, input: Cow<'a, str>) -> Cow<'a, str> {
         if self.ascii_folding {
@@ -56,6 +77,22 @@ impl TokensProcessor {
         self.stopwords_filter.is_stopword(token)
     }
 
+    /// All stopwords, sorted
+    pub fn stopwords(&self) -> Vec<String> {
+        self.stopwords_filter.stopwords()
+    }
+
+    /// Copy of this processor, which additionally removes stopwords of the given `language`.
+    pub fn with_language_stopwords(&self, language: &Language) -> Self {
+        let stopwords_filter = self
+            .stopwords_filter
+            .with_language(language, self.lowercase);
+        Self {
+            stopwords_filter: Arc::new(stopwords_filter),
+            ..self.clone()
+        }
+    }
+
     pub fn process_token_cow<'a>(
         &self,
         mut token_cow: Cow<'a, str>,
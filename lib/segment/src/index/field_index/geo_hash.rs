@@ -8,7 +8,7 @@ use itertools::Itertools;
 use ordered_float::OrderedFloat;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::types::{GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius};
+use crate::types::{GeoBoundingBox, GeoNearPath, GeoPoint, GeoPolygon, GeoRadius};
 
 /// Packed representation of a geohash string.
 ///
@@ -533,6 +533,154 @@ pub fn polygon_hashes(polygon: &GeoPolygon, max_regions: usize) -> OperationResu
     create_hashes(mapping_fn)
 }
 
+/// Check if geohash tile intersects the area within `near_path.distance` of the path
+fn check_path_intersection(geohash: &str, near_path: &GeoNearPath) -> bool {
+    let precision = geohash.len();
+    if precision == 0 {
+        return true;
+    }
+    let rect = decode_bbox(geohash).unwrap();
+    let c0 = rect.min();
+    let c1 = rect.max();
+
+    let bbox_center = Point::new((c0.x + c1.x) / 2f64, (c0.y + c1.y) / 2f64);
+    // Tiles are not symmetric on a sphere, take the farthest of the southern and northern corners
+    let half_diagonal = Haversine
+        .distance(bbox_center, Point(c0))
+        .max(Haversine.distance(bbox_center, Point::new(c0.x, c1.y)));
+
+    let center = GeoPoint {
+        lon: OrderedFloat(bbox_center.x()),
+        lat: OrderedFloat(bbox_center.y()),
+    };
+
+    near_path.segments().any(|(start, end)| {
+        distance_to_segment(&center, start, end) < half_diagonal + near_path.distance.0
+    })
+}
+
+/// Return as-high-as-possible with maximum of `max_regions`
+/// number of geo-hash guaranteed to contain the whole area within `distance` of the path.
+pub fn path_hashes(near_path: &GeoNearPath, max_regions: usize) -> OperationResult<Vec<GeoHash>> {
+    if max_regions == 0 {
+        return Err(OperationError::service_error(
+            "max_regions cannot be equal to zero",
+        ));
+    }
+
+    let segment_bounding_boxes: Vec<GeohashBoundingBox> = near_path
+        .segments()
+        .map(|(start, end)| {
+            minimum_bounding_rectangle_for_segment(start, end, near_path.distance.0).into()
+        })
+        .collect();
+
+    let mapping_fn = |precision| {
+        let mut hashes = Vec::new();
+        for bounding_box in &segment_bounding_boxes {
+            hashes.extend(bounding_box.geohash_regions(precision, max_regions)?);
+            hashes.sort_unstable();
+            hashes.dedup();
+            if hashes.len() > max_regions {
+                return None;
+            }
+        }
+        Some(
+            hashes
+                .into_iter()
+                .filter(|hash| check_path_intersection(EcoString::from(*hash).as_str(), near_path))
+                .collect_vec(),
+        )
+    };
+    create_hashes(mapping_fn)
+}
+
+/// Angular distance in radians between two points on a sphere
+fn angular_distance(a: &GeoPoint, b: &GeoPoint) -> f64 {
+    Haversine.distance(Point::from(*a), Point::from(*b)) / EARTH_RADIUS_METERS
+}
+
+/// Initial bearing in radians of the great-circle arc going from `a` to `b`
+fn initial_bearing(a: &GeoPoint, b: &GeoPoint) -> f64 {
+    let lat_a = a.lat.to_radians();
+    let lat_b = b.lat.to_radians();
+    let delta_lon = (b.lon.0 - a.lon.0).to_radians();
+
+    let y = delta_lon.sin() * lat_b.cos();
+    let x = lat_a.cos() * lat_b.sin() - lat_a.sin() * lat_b.cos() * delta_lon.cos();
+    y.atan2(x)
+}
+
+/// Distance in meters from `point` to the shortest great-circle arc between `start` and `end`
+/// <https://www.movable-type.co.uk/scripts/latlong.html#cross-track>
+pub fn distance_to_segment(point: &GeoPoint, start: &GeoPoint, end: &GeoPoint) -> f64 {
+    let distance_to_start = angular_distance(start, point);
+    let segment_length = angular_distance(start, end);
+
+    if segment_length == 0.0 {
+        return distance_to_start * EARTH_RADIUS_METERS;
+    }
+
+    let bearing_delta = initial_bearing(start, point) - initial_bearing(start, end);
+
+    // Point lies behind the start of the segment
+    if bearing_delta.cos() < 0.0 {
+        return distance_to_start * EARTH_RADIUS_METERS;
+    }
+
+    let cross_track = (distance_to_start.sin() * bearing_delta.sin())
+        .clamp(-1.0, 1.0)
+        .asin();
+    let along_track = (distance_to_start.cos() / cross_track.cos())
+        .clamp(-1.0, 1.0)
+        .acos();
+
+    // Projection of the point lies beyond the end of the segment
+    if along_track > segment_length {
+        return angular_distance(end, point) * EARTH_RADIUS_METERS;
+    }
+
+    cross_track.abs() * EARTH_RADIUS_METERS
+}
+
+/// Simplify the line with the Douglas-Peucker algorithm: drop points, which are closer than
+/// `tolerance` meters to the simplified line. The first and the last points are always kept.
+pub fn simplify_line(points: &[GeoPoint], tolerance: f64) -> Vec<GeoPoint> {
+    if points.len() <= 2 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        let farthest = (start + 1..end)
+            .map(|i| {
+                (
+                    i,
+                    distance_to_segment(&points[i], &points[start], &points[end]),
+                )
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((i, distance)) = farthest
+            && distance > tolerance
+        {
+            keep[i] = true;
+            ranges.push((start, i));
+            ranges.push((i, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(*point))
+        .collect()
+}
+
 /// A globally-average value is usually considered to be 6,371 kilometres (3,959 mi) with a 0.3% variability (±10 km).
 /// <https://en.wikipedia.org/wiki/Earth_radius>.
 const EARTH_RADIUS_METERS: f64 = 6371.0 * 1000.;
@@ -583,6 +731,52 @@ fn minimum_bounding_rectangle_for_circle(circle: &GeoRadius) -> GeoBoundingBox {
     }
 }
 
+/// Returns the GeoBoundingBox covering everything within `distance` of the great-circle segment.
+///
+/// Every point of the arc is within half of its length from the arc midpoint,
+/// so the rectangle of a circle around the midpoint is a valid (if not the tightest) cover.
+fn minimum_bounding_rectangle_for_segment(
+    start: &GeoPoint,
+    end: &GeoPoint,
+    distance: f64,
+) -> GeoBoundingBox {
+    let lat_start = start.lat.to_radians();
+    let lat_end = end.lat.to_radians();
+    let lon_start = start.lon.to_radians();
+    let delta_lon = (end.lon.0 - start.lon.0).to_radians();
+
+    // Midpoint of the great-circle arc
+    let bx = lat_end.cos() * delta_lon.cos();
+    let by = lat_end.cos() * delta_lon.sin();
+    let mid_lat = (lat_start.sin() + lat_end.sin())
+        .atan2(((lat_start.cos() + bx).powi(2) + by.powi(2)).sqrt());
+    let mid_lon = lon_start + by.atan2(lat_start.cos() + bx);
+
+    let circle = GeoRadius {
+        center: GeoPoint {
+            lon: OrderedFloat(sphere_lon(mid_lon.to_degrees())),
+            lat: OrderedFloat(mid_lat.to_degrees()),
+        },
+        radius: OrderedFloat(angular_distance(start, end) * EARTH_RADIUS_METERS / 2.0 + distance),
+    };
+
+    let bounding_box = minimum_bounding_rectangle_for_circle(&circle);
+    if bounding_box.top_left.lon.is_nan() || bounding_box.bottom_right.lon.is_nan() {
+        // Circle is too wide for the longitude delta to be defined - cover all longitudes
+        return GeoBoundingBox {
+            top_left: GeoPoint {
+                lon: OrderedFloat(LON_RANGE.start + COORD_EPS),
+                lat: bounding_box.top_left.lat,
+            },
+            bottom_right: GeoPoint {
+                lon: OrderedFloat(LON_RANGE.end - COORD_EPS),
+                lat: bounding_box.bottom_right.lat,
+            },
+        };
+    }
+    bounding_box
+}
+
 fn minimum_bounding_rectangle_for_boundary(boundary: &LineString) -> GeoBoundingBox {
     let mut min_lon = f64::MAX;
     let mut max_lon = f64::MIN;
@@ -637,6 +831,8 @@ mod tests {
         lon: OrderedFloat(-73.991516),
     };
 
+    const GEO_QUERY_MAX_REGION_TEST: usize = 12;
+
     #[test]
     fn geohash_ordering() {
         let mut v: Vec<&[u8]> = vec![
@@ -1463,4 +1659,155 @@ mod tests {
         let hashes2 = circle_hashes(&circle2, GeoHash::MAX_LEN);
         assert!(hashes2.is_err());
     }
+
+    #[test]
+    fn distance_to_segment_projection() {
+        let start = GeoPoint {
+            lon: OrderedFloat(0.0),
+            lat: OrderedFloat(0.0),
+        };
+        let end = GeoPoint {
+            lon: OrderedFloat(10.0),
+            lat: OrderedFloat(0.0),
+        };
+
+        // Perpendicular projection falls inside the segment
+        let above = GeoPoint {
+            lon: OrderedFloat(5.0),
+            lat: OrderedFloat(1.0),
+        };
+        let expected = Haversine.distance(Point::new(5.0, 0.0), Point::from(above));
+        assert!((distance_to_segment(&above, &start, &end) - expected).abs() < 10.0);
+
+        // Points outside of the segment are measured to the closest end
+        let before = GeoPoint {
+            lon: OrderedFloat(-1.0),
+            lat: OrderedFloat(0.5),
+        };
+        let expected = Haversine.distance(Point::from(start), Point::from(before));
+        assert!((distance_to_segment(&before, &start, &end) - expected).abs() < 10.0);
+
+        let after = GeoPoint {
+            lon: OrderedFloat(11.0),
+            lat: OrderedFloat(-0.5),
+        };
+        let expected = Haversine.distance(Point::from(end), Point::from(after));
+        assert!((distance_to_segment(&after, &start, &end) - expected).abs() < 10.0);
+
+        // Degenerate segment acts as a point
+        let expected = Haversine.distance(Point::from(start), Point::from(above));
+        assert!((distance_to_segment(&above, &start, &start) - expected).abs() < 10.0);
+    }
+
+    #[test]
+    fn test_simplify_line() {
+        let points = [
+            (0.0, 0.0),
+            (1.0, 0.0001),
+            (2.0, 0.0),
+            (3.0, 1.0),
+            (4.0, 0.0),
+        ]
+        .map(|(lon, lat)| GeoPoint::new_unchecked(lon, lat));
+
+        // The second point is about 11 meters away from the line
+        let simplified = simplify_line(&points, 100.0);
+        assert_eq!(simplified, [points[0], points[2], points[3], points[4]]);
+
+        assert_eq!(simplify_line(&points, 0.0), points);
+        assert_eq!(simplify_line(&points, 1e9), [points[0], points[4]]);
+        assert_eq!(simplify_line(&points[..2], 1e9), points[..2]);
+    }
+
+    #[test]
+    fn path_hashes_nyc_berlin() {
+        let near_path = GeoNearPath {
+            path: vec![NYC, BERLIN],
+            distance: OrderedFloat(50_000.0),
+        };
+
+        let hashes = path_hashes(&near_path, GEO_QUERY_MAX_REGION_TEST).unwrap();
+        assert!(!hashes.is_empty());
+        assert!(hashes.len() <= GEO_QUERY_MAX_REGION_TEST);
+
+        // Both ends of the route must be covered
+        for point in [NYC, BERLIN] {
+            let point_hash = GeoHash::from(point);
+            assert!(hashes.iter().any(|hash| point_hash.starts_with(*hash)));
+        }
+
+        // Single region is still enough to cover the whole route
+        assert!(!path_hashes(&near_path, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn path_hashes_crossing_antimeridian() {
+        let west = GeoPoint {
+            lon: OrderedFloat(179.5),
+            lat: OrderedFloat(10.0),
+        };
+        let east = GeoPoint {
+            lon: OrderedFloat(-179.5),
+            lat: OrderedFloat(10.0),
+        };
+        let near_path = GeoNearPath {
+            path: vec![west, east],
+            distance: OrderedFloat(1000.0),
+        };
+
+        // Shortest arc crosses the antimeridian, not the prime meridian
+        let on_antimeridian = GeoPoint {
+            lon: OrderedFloat(180.0 - COORD_EPS),
+            lat: OrderedFloat(10.0),
+        };
+        assert!(near_path.check_point(&on_antimeridian));
+        let on_prime_meridian = GeoPoint {
+            lon: OrderedFloat(0.0),
+            lat: OrderedFloat(10.0),
+        };
+        assert!(!near_path.check_point(&on_prime_meridian));
+
+        let hashes = path_hashes(&near_path, GEO_QUERY_MAX_REGION_TEST).unwrap();
+        for point in [west, east, on_antimeridian] {
+            let point_hash = GeoHash::from(point);
+            assert!(hashes.iter().any(|hash| point_hash.starts_with(*hash)));
+        }
+        let prime_hash = GeoHash::from(on_prime_meridian);
+        assert!(!hashes.iter().any(|hash| prime_hash.starts_with(*hash)));
+    }
+
+    #[test]
+    fn random_paths_cover_points() {
+        let mut r = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let path = (0..r.random_range(2..5))
+                .map(|_| GeoPoint {
+                    lon: OrderedFloat(r.random_range(LON_RANGE)),
+                    lat: OrderedFloat(r.random_range(-80.0..80.0)),
+                })
+                .collect_vec();
+            let near_path = GeoNearPath {
+                path,
+                distance: OrderedFloat(r.random_range(1.0..100_000.0)),
+            };
+
+            let hashes = path_hashes(&near_path, GEO_QUERY_MAX_REGION_TEST).unwrap();
+            assert!(hashes.len() <= GEO_QUERY_MAX_REGION_TEST);
+
+            for point in &near_path.path {
+                let point_hash = GeoHash::from(*point);
+                assert!(hashes.iter().any(|hash| point_hash.starts_with(*hash)));
+            }
+        }
+    }
+
+    #[test]
+    fn path_hashes_invalid_max_regions() {
+        let near_path = GeoNearPath {
+            path: vec![NYC, BERLIN],
+            distance: OrderedFloat(1000.0),
+        };
+        assert!(path_hashes(&near_path, 0).is_err());
+    }
 }
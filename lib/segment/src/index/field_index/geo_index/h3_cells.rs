@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+use common::types::PointOffsetType;
+use h3o::{CellIndex, LatLng, Resolution};
+use roaring::RoaringBitmap;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::types::GeoPoint;
+
+/// Points of a geo index, grouped by the H3 cells containing their locations.
+///
+/// Unlike geohash regions, H3 cells of the same resolution have nearly the same area everywhere
+/// on the globe, so the point counts per cell are comparable, also close to the poles.
+///
+/// Kept in RAM only, and built again from the values of the geo index, when it is loaded.
+#[derive(Debug)]
+pub struct H3Cells {
+    resolution: Resolution,
+    /// Points per cell, a point with several locations is contained in all of their cells
+    points_per_cell: BTreeMap<CellIndex, RoaringBitmap>,
+}
+
+impl H3Cells {
+    pub fn new(resolution: u8) -> OperationResult<Self> {
+        let resolution = Resolution::try_from(resolution).map_err(|err| {
+            OperationError::validation_error(format!("Invalid H3 resolution: {err}"))
+        })?;
+        Ok(Self {
+            resolution,
+            points_per_cell: BTreeMap::new(),
+        })
+    }
+
+    /// Distinct cells containing the given locations, sorted
+    pub fn cells_of(&self, values: impl IntoIterator<Item = GeoPoint>) -> Vec<CellIndex> {
+        let mut cells: Vec<_> = values
+            .into_iter()
+            .filter_map(|point| LatLng::new(point.lat.0, point.lon.0).ok())
+            .map(|lat_lng| lat_lng.to_cell(self.resolution))
+            .collect();
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+
+    pub fn add_point(&mut self, idx: PointOffsetType, values: impl IntoIterator<Item = GeoPoint>) {
+        for cell in self.cells_of(values) {
+            self.points_per_cell.entry(cell).or_default().insert(idx);
+        }
+    }
+
+    /// Remove the point from the cells of its former `values`
+    pub fn remove_point(
+        &mut self,
+        idx: PointOffsetType,
+        values: impl IntoIterator<Item = GeoPoint>,
+    ) {
+        for cell in self.cells_of(values) {
+            if let Some(points) = self.points_per_cell.get_mut(&cell) {
+                points.remove(idx);
+                if points.is_empty() {
+                    self.points_per_cell.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// Iterate over the cells and their points, in the order of the cells
+    pub fn iter_cells(&self) -> impl Iterator<Item = (CellIndex, &RoaringBitmap)> + '_ {
+        self.points_per_cell
+            .iter()
+            .map(|(&cell, points)| (cell, points))
+    }
+
+    /// Number of points in each of the cells
+    ///
+    /// Points with internal id `>= deferred_internal_id` are not counted, cells without any
+    /// counted point are skipped.
+    pub fn counts_per_cell(
+        &self,
+        deferred_internal_id: Option<PointOffsetType>,
+    ) -> impl Iterator<Item = (CellIndex, usize)> + '_ {
+        self.iter_cells().filter_map(move |(cell, points)| {
+            let count = match deferred_internal_id {
+                Some(deferred_internal_id) => points.range_cardinality(..deferred_internal_id),
+                None => points.len(),
+            };
+            (count > 0).then_some((cell, count as usize))
+        })
+    }
+
+    /// Approximate RAM usage in bytes.
+    pub fn ram_usage_bytes(&self) -> usize {
+        let btree_entry_overhead = size_of::<usize>() * 3;
+        self.points_per_cell
+            .values()
+            .map(|points| size_of::<CellIndex>() + btree_entry_overhead + points.serialized_size())
+            .sum()
+    }
+}
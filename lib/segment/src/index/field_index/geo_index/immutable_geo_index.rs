@@ -6,6 +6,7 @@ use common::generic_consts::Random;
 use common::types::PointOffsetType;
 use common::universal_io::{MmapFile, ReadRange, UniversalRead};
 
+use super::h3_cells::H3Cells;
 use super::mmap_geo_index::StoredGeoMapIndex;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -57,6 +58,7 @@ pub struct ImmutableGeoMapIndex {
     max_values_per_point: usize,
     storage: Storage,
     cached_ram_usage_bytes: usize,
+    pub(super) h3_cells: Option<H3Cells>,
 }
 
 enum Storage {
@@ -161,6 +163,7 @@ impl ImmutableGeoMapIndex {
             max_values_per_point: index.max_values_per_point(),
             storage: Storage::Mmap(Box::new(index)),
             cached_ram_usage_bytes: 0,
+            h3_cells: None,
         };
 
         // Update point and value counts based on deleted points
@@ -422,6 +425,7 @@ impl ImmutableGeoMapIndex {
             max_values_per_point: _,
             storage: _,
             cached_ram_usage_bytes: _,
+            h3_cells: _, // accounted by `GeoMapIndex`
         } = self;
 
         let cph_bytes = counts_per_hash.capacity() * size_of::<Counts>();
@@ -11,6 +11,7 @@ use common::fs::{atomic_save_json, clear_disk_cache, read_json};
 use common::generic_consts::{Random, Sequential};
 use common::iterator_ext::ordering_iterator::OrderingIterator;
 use common::mmap::{MmapSlice, create_and_ensure_length};
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::stored_bitslice::MmapBitSlice;
 use common::types::PointOffsetType;
 use common::universal_io::{MmapFile, OpenOptions, ReadRange, TypedStorage, UniversalRead};
@@ -18,6 +19,7 @@ use fs_err as fs;
 use memmap2::MmapMut;
 use serde::{Deserialize, Serialize};
 
+use super::h3_cells::H3Cells;
 use super::mutable_geo_index::InMemoryGeoMapIndex;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -97,6 +99,7 @@ pub struct StoredGeoMapIndex<S: StoredGeoMapIndexStorage> {
     points_values_count: usize,
     max_values_per_point: usize,
     is_on_disk: bool,
+    pub(super) h3_cells: Option<H3Cells>,
 }
 
 pub(super) struct Storage<S: StoredGeoMapIndexStorage> {
@@ -319,6 +322,7 @@ impl<S: StoredGeoMapIndexStorage> StoredGeoMapIndex<S> {
             points_values_count: stats.points_values_count,
             max_values_per_point: stats.max_values_per_point,
             is_on_disk,
+            h3_cells: None,
         }))
     }
 
@@ -636,6 +640,16 @@ impl<S: StoredGeoMapIndexStorage> StoredGeoMapIndex<S> {
         Ok(())
     }
 
+    /// Populate pages in the storage, which fit into the `budget`.
+    /// Geo-hash lookups are populated first, they are used by every filter on the index.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        budget.populate(PopulateStage::Lookup, &self.storage.counts_per_hash)?;
+        budget.populate(PopulateStage::Lookup, &self.storage.points_map)?;
+        budget.populate(PopulateStage::Data, &self.storage.points_map_ids)?;
+        self.storage.point_to_values.populate_with_budget(budget)?;
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         let Self {
@@ -645,6 +659,7 @@ impl<S: StoredGeoMapIndexStorage> StoredGeoMapIndex<S> {
             points_values_count: _,
             max_values_per_point: _,
             is_on_disk: _,
+            h3_cells: _,
         } = self;
         let Storage {
             counts_per_hash,
@@ -1,22 +1,27 @@
+use std::borrow::Cow;
 use std::cmp::{max, min};
 use std::path::{Path, PathBuf};
 
 use common::bitvec::{BitSlice, BitVec};
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::populate_budget::PopulateBudget;
 use common::types::PointOffsetType;
 use common::universal_io::MmapFile;
 use itertools::{Either, Itertools};
 use mutable_geo_index::InMemoryGeoMapIndex;
 use serde_json::Value;
 
+use self::h3_cells::H3Cells;
 use self::immutable_geo_index::ImmutableGeoMapIndex;
 use self::mmap_geo_index::StoredGeoMapIndex;
 use self::mutable_geo_index::MutableGeoMapIndex;
 use super::FieldIndexBuilderTrait;
+use super::facet_index::FacetIndex;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::facets::{FacetHit, FacetValueRef};
 use crate::index::field_index::geo_hash::{
-    GeoHash, circle_hashes, common_hash_prefix, geo_hash_to_box, polygon_hashes,
+    GeoHash, circle_hashes, common_hash_prefix, geo_hash_to_box, path_hashes, polygon_hashes,
     polygon_hashes_estimation, rectangle_hashes,
 };
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
@@ -27,6 +32,7 @@ use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{FieldCondition, GeoPoint, PayloadKeyType};
 
+pub mod h3_cells;
 pub mod immutable_geo_index;
 pub mod mmap_geo_index;
 pub mod mutable_geo_index;
@@ -83,6 +89,7 @@ impl GeoMapIndex {
             in_memory_index: InMemoryGeoMapIndex::new(),
             is_on_disk,
             deleted_points: deleted_points.to_owned(),
+            h3_resolution: None,
         }
     }
 
@@ -90,6 +97,42 @@ impl GeoMapIndex {
         GeoMapIndexGridstoreBuilder::new(dir)
     }
 
+    /// Additionally index the points by the H3 cells of the given `resolution`.
+    ///
+    /// Cells are built from the values already in the index, and kept up to date on updates.
+    /// Does nothing if `resolution` is `None`.
+    pub fn with_h3_cells(mut self, resolution: Option<u8>) -> OperationResult<Self> {
+        let Some(resolution) = resolution else {
+            return Ok(self);
+        };
+
+        let mut h3_cells = H3Cells::new(resolution)?;
+        for idx in self.iterator(vec![GeoHash::default()])? {
+            if let Some(values) = self.get_values(idx) {
+                h3_cells.add_point(idx, values);
+            }
+        }
+        *self.h3_cells_slot() = Some(h3_cells);
+        Ok(self)
+    }
+
+    /// H3 cells of the points, if the index is configured with a H3 resolution
+    pub fn h3_cells(&self) -> Option<&H3Cells> {
+        match self {
+            GeoMapIndex::Mutable(index) => index.h3_cells.as_ref(),
+            GeoMapIndex::Immutable(index) => index.h3_cells.as_ref(),
+            GeoMapIndex::Storage(index) => index.h3_cells.as_ref(),
+        }
+    }
+
+    fn h3_cells_slot(&mut self) -> &mut Option<H3Cells> {
+        match self {
+            GeoMapIndex::Mutable(index) => &mut index.h3_cells,
+            GeoMapIndex::Immutable(index) => &mut index.h3_cells,
+            GeoMapIndex::Storage(index) => &mut index.h3_cells,
+        }
+    }
+
     fn points_count(&self) -> usize {
         match self {
             GeoMapIndex::Mutable(index) => index.points_count(),
@@ -237,6 +280,11 @@ impl GeoMapIndex {
             points_count: self.points_count(),
             points_values_count: self.points_values_count(),
             histogram_bucket_size: None,
+            write_amplification: None,
+            oversized_values: None,
+            stopwords_language: None,
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
             index_type: match self {
                 GeoMapIndex::Mutable(_) => "mutable_geo",
                 GeoMapIndex::Immutable(_) => "immutable_geo",
@@ -311,11 +359,12 @@ impl GeoMapIndex {
 
     /// Approximate RAM usage in bytes for in-memory structures.
     pub fn ram_usage_bytes(&self) -> usize {
-        match self {
+        let index_bytes = match self {
             GeoMapIndex::Mutable(index) => index.ram_usage_bytes(),
             GeoMapIndex::Immutable(index) => index.ram_usage_bytes(),
             GeoMapIndex::Storage(index) => index.ram_usage_bytes(),
-        }
+        };
+        index_bytes + self.h3_cells().map_or(0, H3Cells::ram_usage_bytes)
     }
 
     pub fn is_on_disk(&self) -> bool {
@@ -337,6 +386,16 @@ impl GeoMapIndex {
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        match self {
+            GeoMapIndex::Mutable(_) => {}   // Not a mmap
+            GeoMapIndex::Immutable(_) => {} // Not a mmap
+            GeoMapIndex::Storage(index) => index.populate_with_budget(budget)?,
+        }
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
@@ -372,6 +431,15 @@ pub struct GeoMapIndexMmapBuilder {
     in_memory_index: InMemoryGeoMapIndex,
     is_on_disk: bool,
     deleted_points: BitVec,
+    h3_resolution: Option<u8>,
+}
+
+impl GeoMapIndexMmapBuilder {
+    /// Build the index with H3 cells of the given `resolution`, see [`GeoMapIndex::with_h3_cells`]
+    pub fn with_h3_resolution(mut self, h3_resolution: Option<u8>) -> Self {
+        self.h3_resolution = h3_resolution;
+        self
+    }
 }
 
 impl FieldIndexBuilderTrait for GeoMapIndexMmapBuilder {
@@ -396,12 +464,13 @@ impl FieldIndexBuilderTrait for GeoMapIndexMmapBuilder {
     }
 
     fn finalize(self) -> OperationResult<Self::FieldIndexType> {
-        Ok(GeoMapIndex::Storage(Box::new(StoredGeoMapIndex::build(
+        GeoMapIndex::Storage(Box::new(StoredGeoMapIndex::build(
             self.in_memory_index,
             &self.path,
             self.is_on_disk,
             &self.deleted_points,
-        )?)))
+        )?))
+        .with_h3_cells(self.h3_resolution)
     }
 }
 
@@ -415,14 +484,23 @@ impl ValueIndexer for GeoMapIndex {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         match self {
-            GeoMapIndex::Mutable(index) => index.add_many_geo_points(id, &values, hw_counter),
-            GeoMapIndex::Immutable(_) => Err(OperationError::service_error(
-                "Can't add values to immutable geo index",
-            )),
-            GeoMapIndex::Storage(_) => Err(OperationError::service_error(
-                "Can't add values to mmap geo index",
-            )),
+            GeoMapIndex::Mutable(index) => index.add_many_geo_points(id, &values, hw_counter)?,
+            GeoMapIndex::Immutable(_) => {
+                return Err(OperationError::service_error(
+                    "Can't add values to immutable geo index",
+                ));
+            }
+            GeoMapIndex::Storage(_) => {
+                return Err(OperationError::service_error(
+                    "Can't add values to mmap geo index",
+                ));
+            }
+        }
+
+        if let Some(h3_cells) = self.h3_cells_slot() {
+            h3_cells.add_point(id, values);
         }
+        Ok(())
     }
 
     fn get_value(value: &Value) -> Option<GeoPoint> {
@@ -441,6 +519,17 @@ impl ValueIndexer for GeoMapIndex {
     }
 
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        // Cells are found by the values, which are gone after the removal
+        if self.h3_cells().is_some() {
+            let values: Vec<_> = self
+                .get_values(id)
+                .map(|values| values.collect())
+                .unwrap_or_default();
+            if let Some(h3_cells) = self.h3_cells_slot() {
+                h3_cells.remove_point(id, values);
+            }
+        }
+
         match self {
             GeoMapIndex::Mutable(index) => index.remove_point(id),
             GeoMapIndex::Immutable(index) => index.remove_point(id),
@@ -455,11 +544,22 @@ impl ValueIndexer for GeoMapIndex {
 pub struct GeoMapIndexGridstoreBuilder {
     dir: PathBuf,
     index: Option<GeoMapIndex>,
+    h3_resolution: Option<u8>,
 }
 
 impl GeoMapIndexGridstoreBuilder {
     fn new(dir: PathBuf) -> Self {
-        Self { dir, index: None }
+        Self {
+            dir,
+            index: None,
+            h3_resolution: None,
+        }
+    }
+
+    /// Build the index with H3 cells of the given `resolution`, see [`GeoMapIndex::with_h3_cells`]
+    pub fn with_h3_resolution(mut self, h3_resolution: Option<u8>) -> Self {
+        self.h3_resolution = h3_resolution;
+        self
     }
 }
 
@@ -472,9 +572,11 @@ impl FieldIndexBuilderTrait for GeoMapIndexGridstoreBuilder {
             "index must be initialized exactly once",
         );
         self.index.replace(
-            GeoMapIndex::new_gridstore(self.dir.clone(), true)?.ok_or_else(|| {
-                OperationError::service_error("Failed to open GeoMapIndex after creating it")
-            })?,
+            GeoMapIndex::new_gridstore(self.dir.clone(), true)?
+                .ok_or_else(|| {
+                    OperationError::service_error("Failed to open GeoMapIndex after creating it")
+                })?
+                .with_h3_cells(self.h3_resolution)?,
         );
         Ok(())
     }
@@ -582,6 +684,18 @@ impl PayloadFieldIndex for GeoMapIndex {
             ))));
         }
 
+        if let Some(geo_near_path) = &condition.geo_near_path {
+            let geo_hashes = path_hashes(geo_near_path, GEO_QUERY_MAX_REGION)?;
+            let geo_condition_copy = geo_near_path.clone();
+            return Ok(Some(Box::new(self.iterator(geo_hashes)?.filter(
+                move |&point| {
+                    self.check_values_any(point, hw_counter, |geo_point| {
+                        geo_condition_copy.check_point(geo_point)
+                    })
+                },
+            ))));
+        }
+
         Ok(None)
     }
 
@@ -639,9 +753,23 @@ impl PayloadFieldIndex for GeoMapIndex {
             return Ok(Some(exterior_estimation));
         }
 
+        if let Some(geo_near_path) = &condition.geo_near_path {
+            let Some(geo_hashes) = path_hashes(geo_near_path, GEO_QUERY_MAX_REGION).ok() else {
+                return Ok(None);
+            };
+            let mut estimation = self.match_cardinality(&geo_hashes, hw_counter)?;
+            estimation
+                .primary_clauses
+                .push(PrimaryCondition::Condition(Box::new(condition.clone())));
+            return Ok(Some(estimation));
+        }
+
         Ok(None)
     }
 
+    /// Blocks are the smallest geo-hash regions with more than `threshold` points, so the
+    /// precision of the regions adapts to the density of the points.
+    /// Each block condition is a `GeoBoundingBox` of its geo-hash region.
     fn for_each_payload_block(
         &self,
         threshold: usize,
@@ -661,9 +789,85 @@ impl PayloadFieldIndex for GeoMapIndex {
     }
 }
 
+/// Facets of a geo index are the H3 cells of the points, identified by their hex string
+impl FacetIndex for GeoMapIndex {
+    fn for_points_values(
+        &self,
+        points: impl Iterator<Item = PointOffsetType>,
+        _hw_counter: &HardwareCounterCell,
+        mut f: impl FnMut(PointOffsetType, &mut dyn Iterator<Item = FacetValueRef<'_>>),
+    ) -> OperationResult<()> {
+        let Some(h3_cells) = self.h3_cells() else {
+            return Ok(());
+        };
+        for point_id in points {
+            let cells = self
+                .get_values(point_id)
+                .map(|values| h3_cells.cells_of(values))
+                .unwrap_or_default();
+            f(
+                point_id,
+                &mut cells
+                    .into_iter()
+                    .map(|cell| FacetValueRef::Keyword(Cow::Owned(cell.to_string()))),
+            );
+        }
+        Ok(())
+    }
+
+    fn for_each_value(
+        &self,
+        mut f: impl FnMut(FacetValueRef<'_>) -> OperationResult<()>,
+    ) -> OperationResult<()> {
+        let Some(h3_cells) = self.h3_cells() else {
+            return Ok(());
+        };
+        h3_cells
+            .iter_cells()
+            .try_for_each(|(cell, _)| f(FacetValueRef::Keyword(Cow::Owned(cell.to_string()))))
+    }
+
+    fn for_each_value_map(
+        &self,
+        _hw_counter: &HardwareCounterCell,
+        mut f: impl FnMut(
+            FacetValueRef<'_>,
+            &mut dyn Iterator<Item = PointOffsetType>,
+        ) -> OperationResult<()>,
+    ) -> OperationResult<()> {
+        let Some(h3_cells) = self.h3_cells() else {
+            return Ok(());
+        };
+        h3_cells.iter_cells().try_for_each(|(cell, points)| {
+            f(
+                FacetValueRef::Keyword(Cow::Owned(cell.to_string())),
+                &mut points.iter(),
+            )
+        })
+    }
+
+    fn for_each_count_per_value(
+        &self,
+        deferred_internal_id: Option<PointOffsetType>,
+        mut f: impl FnMut(FacetHit<FacetValueRef<'_>>) -> OperationResult<()>,
+    ) -> OperationResult<()> {
+        let Some(h3_cells) = self.h3_cells() else {
+            return Ok(());
+        };
+        h3_cells
+            .counts_per_cell(deferred_internal_id)
+            .try_for_each(|(cell, count)| {
+                f(FacetHit {
+                    value: FacetValueRef::Keyword(Cow::Owned(cell.to_string())),
+                    count,
+                })
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::collections::{BTreeSet, HashSet};
+    use std::collections::{BTreeSet, HashMap, HashSet};
     use std::ops::Range;
 
     use common::counter::hardware_accumulator::HwMeasurementAcc;
@@ -677,10 +881,11 @@ mod tests {
     use tempfile::{Builder, TempDir};
 
     use super::*;
+    use crate::data_types::facets::FacetValue;
     use crate::fixtures::payload_fixtures::random_geo_payload;
     use crate::json_path::JsonPath;
     use crate::types::test_utils::build_polygon;
-    use crate::types::{GeoBoundingBox, GeoLineString, GeoPolygon, GeoRadius};
+    use crate::types::{GeoBoundingBox, GeoLineString, GeoNearPath, GeoPolygon, GeoRadius};
 
     /// Generous default size for the deleted-points bitslice used in tests.
     ///
@@ -770,6 +975,10 @@ mod tests {
         FieldCondition::new_geo_polygon(JsonPath::new(key), geo_polygon)
     }
 
+    fn condition_for_geo_near_path(key: &str, geo_near_path: GeoNearPath) -> FieldCondition {
+        FieldCondition::new_geo_near_path(JsonPath::new(key), geo_near_path)
+    }
+
     fn condition_for_geo_box(key: &str, geo_bounding_box: GeoBoundingBox) -> FieldCondition {
         FieldCondition::new_geo_bounding_box(JsonPath::new(key), geo_bounding_box)
     }
@@ -936,6 +1145,7 @@ mod tests {
         let europe_no_berlin = GeoPolygon {
             exterior: europe,
             interiors: Some(vec![berlin]),
+            tolerance: None,
         };
         check_cardinality_match(
             polygon_hashes(&europe_no_berlin, GEO_QUERY_MAX_REGION).unwrap(),
@@ -1062,6 +1272,16 @@ mod tests {
             |geo_point| geo_polygon.convert().check_point(geo_point),
             index_type,
         );
+
+        let geo_near_path = GeoNearPath {
+            path: vec![NYC, LOS_ANGELES],
+            distance: OrderedFloat(300_000.0),
+        };
+        check_geo_indexed_filtering(
+            condition_for_geo_near_path("test", geo_near_path.clone()),
+            |geo_point| geo_near_path.check_point(geo_point),
+            index_type,
+        );
     }
 
     #[rstest]
@@ -1395,6 +1615,7 @@ mod tests {
                 ],
             },
             interiors: None,
+            tolerance: None,
         };
         let polygon_with_interior = GeoPolygon {
             exterior: polygon.exterior.clone(),
@@ -1406,6 +1627,7 @@ mod tests {
                     GeoPoint::new_unchecked(13.2257943327987, 52.62328249733332),
                 ],
             }]),
+            tolerance: None,
         };
         let hashes = polygon_hashes(&polygon, GEO_QUERY_MAX_REGION).unwrap();
         let hashes_with_interior =
@@ -1658,6 +1880,7 @@ mod tests {
                 ],
             },
             interiors: None,
+            tolerance: None,
         };
         let hashes = polygon_hashes(&polygon, GEO_QUERY_MAX_REGION).unwrap();
 
@@ -1770,6 +1993,7 @@ mod tests {
                 ],
             },
             interiors: None,
+            tolerance: None,
         };
         let europe_hashes = polygon_hashes(&europe_polygon, GEO_QUERY_MAX_REGION).unwrap();
 
@@ -1815,6 +2039,7 @@ mod tests {
                 ],
             },
             interiors: None,
+            tolerance: None,
         };
         let global_hashes = polygon_hashes(&global_polygon, GEO_QUERY_MAX_REGION).unwrap();
 
@@ -2063,4 +2288,110 @@ mod tests {
         hits.sort();
         assert_eq!(hits, vec![2, 4]);
     }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_h3_cell_counts(#[case] index_type: IndexType) {
+        let (mut builder, _temp_dir, _) = create_builder(index_type);
+        let hw_counter = HardwareCounterCell::new();
+
+        let payload = |points: &[GeoPoint]| {
+            Value::Array(
+                points
+                    .iter()
+                    .map(|point| json!({ "lon": point.lon, "lat": point.lat }))
+                    .collect(),
+            )
+        };
+        builder
+            .add_point(0, &[&payload(&[BERLIN])], &hw_counter)
+            .unwrap();
+        builder
+            .add_point(1, &[&payload(&[POTSDAM])], &hw_counter)
+            .unwrap();
+        builder
+            .add_point(2, &[&payload(&[BERLIN, POTSDAM])], &hw_counter)
+            .unwrap();
+        builder
+            .add_point(3, &[&payload(&[TOKYO])], &hw_counter)
+            .unwrap();
+
+        // Not a facet index without H3 cells
+        let index = builder.finalize().unwrap();
+        assert!(index.h3_cells().is_none());
+        let mut index = index.with_h3_cells(Some(6)).unwrap();
+
+        let counts = |index: &GeoMapIndex, deferred_internal_id| {
+            let mut counts = HashMap::new();
+            FacetIndex::for_each_count_per_value(index, deferred_internal_id, |hit| {
+                counts.insert(hit.value.to_owned(), hit.count);
+                Ok(())
+            })
+            .unwrap();
+            counts
+        };
+        let cell_of = |point: GeoPoint| {
+            let cells = index.h3_cells().unwrap().cells_of([point]);
+            FacetValue::Keyword(cells[0].to_string())
+        };
+        let (berlin, potsdam, tokyo) = (cell_of(BERLIN), cell_of(POTSDAM), cell_of(TOKYO));
+        // Berlin <-> Potsdam is 27 km, cells of resolution 6 are about 3 km wide
+        assert_ne!(berlin, potsdam);
+
+        assert_eq!(
+            counts(&index, None),
+            HashMap::from([
+                (berlin.clone(), 2),
+                (potsdam.clone(), 2),
+                (tokyo.clone(), 1),
+            ]),
+        );
+        // Cells of deferred points only are skipped
+        assert_eq!(
+            counts(&index, Some(1)),
+            HashMap::from([(berlin.clone(), 1)]),
+        );
+
+        let mut values = HashMap::new();
+        index
+            .for_points_values([2, 3].into_iter(), &hw_counter, |point_id, cells| {
+                values.insert(point_id, cells.map(|cell| cell.to_owned()).collect_vec());
+            })
+            .unwrap();
+        let mut berlin_potsdam = vec![berlin.clone(), potsdam.clone()];
+        berlin_potsdam.sort();
+        assert_eq!(
+            values,
+            HashMap::from([(2, berlin_potsdam), (3, vec![tokyo.clone()])]),
+        );
+
+        index.remove_point(2).unwrap();
+        assert_eq!(
+            counts(&index, None),
+            HashMap::from([
+                (berlin.clone(), 1),
+                (potsdam.clone(), 1),
+                (tokyo.clone(), 1),
+            ]),
+        );
+
+        if index_type == IndexType::MutableGridstore {
+            index
+                .add_point(4, &[&payload(&[TOKYO])], &hw_counter)
+                .unwrap();
+            assert_eq!(
+                counts(&index, None),
+                HashMap::from([(berlin, 1), (potsdam, 1), (tokyo, 2)]),
+            );
+        }
+
+        // The coarsest cells contain both Berlin and Potsdam
+        let index = index.with_h3_cells(Some(0)).unwrap();
+        let cells = index.h3_cells().unwrap().cells_of([BERLIN, POTSDAM]);
+        assert_eq!(cells.len(), 1);
+
+        assert!(H3Cells::new(16).is_err());
+    }
 }
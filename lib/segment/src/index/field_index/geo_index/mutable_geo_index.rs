@@ -8,6 +8,7 @@ use common::types::PointOffsetType;
 use gridstore::Gridstore;
 use gridstore::config::StorageOptions;
 
+use super::h3_cells::H3Cells;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::geo_hash::{GeoHash, encode_max_precision};
@@ -28,6 +29,7 @@ const GRIDSTORE_OPTIONS: StorageOptions = StorageOptions {
 pub struct MutableGeoMapIndex {
     in_memory_index: InMemoryGeoMapIndex,
     storage: Storage,
+    pub(super) h3_cells: Option<H3Cells>,
 }
 
 enum Storage {
@@ -143,6 +145,7 @@ impl MutableGeoMapIndex {
         Ok(Some(Self {
             in_memory_index,
             storage: Storage::Gridstore(store),
+            h3_cells: None,
         }))
     }
 
@@ -540,6 +543,7 @@ impl MutableGeoMapIndex {
         let Self {
             in_memory_index,
             storage: _,
+            h3_cells: _, // accounted by `GeoMapIndex`
         } = self;
         in_memory_index.ram_usage_bytes()
     }
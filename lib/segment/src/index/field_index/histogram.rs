@@ -51,6 +51,27 @@ impl<T: Numericable + Serialize + DeserializeOwned> Histogram<T> {
         }
     }
 
+    /// Build the histogram of unique `points`, sorted in ascending order
+    pub fn from_sorted(max_bucket_size: usize, precision: f64, points: &[Point<T>]) -> Self {
+        let mut histogram = Self::new(max_bucket_size, precision);
+        for (i, point) in points.iter().enumerate() {
+            // Neighbours are looked up among the points inserted so far
+            let inserted = &points[..=i];
+            histogram.insert(
+                *point,
+                |x| {
+                    let pos = inserted.partition_point(|p| p < x);
+                    pos.checked_sub(1).map(|pos| inserted[pos])
+                },
+                |x| {
+                    let pos = inserted.partition_point(|p| p <= x);
+                    inserted.get(pos).copied()
+                },
+            );
+        }
+        histogram
+    }
+
     pub fn load(path: &Path) -> OperationResult<Self> {
         let config_path = path.join(CONFIG_PATH);
         let borders_path = path.join(BORDERS_PATH);
@@ -112,6 +133,14 @@ impl<T: Numericable + Serialize + DeserializeOwned> Histogram<T> {
         self.total_count
     }
 
+    /// Borders of the histogram in the order of values, with the number of values each of them
+    /// stands for: the border itself and the values up to the next border.
+    pub fn border_samples(&self) -> impl Iterator<Item = (T, usize)> + '_ {
+        self.borders
+            .iter()
+            .map(|(border, counts)| (border.val, counts.right + 1))
+    }
+
     /// Infers boundaries for bucket of given size and starting point.
     /// Returns `to` range of values starting provided `from`value which is expected to contain
     /// `range_size` values
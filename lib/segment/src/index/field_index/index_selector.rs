@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use common::bitvec::BitSlice;
 use gridstore::Blob;
+use ordered_float::OrderedFloat;
 
 use super::bool_index::BoolIndex;
 use super::bool_index::immutable_bool_index::ImmutableBoolIndex;
@@ -9,13 +10,14 @@ use super::bool_index::mutable_bool_index::MutableBoolIndex;
 use super::geo_index::{GeoMapIndexGridstoreBuilder, GeoMapIndexMmapBuilder};
 use super::map_index::{MapIndex, MapIndexGridstoreBuilder, MapIndexKey, MapIndexMmapBuilder};
 use super::null_index::{ImmutableNullIndex, NullIndex};
+use super::numeric_index::scaled_float_index::ScaledFloatIndex;
 use super::numeric_index::{
     Encodable, NumericIndexGridstoreBuilder, NumericIndexIntoInnerValue, NumericIndexMmapBuilder,
 };
 use super::stored_point_to_values::StoredValue;
 use super::{FieldIndexBuilder, ValueIndexer};
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::index::TextIndexParams;
+use crate::data_types::index::{FloatIndexParams, GeoIndexParams, TextIndexParams};
 use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
 use crate::index::field_index::FieldIndex;
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
@@ -85,23 +87,45 @@ impl IndexSelector<'_> {
                     );
                 }
 
-                self.map_new(field, create_if_missing, deleted_points)?
+                self.map_new(field, create_if_missing, false, deleted_points)?
                     .map(FieldIndex::IntMapIndex)
             }
             (PayloadIndexType::DatetimeIndex, PayloadSchemaParams::Datetime(_)) => self
                 .numeric_new(field, create_if_missing, deleted_points)?
                 .map(FieldIndex::DatetimeIndex),
 
-            (PayloadIndexType::KeywordIndex, PayloadSchemaParams::Keyword(_)) => self
-                .map_new(field, create_if_missing, deleted_points)?
+            (PayloadIndexType::KeywordIndex, PayloadSchemaParams::Keyword(params)) => self
+                .map_new(
+                    field,
+                    create_if_missing,
+                    params.deduplicate_values.unwrap_or_default(),
+                    deleted_points,
+                )?
+                .map(|index| {
+                    index.with_case_insensitive(params.case_insensitive.unwrap_or_default())
+                })
+                .transpose()?
                 .map(FieldIndex::KeywordIndex),
 
-            (PayloadIndexType::FloatIndex, PayloadSchemaParams::Float(_)) => self
-                .numeric_new(field, create_if_missing, deleted_points)?
-                .map(FieldIndex::FloatIndex),
+            (PayloadIndexType::FloatIndex, PayloadSchemaParams::Float(params))
+                if params.precision.is_none() =>
+            {
+                self.numeric_new(field, create_if_missing, deleted_points)?
+                    .map(FieldIndex::FloatIndex)
+            }
 
-            (PayloadIndexType::GeoIndex, PayloadSchemaParams::Geo(_)) => self
-                .geo_new(field, create_if_missing, deleted_points)?
+            (
+                PayloadIndexType::ScaledFloatIndex,
+                PayloadSchemaParams::Float(FloatIndexParams {
+                    precision: Some(OrderedFloat(precision)),
+                    ..
+                }),
+            ) => self
+                .scaled_float_new(field, *precision, create_if_missing, deleted_points)?
+                .map(FieldIndex::ScaledFloatIndex),
+
+            (PayloadIndexType::GeoIndex, PayloadSchemaParams::Geo(params)) => self
+                .geo_new(field, params, create_if_missing, deleted_points)?
                 .map(FieldIndex::GeoIndex),
 
             (PayloadIndexType::FullTextIndex, PayloadSchemaParams::Text(params)) => self
@@ -118,11 +142,11 @@ impl IndexSelector<'_> {
                 .map(FieldIndex::BoolIndex),
 
             (PayloadIndexType::UuidIndex, PayloadSchemaParams::Uuid(_)) => self
-                .map_new(field, create_if_missing, deleted_points)?
+                .map_new(field, create_if_missing, false, deleted_points)?
                 .map(FieldIndex::UuidMapIndex),
 
             (PayloadIndexType::UuidMapIndex, PayloadSchemaParams::Uuid(_)) => self
-                .map_new(field, create_if_missing, deleted_points)?
+                .map_new(field, create_if_missing, false, deleted_points)?
                 .map(FieldIndex::UuidMapIndex),
 
             (PayloadIndexType::NullIndex, _) => {
@@ -149,15 +173,24 @@ impl IndexSelector<'_> {
         deleted_points: &BitSlice,
     ) -> OperationResult<Option<Vec<FieldIndex>>> {
         let indexes = match payload_schema.expand().as_ref() {
-            PayloadSchemaParams::Keyword(_) => self
-                .map_new(field, create_if_missing, deleted_points)?
+            PayloadSchemaParams::Keyword(keyword_params) => self
+                .map_new(
+                    field,
+                    create_if_missing,
+                    keyword_params.deduplicate_values.unwrap_or_default(),
+                    deleted_points,
+                )?
+                .map(|index| {
+                    index.with_case_insensitive(keyword_params.case_insensitive.unwrap_or_default())
+                })
+                .transpose()?
                 .map(|index| vec![FieldIndex::KeywordIndex(index)]),
             PayloadSchemaParams::Integer(integer_params) => {
                 let use_lookup = integer_params.lookup.unwrap_or(true);
                 let use_range = integer_params.range.unwrap_or(true);
 
                 let lookup = if use_lookup {
-                    match self.map_new(field, create_if_missing, deleted_points)? {
+                    match self.map_new(field, create_if_missing, false, deleted_points)? {
                         Some(index) => Some(FieldIndex::IntMapIndex(index)),
                         None => return Ok(None),
                     }
@@ -175,11 +208,16 @@ impl IndexSelector<'_> {
 
                 Some(lookup.into_iter().chain(range).collect())
             }
-            PayloadSchemaParams::Float(_) => self
-                .numeric_new(field, create_if_missing, deleted_points)?
-                .map(|index| vec![FieldIndex::FloatIndex(index)]),
-            PayloadSchemaParams::Geo(_) => self
-                .geo_new(field, create_if_missing, deleted_points)?
+            PayloadSchemaParams::Float(float_params) => match float_params.precision {
+                Some(OrderedFloat(precision)) => self
+                    .scaled_float_new(field, precision, create_if_missing, deleted_points)?
+                    .map(|index| vec![FieldIndex::ScaledFloatIndex(index)]),
+                None => self
+                    .numeric_new(field, create_if_missing, deleted_points)?
+                    .map(|index| vec![FieldIndex::FloatIndex(index)]),
+            },
+            PayloadSchemaParams::Geo(geo_index_params) => self
+                .geo_new(field, geo_index_params, create_if_missing, deleted_points)?
                 .map(|index| vec![FieldIndex::GeoIndex(index)]),
             PayloadSchemaParams::Text(text_index_params) => self
                 .text_new(
@@ -201,7 +239,7 @@ impl IndexSelector<'_> {
                 .numeric_new(field, create_if_missing, deleted_points)?
                 .map(|index| vec![FieldIndex::DatetimeIndex(index)]),
             PayloadSchemaParams::Uuid(_) => self
-                .map_new(field, create_if_missing, deleted_points)?
+                .map_new(field, create_if_missing, false, deleted_points)?
                 .map(|index| vec![FieldIndex::UuidMapIndex(index)]),
         };
 
@@ -216,11 +254,13 @@ impl IndexSelector<'_> {
         deleted_points: &BitSlice,
     ) -> OperationResult<Vec<FieldIndexBuilder>> {
         let builders = match payload_schema.expand().as_ref() {
-            PayloadSchemaParams::Keyword(_) => {
+            PayloadSchemaParams::Keyword(keyword_params) => {
                 vec![self.map_builder(
                     field,
                     FieldIndexBuilder::KeywordMmapIndex,
                     FieldIndexBuilder::KeywordGridstoreIndex,
+                    keyword_params.deduplicate_values.unwrap_or_default(),
+                    keyword_params.case_insensitive.unwrap_or_default(),
                     deleted_points,
                 )]
             }
@@ -233,6 +273,8 @@ impl IndexSelector<'_> {
                         field,
                         FieldIndexBuilder::IntMapMmapIndex,
                         FieldIndexBuilder::IntMapGridstoreIndex,
+                        false,
+                        false,
                         deleted_points,
                     ))
                 } else {
@@ -252,17 +294,23 @@ impl IndexSelector<'_> {
 
                 lookup.into_iter().chain(range).collect()
             }
-            PayloadSchemaParams::Float(_) => {
-                vec![self.numeric_builder(
-                    field,
-                    FieldIndexBuilder::FloatMmapIndex,
-                    FieldIndexBuilder::FloatGridstoreIndex,
-                    deleted_points,
-                )]
-            }
-            PayloadSchemaParams::Geo(_) => {
+            PayloadSchemaParams::Float(float_params) => match float_params.precision {
+                Some(OrderedFloat(precision)) => {
+                    vec![self.scaled_float_builder(field, precision, deleted_points)]
+                }
+                None => {
+                    vec![self.numeric_builder(
+                        field,
+                        FieldIndexBuilder::FloatMmapIndex,
+                        FieldIndexBuilder::FloatGridstoreIndex,
+                        deleted_points,
+                    )]
+                }
+            },
+            PayloadSchemaParams::Geo(geo_index_params) => {
                 vec![self.geo_builder(
                     field,
+                    geo_index_params,
                     FieldIndexBuilder::GeoMmapIndex,
                     FieldIndexBuilder::GeoGridstoreIndex,
                     deleted_points,
@@ -287,6 +335,8 @@ impl IndexSelector<'_> {
                     field,
                     FieldIndexBuilder::UuidMmapIndex,
                     FieldIndexBuilder::UuidGridstoreIndex,
+                    false,
+                    false,
                     deleted_points,
                 )]
             }
@@ -299,6 +349,7 @@ impl IndexSelector<'_> {
         &self,
         field: &JsonPath,
         create_if_missing: bool,
+        deduplicate_values: bool,
         deleted_points: &BitSlice,
     ) -> OperationResult<Option<MapIndex<N>>>
     where
@@ -306,10 +357,11 @@ impl IndexSelector<'_> {
     {
         Ok(match self {
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
+                // Immutable index keeps whatever policy was applied when it was built
                 MapIndex::new_mmap(&map_dir(dir, field), *is_on_disk, deleted_points)?
             }
             IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
-                MapIndex::new_gridstore(map_dir(dir, field), create_if_missing)?
+                MapIndex::new_gridstore(map_dir(dir, field), create_if_missing, deduplicate_values)?
             }
         })
     }
@@ -319,6 +371,8 @@ impl IndexSelector<'_> {
         field: &JsonPath,
         make_mmap: fn(MapIndexMmapBuilder<N>) -> FieldIndexBuilder,
         make_gridstore: fn(MapIndexGridstoreBuilder<N>) -> FieldIndexBuilder,
+        deduplicate_values: bool,
+        case_insensitive: bool,
         deleted_points: &BitSlice,
     ) -> FieldIndexBuilder
     where
@@ -326,11 +380,18 @@ impl IndexSelector<'_> {
     {
         match self {
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => make_mmap(
-                MapIndex::builder_mmap(&map_dir(dir, field), *is_on_disk, deleted_points),
+                MapIndex::builder_mmap(
+                    &map_dir(dir, field),
+                    *is_on_disk,
+                    deduplicate_values,
+                    deleted_points,
+                )
+                .with_case_insensitive(case_insensitive),
+            ),
+            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => make_gridstore(
+                MapIndex::builder_gridstore(map_dir(dir, field), deduplicate_values)
+                    .with_case_insensitive(case_insensitive),
             ),
-            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
-                make_gridstore(MapIndex::builder_gridstore(map_dir(dir, field)))
-            }
         }
     }
 
@@ -374,36 +435,93 @@ impl IndexSelector<'_> {
         }
     }
 
+    fn scaled_float_new(
+        &self,
+        field: &JsonPath,
+        precision: f64,
+        create_if_missing: bool,
+        deleted_points: &BitSlice,
+    ) -> OperationResult<Option<ScaledFloatIndex>> {
+        Ok(match self {
+            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
+                ScaledFloatIndex::new_mmap(
+                    &numeric_dir(dir, field),
+                    *is_on_disk,
+                    deleted_points,
+                    precision,
+                )?
+            }
+            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
+                ScaledFloatIndex::new_gridstore(
+                    numeric_dir(dir, field),
+                    create_if_missing,
+                    precision,
+                )?
+            }
+        })
+    }
+
+    fn scaled_float_builder(
+        &self,
+        field: &JsonPath,
+        precision: f64,
+        deleted_points: &BitSlice,
+    ) -> FieldIndexBuilder {
+        match self {
+            IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
+                FieldIndexBuilder::ScaledFloatMmapIndex(ScaledFloatIndex::builder_mmap(
+                    &numeric_dir(dir, field),
+                    *is_on_disk,
+                    deleted_points,
+                    precision,
+                ))
+            }
+            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
+                FieldIndexBuilder::ScaledFloatGridstoreIndex(ScaledFloatIndex::builder_gridstore(
+                    numeric_dir(dir, field),
+                    precision,
+                ))
+            }
+        }
+    }
+
     fn geo_new(
         &self,
         field: &JsonPath,
+        params: &GeoIndexParams,
         create_if_missing: bool,
         deleted_points: &BitSlice,
     ) -> OperationResult<Option<GeoMapIndex>> {
-        Ok(match self {
+        let index = match self {
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => {
                 GeoMapIndex::new_mmap(&map_dir(dir, field), *is_on_disk, deleted_points)?
             }
             IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
                 GeoMapIndex::new_gridstore(map_dir(dir, field), create_if_missing)?
             }
-        })
+        };
+        index
+            .map(|index| index.with_h3_cells(params.h3_resolution))
+            .transpose()
     }
 
     fn geo_builder(
         &self,
         field: &JsonPath,
+        params: &GeoIndexParams,
         make_mmap: fn(GeoMapIndexMmapBuilder) -> FieldIndexBuilder,
         make_gridstore: fn(GeoMapIndexGridstoreBuilder) -> FieldIndexBuilder,
         deleted_points: &BitSlice,
     ) -> FieldIndexBuilder {
         match self {
             IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk }) => make_mmap(
-                GeoMapIndex::builder_mmap(&map_dir(dir, field), *is_on_disk, deleted_points),
+                GeoMapIndex::builder_mmap(&map_dir(dir, field), *is_on_disk, deleted_points)
+                    .with_h3_resolution(params.h3_resolution),
+            ),
+            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => make_gridstore(
+                GeoMapIndex::builder_gridstore(map_dir(dir, field))
+                    .with_h3_resolution(params.h3_resolution),
             ),
-            IndexSelector::Gridstore(IndexSelectorGridstore { dir }) => {
-                make_gridstore(GeoMapIndex::builder_gridstore(map_dir(dir, field)))
-            }
         }
     }
 
@@ -0,0 +1,75 @@
+use ahash::AHashMap;
+use fnv::FnvBuildHasher;
+use indexmap::IndexSet;
+
+use super::MapIndexKey;
+
+/// Stored keys of a case-insensitive index, by their lowercase form.
+///
+/// Keys are stored as is, so that facets return the original values. The lookup is built once
+/// when the index is opened or built, and extended with new keys of a mutable index. Keys of
+/// removed points stay in the lookup, they just don't match any points.
+#[derive(Debug, Default)]
+pub struct CaseFoldedKeys {
+    keys: AHashMap<String, Vec<String>>,
+}
+
+impl CaseFoldedKeys {
+    pub fn insert<N: MapIndexKey + ?Sized>(&mut self, key: &N) {
+        let Some(folded) = key.case_folded() else {
+            return;
+        };
+        let key = key.to_string();
+        let keys = self.keys.entry(folded).or_default();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    /// Stored keys, which are equal to the `keyword` ignoring their case
+    pub fn get(&self, keyword: &str) -> &[String] {
+        self.keys
+            .get(&keyword.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Stored keys, which are equal to any of the `keywords` ignoring their case
+    pub fn get_any<'k>(
+        &self,
+        keywords: impl IntoIterator<Item = &'k String>,
+    ) -> IndexSet<&str, FnvBuildHasher> {
+        keywords
+            .into_iter()
+            .flat_map(|keyword| self.get(keyword))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_folded_keys() {
+        let mut keys = CaseFoldedKeys::default();
+        for key in ["Été", "été", "ÉTÉ", "été", "summer"] {
+            keys.insert(key);
+        }
+
+        assert_eq!(keys.get("été"), ["Été", "été", "ÉTÉ"]);
+        assert_eq!(keys.get("ÉtÉ"), ["Été", "été", "ÉTÉ"]);
+        assert_eq!(keys.get("Summer"), ["summer"]);
+        assert!(keys.get("winter").is_empty());
+
+        let any = keys.get_any(&["ÉTÉ".to_string(), "été".to_string(), "SUMMER".to_string()]);
+        assert_eq!(
+            any.into_iter().collect::<Vec<_>>(),
+            ["Été", "été", "ÉTÉ", "summer"]
+        );
+
+        // Keys without a case are not stored
+        keys.insert(&42_i64);
+        assert!(keys.get("42").is_empty());
+    }
+}
@@ -8,7 +8,9 @@ use common::bitvec::{BitSliceExt, BitVec};
 use common::persisted_hashmap::Key;
 use common::types::PointOffsetType;
 use gridstore::Blob;
+use itertools::Itertools;
 
+use super::case_folded_keys::CaseFoldedKeys;
 use super::mmap_map_index::MmapMapIndex;
 use super::{IdIter, MapIndexKey};
 use crate::common::Flusher;
@@ -26,6 +28,8 @@ pub struct ImmutableMapIndex<N: MapIndexKey + Key + ?Sized> {
     /// Amount of point which have at least one indexed payload value
     indexed_points: usize,
     values_count: usize,
+    /// Lookup of the keys ignoring their case, if the index is case-insensitive
+    pub(super) case_folded_keys: Option<CaseFoldedKeys>,
     // Backing storage, source of state, persists deletions
     storage: Storage<N>,
     /// Snapshot of approximate RAM usage at construction time.
@@ -144,6 +148,7 @@ where
             point_to_values,
             indexed_points,
             values_count,
+            case_folded_keys: None,
             storage: Storage::Mmap(Box::new(index)),
             cached_ram_usage_bytes: 0,
         };
@@ -407,6 +412,8 @@ where
             .zip(deleted_flags)
             .filter(|(_, is_deleted)| !is_deleted)
             .map(|(idx, _)| *idx)
+            // A value repeated within a point references the point repeatedly
+            .dedup()
     }
 
     pub fn for_each_value(
@@ -437,6 +444,7 @@ where
             point_to_values,
             indexed_points: _,
             values_count: _,
+            case_folded_keys: _,
             storage: _,
             cached_ram_usage_bytes: _,
         } = self;
@@ -11,6 +11,7 @@ use common::counter::iterator_hw_measurement::HwMeasurementIteratorExt;
 use common::fs::{atomic_save_json, clear_disk_cache, read_json};
 use common::mmap::create_and_ensure_length;
 use common::persisted_hashmap::{Key, MmapHashMap, READ_ENTRY_OVERHEAD, serialize_hashmap};
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::stored_bitslice::MmapBitSlice;
 use common::types::PointOffsetType;
 use common::universal_io::{MmapFile, OpenOptions};
@@ -18,6 +19,7 @@ use fs_err as fs;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
+use super::case_folded_keys::CaseFoldedKeys;
 use super::{IdIter, MapIndexKey};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -45,6 +47,8 @@ pub struct MmapMapIndex<N: MapIndexKey + Key + ?Sized> {
     deleted_count: usize,
     total_key_value_pairs: usize,
     is_on_disk: bool,
+    /// Lookup of the keys ignoring their case, if the index is case-insensitive
+    pub(super) case_folded_keys: Option<CaseFoldedKeys>,
 }
 
 pub(super) struct Storage<N: MapIndexKey + Key + ?Sized> {
@@ -122,6 +126,7 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
             deleted_count,
             total_key_value_pairs: config.total_key_value_pairs,
             is_on_disk,
+            case_folded_keys: None,
         }))
     }
 
@@ -375,7 +380,9 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
                                 .get_bit(**idx as usize)
                                 .unwrap_or(false)
                         })
-                        .copied(),
+                        .copied()
+                        // A value repeated within a point references the point repeatedly
+                        .dedup(),
                 )
             }
             Ok(None) => {
@@ -472,6 +479,16 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    /// The value lookup is populated first, it is used by every filter on the index.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        self.storage
+            .value_to_points
+            .populate_with_budget(PopulateStage::Lookup, budget)?;
+        self.storage.point_to_values.populate_with_budget(budget)?;
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         let Self {
@@ -480,6 +497,7 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
             deleted_count: _,
             total_key_value_pairs: _,
             is_on_disk: _,
+            case_folded_keys: _,
         } = self;
         let Storage {
             value_to_points,
@@ -10,6 +10,7 @@ use ahash::HashMap;
 use common::bitvec::{BitSlice, BitVec};
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::persisted_hashmap::Key;
+use common::populate_budget::PopulateBudget;
 use common::types::PointOffsetType;
 use ecow::EcoString;
 use gridstore::Blob;
@@ -19,6 +20,7 @@ use mmap_map_index::MmapMapIndex;
 use serde_json::Value;
 use uuid::Uuid;
 
+use self::case_folded_keys::CaseFoldedKeys;
 use self::immutable_map_index::ImmutableMapIndex;
 use self::mutable_map_index::MutableMapIndex;
 use super::FieldIndexBuilderTrait;
@@ -33,13 +35,14 @@ use crate::index::field_index::{
     CardinalityEstimation, PayloadBlockCondition, PayloadFieldIndex, PrimaryCondition, ValueIndexer,
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
-use crate::index::query_estimator::combine_should_estimations;
+use crate::index::query_estimator::{combine_must_estimations, combine_should_estimations};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    AnyVariants, FieldCondition, IntPayloadType, Match, MatchAny, MatchExcept, MatchValue,
-    PayloadKeyType, UuidIntType, ValueVariants,
+    AnyVariants, FieldCondition, IntPayloadType, Match, MatchAny, MatchExactSet, MatchExcept,
+    MatchKeywordPrefix, MatchValue, PayloadKeyType, UuidIntType, ValueVariants,
 };
 
+pub mod case_folded_keys;
 pub mod immutable_map_index;
 pub mod mmap_map_index;
 pub mod mutable_map_index;
@@ -50,6 +53,10 @@ pub mod mutable_map_index;
 /// - "some", "text", "here" - 16 bytes
 pub(super) const BLOCK_SIZE_KEYWORD: usize = 16;
 
+/// Size of the `match any` set, starting from which the values of the index may be checked
+/// against the set, instead of looking up each value of the set.
+const LARGE_MATCH_ANY_SIZE: usize = 256;
+
 pub type IdRefIter<'a> = Box<dyn Iterator<Item = &'a PointOffsetType> + 'a>;
 pub type IdIter<'a> = Box<dyn Iterator<Item = PointOffsetType> + 'a>;
 
@@ -67,6 +74,11 @@ pub trait MapIndexKey: Key + StoredValue + Eq + Display + Debug {
     fn owned_heap_bytes(_value: &<Self as MapIndexKey>::Owned) -> usize {
         0
     }
+
+    /// Form of the key, which case-insensitive indexes match. `None` for keys without a case.
+    fn case_folded(&self) -> Option<String> {
+        None
+    }
 }
 
 impl MapIndexKey for str {
@@ -80,6 +92,10 @@ impl MapIndexKey for str {
         BLOCK_SIZE_KEYWORD
     }
 
+    fn case_folded(&self) -> Option<String> {
+        Some(self.to_lowercase())
+    }
+
     fn owned_heap_bytes(value: &<Self as MapIndexKey>::Owned) -> usize {
         // EcoString inlines strings up to INLINE_LIMIT bytes.
         // Longer strings are heap-allocated.
@@ -149,14 +165,19 @@ where
         Ok(Some(index))
     }
 
-    pub fn new_gridstore(dir: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
-        let index = MutableMapIndex::open_gridstore(dir, create_if_missing)?;
+    pub fn new_gridstore(
+        dir: PathBuf,
+        create_if_missing: bool,
+        deduplicate_values: bool,
+    ) -> OperationResult<Option<Self>> {
+        let index = MutableMapIndex::open_gridstore(dir, create_if_missing, deduplicate_values)?;
         Ok(index.map(MapIndex::Mutable))
     }
 
     pub fn builder_mmap(
         path: &Path,
         is_on_disk: bool,
+        deduplicate_values: bool,
         deleted_points: &BitSlice,
     ) -> MapIndexMmapBuilder<N> {
         MapIndexMmapBuilder {
@@ -164,12 +185,52 @@ where
             point_to_values: Default::default(),
             values_to_points: Default::default(),
             is_on_disk,
+            deduplicate_values,
+            case_insensitive: false,
             deleted_points: deleted_points.to_owned(),
         }
     }
 
-    pub fn builder_gridstore(dir: PathBuf) -> MapIndexGridstoreBuilder<N> {
-        MapIndexGridstoreBuilder::new(dir)
+    pub fn builder_gridstore(
+        dir: PathBuf,
+        deduplicate_values: bool,
+    ) -> MapIndexGridstoreBuilder<N> {
+        MapIndexGridstoreBuilder::new(dir, deduplicate_values)
+    }
+
+    /// Match string keys ignoring their case.
+    ///
+    /// Keys are stored as is, only the matching of the keys is affected. The lookup of the keys
+    /// by their lowercase form is built here, once.
+    pub fn with_case_insensitive(mut self, case_insensitive: bool) -> OperationResult<Self> {
+        let case_folded_keys = if case_insensitive {
+            let mut case_folded_keys = CaseFoldedKeys::default();
+            self.for_each_value(|key| {
+                case_folded_keys.insert(key);
+                Ok(())
+            })?;
+            Some(case_folded_keys)
+        } else {
+            None
+        };
+        match &mut self {
+            MapIndex::Mutable(index) => index.case_folded_keys = case_folded_keys,
+            MapIndex::Immutable(index) => index.case_folded_keys = case_folded_keys,
+            MapIndex::Mmap(index) => index.case_folded_keys = case_folded_keys,
+        }
+        Ok(self)
+    }
+
+    fn case_folded_keys(&self) -> Option<&CaseFoldedKeys> {
+        match self {
+            MapIndex::Mutable(index) => index.case_folded_keys.as_ref(),
+            MapIndex::Immutable(index) => index.case_folded_keys.as_ref(),
+            MapIndex::Mmap(index) => index.case_folded_keys.as_ref(),
+        }
+    }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_folded_keys().is_some()
     }
 
     pub fn check_values_any(
@@ -307,6 +368,72 @@ where
         CardinalityEstimation::exact(values_count)
     }
 
+    /// Whether a large `match any` set of `size` has more values than the index.
+    ///
+    /// If so, the values of the index are iterated and checked against the set.
+    fn iterate_values_for_any(&self, size: usize) -> bool {
+        size >= LARGE_MATCH_ANY_SIZE && self.get_unique_values_count() < size
+    }
+
+    /// Points, which have any of the `keys`, found by checking the values of the index against
+    /// the set.
+    fn any_set_by_values<'a, Q, S>(
+        &'a self,
+        keys: &IndexSet<Q, S>,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> OperationResult<IdIter<'a>>
+    where
+        Q: Borrow<N> + Hash + Eq,
+        S: BuildHasher,
+    {
+        let mut points = Vec::new();
+        self.for_each_value(|value| {
+            if keys.contains(value) {
+                points.extend(self.get_iterator(value, hw_counter));
+            }
+            Ok(())
+        })?;
+        points.sort_unstable();
+        points.dedup();
+        Ok(Box::new(points.into_iter()))
+    }
+
+    /// Estimates cardinality of the points, which have any of the `keys`.
+    ///
+    /// The max is the sum of the matched posting lengths, clamped to the number of indexed points.
+    fn any_set_cardinality<Q, S>(
+        &self,
+        keys: &IndexSet<Q, S>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<CardinalityEstimation>
+    where
+        Q: Borrow<N> + Hash + Eq,
+        S: BuildHasher,
+    {
+        let mut estimations = Vec::new();
+        if self.iterate_values_for_any(keys.len()) {
+            self.for_each_value(|value| {
+                if keys.contains(value) {
+                    estimations.push(self.match_cardinality(value, hw_counter));
+                }
+                Ok(())
+            })?;
+        } else {
+            estimations.extend(
+                keys.iter()
+                    .map(|key| self.match_cardinality(key.borrow(), hw_counter)),
+            );
+        }
+
+        if estimations.is_empty() {
+            return Ok(CardinalityEstimation::exact(0));
+        }
+        Ok(combine_should_estimations(
+            &estimations,
+            self.get_indexed_points(),
+        ))
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         PayloadIndexTelemetry {
             field_name: None,
@@ -318,6 +445,14 @@ where
                 MapIndex::Immutable(_) => "immutable_map",
                 MapIndex::Mmap(_) => "mmap_map",
             },
+            write_amplification: match self {
+                MapIndex::Mutable(index) => index.write_stats().write_amplification(),
+                MapIndex::Immutable(_) | MapIndex::Mmap(_) => None,
+            },
+            oversized_values: None,
+            stopwords_language: None,
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
         }
     }
 
@@ -475,7 +610,7 @@ where
 
     fn except_set<'a, K, A>(
         &'a self,
-        excluded: &'a IndexSet<K, A>,
+        excluded: &IndexSet<K, A>,
         hw_counter: &'a HardwareCounterCell,
     ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + 'a>>
     where
@@ -494,6 +629,89 @@ where
         Ok(Box::new(points.into_iter()))
     }
 
+    /// Estimates cardinality for `exact_set` clause
+    ///
+    /// Matching points have all of the values, so the intersection of the posting lists is an
+    /// upper bound. It can't be a lower bound, as points of the intersection may have other values.
+    fn exact_set_cardinality<'a>(
+        &'a self,
+        values: impl Iterator<Item = &'a N>,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let estimations = values
+            .map(|value| self.match_cardinality(value, hw_counter))
+            .collect::<Vec<_>>();
+        if estimations.is_empty() {
+            return CardinalityEstimation::exact(0);
+        }
+
+        let intersection = combine_must_estimations(&estimations, self.get_indexed_points());
+        CardinalityEstimation {
+            primary_clauses: vec![],
+            min: 0,
+            exp: intersection.exp,
+            max: intersection.max,
+        }
+    }
+
+    /// Points, which have exactly the given set of values.
+    ///
+    /// Candidates are taken from the shortest posting list of the set,
+    /// each of them is then checked to have all of the values and nothing else.
+    fn exact_set<'a, K, A>(
+        &'a self,
+        values: &'a IndexSet<K, A>,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a>
+    where
+        A: BuildHasher,
+        K: Borrow<N> + Hash + Eq,
+    {
+        let shortest = values.iter().min_by_key(|value| {
+            self.get_count_for_value((*value).borrow(), hw_counter)
+                .unwrap_or(0)
+        });
+        let Some(shortest) = shortest else {
+            return Box::new(iter::empty());
+        };
+
+        Box::new(
+            self.get_iterator(shortest.borrow(), hw_counter)
+                .filter(move |&idx| self.check_exact_set(idx, values, hw_counter)),
+        )
+    }
+
+    /// Check that the point has exactly the given set of values, ignoring duplicates
+    pub fn check_exact_set<K, A>(
+        &self,
+        idx: PointOffsetType,
+        values: &IndexSet<K, A>,
+        hw_counter: &HardwareCounterCell,
+    ) -> bool
+    where
+        A: BuildHasher,
+        K: Borrow<N> + Hash + Eq,
+    {
+        // Cheap check first: each value of the set requires at least one value of the point
+        if values.is_empty() || self.values_count(idx) < values.len() {
+            return false;
+        }
+
+        let Some(point_values) = self.get_values(idx, hw_counter) else {
+            return false;
+        };
+
+        let mut matched = BitVec::repeat(false, values.len());
+        for value in point_values {
+            let Some(index) = values.get_index_of(&*value) else {
+                return false;
+            };
+            matched.set(index, true);
+        }
+
+        matched.all()
+    }
+
     /// Approximate RAM usage in bytes for in-memory structures.
     pub fn ram_usage_bytes(&self) -> usize {
         match self {
@@ -522,6 +740,16 @@ where
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        match self {
+            MapIndex::Mutable(_) => {}   // Not a mmap
+            MapIndex::Immutable(_) => {} // Not a mmap
+            MapIndex::Mmap(index) => index.populate_with_budget(budget)?,
+        }
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
@@ -591,9 +819,18 @@ pub struct MapIndexMmapBuilder<N: MapIndexKey + ?Sized> {
     point_to_values: Vec<Vec<<N as MapIndexKey>::Owned>>,
     values_to_points: HashMap<<N as MapIndexKey>::Owned, Vec<PointOffsetType>>,
     is_on_disk: bool,
+    deduplicate_values: bool,
+    case_insensitive: bool,
     deleted_points: BitVec,
 }
 
+impl<N: MapIndexKey + ?Sized> MapIndexMmapBuilder<N> {
+    pub fn with_case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+}
+
 impl<N: MapIndexKey + ?Sized> FieldIndexBuilderTrait for MapIndexMmapBuilder<N>
 where
     Vec<<N as MapIndexKey>::Owned>: Blob + Send + Sync,
@@ -617,8 +854,15 @@ where
             let payload_values = <MapIndex<N> as ValueIndexer>::get_values(value);
             flatten_values.extend(payload_values);
         }
-        let flatten_values: Vec<<N as MapIndexKey>::Owned> =
-            flatten_values.into_iter().map(Into::into).collect();
+        let flatten_values: Vec<<N as MapIndexKey>::Owned> = if self.deduplicate_values {
+            flatten_values
+                .into_iter()
+                .map(Into::into)
+                .unique()
+                .collect()
+        } else {
+            flatten_values.into_iter().map(Into::into).collect()
+        };
 
         if self.point_to_values.len() <= id as usize {
             self.point_to_values.resize_with(id as usize + 1, Vec::new);
@@ -646,13 +890,14 @@ where
     }
 
     fn finalize(self) -> OperationResult<Self::FieldIndexType> {
-        Ok(MapIndex::Mmap(Box::new(MmapMapIndex::build(
+        let index = MmapMapIndex::build(
             &self.path,
             self.point_to_values,
             self.values_to_points,
             self.is_on_disk,
             &self.deleted_points,
-        )?)))
+        )?;
+        MapIndex::Mmap(Box::new(index)).with_case_insensitive(self.case_insensitive)
     }
 }
 
@@ -661,6 +906,8 @@ where
     Vec<<N as MapIndexKey>::Owned>: Blob + Send + Sync,
 {
     dir: PathBuf,
+    deduplicate_values: bool,
+    case_insensitive: bool,
     index: Option<MapIndex<N>>,
 }
 
@@ -668,8 +915,18 @@ impl<N: MapIndexKey + ?Sized> MapIndexGridstoreBuilder<N>
 where
     Vec<<N as MapIndexKey>::Owned>: Blob + Send + Sync,
 {
-    fn new(dir: PathBuf) -> Self {
-        Self { dir, index: None }
+    fn new(dir: PathBuf, deduplicate_values: bool) -> Self {
+        Self {
+            dir,
+            deduplicate_values,
+            case_insensitive: false,
+            index: None,
+        }
+    }
+
+    pub fn with_case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
     }
 }
 
@@ -687,9 +944,9 @@ where
             "index must be initialized exactly once",
         );
         self.index.replace(
-            MapIndex::new_gridstore(self.dir.clone(), true)?.ok_or_else(|| {
-                OperationError::service_error("Failed to create mutable map index")
-            })?,
+            MapIndex::new_gridstore(self.dir.clone(), true, self.deduplicate_values)?.ok_or_else(
+                || OperationError::service_error("Failed to create mutable map index"),
+            )?,
         );
         Ok(())
     }
@@ -715,32 +972,103 @@ where
             ));
         };
         index.flusher()()?;
-        Ok(index)
+        index.with_case_insensitive(self.case_insensitive)
     }
 }
 
-impl PayloadFieldIndex for MapIndex<str> {
-    fn count_indexed_points(&self) -> usize {
-        self.get_indexed_points()
-    }
-
-    fn wipe(self) -> OperationResult<()> {
-        self.wipe()
-    }
-
-    fn flusher(&self) -> Flusher {
-        MapIndex::flusher(self)
+impl MapIndex<str> {
+    /// Points, which have any of the keys starting with the `prefix`.
+    ///
+    /// Keys are not ordered in the dictionary, so all of them are checked.
+    fn prefix_set<'a>(
+        &'a self,
+        prefix: &str,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        let mut points = IndexSet::new();
+        self.for_each_value(|key| {
+            if key.starts_with(prefix) {
+                self.get_iterator(key, hw_counter).for_each(|p| {
+                    points.insert(p);
+                });
+            }
+            Ok(())
+        })?;
+        Ok(Box::new(points.into_iter()))
     }
 
-    fn files(&self) -> Vec<PathBuf> {
-        self.files()
+    /// Points matching the `condition` with the keys, which are equal to the requested ones
+    /// ignoring their case.
+    ///
+    /// Returns `None` if the condition is not affected by the case of the keys.
+    fn case_insensitive_filter<'a>(
+        &'a self,
+        case_folded_keys: &'a CaseFoldedKeys,
+        condition: &'a FieldCondition,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> OperationResult<Option<IdIter<'a>>> {
+        let result: IdIter<'a> = match &condition.r#match {
+            Some(Match::Value(MatchValue {
+                value: ValueVariants::String(keyword),
+            })) => Box::new(
+                case_folded_keys
+                    .get(keyword)
+                    .iter()
+                    .flat_map(move |key| self.get_iterator(key.as_str(), hw_counter))
+                    .unique(),
+            ),
+            Some(Match::Any(MatchAny {
+                any: AnyVariants::Strings(keywords),
+            })) => Box::new(
+                keywords
+                    .iter()
+                    .flat_map(move |keyword| case_folded_keys.get(keyword))
+                    .flat_map(move |key| self.get_iterator(key.as_str(), hw_counter))
+                    .unique(),
+            ),
+            Some(Match::Except(MatchExcept {
+                except: AnyVariants::Strings(keywords),
+            })) => self.except_set(&case_folded_keys.get_any(keywords), hw_counter)?,
+            _ => return Ok(None),
+        };
+        Ok(Some(result))
     }
 
-    fn immutable_files(&self) -> Vec<PathBuf> {
-        self.immutable_files()
+    /// Estimates cardinality of the `condition` with the keys, which are equal to the requested
+    /// ones ignoring their case.
+    ///
+    /// Returns `None` if the condition is not affected by the case of the keys.
+    fn case_insensitive_cardinality(
+        &self,
+        case_folded_keys: &CaseFoldedKeys,
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<CardinalityEstimation>> {
+        // Points are selected with the original condition
+        let primary_clause = || PrimaryCondition::Condition(Box::new(condition.clone()));
+        let estimation = match &condition.r#match {
+            Some(Match::Value(MatchValue {
+                value: ValueVariants::String(keyword),
+            })) => self
+                .any_set_cardinality(&case_folded_keys.get_any([keyword]), hw_counter)?
+                .with_primary_clause(primary_clause()),
+            Some(Match::Any(MatchAny {
+                any: AnyVariants::Strings(keywords),
+            })) => self
+                .any_set_cardinality(&case_folded_keys.get_any(keywords), hw_counter)?
+                .with_primary_clause(primary_clause()),
+            Some(Match::Except(MatchExcept {
+                except: AnyVariants::Strings(keywords),
+            })) => {
+                self.except_cardinality(case_folded_keys.get_any(keywords).into_iter(), hw_counter)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(estimation))
     }
 
-    fn filter<'a>(
+    /// Points matching the `condition` with the keys as they are stored
+    fn filter_keys<'a>(
         &'a self,
         condition: &'a FieldCondition,
         hw_counter: &'a HardwareCounterCell,
@@ -756,6 +1084,9 @@ impl PayloadFieldIndex for MapIndex<str> {
                 ValueVariants::Bool(_) => None,
             },
             Some(Match::Any(MatchAny { any: any_variant })) => match any_variant {
+                AnyVariants::Strings(keywords) if self.iterate_values_for_any(keywords.len()) => {
+                    Some(self.any_set_by_values(keywords, hw_counter)?)
+                }
                 AnyVariants::Strings(keywords) => Some(Box::new(
                     keywords
                         .iter()
@@ -780,13 +1111,26 @@ impl PayloadFieldIndex for MapIndex<str> {
                     }
                 }
             },
+            Some(Match::ExactSet(MatchExactSet { exact_set })) => match exact_set {
+                AnyVariants::Strings(keywords) => Some(self.exact_set(keywords, hw_counter)),
+                AnyVariants::Integers(integers) => {
+                    if integers.is_empty() {
+                        Some(Box::new(iter::empty()))
+                    } else {
+                        None
+                    }
+                }
+            },
+            Some(Match::KeywordPrefix(MatchKeywordPrefix { prefix })) => {
+                Some(self.prefix_set(prefix, hw_counter)?)
+            }
             _ => None,
         };
 
         Ok(result)
     }
 
-    fn estimate_cardinality(
+    fn estimate_keys_cardinality(
         &self,
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
@@ -805,15 +1149,7 @@ impl PayloadFieldIndex for MapIndex<str> {
             },
             Some(Match::Any(MatchAny { any: any_variant })) => match any_variant {
                 AnyVariants::Strings(keywords) => {
-                    let estimations = keywords
-                        .iter()
-                        .map(|keyword| self.match_cardinality(keyword.as_str(), hw_counter))
-                        .collect::<Vec<_>>();
-                    let estimation = if estimations.is_empty() {
-                        CardinalityEstimation::exact(0)
-                    } else {
-                        combine_should_estimations(&estimations, self.get_indexed_points())
-                    };
+                    let estimation = self.any_set_cardinality(keywords, hw_counter)?;
                     Some(
                         estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
                             condition.clone(),
@@ -844,10 +1180,122 @@ impl PayloadFieldIndex for MapIndex<str> {
                     }
                 }
             },
+            Some(Match::ExactSet(MatchExactSet { exact_set })) => match exact_set {
+                AnyVariants::Strings(keywords) => {
+                    let estimation =
+                        self.exact_set_cardinality(keywords.iter().map(|k| k.as_str()), hw_counter);
+                    Some(
+                        estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
+                            condition.clone(),
+                        ))),
+                    )
+                }
+                AnyVariants::Integers(integers) => {
+                    if integers.is_empty() {
+                        Some(CardinalityEstimation::exact(0).with_primary_clause(
+                            PrimaryCondition::Condition(Box::new(condition.clone())),
+                        ))
+                    } else {
+                        None
+                    }
+                }
+            },
+            Some(Match::KeywordPrefix(MatchKeywordPrefix { prefix })) => {
+                let estimation = self.prefix_cardinality(prefix)?;
+                Some(
+                    estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
+                        condition.clone(),
+                    ))),
+                )
+            }
             _ => None,
         })
     }
 
+    /// Estimates cardinality for `keyword_prefix` clause
+    ///
+    /// Posting lists of the matched keys are assumed to be of the average length.
+    fn prefix_cardinality(&self, prefix: &str) -> OperationResult<CardinalityEstimation> {
+        let mut matched_keys = 0;
+        self.for_each_value(|key| {
+            if key.starts_with(prefix) {
+                matched_keys += 1;
+            }
+            Ok(())
+        })?;
+        if matched_keys == 0 {
+            return Ok(CardinalityEstimation::exact(0));
+        }
+
+        let indexed_points = self.get_indexed_points();
+        let values_count = self.get_values_count();
+        let unique_values_count = self.get_unique_values_count().max(matched_keys);
+
+        // Each of the not matched keys has at least one value
+        let max_values = values_count.saturating_sub(unique_values_count - matched_keys);
+        let exp_values = matched_keys * values_count / unique_values_count;
+
+        let max = indexed_points.min(max_values);
+        let exp = number_of_selected_points(indexed_points, exp_values).min(max);
+
+        Ok(CardinalityEstimation {
+            primary_clauses: vec![],
+            min: 0,
+            exp,
+            max,
+        })
+    }
+}
+
+impl PayloadFieldIndex for MapIndex<str> {
+    fn count_indexed_points(&self) -> usize {
+        self.get_indexed_points()
+    }
+
+    fn wipe(self) -> OperationResult<()> {
+        self.wipe()
+    }
+
+    fn flusher(&self) -> Flusher {
+        MapIndex::flusher(self)
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        self.files()
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.immutable_files()
+    }
+
+    fn filter<'a>(
+        &'a self,
+        condition: &'a FieldCondition,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> OperationResult<Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>>> {
+        if let Some(case_folded_keys) = self.case_folded_keys()
+            && let Some(points) =
+                self.case_insensitive_filter(case_folded_keys, condition, hw_counter)?
+        {
+            return Ok(Some(points));
+        }
+        self.filter_keys(condition, hw_counter)
+    }
+
+    fn estimate_cardinality(
+        &self,
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<CardinalityEstimation>> {
+        if let Some(case_folded_keys) = self.case_folded_keys()
+            && let Some(estimation) =
+                self.case_insensitive_cardinality(case_folded_keys, condition, hw_counter)?
+        {
+            return Ok(Some(estimation));
+        }
+        self.estimate_keys_cardinality(condition, hw_counter)
+    }
+
     fn for_each_payload_block(
         &self,
         threshold: usize,
@@ -917,12 +1365,16 @@ impl PayloadFieldIndex for MapIndex<UuidIntType> {
                             return Ok(None);
                         };
 
-                        Some(Box::new(
-                            uuids
-                                .into_iter()
-                                .flat_map(move |uuid| self.get_iterator(&uuid, hw_counter))
-                                .unique(),
-                        ))
+                        if self.iterate_values_for_any(uuids.len()) {
+                            Some(self.any_set_by_values(&uuids, hw_counter)?)
+                        } else {
+                            Some(Box::new(
+                                uuids
+                                    .into_iter()
+                                    .flat_map(move |uuid| self.get_iterator(&uuid, hw_counter))
+                                    .unique(),
+                            ))
+                        }
                     }
                     AnyVariants::Integers(integers) => {
                         if integers.is_empty() {
@@ -960,6 +1412,26 @@ impl PayloadFieldIndex for MapIndex<UuidIntType> {
                         }
                     }
                 },
+                Some(Match::ExactSet(MatchExactSet { exact_set })) => match exact_set {
+                    AnyVariants::Strings(uuids_string) => {
+                        let Ok(uuids) = uuids_string
+                            .iter()
+                            .map(|uuid_string| Uuid::from_str(uuid_string).map(|x| x.as_u128()))
+                            .collect::<Result<IndexSet<u128>, _>>()
+                        else {
+                            return Ok(None);
+                        };
+                        let points = self.exact_set(&uuids, hw_counter).collect::<Vec<_>>();
+                        Some(Box::new(points.into_iter()))
+                    }
+                    AnyVariants::Integers(integers) => {
+                        if integers.is_empty() {
+                            Some(Box::new(iter::empty()))
+                        } else {
+                            None
+                        }
+                    }
+                },
                 _ => None,
             };
 
@@ -997,15 +1469,7 @@ impl PayloadFieldIndex for MapIndex<UuidIntType> {
                         return Ok(None);
                     };
 
-                    let estimations = uuids
-                        .into_iter()
-                        .map(|uuid| self.match_cardinality(&uuid, hw_counter))
-                        .collect::<Vec<_>>();
-                    let estimation = if estimations.is_empty() {
-                        CardinalityEstimation::exact(0)
-                    } else {
-                        combine_should_estimations(&estimations, self.get_indexed_points())
-                    };
+                    let estimation = self.any_set_cardinality(&uuids, hw_counter)?;
                     Some(
                         estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
                             condition.clone(),
@@ -1045,7 +1509,35 @@ impl PayloadFieldIndex for MapIndex<UuidIntType> {
                     }
                 }
             },
-            _ => None,
+            Some(Match::ExactSet(MatchExactSet { exact_set })) => match exact_set {
+                AnyVariants::Strings(uuids_string) => {
+                    let uuids: Result<IndexSet<u128>, _> = uuids_string
+                        .iter()
+                        .map(|uuid_string| Uuid::from_str(uuid_string).map(|x| x.as_u128()))
+                        .collect();
+
+                    let Some(uuids) = uuids.ok() else {
+                        return Ok(None);
+                    };
+
+                    let estimation = self.exact_set_cardinality(uuids.iter(), hw_counter);
+                    Some(
+                        estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
+                            condition.clone(),
+                        ))),
+                    )
+                }
+                AnyVariants::Integers(integers) => {
+                    if integers.is_empty() {
+                        Some(CardinalityEstimation::exact(0).with_primary_clause(
+                            PrimaryCondition::Condition(Box::new(condition.clone())),
+                        ))
+                    } else {
+                        None
+                    }
+                }
+            },
+            _ => None,
         })
     }
 
@@ -1099,42 +1591,56 @@ impl PayloadFieldIndex for MapIndex<IntPayloadType> {
         condition: &'a FieldCondition,
         hw_counter: &'a HardwareCounterCell,
     ) -> OperationResult<Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>>> {
-        let result: Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> =
-            match &condition.r#match {
-                Some(Match::Value(MatchValue { value })) => match value {
-                    ValueVariants::String(_) => None,
-                    ValueVariants::Integer(integer) => {
-                        Some(Box::new(self.get_iterator(integer, hw_counter)))
+        let result: Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> = match &condition
+            .r#match
+        {
+            Some(Match::Value(MatchValue { value })) => match value {
+                ValueVariants::String(_) => None,
+                ValueVariants::Integer(integer) => {
+                    Some(Box::new(self.get_iterator(integer, hw_counter)))
+                }
+                ValueVariants::Bool(_) => None,
+            },
+            Some(Match::Any(MatchAny { any: any_variant })) => match any_variant {
+                AnyVariants::Strings(keywords) => {
+                    if keywords.is_empty() {
+                        Some(Box::new(std::iter::empty()))
+                    } else {
+                        None
                     }
-                    ValueVariants::Bool(_) => None,
-                },
-                Some(Match::Any(MatchAny { any: any_variant })) => match any_variant {
-                    AnyVariants::Strings(keywords) => {
-                        if keywords.is_empty() {
-                            Some(Box::new(std::iter::empty()))
-                        } else {
-                            None
-                        }
+                }
+                AnyVariants::Integers(integers) if self.iterate_values_for_any(integers.len()) => {
+                    Some(self.any_set_by_values(integers, hw_counter)?)
+                }
+                AnyVariants::Integers(integers) => Some(Box::new(
+                    integers
+                        .iter()
+                        .flat_map(move |integer| self.get_iterator(integer, hw_counter))
+                        .unique(),
+                )),
+            },
+            Some(Match::Except(MatchExcept { except })) => match except {
+                AnyVariants::Strings(other) => {
+                    if other.is_empty() {
+                        Some(Box::new(iter::empty()))
+                    } else {
+                        None
                     }
-                    AnyVariants::Integers(integers) => Some(Box::new(
-                        integers
-                            .iter()
-                            .flat_map(move |integer| self.get_iterator(integer, hw_counter))
-                            .unique(),
-                    )),
-                },
-                Some(Match::Except(MatchExcept { except })) => match except {
-                    AnyVariants::Strings(other) => {
-                        if other.is_empty() {
-                            Some(Box::new(iter::empty()))
-                        } else {
-                            None
-                        }
+                }
+                AnyVariants::Integers(integers) => Some(self.except_set(integers, hw_counter)?),
+            },
+            Some(Match::ExactSet(MatchExactSet { exact_set })) => match exact_set {
+                AnyVariants::Strings(keywords) => {
+                    if keywords.is_empty() {
+                        Some(Box::new(iter::empty()))
+                    } else {
+                        None
                     }
-                    AnyVariants::Integers(integers) => Some(self.except_set(integers, hw_counter)?),
-                },
-                _ => None,
-            };
+                }
+                AnyVariants::Integers(integers) => Some(self.exact_set(integers, hw_counter)),
+            },
+            _ => None,
+        };
 
         Ok(result)
     }
@@ -1167,15 +1673,7 @@ impl PayloadFieldIndex for MapIndex<IntPayloadType> {
                     }
                 }
                 AnyVariants::Integers(integers) => {
-                    let estimations = integers
-                        .iter()
-                        .map(|integer| self.match_cardinality(integer, hw_counter))
-                        .collect::<Vec<_>>();
-                    let estimation = if estimations.is_empty() {
-                        CardinalityEstimation::exact(0)
-                    } else {
-                        combine_should_estimations(&estimations, self.get_indexed_points())
-                    };
+                    let estimation = self.any_set_cardinality(integers, hw_counter)?;
                     Some(
                         estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
                             condition.clone(),
@@ -1197,6 +1695,25 @@ impl PayloadFieldIndex for MapIndex<IntPayloadType> {
                     Some(self.except_cardinality(integers.iter(), hw_counter))
                 }
             },
+            Some(Match::ExactSet(MatchExactSet { exact_set })) => match exact_set {
+                AnyVariants::Strings(keywords) => {
+                    if keywords.is_empty() {
+                        Some(CardinalityEstimation::exact(0).with_primary_clause(
+                            PrimaryCondition::Condition(Box::new(condition.clone())),
+                        ))
+                    } else {
+                        None
+                    }
+                }
+                AnyVariants::Integers(integers) => {
+                    let estimation = self.exact_set_cardinality(integers.iter(), hw_counter);
+                    Some(
+                        estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(
+                            condition.clone(),
+                        ))),
+                    )
+                }
+            },
             _ => None,
         })
     }
@@ -1376,10 +1893,17 @@ mod tests {
     use std::hint::black_box;
     use std::path::Path;
 
+    use common::types::{DetailsLevel, TelemetryDetail};
+    use fnv::FnvBuildHasher;
     use rstest::rstest;
+    use serde_json::json;
     use tempfile::Builder;
 
     use super::*;
+    use crate::data_types::facets::FacetValue;
+    use crate::index::field_index::FieldIndex;
+    use crate::index::field_index::facet_index::FacetIndexEnum;
+    use crate::json_path::JsonPath;
 
     /// Generous default size for the deleted-points bitslice used in tests.
     ///
@@ -1424,7 +1948,7 @@ mod tests {
 
         match index_type {
             IndexType::MutableGridstore => {
-                let mut builder = MapIndex::<N>::builder_gridstore(path.to_path_buf());
+                let mut builder = MapIndex::<N>::builder_gridstore(path.to_path_buf(), false);
                 builder.init().unwrap();
                 for (idx, values) in data.iter().enumerate() {
                     let values: Vec<Value> = values.iter().map(&into_value).collect();
@@ -1436,7 +1960,7 @@ mod tests {
                 builder.finalize().unwrap();
             }
             IndexType::Mmap | IndexType::RamMmap => {
-                let mut builder = MapIndex::<N>::builder_mmap(path, false, &empty_deleted());
+                let mut builder = MapIndex::<N>::builder_mmap(path, false, false, &empty_deleted());
                 builder.init().unwrap();
                 for (idx, values) in data.iter().enumerate() {
                     let values: Vec<Value> = values.iter().map(&into_value).collect();
@@ -1459,9 +1983,11 @@ mod tests {
         Vec<<N as MapIndexKey>::Owned>: Blob + Send + Sync,
     {
         let index = match index_type {
-            IndexType::MutableGridstore => MapIndex::<N>::new_gridstore(path.to_path_buf(), true)
-                .unwrap()
-                .unwrap(),
+            IndexType::MutableGridstore => {
+                MapIndex::<N>::new_gridstore(path.to_path_buf(), true, false)
+                    .unwrap()
+                    .unwrap()
+            }
             IndexType::Mmap => MapIndex::<N>::new_mmap(path, true, &empty_deleted())
                 .unwrap()
                 .unwrap(),
@@ -1488,7 +2014,7 @@ mod tests {
     fn test_uuid_payload_index() {
         let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
         let mut builder =
-            MapIndex::<UuidIntType>::builder_mmap(temp_dir.path(), false, &empty_deleted());
+            MapIndex::<UuidIntType>::builder_mmap(temp_dir.path(), false, false, &empty_deleted());
 
         builder.init().unwrap();
 
@@ -1516,8 +2042,12 @@ mod tests {
     #[test]
     fn test_index_non_ascending_insertion() {
         let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
-        let mut builder =
-            MapIndex::<IntPayloadType>::builder_mmap(temp_dir.path(), false, &empty_deleted());
+        let mut builder = MapIndex::<IntPayloadType>::builder_mmap(
+            temp_dir.path(),
+            false,
+            false,
+            &empty_deleted(),
+        );
         builder.init().unwrap();
 
         let data = [vec![1, 2, 3, 4, 5, 6], vec![25], vec![10, 11]];
@@ -1720,11 +2250,13 @@ mod tests {
         // gridstore the argument is ignored.
         let deleted = deleted_with(&[1, 2, 5]);
         let new_index = match index_type {
-            IndexType::MutableGridstore => {
-                MapIndex::<IntPayloadType>::new_gridstore(temp_dir.path().to_path_buf(), true)
-                    .unwrap()
-                    .unwrap()
-            }
+            IndexType::MutableGridstore => MapIndex::<IntPayloadType>::new_gridstore(
+                temp_dir.path().to_path_buf(),
+                true,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
             IndexType::Mmap => {
                 MapIndex::<IntPayloadType>::new_mmap(temp_dir.path(), true, &deleted)
                     .unwrap()
@@ -1841,4 +2373,421 @@ mod tests {
         hits.sort();
         assert_eq!(hits, vec![3]);
     }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore, false)]
+    #[case(IndexType::MutableGridstore, true)]
+    #[case(IndexType::Mmap, false)]
+    #[case(IndexType::Mmap, true)]
+    #[case(IndexType::RamMmap, false)]
+    #[case(IndexType::RamMmap, true)]
+    fn test_duplicate_values_in_point(
+        #[case] index_type: IndexType,
+        #[case] deduplicate_values: bool,
+    ) {
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let duplicated = json!(["a", "a", "b"]);
+        let single = json!("a");
+
+        match index_type {
+            IndexType::MutableGridstore => {
+                let mut builder = MapIndex::<str>::builder_gridstore(
+                    temp_dir.path().to_path_buf(),
+                    deduplicate_values,
+                );
+                builder.init().unwrap();
+                builder.add_point(0, &[&duplicated], &hw_counter).unwrap();
+                builder.add_point(1, &[&single], &hw_counter).unwrap();
+                builder.finalize().unwrap();
+            }
+            IndexType::Mmap | IndexType::RamMmap => {
+                let mut builder = MapIndex::<str>::builder_mmap(
+                    temp_dir.path(),
+                    false,
+                    deduplicate_values,
+                    &empty_deleted(),
+                );
+                builder.init().unwrap();
+                builder.add_point(0, &[&duplicated], &hw_counter).unwrap();
+                builder.add_point(1, &[&single], &hw_counter).unwrap();
+                builder.finalize().unwrap();
+            }
+        }
+
+        // Reload to make sure the policy outcome survives persistence
+        let index = match index_type {
+            IndexType::MutableGridstore => MapIndex::<str>::new_gridstore(
+                temp_dir.path().to_path_buf(),
+                false,
+                deduplicate_values,
+            )
+            .unwrap()
+            .unwrap(),
+            IndexType::Mmap => MapIndex::<str>::new_mmap(temp_dir.path(), true, &empty_deleted())
+                .unwrap()
+                .unwrap(),
+            IndexType::RamMmap => {
+                MapIndex::<str>::new_mmap(temp_dir.path(), false, &empty_deleted())
+                    .unwrap()
+                    .unwrap()
+            }
+        };
+
+        let expected_count = if deduplicate_values { 2 } else { 3 };
+        assert_eq!(index.values_count(0), expected_count);
+        assert_eq!(index.values_count(1), 1);
+        assert_eq!(index.get_values_count(), expected_count + 1);
+
+        if deduplicate_values {
+            assert_eq!(index.get_count_for_value("a", &hw_counter), Some(2));
+        }
+
+        // Filter matches each point once, regardless of the policy
+        let condition = FieldCondition::new_match(JsonPath::new("key"), "a".to_string().into());
+        let matched: Vec<_> = index
+            .filter(&condition, &hw_counter)
+            .unwrap()
+            .unwrap()
+            .collect();
+        assert_eq!(matched, vec![0, 1]);
+    }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_exact_set_filter(#[case] index_type: IndexType) {
+        let data: Vec<Vec<String>> = vec![
+            vec!["a".into(), "b".into()],             // exact
+            vec!["b".into(), "a".into(), "a".into()], // exact, with duplicates
+            vec!["a".into()],                         // subset
+            vec!["a".into(), "b".into(), "c".into()], // superset
+            vec!["b".into(), "c".into()],             // overlap
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let exact_set = |values: &[&str]| {
+            FieldCondition::new_match(
+                JsonPath::new("key"),
+                Match::new_exact_set(AnyVariants::Strings(
+                    values.iter().map(|v| v.to_string()).collect(),
+                )),
+            )
+        };
+
+        let filter = |condition: &FieldCondition| -> Vec<PointOffsetType> {
+            let mut points: Vec<_> = index
+                .filter(condition, &hw_counter)
+                .unwrap()
+                .unwrap()
+                .collect();
+            points.sort_unstable();
+            points
+        };
+
+        assert_eq!(filter(&exact_set(&["a", "b"])), vec![0, 1]);
+        assert_eq!(filter(&exact_set(&["a"])), vec![2]);
+        assert_eq!(filter(&exact_set(&["a", "b", "c"])), vec![3]);
+        assert_eq!(
+            filter(&exact_set(&["a", "c"])),
+            Vec::<PointOffsetType>::new()
+        );
+        assert_eq!(filter(&exact_set(&["d"])), Vec::<PointOffsetType>::new());
+        assert_eq!(filter(&exact_set(&[])), Vec::<PointOffsetType>::new());
+
+        // Intersection of the posting lists is an upper bound
+        let estimation = index
+            .estimate_cardinality(&exact_set(&["a", "b"]), &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimation.min, 0);
+        assert!(estimation.max >= 2);
+        assert!(estimation.exp <= estimation.max);
+
+        let estimation = index
+            .estimate_cardinality(&exact_set(&["d"]), &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimation.max, 0);
+    }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_keyword_prefix_filter(#[case] index_type: IndexType) {
+        let data: Vec<Vec<String>> = vec![
+            vec!["SKU-100".into(), "SKU-200".into()],
+            vec!["SKU-101".into()],
+            vec!["ABC-100".into()],
+            vec!["sku-100".into()],
+            vec!["SKU-100".into(), "ABC-1".into()],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let starts_with = |prefix: &str| {
+            FieldCondition::new_match(JsonPath::new("key"), Match::new_keyword_prefix(prefix))
+        };
+
+        let filter = |condition: &FieldCondition| -> Vec<PointOffsetType> {
+            let mut points: Vec<_> = index
+                .filter(condition, &hw_counter)
+                .unwrap()
+                .unwrap()
+                .collect();
+            points.sort_unstable();
+            points
+        };
+
+        assert_eq!(filter(&starts_with("SKU-1")), vec![0, 1, 4]);
+        assert_eq!(filter(&starts_with("SKU-")), vec![0, 1, 4]);
+        assert_eq!(filter(&starts_with("ABC")), vec![2, 4]);
+        assert_eq!(filter(&starts_with("sku")), vec![3]);
+        assert_eq!(filter(&starts_with("")), vec![0, 1, 2, 3, 4]);
+        assert_eq!(filter(&starts_with("XYZ")), Vec::<PointOffsetType>::new());
+
+        let estimation = index
+            .estimate_cardinality(&starts_with("SKU-1"), &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimation.primary_clauses.len(), 1);
+        assert!(estimation.max >= 3);
+        assert!(estimation.min <= estimation.exp && estimation.exp <= estimation.max);
+
+        let estimation = index
+            .estimate_cardinality(&starts_with("XYZ"), &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimation.max, 0);
+    }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_large_match_any(#[case] index_type: IndexType) {
+        let data: Vec<Vec<IntPayloadType>> = (0..50).map(|i| vec![i % 10, i % 7 + 100]).collect();
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<IntPayloadType>(&data, temp_dir.path(), index_type, |v| (*v).into());
+        let index = load_map_index::<IntPayloadType>(&data, temp_dir.path(), index_type);
+
+        let hw_counter = HardwareCounterCell::new();
+
+        // Set is larger than the index, so the values of the index are checked against it
+        let integers: IndexSet<IntPayloadType, FnvBuildHasher> =
+            (0..LARGE_MATCH_ANY_SIZE as IntPayloadType * 2)
+                .step_by(2)
+                .collect();
+        assert!(index.iterate_values_for_any(integers.len()));
+        let condition = FieldCondition::new_match(
+            JsonPath::new("key"),
+            Match::new_any(AnyVariants::Integers(integers)),
+        );
+
+        let mut points: Vec<_> = index
+            .filter(&condition, &hw_counter)
+            .unwrap()
+            .unwrap()
+            .collect();
+        points.sort_unstable();
+        let expected: Vec<_> = (0..data.len() as PointOffsetType)
+            .filter(|&i| i % 10 % 2 == 0 || (i % 7 + 100) % 2 == 0)
+            .collect();
+        assert_eq!(points, expected);
+
+        let estimation = index
+            .estimate_cardinality(&condition, &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimation.primary_clauses.len(), 1);
+        assert!(estimation.min <= expected.len() && expected.len() <= estimation.max);
+        assert_eq!(estimation.max, data.len());
+    }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_memory_usage_bytes(#[case] index_type: IndexType) {
+        let data: Vec<Vec<String>> = (0..100)
+            .map(|i| vec![format!("value-{}", i % 10)])
+            .collect();
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index =
+            FieldIndex::KeywordIndex(load_map_index::<str>(&data, temp_dir.path(), index_type));
+
+        let ram_usage_bytes = index.ram_usage_bytes();
+        let memory_usage_bytes = index.memory_usage_bytes();
+        assert!(memory_usage_bytes >= ram_usage_bytes);
+        if index_type == IndexType::RamMmap {
+            // Indexes loaded into RAM keep their files populated, which are counted too
+            assert!(memory_usage_bytes > ram_usage_bytes);
+        }
+
+        // Index files are only probed for segment level details
+        let telemetry = index.get_telemetry_data(TelemetryDetail::default());
+        assert_eq!(telemetry.memory_usage_bytes, None);
+
+        let telemetry = index.get_telemetry_data(TelemetryDetail::new(DetailsLevel::Level4, false));
+        assert!(
+            telemetry
+                .memory_usage_bytes
+                .is_some_and(|bytes| bytes >= ram_usage_bytes)
+        );
+    }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_facet_counts(#[case] index_type: IndexType) {
+        let data: Vec<Vec<String>> = vec![
+            vec!["a".into(), "b".into()],
+            vec!["a".into()],
+            vec!["b".into(), "c".into()],
+            vec!["a".into(), "c".into()],
+            vec!["c".into()],
+            vec!["d".into()],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+        let facet_index = FacetIndexEnum::Keyword(&index);
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut filter_ids = BitVec::repeat(false, data.len());
+        for point_id in [0, 2, 3, 4] {
+            filter_ids.set(point_id, true);
+        }
+
+        let facet_counts = |top_k: usize| -> Vec<(String, usize)> {
+            facet_index
+                .facet_counts(&filter_ids, top_k, &hw_counter)
+                .unwrap()
+                .into_iter()
+                .map(|hit| match hit.value {
+                    FacetValue::Keyword(value) => (value, hit.count),
+                    other => panic!("unexpected facet value {other:?}"),
+                })
+                .collect()
+        };
+
+        // Same counts are ordered by value
+        let hit = |value: &str, count: usize| (value.to_string(), count);
+        assert_eq!(facet_counts(2), [hit("c", 3), hit("a", 2)]);
+        assert_eq!(facet_counts(10), [hit("c", 3), hit("a", 2), hit("b", 2)]);
+        assert!(facet_counts(0).is_empty());
+    }
+
+    #[rstest]
+    #[case(IndexType::MutableGridstore)]
+    #[case(IndexType::Mmap)]
+    #[case(IndexType::RamMmap)]
+    fn test_case_insensitive_filter(#[case] index_type: IndexType) {
+        let data: Vec<Vec<String>> = vec![
+            vec!["Été".into()],
+            vec!["été".into(), "Active".into()],
+            vec!["active".into()],
+            vec!["ÉTÉ".into()],
+            vec!["hiver".into()],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let filter = |index: &MapIndex<str>, condition: &FieldCondition| {
+            let mut points: Vec<_> = index
+                .filter(condition, &hw_counter)
+                .unwrap()
+                .unwrap()
+                .collect();
+            points.sort_unstable();
+            points
+        };
+        let key = JsonPath::new("key");
+        let value =
+            |keyword: &str| FieldCondition::new_match(key.clone(), keyword.to_string().into());
+        let any = |keywords: &[&str]| {
+            let keywords = keywords.iter().map(|k| k.to_string()).collect();
+            FieldCondition::new_match(key.clone(), Match::new_any(AnyVariants::Strings(keywords)))
+        };
+        let except = |keywords: &[&str]| {
+            let keywords = keywords.iter().map(|k| k.to_string()).collect();
+            FieldCondition::new_match(
+                key.clone(),
+                Match::new_except(AnyVariants::Strings(keywords)),
+            )
+        };
+
+        // Keys are matched as is by default
+        assert_eq!(filter(&index, &value("été")), vec![1]);
+
+        let mut index = index.with_case_insensitive(true).unwrap();
+        assert_eq!(filter(&index, &value("été")), vec![0, 1, 3]);
+        assert_eq!(filter(&index, &value("ÉtÉ")), vec![0, 1, 3]);
+        assert_eq!(filter(&index, &value("ACTIVE")), vec![1, 2]);
+        assert_eq!(
+            filter(&index, &value("automne")),
+            Vec::<PointOffsetType>::new()
+        );
+        assert_eq!(filter(&index, &any(&["Hiver", "active"])), vec![1, 2, 4]);
+        assert_eq!(filter(&index, &except(&["été", "ACTIVE"])), vec![4]);
+        assert_eq!(
+            index
+                .estimate_cardinality(&except(&["été", "ACTIVE"]), &hw_counter)
+                .unwrap()
+                .unwrap()
+                .max,
+            1,
+        );
+
+        let estimation = index
+            .estimate_cardinality(&value("été"), &hw_counter)
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimation.primary_clauses.len(), 1);
+        assert!(estimation.min <= 3 && 3 <= estimation.max);
+        assert!(matches!(
+            &estimation.primary_clauses[0],
+            PrimaryCondition::Condition(condition) if **condition == value("été"),
+        ));
+
+        // Original values are kept for facets
+        let mut keys = Vec::new();
+        index
+            .for_each_value(|key| {
+                keys.push(key.to_string());
+                Ok(())
+            })
+            .unwrap();
+        keys.sort_unstable();
+        assert_eq!(keys, ["Active", "active", "hiver", "ÉTÉ", "Été", "été"]);
+        // Keys added to a mutable index are matched too
+        if let MapIndex::Mutable(_) = index {
+            index
+                .add_many(5, vec!["AUTOMNE".to_string()], &hw_counter)
+                .unwrap();
+            assert_eq!(filter(&index, &value("automne")), vec![5]);
+        }
+    }
 }
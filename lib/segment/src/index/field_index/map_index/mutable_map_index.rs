@@ -1,15 +1,19 @@
 use std::borrow::{Borrow, Cow};
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::iter;
 use std::path::PathBuf;
 
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::gridstore_index::gridstore_index_config;
 use common::types::PointOffsetType;
-use gridstore::config::StorageOptions;
+use gridstore::config::{DEFAULT_REGION_SIZE_BLOCKS, StorageOptions};
 use gridstore::error::GridstoreError;
-use gridstore::{Blob, Gridstore};
+use gridstore::{Blob, Gridstore, WriteStats};
+use itertools::Itertools;
 use roaring::RoaringBitmap;
 
+use super::case_folded_keys::CaseFoldedKeys;
 use super::{IdIter, MapIndexKey};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -26,6 +30,17 @@ const fn default_gridstore_options(block_size: usize) -> StorageOptions {
     }
 }
 
+/// Options for new Gridstore storage, with page size tuned by the global config
+fn gridstore_options(block_size: usize) -> StorageOptions {
+    let mut options = default_gridstore_options(block_size);
+    if let Some(page_size_bytes) = gridstore_index_config().page_size_bytes {
+        // Page size must be a multiple of the region size
+        let region_size_bytes = block_size * DEFAULT_REGION_SIZE_BLOCKS;
+        options.page_size_bytes = Some(page_size_bytes.max(1).next_multiple_of(region_size_bytes));
+    }
+    options
+}
+
 pub struct MutableMapIndex<N: MapIndexKey + ?Sized>
 where
     Vec<<N as MapIndexKey>::Owned>: Blob + Send + Sync,
@@ -35,6 +50,10 @@ where
     /// Amount of point which have at least one indexed payload value
     pub(super) indexed_points: usize,
     pub(super) values_count: usize,
+    /// Store repeated occurrences of the same value within a point only once
+    deduplicate_values: bool,
+    /// Lookup of the keys ignoring their case, if the index is case-insensitive
+    pub(super) case_folded_keys: Option<CaseFoldedKeys>,
     storage: Storage<<N as MapIndexKey>::Owned>,
 }
 
@@ -54,9 +73,16 @@ where
     /// The `create_if_missing` parameter indicates whether to create a new Gridstore if it does
     /// not exist. If false and files don't exist, the load function will indicate nothing could be
     /// loaded.
-    pub fn open_gridstore(path: PathBuf, create_if_missing: bool) -> OperationResult<Option<Self>> {
-        let store = if create_if_missing {
-            let options = default_gridstore_options(N::gridstore_block_size());
+    ///
+    /// With `deduplicate_values`, newly added points keep only distinct values, so their
+    /// `values_count` reflects the number of distinct values instead of raw occurrences.
+    pub fn open_gridstore(
+        path: PathBuf,
+        create_if_missing: bool,
+        deduplicate_values: bool,
+    ) -> OperationResult<Option<Self>> {
+        let mut store = if create_if_missing {
+            let options = gridstore_options(N::gridstore_block_size());
             Gridstore::open_or_create(path, options).map_err(|err| {
                 OperationError::service_error(format!(
                     "failed to open mutable map index on gridstore: {err}"
@@ -73,6 +99,17 @@ where
             return Ok(None);
         };
 
+        let write_coalescing_window = gridstore_index_config()
+            .write_coalescing_window
+            .unwrap_or_default();
+        store
+            .set_write_coalescing_window(write_coalescing_window)
+            .map_err(|err| {
+                OperationError::service_error(format!(
+                    "failed to configure mutable map index gridstore: {err}"
+                ))
+            })?;
+
         // Load in-memory index from Gridstore
         let mut map = HashMap::<_, RoaringBitmap>::new();
         let mut point_to_values = Vec::new();
@@ -111,6 +148,8 @@ where
             point_to_values,
             indexed_points,
             values_count,
+            deduplicate_values,
+            case_folded_keys: None,
             storage: Storage::Gridstore(store),
         }))
     }
@@ -128,6 +167,12 @@ where
             return Ok(());
         }
 
+        let values: Vec<<N as MapIndexKey>::Owned> = if self.deduplicate_values {
+            values.into_iter().map(Into::into).unique().collect()
+        } else {
+            values.into_iter().map(Into::into).collect()
+        };
+
         self.values_count += values.len();
         if self.point_to_values.len() <= idx as usize {
             self.point_to_values.resize_with(idx as usize + 1, Vec::new)
@@ -139,13 +184,17 @@ where
             Storage::Gridstore(store) => {
                 let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
 
-                for value in values.clone() {
-                    let entry = self.map.entry(value.into());
+                for value in values.iter().cloned() {
+                    let entry = self.map.entry(value);
+                    if let (Entry::Vacant(vacant), Some(case_folded_keys)) =
+                        (&entry, &mut self.case_folded_keys)
+                    {
+                        case_folded_keys.insert::<N>(vacant.key().borrow());
+                    }
                     self.point_to_values[idx as usize].push(entry.key().clone());
                     entry.or_default().insert(idx);
                 }
 
-                let values = values.into_iter().map(Into::into).collect::<Vec<_>>();
                 store
                     .put_value(idx, &values, hw_counter_ref)
                     .map_err(|err| {
@@ -226,6 +275,13 @@ where
         }
     }
 
+    /// Counters of data written into Gridstore storage, since the index was loaded.
+    pub fn write_stats(&self) -> WriteStats {
+        match &self.storage {
+            Storage::Gridstore(store) => store.write_stats(),
+        }
+    }
+
     #[inline]
     pub(super) fn flusher(&self) -> Flusher {
         match &self.storage {
@@ -337,6 +393,8 @@ where
             point_to_values,
             indexed_points: _,
             values_count: _,
+            deduplicate_values: _,
+            case_folded_keys: _,
             storage: _, // disk-backed, accounted via files
         } = self;
 
@@ -24,6 +24,7 @@ mod stored_point_to_values;
 #[cfg(test)]
 mod tests;
 mod utils;
+pub mod value_size_limit;
 
 pub use facet_index::FacetIndex;
 pub use field_index_base::*;
@@ -11,7 +11,7 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::StorageType;
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, PayloadKeyType};
+use crate::types::{FieldCondition, NullElements, PayloadKeyType};
 
 pub struct ImmutableNullIndex(MutableNullIndex);
 
@@ -45,6 +45,12 @@ impl ImmutableNullIndex {
         self.0.remove_point_immutable(id);
         Ok(())
     }
+
+    #[inline]
+    pub fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        self.0.remove_points_immutable(ids);
+        Ok(())
+    }
 }
 
 impl ImmutableNullIndex {
@@ -65,6 +71,20 @@ impl ImmutableNullIndex {
         self.0.values_is_null(id)
     }
 
+    #[inline]
+    pub fn values_is_null_with(
+        &self,
+        id: PointOffsetType,
+        null_elements: NullElements,
+    ) -> Option<bool> {
+        self.0.values_is_null_with(id, null_elements)
+    }
+
+    #[inline]
+    pub fn supports_null_elements(&self, null_elements: NullElements) -> bool {
+        self.0.supports_null_elements(null_elements)
+    }
+
     #[inline]
     pub fn is_on_disk(&self) -> bool {
         self.0.is_on_disk()
@@ -98,6 +118,11 @@ impl ImmutableNullIndex {
             points_count,
             points_values_count: points_count,
             histogram_bucket_size: None,
+            write_amplification: None,
+            oversized_values: None,
+            stopwords_language: None,
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
             index_type: "immutable_null_index",
         }
     }
@@ -226,6 +251,7 @@ mod tests {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: Some(false),
             is_null: None,
@@ -427,6 +453,7 @@ mod tests {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: Some(false),
             is_null: None,
@@ -11,6 +11,7 @@ use super::PayloadFieldIndex;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
+use crate::types::NullElements;
 
 pub enum NullIndex {
     Mutable(MutableNullIndex),
@@ -51,6 +52,13 @@ impl NullIndex {
         }
     }
 
+    pub fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        match self {
+            NullIndex::Mutable(mutable) => mutable.remove_points(ids),
+            NullIndex::Immutable(immutable) => immutable.remove_points(ids),
+        }
+    }
+
     pub fn values_count(&self, id: PointOffsetType) -> usize {
         match self {
             NullIndex::Mutable(mutable) => mutable.values_count(id),
@@ -72,6 +80,24 @@ impl NullIndex {
         }
     }
 
+    pub fn values_is_null_with(
+        &self,
+        id: PointOffsetType,
+        null_elements: NullElements,
+    ) -> Option<bool> {
+        match self {
+            NullIndex::Mutable(mutable) => mutable.values_is_null_with(id, null_elements),
+            NullIndex::Immutable(immutable) => immutable.values_is_null_with(id, null_elements),
+        }
+    }
+
+    pub fn supports_null_elements(&self, null_elements: NullElements) -> bool {
+        match self {
+            NullIndex::Mutable(mutable) => mutable.supports_null_elements(null_elements),
+            NullIndex::Immutable(immutable) => immutable.supports_null_elements(null_elements),
+        }
+    }
+
     pub fn populate(&self) -> OperationResult<()> {
         match self {
             NullIndex::Mutable(mutable) => mutable.populate(),
@@ -17,10 +17,12 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, PayloadKeyType};
+use crate::types::{FieldCondition, NullElements, PayloadKeyType};
 
 const HAS_VALUES_DIRNAME: &str = "has_values";
 const IS_NULL_DIRNAME: &str = "is_null";
+const NULL_VALUES_DIRNAME: &str = "null_values";
+const NULL_ELEMENTS_DIRNAME: &str = "null_elements";
 
 /// Mutable variant of null index that uses roaring bitmaps for in-memory operations
 /// and buffers updates before persisting them to DynamicMmapFlags.
@@ -33,8 +35,18 @@ pub struct MutableNullIndex {
 struct Storage<S> {
     /// Points which have at least one value
     has_values_flags: RoaringFlags<S>,
-    /// Points which have null values
+    /// Points which have null values, or arrays with at least one null element
     is_null_flags: RoaringFlags<S>,
+    /// Element-level nullness.
+    /// Not available in indexes created before it was tracked.
+    element_flags: Option<ElementFlags<S>>,
+}
+
+struct ElementFlags<S> {
+    /// Points which have null values
+    null_values_flags: RoaringFlags<S>,
+    /// Points which have arrays with at least one null element
+    null_elements_flags: RoaringFlags<S>,
 }
 
 impl MutableNullIndex {
@@ -98,6 +110,7 @@ impl MutableNullIndex {
         })?;
 
         let has_values_path = path.join(HAS_VALUES_DIRNAME);
+        let is_new = !has_values_path.is_dir();
         let has_values_mmap = DynamicStoredFlags::open(&has_values_path, false)?;
         let has_values_flags = RoaringFlags::new(has_values_mmap)?;
 
@@ -105,9 +118,27 @@ impl MutableNullIndex {
         let is_null_mmap = DynamicStoredFlags::open(&is_null_path, false)?;
         let is_null_flags = RoaringFlags::new(is_null_mmap)?;
 
+        let null_values_path = path.join(NULL_VALUES_DIRNAME);
+        let null_elements_path = path.join(NULL_ELEMENTS_DIRNAME);
+
+        // Existing indexes without element-level flags can't tell null values from null elements,
+        // don't create empty flags for them
+        let has_element_flags = null_values_path.is_dir() && null_elements_path.is_dir();
+        let element_flags = if is_new || has_element_flags {
+            let null_values_mmap = DynamicStoredFlags::open(&null_values_path, false)?;
+            let null_elements_mmap = DynamicStoredFlags::open(&null_elements_path, false)?;
+            Some(ElementFlags {
+                null_values_flags: RoaringFlags::new(null_values_mmap)?,
+                null_elements_flags: RoaringFlags::new(null_elements_mmap)?,
+            })
+        } else {
+            None
+        };
+
         let storage = Storage {
             has_values_flags,
             is_null_flags,
+            element_flags,
         };
 
         Ok(Self {
@@ -123,13 +154,14 @@ impl MutableNullIndex {
         payload: &[&Value],
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        let mut is_null = false;
+        let mut has_null_value = false;
+        let mut has_null_elements = false;
         let mut has_values = false;
 
         for value in payload {
             match value {
                 Value::Null => {
-                    is_null = true;
+                    has_null_value = true;
                 }
                 Value::Bool(_) => {
                     has_values = true;
@@ -142,7 +174,7 @@ impl MutableNullIndex {
                 }
                 Value::Array(array) => {
                     if array.iter().any(|v| v.is_null()) {
-                        is_null = true;
+                        has_null_elements = true;
                     }
                     if !array.is_empty() {
                         has_values = true;
@@ -152,13 +184,18 @@ impl MutableNullIndex {
                     has_values = true;
                 }
             }
-            if is_null && has_values {
+            if has_null_value && has_null_elements && has_values {
                 break;
             }
         }
 
+        let is_null = has_null_value || has_null_elements;
         self.storage.has_values_flags.set(id, has_values);
         self.storage.is_null_flags.set(id, is_null);
+        if let Some(element_flags) = &mut self.storage.element_flags {
+            element_flags.null_values_flags.set(id, has_null_value);
+            element_flags.null_elements_flags.set(id, has_null_elements);
+        }
 
         // Bump total points
         self.total_point_count = std::cmp::max(self.total_point_count, id as usize + 1);
@@ -173,6 +210,10 @@ impl MutableNullIndex {
         // Update bitmaps immediately
         self.storage.has_values_flags.set(id, false);
         self.storage.is_null_flags.set(id, false);
+        if let Some(element_flags) = &mut self.storage.element_flags {
+            element_flags.null_values_flags.set(id, false);
+            element_flags.null_elements_flags.set(id, false);
+        }
 
         // Bump total points
         // We MUST bump the total point count when removing a point too
@@ -188,10 +229,40 @@ impl MutableNullIndex {
         Ok(())
     }
 
+    /// Removes many points at once, clearing each of the bitmaps in one pass.
+    pub fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        let Some(&max_id) = ids.iter().max() else {
+            return Ok(());
+        };
+
+        // Update bitmaps immediately
+        self.storage.has_values_flags.clear_many(ids);
+        self.storage.is_null_flags.clear_many(ids);
+        if let Some(element_flags) = &mut self.storage.element_flags {
+            element_flags.null_values_flags.clear_many(ids);
+            element_flags.null_elements_flags.clear_many(ids);
+        }
+
+        // Bump total points, same as in `remove_point`
+        self.total_point_count = std::cmp::max(self.total_point_count, max_id as usize + 1);
+
+        // Account for I/O cost as if we were writing to disk now
+        let hw_counter = HardwareCounterCell::disposable();
+        hw_counter
+            .payload_index_io_write_counter()
+            .incr_delta(2 * ids.len());
+
+        Ok(())
+    }
+
     pub(super) fn remove_point_immutable(&mut self, id: PointOffsetType) {
         // Update bitmaps immediately
         self.storage.has_values_flags.set_immutable(id, false);
         self.storage.is_null_flags.set_immutable(id, false);
+        if let Some(element_flags) = &mut self.storage.element_flags {
+            element_flags.null_values_flags.set_immutable(id, false);
+            element_flags.null_elements_flags.set_immutable(id, false);
+        }
 
         // N.B. We do not update total_point_count because it comes from the id tracker and is not not changed
         // in non-appendable segments.
@@ -199,6 +270,15 @@ impl MutableNullIndex {
         // N.B. No I/O, do not update hw_counter.
     }
 
+    pub(super) fn remove_points_immutable(&mut self, ids: &[PointOffsetType]) {
+        self.storage.has_values_flags.clear_many_immutable(ids);
+        self.storage.is_null_flags.clear_many_immutable(ids);
+        if let Some(element_flags) = &mut self.storage.element_flags {
+            element_flags.null_values_flags.clear_many_immutable(ids);
+            element_flags.null_elements_flags.clear_many_immutable(ids);
+        }
+    }
+
     pub fn values_count(&self, id: PointOffsetType) -> usize {
         usize::from(self.storage.has_values_flags.get(id))
     }
@@ -207,10 +287,41 @@ impl MutableNullIndex {
         !self.storage.has_values_flags.get(id)
     }
 
+    /// Whether the point has null values, or arrays with at least one null element
     pub fn values_is_null(&self, id: PointOffsetType) -> bool {
         self.storage.is_null_flags.get(id)
     }
 
+    /// Whether the point has null values, with the given treatment of null array elements.
+    ///
+    /// Returns `None` if the index can't tell, because it was created before element-level
+    /// nullness was tracked. [`NullElements::Include`] is always known.
+    pub fn values_is_null_with(
+        &self,
+        id: PointOffsetType,
+        null_elements: NullElements,
+    ) -> Option<bool> {
+        match null_elements {
+            NullElements::Include => Some(self.values_is_null(id)),
+            NullElements::Ignore => {
+                let element_flags = self.storage.element_flags.as_ref()?;
+                Some(element_flags.null_values_flags.get(id))
+            }
+            NullElements::Only => {
+                let element_flags = self.storage.element_flags.as_ref()?;
+                Some(element_flags.null_elements_flags.get(id))
+            }
+        }
+    }
+
+    /// Whether [`Self::values_is_null_with`] is known for the given treatment of null elements
+    pub fn supports_null_elements(&self, null_elements: NullElements) -> bool {
+        match null_elements {
+            NullElements::Include => true,
+            NullElements::Ignore | NullElements::Only => self.storage.element_flags.is_some(),
+        }
+    }
+
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
         let points_count = self.storage.has_values_flags.len();
 
@@ -219,6 +330,11 @@ impl MutableNullIndex {
             points_count,
             points_values_count: points_count,
             histogram_bucket_size: None,
+            write_amplification: None,
+            oversized_values: None,
+            stopwords_language: None,
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
             index_type: "mutable_null_index",
         }
     }
@@ -237,9 +353,19 @@ impl MutableNullIndex {
         let Storage {
             has_values_flags,
             is_null_flags,
+            element_flags,
         } = storage;
+        let element_flags_size = element_flags.as_ref().map_or(0, |element_flags| {
+            let ElementFlags {
+                null_values_flags,
+                null_elements_flags,
+            } = element_flags;
+            null_values_flags.get_bitmap().serialized_size()
+                + null_elements_flags.get_bitmap().serialized_size()
+        });
         has_values_flags.get_bitmap().serialized_size()
             + is_null_flags.get_bitmap().serialized_size()
+            + element_flags_size
     }
 
     pub fn is_on_disk(&self) -> bool {
@@ -248,6 +374,10 @@ impl MutableNullIndex {
 
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
+        if let Some(element_flags) = &self.storage.element_flags {
+            element_flags.null_values_flags.clear_cache()?;
+            element_flags.null_elements_flags.clear_cache()?;
+        }
         self.storage.is_null_flags.clear_cache()?;
         self.storage.has_values_flags.clear_cache()
     }
@@ -281,10 +411,20 @@ impl PayloadFieldIndex for MutableNullIndex {
     fn flusher(&self) -> Flusher {
         let flush_has_values = self.storage.has_values_flags.flusher();
         let flush_is_null = self.storage.is_null_flags.flusher();
+        let flush_element_flags = self.storage.element_flags.as_ref().map(|element_flags| {
+            (
+                element_flags.null_values_flags.flusher(),
+                element_flags.null_elements_flags.flusher(),
+            )
+        });
 
         Box::new(move || {
             flush_has_values()?;
             flush_is_null()?;
+            if let Some((flush_null_values, flush_null_elements)) = flush_element_flags {
+                flush_null_values()?;
+                flush_null_elements()?;
+            }
             Ok(())
         })
     }
@@ -292,6 +432,10 @@ impl PayloadFieldIndex for MutableNullIndex {
     fn files(&self) -> Vec<PathBuf> {
         let mut files = self.storage.has_values_flags.files();
         files.extend(self.storage.is_null_flags.files());
+        if let Some(element_flags) = &self.storage.element_flags {
+            files.extend(element_flags.null_values_flags.files());
+            files.extend(element_flags.null_elements_flags.files());
+        }
         files
     }
 
@@ -311,6 +455,7 @@ impl PayloadFieldIndex for MutableNullIndex {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
+            geo_near_path: _,
             values_count: _,
             is_empty,
             is_null,
@@ -360,6 +505,7 @@ impl PayloadFieldIndex for MutableNullIndex {
             geo_bounding_box: _,
             geo_radius: _,
             geo_polygon: _,
+            geo_near_path: _,
             values_count: _,
             is_empty,
             is_null,
@@ -495,6 +641,7 @@ mod tests {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: Some(false),
             is_null: None,
@@ -573,6 +720,83 @@ mod tests {
         assert_eq!(non_empty_cardinality.exp, 50);
     }
 
+    #[test]
+    fn test_null_elements() {
+        let dir = TempDir::with_prefix("test_null_elements").unwrap();
+
+        let null_value = Value::Null;
+        let fully_null_array = Value::Array(vec![Value::Null, Value::Null]);
+        let partially_null_array = Value::Array(vec![Value::from(1), Value::Null, Value::from(3)]);
+        let no_null_array = Value::Array(vec![Value::from(1), Value::from(3)]);
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut builder = MutableNullIndex::builder(dir.path(), 5).unwrap();
+        builder.add_point(0, &[&null_value], &hw_counter).unwrap();
+        builder
+            .add_point(1, &[&fully_null_array], &hw_counter)
+            .unwrap();
+        builder
+            .add_point(2, &[&partially_null_array], &hw_counter)
+            .unwrap();
+        builder
+            .add_point(3, &[&no_null_array], &hw_counter)
+            .unwrap();
+        builder
+            .add_point(4, &[&null_value, &partially_null_array], &hw_counter)
+            .unwrap();
+        let null_index = builder.finalize().unwrap();
+
+        let matching = |null_elements| -> Vec<PointOffsetType> {
+            (0..5)
+                .filter(|&id| null_index.values_is_null_with(id, null_elements).unwrap())
+                .collect()
+        };
+
+        assert_eq!(matching(NullElements::Ignore), vec![0, 4]);
+        assert_eq!(matching(NullElements::Include), vec![0, 1, 2, 4]);
+        assert_eq!(matching(NullElements::Only), vec![1, 2, 4]);
+
+        // Partially null arrays still have values, fully null arrays don't count as empty either
+        assert!(!null_index.values_is_empty(1));
+        assert!(!null_index.values_is_empty(2));
+        assert!(null_index.values_is_empty(0));
+
+        drop(null_index);
+
+        // Reopen the index, element-level nullness is persisted
+        let null_index = MutableNullIndex::open(dir.path(), 5, false)
+            .unwrap()
+            .unwrap();
+        assert!(null_index.supports_null_elements(NullElements::Only));
+        assert_eq!(
+            null_index.values_is_null_with(2, NullElements::Ignore),
+            Some(false)
+        );
+        assert_eq!(
+            null_index.values_is_null_with(2, NullElements::Only),
+            Some(true)
+        );
+        drop(null_index);
+
+        // Index without element-level nullness, as created by older versions
+        fs::remove_dir_all(dir.path().join(NULL_VALUES_DIRNAME)).unwrap();
+        fs::remove_dir_all(dir.path().join(NULL_ELEMENTS_DIRNAME)).unwrap();
+
+        let null_index = MutableNullIndex::open(dir.path(), 5, false)
+            .unwrap()
+            .unwrap();
+        assert!(null_index.supports_null_elements(NullElements::Include));
+        assert!(!null_index.supports_null_elements(NullElements::Ignore));
+        assert!(!null_index.supports_null_elements(NullElements::Only));
+        assert_eq!(null_index.values_is_null_with(2, NullElements::Only), None);
+        assert_eq!(
+            null_index.values_is_null_with(2, NullElements::Include),
+            Some(true)
+        );
+        assert!(!dir.path().join(NULL_VALUES_DIRNAME).exists());
+    }
+
     #[test]
     fn test_manual_buffer_flushing() {
         let dir = TempDir::with_prefix("test_manual_buffer_flushing").unwrap();
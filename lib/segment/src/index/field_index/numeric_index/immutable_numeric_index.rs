@@ -105,6 +105,19 @@ impl<T: Encodable + Numericable> NumericKeySortedVec<T> {
         false
     }
 
+    /// Same as [`Self::remove`], but only searches for the `key` from the `start` position.
+    ///
+    /// Returns the position of the removed key, so that sorted keys are removed in one pass.
+    fn remove_from(&mut self, start: usize, key: &Point<T>) -> Option<usize> {
+        let index = start + self.data.get(start..)?.binary_search(key).ok()?;
+        let mut is_deleted = self.deleted.get_mut(index)?;
+        if !*is_deleted {
+            self.deleted_count += 1;
+            *is_deleted = true;
+        }
+        Some(index)
+    }
+
     fn values_range(
         &self,
         start_bound: Bound<Point<T>>,
@@ -332,6 +345,45 @@ where
         self.point_to_values.remove_point(idx);
     }
 
+    /// Removes many points at once.
+    ///
+    /// Values of all points are sorted first, and then removed in one pass over the sorted map.
+    pub(super) fn remove_points(&mut self, ids: &[PointOffsetType]) {
+        let mut keys = Vec::new();
+        for &idx in ids {
+            let Some(removed_values) = self.point_to_values.get_values(idx) else {
+                continue;
+            };
+            let keys_count = keys.len();
+            keys.extend(removed_values.map(|value| Point::new(*value, idx)));
+            if keys.len() > keys_count {
+                self.points_count = self.points_count.saturating_sub(1);
+
+                // Update persisted storage
+                match &mut self.storage {
+                    Storage::Mmap(index) => {
+                        index.remove_point(idx);
+                    }
+                }
+            }
+            self.point_to_values.remove_point(idx);
+        }
+        keys.sort_unstable();
+
+        let mut start = 0;
+        for key in &keys {
+            let Some(index) = self.map.remove_from(start, key) else {
+                continue;
+            };
+            start = index + 1;
+            self.histogram.remove(
+                key,
+                |x| Self::get_histogram_left_neighbor(&self.map, x),
+                |x| Self::get_histogram_right_neighbor(&self.map, x),
+            );
+        }
+    }
+
     pub(super) fn get_histogram(&self) -> &Histogram<T> {
         &self.histogram
     }
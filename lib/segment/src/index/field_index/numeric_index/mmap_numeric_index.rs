@@ -9,6 +9,7 @@ use common::counter::iterator_hw_measurement::HwMeasurementIteratorExt;
 use common::fs::{atomic_save_json, clear_disk_cache, read_json};
 use common::generic_consts::Random;
 use common::mmap::{MmapSlice, create_and_ensure_length};
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::stored_bitslice::MmapBitSlice;
 use common::types::PointOffsetType;
 use common::universal_io::{MmapFile, OpenOptions, ReadRange, TypedStorage, UniversalRead};
@@ -17,8 +18,8 @@ use itertools::Either;
 use memmap2::MmapMut;
 use serde::{Deserialize, Serialize};
 
-use super::Encodable;
 use super::mutable_numeric_index::InMemoryNumericIndex;
+use super::{Encodable, HISTOGRAM_MAX_BUCKET_SIZE, HISTOGRAM_PRECISION};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::histogram::Histogram;
@@ -166,7 +167,6 @@ impl<T: Encodable + Numericable + Default + StoredValue + bytemuck::Pod> MmapNum
             return Ok(None);
         }
 
-        let histogram = Histogram::<T>::load(path)?;
         let config: MmapNumericIndexConfig = read_json(&config_path)?;
         let do_populate = !is_on_disk;
 
@@ -179,6 +179,7 @@ impl<T: Encodable + Numericable + Default + StoredValue + bytemuck::Pod> MmapNum
             prevent_caching: None,
         };
         let pairs = TypedStorage::open(pairs_path, pairs_options)?;
+        let histogram = Self::load_histogram(path, &pairs)?;
 
         let point_to_values = StoredPointToValues::open(path, do_populate)?;
         let mut deleted = deleted_points.to_owned();
@@ -210,6 +211,42 @@ impl<T: Encodable + Numericable + Default + StoredValue + bytemuck::Pod> MmapNum
         }))
     }
 
+    /// Load the histogram, saved along with the index.
+    ///
+    /// If it is missing, corrupted, or doesn't match the stored pairs, it is rebuilt from the
+    /// pairs and saved again.
+    fn load_histogram(
+        path: &Path,
+        pairs: &TypedStorage<MmapFile, Point<T>>,
+    ) -> OperationResult<Histogram<T>> {
+        let pairs_count = pairs.len()? as usize;
+        match Histogram::<T>::load(path) {
+            Ok(histogram) if histogram.get_total_count() == pairs_count => return Ok(histogram),
+            Ok(histogram) => log::warn!(
+                "Numeric index histogram at {} has {} values, expected {pairs_count}, rebuilding",
+                path.display(),
+                histogram.get_total_count(),
+            ),
+            Err(err) => log::warn!(
+                "Failed to load numeric index histogram at {}: {err}, rebuilding",
+                path.display(),
+            ),
+        }
+
+        let pairs = if pairs_count > 0 {
+            pairs.read::<Random>(ReadRange {
+                byte_offset: 0,
+                length: pairs_count as u64,
+            })?
+        } else {
+            Cow::Borrowed(&[][..])
+        };
+        let histogram =
+            Histogram::from_sorted(HISTOGRAM_MAX_BUCKET_SIZE, HISTOGRAM_PRECISION, &pairs);
+        histogram.save(path)?;
+        Ok(histogram)
+    }
+
     pub fn wipe(self) -> OperationResult<()> {
         let files = self.files();
         let path = self.path.clone();
@@ -467,6 +504,14 @@ impl<T: Encodable + Numericable + Default + StoredValue + bytemuck::Pod> MmapNum
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    /// Sorted pairs are populated first, they are used by every range lookup.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        budget.populate(PopulateStage::Lookup, &self.storage.pairs)?;
+        self.storage.point_to_values.populate_with_budget(budget)?;
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         let Self {
@@ -1,6 +1,7 @@
 pub mod immutable_numeric_index;
 pub mod mmap_numeric_index;
 pub mod mutable_numeric_index;
+pub mod scaled_float_index;
 
 #[cfg(test)]
 mod tests;
@@ -16,6 +17,7 @@ use chrono::DateTime;
 use common::bitvec::{BitSlice, BitVec};
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::either_variant::EitherVariant;
+use common::populate_budget::PopulateBudget;
 use common::types::PointOffsetType;
 use gridstore::Blob;
 use mmap_numeric_index::MmapNumericIndex;
@@ -32,6 +34,7 @@ use super::stored_point_to_values::StoredValue;
 use super::utils::{check_boundaries, value_to_integer};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::quantiles::{ValueSample, ValueSamples};
 use crate::index::field_index::histogram::Histogram;
 use crate::index::field_index::numeric_point::{Numericable, Point};
 use crate::index::field_index::stat_tools::estimate_multi_value_selection_cardinality;
@@ -81,6 +84,22 @@ impl Encodable for IntPayloadType {
     }
 }
 
+/// Encodes as i64, which covers all values
+impl Encodable for i32 {
+    fn encode_key(&self, id: PointOffsetType) -> Vec<u8> {
+        encode_i64_key_ascending(i64::from(*self), id)
+    }
+
+    fn decode_key(key: &[u8]) -> (PointOffsetType, Self) {
+        let (id, value) = decode_i64_key_ascending(key);
+        (id, value as i32)
+    }
+
+    fn cmp_encoded(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp(other)
+    }
+}
+
 impl Encodable for u128 {
     fn encode_key(&self, id: PointOffsetType) -> Vec<u8> {
         encode_u128_key_ascending(*self, id)
@@ -160,6 +179,14 @@ impl<T: Encodable + Numericable> Range<T> {
     }
 }
 
+/// Statistics of the index, shared by the cardinality estimations of ranges
+#[derive(Debug, Clone, Copy)]
+struct RangeCardinalityStats {
+    max_values_per_point: usize,
+    total_values: usize,
+    points_count: usize,
+}
+
 pub enum NumericIndexInner<T: Encodable + Numericable + StoredValue + Send + Sync + Default>
 where
     Vec<T>: Blob,
@@ -265,6 +292,23 @@ where
         Ok(())
     }
 
+    pub fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        match self {
+            NumericIndexInner::Mutable(index) => {
+                for &idx in ids {
+                    index.remove_point(idx)?;
+                }
+            }
+            NumericIndexInner::Immutable(index) => index.remove_points(ids),
+            NumericIndexInner::Mmap(index) => {
+                for &idx in ids {
+                    index.remove_point(idx);
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn check_values_any(
         &self,
         idx: PointOffsetType,
@@ -310,18 +354,54 @@ where
         }
     }
 
-    fn range_cardinality(&self, range: &RangeInterface) -> OperationResult<CardinalityEstimation> {
+    fn range_cardinality_stats(&self) -> OperationResult<RangeCardinalityStats> {
         let max_values_per_point = self.max_values_per_point();
-        if max_values_per_point == 0 {
-            return Ok(CardinalityEstimation::exact(0));
-        }
+        let total_values = if max_values_per_point == 0 {
+            0
+        } else {
+            self.total_unique_values_count()?
+        };
+        Ok(RangeCardinalityStats {
+            max_values_per_point,
+            total_values,
+            points_count: self.get_points_count(),
+        })
+    }
 
+    fn range_cardinality(&self, range: &RangeInterface) -> OperationResult<CardinalityEstimation> {
+        let stats = self.range_cardinality_stats()?;
+        Ok(self.range_cardinality_with_stats(range, stats))
+    }
+
+    fn range_cardinality_with_stats(
+        &self,
+        range: &RangeInterface,
+        stats: RangeCardinalityStats,
+    ) -> CardinalityEstimation {
         let range = match range {
             RangeInterface::Float(float_range) => float_range.map(|float| T::from_f64(float.0)),
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
             }
+            RangeInterface::Uuid(uuid_range) => uuid_range.map(|id| T::from_u128(id.as_u128())),
         };
+        self.values_range_cardinality(&range, stats)
+    }
+
+    /// Same as [`Self::range_cardinality_with_stats`], for a `range` of index values
+    fn values_range_cardinality(
+        &self,
+        range: &Range<T>,
+        stats: RangeCardinalityStats,
+    ) -> CardinalityEstimation {
+        let RangeCardinalityStats {
+            max_values_per_point,
+            total_values,
+            points_count,
+        } = stats;
+        if max_values_per_point == 0 {
+            return CardinalityEstimation::exact(0);
+        }
 
         let lbound = if let Some(lte) = range.lte {
             Included(lte)
@@ -343,7 +423,6 @@ where
         let min_estimation = histogram_estimation.0;
         let max_estimation = histogram_estimation.2;
 
-        let total_values = self.total_unique_values_count()?;
         // Example: points_count = 1000, total values = 2000, values_count = 500
         // min = max(1, 500 - (2000 - 1000)) = 1
         // exp = 500 / (2000 / 1000) = 250
@@ -358,24 +437,63 @@ where
             min_estimation / max_values_per_point,
             max(
                 min(1, min_estimation),
-                min_estimation.saturating_sub(total_values - self.get_points_count()),
+                min_estimation.saturating_sub(total_values - points_count),
             ),
         );
-        let expected_max = min(self.get_points_count(), max_estimation);
+        let expected_max = min(points_count, max_estimation);
 
         let estimation = estimate_multi_value_selection_cardinality(
-            self.get_points_count(),
+            points_count,
             total_values,
             histogram_estimation.1,
         )
         .round() as usize;
 
-        Ok(CardinalityEstimation {
+        CardinalityEstimation {
             primary_clauses: vec![],
             min: expected_min,
             exp: min(expected_max, max(estimation, expected_min)),
             max: expected_max,
-        })
+        }
+    }
+
+    /// Estimate cardinality of the `condition`, with the range statistics computed on first use.
+    fn estimate_condition_cardinality(
+        &self,
+        condition: &FieldCondition,
+        stats: &mut Option<RangeCardinalityStats>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<CardinalityEstimation>> {
+        if let Some(Match::Value(MatchValue {
+            value: ValueVariants::String(keyword),
+        })) = &condition.r#match
+        {
+            let keyword = keyword.as_str();
+            if let Ok(uuid) = Uuid::from_str(keyword) {
+                let key = T::from_u128(uuid.as_u128());
+
+                let estimated_count = self.estimate_points(&key, hw_counter)?;
+                return Ok(Some(
+                    CardinalityEstimation::exact(estimated_count).with_primary_clause(
+                        PrimaryCondition::Condition(Box::new(condition.clone())),
+                    ),
+                ));
+            }
+        }
+
+        let Some(range) = condition.range.as_ref() else {
+            return Ok(None);
+        };
+
+        let stats = match stats {
+            Some(stats) => *stats,
+            None => *stats.insert(self.range_cardinality_stats()?),
+        };
+        let mut cardinality = self.range_cardinality_with_stats(range, stats);
+        cardinality
+            .primary_clauses
+            .push(PrimaryCondition::Condition(Box::new(condition.clone())));
+        Ok(Some(cardinality))
     }
 
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
@@ -384,6 +502,11 @@ where
             points_count: self.get_points_count(),
             points_values_count: self.get_histogram().get_total_count(),
             histogram_bucket_size: Some(self.get_histogram().current_bucket_size()),
+            write_amplification: None,
+            oversized_values: None,
+            stopwords_language: None,
+            memory_usage_bytes: None,
+            is_on_disk: self.is_on_disk(),
             index_type: match self {
                 NumericIndexInner::Mutable(_) => "mutable_numeric",
                 NumericIndexInner::Immutable(_) => "immutable_numeric",
@@ -396,6 +519,48 @@ where
         self.values_count(idx) == 0
     }
 
+    /// Values sampled at about evenly spaced positions of the sorted values.
+    ///
+    /// The borders of the histogram are such samples, so it takes no scan of the values.
+    pub fn value_samples(&self) -> ValueSamples {
+        let samples = self
+            .get_histogram()
+            .border_samples()
+            .map(|(value, count)| ValueSample {
+                value: value.to_f64(),
+                count,
+            })
+            .collect();
+        ValueSamples { samples }
+    }
+
+    /// Points with at least one value within the `range` of index values
+    fn range_points<'a>(
+        &'a self,
+        range: &Range<T>,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> OperationResult<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        let (start_bound, end_bound) = range.as_index_key_bounds();
+
+        // map.range
+        // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
+        if !check_boundaries(&start_bound, &end_bound) {
+            return Ok(Box::new(std::iter::empty()));
+        }
+
+        Ok(match self {
+            NumericIndexInner::Mutable(index) => {
+                Box::new(index.values_range(start_bound, end_bound))
+            }
+            NumericIndexInner::Immutable(index) => {
+                Box::new(index.values_range(start_bound, end_bound))
+            }
+            NumericIndexInner::Mmap(index) => {
+                Box::new(index.values_range(start_bound, end_bound, hw_counter)?)
+            }
+        })
+    }
+
     pub fn point_ids_by_value<'a>(
         &'a self,
         value: T,
@@ -485,6 +650,16 @@ where
         Ok(())
     }
 
+    /// Populate pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        match self {
+            NumericIndexInner::Mutable(_) => {}   // Not a mmap
+            NumericIndexInner::Immutable(_) => {} // Not a mmap
+            NumericIndexInner::Mmap(index) => index.populate_with_budget(budget)?,
+        }
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         match self {
@@ -624,6 +799,10 @@ where
         self.inner.populate()
     }
 
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        self.inner.populate_with_budget(budget)
+    }
+
     pub fn clear_cache(&self) -> OperationResult<()> {
         self.inner.clear_cache()
     }
@@ -849,34 +1028,15 @@ where
             return Ok(None);
         };
 
-        let (start_bound, end_bound) = match range_cond {
+        let range = match range_cond {
             RangeInterface::Float(float_range) => float_range.map(|float| T::from_f64(float.0)),
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
             }
-        }
-        .as_index_key_bounds();
-
-        // map.range
-        // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
-        if !check_boundaries(&start_bound, &end_bound) {
-            return Ok(Some(Box::new(std::iter::empty())));
-        }
-
-        let result: Box<dyn Iterator<Item = PointOffsetType> + 'a> = match self {
-            NumericIndexInner::Mutable(index) => {
-                Box::new(index.values_range(start_bound, end_bound))
-            }
-            NumericIndexInner::Immutable(index) => {
-                Box::new(index.values_range(start_bound, end_bound))
-            }
-
-            NumericIndexInner::Mmap(index) => {
-                Box::new(index.values_range(start_bound, end_bound, hw_counter)?)
-            }
+            RangeInterface::Uuid(uuid_range) => uuid_range.map(|id| T::from_u128(id.as_u128())),
         };
 
-        Ok(Some(result))
+        Ok(Some(self.range_points(&range, hw_counter)?))
     }
 
     fn estimate_cardinality(
@@ -884,34 +1044,19 @@ where
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<CardinalityEstimation>> {
-        if let Some(Match::Value(MatchValue {
-            value: ValueVariants::String(keyword),
-        })) = &condition.r#match
-        {
-            let keyword = keyword.as_str();
-            if let Ok(uuid) = Uuid::from_str(keyword) {
-                let key = T::from_u128(uuid.as_u128());
-
-                let estimated_count = self.estimate_points(&key, hw_counter)?;
-                return Ok(Some(
-                    CardinalityEstimation::exact(estimated_count).with_primary_clause(
-                        PrimaryCondition::Condition(Box::new(condition.clone())),
-                    ),
-                ));
-            }
-        }
+        self.estimate_condition_cardinality(condition, &mut None, hw_counter)
+    }
 
-        condition
-            .range
-            .as_ref()
-            .map(|range| {
-                let mut cardinality = self.range_cardinality(range)?;
-                cardinality
-                    .primary_clauses
-                    .push(PrimaryCondition::Condition(Box::new(condition.clone())));
-                Ok(cardinality)
-            })
-            .transpose()
+    fn estimate_cardinality_batch(
+        &self,
+        conditions: &[&FieldCondition],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<Option<CardinalityEstimation>>> {
+        let mut stats = None;
+        conditions
+            .iter()
+            .map(|condition| self.estimate_condition_cardinality(condition, &mut stats, hw_counter))
+            .collect()
     }
 
     fn for_each_payload_block(
@@ -925,6 +1070,7 @@ where
             let mut pre_lower_bound: Option<Bound<T>> = None;
             let mut payload_conditions = Vec::new();
 
+            let stats = self.range_cardinality_stats()?;
             let value_per_point =
                 self.total_unique_values_count()? as f64 / self.get_points_count() as f64;
             let effective_threshold = (threshold as f64 * value_per_point) as usize;
@@ -953,7 +1099,8 @@ where
                             _ => None,
                         },
                     };
-                    let cardinality = self.range_cardinality(&RangeInterface::Float(range))?;
+                    let cardinality =
+                        self.range_cardinality_with_stats(&RangeInterface::Float(range), stats);
                     let condition = PayloadBlockCondition {
                         condition: FieldCondition::new_range(key.clone(), range),
                         cardinality: cardinality.exp,
@@ -1018,6 +1165,10 @@ impl ValueIndexer for NumericIndex<IntPayloadType, IntPayloadType> {
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
         self.inner.remove_point(id)
     }
+
+    fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        self.inner.remove_points(ids)
+    }
 }
 
 impl NumericIndexIntoInnerValue<IntPayloadType, IntPayloadType>
@@ -1059,6 +1210,10 @@ impl ValueIndexer for NumericIndex<IntPayloadType, DateTimePayloadType> {
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
         self.inner.remove_point(id)
     }
+
+    fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        self.inner.remove_points(ids)
+    }
 }
 
 impl NumericIndexIntoInnerValue<IntPayloadType, DateTimePayloadType>
@@ -1096,6 +1251,10 @@ impl ValueIndexer for NumericIndex<FloatPayloadType, FloatPayloadType> {
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
         self.inner.remove_point(id)
     }
+
+    fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        self.inner.remove_points(ids)
+    }
 }
 
 impl NumericIndexIntoInnerValue<FloatPayloadType, FloatPayloadType>
@@ -1136,6 +1295,10 @@ impl ValueIndexer for NumericIndex<UuidIntType, UuidPayloadType> {
     fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
         self.inner.remove_point(id)
     }
+
+    fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        self.inner.remove_points(ids)
+    }
 }
 
 impl NumericIndexIntoInnerValue<UuidIntType, UuidPayloadType>
@@ -1160,6 +1323,7 @@ where
             RangeInterface::DateTime(datetime_range) => {
                 datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
             }
+            RangeInterface::Uuid(uuid_range) => uuid_range.map(|id| T::from_u128(id.as_u128())),
         };
         let (start_bound, end_bound) = range.as_index_key_bounds();
 
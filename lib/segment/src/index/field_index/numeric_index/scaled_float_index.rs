@@ -0,0 +1,565 @@
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use common::bitvec::{BitSlice, BitVec};
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::fs::{atomic_save_json, read_json};
+use common::populate_budget::PopulateBudget;
+use common::types::PointOffsetType;
+use fs_err as fs;
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::mmap_numeric_index::MmapNumericIndex;
+use super::mutable_numeric_index::InMemoryNumericIndex;
+use super::{NumericIndex, NumericIndexInner, RangeCardinalityStats, StreamRange};
+use crate::common::Flusher;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::quantiles::{ValueSample, ValueSamples};
+use crate::index::field_index::{
+    CardinalityEstimation, FieldIndexBuilderTrait, PayloadBlockCondition, PayloadFieldIndex,
+    PrimaryCondition, ValueIndexer,
+};
+use crate::index::payload_config::{IndexMutability, StorageType};
+use crate::telemetry::PayloadIndexTelemetry;
+use crate::types::{FieldCondition, FloatPayloadType, PayloadKeyType, Range, RangeInterface};
+
+/// Values of float indexes with a precision, stored as multiples of the precision
+pub type ScaledFloatType = i32;
+
+const CONFIG_PATH: &str = "scaled_float_index_config.json";
+
+/// Relative difference to a multiple of the precision, up to which range bounds are considered
+/// to be exactly on that multiple. Compensates the rounding error of the division by the
+/// precision, e.g. `0.3 / 0.1 = 2.9999999999999996`.
+const BOUND_TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScaledFloatIndexConfig {
+    precision: f64,
+}
+
+/// Round the `value` to the nearest multiple of the `precision`, as it is stored in the index.
+///
+/// Fails if the number of multiples doesn't fit into [`ScaledFloatType`].
+pub fn scale_value(value: FloatPayloadType, precision: f64) -> OperationResult<ScaledFloatType> {
+    let steps = (value / precision).round();
+    if !(f64::from(ScaledFloatType::MIN)..=f64::from(ScaledFloatType::MAX)).contains(&steps) {
+        return Err(OperationError::validation_error(format!(
+            "Value {value} can't be indexed with precision {precision}, \
+             it must be between {} and {}",
+            f64::from(ScaledFloatType::MIN) * precision,
+            f64::from(ScaledFloatType::MAX) * precision,
+        )));
+    }
+    Ok(steps as ScaledFloatType)
+}
+
+/// Float index, which stores values as [`ScaledFloatType`] multiples of its precision.
+///
+/// Values are rounded to the nearest multiple of the precision, which takes half of the memory
+/// of [`FloatPayloadType`] values, at the cost of resolution. Range conditions are applied to the
+/// rounded values, so values within half of the precision outside of the range may match, and
+/// values within half of the precision inside of the range may not. Values with more multiples of
+/// the precision than [`ScaledFloatType`] can hold are rejected.
+///
+/// The precision is persisted along with the index, and must match the configured one on load.
+pub struct ScaledFloatIndex {
+    index: NumericIndex<ScaledFloatType, FloatPayloadType>,
+    precision: f64,
+    config_path: PathBuf,
+}
+
+impl ScaledFloatIndex {
+    /// Load immutable mmap based index, either in RAM or on disk
+    pub fn new_mmap(
+        path: &Path,
+        is_on_disk: bool,
+        deleted_points: &BitSlice,
+        precision: f64,
+    ) -> OperationResult<Option<Self>> {
+        if !Self::check_precision(path, precision)? {
+            return Ok(None);
+        }
+        let index = NumericIndex::new_mmap(path, is_on_disk, deleted_points)?;
+        Ok(index.map(|index| Self::new(index, path, precision)))
+    }
+
+    pub fn new_gridstore(
+        dir: PathBuf,
+        create_if_missing: bool,
+        precision: f64,
+    ) -> OperationResult<Option<Self>> {
+        if !Self::check_precision(&dir, precision)? {
+            if !create_if_missing {
+                return Ok(None);
+            }
+            Self::save_precision(&dir, precision)?;
+        }
+        let index = NumericIndex::new_gridstore(dir.clone(), create_if_missing)?;
+        Ok(index.map(|index| Self::new(index, &dir, precision)))
+    }
+
+    fn new(
+        index: NumericIndex<ScaledFloatType, FloatPayloadType>,
+        dir: &Path,
+        precision: f64,
+    ) -> Self {
+        Self {
+            index,
+            precision,
+            config_path: dir.join(CONFIG_PATH),
+        }
+    }
+
+    fn save_precision(dir: &Path, precision: f64) -> OperationResult<()> {
+        fs::create_dir_all(dir)?;
+        atomic_save_json(
+            &dir.join(CONFIG_PATH),
+            &ScaledFloatIndexConfig { precision },
+        )?;
+        Ok(())
+    }
+
+    /// Check that the index stored in `dir` has the configured `precision`.
+    ///
+    /// Returns `false` if there is no stored index.
+    fn check_precision(dir: &Path, precision: f64) -> OperationResult<bool> {
+        let config_path = dir.join(CONFIG_PATH);
+        // If config doesn't exist, assume the index doesn't exist on disk
+        if !config_path.is_file() {
+            return Ok(false);
+        }
+        let config: ScaledFloatIndexConfig = read_json(&config_path)?;
+        if config.precision != precision {
+            return Err(OperationError::service_error(format!(
+                "Scaled float index at {} is stored with precision {}, but precision {precision} \
+                 is configured",
+                dir.display(),
+                config.precision,
+            )));
+        }
+        Ok(true)
+    }
+
+    pub fn builder_mmap(
+        path: &Path,
+        is_on_disk: bool,
+        deleted_points: &BitSlice,
+        precision: f64,
+    ) -> ScaledFloatIndexMmapBuilder {
+        ScaledFloatIndexMmapBuilder {
+            path: path.to_owned(),
+            in_memory_index: InMemoryNumericIndex::default(),
+            is_on_disk,
+            deleted_points: deleted_points.to_owned(),
+            precision,
+        }
+    }
+
+    pub fn builder_gridstore(dir: PathBuf, precision: f64) -> ScaledFloatIndexGridstoreBuilder {
+        ScaledFloatIndexGridstoreBuilder {
+            dir,
+            precision,
+            index: None,
+        }
+    }
+
+    pub fn inner(&self) -> &NumericIndexInner<ScaledFloatType> {
+        self.index.inner()
+    }
+
+    pub fn precision(&self) -> f64 {
+        self.precision
+    }
+
+    /// Value, which the stored multiple of the precision stands for
+    pub fn unscale_value(&self, value: ScaledFloatType) -> FloatPayloadType {
+        f64::from(value) * self.precision
+    }
+
+    /// Range of the stored multiples of the precision, which stand for values within `range`.
+    ///
+    /// Returns `None` if no stored value can be within the range.
+    pub fn scale_range(
+        &self,
+        range: &Range<OrderedFloat<FloatPayloadType>>,
+    ) -> Option<Range<ScaledFloatType>> {
+        let min = f64::from(ScaledFloatType::MIN);
+        let max = f64::from(ScaledFloatType::MAX);
+
+        // Multiples of the precision at or below, and at or above, the bound
+        let steps = |OrderedFloat(bound): OrderedFloat<FloatPayloadType>| {
+            let steps = bound / self.precision;
+            let nearest = steps.round();
+            if (steps - nearest).abs() <= BOUND_TOLERANCE * nearest.abs().max(1.0) {
+                (nearest, nearest)
+            } else {
+                (steps.floor(), steps.ceil())
+            }
+        };
+
+        let Range { lt, gt, gte, lte } = *range;
+        if [lt, gt, gte, lte]
+            .iter()
+            .flatten()
+            .any(|bound| bound.0.is_nan())
+        {
+            return None;
+        }
+
+        let mut scaled = Range::default();
+        if let Some((_, ceil)) = gte.map(steps) {
+            if ceil > max {
+                return None;
+            }
+            scaled.gte = (ceil >= min).then_some(ceil as ScaledFloatType);
+        }
+        if let Some((floor, _)) = gt.map(steps) {
+            if floor >= max {
+                return None;
+            }
+            scaled.gt = (floor >= min).then_some(floor as ScaledFloatType);
+        }
+        if let Some((floor, _)) = lte.map(steps) {
+            if floor < min {
+                return None;
+            }
+            scaled.lte = (floor <= max).then_some(floor as ScaledFloatType);
+        }
+        if let Some((_, ceil)) = lt.map(steps) {
+            if ceil <= min {
+                return None;
+            }
+            scaled.lt = (ceil <= max).then_some(ceil as ScaledFloatType);
+        }
+        Some(scaled)
+    }
+
+    pub fn get_values(
+        &self,
+        idx: PointOffsetType,
+    ) -> Option<Box<dyn Iterator<Item = FloatPayloadType> + '_>> {
+        let values = self.index.get_values(idx)?;
+        Some(Box::new(values.map(|value| self.unscale_value(value))))
+    }
+
+    /// Values and points within the `range`, sorted by value
+    pub fn stream_range(
+        &self,
+        range: &RangeInterface,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = (FloatPayloadType, PointOffsetType)> + '_>
+    {
+        let scaled_range = match range {
+            RangeInterface::Float(range) => self.scale_range(range),
+            RangeInterface::DateTime(_) | RangeInterface::Uuid(_) => None,
+        };
+        let values = scaled_range
+            .map(|range| {
+                let range = range.map(|value| OrderedFloat(f64::from(value)));
+                self.index.inner.stream_range(&RangeInterface::Float(range))
+            })
+            .transpose()?;
+        Ok(values
+            .into_iter()
+            .flatten()
+            .map(|(value, idx)| (self.unscale_value(value), idx)))
+    }
+
+    pub fn values_count(&self, idx: PointOffsetType) -> usize {
+        self.index.values_count(idx)
+    }
+
+    pub fn values_is_empty(&self, idx: PointOffsetType) -> bool {
+        self.index.values_is_empty(idx)
+    }
+
+    /// Values sampled at about evenly spaced positions of the sorted values
+    pub fn value_samples(&self) -> ValueSamples {
+        let ValueSamples { samples } = self.index.inner.value_samples();
+        let samples = samples
+            .into_iter()
+            .map(|ValueSample { value, count }| ValueSample {
+                value: value * self.precision,
+                count,
+            })
+            .collect();
+        ValueSamples { samples }
+    }
+
+    pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
+        self.index.get_telemetry_data()
+    }
+
+    pub fn get_mutability_type(&self) -> IndexMutability {
+        self.index.get_mutability_type()
+    }
+
+    pub fn get_storage_type(&self) -> StorageType {
+        self.index.get_storage_type()
+    }
+
+    pub fn ram_usage_bytes(&self) -> usize {
+        self.index.inner.ram_usage_bytes()
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        self.index.is_on_disk()
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        self.index.populate()
+    }
+
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        self.index.populate_with_budget(budget)
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.index.clear_cache()
+    }
+
+    /// Estimate cardinality of the `condition`, with the range statistics computed on first use.
+    fn estimate_condition_cardinality(
+        &self,
+        condition: &FieldCondition,
+        stats: &mut Option<RangeCardinalityStats>,
+    ) -> OperationResult<Option<CardinalityEstimation>> {
+        let Some(RangeInterface::Float(range)) = &condition.range else {
+            return Ok(None);
+        };
+
+        let cardinality = match self.scale_range(range) {
+            Some(range) => {
+                let stats = match stats {
+                    Some(stats) => *stats,
+                    None => *stats.insert(self.index.inner.range_cardinality_stats()?),
+                };
+                self.index.inner.values_range_cardinality(&range, stats)
+            }
+            None => CardinalityEstimation::exact(0),
+        };
+        Ok(Some(cardinality.with_primary_clause(
+            PrimaryCondition::Condition(Box::new(condition.clone())),
+        )))
+    }
+}
+
+impl PayloadFieldIndex for ScaledFloatIndex {
+    fn count_indexed_points(&self) -> usize {
+        self.index.inner.get_points_count()
+    }
+
+    fn wipe(self) -> OperationResult<()> {
+        // Remove the config first, so that the directory of the index is empty after wiping it
+        fs::remove_file(&self.config_path)?;
+        self.index.wipe()
+    }
+
+    fn flusher(&self) -> Flusher {
+        self.index.inner.flusher()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.index.inner.files();
+        files.push(self.config_path.clone());
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        let mut files = self.index.inner.immutable_files();
+        files.push(self.config_path.clone());
+        files
+    }
+
+    fn filter<'a>(
+        &'a self,
+        condition: &FieldCondition,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> OperationResult<Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>>> {
+        let Some(RangeInterface::Float(range)) = &condition.range else {
+            return Ok(None);
+        };
+        let Some(range) = self.scale_range(range) else {
+            return Ok(Some(Box::new(std::iter::empty())));
+        };
+        Ok(Some(self.index.inner.range_points(&range, hw_counter)?))
+    }
+
+    fn estimate_cardinality(
+        &self,
+        condition: &FieldCondition,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<CardinalityEstimation>> {
+        self.estimate_condition_cardinality(condition, &mut None)
+    }
+
+    fn estimate_cardinality_batch(
+        &self,
+        conditions: &[&FieldCondition],
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<Option<CardinalityEstimation>>> {
+        let mut stats = None;
+        conditions
+            .iter()
+            .map(|condition| self.estimate_condition_cardinality(condition, &mut stats))
+            .collect()
+    }
+
+    fn for_each_payload_block(
+        &self,
+        threshold: usize,
+        key: PayloadKeyType,
+        f: &mut dyn FnMut(PayloadBlockCondition) -> OperationResult<()>,
+    ) -> OperationResult<()> {
+        // Blocks are split by the stored multiples, condition them on the values they stand for
+        self.index.inner.for_each_payload_block(
+            threshold,
+            key,
+            &mut |mut block: PayloadBlockCondition| {
+                if let Some(RangeInterface::Float(range)) = &mut block.condition.range {
+                    *range = range.map(|OrderedFloat(value)| OrderedFloat(value * self.precision));
+                }
+                f(block)
+            },
+        )
+    }
+}
+
+impl ValueIndexer for ScaledFloatIndex {
+    type ValueType = FloatPayloadType;
+
+    fn add_many(
+        &mut self,
+        id: PointOffsetType,
+        values: Vec<FloatPayloadType>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let values = values
+            .into_iter()
+            .map(|value| scale_value(value, self.precision))
+            .collect::<OperationResult<_>>()?;
+        match &mut self.index.inner {
+            NumericIndexInner::Mutable(index) => index.add_many_to_list(id, values, hw_counter),
+            NumericIndexInner::Immutable(_) => Err(OperationError::service_error(
+                "Can't add values to immutable numeric index",
+            )),
+            NumericIndexInner::Mmap(_) => Err(OperationError::service_error(
+                "Can't add values to mmap numeric index",
+            )),
+        }
+    }
+
+    fn get_value(value: &Value) -> Option<FloatPayloadType> {
+        value.as_f64()
+    }
+
+    fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        self.index.inner.remove_point(id)
+    }
+
+    fn remove_points(&mut self, ids: &[PointOffsetType]) -> OperationResult<()> {
+        self.index.inner.remove_points(ids)
+    }
+}
+
+pub struct ScaledFloatIndexMmapBuilder {
+    path: PathBuf,
+    in_memory_index: InMemoryNumericIndex<ScaledFloatType>,
+    is_on_disk: bool,
+    deleted_points: BitVec,
+    precision: f64,
+}
+
+impl FieldIndexBuilderTrait for ScaledFloatIndexMmapBuilder {
+    type FieldIndexType = ScaledFloatIndex;
+
+    fn init(&mut self) -> OperationResult<()> {
+        Ok(())
+    }
+
+    fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        self.in_memory_index.remove_point(id);
+        let mut flatten_values: Vec<_> = vec![];
+        for value in payload {
+            for value in <ScaledFloatIndex as ValueIndexer>::get_values(value) {
+                flatten_values.push(scale_value(value, self.precision)?);
+            }
+        }
+
+        hw_counter
+            .payload_index_io_write_counter()
+            .incr_delta(size_of_val(&flatten_values));
+
+        self.in_memory_index.add_many_to_list(id, flatten_values);
+        Ok(())
+    }
+
+    fn finalize(self) -> OperationResult<Self::FieldIndexType> {
+        ScaledFloatIndex::save_precision(&self.path, self.precision)?;
+        let inner = MmapNumericIndex::build(
+            self.in_memory_index,
+            &self.path,
+            self.is_on_disk,
+            &self.deleted_points,
+        )?;
+        let index = NumericIndex {
+            inner: NumericIndexInner::Mmap(inner),
+            _phantom: PhantomData,
+        };
+        Ok(ScaledFloatIndex::new(index, &self.path, self.precision))
+    }
+}
+
+pub struct ScaledFloatIndexGridstoreBuilder {
+    dir: PathBuf,
+    precision: f64,
+    index: Option<ScaledFloatIndex>,
+}
+
+impl FieldIndexBuilderTrait for ScaledFloatIndexGridstoreBuilder {
+    type FieldIndexType = ScaledFloatIndex;
+
+    fn init(&mut self) -> OperationResult<()> {
+        assert!(
+            self.index.is_none(),
+            "index must be initialized exactly once",
+        );
+        self.index.replace(
+            ScaledFloatIndex::new_gridstore(self.dir.clone(), true, self.precision)?
+                // unwrap safety: cannot fail because create_if_missing is true
+                .unwrap(),
+        );
+        Ok(())
+    }
+
+    fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &[&Value],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let Some(index) = &mut self.index else {
+            return Err(OperationError::service_error(
+                "ScaledFloatIndexGridstoreBuilder: index must be initialized before adding points",
+            ));
+        };
+        index.add_point(id, payload, hw_counter)
+    }
+
+    fn finalize(mut self) -> OperationResult<Self::FieldIndexType> {
+        let Some(index) = self.index.take() else {
+            return Err(OperationError::service_error(
+                "ScaledFloatIndexGridstoreBuilder: index must be initialized to finalize",
+            ));
+        };
+        index.index.inner.flusher()()?;
+        Ok(index)
+    }
+}
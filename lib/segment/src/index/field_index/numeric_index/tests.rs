@@ -5,7 +5,12 @@ use rand::{RngExt, SeedableRng};
 use rstest::rstest;
 use tempfile::{Builder, TempDir};
 
+use super::scaled_float_index::ScaledFloatIndex;
 use super::*;
+use crate::data_types::order_by::OrderValue;
+use crate::index::field_index::{
+    FieldIndex, FieldIndexBuilder, NumericAggregate, NumericFieldIndex,
+};
 use crate::json_path::JsonPath;
 
 /// Generous default size for the deleted-points bitslice used in tests.
@@ -276,6 +281,66 @@ fn test_cardinality_exp(#[case] index_type: IndexType) {
     );
 }
 
+#[rstest]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_cardinality_batch(#[case] index_type: IndexType) {
+    let (_temp_dir, index) = random_index(1000, 2, index_type);
+    let key = JsonPath::new("unused");
+
+    let mut conditions = (0..10)
+        .map(|i| {
+            let range = Range {
+                lt: Some(OrderedFloat(i as f64 * 10.0 + 25.0)),
+                gt: None,
+                gte: Some(OrderedFloat(i as f64 * 10.0)),
+                lte: None,
+            };
+            FieldCondition::new_range(key.clone(), range)
+        })
+        .collect_vec();
+    conditions.push(FieldCondition::new_match(
+        key.clone(),
+        Match::Value(MatchValue {
+            value: ValueVariants::String(Uuid::from_u128(42).to_string()),
+        }),
+    ));
+    conditions.push(FieldCondition::new_match(key, Match::from(true)));
+    let conditions = conditions.iter().collect_vec();
+
+    let single_acc = HwMeasurementAcc::new();
+    let single_counter = single_acc.get_counter_cell();
+    let expected = conditions
+        .iter()
+        .map(|condition| {
+            index
+                .inner()
+                .estimate_cardinality(condition, &single_counter)
+                .unwrap()
+        })
+        .collect_vec();
+
+    let batch_acc = HwMeasurementAcc::new();
+    let batch_counter = batch_acc.get_counter_cell();
+    let estimations = index
+        .inner()
+        .estimate_cardinality_batch(&conditions, &batch_counter)
+        .unwrap();
+
+    assert_eq!(estimations, expected);
+    assert!(estimations[..11].iter().all(Option::is_some));
+    assert!(estimations[11].is_none());
+
+    drop(single_counter);
+    drop(batch_counter);
+    assert!(batch_acc.get_payload_index_io_read() > 0);
+    assert_eq!(
+        batch_acc.get_payload_index_io_read(),
+        single_acc.get_payload_index_io_read(),
+    );
+}
+
 #[rstest]
 #[case(IndexType::MutableGridstore)]
 #[case(IndexType::Mmap)]
@@ -786,6 +851,140 @@ fn test_numeric_index_reload_short_deleted_bitslice(#[case] index_type: IndexTyp
     assert_eq!(index.inner().get_points_count(), 7);
 }
 
+#[rstest]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_numeric_index_rebuild_histogram(#[case] index_type: IndexType) {
+    let (temp_dir, index) = random_index(1000, 2, index_type);
+    let query = Range {
+        gt: None,
+        gte: Some(10.0),
+        lt: Some(60.0),
+        lte: None,
+    };
+    cardinality_request(&index, query, HwMeasurementAcc::new());
+    let total_count = index.inner().get_histogram().get_total_count();
+    drop(index);
+
+    // Damaged histogram is rebuilt from the stored pairs
+    std::fs::write(temp_dir.path().join("histogram_borders.bin"), b"damaged").unwrap();
+    let index = open_index_from_disk(temp_dir.path(), index_type, &empty_deleted());
+    cardinality_request(&index, query, HwMeasurementAcc::new());
+    assert_eq!(index.inner().get_histogram().get_total_count(), total_count);
+
+    // Rebuilt histogram is saved, and loaded as is
+    let reopened = open_index_from_disk(temp_dir.path(), index_type, &empty_deleted());
+    assert_eq!(
+        reopened.inner().get_histogram(),
+        index.inner().get_histogram()
+    );
+}
+
+#[rstest]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_remove_points(#[case] index_type: IndexType) {
+    let (_temp_dir, mut one_by_one) = random_index(1000, 2, index_type);
+    let (_other_temp_dir, mut batch) = random_index(1000, 2, index_type);
+
+    // Duplicated and unknown ids are allowed
+    let mut rng = StdRng::seed_from_u64(43);
+    let mut ids: Vec<PointOffsetType> = (0..400).map(|_| rng.random_range(0..1000)).collect();
+    ids.extend([5, 5, 2000]);
+
+    for &id in &ids {
+        one_by_one.mut_inner().remove_point(id).unwrap();
+    }
+    batch.mut_inner().remove_points(&ids).unwrap();
+
+    assert_eq!(
+        batch.inner().get_points_count(),
+        one_by_one.inner().get_points_count()
+    );
+    // Values may be removed in another order, so only the totals of the histograms match
+    assert_eq!(
+        batch.inner().get_histogram().get_total_count(),
+        one_by_one.inner().get_histogram().get_total_count(),
+    );
+    for &id in &ids {
+        assert_eq!(batch.values_count(id), 0);
+    }
+
+    let hw_counter = HardwareCounterCell::new();
+    let range = Range {
+        lt: Some(OrderedFloat(60.0)),
+        gt: None,
+        gte: Some(OrderedFloat(10.0)),
+        lte: None,
+    };
+    let condition = FieldCondition::new_range(JsonPath::new("unused"), range);
+    let filter = |index: &NumericIndex<FloatPayloadType, FloatPayloadType>| {
+        let points = index
+            .inner()
+            .filter(&condition, &hw_counter)
+            .unwrap()
+            .unwrap();
+        points.sorted().dedup().collect_vec()
+    };
+    assert_eq!(filter(&batch), filter(&one_by_one));
+
+    let query = Range {
+        gt: None,
+        gte: Some(10.0),
+        lt: Some(60.0),
+        lte: None,
+    };
+    cardinality_request(&batch, query, HwMeasurementAcc::new());
+}
+
+#[rstest]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_aggregate(#[case] index_type: IndexType) {
+    let num_points = 1000;
+    let (_temp_dir, index) = random_index(num_points, 2, index_type);
+    let numeric_index = NumericFieldIndex::FloatIndex(index.inner());
+
+    let expected = |ids: &mut dyn Iterator<Item = PointOffsetType>| {
+        let values = ids
+            .flat_map(|id| index.get_values(id).unwrap())
+            .collect_vec();
+        let min = values.iter().copied().min_by(f64::total_cmp);
+        let max = values.iter().copied().max_by(f64::total_cmp);
+        (
+            min.map(OrderValue::Float),
+            max.map(OrderValue::Float),
+            values.len(),
+        )
+    };
+
+    let all = numeric_index.aggregate(None).unwrap();
+    let (min, max, count) = expected(&mut (0..num_points as PointOffsetType));
+    assert_eq!((all.min, all.max, all.count), (min, max, count));
+
+    let mut ids = BitVec::repeat(false, num_points);
+    for id in (0..num_points).step_by(7) {
+        ids.set(id, true);
+    }
+    let subset = numeric_index.aggregate(Some(&ids)).unwrap();
+    let (min, max, count) = expected(&mut ids.iter_ones().map(|id| id as PointOffsetType));
+    assert_eq!((subset.min, subset.max, subset.count), (min, max, count));
+    let sum: f64 = ids
+        .iter_ones()
+        .flat_map(|id| index.get_values(id as PointOffsetType).unwrap())
+        .sum();
+    assert!((subset.sum - sum).abs() < 1e-6);
+    assert!((subset.avg().unwrap() - sum / count as f64).abs() < 1e-6);
+
+    let empty = numeric_index
+        .aggregate(Some(&BitVec::repeat(false, num_points)))
+        .unwrap();
+    assert_eq!(empty, NumericAggregate::default());
+    assert_eq!(empty.avg(), None);
+}
+
 fn test_cond<
     T: Encodable + Numericable + PartialOrd + Clone + StoredValue + Send + Sync + Default + 'static,
 >(
@@ -844,6 +1043,92 @@ fn test_empty_cardinality(#[case] index_type: IndexType) {
     );
 }
 
+#[rstest]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+fn test_uuid_range(#[case] index_type: IndexType) {
+    let temp_dir = Builder::new().prefix("test_uuid_index").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let uuids = [
+        "01900000-0000-7000-8000-000000000000", // UUIDv7, earliest
+        "01910000-0000-7000-8000-000000000000", // UUIDv7, later
+        "01920000-0000-7000-8000-000000000000", // UUIDv7, latest
+        "550e8400-e29b-41d4-a716-446655440000", // UUIDv4, compared by value
+        "00000000-0000-0000-0000-000000000001",
+    ];
+    let uuid = |id: usize| Uuid::parse_str(uuids[id]).unwrap();
+
+    let values = uuids.map(Value::from);
+    let index: NumericIndex<UuidIntType, UuidPayloadType> = match index_type {
+        IndexType::MutableGridstore => {
+            let mut builder = NumericIndex::builder_gridstore(temp_dir.path().to_path_buf());
+            builder.init().unwrap();
+            for (id, value) in values.iter().enumerate() {
+                builder
+                    .add_point(id as PointOffsetType, &[value], &hw_counter)
+                    .unwrap();
+            }
+            builder.finalize().unwrap()
+        }
+        IndexType::Mmap | IndexType::RamMmap => {
+            let mut builder = NumericIndex::builder_mmap(temp_dir.path(), false, &empty_deleted());
+            builder.init().unwrap();
+            for (id, value) in values.iter().enumerate() {
+                builder
+                    .add_point(id as PointOffsetType, &[value], &hw_counter)
+                    .unwrap();
+            }
+            builder.finalize().unwrap()
+        }
+    };
+
+    let filter = |range: Range<UuidPayloadType>| {
+        let condition = FieldCondition::new_uuid_range(JsonPath::new("unused"), range);
+        let estimation = index
+            .inner()
+            .estimate_cardinality(&condition, &hw_counter)
+            .unwrap()
+            .unwrap();
+        let matched = index
+            .inner()
+            .filter(&condition, &hw_counter)
+            .unwrap()
+            .unwrap()
+            .sorted()
+            .collect_vec();
+        assert!(estimation.min <= matched.len() && matched.len() <= estimation.max);
+        matched
+    };
+
+    // Half-open ranges
+    let created_after = Range {
+        gt: Some(uuid(0)),
+        ..Default::default()
+    };
+    assert_eq!(filter(created_after), vec![1, 2, 3]);
+
+    let created_until = Range {
+        lte: Some(uuid(1)),
+        ..Default::default()
+    };
+    assert_eq!(filter(created_until), vec![0, 1, 4]);
+
+    let between = Range {
+        gte: Some(uuid(1)),
+        lt: Some(uuid(3)),
+        ..Default::default()
+    };
+    assert_eq!(filter(between), vec![1, 2]);
+
+    let empty = Range {
+        gt: Some(uuid(2)),
+        lt: Some(uuid(1)),
+        ..Default::default()
+    };
+    assert_eq!(filter(empty), Vec::<PointOffsetType>::new());
+}
+
 /// Reopen an immutable numeric index with an id-tracker deletion bitslice and
 /// verify the deleted points are excluded from counts and queries. Locks the
 /// open-time deletion path that the milestone-49 fix exposed in the map index
@@ -903,3 +1188,186 @@ fn test_remove_reopen() {
     assert_eq!(index.values_count(2), 1);
     assert_eq!(index.values_count(3), 0);
 }
+
+fn scaled_float_index_builder(
+    index_type: IndexType,
+    path: &Path,
+    precision: f64,
+) -> FieldIndexBuilder {
+    let mut builder = match index_type {
+        IndexType::MutableGridstore => FieldIndexBuilder::ScaledFloatGridstoreIndex(
+            ScaledFloatIndex::builder_gridstore(path.to_path_buf(), precision),
+        ),
+        IndexType::Mmap | IndexType::RamMmap => FieldIndexBuilder::ScaledFloatMmapIndex(
+            ScaledFloatIndex::builder_mmap(path, false, &empty_deleted(), precision),
+        ),
+    };
+    builder.init().unwrap();
+    builder
+}
+
+fn open_scaled_float_index(
+    index_type: IndexType,
+    path: &Path,
+    precision: f64,
+) -> OperationResult<Option<ScaledFloatIndex>> {
+    let deleted = empty_deleted();
+    match index_type {
+        IndexType::MutableGridstore => {
+            ScaledFloatIndex::new_gridstore(path.to_path_buf(), false, precision)
+        }
+        IndexType::Mmap => ScaledFloatIndex::new_mmap(path, true, &deleted, precision),
+        IndexType::RamMmap => ScaledFloatIndex::new_mmap(path, false, &deleted, precision),
+    }
+}
+
+#[rstest]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_scaled_float_index(#[case] index_type: IndexType) {
+    let temp_dir = Builder::new()
+        .prefix("test_scaled_float_index")
+        .tempdir()
+        .unwrap();
+    let mut builder = scaled_float_index_builder(index_type, temp_dir.path(), 0.5);
+    let hw_counter = HardwareCounterCell::new();
+
+    let values = [1.2, 1.3, 2.7, 10.0, -0.8];
+    for (id, value) in values.into_iter().enumerate() {
+        builder
+            .add_point(id as PointOffsetType, &[&Value::from(value)], &hw_counter)
+            .unwrap();
+    }
+
+    // Too many multiples of the precision to be stored
+    let too_large = Value::from(1e300);
+    assert!(
+        builder
+            .add_point(values.len() as PointOffsetType, &[&too_large], &hw_counter)
+            .is_err()
+    );
+
+    drop(builder.finalize().unwrap());
+
+    let index = open_scaled_float_index(index_type, temp_dir.path(), 0.5)
+        .unwrap()
+        .unwrap();
+
+    let rounded = (0..values.len() as PointOffsetType)
+        .map(|id| index.get_values(id).unwrap().collect_vec())
+        .collect_vec();
+    assert_eq!(
+        rounded,
+        vec![vec![1.0], vec![1.5], vec![2.5], vec![10.0], vec![-1.0]],
+    );
+
+    // The range is applied to the rounded values, point 0 has value 1.2 but is stored as 1.0
+    let range = Range {
+        lt: None,
+        gt: None,
+        gte: Some(OrderedFloat(1.1)),
+        lte: Some(OrderedFloat(2.6)),
+    };
+    let condition = FieldCondition::new_range(JsonPath::new("unused"), range);
+    let matched = index
+        .filter(&condition, &hw_counter)
+        .unwrap()
+        .unwrap()
+        .sorted()
+        .collect_vec();
+    assert_eq!(matched, vec![1, 2]);
+
+    let estimation = index
+        .estimate_cardinality(&condition, &hw_counter)
+        .unwrap()
+        .unwrap();
+    assert!(estimation.min <= matched.len() && matched.len() <= estimation.max);
+    assert_eq!(
+        estimation.primary_clauses,
+        vec![PrimaryCondition::Condition(Box::new(condition))],
+    );
+
+    // No value beyond the stored multiples of the precision
+    let beyond = FieldCondition::new_range(
+        JsonPath::new("unused"),
+        Range {
+            gt: Some(OrderedFloat(1e300)),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        index.filter(&beyond, &hw_counter).unwrap().unwrap().count(),
+        0
+    );
+
+    drop(index);
+
+    // The precision is stored with the index, it doesn't load with another one
+    assert!(open_scaled_float_index(index_type, temp_dir.path(), 0.25).is_err());
+}
+
+#[test]
+fn test_scaled_float_range() {
+    let temp_dir = Builder::new()
+        .prefix("test_scaled_float_range")
+        .tempdir()
+        .unwrap();
+    let builder = scaled_float_index_builder(IndexType::MutableGridstore, temp_dir.path(), 0.1);
+    let FieldIndex::ScaledFloatIndex(index) = builder.finalize().unwrap() else {
+        panic!("Expected scaled float index");
+    };
+    let scale = |range: Range<FloatPayloadType>| index.scale_range(&range.map(OrderedFloat));
+
+    // Bounds on multiples of the precision are kept, despite the rounding error of the division
+    assert_eq!(
+        scale(Range {
+            gte: Some(0.3),
+            lt: Some(0.7),
+            ..Default::default()
+        }),
+        Some(Range {
+            gte: Some(3),
+            lt: Some(7),
+            ..Default::default()
+        }),
+    );
+
+    // Other bounds are narrowed to the multiples within the range
+    assert_eq!(
+        scale(Range {
+            gt: Some(0.25),
+            lte: Some(0.75),
+            ..Default::default()
+        }),
+        Some(Range {
+            gt: Some(2),
+            lte: Some(7),
+            ..Default::default()
+        }),
+    );
+
+    // Bounds beyond the stored multiples either cover all of them, or none
+    assert_eq!(
+        scale(Range {
+            gte: Some(-1e300),
+            lte: Some(1e300),
+            ..Default::default()
+        }),
+        Some(Range::default()),
+    );
+    assert_eq!(
+        scale(Range {
+            gt: Some(1e300),
+            ..Default::default()
+        }),
+        None,
+    );
+    assert_eq!(
+        scale(Range {
+            lt: Some(-1e300),
+            ..Default::default()
+        }),
+        None,
+    );
+}
@@ -68,11 +68,12 @@ const fn derive_point_padding<T: bytemuck::Pod>() -> usize {
     // of PointOffsetType, so that there is no inter-field padding
     assert!(std::mem::align_of::<T>() >= std::mem::align_of::<PointOffsetType>());
 
-    align - (std::mem::size_of::<T>() + std::mem::size_of::<PointOffsetType>()) % align
+    // No padding if the fields already fill the alignment, like for 4 bytes numbers
+    (align - (std::mem::size_of::<T>() + std::mem::size_of::<PointOffsetType>()) % align) % align
 }
 
 /// A trait that should represent common properties of integer and floating point types.
-/// In particular, i64 and f64.
+/// In particular, i64, i32 and f64.
 pub trait Numericable: Num + PartialEq + PartialOrd + Copy + bytemuck::Pod {
     /// This is to be able to derive [`bytemuck::Pod`] for [`Point<T>`], which is required for safe de/serialization.
     ///
@@ -125,6 +126,29 @@ impl Numericable for i64 {
     }
 }
 
+impl Numericable for i32 {
+    type PointPadding = [u8; derive_point_padding::<Self>()];
+
+    fn min_value() -> Self {
+        i32::MIN
+    }
+    fn max_value() -> Self {
+        i32::MAX
+    }
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+    fn from_f64(x: f64) -> Self {
+        x as Self
+    }
+    fn from_u128(x: u128) -> Self {
+        x as i32
+    }
+    fn abs_diff(self, b: Self) -> Self {
+        i32::abs_diff(self, b) as i32
+    }
+}
+
 impl Numericable for f64 {
     type PointPadding = [u8; derive_point_padding::<Self>()];
 
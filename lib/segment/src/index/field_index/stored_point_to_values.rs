@@ -7,6 +7,7 @@ use common::counter::conditioned_counter::ConditionedCounter;
 use common::ext::ResultOptionExt;
 use common::generic_consts::Random;
 use common::mmap::{AdviceSetting, create_and_ensure_length, open_write_mmap};
+use common::populate_budget::{PopulateBudget, PopulateStage};
 use common::types::PointOffsetType;
 use common::universal_io::{self, ReadOnly, ReadRange, UniversalRead};
 use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
@@ -310,6 +311,12 @@ where
         self.store.populate().map_err(Into::into)
     }
 
+    /// Populate the pages in the mmap, which fit into the `budget`.
+    pub fn populate_with_budget(&self, budget: &mut PopulateBudget) -> OperationResult<()> {
+        budget.populate::<u8>(PopulateStage::Data, &self.store)?;
+        Ok(())
+    }
+
     /// Drop disk cache.
     pub fn clear_cache(&self) -> OperationResult<()> {
         let Self {
@@ -281,3 +281,36 @@ fn test_save_load_histogram() {
     let loaded_histogram = Histogram::<f64>::load(dir.path()).unwrap();
     assert_eq!(histogram, loaded_histogram);
 }
+
+#[test]
+fn test_border_samples() {
+    let max_bucket_size = 1000;
+    let precision = 0.01;
+    let num_samples = 100_000;
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let points = (0..num_samples)
+        .map(|i| Point::new(rnd.sample(StandardNormal), i))
+        .collect_vec();
+    let (histogram, points_index) = build_histogram(max_bucket_size, precision, points);
+
+    let samples = histogram.border_samples().collect_vec();
+
+    // Samples stand for all values, and start at the smallest and end at the largest one
+    let total: usize = samples.iter().map(|(_, count)| count).sum();
+    assert_eq!(total, num_samples as usize);
+    assert_eq!(
+        samples.first().unwrap().0,
+        points_index.first().unwrap().val
+    );
+    assert_eq!(samples.last().unwrap().0, points_index.last().unwrap().val);
+
+    // Each sample stands for the values up to the next one
+    for ((value, count), (next_value, _)) in samples.iter().tuple_windows() {
+        let real = points_index
+            .iter()
+            .filter(|point| *value <= point.val && point.val < *next_value)
+            .count();
+        assert_eq!(real, *count);
+    }
+}
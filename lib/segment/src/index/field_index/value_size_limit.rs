@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use serde_json::Value;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::index::OversizedValuePolicy;
+use crate::telemetry::OversizedValuesTelemetry;
+use crate::types::{PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaParams};
+
+/// Limit on the size of string values of a single payload field index.
+///
+/// Very large values, like a whole document in a single keyword, blow up the memory of index
+/// builds. Values larger than the limit are skipped, truncated, or rejected, according to the
+/// [`OversizedValuePolicy`] of the field index.
+#[derive(Debug, Clone, Copy)]
+pub struct ValueSizeLimit {
+    max_bytes: usize,
+    policy: OversizedValuePolicy,
+    /// Truncate at a token boundary, so the index never contains a partial token
+    is_full_text: bool,
+}
+
+impl ValueSizeLimit {
+    /// Size limit configured in the params of the field index, if any
+    pub fn from_schema(schema: &PayloadFieldSchema) -> Option<Self> {
+        let PayloadFieldSchema::FieldParams(params) = schema else {
+            return None;
+        };
+        let (max_bytes, policy, is_full_text) = match params {
+            PayloadSchemaParams::Keyword(params) => (
+                params.max_value_bytes?,
+                params.oversized_value_policy,
+                false,
+            ),
+            PayloadSchemaParams::Text(params) => {
+                (params.max_value_bytes?, params.oversized_value_policy, true)
+            }
+            PayloadSchemaParams::Integer(_)
+            | PayloadSchemaParams::Float(_)
+            | PayloadSchemaParams::Geo(_)
+            | PayloadSchemaParams::Bool(_)
+            | PayloadSchemaParams::Datetime(_)
+            | PayloadSchemaParams::Uuid(_) => return None,
+        };
+        Some(Self {
+            max_bytes,
+            policy: policy.unwrap_or_default(),
+            is_full_text,
+        })
+    }
+
+    /// Apply the limit to the payload values of a single point.
+    ///
+    /// Returns `None` if all values fit into the limit, and can be indexed as they are.
+    pub fn apply(
+        &self,
+        field: PayloadKeyTypeRef,
+        values: &[&Value],
+        stats: &OversizedValueStats,
+    ) -> OperationResult<Option<Vec<Value>>> {
+        let mut counts = OversizedValuesTelemetry::default();
+        let limited = self.limit(field, values, &mut counts)?;
+        if limited.is_some() {
+            stats.record(field, counts);
+        }
+        Ok(limited)
+    }
+
+    /// Payload values of a single point as they are indexed, to check conditions against the
+    /// payload the same way as against the index.
+    ///
+    /// Returns `None` if all values are indexed as they are. With the
+    /// [`OversizedValuePolicy::Error`] policy oversized values are never stored, so they are
+    /// checked as they are.
+    pub fn indexed_values(
+        &self,
+        field: PayloadKeyTypeRef,
+        values: &[&Value],
+    ) -> Option<Vec<Value>> {
+        if self.rejects_oversized() {
+            return None;
+        }
+        let mut counts = OversizedValuesTelemetry::default();
+        self.limit(field, values, &mut counts).ok().flatten()
+    }
+
+    /// Whether oversized values are rejected, see [`Self::check`]
+    pub fn rejects_oversized(&self) -> bool {
+        self.policy == OversizedValuePolicy::Error
+    }
+
+    fn limit(
+        &self,
+        field: PayloadKeyTypeRef,
+        values: &[&Value],
+        counts: &mut OversizedValuesTelemetry,
+    ) -> OperationResult<Option<Vec<Value>>> {
+        let is_oversized =
+            |value: &Value| matches!(value, Value::String(s) if s.len() > self.max_bytes);
+        let has_oversized = values.iter().any(|value| match value {
+            Value::Array(items) => items.iter().any(is_oversized),
+            value => is_oversized(value),
+        });
+        if !has_oversized {
+            return Ok(None);
+        }
+
+        let mut limited = Vec::with_capacity(values.len());
+        for value in values {
+            match value {
+                Value::Array(items) => {
+                    let items = items
+                        .iter()
+                        .map(|item| self.limit_value(field, item, counts))
+                        .collect::<OperationResult<Vec<_>>>()?;
+                    limited.push(Value::Array(items.into_iter().flatten().collect()));
+                }
+                value => limited.extend(self.limit_value(field, value, counts)?),
+            }
+        }
+        Ok(Some(limited))
+    }
+
+    /// Check that the payload values of a point can be indexed, without limiting them.
+    ///
+    /// Only fails with the [`OversizedValuePolicy::Error`] policy.
+    pub fn check(&self, field: PayloadKeyTypeRef, values: &[&Value]) -> OperationResult<()> {
+        if !self.rejects_oversized() {
+            return Ok(());
+        }
+        let mut counts = OversizedValuesTelemetry::default();
+        for value in values {
+            match value {
+                Value::Array(items) => {
+                    for item in items {
+                        self.limit_value(field, item, &mut counts)?;
+                    }
+                }
+                value => {
+                    self.limit_value(field, value, &mut counts)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn limit_value(
+        &self,
+        field: PayloadKeyTypeRef,
+        value: &Value,
+        counts: &mut OversizedValuesTelemetry,
+    ) -> OperationResult<Option<Value>> {
+        let Value::String(string) = value else {
+            return Ok(Some(value.clone()));
+        };
+        if string.len() <= self.max_bytes {
+            return Ok(Some(value.clone()));
+        }
+
+        match self.policy {
+            OversizedValuePolicy::Skip => {
+                counts.skipped += 1;
+                Ok(None)
+            }
+            OversizedValuePolicy::Truncate => match self.truncate(string) {
+                Some(truncated) => {
+                    counts.truncated += 1;
+                    Ok(Some(Value::String(truncated.to_string())))
+                }
+                None => {
+                    counts.skipped += 1;
+                    Ok(None)
+                }
+            },
+            OversizedValuePolicy::Error => Err(OperationError::ValidationError {
+                description: format!(
+                    "Value of payload field `{field}` is {} bytes, which exceeds `max_value_bytes` \
+                     of {} bytes",
+                    string.len(),
+                    self.max_bytes,
+                ),
+            }),
+        }
+    }
+
+    /// Beginning of an oversized `value`, which fits into the limit.
+    ///
+    /// Returns `None` if nothing of the value can be kept, e.g. if the first token of a text
+    /// value alone exceeds the limit.
+    fn truncate<'a>(&self, value: &'a str) -> Option<&'a str> {
+        let mut end = self.max_bytes;
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        let truncated = &value[..end];
+
+        // Unless the cut is between tokens, drop the partial token at the end
+        let truncated = if !self.is_full_text || value[end..].starts_with(char::is_whitespace) {
+            truncated
+        } else {
+            &truncated[..truncated.rfind(char::is_whitespace)?]
+        };
+
+        let truncated = if self.is_full_text {
+            truncated.trim_end()
+        } else {
+            truncated
+        };
+        (!truncated.is_empty()).then_some(truncated)
+    }
+}
+
+/// Number of skipped and truncated oversized values of each field, since the index was loaded
+#[derive(Debug, Default)]
+pub struct OversizedValueStats {
+    counts: Mutex<HashMap<PayloadKeyType, OversizedValuesTelemetry>>,
+}
+
+impl OversizedValueStats {
+    fn record(&self, field: PayloadKeyTypeRef, counts: OversizedValuesTelemetry) {
+        let mut stats = self.counts.lock();
+        let field_counts = stats.entry(field.clone()).or_default();
+        field_counts.skipped += counts.skipped;
+        field_counts.truncated += counts.truncated;
+    }
+
+    pub fn get(&self, field: PayloadKeyTypeRef) -> Option<OversizedValuesTelemetry> {
+        self.counts.lock().get(field).copied()
+    }
+
+    pub fn remove(&self, field: PayloadKeyTypeRef) {
+        self.counts.lock().remove(field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::json_path::JsonPath;
+
+    fn limit(max_bytes: usize, policy: OversizedValuePolicy, is_full_text: bool) -> ValueSizeLimit {
+        ValueSizeLimit {
+            max_bytes,
+            policy,
+            is_full_text,
+        }
+    }
+
+    #[test]
+    fn test_truncate_keyword() {
+        let limit = limit(4, OversizedValuePolicy::Truncate, false);
+        assert_eq!(limit.truncate("abcdef"), Some("abcd"));
+        // Never splits a character
+        assert_eq!(limit.truncate("abcäf"), Some("abc"));
+    }
+
+    #[test]
+    fn test_truncate_text_at_token_boundary() {
+        let limit = limit(12, OversizedValuePolicy::Truncate, true);
+        assert_eq!(limit.truncate("hello world, hello"), Some("hello world,"));
+        assert_eq!(limit.truncate("hello wonderful world"), Some("hello"));
+        assert_eq!(limit.truncate("hello world  again"), Some("hello world"));
+        // The first token alone exceeds the limit
+        assert_eq!(limit.truncate("supercalifragilistic"), None);
+    }
+
+    #[test]
+    fn test_apply_policies() {
+        let field = JsonPath::new("text");
+        let short = json!("short");
+        let long = json!("a very long value");
+        let array = json!(["short", "a very long value"]);
+        let values = [&short, &long, &array];
+
+        let stats = OversizedValueStats::default();
+        let skip = limit(8, OversizedValuePolicy::Skip, true);
+        assert_eq!(skip.apply(&field, &[&short], &stats).unwrap(), None);
+        assert_eq!(
+            skip.apply(&field, &values, &stats).unwrap(),
+            Some(vec![json!("short"), json!(["short"])]),
+        );
+
+        let truncate = limit(8, OversizedValuePolicy::Truncate, true);
+        assert_eq!(
+            truncate.apply(&field, &values, &stats).unwrap(),
+            Some(vec![
+                json!("short"),
+                json!("a very"),
+                json!(["short", "a very"])
+            ]),
+        );
+
+        let counts = stats.get(&field).unwrap();
+        assert_eq!((counts.skipped, counts.truncated), (2, 2));
+
+        let error = limit(8, OversizedValuePolicy::Error, true);
+        assert!(error.apply(&field, &values, &stats).is_err());
+        assert!(error.check(&field, &values).is_err());
+        assert!(error.check(&field, &[&short]).is_ok());
+        assert!(skip.check(&field, &values).is_ok());
+    }
+
+    #[test]
+    fn test_indexed_values() {
+        let field = JsonPath::new("text");
+        let short = json!("short");
+        let long = json!("a very long value");
+
+        let truncate = limit(8, OversizedValuePolicy::Truncate, false);
+        assert_eq!(truncate.indexed_values(&field, &[&short]), None);
+        assert_eq!(
+            truncate.indexed_values(&field, &[&short, &long]),
+            Some(vec![json!("short"), json!("a very l")]),
+        );
+
+        let skip = limit(8, OversizedValuePolicy::Skip, false);
+        assert_eq!(
+            skip.indexed_values(&field, &[&short, &long]),
+            Some(vec![json!("short")]),
+        );
+
+        // Oversized values are never stored with the error policy
+        let error = limit(8, OversizedValuePolicy::Error, false);
+        assert_eq!(error.indexed_values(&field, &[&long]), None);
+    }
+}
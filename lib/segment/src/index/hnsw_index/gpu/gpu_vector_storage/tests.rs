@@ -119,6 +119,7 @@ fn test_gpu_vector_storage_sq(
             always_ram: Some(true),
             r#type: crate::types::ScalarType::Int8,
             quantile: Some(0.99),
+            outliers: None,
         },
     });
 
@@ -56,7 +56,8 @@ use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::vector_index_search_common::{
-    get_oversampled_top, is_quantized_search, postprocess_search_result,
+    QuantizationDistortionStatistics, get_oversampled_top, is_quantized_search, is_rescored_search,
+    postprocess_search_result, search_with_quantization_fallback,
 };
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
 use crate::index::{PayloadIndexRead, VectorIndex, VectorIndexEnum, VectorIndexRead};
@@ -107,6 +108,10 @@ struct HNSWSearchesTelemetry {
     large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
     exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
     exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+    /// Searches, which were retried with larger oversampling because of quantization distortion
+    quantization_fallback: Arc<Mutex<OperationDurationsAggregator>>,
+    /// Distortion of the quantized ranking in rescored searches with oversampling
+    quantization_distortion: Mutex<QuantizationDistortionStatistics>,
 }
 
 impl HNSWSearchesTelemetry {
@@ -119,6 +124,8 @@ impl HNSWSearchesTelemetry {
             large_cardinality: OperationDurationsAggregator::new(),
             exact_filtered: OperationDurationsAggregator::new(),
             exact_unfiltered: OperationDurationsAggregator::new(),
+            quantization_fallback: OperationDurationsAggregator::new(),
+            quantization_distortion: Mutex::default(),
         }
     }
 }
@@ -193,6 +200,12 @@ impl HNSWIndex {
         self.is_on_disk
     }
 
+    /// Distortion of the quantized ranking, observed in rescored searches with oversampling since
+    /// the index was loaded.
+    pub fn quantization_distortion(&self) -> QuantizationDistortionStatistics {
+        *self.searches_telemetry.quantization_distortion.lock()
+    }
+
     #[cfg(test)]
     pub(super) fn graph(&self) -> &GraphLayers {
         &self.graph
@@ -1155,38 +1168,69 @@ impl HNSWIndex {
         };
 
         let regular_search = || -> OperationResult<Vec<ScoredPointOffset>> {
-            let filter_context = filter
-                .map(|f| payload_index.filter_context(f, &hw_counter))
-                .transpose()?;
-            let points_scorer = Self::construct_search_scorer(
-                vector,
-                &vector_storage,
-                quantized_vectors.as_ref(),
-                deleted_points,
-                params,
-                vector_query_context.hardware_counter(),
-                filter_context,
-            )?;
+            let search = |oversampled_top: usize| -> OperationResult<Vec<ScoredPointOffset>> {
+                let filter_context = filter
+                    .map(|f| payload_index.filter_context(f, &hw_counter))
+                    .transpose()?;
+                let points_scorer = Self::construct_search_scorer(
+                    vector,
+                    &vector_storage,
+                    quantized_vectors.as_ref(),
+                    deleted_points,
+                    params,
+                    vector_query_context.hardware_counter(),
+                    filter_context,
+                )?;
 
-            let search_result = self.graph.search(
-                oversampled_top,
-                ef,
-                algorithm,
-                points_scorer,
-                custom_entry_points,
-                &is_stopped,
-            )?;
+                self.graph.search(
+                    oversampled_top,
+                    ef,
+                    algorithm,
+                    points_scorer,
+                    custom_entry_points,
+                    &is_stopped,
+                )
+            };
 
-            postprocess_search_result(
-                search_result,
-                id_tracker.deleted_point_bitslice(),
-                &vector_storage,
-                quantized_vectors.as_ref(),
-                vector,
+            let postprocess = |search_result| {
+                postprocess_search_result(
+                    search_result,
+                    id_tracker.deleted_point_bitslice(),
+                    &vector_storage,
+                    quantized_vectors.as_ref(),
+                    vector,
+                    params,
+                    top,
+                    vector_query_context.hardware_counter(),
+                )
+            };
+
+            let timer = std::time::Instant::now();
+            let (search_result, retried, distortion) = search_with_quantization_fallback(
                 params,
                 top,
-                vector_query_context.hardware_counter(),
-            )
+                oversampled_top,
+                search,
+                postprocess,
+            )?;
+            if retried {
+                self.searches_telemetry
+                    .quantization_fallback
+                    .lock()
+                    .add_operation_result(true, timer.elapsed());
+                if let Some(query_stats) = hw_counter.query_stats() {
+                    query_stats.record_quantization_fallbacks(1);
+                }
+            }
+            // Distortion is only observable, if rescoring can pick results beyond the quantized top
+            if oversampled_top > top && is_rescored_search(quantized_vectors.as_ref(), params) {
+                self.searches_telemetry
+                    .quantization_distortion
+                    .lock()
+                    .add(distortion);
+            }
+
+            Ok(search_result)
         };
 
         // Try to use graph with vectors first.
@@ -1455,6 +1499,7 @@ impl VectorIndexRead for HNSWIndex {
                     ignore: true,
                     rescore: Some(false),
                     oversampling: None,
+                    fallback: None,
                 }); // disable quantization for exact search
                 params
             })
@@ -1592,6 +1637,8 @@ impl VectorIndexRead for HNSWIndex {
             filtered_sparse: Default::default(),
             unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
             unfiltered_sparse: Default::default(),
+            quantization_fallback: tm.quantization_fallback.lock().get_statistics(detail),
+            quantization_distortion: tm.quantization_distortion.lock().mean(),
         }
     }
 
@@ -18,3 +18,4 @@ mod visited_pool;
 
 pub use payload_index_base::*;
 pub use vector_index_base::*;
+pub use vector_index_search_common::QuantizationDistortionStatistics;
@@ -16,6 +16,11 @@ pub struct PayloadConfig {
     /// Mapping of payload index schemas and types
     #[serde(flatten)]
     pub indices: PayloadIndices,
+    /// Per-position indexes, which back positional bool indexes of the `indices`
+    ///
+    /// Kept apart from `indices`, so they never replace an index created on the same key.
+    #[serde(default, skip_serializing_if = "PayloadIndices::is_empty")]
+    pub positional_indices: PayloadIndices,
 }
 
 impl PayloadConfig {
@@ -42,6 +47,10 @@ pub struct PayloadIndices {
 }
 
 impl PayloadIndices {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
     pub fn to_schemas(&self) -> HashMap<PayloadKeyType, PayloadFieldSchema> {
         self.fields
             .iter()
@@ -138,6 +147,7 @@ pub enum PayloadIndexType {
     IntMapIndex,
     KeywordIndex,
     FloatIndex,
+    ScaledFloatIndex,
     GeoIndex,
     FullTextIndex,
     BoolIndex,
@@ -4,7 +4,7 @@ use std::sync::atomic::AtomicBool;
 
 use ahash::AHashMap;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::{PointOffsetType, ScoreType};
+use common::types::{PointOffsetType, ScoreType, TelemetryDetail};
 use serde_json::Value;
 
 use super::field_index::{FacetIndex, FieldIndex, NumericFieldIndexRead};
@@ -91,7 +91,7 @@ pub trait PayloadIndexRead {
     fn facet_index_for(&self, key: &JsonPath) -> Option<impl FacetIndex + '_>;
 
     /// Per-field-index telemetry data.
-    fn get_telemetry_data(&self) -> Vec<PayloadIndexTelemetry>;
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> Vec<PayloadIndexTelemetry>;
 
     /// Build a per-query formula scorer that evaluates the given parsed
     /// formula against this index's payload, using the prefetch scores as
@@ -212,6 +212,18 @@ pub trait PayloadIndex: PayloadIndexRead {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<Payload>>;
 
+    /// Drop all payload of many points at once
+    fn clear_payloads(
+        &mut self,
+        point_ids: &[PointOffsetType],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        for &point_id in point_ids {
+            self.clear_payload(point_id, hw_counter)?;
+        }
+        Ok(())
+    }
+
     /// Return function that forces persistence of current storage state.
     fn flusher(&self) -> Flusher;
 
@@ -7,7 +7,7 @@ use ahash::AHashMap;
 use atomic_refcell::AtomicRefCell;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::iterator_ext::IteratorExt;
-use common::types::{PointOffsetType, ScoreType};
+use common::types::{PointOffsetType, ScoreType, TelemetryDetail};
 use fs_err as fs;
 use schemars::_serde_json::Value;
 
@@ -176,7 +176,7 @@ impl PayloadIndexRead for PlainPayloadIndex {
         None::<FacetIndexEnum<'_>>
     }
 
-    fn get_telemetry_data(&self) -> Vec<PayloadIndexTelemetry> {
+    fn get_telemetry_data(&self, _detail: TelemetryDetail) -> Vec<PayloadIndexTelemetry> {
         // Plain index has no field indexes to report telemetry for.
         Vec::new()
     }
@@ -184,6 +184,8 @@ impl VectorIndexRead for PlainVectorIndex {
             filtered_sparse: Default::default(),
             unfiltered_exact: OperationDurationStatistics::default(),
             unfiltered_sparse: OperationDurationStatistics::default(),
+            quantization_fallback: OperationDurationStatistics::default(),
+            quantization_distortion: None,
         }
     }
 
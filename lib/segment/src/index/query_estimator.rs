@@ -365,6 +365,7 @@ mod tests {
             values_count: None,
             is_empty: None,
             geo_polygon: None,
+            geo_near_path: None,
             is_null: None,
         })
     }
@@ -378,6 +379,8 @@ mod tests {
             Condition::Filter(_) => panic!("unexpected Filter"),
             Condition::Nested(_) => panic!("unexpected Nested"),
             Condition::CustomIdChecker(_) => panic!("unexpected CustomIdChecker"),
+            Condition::UpdatedAfter(_) => panic!("unexpected UpdatedAfter"),
+            Condition::Expression(_) => panic!("unexpected Expression"),
             Condition::Field(field) => match field.key.to_string().as_str() {
                 "color" => CardinalityEstimation {
                     primary_clauses: vec![PrimaryCondition::Condition(Box::new(field.clone()))],
@@ -14,13 +14,15 @@ use crate::index::field_index::null_index::NullIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::payload_storage::condition_checker::ValueChecker;
 use crate::payload_storage::query_checker::{
-    check_field_condition, check_is_empty_condition, check_is_null_condition, check_payload,
-    select_nested_indexes,
+    check_expression_condition, check_field_condition, check_is_empty_condition,
+    check_is_null_condition, check_payload, select_nested_indexes, select_nested_value_size_limits,
 };
 use crate::types::{
-    Condition, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPolygon,
-    GeoRadius, IntPayloadType, OwnedPayloadRef, PayloadContainer, Range, RangeInterface,
+    Condition, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoNearPath,
+    GeoPolygon, GeoRadius, IntPayloadType, NullElements, OwnedPayloadRef, PayloadContainer, Range,
+    RangeInterface, UuidPayloadType,
 };
 use crate::vector_storage::VectorStorageRead;
 
@@ -46,12 +48,37 @@ impl StructPayloadIndex {
                 })
                 .unwrap_or_else(|| {
                     let hw = hw_counter.fork();
+
+                    // Convert the polygon once for all points, it may be simplified
+                    if let Some(geo_polygon) = &field_condition.geo_polygon {
+                        let polygon_wrapper = geo_polygon.convert();
+                        return Box::new(move |point_id| {
+                            payload_provider.with_payload(
+                                point_id,
+                                |payload| {
+                                    payload
+                                        .get_value(&field_condition.key)
+                                        .into_iter()
+                                        .any(|value| polygon_wrapper.check(value))
+                                },
+                                &hw,
+                            )
+                        });
+                    }
+
+                    let value_size_limit = self.value_size_limit(&field_condition.key);
                     Box::new(move |point_id| {
                         payload_provider.with_payload(
                             point_id,
                             |payload| {
-                                check_field_condition(field_condition, &payload, field_indexes, &hw)
-                                    .unwrap(/* TODO(uio): handle errors */)
+                                check_field_condition(
+                                    field_condition,
+                                    &payload,
+                                    field_indexes,
+                                    value_size_limit,
+                                    &hw,
+                                )
+                                .unwrap(/* TODO(uio): handle errors */)
                             },
                             &hw,
                         )
@@ -91,10 +118,12 @@ impl StructPayloadIndex {
             Condition::IsNull(is_null) => {
                 let field_indexes = field_indexes.get(&is_null.is_null.key);
 
+                let null_elements = is_null.null_elements.unwrap_or_default();
+
                 let is_null_checker = field_indexes.and_then(|field_indexes| {
                     field_indexes
                         .iter()
-                        .find_map(|index| get_is_null_checker(index, true))
+                        .find_map(|index| get_null_elements_checker(index, null_elements))
                 });
 
                 if let Some(checker) = is_null_checker {
@@ -129,6 +158,24 @@ impl StructPayloadIndex {
                     Box::new(|_point_id| false)
                 }
             }
+            Condition::UpdatedAfter(updated_after) => {
+                let updated_after = *updated_after;
+                Box::new(move |point_id| {
+                    updated_after.check(self.id_tracker.borrow().internal_version(point_id))
+                })
+            }
+            // Indexes are only used to narrow down the candidates, the expression is always
+            // evaluated on the payload to consistently pick the first value of the fields
+            Condition::Expression(expression) => {
+                let hw = hw_counter.fork();
+                Box::new(move |point_id| {
+                    payload_provider.with_payload(
+                        point_id,
+                        |payload| check_expression_condition(expression, &payload),
+                        &hw,
+                    )
+                })
+            }
             Condition::Nested(nested) => {
                 // Select indexes for nested fields. Trim nested part from key, so
                 // that nested condition can address fields without nested part.
@@ -151,6 +198,8 @@ impl StructPayloadIndex {
                 let nested_path = nested.array_key();
 
                 let nested_indexes = select_nested_indexes(&nested_path, field_indexes);
+                let nested_value_size_limits =
+                    select_nested_value_size_limits(&nested_path, &self.value_size_limits());
 
                 let hw = hw_counter.fork();
                 Box::new(move |point_id| {
@@ -172,6 +221,7 @@ impl StructPayloadIndex {
                                         &nested.nested.filter,
                                         point_id,
                                         &nested_indexes,
+                                        &nested_value_size_limits,
                                         &hw,
                                     ) {
                                         // If at least one nested object matches, return true
@@ -230,6 +280,11 @@ pub fn field_condition_index<'a>(
             ..
         } => get_geo_polygon_checkers(index, geo_polygon.clone(), hw_acc),
 
+        FieldCondition {
+            geo_near_path: Some(geo_near_path),
+            ..
+        } => get_geo_near_path_checkers(index, geo_near_path.clone(), hw_acc),
+
         FieldCondition {
             is_empty: Some(is_empty),
             ..
@@ -247,6 +302,7 @@ pub fn field_condition_index<'a>(
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_near_path: None,
             // We can't use index for this condition, since some indices don't count values,
             // like boolean index, where [true, true, true] is the same as [true]. Count should be 3 but they think is 1.
             //
@@ -274,6 +330,33 @@ pub fn get_geo_polygon_checkers(
         FieldIndex::BoolIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_)
+        | FieldIndex::NullIndex(_) => None,
+    }
+}
+
+pub fn get_geo_near_path_checkers(
+    index: &FieldIndex,
+    geo_near_path: GeoNearPath,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    let hw_counter = hw_acc.get_counter_cell();
+    match index {
+        FieldIndex::GeoIndex(geo_index) => Some(Box::new(move |point_id: PointOffsetType| {
+            geo_index.check_values_any(point_id, &hw_counter, |value| {
+                geo_near_path.check_point(value)
+            })
+        })),
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
@@ -297,6 +380,7 @@ pub fn get_geo_radius_checkers(
         FieldIndex::BoolIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
@@ -322,6 +406,7 @@ pub fn get_geo_bounding_box_checkers(
         FieldIndex::BoolIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
@@ -340,6 +425,7 @@ pub fn get_range_checkers(
     match range {
         RangeInterface::Float(range) => get_float_range_checkers(index, range, hw_acc),
         RangeInterface::DateTime(range) => get_datetime_range_checkers(index, range, hw_acc),
+        RangeInterface::Uuid(range) => get_uuid_range_checkers(index, range, hw_acc),
     }
 }
 
@@ -363,6 +449,17 @@ pub fn get_float_range_checkers(
                 &hw_counter,
             )
         })),
+        FieldIndex::ScaledFloatIndex(num_index) => match num_index.scale_range(&range) {
+            Some(range) => Some(Box::new(move |point_id: PointOffsetType| {
+                num_index.inner().check_values_any(
+                    point_id,
+                    |value| range.check_range(*value),
+                    &hw_counter,
+                )
+            })),
+            // No stored value can be within the range
+            None => Some(Box::new(|_point_id: PointOffsetType| false)),
+        },
         FieldIndex::BoolIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FullTextIndex(_)
@@ -390,6 +487,7 @@ pub fn get_datetime_range_checkers(
         }
         FieldIndex::BoolIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::GeoIndex(_)
         | FieldIndex::IntIndex(_)
@@ -401,6 +499,33 @@ pub fn get_datetime_range_checkers(
     }
 }
 
+pub fn get_uuid_range_checkers(
+    index: &FieldIndex,
+    range: Range<UuidPayloadType>,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    let range = range.map(|uuid| uuid.as_u128());
+    let hw_counter = hw_acc.get_counter_cell();
+    match index {
+        FieldIndex::UuidIndex(num_index) => Some(Box::new(move |point_id: PointOffsetType| {
+            num_index.check_values_any(point_id, |value| range.check_range(*value), &hw_counter)
+        })),
+        FieldIndex::UuidMapIndex(map_index) => Some(Box::new(move |point_id: PointOffsetType| {
+            map_index.check_values_any(point_id, &hw_counter, |value| range.check_range(*value))
+        })),
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::NullIndex(_) => None,
+    }
+}
+
 fn get_is_empty_indexes(indexes: &[FieldIndex]) -> (Option<&NullIndex>, Option<&FieldIndex>) {
     let mut primary_null_index: Option<&NullIndex> = None;
     let mut fallback_index: Option<&FieldIndex> = None;
@@ -457,6 +582,36 @@ fn get_is_empty_checker(index: &FieldIndex, is_empty: bool) -> Option<ConditionC
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::BoolIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+pub fn get_null_elements_checker(
+    index: &FieldIndex,
+    null_elements: NullElements,
+) -> Option<ConditionCheckerFn<'_>> {
+    match index {
+        FieldIndex::NullIndex(null_index) => {
+            if !null_index.supports_null_elements(null_elements) {
+                return None;
+            }
+            Some(Box::new(move |point_id: PointOffsetType| {
+                null_index
+                    .values_is_null_with(point_id, null_elements)
+                    .unwrap_or(false)
+            }))
+        }
+        FieldIndex::IntIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::GeoIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::BoolIndex(_)
@@ -475,6 +630,7 @@ fn get_is_null_checker(index: &FieldIndex, is_null: bool) -> Option<ConditionChe
         | FieldIndex::IntMapIndex(_)
         | FieldIndex::KeywordIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::GeoIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::BoolIndex(_)
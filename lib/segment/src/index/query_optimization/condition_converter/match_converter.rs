@@ -3,12 +3,13 @@ use common::types::PointOffsetType;
 use indexmap::IndexSet;
 use uuid::Uuid;
 
+use crate::data_types::index::Language;
 use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::payload_storage::condition_checker::INDEXSET_ITER_THRESHOLD;
 use crate::types::{
-    AnyVariants, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny, MatchValue,
-    ValueVariants,
+    AnyVariants, Match, MatchAny, MatchExactSet, MatchExcept, MatchKeywordPrefix, MatchPhrase,
+    MatchText, MatchTextAny, MatchValue, MinShouldMatch, ValueVariants,
 };
 
 pub fn get_match_checkers(
@@ -18,17 +19,44 @@ pub fn get_match_checkers(
 ) -> Option<ConditionCheckerFn<'_>> {
     match cond_match {
         Match::Value(MatchValue { value }) => get_match_value_checker(value, index, hw_acc),
-        Match::Text(MatchText { text }) => {
-            get_match_text_checker(text, TextQueryType::Text, index, hw_acc)
-        }
-        Match::TextAny(MatchTextAny { text_any }) => {
-            get_match_text_checker(text_any, TextQueryType::TextAny, index, hw_acc)
-        }
-        Match::Phrase(MatchPhrase { phrase }) => {
-            get_match_text_checker(phrase, TextQueryType::Phrase, index, hw_acc)
-        }
+        Match::Text(MatchText {
+            text,
+            language,
+            unstemmed,
+        }) => get_match_text_checker(
+            text,
+            TextQueryType::Text {
+                language,
+                unstemmed: unstemmed.unwrap_or(false),
+            },
+            index,
+            hw_acc,
+        ),
+        Match::TextAny(MatchTextAny {
+            text_any,
+            min_should_match,
+        }) => get_match_text_checker(
+            text_any,
+            TextQueryType::TextAny(min_should_match),
+            index,
+            hw_acc,
+        ),
+        Match::Phrase(MatchPhrase { phrase, slop }) => get_match_text_checker(
+            phrase,
+            TextQueryType::Phrase {
+                slop: slop.unwrap_or(0),
+            },
+            index,
+            hw_acc,
+        ),
         Match::Any(MatchAny { any }) => get_match_any_checker(any, index, hw_acc),
         Match::Except(MatchExcept { except }) => get_match_except_checker(except, index, hw_acc),
+        Match::ExactSet(MatchExactSet { exact_set }) => {
+            get_match_exact_set_checker(exact_set, index, hw_acc)
+        }
+        Match::KeywordPrefix(MatchKeywordPrefix { prefix }) => {
+            get_match_keyword_prefix_checker(prefix, index, hw_acc)
+        }
     }
 }
 
@@ -38,6 +66,15 @@ fn get_match_value_checker(
     hw_acc: HwMeasurementAcc,
 ) -> Option<ConditionCheckerFn<'_>> {
     match (value_variant, index) {
+        (ValueVariants::String(keyword), FieldIndex::KeywordIndex(index))
+            if index.is_case_insensitive() =>
+        {
+            let keyword = keyword.to_lowercase();
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, &hw_counter, |k| k.to_lowercase() == keyword)
+            }))
+        }
         (ValueVariants::String(keyword), FieldIndex::KeywordIndex(index)) => {
             let hw_counter = hw_acc.get_counter_cell();
             Some(Box::new(move |point_id: PointOffsetType| {
@@ -65,6 +102,7 @@ fn get_match_value_checker(
         }
         (ValueVariants::Bool(_), FieldIndex::DatetimeIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::FloatIndex(_))
+        | (ValueVariants::Bool(_), FieldIndex::ScaledFloatIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::FullTextIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::GeoIndex(_))
         | (ValueVariants::Bool(_), FieldIndex::IntIndex(_))
@@ -76,6 +114,7 @@ fn get_match_value_checker(
         | (ValueVariants::Integer(_), FieldIndex::BoolIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::DatetimeIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::FloatIndex(_))
+        | (ValueVariants::Integer(_), FieldIndex::ScaledFloatIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::FullTextIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::GeoIndex(_))
         | (ValueVariants::Integer(_), FieldIndex::IntIndex(_))
@@ -86,6 +125,7 @@ fn get_match_value_checker(
         | (ValueVariants::String(_), FieldIndex::BoolIndex(_))
         | (ValueVariants::String(_), FieldIndex::DatetimeIndex(_))
         | (ValueVariants::String(_), FieldIndex::FloatIndex(_))
+        | (ValueVariants::String(_), FieldIndex::ScaledFloatIndex(_))
         | (ValueVariants::String(_), FieldIndex::FullTextIndex(_))
         | (ValueVariants::String(_), FieldIndex::GeoIndex(_))
         | (ValueVariants::String(_), FieldIndex::IntIndex(_))
@@ -101,6 +141,17 @@ fn get_match_any_checker(
     hw_acc: HwMeasurementAcc,
 ) -> Option<ConditionCheckerFn<'_>> {
     match (any_variant, index) {
+        (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index))
+            if index.is_case_insensitive() =>
+        {
+            let list: IndexSet<_> = list.iter().map(|s| s.to_lowercase()).collect();
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, &hw_counter, |value| {
+                    list.contains(&value.to_lowercase())
+                })
+            }))
+        }
         (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index)) => {
             if list.len() < INDEXSET_ITER_THRESHOLD {
                 let hw_counter = hw_acc.get_counter_cell();
@@ -152,6 +203,7 @@ fn get_match_any_checker(
         (AnyVariants::Integers(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::DatetimeIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FloatIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::ScaledFloatIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::GeoIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::IntIndex(_))
@@ -162,6 +214,7 @@ fn get_match_any_checker(
         | (AnyVariants::Strings(_), FieldIndex::BoolIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::DatetimeIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FloatIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::ScaledFloatIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::GeoIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::IntIndex(_))
@@ -171,12 +224,71 @@ fn get_match_any_checker(
     }
 }
 
+fn get_match_exact_set_checker(
+    exact_set: AnyVariants,
+    index: &FieldIndex,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    match (exact_set, index) {
+        (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index)) => {
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_exact_set(point_id, &list, &hw_counter)
+            }))
+        }
+        (AnyVariants::Strings(list), FieldIndex::UuidMapIndex(index)) => {
+            let list = list
+                .iter()
+                .map(|s| Uuid::parse_str(s).map(|uuid| uuid.as_u128()).ok())
+                .collect::<Option<IndexSet<_>>>()?;
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_exact_set(point_id, &list, &hw_counter)
+            }))
+        }
+        (AnyVariants::Integers(list), FieldIndex::IntMapIndex(index)) => {
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_exact_set(point_id, &list, &hw_counter)
+            }))
+        }
+        (AnyVariants::Strings(_), _) | (AnyVariants::Integers(_), _) => None,
+    }
+}
+
+fn get_match_keyword_prefix_checker(
+    prefix: String,
+    index: &FieldIndex,
+    hw_acc: HwMeasurementAcc,
+) -> Option<ConditionCheckerFn<'_>> {
+    match index {
+        FieldIndex::KeywordIndex(index) => {
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, &hw_counter, |value| value.starts_with(&prefix))
+            }))
+        }
+        _ => None,
+    }
+}
+
 fn get_match_except_checker(
     except: AnyVariants,
     index: &FieldIndex,
     hw_acc: HwMeasurementAcc,
 ) -> Option<ConditionCheckerFn<'_>> {
     let checker: Option<ConditionCheckerFn> = match (except, index) {
+        (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index))
+            if index.is_case_insensitive() =>
+        {
+            let list: IndexSet<_> = list.iter().map(|s| s.to_lowercase()).collect();
+            let hw_counter = hw_acc.get_counter_cell();
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, &hw_counter, |value| {
+                    !list.contains(&value.to_lowercase())
+                })
+            }))
+        }
         (AnyVariants::Strings(list), FieldIndex::KeywordIndex(index)) => {
             let hw_counter = hw_acc.get_counter_cell();
             if list.len() < INDEXSET_ITER_THRESHOLD {
@@ -228,6 +340,7 @@ fn get_match_except_checker(
         | (AnyVariants::Strings(_), FieldIndex::DatetimeIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::IntMapIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FloatIndex(_))
+        | (AnyVariants::Strings(_), FieldIndex::ScaledFloatIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::GeoIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Strings(_), FieldIndex::BoolIndex(_))
@@ -237,6 +350,7 @@ fn get_match_except_checker(
         | (AnyVariants::Integers(_), FieldIndex::DatetimeIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::KeywordIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FloatIndex(_))
+        | (AnyVariants::Integers(_), FieldIndex::ScaledFloatIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::GeoIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::FullTextIndex(_))
         | (AnyVariants::Integers(_), FieldIndex::BoolIndex(_))
@@ -256,9 +370,14 @@ fn get_match_except_checker(
 }
 
 enum TextQueryType {
-    Phrase,
-    Text,
-    TextAny,
+    Phrase {
+        slop: u32,
+    },
+    Text {
+        language: Option<Language>,
+        unstemmed: bool,
+    },
+    TextAny(Option<MinShouldMatch>),
 }
 
 fn get_match_text_checker(
@@ -271,9 +390,16 @@ fn get_match_text_checker(
     match index {
         FieldIndex::FullTextIndex(full_text_index) => {
             let query_opt = match query_type {
-                TextQueryType::Phrase => full_text_index.parse_phrase_query(&text, &hw_counter),
-                TextQueryType::Text => full_text_index.parse_text_query(&text, &hw_counter),
-                TextQueryType::TextAny => full_text_index.parse_text_any_query(&text, &hw_counter),
+                TextQueryType::Phrase { slop } => {
+                    full_text_index.parse_sloppy_phrase_query(&text, slop, &hw_counter)
+                }
+                TextQueryType::Text {
+                    language,
+                    unstemmed,
+                } => full_text_index.parse_text_query(&text, language, unstemmed, &hw_counter),
+                TextQueryType::TextAny(min_should_match) => {
+                    full_text_index.parse_text_any_query(&text, min_should_match, &hw_counter)
+                }
             };
 
             let parsed_query = match query_opt {
@@ -295,6 +421,7 @@ fn get_match_text_checker(
         FieldIndex::BoolIndex(_)
         | FieldIndex::DatetimeIndex(_)
         | FieldIndex::FloatIndex(_)
+        | FieldIndex::ScaledFloatIndex(_)
         | FieldIndex::GeoIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
@@ -0,0 +1,179 @@
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::counter::hardware_counter::HardwareCounterCell;
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use crate::common::operation_error::OperationResult;
+use crate::index::PayloadIndexRead as _;
+use crate::index::field_index::{CardinalityEstimation, FieldIndex};
+use crate::index::query_optimization::condition_converter::{
+    field_condition_index, get_null_elements_checker,
+};
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::types::{Condition, Filter, PayloadKeyType};
+
+/// Relative cost of checking a condition with an index, or by the point id
+pub const INDEX_CHECK_COST: usize = 1;
+
+/// Relative cost of checking a full-text condition, which compares every token of the query
+/// with the tokens of the point
+pub const FULL_TEXT_CHECK_COST: usize = 4;
+
+/// Relative cost of checking a condition by reading and parsing the payload of the point
+pub const PAYLOAD_CHECK_COST: usize = 16;
+
+/// Position of a `must` condition in the order in which points are checked against it
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedCondition {
+    /// Index of the condition in the `must` clause of the filter
+    pub position: usize,
+    /// Payload field of the condition, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<PayloadKeyType>,
+    /// Relative cost of checking the condition for a single point
+    pub cost: usize,
+    /// Expected number of points matching the condition
+    pub estimated_exp: usize,
+}
+
+/// Rank of a `must` condition, conditions with a lower rank are checked first.
+///
+/// Checks of a point stop at the first condition it fails, so later conditions are only checked
+/// on the candidates narrowed by the earlier ones. Ordering by `cost / (1 - selectivity)`
+/// minimizes the expected cost of checking a point: expensive conditions, like full-text matching
+/// or reading the payload, are only checked on the points, which passed the cheap ones.
+pub fn must_check_rank(
+    cost: usize,
+    estimation: &CardinalityEstimation,
+    total: usize,
+) -> OrderedFloat<f64> {
+    let selectivity = if total == 0 {
+        0.0
+    } else {
+        estimation.exp as f64 / total as f64
+    };
+    if selectivity >= 1.0 {
+        // Matches every point, never narrows the candidates
+        return OrderedFloat(f64::INFINITY);
+    }
+    OrderedFloat(cost as f64 / (1.0 - selectivity))
+}
+
+impl StructPayloadIndex {
+    /// Relative cost of checking the `condition` for a single point
+    pub fn condition_cost(&self, condition: &Condition) -> usize {
+        let field_indexes = &self.field_indexes;
+        match condition {
+            Condition::Field(field_condition) => {
+                let index = field_indexes.get(&field_condition.key).and_then(|indexes| {
+                    indexes.iter().find(|index| {
+                        let hw_acc = HwMeasurementAcc::disposable();
+                        field_condition_index(index, field_condition, hw_acc).is_some()
+                    })
+                });
+                match index {
+                    Some(FieldIndex::FullTextIndex(_)) => FULL_TEXT_CHECK_COST,
+                    Some(_) => INDEX_CHECK_COST,
+                    None => PAYLOAD_CHECK_COST,
+                }
+            }
+            Condition::IsEmpty(is_empty) => {
+                let has_null_index =
+                    field_indexes
+                        .get(&is_empty.is_empty.key)
+                        .is_some_and(|indexes| {
+                            indexes
+                                .iter()
+                                .any(|index| matches!(index, FieldIndex::NullIndex(_)))
+                        });
+                if has_null_index {
+                    INDEX_CHECK_COST
+                } else {
+                    PAYLOAD_CHECK_COST
+                }
+            }
+            Condition::IsNull(is_null) => {
+                let null_elements = is_null.null_elements.unwrap_or_default();
+                let has_checker = field_indexes
+                    .get(&is_null.is_null.key)
+                    .is_some_and(|indexes| {
+                        indexes
+                            .iter()
+                            .any(|index| get_null_elements_checker(index, null_elements).is_some())
+                    });
+                if has_checker {
+                    INDEX_CHECK_COST
+                } else {
+                    PAYLOAD_CHECK_COST
+                }
+            }
+            Condition::HasId(_)
+            | Condition::HasVector(_)
+            | Condition::UpdatedAfter(_)
+            | Condition::CustomIdChecker(_) => INDEX_CHECK_COST,
+            Condition::Nested(_) | Condition::Expression(_) => PAYLOAD_CHECK_COST,
+            // Worst case: all conditions of the nested filter are checked
+            Condition::Filter(filter) => filter
+                .iter_conditions()
+                .map(|condition| self.condition_cost(condition))
+                .sum(),
+        }
+    }
+
+    /// Order in which points are checked against the `must` conditions of the `filter`
+    pub fn plan_must_conditions(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<PlannedCondition>> {
+        let Some(conditions) = filter.must.as_ref() else {
+            return Ok(Vec::new());
+        };
+
+        let total = self.available_point_count();
+        let mut planned = conditions
+            .iter()
+            .enumerate()
+            .map(|(position, condition)| {
+                let estimation = match condition {
+                    Condition::Filter(filter) => self.estimate_cardinality(filter, hw_counter)?,
+                    _ => self.condition_cardinality(condition, None, hw_counter)?,
+                };
+                let cost = self.condition_cost(condition);
+                Ok((position, condition, cost, estimation))
+            })
+            .collect::<OperationResult<Vec<_>>>()?;
+        planned.sort_by_key(|(_, _, cost, estimation)| must_check_rank(*cost, estimation, total));
+
+        Ok(planned
+            .into_iter()
+            .map(|(position, condition, cost, estimation)| PlannedCondition {
+                position,
+                key: condition.targeted_key(),
+                cost,
+                estimated_exp: estimation.exp,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_must_check_rank() {
+        let total = 1000;
+        let rank = |cost, exp| must_check_rank(cost, &CardinalityEstimation::exact(exp), total);
+
+        // Same cost: more selective conditions first
+        assert!(rank(INDEX_CHECK_COST, 10) < rank(INDEX_CHECK_COST, 500));
+
+        // Cheap condition first, unless the expensive one is much more selective
+        assert!(rank(INDEX_CHECK_COST, 100) < rank(FULL_TEXT_CHECK_COST, 100));
+        assert!(rank(FULL_TEXT_CHECK_COST, 1) < rank(INDEX_CHECK_COST, 900));
+
+        // Conditions matching every point are checked last
+        assert!(rank(PAYLOAD_CHECK_COST, 999) < rank(INDEX_CHECK_COST, total));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod condition_converter;
+pub mod condition_cost;
 pub mod optimized_filter;
 pub mod optimizer;
 pub mod payload_provider;
@@ -9,6 +9,7 @@ use crate::index::query_estimator::{
     combine_min_should_estimations, combine_must_estimations, combine_should_estimations,
     invert_estimation,
 };
+use crate::index::query_optimization::condition_cost::must_check_rank;
 use crate::index::query_optimization::optimized_filter::{
     OptimizedCondition, OptimizedFilter, OptimizedMinShould,
 };
@@ -24,6 +25,7 @@ impl StructPayloadIndex {
     /// * Convert each condition into a checker function
     /// * Use column index, avoid reading Payload, if possible
     /// * Re-order operations using estimated cardinalities
+    /// * Check cheap `must` conditions first, see [`must_check_rank`]
     ///
     /// ToDo: Add optimizations between clauses
     ///
@@ -112,6 +114,18 @@ impl StructPayloadIndex {
         total: usize,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<(OptimizedCondition<'a>, CardinalityEstimation)>> {
+        // Estimate the field conditions together, so conditions on the same field share the work
+        let field_conditions = conditions
+            .iter()
+            .filter_map(|condition| match condition {
+                Condition::Field(field_condition) => Some(field_condition),
+                _ => None,
+            })
+            .collect_vec();
+        let mut field_estimations = self
+            .estimate_field_conditions(&field_conditions, None, hw_counter)?
+            .into_iter();
+
         conditions
             .iter()
             .map(|condition| match condition {
@@ -120,6 +134,14 @@ impl StructPayloadIndex {
                         self.optimize_filter(filter, payload_provider.clone(), total, hw_counter)?;
                     Ok((OptimizedCondition::Filter(optimized_filter), estimation))
                 }
+                Condition::Field(_) => {
+                    let estimation = field_estimations.next().flatten().unwrap_or_else(|| {
+                        CardinalityEstimation::unknown(self.available_point_count())
+                    });
+                    let condition_checker =
+                        self.condition_converter(condition, payload_provider.clone(), hw_counter);
+                    Ok((OptimizedCondition::Checker(condition_checker), estimation))
+                }
                 _ => {
                     let estimation = self.condition_cardinality(condition, None, hw_counter)?;
                     let condition_checker =
@@ -178,11 +200,21 @@ impl StructPayloadIndex {
         total: usize,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<(Vec<OptimizedCondition<'a>>, CardinalityEstimation)> {
-        let mut converted =
-            self.convert_conditions(conditions, payload_provider, total, hw_counter)?;
-        // Less probable conditions first
-        converted.sort_by_key(|(_, estimation)| estimation.exp);
-        let (conditions, estimations): (Vec<_>, Vec<_>) = converted.into_iter().unzip();
+        let converted = self.convert_conditions(conditions, payload_provider, total, hw_counter)?;
+        // Cheap and selective conditions first, so expensive ones only check the remaining points
+        let mut ranked = converted
+            .into_iter()
+            .zip(conditions)
+            .map(|((condition, estimation), original)| {
+                let rank = must_check_rank(self.condition_cost(original), &estimation, total);
+                (condition, estimation, rank)
+            })
+            .collect_vec();
+        ranked.sort_by_key(|(_, _, rank)| *rank);
+        let (conditions, estimations): (Vec<_>, Vec<_>) = ranked
+            .into_iter()
+            .map(|(condition, estimation, _)| (condition, estimation))
+            .unzip();
 
         Ok((conditions, combine_must_estimations(&estimations, total)))
     }
@@ -9,7 +9,7 @@ use serde_json::Value;
 use super::parsed_formula::{
     DatetimeExpression, DecayKind, ParsedExpression, PreciseScore, VariableId,
 };
-use super::value_retriever::VariableRetrieverFn;
+use super::value_retriever::{TextScorerFn, VariableRetrieverFn};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::query_optimization::optimized_filter::{OptimizedCondition, check_condition};
 use crate::json_path::JsonPath;
@@ -28,6 +28,8 @@ pub struct FormulaScorer<'a> {
     payload_retrievers: HashMap<JsonPath, VariableRetrieverFn<'a>>,
     /// Condition id -> checker function
     condition_checkers: Vec<OptimizedCondition<'a>>,
+    /// Text score id -> scorer function
+    text_scorers: Vec<TextScorerFn<'a>>,
     /// Default values for all variables
     defaults: HashMap<VariableId, Value>,
 }
@@ -60,6 +62,7 @@ impl<'a> FormulaScorer<'a> {
         prefetches_scores: &'a [AHashMap<PointOffsetType, ScoreType>],
         payload_retrievers: HashMap<JsonPath, VariableRetrieverFn<'a>>,
         condition_checkers: Vec<OptimizedCondition<'a>>,
+        text_scorers: Vec<TextScorerFn<'a>>,
         defaults: HashMap<VariableId, Value>,
     ) -> Self {
         FormulaScorer {
@@ -67,6 +70,7 @@ impl<'a> FormulaScorer<'a> {
             prefetches_scores,
             payload_retrievers,
             condition_checkers,
+            text_scorers,
             defaults,
         }
     }
@@ -152,6 +156,7 @@ impl FormulaScorer<'_> {
 
                 Ok(float_seconds)
             }
+            ParsedExpression::TextScore(id) => (self.text_scorers[*id])(point_id),
             ParsedExpression::Mult(expressions) => {
                 let mut product = 1.0;
                 for expr in expressions {
@@ -403,11 +408,14 @@ mod tests {
                 OptimizedCondition::Checker(Box::new(|_| false)),
             ];
 
+            let text_scorers: Vec<TextScorerFn> = vec![Box::new(|_| Ok(0.5))];
+
             FormulaScorer {
                 formula: ParsedExpression::Constant(PreciseScoreOrdered::from(0.0)),
                 prefetches_scores,
                 payload_retrievers,
                 condition_checkers,
+                text_scorers,
                 defaults: defaults.clone(),
             }
         })
@@ -421,6 +429,7 @@ mod tests {
     #[case(ParsedExpression::new_payload_id(JsonPath::new(FIELD_NAME)), 85.0)]
     #[case(ParsedExpression::new_condition_id(0), 1.0)]
     #[case(ParsedExpression::new_condition_id(1), 0.0)]
+    #[case(ParsedExpression::new_text_score_id(0), 0.5)]
     // Operations
     #[case(ParsedExpression::Sum(vec![
         ParsedExpression::Constant(PreciseScoreOrdered::from(1.0)),
@@ -10,13 +10,14 @@ use serde_json::Value;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::unordered_hash_unique;
 use crate::json_path::{JsonPath, JsonPathItem};
-use crate::types::{Condition, DateTimePayloadType, GeoPoint};
+use crate::types::{Condition, DateTimePayloadType, GeoPoint, TextScoreAggregation};
 
 const SCORE_KEYWORD: &str = "score";
 const DEFAULT_DECAY_MIDPOINT: f32 = 0.5;
 const DEFAULT_DECAY_SCALE: f32 = 1.0;
 
 pub type ConditionId = usize;
+pub type TextScoreId = usize;
 pub type PreciseScore = f64;
 pub type PreciseScoreOrdered = OrderedFloat<PreciseScore>;
 
@@ -28,6 +29,9 @@ pub struct ParsedFormula {
     /// Conditions used in the formula. Their index in the array is used as a variable id
     pub conditions: Vec<Condition>,
 
+    /// Text scores used in the formula. Their index in the array is used as an expression id
+    pub text_scores: Vec<TextScore>,
+
     /// Defaults to use when variable is not found
     pub defaults: HashMap<VariableId, Value>,
 
@@ -40,12 +44,14 @@ impl Hash for ParsedFormula {
         let Self {
             payload_vars,
             conditions,
+            text_scores,
             defaults,
             formula,
         } = self;
 
         unordered_hash_unique(state, payload_vars.iter());
         conditions.hash(state);
+        text_scores.hash(state);
         unordered_hash_unique(state, defaults.iter());
         formula.hash(state);
     }
@@ -61,6 +67,7 @@ pub enum ParsedExpression {
         key: JsonPath,
     },
     Datetime(DatetimeExpression),
+    TextScore(TextScoreId),
 
     // Nested
     Mult(Vec<ParsedExpression>),
@@ -121,6 +128,14 @@ impl VariableId {
     }
 }
 
+/// Relevance of the text at `key` to the `text`, aggregated over the values of an array
+#[derive(Debug, Clone, PartialEq, Hash, Serialize)]
+pub struct TextScore {
+    pub key: JsonPath,
+    pub text: String,
+    pub aggregation: TextScoreAggregation,
+}
+
 #[derive(Debug, Clone, PartialEq, Hash, Serialize)]
 pub enum DatetimeExpression {
     Constant(DateTimePayloadType),
@@ -179,6 +194,10 @@ impl ParsedExpression {
         ParsedExpression::Variable(VariableId::Condition(index))
     }
 
+    pub fn new_text_score_id(index: TextScoreId) -> Self {
+        ParsedExpression::TextScore(index)
+    }
+
     /// Transforms the constant part of the decay function into a single `lambda` value.
     ///
     /// Graphical representation of the formulas:
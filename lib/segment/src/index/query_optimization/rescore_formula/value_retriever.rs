@@ -4,6 +4,8 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use serde_json::{Number, Value};
 
+use super::parsed_formula::{PreciseScore, TextScore};
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::MultiValue;
 use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
@@ -13,6 +15,8 @@ use crate::types::{DateTimePayloadType, PayloadContainer, UuidPayloadType};
 
 pub type VariableRetrieverFn<'a> = Box<dyn Fn(PointOffsetType) -> MultiValue<Value> + 'a>;
 
+pub type TextScorerFn<'a> = Box<dyn Fn(PointOffsetType) -> OperationResult<PreciseScore> + 'a>;
+
 impl StructPayloadIndex {
     /// Prepares optimized functions to extract each of the variables, given a point id.
     pub(crate) fn retrievers_map<'a, 'q>(
@@ -42,6 +46,60 @@ impl StructPayloadIndex {
 
         var_retrievers
     }
+
+    /// Prepares functions to calculate each of the text scores, given a point id.
+    ///
+    /// The query text is tokenized once. The index doesn't keep which tokens belong to which
+    /// value of an array, so the values of the point are read from the payload and tokenized.
+    pub(crate) fn text_scorers<'a, 'q>(
+        &'a self,
+        text_scores: &[TextScore],
+        hw_counter: &'q HardwareCounterCell,
+    ) -> OperationResult<Vec<TextScorerFn<'q>>>
+    where
+        'a: 'q,
+    {
+        let payload_provider = PayloadProvider::new(self.payload.clone());
+
+        text_scores
+            .iter()
+            .map(|text_score| {
+                let TextScore {
+                    key,
+                    text,
+                    aggregation,
+                } = text_score;
+
+                let text_index = self
+                    .field_indexes
+                    .get(key)
+                    .and_then(|indexes| {
+                        indexes.iter().find_map(|index| match index {
+                            FieldIndex::FullTextIndex(index) => Some(index),
+                            _ => None,
+                        })
+                    })
+                    .ok_or_else(|| {
+                        OperationError::validation_error(format!(
+                            "Text score requires a full-text index on `{key}`",
+                        ))
+                    })?;
+
+                let query = text_index.parse_score_query(text, hw_counter)?;
+                let aggregation = *aggregation;
+                let retriever =
+                    payload_variable_retriever(payload_provider.clone(), key.clone(), hw_counter);
+
+                let scorer_fn = move |point_id: PointOffsetType| {
+                    let values = retriever(point_id);
+                    let score =
+                        text_index.score_values(&query, &values, aggregation, hw_counter)?;
+                    Ok(PreciseScore::from(score))
+                };
+                Ok(Box::new(scorer_fn) as TextScorerFn)
+            })
+            .collect()
+    }
 }
 
 fn variable_retriever<'a, 'q>(
@@ -147,6 +205,17 @@ where
             };
             Some(Box::new(extract_fn))
         }
+        FieldIndex::ScaledFloatIndex(numeric_index) => {
+            let extract_fn = move |point_id: PointOffsetType| -> MultiValue<Value> {
+                numeric_index
+                    .get_values(point_id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|v| Some(Value::Number(Number::from_f64(v)?)))
+                    .collect()
+            };
+            Some(Box::new(extract_fn))
+        }
         FieldIndex::DatetimeIndex(numeric_index) => {
             let extract_fn = move |point_id: PointOffsetType| -> MultiValue<Value> {
                 numeric_index
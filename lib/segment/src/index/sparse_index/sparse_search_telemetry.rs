@@ -38,6 +38,8 @@ impl SparseSearchesTelemetry {
             filtered_sparse: self.filtered_sparse.lock().get_statistics(detail),
             unfiltered_sparse: self.unfiltered_sparse.lock().get_statistics(detail),
             unfiltered_exact: Default::default(),
+            quantization_fallback: Default::default(),
+            quantization_distortion: None,
         }
     }
 }
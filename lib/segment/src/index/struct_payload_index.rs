@@ -1,29 +1,37 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
+use std::ptr;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::Instant;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use atomic_refcell::AtomicRefCell;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::counter::iterator_hw_measurement::HwMeasurementIteratorExt;
 use common::defaults::log_load_timing;
 use common::either_variant::EitherVariant;
 use common::iterator_ext::IteratorExt;
-use common::types::{PointOffsetType, ScoreType};
+use common::populate_budget::PopulateBudget;
+use common::types::{PointOffsetType, ScoreType, TelemetryDetail};
 use fs_err as fs;
 use schemars::_serde_json::Value;
 
 use super::field_index::facet_index::FacetIndexEnum;
+use super::field_index::full_text_index::text_index::FullTextIndex;
 use super::field_index::index_selector::{
     IndexSelector, IndexSelectorGridstore, IndexSelectorMmap,
 };
-use super::field_index::{FieldIndexBuilderTrait as _, ResolvedHasId};
+use super::field_index::numeric_index::scaled_float_index::{ScaledFloatIndex, scale_value};
+use super::field_index::value_size_limit::{OversizedValueStats, ValueSizeLimit};
+use super::field_index::{FieldIndexBuilderTrait as _, ResolvedHasId, ValueIndexer};
 use super::payload_config::{FullPayloadIndexType, PayloadFieldSchemaWithIndexType};
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::IndexesMap;
+use crate::data_types::index::Language;
+use crate::data_types::quantiles::ValueSamples;
+use crate::data_types::text_query::{QueryStopwords, SnippetParams, TextSnippet, TextValueMatches};
 use crate::id_tracker::{IdTrackerEnum, IdTrackerRead, PointMappingsRefEnum};
 use crate::index::field_index::{
     CardinalityEstimation, FacetIndex, FieldIndex, NumericFieldIndexRead, PayloadBlockCondition,
@@ -42,11 +50,26 @@ use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::{FilterContext, PayloadStorage, PayloadStorageRead};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, Payload,
-    PayloadContainer, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, VectorNameBuf,
+    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, NullElements, Payload,
+    PayloadContainer, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PayloadSchemaParams,
+    Range, VectorNameBuf,
 };
 use crate::vector_storage::{VectorStorageEnum, VectorStorageRead};
 
+/// Directory inside the payload index dir, where replacement indexes are built
+const SWAP_STAGING_DIR: &str = "swap-staging";
+
+/// Directory inside the payload index dir, where replaced indexes are kept until the swap commits
+const SWAP_BACKUP_DIR: &str = "swap-backup";
+
+/// Directory inside the payload index dir, where per-position indexes of positional bool
+/// indexes are kept, apart from indexes created on the same keys
+const POSITIONAL_INDEX_DIR: &str = "positional";
+
+/// File inside the backup dir of a swap, with the new schema of the field.
+/// The swap is committed once it is written, recovery completes committed swaps.
+const SWAP_COMMIT_FILE: &str = "commit.json";
+
 #[derive(Debug)]
 #[allow(clippy::enum_variant_names)]
 enum StorageType {
@@ -72,6 +95,11 @@ pub struct StructPayloadIndex {
     visited_pool: VisitedPool,
     /// Desired storage type for payload indices, used in builder to pick correct type
     storage_type: StorageType,
+    /// Number of values per field, which exceeded the size limit of the field index
+    oversized_values: OversizedValueStats,
+    /// Stopwords detected by the shard for the full-text indexes of the fields, also assigned to
+    /// the indexes built later
+    text_auto_stopwords: AHashMap<PayloadKeyType, Arc<AHashSet<String>>>,
 }
 
 impl StructPayloadIndex {
@@ -100,6 +128,104 @@ impl StructPayloadIndex {
             .transpose()
     }
 
+    /// Estimate each of the `conditions`, same as [`Self::estimate_field_condition`].
+    /// Conditions on the same field are estimated by its indexes together.
+    pub fn estimate_field_conditions(
+        &self,
+        conditions: &[&FieldCondition],
+        nested_path: Option<&JsonPath>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<Option<CardinalityEstimation>>> {
+        let mut estimations = vec![None; conditions.len()];
+
+        let mut positions_by_field: AHashMap<JsonPath, Vec<usize>> = AHashMap::new();
+        for (position, condition) in conditions.iter().enumerate() {
+            let full_path = JsonPath::extend_or_new(nested_path, &condition.key);
+            if self.field_indexes.contains_key(&full_path) {
+                positions_by_field
+                    .entry(full_path)
+                    .or_default()
+                    .push(position);
+            }
+        }
+
+        for (full_path, mut positions) in positions_by_field {
+            // rewrite conditions with fullpath to enable cardinality estimation
+            let full_path_conditions: Vec<_> = positions
+                .iter()
+                .map(|&position| FieldCondition {
+                    key: full_path.clone(),
+                    ..conditions[position].clone()
+                })
+                .collect();
+            let mut pending: Vec<_> = full_path_conditions.iter().collect();
+
+            // Each condition is estimated by the first index which supports it
+            for index in &self.field_indexes[&full_path] {
+                if pending.is_empty() {
+                    break;
+                }
+                let index_estimations = index.estimate_cardinality_batch(&pending, hw_counter)?;
+                let mut unsupported = 0;
+                for (i, estimation) in index_estimations.into_iter().enumerate() {
+                    match estimation {
+                        Some(estimation) => estimations[positions[i]] = Some(estimation),
+                        None => {
+                            pending[unsupported] = pending[i];
+                            positions[unsupported] = positions[i];
+                            unsupported += 1;
+                        }
+                    }
+                }
+                pending.truncate(unsupported);
+                positions.truncate(unsupported);
+            }
+        }
+
+        Ok(estimations)
+    }
+
+    /// Estimate the `query`, with its field conditions estimated in batches.
+    fn estimate_filter_cardinality(
+        &self,
+        query: &Filter,
+        nested_path: Option<&JsonPath>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<CardinalityEstimation> {
+        fn collect_field_conditions<'a>(filter: &'a Filter, output: &mut Vec<&'a FieldCondition>) {
+            for condition in filter.iter_conditions() {
+                match condition {
+                    Condition::Field(field_condition) => output.push(field_condition),
+                    Condition::Filter(filter) => collect_field_conditions(filter, output),
+                    _ => {}
+                }
+            }
+        }
+
+        let available_points = self.available_point_count();
+
+        let mut field_conditions = Vec::new();
+        collect_field_conditions(query, &mut field_conditions);
+        let field_estimations =
+            self.estimate_field_conditions(&field_conditions, nested_path, hw_counter)?;
+        // Conditions are identified by their address, as equal conditions may appear more than once
+        let field_estimations: AHashMap<*const FieldCondition, CardinalityEstimation> =
+            field_conditions
+                .into_iter()
+                .zip(field_estimations)
+                .filter_map(|(condition, estimation)| Some((ptr::from_ref(condition), estimation?)))
+                .collect();
+
+        let estimator = |condition: &Condition| match condition {
+            Condition::Field(field_condition) => Ok(field_estimations
+                .get(&ptr::from_ref(field_condition))
+                .cloned()
+                .unwrap_or_else(|| CardinalityEstimation::unknown(available_points))),
+            _ => self.condition_cardinality(condition, nested_path, hw_counter),
+        };
+        estimate_filter(&estimator, query, available_points)
+    }
+
     fn query_field<'a>(
         &'a self,
         condition: &'a PrimaryCondition,
@@ -151,6 +277,16 @@ impl StructPayloadIndex {
         // Put updated payload schemas back into the config
         self.config.indices = indices;
 
+        let mut positional_indices = std::mem::take(&mut self.config.positional_indices);
+        let positional_dir = self.positional_dir();
+        for (key, payload_schema) in positional_indices.iter_mut() {
+            let (field_index, dirty) =
+                self.load_from_db_in(&positional_dir, key, payload_schema, create_if_missing)?;
+            field_indexes.insert(key.clone(), field_index);
+            is_dirty |= dirty;
+        }
+        self.config.positional_indices = positional_indices;
+
         if is_dirty {
             self.save_config()?;
         }
@@ -165,6 +301,17 @@ impl StructPayloadIndex {
         // TODO: refactor this and remove the &mut reference.
         payload_schema: &mut PayloadFieldSchemaWithIndexType,
         create_if_missing: bool,
+    ) -> OperationResult<(Vec<FieldIndex>, bool)> {
+        self.load_from_db_in(&self.path, field, payload_schema, create_if_missing)
+    }
+
+    /// Same as [`Self::load_from_db`], for indexes placed in `dir`
+    fn load_from_db_in(
+        &self,
+        dir: &Path,
+        field: PayloadKeyTypeRef,
+        payload_schema: &mut PayloadFieldSchemaWithIndexType,
+        create_if_missing: bool,
     ) -> OperationResult<(Vec<FieldIndex>, bool)> {
         let id_tracker_borrow = self.id_tracker.borrow();
         let deleted_points = id_tracker_borrow.deleted_point_bitslice();
@@ -172,7 +319,7 @@ impl StructPayloadIndex {
         let mut is_dirty = false;
 
         let mut indexes = if payload_schema.types.is_empty() {
-            let selector = self.selector(&payload_schema.schema);
+            let selector = self.selector_in(dir, &payload_schema.schema);
             let indexes = selector.new_index(
                 field,
                 &payload_schema.schema,
@@ -213,7 +360,7 @@ impl StructPayloadIndex {
                 .iter()
                 // Load each index
                 .map(|index| {
-                    self.selector_with_type(index).and_then(|selector| {
+                    self.selector_with_type_in(dir, index).and_then(|selector| {
                         selector.new_index_with_type(
                             field,
                             &payload_schema.schema,
@@ -240,7 +387,8 @@ impl StructPayloadIndex {
         // If index is not properly loaded or when migrating, rebuild indices
         if rebuild {
             log::debug!("Rebuilding payload index for field `{field}`...");
-            indexes = self.build_field_indexes(
+            indexes = self.build_field_indexes_in(
+                dir,
                 field,
                 &payload_schema.schema,
                 &HardwareCounterCell::disposable(), // Internal operation
@@ -264,12 +412,14 @@ impl StructPayloadIndex {
     ) -> OperationResult<Self> {
         fs::create_dir_all(path)?;
         let config_path = PayloadConfig::get_config_path(path);
-        let config = if config_path.exists() {
+        let mut config = if config_path.exists() {
             PayloadConfig::load(&config_path)?
         } else {
             PayloadConfig::default()
         };
 
+        Self::recover_interrupted_swaps(path, &mut config)?;
+
         let storage_type = if is_appendable {
             StorageType::GridstoreAppendable
         } else {
@@ -285,6 +435,8 @@ impl StructPayloadIndex {
             path: path.to_owned(),
             visited_pool: Default::default(),
             storage_type,
+            oversized_values: Default::default(),
+            text_auto_stopwords: Default::default(),
         };
 
         if !index.config_path().exists() {
@@ -324,10 +476,20 @@ impl StructPayloadIndex {
         field: PayloadKeyTypeRef,
         payload_schema: &PayloadFieldSchema,
         hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<FieldIndex>> {
+        self.build_field_indexes_in(&self.path, field, payload_schema, hw_counter)
+    }
+
+    fn build_field_indexes_in(
+        &self,
+        dir: &Path,
+        field: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<FieldIndex>> {
         let payload_storage = self.payload.borrow();
         let id_tracker_borrow = self.id_tracker.borrow();
-        let selector = self.selector(payload_schema);
+        let selector = self.selector_in(dir, payload_schema);
         let mut builders = selector.index_builder(
             field,
             payload_schema,
@@ -346,11 +508,24 @@ impl StructPayloadIndex {
             index.init()?;
         }
 
+        let value_size_limit = ValueSizeLimit::from_schema(payload_schema);
+
         payload_storage.iter(
             |point_id, point_payload| {
                 let field_value = &point_payload.get_value(field);
+                let limited_value = value_size_limit
+                    .map(|limit| limit.apply(field, field_value, &self.oversized_values))
+                    .transpose()?
+                    .flatten();
+                let limited_value: Option<Vec<_>> =
+                    limited_value.as_ref().map(|values| values.iter().collect());
                 for builder in builders.iter_mut() {
-                    builder.add_point(point_id, field_value, hw_counter)?;
+                    // Oversized values still exist in the payload, so the null index sees them
+                    let values: &[&Value] = match &limited_value {
+                        Some(values) if !builder.is_null_index() => values,
+                        _ => field_value,
+                    };
+                    builder.add_point(point_id, values, hw_counter)?;
                 }
                 Ok(true)
             },
@@ -363,6 +538,482 @@ impl StructPayloadIndex {
             .collect()
     }
 
+    /// Build a replacement for the index of `field`, without touching the current one.
+    ///
+    /// Indexes are built in a staging directory, so the current index keeps serving queries
+    /// while the replacement is being built. Pass the result to [`Self::swap_index`] to
+    /// replace the current index.
+    pub fn build_replacement_index(
+        &self,
+        field: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<FieldIndex>> {
+        let staging_dir = self.swap_staging_dir(field);
+        if staging_dir.exists() {
+            // Leftover of a previous swap attempt
+            fs::remove_dir_all(&staging_dir)?;
+        }
+        fs::create_dir_all(&staging_dir)?;
+
+        self.build_field_indexes_in(&staging_dir, field, payload_schema, hw_counter)
+    }
+
+    /// Atomically replace the index of `field` with indexes built by
+    /// [`Self::build_replacement_index`].
+    ///
+    /// Queries use the current index until the new payload config is persisted, and the new
+    /// index afterwards. If the swap fails, the current index is restored.
+    pub fn swap_index(
+        &mut self,
+        field: PayloadKeyType,
+        payload_schema: PayloadFieldSchema,
+        field_index: Vec<FieldIndex>,
+    ) -> OperationResult<()> {
+        // Replaces a per-position index on the same key, if there is one
+        self.drop_positional_index(&field)?;
+
+        let staging_dir = self.swap_staging_dir(&field);
+        let old_schema = self.config.indices.get(&field).map(|i| i.schema.clone());
+        let result = self.swap_index_impl(&field, payload_schema, field_index, &staging_dir);
+
+        if staging_dir.exists()
+            && let Err(err) = fs::remove_dir_all(&staging_dir)
+        {
+            log::warn!(
+                "Failed to remove payload index staging dir {}: {err}",
+                staging_dir.display(),
+            );
+        }
+
+        result?;
+
+        self.sync_positional_indexes(&field, old_schema.as_ref())
+    }
+
+    /// Switch the index of `field` between on-disk and in-memory mode, without rebuilding it.
+    ///
+    /// Only possible if `payload_schema` differs from the current schema in `on_disk` only, and
+    /// the index is stored in mmap files, which can be either read from disk or loaded into
+    /// memory. Returns the effective on-disk status of the index, or `None` if the index has to
+    /// be rebuilt instead.
+    pub fn set_index_on_disk(
+        &mut self,
+        field: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+    ) -> OperationResult<Option<bool>> {
+        let Some(current_schema) = self.config.indices.get(field) else {
+            return Ok(None);
+        };
+        if !current_schema.schema.eq_ignoring_on_disk(payload_schema) {
+            return Ok(None);
+        }
+
+        let is_on_disk = payload_schema.is_on_disk();
+        let mut index_types = current_schema.types.clone();
+        for index_type in &mut index_types {
+            match &mut index_type.storage_type {
+                payload_config::StorageType::Mmap {
+                    is_on_disk: index_is_on_disk,
+                } => *index_is_on_disk = is_on_disk,
+                // Can't be loaded from mmap files, index must be rebuilt
+                payload_config::StorageType::Gridstore | payload_config::StorageType::RocksDb => {
+                    return Ok(None);
+                }
+            }
+        }
+
+        // Files of the current index are reopened, make sure they are up to date
+        for index in self.field_indexes.get(field).into_iter().flatten() {
+            index.flusher()()?;
+        }
+
+        let indexes = {
+            let id_tracker_borrow = self.id_tracker.borrow();
+            let deleted_points = id_tracker_borrow.deleted_point_bitslice();
+            index_types
+                .iter()
+                .map(|index_type| {
+                    self.selector_with_type(index_type)?.new_index_with_type(
+                        field,
+                        payload_schema,
+                        index_type,
+                        false,
+                        &id_tracker_borrow,
+                        deleted_points,
+                    )
+                })
+                .collect::<OperationResult<Option<Vec<_>>>>()?
+        };
+        let Some(indexes) = indexes else {
+            // Some files are missing, can't reuse them
+            return Ok(None);
+        };
+
+        for index in &indexes {
+            if index.is_on_disk() {
+                index.clear_cache()?;
+            } else {
+                index.populate()?;
+            }
+        }
+
+        // Null index is always kept in memory, it doesn't count
+        let effective_is_on_disk = indexes
+            .iter()
+            .filter(|index| !index.is_null_index())
+            .all(|index| index.is_on_disk());
+
+        self.insert_field_indexes(field.to_owned(), indexes);
+        let old_schema = self.config.indices.insert(
+            field.to_owned(),
+            PayloadFieldSchemaWithIndexType::new(payload_schema.clone(), index_types),
+        );
+        self.save_config()?;
+
+        self.sync_positional_indexes(field, old_schema.map(|i| i.schema).as_ref())?;
+
+        Ok(Some(effective_is_on_disk))
+    }
+
+    /// Keys of the per-position indexes, which back a positional bool index of `field`.
+    ///
+    /// E.g. `permissions[0]`, `permissions[1]`, ... for a bool index with `max_positions` set.
+    fn positional_keys(
+        field: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+    ) -> Vec<PayloadKeyType> {
+        match payload_schema {
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(params)) => params
+                .max_positions
+                .map(|max_positions| (0..max_positions).map(|i| field.element_key(i)).collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn positional_dir(&self) -> PathBuf {
+        self.path.join(POSITIONAL_INDEX_DIR)
+    }
+
+    /// Bring per-position indexes of `field` in line with its current schema.
+    ///
+    /// Positions, which are no longer covered, are dropped. Missing positions are built with
+    /// a plain bool index, which is a no-op for already existing ones.
+    fn sync_positional_indexes(
+        &mut self,
+        field: PayloadKeyTypeRef,
+        old_schema: Option<&PayloadFieldSchema>,
+    ) -> OperationResult<()> {
+        let new_schema = self.config.indices.get(field).map(|i| i.schema.clone());
+        let new_keys = new_schema
+            .as_ref()
+            .map(|schema| Self::positional_keys(field, schema))
+            .unwrap_or_default();
+
+        if let Some(old_schema) = old_schema {
+            for key in Self::positional_keys(field, old_schema) {
+                if !new_keys.contains(&key) {
+                    self.drop_positional_index(&key)?;
+                }
+            }
+        }
+
+        let Some(PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(params))) = new_schema
+        else {
+            return Ok(());
+        };
+        let element_schema =
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(params.element_params()));
+
+        let hw_counter = HardwareCounterCell::disposable(); // Internal operation
+        for key in new_keys {
+            self.set_positional_indexed(&key, &element_schema, &hw_counter)?;
+        }
+
+        Ok(())
+    }
+
+    /// Build the per-position index of `key`, unless an index was created on the same key.
+    fn set_positional_indexed(
+        &mut self,
+        key: PayloadKeyTypeRef,
+        payload_schema: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        if self.config.indices.contains_key(key) {
+            return Ok(());
+        }
+        if let Some(current_schema) = self.config.positional_indices.get(key) {
+            if current_schema.schema == *payload_schema {
+                return Ok(());
+            }
+            self.drop_positional_index(key)?;
+        }
+
+        let positional_dir = self.positional_dir();
+        fs::create_dir_all(&positional_dir)?;
+        let indexes =
+            self.build_field_indexes_in(&positional_dir, key, payload_schema, hw_counter)?;
+        let index_types = indexes.iter().map(|i| i.get_full_index_type()).collect();
+
+        self.insert_field_indexes(key.to_owned(), indexes);
+        self.config.positional_indices.insert(
+            key.to_owned(),
+            PayloadFieldSchemaWithIndexType::new(payload_schema.clone(), index_types),
+        );
+        self.save_config()
+    }
+
+    /// Drop the per-position index of `key`, if there is one.
+    fn drop_positional_index(&mut self, key: PayloadKeyTypeRef) -> OperationResult<()> {
+        if self.config.positional_indices.remove(key).is_none() {
+            return Ok(());
+        }
+
+        // An index created on the same key is never replaced
+        if !self.config.indices.contains_key(key)
+            && let Some(indexes) = self.field_indexes.remove(key)
+        {
+            for index in indexes {
+                index.wipe()?;
+            }
+        }
+
+        self.save_config()
+    }
+
+    /// Build the per-position index of `key` again, after an index created on the same key is
+    /// dropped, if a positional bool index covers it.
+    fn restore_positional_index(&mut self, key: PayloadKeyTypeRef) -> OperationResult<()> {
+        let parent = self
+            .config
+            .indices
+            .iter()
+            .find(|(field, schema)| Self::positional_keys(field, &schema.schema).contains(key))
+            .map(|(field, _)| field.clone());
+
+        match parent {
+            Some(parent) => self.sync_positional_indexes(&parent, None),
+            None => Ok(()),
+        }
+    }
+
+    /// Drop the index of `field`, without restoring the per-position index on the same key
+    fn remove_index(&mut self, field: PayloadKeyTypeRef) -> OperationResult<bool> {
+        let removed_config = self.config.indices.remove(field);
+        // Per-position index on the same key is not removed with the created one
+        let is_positional =
+            removed_config.is_none() && self.config.positional_indices.contains_key(field);
+        let removed_indexes = if is_positional {
+            None
+        } else {
+            self.field_indexes.remove(field)
+        };
+        self.oversized_values.remove(field);
+
+        let is_removed = removed_config.is_some() || removed_indexes.is_some();
+
+        if let Some(indexes) = removed_indexes {
+            for index in indexes {
+                index.wipe()?;
+            }
+        }
+
+        if let Some(removed_config) = &removed_config {
+            for key in Self::positional_keys(field, &removed_config.schema) {
+                self.drop_positional_index(&key)?;
+            }
+        }
+
+        self.save_config()?;
+
+        Ok(is_removed)
+    }
+
+    fn swap_index_impl(
+        &mut self,
+        field: &PayloadKeyType,
+        payload_schema: PayloadFieldSchema,
+        field_index: Vec<FieldIndex>,
+        staging_dir: &Path,
+    ) -> OperationResult<()> {
+        // Persist built indexes and close them, they are reopened from their final location
+        let index_types: Vec<_> = field_index
+            .iter()
+            .map(|i| i.get_full_index_type())
+            .collect();
+        let mut new_entries = BTreeSet::new();
+        for index in &field_index {
+            index.flusher()()?;
+            new_entries.extend(top_level_entries(staging_dir, index.files()));
+        }
+        drop(field_index);
+
+        let mut old_entries = BTreeSet::new();
+        for index in self.field_indexes.get(field).into_iter().flatten() {
+            index.flusher()()?;
+            old_entries.extend(top_level_entries(&self.path, index.files()));
+        }
+
+        let backup_dir = self.swap_backup_dir(field);
+        let old_schema = self.config.indices.get(field).cloned();
+
+        // Nothing is changed until this point, from now on failures are rolled back.
+        // Close the current index, so its files can be moved.
+        self.field_indexes.remove(field);
+
+        let commit_path = backup_dir.join(SWAP_COMMIT_FILE);
+
+        let mut moved_entries = Vec::new();
+        let swapped = self.move_swapped_entries(
+            &old_entries,
+            &new_entries,
+            staging_dir,
+            &backup_dir,
+            &mut moved_entries,
+        );
+
+        let committed = swapped.and_then(|()| {
+            let mut schema_with_types =
+                PayloadFieldSchemaWithIndexType::new(payload_schema, index_types);
+            let (indexes, _) = self.load_from_db(field, &mut schema_with_types, false)?;
+            self.insert_field_indexes(field.clone(), indexes);
+
+            // Commit point of the swap, from here on recovery keeps the new index
+            let mut commit_config = PayloadConfig::default();
+            commit_config
+                .indices
+                .insert(field.clone(), schema_with_types.clone());
+            commit_config.save(&commit_path)?;
+
+            self.config.indices.insert(field.clone(), schema_with_types);
+            self.save_config()
+        });
+
+        if let Err(err) = committed {
+            log::error!("Failed to swap payload index for field `{field}`, restoring: {err}");
+            // Revert the commit first, so recovery doesn't keep the new index
+            remove_path(&commit_path)?;
+            self.field_indexes.remove(field);
+            match &old_schema {
+                Some(old_schema) => self
+                    .config
+                    .indices
+                    .insert(field.clone(), old_schema.clone()),
+                None => self.config.indices.remove(field),
+            };
+
+            for entry in &moved_entries {
+                remove_path(&self.path.join(entry))?;
+            }
+            if backup_dir.exists() {
+                restore_entries(&self.path, &backup_dir)?;
+                fs::remove_dir_all(&backup_dir)?;
+            }
+            if let Some(mut old_schema) = old_schema {
+                let (indexes, _) = self.load_from_db(field, &mut old_schema, false)?;
+                self.insert_field_indexes(field.clone(), indexes);
+            }
+            return Err(err);
+        }
+
+        if backup_dir.exists()
+            && let Err(err) = fs::remove_dir_all(&backup_dir)
+        {
+            log::warn!(
+                "Failed to remove payload index backup dir {}: {err}",
+                backup_dir.display(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Move files of the current index into the backup dir, and files of the new index in place
+    fn move_swapped_entries(
+        &self,
+        old_entries: &BTreeSet<PathBuf>,
+        new_entries: &BTreeSet<PathBuf>,
+        staging_dir: &Path,
+        backup_dir: &Path,
+        moved_entries: &mut Vec<PathBuf>,
+    ) -> OperationResult<()> {
+        if backup_dir.exists() {
+            fs::remove_dir_all(backup_dir)?;
+        }
+        fs::create_dir_all(backup_dir)?;
+
+        for entry in old_entries {
+            fs::rename(self.path.join(entry), backup_dir.join(entry))?;
+        }
+
+        for entry in new_entries {
+            let target = self.path.join(entry);
+            if target.exists() {
+                // Not owned by any loaded index
+                remove_path(&target)?;
+            }
+            fs::rename(staging_dir.join(entry), &target)?;
+            moved_entries.push(entry.clone());
+        }
+
+        Ok(())
+    }
+
+    fn swap_staging_dir(&self, field: PayloadKeyTypeRef) -> PathBuf {
+        self.path.join(SWAP_STAGING_DIR).join(field.filename())
+    }
+
+    fn swap_backup_dir(&self, field: PayloadKeyTypeRef) -> PathBuf {
+        self.path.join(SWAP_BACKUP_DIR).join(field.filename())
+    }
+
+    /// Clean up after a swap, which was interrupted by a crash
+    ///
+    /// Committed swaps are completed, `config` is updated to the new schema if it was not
+    /// persisted yet. Files of the replaced index are restored for swaps that were not committed.
+    fn recover_interrupted_swaps(path: &Path, config: &mut PayloadConfig) -> OperationResult<()> {
+        let backup_root = path.join(SWAP_BACKUP_DIR);
+        if backup_root.exists() {
+            let mut is_config_changed = false;
+            for backup_dir in fs::read_dir(&backup_root)? {
+                let backup_dir = backup_dir?.path();
+                let commit_path = backup_dir.join(SWAP_COMMIT_FILE);
+
+                if commit_path.exists() {
+                    log::warn!(
+                        "Completing interrupted payload index swap: {}",
+                        backup_dir.display(),
+                    );
+                    let commit_config = PayloadConfig::load(&commit_path)?;
+                    for (field, schema) in commit_config.indices.iter() {
+                        config.indices.insert(field.clone(), schema.clone());
+                    }
+                    is_config_changed = true;
+                } else {
+                    log::warn!(
+                        "Restoring payload index after interrupted swap: {}",
+                        backup_dir.display(),
+                    );
+                    restore_entries(path, &backup_dir)?;
+                }
+            }
+
+            if is_config_changed {
+                config.save(&PayloadConfig::get_config_path(path))?;
+            }
+            fs::remove_dir_all(&backup_root)?;
+        }
+
+        let staging_root = path.join(SWAP_STAGING_DIR);
+        if staging_root.exists() {
+            fs::remove_dir_all(&staging_root)?;
+        }
+
+        Ok(())
+    }
+
     /// Number of available points
     ///
     /// - excludes soft deleted points
@@ -404,23 +1055,61 @@ impl StructPayloadIndex {
                 let available_points = self.available_point_count();
                 let condition = FieldCondition::new_is_empty(field.key.clone(), true);
 
-                self.estimate_field_condition(&condition, nested_path, hw_counter)?
-                    .unwrap_or_else(|| CardinalityEstimation::unknown(available_points))
+                let Some(estimation) =
+                    self.estimate_field_condition(&condition, nested_path, hw_counter)?
+                else {
+                    return Ok(CardinalityEstimation::unknown(available_points));
+                };
+
+                // Null index doesn't know deleted points, so it can't count empty ones exactly.
+                // But it counts points with values exactly, the rest of available points are empty.
+                if nested_path.is_none() {
+                    let has_values = FieldCondition::new_is_empty(field.key.clone(), false);
+                    if let Some(not_empty) =
+                        self.estimate_field_condition(&has_values, None, hw_counter)?
+                        && not_empty.min == not_empty.max
+                    {
+                        let empty = available_points.saturating_sub(not_empty.max);
+                        return Ok(CardinalityEstimation {
+                            min: empty,
+                            exp: empty,
+                            max: empty,
+                            ..estimation
+                        });
+                    }
+                }
+
+                estimation
             }
-            Condition::IsNull(IsNullCondition { is_null: field }) => {
+            Condition::IsNull(IsNullCondition {
+                is_null: field,
+                null_elements,
+            }) => {
                 let available_points = self.available_point_count();
                 let condition = FieldCondition::new_is_null(field.key.clone(), true);
 
+                // Index selects points with null values and null elements,
+                // which is only an upper bound for other treatments of null elements
                 self.estimate_field_condition(&condition, nested_path, hw_counter)?
+                    .map(|estimation| match null_elements.unwrap_or_default() {
+                        NullElements::Include => estimation,
+                        NullElements::Ignore | NullElements::Only => CardinalityEstimation {
+                            min: 0,
+                            ..estimation
+                        },
+                    })
                     .unwrap_or_else(|| CardinalityEstimation::unknown(available_points))
             }
             Condition::HasId(has_id) => {
                 let point_ids = has_id.has_id.clone();
                 let id_tracker = self.id_tracker.borrow();
-                let resolved_point_offsets: Vec<PointOffsetType> = point_ids
+                let mut resolved_point_offsets: Vec<PointOffsetType> = point_ids
                     .iter()
                     .filter_map(|external_id| id_tracker.internal_id(*external_id))
                     .collect();
+                // Iterate the points in the storage order, so that scoring them reads the storage
+                // sequentially instead of at random
+                resolved_point_offsets.sort_unstable();
                 let num_ids = resolved_point_offsets.len();
                 CardinalityEstimation {
                     primary_clauses: vec![PrimaryCondition::Ids(ResolvedHasId {
@@ -443,6 +1132,32 @@ impl StructPayloadIndex {
                     CardinalityEstimation::exact(0)
                 }
             }
+            Condition::UpdatedAfter(_) => {
+                CardinalityEstimation::unknown(self.available_point_count())
+            }
+            Condition::Expression(expression) => {
+                // Only points with values of all fields may match, so narrow down the
+                // candidates to the least populated indexed field
+                let estimations = expression
+                    .expression
+                    .keys()
+                    .into_iter()
+                    .map(|key| {
+                        let has_value = FieldCondition::new_range(key.clone(), Range::default());
+                        self.estimate_field_condition(&has_value, nested_path, hw_counter)
+                    })
+                    .collect::<OperationResult<Vec<_>>>()?;
+                estimations
+                    .into_iter()
+                    .flatten()
+                    .min_by_key(|estimation| estimation.max)
+                    .map(|estimation| CardinalityEstimation {
+                        min: 0,
+                        exp: estimation.exp / 2,
+                        ..estimation
+                    })
+                    .unwrap_or_else(|| CardinalityEstimation::unknown(self.available_point_count()))
+            }
             Condition::Field(field_condition) => self
                 .estimate_field_condition(field_condition, nested_path, hw_counter)?
                 .unwrap_or_else(|| CardinalityEstimation::unknown(self.available_point_count())),
@@ -462,6 +1177,74 @@ impl StructPayloadIndex {
         Ok(())
     }
 
+    /// Reject `payload`, if any of its values exceeds the size limit of a field index with the
+    /// `error` policy.
+    ///
+    /// Checked before the payload is stored, so a rejected update doesn't leave the stored payload
+    /// out of sync with the index.
+    fn check_value_sizes(&self, payload: &Payload) -> OperationResult<()> {
+        for (field, schema) in self.config.indices.iter() {
+            if let Some(limit) = ValueSizeLimit::from_schema(&schema.schema) {
+                limit.check(field, &payload.get_value(field))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reject `payload`, if any of its values can't be stored at the precision of a float index.
+    ///
+    /// Checked before the payload is stored, like [`Self::check_value_sizes`].
+    fn check_value_precisions(&self, payload: &Payload) -> OperationResult<()> {
+        for (field, precision) in self.value_precisions() {
+            for value in payload.get_value(field) {
+                for number in <ScaledFloatIndex as ValueIndexer>::get_values(value) {
+                    scale_value(number, precision)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Precisions of the float indexes of all fields with a precision
+    fn value_precisions(&self) -> impl Iterator<Item = (&PayloadKeyType, f64)> {
+        self.config
+            .indices
+            .iter()
+            .filter_map(|(field, schema)| match &schema.schema {
+                PayloadFieldSchema::FieldParams(PayloadSchemaParams::Float(params)) => {
+                    Some((field, params.precision?.into_inner()))
+                }
+                _ => None,
+            })
+    }
+
+    /// Size limit of the indexed values of the field, if any
+    pub(crate) fn value_size_limit(&self, field: PayloadKeyTypeRef) -> Option<ValueSizeLimit> {
+        self.config
+            .indices
+            .get(field)
+            .and_then(|schema| ValueSizeLimit::from_schema(&schema.schema))
+    }
+
+    /// Size limits of the indexed values of all fields with a limit
+    pub(crate) fn value_size_limits(&self) -> HashMap<PayloadKeyType, ValueSizeLimit> {
+        self.config
+            .indices
+            .iter()
+            .filter_map(|(field, schema)| {
+                Some((field.clone(), ValueSizeLimit::from_schema(&schema.schema)?))
+            })
+            .collect()
+    }
+
+    /// Whether any field index rejects oversized values, see [`Self::check_value_sizes`]
+    fn rejects_oversized_values(&self) -> bool {
+        self.config.indices.iter().any(|(_, schema)| {
+            ValueSizeLimit::from_schema(&schema.schema)
+                .is_some_and(|limit| limit.rejects_oversized())
+        })
+    }
+
     pub fn config(&self) -> &PayloadConfig {
         &self.config
     }
@@ -476,50 +1259,232 @@ impl StructPayloadIndex {
 
     /// Select which type of PayloadIndex to use for the field
     fn selector(&self, payload_schema: &PayloadFieldSchema) -> IndexSelector<'_> {
+        self.selector_in(&self.path, payload_schema)
+    }
+
+    /// Same as [`Self::selector`], but places indexes in `dir`
+    fn selector_in<'a>(
+        &self,
+        dir: &'a Path,
+        payload_schema: &PayloadFieldSchema,
+    ) -> IndexSelector<'a> {
         let is_on_disk = payload_schema.is_on_disk();
 
         match &self.storage_type {
             StorageType::GridstoreAppendable => {
-                IndexSelector::Gridstore(IndexSelectorGridstore { dir: &self.path })
+                IndexSelector::Gridstore(IndexSelectorGridstore { dir })
+            }
+            StorageType::GridstoreNonAppendable => {
+                IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk })
             }
-            StorageType::GridstoreNonAppendable => IndexSelector::Mmap(IndexSelectorMmap {
-                dir: &self.path,
-                is_on_disk,
-            }),
         }
     }
 
-    fn selector_with_type(
-        &self,
-        index_type: &FullPayloadIndexType,
-    ) -> OperationResult<IndexSelector<'_>> {
-        let selector = match index_type.storage_type {
-            payload_config::StorageType::Gridstore => {
-                IndexSelector::Gridstore(IndexSelectorGridstore { dir: &self.path })
-            }
-            payload_config::StorageType::RocksDb => {
-                return Err(OperationError::service_error(
-                    "Loading payload index failed: Index is RocksDB but RocksDB feature is disabled.",
-                ));
-            }
-            payload_config::StorageType::Mmap { is_on_disk } => {
-                IndexSelector::Mmap(IndexSelectorMmap {
-                    dir: &self.path,
-                    is_on_disk,
-                })
+    fn selector_with_type(
+        &self,
+        index_type: &FullPayloadIndexType,
+    ) -> OperationResult<IndexSelector<'_>> {
+        self.selector_with_type_in(&self.path, index_type)
+    }
+
+    /// Same as [`Self::selector_with_type`], but places indexes in `dir`
+    fn selector_with_type_in<'a>(
+        &self,
+        dir: &'a Path,
+        index_type: &FullPayloadIndexType,
+    ) -> OperationResult<IndexSelector<'a>> {
+        let selector = match index_type.storage_type {
+            payload_config::StorageType::Gridstore => {
+                IndexSelector::Gridstore(IndexSelectorGridstore { dir })
+            }
+            payload_config::StorageType::RocksDb => {
+                return Err(OperationError::service_error(
+                    "Loading payload index failed: Index is RocksDB but RocksDB feature is disabled.",
+                ));
+            }
+            payload_config::StorageType::Mmap { is_on_disk } => {
+                IndexSelector::Mmap(IndexSelectorMmap { dir, is_on_disk })
+            }
+        };
+
+        Ok(selector)
+    }
+
+    pub fn get_facet_index(&self, key: &JsonPath) -> OperationResult<FacetIndexEnum<'_>> {
+        self.field_indexes
+            .get(key)
+            .and_then(|index| index.iter().find_map(|index| index.as_facet_index()))
+            .ok_or_else(|| OperationError::MissingMapIndexForFacet {
+                key: key.to_string(),
+            })
+    }
+
+    /// Tokens stored for the point in the full-text index of the field.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub fn point_text_tokens(
+        &self,
+        key: &JsonPath,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<String>>> {
+        self.field_indexes
+            .get(key)
+            .and_then(|indexes| {
+                indexes.iter().find_map(|index| match index {
+                    FieldIndex::FullTextIndex(index) => Some(index),
+                    _ => None,
+                })
+            })
+            .map(|index| index.point_tokens(point_id, hw_counter))
+            .transpose()
+    }
+
+    /// Occurrences of the query tokens of the `text` in the values of the field of the point,
+    /// tokenized with its full-text index.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub fn point_text_match_offsets(
+        &self,
+        key: &JsonPath,
+        point_id: PointOffsetType,
+        text: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextValueMatches>>> {
+        let Some(index) = self.field_indexes.get(key).and_then(|indexes| {
+            indexes.iter().find_map(|index| match index {
+                FieldIndex::FullTextIndex(index) => Some(index),
+                _ => None,
+            })
+        }) else {
+            return Ok(None);
+        };
+
+        let payload = self.get_payload(point_id, hw_counter)?;
+        let values = payload.get_value(key);
+        Ok(Some(index.text_match_offsets(&values, text)))
+    }
+
+    /// Excerpts of the values of the field of the point around the matches of the `text`, with
+    /// the query tokens highlighted.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub fn point_text_snippets(
+        &self,
+        key: &JsonPath,
+        point_id: PointOffsetType,
+        text: &str,
+        params: SnippetParams,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextSnippet>>> {
+        let Some(index) = self.field_indexes.get(key).and_then(|indexes| {
+            indexes.iter().find_map(|index| match index {
+                FieldIndex::FullTextIndex(index) => Some(index),
+                _ => None,
+            })
+        }) else {
+            return Ok(None);
+        };
+
+        let payload = self.get_payload(point_id, hw_counter)?;
+        let values = payload.get_value(key);
+        Ok(Some(index.text_snippets(&values, text, params)))
+    }
+
+    /// Values of the field sampled from its integer or float index, for splitting them into
+    /// quantiles.
+    ///
+    /// Returns `None` if the field has no such index.
+    pub fn value_samples(&self, key: &JsonPath) -> Option<ValueSamples> {
+        self.field_indexes.get(key).and_then(|indexes| {
+            indexes.iter().find_map(|index| match index {
+                FieldIndex::IntIndex(index) => Some(index.inner().value_samples()),
+                FieldIndex::FloatIndex(index) => Some(index.inner().value_samples()),
+                FieldIndex::ScaledFloatIndex(index) => Some(index.value_samples()),
+                _ => None,
+            })
+        })
+    }
+
+    fn full_text_index(&self, key: &JsonPath) -> Option<&FullTextIndex> {
+        self.field_indexes.get(key).and_then(|indexes| {
+            indexes.iter().find_map(|index| match index {
+                FieldIndex::FullTextIndex(index) => Some(index),
+                _ => None,
+            })
+        })
+    }
+
+    /// Insert the indexes of the field, assigning the stopwords detected by the shard for it
+    fn insert_field_indexes(&mut self, field: PayloadKeyType, mut indexes: Vec<FieldIndex>) {
+        if let Some(stopwords) = self.text_auto_stopwords.get(&field) {
+            for index in &mut indexes {
+                if let FieldIndex::FullTextIndex(index) = index {
+                    index.set_detected_stopwords(stopwords.clone());
+                }
+            }
+        }
+        self.field_indexes.insert(field, indexes);
+    }
+
+    /// Number of points in the full-text index of the field, `0` if there is no such index.
+    pub fn text_index_points_count(&self, key: &JsonPath) -> usize {
+        self.full_text_index(key)
+            .map_or(0, |index| index.points_count())
+    }
+
+    /// Tokens contained in at least `min_fraction` of the points in the full-text index of the
+    /// field. Empty if there is no such index.
+    pub fn text_frequent_tokens(
+        &self,
+        key: &JsonPath,
+        min_fraction: f64,
+    ) -> OperationResult<Vec<String>> {
+        match self.full_text_index(key) {
+            Some(index) => index.frequent_tokens(min_fraction),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Number of points containing each of the `tokens` in the full-text index of the field.
+    /// All `0` if there is no such index.
+    pub fn text_tokens_points_count(
+        &self,
+        key: &JsonPath,
+        tokens: &[String],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<usize>> {
+        match self.full_text_index(key) {
+            Some(index) => index.tokens_points_count(tokens, hw_counter),
+            None => Ok(vec![0; tokens.len()]),
+        }
+    }
+
+    /// Assign the stopwords detected by the shard to the full-text index of the field, and to
+    /// the full-text indexes of the field built later.
+    pub fn set_text_auto_stopwords(&mut self, key: &JsonPath, stopwords: Arc<AHashSet<String>>) {
+        if let Some(indexes) = self.field_indexes.get_mut(key) {
+            for index in indexes {
+                if let FieldIndex::FullTextIndex(index) = index {
+                    index.set_detected_stopwords(stopwords.clone());
+                }
             }
-        };
-
-        Ok(selector)
+        }
+        self.text_auto_stopwords.insert(key.clone(), stopwords);
     }
 
-    pub fn get_facet_index(&self, key: &JsonPath) -> OperationResult<FacetIndexEnum<'_>> {
-        self.field_indexes
-            .get(key)
-            .and_then(|index| index.iter().find_map(|index| index.as_facet_index()))
-            .ok_or_else(|| OperationError::MissingMapIndexForFacet {
-                key: key.to_string(),
-            })
+    /// Query tokens removed as stopwords, when querying the full-text index of the field with
+    /// the `text`.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub fn text_query_stopwords(
+        &self,
+        key: &JsonPath,
+        text: &str,
+        language: Option<Language>,
+    ) -> Option<QueryStopwords> {
+        self.full_text_index(key)
+            .map(|index| index.text_query_stopwords(text, language))
     }
 
     pub fn populate(&self) -> OperationResult<()> {
@@ -531,6 +1496,29 @@ impl StructPayloadIndex {
         Ok(())
     }
 
+    /// Populate pages of the indexes of the field, which fit into the `budget`.
+    pub fn populate_with_budget(
+        &self,
+        key: &PayloadKeyType,
+        budget: &mut PopulateBudget,
+    ) -> OperationResult<()> {
+        for index in self.field_indexes.get(key).into_iter().flatten() {
+            index.populate_with_budget(budget)?;
+        }
+        Ok(())
+    }
+
+    /// Approximate RAM usage in bytes of the field indexes kept in memory, excluding indexes
+    /// read from disk.
+    pub fn in_memory_indexes_ram_bytes(&self) -> usize {
+        self.field_indexes
+            .values()
+            .flatten()
+            .filter(|index| !index.is_on_disk())
+            .map(|index| index.ram_usage_bytes())
+            .sum()
+    }
+
     pub fn clear_cache(&self) -> OperationResult<()> {
         for field_indexes in self.field_indexes.values() {
             for index in field_indexes {
@@ -552,6 +1540,68 @@ impl StructPayloadIndex {
     }
 }
 
+/// Names of the entries directly inside `dir`, which contain the given files
+fn top_level_entries(dir: &Path, files: Vec<PathBuf>) -> impl Iterator<Item = PathBuf> + '_ {
+    files.into_iter().filter_map(move |file| {
+        let relative = file.strip_prefix(dir).ok()?;
+        relative
+            .components()
+            .next()
+            .map(|entry| PathBuf::from(entry.as_os_str()))
+    })
+}
+
+/// Move all entries of `backup_dir` back into `dir`
+fn restore_entries(dir: &Path, backup_dir: &Path) -> OperationResult<()> {
+    for entry in fs::read_dir(backup_dir)? {
+        let entry = entry?;
+        let target = dir.join(entry.file_name());
+        remove_path(&target)?;
+        fs::rename(entry.path(), target)?;
+    }
+    Ok(())
+}
+
+fn remove_path(path: &Path) -> OperationResult<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)?;
+    } else if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Add payload values of a point to all indexes of `field`.
+///
+/// Values are limited according to `value_size_limit` of the field. The null index gets the
+/// values as they are, oversized values still exist in the payload.
+fn add_point_to_field_indexes(
+    indexes: &mut [FieldIndex],
+    point_id: PointOffsetType,
+    field: PayloadKeyTypeRef,
+    values: &[&Value],
+    value_size_limit: Option<ValueSizeLimit>,
+    oversized_values: &OversizedValueStats,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<()> {
+    let limited_values = value_size_limit
+        .map(|limit| limit.apply(field, values, oversized_values))
+        .transpose()?
+        .flatten();
+    let limited_values: Option<Vec<_>> = limited_values
+        .as_ref()
+        .map(|values| values.iter().collect());
+
+    for index in indexes {
+        let values: &[&Value] = match &limited_values {
+            Some(limited_values) if !index.is_null_index() => limited_values,
+            _ => values,
+        };
+        index.add_point(point_id, values, hw_counter)?;
+    }
+    Ok(())
+}
+
 impl PayloadIndexRead for StructPayloadIndex {
     fn indexed_fields(&self) -> HashMap<PayloadKeyType, PayloadFieldSchema> {
         self.config.indices.to_schemas()
@@ -562,10 +1612,7 @@ impl PayloadIndexRead for StructPayloadIndex {
         query: &Filter,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<CardinalityEstimation> {
-        let available_points = self.available_point_count();
-        let estimator =
-            |condition: &Condition| self.condition_cardinality(condition, None, hw_counter);
-        estimate_filter(&estimator, query, available_points)
+        self.estimate_filter_cardinality(query, None, hw_counter)
     }
 
     fn estimate_nested_cardinality(
@@ -574,11 +1621,7 @@ impl PayloadIndexRead for StructPayloadIndex {
         nested_path: &JsonPath,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<CardinalityEstimation> {
-        let available_points = self.available_point_count();
-        let estimator = |condition: &Condition| {
-            self.condition_cardinality(condition, Some(nested_path), hw_counter)
-        };
-        estimate_filter(&estimator, query, available_points)
+        self.estimate_filter_cardinality(query, Some(nested_path), hw_counter)
     }
 
     fn query_points(
@@ -612,13 +1655,29 @@ impl PayloadIndexRead for StructPayloadIndex {
             .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
     }
 
-    fn get_telemetry_data(&self) -> Vec<PayloadIndexTelemetry> {
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> Vec<PayloadIndexTelemetry> {
         self.field_indexes
             .iter()
             .flat_map(|(name, field)| -> Vec<PayloadIndexTelemetry> {
+                let has_size_limit = self
+                    .config
+                    .indices
+                    .get(name)
+                    .and_then(|schema| ValueSizeLimit::from_schema(&schema.schema))
+                    .is_some();
+                let oversized_values =
+                    has_size_limit.then(|| self.oversized_values.get(name).unwrap_or_default());
                 field
                     .iter()
-                    .map(|field| field.get_telemetry_data().set_name(name.to_string()))
+                    .map(|field| {
+                        let telemetry = field.get_telemetry_data(detail).set_name(name.to_string());
+                        match oversized_values {
+                            Some(counts) if !field.is_null_index() => {
+                                telemetry.set_oversized_values(counts)
+                            }
+                            _ => telemetry,
+                        }
+                    })
                     .collect()
             })
             .collect()
@@ -639,11 +1698,13 @@ impl PayloadIndexRead for StructPayloadIndex {
         let ParsedFormula {
             payload_vars,
             conditions,
+            text_scores,
             defaults,
             formula,
         } = parsed_formula;
 
         let payload_retrievers = self.retrievers_map(payload_vars.clone(), hw_counter);
+        let text_scorers = self.text_scorers(text_scores, hw_counter)?;
 
         let payload_provider = PayloadProvider::new(self.payload.clone());
         let total = self.available_point_count();
@@ -658,6 +1719,7 @@ impl PayloadIndexRead for StructPayloadIndex {
             prefetches_scores,
             payload_retrievers,
             condition_checkers,
+            text_scorers,
             defaults.clone(),
         ))
     }
@@ -829,15 +1891,20 @@ impl PayloadIndex for StructPayloadIndex {
             .iter()
             .map(|i| i.get_full_index_type())
             .collect();
-        self.field_indexes.insert(field.clone(), field_index);
 
-        self.config.indices.insert(
-            field,
+        // Replaces a per-position index on the same key, if there is one
+        self.drop_positional_index(&field)?;
+        self.insert_field_indexes(field.clone(), field_index);
+
+        let old_schema = self.config.indices.insert(
+            field.clone(),
             PayloadFieldSchemaWithIndexType::new(payload_schema, index_types),
         );
 
         self.save_config()?;
 
+        self.sync_positional_indexes(&field, old_schema.map(|i| i.schema).as_ref())?;
+
         Ok(())
     }
 
@@ -872,19 +1939,10 @@ impl PayloadIndex for StructPayloadIndex {
     }
 
     fn drop_index(&mut self, field: PayloadKeyTypeRef) -> OperationResult<bool> {
-        let removed_config = self.config.indices.remove(field);
-        let removed_indexes = self.field_indexes.remove(field);
-
-        let is_removed = removed_config.is_some() || removed_indexes.is_some();
-
-        if let Some(indexes) = removed_indexes {
-            for index in indexes {
-                index.wipe()?;
-            }
+        let is_removed = self.remove_index(field)?;
+        if is_removed {
+            self.restore_positional_index(field)?;
         }
-
-        self.save_config()?;
-
         Ok(is_removed)
     }
 
@@ -903,7 +1961,7 @@ impl PayloadIndex for StructPayloadIndex {
             return Ok(false);
         }
 
-        self.drop_index(field)
+        self.remove_index(field)
     }
 
     fn overwrite_payload(
@@ -912,6 +1970,9 @@ impl PayloadIndex for StructPayloadIndex {
         payload: &Payload,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
+        self.check_value_sizes(payload)?;
+        self.check_value_precisions(payload)?;
+
         self.payload
             .borrow_mut()
             .overwrite(point_id, payload, hw_counter)?;
@@ -919,9 +1980,20 @@ impl PayloadIndex for StructPayloadIndex {
         for (field, field_index) in &mut self.field_indexes {
             let field_value = payload.get_value(field);
             if !field_value.is_empty() {
-                for index in field_index {
-                    index.add_point(point_id, &field_value, hw_counter)?;
-                }
+                let value_size_limit = self
+                    .config
+                    .indices
+                    .get(field)
+                    .and_then(|schema| ValueSizeLimit::from_schema(&schema.schema));
+                add_point_to_field_indexes(
+                    field_index,
+                    point_id,
+                    field,
+                    &field_value,
+                    value_size_limit,
+                    &self.oversized_values,
+                    hw_counter,
+                )?;
             } else {
                 for index in field_index {
                     index.remove_point(point_id)?;
@@ -939,10 +2011,20 @@ impl PayloadIndex for StructPayloadIndex {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         if let Some(key) = key {
+            // Values are merged into the stored payload under the key, check the result
+            if self.rejects_oversized_values() || self.value_precisions().next().is_some() {
+                let mut updated_payload = self.get_payload(point_id, hw_counter)?;
+                updated_payload.merge_by_key(payload, key);
+                self.check_value_sizes(&updated_payload)?;
+                self.check_value_precisions(&updated_payload)?;
+            }
             self.payload
                 .borrow_mut()
                 .set_by_key(point_id, payload, key, hw_counter)?;
         } else {
+            // Top-level keys of the payload replace the stored ones, so it has all new values
+            self.check_value_sizes(payload)?;
+            self.check_value_precisions(payload)?;
             self.payload
                 .borrow_mut()
                 .set(point_id, payload, hw_counter)?;
@@ -955,9 +2037,20 @@ impl PayloadIndex for StructPayloadIndex {
             }
             let field_value = updated_payload.get_value(field);
             if !field_value.is_empty() {
-                for index in field_index {
-                    index.add_point(point_id, &field_value, hw_counter)?;
-                }
+                let value_size_limit = self
+                    .config
+                    .indices
+                    .get(field)
+                    .and_then(|schema| ValueSizeLimit::from_schema(&schema.schema));
+                add_point_to_field_indexes(
+                    field_index,
+                    point_id,
+                    field,
+                    &field_value,
+                    value_size_limit,
+                    &self.oversized_values,
+                    hw_counter,
+                )?;
             } else {
                 for index in field_index {
                     index.remove_point(point_id)?;
@@ -990,6 +2083,24 @@ impl PayloadIndex for StructPayloadIndex {
         self.payload.borrow_mut().clear(point_id, hw_counter)
     }
 
+    fn clear_payloads(
+        &mut self,
+        point_ids: &[PointOffsetType],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        // Some indexes remove many points much faster than one by one
+        for field_indexes in self.field_indexes.values_mut() {
+            for index in field_indexes {
+                index.remove_points(point_ids)?;
+            }
+        }
+        let mut payload = self.payload.borrow_mut();
+        for &point_id in point_ids {
+            payload.clear(point_id, hw_counter)?;
+        }
+        Ok(())
+    }
+
     fn flusher(&self) -> Flusher {
         // Most field indices have either 2 or 3 indices (including null), we also have an extra
         // payload storage flusher. Overallocate to save potential reallocations.
@@ -1040,16 +2151,26 @@ mod tests {
     use std::str::FromStr;
     use std::sync::atomic::AtomicBool;
 
+    use common::types::DeferredBehavior;
     use tempfile::Builder;
     use uuid::Uuid;
 
     use super::*;
+    use crate::data_types::build_index_result::BuildFieldIndexResult;
+    use crate::data_types::index::{KeywordIndexParams, OversizedValuePolicy};
     use crate::data_types::vectors::only_default_vector;
-    use crate::entry::{NonAppendableSegmentEntry, SegmentEntry};
+    use crate::entry::{NonAppendableSegmentEntry, ReadSegmentEntry, SegmentEntry};
+    use crate::fixtures::payload_context_fixture::{
+        create_id_tracker_fixture, create_payload_storage_fixture,
+    };
+    use crate::fixtures::payload_fixtures::STR_KEY;
     use crate::index::payload_config::{IndexMutability, PayloadIndexType};
+    use crate::segment::Segment;
     use crate::segment_constructor::load_segment;
     use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
-    use crate::types::{Distance, PayloadSchemaType};
+    use crate::types::{
+        Distance, Match, Nested, NestedCondition, PayloadField, PayloadSchemaType, ValueVariants,
+    };
 
     #[test]
     fn test_load_payload_index() {
@@ -1127,4 +2248,643 @@ mod tests {
         let schema = payload_config.indices.get(&key).unwrap();
         check_index_types(&schema.types);
     }
+
+    #[test]
+    fn test_swap_field_index() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let dim = 2;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let key = JsonPath::from_str("name").unwrap();
+        let keyword_schema = PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword);
+        let text_schema = PayloadFieldSchema::FieldType(PayloadSchemaType::Text);
+
+        let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+        for (idx, name) in ["John Doe", "Jane Doe", "John Smith"]
+            .into_iter()
+            .enumerate()
+        {
+            let point_id = (idx as u64).into();
+            segment
+                .upsert_point(0, point_id, only_default_vector(&[1.0, 1.0]), &hw_counter)
+                .unwrap();
+            let payload: Payload =
+                serde_json::from_value(serde_json::json!({ "name": name })).unwrap();
+            segment
+                .set_full_payload(0, point_id, &payload, &hw_counter)
+                .unwrap();
+        }
+
+        segment
+            .create_field_index(1, &key, Some(&keyword_schema), &hw_counter)
+            .unwrap();
+
+        let BuildFieldIndexResult::Built { indexes, schema } = segment
+            .build_replacement_field_index(2, &key, &text_schema, &hw_counter)
+            .unwrap()
+        else {
+            panic!("replacement index must be built");
+        };
+
+        // The current index is not affected until the swap
+        assert_eq!(segment.get_indexed_fields()[&key], keyword_schema);
+
+        assert!(
+            segment
+                .swap_field_index(2, key.clone(), schema, indexes)
+                .unwrap()
+        );
+        assert_eq!(segment.get_indexed_fields()[&key], text_schema);
+
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            key.clone(),
+            Match::new_text("john"),
+        )));
+        let found = segment
+            .read_filtered(
+                None,
+                None,
+                Some(&filter),
+                &AtomicBool::new(false),
+                &hw_counter,
+                DeferredBehavior::IncludeAll,
+            )
+            .unwrap();
+        assert_eq!(found.len(), 2);
+
+        let payload_index_path = segment.segment_path.join("payload_index");
+        assert!(
+            !payload_index_path
+                .join(SWAP_STAGING_DIR)
+                .join(key.filename())
+                .exists()
+        );
+        assert!(
+            !payload_index_path
+                .join(SWAP_BACKUP_DIR)
+                .join(key.filename())
+                .exists()
+        );
+
+        // Swapped index is loaded from its final location
+        let full_segment_path = segment.segment_path.clone();
+        drop(segment);
+        let segment = load_segment(
+            &full_segment_path,
+            Uuid::nil(),
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        let payload_index = segment.payload_index.borrow();
+        let index_types: Vec<_> = payload_index.field_indexes[&key]
+            .iter()
+            .map(|index| index.get_full_index_type().index_type)
+            .collect();
+        assert_eq!(
+            index_types,
+            [PayloadIndexType::FullTextIndex, PayloadIndexType::NullIndex],
+        );
+    }
+
+    #[test]
+    fn test_recover_interrupted_swaps() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let dim = 2;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let key = JsonPath::from_str("name").unwrap();
+        let keyword_schema = PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword);
+
+        let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+        segment
+            .upsert_point(0, 0.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+            .unwrap();
+        let payload: Payload =
+            serde_json::from_value(serde_json::json!({ "name": "John Doe" })).unwrap();
+        segment
+            .set_full_payload(0, 0.into(), &payload, &hw_counter)
+            .unwrap();
+        segment
+            .create_field_index(1, &key, Some(&keyword_schema), &hw_counter)
+            .unwrap();
+
+        let full_segment_path = segment.segment_path.clone();
+        let payload_index_path = full_segment_path.join("payload_index");
+        drop(segment);
+
+        // Committed swap to an identical schema, interrupted before the payload config was saved
+        let config_path = PayloadConfig::get_config_path(&payload_index_path);
+        let mut config = PayloadConfig::load(&config_path).unwrap();
+        let mut commit_config = PayloadConfig::default();
+        commit_config
+            .indices
+            .insert(key.clone(), config.indices.remove(&key).unwrap());
+        config.save(&config_path).unwrap();
+
+        let committed_dir = payload_index_path
+            .join(SWAP_BACKUP_DIR)
+            .join(key.filename());
+        fs::create_dir_all(committed_dir.join("replaced")).unwrap();
+        commit_config
+            .save(&committed_dir.join(SWAP_COMMIT_FILE))
+            .unwrap();
+
+        // Swap interrupted before the commit
+        let uncommitted_dir = payload_index_path.join(SWAP_BACKUP_DIR).join("other");
+        fs::create_dir_all(uncommitted_dir.join("restored")).unwrap();
+
+        let segment = load_segment(
+            &full_segment_path,
+            Uuid::nil(),
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        // Committed swap is completed, the replaced index is not restored
+        assert_eq!(segment.get_indexed_fields()[&key], keyword_schema);
+        let config = PayloadConfig::load(&config_path).unwrap();
+        assert!(config.indices.contains_key(&key));
+        assert!(!payload_index_path.join("replaced").exists());
+
+        // Uncommitted swap is reverted
+        assert!(payload_index_path.join("restored").exists());
+        assert!(!payload_index_path.join(SWAP_BACKUP_DIR).exists());
+    }
+
+    #[test]
+    fn test_positional_bool_index() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let dim = 2;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let key = JsonPath::from_str("permissions").unwrap();
+        let schema = PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(
+            crate::data_types::index::BoolIndexParams {
+                max_positions: Some(3),
+                ..Default::default()
+            },
+        ));
+
+        let permissions = [
+            serde_json::json!([true, false, true]),
+            serde_json::json!([false]),
+            serde_json::json!([true, true]),
+            serde_json::json!([]),
+            serde_json::json!([false, false, false, false, true]),
+        ];
+
+        let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+        for (idx, value) in permissions.into_iter().enumerate() {
+            let point_id = (idx as u64).into();
+            segment
+                .upsert_point(0, point_id, only_default_vector(&[1.0, 1.0]), &hw_counter)
+                .unwrap();
+            let payload: Payload =
+                serde_json::from_value(serde_json::json!({ "permissions": value })).unwrap();
+            segment
+                .set_full_payload(0, point_id, &payload, &hw_counter)
+                .unwrap();
+        }
+
+        segment
+            .create_field_index(1, &key, Some(&schema), &hw_counter)
+            .unwrap();
+
+        {
+            let payload_index = segment.payload_index.borrow();
+            for position in 0..3 {
+                assert!(
+                    payload_index
+                        .field_indexes
+                        .contains_key(&key.element_key(position))
+                );
+            }
+            assert!(
+                !payload_index
+                    .field_indexes
+                    .contains_key(&key.element_key(3))
+            );
+        }
+
+        let matching_points = |position: usize, value: bool| {
+            let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+                key.element_key(position),
+                Match::from(value),
+            )));
+            let mut found: Vec<_> = segment
+                .read_filtered(
+                    None,
+                    None,
+                    Some(&filter),
+                    &AtomicBool::new(false),
+                    &hw_counter,
+                    DeferredBehavior::IncludeAll,
+                )
+                .unwrap()
+                .into_iter()
+                .map(|point_id| point_id.to_string())
+                .collect();
+            found.sort();
+            found
+        };
+
+        assert_eq!(matching_points(0, true), ["0", "2"]);
+        assert_eq!(matching_points(0, false), ["1", "4"]);
+        assert_eq!(matching_points(1, true), ["2"]);
+        assert_eq!(matching_points(2, true), ["0"]);
+        assert_eq!(matching_points(2, false), ["4"]);
+
+        // Positions beyond `max_positions` are not indexed, but still filterable
+        assert_eq!(matching_points(4, true), ["4"]);
+        // Positions beyond every array never match
+        assert_eq!(matching_points(10, true), Vec::<String>::new());
+        assert_eq!(matching_points(10, false), Vec::<String>::new());
+
+        segment.delete_field_index(2, &key).unwrap();
+
+        let payload_index = segment.payload_index.borrow();
+        assert!(payload_index.field_indexes.is_empty());
+        assert!(payload_index.config.indices.is_empty());
+        assert!(payload_index.config.positional_indices.is_empty());
+    }
+
+    #[test]
+    fn test_positional_bool_index_with_index_on_position() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let dim = 2;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let key = JsonPath::from_str("permissions").unwrap();
+        let position_key = key.element_key(1);
+        let positional_schema = |max_positions| {
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(
+                crate::data_types::index::BoolIndexParams {
+                    max_positions: Some(max_positions),
+                    ..Default::default()
+                },
+            ))
+        };
+        let position_schema = PayloadFieldSchema::FieldParams(PayloadSchemaParams::Bool(
+            crate::data_types::index::BoolIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            },
+        ));
+
+        let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+        segment
+            .upsert_point(0, 0.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+            .unwrap();
+        let payload: Payload =
+            serde_json::from_value(serde_json::json!({ "permissions": [true, false, true] }))
+                .unwrap();
+        segment
+            .set_full_payload(0, 0.into(), &payload, &hw_counter)
+            .unwrap();
+
+        segment
+            .create_field_index(1, &position_key, Some(&position_schema), &hw_counter)
+            .unwrap();
+        segment
+            .create_field_index(2, &key, Some(&positional_schema(3)), &hw_counter)
+            .unwrap();
+
+        let indexed_fields = |segment: &Segment| {
+            let payload_index = segment.payload_index.borrow();
+            let indices = payload_index.config.indices.to_schemas();
+            let mut positional: Vec<_> = payload_index
+                .config
+                .positional_indices
+                .keys()
+                .map(|key| key.to_string())
+                .collect();
+            positional.sort();
+            (indices, positional)
+        };
+
+        // The created index on the position is kept, other positions are indexed separately
+        let (indices, positional) = indexed_fields(&segment);
+        assert_eq!(indices.len(), 2);
+        assert_eq!(indices[&position_key], position_schema);
+        assert_eq!(positional, ["permissions[0]", "permissions[2]"]);
+
+        // Changing the positional index doesn't touch the created index
+        segment
+            .create_field_index(3, &key, Some(&positional_schema(2)), &hw_counter)
+            .unwrap();
+        let (indices, positional) = indexed_fields(&segment);
+        assert_eq!(indices[&position_key], position_schema);
+        assert_eq!(positional, ["permissions[0]"]);
+
+        // Per-position index takes over, once the created index is dropped
+        segment.delete_field_index(4, &position_key).unwrap();
+        let (indices, positional) = indexed_fields(&segment);
+        assert_eq!(indices.keys().collect::<Vec<_>>(), [&key]);
+        assert_eq!(positional, ["permissions[0]", "permissions[1]"]);
+
+        // Per-position indexes are loaded from their own namespace
+        let full_segment_path = segment.segment_path.clone();
+        drop(segment);
+        let segment = load_segment(
+            &full_segment_path,
+            Uuid::nil(),
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let (_, positional) = indexed_fields(&segment);
+        assert_eq!(positional, ["permissions[0]", "permissions[1]"]);
+        assert!(
+            segment
+                .payload_index
+                .borrow()
+                .field_indexes
+                .contains_key(&position_key)
+        );
+    }
+
+    #[test]
+    fn test_is_empty_cardinality() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let dim = 2;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let key = JsonPath::from_str("name").unwrap();
+        let names = [
+            serde_json::json!("John"),
+            serde_json::json!([]),
+            serde_json::json!(null),
+            serde_json::json!(["Jane", "Jim"]),
+            serde_json::json!([]),
+        ];
+
+        let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+        for (idx, value) in names.into_iter().enumerate() {
+            let point_id = (idx as u64).into();
+            segment
+                .upsert_point(0, point_id, only_default_vector(&[1.0, 1.0]), &hw_counter)
+                .unwrap();
+            let payload: Payload =
+                serde_json::from_value(serde_json::json!({ "name": value })).unwrap();
+            segment
+                .set_full_payload(0, point_id, &payload, &hw_counter)
+                .unwrap();
+        }
+        // Point without the field
+        segment
+            .upsert_point(0, 5.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+            .unwrap();
+
+        segment
+            .create_field_index(
+                1,
+                &key,
+                Some(&PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword)),
+                &hw_counter,
+            )
+            .unwrap();
+
+        // Deleted points are not counted as empty
+        segment.delete_point(2, 4.into(), &hw_counter).unwrap();
+
+        let condition = Condition::IsEmpty(IsEmptyCondition {
+            is_empty: PayloadField { key },
+        });
+        let estimation = segment
+            .payload_index
+            .borrow()
+            .condition_cardinality(&condition, None, &hw_counter)
+            .unwrap();
+
+        assert_eq!(estimation.min, 3);
+        assert_eq!(estimation.exp, 3);
+        assert_eq!(estimation.max, 3);
+        assert!(!estimation.primary_clauses.is_empty());
+    }
+
+    #[test]
+    fn test_estimate_field_conditions() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let dim = 2;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut segment = build_simple_segment(dir.path(), dim, Distance::Dot).unwrap();
+        for idx in 0..100u64 {
+            let point_id = idx.into();
+            segment
+                .upsert_point(0, point_id, only_default_vector(&[1.0, 1.0]), &hw_counter)
+                .unwrap();
+            let payload: Payload = serde_json::from_value(serde_json::json!({
+                "number": idx,
+                "name": format!("name {}", idx % 3),
+                "other": idx,
+            }))
+            .unwrap();
+            segment
+                .set_full_payload(0, point_id, &payload, &hw_counter)
+                .unwrap();
+        }
+
+        let number = JsonPath::new("number");
+        let name = JsonPath::new("name");
+        for (key, schema) in [
+            (&number, PayloadSchemaType::Integer),
+            (&name, PayloadSchemaType::Keyword),
+        ] {
+            segment
+                .create_field_index(1, key, Some(&schema.into()), &hw_counter)
+                .unwrap();
+        }
+
+        let range = |gte: f64, lt: f64| Range {
+            gte: Some(gte.into()),
+            lt: Some(lt.into()),
+            ..Default::default()
+        };
+        let conditions = [
+            FieldCondition::new_range(number.clone(), range(0.0, 10.0)),
+            FieldCondition::new_match(name.clone(), "name 1".to_owned().into()),
+            FieldCondition::new_range(number.clone(), range(10.0, 60.0)),
+            FieldCondition::new_range(JsonPath::new("other"), range(0.0, 10.0)),
+            FieldCondition::new_match(number.clone(), "name 1".to_owned().into()),
+            FieldCondition::new_range(number.clone(), range(90.0, 200.0)),
+        ];
+        let conditions = conditions.iter().collect::<Vec<_>>();
+
+        let payload_index = segment.payload_index.borrow();
+        let estimations = payload_index
+            .estimate_field_conditions(&conditions, None, &hw_counter)
+            .unwrap();
+        let expected = conditions
+            .iter()
+            .map(|condition| {
+                payload_index
+                    .estimate_field_condition(condition, None, &hw_counter)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(estimations, expected);
+        assert!(estimations[0].is_some());
+        assert!(estimations[1].is_some());
+        assert!(estimations[3].is_none());
+    }
+
+    #[test]
+    fn test_set_index_on_disk() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let num_points = 100;
+
+        let hw_counter = HardwareCounterCell::new();
+
+        let payload_storage = Arc::new(AtomicRefCell::new(
+            create_payload_storage_fixture(num_points, 42).into(),
+        ));
+        let id_tracker = Arc::new(AtomicRefCell::new(create_id_tracker_fixture(num_points)));
+        let mut index = StructPayloadIndex::open(
+            payload_storage,
+            id_tracker,
+            HashMap::new(),
+            dir.path(),
+            false,
+            true,
+        )
+        .unwrap();
+
+        let key = JsonPath::new(STR_KEY);
+        let keyword_schema = |on_disk, is_tenant| {
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+                on_disk: Some(on_disk),
+                is_tenant,
+                ..Default::default()
+            }))
+        };
+
+        index
+            .set_indexed(&key, keyword_schema(false, None), &hw_counter)
+            .unwrap();
+        let indexed_points = index.field_indexes[&key][0].count_indexed_points();
+        assert!(!index.field_indexes[&key][0].is_on_disk());
+
+        // Mmap files of the index are reopened in the other mode
+        let is_on_disk = index
+            .set_index_on_disk(&key, &keyword_schema(true, None))
+            .unwrap();
+        assert_eq!(is_on_disk, Some(true));
+        assert!(index.field_indexes[&key][0].is_on_disk());
+        assert_eq!(
+            index.field_indexes[&key][0].count_indexed_points(),
+            indexed_points,
+        );
+        assert_eq!(index.indexed_fields()[&key], keyword_schema(true, None));
+
+        let is_on_disk = index
+            .set_index_on_disk(&key, &keyword_schema(false, None))
+            .unwrap();
+        assert_eq!(is_on_disk, Some(false));
+        assert!(!index.field_indexes[&key][0].is_on_disk());
+
+        // Other changes of the schema require a rebuild
+        let is_on_disk = index
+            .set_index_on_disk(&key, &keyword_schema(true, Some(true)))
+            .unwrap();
+        assert_eq!(is_on_disk, None);
+        assert_eq!(index.indexed_fields()[&key], keyword_schema(false, None));
+
+        // Mode is persisted in the config
+        let config = PayloadConfig::load(&PayloadConfig::get_config_path(dir.path())).unwrap();
+        let in_memory = payload_config::StorageType::Mmap { is_on_disk: false };
+        assert!(
+            config.indices[&key]
+                .types
+                .iter()
+                .all(|index_type| index_type.storage_type == in_memory),
+        );
+    }
+
+    #[test]
+    fn test_oversized_values() {
+        let dir = Builder::new().prefix("payload_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+        let is_stopped = AtomicBool::new(false);
+
+        let keyword_schema = |policy| {
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+                max_value_bytes: Some(4),
+                oversized_value_policy: Some(policy),
+                ..Default::default()
+            }))
+        };
+
+        let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+        segment
+            .create_field_index(
+                0,
+                &JsonPath::new("doc.strict"),
+                Some(&keyword_schema(OversizedValuePolicy::Error)),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .create_field_index(
+                0,
+                &JsonPath::new("items[].name"),
+                Some(&keyword_schema(OversizedValuePolicy::Truncate)),
+                &hw_counter,
+            )
+            .unwrap();
+
+        segment
+            .upsert_point(1, 0.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+            .unwrap();
+        let payload: Payload = serde_json::from_value(serde_json::json!({
+            "doc": { "strict": "fits" },
+            "items": [{ "name": "abcdef" }],
+        }))
+        .unwrap();
+        segment
+            .set_full_payload(1, 0.into(), &payload, &hw_counter)
+            .unwrap();
+
+        // Setting a value by key is rejected before the payload is stored
+        let update: Payload =
+            serde_json::from_value(serde_json::json!({ "strict": "too long" })).unwrap();
+        let result = segment.set_payload(
+            2,
+            0.into(),
+            &update,
+            &Some(JsonPath::new("doc")),
+            &hw_counter,
+        );
+        assert!(result.is_err());
+        assert_eq!(segment.payload(0.into(), &hw_counter).unwrap(), payload,);
+
+        // Nested conditions are checked against the payload, with the same truncation as the index
+        let nested_match = |value: &str| {
+            Filter::new_must(Condition::Nested(NestedCondition::new(Nested {
+                key: JsonPath::new("items"),
+                filter: Filter::new_must(Condition::Field(FieldCondition::new_match(
+                    JsonPath::new("name"),
+                    Match::new_value(ValueVariants::String(value.to_string())),
+                ))),
+            })))
+        };
+        let payload_index = segment.payload_index.borrow();
+        let query = |filter: &Filter| {
+            payload_index
+                .query_points(filter, &hw_counter, &is_stopped, None)
+                .unwrap()
+        };
+        assert_eq!(query(&nested_match("abcd")), vec![0]);
+        assert!(query(&nested_match("abcdef")).is_empty());
+    }
 }
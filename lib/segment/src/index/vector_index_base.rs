@@ -15,6 +15,7 @@ use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use super::hnsw_index::hnsw::HNSWIndex;
 use super::plain_vector_index::PlainVectorIndex;
 use super::sparse_index::sparse_vector_index::SparseVectorIndex;
+use super::vector_index_search_common::QuantizationDistortionStatistics;
 use crate::common::operation_error::OperationResult;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
@@ -123,6 +124,23 @@ impl VectorIndexEnum {
         }
     }
 
+    /// Distortion of the quantized ranking observed in searches, only tracked by HNSW indexes
+    pub fn quantization_distortion(&self) -> Option<QuantizationDistortionStatistics> {
+        match self {
+            Self::Plain(_) => None,
+            Self::Hnsw(index) => Some(index.quantization_distortion()),
+            Self::SparseRam(_) => None,
+            Self::SparseImmutableRam(_) => None,
+            Self::SparseMmap(_) => None,
+            Self::SparseCompressedImmutableRamF32(_) => None,
+            Self::SparseCompressedImmutableRamF16(_) => None,
+            Self::SparseCompressedImmutableRamU8(_) => None,
+            Self::SparseCompressedMmapF32(_) => None,
+            Self::SparseCompressedMmapF16(_) => None,
+            Self::SparseCompressedMmapU8(_) => None,
+        }
+    }
+
     pub fn populate(&self) -> OperationResult<()> {
         match self {
             Self::Plain(_) => {}
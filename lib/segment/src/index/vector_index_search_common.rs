@@ -1,12 +1,14 @@
+use ahash::AHashSet;
 use common::bitvec::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::ScoredPointOffset;
+use common::types::{PointOffsetType, ScoredPointOffset};
 use itertools::Itertools;
 
 use crate::common::operation_error::OperationResult;
 use crate::data_types::vectors::QueryVector;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
 use crate::types::{
+    QUANTIZATION_FALLBACK_MAX_DISTORTION_DEFAULT, QUANTIZATION_FALLBACK_MAX_OVERSAMPLING_DEFAULT,
     SearchParams, default_quantization_ignore_value, default_quantization_oversampling_value,
 };
 use crate::vector_storage::VectorStorageEnum;
@@ -44,6 +46,22 @@ pub fn get_oversampled_top(
     }
 }
 
+/// Whether the quantized search results are rescored with the original vectors
+pub fn is_rescored_search(
+    quantized_storage: Option<&QuantizedVectors>,
+    params: Option<&SearchParams>,
+) -> bool {
+    let default_rescoring = quantized_storage
+        .as_ref()
+        .map(|q| q.default_rescoring())
+        .unwrap_or(false);
+    is_quantized_search(quantized_storage, params)
+        && params
+            .and_then(|p| p.quantization)
+            .and_then(|q| q.rescore)
+            .unwrap_or(default_rescoring)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn postprocess_search_result(
     mut search_result: Vec<ScoredPointOffset>,
@@ -55,18 +73,7 @@ pub fn postprocess_search_result(
     top: usize,
     hardware_counter: HardwareCounterCell,
 ) -> OperationResult<Vec<ScoredPointOffset>> {
-    let quantization_enabled = is_quantized_search(quantized_vectors, params);
-
-    let default_rescoring = quantized_vectors
-        .as_ref()
-        .map(|q| q.default_rescoring())
-        .unwrap_or(false);
-    let rescore = quantization_enabled
-        && params
-            .and_then(|p| p.quantization)
-            .and_then(|q| q.rescore)
-            .unwrap_or(default_rescoring);
-    if rescore {
+    if is_rescored_search(quantized_vectors, params) {
         let mut scorer = FilteredScorer::new(
             vector.to_owned(),
             vector_storage,
@@ -85,3 +92,164 @@ pub fn postprocess_search_result(
     search_result.truncate(top);
     Ok(search_result)
 }
+
+/// Fraction of the rescored results, which are not among the same number of top results by
+/// quantized score.
+///
+/// `quantized_top` are the ids of the search result, ordered by quantized score.
+pub fn quantization_distortion(
+    quantized_top: &[PointOffsetType],
+    rescored: &[ScoredPointOffset],
+) -> f64 {
+    if rescored.is_empty() {
+        return 0.0;
+    }
+
+    let quantized_top: AHashSet<_> = quantized_top.iter().take(rescored.len()).collect();
+    let missing = rescored
+        .iter()
+        .filter(|point| !quantized_top.contains(&point.idx))
+        .count();
+    missing as f64 / rescored.len() as f64
+}
+
+/// Moving average of the quantization distortion, observed in rescored searches.
+///
+/// Averages over all searches at first, and then weights recent searches more, so it follows
+/// the drift of the data.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuantizationDistortionStatistics {
+    searches: usize,
+    mean: f64,
+}
+
+impl QuantizationDistortionStatistics {
+    /// Number of recent searches, which make up most of the average
+    const WINDOW: usize = 1000;
+
+    pub fn add(&mut self, distortion: f64) {
+        self.searches = self.searches.saturating_add(1);
+        let weight = 1.0 / self.searches.min(Self::WINDOW) as f64;
+        self.mean += (distortion - self.mean) * weight;
+    }
+
+    /// Number of searches observed
+    pub fn searches(&self) -> usize {
+        self.searches
+    }
+
+    /// Average distortion, `None` if no searches were observed
+    pub fn mean(&self) -> Option<f64> {
+        (self.searches > 0).then_some(self.mean)
+    }
+}
+
+/// Run quantized search, and retry it with doubled oversampling, while the quantized ranking
+/// is too distorted. See [`crate::types::QuantizationFallbackParams`].
+///
+/// `search` returns candidates for the given oversampled top, ordered by quantized score,
+/// `postprocess` rescores them into the final result.
+///
+/// Returns the final result, whether the search was retried, and the distortion of the first
+/// attempt. The distortion is only meaningful if the results are rescored.
+pub fn search_with_quantization_fallback(
+    params: Option<&SearchParams>,
+    top: usize,
+    mut oversampled_top: usize,
+    mut search: impl FnMut(usize) -> OperationResult<Vec<ScoredPointOffset>>,
+    mut postprocess: impl FnMut(Vec<ScoredPointOffset>) -> OperationResult<Vec<ScoredPointOffset>>,
+) -> OperationResult<(Vec<ScoredPointOffset>, bool, f64)> {
+    let fallback = params
+        .and_then(|p| p.quantization)
+        .and_then(|q| q.fallback)
+        .filter(|fallback| fallback.enable);
+
+    let Some(fallback) = fallback else {
+        let search_result = search(oversampled_top)?;
+        let quantized_top = quantized_top(&search_result, top);
+        let result = postprocess(search_result)?;
+        let distortion = quantization_distortion(&quantized_top, &result);
+        return Ok((result, false, distortion));
+    };
+
+    let max_distortion = fallback
+        .max_distortion
+        .map_or(QUANTIZATION_FALLBACK_MAX_DISTORTION_DEFAULT, |v| *v);
+    let max_oversampling = fallback
+        .max_oversampling
+        .map_or(QUANTIZATION_FALLBACK_MAX_OVERSAMPLING_DEFAULT, |v| *v);
+    let max_oversampled_top = (max_oversampling * top as f64) as usize;
+
+    let mut retried = false;
+    let mut first_distortion = None;
+    loop {
+        let search_result = search(oversampled_top)?;
+        let quantized_top = quantized_top(&search_result, top);
+        let result = postprocess(search_result)?;
+
+        let distortion = quantization_distortion(&quantized_top, &result);
+        first_distortion.get_or_insert(distortion);
+
+        let next_oversampled_top = (oversampled_top * 2).min(max_oversampled_top);
+        if next_oversampled_top <= oversampled_top || distortion <= max_distortion {
+            return Ok((result, retried, first_distortion.unwrap_or(distortion)));
+        }
+
+        oversampled_top = next_oversampled_top;
+        retried = true;
+    }
+}
+
+fn quantized_top(search_result: &[ScoredPointOffset], top: usize) -> Vec<PointOffsetType> {
+    search_result
+        .iter()
+        .take(top)
+        .map(|point| point.idx)
+        .collect_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scored(ids: &[PointOffsetType]) -> Vec<ScoredPointOffset> {
+        ids.iter()
+            .map(|&idx| ScoredPointOffset { idx, score: 0.0 })
+            .collect()
+    }
+
+    #[test]
+    fn test_quantization_distortion() {
+        assert_eq!(
+            quantization_distortion(&[1, 2, 3, 4], &scored(&[1, 2])),
+            0.0
+        );
+        assert_eq!(
+            quantization_distortion(&[1, 2, 3, 4], &scored(&[2, 3])),
+            0.5
+        );
+        assert_eq!(
+            quantization_distortion(&[1, 2, 3, 4], &scored(&[3, 4])),
+            1.0
+        );
+        assert_eq!(quantization_distortion(&[], &scored(&[])), 0.0);
+    }
+
+    #[test]
+    fn test_quantization_distortion_statistics() {
+        let mut statistics = QuantizationDistortionStatistics::default();
+        assert_eq!(statistics.mean(), None);
+
+        // Plain average at first
+        statistics.add(0.0);
+        statistics.add(0.5);
+        assert_eq!(statistics.searches(), 2);
+        assert_eq!(statistics.mean(), Some(0.25));
+
+        // Follows the drift, once there are more searches than the window
+        for _ in 0..QuantizationDistortionStatistics::WINDOW * 5 {
+            statistics.add(1.0);
+        }
+        assert!(statistics.mean().unwrap() > 0.99);
+    }
+}
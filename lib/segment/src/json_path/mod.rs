@@ -101,6 +101,29 @@ impl JsonPath {
         new_map
     }
 
+    /// Select values at the given `paths` from a JSON map.
+    ///
+    /// Selected values keep their place in the structure of the map. Array indices in the paths
+    /// select single elements, and wildcards select all of them. Paths, which don't exist in the
+    /// map, are omitted.
+    pub fn value_select(
+        json_map: &serde_json::Map<String, Value>,
+        paths: &[Self],
+    ) -> serde_json::Map<String, Value> {
+        json_map
+            .iter()
+            .filter_map(|(key, value)| {
+                let rests = paths
+                    .iter()
+                    .filter(|path| &path.first_key == key)
+                    .map(|path| path.rest.as_slice())
+                    .collect_vec();
+                let value = run_select(value, &rests)?;
+                Some((key.clone(), value))
+            })
+            .collect()
+    }
+
     /// Remove the wildcard suffix from the path, if it exists.
     /// E.g. `a.b[]` -> `a.b`.
     pub fn strip_wildcard_suffix(&self) -> Self {
@@ -173,6 +196,18 @@ impl JsonPath {
         result
     }
 
+    /// Returns a new path with an array index appended to the end.
+    /// E.g. `a.b` -> `a.b[2]`.
+    pub fn element_key(&self, index: usize) -> Self {
+        let mut result = JsonPath {
+            first_key: self.first_key.clone(),
+            rest: Vec::with_capacity(self.rest.len() + 1),
+        };
+        result.rest.extend_from_slice(&self.rest);
+        result.rest.push(JsonPathItem::Index(index));
+        result
+    }
+
     pub fn has_wildcard_suffix(&self) -> bool {
         self.rest.last() == Some(&JsonPathItem::WildcardIndex)
     }
@@ -474,6 +509,57 @@ fn run_filter<'a>(
     }
 }
 
+/// Select the parts of `value` at the relative `paths`, see [`JsonPath::value_select`].
+fn run_select(value: &Value, paths: &[&[JsonPathItem]]) -> Option<Value> {
+    if paths.is_empty() {
+        return None;
+    }
+    if paths.iter().any(|path| path.is_empty()) {
+        return Some(value.clone());
+    }
+
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => None,
+        Value::Array(array) => {
+            let selected = array
+                .iter()
+                .enumerate()
+                .filter_map(|(i, value)| {
+                    let rests = paths
+                        .iter()
+                        .filter_map(|path| match path.split_first() {
+                            Some((JsonPathItem::WildcardIndex, rest)) => Some(rest),
+                            Some((JsonPathItem::Index(index), rest)) if *index == i => Some(rest),
+                            _ => None,
+                        })
+                        .collect_vec();
+                    run_select(value, &rests)
+                })
+                .collect_vec();
+            (!selected.is_empty()).then_some(Value::Array(selected))
+        }
+        Value::Object(object) => {
+            let selected: serde_json::Map<_, _> = object
+                .iter()
+                .filter_map(|(key, value)| {
+                    let rests = paths
+                        .iter()
+                        .filter_map(|path| match path.split_first() {
+                            Some((JsonPathItem::Key(path_key), rest)) if path_key == key => {
+                                Some(rest)
+                            }
+                            _ => None,
+                        })
+                        .collect_vec();
+                    let value = run_select(value, &rests)?;
+                    Some((key.clone(), value))
+                })
+                .collect();
+            (!selected.is_empty()).then_some(Value::Object(selected))
+        }
+    }
+}
+
 impl Display for JsonPath {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let write_key = |f: &mut Formatter<'_>, key: &str| {
@@ -1002,6 +1088,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_select_json() {
+        let map = json(
+            r#"
+            {
+                "a": {
+                    "b": [
+                        { "c": 1 },
+                        { "c": 2, "d": 4 },
+                        { "d": { "e": 3 } }
+                    ],
+                    "f": {}
+                },
+                "g": ["g0", "g1", "g2"]
+            }
+            "#,
+        );
+
+        let select = |paths: &[&str]| {
+            let paths = paths.iter().map(|path| JsonPath::new(path)).collect_vec();
+            JsonPath::value_select(&map, &paths)
+        };
+
+        assert_eq!(
+            select(&["a.b[].c", "g[1]"]),
+            json(r#"{ "a": { "b": [{ "c": 1 }, { "c": 2 }] }, "g": ["g1"] }"#),
+        );
+        assert_eq!(
+            select(&["a.b[1]", "a.b[2].d.e", "a.f"]),
+            json(r#"{ "a": { "b": [{ "c": 2, "d": 4 }, { "d": { "e": 3 } }], "f": {} } }"#),
+        );
+
+        // Paths, which don't exist, are omitted
+        assert_eq!(
+            select(&["a.b[5]", "a.x.y", "g[].h", "x", "a.b[0].c"]),
+            json(r#"{ "a": { "b": [{ "c": 1 }] } }"#),
+        );
+    }
+
     #[test]
     fn test_check_include_pattern() {
         assert!(JsonPath::new("a.b.c").check_include_pattern(&JsonPath::new("a.b.c")));
@@ -2,13 +2,16 @@
 
 use std::str::FromStr;
 
+use common::bitvec::BitVec;
+use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use serde_json::Value;
 
 use crate::types::{
-    AnyVariants, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPoint,
-    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny,
-    MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
+    AnyVariants, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox,
+    GeoNearPath, GeoPoint, GeoPolygon, GeoRadius, Match, MatchAny, MatchExactSet, MatchExcept,
+    MatchKeywordPrefix, MatchPhrase, MatchText, MatchTextAny, MatchValue, PolygonWrapper, Range,
+    RangeInterface, UuidPayloadType, ValueVariants, ValuesCount,
 };
 
 /// Threshold representing the point to which iterating through an IndexSet is more efficient than using hashing.
@@ -60,6 +63,39 @@ fn check_is_null(is_null: bool, payload_value: &Value) -> bool {
     }
 }
 
+/// Check that the payload value consists of exactly the given set of values, ignoring duplicates.
+///
+/// A non-array value is checked as an array of a single value.
+pub fn check_exact_set(exact_set: &AnyVariants, payload_value: &Value) -> bool {
+    let values = match payload_value {
+        Value::Array(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+
+    // Each value of the set must be covered by at least one payload value
+    if exact_set.is_empty() || values.len() < exact_set.len() {
+        return false;
+    }
+
+    let mut matched = BitVec::repeat(false, exact_set.len());
+    for value in values {
+        let index = match (value, exact_set) {
+            (Value::String(stored), AnyVariants::Strings(set)) => set.get_index_of(stored.as_str()),
+            (Value::Number(stored), AnyVariants::Integers(set)) => {
+                stored.as_i64().and_then(|num| set.get_index_of(&num))
+            }
+            _ => None,
+        };
+
+        let Some(index) = index else {
+            return false;
+        };
+        matched.set(index, true);
+    }
+
+    matched.all()
+}
+
 impl ValueChecker for FieldCondition {
     fn check_match(&self, payload: &Value) -> bool {
         // Destructuring so compiler can check that we don't forget a condition
@@ -69,6 +105,7 @@ impl ValueChecker for FieldCondition {
             geo_radius,
             geo_bounding_box,
             geo_polygon,
+            geo_near_path,
             values_count,
             key: _,
             is_empty,
@@ -83,6 +120,7 @@ impl ValueChecker for FieldCondition {
                 .is_some_and(|range_interface| match range_interface {
                     RangeInterface::Float(condition) => condition.check_match(payload),
                     RangeInterface::DateTime(condition) => condition.check_match(payload),
+                    RangeInterface::Uuid(condition) => condition.check_match(payload),
                 })
             || geo_radius
                 .as_ref()
@@ -93,6 +131,9 @@ impl ValueChecker for FieldCondition {
             || geo_polygon
                 .as_ref()
                 .is_some_and(|condition| condition.check_match(payload))
+            || geo_near_path
+                .as_ref()
+                .is_some_and(|condition| condition.check_match(payload))
             || values_count
                 .as_ref()
                 .is_some_and(|condition| condition.check_match(payload))
@@ -102,11 +143,12 @@ impl ValueChecker for FieldCondition {
 
     fn check(&self, payload: &Value) -> bool {
         let FieldCondition {
-            r#match: _,
+            r#match,
             range: _,
             geo_radius: _,
             geo_bounding_box: _,
             geo_polygon: _,
+            geo_near_path: _,
             values_count,
             key: _,
             is_empty,
@@ -122,6 +164,9 @@ impl ValueChecker for FieldCondition {
             check_is_empty(is_empty.unwrap(), payload)
         } else if is_null.is_some() {
             check_is_null(is_null.unwrap(), payload)
+        } else if let Some(Match::ExactSet(MatchExactSet { exact_set })) = r#match {
+            // Exact set is checked against all values of the array at once
+            check_exact_set(exact_set, payload)
         } else {
             self._check(payload)
         }
@@ -134,6 +179,7 @@ impl ValueChecker for FieldCondition {
             geo_radius: _,
             geo_bounding_box: _,
             geo_polygon: _,
+            geo_near_path: _,
             values_count: _,
             key: _,
             is_empty,
@@ -160,16 +206,32 @@ impl ValueChecker for Match {
                 }
                 _ => false,
             },
-            Match::Text(MatchText { text }) | Match::Phrase(MatchPhrase { phrase: text }) => {
-                match payload {
-                    Value::String(stored) => stored.contains(text),
-                    _ => false,
+            Match::Text(MatchText {
+                text,
+                language: _,
+                unstemmed: _,
+            })
+            | Match::Phrase(MatchPhrase {
+                phrase: text,
+                slop: _,
+            }) => match payload {
+                Value::String(stored) => stored.contains(text),
+                _ => false,
+            },
+            Match::TextAny(MatchTextAny {
+                text_any,
+                min_should_match,
+            }) => match payload {
+                Value::String(stored) => {
+                    let tokens: Vec<_> = text_any.split_whitespace().unique().collect();
+                    let min_match = min_should_match.map_or(1, |min| min.min_tokens(tokens.len()));
+                    tokens
+                        .into_iter()
+                        .filter(|token| stored.contains(token))
+                        .take(min_match)
+                        .count()
+                        >= min_match
                 }
-            }
-            Match::TextAny(MatchTextAny { text_any }) => match payload {
-                Value::String(stored) => text_any
-                    .split_whitespace()
-                    .any(|token| stored.contains(token)),
                 _ => false,
             },
             Match::Any(MatchAny { any }) => match (payload, any) {
@@ -217,6 +279,11 @@ impl ValueChecker for Match {
                 (Value::Number(_), _) => true,
                 (Value::String(_), _) => true,
             },
+            Match::ExactSet(MatchExactSet { exact_set }) => check_exact_set(exact_set, payload),
+            Match::KeywordPrefix(MatchKeywordPrefix { prefix }) => match payload {
+                Value::String(stored) => stored.starts_with(prefix.as_str()),
+                _ => false,
+            },
         }
     }
 }
@@ -242,6 +309,16 @@ impl ValueChecker for Range<DateTimePayloadType> {
     }
 }
 
+impl ValueChecker for Range<UuidPayloadType> {
+    fn check_match(&self, payload: &Value) -> bool {
+        let range = self.map(|uuid| uuid.as_u128());
+        payload
+            .as_str()
+            .and_then(|s| UuidPayloadType::parse_str(s).ok())
+            .is_some_and(|uuid| range.check_range(uuid.as_u128()))
+    }
+}
+
 impl ValueChecker for GeoBoundingBox {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -277,6 +354,30 @@ impl ValueChecker for GeoRadius {
 }
 
 impl ValueChecker for GeoPolygon {
+    fn check_match(&self, payload: &Value) -> bool {
+        self.convert().check_match(payload)
+    }
+}
+
+/// Checks against a polygon converted once, as [`GeoPolygon::convert`] may simplify the lines
+impl ValueChecker for PolygonWrapper {
+    fn check_match(&self, payload: &Value) -> bool {
+        match payload {
+            Value::Object(obj) => {
+                let lon_op = obj.get("lon").and_then(|x| x.as_f64());
+                let lat_op = obj.get("lat").and_then(|x| x.as_f64());
+
+                if let (Some(lon), Some(lat)) = (lon_op, lat_op) {
+                    return self.check_point(&GeoPoint::new_unchecked(lon, lat));
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ValueChecker for GeoNearPath {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
             Value::Object(obj) => {
@@ -284,9 +385,7 @@ impl ValueChecker for GeoPolygon {
                 let lat_op = obj.get("lat").and_then(|x| x.as_f64());
 
                 if let (Some(lon), Some(lat)) = (lon_op, lat_op) {
-                    return self
-                        .convert()
-                        .check_point(&GeoPoint::new_unchecked(lon, lat));
+                    return self.check_point(&GeoPoint::new_unchecked(lon, lat));
                 }
                 false
             }
@@ -341,6 +440,27 @@ mod tests {
 
         assert!(near_berlin_query.check(&berlin_and_moscow));
         assert!(!miss_geo_query.check(&berlin_and_moscow));
+
+        // Route from Warsaw to Minsk passes far from both cities
+        let miss_path_query = GeoNearPath {
+            path: vec![
+                GeoPoint::new_unchecked(21.012229, 52.229676),
+                GeoPoint::new_unchecked(27.561481, 53.902496),
+            ],
+            distance: OrderedFloat(50_000.0),
+        };
+        // Route from Hamburg through Berlin to Dresden
+        let near_path_query = GeoNearPath {
+            path: vec![
+                GeoPoint::new_unchecked(9.993682, 53.551086),
+                GeoPoint::new_unchecked(13.404954, 52.520008),
+                GeoPoint::new_unchecked(13.737262, 51.050409),
+            ],
+            distance: OrderedFloat(2000.0),
+        };
+
+        assert!(near_path_query.check(&berlin_and_moscow));
+        assert!(!miss_path_query.check(&berlin_and_moscow));
     }
 
     #[test]
@@ -359,6 +479,7 @@ mod tests {
             gt: Some(1),
             gte: None,
             lte: None,
+            distinct: None,
         };
         assert!(gt_one_country_query.check(&countries));
 
@@ -367,6 +488,7 @@ mod tests {
             gt: Some(2),
             gte: None,
             lte: None,
+            distinct: None,
         };
         assert!(!gt_two_countries_query.check(&countries));
 
@@ -375,10 +497,49 @@ mod tests {
             gt: None,
             gte: Some(2),
             lte: None,
+            distinct: None,
         };
         assert!(gte_two_countries_query.check(&countries));
     }
 
+    #[test]
+    fn test_distinct_value_count() {
+        let categories = json!(["books", "music", "books", {"a": 1}, {"a": 1}, 1, 1.0]);
+
+        let gte_seven = ValuesCount {
+            lt: None,
+            gt: None,
+            gte: Some(7),
+            lte: None,
+            distinct: None,
+        };
+        assert!(gte_seven.check(&categories));
+
+        let distinct_gte_seven = ValuesCount {
+            distinct: Some(true),
+            ..gte_seven
+        };
+        assert!(!distinct_gte_seven.check(&categories));
+
+        // `1` and `1.0` are different values
+        let distinct_gte_five = ValuesCount {
+            gte: Some(5),
+            ..distinct_gte_seven
+        };
+        assert!(distinct_gte_five.check(&categories));
+
+        let distinct_gt_five = ValuesCount {
+            gt: Some(5),
+            gte: None,
+            ..distinct_gte_seven
+        };
+        assert!(!distinct_gt_five.check(&categories));
+
+        // Non-array values count as a single distinct value
+        assert!(!distinct_gt_five.check(&json!("books")));
+        assert!(!distinct_gt_five.check(&json!(null)));
+    }
+
     #[test]
     fn test_value_checker_for_null_or_empty() {
         let array = json!([]);
@@ -398,6 +559,7 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             key: key.clone(),
             is_empty: Some(true),
@@ -410,6 +572,7 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             key: key.clone(),
             is_empty: Some(false),
@@ -422,6 +585,7 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             key: key.clone(),
             is_empty: None,
@@ -434,6 +598,7 @@ mod tests {
             geo_radius: None,
             geo_bounding_box: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             key: key.clone(),
             is_empty: None,
@@ -476,4 +641,38 @@ mod tests {
         assert!(is_not_null.check(&number));
         assert!(is_not_null.check(&bool));
     }
+
+    #[test]
+    fn test_exact_set_matching() {
+        let exact_set = FieldCondition::new_match(
+            JsonPath::new("tags"),
+            Match::new_exact_set(AnyVariants::Strings(
+                ["a", "b"].into_iter().map(String::from).collect(),
+            )),
+        );
+
+        // Exact
+        assert!(exact_set.check(&json!(["a", "b"])));
+        assert!(exact_set.check(&json!(["b", "a"])));
+        // Duplicates are ignored
+        assert!(exact_set.check(&json!(["a", "b", "a"])));
+        // Subset
+        assert!(!exact_set.check(&json!(["a"])));
+        assert!(!exact_set.check(&json!(["a", "a"])));
+        assert!(!exact_set.check(&json!("a")));
+        // Superset
+        assert!(!exact_set.check(&json!(["a", "b", "c"])));
+        assert!(!exact_set.check(&json!(["a", "b", 1])));
+        // Empty
+        assert!(!exact_set.check(&json!([])));
+        assert!(!exact_set.check_empty());
+
+        let single_value = FieldCondition::new_match(
+            JsonPath::new("tags"),
+            Match::new_exact_set(AnyVariants::Integers([1].into_iter().collect())),
+        );
+        assert!(single_value.check(&json!(1)));
+        assert!(single_value.check(&json!([1, 1])));
+        assert!(!single_value.check(&json!([1, 2])));
+    }
 }
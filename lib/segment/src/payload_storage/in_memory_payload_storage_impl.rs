@@ -207,6 +207,7 @@ mod tests {
             &query,
             0,
             &IndexesMap::new(),
+            &std::collections::HashMap::new(),
             &HardwareCounterCell::new(),
         );
     }
@@ -8,17 +8,19 @@ use std::sync::Arc;
 use atomic_refcell::AtomicRefCell;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
+use serde_json::Value;
 
 use crate::common::operation_error::OperationResult;
 use crate::common::utils::{IndexesMap, check_is_empty, check_is_null};
 use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
 use crate::index::field_index::FieldIndex;
+use crate::index::field_index::value_size_limit::ValueSizeLimit;
 use crate::payload_storage::condition_checker::ValueChecker;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::payload_storage::{ConditionChecker, PayloadStorageRead};
 use crate::types::{
-    Condition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition, MinShould,
-    OwnedPayloadRef, Payload, PayloadContainer, PayloadKeyType, VectorNameBuf,
+    Condition, ExpressionCondition, FieldCondition, Filter, IsEmptyCondition, IsNullCondition,
+    MinShould, OwnedPayloadRef, Payload, PayloadContainer, PayloadKeyType, VectorNameBuf,
 };
 use crate::vector_storage::{VectorStorageEnum, VectorStorageRead};
 
@@ -113,6 +115,18 @@ where
     nested_indexes
 }
 
+/// Size limits of the indexed values of the fields, relative to the `nested_path`
+pub fn select_nested_value_size_limits(
+    nested_path: &PayloadKeyType,
+    value_size_limits: &HashMap<PayloadKeyType, ValueSizeLimit>,
+) -> HashMap<PayloadKeyType, ValueSizeLimit> {
+    value_size_limits
+        .iter()
+        .filter_map(|(key, limit)| key.strip_prefix(nested_path).map(|key| (key, *limit)))
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn check_payload<'a, R>(
     get_payload: Box<dyn Fn() -> OwnedPayloadRef<'a> + 'a>,
     id_tracker: Option<&IdTrackerEnum>,
@@ -120,6 +134,7 @@ pub fn check_payload<'a, R>(
     query: &Filter,
     point_id: PointOffsetType,
     field_indexes: &HashMap<PayloadKeyType, R>,
+    value_size_limits: &HashMap<PayloadKeyType, ValueSizeLimit>,
     hw_counter: &HardwareCounterCell,
 ) -> bool
 where
@@ -130,6 +145,7 @@ where
             field_condition,
             get_payload().deref(),
             field_indexes,
+            value_size_limits.get(&field_condition.key).copied(),
             hw_counter,
         )
         .unwrap(/* TODO(uio): handle errors */),
@@ -145,9 +161,16 @@ where
                 false
             }
         }
+        Condition::UpdatedAfter(updated_after) => updated_after
+            .check(id_tracker.and_then(|id_tracker| id_tracker.internal_version(point_id))),
+        Condition::Expression(expression) => {
+            check_expression_condition(expression, get_payload().deref())
+        }
         Condition::Nested(nested) => {
             let nested_path = nested.array_key();
             let nested_indexes = select_nested_indexes(&nested_path, field_indexes);
+            let nested_value_size_limits =
+                select_nested_value_size_limits(&nested_path, value_size_limits);
             get_payload()
                 .get_value(&nested_path)
                 .iter()
@@ -160,6 +183,7 @@ where
                         &nested.nested.filter,
                         point_id,
                         &nested_indexes,
+                        &nested_value_size_limits,
                         hw_counter,
                     )
                 })
@@ -183,13 +207,37 @@ pub fn check_is_empty_condition(
 }
 
 pub fn check_is_null_condition(is_null: &IsNullCondition, payload: &impl PayloadContainer) -> bool {
-    check_is_null(payload.get_value(&is_null.is_null.key).iter().copied())
+    check_is_null(
+        payload.get_value(&is_null.is_null.key).iter().copied(),
+        is_null.null_elements.unwrap_or_default(),
+    )
+}
+
+pub fn check_expression_condition(
+    expression: &ExpressionCondition,
+    payload: &impl PayloadContainer,
+) -> bool {
+    expression.check(&|key| first_number(payload.get_value(key).iter().copied()))
 }
 
+/// First numeric value of the field, looking into arrays
+pub fn first_number<'a>(values: impl IntoIterator<Item = &'a Value>) -> Option<f64> {
+    values.into_iter().find_map(|value| match value {
+        Value::Number(number) => number.as_f64(),
+        Value::Array(array) => first_number(array),
+        _ => None,
+    })
+}
+
+/// Check the condition against the payload values of the field.
+///
+/// Values are checked as they are indexed, limited by the `value_size_limit` of the field, so
+/// the result is the same as the one of the index.
 pub fn check_field_condition<R>(
     field_condition: &FieldCondition,
     payload: &impl PayloadContainer,
     field_indexes: &HashMap<PayloadKeyType, R>,
+    value_size_limit: Option<ValueSizeLimit>,
     hw_counter: &HardwareCounterCell,
 ) -> OperationResult<bool>
 where
@@ -202,6 +250,13 @@ where
         return Ok(field_condition.check_empty());
     }
 
+    let indexed_values = value_size_limit
+        .and_then(|limit| limit.indexed_values(&field_condition.key, &field_values));
+    let field_values = match &indexed_values {
+        Some(values) => values.iter().collect(),
+        None => field_values,
+    };
+
     // This covers a case, when a field index affects the result of the condition.
     if let Some(field_indexes) = field_indexes {
         for p in field_values {
@@ -297,6 +352,7 @@ impl ConditionChecker for SimpleConditionChecker {
             query,
             point_id,
             &IndexesMap::new(),
+            &HashMap::new(),
             &HardwareCounterCell::new(),
         )
     }
@@ -317,7 +373,8 @@ mod tests {
     use crate::payload_storage::PayloadStorage;
     use crate::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
     use crate::types::{
-        DateTimeWrapper, FieldCondition, GeoBoundingBox, GeoPoint, PayloadField, Range, ValuesCount,
+        DateTimeWrapper, FieldCondition, GeoBoundingBox, GeoPoint, NullElements, PayloadField,
+        Range, ValuesCount,
     };
 
     #[test]
@@ -390,6 +447,7 @@ mod tests {
             is_null: PayloadField {
                 key: JsonPath::new("amount"),
             },
+            null_elements: None,
         }));
         assert!(!payload_checker.check(0, &is_null_condition));
 
@@ -397,6 +455,7 @@ mod tests {
             is_null: PayloadField {
                 key: JsonPath::new("parts"),
             },
+            null_elements: None,
         }));
         assert!(!payload_checker.check(0, &is_null_condition));
 
@@ -404,6 +463,7 @@ mod tests {
             is_null: PayloadField {
                 key: JsonPath::new("something_else"),
             },
+            null_elements: None,
         }));
         assert!(!payload_checker.check(0, &is_null_condition));
 
@@ -411,6 +471,7 @@ mod tests {
             is_null: PayloadField {
                 key: JsonPath::new("packaging"),
             },
+            null_elements: None,
         }));
         assert!(payload_checker.check(0, &is_null_condition));
 
@@ -418,6 +479,31 @@ mod tests {
             is_null: PayloadField {
                 key: JsonPath::new("not_null"),
             },
+            null_elements: None,
+        }));
+        assert!(!payload_checker.check(0, &is_null_condition));
+
+        let is_null_condition = Filter::new_must(Condition::IsNull(IsNullCondition {
+            is_null: PayloadField {
+                key: JsonPath::new("not_null"),
+            },
+            null_elements: Some(NullElements::Include),
+        }));
+        assert!(payload_checker.check(0, &is_null_condition));
+
+        let is_null_condition = Filter::new_must(Condition::IsNull(IsNullCondition {
+            is_null: PayloadField {
+                key: JsonPath::new("not_null"),
+            },
+            null_elements: Some(NullElements::Only),
+        }));
+        assert!(payload_checker.check(0, &is_null_condition));
+
+        let is_null_condition = Filter::new_must(Condition::IsNull(IsNullCondition {
+            is_null: PayloadField {
+                key: JsonPath::new("packaging"),
+            },
+            null_elements: Some(NullElements::Only),
         }));
         assert!(!payload_checker.check(0, &is_null_condition));
 
@@ -460,6 +546,7 @@ mod tests {
                     gt: None,
                     gte: Some(10),
                     lte: None,
+                    distinct: None,
                 },
             )));
         assert!(!payload_checker.check(0, &many_value_count_condition));
@@ -472,6 +559,7 @@ mod tests {
                     gt: None,
                     gte: None,
                     lte: None,
+                    distinct: None,
                 },
             )));
         assert!(payload_checker.check(0, &few_value_count_condition));
@@ -682,6 +770,15 @@ mod tests {
             stemmer: None,
             ascii_folding: None,
             enable_hnsw: None,
+            compression: None,
+            index_unstemmed: None,
+            max_value_bytes: None,
+            oversized_value_policy: None,
+            auto_stopwords_threshold: None,
+            strip_html: None,
+            decode_html_entities: None,
+            strip_html_queries: None,
+            forward_index: None,
         };
 
         let mut ft_index =
@@ -712,6 +809,7 @@ mod tests {
                 JsonPath::new("title"),
                 crate::types::Match::TextAny(MatchTextAny {
                     text_any: "good cheap".to_string(),
+                    min_should_match: None,
                 }),
             ))),
         })));
@@ -727,6 +825,7 @@ mod tests {
                     &nested_filter,
                     point_id,
                     &field_indexes,
+                    &HashMap::new(),
                     &hw_counter,
                 )
             })
@@ -0,0 +1,265 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::ControlFlow;
+use std::sync::atomic::AtomicBool;
+
+use ahash::AHashMap;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::generic_consts::{Random, Sequential};
+use common::types::{PointOffsetType, ScoreType};
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::{check_stopped, check_vector_name};
+use crate::data_types::vectors::{QueryVector, VectorRef};
+use crate::id_tracker::IdTrackerRead;
+use crate::types::{PointIdType, VectorName};
+use crate::vector_storage::{VectorStorageRead, new_raw_scorer};
+
+/// How often to check the stop flag while scanning vectors
+const STOP_CHECK_INTERVAL: usize = 1024;
+
+/// Number of random hyperplanes, which define the bucket of near-duplicate dense vectors
+pub(super) const NEAR_DUPLICATE_HYPERPLANES: usize = 16;
+
+/// Seed of the random hyperplanes, so that the buckets are the same in every scan
+const NEAR_DUPLICATE_HYPERPLANES_SEED: u64 = 42;
+
+impl Segment {
+    /// Find groups of points, whose vectors are duplicates of each other.
+    ///
+    /// Without `epsilon` only identical vectors are grouped. Vectors are bucketed by a hash
+    /// of their contents, and compared for equality within buckets.
+    ///
+    /// With `epsilon`, vectors whose similarity to each other differs from their similarity to
+    /// themselves by at most `epsilon` are grouped. Vectors are bucketed by the sides of random
+    /// hyperplanes they lie on, and scored pairwise within buckets. This is approximate:
+    /// near-duplicates close to one of the hyperplanes may end up in different buckets.
+    ///
+    /// Groups are passed to `f` one by one, with the point IDs of each group sorted.
+    /// Return [`ControlFlow::Break`] from `f` to stop the scan early.
+    pub fn for_each_duplicate_vector_group(
+        &self,
+        vector_name: &VectorName,
+        epsilon: Option<ScoreType>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+        mut f: impl FnMut(Vec<PointIdType>) -> ControlFlow<()>,
+    ) -> OperationResult<()> {
+        check_vector_name(vector_name, &self.segment_config)?;
+        let vector_data = self
+            .vector_data
+            .get(vector_name)
+            .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = vector_data.vector_storage.borrow();
+
+        // Bucket all available vectors
+        let mut buckets: AHashMap<u64, Vec<PointOffsetType>> = AHashMap::new();
+        let mut near_duplicate_buckets = NearDuplicateBuckets::default();
+        for (i, internal_id) in id_tracker.point_mappings().iter_internal().enumerate() {
+            if i % STOP_CHECK_INTERVAL == 0 {
+                check_stopped(is_stopped)?;
+            }
+
+            if id_tracker.is_deleted_point(internal_id)
+                || vector_storage.is_deleted_vector(internal_id)
+            {
+                continue;
+            }
+            let Some(vector) = vector_storage.get_vector_opt::<Sequential>(internal_id) else {
+                continue;
+            };
+            hw_counter
+                .vector_io_read()
+                .incr_delta(vector.estimate_size_in_bytes());
+
+            let bucket = match epsilon {
+                None => exact_duplicate_bucket(vector.as_vec_ref()),
+                Some(_) => near_duplicate_buckets.bucket(vector.as_vec_ref()),
+            };
+            buckets.entry(bucket).or_default().push(internal_id);
+        }
+
+        // Visit buckets in a deterministic order
+        let mut buckets: Vec<_> = buckets
+            .into_values()
+            .filter(|bucket| bucket.len() > 1)
+            .collect();
+        buckets.sort_unstable_by_key(|bucket| bucket[0]);
+
+        for mut remaining in buckets {
+            check_stopped(is_stopped)?;
+
+            while remaining.len() > 1 {
+                let anchor = remaining[0];
+                let anchor_vector = vector_storage.get_vector::<Random>(anchor);
+                let anchor_size = anchor_vector.estimate_size_in_bytes();
+
+                let is_duplicate: Box<dyn Fn(PointOffsetType) -> bool + '_> = match epsilon {
+                    None => Box::new(|other| {
+                        let other_vector = vector_storage.get_vector::<Random>(other);
+                        hw_counter
+                            .vector_io_read()
+                            .incr_delta(other_vector.estimate_size_in_bytes());
+                        other_vector == anchor_vector
+                    }),
+                    Some(epsilon) => {
+                        let query = QueryVector::from(anchor_vector.as_vec_ref());
+                        let scorer = new_raw_scorer(query, &vector_storage, hw_counter.fork())?;
+                        let self_score = scorer.score_internal(anchor, anchor);
+                        Box::new(move |other| {
+                            hw_counter.vector_io_read().incr_delta(anchor_size);
+                            let score = scorer.score_internal(anchor, other);
+                            (score - self_score).abs() <= epsilon
+                        })
+                    }
+                };
+
+                let (duplicates, rest): (Vec<_>, Vec<_>) = remaining[1..]
+                    .iter()
+                    .copied()
+                    .partition(|&other| is_duplicate(other));
+                drop(is_duplicate);
+                remaining = rest;
+
+                if duplicates.is_empty() {
+                    continue;
+                }
+
+                let mut group: Vec<_> = std::iter::once(anchor)
+                    .chain(duplicates)
+                    .filter_map(|internal_id| id_tracker.external_id(internal_id))
+                    .collect();
+                group.sort_unstable();
+
+                if f(group).is_break() {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Find up to `limit` groups of points, whose vectors are duplicates of each other.
+    ///
+    /// See [`Self::for_each_duplicate_vector_group`] for the meaning of `epsilon`.
+    pub fn find_duplicate_vectors(
+        &self,
+        vector_name: &VectorName,
+        epsilon: Option<ScoreType>,
+        limit: usize,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<Vec<PointIdType>>> {
+        let mut groups = Vec::new();
+        if limit == 0 {
+            return Ok(groups);
+        }
+
+        self.for_each_duplicate_vector_group(
+            vector_name,
+            epsilon,
+            is_stopped,
+            hw_counter,
+            |group| {
+                groups.push(group);
+                if groups.len() >= limit {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            },
+        )?;
+
+        Ok(groups)
+    }
+}
+
+/// Bucket of identical vectors, a hash of the exact vector contents
+fn exact_duplicate_bucket(vector: VectorRef) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    match vector {
+        VectorRef::Dense(values) => {
+            values
+                .iter()
+                .for_each(|value| value.to_bits().hash(&mut hasher));
+        }
+        VectorRef::Sparse(sparse) => {
+            sparse.indices.hash(&mut hasher);
+            sparse
+                .values
+                .iter()
+                .for_each(|value| value.to_bits().hash(&mut hasher));
+        }
+        VectorRef::MultiDense(multi) => {
+            multi.dim.hash(&mut hasher);
+            multi
+                .flattened_vectors
+                .iter()
+                .for_each(|value| value.to_bits().hash(&mut hasher));
+        }
+    }
+    hasher.finish()
+}
+
+/// Buckets of near-identical vectors.
+///
+/// Dense vectors are bucketed by the signs of their dot products with random hyperplanes, so
+/// vectors pointing in close directions share a bucket regardless of the signs of their
+/// components. Multi-dense vectors are bucketed by the sum of their vectors. Sparse vectors are
+/// bucketed by their set of indices.
+#[derive(Default)]
+pub(super) struct NearDuplicateBuckets {
+    /// Normals of the hyperplanes one after another, generated for the dimension of the first
+    /// dense vector
+    hyperplanes: Vec<f32>,
+}
+
+impl NearDuplicateBuckets {
+    pub(super) fn bucket(&mut self, vector: VectorRef) -> u64 {
+        match vector {
+            VectorRef::Dense(values) => self.hyperplane_bits(values),
+            VectorRef::Sparse(sparse) => {
+                let mut hasher = DefaultHasher::new();
+                sparse.indices.hash(&mut hasher);
+                hasher.finish()
+            }
+            VectorRef::MultiDense(multi) => {
+                let mut sum = vec![0.0; multi.dim];
+                for vector in multi.flattened_vectors.chunks_exact(multi.dim.max(1)) {
+                    sum.iter_mut()
+                        .zip(vector)
+                        .for_each(|(sum, value)| *sum += value);
+                }
+                self.hyperplane_bits(&sum)
+            }
+        }
+    }
+
+    fn hyperplane_bits(&mut self, values: &[f32]) -> u64 {
+        if values.is_empty() {
+            return 0;
+        }
+
+        let dim = values.len();
+        if self.hyperplanes.len() != dim * NEAR_DUPLICATE_HYPERPLANES {
+            let mut rng = StdRng::seed_from_u64(NEAR_DUPLICATE_HYPERPLANES_SEED);
+            self.hyperplanes = (0..dim * NEAR_DUPLICATE_HYPERPLANES)
+                .map(|_| rng.random_range(-1.0..1.0))
+                .collect();
+        }
+
+        self.hyperplanes
+            .chunks_exact(dim)
+            .enumerate()
+            .filter(|(_, normal)| {
+                let dot: f32 = normal.iter().zip(values).map(|(n, v)| n * v).sum();
+                dot.is_sign_negative()
+            })
+            .fold(0u64, |bits, (i, _)| bits | (1 << i))
+    }
+}
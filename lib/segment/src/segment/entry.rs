@@ -3,10 +3,11 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::fs::safe_delete_with_suffix;
-use common::types::{DeferredBehavior, TelemetryDetail};
+use common::populate_budget::PopulateBudget;
+use common::types::{DeferredBehavior, ScoreType, TelemetryDetail};
 use uuid::Uuid;
 
 use super::Segment;
@@ -14,17 +15,22 @@ use crate::common::operation_error::{OperationError, OperationResult, SegmentFai
 use crate::common::{Flusher, check_named_vectors, check_vector_name};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
 use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::index::Language;
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::quantiles::ValueSamples;
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use crate::data_types::segment_record::SegmentRecord;
+use crate::data_types::text_query::{QueryStopwords, SnippetParams, TextSnippet, TextValueMatches};
 use crate::data_types::vector_name_config::VectorNameConfig;
 use crate::data_types::vectors::{QueryVector, VectorInternal};
+use crate::data_types::weighted_sample::WeightedSample;
 use crate::entry::entry_point::{
     NonAppendableSegmentEntry, ReadSegmentEntry, SegmentEntry, StorageSegmentEntry,
 };
 use crate::id_tracker::{IdTracker, IdTrackerRead, PointMappingsGuard};
 use crate::index::field_index::{CardinalityEstimation, FieldIndex};
+use crate::index::query_optimization::condition_cost::PlannedCondition;
 use crate::index::{BuildIndexResult, PayloadIndex, PayloadIndexRead};
 use crate::json_path::JsonPath;
 use crate::telemetry::SegmentTelemetry;
@@ -33,7 +39,8 @@ use crate::types::{
     PointIdType, ScoredPoint, SearchParams, SegmentConfig, SegmentInfo, SegmentType, SeqNumberType,
     VectorName, VectorNameBuf, WithPayload, WithVector,
 };
-use crate::vector_storage::VectorStorage;
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
+use crate::vector_storage::{VectorStorage, VectorStorageRead};
 
 /// This is a basic implementation of the trait, meaning that it implements the _actual_ operations with data and not
 /// any kind of proxy or wrapping.
@@ -61,7 +68,7 @@ impl ReadSegmentEntry for Segment {
         params: Option<&SearchParams>,
         query_context: &SegmentQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPoint>>> {
-        self.with_view(|view| {
+        let mut results = self.with_view(|view| {
             view.search_batch(
                 vector_name,
                 query_vectors,
@@ -72,7 +79,11 @@ impl ReadSegmentEntry for Segment {
                 params,
                 query_context,
             )
-        })
+        })?;
+        for points in &mut results {
+            self.set_provenance(points);
+        }
+        Ok(results)
     }
 
     fn rescore_with_formula(
@@ -80,7 +91,9 @@ impl ReadSegmentEntry for Segment {
         ctx: Arc<FormulaContext>,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<ScoredPoint>> {
-        self.with_view(|view| view.rescore_with_formula(ctx, hw_counter))
+        let mut points = self.with_view(|view| view.rescore_with_formula(ctx, hw_counter))?;
+        self.set_provenance(&mut points);
+        Ok(points)
     }
 
     fn vector(
@@ -187,6 +200,19 @@ impl ReadSegmentEntry for Segment {
         self.with_view(|view| view.read_random_filtered(limit, filter, is_stopped, hw_counter))
     }
 
+    fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        sample: &WeightedSample,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        self.with_view(|view| {
+            view.read_weighted_random_filtered(limit, filter, sample, is_stopped, hw_counter)
+        })
+    }
+
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         self.with_view(|view| view.read_range(from, to))
     }
@@ -219,6 +245,160 @@ impl ReadSegmentEntry for Segment {
         self.with_view(|view| view.estimate_point_count(filter, hw_counter))
     }
 
+    fn plan_must_conditions(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<PlannedCondition>> {
+        self.payload_index
+            .borrow()
+            .plan_must_conditions(filter, hw_counter)
+    }
+
+    fn quantized_vector(
+        &self,
+        vector_name: &VectorName,
+        point_id: PointIdType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<QuantizedVectorRecord>> {
+        check_vector_name(vector_name, &self.segment_config)?;
+        let vector_data = self
+            .vector_data
+            .get(vector_name)
+            .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
+
+        let point_offset = self.with_view(|view| view.lookup_internal_id(point_id))?;
+
+        let is_deleted = self.id_tracker.borrow().is_deleted_point(point_offset)
+            || vector_data
+                .vector_storage
+                .borrow()
+                .is_deleted_vector(point_offset);
+        if is_deleted {
+            return Ok(None);
+        }
+
+        let quantized_vectors = vector_data.quantized_vectors.borrow();
+        let Some(quantized_vectors) = quantized_vectors.as_ref() else {
+            return Ok(None);
+        };
+
+        quantized_vectors
+            .read_quantized_vector(point_offset, hw_counter)
+            .map(Some)
+    }
+
+    fn point_text_tokens(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<String>>> {
+        let internal_id =
+            self.id_tracker
+                .borrow()
+                .internal_id(point_id)
+                .ok_or(OperationError::PointIdError {
+                    missed_point_id: point_id,
+                })?;
+        self.payload_index
+            .borrow()
+            .point_text_tokens(key, internal_id, hw_counter)
+    }
+
+    fn point_text_match_offsets(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        text: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextValueMatches>>> {
+        let internal_id =
+            self.id_tracker
+                .borrow()
+                .internal_id(point_id)
+                .ok_or(OperationError::PointIdError {
+                    missed_point_id: point_id,
+                })?;
+        self.payload_index
+            .borrow()
+            .point_text_match_offsets(key, internal_id, text, hw_counter)
+    }
+
+    fn point_text_snippets(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        text: &str,
+        params: SnippetParams,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextSnippet>>> {
+        let internal_id =
+            self.id_tracker
+                .borrow()
+                .internal_id(point_id)
+                .ok_or(OperationError::PointIdError {
+                    missed_point_id: point_id,
+                })?;
+        self.payload_index
+            .borrow()
+            .point_text_snippets(key, internal_id, text, params, hw_counter)
+    }
+
+    fn value_samples(&self, key: &JsonPath) -> Option<ValueSamples> {
+        self.payload_index.borrow().value_samples(key)
+    }
+
+    fn text_index_points_count(&self, key: &JsonPath) -> usize {
+        self.payload_index.borrow().text_index_points_count(key)
+    }
+
+    fn text_frequent_tokens(
+        &self,
+        key: &JsonPath,
+        min_fraction: f64,
+    ) -> OperationResult<Vec<String>> {
+        self.payload_index
+            .borrow()
+            .text_frequent_tokens(key, min_fraction)
+    }
+
+    fn text_tokens_points_count(
+        &self,
+        key: &JsonPath,
+        tokens: &[String],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<usize>> {
+        self.payload_index
+            .borrow()
+            .text_tokens_points_count(key, tokens, hw_counter)
+    }
+
+    fn text_query_stopwords(
+        &self,
+        key: &JsonPath,
+        text: &str,
+        language: Option<Language>,
+    ) -> Option<QueryStopwords> {
+        self.payload_index
+            .borrow()
+            .text_query_stopwords(key, text, language)
+    }
+
+    fn populate_payload_indexes(
+        &self,
+        key: &PayloadKeyType,
+        budget: &mut PopulateBudget,
+    ) -> OperationResult<()> {
+        self.payload_index
+            .borrow()
+            .populate_with_budget(key, budget)
+    }
+
+    fn payload_indexes_ram_bytes(&self) -> usize {
+        self.payload_index.borrow().in_memory_indexes_ram_bytes()
+    }
+
     fn unique_values(
         &self,
         key: &JsonPath,
@@ -545,6 +725,48 @@ impl NonAppendableSegmentEntry for Segment {
         }
     }
 
+    fn delete_points(
+        &mut self,
+        op_num: SeqNumberType,
+        point_ids: &[PointIdType],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<usize> {
+        let mut internal_ids: Vec<_> = {
+            let id_tracker = self.id_tracker.borrow();
+            point_ids
+                .iter()
+                .filter_map(|point_id| id_tracker.internal_id(*point_id))
+                // Points with a higher version are not affected by the operation
+                .filter(|internal_id| {
+                    id_tracker
+                        .internal_version(*internal_id)
+                        .is_none_or(|version| version <= op_num)
+                })
+                .collect()
+        };
+        internal_ids.sort_unstable();
+        internal_ids.dedup();
+
+        if internal_ids.is_empty() {
+            return Ok(0);
+        }
+
+        self.handle_point_version_and_failure(op_num, None, |segment| {
+            segment.delete_points_internal(&internal_ids, hw_counter)?;
+
+            segment.version_tracker.set_payload(Some(op_num));
+
+            Ok((true, None))
+        })?;
+
+        let mut id_tracker = self.id_tracker.borrow_mut();
+        for &internal_id in &internal_ids {
+            id_tracker.set_internal_version(internal_id, op_num)?;
+        }
+
+        Ok(internal_ids.len())
+    }
+
     fn delete_field_index(&mut self, op_num: u64, key: PayloadKeyTypeRef) -> OperationResult<bool> {
         self.handle_segment_version_and_failure(op_num, |segment| {
             segment.payload_index.borrow_mut().drop_index(key)?;
@@ -627,6 +849,85 @@ impl NonAppendableSegmentEntry for Segment {
         })
     }
 
+    fn build_replacement_field_index(
+        &self,
+        op_num: SeqNumberType,
+        key: PayloadKeyTypeRef,
+        field_schema: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<BuildFieldIndexResult> {
+        // Check version without updating it
+        if self.version.unwrap_or(0) > op_num {
+            return Ok(BuildFieldIndexResult::SkippedByVersion);
+        }
+
+        let indexes =
+            self.payload_index
+                .borrow()
+                .build_replacement_index(key, field_schema, hw_counter)?;
+
+        Ok(BuildFieldIndexResult::Built {
+            indexes,
+            schema: field_schema.clone(),
+        })
+    }
+
+    fn swap_field_index(
+        &mut self,
+        op_num: SeqNumberType,
+        key: PayloadKeyType,
+        schema: PayloadFieldSchema,
+        field_index: Vec<FieldIndex>,
+    ) -> OperationResult<bool> {
+        self.handle_segment_version_and_failure(op_num, |segment| {
+            segment
+                .payload_index
+                .borrow_mut()
+                .swap_index(key.clone(), schema, field_index)?;
+
+            segment
+                .version_tracker
+                .set_payload_index_schema(&key, Some(op_num));
+
+            Ok(true)
+        })
+    }
+
+    fn set_field_index_on_disk(
+        &mut self,
+        op_num: SeqNumberType,
+        key: PayloadKeyTypeRef,
+        field_schema: &PayloadFieldSchema,
+    ) -> OperationResult<bool> {
+        self.handle_segment_version_and_failure(op_num, |segment| {
+            let Some(is_on_disk) = segment
+                .payload_index
+                .borrow_mut()
+                .set_index_on_disk(key, field_schema)?
+            else {
+                return Ok(false);
+            };
+
+            log::debug!(
+                "Index of field `{key}` in segment {} is {}",
+                segment.segment_path.display(),
+                if is_on_disk { "on disk" } else { "in memory" },
+            );
+
+            segment
+                .version_tracker
+                .set_payload_index_schema(key, Some(op_num));
+
+            Ok(true)
+        })
+    }
+
+    fn set_text_auto_stopwords(&mut self, key: &JsonPath, stopwords: Arc<AHashSet<String>>) {
+        self.payload_index
+            .borrow_mut()
+            .set_text_auto_stopwords(key, stopwords);
+    }
+
     fn create_vector_name(
         &mut self,
         op_num: SeqNumberType,
@@ -1,3 +1,4 @@
+mod duplicates;
 mod entry;
 pub mod memory;
 mod search;
@@ -64,6 +64,7 @@ where
                     num_vectors,
                     num_indexed_vectors,
                     num_deleted_vectors: vector_storage.deleted_vector_count(),
+                    num_full_precision_vectors: vector_data.full_precision_vectors_count(),
                 };
                 (key.clone(), info)
             })
@@ -140,7 +141,7 @@ where
             info: self.build_info(uuid, segment_type, is_appendable),
             config: config.clone(),
             vector_index_searches,
-            payload_field_indices: self.payload_index.get_telemetry_data(),
+            payload_field_indices: self.payload_index.get_telemetry_data(detail),
         }
     }
 }
@@ -1,15 +1,22 @@
+use std::cmp::Reverse;
 use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::iterator_ext::IteratorExt;
+use common::types::{PointOffsetType, ScoreType};
+use ordered_float::OrderedFloat;
 use rand::seq::{IteratorRandom, SliceRandom};
 
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::order_by::OrderValue;
+use crate::data_types::weighted_sample::WeightedSample;
 use crate::id_tracker::IdTrackerRead;
 use crate::index::PayloadIndexRead;
+use crate::index::field_index::NumericFieldIndexRead;
 use crate::payload_storage::PayloadStorageRead;
 use crate::segment::read_view::SegmentReadView;
 use crate::segment::vector_data_read::VectorDataRead;
+use crate::spaces::tools::peek_top_largest_iterable;
 use crate::types::{Filter, PointIdType};
 
 impl<'s, TIdT, TPI, TPS, TVD> SegmentReadView<'s, TIdT, TPI, TPS, TVD>
@@ -95,4 +102,73 @@ where
             }
         }
     }
+
+    /// Weighted random sample of points, which satisfy the filter.
+    ///
+    /// Every filtered point is assigned a sampling key, see [`WeightedSample`], so the whole
+    /// filtered set is read. Returns up to `limit` points with the largest keys, largest first.
+    pub fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        sample: &WeightedSample,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        let numeric_index = self
+            .payload_index
+            .numeric_index_for(&sample.key)
+            .ok_or_else(|| OperationError::MissingRangeIndexForSample {
+                key: sample.key.to_string(),
+            })?;
+
+        let sampling_key = |(external_id, internal_id): (PointIdType, PointOffsetType)| {
+            let weight = numeric_index
+                .get_ordering_values(internal_id)
+                .map(|value| match value {
+                    OrderValue::Int(value) => value as f64,
+                    OrderValue::Float(value) => value,
+                })
+                .reduce(f64::max);
+            let key = sample.sampling_key(external_id, weight)?;
+            Some((OrderedFloat(key), external_id))
+        };
+
+        let point_mappings = self.id_tracker.point_mappings();
+        let mut sampled = match filter {
+            None => {
+                let ids_iterator = point_mappings
+                    .iter_from_visible(None, self.deferred_internal_id())
+                    .stop_if(is_stopped);
+                peek_top_largest_iterable(ids_iterator.filter_map(sampling_key), limit)
+            }
+            Some(condition) => {
+                let cardinality_estimation = self
+                    .payload_index
+                    .estimate_cardinality(condition, hw_counter)?;
+                let ids_iterator = self
+                    .payload_index
+                    .iter_filtered_points(
+                        condition,
+                        self.id_tracker,
+                        &point_mappings,
+                        &cardinality_estimation,
+                        hw_counter,
+                        is_stopped,
+                        self.deferred_internal_id(),
+                    )?
+                    .filter_map(|internal_id| {
+                        let external_id = self.id_tracker.external_id(internal_id)?;
+                        Some((external_id, internal_id))
+                    });
+                peek_top_largest_iterable(ids_iterator.filter_map(sampling_key), limit)
+            }
+        };
+
+        sampled.sort_unstable_by_key(|&(key, _)| Reverse(key));
+        Ok(sampled
+            .into_iter()
+            .map(|(key, external_id)| (key.into_inner() as ScoreType, external_id))
+            .collect())
+    }
 }
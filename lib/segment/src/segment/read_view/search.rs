@@ -2,6 +2,7 @@ use std::sync::atomic::AtomicBool;
 
 use ahash::AHashMap;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::iterator_ext::IteratorExt;
 use common::types::{DeferredBehavior, ScoredPointOffset};
 
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -15,9 +16,10 @@ use crate::payload_storage::PayloadStorageRead;
 use crate::segment::read_view::SegmentReadView;
 use crate::segment::vector_data_read::VectorDataRead;
 use crate::types::{
-    ExtendedPointId, Filter, PointIdType, ScoredPoint, SearchParams, VectorName, VectorNameBuf,
-    WithPayload, WithVector,
+    ExtendedPointId, Filter, MISSING_VECTOR_SCORE, MissingVectors, PointIdType, ScoredPoint,
+    SearchParams, VectorName, VectorNameBuf, WithPayload, WithVector,
 };
+use crate::vector_storage::VectorStorageRead;
 
 impl<'s, TIdT, TPI, TPS, TVD> SegmentReadView<'s, TIdT, TPI, TPS, TVD>
 where
@@ -203,6 +205,8 @@ where
                 vector: vectors.map(VectorStructInternal::from),
                 shard_key: None,
                 order_value: None,
+                provenance: None,
+                score_stages: Vec::new(),
             });
         }
 
@@ -228,7 +232,7 @@ where
             .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
         let vector_query_context =
             query_context.get_vector_context(vector_name, self.deferred_internal_id());
-        let internal_results = vector_data.vector_index().search(
+        let mut internal_results = vector_data.vector_index().search(
             query_vectors,
             filter,
             top,
@@ -240,6 +244,21 @@ where
 
         let hw_counter = vector_query_context.hardware_counter();
 
+        let missing_vectors = params
+            .and_then(|params| params.missing_vectors)
+            .unwrap_or_default();
+        match missing_vectors {
+            MissingVectors::Exclude => {}
+            MissingVectors::Include => self.fill_with_missing_vectors(
+                vector_data,
+                &mut internal_results,
+                filter,
+                top,
+                &hw_counter,
+                &vector_query_context.is_stopped(),
+            )?,
+        }
+
         internal_results
             .into_iter()
             .map(|internal_result| {
@@ -254,6 +273,66 @@ where
             .collect()
     }
 
+    /// Append points without the searched vector to search results with fewer than `top` points.
+    /// Appended points get [`MISSING_VECTOR_SCORE`], so they go after all scored points.
+    fn fill_with_missing_vectors(
+        &self,
+        vector_data: &TVD,
+        internal_results: &mut [Vec<ScoredPointOffset>],
+        filter: Option<&Filter>,
+        top: usize,
+        hw_counter: &HardwareCounterCell,
+        is_stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        let Some(missing_count) = internal_results
+            .iter()
+            .map(|result| top.saturating_sub(result.len()))
+            .max()
+            .filter(|&count| count > 0)
+        else {
+            return Ok(());
+        };
+
+        let vector_storage = vector_data.vector_storage();
+        if vector_storage.deleted_vector_count() == 0 {
+            return Ok(());
+        }
+
+        let filter_context = filter
+            .map(|filter| self.payload_index.filter_context(filter, hw_counter))
+            .transpose()?;
+
+        let missing: Vec<_> = self
+            .id_tracker
+            .point_mappings()
+            .iter_internal_visible(self.deferred_internal_id())
+            .stop_if(is_stopped)
+            .filter(|&internal_id| {
+                vector_storage.is_deleted_vector(internal_id)
+                    && !self.id_tracker.is_deleted_point(internal_id)
+            })
+            .filter(|&internal_id| {
+                filter_context
+                    .as_ref()
+                    .is_none_or(|filter_context| filter_context.check(internal_id))
+            })
+            .take(missing_count)
+            .map(|idx| ScoredPointOffset {
+                idx,
+                score: MISSING_VECTOR_SCORE,
+            })
+            .collect();
+
+        check_stopped(is_stopped)?;
+
+        for result in internal_results {
+            let count = top.saturating_sub(result.len());
+            result.extend_from_slice(&missing[..count.min(missing.len())]);
+        }
+
+        Ok(())
+    }
+
     pub fn fill_query_context(&self, query_context: &mut QueryContext) {
         query_context.add_available_point_count(self.available_point_count_without_deferred());
         let hw_acc = query_context.hardware_usage_accumulator();
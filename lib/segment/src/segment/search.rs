@@ -1,4 +1,3 @@
-#[cfg(feature = "testing")]
 use super::Segment;
 #[cfg(feature = "testing")]
 use crate::common::operation_error::OperationResult;
@@ -9,7 +8,18 @@ use crate::data_types::vectors::QueryVector;
 #[cfg(feature = "testing")]
 use crate::entry::ReadSegmentEntry;
 #[cfg(feature = "testing")]
-use crate::types::{Filter, ScoredPoint, SearchParams, VectorName, WithPayload, WithVector};
+use crate::types::{Filter, SearchParams, VectorName, WithPayload, WithVector};
+use crate::types::{ScoredPoint, ScoredPointProvenance};
+
+impl Segment {
+    /// Mark the points as scored by this segment
+    pub(super) fn set_provenance(&self, points: &mut [ScoredPoint]) {
+        let provenance = ScoredPointProvenance::segment(self.uuid, self.appendable_flag);
+        for point in points {
+            point.provenance = Some(provenance);
+        }
+    }
+}
 
 #[cfg(feature = "testing")]
 impl Segment {
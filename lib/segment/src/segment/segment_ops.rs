@@ -308,11 +308,30 @@ impl Segment {
         internal_id: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        // Mark point as deleted, drop mapping
         self.payload_index
             .borrow_mut()
             .clear_payload(internal_id, hw_counter)?;
+        self.drop_point_internal(internal_id)
+    }
+
+    /// Same as [`Self::delete_point_internal`] for many points, which removes them from the
+    /// payload indexes at once
+    pub fn delete_points_internal(
+        &mut self,
+        internal_ids: &[PointOffsetType],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        self.payload_index
+            .borrow_mut()
+            .clear_payloads(internal_ids, hw_counter)?;
+        for &internal_id in internal_ids {
+            self.drop_point_internal(internal_id)?;
+        }
+        Ok(())
+    }
 
+    /// Mark point as deleted, drop mapping
+    fn drop_point_internal(&mut self, internal_id: PointOffsetType) -> OperationResult<()> {
         let mut id_tracker = self.id_tracker.borrow_mut();
 
         let is_point_already_deleted = id_tracker.is_deleted_point(internal_id);
@@ -1,5 +1,10 @@
+mod test_duplicate_vectors;
+mod test_expression_condition;
 mod test_immutable_payload_index_files;
+mod test_missing_vectors;
+mod test_updated_after;
 mod test_vector_name_ops;
+mod test_weighted_sample;
 
 use std::sync::atomic::AtomicBool;
 
@@ -22,6 +27,7 @@ use super::*;
 use crate::common::operation_error::OperationError::PointIdError;
 use crate::common::{check_named_vectors, check_vector, check_vector_name};
 use crate::data_types::facets::{FacetParams, FacetValue};
+use crate::data_types::index::GeoIndexParams;
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::OrderBy;
 use crate::data_types::query_context::QueryContext;
@@ -41,9 +47,9 @@ use crate::segment_constructor::simple_segment_constructor::{
 use crate::segment_constructor::{build_segment, load_segment};
 use crate::types::{
     Condition, Distance, ExtendedPointId, FieldCondition, Filter, HasIdCondition, Indexes, Match,
-    Payload, PayloadContainer, PayloadFieldSchema, PayloadSchemaType, PointIdType, SearchParams,
-    SnapshotFormat, SparseVectorDataConfig, SparseVectorStorageType, ValueVariants,
-    VectorDataConfig, VectorStorageType, WithPayload, WithVector,
+    Payload, PayloadContainer, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
+    PointIdType, SearchParams, SnapshotFormat, SparseVectorDataConfig, SparseVectorStorageType,
+    ValueVariants, VectorDataConfig, VectorStorageType, WithPayload, WithVector,
 };
 use crate::utils::maybe_arc::MaybeArc;
 use crate::vector_storage::query::{FeedbackItem, NaiveFeedbackCoefficients, NaiveFeedbackQuery};
@@ -107,6 +113,48 @@ fn test_search_batch_equivalence_single() {
     assert_eq!(search_result, search_batch_result[0].clone());
 }
 
+#[test]
+fn test_search_allow_list_of_ids() {
+    init_logger();
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    for i in 0..20u64 {
+        let vector = vec![1.0, i as f32];
+        segment
+            .upsert_point(i, i.into(), only_default_vector(&vector), &hw_counter)
+            .unwrap();
+    }
+
+    // Unknown ids are skipped
+    let allow_list = Filter::new_must(Condition::HasId(HasIdCondition {
+        has_id: MaybeArc::NoArc(AHashSet::from_iter(
+            [3, 11, 5, 42, 17].map(ExtendedPointId::NumId),
+        )),
+    }));
+    let params = SearchParams {
+        exact: true,
+        ..Default::default()
+    };
+
+    let query_vector = [0.0, 1.0].into();
+    let search_result = segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            &query_vector,
+            &WithPayload::default(),
+            &false.into(),
+            Some(&allow_list),
+            10,
+            Some(&params),
+        )
+        .unwrap();
+
+    let ids: Vec<_> = search_result.iter().map(|point| point.id).collect();
+    assert_eq!(ids, [17, 11, 5, 3].map(ExtendedPointId::NumId));
+}
+
 #[test]
 fn test_from_filter_attributes() {
     init_logger();
@@ -1273,6 +1321,80 @@ fn test_deferred_point_sparse() {
     }
 }
 
+#[test]
+fn test_h3_cell_facets() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    // Berlin twice, Potsdam and Tokyo
+    let locations = [
+        (13.41053, 52.52437),
+        (13.064473, 52.390569),
+        (13.41053, 52.52437),
+        (139.691706, 35.689487),
+    ];
+    for (i, (lon, lat)) in locations.into_iter().enumerate() {
+        let op_num = i as SeqNumberType;
+        let point_id = (i as u64).into();
+        segment
+            .upsert_point(
+                op_num,
+                point_id,
+                only_default_vector(&[1.0, 0.0]),
+                &hw_counter,
+            )
+            .unwrap();
+        let payload: Payload =
+            serde_json::from_value(serde_json::json!({"location": {"lon": lon, "lat": lat}}))
+                .unwrap();
+        segment
+            .set_full_payload(op_num, point_id, &payload, &hw_counter)
+            .unwrap();
+    }
+
+    // Cells are built from the payload already stored in the segment
+    let key = JsonPath::new("location");
+    let schema = PayloadFieldSchema::FieldParams(PayloadSchemaParams::Geo(GeoIndexParams {
+        h3_resolution: Some(6),
+        ..Default::default()
+    }));
+    segment
+        .create_field_index(10, &key, Some(&schema), &hw_counter)
+        .unwrap();
+
+    let facet = |filter: Option<Filter>| {
+        let request = FacetParams {
+            key: key.clone(),
+            limit: 10,
+            filter,
+            exact: false,
+        };
+        segment
+            .facet(&request, &AtomicBool::new(false), &hw_counter)
+            .unwrap()
+    };
+
+    let counts = facet(None);
+    let mut sorted_counts: Vec<_> = counts.values().copied().collect();
+    sorted_counts.sort_unstable();
+    assert_eq!(sorted_counts, vec![1, 1, 2]);
+    assert!(
+        counts
+            .keys()
+            .all(|cell| matches!(cell, FacetValue::Keyword(hex) if hex.len() == 15))
+    );
+
+    // Only cells of the filtered points are counted
+    let filter = Filter::new_must(Condition::HasId(HasIdCondition::from_iter(
+        [0, 1].map(ExtendedPointId::NumId),
+    )));
+    let filtered_counts = facet(Some(filter));
+    assert_eq!(filtered_counts.len(), 2);
+    assert!(filtered_counts.values().all(|&count| count == 1));
+    assert!(filtered_counts.keys().all(|cell| counts.contains_key(cell)));
+}
+
 #[test]
 fn test_deferred_point_facets() {
     init_logger();
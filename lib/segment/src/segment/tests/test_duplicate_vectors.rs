@@ -0,0 +1,145 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use tempfile::Builder;
+
+use crate::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorRef, only_default_vector};
+use crate::entry::entry_point::SegmentEntry as _;
+use crate::segment::Segment;
+use crate::segment::duplicates::{NEAR_DUPLICATE_HYPERPLANES, NearDuplicateBuckets};
+use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
+use crate::types::Distance;
+
+const DIM: usize = 4;
+
+fn build_segment_with_vectors(path: &std::path::Path, vectors: &[[f32; DIM]]) -> Segment {
+    let mut segment = build_simple_segment(path, DIM, Distance::Dot).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    for (i, vector) in vectors.iter().enumerate() {
+        segment
+            .upsert_point(
+                i as u64,
+                (i as u64).into(),
+                only_default_vector(vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+    segment
+}
+
+#[test]
+fn test_find_exact_duplicate_vectors() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = build_segment_with_vectors(
+        dir.path(),
+        &[
+            [1.0, 2.0, 3.0, 4.0],
+            [0.5, 0.5, 0.5, 0.5],
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.00001],
+            [0.5, 0.5, 0.5, 0.5],
+            [1.0, 2.0, 3.0, 4.0],
+            [-1.0, 0.0, 0.0, 0.0],
+        ],
+    );
+
+    let hw_counter = HardwareCounterCell::new();
+    let is_stopped = AtomicBool::new(false);
+
+    let mut groups = segment
+        .find_duplicate_vectors(DEFAULT_VECTOR_NAME, None, 10, &is_stopped, &hw_counter)
+        .unwrap();
+    groups.sort();
+    assert_eq!(
+        groups,
+        vec![vec![0.into(), 2.into(), 5.into()], vec![1.into(), 4.into()],],
+    );
+    assert!(hw_counter.vector_io_read().get() > 0);
+
+    // Deleted points are not reported
+    segment.delete_point(10, 4.into(), &hw_counter).unwrap();
+    let groups = segment
+        .find_duplicate_vectors(DEFAULT_VECTOR_NAME, None, 10, &is_stopped, &hw_counter)
+        .unwrap();
+    assert_eq!(groups, vec![vec![0.into(), 2.into(), 5.into()]]);
+
+    // The number of groups is bounded
+    let groups = segment
+        .find_duplicate_vectors(DEFAULT_VECTOR_NAME, None, 0, &is_stopped, &hw_counter)
+        .unwrap();
+    assert!(groups.is_empty());
+}
+
+#[test]
+fn test_find_near_duplicate_vectors() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment = build_segment_with_vectors(
+        dir.path(),
+        &[
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.001],
+            [1.0, 2.0, 3.0, 5.0],
+            [-1.0, -2.0, -3.0, -4.0],
+        ],
+    );
+
+    let hw_counter = HardwareCounterCell::new();
+    let is_stopped = AtomicBool::new(false);
+
+    // Exact scan does not group near duplicates
+    let groups = segment
+        .find_duplicate_vectors(DEFAULT_VECTOR_NAME, None, 10, &is_stopped, &hw_counter)
+        .unwrap();
+    assert!(groups.is_empty());
+
+    let groups = segment
+        .find_duplicate_vectors(
+            DEFAULT_VECTOR_NAME,
+            Some(0.01),
+            10,
+            &is_stopped,
+            &hw_counter,
+        )
+        .unwrap();
+    assert_eq!(groups, vec![vec![0.into(), 1.into()]]);
+
+    // Large epsilon groups everything within the same bucket, but not across buckets
+    let groups = segment
+        .find_duplicate_vectors(
+            DEFAULT_VECTOR_NAME,
+            Some(100.0),
+            10,
+            &is_stopped,
+            &hw_counter,
+        )
+        .unwrap();
+    assert!(groups.iter().flatten().all(|id| *id != 3.into()));
+}
+
+#[test]
+fn test_near_duplicate_buckets() {
+    let mut buckets = NearDuplicateBuckets::default();
+
+    // Vectors with all components positive are spread over buckets
+    let axes: Vec<_> = (0..DIM)
+        .map(|i| {
+            let mut vector = [0.0; DIM];
+            vector[i] = 1.0;
+            buckets.bucket(VectorRef::Dense(&vector))
+        })
+        .collect();
+    assert!(axes.iter().any(|bucket| *bucket != axes[0]));
+
+    // Opposite vectors lie on opposite sides of every hyperplane
+    let bucket = buckets.bucket(VectorRef::Dense(&[1.0, 2.0, 3.0, 4.0]));
+    let opposite = buckets.bucket(VectorRef::Dense(&[-1.0, -2.0, -3.0, -4.0]));
+    assert_eq!(bucket ^ opposite, (1 << NEAR_DUPLICATE_HYPERPLANES) - 1);
+
+    // Buckets don't depend on the length of the vector
+    assert_eq!(
+        buckets.bucket(VectorRef::Dense(&[2.0, 4.0, 6.0, 8.0])),
+        bucket
+    );
+}
@@ -0,0 +1,90 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::DeferredBehavior;
+use serde_json::json;
+use tempfile::Builder;
+
+use crate::data_types::vectors::only_default_vector;
+use crate::entry::entry_point::{ReadSegmentEntry as _, SegmentEntry as _};
+use crate::json_path::JsonPath;
+use crate::payload_json;
+use crate::segment::Segment;
+use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
+use crate::types::{
+    Condition, Distance, Filter, PayloadFieldSchema, PayloadSchemaType, PointIdType,
+};
+
+fn matching(segment: &Segment, condition: serde_json::Value) -> Vec<PointIdType> {
+    let condition: Condition = serde_json::from_value(condition).unwrap();
+    let filter = Filter::new_must(condition);
+    let mut ids = segment
+        .read_filtered(
+            None,
+            None,
+            Some(&filter),
+            &AtomicBool::new(false),
+            &HardwareCounterCell::new(),
+            DeferredBehavior::Exclude,
+        )
+        .unwrap();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_expression_condition() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let payloads = [
+        payload_json! {"price": 10, "quantity": 20},
+        payload_json! {"price": 5, "quantity": 10},
+        payload_json! {"price": 30.0, "quantity": [4, 100]},
+        payload_json! {"price": 50},
+        payload_json! {"price": "abc", "quantity": 10},
+        payload_json! {"price": 1e308, "quantity": 10},
+    ];
+    for (id, payload) in (1..).zip(payloads) {
+        segment
+            .upsert_point(id, id.into(), only_default_vector(&[1.0, 0.0]), &hw_counter)
+            .unwrap();
+        segment
+            .set_full_payload(10 + id, id.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+
+    // Only the first value of `quantity` is used, overflow doesn't match
+    let total = json!({
+        "expression": {"mult": ["price", "quantity"]},
+        "range": {"gt": 100},
+    });
+    // Division by zero doesn't match
+    let per_extra_item = json!({
+        "expression": {"div": {"left": "price", "right": {"sum": ["quantity", {"neg": 10}]}}},
+        "range": {"gte": 0},
+    });
+
+    let expected: Vec<PointIdType> = [1, 3].map(PointIdType::from).to_vec();
+    assert_eq!(matching(&segment, total.clone()), expected);
+    assert_eq!(
+        matching(&segment, per_extra_item.clone()),
+        vec![PointIdType::from(1)]
+    );
+
+    // Same results, with candidates narrowed down by the index
+    segment
+        .create_field_index(
+            20,
+            &JsonPath::new("price"),
+            Some(&PayloadFieldSchema::FieldType(PayloadSchemaType::Float)),
+            &hw_counter,
+        )
+        .unwrap();
+    assert_eq!(matching(&segment, total), expected);
+    assert_eq!(
+        matching(&segment, per_extra_item),
+        vec![PointIdType::from(1)]
+    );
+}
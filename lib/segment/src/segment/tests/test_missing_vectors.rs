@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::ScoreType;
+use tempfile::Builder;
+
+use crate::data_types::named_vectors::NamedVectors;
+use crate::data_types::vectors::QueryVector;
+use crate::entry::entry_point::SegmentEntry as _;
+use crate::segment::Segment;
+use crate::segment_constructor::build_segment;
+use crate::types::{
+    Distance, Indexes, MISSING_VECTOR_SCORE, MissingVectors, PointIdType, SearchParams,
+    SegmentConfig, VectorDataConfig, VectorStorageType, WithPayload, WithVector,
+};
+
+const DIM: usize = 2;
+const FULL_VECTOR: &str = "full";
+const PARTIAL_VECTOR: &str = "partial";
+
+fn vector_config() -> VectorDataConfig {
+    VectorDataConfig {
+        size: DIM,
+        distance: Distance::Dot,
+        storage_type: VectorStorageType::default(),
+        index: Indexes::Plain {},
+        quantization_config: None,
+        multivector_config: None,
+        datatype: None,
+    }
+}
+
+/// Build a segment with 4 points, only first 2 of them have the partial vector.
+fn build_segment_with_missing_vectors(path: &std::path::Path) -> Segment {
+    let mut segment = build_segment(
+        path,
+        &SegmentConfig {
+            vector_data: HashMap::from([
+                (FULL_VECTOR.to_owned(), vector_config()),
+                (PARTIAL_VECTOR.to_owned(), vector_config()),
+            ]),
+            sparse_vector_data: Default::default(),
+            payload_storage_type: Default::default(),
+        },
+        None,
+        true,
+    )
+    .unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    for i in 0..4u64 {
+        let vector = vec![1.0, i as f32];
+        let mut vectors = NamedVectors::default();
+        vectors.insert(FULL_VECTOR.to_owned(), vector.clone().into());
+        if i < 2 {
+            vectors.insert(PARTIAL_VECTOR.to_owned(), vector.into());
+        }
+        segment
+            .upsert_point(i, i.into(), vectors, &hw_counter)
+            .unwrap();
+    }
+    segment
+}
+
+fn search(
+    segment: &Segment,
+    missing_vectors: MissingVectors,
+    top: usize,
+) -> Vec<(PointIdType, ScoreType)> {
+    let params = SearchParams {
+        missing_vectors: Some(missing_vectors),
+        ..Default::default()
+    };
+    segment
+        .search(
+            PARTIAL_VECTOR,
+            &QueryVector::from(vec![1.0, 1.0]),
+            &WithPayload::default(),
+            &WithVector::Bool(false),
+            None,
+            top,
+            Some(&params),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|point| (point.id, point.score))
+        .collect()
+}
+
+#[test]
+fn test_search_missing_vectors() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment = build_segment_with_missing_vectors(dir.path());
+
+    // Points without the vector are never returned by default
+    let results = search(&segment, MissingVectors::Exclude, 10);
+    assert_eq!(results, vec![(1.into(), 2.0), (0.into(), 1.0)]);
+
+    // Points without the vector go after all points with the vector
+    let results = search(&segment, MissingVectors::Include, 10);
+    assert_eq!(results.len(), 4);
+    assert_eq!(&results[..2], &[(1.into(), 2.0), (0.into(), 1.0)]);
+    for (id, score) in &results[2..] {
+        assert!([2.into(), 3.into()].contains(id));
+        assert_eq!(*score, MISSING_VECTOR_SCORE);
+    }
+
+    // Only fill the gap up to the limit
+    let results = search(&segment, MissingVectors::Include, 3);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[2].1, MISSING_VECTOR_SCORE);
+
+    // Points with the vector are enough, nothing to fill
+    let results = search(&segment, MissingVectors::Include, 2);
+    assert_eq!(results, vec![(1.into(), 2.0), (0.into(), 1.0)]);
+}
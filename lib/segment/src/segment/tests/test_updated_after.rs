@@ -0,0 +1,57 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::DeferredBehavior;
+use tempfile::Builder;
+
+use crate::data_types::vectors::only_default_vector;
+use crate::entry::entry_point::{ReadSegmentEntry as _, SegmentEntry as _};
+use crate::segment::Segment;
+use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
+use crate::types::{Condition, Distance, Filter, PointIdType, UpdatedAfterCondition};
+
+fn updated_after(segment: &Segment, version: u64) -> Vec<PointIdType> {
+    let filter = Filter::new_must(Condition::UpdatedAfter(UpdatedAfterCondition {
+        updated_after: version,
+    }));
+    let mut ids = segment
+        .read_filtered(
+            None,
+            None,
+            Some(&filter),
+            &AtomicBool::new(false),
+            &HardwareCounterCell::new(),
+            DeferredBehavior::Exclude,
+        )
+        .unwrap();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_updated_after_condition() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    // Point `id` is inserted by operation `id`
+    for id in 1..=10 {
+        segment
+            .upsert_point(id, id.into(), only_default_vector(&[1.0, 0.0]), &hw_counter)
+            .unwrap();
+    }
+
+    let expected: Vec<PointIdType> = (6..=10).map(PointIdType::from).collect();
+    assert_eq!(updated_after(&segment, 5), expected);
+    assert!(updated_after(&segment, 10).is_empty());
+
+    // Updated points match again, deleted points never do
+    segment
+        .upsert_point(11, 2.into(), only_default_vector(&[0.0, 1.0]), &hw_counter)
+        .unwrap();
+    segment.delete_point(12, 8.into(), &hw_counter).unwrap();
+
+    let expected: Vec<PointIdType> = [2, 6, 7, 9, 10].map(PointIdType::from).to_vec();
+    assert_eq!(updated_after(&segment, 5), expected);
+    assert_eq!(updated_after(&segment, 10), vec![PointIdType::from(2)]);
+}
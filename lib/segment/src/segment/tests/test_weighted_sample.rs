@@ -0,0 +1,156 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use ordered_float::OrderedFloat;
+use tempfile::Builder;
+
+use crate::common::operation_error::OperationError;
+use crate::data_types::vectors::only_default_vector;
+use crate::data_types::weighted_sample::WeightedSample;
+use crate::entry::entry_point::{
+    NonAppendableSegmentEntry as _, ReadSegmentEntry as _, SegmentEntry as _,
+};
+use crate::json_path::JsonPath;
+use crate::payload_json;
+use crate::segment::Segment;
+use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
+use crate::types::{
+    Condition, Distance, FieldCondition, Filter, PayloadFieldSchema, PayloadSchemaType,
+    PointIdType, Range,
+};
+
+const NUM_POINTS: u64 = 200;
+
+/// Points `0..NUM_POINTS` with `weight = id % 4`, every 10th point has no weight at all
+fn build_weighted_segment(path: &std::path::Path) -> Segment {
+    let mut segment = build_simple_segment(path, 2, Distance::Dot).unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    for id in 0..NUM_POINTS {
+        segment
+            .upsert_point(id, id.into(), only_default_vector(&[1.0, 0.0]), &hw_counter)
+            .unwrap();
+        let payload = if id % 10 == 0 {
+            payload_json! { "group": id % 2 }
+        } else {
+            payload_json! { "weight": id % 4, "group": id % 2 }
+        };
+        segment
+            .set_full_payload(id, id.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+
+    segment
+        .create_field_index(
+            NUM_POINTS,
+            &JsonPath::new("weight"),
+            Some(&PayloadFieldSchema::FieldType(PayloadSchemaType::Integer)),
+            &hw_counter,
+        )
+        .unwrap();
+    segment
+}
+
+fn sample_ids(
+    segment: &Segment,
+    limit: usize,
+    filter: Option<&Filter>,
+    sample: &WeightedSample,
+) -> Vec<PointIdType> {
+    let hw_counter = HardwareCounterCell::new();
+    segment
+        .read_weighted_random_filtered(limit, filter, sample, &AtomicBool::new(false), &hw_counter)
+        .unwrap()
+        .into_iter()
+        .map(|(_, id)| id)
+        .collect()
+}
+
+fn weight_of(id: PointIdType) -> Option<u64> {
+    let PointIdType::NumId(id) = id else {
+        unreachable!("only numeric ids are used in this test")
+    };
+    (id % 10 != 0).then_some(id % 4)
+}
+
+#[test]
+fn test_weighted_sample() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment = build_weighted_segment(dir.path());
+
+    let sample = WeightedSample {
+        key: JsonPath::new("weight"),
+        seed: 42,
+        default_weight: OrderedFloat(0.0),
+    };
+
+    // Deterministic for the same seed
+    let ids = sample_ids(&segment, 20, None, &sample);
+    assert_eq!(ids.len(), 20);
+    assert_eq!(ids, sample_ids(&segment, 20, None, &sample));
+
+    // Different seeds give different samples
+    let other_seed = WeightedSample {
+        seed: 43,
+        ..sample.clone()
+    };
+    assert_ne!(ids, sample_ids(&segment, 20, None, &other_seed));
+
+    // Points with zero weight, or without any weight, are never sampled
+    let all_ids = sample_ids(&segment, NUM_POINTS as usize, None, &sample);
+    let expected = (0..NUM_POINTS)
+        .map(PointIdType::from)
+        .filter(|&id| weight_of(id).is_some_and(|weight| weight > 0))
+        .count();
+    assert_eq!(all_ids.len(), expected);
+
+    // Unless they have a default weight
+    let with_default = WeightedSample {
+        default_weight: OrderedFloat(1.0),
+        ..sample.clone()
+    };
+    let all_ids = sample_ids(&segment, NUM_POINTS as usize, None, &with_default);
+    assert!(all_ids.iter().any(|&id| weight_of(id).is_none()));
+    assert!(all_ids.iter().all(|&id| weight_of(id) != Some(0)));
+
+    // Only points which match the filter are sampled
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+        JsonPath::new("weight"),
+        Range {
+            gte: Some(OrderedFloat(2.0)),
+            ..Default::default()
+        },
+    )));
+    let filtered = sample_ids(&segment, NUM_POINTS as usize, Some(&filter), &sample);
+    assert!(!filtered.is_empty());
+    assert!(
+        filtered
+            .iter()
+            .all(|&id| weight_of(id).is_some_and(|weight| weight >= 2)),
+    );
+}
+
+#[test]
+fn test_weighted_sample_requires_range_index() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment = build_weighted_segment(dir.path());
+
+    let sample = WeightedSample {
+        key: JsonPath::new("group"),
+        seed: 42,
+        default_weight: OrderedFloat(0.0),
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+    let result = segment.read_weighted_random_filtered(
+        10,
+        None,
+        &sample,
+        &AtomicBool::new(false),
+        &hw_counter,
+    );
+    assert!(matches!(
+        result,
+        Err(OperationError::MissingRangeIndexForSample { .. }),
+    ));
+}
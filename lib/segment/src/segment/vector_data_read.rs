@@ -4,6 +4,7 @@ use atomic_refcell::AtomicRef;
 
 use crate::index::{VectorIndexEnum, VectorIndexRead};
 use crate::segment::VectorData;
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::{VectorStorageEnum, VectorStorageRead};
 
 /// Read-only view over a single named vector entry of a segment.
@@ -23,6 +24,9 @@ pub trait VectorDataRead {
     fn vector_index(&self) -> Self::IndexRef<'_>;
 
     fn vector_storage(&self) -> Self::StorageRef<'_>;
+
+    /// Number of vectors kept in full precision next to the quantized ones, if any
+    fn full_precision_vectors_count(&self) -> Option<usize>;
 }
 
 impl VectorDataRead for VectorData {
@@ -36,4 +40,11 @@ impl VectorDataRead for VectorData {
     fn vector_storage(&self) -> Self::StorageRef<'_> {
         self.vector_storage.borrow()
     }
+
+    fn full_precision_vectors_count(&self) -> Option<usize> {
+        self.quantized_vectors
+            .borrow()
+            .as_ref()
+            .and_then(QuantizedVectors::full_precision_vectors_count)
+    }
 }
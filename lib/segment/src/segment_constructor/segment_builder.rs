@@ -222,6 +222,15 @@ impl SegmentBuilder {
                     }
                     break;
                 }
+                FieldIndex::ScaledFloatIndex(index) => {
+                    // Stored multiples of the precision preserve the ordering of the values
+                    if let Some(numbers) = index.inner().get_values(internal_id) {
+                        for number in numbers {
+                            ordering = ordering.wrapping_add(number as u64);
+                        }
+                    }
+                    break;
+                }
                 FieldIndex::DatetimeIndex(index) => {
                     if let Some(dates) = index.get_values(internal_id) {
                         for date in dates {
@@ -14,6 +14,14 @@ pub trait Metric<T: PrimitiveVectorElement> {
     /// Necessary vector transformations performed before adding it to the collection (like normalization)
     /// If no transformation is needed - returns the same vector
     fn preprocess(vector: DenseVector) -> DenseVector;
+
+    /// Same as [`Self::similarity`], with precomputed euclidean norms of both vectors.
+    ///
+    /// Only metrics, which compute the norms while scoring, make use of them.
+    /// See [`VectorNorms`](crate::vector_storage::dense::vector_norms::VectorNorms).
+    fn similarity_with_norms(v1: &[T], v2: &[T], _norm1: f32, _norm2: f32) -> ScoreType {
+        Self::similarity(v1, v2)
+    }
 }
 
 pub trait MetricPostProcessing {
@@ -10,7 +10,7 @@ use crate::spaces::metric_uint::neon::cosine::neon_cosine_similarity_bytes;
 use crate::spaces::metric_uint::sse2::cosine::sse_cosine_similarity_bytes;
 #[cfg(target_arch = "x86_64")]
 use crate::spaces::simple::MIN_DIM_SIZE_AVX;
-use crate::spaces::simple::{CosineMetric, MIN_DIM_SIZE_SIMD};
+use crate::spaces::simple::{CosineMetric, DotProductMetric, MIN_DIM_SIZE_SIMD};
 use crate::types::Distance;
 
 impl Metric<VectorElementTypeByte> for CosineMetric {
@@ -53,6 +53,19 @@ impl Metric<VectorElementTypeByte> for CosineMetric {
     fn preprocess(vector: DenseVector) -> DenseVector {
         vector
     }
+
+    fn similarity_with_norms(
+        v1: &[VectorElementTypeByte],
+        v2: &[VectorElementTypeByte],
+        norm1: f32,
+        norm2: f32,
+    ) -> ScoreType {
+        if norm1 == 0.0 || norm2 == 0.0 {
+            return 0.0;
+        }
+
+        <DotProductMetric as Metric<VectorElementTypeByte>>::similarity(v1, v2) / (norm1 * norm2)
+    }
 }
 
 pub fn cosine_similarity_bytes(
@@ -3,6 +3,7 @@ use serde::Serialize;
 
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_time_statistics::OperationDurationStatistics;
+use crate::data_types::index::Language;
 use crate::types::{SegmentConfig, SegmentInfo, VectorNameBuf};
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -21,6 +22,9 @@ pub struct PayloadIndexTelemetry {
     #[anonymize(false)]
     pub index_type: &'static str,
 
+    /// Whether the index is read from disk, rather than loaded into memory.
+    pub is_on_disk: bool,
+
     /// The amount of values indexed for all points.
     pub points_values_count: usize,
 
@@ -30,6 +34,28 @@ pub struct PayloadIndexTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub histogram_bucket_size: Option<usize>,
+
+    /// Bytes written into index storage per byte of indexed data, since the index was loaded.
+    /// Only reported for mutable indexes stored in gridstore.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub write_amplification: Option<f64>,
+
+    /// Values larger than `max_value_bytes` of the field, since the index was loaded.
+    /// Only reported for fields with a size limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oversized_values: Option<OversizedValuesTelemetry>,
+
+    /// Language of the stopwords, detected from the indexed texts.
+    /// Only reported for full-text indexes with automatic stopwords language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub stopwords_language: Option<Language>,
+
+    /// Approximate memory used by the index, including its pages in the page cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub memory_usage_bytes: Option<usize>,
 }
 
 impl PayloadIndexTelemetry {
@@ -37,6 +63,24 @@ impl PayloadIndexTelemetry {
         self.field_name = Some(name);
         self
     }
+
+    pub fn set_oversized_values(mut self, oversized_values: OversizedValuesTelemetry) -> Self {
+        self.oversized_values = Some(oversized_values);
+        self
+    }
+
+    pub fn set_memory_usage_bytes(mut self, memory_usage_bytes: usize) -> Self {
+        self.memory_usage_bytes = Some(memory_usage_bytes);
+        self
+    }
+}
+
+#[derive(Serialize, Clone, Copy, Debug, Default, JsonSchema, Anonymize)]
+pub struct OversizedValuesTelemetry {
+    /// Number of values, which were not indexed
+    pub skipped: usize,
+    /// Number of values, which were indexed partially
+    pub truncated: usize,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize, Default)]
@@ -71,4 +115,15 @@ pub struct VectorIndexSearchesTelemetry {
 
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub unfiltered_exact: OperationDurationStatistics,
+
+    /// Quantized searches, which were retried with larger oversampling,
+    /// because quantized and rescored rankings disagreed too much
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub quantization_fallback: OperationDurationStatistics,
+
+    /// Average fraction of the rescored results, which were not in the top by quantized score.
+    /// Observed in searches with oversampling, recent searches are weighted more
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub quantization_distortion: Option<f64>,
 }
@@ -33,14 +33,14 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MaybeOneOrMany, MultiValue};
 use crate::data_types::index::{
     BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
-    KeywordIndexParams, TextIndexParams, UuidIndexParams,
+    KeywordIndexParams, Language, TextIndexParams, UuidIndexParams,
 };
 use crate::data_types::modifier::Modifier;
 use crate::data_types::order_by::OrderValue;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::tiny_map::TinyMap;
 use crate::data_types::vectors::{DenseVector, VectorStructInternal};
-use crate::index::field_index::CardinalityEstimation;
+use crate::index::field_index::{CardinalityEstimation, geo_hash};
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
 use crate::json_path::JsonPath;
 use crate::spaces::metric::{Metric, MetricPostProcessing};
@@ -387,6 +387,59 @@ pub struct ScoredPoint {
     pub shard_key: Option<ShardKey>,
     /// Order-by value
     pub order_value: Option<OrderValue>,
+    /// Where the point was found, see [`ScoredPointProvenance`]
+    pub provenance: Option<ScoredPointProvenance>,
+    /// Stages, which composed the score, in the order they were applied.
+    /// Only recorded for queries explaining their scores, see [`ScoreStage`]
+    pub score_stages: Vec<ScoreStage>,
+}
+
+/// Segment, which scored a search result, carried along with the result through the merges.
+///
+/// Set by the segment itself, so results found through a proxy segment name the segment which
+/// actually stores the point. Shard and peer are filled in when results of shards are merged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScoredPointProvenance {
+    pub segment_uuid: Uuid,
+    /// Whether the segment is appendable, i.e. receives new points
+    pub appendable: bool,
+    pub shard_id: Option<u32>,
+    pub peer_id: Option<u64>,
+}
+
+impl ScoredPointProvenance {
+    pub fn segment(segment_uuid: Uuid, appendable: bool) -> Self {
+        Self {
+            segment_uuid,
+            appendable,
+            shard_id: None,
+            peer_id: None,
+        }
+    }
+}
+
+/// Stage of the scoring of a search result, with the score of the point after the stage.
+///
+/// Stages rescoring prefetched points follow the stages of the prefetch, which found the point.
+/// If several prefetches found it, the stages of the first of them are kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreStage {
+    pub kind: ScoreStageKind,
+    pub score: ScoreType,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScoreStageKind {
+    /// Scored against the query vector(s)
+    Vector,
+    /// Scored by a formula, like recency or proximity boosting
+    Formula,
+    /// Fusion of the prefetches
+    Fusion,
+    /// Reordered by maximal marginal relevance
+    Mmr,
+    /// Multiplied by a boost rule of the collection
+    Boost { rule: String, multiplier: f64 },
 }
 
 impl Eq for ScoredPoint {}
@@ -461,6 +514,10 @@ pub struct VectorDataInfo {
     pub num_vectors: usize,
     pub num_indexed_vectors: usize,
     pub num_deleted_vectors: usize,
+    /// Number of vectors kept in full precision, because they don't fit into the range of
+    /// scalar quantization. Only set with the `full_precision` outliers policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_full_precision_vectors: Option<usize>,
 }
 
 /// Aggregated information about segment
@@ -527,6 +584,12 @@ pub struct QuantizationSearchParams {
     #[validate(range(min = 1.0))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oversampling: Option<f64>,
+
+    /// Retry of the search with larger oversampling, if quantized scores are too inaccurate.
+    #[serde(default)]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<QuantizationFallbackParams>,
 }
 
 impl Hash for QuantizationSearchParams {
@@ -535,10 +598,12 @@ impl Hash for QuantizationSearchParams {
             ignore,
             rescore,
             oversampling,
+            fallback,
         } = self;
         ignore.hash(state);
         rescore.hash(state);
         oversampling.map(OrderedFloat).hash(state);
+        fallback.hash(state);
     }
 }
 
@@ -550,6 +615,48 @@ pub const fn default_quantization_oversampling_value() -> Option<f64> {
     None
 }
 
+/// Default value for [`QuantizationFallbackParams::max_distortion`].
+///
+/// After change, update docs for GRPC and REST API.
+pub const QUANTIZATION_FALLBACK_MAX_DISTORTION_DEFAULT: f64 = 0.3;
+
+/// Default value for [`QuantizationFallbackParams::max_oversampling`].
+///
+/// After change, update docs for GRPC and REST API.
+pub const QUANTIZATION_FALLBACK_MAX_OVERSAMPLING_DEFAULT: f64 = 8.0;
+
+/// Fallback for quantized search, if quantized and rescored rankings disagree too much
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, Copy, PartialEq, Default, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct QuantizationFallbackParams {
+    /// If true, the search is retried with doubled oversampling, while too many of the rescored
+    /// top results are missing in the top results by quantized score.
+    ///
+    /// Improves recall for collections with high quantization distortion, at cost of latency.
+    /// Only applies if rescoring is enabled.
+    #[serde(default)]
+    pub enable: bool,
+
+    /// Maximum distortion of the quantized ranking, before the search is retried.
+    ///
+    /// Distortion is the fraction of the rescored top results,
+    /// which are not among the top results by quantized score.
+    ///
+    /// 0.0 to retry on any difference, 1.0 for never. Default is 0.3.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub max_distortion: Option<OrderedFloat<f64>>,
+
+    /// Maximum oversampling of the retries, the search is not retried beyond it. Default is 8.0.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1.0))]
+    pub max_oversampling: Option<OrderedFloat<f64>>,
+}
+
 /// Default value for [`AcornSearchParams::max_selectivity`].
 ///
 /// After change, update docs for GRPC and REST API.
@@ -614,6 +721,46 @@ pub struct SearchParams {
     #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acorn: Option<AcornSearchParams>,
+
+    /// How to treat points, which don't have the searched vector.
+    /// Default: `exclude` - such points are never returned.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_vectors: Option<MissingVectors>,
+
+    /// Limit the number of results each segment of a shard contributes to the merge of the shard
+    /// results to `segment_limit_factor * limit / number of segments`, rounded up.
+    ///
+    /// Bounds the merge work on shards with many segments, at cost of recall: if the best points
+    /// are concentrated in a few segments, some of them are not returned. The lower the factor,
+    /// the more points may be lost, a factor not lower than the number of segments is exact.
+    /// The limit is reported in the statistics of the query response.
+    /// Default: no limit.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1.0))]
+    pub segment_limit_factor: Option<OrderedFloat<f64>>,
+
+    /// Acknowledge, that the filter of the request might match a large part of the collection.
+    /// Allows the request, if strict mode rejects filters exceeding `filter_max_selectivity`.
+    #[serde(default)]
+    pub allow_full_scan: bool,
+}
+
+/// Score of points without the searched vector, worse than any real score
+pub const MISSING_VECTOR_SCORE: ScoreType = ScoreType::MIN;
+
+/// Treatment of points without the searched vector, in collections with multiple named vectors
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingVectors {
+    /// Points without the vector are not returned
+    #[default]
+    Exclude,
+    /// Points without the vector, which match the filter, are returned after all points with
+    /// the vector, with the worst possible score. They only surface if fewer points with the
+    /// vector match the filter than requested, and never pass a score threshold.
+    Include,
 }
 
 /// Configuration for vectors.
@@ -776,6 +923,23 @@ pub struct ScalarQuantizationConfig {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+    /// How to handle vectors with values outside of the quantile range.
+    /// Default is `clamp`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<ScalarOutliers>,
+}
+
+/// Handling of vectors with values outside of the range covered by scalar quantization
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalarOutliers {
+    /// Clamp the values into the range, such vectors are scored less accurately
+    #[default]
+    Clamp,
+    /// Keep the vectors distorted the most by clamping in full precision next to the quantized
+    /// ones, and score them exactly. At most 1% of the vectors are kept in full precision.
+    /// Only applies to single dense vectors searched by nearest query.
+    FullPrecision,
 }
 
 impl ScalarQuantizationConfig {
@@ -825,6 +989,7 @@ impl Hash for ScalarQuantizationConfig {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.always_ram.hash(state);
         self.r#type.hash(state);
+        self.outliers.hash(state);
     }
 }
 
@@ -1154,6 +1319,19 @@ pub struct StrictModeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub condition_max_size: Option<usize>,
 
+    /// Max estimated selectivity of filters in search requests, e.g. search or query.
+    /// Selectivity is estimated as:
+    /// `estimated number of points satisfying the filter / total number of points`.
+    /// Requests with a less selective filter are rejected, unless they set `allow_full_scan`
+    /// in the search params. Requests without a filter match all points. Value in [0, 1].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub filter_max_selectivity: Option<f64>,
+
+    /// Only log a warning instead of rejecting requests exceeding `filter_max_selectivity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_selectivity_warn_only: Option<bool>,
+
     /// Multivector strict mode configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(nested)]
@@ -1203,6 +1381,9 @@ impl Hash for StrictModeConfig {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            // We skip hashing this field because we cannot reliably hash a float
+            filter_max_selectivity: _,
+            filter_selectivity_warn_only,
             multivector_config,
             sparse_config,
             max_payload_index_count,
@@ -1224,6 +1405,7 @@ impl Hash for StrictModeConfig {
         max_points_count.hash(state);
         filter_max_conditions.hash(state);
         condition_max_size.hash(state);
+        filter_selectivity_warn_only.hash(state);
         multivector_config.hash(state);
         sparse_config.hash(state);
         max_payload_index_count.hash(state);
@@ -1316,6 +1498,15 @@ pub struct StrictModeConfigOutput {
     #[anonymize(false)]
     pub condition_max_size: Option<usize>,
 
+    /// Max estimated selectivity of filters in search requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[anonymize(false)]
+    pub filter_max_selectivity: Option<f64>,
+
+    /// Only log a warning instead of rejecting requests exceeding `filter_max_selectivity`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_selectivity_warn_only: Option<bool>,
+
     /// Multivector configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub multivector_config: Option<StrictModeMultivectorConfigOutput>,
@@ -1354,6 +1545,8 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            filter_max_selectivity,
+            filter_selectivity_warn_only,
             multivector_config,
             sparse_config,
             max_payload_index_count,
@@ -1378,6 +1571,8 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             max_points_count,
             filter_max_conditions,
             condition_max_size,
+            filter_max_selectivity,
+            filter_selectivity_warn_only,
             multivector_config: multivector_config.map(StrictModeMultivectorConfigOutput::from),
             sparse_config: sparse_config.map(StrictModeSparseConfigOutput::from),
             max_payload_index_count,
@@ -2243,6 +2438,19 @@ impl PayloadSchemaParams {
         }
     }
 
+    fn on_disk_mut(&mut self) -> &mut Option<bool> {
+        match self {
+            PayloadSchemaParams::Keyword(i) => &mut i.on_disk,
+            PayloadSchemaParams::Integer(i) => &mut i.on_disk,
+            PayloadSchemaParams::Float(i) => &mut i.on_disk,
+            PayloadSchemaParams::Datetime(i) => &mut i.on_disk,
+            PayloadSchemaParams::Uuid(i) => &mut i.on_disk,
+            PayloadSchemaParams::Text(i) => &mut i.on_disk,
+            PayloadSchemaParams::Geo(i) => &mut i.on_disk,
+            PayloadSchemaParams::Bool(i) => &mut i.on_disk,
+        }
+    }
+
     pub fn enable_hnsw(&self) -> bool {
         match self {
             PayloadSchemaParams::Keyword(params) => params.enable_hnsw.unwrap_or(true),
@@ -2262,10 +2470,10 @@ impl Validate for PayloadSchemaParams {
         match self {
             PayloadSchemaParams::Keyword(_) => Ok(()),
             PayloadSchemaParams::Integer(integer_index_params) => integer_index_params.validate(),
-            PayloadSchemaParams::Float(_) => Ok(()),
-            PayloadSchemaParams::Geo(_) => Ok(()),
+            PayloadSchemaParams::Float(float_index_params) => float_index_params.validate(),
+            PayloadSchemaParams::Geo(geo_index_params) => geo_index_params.validate(),
             PayloadSchemaParams::Text(_) => Ok(()),
-            PayloadSchemaParams::Bool(_) => Ok(()),
+            PayloadSchemaParams::Bool(bool_index_params) => bool_index_params.validate(),
             PayloadSchemaParams::Datetime(_) => Ok(()),
             PayloadSchemaParams::Uuid(_) => Ok(()),
         }
@@ -2372,6 +2580,15 @@ impl PayloadFieldSchema {
         }
     }
 
+    /// Whether both schemas are the same, apart from the `on_disk` option
+    pub fn eq_ignoring_on_disk(&self, other: &PayloadFieldSchema) -> bool {
+        let mut this = self.expand().into_owned();
+        let mut other = other.expand().into_owned();
+        *this.on_disk_mut() = None;
+        *other.on_disk_mut() = None;
+        this == other
+    }
+
     pub fn kind(&self) -> PayloadSchemaType {
         match self {
             PayloadFieldSchema::FieldType(t) => *t,
@@ -2517,6 +2734,16 @@ pub struct MatchValue {
 #[serde(rename_all = "snake_case")]
 pub struct MatchText {
     pub text: String,
+    /// Language of the query. Stopwords of this language are removed from the query tokens,
+    /// in addition to the stopwords configured for the field.
+    /// Default: only stopwords configured for the field are removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    /// If true, match the original form of the query tokens instead of the stemmed one.
+    /// Requires `index_unstemmed` for the field, otherwise the query is stemmed as usual.
+    /// Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unstemmed: Option<bool>,
 }
 
 /// Full-text match of at least one token of the string.
@@ -2524,11 +2751,85 @@ pub struct MatchText {
 #[serde(rename_all = "snake_case")]
 pub struct MatchTextAny {
     pub text_any: String,
+    /// Minimal number of query tokens, which must be present in the document.
+    /// Default: 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_should_match: Option<MinShouldMatch>,
+}
+
+impl<S: Into<String>> From<S> for MatchTextAny {
+    fn from(text: S) -> Self {
+        MatchTextAny {
+            text_any: text.into(),
+            min_should_match: None,
+        }
+    }
+}
+
+/// Minimal number of query tokens, which must be present in the document.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum MinShouldMatch {
+    /// Absolute number of query tokens
+    Count(usize),
+    /// Percentage of the query tokens, rounded down
+    Percentage(MinShouldMatchPercentage),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MinShouldMatchPercentage {
+    /// Percentage of the query tokens, from 0 to 100
+    pub percent: u32,
+}
+
+impl MinShouldMatch {
+    pub const MAX_PERCENT: u32 = 100;
+
+    /// Number of tokens, which must match for a query of `query_tokens` tokens.
+    ///
+    /// At least one token must always match.
+    pub fn min_tokens(&self, query_tokens: usize) -> usize {
+        let min_tokens = match self {
+            MinShouldMatch::Count(count) => *count,
+            MinShouldMatch::Percentage(MinShouldMatchPercentage { percent }) => {
+                let percent = (*percent).min(Self::MAX_PERCENT) as usize;
+                query_tokens * percent / Self::MAX_PERCENT as usize
+            }
+        };
+        min_tokens.max(1)
+    }
+}
+
+/// How the relevance of a text match is aggregated over the values of an array field.
+///
+/// For a single (non-array) value both aggregations give the same score.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TextScoreAggregation {
+    /// Score of the best matching value
+    #[default]
+    Max,
+    /// Sum of the scores of all values
+    Sum,
+}
+
+impl TextScoreAggregation {
+    pub fn aggregate(self, scores: impl IntoIterator<Item = f32>) -> f32 {
+        match self {
+            TextScoreAggregation::Max => scores.into_iter().fold(0.0, f32::max),
+            TextScoreAggregation::Sum => scores.into_iter().sum(),
+        }
+    }
 }
 
 impl<S: Into<String>> From<S> for MatchText {
     fn from(text: S) -> Self {
-        MatchText { text: text.into() }
+        MatchText {
+            text: text.into(),
+            language: None,
+            unstemmed: None,
+        }
     }
 }
 
@@ -2537,12 +2838,18 @@ impl<S: Into<String>> From<S> for MatchText {
 #[serde(rename_all = "snake_case")]
 pub struct MatchPhrase {
     pub phrase: String,
+    /// Max number of other tokens between the tokens of the phrase, in total.
+    /// The tokens must still be in the order of the phrase, and within one value of an array.
+    /// Default: 0, the tokens must be adjacent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slop: Option<u32>,
 }
 
 impl<S: Into<String>> From<S> for MatchPhrase {
     fn from(text: S) -> Self {
         MatchPhrase {
             phrase: text.into(),
+            slop: None,
         }
     }
 }
@@ -2561,6 +2868,26 @@ pub struct MatchExcept {
     pub except: AnyVariants,
 }
 
+/// Should have exactly the given set of values, no more and no less.
+///
+/// Duplicates are ignored, both in the condition and in the payload:
+/// `["a", "a", "b"]` matches `{"a", "b"}`. A single value is treated as a set of one value.
+/// An empty set matches nothing, use `is_empty` condition instead.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchExactSet {
+    pub exact_set: AnyVariants,
+}
+
+/// Keyword match of the values starting with the given prefix.
+///
+/// The prefix is matched as is, without tokenization. Case-sensitive.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchKeywordPrefix {
+    pub prefix: String,
+}
+
 /// Match filter request
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
 #[serde(untagged, rename_all = "snake_case")]
@@ -2571,6 +2898,8 @@ pub enum MatchInterface {
     Phrase(MatchPhrase),
     Any(MatchAny),
     Except(MatchExcept),
+    ExactSet(MatchExactSet),
+    KeywordPrefix(MatchKeywordPrefix),
 }
 
 /// Match filter request
@@ -2583,6 +2912,8 @@ pub enum Match {
     Phrase(MatchPhrase),
     Any(MatchAny),
     Except(MatchExcept),
+    ExactSet(MatchExactSet),
+    KeywordPrefix(MatchKeywordPrefix),
 }
 
 impl Match {
@@ -2591,7 +2922,11 @@ impl Match {
     }
 
     pub fn new_text(text: &str) -> Self {
-        Self::Text(MatchText { text: text.into() })
+        Self::Text(MatchText {
+            text: text.into(),
+            language: None,
+            unstemmed: None,
+        })
     }
 
     pub fn new_any(any: AnyVariants) -> Self {
@@ -2601,6 +2936,16 @@ impl Match {
     pub fn new_except(except: AnyVariants) -> Self {
         Self::Except(MatchExcept { except })
     }
+
+    pub fn new_exact_set(exact_set: AnyVariants) -> Self {
+        Self::ExactSet(MatchExactSet { exact_set })
+    }
+
+    pub fn new_keyword_prefix(prefix: impl Into<String>) -> Self {
+        Self::KeywordPrefix(MatchKeywordPrefix {
+            prefix: prefix.into(),
+        })
+    }
 }
 
 impl From<AnyVariants> for Match {
@@ -2613,15 +2958,35 @@ impl From<MatchInterface> for Match {
     fn from(value: MatchInterface) -> Self {
         match value {
             MatchInterface::Value(value) => Self::Value(MatchValue { value: value.value }),
-            MatchInterface::Text(text) => Self::Text(MatchText { text: text.text }),
-            MatchInterface::TextAny(text_any) => Self::TextAny(MatchTextAny {
-                text_any: text_any.text_any,
+            MatchInterface::Text(MatchText {
+                text,
+                language,
+                unstemmed,
+            }) => Self::Text(MatchText {
+                text,
+                language,
+                unstemmed,
+            }),
+            MatchInterface::TextAny(MatchTextAny {
+                text_any,
+                min_should_match,
+            }) => Self::TextAny(MatchTextAny {
+                text_any,
+                min_should_match,
             }),
             MatchInterface::Any(any) => Self::Any(MatchAny { any: any.any }),
             MatchInterface::Except(except) => Self::Except(MatchExcept {
                 except: except.except,
             }),
-            MatchInterface::Phrase(MatchPhrase { phrase }) => Self::Phrase(MatchPhrase { phrase }),
+            MatchInterface::ExactSet(exact_set) => Self::ExactSet(MatchExactSet {
+                exact_set: exact_set.exact_set,
+            }),
+            MatchInterface::Phrase(MatchPhrase { phrase, slop }) => {
+                Self::Phrase(MatchPhrase { phrase, slop })
+            }
+            MatchInterface::KeywordPrefix(MatchKeywordPrefix { prefix }) => {
+                Self::KeywordPrefix(MatchKeywordPrefix { prefix })
+            }
         }
     }
 }
@@ -2700,11 +3065,31 @@ impl From<Vec<IntPayloadType>> for MatchExcept {
     }
 }
 
+impl From<Vec<String>> for MatchExactSet {
+    fn from(keywords: Vec<String>) -> Self {
+        let keywords: IndexSet<String, FnvBuildHasher> = keywords.into_iter().collect();
+        MatchExactSet {
+            exact_set: AnyVariants::Strings(keywords),
+        }
+    }
+}
+
+impl From<Vec<IntPayloadType>> for MatchExactSet {
+    fn from(integers: Vec<IntPayloadType>) -> Self {
+        let integers: IndexSet<_, FnvBuildHasher> = integers.into_iter().collect();
+        MatchExactSet {
+            exact_set: AnyVariants::Integers(integers),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum RangeInterface {
     Float(Range<OrderedFloat<FloatPayloadType>>),
     DateTime(Range<DateTimePayloadType>),
+    /// UUIDs are compared by their 128-bit value, so time-based UUIDv7 are ordered by time
+    Uuid(Range<UuidPayloadType>),
 }
 
 impl Hash for RangeInterface {
@@ -2724,6 +3109,13 @@ impl Hash for RangeInterface {
                 gte.hash(state);
                 lte.hash(state);
             }
+            RangeInterface::Uuid(range) => {
+                let Range { lt, gt, gte, lte } = range;
+                lt.hash(state);
+                gt.hash(state);
+                gte.hash(state);
+                lte.hash(state);
+            }
         }
     }
 }
@@ -2733,12 +3125,14 @@ impl Hash for RangeInterface {
 enum RangeInterfaceUntagged {
     Float(Range<OrderedFloatPayloadType>),
     DateTime(Range<DateTimePayloadType>),
+    Uuid(Range<UuidPayloadType>),
 }
 
 impl<'de> serde::Deserialize<'de> for RangeInterface {
     /// Parses range bounds, treating string bounds as RFC3339 datetimes for REST/JSON `datetime_range` filters.
     /// Preserves clear user-facing errors when datetime formats are invalid.
     /// Example accepted datetime bound: `2014-01-01T00:00:00Z`.
+    /// If all string bounds are UUIDs, the range is a UUID range instead.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -2747,6 +3141,7 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
             return RangeInterfaceUntagged::deserialize(deserializer).map(|parsed| match parsed {
                 RangeInterfaceUntagged::Float(r) => RangeInterface::Float(r),
                 RangeInterfaceUntagged::DateTime(r) => RangeInterface::DateTime(r),
+                RangeInterfaceUntagged::Uuid(r) => RangeInterface::Uuid(r),
             });
         }
 
@@ -2760,6 +3155,10 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
                 .any(|k| obj.get(*k).is_some_and(|v| v.is_string()));
 
             if has_string_bound {
+                if let Ok(range) = serde_json::from_value::<Range<UuidPayloadType>>(value.clone()) {
+                    return Ok(RangeInterface::Uuid(range));
+                }
+
                 return serde_json::from_value::<Range<DateTimePayloadType>>(value)
                     .map(RangeInterface::DateTime)
                     .map_err(serde::de::Error::custom);
@@ -2773,6 +3172,7 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
         Ok(match parsed {
             RangeInterfaceUntagged::Float(r) => RangeInterface::Float(r),
             RangeInterfaceUntagged::DateTime(r) => RangeInterface::DateTime(r),
+            RangeInterfaceUntagged::Uuid(r) => RangeInterface::Uuid(r),
         })
     }
 }
@@ -2781,7 +3181,11 @@ type OrderedFloatPayloadType = OrderedFloat<FloatPayloadType>;
 
 /// Range filter request
 #[macro_rules_attribute::macro_rules_derive(crate::common::macros::schemars_rename_generics)]
-#[derive_args(< OrderedFloatPayloadType > => "Range", < DateTimePayloadType > => "DatetimeRange")]
+#[derive_args(
+    < OrderedFloatPayloadType > => "Range",
+    < DateTimePayloadType > => "DatetimeRange",
+    < UuidPayloadType > => "UuidRange"
+)]
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Range<T> {
@@ -2830,11 +3234,23 @@ pub struct ValuesCount {
     pub gte: Option<usize>,
     /// point.key.length() <= values_count.lte
     pub lte: Option<usize>,
+    /// If true, only distinct values are counted, e.g. `[1, 1, 2]` has 2 values.
+    /// Counting is done over the payload, so it doesn't depend on the deduplication policy
+    /// of the index.
+    /// Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distinct: Option<bool>,
 }
 
 impl ValuesCount {
     pub fn check_count(&self, count: usize) -> bool {
-        let Self { lt, gt, gte, lte } = self;
+        let Self {
+            lt,
+            gt,
+            gte,
+            lte,
+            distinct: _,
+        } = self;
         lt.is_none_or(|x| count < x)
             && gt.is_none_or(|x| count > x)
             && lte.is_none_or(|x| count <= x)
@@ -2844,6 +3260,7 @@ impl ValuesCount {
     pub fn check_count_from(&self, value: &Value) -> bool {
         let count = match value {
             Value::Null => 0,
+            Value::Array(array) if self.distinct == Some(true) => count_distinct(array),
             Value::Array(array) => array.len(),
             _ => 1,
         };
@@ -2852,6 +3269,17 @@ impl ValuesCount {
     }
 }
 
+/// Number of distinct values in the `array`.
+///
+/// Values are not hashable, so this is quadratic, but payload arrays are normally short.
+fn count_distinct(array: &[Value]) -> usize {
+    array
+        .iter()
+        .enumerate()
+        .filter(|(i, value)| !array[..*i].contains(value))
+        .count()
+}
+
 #[cfg(test)]
 impl From<std::ops::Range<usize>> for ValuesCount {
     fn from(range: std::ops::Range<usize>) -> Self {
@@ -2860,6 +3288,7 @@ impl From<std::ops::Range<usize>> for ValuesCount {
             lt: Some(range.end),
             gt: None,
             lte: None,
+            distinct: None,
         }
     }
 }
@@ -2923,6 +3352,8 @@ impl GeoRadius {
 pub struct GeoPolygonShadow {
     pub exterior: GeoLineString,
     pub interiors: Option<Vec<GeoLineString>>,
+    #[serde(default)]
+    pub tolerance: Option<OrderedFloat<f64>>,
 }
 
 pub struct PolygonWrapper {
@@ -2950,6 +3381,12 @@ pub struct GeoPolygon {
     /// each GeoLineString must consist of a minimum of 4 points, and the first
     /// and last points must be the same.
     pub interiors: Option<Vec<GeoLineString>>,
+    /// Simplify the lines of the polygon to this tolerance in meters before matching points,
+    /// using the Douglas-Peucker algorithm. Speeds up matching for polygons with many points.
+    /// Points closer than `tolerance` to the boundary might be matched incorrectly.
+    /// Default: 0 - no simplification, exact matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<OrderedFloat<f64>>,
 }
 
 impl GeoPolygon {
@@ -2973,36 +3410,45 @@ impl GeoPolygon {
         Ok(())
     }
 
+    /// Points of the `line`, simplified to the `tolerance` of the polygon.
+    ///
+    /// Keeps the original points, if simplification would leave less than 4 points.
+    fn simplified_points<'a>(&self, line: &'a GeoLineString) -> Cow<'a, [GeoPoint]> {
+        let tolerance = self
+            .tolerance
+            .map_or(0.0, |OrderedFloat(tolerance)| tolerance);
+        if tolerance <= 0.0 {
+            return Cow::Borrowed(&line.points);
+        }
+
+        let simplified = geo_hash::simplify_line(&line.points, tolerance);
+        if simplified.len() > 3 {
+            Cow::Owned(simplified)
+        } else {
+            Cow::Borrowed(&line.points)
+        }
+    }
+
     // convert GeoPolygon to Geo crate Polygon class for checking point intersection
     pub fn convert(&self) -> PolygonWrapper {
-        let exterior_line: LineString = LineString(
-            self.exterior
-                .points
-                .iter()
-                .map(|p| Coord {
-                    x: p.lon.0,
-                    y: p.lat.0,
-                })
-                .collect(),
-        );
+        let to_line_string = |line: &GeoLineString| {
+            LineString(
+                self.simplified_points(line)
+                    .iter()
+                    .map(|p| Coord {
+                        x: p.lon.0,
+                        y: p.lat.0,
+                    })
+                    .collect(),
+            )
+        };
+
+        let exterior_line = to_line_string(&self.exterior);
 
         // Convert the interior points to coordinates (if any)
         let interior_lines: Vec<LineString> = match &self.interiors {
             None => vec![],
-            Some(interiors) => interiors
-                .iter()
-                .map(|interior_points| {
-                    interior_points
-                        .points
-                        .iter()
-                        .map(|p| Coord {
-                            x: p.lon.0,
-                            y: p.lat.0,
-                        })
-                        .collect()
-                })
-                .map(LineString)
-                .collect(),
+            Some(interiors) => interiors.iter().map(to_line_string).collect(),
         };
         PolygonWrapper {
             polygon: Polygon::new(exterior_line, interior_lines),
@@ -3017,6 +3463,7 @@ impl TryFrom<GeoPolygonShadow> for GeoPolygon {
         let GeoPolygonShadow {
             exterior,
             interiors,
+            tolerance,
         } = value;
         Self::validate_line_string(&exterior)?;
 
@@ -3026,13 +3473,77 @@ impl TryFrom<GeoPolygonShadow> for GeoPolygon {
             }
         }
 
+        if let Some(OrderedFloat(tolerance)) = tolerance
+            && tolerance < 0.0
+        {
+            return Err(OperationError::validation_error(format!(
+                "polygon invalid, the tolerance must be non-negative, got {tolerance}"
+            )));
+        }
+
         Ok(GeoPolygon {
             exterior,
             interiors,
+            tolerance,
         })
     }
 }
 
+#[derive(Deserialize)]
+pub struct GeoNearPathShadow {
+    pub path: Vec<GeoPoint>,
+    pub distance: OrderedFloat<f64>,
+}
+
+/// Geo filter request
+///
+/// Matches coordinates within `distance` meters of the polyline (route) described by `path`.
+/// Each segment of the path follows the shortest great-circle arc between its two points,
+/// so a segment between longitudes 179 and -179 crosses the antimeridian instead of going around the globe.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(try_from = "GeoNearPathShadow", rename_all = "snake_case")]
+pub struct GeoNearPath {
+    /// Ordered sequence of points describing the path, must consist of at least 2 points
+    pub path: Vec<GeoPoint>,
+    /// Maximal distance from the path in meters
+    pub distance: OrderedFloat<f64>,
+}
+
+impl GeoNearPath {
+    /// Iterate over consecutive pairs of points of the path
+    pub fn segments(&self) -> impl Iterator<Item = (&GeoPoint, &GeoPoint)> {
+        self.path.iter().tuple_windows()
+    }
+
+    pub fn check_point(&self, point: &GeoPoint) -> bool {
+        self.segments()
+            .any(|(start, end)| geo_hash::distance_to_segment(point, start, end) < self.distance.0)
+    }
+}
+
+impl TryFrom<GeoNearPathShadow> for GeoNearPath {
+    type Error = OperationError;
+
+    fn try_from(value: GeoNearPathShadow) -> OperationResult<Self> {
+        let GeoNearPathShadow { path, distance } = value;
+
+        if path.len() < 2 {
+            return Err(OperationError::validation_error(format!(
+                "path invalid, the size must be at least 2, got {}",
+                path.len()
+            )));
+        }
+
+        if !distance.is_finite() || distance.0 < 0.0 {
+            return Err(OperationError::validation_error(format!(
+                "path distance must be a non-negative number, got {distance}"
+            )));
+        }
+
+        Ok(GeoNearPath { path, distance })
+    }
+}
+
 /// All possible payload filtering conditions
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
 #[validate(schema(function = "validate_field_condition"))]
@@ -3055,6 +3566,9 @@ pub struct FieldCondition {
     /// Check if geo point is within a given polygon
     #[serde(skip_serializing_if = "Option::is_none")]
     pub geo_polygon: Option<GeoPolygon>,
+    /// Check if geo point is within a given distance of a path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo_near_path: Option<GeoNearPath>,
     /// Check number of values of the field
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values_count: Option<ValuesCount>,
@@ -3075,6 +3589,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: None,
             is_null: None,
@@ -3089,6 +3604,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: None,
             is_null: None,
@@ -3106,6 +3622,22 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+        }
+    }
+
+    pub fn new_uuid_range(key: PayloadKeyType, uuid_range: Range<UuidPayloadType>) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: Some(RangeInterface::Uuid(uuid_range)),
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: None,
             is_null: None,
@@ -3120,6 +3652,7 @@ impl FieldCondition {
             geo_bounding_box: Some(geo_bounding_box),
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: None,
             is_null: None,
@@ -3134,6 +3667,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: Some(geo_radius),
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: None,
             is_null: None,
@@ -3148,6 +3682,22 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: Some(geo_polygon),
+            geo_near_path: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+        }
+    }
+
+    pub fn new_geo_near_path(key: PayloadKeyType, geo_near_path: GeoNearPath) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: None,
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            geo_near_path: Some(geo_near_path),
             values_count: None,
             is_empty: None,
             is_null: None,
@@ -3162,6 +3712,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: Some(values_count),
             is_empty: None,
             is_null: None,
@@ -3176,6 +3727,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: Some(is_empty),
             is_null: None,
@@ -3190,6 +3742,7 @@ impl FieldCondition {
             geo_bounding_box: None,
             geo_radius: None,
             geo_polygon: None,
+            geo_near_path: None,
             values_count: None,
             is_empty: None,
             is_null: Some(is_null),
@@ -3205,6 +3758,7 @@ impl FieldCondition {
                 geo_bounding_box: None,
                 geo_radius: None,
                 geo_polygon: None,
+                geo_near_path: None,
                 values_count: None,
                 key: _,
                 is_empty: None,
@@ -3221,22 +3775,35 @@ impl FieldCondition {
         match self.r#match.as_ref().unwrap() {
             Match::Any(match_any) => match_any.any.len(),
             Match::Except(match_except) => match_except.except.len(),
+            Match::ExactSet(match_exact_set) => match_exact_set.exact_set.len(),
             Match::Value(_) => 0,
             Match::Text(_) => 0,
             Match::Phrase(_) => 0,
             Match::TextAny(_) => 0,
+            Match::KeywordPrefix(_) => 0,
         }
     }
 }
 
 pub fn validate_field_condition(field_condition: &FieldCondition) -> Result<(), ValidationError> {
     if field_condition.all_fields_none() {
-        Err(ValidationError::new(
+        return Err(ValidationError::new(
             "At least one field condition must be specified",
-        ))
-    } else {
-        Ok(())
+        ));
     }
+
+    if let Some(Match::TextAny(MatchTextAny {
+        min_should_match: Some(MinShouldMatch::Percentage(MinShouldMatchPercentage { percent })),
+        ..
+    })) = &field_condition.r#match
+        && *percent > MinShouldMatch::MAX_PERCENT
+    {
+        return Err(ValidationError::new(
+            "min_should_match percentage must be in range 0..=100",
+        ));
+    }
+
+    Ok(())
 }
 
 /// Payload field
@@ -3256,16 +3823,34 @@ pub struct IsEmptyCondition {
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 pub struct IsNullCondition {
     pub is_null: PayloadField,
+    /// How to treat arrays with null elements, like `[1, null, 3]`.
+    /// Default: `ignore` - only null values match, arrays never do.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null_elements: Option<NullElements>,
 }
 
 impl From<JsonPath> for IsNullCondition {
     fn from(key: PayloadKeyType) -> Self {
         IsNullCondition {
             is_null: PayloadField { key },
+            null_elements: None,
         }
     }
 }
 
+/// Treatment of null elements of arrays in `IsNullCondition`
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NullElements {
+    /// Only null values match, arrays don't match regardless of their elements
+    #[default]
+    Ignore,
+    /// Null values match, as well as arrays with at least one null element
+    Include,
+    /// Only arrays with at least one null element match, null values don't
+    Only,
+}
+
 impl From<JsonPath> for IsEmptyCondition {
     fn from(key: PayloadKeyType) -> Self {
         IsEmptyCondition {
@@ -3299,6 +3884,180 @@ impl From<VectorNameBuf> for HasVectorCondition {
     }
 }
 
+/// Filter points which were updated after the given version.
+///
+/// Version of a point is the number of the last operation, which has changed it. Versions are
+/// local to a shard replica: pass the `max_version` of the same replica from the cluster info
+/// to get all changes since the last request. Deleted points are not reported.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UpdatedAfterCondition {
+    pub updated_after: SeqNumberType,
+}
+
+impl UpdatedAfterCondition {
+    pub fn check(&self, point_version: Option<SeqNumberType>) -> bool {
+        point_version.is_some_and(|version| version > self.updated_after)
+    }
+}
+
+/// Filter points by the value of an arithmetic expression over numeric payload fields.
+///
+/// The expression is evaluated in double precision. A point doesn't match if any of the used
+/// fields has no numeric value, or if the result is not a finite number, e.g. on division by
+/// zero or overflow. For fields with multiple values only the first numeric one is used.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+pub struct ExpressionCondition {
+    pub expression: ArithmeticExpression,
+    /// Range the result of the expression must be in
+    pub range: Range<OrderedFloat<FloatPayloadType>>,
+}
+
+impl Hash for ExpressionCondition {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        let Self { expression, range } = self;
+        expression.hash(state);
+        let Range { lt, gt, gte, lte } = range;
+        lt.hash(state);
+        gt.hash(state);
+        gte.hash(state);
+        lte.hash(state);
+    }
+}
+
+impl ExpressionCondition {
+    /// Check the result of the expression, given the numeric value of each used field
+    pub fn check(&self, value_of: &impl Fn(&JsonPath) -> Option<FloatPayloadType>) -> bool {
+        self.expression
+            .evaluate(value_of)
+            .is_some_and(|result| self.range.check_range(OrderedFloat(result)))
+    }
+}
+
+impl Validate for ExpressionCondition {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.expression.validate()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum ArithmeticExpression {
+    Constant(OrderedFloat<FloatPayloadType>),
+    /// Payload key with numeric values
+    Key(JsonPath),
+    Sum(ArithmeticSum),
+    Mult(ArithmeticMult),
+    Neg(ArithmeticNeg),
+    Div(ArithmeticDiv),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+pub struct ArithmeticSum {
+    pub sum: Vec<ArithmeticExpression>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+pub struct ArithmeticMult {
+    pub mult: Vec<ArithmeticExpression>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+pub struct ArithmeticNeg {
+    pub neg: Box<ArithmeticExpression>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+pub struct ArithmeticDiv {
+    pub div: ArithmeticDivParams,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+pub struct ArithmeticDivParams {
+    pub left: Box<ArithmeticExpression>,
+    pub right: Box<ArithmeticExpression>,
+}
+
+impl ArithmeticExpression {
+    /// Payload keys used in the expression, without duplicates
+    pub fn keys(&self) -> Vec<&JsonPath> {
+        let mut keys = Vec::new();
+        self.collect_keys(&mut keys);
+        keys
+    }
+
+    fn collect_keys<'a>(&'a self, keys: &mut Vec<&'a JsonPath>) {
+        match self {
+            ArithmeticExpression::Constant(_) => {}
+            ArithmeticExpression::Key(key) => {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            ArithmeticExpression::Sum(ArithmeticSum { sum: operands })
+            | ArithmeticExpression::Mult(ArithmeticMult { mult: operands }) => {
+                for operand in operands {
+                    operand.collect_keys(keys);
+                }
+            }
+            ArithmeticExpression::Neg(ArithmeticNeg { neg }) => neg.collect_keys(keys),
+            ArithmeticExpression::Div(ArithmeticDiv { div }) => {
+                div.left.collect_keys(keys);
+                div.right.collect_keys(keys);
+            }
+        }
+    }
+
+    /// Evaluate the expression, given the numeric value of each used field.
+    ///
+    /// Returns `None` if any of the fields has no value, or if the result is not finite.
+    pub fn evaluate(
+        &self,
+        value_of: &impl Fn(&JsonPath) -> Option<FloatPayloadType>,
+    ) -> Option<FloatPayloadType> {
+        let result = match self {
+            ArithmeticExpression::Constant(constant) => constant.0,
+            ArithmeticExpression::Key(key) => value_of(key)?,
+            ArithmeticExpression::Sum(ArithmeticSum { sum }) => sum
+                .iter()
+                .try_fold(0.0, |acc, operand| Some(acc + operand.evaluate(value_of)?))?,
+            ArithmeticExpression::Mult(ArithmeticMult { mult }) => mult
+                .iter()
+                .try_fold(1.0, |acc, operand| Some(acc * operand.evaluate(value_of)?))?,
+            ArithmeticExpression::Neg(ArithmeticNeg { neg }) => -neg.evaluate(value_of)?,
+            ArithmeticExpression::Div(ArithmeticDiv { div }) => {
+                div.left.evaluate(value_of)? / div.right.evaluate(value_of)?
+            }
+        };
+        result.is_finite().then_some(result)
+    }
+}
+
+// The validator crate does not support deriving for enums.
+impl Validate for ArithmeticExpression {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            ArithmeticExpression::Constant(_) | ArithmeticExpression::Key(_) => Ok(()),
+            ArithmeticExpression::Sum(ArithmeticSum { sum: operands })
+            | ArithmeticExpression::Mult(ArithmeticMult { mult: operands }) => {
+                if operands.is_empty() {
+                    let mut errors = ValidationErrors::new();
+                    errors.add(
+                        "expression",
+                        ValidationError::new("sum and mult expressions must have operands"),
+                    );
+                    return Err(errors);
+                }
+                operands.iter().try_for_each(Validate::validate)
+            }
+            ArithmeticExpression::Neg(ArithmeticNeg { neg }) => neg.validate(),
+            ArithmeticExpression::Div(ArithmeticDiv { div }) => {
+                div.left.validate()?;
+                div.right.validate()
+            }
+        }
+    }
+}
+
 /// Threshold determining when to use an `Arc` in `HasIdCondition` if the condition includes many points.
 /// Since we're cloning filters quite a lot, using an Arc for larger conditions reduces risk of memory leaks
 /// and potentially improves performance in some places.
@@ -3378,6 +4137,10 @@ pub enum Condition {
     HasId(HasIdCondition),
     /// Check if point has vector assigned
     HasVector(HasVectorCondition),
+    /// Check if point was updated after a given version
+    UpdatedAfter(UpdatedAfterCondition),
+    /// Check if arithmetic expression over payload fields is in a given range
+    Expression(ExpressionCondition),
     /// Nested filters
     Nested(NestedCondition),
     /// Nested filter
@@ -3399,6 +4162,8 @@ enum ConditionUntagged {
     IsNull(IsNullCondition),
     HasId(HasIdCondition),
     HasVector(HasVectorCondition),
+    UpdatedAfter(UpdatedAfterCondition),
+    Expression(ExpressionCondition),
     Nested(NestedCondition),
     Filter(Filter),
 
@@ -3414,6 +4179,8 @@ impl From<ConditionUntagged> for Condition {
             ConditionUntagged::IsNull(condition) => Condition::IsNull(condition),
             ConditionUntagged::HasId(condition) => Condition::HasId(condition),
             ConditionUntagged::HasVector(condition) => Condition::HasVector(condition),
+            ConditionUntagged::UpdatedAfter(condition) => Condition::UpdatedAfter(condition),
+            ConditionUntagged::Expression(condition) => Condition::Expression(condition),
             ConditionUntagged::Nested(condition) => Condition::Nested(condition),
             ConditionUntagged::Filter(condition) => Condition::Filter(condition),
             ConditionUntagged::CustomIdChecker(condition) => Condition::CustomIdChecker(condition),
@@ -3503,6 +4270,8 @@ impl Condition {
             Condition::IsEmpty(_)
             | Condition::IsNull(_)
             | Condition::HasVector(_)
+            | Condition::UpdatedAfter(_)
+            | Condition::Expression(_)
             | Condition::CustomIdChecker(_) => 0,
         }
     }
@@ -3518,7 +4287,9 @@ impl Condition {
             | Condition::IsNull(_)
             | Condition::CustomIdChecker(_)
             | Condition::HasId(_)
-            | Condition::HasVector(_) => 1,
+            | Condition::HasVector(_)
+            | Condition::UpdatedAfter(_)
+            | Condition::Expression(_) => 1,
         }
     }
 
@@ -3529,7 +4300,15 @@ impl Condition {
             Condition::IsNull(is_null_condition) => Some(is_null_condition.is_null.key.clone()),
             Condition::Nested(nested_condition) => Some(nested_condition.array_key()),
             Condition::Filter(filter) => filter.iter_conditions().find_map(|c| c.targeted_key()),
-            Condition::HasId(_) | Condition::HasVector(_) | Condition::CustomIdChecker(_) => None,
+            Condition::Expression(expression) => expression
+                .expression
+                .keys()
+                .first()
+                .map(|key| (*key).clone()),
+            Condition::HasId(_)
+            | Condition::HasVector(_)
+            | Condition::UpdatedAfter(_)
+            | Condition::CustomIdChecker(_) => None,
         }
     }
 }
@@ -3541,8 +4320,10 @@ impl Validate for Condition {
             Condition::HasId(_)
             | Condition::IsEmpty(_)
             | Condition::IsNull(_)
-            | Condition::HasVector(_) => Ok(()),
+            | Condition::HasVector(_)
+            | Condition::UpdatedAfter(_) => Ok(()),
             Condition::Field(field_condition) => field_condition.validate(),
+            Condition::Expression(expression) => expression.validate(),
             Condition::Nested(nested_condition) => nested_condition.validate(),
             Condition::Filter(filter) => filter.validate(),
             Condition::CustomIdChecker(_) => Ok(()),
@@ -3689,7 +4470,9 @@ impl From<&WithPayloadInterface> for WithPayload {
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
 pub struct PayloadSelectorInclude {
-    /// Only include this payload keys
+    /// Only include this payload keys.
+    /// Nested values can be selected by JSON path, e.g. `user.address.city` or `items[0].name`.
+    /// Paths, which don't exist in the payload, are omitted.
     pub include: Vec<PayloadKeyType>,
 }
 
@@ -3750,13 +4533,9 @@ impl PayloadSelector {
     /// Process payload selector
     pub fn process(&self, x: Payload) -> Payload {
         match self {
-            PayloadSelector::Include(selector) => JsonPath::value_filter(&x.0, |key, _| {
-                selector
-                    .include
-                    .iter()
-                    .any(|pattern| pattern.check_include_pattern(key))
-            })
-            .into(),
+            PayloadSelector::Include(selector) => {
+                JsonPath::value_select(&x.0, &selector.include).into()
+            }
             PayloadSelector::Exclude(selector) => JsonPath::value_filter(&x.0, |key, _| {
                 selector
                     .exclude
@@ -4059,6 +4838,7 @@ pub(crate) mod test_utils {
         GeoPolygon {
             exterior: exterior_line,
             interiors: None,
+            tolerance: None,
         }
     }
 
@@ -4088,6 +4868,7 @@ pub(crate) mod test_utils {
         GeoPolygon {
             exterior: exterior_line,
             interiors: interior_lines,
+            tolerance: None,
         }
     }
 }
@@ -4098,7 +4879,7 @@ mod tests {
     use rstest::rstest;
     use serde_json;
 
-    use super::test_utils::build_polygon_with_interiors;
+    use super::test_utils::{build_polygon, build_polygon_with_interiors};
     use super::*;
 
     #[test]
@@ -4161,6 +4942,41 @@ mod tests {
         assert!(err.contains("Example"), "err was: {err}");
     }
 
+    #[test]
+    fn test_parse_uuid_range() {
+        let json = r#"{
+            "key": "id",
+            "range": {
+                "gt": "01900000-0000-7000-8000-000000000000",
+                "lte": "550e8400-e29b-41d4-a716-446655440000"
+            }
+        }"#;
+        let condition: FieldCondition = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            condition.range,
+            Some(RangeInterface::Uuid(Range {
+                gt: Some(Uuid::parse_str("01900000-0000-7000-8000-000000000000").unwrap()),
+                lte: Some(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()),
+                ..Default::default()
+            })),
+        );
+
+        // Binary roundtrip
+        let binary = rmp_serde::to_vec(&condition.range).unwrap();
+        let restored: Option<RangeInterface> = rmp_serde::from_slice(&binary).unwrap();
+        assert_eq!(restored, condition.range);
+
+        // Mixed bounds are parsed as datetimes
+        let json = r#"{
+            "key": "id",
+            "range": {
+                "gt": "01900000-0000-7000-8000-000000000000",
+                "lte": "2014-01-01T00:00:00Z"
+            }
+        }"#;
+        assert!(serde_json::from_str::<FieldCondition>(json).is_err());
+    }
+
     /// Regression test: DateTimePayloadType binary serialization roundtrip.
     /// Ensures DateTimePayloadType parses binary-encoded RFC3339 strings.
     #[test]
@@ -4371,6 +5187,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_geo_polygon_tolerance() {
+        // Square with a notch of about 11 meters in the bottom line
+        let mut polygon = build_polygon(vec![
+            (-1.0, -1.0),
+            (0.0, -0.9999),
+            (1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (-1.0, -1.0),
+        ]);
+        let in_notch = GeoPoint::new_unchecked(0.0, -0.99995);
+        let inside = GeoPoint::new_unchecked(0.5, 0.5);
+
+        assert!(!polygon.convert().check_point(&in_notch));
+
+        polygon.tolerance = Some(OrderedFloat(0.0));
+        assert!(!polygon.convert().check_point(&in_notch));
+
+        polygon.tolerance = Some(OrderedFloat(100.0));
+        assert_eq!(polygon.convert().polygon.exterior().0.len(), 5);
+        assert!(polygon.convert().check_point(&in_notch));
+        assert!(polygon.convert().check_point(&inside));
+
+        // Lines are not simplified to less than 4 points
+        polygon.tolerance = Some(OrderedFloat(1e9));
+        assert_eq!(polygon.convert().polygon.exterior().0.len(), 6);
+        assert!(polygon.convert().check_point(&inside));
+
+        let json = r#"{
+            "exterior": {"points": [
+                {"lon": 0, "lat": 0}, {"lon": 1, "lat": 0},
+                {"lon": 1, "lat": 1}, {"lon": 0, "lat": 0}
+            ]},
+            "tolerance": -1
+        }"#;
+        assert!(serde_json::from_str::<GeoPolygon>(json).is_err());
+    }
+
     #[test]
     fn test_serialize_query() {
         let filter = Filter {
@@ -4442,6 +5297,16 @@ mod tests {
                 value: ValueVariants::String("world".to_owned())
             })
         );
+
+        let query = r#"
+        {
+            "key": "hello",
+            "match": { "prefix": "wor" }
+        }
+        "#;
+
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        assert_eq!(condition.r#match.unwrap(), Match::new_keyword_prefix("wor"));
     }
 
     #[test]
@@ -20,6 +20,7 @@ use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{VectorElementType, VectorRef};
 use crate::types::{Distance, VectorStorageDatatype};
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
+use crate::vector_storage::dense::vector_norms::VectorNorms;
 use crate::vector_storage::{
     DenseVectorStorage, VectorOffsetType, VectorStorage, VectorStorageEnum, VectorStorageRead,
 };
@@ -37,6 +38,8 @@ pub struct AppendableMmapDenseVectorStorage<T: PrimitiveVectorElement> {
     deleted: BitvecFlags<MmapFile>,
     distance: Distance,
     deleted_count: usize,
+    /// Cached norms of the vectors, only if the distance needs them and vectors are in RAM
+    norms: Option<VectorNorms>,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -91,6 +94,10 @@ impl<T: PrimitiveVectorElement> DenseVectorStorage<T> for AppendableMmapDenseVec
     fn for_each_in_dense_batch<F: FnMut(usize, &[T])>(&self, keys: &[PointOffsetType], f: F) {
         self.vectors.for_each_in_batch(keys, f);
     }
+
+    fn vector_norms(&self) -> Option<&VectorNorms> {
+        self.norms.as_ref()
+    }
 }
 
 impl<T: PrimitiveVectorElement> VectorStorageRead for AppendableMmapDenseVectorStorage<T> {
@@ -147,6 +154,9 @@ impl<T: PrimitiveVectorElement> VectorStorage for AppendableMmapDenseVectorStora
         let vector = T::slice_from_float_cow(Cow::from(vector));
         self.vectors
             .insert(key as VectorOffsetType, vector.as_ref(), hw_counter)?;
+        if let Some(norms) = &mut self.norms {
+            norms.set(key, vector.as_ref());
+        }
         self.set_deleted(key, false);
         Ok(())
     }
@@ -163,6 +173,9 @@ impl<T: PrimitiveVectorElement> VectorStorage for AppendableMmapDenseVectorStora
             // Do not perform preprocessing - vectors should be already processed
             let other_vector = T::slice_from_float_cow(Cow::try_from(other_vector)?);
             let new_id = self.vectors.push(other_vector.as_ref(), &disposed_hw)?;
+            if let Some(norms) = &mut self.norms {
+                norms.set(new_id as PointOffsetType, other_vector.as_ref());
+            }
             self.set_deleted(new_id as PointOffsetType, other_deleted);
         }
         let end_index = self.vectors.len() as PointOffsetType;
@@ -257,13 +270,20 @@ pub fn open_appendable_memmap_vector_storage_impl<T: PrimitiveVectorElement>(
     let deleted = BitvecFlags::new(DynamicStoredFlags::open(&deleted_path, populate)?)?;
     let deleted_count = deleted.count_trues();
 
-    Ok(AppendableMmapDenseVectorStorage {
+    let mut storage = AppendableMmapDenseVectorStorage {
         vectors,
         deleted,
         distance,
         deleted_count,
+        norms: None,
         _phantom: Default::default(),
-    })
+    };
+
+    if populate && VectorNorms::is_required::<T>(distance) {
+        storage.norms = Some(VectorNorms::from_storage(&storage));
+    }
+
+    Ok(storage)
 }
 
 /// Find files related to this dense vector storage
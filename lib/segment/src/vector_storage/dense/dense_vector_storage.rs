@@ -22,6 +22,7 @@ use crate::types::{Distance, VectorStorageDatatype};
 #[cfg(target_os = "linux")]
 use crate::vector_storage::common::get_async_scorer;
 use crate::vector_storage::dense::immutable_dense_vectors::ImmutableDenseVectors;
+use crate::vector_storage::dense::vector_norms::VectorNorms;
 use crate::vector_storage::{
     DenseVectorStorage, VectorStorage, VectorStorageEnum, VectorStorageRead,
 };
@@ -46,6 +47,8 @@ where
     vectors: Option<ImmutableDenseVectors<T, S>>,
     distance: Distance,
     populated: bool,
+    /// Cached norms of the vectors, only if the distance needs them and vectors are in RAM
+    norms: Option<VectorNorms>,
 }
 
 impl<T, S> DenseVectorStorageImpl<T, S>
@@ -69,6 +72,7 @@ where
             vectors,
             distance: _,
             populated: _,
+            norms: _,
         } = self;
         if let Some(vectors) = vectors {
             vectors.clear_cache()?;
@@ -178,14 +182,19 @@ where
     let deleted_path = path.join(DELETED_PATH);
 
     let vectors = ImmutableDenseVectors::open(&vectors_path, &deleted_path, dim, populate)?;
-    let storage = DenseVectorStorageImpl {
+    let mut storage = DenseVectorStorageImpl {
         vectors_path,
         deleted_path,
         vectors: Some(vectors),
         distance,
         populated: populate,
+        norms: None,
     };
 
+    if populate && VectorNorms::is_required::<T>(distance) {
+        storage.norms = Some(VectorNorms::from_storage(&storage));
+    }
+
     Ok(storage)
 }
 
@@ -210,6 +219,10 @@ where
         let mmap_store = self.vectors.as_ref().unwrap();
         mmap_store.for_each_in_batch(keys, f);
     }
+
+    fn vector_norms(&self) -> Option<&VectorNorms> {
+        self.norms.as_ref()
+    }
 }
 
 impl<T, S> VectorStorageRead for DenseVectorStorageImpl<T, S>
@@ -314,6 +327,9 @@ where
             #[expect(deprecated, reason = "legacy code")]
             let raw_bites = unsafe { mmap::transmute_to_u8_slice(vector.as_ref()) };
             vectors_file.write_all(raw_bites)?;
+            if let Some(norms) = &mut self.norms {
+                norms.set(end_index, vector.as_ref());
+            }
             end_index += 1;
 
             // Remember deleted IDs so we can propagate deletions later
@@ -834,6 +850,7 @@ mod tests {
             r#type: Default::default(),
             quantile: None,
             always_ram: None,
+            outliers: None,
         }
         .into();
 
@@ -3,4 +3,5 @@ pub mod dense_vector_storage;
 pub mod empty_dense_vector_storage;
 pub mod immutable_dense_vectors;
 pub mod read_only;
+pub mod vector_norms;
 pub mod volatile_dense_vector_storage;
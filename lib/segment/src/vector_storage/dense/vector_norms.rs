@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use common::generic_consts::Sequential;
+use common::types::PointOffsetType;
+
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::types::{Distance, VectorStorageDatatype};
+use crate::vector_storage::DenseVectorStorage;
+
+/// Cached norms of stored vectors, so cosine similarity doesn't recompute them on every score.
+///
+/// Float vectors are normalized on upsert, and their cosine similarity is scored as a plain dot
+/// product, so they never need norms. Byte vectors can't be normalized without losing precision,
+/// so cosine similarity of byte vectors computes the norms of both vectors on every score. With
+/// cached norms it is a single dot product, see [`Metric::similarity_with_norms`], for the cost of
+/// 4 bytes per vector.
+///
+/// Norms are kept in memory only. They are computed from the vectors on load, and updated on
+/// every insert, so they are only cached for storages, which keep their vectors in RAM anyway.
+///
+/// Quantized vectors are normalized before they are quantized, so quantized scoring doesn't use
+/// these norms. Rescoring of the quantized results with the original vectors does.
+///
+/// [`Metric::similarity_with_norms`]: crate::spaces::metric::Metric::similarity_with_norms
+#[derive(Debug, Default)]
+pub struct VectorNorms {
+    norms: Vec<f32>,
+}
+
+impl VectorNorms {
+    /// Whether scoring vectors of type `T` with `distance` benefits from cached norms
+    pub fn is_required<T: PrimitiveVectorElement>(distance: Distance) -> bool {
+        distance == Distance::Cosine && T::datatype() == VectorStorageDatatype::Uint8
+    }
+
+    /// Compute norms of all vectors in the storage
+    pub fn from_storage<T, S>(storage: &S) -> Self
+    where
+        T: PrimitiveVectorElement,
+        S: DenseVectorStorage<T>,
+    {
+        let norms = (0..storage.total_vector_count() as PointOffsetType)
+            .map(|key| Self::norm(&storage.get_dense::<Sequential>(key)))
+            .collect();
+        Self { norms }
+    }
+
+    /// Euclidean norm of the vector
+    pub fn norm<T: PrimitiveVectorElement>(vector: &[T]) -> f32 {
+        let squared_sum: f64 = T::slice_to_float_cow(Cow::Borrowed(vector))
+            .iter()
+            .map(|&x| f64::from(x) * f64::from(x))
+            .sum();
+        squared_sum.sqrt() as f32
+    }
+
+    /// Cached norm of the vector, zero for unknown vectors
+    pub fn get(&self, key: PointOffsetType) -> f32 {
+        self.norms.get(key as usize).copied().unwrap_or_default()
+    }
+
+    /// Update the cached norm of the vector, after it was inserted or replaced
+    pub fn set<T: PrimitiveVectorElement>(&mut self, key: PointOffsetType, vector: &[T]) {
+        let key = key as usize;
+        if self.norms.len() <= key {
+            self.norms.resize(key + 1, 0.0);
+        }
+        self.norms[key] = Self::norm(vector);
+    }
+}
@@ -13,6 +13,7 @@ use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{VectorElementType, VectorRef};
 use crate::types::{Distance, VectorStorageDatatype};
+use crate::vector_storage::dense::vector_norms::VectorNorms;
 use crate::vector_storage::volatile_chunked_vectors::VolatileChunkedVectors;
 use crate::vector_storage::{
     DenseVectorStorage, VectorOffsetType, VectorStorage, VectorStorageEnum, VectorStorageRead,
@@ -30,6 +31,8 @@ pub struct VolatileDenseVectorStorage<T: PrimitiveVectorElement> {
     deleted: BitVec,
     /// Current number of deleted vectors.
     deleted_count: usize,
+    /// Cached norms of the vectors, only if the distance needs them
+    norms: Option<VectorNorms>,
 }
 
 pub fn new_volatile_dense_vector_storage(dim: usize, distance: Distance) -> VectorStorageEnum {
@@ -54,6 +57,7 @@ impl<T: PrimitiveVectorElement> VolatileDenseVectorStorage<T> {
             vectors: VolatileChunkedVectors::new(dim),
             deleted: BitVec::new(),
             deleted_count: 0,
+            norms: VectorNorms::is_required::<T>(distance).then(VectorNorms::default),
         }
     }
 
@@ -83,6 +87,10 @@ impl<T: PrimitiveVectorElement> DenseVectorStorage<T> for VolatileDenseVectorSto
     fn get_dense<P: AccessPattern>(&self, key: PointOffsetType) -> Cow<'_, [T]> {
         Cow::Borrowed(self.vectors.get(key as VectorOffsetType))
     }
+
+    fn vector_norms(&self) -> Option<&VectorNorms> {
+        self.norms.as_ref()
+    }
 }
 
 impl<T: PrimitiveVectorElement> VectorStorageRead for VolatileDenseVectorStorage<T> {
@@ -138,6 +146,9 @@ impl<T: PrimitiveVectorElement> VectorStorage for VolatileDenseVectorStorage<T>
         let vector = T::slice_from_float_cow(Cow::from(vector));
         self.vectors
             .insert(key as VectorOffsetType, vector.as_ref())?;
+        if let Some(norms) = &mut self.norms {
+            norms.set(key, vector.as_ref());
+        }
         self.set_deleted(key, false);
         Ok(())
     }
@@ -153,6 +164,9 @@ impl<T: PrimitiveVectorElement> VectorStorage for VolatileDenseVectorStorage<T>
             // Do not perform preprocessing - vectors should be already processed
             let other_vector = T::slice_from_float_cow(Cow::try_from(other_vector)?);
             let new_id = self.vectors.push(other_vector.as_ref())? as PointOffsetType;
+            if let Some(norms) = &mut self.norms {
+                norms.set(new_id, other_vector.as_ref());
+            }
             self.set_deleted(new_id, other_deleted);
         }
         let end_index = self.vectors.len() as PointOffsetType;
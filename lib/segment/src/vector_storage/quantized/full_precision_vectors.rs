@@ -0,0 +1,222 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::path::Path;
+
+use common::fs::{atomic_save_bin, read_bin};
+use common::types::PointOffsetType;
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+use crate::data_types::vectors::VectorElementType;
+
+/// Minimal error of clamping the values of a vector, relative to its norm, to keep it in full
+/// precision.
+///
+/// About twice the relative error of rounding the values to 8 bits, so vectors with just a few
+/// values slightly out of the range are not worth keeping.
+pub const FULL_PRECISION_MIN_CLAMP_ERROR: f32 = 0.02;
+
+/// Maximal fraction of the vectors kept in full precision.
+///
+/// Bounds the memory, as full precision vectors are kept in RAM. With int8 quantization, they
+/// take at most 4% of the size of the quantized vectors.
+pub const FULL_PRECISION_MAX_FRACTION: f64 = 0.01;
+
+/// Vectors kept in full precision next to the quantized ones, because some of their values are
+/// outside of the range covered by scalar quantization and would be clamped.
+///
+/// Vectors are stored preprocessed for quantization, so they are scored the same way as the
+/// quantized ones, just without the loss of precision.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FullPrecisionVectors {
+    dim: usize,
+    /// Sorted ids of the vectors
+    ids: Vec<PointOffsetType>,
+    /// Vectors in the order of `ids`, flattened
+    vectors: Vec<VectorElementType>,
+}
+
+impl FullPrecisionVectors {
+    /// Collect the vectors, which are distorted the most by clamping their values into
+    /// `min..=max`.
+    ///
+    /// Only vectors with relative clamping error above [`FULL_PRECISION_MIN_CLAMP_ERROR`] are
+    /// collected, at most `max_count` of them with the largest errors.
+    pub fn from_outliers<I>(
+        vectors: I,
+        dim: usize,
+        (min, max): (f32, f32),
+        max_count: usize,
+    ) -> Self
+    where
+        I: Iterator + Clone,
+        I::Item: AsRef<[VectorElementType]>,
+    {
+        // Min-heap of the largest errors, so only `max_count` candidates are kept in memory
+        let mut outliers = BinaryHeap::with_capacity(max_count.saturating_add(1));
+        if max_count > 0 {
+            for (id, vector) in vectors.clone().enumerate() {
+                let error = relative_clamp_error(vector.as_ref(), min, max);
+                if error <= FULL_PRECISION_MIN_CLAMP_ERROR {
+                    continue;
+                }
+                outliers.push(Reverse((OrderedFloat(error), id as PointOffsetType)));
+                if outliers.len() > max_count {
+                    outliers.pop();
+                }
+            }
+        }
+
+        let mut ids: Vec<_> = outliers.into_iter().map(|Reverse((_, id))| id).collect();
+        ids.sort_unstable();
+
+        let mut full_precision_vectors = Vec::with_capacity(ids.len() * dim);
+        let mut next_ids = ids.iter().peekable();
+        for (id, vector) in vectors.enumerate() {
+            let Some(&&next_id) = next_ids.peek() else {
+                break;
+            };
+            if id as PointOffsetType == next_id {
+                full_precision_vectors.extend_from_slice(vector.as_ref());
+                next_ids.next();
+            }
+        }
+
+        Self {
+            dim,
+            ids,
+            vectors: full_precision_vectors,
+        }
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_bin(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_bin(path, self)?)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn get(&self, id: PointOffsetType) -> Option<&[VectorElementType]> {
+        let position = self.ids.binary_search(&id).ok()?;
+        let start = position * self.dim;
+        Some(&self.vectors[start..start + self.dim])
+    }
+
+    pub fn heap_size_bytes(&self) -> usize {
+        self.ids.capacity() * size_of::<PointOffsetType>()
+            + self.vectors.capacity() * size_of::<VectorElementType>()
+    }
+}
+
+/// Norm of the change of the vector by clamping its values into `min..=max`, relative to the norm
+/// of the vector
+fn relative_clamp_error(vector: &[VectorElementType], min: f32, max: f32) -> f32 {
+    let (error, norm) = vector
+        .iter()
+        .fold((0.0f32, 0.0f32), |(error, norm), &value| {
+            let clamp_error = value - value.clamp(min, max);
+            (error + clamp_error * clamp_error, norm + value * value)
+        });
+    if norm == 0.0 {
+        return 0.0;
+    }
+    (error / norm).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_collect_outliers() {
+        let vectors = [
+            vec![0.0, 0.5, 1.0],
+            vec![0.0, 1.5, 1.0],
+            vec![0.1, 0.2, 0.3],
+            vec![-0.5, 0.0, 0.0],
+            vec![1.0, 1.0, 1.01],
+        ];
+
+        let full_precision =
+            FullPrecisionVectors::from_outliers(vectors.iter(), 3, (0.0, 1.0), vectors.len());
+
+        // The last vector is out of the range only negligibly
+        assert_eq!(full_precision.len(), 2);
+        assert_eq!(full_precision.get(0), None);
+        assert_eq!(full_precision.get(1), Some(vectors[1].as_slice()));
+        assert_eq!(full_precision.get(2), None);
+        assert_eq!(full_precision.get(3), Some(vectors[3].as_slice()));
+        assert_eq!(full_precision.get(4), None);
+    }
+
+    #[test]
+    fn test_collect_outliers_realistic_dim() {
+        const DIM: usize = 768;
+        const COUNT: usize = 2000;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut vectors: Vec<Vec<f32>> = (0..COUNT)
+            .map(|_| (0..DIM).map(|_| rng.random_range(-1.0..1.0)).collect())
+            .collect();
+
+        // Every 50th vector has a single extreme value, growing with its id
+        let outlier_ids = (0..COUNT).step_by(50).collect::<Vec<_>>();
+        for (i, &id) in outlier_ids.iter().enumerate() {
+            vectors[id][i % DIM] = 100.0 + i as f32;
+        }
+
+        // Nearly every vector has some value out of such a range, clamping only those slightly
+        let value_range = (-0.99, 0.99);
+        assert!(
+            vectors
+                .iter()
+                .filter(|vector| vector.iter().any(|value| value.abs() > 0.99))
+                .count()
+                > COUNT / 2
+        );
+
+        let full_precision =
+            FullPrecisionVectors::from_outliers(vectors.iter(), DIM, value_range, COUNT);
+        assert_eq!(full_precision.len(), outlier_ids.len());
+        for &id in &outlier_ids {
+            assert_eq!(
+                full_precision.get(id as PointOffsetType),
+                Some(vectors[id].as_slice()),
+            );
+        }
+
+        // Only the vectors with the largest errors are kept over the limit
+        let max_count = 10;
+        let full_precision =
+            FullPrecisionVectors::from_outliers(vectors.iter(), DIM, value_range, max_count);
+        assert_eq!(full_precision.len(), max_count);
+        assert_eq!(full_precision.vectors.len(), max_count * DIM);
+
+        let mut largest_errors = outlier_ids.clone();
+        largest_errors.sort_by_key(|&id| {
+            Reverse(OrderedFloat(relative_clamp_error(
+                &vectors[id],
+                value_range.0,
+                value_range.1,
+            )))
+        });
+        largest_errors.truncate(max_count);
+        for &id in &outlier_ids {
+            let is_kept = full_precision.get(id as PointOffsetType).is_some();
+            assert_eq!(is_kept, largest_errors.contains(&id));
+        }
+    }
+}
@@ -1,7 +1,12 @@
+mod full_precision_vectors;
 mod quantized_chunked_mmap_storage;
 mod quantized_custom_query_scorer;
+mod quantized_full_precision_query_scorer;
 mod quantized_mmap_storage;
 mod quantized_multi_custom_query_scorer;
+#[cfg(feature = "testing")]
+pub mod quantized_multi_query_scorer;
+#[cfg(not(feature = "testing"))]
 mod quantized_multi_query_scorer;
 pub mod quantized_multivector_storage;
 pub mod quantized_query_scorer;
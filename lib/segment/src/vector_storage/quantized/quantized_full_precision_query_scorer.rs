@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::types::{PointOffsetType, ScoreType};
+
+use super::full_precision_vectors::FullPrecisionVectors;
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{DenseVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::types::QuantizationConfig;
+use crate::vector_storage::query_scorer::QueryScorer;
+
+/// Scores the vectors kept in full precision exactly, and all others with the quantized scorer
+pub struct QuantizedFullPrecisionQueryScorer<'a, TQueryScorer, TMetric> {
+    quantized_scorer: TQueryScorer,
+    full_precision: &'a FullPrecisionVectors,
+    /// Query, preprocessed the same way as the vectors kept in full precision
+    query: Vec<VectorElementType>,
+    metric: PhantomData<TMetric>,
+}
+
+impl<'a, TQueryScorer, TMetric> QuantizedFullPrecisionQueryScorer<'a, TQueryScorer, TMetric>
+where
+    TQueryScorer: QueryScorer<TVector = [VectorElementType]>,
+    TMetric: Metric<VectorElementType>,
+{
+    pub fn new<TElement>(
+        raw_query: DenseVector,
+        quantized_scorer: TQueryScorer,
+        full_precision: &'a FullPrecisionVectors,
+        quantization_config: &QuantizationConfig,
+    ) -> Self
+    where
+        TElement: PrimitiveVectorElement,
+        TMetric: Metric<TElement>,
+    {
+        let raw_preprocessed_query = <TMetric as Metric<TElement>>::preprocess(raw_query);
+        let original_query = TElement::slice_from_float_cow(Cow::Owned(raw_preprocessed_query));
+        let query = TElement::quantization_preprocess(
+            quantization_config,
+            <TMetric as Metric<TElement>>::distance(),
+            original_query.as_ref(),
+        )
+        .into_owned();
+
+        Self {
+            quantized_scorer,
+            full_precision,
+            query,
+            metric: PhantomData,
+        }
+    }
+}
+
+impl<TQueryScorer, TMetric> QueryScorer
+    for QuantizedFullPrecisionQueryScorer<'_, TQueryScorer, TMetric>
+where
+    TQueryScorer: QueryScorer<TVector = [VectorElementType]>,
+    TMetric: Metric<VectorElementType>,
+{
+    type TVector = [VectorElementType];
+
+    fn score_stored_batch(&self, ids: &[PointOffsetType], scores: &mut [ScoreType]) {
+        self.quantized_scorer.score_stored_batch(ids, scores);
+
+        for (id, score) in ids.iter().zip(scores.iter_mut()) {
+            if let Some(vector) = self.full_precision.get(*id) {
+                *score = TMetric::similarity(&self.query, vector);
+            }
+        }
+    }
+
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        match self.full_precision.get(idx) {
+            Some(vector) => TMetric::similarity(&self.query, vector),
+            None => self.quantized_scorer.score_stored(idx),
+        }
+    }
+
+    fn score(&self, v2: &[VectorElementType]) -> ScoreType {
+        self.quantized_scorer.score(v2)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        match (
+            self.full_precision.get(point_a),
+            self.full_precision.get(point_b),
+        ) {
+            (Some(vector_a), Some(vector_b)) => TMetric::similarity(vector_a, vector_b),
+            _ => self.quantized_scorer.score_internal(point_a, point_b),
+        }
+    }
+
+    type SupportsBytes = TQueryScorer::SupportsBytes;
+    fn score_bytes(&self, enabled: Self::SupportsBytes, bytes: &[u8]) -> ScoreType {
+        // Bytes of the quantized vector, without the id to look up the full precision one
+        self.quantized_scorer.score_bytes(enabled, bytes)
+    }
+}
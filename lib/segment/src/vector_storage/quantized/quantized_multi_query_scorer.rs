@@ -9,6 +9,10 @@ use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::MultiDenseVectorInternal;
 use crate::spaces::metric::Metric;
 use crate::types::QuantizationConfig;
+#[cfg(feature = "testing")]
+use crate::vector_storage::quantized::quantized_multivector_storage::{
+    MaxSimAlignment, MultivectorMaxSimAlignment,
+};
 use crate::vector_storage::quantized::quantized_multivector_storage::{
     MultivectorOffset, MultivectorOffsets,
 };
@@ -81,12 +85,63 @@ where
     }
 }
 
+#[cfg(feature = "testing")]
+impl<TEncodedVectors> QuantizedMultiQueryScorer<'_, TEncodedVectors>
+where
+    TEncodedVectors: MultivectorMaxSimAlignment + MultivectorOffsets,
+{
+    /// Best matching stored sub-vector of `idx` for each sub-vector of the query.
+    ///
+    /// Scores of the alignment sum up to the score of [`QueryScorer::score_stored`].
+    pub fn max_sim_alignment(&self, idx: PointOffsetType) -> Vec<MaxSimAlignment> {
+        let multi_vector_offset = self.quantized_multivector_storage.get_offset(idx);
+        let sub_vectors_count = multi_vector_offset.count as usize;
+        self.hardware_counter.vector_io_read().incr_delta(
+            size_of::<MultivectorOffset>()
+                + self.quantized_multivector_storage.quantized_vector_size() * sub_vectors_count,
+        );
+        self.quantized_multivector_storage.max_sim_alignment(
+            &self.query,
+            idx,
+            &self.hardware_counter,
+        )
+    }
+}
+
 impl<TEncodedVectors> QueryScorer for QuantizedMultiQueryScorer<'_, TEncodedVectors>
 where
     TEncodedVectors: quantization::EncodedVectors + MultivectorOffsets,
 {
     type TVector = ();
 
+    fn score_stored_batch(&self, ids: &[PointOffsetType], scores: &mut [ScoreType]) {
+        debug_assert_eq!(ids.len(), scores.len());
+
+        let storage = self.quantized_multivector_storage;
+
+        // Fetch all offsets first, to score points in the order of their inner vectors
+        let mut offsets: Vec<_> = ids
+            .iter()
+            .enumerate()
+            .map(|(idx, &id)| (idx, storage.get_offset(id)))
+            .collect();
+        offsets.sort_unstable_by_key(|(_, offset)| offset.start);
+
+        let sub_vectors_count: usize = offsets
+            .iter()
+            .map(|(_, offset)| offset.count as usize)
+            .sum();
+        self.hardware_counter.vector_io_read().incr_delta(
+            size_of::<MultivectorOffset>() * ids.len()
+                + storage.quantized_vector_size() * sub_vectors_count,
+        );
+
+        for (idx, offset) in offsets {
+            scores[idx] =
+                storage.score_point_at_offset(&self.query, offset, &self.hardware_counter);
+        }
+    }
+
     fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
         let multi_vector_offset = self.quantized_multivector_storage.get_offset(idx);
         let sub_vectors_count = multi_vector_offset.count as usize;
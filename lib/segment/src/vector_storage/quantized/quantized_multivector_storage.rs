@@ -35,8 +35,36 @@ pub struct MultivectorOffset {
     pub count: PointOffsetType,
 }
 
-pub trait MultivectorOffsets {
+pub trait MultivectorOffsets: EncodedVectors {
     fn get_offset(&self, idx: PointOffsetType) -> MultivectorOffset;
+
+    /// Same as [`EncodedVectors::score_point`], for a point of which the offset is already known
+    fn score_point_at_offset(
+        &self,
+        query: &Self::EncodedQuery,
+        offset: MultivectorOffset,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType;
+}
+
+/// Best matching stored sub-vector for a single sub-vector of the query
+#[cfg(feature = "testing")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MaxSimAlignment {
+    /// Index of the sub-vector within the stored multivector
+    pub vector_index: PointOffsetType,
+    pub score: ScoreType,
+}
+
+#[cfg(feature = "testing")]
+pub trait MultivectorMaxSimAlignment: EncodedVectors {
+    /// Per query sub-vector maxima, which are summed up by `score_point`
+    fn max_sim_alignment(
+        &self,
+        query: &Self::EncodedQuery,
+        idx: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> Vec<MaxSimAlignment>;
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -345,11 +373,10 @@ where
     /// Custom `score_max_similarity` implementation for quantized vectors
     fn score_point_max_similarity(
         &self,
-        query: &Vec<QuantizedStorage::EncodedQuery>,
-        vector_index: PointOffsetType,
+        query: &[QuantizedStorage::EncodedQuery],
+        offset: MultivectorOffset,
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
-        let offset = self.offsets.get_offset(vector_index);
         let mut sum = 0.0;
         for inner_query in query {
             let mut max_sim = ScoreType::NEG_INFINITY;
@@ -397,6 +424,40 @@ where
         sum
     }
 
+    /// Same as `score_point_max_similarity`, but keeps the argmax of each query sub-vector
+    #[cfg(feature = "testing")]
+    fn point_max_similarity_alignment(
+        &self,
+        query: &[QuantizedStorage::EncodedQuery],
+        vector_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> Vec<MaxSimAlignment> {
+        let offset = self.offsets.get_offset(vector_index);
+        query
+            .iter()
+            .map(|inner_query| {
+                let mut best = MaxSimAlignment {
+                    vector_index: 0,
+                    score: ScoreType::NEG_INFINITY,
+                };
+                for i in 0..offset.count {
+                    let sim = self.quantized_storage.score_point(
+                        inner_query,
+                        offset.start + i,
+                        hw_counter,
+                    );
+                    if sim > best.score {
+                        best = MaxSimAlignment {
+                            vector_index: i,
+                            score: sim,
+                        };
+                    }
+                }
+                best
+            })
+            .collect()
+    }
+
     pub fn inner_storage(&self) -> &QuantizedStorage {
         &self.quantized_storage
     }
@@ -451,9 +512,7 @@ where
         i: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
-        match self.multi_vector_config.comparator {
-            MultiVectorComparator::MaxSim => self.score_point_max_similarity(query, i, hw_counter),
-        }
+        self.score_point_at_offset(query, self.offsets.get_offset(i), hw_counter)
     }
 
     fn score_internal(
@@ -587,6 +646,40 @@ where
     fn get_offset(&self, idx: PointOffsetType) -> MultivectorOffset {
         self.offsets.get_offset(idx)
     }
+
+    fn score_point_at_offset(
+        &self,
+        query: &Vec<QuantizedStorage::EncodedQuery>,
+        offset: MultivectorOffset,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim => {
+                self.score_point_max_similarity(query, offset, hw_counter)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<QuantizedStorage, TMultivectorOffsetsStorage> MultivectorMaxSimAlignment
+    for QuantizedMultivectorStorage<QuantizedStorage, TMultivectorOffsetsStorage>
+where
+    QuantizedStorage: EncodedVectors,
+    TMultivectorOffsetsStorage: MultivectorOffsetsStorage,
+{
+    fn max_sim_alignment(
+        &self,
+        query: &Vec<QuantizedStorage::EncodedQuery>,
+        idx: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> Vec<MaxSimAlignment> {
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim => {
+                self.point_max_similarity_alignment(query, idx, hw_counter)
+            }
+        }
+    }
 }
 
 fn create_offsets_file_from_iter(
@@ -1,7 +1,9 @@
 use common::counter::hardware_counter::HardwareCounterCell;
 use quantization::EncodedVectors;
 
+use super::full_precision_vectors::FullPrecisionVectors;
 use super::quantized_custom_query_scorer::QuantizedCustomQueryScorer;
+use super::quantized_full_precision_query_scorer::QuantizedFullPrecisionQueryScorer;
 use super::quantized_query_scorer::QuantizedQueryScorer;
 use super::quantized_vectors::QuantizedVectorStorage;
 use crate::common::operation_error::OperationResult;
@@ -25,6 +27,7 @@ use crate::vector_storage::{RawScorer, raw_scorer_from_query_scorer};
 pub(super) struct QuantizedScorerBuilder<'a> {
     quantized_storage: &'a QuantizedVectorStorage,
     quantization_config: &'a QuantizationConfig,
+    full_precision: Option<&'a FullPrecisionVectors>,
     query: QueryVector,
     distance: &'a Distance,
     datatype: VectorStorageDatatype,
@@ -36,6 +39,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
     pub fn new(
         quantized_storage: &'a QuantizedVectorStorage,
         quantization_config: &'a QuantizationConfig,
+        full_precision: Option<&'a FullPrecisionVectors>,
         query: QueryVector,
         distance: &'a Distance,
         datatype: VectorStorageDatatype,
@@ -46,6 +50,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
         Self {
             quantized_storage,
             quantization_config,
+            full_precision,
             query,
             distance,
             datatype,
@@ -89,7 +94,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
     pub fn build_with_metric<TElement, TMetric>(self) -> OperationResult<Box<dyn RawScorer + 'a>>
     where
         TElement: PrimitiveVectorElement,
-        TMetric: Metric<TElement> + 'a,
+        TMetric: Metric<TElement> + Metric<VectorElementType> + 'a,
     {
         match self.quantized_storage {
             QuantizedVectorStorage::ScalarRam(storage) => {
@@ -173,11 +178,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
     ) -> OperationResult<Box<dyn RawScorer + 'a>>
     where
         TElement: PrimitiveVectorElement,
-        TMetric: Metric<TElement> + 'a,
+        TMetric: Metric<TElement> + Metric<VectorElementType> + 'a,
     {
         let Self {
             quantized_storage: _same_as_quantized_storage_in_args,
             quantization_config,
+            full_precision,
             query,
             distance: _,
             datatype: _,
@@ -186,12 +192,30 @@ impl<'a> QuantizedScorerBuilder<'a> {
 
         match query {
             QueryVector::Nearest(vector) => {
-                let query_scorer = QuantizedQueryScorer::<_>::new::<TElement, TMetric>(
-                    DenseVector::try_from(vector)?,
+                let vector = DenseVector::try_from(vector)?;
+                let Some(full_precision) = full_precision.filter(|vectors| !vectors.is_empty())
+                else {
+                    let query_scorer = QuantizedQueryScorer::<_>::new::<TElement, TMetric>(
+                        vector,
+                        quantized_storage,
+                        quantization_config,
+                        hardware_counter,
+                    );
+                    return raw_scorer_from_query_scorer(query_scorer);
+                };
+
+                let quantized_scorer = QuantizedQueryScorer::<_>::new::<TElement, TMetric>(
+                    vector.clone(),
                     quantized_storage,
                     quantization_config,
                     hardware_counter,
                 );
+                let query_scorer = QuantizedFullPrecisionQueryScorer::<_, TMetric>::new::<TElement>(
+                    vector,
+                    quantized_scorer,
+                    full_precision,
+                    quantization_config,
+                );
                 raw_scorer_from_query_scorer(query_scorer)
             }
             QueryVector::RecommendBestScore(reco_query) => {
@@ -259,6 +283,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
         let Self {
             quantized_storage: _same_as_quantized_storage_in_args,
             quantization_config,
+            full_precision: _,
             query,
             distance: _,
             datatype: _,
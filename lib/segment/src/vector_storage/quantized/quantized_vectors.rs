@@ -15,6 +15,7 @@ use quantization::turboquant::{EncodedVectorsTQ, TQBits, TQMode};
 use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
 use serde::{Deserialize, Serialize};
 
+use super::full_precision_vectors::{FULL_PRECISION_MAX_FRACTION, FullPrecisionVectors};
 use super::quantized_multivector_storage::{
     MultivectorOffset, MultivectorOffsetsStorageMmap, QuantizedMultivectorStorage,
 };
@@ -26,9 +27,9 @@ use crate::data_types::vectors::{QueryVector, VectorElementType, VectorRef};
 use crate::types::{
     BinaryQuantization, BinaryQuantizationConfig, BinaryQuantizationEncoding,
     BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorConfig,
-    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarQuantization,
-    ScalarQuantizationConfig, ScalarType, TurboQuantBitSize, TurboQuantQuantizationConfig,
-    TurboQuantization, VectorStorageDatatype,
+    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarOutliers,
+    ScalarQuantization, ScalarQuantizationConfig, ScalarType, TurboQuantBitSize,
+    TurboQuantQuantizationConfig, TurboQuantization, VectorStorageDatatype,
 };
 use crate::vector_storage::quantized::quantized_chunked_mmap_storage::{
     QuantizedChunkedMmapStorage, QuantizedChunkedMmapStorageBuilder,
@@ -57,6 +58,7 @@ pub const QUANTIZED_APPENDABLE_DATA_PATH: &str = "quantized_data";
 pub const QUANTIZED_META_PATH: &str = "quantized.meta.json";
 pub const QUANTIZED_OFFSETS_PATH: &str = "quantized.offsets.data";
 pub const QUANTIZED_APPENDABLE_OFFSETS_PATH: &str = "quantized_offsets_data";
+pub const QUANTIZED_FULL_PRECISION_PATH: &str = "quantized.full_precision.bin";
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct QuantizedVectorsConfig {
@@ -240,10 +242,31 @@ impl fmt::Debug for QuantizedVectorStorage {
     }
 }
 
+/// Encoded representation of a stored vector, along with the config needed to interpret it.
+///
+/// Byte layout of `bytes` per quantization method:
+///
+/// - Scalar (`int8`): little-endian `f32` offset correction, followed by one `u8` code per
+///   dimension. Codes are padded with zeros to a multiple of 16 dimensions.
+/// - Product: one `u8` centroid index per chunk of the original vector.
+/// - Binary: bits packed into little-endian `u128` words. One bit per dimension for
+///   `one_bit` encoding, two bits per dimension for `two_bits`, three bits per two dimensions
+///   for `one_and_half_bits`.
+/// - TurboQuant: packed codes of the rotated vector, `bits` per dimension with the
+///   dimension padded to a whole number of bytes, followed by per-vector extras
+///   (such as the vector length) depending on distance and mode.
+#[derive(Debug, Clone)]
+pub struct QuantizedVectorRecord {
+    pub config: QuantizedVectorsConfig,
+    pub bytes: Vec<u8>,
+}
+
 #[derive(Debug)]
 pub struct QuantizedVectors {
     storage_impl: QuantizedVectorStorage,
     config: QuantizedVectorsConfig,
+    /// Vectors kept in full precision, if scalar quantization would clamp their values
+    full_precision: Option<FullPrecisionVectors>,
     path: PathBuf,
     distance: Distance,
     datatype: VectorStorageDatatype,
@@ -254,6 +277,11 @@ impl QuantizedVectors {
         &self.config
     }
 
+    /// Number of vectors kept in full precision next to the quantized ones
+    pub fn full_precision_vectors_count(&self) -> Option<usize> {
+        self.full_precision.as_ref().map(FullPrecisionVectors::len)
+    }
+
     pub fn default_rescoring(&self) -> bool {
         match &self.storage_impl {
             QuantizedVectorStorage::ScalarRam(_) => false,
@@ -360,6 +388,48 @@ impl QuantizedVectors {
         }
     }
 
+    /// Read the encoded representation of a single dense vector, as used by the scorers.
+    ///
+    /// Byte layout depends on the quantization method, see [`QuantizedVectorRecord`].
+    /// Multivector storages are not supported.
+    pub fn read_quantized_vector(
+        &self,
+        id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<QuantizedVectorRecord> {
+        let is_multivector = matches!(
+            self.storage_impl,
+            QuantizedVectorStorage::ScalarRamMulti(_)
+                | QuantizedVectorStorage::ScalarMmapMulti(_)
+                | QuantizedVectorStorage::ScalarChunkedMmapMulti(_)
+                | QuantizedVectorStorage::PQRamMulti(_)
+                | QuantizedVectorStorage::PQMmapMulti(_)
+                | QuantizedVectorStorage::PQChunkedMmapMulti(_)
+                | QuantizedVectorStorage::BinaryRamMulti(_)
+                | QuantizedVectorStorage::BinaryMmapMulti(_)
+                | QuantizedVectorStorage::BinaryChunkedMmapMulti(_)
+                | QuantizedVectorStorage::TQRamMulti(_)
+                | QuantizedVectorStorage::TQMmapMulti(_)
+                | QuantizedVectorStorage::TQChunkedMmapMulti(_)
+        );
+        if is_multivector {
+            return Err(OperationError::service_error(
+                "Cannot read quantized vector from multivector storage",
+            ));
+        }
+
+        let bytes = self.get_quantized_vector(id).into_owned();
+
+        if self.storage_impl.is_on_disk() {
+            hw_counter.vector_io_read().incr_delta(bytes.len());
+        }
+
+        Ok(QuantizedVectorRecord {
+            config: self.config.clone(),
+            bytes,
+        })
+    }
+
     pub fn raw_scorer<'a>(
         &'a self,
         query: QueryVector,
@@ -368,6 +438,7 @@ impl QuantizedVectors {
         QuantizedScorerBuilder::new(
             &self.storage_impl,
             &self.config.quantization_config,
+            self.full_precision.as_ref(),
             query,
             &self.distance,
             self.datatype,
@@ -490,6 +561,10 @@ impl QuantizedVectors {
         path.join(QUANTIZED_META_PATH)
     }
 
+    fn get_full_precision_path(path: &Path) -> PathBuf {
+        path.join(QUANTIZED_FULL_PRECISION_PATH)
+    }
+
     fn get_offsets_path(path: &Path, storage_type: QuantizedVectorsStorageType) -> PathBuf {
         match storage_type {
             QuantizedVectorsStorageType::Immutable => path.join(QUANTIZED_OFFSETS_PATH),
@@ -525,6 +600,9 @@ impl QuantizedVectors {
             QuantizedVectorStorage::TQChunkedMmapMulti(q) => q.files(),
         };
         files.push(self.path.join(QUANTIZED_CONFIG_PATH));
+        if self.full_precision.is_some() {
+            files.push(Self::get_full_precision_path(&self.path));
+        }
         files
     }
 
@@ -556,6 +634,9 @@ impl QuantizedVectors {
             QuantizedVectorStorage::TQChunkedMmapMulti(q) => q.immutable_files(),
         };
         files.push(self.path.join(QUANTIZED_CONFIG_PATH));
+        if self.full_precision.is_some() {
+            files.push(Self::get_full_precision_path(&self.path));
+        }
         files
     }
 
@@ -776,6 +857,7 @@ impl QuantizedVectors {
             storage_type,
         );
 
+        let outlier_candidates = vectors.clone();
         let quantized_storage = match quantization_config {
             QuantizationConfig::Scalar(ScalarQuantization {
                 scalar: scalar_config,
@@ -829,6 +911,15 @@ impl QuantizedVectors {
             )?,
         };
 
+        let full_precision = Self::create_full_precision(
+            outlier_candidates,
+            dim,
+            count,
+            quantization_config,
+            &quantized_storage,
+            path,
+        )?;
+
         let quantized_vectors_config = QuantizedVectorsConfig {
             quantization_config: quantization_config.clone(),
             vector_parameters,
@@ -838,6 +929,7 @@ impl QuantizedVectors {
         let quantized_vectors = QuantizedVectors {
             storage_impl: quantized_storage,
             config: quantized_vectors_config,
+            full_precision,
             path: path.to_path_buf(),
             distance,
             datatype,
@@ -847,6 +939,38 @@ impl QuantizedVectors {
         Ok(quantized_vectors)
     }
 
+    /// Keep the vectors in full precision, which scalar quantization would clamp, if configured
+    fn create_full_precision<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        dim: usize,
+        count: usize,
+        quantization_config: &QuantizationConfig,
+        quantized_storage: &QuantizedVectorStorage,
+        path: &Path,
+    ) -> OperationResult<Option<FullPrecisionVectors>> {
+        let QuantizationConfig::Scalar(ScalarQuantization { scalar }) = quantization_config else {
+            return Ok(None);
+        };
+        // Without a quantile, the range covers all values and nothing is clamped
+        if scalar.outliers.unwrap_or_default() != ScalarOutliers::FullPrecision
+            || scalar.quantile.is_none()
+        {
+            return Ok(None);
+        }
+
+        let value_range = match quantized_storage {
+            QuantizedVectorStorage::ScalarRam(storage) => storage.value_range(),
+            QuantizedVectorStorage::ScalarMmap(storage) => storage.value_range(),
+            QuantizedVectorStorage::ScalarChunkedMmap(storage) => storage.value_range(),
+            _ => return Ok(None),
+        };
+        let max_count = (count as f64 * FULL_PRECISION_MAX_FRACTION).ceil() as usize;
+        let full_precision =
+            FullPrecisionVectors::from_outliers(vectors, dim, value_range, max_count);
+        full_precision.save(&Self::get_full_precision_path(path))?;
+        Ok(Some(full_precision))
+    }
+
     fn create_multi_impl<
         TElement: PrimitiveVectorElement + 'static,
         TVectorStorage: MultiVectorStorage<TElement> + Send + Sync,
@@ -980,6 +1104,7 @@ impl QuantizedVectors {
         let quantized_vectors = QuantizedVectors {
             storage_impl: quantized_storage,
             config: quantized_vectors_config,
+            full_precision: None,
             path: path.to_path_buf(),
             distance,
             datatype,
@@ -1086,11 +1211,19 @@ impl QuantizedVectors {
             }
         };
 
+        let full_precision_path = Self::get_full_precision_path(path);
+        let full_precision = if full_precision_path.exists() {
+            Some(FullPrecisionVectors::load(&full_precision_path)?)
+        } else {
+            None
+        };
+
         let distance = vector_storage.distance();
         let datatype = vector_storage.datatype();
         Ok(QuantizedVectors {
             storage_impl: quantized_store,
             config,
+            full_precision,
             path: path.to_path_buf(),
             distance,
             datatype,
@@ -2625,7 +2758,11 @@ impl crate::common::memory_usage::MemoryReporter for QuantizedVectors {
         use crate::common::memory_usage::{ComponentMemoryUsage, FileStorageIntent};
 
         let files = self.files();
-        let heap_bytes = self.storage_impl.heap_size_bytes() as u64;
+        let full_precision_bytes = self
+            .full_precision
+            .as_ref()
+            .map_or(0, FullPrecisionVectors::heap_size_bytes);
+        let heap_bytes = (self.storage_impl.heap_size_bytes() + full_precision_bytes) as u64;
 
         // Either always_ram, then we only load on in ram and track heap_bytes
         // Or full on_disk, and we don't preload anything
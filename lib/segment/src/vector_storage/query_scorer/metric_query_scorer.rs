@@ -11,6 +11,7 @@ use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{TypedDenseVector, VectorElementType};
 use crate::spaces::metric::Metric;
 use crate::vector_storage::DenseVectorStorage;
+use crate::vector_storage::dense::vector_norms::VectorNorms;
 use crate::vector_storage::query_scorer::QueryScorer;
 
 pub struct MetricQueryScorer<
@@ -21,6 +22,8 @@ pub struct MetricQueryScorer<
 > {
     vector_storage: &'a TVectorStorage,
     query: TypedDenseVector<TElement>,
+    /// Cached norms of the stored vectors, with the norm of the query
+    norms: Option<(&'a VectorNorms, f32)>,
     metric: PhantomData<TMetric>,
     hardware_counter: HardwareCounterCell,
 }
@@ -47,15 +50,31 @@ impl<
             hardware_counter.set_vector_io_read_multiplier(0);
         }
 
+        let query = TypedDenseVector::from(TElement::slice_from_float_cow(Cow::from(
+            preprocessed_vector,
+        )));
+        let norms = vector_storage
+            .vector_norms()
+            .map(|norms| (norms, VectorNorms::norm(&query)));
+
         Self {
-            query: TypedDenseVector::from(TElement::slice_from_float_cow(Cow::from(
-                preprocessed_vector,
-            ))),
+            query,
+            norms,
             vector_storage,
             metric: PhantomData,
             hardware_counter,
         }
     }
+
+    #[inline]
+    fn similarity_to_stored(&self, idx: PointOffsetType, vector: &[TElement]) -> ScoreType {
+        match self.norms {
+            Some((norms, query_norm)) => {
+                TMetric::similarity_with_norms(&self.query, vector, query_norm, norms.get(idx))
+            }
+            None => TMetric::similarity(&self.query, vector),
+        }
+    }
 }
 
 impl<
@@ -70,7 +89,7 @@ impl<
     fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
         self.hardware_counter.cpu_counter().incr();
         self.hardware_counter.vector_io_read().incr();
-        TMetric::similarity(&self.query, &self.vector_storage.get_dense::<Random>(idx))
+        self.similarity_to_stored(idx, &self.vector_storage.get_dense::<Random>(idx))
     }
 
     #[inline]
@@ -82,7 +101,7 @@ impl<
 
         self.vector_storage
             .for_each_in_dense_batch(ids, |idx, vector| {
-                scores[idx] = TMetric::similarity(&self.query, vector);
+                scores[idx] = self.similarity_to_stored(ids[idx], vector);
             });
     }
 
@@ -96,7 +115,12 @@ impl<
         self.hardware_counter.cpu_counter().incr();
         let v1 = self.vector_storage.get_dense::<Random>(point_a);
         let v2 = self.vector_storage.get_dense::<Random>(point_b);
-        TMetric::similarity(&v1, &v2)
+        match self.norms {
+            Some((norms, _)) => {
+                TMetric::similarity_with_norms(&v1, &v2, norms.get(point_a), norms.get(point_b))
+            }
+            None => TMetric::similarity(&v1, &v2),
+        }
     }
 
     type SupportsBytes = True;
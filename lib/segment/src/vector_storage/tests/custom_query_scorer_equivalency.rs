@@ -70,6 +70,7 @@ fn scalar_u8() -> WithQuantization {
         r#type: crate::types::ScalarType::Int8,
         quantile: Some(0.5),
         always_ram: Some(true),
+        outliers: None,
     }
     .into();
 
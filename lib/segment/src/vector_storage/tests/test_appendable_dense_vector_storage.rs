@@ -12,13 +12,16 @@ use crate::fixtures::payload_context_fixture::create_id_tracker_fixture;
 use crate::id_tracker::{IdTracker, IdTrackerRead};
 use crate::index::hnsw_index::point_scorer::{BatchFilteredSearcher, FilteredScorer};
 use crate::types::{Distance, PointIdType, QuantizationConfig, ScalarQuantizationConfig};
-use crate::vector_storage::dense::appendable_dense_vector_storage::open_appendable_memmap_vector_storage_full;
+use crate::vector_storage::dense::appendable_dense_vector_storage::{
+    open_appendable_memmap_vector_storage_byte, open_appendable_memmap_vector_storage_full,
+};
 use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
 use crate::vector_storage::quantized::quantized_vectors::{
     QuantizedVectors, QuantizedVectorsStorageType,
 };
 use crate::vector_storage::{
-    DEFAULT_STOPPED, VectorStorage, VectorStorageEnum, VectorStorageRead, new_raw_scorer,
+    DEFAULT_STOPPED, DenseVectorStorage, VectorStorage, VectorStorageEnum, VectorStorageRead,
+    new_raw_scorer,
 };
 
 fn do_test_delete_points(storage: &mut VectorStorageEnum) {
@@ -318,6 +321,7 @@ fn test_score_quantized_points(storage: &mut VectorStorageEnum) {
         r#type: Default::default(),
         quantile: None,
         always_ram: None,
+        outliers: None,
     }
     .into();
 
@@ -510,3 +514,73 @@ fn test_score_quantized_points_appendable_memmap_vector_storages() {
     )
     .unwrap();
 }
+
+#[test]
+fn test_cached_norms_for_byte_cosine() {
+    let points = [
+        vec![1.0, 0.0, 10.0, 100.0],
+        vec![200.0, 3.0, 1.0, 0.0],
+        vec![255.0, 255.0, 255.0, 255.0],
+        vec![0.0, 0.0, 0.0, 0.0],
+        vec![7.0, 80.0, 0.0, 12.0],
+    ];
+    let query: QueryVector = [5.0, 100.0, 11.0, 0.0].into();
+    let hw_counter = HardwareCounterCell::new();
+
+    let open_storage = |path: &std::path::Path, populate: bool| {
+        open_appendable_memmap_vector_storage_byte(
+            path,
+            4,
+            Distance::Cosine,
+            AdviceSetting::Global,
+            populate,
+        )
+        .unwrap()
+    };
+    let vector_norms = |storage: &VectorStorageEnum| match storage {
+        VectorStorageEnum::DenseAppendableMemmapByte(storage) => storage.vector_norms().is_some(),
+        _ => unreachable!("storage must be a byte appendable storage"),
+    };
+    let scores = |storage: &VectorStorageEnum| {
+        let scorer = new_raw_scorer(query.clone(), storage, HardwareCounterCell::new()).unwrap();
+        let internal = scorer.score_internal(0, 1);
+        let mut scores = (0..points.len() as PointOffsetType)
+            .map(|idx| scorer.score_point(idx))
+            .collect_vec();
+        scores.push(internal);
+        scores
+    };
+
+    let cached_dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let plain_dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let mut cached = open_storage(cached_dir.path(), true);
+    let mut plain = open_storage(plain_dir.path(), false);
+    assert!(vector_norms(&cached));
+    assert!(!vector_norms(&plain));
+
+    for storage in [&mut cached, &mut plain] {
+        for (i, vec) in points.iter().enumerate() {
+            storage
+                .insert_vector(i as PointOffsetType, vec.as_slice().into(), &hw_counter)
+                .unwrap();
+        }
+        // Replaced vectors must update their norms
+        storage
+            .insert_vector(1, [3.0, 2.0, 1.0, 0.0].as_slice().into(), &hw_counter)
+            .unwrap();
+        storage.flusher()().unwrap();
+    }
+
+    let assert_same_scores = |a: Vec<f32>, b: Vec<f32>| {
+        for (a, b) in a.into_iter().zip(b) {
+            assert!((a - b).abs() < 1e-5, "scores differ: {a} != {b}");
+        }
+    };
+    assert_same_scores(scores(&cached), scores(&plain));
+
+    // Norms are computed on load
+    drop(cached);
+    let cached = open_storage(cached_dir.path(), true);
+    assert!(vector_norms(&cached));
+    assert_same_scores(scores(&cached), scores(&plain));
+}
@@ -15,6 +15,7 @@ use sparse::common::sparse_vector::SparseVector;
 
 use super::dense::dense_vector_storage::DenseVectorStorageImpl;
 use super::dense::empty_dense_vector_storage::EmptyDenseVectorStorage;
+use super::dense::vector_norms::VectorNorms;
 use super::dense::volatile_dense_vector_storage::VolatileDenseVectorStorage;
 use super::multi_dense::appendable_mmap_multi_dense_vector_storage::AppendableMmapMultiDenseVectorStorage;
 use super::multi_dense::volatile_multi_dense_vector_storage::VolatileMultiDenseVectorStorage;
@@ -209,6 +210,11 @@ pub trait DenseVectorStorage<T: PrimitiveVectorElement>: VectorStorageRead {
     fn size_of_available_vectors_in_bytes(&self) -> usize {
         self.available_vector_count() * self.vector_dim() * std::mem::size_of::<T>()
     }
+
+    /// Cached norms of the stored vectors, if this storage keeps them
+    fn vector_norms(&self) -> Option<&VectorNorms> {
+        None
+    }
 }
 
 pub trait SparseVectorStorage: VectorStorageRead {
@@ -288,6 +288,7 @@ fn test_byte_storage_binary_quantization_hnsw(
             r#type: Default::default(),
             quantile: None,
             always_ram: None,
+            outliers: None,
         }
         .into(),
         QuantizationVariant::PQ => ProductQuantizationConfig {
@@ -356,6 +356,7 @@ fn hnsw_quantized_search_cosine_test() {
             r#type: Default::default(),
             quantile: None,
             always_ram: None,
+            outliers: None,
         }
         .into(),
         true,
@@ -372,6 +373,7 @@ fn hnsw_quantized_search_euclid_test() {
             r#type: Default::default(),
             quantile: None,
             always_ram: None,
+            outliers: None,
         }
         .into(),
         true,
@@ -388,6 +390,7 @@ fn hnsw_quantized_search_manhattan_test() {
             r#type: Default::default(),
             quantile: None,
             always_ram: None,
+            outliers: None,
         }
         .into(),
         true,
@@ -966,6 +969,7 @@ fn test_build_hnsw_using_quantization() {
             r#type: Default::default(),
             quantile: None,
             always_ram: None,
+            outliers: None,
         }
         .into(),
     );
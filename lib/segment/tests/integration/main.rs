@@ -17,6 +17,7 @@ mod multivector_hnsw_test;
 mod multivector_quantization_test;
 mod nested_filtering_test;
 mod payload_index_test;
+mod quantized_vector_retrieval_test;
 mod scroll_filtering_test;
 mod segment_builder_test;
 mod segment_on_disk_snapshot;
@@ -7,13 +7,14 @@ use common::budget::ResourcePermit;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::flags::FeatureFlags;
 use common::progress_tracker::ProgressTracker;
-use common::types::ScoredPointOffset;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 use ordered_float::OrderedFloat;
 use rand::prelude::StdRng;
 use rand::{Rng, RngExt, SeedableRng};
 use rstest::rstest;
 use segment::data_types::vectors::{
-    DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, QueryVector, only_default_multi_vector,
+    DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, QueryVector, VectorElementType,
+    only_default_multi_vector,
 };
 use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::payload_fixtures::{random_int_payload, random_multi_vector};
@@ -22,15 +23,18 @@ use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
 use segment::index::{PayloadIndex, VectorIndexRead};
 use segment::json_path::JsonPath;
 use segment::segment_constructor::build_segment;
+use segment::spaces::simple::DotProductMetric;
 use segment::types::{
     BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
     HnswConfig, Indexes, MultiVectorConfig, PayloadSchemaType, ProductQuantizationConfig,
     QuantizationSearchParams, Range, ScalarQuantizationConfig, SearchParams, SegmentConfig,
     SeqNumberType, VectorDataConfig, VectorStorageType,
 };
+use segment::vector_storage::quantized::quantized_multi_query_scorer::QuantizedMultiQueryScorer;
 use segment::vector_storage::quantized::quantized_vectors::{
-    QuantizedVectors, QuantizedVectorsStorageType,
+    QuantizedVectorStorage, QuantizedVectors, QuantizedVectorsStorageType,
 };
+use segment::vector_storage::query_scorer::QueryScorer;
 use tempfile::Builder;
 
 const MAX_VECTORS_COUNT: usize = 3;
@@ -262,6 +266,7 @@ fn test_multivector_quantization_hnsw(
             r#type: Default::default(),
             quantile: None,
             always_ram: Some(false),
+            outliers: None,
         }
         .into(),
         QuantizationVariant::PQ => ProductQuantizationConfig {
@@ -403,3 +408,186 @@ fn test_multivector_quantization_hnsw(
     println!("sames = {sames}, attempts = {attempts}, top = {top}, acc = {acc}");
     assert!(acc > min_acc);
 }
+
+#[test]
+fn test_multivector_quantization_batch_scoring() {
+    let stopped = AtomicBool::new(false);
+    let dim = 8;
+    let num_vectors: u64 = 100;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let quantized_dir = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::InRamChunkedMmap,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: Some(MultiVectorConfig::default()),
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    for n in 0..num_vectors {
+        let vector = random_vector(&mut rng, dim);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_multi_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let quantization_config = ScalarQuantizationConfig {
+        r#type: Default::default(),
+        quantile: None,
+        always_ram: Some(true),
+        outliers: None,
+    }
+    .into();
+
+    let vector_storage = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_storage
+        .borrow();
+    let quantized_vectors = QuantizedVectors::create(
+        &vector_storage,
+        &quantization_config,
+        QuantizedVectorsStorageType::Immutable,
+        quantized_dir.path(),
+        1,
+        &stopped,
+    )
+    .unwrap();
+
+    let query = QueryVector::Nearest(random_vector(&mut rng, dim).into());
+    let scorer = quantized_vectors
+        .raw_scorer(query, HardwareCounterCell::new())
+        .unwrap();
+
+    // Unordered ids with a duplicate, the batch must assign each score to its own position
+    let mut ids: Vec<_> = (0..num_vectors as PointOffsetType)
+        .rev()
+        .step_by(3)
+        .collect();
+    ids.push(ids[ids.len() / 2]);
+
+    let mut scores = vec![0.0; ids.len()];
+    scorer.score_points(&ids, &mut scores);
+
+    for (&id, &score) in ids.iter().zip(&scores) {
+        assert_eq!(score, scorer.score_point(id), "point {id}");
+    }
+}
+
+#[test]
+fn test_multivector_quantization_max_sim_alignment() {
+    let stopped = AtomicBool::new(false);
+    let dim = 8;
+    let num_vectors: u64 = 100;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let quantized_dir = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::InRamChunkedMmap,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: Some(MultiVectorConfig::default()),
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut vectors = Vec::new();
+    for n in 0..num_vectors {
+        let vector = random_vector(&mut rng, dim);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_multi_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        vectors.push(vector);
+    }
+
+    let quantization_config = ScalarQuantizationConfig {
+        r#type: Default::default(),
+        quantile: None,
+        always_ram: Some(true),
+        outliers: None,
+    }
+    .into();
+
+    let vector_storage = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_storage
+        .borrow();
+    let quantized_vectors = QuantizedVectors::create(
+        &vector_storage,
+        &quantization_config,
+        QuantizedVectorsStorageType::Immutable,
+        quantized_dir.path(),
+        1,
+        &stopped,
+    )
+    .unwrap();
+    let QuantizedVectorStorage::ScalarRamMulti(quantized_storage) = quantized_vectors.get_storage()
+    else {
+        panic!("expected in-RAM scalar quantized multivectors");
+    };
+
+    let query = random_vector(&mut rng, dim);
+    let scorer = QuantizedMultiQueryScorer::new_multi::<VectorElementType, DotProductMetric>(
+        &query,
+        quantized_storage,
+        &quantization_config,
+        HardwareCounterCell::new(),
+    );
+
+    for (idx, vector) in vectors.iter().enumerate() {
+        let idx = idx as PointOffsetType;
+        let alignment = scorer.max_sim_alignment(idx);
+        assert_eq!(alignment.len(), query.vectors_count());
+        assert!(
+            alignment
+                .iter()
+                .all(|aligned| (aligned.vector_index as usize) < vector.vectors_count()),
+        );
+
+        // Maxima of the alignment sum up to the max-sim score
+        let score: ScoreType = alignment.iter().map(|aligned| aligned.score).sum();
+        assert!(
+            (score - scorer.score_stored(idx)).abs() < 1e-4,
+            "point {idx}",
+        );
+    }
+}
@@ -43,9 +43,10 @@ use segment::types::PayloadFieldSchema::{FieldParams, FieldType};
 use segment::types::PayloadSchemaType::{Integer, Keyword};
 use segment::types::{
     AnyVariants, Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoLineString,
-    GeoPoint, GeoPolygon, GeoRadius, HnswConfig, HnswGlobalConfig, Indexes, IsEmptyCondition,
-    Match, Payload, PayloadField, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
-    Range, SegmentConfig, ValueVariants, VectorDataConfig, VectorStorageType, WithPayload,
+    GeoNearPath, GeoPoint, GeoPolygon, GeoRadius, HnswConfig, HnswGlobalConfig, Indexes,
+    IsEmptyCondition, IsNullCondition, Match, NullElements, Payload, PayloadField,
+    PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType, Range, SegmentConfig,
+    ValueVariants, VectorDataConfig, VectorStorageType, WithPayload,
 };
 use segment::utils::scored_point_ties::ScoredPointTies;
 use tempfile::{Builder, TempDir};
@@ -308,6 +309,10 @@ impl TestSegments {
                         is_tenant: None,
                         on_disk: Some(true),
                         enable_hnsw: None,
+                        deduplicate_values: None,
+                        max_value_bytes: None,
+                        oversized_value_policy: None,
+                        case_insensitive: None,
                     },
                 ))),
                 &hw_counter,
@@ -373,6 +378,7 @@ impl TestSegments {
                     is_principal: None,
                     on_disk: Some(true),
                     enable_hnsw: None,
+                    precision: None,
                 }))),
                 &hw_counter,
             )
@@ -591,6 +597,7 @@ fn test_read_operations() -> Result<()> {
         test_struct_payload_geo_boundingbox_index,
         test_struct_payload_geo_radius_index,
         test_struct_payload_geo_polygon_index,
+        test_struct_payload_geo_near_path_index,
         test_any_matcher_cardinality_estimation,
         test_struct_keyword_facet,
         test_mmap_keyword_facet,
@@ -1065,6 +1072,27 @@ fn test_struct_payload_geo_radius_index(test_segments: &TestSegments) -> Result<
     validate_geo_filter(test_segments, query_filter).context(here!())
 }
 
+fn test_struct_payload_geo_near_path_index(test_segments: &TestSegments) -> Result<()> {
+    let mut rng = rand::rng();
+
+    let path = (0..rng.random_range(2..5))
+        .map(|_| GeoPoint::new_unchecked(rng.random_range(LON_RANGE), rng.random_range(LAT_RANGE)))
+        .collect();
+    let geo_near_path = GeoNearPath {
+        path,
+        distance: OrderedFloat(rng.random_range(1.0..100000.0)),
+    };
+
+    let condition = Condition::Field(FieldCondition::new_geo_near_path(
+        JsonPath::new("geo_key"),
+        geo_near_path,
+    ));
+
+    let query_filter = Filter::new_must(condition);
+
+    validate_geo_filter(test_segments, query_filter).context(here!())
+}
+
 fn test_struct_payload_geo_polygon_index(test_segments: &TestSegments) -> Result<()> {
     let polygon_edge = 5;
     let interiors_num = 3;
@@ -1095,6 +1123,7 @@ fn test_struct_payload_geo_polygon_index(test_segments: &TestSegments) -> Result
     let geo_polygon = GeoPolygon {
         exterior,
         interiors,
+        tolerance: None,
     };
 
     let condition = Condition::Field(FieldCondition::new_geo_polygon(
@@ -1392,6 +1421,92 @@ fn test_null_index_appendable_reopen_loads_and_accepts_updates() {
     );
 }
 
+/// The same `is_null` condition must select the same points, whether the field has a null index
+/// or the condition is checked against the payload.
+#[test]
+fn test_is_null_with_and_without_null_index() {
+    let field = JsonPath::new("name");
+    let hw_counter = HardwareCounterCell::new();
+    let is_stopped = AtomicBool::new(false);
+
+    let payloads = [
+        payload_json! {"name": null},
+        payload_json! {"name": [null, null]},
+        payload_json! {"name": ["foo", null, "bar"]},
+        payload_json! {"name": ["foo", "bar"]},
+        payload_json! {"name": "foo"},
+        payload_json! {},
+    ];
+    let null_elements_variants = [
+        None,
+        Some(NullElements::Ignore),
+        Some(NullElements::Include),
+        Some(NullElements::Only),
+    ];
+
+    let matching_points = |with_null_index: bool| -> Vec<Vec<PointOffsetType>> {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+
+        let mut payload_storage = InMemoryPayloadStorage::default();
+        for (point_id, payload) in payloads.iter().enumerate() {
+            payload_storage
+                .set(point_id as PointOffsetType, payload, &hw_counter)
+                .unwrap();
+        }
+
+        let payload_storage = Arc::new(AtomicRefCell::new(payload_storage.into()));
+        let id_tracker = Arc::new(AtomicRefCell::new(create_id_tracker_fixture(
+            payloads.len(),
+        )));
+
+        let mut index = StructPayloadIndex::open(
+            payload_storage,
+            id_tracker,
+            HashMap::new(),
+            dir.path(),
+            true,
+            true,
+        )
+        .unwrap();
+
+        if with_null_index {
+            // Keyword index carries a companion null index
+            index
+                .set_indexed(&field, FieldType(Keyword), &hw_counter)
+                .unwrap();
+            assert!(
+                index
+                    .field_indexes
+                    .get(&field)
+                    .unwrap()
+                    .iter()
+                    .any(|field_index| matches!(field_index, FieldIndex::NullIndex(_))),
+            );
+        }
+
+        null_elements_variants
+            .iter()
+            .map(|&null_elements| {
+                let filter = Filter::new_must(Condition::IsNull(IsNullCondition {
+                    is_null: PayloadField { key: field.clone() },
+                    null_elements,
+                }));
+                index
+                    .query_points(&filter, &hw_counter, &is_stopped, None)
+                    .unwrap()
+            })
+            .collect()
+    };
+
+    let payload_result = matching_points(false);
+    let index_result = matching_points(true);
+    assert_eq!(payload_result, index_result);
+
+    // Without an explicit treatment, null elements are ignored
+    assert_eq!(payload_result[0], vec![0]);
+    assert_eq!(payload_result[0], payload_result[1]);
+}
+
 fn test_any_matcher_cardinality_estimation(test_segments: &TestSegments) -> Result<()> {
     let keywords: IndexSet<String, FnvBuildHasher> = ["value1", "value2"]
         .iter()
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use atomic_refcell::AtomicRefCell;
+use common::counter::hardware_counter::HardwareCounterCell;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, only_default_vector};
+use segment::entry::entry_point::{
+    NonAppendableSegmentEntry as _, ReadSegmentEntry as _, SegmentEntry as _,
+};
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, Indexes, QuantizationConfig, ScalarQuantizationConfig, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+use tempfile::Builder;
+
+#[test]
+fn test_read_scalar_quantized_vector() {
+    let stopped = AtomicBool::new(false);
+    let dim = 8;
+    let num_vectors: u64 = 16;
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    for n in 0..num_vectors {
+        let vector: Vec<f32> = (0..dim).map(|i| (n as f32 + i as f32) / 32.0).collect();
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    // No quantization configured yet
+    let record = segment
+        .quantized_vector(DEFAULT_VECTOR_NAME, 3.into(), &hw_counter)
+        .unwrap();
+    assert!(record.is_none());
+
+    let quantization_config: QuantizationConfig = ScalarQuantizationConfig {
+        r#type: Default::default(),
+        quantile: None,
+        always_ram: None,
+        outliers: None,
+    }
+    .into();
+
+    segment.vector_data.values_mut().for_each(|vector_data| {
+        let quantized_vectors = QuantizedVectors::create(
+            &vector_data.vector_storage.borrow(),
+            &quantization_config,
+            QuantizedVectorsStorageType::Immutable,
+            dir.path(),
+            1,
+            &stopped,
+        )
+        .unwrap();
+        vector_data.quantized_vectors = Arc::new(AtomicRefCell::new(Some(quantized_vectors)));
+    });
+
+    let record = segment
+        .quantized_vector(DEFAULT_VECTOR_NAME, 3.into(), &hw_counter)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(record.config.quantization_config, quantization_config);
+    assert_eq!(record.config.vector_parameters.dim, dim);
+    // f32 offset followed by codes padded to 16 dimensions
+    assert_eq!(record.bytes.len(), size_of::<f32>() + 16);
+
+    segment
+        .delete_point(num_vectors, 3.into(), &hw_counter)
+        .unwrap();
+    assert!(
+        segment
+            .quantized_vector(DEFAULT_VECTOR_NAME, 3.into(), &hw_counter)
+            .is_err()
+    );
+}
@@ -82,6 +82,10 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                     is_tenant: None,
                     on_disk: Some(true),
                     enable_hnsw: None,
+                    deduplicate_values: None,
+                    max_value_bytes: None,
+                    oversized_value_policy: None,
+                    case_insensitive: None,
                 }),
             )),
             &hw_counter,
@@ -4,6 +4,7 @@ use std::sync::atomic::AtomicBool;
 
 use ahash::AHashSet;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::DeferredBehavior;
 use fs_err as fs;
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
@@ -14,13 +15,17 @@ use segment::data_types::vectors::{
 use segment::entry::StorageSegmentEntry as _;
 use segment::entry::entry_point::{NonAppendableSegmentEntry as _, ReadSegmentEntry, SegmentEntry};
 use segment::fixtures::index_fixtures::random_vector;
+use segment::json_path::JsonPath;
 use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
 use segment::segment_constructor::{load_segment, normalize_segment_dir};
-use segment::types::{Condition, Distance, Filter, SearchParams, SegmentType, WithPayload};
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, PayloadSchemaType, SearchParams, SegmentType,
+    WithPayload,
+};
 use tempfile::{Builder, TempDir};
 use uuid::Uuid;
 
-use crate::fixtures::segment::{build_segment_1, build_segment_3};
+use crate::fixtures::segment::{PAYLOAD_KEY, build_segment_1, build_segment_3};
 
 #[test]
 fn test_point_exclusion() {
@@ -397,3 +402,60 @@ fn test_plain_search_top_zero() {
         )
         .unwrap();
 }
+
+#[test]
+fn test_delete_points_batch() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_segment_1(dir.path());
+    segment
+        .create_field_index(
+            7,
+            &JsonPath::new(PAYLOAD_KEY),
+            Some(&PayloadSchemaType::Keyword.into()),
+            &hw_counter,
+        )
+        .unwrap();
+
+    // Point 1 is updated after the deletion, so the deletion must not remove it
+    segment
+        .upsert_point(
+            10,
+            1.into(),
+            only_default_vector(&[1.0, 0.0, 1.0, 1.0]),
+            &hw_counter,
+        )
+        .unwrap();
+
+    let deleted = segment
+        .delete_points(
+            8,
+            &[1.into(), 2.into(), 4.into(), 2.into(), 42.into()],
+            &hw_counter,
+        )
+        .unwrap();
+    assert_eq!(deleted, 2);
+
+    assert!(segment.has_point(1.into()));
+    assert!(!segment.has_point(2.into()));
+    assert!(segment.has_point(3.into()));
+    assert!(!segment.has_point(4.into()));
+    assert!(segment.has_point(5.into()));
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        JsonPath::new(PAYLOAD_KEY),
+        "red".to_owned().into(),
+    )));
+    let red_points = segment
+        .read_filtered(
+            None,
+            None,
+            Some(&filter),
+            &AtomicBool::new(false),
+            &hw_counter,
+            DeferredBehavior::Exclude,
+        )
+        .unwrap();
+    assert_eq!(red_points, vec![1.into(), 5.into()]);
+}
@@ -0,0 +1,336 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use ahash::AHashSet;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::save_on_disk::SaveOnDisk;
+use segment::common::operation_error::{OperationError, OperationResult};
+use segment::data_types::index::TokenizerType;
+use segment::types::{PayloadFieldSchema, PayloadKeyType, PayloadSchemaParams};
+use serde::{Deserialize, Serialize};
+
+use crate::locked_segment::LockedSegment;
+use crate::segment_holder::SegmentHolder;
+use crate::segment_holder::locked::LockedSegmentHolder;
+
+/// Min number of points in the full-text indexes of the shard to detect stopwords from.
+/// In smaller shards the frequencies are too noisy, nearly every token would be a stopword.
+const MIN_POINTS_COUNT: usize = 100;
+
+/// Stopwords are detected again, once the number of points in the full-text indexes of the shard
+/// changes by this fraction since the last detection.
+const REDETECT_POINTS_DRIFT: f64 = 0.1;
+
+/// Stopwords detected from the document frequencies of the tokens in the full-text indexes of
+/// all segments of the shard, for the fields with `auto_stopwords_threshold`.
+///
+/// Detected for the whole shard and assigned to every segment, so a query token is either
+/// removed in all segments or in none of them. Persisted with the shard, so the stopwords only
+/// change when they are detected again: when the threshold of the field changes, or when the
+/// number of indexed points drifts by more than [`REDETECT_POINTS_DRIFT`].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct AutoStopwords {
+    fields: HashMap<PayloadKeyType, DetectedStopwords>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+struct DetectedStopwords {
+    /// `auto_stopwords_threshold` of the field at the detection
+    threshold: u32,
+    /// Number of points in the full-text indexes of the shard at the detection
+    points_count: usize,
+    stopwords: BTreeSet<String>,
+}
+
+impl DetectedStopwords {
+    fn is_outdated(&self, threshold: u32, points_count: usize) -> bool {
+        let drift = points_count.abs_diff(self.points_count);
+        self.threshold != threshold
+            || drift as f64 > self.points_count as f64 * REDETECT_POINTS_DRIFT
+    }
+}
+
+impl AutoStopwords {
+    /// Stopwords detected for the field, sorted. Empty if not detected.
+    pub fn stopwords(&self, key: &PayloadKeyType) -> Vec<String> {
+        self.fields
+            .get(key)
+            .map(|detected| detected.stopwords.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Assign the detected stopwords to all segments of the `segment_holder`, on load
+    pub fn assign(&self, segment_holder: &mut SegmentHolder) {
+        for (key, detected) in &self.fields {
+            segment_holder.set_text_auto_stopwords(key.clone(), to_set(&detected.stopwords));
+        }
+    }
+
+    /// Detect the stopwords of the fields, which have no up-to-date stopwords.
+    ///
+    /// Returns the stopwords of the fields, which changed.
+    fn refresh(
+        &mut self,
+        segments: &LockedSegmentHolder,
+        schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+    ) -> OperationResult<Vec<(PayloadKeyType, BTreeSet<String>)>> {
+        let thresholds: HashMap<_, _> = schema
+            .iter()
+            .filter_map(|(key, schema)| Some((key, auto_stopwords_threshold(schema)?)))
+            .collect();
+
+        // Threshold removed, or the index is gone
+        let mut changed: Vec<_> = self
+            .fields
+            .keys()
+            .filter(|key| !thresholds.contains_key(key))
+            .map(|key| (key.clone(), BTreeSet::new()))
+            .collect();
+        self.fields.retain(|key, _| thresholds.contains_key(key));
+
+        if thresholds.is_empty() {
+            return Ok(changed);
+        }
+
+        // Collect the segments first so we don't lock the segment holder during detection
+        let segments: Vec<_> = segments
+            .read()
+            .iter()
+            .map(|(_, segment)| segment.clone())
+            .collect();
+
+        for (key, threshold) in thresholds {
+            let points_count = segments
+                .iter()
+                .map(|segment| segment.get().read().text_index_points_count(key))
+                .sum();
+
+            let is_outdated = self
+                .fields
+                .get(key)
+                .is_none_or(|detected| detected.is_outdated(threshold, points_count));
+            if !is_outdated {
+                continue;
+            }
+
+            let stopwords = detect(&segments, key, threshold, points_count)?;
+            let previous = self.fields.insert(
+                key.clone(),
+                DetectedStopwords {
+                    threshold,
+                    points_count,
+                    stopwords: stopwords.clone(),
+                },
+            );
+            if previous.is_none_or(|previous| previous.stopwords != stopwords) {
+                changed.push((key.clone(), stopwords));
+            }
+        }
+
+        Ok(changed)
+    }
+}
+
+/// Detect the stopwords of the fields, which have no up-to-date stopwords, persist them and
+/// assign them to all segments.
+///
+/// The `schema` holds the payload indexes of the shard. Cheap if nothing has to be detected, it
+/// only sums the number of indexed points of the fields with automatic stopwords.
+pub fn refresh_auto_stopwords(
+    auto_stopwords: &SaveOnDisk<AutoStopwords>,
+    segments: &LockedSegmentHolder,
+    schema: &HashMap<PayloadKeyType, PayloadFieldSchema>,
+) -> OperationResult<()> {
+    let current = auto_stopwords.read().clone();
+    let mut refreshed = current.clone();
+    let changed = refreshed.refresh(segments, schema)?;
+
+    if refreshed != current {
+        auto_stopwords
+            .write_optional(|_| Some(refreshed))
+            .map_err(|err| {
+                OperationError::service_error(format!("failed to save detected stopwords: {err}"))
+            })?;
+    }
+
+    if !changed.is_empty() {
+        let mut segments = segments.write();
+        for (key, stopwords) in changed {
+            segments.set_text_auto_stopwords(key, to_set(&stopwords));
+        }
+    }
+    Ok(())
+}
+
+/// Threshold of automatic stopwords in percent, if enabled for the full-text index
+fn auto_stopwords_threshold(schema: &PayloadFieldSchema) -> Option<u32> {
+    let PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(params)) = schema else {
+        return None;
+    };
+
+    // Short prefixes are contained in most of the points, they are not stopwords
+    params
+        .auto_stopwords_threshold
+        .filter(|&percent| percent > 0 && params.tokenizer != TokenizerType::Prefix)
+}
+
+/// Tokens contained in at least `threshold` percent of the `points_count` points of all segments
+fn detect(
+    segments: &[LockedSegment],
+    key: &PayloadKeyType,
+    threshold: u32,
+    points_count: usize,
+) -> OperationResult<BTreeSet<String>> {
+    if points_count < MIN_POINTS_COUNT {
+        return Ok(BTreeSet::new());
+    }
+
+    let fraction = f64::from(threshold) / 100.0;
+
+    // A token contained in this fraction of all points is contained in at least this fraction
+    // of the points of one of the segments, so the candidates don't miss any stopword
+    let mut candidates = BTreeSet::new();
+    for segment in segments {
+        candidates.extend(segment.get().read().text_frequent_tokens(key, fraction)?);
+    }
+    let candidates: Vec<_> = candidates.into_iter().collect();
+
+    let hw_counter = HardwareCounterCell::disposable();
+    let mut counts = vec![0; candidates.len()];
+    for segment in segments {
+        let segment_counts =
+            segment
+                .get()
+                .read()
+                .text_tokens_points_count(key, &candidates, &hw_counter)?;
+        for (count, segment_count) in counts.iter_mut().zip(segment_counts) {
+            *count += segment_count;
+        }
+    }
+
+    let min_points_count = (points_count as f64 * fraction).ceil() as usize;
+    Ok(candidates
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count >= min_points_count)
+        .map(|(token, _)| token)
+        .collect())
+}
+
+fn to_set(stopwords: &BTreeSet<String>) -> Arc<AHashSet<String>> {
+    Arc::new(stopwords.iter().cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::counter::hardware_counter::HardwareCounterCell;
+    use segment::data_types::index::{TextIndexParams, TextIndexType};
+    use segment::data_types::vectors::only_default_vector;
+    use segment::entry::{
+        NonAppendableSegmentEntry as _, ReadSegmentEntry as _, SegmentEntry as _,
+    };
+    use segment::payload_json;
+    use segment::segment::Segment;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::fixtures::empty_segment;
+
+    fn text_segment(
+        path: &std::path::Path,
+        key: &PayloadKeyType,
+        schema: &PayloadFieldSchema,
+        texts: impl IntoIterator<Item = String>,
+        first_id: u64,
+    ) -> Segment {
+        let hw_counter = HardwareCounterCell::new();
+        let mut segment = empty_segment(path);
+        segment
+            .create_field_index(1, key, Some(schema), &hw_counter)
+            .unwrap();
+        for (id, text) in (first_id..).zip(texts) {
+            let point_id = id.into();
+            segment
+                .upsert_point(2, point_id, only_default_vector(&[1.0; 4]), &hw_counter)
+                .unwrap();
+            let payload = payload_json! {"text": text};
+            segment
+                .set_payload(2, point_id, &payload, &None, &hw_counter)
+                .unwrap();
+        }
+        segment
+    }
+
+    #[test]
+    fn test_detect_stopwords_per_shard() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+        let key: PayloadKeyType = "text".parse().unwrap();
+        let schema = PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            auto_stopwords_threshold: Some(50),
+            ..Default::default()
+        }));
+
+        // `alpha` is in all points of the first segment and in none of the second one, so it is
+        // contained in half of the points of the shard. `beta` is in 40% of the points of each.
+        let texts = |with_alpha: bool| {
+            (0..100).map(move |i| {
+                let mut text = format!("word{i}");
+                if with_alpha {
+                    text.push_str(" alpha");
+                }
+                if i % 5 < 2 {
+                    text.push_str(" beta");
+                }
+                text
+            })
+        };
+
+        let mut holder = SegmentHolder::default();
+        holder.add_new(text_segment(dir.path(), &key, &schema, texts(true), 0));
+        holder.add_new(text_segment(dir.path(), &key, &schema, texts(false), 100));
+        let segments = LockedSegmentHolder::new(holder);
+
+        let auto_stopwords =
+            SaveOnDisk::<AutoStopwords>::load_or_init_default(dir.path().join("stopwords.json"))
+                .unwrap();
+        let schema = HashMap::from([(key.clone(), schema)]);
+        refresh_auto_stopwords(&auto_stopwords, &segments, &schema).unwrap();
+
+        assert_eq!(
+            auto_stopwords.read().stopwords(&key),
+            vec!["alpha".to_string()]
+        );
+
+        // The same stopwords are removed from the query in every segment
+        for (_, segment) in segments.read().iter() {
+            let stopwords = segment
+                .get()
+                .read()
+                .text_query_stopwords(&key, "alpha beta", None)
+                .unwrap();
+            assert_eq!(stopwords.removed, BTreeSet::from(["alpha".to_string()]));
+            assert!(!stopwords.is_empty);
+        }
+
+        // Persisted
+        let reloaded =
+            SaveOnDisk::<AutoStopwords>::load_or_init_default(dir.path().join("stopwords.json"))
+                .unwrap();
+        assert_eq!(*reloaded.read(), *auto_stopwords.read());
+
+        // Dropping the threshold removes the stopwords
+        refresh_auto_stopwords(&auto_stopwords, &segments, &HashMap::new()).unwrap();
+        assert!(auto_stopwords.read().stopwords(&key).is_empty());
+        for (_, segment) in segments.read().iter() {
+            let stopwords = segment
+                .get()
+                .read()
+                .text_query_stopwords(&key, "alpha beta", None)
+                .unwrap();
+            assert!(stopwords.removed.is_empty());
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub const SEGMENTS_PATH: &str = "segments";
 pub const NEWEST_CLOCKS_PATH: &str = "newest_clocks.json";
 pub const OLDEST_CLOCKS_PATH: &str = "oldest_clocks.json";
 pub const APPLIED_SEQ_FILE: &str = "applied_seq.json";
+pub const AUTO_STOPWORDS_FILE: &str = "auto_stopwords.json";
 
 /// Shard represents all files, associated with a shard data (excluding configs)
 /// Useful to not forget some files, while making operations on shard data
@@ -17,6 +18,7 @@ pub struct ShardDataFiles {
     pub newest_clocks_path: PathBuf,
     pub oldest_clocks_path: PathBuf,
     pub applied_seq_path: PathBuf,
+    pub auto_stopwords_path: PathBuf,
 }
 
 #[inline]
@@ -44,6 +46,11 @@ pub fn applied_seq_path(shard_path: &Path) -> PathBuf {
     shard_path.join(APPLIED_SEQ_FILE)
 }
 
+#[inline]
+pub fn auto_stopwords_path(shard_path: &Path) -> PathBuf {
+    shard_path.join(AUTO_STOPWORDS_FILE)
+}
+
 #[inline]
 pub fn get_shard_data_files(shard_path: &Path) -> ShardDataFiles {
     ShardDataFiles {
@@ -52,6 +59,7 @@ pub fn get_shard_data_files(shard_path: &Path) -> ShardDataFiles {
         newest_clocks_path: newest_clocks_path(shard_path),
         oldest_clocks_path: oldest_clocks_path(shard_path),
         applied_seq_path: applied_seq_path(shard_path),
+        auto_stopwords_path: auto_stopwords_path(shard_path),
     }
 }
 
@@ -70,6 +78,7 @@ pub fn clear_data(shard_path: &Path) -> std::io::Result<()> {
         newest_clocks_path,
         oldest_clocks_path,
         applied_seq_path,
+        auto_stopwords_path,
     } = shard_data_files;
 
     if wal_path.exists() {
@@ -92,6 +101,10 @@ pub fn clear_data(shard_path: &Path) -> std::io::Result<()> {
         fs::remove_file(applied_seq_path)?;
     }
 
+    if auto_stopwords_path.exists() {
+        fs::remove_file(auto_stopwords_path)?;
+    }
+
     Ok(())
 }
 
@@ -105,6 +118,7 @@ pub fn move_data(from_shard_path: &Path, to_shard_path: &Path) -> std::io::Resul
         newest_clocks_path: from_newest_clocks_path,
         oldest_clocks_path: from_oldest_clocks_path,
         applied_seq_path: from_applied_seq_path,
+        auto_stopwords_path: from_auto_stopwords_path,
     } = from_shard_data_files;
 
     let ShardDataFiles {
@@ -113,6 +127,7 @@ pub fn move_data(from_shard_path: &Path, to_shard_path: &Path) -> std::io::Resul
         newest_clocks_path: to_newest_clocks_path,
         oldest_clocks_path: to_oldest_clocks_path,
         applied_seq_path: to_applied_seq_path,
+        auto_stopwords_path: to_auto_stopwords_path,
     } = to_shard_data_files;
 
     if from_wal_path.exists() {
@@ -135,6 +150,10 @@ pub fn move_data(from_shard_path: &Path, to_shard_path: &Path) -> std::io::Resul
         common::fs::move_file(&from_applied_seq_path, &to_applied_seq_path)?;
     }
 
+    if from_auto_stopwords_path.exists() {
+        common::fs::move_file(&from_auto_stopwords_path, &to_auto_stopwords_path)?;
+    }
+
     Ok(())
 }
 
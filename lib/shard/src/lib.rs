@@ -1,3 +1,4 @@
+pub mod auto_stopwords;
 pub mod common;
 pub mod count;
 pub mod facet;
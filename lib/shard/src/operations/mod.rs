@@ -7,7 +7,7 @@ pub mod vector_name_ops;
 pub mod vector_ops;
 
 use segment::json_path::JsonPath;
-use segment::types::{PayloadFieldSchema, PointIdType};
+use segment::types::{PayloadFieldSchema, PointIdType, SeqNumberType};
 use serde::{Deserialize, Serialize};
 use strum::{EnumDiscriminants, EnumIter};
 
@@ -80,6 +80,22 @@ impl CollectionUpdateOperations {
         }
     }
 
+    /// Version, which all points of the operation must have for it to be applied
+    pub fn expected_version(&self) -> Option<SeqNumberType> {
+        match self {
+            Self::PointOperation(PointOperations::UpsertPointsConditional(op)) => {
+                op.expected_version
+            }
+            Self::PointOperation(_) => None,
+            Self::VectorOperation(_) => None,
+            Self::PayloadOperation(_) => None,
+            Self::FieldIndexOperation(_) => None,
+            Self::VectorNameOperation(_) => None,
+            #[cfg(feature = "staging")]
+            Self::StagingOperation(_) => None,
+        }
+    }
+
     pub fn retain_point_ids<F>(&mut self, filter: F)
     where
         F: Fn(&PointIdType) -> bool,
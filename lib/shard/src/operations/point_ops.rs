@@ -15,7 +15,7 @@ use segment::data_types::vectors::{
     BatchVectorStructInternal, DEFAULT_VECTOR_NAME, DenseVector, MultiDenseVector,
     MultiDenseVectorInternal, VectorInternal, VectorStructInternal,
 };
-use segment::types::{Filter, Payload, PointIdType, VectorNameBuf};
+use segment::types::{Filter, Payload, PointIdType, SeqNumberType, VectorNameBuf};
 use serde::{Deserialize, Serialize};
 use sparse::common::types::{DimId, DimWeight};
 use strum::{EnumDiscriminants, EnumIter};
@@ -199,39 +199,43 @@ impl PointInsertOperationsInternal {
     {
         match self {
             Self::PointsBatch(batch) => {
-                let mut retain_indices = HashSet::new();
-
-                retain_with_index(&mut batch.ids, |index, id| {
-                    if filter(id) {
-                        retain_indices.insert(index);
-                        true
-                    } else {
-                        false
-                    }
-                });
-
-                match &mut batch.vectors {
-                    BatchVectorStructPersisted::Single(vectors) => {
-                        retain_with_index(vectors, |index, _| retain_indices.contains(&index));
-                    }
+                let retain = batch.ids.iter().map(filter).collect_vec();
+                batch.retain_indices(|index| retain[index]);
+            }
 
-                    BatchVectorStructPersisted::MultiDense(vectors) => {
-                        retain_with_index(vectors, |index, _| retain_indices.contains(&index));
-                    }
+            Self::PointsList(points) => points.retain(|point| filter(&point.id)),
+        }
+    }
 
-                    BatchVectorStructPersisted::Named(vectors) => {
-                        for (_, vectors) in vectors.iter_mut() {
-                            retain_with_index(vectors, |index, _| retain_indices.contains(&index));
-                        }
-                    }
-                }
+    /// First point id, which occurs more than once in the operation
+    pub fn first_duplicate_id(&self) -> Option<PointIdType> {
+        let mut seen = HashSet::new();
+        self.point_ids().into_iter().find(|id| !seen.insert(*id))
+    }
 
-                if let Some(payload) = &mut batch.payloads {
-                    retain_with_index(payload, |index, _| retain_indices.contains(&index));
-                }
+    /// Keep only a single point for each id, either the first or the last one of the duplicates.
+    /// The order of the remaining points is preserved.
+    pub fn dedup_point_ids(&mut self, keep_last: bool) {
+        let ids = self.point_ids();
+        let mut seen = HashSet::with_capacity(ids.len());
+        let mut retain = vec![false; ids.len()];
+        if keep_last {
+            for (index, id) in ids.iter().enumerate().rev() {
+                retain[index] = seen.insert(*id);
             }
+        } else {
+            for (index, id) in ids.iter().enumerate() {
+                retain[index] = seen.insert(*id);
+            }
+        }
 
-            Self::PointsList(points) => points.retain(|point| filter(&point.id)),
+        if retain.iter().all(|&retain| retain) {
+            return;
+        }
+
+        match self {
+            Self::PointsBatch(batch) => batch.retain_indices(|index| retain[index]),
+            Self::PointsList(points) => retain_with_index(points, |index, _| retain[index]),
         }
     }
 }
@@ -256,6 +260,10 @@ pub struct ConditionalInsertOperationInternal {
     /// Mode of the upsert operation. If None, defaults to Upsert behavior.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+    /// If set, all points of the operation must exist with exactly this version,
+    /// otherwise the whole operation fails with a version conflict
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<SeqNumberType>,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Hash)]
@@ -275,6 +283,33 @@ pub struct BatchPersisted {
     pub payloads: Option<Vec<Option<Payload>>>,
 }
 
+impl BatchPersisted {
+    /// Retain the ids, vectors and payloads at the indices matching the `retain`
+    fn retain_indices(&mut self, retain: impl Fn(usize) -> bool) {
+        retain_with_index(&mut self.ids, |index, _| retain(index));
+
+        match &mut self.vectors {
+            BatchVectorStructPersisted::Single(vectors) => {
+                retain_with_index(vectors, |index, _| retain(index));
+            }
+
+            BatchVectorStructPersisted::MultiDense(vectors) => {
+                retain_with_index(vectors, |index, _| retain(index));
+            }
+
+            BatchVectorStructPersisted::Named(vectors) => {
+                for (_, vectors) in vectors.iter_mut() {
+                    retain_with_index(vectors, |index, _| retain(index));
+                }
+            }
+        }
+
+        if let Some(payload) = &mut self.payloads {
+            retain_with_index(payload, |index, _| retain(index));
+        }
+    }
+}
+
 #[cfg(feature = "api")]
 impl TryFrom<BatchPersisted> for Vec<api::grpc::qdrant::PointStruct> {
     type Error = tonic::Status;
@@ -442,6 +477,7 @@ impl TryFrom<api::rest::schema::Record> for PointStructPersisted {
             vector,
             shard_key: _,
             order_value: _,
+            score: _,
         } = record;
 
         if vector.is_none() {
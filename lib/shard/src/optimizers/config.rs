@@ -15,6 +15,8 @@ pub const DEFAULT_MAX_SEGMENT_PER_CPU_KB: usize = 256_000;
 pub const DEFAULT_INDEXING_THRESHOLD_KB: usize = 10_000;
 pub const DEFAULT_DELETED_THRESHOLD: f64 = 0.2;
 pub const DEFAULT_VACUUM_MIN_VECTOR_NUMBER: usize = 1000;
+pub const DEFAULT_REQUANTIZATION_MAX_DISTORTION: f64 = 0.3;
+pub const DEFAULT_REQUANTIZATION_CHECK_INTERVAL_SEC: u64 = 600;
 
 /// Extra configuration for dense vectors, applied on top of the plain config during optimization.
 #[derive(Debug, Clone, PartialEq)]
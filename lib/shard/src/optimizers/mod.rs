@@ -2,5 +2,6 @@ pub mod config;
 pub mod config_mismatch_optimizer;
 pub mod indexing_optimizer;
 pub mod merge_optimizer;
+pub mod requantization_optimizer;
 pub mod segment_optimizer;
 pub mod vacuum_optimizer;
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use common::budget::{ResourceBudget, ResourcePermit};
+use common::progress_tracker::ProgressTracker;
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use parking_lot::Mutex;
+use segment::common::operation_error::OperationResult;
+use segment::common::operation_time_statistics::OperationDurationsAggregator;
+use segment::entry::ReadSegmentEntry;
+use segment::segment::Segment;
+use segment::types::{HnswGlobalConfig, QuantizationConfig};
+use uuid::Uuid;
+
+use super::config::SegmentOptimizerConfig;
+use super::segment_optimizer::{OptimizationPlanner, SegmentOptimizer, optimize_segments};
+use crate::locked_segment::LockedSegment;
+use crate::operations::optimization::OptimizerThresholds;
+use crate::segment_holder::SegmentId;
+use crate::segment_holder::locked::LockedSegmentHolder;
+
+/// Number of rescored searches in a segment, required to trust its average distortion
+const REQUANTIZATION_MIN_SEARCHES: usize = 100;
+
+/// Increase of the distortion over the one, which triggered the rebuild of a segment, required
+/// to rebuild it again
+const REQUANTIZATION_MIN_DISTORTION_INCREASE: f64 = 0.1;
+
+/// Optimizer which looks for segments with drifted quantization
+///
+/// Quantization parameters are fit to the data at the time the segment is built. As the data
+/// distribution shifts, the quantized ranking diverges from the exact one, which is observed as
+/// distortion in rescored searches.
+///
+/// This optimizer rebuilds the segments with the highest distortion, which fits the quantization
+/// to the current data again. Binary quantization is not fit to the data, so it is never rebuilt.
+///
+/// Rebuilding doesn't help if the quantization can't rank the data well in the first place. So a
+/// rebuilt segment is only rebuilt again, once its distortion grows noticeably over the one which
+/// triggered its rebuild, meaning the data has drifted further.
+pub struct RequantizationOptimizer {
+    max_distortion: f64,
+    check_interval: Duration,
+    thresholds_config: OptimizerThresholds,
+    segments_path: PathBuf,
+    temp_path: PathBuf,
+    segment_optimizer_config: SegmentOptimizerConfig,
+    hnsw_global_config: HnswGlobalConfig,
+    telemetry_durations_aggregator: Arc<Mutex<OperationDurationsAggregator>>,
+    /// Distortion, which triggered the rebuild, by UUID of the rebuilt segment
+    rebuilt_distortion: Mutex<HashMap<Uuid, f64>>,
+}
+
+impl RequantizationOptimizer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        max_distortion: f64,
+        check_interval: Duration,
+        thresholds_config: OptimizerThresholds,
+        segments_path: PathBuf,
+        temp_path: PathBuf,
+        segment_optimizer_config: SegmentOptimizerConfig,
+        hnsw_global_config: HnswGlobalConfig,
+    ) -> Self {
+        RequantizationOptimizer {
+            max_distortion,
+            check_interval,
+            thresholds_config,
+            segments_path,
+            temp_path,
+            segment_optimizer_config,
+            hnsw_global_config,
+            telemetry_durations_aggregator: OperationDurationsAggregator::new(),
+            rebuilt_distortion: Mutex::default(),
+        }
+    }
+
+    /// Highest average distortion among the quantized vectors of the segment, which would be
+    /// fit to the data again by the rebuild
+    ///
+    /// Returns `None` if the distortion was observed in too few searches for all vectors.
+    fn worst_distortion(&self, segment: &Segment) -> Option<f64> {
+        segment
+            .vector_data
+            .iter()
+            .filter(|(vector_name, _)| {
+                let quantization_config = self
+                    .segment_optimizer_config
+                    .dense_vector
+                    .get(*vector_name)
+                    .and_then(|config| config.quantization_config.as_ref());
+                !matches!(quantization_config, Some(QuantizationConfig::Binary(_)))
+            })
+            .filter_map(|(_, vector_data)| {
+                let distortion = vector_data
+                    .vector_index
+                    .borrow()
+                    .quantization_distortion()?;
+                if distortion.searches() < REQUANTIZATION_MIN_SEARCHES {
+                    return None;
+                }
+                distortion.mean()
+            })
+            .max_by_key(|&distortion| OrderedFloat(distortion))
+    }
+
+    /// Distortion of the segment, if it should be rebuilt
+    fn distortion_to_requantize(
+        &self,
+        segment: &Segment,
+        rebuilt_distortion: &HashMap<Uuid, f64>,
+    ) -> Option<f64> {
+        let distortion = self.worst_distortion(segment)?;
+        let min_distortion = match rebuilt_distortion.get(&segment.segment_uuid()) {
+            Some(previous) => self
+                .max_distortion
+                .max(previous + REQUANTIZATION_MIN_DISTORTION_INCREASE),
+            None => self.max_distortion,
+        };
+        (distortion > min_distortion).then_some(distortion)
+    }
+}
+
+impl SegmentOptimizer for RequantizationOptimizer {
+    fn name(&self) -> &'static str {
+        "requantization"
+    }
+
+    fn segments_path(&self) -> &Path {
+        self.segments_path.as_path()
+    }
+
+    fn temp_path(&self) -> &Path {
+        self.temp_path.as_path()
+    }
+
+    fn segment_optimizer_config(&self) -> &SegmentOptimizerConfig {
+        &self.segment_optimizer_config
+    }
+
+    fn hnsw_global_config(&self) -> &HnswGlobalConfig {
+        &self.hnsw_global_config
+    }
+
+    fn threshold_config(&self) -> &OptimizerThresholds {
+        &self.thresholds_config
+    }
+
+    fn plan_optimizations(&self, planner: &mut OptimizationPlanner) {
+        let mut rebuilt_distortion = self.rebuilt_distortion.lock();
+
+        // Forget segments, which are gone or being optimized. In the latter case the segment
+        // might be rebuilt once more, if the other optimization gets cancelled.
+        let remaining_uuids = planner
+            .remaining()
+            .values()
+            .map(|segment| segment.read().segment_uuid())
+            .collect_vec();
+        rebuilt_distortion.retain(|uuid, _| remaining_uuids.contains(uuid));
+
+        let to_optimize = planner
+            .remaining()
+            .iter()
+            .filter_map(|(&segment_id, segment)| {
+                let distortion =
+                    self.distortion_to_requantize(&segment.read(), &rebuilt_distortion)?;
+                Some((segment_id, distortion))
+            })
+            .sorted_by_key(|(_, distortion)| OrderedFloat(-distortion))
+            .collect_vec();
+        for (segment_id, _) in to_optimize {
+            planner.plan(vec![segment_id]);
+        }
+    }
+
+    fn check_interval(&self) -> Option<Duration> {
+        Some(self.check_interval)
+    }
+
+    fn get_telemetry_counter(&self) -> &Mutex<OperationDurationsAggregator> {
+        &self.telemetry_durations_aggregator
+    }
+
+    fn optimize(
+        &self,
+        segment_holder: LockedSegmentHolder,
+        input_segment_ids: Vec<SegmentId>,
+        output_segment_uuid: Uuid,
+        permit: ResourcePermit,
+        resource_budget: ResourceBudget,
+        stopped: &AtomicBool,
+        progress: ProgressTracker,
+        on_successful_start: Box<dyn FnOnce()>,
+    ) -> OperationResult<usize> {
+        // Remember the distortion, which triggered the rebuild, before the segments are replaced
+        let distortion = {
+            let segments = segment_holder.read();
+            input_segment_ids
+                .iter()
+                .filter_map(|&segment_id| match segments.get(segment_id)? {
+                    LockedSegment::Original(segment) => self.worst_distortion(&segment.read()),
+                    LockedSegment::Proxy(_) => None,
+                })
+                .max_by_key(|&distortion| OrderedFloat(distortion))
+        };
+
+        let optimized_points = optimize_segments(
+            self,
+            segment_holder,
+            input_segment_ids,
+            output_segment_uuid,
+            permit,
+            resource_budget,
+            stopped,
+            progress,
+            on_successful_start,
+        )?;
+
+        if let Some(distortion) = distortion {
+            self.rebuilt_distortion
+                .lock()
+                .insert(output_segment_uuid, distortion);
+        }
+
+        Ok(optimized_points)
+    }
+}
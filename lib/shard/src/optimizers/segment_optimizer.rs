@@ -2,6 +2,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
+use std::time::Duration;
 
 use common::budget::{ResourceBudget, ResourcePermit};
 use common::progress_tracker::ProgressTracker;
@@ -98,6 +99,13 @@ pub trait SegmentOptimizer: Sync {
     /// Find segments that require optimization and write them into `planner`.
     fn plan_optimizations(&self, planner: &mut OptimizationPlanner);
 
+    /// Interval, at which optimizations should be planned even if there are no updates.
+    ///
+    /// Required by optimizers, which look at statistics collected by reads.
+    fn check_interval(&self) -> Option<Duration> {
+        None
+    }
+
     /// Wrapper around [`SegmentOptimizer::plan_optimizations`].
     /// Simplified interface and extra checks.
     #[cfg(any(test, feature = "testing"))]
@@ -335,33 +343,60 @@ pub trait SegmentOptimizer: Sync {
     where
         Self: Sync,
     {
-        let paths = OptimizationPaths {
-            segments_path: self.segments_path().to_path_buf(),
-            temp_path: self.temp_path().to_path_buf(),
-        };
-        let optimization_strategy = ShardOptimizationStrategy { optimizer: self };
-
-        // Delegate to shard's execute_optimization
-        let result = execute_optimization(
-            self.name(),
+        optimize_segments(
+            self,
             segment_holder,
             input_segment_ids,
             output_segment_uuid,
-            self.threshold_config().deferred_internal_id,
-            &paths,
             permit,
             resource_budget,
             stopped,
             progress,
-            self.get_telemetry_counter(),
-            &optimization_strategy,
             on_successful_start,
-        )?;
-
-        Ok(result.points_count)
+        )
     }
 }
 
+/// Default implementation of [`SegmentOptimizer::optimize`], for optimizers which override it to
+/// do extra work around the optimization.
+#[expect(clippy::too_many_arguments)]
+pub fn optimize_segments<O: SegmentOptimizer + Sync + ?Sized>(
+    optimizer: &O,
+    segment_holder: LockedSegmentHolder,
+    input_segment_ids: Vec<SegmentId>,
+    output_segment_uuid: Uuid,
+    permit: ResourcePermit,
+    resource_budget: ResourceBudget,
+    stopped: &AtomicBool,
+    progress: ProgressTracker,
+    on_successful_start: Box<dyn FnOnce()>,
+) -> OperationResult<usize> {
+    let paths = OptimizationPaths {
+        segments_path: optimizer.segments_path().to_path_buf(),
+        temp_path: optimizer.temp_path().to_path_buf(),
+    };
+    let optimization_strategy = ShardOptimizationStrategy { optimizer };
+
+    // Delegate to shard's execute_optimization
+    let result = execute_optimization(
+        optimizer.name(),
+        segment_holder,
+        input_segment_ids,
+        output_segment_uuid,
+        optimizer.threshold_config().deferred_internal_id,
+        &paths,
+        permit,
+        resource_budget,
+        stopped,
+        progress,
+        optimizer.get_telemetry_counter(),
+        &optimization_strategy,
+        on_successful_start,
+    )?;
+
+    Ok(result.points_count)
+}
+
 pub struct OptimizationPlanner<'a> {
     /// Segments that could be scheduled for optimization.
     remaining: BTreeMap<SegmentId, &'a Arc<RwLock<Segment>>>,
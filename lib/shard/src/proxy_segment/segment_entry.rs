@@ -4,25 +4,34 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::{DeferredBehavior, TelemetryDetail};
+use common::populate_budget::PopulateBudget;
+use common::types::{DeferredBehavior, ScoreType, TelemetryDetail};
 use segment::common::Flusher;
 use segment::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::facets::{FacetParams, FacetValue};
+use segment::data_types::index::Language;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::order_by::OrderValue;
+use segment::data_types::quantiles::ValueSamples;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use segment::data_types::segment_record::SegmentRecord;
+use segment::data_types::text_query::{
+    QueryStopwords, SnippetParams, TextSnippet, TextValueMatches,
+};
 use segment::data_types::vector_name_config::VectorNameConfig;
 use segment::data_types::vectors::{QueryVector, VectorInternal};
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::entry::StorageSegmentEntry;
 use segment::entry::entry_point::{NonAppendableSegmentEntry, ReadSegmentEntry, SegmentEntry};
 use segment::index::field_index::{CardinalityEstimation, FieldIndex};
+use segment::index::query_optimization::condition_cost::PlannedCondition;
 use segment::json_path::JsonPath;
 use segment::telemetry::SegmentTelemetry;
 use segment::types::*;
+use segment::vector_storage::quantized::quantized_vectors::QuantizedVectorRecord;
 use uuid::Uuid;
 
 use super::{ProxyDeletedPoint, ProxyIndexChange, ProxySegment};
@@ -370,6 +379,45 @@ impl ReadSegmentEntry for ProxySegment {
         }
     }
 
+    fn read_weighted_random_filtered(
+        &self,
+        limit: usize,
+        filter: Option<&Filter>,
+        sample: &WeightedSample,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        let filter = filter.map(|f| self.changed_vector_names.redact_filter(f));
+
+        if self.deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_weighted_random_filtered(
+                    limit,
+                    filter.as_deref(),
+                    sample,
+                    is_stopped,
+                    hw_counter,
+                )
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                filter,
+                self.deleted_points.keys().copied(),
+            );
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_weighted_random_filtered(
+                    limit,
+                    Some(&wrapped_filter),
+                    sample,
+                    is_stopped,
+                    hw_counter,
+                )
+        }
+    }
+
     /// Read points in [from; to) range
     fn read_range(&self, from: Option<PointIdType>, to: Option<PointIdType>) -> Vec<PointIdType> {
         let read_points = self.wrapped_segment.get().read().read_range(from, to);
@@ -557,6 +605,155 @@ impl ReadSegmentEntry for ProxySegment {
         })
     }
 
+    fn plan_must_conditions(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<PlannedCondition>> {
+        // Conditions are checked on the points of the wrapped segment
+        let filter = self.changed_vector_names.redact_filter(filter);
+        self.wrapped_segment
+            .get()
+            .read()
+            .plan_must_conditions(&filter, hw_counter)
+    }
+
+    fn quantized_vector(
+        &self,
+        vector_name: &VectorName,
+        point_id: PointIdType,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<QuantizedVectorRecord>> {
+        if self.deleted_points.contains_key(&point_id) {
+            return Err(OperationError::PointIdError {
+                missed_point_id: point_id,
+            });
+        }
+        self.wrapped_segment
+            .get()
+            .read()
+            .quantized_vector(vector_name, point_id, hw_counter)
+    }
+
+    fn point_text_tokens(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<String>>> {
+        if self.deleted_points.contains_key(&point_id) {
+            return Err(OperationError::PointIdError {
+                missed_point_id: point_id,
+            });
+        }
+        self.wrapped_segment
+            .get()
+            .read()
+            .point_text_tokens(point_id, key, hw_counter)
+    }
+
+    fn point_text_match_offsets(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        text: &str,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextValueMatches>>> {
+        if self.deleted_points.contains_key(&point_id) {
+            return Err(OperationError::PointIdError {
+                missed_point_id: point_id,
+            });
+        }
+        self.wrapped_segment
+            .get()
+            .read()
+            .point_text_match_offsets(point_id, key, text, hw_counter)
+    }
+
+    fn point_text_snippets(
+        &self,
+        point_id: PointIdType,
+        key: &JsonPath,
+        text: &str,
+        params: SnippetParams,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Option<Vec<TextSnippet>>> {
+        if self.deleted_points.contains_key(&point_id) {
+            return Err(OperationError::PointIdError {
+                missed_point_id: point_id,
+            });
+        }
+        self.wrapped_segment
+            .get()
+            .read()
+            .point_text_snippets(point_id, key, text, params, hw_counter)
+    }
+
+    fn value_samples(&self, key: &JsonPath) -> Option<ValueSamples> {
+        // Values of deleted points are still sampled, samples are approximate anyway
+        self.wrapped_segment.get().read().value_samples(key)
+    }
+
+    fn text_index_points_count(&self, key: &JsonPath) -> usize {
+        self.wrapped_segment
+            .get()
+            .read()
+            .text_index_points_count(key)
+    }
+
+    fn text_frequent_tokens(
+        &self,
+        key: &JsonPath,
+        min_fraction: f64,
+    ) -> OperationResult<Vec<String>> {
+        self.wrapped_segment
+            .get()
+            .read()
+            .text_frequent_tokens(key, min_fraction)
+    }
+
+    fn text_tokens_points_count(
+        &self,
+        key: &JsonPath,
+        tokens: &[String],
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<usize>> {
+        self.wrapped_segment
+            .get()
+            .read()
+            .text_tokens_points_count(key, tokens, hw_counter)
+    }
+
+    fn text_query_stopwords(
+        &self,
+        key: &JsonPath,
+        text: &str,
+        language: Option<Language>,
+    ) -> Option<QueryStopwords> {
+        self.wrapped_segment
+            .get()
+            .read()
+            .text_query_stopwords(key, text, language)
+    }
+
+    fn populate_payload_indexes(
+        &self,
+        key: &PayloadKeyType,
+        budget: &mut PopulateBudget,
+    ) -> OperationResult<()> {
+        self.wrapped_segment
+            .get()
+            .read()
+            .populate_payload_indexes(key, budget)
+    }
+
+    fn payload_indexes_ram_bytes(&self) -> usize {
+        self.wrapped_segment
+            .get()
+            .read()
+            .payload_indexes_ram_bytes()
+    }
+
     fn segment_uuid(&self) -> Uuid {
         self.wrapped_segment.get().read().segment_uuid()
     }
@@ -980,6 +1177,44 @@ impl NonAppendableSegmentEntry for ProxySegment {
         Ok(true)
     }
 
+    fn build_replacement_field_index(
+        &self,
+        op_num: SeqNumberType,
+        key: PayloadKeyTypeRef,
+        field_type: &PayloadFieldSchema,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<BuildFieldIndexResult> {
+        self.build_field_index(op_num, key, field_type, hw_counter)
+    }
+
+    fn swap_field_index(
+        &mut self,
+        op_num: SeqNumberType,
+        key: PayloadKeyType,
+        field_schema: PayloadFieldSchema,
+        field_index: Vec<FieldIndex>,
+    ) -> OperationResult<bool> {
+        // Creating an index on the wrapped segment replaces an incompatible one
+        self.apply_field_index(op_num, key, field_schema, field_index)
+    }
+
+    fn set_field_index_on_disk(
+        &mut self,
+        op_num: SeqNumberType,
+        key: PayloadKeyTypeRef,
+        field_schema: &PayloadFieldSchema,
+    ) -> OperationResult<bool> {
+        // Index of the optimized segment is created with the new schema
+        self.apply_field_index(op_num, key.clone(), field_schema.clone(), vec![])
+    }
+
+    fn set_text_auto_stopwords(&mut self, key: &JsonPath, stopwords: Arc<AHashSet<String>>) {
+        self.wrapped_segment
+            .get()
+            .write()
+            .set_text_auto_stopwords(key, stopwords);
+    }
+
     fn create_vector_name(
         &mut self,
         op_num: SeqNumberType,
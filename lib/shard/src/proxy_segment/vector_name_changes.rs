@@ -299,6 +299,8 @@ impl ProxyVectorNameChanges {
                 Condition::IsEmpty(_) => {}
                 Condition::IsNull(_) => {}
                 Condition::HasId(_) => {}
+                Condition::UpdatedAfter(_) => {}
+                Condition::Expression(_) => {}
                 Condition::CustomIdChecker(_) => {}
             }
         }
@@ -347,6 +349,8 @@ impl ProxyVectorNameChanges {
                 Condition::IsEmpty(_) => {}
                 Condition::IsNull(_) => {}
                 Condition::HasId(_) => {}
+                Condition::UpdatedAfter(_) => {}
+                Condition::Expression(_) => {}
                 Condition::CustomIdChecker(_) => {}
             }
         }
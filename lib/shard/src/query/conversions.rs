@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use api::{grpc, rest};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
@@ -6,10 +8,13 @@ use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{
     DEFAULT_VECTOR_NAME, NamedQuery, NamedVectorStruct, VectorInternal,
 };
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::index::query_optimization::rescore_formula::parsed_formula::{
     DecayKind, ParsedFormula,
 };
-use segment::types::{Filter, SearchParams, VectorNameBuf, WithPayloadInterface, WithVector};
+use segment::types::{
+    Filter, SearchParams, TextScoreAggregation, VectorNameBuf, WithPayloadInterface, WithVector,
+};
 use segment::vector_storage::query::{
     ContextQuery, DiscoverQuery, FeedbackItem, NaiveFeedbackCoefficients, NaiveFeedbackQuery,
     RecoQuery,
@@ -46,6 +51,9 @@ impl From<rest::schema::SearchRequestInternal> for ShardQueryRequest {
             params,
             with_vector: with_vector.unwrap_or_default(),
             with_payload: with_payload.unwrap_or_default(),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         }
     }
 }
@@ -65,6 +73,9 @@ impl TryFrom<grpc::QueryShardPoints> for ShardQueryRequest {
             offset,
             with_payload,
             with_vectors,
+            with_provenance,
+            with_explanation,
+            soft_timeout_ms,
         } = value;
 
         let request = Self {
@@ -87,6 +98,9 @@ impl TryFrom<grpc::QueryShardPoints> for ShardQueryRequest {
                 .map(WithPayloadInterface::try_from)
                 .transpose()?
                 .unwrap_or(WithPayloadInterface::Bool(true)),
+            with_provenance,
+            with_explanation,
+            soft_timeout: soft_timeout_ms.map(Duration::from_millis),
         };
 
         Ok(request)
@@ -105,6 +119,9 @@ impl From<ShardQueryRequest> for grpc::QueryShardPoints {
             params,
             with_vector,
             with_payload,
+            with_provenance,
+            with_explanation,
+            soft_timeout,
         } = value;
 
         Self {
@@ -123,6 +140,9 @@ impl From<ShardQueryRequest> for grpc::QueryShardPoints {
             offset: offset as u64,
             with_payload: Some(grpc::WithPayloadSelector::from(with_payload)),
             with_vectors: Some(grpc::WithVectorsSelector::from(with_vector)),
+            with_provenance,
+            with_explanation,
+            soft_timeout_ms: soft_timeout.map(|timeout| timeout.as_millis() as u64),
         }
     }
 }
@@ -325,11 +345,41 @@ impl TryFrom<i32> for SampleInternal {
     }
 }
 
-impl From<SampleInternal> for grpc::Sample {
-    fn from(value: SampleInternal) -> Self {
-        match value {
-            SampleInternal::Random => grpc::Sample::Random,
-        }
+impl From<rest::WeightedSample> for SampleInternal {
+    fn from(value: rest::WeightedSample) -> Self {
+        let rest::WeightedSample {
+            key,
+            seed,
+            default_weight,
+        } = value;
+
+        SampleInternal::Weighted(WeightedSample {
+            key,
+            seed: seed.unwrap_or_else(rand::random),
+            default_weight: OrderedFloat(default_weight.unwrap_or_default()),
+        })
+    }
+}
+
+impl TryFrom<grpc::WeightedSample> for SampleInternal {
+    type Error = tonic::Status;
+
+    fn try_from(value: grpc::WeightedSample) -> Result<Self, Self::Error> {
+        let grpc::WeightedSample {
+            key,
+            seed,
+            default_weight,
+        } = value;
+
+        let key = key
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
+
+        Ok(SampleInternal::Weighted(WeightedSample {
+            key,
+            seed: seed.unwrap_or_else(rand::random),
+            default_weight: OrderedFloat(default_weight.unwrap_or_default()),
+        }))
     }
 }
 
@@ -357,6 +407,9 @@ impl ScoringQuery {
             grpc::query_shard_points::query::Score::Sample(sample) => {
                 ScoringQuery::Sample(SampleInternal::try_from(sample)?)
             }
+            grpc::query_shard_points::query::Score::WeightedSample(sample) => {
+                ScoringQuery::Sample(SampleInternal::try_from(sample)?)
+            }
             grpc::query_shard_points::query::Score::Formula(formula) => ScoringQuery::Formula(
                 ParsedFormula::try_from(FormulaInternal::try_from(formula)?).map_err(|e| {
                     tonic::Status::invalid_argument(format!("failed to parse formula: {e}"))
@@ -398,8 +451,19 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
             ScoringQuery::Formula(parsed_formula) => Self {
                 score: Some(Score::Formula(grpc::Formula::from_parsed(parsed_formula))),
             },
-            ScoringQuery::Sample(sample) => Self {
-                score: Some(Score::Sample(grpc::Sample::from(sample) as i32)),
+            ScoringQuery::Sample(SampleInternal::Random) => Self {
+                score: Some(Score::Sample(grpc::Sample::Random as i32)),
+            },
+            ScoringQuery::Sample(SampleInternal::Weighted(WeightedSample {
+                key,
+                seed,
+                default_weight,
+            })) => Self {
+                score: Some(Score::WeightedSample(grpc::WeightedSample {
+                    key: key.to_string(),
+                    seed: Some(seed),
+                    default_weight: Some(default_weight.into_inner()),
+                })),
             },
             ScoringQuery::Mmr(MmrInternal {
                 vector,
@@ -630,6 +694,134 @@ impl TryFrom<grpc::Formula> for FormulaInternal {
     }
 }
 
+impl From<rest::RecencyQuery> for FormulaInternal {
+    fn from(value: rest::RecencyQuery) -> Self {
+        let rest::RecencyQuery {
+            recency:
+                rest::RecencyBoost {
+                    key,
+                    function,
+                    scale,
+                    midpoint,
+                },
+        } = value;
+
+        let kind = DecayKind::from(function.unwrap_or_default());
+        FormulaInternal::recency_boost(key, kind, scale, midpoint, chrono::Utc::now())
+    }
+}
+
+impl TryFrom<grpc::RecencyBoost> for FormulaInternal {
+    type Error = tonic::Status;
+
+    fn try_from(value: grpc::RecencyBoost) -> Result<Self, Self::Error> {
+        let grpc::RecencyBoost {
+            key,
+            scale,
+            function,
+            midpoint,
+        } = value;
+
+        let key = key
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
+        let function = function
+            .map(|function| {
+                grpc::DecayFunction::try_from(function).map_err(|_| {
+                    tonic::Status::invalid_argument(format!("invalid decay function {function}"))
+                })
+            })
+            .transpose()?
+            .unwrap_or(grpc::DecayFunction::Exp);
+
+        Ok(FormulaInternal::recency_boost(
+            key,
+            DecayKind::from(function),
+            scale,
+            midpoint,
+            chrono::Utc::now(),
+        ))
+    }
+}
+
+impl From<rest::ProximityQuery> for FormulaInternal {
+    fn from(value: rest::ProximityQuery) -> Self {
+        let rest::ProximityQuery {
+            proximity:
+                rest::ProximityBoost {
+                    key,
+                    origin,
+                    function,
+                    scale,
+                    midpoint,
+                    missing_boost,
+                },
+        } = value;
+
+        let kind = DecayKind::from(function.unwrap_or_default());
+        FormulaInternal::proximity_boost(key, origin, kind, scale, midpoint, missing_boost)
+    }
+}
+
+impl TryFrom<grpc::ProximityBoost> for FormulaInternal {
+    type Error = tonic::Status;
+
+    fn try_from(value: grpc::ProximityBoost) -> Result<Self, Self::Error> {
+        let grpc::ProximityBoost {
+            key,
+            origin,
+            scale,
+            function,
+            midpoint,
+            missing_boost,
+        } = value;
+
+        let key = key
+            .parse()
+            .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
+        let origin = origin
+            .ok_or_else(|| tonic::Status::invalid_argument("missing field: origin"))?
+            .into();
+        let function = function
+            .map(|function| {
+                grpc::DecayFunction::try_from(function).map_err(|_| {
+                    tonic::Status::invalid_argument(format!("invalid decay function {function}"))
+                })
+            })
+            .transpose()?
+            .unwrap_or(grpc::DecayFunction::Exp);
+
+        Ok(FormulaInternal::proximity_boost(
+            key,
+            origin,
+            DecayKind::from(function),
+            scale,
+            midpoint,
+            missing_boost,
+        ))
+    }
+}
+
+impl From<rest::DecayFunction> for DecayKind {
+    fn from(value: rest::DecayFunction) -> Self {
+        match value {
+            rest::DecayFunction::Lin => DecayKind::Lin,
+            rest::DecayFunction::Exp => DecayKind::Exp,
+            rest::DecayFunction::Gauss => DecayKind::Gauss,
+        }
+    }
+}
+
+impl From<grpc::DecayFunction> for DecayKind {
+    fn from(value: grpc::DecayFunction) -> Self {
+        match value {
+            grpc::DecayFunction::Lin => DecayKind::Lin,
+            grpc::DecayFunction::Exp => DecayKind::Exp,
+            grpc::DecayFunction::Gauss => DecayKind::Gauss,
+        }
+    }
+}
+
 impl From<rest::Expression> for ExpressionInternal {
     fn from(value: rest::Expression) -> Self {
         match value {
@@ -645,6 +837,18 @@ impl From<rest::Expression> for ExpressionInternal {
             rest::Expression::DatetimeKey(rest::DatetimeKeyExpression { datetime_key }) => {
                 ExpressionInternal::DatetimeKey(datetime_key)
             }
+            rest::Expression::TextScore(rest::TextScoreExpression {
+                text_score:
+                    rest::TextScoreParams {
+                        key,
+                        text,
+                        aggregation,
+                    },
+            }) => ExpressionInternal::TextScore {
+                key,
+                text,
+                aggregation: aggregation.unwrap_or_default(),
+            },
             rest::Expression::Mult(rest::MultExpression { mult: exprs }) => {
                 ExpressionInternal::Mult(exprs.into_iter().map(ExpressionInternal::from).collect())
             }
@@ -772,6 +976,31 @@ impl TryFrom<grpc::Expression> for ExpressionInternal {
                     .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
                 ExpressionInternal::DatetimeKey(json_path)
             }
+            Variant::TextScore(grpc::TextScore {
+                key,
+                text,
+                aggregation,
+            }) => {
+                let key = key
+                    .parse()
+                    .map_err(|_| tonic::Status::invalid_argument("invalid payload key"))?;
+                let aggregation = aggregation
+                    .map(|aggregation| {
+                        grpc::TextScoreAggregation::try_from(aggregation).map_err(|_| {
+                            tonic::Status::invalid_argument(format!(
+                                "invalid text score aggregation {aggregation}"
+                            ))
+                        })
+                    })
+                    .transpose()?
+                    .map(TextScoreAggregation::from)
+                    .unwrap_or_default();
+                ExpressionInternal::TextScore {
+                    key,
+                    text,
+                    aggregation,
+                }
+            }
             Variant::Mult(grpc::MultExpression { mult }) => {
                 let mult = mult
                     .into_iter()
@@ -1,12 +1,15 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
+use chrono::{DateTime, Utc};
 use common::types::ScoreType;
 use itertools::Itertools;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::index::query_optimization::rescore_formula::parsed_formula::*;
 use segment::json_path::JsonPath;
-use segment::types::{Condition, GeoPoint};
+use segment::types::{
+    Condition, Filter, GeoPoint, IsEmptyCondition, PayloadField, TextScoreAggregation,
+};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -16,6 +19,106 @@ pub struct FormulaInternal {
     pub defaults: HashMap<String, Value>,
 }
 
+impl FormulaInternal {
+    /// Boost the score of the prefetched points by the recency of the datetime at `key`:
+    ///
+    /// `$score * (1 + decay(datetime_key(key), now))`
+    ///
+    /// The boost of a brand-new point is `1`, it decays with the age of the point and reaches
+    /// `midpoint` at the age of `scale` seconds. Points without a value at `key` are not boosted.
+    pub fn recency_boost(
+        key: JsonPath,
+        kind: DecayKind,
+        scale: f32,
+        midpoint: Option<f32>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let has_datetime =
+            Condition::Filter(Filter::new_must_not(Condition::IsEmpty(IsEmptyCondition {
+                is_empty: PayloadField { key: key.clone() },
+            })));
+
+        let decay = ExpressionInternal::Decay {
+            kind,
+            x: Box::new(ExpressionInternal::DatetimeKey(key)),
+            target: Some(Box::new(ExpressionInternal::Datetime(now.to_rfc3339()))),
+            midpoint,
+            scale: Some(scale),
+        };
+
+        // Condition goes first, multiplication by zero skips the decay of points without datetime
+        let boost = ExpressionInternal::Mult(vec![
+            ExpressionInternal::Condition(Box::new(has_datetime)),
+            decay,
+        ]);
+
+        let formula = ExpressionInternal::Mult(vec![
+            ExpressionInternal::Variable("$score".to_string()),
+            ExpressionInternal::Sum(vec![ExpressionInternal::Constant(1.0), boost]),
+        ]);
+
+        FormulaInternal {
+            formula,
+            defaults: HashMap::new(),
+        }
+    }
+
+    /// Boost the score of the prefetched points by the proximity of the geo point at `key` to
+    /// the `origin`:
+    ///
+    /// `$score * (1 + decay(geo_distance(origin, key)))`
+    ///
+    /// The boost of a point at the origin is `1`, it decays with the distance and reaches
+    /// `midpoint` at the distance of `scale` meters. Points without a value at `key` are boosted
+    /// by `missing_boost` if specified, and not boosted otherwise.
+    pub fn proximity_boost(
+        key: JsonPath,
+        origin: GeoPoint,
+        kind: DecayKind,
+        scale: f32,
+        midpoint: Option<f32>,
+        missing_boost: Option<f32>,
+    ) -> Self {
+        let is_missing = Condition::IsEmpty(IsEmptyCondition {
+            is_empty: PayloadField { key: key.clone() },
+        });
+        let has_location = Condition::Filter(Filter::new_must_not(is_missing.clone()));
+
+        let decay = ExpressionInternal::Decay {
+            kind,
+            x: Box::new(ExpressionInternal::GeoDistance { origin, to: key }),
+            target: None,
+            midpoint,
+            scale: Some(scale),
+        };
+
+        // Condition goes first, multiplication by zero skips the decay of points without location
+        let mut boosts = vec![
+            ExpressionInternal::Constant(1.0),
+            ExpressionInternal::Mult(vec![
+                ExpressionInternal::Condition(Box::new(has_location)),
+                decay,
+            ]),
+        ];
+        if let Some(missing_boost) = missing_boost {
+            boosts.push(ExpressionInternal::Mult(vec![
+                ExpressionInternal::Condition(Box::new(is_missing)),
+                ExpressionInternal::Constant(missing_boost),
+            ]));
+        }
+
+        let formula = ExpressionInternal::Mult(vec![
+            ExpressionInternal::Variable("$score".to_string()),
+            ExpressionInternal::Sum(boosts),
+        ]);
+
+        FormulaInternal {
+            formula,
+            defaults: HashMap::new(),
+        }
+    }
+}
+
 impl TryFrom<FormulaInternal> for ParsedFormula {
     type Error = OperationError;
 
@@ -24,8 +127,10 @@ impl TryFrom<FormulaInternal> for ParsedFormula {
 
         let mut payload_vars = HashSet::new();
         let mut conditions = Vec::new();
+        let mut text_scores = Vec::new();
 
-        let parsed_expression = formula.parse_and_convert(&mut payload_vars, &mut conditions)?;
+        let parsed_expression =
+            formula.parse_and_convert(&mut payload_vars, &mut conditions, &mut text_scores)?;
 
         let defaults = defaults
             .into_iter()
@@ -42,6 +147,7 @@ impl TryFrom<FormulaInternal> for ParsedFormula {
             formula: parsed_expression,
             payload_vars,
             conditions,
+            text_scores,
             defaults,
         })
     }
@@ -58,6 +164,11 @@ pub enum ExpressionInternal {
     },
     Datetime(String),
     DatetimeKey(JsonPath),
+    TextScore {
+        key: JsonPath,
+        text: String,
+        aggregation: TextScoreAggregation,
+    },
     Mult(Vec<ExpressionInternal>),
     Sum(Vec<ExpressionInternal>),
     Neg(Box<ExpressionInternal>),
@@ -89,6 +200,7 @@ impl ExpressionInternal {
         self,
         payload_vars: &mut HashSet<JsonPath>,
         conditions: &mut Vec<Condition>,
+        text_scores: &mut Vec<TextScore>,
     ) -> OperationResult<ParsedExpression> {
         let expr = match self {
             ExpressionInternal::Constant(c) => {
@@ -123,48 +235,65 @@ impl ExpressionInternal {
                 payload_vars.insert(json_path.clone());
                 ParsedExpression::Datetime(DatetimeExpression::PayloadVariable(json_path))
             }
+            ExpressionInternal::TextScore {
+                key,
+                text,
+                aggregation,
+            } => {
+                let text_score_id = text_scores.len();
+                text_scores.push(TextScore {
+                    key,
+                    text,
+                    aggregation,
+                });
+                ParsedExpression::new_text_score_id(text_score_id)
+            }
             ExpressionInternal::Mult(internal_expressions) => ParsedExpression::Mult(
                 internal_expressions
                     .into_iter()
-                    .map(|expr| expr.parse_and_convert(payload_vars, conditions))
+                    .map(|expr| expr.parse_and_convert(payload_vars, conditions, text_scores))
                     .try_collect()?,
             ),
             ExpressionInternal::Sum(expression_internals) => ParsedExpression::Sum(
                 expression_internals
                     .into_iter()
-                    .map(|expr| expr.parse_and_convert(payload_vars, conditions))
+                    .map(|expr| expr.parse_and_convert(payload_vars, conditions, text_scores))
                     .try_collect()?,
             ),
             ExpressionInternal::Neg(expression_internal) => ParsedExpression::new_neg(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
+                expression_internal.parse_and_convert(payload_vars, conditions, text_scores)?,
             ),
             ExpressionInternal::Div {
                 left,
                 right,
                 by_zero_default,
             } => ParsedExpression::new_div(
-                left.parse_and_convert(payload_vars, conditions)?,
-                right.parse_and_convert(payload_vars, conditions)?,
+                left.parse_and_convert(payload_vars, conditions, text_scores)?,
+                right.parse_and_convert(payload_vars, conditions, text_scores)?,
                 by_zero_default.map(PreciseScore::from),
             ),
             ExpressionInternal::Sqrt(expression_internal) => ParsedExpression::Sqrt(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
+                expression_internal.parse_and_convert(payload_vars, conditions, text_scores)?,
             )),
             ExpressionInternal::Pow { base, exponent } => ParsedExpression::Pow {
-                base: Box::new(base.parse_and_convert(payload_vars, conditions)?),
-                exponent: Box::new(exponent.parse_and_convert(payload_vars, conditions)?),
+                base: Box::new(base.parse_and_convert(payload_vars, conditions, text_scores)?),
+                exponent: Box::new(exponent.parse_and_convert(
+                    payload_vars,
+                    conditions,
+                    text_scores,
+                )?),
             },
             ExpressionInternal::Exp(expression_internal) => ParsedExpression::Exp(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
+                expression_internal.parse_and_convert(payload_vars, conditions, text_scores)?,
             )),
             ExpressionInternal::Log10(expression_internal) => ParsedExpression::Log10(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
+                expression_internal.parse_and_convert(payload_vars, conditions, text_scores)?,
             )),
             ExpressionInternal::Ln(expression_internal) => ParsedExpression::Ln(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
+                expression_internal.parse_and_convert(payload_vars, conditions, text_scores)?,
             )),
             ExpressionInternal::Abs(expression_internal) => ParsedExpression::Abs(Box::new(
-                expression_internal.parse_and_convert(payload_vars, conditions)?,
+                expression_internal.parse_and_convert(payload_vars, conditions, text_scores)?,
             )),
             ExpressionInternal::Decay {
                 kind,
@@ -175,10 +304,10 @@ impl ExpressionInternal {
             } => {
                 let lambda = ParsedExpression::decay_params_to_lambda(midpoint, scale, kind)?;
 
-                let x = x.parse_and_convert(payload_vars, conditions)?;
+                let x = x.parse_and_convert(payload_vars, conditions, text_scores)?;
 
                 let target = target
-                    .map(|t| t.parse_and_convert(payload_vars, conditions))
+                    .map(|t| t.parse_and_convert(payload_vars, conditions, text_scores))
                     .transpose()?
                     .map(Box::new);
 
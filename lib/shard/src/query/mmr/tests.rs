@@ -35,6 +35,8 @@ fn create_scored_point_with_vector(
         vector: Some(VectorStructInternal::Named(vectors)),
         shard_key: None,
         order_value: None,
+        provenance: None,
+        score_stages: Vec::new(),
     }
 }
 
@@ -48,6 +50,8 @@ fn create_scored_point_without_vector(id: PointIdType) -> ScoredPoint {
         vector: None,
         shard_key: None,
         order_value: None,
+        provenance: None,
+        score_stages: Vec::new(),
     }
 }
 
@@ -73,6 +77,8 @@ fn create_scored_point_with_sparse_vector(
         vector: Some(VectorStructInternal::Named(vectors)),
         shard_key: None,
         order_value: None,
+        provenance: None,
+        score_stages: Vec::new(),
     }
 }
 
@@ -97,6 +103,8 @@ fn create_scored_point_with_multi_vector(
         vector: Some(VectorStructInternal::Named(vector_map)),
         shard_key: None,
         order_value: None,
+        provenance: None,
+        score_stages: Vec::new(),
     }
 }
 
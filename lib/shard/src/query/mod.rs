@@ -4,6 +4,7 @@ pub mod formula;
 pub mod mmr;
 pub mod planned_query;
 pub mod query_enum;
+pub mod score_stages;
 pub mod scroll;
 mod validation;
 
@@ -11,10 +12,13 @@ pub mod query_context;
 #[cfg(test)]
 mod tests;
 
+use std::time::Duration;
+
 use common::types::ScoreType;
 use ordered_float::OrderedFloat;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::VectorInternal;
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::types::*;
 use serde::Serialize;
@@ -48,6 +52,13 @@ pub struct ShardQueryRequest {
     pub params: Option<SearchParams>,
     pub with_vector: WithVector,
     pub with_payload: WithPayloadInterface,
+    /// Keep the segment, shard and peer, which scored each of the points
+    pub with_provenance: bool,
+    /// Record the stages, which composed the score of each of the points
+    pub with_explanation: bool,
+    /// Return the results of the segments searched until then, instead of searching all of them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_timeout: Option<Duration>,
 }
 
 impl ShardQueryRequest {
@@ -117,6 +128,9 @@ pub enum ScoringQuery {
     Fusion(FusionInternal),
 
     /// Order by a payload field
+    ///
+    /// Points are read through the numeric index of the field, without scoring any vectors.
+    /// Scores of prefetched points are discarded, the order of the field always wins.
     OrderBy(OrderBy),
 
     /// Score boosting via an arbitrary formula
@@ -160,9 +174,14 @@ pub enum FusionInternal {
     Dbsf,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, PartialEq, Hash, Serialize)]
 pub enum SampleInternal {
     Random,
+    /// Sample with probability proportional to a numeric payload field.
+    ///
+    /// Scores of the sampled points are their sampling keys, so samples of segments and shards
+    /// are merged like regular search results.
+    Weighted(WeightedSample),
 }
 
 /// Maximal Marginal Relevance configuration
@@ -201,6 +220,9 @@ impl From<CoreSearchRequest> for ShardQueryRequest {
             params,
             with_vector: with_vector.unwrap_or_default(),
             with_payload: with_payload.unwrap_or_default(),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         }
     }
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use common::types::ScoreType;
 use ordered_float::OrderedFloat;
 use segment::common::operation_error::{OperationError, OperationResult};
@@ -26,6 +28,9 @@ pub struct PlannedQuery {
 
     /// All the leaf scrolls
     pub scrolls: Vec<QueryScrollRequestInternal>,
+
+    /// Soft timeout of the leaf searches, the smallest one of the queries in the batch
+    pub soft_timeout: Option<Duration>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -33,6 +38,8 @@ pub struct RootPlan {
     pub merge_plan: MergePlan,
     pub with_vector: WithVector,
     pub with_payload: WithPayloadInterface,
+    /// Record the score stages of the points, see [`segment::types::ScoredPoint::score_stages`]
+    pub with_explanation: bool,
 }
 
 #[derive(Debug, PartialEq)]
@@ -125,8 +132,14 @@ impl PlannedQuery {
             with_vector,
             with_payload,
             params,
+            with_provenance: _, // Kept or cleared by the shard, see `LocalShard::query_batch`
+            with_explanation,
+            soft_timeout,
         } = request;
 
+        // The leaf searches of all queries are executed together
+        self.soft_timeout = self.soft_timeout.into_iter().chain(soft_timeout).min();
+
         // Adjust limit so that we have enough results when we cut off the offset at a higher level
         let limit = limit + offset;
 
@@ -150,6 +163,7 @@ impl PlannedQuery {
                 with_payload,
                 params,
                 limit,
+                with_explanation,
             )?
         } else {
             self.root_plan_with_prefetches(
@@ -161,6 +175,7 @@ impl PlannedQuery {
                 with_payload,
                 params,
                 limit,
+                with_explanation,
             )?
         };
 
@@ -179,6 +194,7 @@ impl PlannedQuery {
         with_payload: WithPayloadInterface,
         params: Option<SearchParams>,
         limit: usize,
+        with_explanation: bool,
     ) -> OperationResult<RootPlan> {
         let rescore_stages = match &query {
             None => None,
@@ -212,6 +228,7 @@ impl PlannedQuery {
             merge_plan,
             with_vector,
             with_payload,
+            with_explanation,
         })
     }
 
@@ -226,6 +243,7 @@ impl PlannedQuery {
         with_payload: WithPayloadInterface,
         params: Option<SearchParams>,
         limit: usize,
+        with_explanation: bool,
     ) -> OperationResult<RootPlan> {
         let rescoring_query = query.ok_or_else(|| {
             OperationError::validation_error("cannot have prefetches without a query".to_string())
@@ -292,6 +310,7 @@ impl PlannedQuery {
             merge_plan,
             with_vector,
             with_payload,
+            with_explanation,
         })
     }
 
@@ -418,9 +437,13 @@ fn leaf_source_from_scoring_query(
                 "cannot apply Formula without prefetches".to_string(),
             ));
         }
-        Some(ScoringQuery::Sample(SampleInternal::Random)) => {
+        Some(ScoringQuery::Sample(sample)) => {
+            let scroll_order = match sample {
+                SampleInternal::Random => ScrollOrder::Random,
+                SampleInternal::Weighted(weighted) => ScrollOrder::WeightedRandom(weighted),
+            };
             let scroll = QueryScrollRequestInternal {
-                scroll_order: ScrollOrder::Random,
+                scroll_order,
                 filter,
                 with_vector: WithVector::from(false),
                 with_payload: WithPayloadInterface::from(false),
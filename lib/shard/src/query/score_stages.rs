@@ -0,0 +1,71 @@
+//! Recording of the stages, which compose the scores of the points of explained queries
+
+use std::mem;
+
+use ahash::AHashMap;
+use segment::types::{PointIdType, ScoreStage, ScoreStageKind, ScoredPoint};
+
+use super::ScoringQuery;
+
+impl ScoringQuery {
+    /// Kind of the stage recorded for the points scored by this query.
+    ///
+    /// `None` for orders and samples, which discard the scores of the points.
+    pub fn score_stage_kind(&self) -> Option<ScoreStageKind> {
+        match self {
+            Self::Vector(_) => Some(ScoreStageKind::Vector),
+            Self::Fusion(_) => Some(ScoreStageKind::Fusion),
+            Self::Formula(_) => Some(ScoreStageKind::Formula),
+            Self::Mmr(_) => Some(ScoreStageKind::Mmr),
+            Self::OrderBy(_) | Self::Sample(_) => None,
+        }
+    }
+}
+
+/// Record the `kind` stage of the `points`, with their current scores
+pub fn push_score_stage(points: &mut [ScoredPoint], kind: &ScoreStageKind) {
+    for point in points {
+        point.score_stages.push(ScoreStage {
+            kind: kind.clone(),
+            score: point.score,
+        });
+    }
+}
+
+/// Take the stages of the points of the `sources`, to be continued by the rescored points.
+///
+/// If several sources contain a point, the stages of the first of them are taken.
+pub fn take_score_stages(
+    sources: &mut [Vec<ScoredPoint>],
+) -> AHashMap<PointIdType, Vec<ScoreStage>> {
+    let mut stages = AHashMap::new();
+    for point in sources.iter_mut().flatten() {
+        let point_stages = mem::take(&mut point.score_stages);
+        stages.entry(point.id).or_insert(point_stages);
+    }
+    stages
+}
+
+/// Record the `kind` stage of the `rescored` points, after the `source_stages` taken from the
+/// points they were rescored from.
+///
+/// If `kind` is `None`, the rescoring discarded the scores, so no stages are kept.
+pub fn record_rescore_stage(
+    rescored: &mut [ScoredPoint],
+    mut source_stages: AHashMap<PointIdType, Vec<ScoreStage>>,
+    kind: Option<ScoreStageKind>,
+) {
+    let Some(kind) = kind else {
+        rescored
+            .iter_mut()
+            .for_each(|point| point.score_stages.clear());
+        return;
+    };
+    for point in rescored {
+        point.score_stages = source_stages.remove(&point.id).unwrap_or_default();
+        point.score_stages.push(ScoreStage {
+            kind: kind.clone(),
+            score: point.score,
+        });
+    }
+}
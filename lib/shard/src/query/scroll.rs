@@ -1,4 +1,5 @@
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::weighted_sample::WeightedSample;
 use segment::types::{Filter, WithPayloadInterface, WithVector};
 
 use crate::operation_rate_cost;
@@ -38,4 +39,5 @@ pub enum ScrollOrder {
     ById,
     ByField(OrderBy),
     Random,
+    WeightedRandom(WeightedSample),
 }
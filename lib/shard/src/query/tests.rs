@@ -62,6 +62,9 @@ fn test_try_from_double_rescore() {
         }),
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(true),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let planned_query = PlannedQuery::try_from(vec![request]).unwrap();
@@ -92,6 +95,7 @@ fn test_try_from_double_rescore() {
         vec![RootPlan {
             with_vector: WithVector::Bool(true),
             with_payload: WithPayloadInterface::Bool(true),
+            with_explanation: false,
             merge_plan: MergePlan {
                 sources: vec![Source::Prefetch(Box::from(MergePlan {
                     sources: vec![Source::SearchesIdx(0)],
@@ -140,6 +144,9 @@ fn test_try_from_no_prefetch() {
         params: Some(SearchParams::default()),
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(true),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let planned_query = PlannedQuery::try_from(vec![request]).unwrap();
@@ -165,6 +172,7 @@ fn test_try_from_no_prefetch() {
         vec![RootPlan {
             with_payload: WithPayloadInterface::Bool(true),
             with_vector: WithVector::Bool(true),
+            with_explanation: false,
             merge_plan: MergePlan {
                 sources: vec![Source::SearchesIdx(0)],
                 rescore_stages: None,
@@ -227,6 +235,9 @@ fn test_try_from_hybrid_query() {
         params: None,
         with_payload: WithPayloadInterface::Bool(false),
         with_vector: WithVector::Bool(true),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let planned_query = PlannedQuery::try_from(vec![request]).unwrap();
@@ -268,6 +279,7 @@ fn test_try_from_hybrid_query() {
         vec![RootPlan {
             with_payload: WithPayloadInterface::Bool(false),
             with_vector: WithVector::Bool(true),
+            with_explanation: false,
             merge_plan: MergePlan {
                 sources: vec![Source::SearchesIdx(0), Source::SearchesIdx(1)],
                 rescore_stages: Some(RescoreStages::collection_level(RescoreParams {
@@ -299,6 +311,9 @@ fn test_try_from_rrf_without_source() {
         params: None,
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let planned_query = PlannedQuery::try_from(vec![request]);
@@ -350,6 +365,9 @@ fn test_base_params_mapping_in_try_from() {
         params: top_level_params,
         with_payload: WithPayloadInterface::Bool(true),
         with_vector: WithVector::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
 
     let planned_query = PlannedQuery::try_from(vec![request]).unwrap();
@@ -359,6 +377,7 @@ fn test_base_params_mapping_in_try_from() {
         vec![RootPlan {
             with_payload: WithPayloadInterface::Bool(true),
             with_vector: WithVector::Bool(false),
+            with_explanation: false,
             merge_plan: MergePlan {
                 sources: vec![Source::SearchesIdx(0)],
                 rescore_stages: Some(RescoreStages::collection_level(RescoreParams {
@@ -445,6 +464,9 @@ fn test_detect_max_depth() {
         params: None,
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(false),
+        with_provenance: false,
+        with_explanation: false,
+        soft_timeout: None,
     };
     assert_eq!(request.prefetches_depth(), 0);
 
@@ -543,6 +565,9 @@ fn test_from_batch_of_requests() {
             params: None,
             with_payload: WithPayloadInterface::Bool(false),
             with_vector: WithVector::Bool(false),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         },
         // A no-prefetch scroll query
         ShardQueryRequest {
@@ -555,6 +580,9 @@ fn test_from_batch_of_requests() {
             params: None,
             with_payload: WithPayloadInterface::Bool(false),
             with_vector: WithVector::Bool(false),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         },
         // A double fusion query
         ShardQueryRequest {
@@ -583,6 +611,9 @@ fn test_from_batch_of_requests() {
             params: None,
             with_payload: WithPayloadInterface::Bool(true),
             with_vector: WithVector::Bool(true),
+            with_provenance: false,
+            with_explanation: false,
+            soft_timeout: None,
         },
     ];
 
@@ -597,6 +628,7 @@ fn test_from_batch_of_requests() {
             RootPlan {
                 with_vector: WithVector::Bool(false),
                 with_payload: WithPayloadInterface::Bool(false),
+                with_explanation: false,
                 merge_plan: MergePlan {
                     sources: vec![Source::SearchesIdx(0)],
                     rescore_stages: None,
@@ -605,6 +637,7 @@ fn test_from_batch_of_requests() {
             RootPlan {
                 with_vector: WithVector::Bool(false),
                 with_payload: WithPayloadInterface::Bool(false),
+                with_explanation: false,
                 merge_plan: MergePlan {
                     sources: vec![Source::ScrollsIdx(0)],
                     rescore_stages: None,
@@ -613,6 +646,7 @@ fn test_from_batch_of_requests() {
             RootPlan {
                 with_vector: WithVector::Bool(true),
                 with_payload: WithPayloadInterface::Bool(true),
+                with_explanation: false,
                 merge_plan: MergePlan {
                     sources: vec![
                         Source::Prefetch(Box::from(MergePlan {
@@ -650,3 +684,74 @@ fn test_from_batch_of_requests() {
     assert_eq!(planned_query.scrolls[0].limit, 20);
     assert_eq!(planned_query.scrolls[1].limit, 50);
 }
+
+#[test]
+fn test_recency_boost_formula() {
+    use segment::index::query_optimization::rescore_formula::parsed_formula::{
+        DecayKind, ParsedFormula,
+    };
+
+    use super::formula::FormulaInternal;
+
+    let key = JsonPath::new("published_at");
+    let now = chrono::Utc::now();
+
+    let formula =
+        FormulaInternal::recency_boost(key.clone(), DecayKind::Exp, 86_400.0, Some(0.5), now);
+    let parsed = ParsedFormula::try_from(formula).unwrap();
+
+    assert_eq!(
+        parsed.payload_vars,
+        std::collections::HashSet::from([key.clone()])
+    );
+    // Points without datetime are excluded from the boost by a condition
+    assert_eq!(parsed.conditions.len(), 1);
+    assert!(parsed.defaults.is_empty());
+
+    // Decay parameters are validated as for any other formula
+    let formula = FormulaInternal::recency_boost(key.clone(), DecayKind::Gauss, 0.0, None, now);
+    assert!(ParsedFormula::try_from(formula).is_err());
+
+    let formula = FormulaInternal::recency_boost(key, DecayKind::Lin, 3_600.0, Some(1.5), now);
+    assert!(ParsedFormula::try_from(formula).is_err());
+}
+
+#[test]
+fn test_proximity_boost_formula() {
+    use segment::index::query_optimization::rescore_formula::parsed_formula::{
+        DecayKind, ParsedFormula,
+    };
+    use segment::types::GeoPoint;
+
+    use super::formula::FormulaInternal;
+
+    let key = JsonPath::new("location");
+    let origin = GeoPoint::new(13.4, 52.5).unwrap();
+
+    let formula =
+        FormulaInternal::proximity_boost(key.clone(), origin, DecayKind::Exp, 1000.0, None, None);
+    let parsed = ParsedFormula::try_from(formula).unwrap();
+
+    assert_eq!(
+        parsed.payload_vars,
+        std::collections::HashSet::from([key.clone()])
+    );
+    // Points without location are excluded from the boost by a condition
+    assert_eq!(parsed.conditions.len(), 1);
+
+    // Points without location are boosted by a condition of their own
+    let formula = FormulaInternal::proximity_boost(
+        key.clone(),
+        origin,
+        DecayKind::Lin,
+        1000.0,
+        Some(0.5),
+        Some(0.1),
+    );
+    let parsed = ParsedFormula::try_from(formula).unwrap();
+    assert_eq!(parsed.conditions.len(), 2);
+
+    // Decay parameters are validated as for any other formula
+    let formula = FormulaInternal::proximity_boost(key, origin, DecayKind::Gauss, 0.0, None, None);
+    assert!(ParsedFormula::try_from(formula).is_err());
+}
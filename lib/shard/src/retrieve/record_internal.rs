@@ -126,6 +126,7 @@ impl From<RecordInternal> for api::rest::Record {
             vector: vector.map(api::rest::VectorStructOutput::from),
             shard_key,
             order_value,
+            score: None,
         }
     }
 }
@@ -66,3 +66,42 @@ pub fn retrieve_blocking(
 
     Ok(point_records)
 }
+
+/// Latest versions of the given points in the segments
+///
+/// Only id trackers are read, payloads and vectors are not touched.
+///
+/// If an id is not found in the segments, it won't be included in the output.
+pub fn point_versions_blocking(
+    segments: LockedSegmentHolder,
+    points: &[PointIdType],
+    timeout: Duration,
+    is_stopped: &AtomicBool,
+    deferred_behavior: DeferredBehavior,
+) -> OperationResult<AHashMap<PointIdType, SeqNumberType>> {
+    let mut point_versions: AHashMap<PointIdType, SeqNumberType> = Default::default();
+
+    let include_deferred = deferred_behavior.include_all_points();
+
+    SegmentHolder::read_points_locked(&segments, points, is_stopped, timeout, |ids, segment| {
+        let mut applied = 0;
+
+        for &id in ids {
+            if !include_deferred && segment.point_is_deferred(id) {
+                continue;
+            }
+
+            let version = segment.point_version(id).ok_or_else(|| {
+                OperationError::service_error(format!("No version for point {id}"))
+            })?;
+
+            let latest_version = point_versions.entry(id).or_default();
+            *latest_version = (*latest_version).max(version);
+            applied += 1;
+        }
+
+        Ok(applied)
+    })?;
+
+    Ok(point_versions)
+}
@@ -30,7 +30,9 @@ use segment::entry::{
 };
 use segment::segment::Segment;
 use segment::segment_constructor::build_segment;
-use segment::types::{ExtendedPointId, Payload, PointIdType, SegmentConfig, SeqNumberType};
+use segment::types::{
+    ExtendedPointId, Payload, PayloadKeyType, PointIdType, SegmentConfig, SeqNumberType,
+};
 use smallvec::SmallVec;
 
 use crate::locked_segment::LockedSegment;
@@ -74,6 +76,10 @@ pub struct SegmentHolder {
 
     /// The amount of currently running optimizations.
     pub running_optimizations: ProcessCounter,
+
+    /// Stopwords detected by the shard for the full-text indexes of the fields.
+    /// Assigned to every segment, including the ones added later.
+    text_auto_stopwords: AHashMap<PayloadKeyType, Arc<AHashSet<String>>>,
 }
 
 impl Drop for SegmentHolder {
@@ -128,9 +134,7 @@ impl SegmentHolder {
     where
         T: Into<LockedSegment>,
     {
-        let segment_id = self.generate_new_key();
-        self.add_existing(segment_id, segment);
-        segment_id
+        self.add_new_locked(segment.into())
     }
 
     /// Add new segment to storage which is already LockedSegment
@@ -138,6 +142,16 @@ impl SegmentHolder {
     /// The segment gets assigned a new unique ID.
     pub fn add_new_locked(&mut self, segment: LockedSegment) -> SegmentId {
         let segment_id = self.generate_new_key();
+        // Segments added back under their existing ID already have the stopwords, and proxies
+        // wrap such segments
+        if let LockedSegment::Original(original) = &segment
+            && !self.text_auto_stopwords.is_empty()
+        {
+            let mut original = original.write();
+            for (key, stopwords) in &self.text_auto_stopwords {
+                original.set_text_auto_stopwords(key, stopwords.clone());
+            }
+        }
         self.add_existing_locked(segment_id, segment);
         segment_id
     }
@@ -239,6 +253,22 @@ impl SegmentHolder {
         Ok(removed.pop().unwrap())
     }
 
+    /// Assign the stopwords detected by the shard for the full-text index of the field to all
+    /// segments, and to the segments added later
+    pub fn set_text_auto_stopwords(
+        &mut self,
+        key: PayloadKeyType,
+        stopwords: Arc<AHashSet<String>>,
+    ) {
+        for (_, segment) in self.iter() {
+            segment
+                .get()
+                .write()
+                .set_text_auto_stopwords(&key, stopwords.clone());
+        }
+        self.text_auto_stopwords.insert(key, stopwords);
+    }
+
     pub fn get(&self, id: SegmentId) -> Option<&LockedSegment> {
         self.appendable_segments
             .get(&id)
@@ -283,6 +313,15 @@ impl SegmentHolder {
             .fetch_max(op_num, Ordering::Relaxed);
     }
 
+    /// Highest version of all segments, which is the number of the last operation applied to
+    /// any of them. No point in this holder has a higher version.
+    pub fn max_segment_version(&self) -> SeqNumberType {
+        self.iter()
+            .map(|(_, segment)| segment.get().read().version())
+            .max()
+            .unwrap_or_default()
+    }
+
     pub fn segment_ids(&self) -> Vec<SegmentId> {
         self.appendable_segments_ids()
             .into_iter()
@@ -4,8 +4,10 @@ use std::sync::atomic::AtomicBool;
 
 use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::index_build_limit::acquire_index_build_permit;
+use common::index_reconfiguration_progress::IndexReconfigurationTracker;
 use common::types::DeferredBehavior;
-use parking_lot::RwLockWriteGuard;
+use parking_lot::{RwLockUpgradableReadGuard, RwLockWriteGuard};
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::named_vectors::NamedVectors;
@@ -268,11 +270,16 @@ pub fn conditional_upsert(
         mut points_op,
         condition,
         update_mode,
+        expected_version,
     } = operation;
 
     let point_ids = points_op.point_ids();
     let update_mode = update_mode.unwrap_or_default();
 
+    if let Some(expected_version) = expected_version {
+        check_points_version(segments, &point_ids, expected_version)?;
+    }
+
     match update_mode {
         UpdateMode::Upsert => {
             // Default behavior: insert new points, update existing points that match the condition
@@ -309,6 +316,49 @@ pub fn conditional_upsert(
     Ok(upserted_points)
 }
 
+/// Check that all given points exist and their latest version is exactly `expected_version`.
+///
+/// A point, which does not exist, is a conflict too, as it has no version to match.
+///
+/// Versions are only checked against this replica, so collections reject expected versions if
+/// shards have more than one replica, see `Collection::update_from_client`.
+fn check_points_version(
+    segments: &SegmentHolder,
+    point_ids: &[PointIdType],
+    expected_version: SeqNumberType,
+) -> OperationResult<()> {
+    // Point may have multiple copies across segments, the latest one has the highest version
+    let mut versions: AHashMap<PointIdType, SeqNumberType> =
+        AHashMap::with_capacity(point_ids.len());
+    for (_segment_id, segment) in segments.iter() {
+        let segment = segment.get().read();
+        for &point_id in point_ids {
+            if let Some(version) = segment.point_version(point_id) {
+                let entry = versions.entry(point_id).or_insert(version);
+                *entry = (*entry).max(version);
+            }
+        }
+    }
+
+    for &point_id in point_ids {
+        match versions.get(&point_id) {
+            Some(&version) if version == expected_version => {}
+            Some(&version) => {
+                return Err(OperationError::version_conflict(format!(
+                    "point {point_id} has version {version}, expected {expected_version}"
+                )));
+            }
+            None => {
+                return Err(OperationError::version_conflict(format!(
+                    "point {point_id} does not exist, expected version {expected_version}"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Upsert to a point ID with the specified vectors and payload in the given segment.
 ///
 /// If the payload is None, the existing payload will be cleared.
@@ -358,11 +408,7 @@ pub fn delete_points(
         for (_segment_id, segment) in segments.iter() {
             let segment_arc = segment.get();
             let mut write_segment = segment_arc.write();
-            for &id in batch {
-                if write_segment.delete_point(op_num, id, hw_counter)? {
-                    total_deleted_points += 1;
-                }
-            }
+            total_deleted_points += write_segment.delete_points(op_num, batch, hw_counter)?;
         }
     }
 
@@ -477,17 +523,9 @@ pub fn delete_points_by_filter(
             return Ok(false);
         }
 
-        let mut deleted_in_batch = 0;
-        while let Some(point_id) = curr_points.pop() {
-            if s.delete_point(op_num, point_id, hw_counter)? {
-                total_deleted += 1;
-                deleted_in_batch += 1;
-            }
-
-            if deleted_in_batch >= DELETION_BATCH_SIZE {
-                break;
-            }
-        }
+        let batch_start = curr_points.len().saturating_sub(DELETION_BATCH_SIZE);
+        let batch: Vec<_> = curr_points.drain(batch_start..).collect();
+        total_deleted += s.delete_points(op_num, &batch, hw_counter)?;
 
         Ok(true)
     })?;
@@ -918,28 +956,78 @@ pub fn create_field_index(
         });
     };
 
+    let total_segments = segments.len();
+    let mut processed_segments = 0;
+    let mut reconfigured_segments = 0;
+
+    let is_reconfigured = |segment: &dyn SegmentEntry| {
+        segment
+            .get_indexed_fields()
+            .get(field_name)
+            .is_some_and(|current_schema| current_schema != field_schema)
+    };
+
+    // Progress is reported in telemetry, until all segments of the shard are processed
+    let reconfiguration_tracker = segments
+        .iter()
+        .any(|(_segment_id, segment)| is_reconfigured(&*segment.get().read()))
+        .then(|| IndexReconfigurationTracker::start(field_name.to_string(), total_segments));
+
     segments.apply_segments(|write_segment| {
+        // All segments before this one are processed already
+        if let Some(tracker) = &reconfiguration_tracker {
+            tracker.set_processed_segments(processed_segments);
+        }
+        processed_segments += 1;
+
+        if is_reconfigured(&**write_segment) {
+            // Switching between on-disk and in-memory mode doesn't require a rebuild, if the
+            // storage of the index allows it
+            let is_switched = write_segment.with_upgraded(|segment| {
+                segment.set_field_index_on_disk(op_num, field_name, field_schema)
+            })?;
+            let is_applied = is_switched
+                || reconfigure_field_index(
+                    write_segment,
+                    op_num,
+                    field_name,
+                    field_schema,
+                    hw_counter,
+                )?;
+            reconfigured_segments += 1;
+            log::info!(
+                "Reconfigured index of field `{field_name}` in \
+                 {reconfigured_segments}/{total_segments} segments",
+            );
+            return Ok(is_applied);
+        }
+
         write_segment.with_upgraded(|segment| {
             segment.delete_field_index_if_incompatible(op_num, field_name, field_schema)
         })?;
 
-        let (schema, indexes) =
-            match write_segment.build_field_index(op_num, field_name, field_schema, hw_counter)? {
-                BuildFieldIndexResult::SkippedByVersion => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::AlreadyExists => {
-                    return Ok(false);
-                }
-                BuildFieldIndexResult::IncompatibleSchema => {
-                    // This is a service error, as we should have just removed the old index
-                    // So it should not be possible to get this error
-                    return Err(OperationError::service_error(format!(
-                        "Incompatible schema for field index on field {field_name}",
-                    )));
-                }
-                BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
-            };
+        // Builds of other shards may be queued before this one, see `IndexBuildLimit`
+        let build_permit = acquire_index_build_permit();
+        let build_result =
+            write_segment.build_field_index(op_num, field_name, field_schema, hw_counter)?;
+        drop(build_permit);
+
+        let (schema, indexes) = match build_result {
+            BuildFieldIndexResult::SkippedByVersion => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::AlreadyExists => {
+                return Ok(false);
+            }
+            BuildFieldIndexResult::IncompatibleSchema => {
+                // This is a service error, as we should have just removed the old index
+                // So it should not be possible to get this error
+                return Err(OperationError::service_error(format!(
+                    "Incompatible schema for field index on field {field_name}",
+                )));
+            }
+            BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
+        };
 
         write_segment.with_upgraded(|segment| {
             segment.apply_field_index(op_num, field_name.to_owned(), schema, indexes)
@@ -947,6 +1035,45 @@ pub fn create_field_index(
     })
 }
 
+/// Replace the existing index of the field in the segment with one of the new schema.
+///
+/// The replacement is built next to the current index, which keeps serving queries until the
+/// replacement is swapped in. If building or swapping fails, the current index is kept.
+fn reconfigure_field_index(
+    write_segment: &mut RwLockUpgradableReadGuard<dyn SegmentEntry + 'static>,
+    op_num: SeqNumberType,
+    field_name: PayloadKeyTypeRef,
+    field_schema: &PayloadFieldSchema,
+    hw_counter: &HardwareCounterCell,
+) -> OperationResult<bool> {
+    // Builds of other shards may be queued before this one, see `IndexBuildLimit`
+    let build_permit = acquire_index_build_permit();
+    let build_result = write_segment.build_replacement_field_index(
+        op_num,
+        field_name,
+        field_schema,
+        hw_counter,
+    )?;
+    drop(build_permit);
+
+    let (schema, indexes) = match build_result {
+        BuildFieldIndexResult::SkippedByVersion => {
+            return Ok(false);
+        }
+        BuildFieldIndexResult::AlreadyExists | BuildFieldIndexResult::IncompatibleSchema => {
+            // Replacement indexes are always built from scratch
+            return Err(OperationError::service_error(format!(
+                "Failed to build replacement of field index on field {field_name}",
+            )));
+        }
+        BuildFieldIndexResult::Built { schema, indexes } => (schema, indexes),
+    };
+
+    write_segment.with_upgraded(|segment| {
+        segment.swap_field_index(op_num, field_name.to_owned(), schema, indexes)
+    })
+}
+
 pub fn delete_field_index(
     segments: &SegmentHolder,
     op_num: SeqNumberType,
@@ -1061,22 +1188,30 @@ mod test {
 
     use common::counter::hardware_counter::HardwareCounterCell;
     use parking_lot::RwLock;
+    use segment::common::operation_error::OperationError;
+    use segment::data_types::index::KeywordIndexParams;
     use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, only_default_vector};
     use segment::entry::ReadSegmentEntry as _;
     use segment::entry::entry_point::SegmentEntry as _;
     use segment::payload_json;
     use segment::types::{
-        Condition, FieldCondition, Filter, Match, MatchValue, PayloadKeyType, ValueVariants,
+        Condition, FieldCondition, Filter, Match, MatchValue, PayloadFieldSchema, PayloadKeyType,
+        PayloadSchemaParams, PayloadSchemaType, ValueVariants,
     };
     use tempfile::Builder;
 
     use crate::fixtures::{
         build_segment_1, build_segment_2, empty_segment, empty_segment_with_deferred,
     };
+    use crate::operations::point_ops::{
+        ConditionalInsertOperationInternal, PointInsertOperationsInternal, PointStructPersisted,
+        VectorStructPersisted,
+    };
     use crate::segment_holder::SegmentHolder;
     use crate::update::{
-        clear_payload_by_filter, delete_payload_by_filter, delete_points_by_filter,
-        delete_vectors_by_filter, overwrite_payload_by_filter, set_payload_by_filter,
+        clear_payload_by_filter, conditional_upsert, create_field_index, delete_payload_by_filter,
+        delete_points_by_filter, delete_vectors_by_filter, overwrite_payload_by_filter,
+        points_by_filter, set_payload_by_filter,
     };
 
     #[test]
@@ -1130,6 +1265,98 @@ mod test {
         assert_eq!(new_version, DELETE_OP_NUM);
     }
 
+    #[test]
+    fn test_reconfigure_field_index() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut holder = SegmentHolder::default();
+        holder.add_new(build_segment_1(dir.path()));
+        holder.add_new(build_segment_2(dir.path()));
+
+        let field: PayloadKeyType = "color".parse().unwrap();
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            field.clone(),
+            Match::Value(MatchValue {
+                value: ValueVariants::String("red".to_string()),
+            }),
+        )));
+
+        let schema = PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword);
+        let created = create_field_index(&holder, 100, &field, Some(&schema), &hw_counter).unwrap();
+        assert_eq!(created, 2);
+        let red_points = || {
+            let mut points = points_by_filter(&holder, &filter, &hw_counter).unwrap();
+            points.sort();
+            points
+        };
+        let indexed_red_points = red_points();
+        assert!(!indexed_red_points.is_empty());
+
+        let new_schema =
+            PayloadFieldSchema::FieldParams(PayloadSchemaParams::Keyword(KeywordIndexParams {
+                on_disk: Some(true),
+                ..Default::default()
+            }));
+        let reconfigured =
+            create_field_index(&holder, 101, &field, Some(&new_schema), &hw_counter).unwrap();
+        assert_eq!(reconfigured, 2);
+
+        for (_, segment) in holder.iter() {
+            let indexed_fields = segment.get().read().get_indexed_fields();
+            assert_eq!(indexed_fields.get(&field), Some(&new_schema));
+        }
+        assert_eq!(red_points(), indexed_red_points);
+    }
+
+    #[test]
+    fn test_conditional_upsert_expected_version() {
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut holder = SegmentHolder::default();
+        holder.add_new(build_segment_1(dir.path()));
+
+        let upsert = |op_num, point_id: u64, expected_version| {
+            let operation = ConditionalInsertOperationInternal {
+                points_op: PointInsertOperationsInternal::PointsList(vec![PointStructPersisted {
+                    id: point_id.into(),
+                    vector: VectorStructPersisted::Single(vec![1.0, 0.0, 1.0, 1.0]),
+                    payload: None,
+                }]),
+                condition: Filter::default(),
+                update_mode: None,
+                expected_version: Some(expected_version),
+            };
+            conditional_upsert(&holder, op_num, operation, &hw_counter)
+        };
+
+        // All points of the fixture have version 6, after their payload was set
+        let result = upsert(10, 1, 5);
+        assert!(matches!(
+            result,
+            Err(OperationError::VersionConflict { .. })
+        ));
+
+        // Missing point has no version to match, and is not inserted
+        let result = upsert(11, 100, 6);
+        assert!(matches!(
+            result,
+            Err(OperationError::VersionConflict { .. })
+        ));
+        assert!(holder.select_existing_points(vec![100.into()]).is_empty());
+
+        assert_eq!(upsert(12, 1, 6).unwrap(), 1);
+
+        // Version of the point moved forward with the update
+        let result = upsert(13, 1, 6);
+        assert!(matches!(
+            result,
+            Err(OperationError::VersionConflict { .. })
+        ));
+        assert_eq!(upsert(14, 1, 12).unwrap(), 1);
+    }
+
     /// Helper: creates a non-appendable segment with a single point at the given version and city payload.
     fn build_non_appendable_with_city(
         path: &std::path::Path,
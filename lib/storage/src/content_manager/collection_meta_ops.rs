@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
 use collection::config::{CollectionConfigInternal, CollectionParams, ShardingMethod};
+use collection::operations::boost_rules::BoostRulesDiff;
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
@@ -293,6 +294,10 @@ pub struct UpdateCollection {
     /// To remove metadata, set it to an empty object.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Boost rules to add, replace, or remove. Rules not mentioned are left unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub boost_rules: Option<BoostRulesDiff>,
 }
 
 /// Operation for updating parameters of the existing collection
@@ -317,6 +322,7 @@ impl UpdateCollectionOperation {
                 sparse_vectors: None,
                 strict_mode_config: None,
                 metadata: None,
+                boost_rules: None,
             },
             shard_replica_changes: None,
         }
@@ -487,6 +493,8 @@ impl From<CollectionConfigInternal> for CreateCollection {
             strict_mode_config,
             uuid,
             metadata,
+            // Not a part of the create operation, set with a separate update
+            boost_rules: _,
         } = value;
 
         let CollectionParams {
@@ -497,6 +505,7 @@ impl From<CollectionConfigInternal> for CreateCollection {
             write_consistency_factor,
             read_fan_out_factor: _,
             read_fan_out_delay_ms: _,
+            default_score_threshold: _,
             on_disk_payload,
             sparse_vectors,
         } = params;
@@ -3,7 +3,11 @@ use std::str::FromStr;
 
 use api::conversions::json;
 use api::grpc::qdrant as grpc;
+use api::grpc::transport_channel_pool::{
+    SHARD_UNAVAILABLE_METADATA_KEY, VERSION_CONFLICT_METADATA_KEY,
+};
 use chrono::{DateTime, Utc};
+use collection::operations::boost_rules::BoostRulesDiff;
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
 };
@@ -49,8 +53,17 @@ impl From<StorageError> for Status {
                 }
                 tonic::Code::ResourceExhausted
             }
-            StorageError::ShardUnavailable { .. } => tonic::Code::Unavailable,
+            StorageError::ShardUnavailable { .. } => {
+                // Lets peers tell the rejection apart from an unreachable or overloaded node
+                metadata_headers.insert(SHARD_UNAVAILABLE_METADATA_KEY, "true".to_string());
+                tonic::Code::Unavailable
+            }
             StorageError::EmptyPartialSnapshot { .. } => tonic::Code::FailedPrecondition,
+            StorageError::Conflict { .. } => {
+                // Lets peers tell the conflict apart from other aborted requests
+                metadata_headers.insert(VERSION_CONFLICT_METADATA_KEY, "true".to_string());
+                tonic::Code::Aborted
+            }
         };
         let mut status = Status::new(error_code, error.to_string());
         // add metadata headers
@@ -140,6 +153,8 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         max_points_count,
         filter_max_conditions,
         condition_max_size,
+        filter_max_selectivity,
+        filter_selectivity_warn_only,
         multivector_config,
         sparse_config,
         max_payload_index_count,
@@ -163,6 +178,8 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         max_points_count: max_points_count.map(|i| i as usize),
         filter_max_conditions: filter_max_conditions.map(|i| i as usize),
         condition_max_size: condition_max_size.map(|i| i as usize),
+        filter_max_selectivity: filter_max_selectivity.map(f64::from),
+        filter_selectivity_warn_only,
         multivector_config: multivector_config.map(StrictModeMultivectorConfig::from),
         sparse_config: sparse_config.map(StrictModeSparseConfig::from),
         max_payload_index_count: max_payload_index_count.map(|i| i as usize),
@@ -185,6 +202,7 @@ impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
             sparse_vectors_config,
             strict_mode_config,
             metadata,
+            boost_rules,
         } = value;
         Ok(Self::UpdateCollection(UpdateCollectionOperation::new(
             collection_name,
@@ -210,6 +228,7 @@ impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
                 } else {
                     Some(json::proto_to_payloads(metadata)?)
                 },
+                boost_rules: boost_rules.map(BoostRulesDiff::try_from).transpose()?,
             },
         )))
     }
@@ -46,6 +46,8 @@ pub enum StorageError {
     ShardUnavailable { description: String },
     #[error("Partial snapshot for shard {shard_id} contains no changes")]
     EmptyPartialSnapshot { shard_id: ShardId },
+    #[error("{description}")]
+    Conflict { description: String }, // conflicts with the current state, e.g. version mismatch
 }
 
 impl StorageError {
@@ -178,6 +180,9 @@ impl StorageError {
             CollectionError::ShardUnavailable { .. } => StorageError::ShardUnavailable {
                 description: overriding_description,
             },
+            CollectionError::Conflict { .. } => StorageError::Conflict {
+                description: overriding_description,
+            },
         }
     }
 }
@@ -237,6 +242,7 @@ impl From<CollectionError> for StorageError {
             CollectionError::ShardUnavailable { description } => {
                 StorageError::ShardUnavailable { description }
             }
+            CollectionError::Conflict { description } => StorageError::Conflict { description },
         }
     }
 }
@@ -138,6 +138,7 @@ pub mod consensus_ops {
                     sparse_vectors: None,
                     strict_mode_config: None,
                     metadata: None,
+                    boost_rules: None,
                 },
             );
             operation
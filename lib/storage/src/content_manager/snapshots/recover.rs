@@ -4,6 +4,7 @@ use collection::collection::Collection;
 use collection::collection::payload_index_schema::PayloadIndexSchema;
 use collection::common::sha_256::hashes_equal;
 use collection::config::CollectionConfigInternal;
+use collection::operations::boost_rules::BoostRulesDiff;
 use collection::operations::snapshot_ops::{SnapshotPriority, SnapshotRecover};
 use collection::operations::verification::new_unchecked_verification_pass;
 use collection::shards::check_shard_path;
@@ -18,6 +19,7 @@ use shard::snapshots::snapshot_manifest::RecoveryType;
 
 use crate::content_manager::collection_meta_ops::{
     CollectionMetaOperations, CreateCollectionOperation, CreatePayloadIndex,
+    UpdateCollectionOperation,
 };
 use crate::content_manager::snapshots::download::download_snapshot;
 use crate::content_manager::snapshots::download_result::DownloadResult;
@@ -209,6 +211,23 @@ async fn _do_recover_from_snapshot(
                     .await?;
             }
 
+            // Boost rules are not a part of the create operation, register them separately
+            if !snapshot_config.boost_rules.is_empty() {
+                let mut operation =
+                    UpdateCollectionOperation::new_empty(collection_pass.to_string());
+                operation.update_collection.boost_rules = Some(BoostRulesDiff {
+                    upsert: snapshot_config.boost_rules.clone(),
+                    delete: Vec::new(),
+                });
+                dispatcher
+                    .submit_collection_meta_op(
+                        CollectionMetaOperations::UpdateCollection(operation),
+                        auth.clone(),
+                        None,
+                    )
+                    .await?;
+            }
+
             toc.get_collection(&collection_pass).await?
         }
     };
@@ -156,6 +156,7 @@ impl TableOfContent {
             sparse_vectors,
             strict_mode_config: strict_mode,
             metadata,
+            boost_rules,
         } = operation.update_collection;
         let collection = self
             .get_collection_unchecked(&operation.collection_name)
@@ -199,6 +200,10 @@ impl TableOfContent {
             collection.update_metadata(metadata).await?;
         }
 
+        if let Some(diff) = boost_rules {
+            collection.update_boost_rules(diff).await?;
+        }
+
         collection.print_warnings().await;
 
         // Recreate optimizers
@@ -268,6 +273,8 @@ impl TableOfContent {
                 collection_id: collection_name.to_string(),
             });
 
+            self.reset_collection_hw_usage(collection_name);
+
             result = true;
         } else {
             // we hold the collection_create lock to make sure no one is creating this collection
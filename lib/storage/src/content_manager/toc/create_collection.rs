@@ -144,6 +144,7 @@ impl TableOfContent {
             )?,
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            default_score_threshold: None,
         };
         let wal_config = self.storage_config.wal.update_opt(wal_config_diff.as_ref());
 
@@ -198,6 +199,7 @@ impl TableOfContent {
             strict_mode_config,
             uuid,
             metadata,
+            boost_rules: Default::default(),
         };
 
         // No shard key mapping on creation, shard keys are set up after creating the collection
@@ -38,6 +38,7 @@ use dashmap::DashMap;
 use fs_err as fs;
 use fs_err::tokio as tokio_fs;
 use futures::{StreamExt, stream};
+pub use point_ops::FederatedQueryResult;
 use segment::data_types::collection_defaults::CollectionConfigDefaults;
 use tokio::runtime::{Handle, Runtime};
 use tokio::sync::{Mutex, RwLock, Semaphore};
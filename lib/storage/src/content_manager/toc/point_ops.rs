@@ -1,9 +1,12 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use collection::collection::Collection;
 use collection::collection::distance_matrix::{
     CollectionSearchMatrixRequest, CollectionSearchMatrixResponse,
+    CollectionSimilarityMatrixRequest, CollectionSimilarityMatrixResponse,
 };
+use collection::collection::federated::merge_federated_results;
 use collection::config::ShardingMethod;
 use collection::grouping::GroupBy;
 use collection::grouping::group_by::GroupRequest;
@@ -16,18 +19,30 @@ use collection::operations::{CollectionUpdateOperations, OperationWithClockTag};
 use collection::shards::shard_trait::WaitUntil;
 use collection::{discovery, recommendations};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use futures::TryStreamExt as _;
 use futures::stream::FuturesUnordered;
+use futures::{TryStreamExt as _, future};
 use segment::data_types::facets::{FacetParams, FacetResponse};
-use segment::types::{ScoredPoint, ShardKey};
+use segment::data_types::quantiles::{QuantilesParams, QuantilesResponse};
+use segment::data_types::text_query::{QueryStopwords, SnippetParams, TextCondition, TextSnippet};
+use segment::json_path::JsonPath;
+use segment::types::{PointIdType, ScoredPoint, ShardKey};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
 
 use super::TableOfContent;
 use crate::content_manager::errors::{StorageError, StorageResult};
-use crate::rbac::Auth;
 use crate::rbac::auditable_operation::AuditableOperation;
+use crate::rbac::{AccessRequirements, Auth};
+
+/// Merged results of a query over several collections
+#[derive(Debug, Default)]
+pub struct FederatedQueryResult {
+    /// Points ranked by score, with the name of their collection
+    pub points: Vec<(String, ScoredPoint)>,
+    /// Collections which failed to respond, if partial results are allowed
+    pub errors: Vec<(String, StorageError)>,
+}
 
 impl TableOfContent {
     /// Recommend points using positive and negative example from the request
@@ -230,6 +245,40 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Whether each of the requested points exists, optionally with its version.
+    pub async fn check_points_exist(
+        &self,
+        collection_name: &str,
+        request: PointsExistenceRequest,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Vec<PointExistence>> {
+        let collection_pass =
+            auth.check_point_op(collection_name, &request, "check_points_exist")?;
+
+        let PointsExistenceRequest {
+            ids,
+            with_version,
+            shard_key: _,
+        } = request;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .check_points_exist(
+                ids,
+                with_version.unwrap_or(false),
+                read_consistency,
+                &shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn group(
         &self,
@@ -387,6 +436,99 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Run the same query over several collections and merge the results by score.
+    ///
+    /// Collections must have the same vectors, otherwise the scores are not comparable.
+    /// If `allow_partial_results` is set, collections which fail to respond are reported in
+    /// the result, instead of failing the whole request.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn federated_query(
+        &self,
+        collection_names: &[String],
+        request: CollectionQueryRequest,
+        allow_partial_results: bool,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<FederatedQueryResult> {
+        if collection_names.is_empty() {
+            return Err(StorageError::bad_input(
+                "At least one collection is required",
+            ));
+        }
+
+        let mut result = FederatedQueryResult::default();
+
+        let mut collections = Vec::with_capacity(collection_names.len());
+        for collection_name in collection_names {
+            let collection_pass =
+                auth.check_point_op(collection_name, &request, "federated_query")?;
+            match self.get_collection(&collection_pass).await {
+                Ok(collection) => collections.push((collection_name, collection)),
+                Err(err) if allow_partial_results => {
+                    result.errors.push((collection_name.clone(), err));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let Some((_, reference)) = collections.first() else {
+            // This can happen only if all collections failed
+            return Err(result.errors.swap_remove(0).1);
+        };
+
+        let order = reference.federated_query_order(&request).await?;
+        for (_, collection) in &collections[1..] {
+            reference.check_federated_compatibility(collection).await?;
+        }
+
+        // Each collection may provide all the points of the requested page
+        let CollectionQueryRequest { offset, limit, .. } = request;
+        let collection_request = CollectionQueryRequest {
+            offset: 0,
+            limit: offset + limit,
+            ..request
+        };
+
+        let responses = future::join_all(collections.iter().map(|(_, collection)| {
+            collection.query_batch(
+                vec![(collection_request.clone(), ShardSelectorInternal::All)],
+                |name| self.get_collection_opt(name),
+                read_consistency,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+        }))
+        .await;
+
+        let mut sources = Vec::with_capacity(collections.len());
+        let mut results = Vec::with_capacity(collections.len());
+        for ((collection_name, _), response) in collections.into_iter().zip(responses) {
+            match response {
+                Ok(points) => {
+                    sources.push(collection_name);
+                    results.push(points.into_iter().next().unwrap_or_default());
+                }
+                Err(err) if allow_partial_results => {
+                    result.errors.push((collection_name.clone(), err.into()));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        if results.is_empty() {
+            return Err(result.errors.swap_remove(0).1);
+        }
+
+        result.points = merge_federated_results(results, order, offset, limit)
+            .into_iter()
+            .map(|(source, point)| (sources[source].clone(), point))
+            .collect();
+
+        Ok(result)
+    }
+
     // Return unique values for a payload key, and a count of points for each value.
     #[allow(clippy::too_many_arguments)]
     pub async fn facet(
@@ -415,6 +557,91 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    /// Query tokens removed as stopwords, when querying the full-text index of the field with
+    /// the `text`, read from the local replicas of the selected shards.
+    ///
+    /// Returns `None` if the field has no full-text index.
+    pub async fn text_query_stopwords(
+        &self,
+        collection_name: &str,
+        condition: &TextCondition,
+        shard_selection: &ShardSelectorInternal,
+        auth: &Auth,
+    ) -> StorageResult<Option<QueryStopwords>> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new(),
+            "text_query_stopwords",
+        )?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        let TextCondition {
+            key,
+            text,
+            language,
+        } = condition;
+        collection
+            .text_query_stopwords(key, text, *language, shard_selection)
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Excerpts of the values of the field of each of the points around the matches of the
+    /// `text`, read from the local replicas of the selected shards.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn points_text_snippets(
+        &self,
+        collection_name: &str,
+        point_ids: &[PointIdType],
+        key: &JsonPath,
+        text: &str,
+        params: SnippetParams,
+        shard_selection: &ShardSelectorInternal,
+        auth: &Auth,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> StorageResult<HashMap<PointIdType, Vec<TextSnippet>>> {
+        let collection_pass = auth.check_collection_access(
+            collection_name,
+            AccessRequirements::new(),
+            "points_text_snippets",
+        )?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .points_text_snippets(
+                point_ids,
+                key,
+                text,
+                params,
+                shard_selection,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    /// Split the values of a numeric payload key into about evenly populated buckets.
+    pub async fn quantiles(
+        &self,
+        collection_name: &str,
+        request: QuantilesParams,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+    ) -> StorageResult<QuantilesResponse> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "quantiles")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .quantiles(request, shard_selection, read_consistency, timeout)
+            .await
+            .map_err(StorageError::from)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn search_points_matrix(
         &self,
@@ -443,6 +670,34 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn similarity_matrix(
+        &self,
+        collection_name: &str,
+        request: CollectionSimilarityMatrixRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionSimilarityMatrixResponse, StorageError> {
+        let collection_pass =
+            auth.check_point_op(collection_name, &request, "similarity_matrix")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .similarity_matrix(
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
     /// # Cancel safety
     ///
     /// This method is cancel safe.
@@ -3,11 +3,13 @@
 use std::time::Duration;
 
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
-use collection::operations::types::UpdateResult;
+use collection::operations::types::{PointVersion, UpdateResult};
 use collection::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::quantiles::ValueSamples;
+use segment::types::{PayloadKeyType, PointIdType};
 
 use super::TableOfContent;
 use crate::content_manager::errors::StorageResult;
@@ -48,6 +50,39 @@ impl TableOfContent {
         Ok(res)
     }
 
+    pub async fn value_samples_internal(
+        &self,
+        collection_name: &str,
+        key: &PayloadKeyType,
+        shard_selection: ShardSelectorInternal,
+        timeout: Option<Duration>,
+    ) -> StorageResult<ValueSamples> {
+        let collection = self.get_collection_unchecked(collection_name).await?;
+
+        let res = collection
+            .value_samples(key, shard_selection, None, timeout)
+            .await?;
+
+        Ok(res)
+    }
+
+    pub async fn point_versions_internal(
+        &self,
+        collection_name: &str,
+        ids: Vec<PointIdType>,
+        shard_id: ShardId,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Vec<PointVersion>> {
+        let collection = self.get_collection_unchecked(collection_name).await?;
+
+        let res = collection
+            .point_versions_internal(ids, shard_id, timeout, hw_measurement_acc)
+            .await?;
+
+        Ok(res)
+    }
+
     pub async fn cleanup_local_shard(
         &self,
         collection_name: &str,
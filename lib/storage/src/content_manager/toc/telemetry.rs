@@ -3,10 +3,12 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Duration;
 
 use ahash::HashSet;
+use api::rest::models::HardwareUsage;
 use collection::operations::types::CollectionResult;
 use collection::telemetry::{
     CollectionSnapshotTelemetry, CollectionTelemetry, CollectionsAggregatedTelemetry,
 };
+use common::counter::hardware_data::HardwareData;
 use common::scope_tracker::{ScopeTracker, ScopeTrackerGuard};
 use common::types::TelemetryDetail;
 use dashmap::DashMap;
@@ -18,6 +20,8 @@ use crate::rbac::Access;
 #[derive(Default)]
 pub(super) struct TocTelemetryCollector {
     snapshots: DashMap<String, SnapshotTelemetryCollector>,
+    /// Hardware usage of each collection at the time of the previous telemetry read
+    hw_usage_last_read: DashMap<String, HardwareData>,
 }
 
 impl TocTelemetryCollector {
@@ -69,7 +73,9 @@ impl TableOfContent {
                 continue;
             }
             if let Ok(collection) = self.get_collection(collection_pass).await {
-                collection_telemetry.push(collection.get_telemetry_data(detail, timeout).await?);
+                let mut telemetry = collection.get_telemetry_data(detail, timeout).await?;
+                telemetry.hardware_usage = self.take_collection_hw_usage(collection_pass.name());
+                collection_telemetry.push(telemetry);
             }
         }
 
@@ -117,6 +123,67 @@ impl TableOfContent {
         Ok(result)
     }
 
+    /// Hardware usage of the collection since the previous call, `None` if nothing was measured.
+    ///
+    /// Only reads the cumulative counters, which are also exposed as metrics and therefore never
+    /// reset. Requests keep accumulating into them without any additional synchronization.
+    fn take_collection_hw_usage(&self, collection_name: &str) -> Option<HardwareUsage> {
+        let drain = self
+            .collection_hw_metrics
+            .get(collection_name)?
+            .value()
+            .clone();
+
+        // Holding the entry makes concurrent telemetry reads of the same collection sequential
+        let mut last_read = self
+            .telemetry
+            .hw_usage_last_read
+            .entry(collection_name.to_string())
+            .or_default();
+        let current = drain.hw_data();
+        let HardwareData {
+            cpu,
+            payload_io_read,
+            payload_io_write,
+            vector_io_read,
+            vector_io_write,
+            payload_index_io_read,
+            payload_index_io_write,
+        } = current.saturating_sub(*last_read);
+        *last_read = current;
+
+        Some(HardwareUsage {
+            cpu,
+            payload_io_read,
+            payload_io_write,
+            payload_index_io_read,
+            payload_index_io_write,
+            vector_io_read,
+            vector_io_write,
+        })
+    }
+
+    /// Drop the hardware usage baseline of a deleted collection.
+    ///
+    /// The cumulative counters outlive the collection, so the baseline is moved to their current
+    /// values instead of being removed. A recreated collection then only reports its own usage.
+    pub(super) fn reset_collection_hw_usage(&self, collection_name: &str) {
+        let current = self
+            .collection_hw_metrics
+            .get(collection_name)
+            .map(|drain| drain.hw_data());
+        match current {
+            Some(current) => {
+                self.telemetry
+                    .hw_usage_last_read
+                    .insert(collection_name.to_string(), current);
+            }
+            None => {
+                self.telemetry.hw_usage_last_read.remove(collection_name);
+            }
+        }
+    }
+
     pub fn max_collections(&self) -> Option<usize> {
         self.storage_config.max_collections
     }
@@ -1,18 +1,21 @@
 use std::borrow::Cow;
 
 use api::rest::{LookupLocation, SearchRequestInternal};
-use collection::collection::distance_matrix::CollectionSearchMatrixRequest;
+use collection::collection::distance_matrix::{
+    CollectionSearchMatrixRequest, CollectionSimilarityMatrixRequest,
+};
 use collection::grouping::group_by::{GroupRequest, SourceRequest};
 use collection::lookup::WithLookup;
 use collection::operations::CollectionUpdateOperations;
 use collection::operations::types::{
     CoreSearchRequest, CountRequestInternal, DiscoverRequestInternal, PointRequestInternal,
-    RecommendRequestInternal,
+    PointsExistenceRequest, RecommendRequestInternal,
 };
 use collection::operations::universal_query::collection_query::{
     CollectionPrefetch, CollectionQueryRequest,
 };
 use segment::data_types::facets::FacetParams;
+use segment::data_types::quantiles::QuantilesParams;
 use shard::scroll::ScrollRequestInternal;
 
 use super::{Access, AccessRequirements, CollectionAccessList, CollectionPass};
@@ -158,6 +161,20 @@ impl CheckableCollectionOperation for PointRequestInternal {
     }
 }
 
+impl CheckableCollectionOperation for PointsExistenceRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> Result<(), StorageError> {
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CoreSearchRequest {
     fn access_requirements(&self) -> AccessRequirements {
         AccessRequirements {
@@ -283,6 +300,20 @@ impl CheckableCollectionOperation for FacetParams {
     }
 }
 
+impl CheckableCollectionOperation for QuantilesParams {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CollectionSearchMatrixRequest {
     fn access_requirements(&self) -> AccessRequirements {
         AccessRequirements {
@@ -297,6 +328,20 @@ impl CheckableCollectionOperation for CollectionSearchMatrixRequest {
     }
 }
 
+impl CheckableCollectionOperation for CollectionSimilarityMatrixRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CollectionUpdateOperations {
     fn access_requirements(&self) -> AccessRequirements {
         match self {
@@ -758,6 +803,7 @@ mod tests_ops {
                         points_op: inner,
                         condition: filter,
                         update_mode: None,
+                        expected_version: None,
                     }),
                 );
 
@@ -14,6 +14,7 @@ use collection::operations::types::{NodeType, PeerMetadata};
 use collection::optimizers_builder::OptimizersConfig;
 use collection::shards::shard::PeerId;
 use collection::shards::transfer::ShardTransferMethod;
+use common::gridstore_index::GridstoreIndexConfig;
 use common::load_concurrency::LoadConcurrencyConfig;
 use common::low_memory::LowMemoryMode;
 use common::mmap;
@@ -57,6 +58,14 @@ pub struct PerformanceConfig {
     pub async_scorer: Option<bool>,
     #[serde(default, flatten)]
     pub load_concurrency: LoadConcurrencyConfig,
+    /// Write amplification control for mutable payload indexes stored in gridstore.
+    #[serde(default)]
+    pub gridstore_index: GridstoreIndexConfig,
+    /// Maximum number of payload field indexes to build concurrently, across all collections.
+    /// Builds beyond the limit are queued.
+    /// If not set or 0 - builds are not limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_field_index_builds: Option<usize>,
 }
 
 const fn default_io_shard_transfers_limit() -> Option<usize> {
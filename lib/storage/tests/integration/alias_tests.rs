@@ -1,77 +1,26 @@
-use std::num::NonZeroUsize;
 use std::sync::Arc;
 
-use collection::operations::vector_params_builder::VectorParamsBuilder;
 use collection::operations::verification::new_unchecked_verification_pass;
-use collection::optimizers_builder::OptimizersConfig;
 use collection::shards::channel_service::ChannelService;
 use common::budget::ResourceBudget;
-use common::load_concurrency::LoadConcurrencyConfig;
-use common::mmap;
-use segment::types::Distance;
 use storage::content_manager::collection_meta_ops::{
-    ChangeAliasesOperation, CollectionMetaOperations, CreateAlias, CreateCollection,
-    CreateCollectionOperation, DeleteAlias, RenameAlias,
+    ChangeAliasesOperation, CollectionMetaOperations, CreateAlias, DeleteAlias, RenameAlias,
 };
 use storage::content_manager::consensus::operation_sender::OperationSender;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
 use storage::rbac::{Access, AccessRequirements, Auth};
-use storage::types::{PerformanceConfig, StorageConfig};
 use tempfile::Builder;
 
+use crate::fixtures::{create_collection_operation, test_storage_config};
+
 const FULL_ACCESS: Auth = Auth::new_internal(Access::full("For test"));
 
 #[test]
 fn test_alias_operation() {
     let storage_dir = Builder::new().prefix("storage").tempdir().unwrap();
 
-    let config = StorageConfig {
-        storage_path: storage_dir.path().to_path_buf(),
-        snapshots_path: storage_dir.path().join("snapshots"),
-        snapshots_config: Default::default(),
-        temp_path: None,
-        on_disk_payload: false,
-        optimizers: OptimizersConfig {
-            deleted_threshold: 0.5,
-            vacuum_min_vector_number: 100,
-            default_segment_number: 2,
-            max_segment_size: None,
-            #[expect(deprecated)]
-            memmap_threshold: Some(100),
-            indexing_threshold: Some(100),
-            flush_interval_sec: 2,
-            max_optimization_threads: Some(2),
-            prevent_unoptimized: None,
-        },
-        optimizers_overwrite: None,
-        wal: Default::default(),
-        performance: PerformanceConfig {
-            max_search_threads: 1,
-            max_optimization_runtime_threads: 1,
-            optimizer_cpu_budget: 0,
-            optimizer_io_budget: 0,
-            update_rate_limit: None,
-            search_timeout_sec: None,
-            incoming_shard_transfers_limit: Some(1),
-            outgoing_shard_transfers_limit: Some(1),
-            async_scorer: None,
-            load_concurrency: LoadConcurrencyConfig::default(),
-        },
-        hnsw_index: Default::default(),
-        hnsw_global_config: Default::default(),
-        mmap_advice: mmap::Advice::Random,
-        low_memory_mode: Default::default(),
-        node_type: Default::default(),
-        update_queue_size: Default::default(),
-        handle_collection_load_errors: false,
-        recovery_mode: None,
-        update_concurrency: Some(NonZeroUsize::new(2).unwrap()),
-        // update_concurrency: None,
-        shard_transfer_method: None,
-        collection: None,
-        max_collections: None,
-    };
+    let config = test_storage_config(storage_dir.path());
 
     let (propose_sender, _propose_receiver) = std::sync::mpsc::channel();
     let propose_operation_sender = OperationSender::new(propose_sender);
@@ -90,36 +39,11 @@ fn test_alias_operation() {
     let dispatcher = Dispatcher::new(toc);
 
     handle
-        .block_on(
-            dispatcher.submit_collection_meta_op(
-                CollectionMetaOperations::CreateCollection(
-                    CreateCollectionOperation::new(
-                        "test".to_string(),
-                        CreateCollection {
-                            vectors: VectorParamsBuilder::new(10, Distance::Cosine)
-                                .build()
-                                .into(),
-                            sparse_vectors: None,
-                            hnsw_config: None,
-                            wal_config: None,
-                            optimizers_config: None,
-                            shard_number: Some(1),
-                            on_disk_payload: None,
-                            replication_factor: None,
-                            write_consistency_factor: None,
-                            quantization_config: None,
-                            sharding_method: None,
-                            strict_mode_config: None,
-                            uuid: None,
-                            metadata: None,
-                        },
-                    )
-                    .unwrap(),
-                ),
-                FULL_ACCESS,
-                None,
-            ),
-        )
+        .block_on(dispatcher.submit_collection_meta_op(
+            CollectionMetaOperations::CreateCollection(create_collection_operation("test")),
+            FULL_ACCESS,
+            None,
+        ))
         .unwrap();
 
     handle
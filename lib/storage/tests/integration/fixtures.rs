@@ -0,0 +1,88 @@
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use collection::operations::vector_params_builder::VectorParamsBuilder;
+use collection::optimizers_builder::OptimizersConfig;
+use common::load_concurrency::LoadConcurrencyConfig;
+use common::mmap;
+use segment::types::Distance;
+use storage::content_manager::collection_meta_ops::{CreateCollection, CreateCollectionOperation};
+use storage::types::{PerformanceConfig, StorageConfig};
+
+pub fn test_storage_config(storage_path: &Path) -> StorageConfig {
+    StorageConfig {
+        storage_path: storage_path.to_path_buf(),
+        snapshots_path: storage_path.join("snapshots"),
+        snapshots_config: Default::default(),
+        temp_path: None,
+        on_disk_payload: false,
+        optimizers: OptimizersConfig {
+            deleted_threshold: 0.5,
+            vacuum_min_vector_number: 100,
+            default_segment_number: 2,
+            max_segment_size: None,
+            #[expect(deprecated)]
+            memmap_threshold: Some(100),
+            indexing_threshold: Some(100),
+            flush_interval_sec: 2,
+            max_optimization_threads: Some(2),
+            prevent_unoptimized: None,
+            requantization: None,
+        },
+        optimizers_overwrite: None,
+        wal: Default::default(),
+        performance: PerformanceConfig {
+            max_search_threads: 1,
+            max_optimization_runtime_threads: 1,
+            optimizer_cpu_budget: 0,
+            optimizer_io_budget: 0,
+            update_rate_limit: None,
+            search_timeout_sec: None,
+            incoming_shard_transfers_limit: Some(1),
+            outgoing_shard_transfers_limit: Some(1),
+            async_scorer: None,
+            load_concurrency: LoadConcurrencyConfig::default(),
+            gridstore_index: Default::default(),
+            max_concurrent_field_index_builds: None,
+        },
+        hnsw_index: Default::default(),
+        hnsw_global_config: Default::default(),
+        mmap_advice: mmap::Advice::Random,
+        low_memory_mode: Default::default(),
+        node_type: Default::default(),
+        update_queue_size: Default::default(),
+        handle_collection_load_errors: false,
+        recovery_mode: None,
+        update_concurrency: Some(NonZeroUsize::new(2).unwrap()),
+        // update_concurrency: None,
+        shard_transfer_method: None,
+        collection: None,
+        max_collections: None,
+    }
+}
+
+/// Create a collection with a single shard of 10-dimensional vectors
+pub fn create_collection_operation(collection_name: &str) -> CreateCollectionOperation {
+    CreateCollectionOperation::new(
+        collection_name.to_string(),
+        CreateCollection {
+            vectors: VectorParamsBuilder::new(10, Distance::Cosine)
+                .build()
+                .into(),
+            sparse_vectors: None,
+            hnsw_config: None,
+            wal_config: None,
+            optimizers_config: None,
+            shard_number: Some(1),
+            on_disk_payload: None,
+            replication_factor: None,
+            write_consistency_factor: None,
+            quantization_config: None,
+            sharding_method: None,
+            strict_mode_config: None,
+            uuid: None,
+            metadata: None,
+        },
+    )
+    .unwrap()
+}
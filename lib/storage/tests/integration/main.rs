@@ -1 +1,3 @@
 mod alias_tests;
+mod fixtures;
+mod telemetry_tests;
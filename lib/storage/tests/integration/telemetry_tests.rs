@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use collection::shards::channel_service::ChannelService;
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::counter::hardware_data::HardwareData;
+use common::types::{DetailsLevel, TelemetryDetail};
+use storage::content_manager::collection_meta_ops::{
+    CollectionMetaOperations, DeleteCollectionOperation,
+};
+use storage::content_manager::consensus::operation_sender::OperationSender;
+use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{Access, Auth};
+use tempfile::Builder;
+
+use crate::fixtures::{create_collection_operation, test_storage_config};
+
+const FULL_ACCESS: Auth = Auth::new_internal(Access::full("For test"));
+
+#[test]
+fn test_collection_hw_usage_after_recreation() {
+    let storage_dir = Builder::new().prefix("storage").tempdir().unwrap();
+
+    let config = test_storage_config(storage_dir.path());
+
+    let (propose_sender, _propose_receiver) = std::sync::mpsc::channel();
+    let propose_operation_sender = OperationSender::new(propose_sender);
+
+    let toc = Arc::new(
+        TableOfContent::new(
+            &config,
+            ResourceBudget::default(),
+            ChannelService::new(6333, false, None, None),
+            0,
+            Some(propose_operation_sender),
+        )
+        .unwrap(),
+    );
+    let handle = toc.general_runtime_handle().clone();
+    let dispatcher = Dispatcher::new(toc.clone());
+
+    let submit = |operation| {
+        handle
+            .block_on(dispatcher.submit_collection_meta_op(operation, FULL_ACCESS, None))
+            .unwrap();
+    };
+    let use_cpu = |cpu| {
+        HwMeasurementAcc::new_with_metrics_drain(toc.get_collection_hw_metrics("test".to_string()))
+            .accumulate(HardwareData {
+                cpu,
+                ..Default::default()
+            });
+    };
+    let is_stopped = AtomicBool::new(false);
+    let read_cpu = || {
+        let telemetry = handle
+            .block_on(toc.get_telemetry_data(
+                TelemetryDetail::new(DetailsLevel::Level2, false),
+                FULL_ACCESS.access("For test"),
+                None,
+                Duration::from_secs(10),
+                &is_stopped,
+            ))
+            .unwrap();
+        let [collection] = telemetry.collection_telemetry.as_slice() else {
+            panic!("expected telemetry of a single collection");
+        };
+        collection.hardware_usage.as_ref().unwrap().cpu
+    };
+
+    submit(CollectionMetaOperations::CreateCollection(
+        create_collection_operation("test"),
+    ));
+
+    use_cpu(10);
+    assert_eq!(read_cpu(), 10);
+    // Usage is reported since the previous read
+    use_cpu(5);
+    assert_eq!(read_cpu(), 5);
+
+    // Usage of the deleted collection is not reported for the recreated one
+    use_cpu(7);
+    submit(CollectionMetaOperations::DeleteCollection(
+        DeleteCollectionOperation("test".to_string()),
+    ));
+    submit(CollectionMetaOperations::CreateCollection(
+        create_collection_operation("test"),
+    ));
+    assert_eq!(read_cpu(), 0);
+
+    use_cpu(3);
+    assert_eq!(read_cpu(), 3);
+}
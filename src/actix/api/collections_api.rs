@@ -4,23 +4,31 @@ use actix_web::rt::time::Instant;
 use actix_web::{HttpResponse, Responder, delete, get, patch, post, put, web};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::cluster_ops::ClusterOperations;
-use collection::operations::types::CollectionError;
+use collection::operations::types::{
+    CollectionError, TokenizeRequest, ValidateFilterRequest, WarmupRequest,
+};
 use collection::operations::verification::new_unchecked_verification_pass;
+use segment::types::PointIdType;
 use serde::Deserialize;
 use shard::operations::optimization::OptimizationsRequestOptions;
 use storage::content_manager::collection_meta_ops::{
     ChangeAliasesOperation, CollectionMetaOperations, CreateCollection, CreateCollectionOperation,
     DeleteCollectionOperation, UpdateCollection, UpdateCollectionOperation,
 };
+use storage::content_manager::errors::StorageError;
 use storage::dispatcher::Dispatcher;
 use storage::rbac::AccessRequirements;
 use validator::Validate;
 
 use super::CollectionPath;
+use super::update_api::FieldPath;
 use crate::actix::api::StrictCollectionPath;
 use crate::actix::auth::ActixAuth;
-use crate::actix::helpers::{self, process_response};
+use crate::actix::helpers::{
+    self, get_request_hardware_counter, process_response, process_response_error,
+};
 use crate::common::collections::*;
+use crate::settings::ServiceConfig;
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct WaitTimeout {
@@ -108,6 +116,131 @@ async fn get_collection_aliases(
     .await
 }
 
+#[post("/collections/{collection_name}/filter/validate")]
+async fn validate_filter(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<ValidateFilterRequest>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    // Filter is not executed, nothing to verify
+    let pass = new_unchecked_verification_pass();
+
+    let ValidateFilterRequest { filter } = request.into_inner();
+
+    helpers::time(do_validate_filter(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.collection_name,
+        &filter,
+    ))
+    .await
+}
+
+#[get("/collections/{collection_name}/index/recommendations")]
+async fn get_index_recommendations(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    // No request to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(do_get_index_recommendations(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.collection_name,
+    ))
+    .await
+}
+
+#[post("/collections/{collection_name}/index/{field_name}/tokenize")]
+async fn tokenize_text(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    field: Path<FieldPath>,
+    request: Json<TokenizeRequest>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    // Text is only tokenized, nothing to verify
+    let pass = new_unchecked_verification_pass();
+
+    let TokenizeRequest { text } = request.into_inner();
+
+    helpers::time(do_tokenize_text(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.collection_name,
+        &field.name,
+        &text,
+    ))
+    .await
+}
+
+#[derive(Deserialize, Validate)]
+struct PointPath {
+    #[validate(length(min = 1))]
+    id: String,
+}
+
+#[get("/collections/{collection_name}/index/{field_name}/points/{id}/tokens")]
+async fn get_point_tokens(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    field: Path<FieldPath>,
+    point: Path<PointPath>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // Tokens are only read, nothing to verify
+    let pass = new_unchecked_verification_pass();
+
+    let Ok(point_id) = point.id.parse::<PointIdType>() else {
+        let err =
+            StorageError::bad_input(format!("Can not recognize \"{}\" as point id", point.id));
+        return process_response_error(err, Instant::now(), None);
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.collection_name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let result = do_get_point_tokens(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.collection_name,
+        &field.name,
+        point_id,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(result, timing, request_hw_counter.to_rest_api())
+}
+
+#[post("/collections/{collection_name}/warmup")]
+async fn warmup_collection(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<WarmupRequest>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    // Indexes are only loaded into the page cache, nothing to verify
+    let pass = new_unchecked_verification_pass();
+
+    helpers::time(do_warmup_collection(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.collection_name,
+        request.into_inner(),
+    ))
+    .await
+}
+
 #[put("/collections/{collection_name}")]
 async fn create_collection(
     dispatcher: web::Data<Dispatcher>,
@@ -327,6 +460,11 @@ pub fn config_collections_api(cfg: &mut web::ServiceConfig) {
         .service(get_collections)
         .service(get_collection)
         .service(get_collection_existence)
+        .service(validate_filter)
+        .service(get_index_recommendations)
+        .service(tokenize_text)
+        .service(get_point_tokens)
+        .service(warmup_collection)
         .service(create_collection)
         .service(update_collection)
         .service(delete_collection)
@@ -33,9 +33,13 @@ async fn update_debugger_config(
 mod staging {
     use collection::operations::verification;
     use collection::shards::shard::ShardId;
-    use segment::types::SeqNumberType;
+    use segment::data_types::index::Language;
+    use segment::data_types::text_query::{QueryStopwords, SnippetParams};
+    use segment::json_path::JsonPath;
+    use segment::types::{PointIdType, SeqNumberType};
     use serde::{Deserialize, Serialize};
     use shard::operations::OperationWithClockTag;
+    use storage::content_manager::errors::StorageError;
     use storage::dispatcher::Dispatcher;
 
     use super::*;
@@ -124,6 +128,233 @@ mod staging {
         })
         .await
     }
+
+    /// Offsets of the query tokens of the `text` in the values of the field of the point, for
+    /// highlighting the matches.
+    #[get("/collections/{collection_name}/shards/{shard}/points/{id}/text_matches")]
+    pub async fn get_shard_point_text_matches(
+        dispatcher: web::Data<Dispatcher>,
+        path: web::Path<(String, ShardId, String)>,
+        query: web::Query<GetPointTextMatchesQuery>,
+        ActixAuth(auth): ActixAuth,
+    ) -> impl Responder {
+        helpers::time(async move {
+            let (collection, shard, point_id) = path.into_inner();
+            let GetPointTextMatchesQuery { key, text } = query.into_inner();
+
+            let Ok(point_id) = point_id.parse::<PointIdType>() else {
+                return Err(StorageError::bad_input(format!(
+                    "Can not recognize \"{point_id}\" as point id",
+                )));
+            };
+
+            let pass = verification::new_unchecked_verification_pass();
+            let collection_pass = auth.check_collection_access(
+                &collection,
+                AccessRequirements::new().write().manage().extras(),
+                "get_shard_point_text_matches",
+            )?;
+
+            let request_hw_counter =
+                helpers::get_request_hardware_counter(&dispatcher, collection.clone(), false, None);
+
+            let matches = dispatcher
+                .toc(&auth, &pass)
+                .get_collection(&collection_pass)
+                .await?
+                .get_shard_point_text_match_offsets(
+                    shard,
+                    point_id,
+                    &key,
+                    &text,
+                    &request_hw_counter.get_counter(),
+                )
+                .await?;
+
+            matches.ok_or_else(|| {
+                StorageError::bad_input(format!("Field `{key}` has no full-text index"))
+            })
+        })
+        .await
+    }
+
+    #[derive(Deserialize)]
+    struct GetPointTextMatchesQuery {
+        key: JsonPath,
+        text: String,
+    }
+
+    /// Excerpts of the values of the field of the point around the matches of the `text`, with
+    /// the query tokens highlighted.
+    #[get("/collections/{collection_name}/shards/{shard}/points/{id}/text_snippets")]
+    pub async fn get_shard_point_text_snippets(
+        dispatcher: web::Data<Dispatcher>,
+        path: web::Path<(String, ShardId, String)>,
+        query: web::Query<GetPointTextSnippetsQuery>,
+        ActixAuth(auth): ActixAuth,
+    ) -> impl Responder {
+        helpers::time(async move {
+            let (collection, shard, point_id) = path.into_inner();
+            let GetPointTextSnippetsQuery {
+                key,
+                text,
+                length,
+                count,
+            } = query.into_inner();
+
+            let Ok(point_id) = point_id.parse::<PointIdType>() else {
+                return Err(StorageError::bad_input(format!(
+                    "Can not recognize \"{point_id}\" as point id",
+                )));
+            };
+
+            if length == Some(0) || count == Some(0) {
+                return Err(StorageError::bad_input(
+                    "`length` and `count` must be greater than 0",
+                ));
+            }
+            let params = SnippetParams {
+                length: length.unwrap_or(SnippetParams::DEFAULT_LENGTH),
+                count: count.unwrap_or(SnippetParams::DEFAULT_COUNT),
+            };
+
+            let pass = verification::new_unchecked_verification_pass();
+            let collection_pass = auth.check_collection_access(
+                &collection,
+                AccessRequirements::new().write().manage().extras(),
+                "get_shard_point_text_snippets",
+            )?;
+
+            let request_hw_counter =
+                helpers::get_request_hardware_counter(&dispatcher, collection.clone(), false, None);
+
+            let snippets = dispatcher
+                .toc(&auth, &pass)
+                .get_collection(&collection_pass)
+                .await?
+                .get_shard_point_text_snippets(
+                    shard,
+                    point_id,
+                    &key,
+                    &text,
+                    params,
+                    &request_hw_counter.get_counter(),
+                )
+                .await?;
+
+            snippets.ok_or_else(|| {
+                StorageError::bad_input(format!("Field `{key}` has no full-text index"))
+            })
+        })
+        .await
+    }
+
+    #[derive(Deserialize)]
+    struct GetPointTextSnippetsQuery {
+        key: JsonPath,
+        text: String,
+        length: Option<usize>,
+        count: Option<usize>,
+    }
+
+    #[get("/collections/{collection_name}/shards/{shard}/stopwords")]
+    pub async fn get_shard_text_stopwords(
+        dispatcher: web::Data<Dispatcher>,
+        path: web::Path<(String, ShardId)>,
+        query: web::Query<GetTextStopwordsQuery>,
+        ActixAuth(auth): ActixAuth,
+    ) -> impl Responder {
+        helpers::time(async move {
+            let (collection, shard) = path.into_inner();
+            let GetTextStopwordsQuery { key } = query.into_inner();
+
+            let pass = verification::new_unchecked_verification_pass();
+            let collection_pass = auth.check_collection_access(
+                &collection,
+                AccessRequirements::new().write().manage().extras(),
+                "get_shard_text_stopwords",
+            )?;
+
+            let stopwords = dispatcher
+                .toc(&auth, &pass)
+                .get_collection(&collection_pass)
+                .await?
+                .get_shard_text_auto_stopwords(shard, &key)
+                .await?;
+
+            stopwords.ok_or_else(|| {
+                StorageError::bad_input(format!("Field `{key}` has no full-text index"))
+            })
+        })
+        .await
+    }
+
+    #[derive(Deserialize)]
+    struct GetTextStopwordsQuery {
+        key: JsonPath,
+    }
+
+    /// Query tokens removed as stopwords, when querying the full-text index of the field with
+    /// the `text`. Warns, if no query tokens are left.
+    #[get("/collections/{collection_name}/shards/{shard}/text_query_stopwords")]
+    pub async fn get_shard_text_query_stopwords(
+        dispatcher: web::Data<Dispatcher>,
+        path: web::Path<(String, ShardId)>,
+        query: web::Query<GetTextQueryStopwordsQuery>,
+        ActixAuth(auth): ActixAuth,
+    ) -> impl Responder {
+        helpers::time(async move {
+            let (collection, shard) = path.into_inner();
+            let GetTextQueryStopwordsQuery {
+                key,
+                text,
+                language,
+            } = query.into_inner();
+
+            let pass = verification::new_unchecked_verification_pass();
+            let collection_pass = auth.check_collection_access(
+                &collection,
+                AccessRequirements::new().write().manage().extras(),
+                "get_shard_text_query_stopwords",
+            )?;
+
+            let stopwords = dispatcher
+                .toc(&auth, &pass)
+                .get_collection(&collection_pass)
+                .await?
+                .get_shard_text_query_stopwords(shard, &key, &text, language)
+                .await?;
+
+            let Some(stopwords) = stopwords else {
+                return Err(StorageError::bad_input(format!(
+                    "Field `{key}` has no full-text index",
+                )));
+            };
+
+            #[derive(Serialize)]
+            struct TextQueryStopwords {
+                #[serde(flatten)]
+                stopwords: QueryStopwords,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                warning: Option<String>,
+            }
+
+            let warning = stopwords.is_empty.then(|| {
+                "All query tokens were removed as stopwords, the query matches no points"
+                    .to_string()
+            });
+
+            Ok(TextQueryStopwords { stopwords, warning })
+        })
+        .await
+    }
+
+    #[derive(Deserialize)]
+    struct GetTextQueryStopwordsQuery {
+        key: JsonPath,
+        text: String,
+        language: Option<Language>,
+    }
 }
 
 // Configure services
@@ -133,5 +364,9 @@ pub fn config_debugger_api(cfg: &mut web::ServiceConfig) {
 
     #[cfg(feature = "staging")]
     cfg.service(staging::get_shard_wal)
-        .service(staging::get_shard_recovery_point);
+        .service(staging::get_shard_recovery_point)
+        .service(staging::get_shard_point_text_matches)
+        .service(staging::get_shard_point_text_snippets)
+        .service(staging::get_shard_text_stopwords)
+        .service(staging::get_shard_text_query_stopwords);
 }
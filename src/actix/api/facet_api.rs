@@ -1,8 +1,11 @@
 use actix_web::{Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
-use api::rest::{FacetRequest, FacetResponse};
+use api::rest::{FacetRequest, FacetResponse, QuantilesRequest};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
-use storage::content_manager::collection_verification::check_strict_mode;
+use segment::data_types::quantiles::QuantilesParams;
+use storage::content_manager::collection_verification::{
+    check_strict_mode, check_strict_mode_timeout,
+};
 use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
 
@@ -74,6 +77,59 @@ async fn facet(
     process_response(response, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{collection_name}/points/quantiles")]
+async fn quantiles(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<QuantilesRequest>,
+    params: Query<ReadParams>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let pass = match check_strict_mode_timeout(
+        params.timeout_as_secs(),
+        &collection.collection_name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, timing, None),
+    };
+
+    let QuantilesRequest {
+        key,
+        buckets,
+        shard_key,
+    } = request.into_inner();
+
+    let quantiles_params = QuantilesParams {
+        key,
+        buckets: buckets.unwrap_or(QuantilesParams::DEFAULT_BUCKETS),
+    };
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let response = dispatcher
+        .toc(&auth, &pass)
+        .quantiles(
+            &collection.collection_name,
+            quantiles_params,
+            shard_selection,
+            params.consistency,
+            auth,
+            params.timeout(),
+        )
+        .await;
+
+    process_response(response, timing, None)
+}
+
 pub fn config_facet_api(cfg: &mut web::ServiceConfig) {
-    cfg.service(facet);
+    cfg.service(facet).service(quantiles);
 }
@@ -1,7 +1,12 @@
 use actix_web::{Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
 use api::rest::models::InferenceUsage;
-use api::rest::{QueryGroupsRequest, QueryRequest, QueryRequestBatch, QueryResponse};
+use api::rest::{
+    FederatedQueryError, FederatedQueryRequest, FederatedQueryResponse, FederatedScoredPoint,
+    MAX_QUERY_NEIGHBORS_SEEDS, PointNeighbors, PointProvenance, PointSnippets, QueryDebugInfo,
+    QueryFacetResponse, QueryGroupsRequest, QueryRequest, QueryRequestBatch, QueryResponse,
+    QueryStats, ScoreExplanation, TextConditionStopwords,
+};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use itertools::Itertools;
 use storage::content_manager::collection_verification::{
@@ -12,7 +17,7 @@ use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
 
 use super::CollectionPath;
-use super::read_params::ReadParams;
+use super::read_params::{ReadParams, ScoreParams};
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter};
 use crate::common::inference::api_keys::InferenceApiKeys;
@@ -21,7 +26,9 @@ use crate::common::inference::query_requests_rest::{
     CollectionQueryGroupsRequestWithUsage, CollectionQueryRequestWithUsage,
     convert_query_groups_request_from_rest, convert_query_request_from_rest,
 };
-use crate::common::query::do_query_point_groups;
+use crate::common::query::{
+    do_query_facets, do_query_neighbors, do_query_point_groups, do_query_text_stopwords,
+};
 use crate::settings::ServiceConfig;
 
 #[cfg(test)]
@@ -34,15 +41,30 @@ async fn query_points(
     collection: Path<CollectionPath>,
     request: Json<QueryRequest>,
     params: Query<ReadParams>,
+    score_params: Query<ScoreParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
     api_keys: InferenceApiKeys,
 ) -> impl Responder {
     let QueryRequest {
-        internal: query_request,
+        internal: mut query_request,
         shard_key,
     } = request.into_inner();
 
+    let facets: Vec<_> = query_request
+        .facets
+        .take()
+        .into_iter()
+        .flatten()
+        .map(|facet| facet.into_facet_params(query_request.filter.clone()))
+        .collect();
+
+    let with_provenance = query_request.with_provenance.unwrap_or(false);
+    let with_explanation = query_request.with_explanation.unwrap_or(false);
+    let with_stopwords = query_request.with_stopwords.unwrap_or(false);
+    let snippets = query_request.snippets.take();
+    let neighbors = query_request.neighbors.take();
+
     let request_hw_counter = get_request_hardware_counter(
         &dispatcher,
         collection.collection_name.clone(),
@@ -75,26 +97,211 @@ async fn query_points(
         )
         .await?;
 
-        let points = dispatcher
-            .toc(&auth, &pass)
-            .query_batch(
+        for facet in &facets {
+            check_strict_mode(
+                facet,
+                params.timeout_as_secs(),
                 &collection.collection_name,
-                vec![(request, shard_selection)],
+                &dispatcher,
+                &auth,
+            )
+            .await?;
+        }
+
+        let toc = dispatcher.toc(&auth, &pass);
+
+        // Neighbors are queried with the options of the request, once its points are known
+        let neighbors_request = neighbors.is_some().then(|| request.clone());
+
+        let text_conditions = if with_stopwords || snippets.is_some() {
+            request.text_conditions()
+        } else {
+            Vec::new()
+        };
+
+        let snippets_text = snippets
+            .as_ref()
+            .map(|snippets| {
+                snippets
+                    .text
+                    .clone()
+                    .or_else(|| {
+                        text_conditions
+                            .iter()
+                            .find(|condition| condition.key == snippets.key)
+                            .map(|condition| condition.text.clone())
+                    })
+                    .ok_or_else(|| {
+                        StorageError::bad_input(format!(
+                            "No text to match for snippets of `{}`, \
+                             set `text` or filter by a full-text condition on the key",
+                            snippets.key,
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        let stopwords_response = async {
+            if !with_stopwords {
+                return Ok(None);
+            }
+            do_query_text_stopwords(
+                toc,
+                &collection.collection_name,
+                text_conditions,
+                &shard_selection,
+                &auth,
+            )
+            .await
+            .map(Some)
+        };
+
+        let facets_response = async {
+            if facets.is_empty() {
+                return Ok(None);
+            }
+            do_query_facets(
+                toc,
+                &collection.collection_name,
+                facets,
                 params.consistency,
-                auth,
+                shard_selection.clone(),
+                auth.clone(),
                 params.timeout(),
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             )
-            .await?
-            .pop()
-            .ok_or_else(|| {
-                StorageError::service_error("Expected at least one response for one query")
-            })?
+            .await
+            .map(Some)
+        };
+
+        let points_response = toc.query_batch(
+            &collection.collection_name,
+            vec![(request, shard_selection.clone())],
+            params.consistency,
+            auth.clone(),
+            params.timeout(),
+            hw_measurement_acc.clone(),
+        );
+
+        let (points, facets, stopwords) =
+            tokio::try_join!(points_response, facets_response, stopwords_response)?;
+
+        let points = points.into_iter().next().ok_or_else(|| {
+            StorageError::service_error("Expected at least one response for one query")
+        })?;
+
+        let explanation = with_explanation.then(|| {
+            points
+                .iter()
+                .map(ScoreExplanation::from_scored_point)
+                .collect()
+        });
+
+        let provenance = with_provenance.then(|| {
+            points
+                .iter()
+                .filter_map(PointProvenance::from_scored_point)
+                .collect()
+        });
+
+        let snippets = match (&snippets, snippets_text) {
+            (Some(snippets), Some(text)) => {
+                let point_ids = points.iter().map(|point| point.id).collect_vec();
+                let mut point_snippets = toc
+                    .points_text_snippets(
+                        &collection.collection_name,
+                        &point_ids,
+                        &snippets.key,
+                        &text,
+                        snippets.snippet_params(),
+                        &shard_selection,
+                        &auth,
+                        &hw_measurement_acc,
+                    )
+                    .await?;
+                let snippets = point_ids
+                    .into_iter()
+                    .map(|id| PointSnippets {
+                        id,
+                        snippets: point_snippets.remove(&id).unwrap_or_default(),
+                    })
+                    .collect();
+                Some(snippets)
+            }
+            _ => None,
+        };
+
+        let neighbors = match (&neighbors, &neighbors_request) {
+            (Some(neighbors), Some(neighbors_request)) => {
+                let seeds = neighbors.seeds.unwrap_or(MAX_QUERY_NEIGHBORS_SEEDS);
+                let seed_ids = points
+                    .iter()
+                    .take(seeds)
+                    .map(|point| point.id)
+                    .collect_vec();
+                let seed_neighbors = do_query_neighbors(
+                    toc,
+                    &collection.collection_name,
+                    neighbors_request,
+                    &seed_ids,
+                    neighbors.limit,
+                    params.consistency,
+                    &shard_selection,
+                    auth.clone(),
+                    params.timeout(),
+                    hw_measurement_acc.clone(),
+                )
+                .await?;
+                let neighbors = seed_ids
+                    .into_iter()
+                    .zip(seed_neighbors)
+                    .map(|(id, neighbors)| {
+                        let mut neighbors = neighbors
+                            .into_iter()
+                            .map(api::rest::ScoredPoint::from)
+                            .collect_vec();
+                        helpers::round_scores(&mut neighbors, score_params.score_precision);
+                        PointNeighbors { id, neighbors }
+                    })
+                    .collect();
+                Some(neighbors)
+            }
+            _ => None,
+        };
+
+        let mut points = points
             .into_iter()
             .map(api::rest::ScoredPoint::from)
             .collect_vec();
-
-        Ok(QueryResponse { points })
+        helpers::round_scores(&mut points, score_params.score_precision);
+
+        let facets =
+            facets.map(|facets| facets.into_iter().map(QueryFacetResponse::from).collect());
+
+        let stopwords = stopwords.map(|stopwords| {
+            stopwords
+                .into_iter()
+                .map(TextConditionStopwords::from)
+                .collect()
+        });
+
+        let debug = (provenance.is_some() || explanation.is_some() || stopwords.is_some())
+            .then_some(QueryDebugInfo {
+                provenance,
+                explanation,
+                stopwords,
+            });
+
+        let stats = QueryStats::from(&hw_measurement_acc.query_stats()).into_non_empty();
+
+        Ok(QueryResponse {
+            points,
+            facets,
+            snippets,
+            neighbors,
+            debug,
+            stats,
+        })
     }
     .await;
 
@@ -113,6 +320,7 @@ async fn query_points_batch(
     collection: Path<CollectionPath>,
     request: Json<QueryRequestBatch>,
     params: Query<ReadParams>,
+    score_params: Query<ScoreParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
     api_keys: InferenceApiKeys,
@@ -141,6 +349,42 @@ async fn query_points_batch(
                 shard_key,
             } = request_item;
 
+            if internal.facets.is_some() {
+                return Err(StorageError::bad_input(
+                    "Facets are not supported in batch queries",
+                ));
+            }
+
+            if internal.with_provenance == Some(true) {
+                return Err(StorageError::bad_input(
+                    "Provenance is not supported in batch queries",
+                ));
+            }
+
+            if internal.with_explanation == Some(true) {
+                return Err(StorageError::bad_input(
+                    "Score explanation is not supported in batch queries",
+                ));
+            }
+
+            if internal.with_stopwords == Some(true) {
+                return Err(StorageError::bad_input(
+                    "Stopwords are not supported in batch queries",
+                ));
+            }
+
+            if internal.snippets.is_some() {
+                return Err(StorageError::bad_input(
+                    "Snippets are not supported in batch queries",
+                ));
+            }
+
+            if internal.neighbors.is_some() {
+                return Err(StorageError::bad_input(
+                    "Neighbors are not supported in batch queries",
+                ));
+            }
+
             let CollectionQueryRequestWithUsage { request, usage } =
                 convert_query_request_from_rest(internal, &inference_params).await?;
 
@@ -176,11 +420,20 @@ async fn query_points_batch(
             )
             .await?
             .into_iter()
-            .map(|response| QueryResponse {
-                points: response
+            .map(|response| {
+                let mut points = response
                     .into_iter()
                     .map(api::rest::ScoredPoint::from)
-                    .collect_vec(),
+                    .collect_vec();
+                helpers::round_scores(&mut points, score_params.score_precision);
+                QueryResponse {
+                    points,
+                    facets: None,
+                    snippets: None,
+                    neighbors: None,
+                    debug: None,
+                    stats: None,
+                }
             })
             .collect_vec();
         Ok(res)
@@ -202,6 +455,7 @@ async fn query_points_groups(
     collection: Path<CollectionPath>,
     request: Json<QueryGroupsRequest>,
     params: Query<ReadParams>,
+    score_params: Query<ScoreParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
     api_keys: InferenceApiKeys,
@@ -242,7 +496,7 @@ async fn query_points_groups(
         )
         .await?;
 
-        let query_result = do_query_point_groups(
+        let mut query_result = do_query_point_groups(
             dispatcher.toc(&auth, &pass),
             &collection.collection_name,
             request,
@@ -253,6 +507,11 @@ async fn query_points_groups(
             hw_measurement_acc,
         )
         .await?;
+        let hits = query_result
+            .groups
+            .iter_mut()
+            .flat_map(|group| &mut group.hits);
+        helpers::round_scores(hits, score_params.score_precision);
         Ok(query_result)
     }
     .await;
@@ -265,8 +524,161 @@ async fn query_points_groups(
     )
 }
 
+#[post("/collections/query/federated")]
+async fn query_points_federated(
+    dispatcher: web::Data<Dispatcher>,
+    request: Json<FederatedQueryRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+    api_keys: InferenceApiKeys,
+) -> impl Responder {
+    let FederatedQueryRequest {
+        collections,
+        internal,
+        allow_partial_results,
+    } = request.into_inner();
+
+    let collection_names = collections.into_iter().unique().collect_vec();
+    let allow_partial_results = allow_partial_results.unwrap_or(false);
+
+    // Hardware usage of all collections is reported to the metrics of the first one
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection_names.first().cloned().unwrap_or_default(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+    let hw_measurement_acc = request_hw_counter.get_counter();
+    let mut inference_usage = InferenceUsage::default();
+
+    let inference_params = InferenceParams::new(api_keys, params.timeout());
+
+    let result = async {
+        if internal.facets.is_some() {
+            return Err(StorageError::bad_input(
+                "Facets are not supported in federated queries",
+            ));
+        }
+
+        if internal.with_provenance == Some(true) {
+            return Err(StorageError::bad_input(
+                "Provenance is not supported in federated queries",
+            ));
+        }
+
+        if internal.with_explanation == Some(true) {
+            return Err(StorageError::bad_input(
+                "Score explanation is not supported in federated queries",
+            ));
+        }
+
+        if internal.with_stopwords == Some(true) {
+            return Err(StorageError::bad_input(
+                "Stopwords are not supported in federated queries",
+            ));
+        }
+
+        if internal.snippets.is_some() {
+            return Err(StorageError::bad_input(
+                "Snippets are not supported in federated queries",
+            ));
+        }
+
+        if internal.neighbors.is_some() {
+            return Err(StorageError::bad_input(
+                "Neighbors are not supported in federated queries",
+            ));
+        }
+
+        let CollectionQueryRequestWithUsage { request, usage } =
+            convert_query_request_from_rest(internal, &inference_params).await?;
+
+        inference_usage.merge_opt(usage);
+
+        let mut pass = None;
+        let mut checked_collections = Vec::with_capacity(collection_names.len());
+        let mut errors = Vec::new();
+        for collection_name in collection_names {
+            let verification = check_strict_mode(
+                &request,
+                params.timeout_as_secs(),
+                &collection_name,
+                &dispatcher,
+                &auth,
+            )
+            .await;
+
+            match verification {
+                Ok(collection_pass) => {
+                    pass = Some(collection_pass);
+                    checked_collections.push(collection_name);
+                }
+                // Access errors are never hidden in partial results
+                Err(err)
+                    if allow_partial_results && !matches!(err, StorageError::Forbidden { .. }) =>
+                {
+                    errors.push((collection_name, err));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let Some(pass) = pass else {
+            // This can happen only if all collections failed
+            return Err(errors.swap_remove(0).1);
+        };
+
+        let result = dispatcher
+            .toc(&auth, &pass)
+            .federated_query(
+                &checked_collections,
+                request,
+                allow_partial_results,
+                params.consistency,
+                auth,
+                params.timeout(),
+                hw_measurement_acc,
+            )
+            .await?;
+
+        errors.extend(result.errors);
+
+        let points = result
+            .points
+            .into_iter()
+            .map(|(collection, point)| FederatedScoredPoint {
+                collection,
+                point: api::rest::ScoredPoint::from(point),
+            })
+            .collect();
+
+        let errors = allow_partial_results.then(|| {
+            errors
+                .into_iter()
+                .map(|(collection, err)| FederatedQueryError {
+                    collection,
+                    error: err.to_string(),
+                })
+                .collect()
+        });
+
+        Ok(FederatedQueryResponse { points, errors })
+    }
+    .await;
+
+    helpers::process_response_with_inference_usage(
+        result,
+        timing,
+        request_hw_counter.to_rest_api(),
+        inference_usage.into_non_empty(),
+    )
+}
+
 pub fn config_query_api(cfg: &mut web::ServiceConfig) {
     cfg.service(query_points);
     cfg.service(query_points_batch);
     cfg.service(query_points_groups);
+    cfg.service(query_points_federated);
 }
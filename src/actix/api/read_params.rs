@@ -25,6 +25,15 @@ impl ReadParams {
     }
 }
 
+/// Shaping of the scores in the response of the search and query endpoints
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, JsonSchema, Validate)]
+pub struct ScoreParams {
+    /// If set, round the scores of the returned points to this number of decimal places.
+    /// Default is full precision.
+    #[validate(range(max = 7))]
+    pub score_precision: Option<u8>,
+}
+
 fn deserialize_read_consistency<'de, D>(
     deserializer: D,
 ) -> Result<Option<ReadConsistency>, D::Error>
@@ -4,11 +4,16 @@ use actix_web::{Responder, get, post, web};
 use actix_web_validator::{Json, Path, Query};
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
-use collection::operations::types::{PointRequest, PointRequestInternal, ScrollRequest};
+use collection::operations::types::{
+    PointRequest, PointRequestInternal, PointsExistenceRequest, QuantizedVectorResponse,
+    ScrollRequest,
+};
+use collection::operations::verification::new_unchecked_verification_pass;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::TryFutureExt;
 use itertools::Itertools;
-use segment::types::{PointIdType, WithPayloadInterface};
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::{PointIdType, VectorNameBuf, WithPayloadInterface};
 use serde::Deserialize;
 use shard::retrieve::record_internal::RecordInternal;
 use storage::content_manager::collection_verification::{
@@ -17,7 +22,7 @@ use storage::content_manager::collection_verification::{
 use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
 use storage::dispatcher::Dispatcher;
-use storage::rbac::Auth;
+use storage::rbac::{AccessRequirements, Auth};
 use tokio::time::Instant;
 use validator::Validate;
 
@@ -27,7 +32,8 @@ use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
     get_request_hardware_counter, process_response, process_response_error,
 };
-use crate::common::query::do_get_points;
+use crate::common::query::{do_check_points_exist, do_get_points, do_score_records};
+use crate::common::strict_mode::StrictModeCheckedTocProvider;
 use crate::settings::ServiceConfig;
 
 #[derive(Deserialize, Validate)]
@@ -122,6 +128,78 @@ async fn get_point(
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
 
+#[derive(Deserialize, Validate)]
+struct QuantizedVectorParams {
+    /// Name of the vector, the default vector if not set
+    using: Option<VectorNameBuf>,
+}
+
+async fn do_get_point_quantized_vector(
+    toc: &TableOfContent,
+    auth: &Auth,
+    collection_name: &str,
+    point_id: PointIdType,
+    vector_name: &str,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<QuantizedVectorResponse, StorageError> {
+    let collection_pass = auth.check_collection_access(
+        collection_name,
+        AccessRequirements::new(),
+        "get_point_quantized_vector",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    let vector = collection
+        .point_quantized_vector(point_id, vector_name, &hw_measurement_acc)
+        .await?
+        .ok_or_else(|| {
+            StorageError::bad_input(format!(
+                "Vector `{vector_name}` of point {point_id} is not quantized",
+            ))
+        })?;
+    Ok(QuantizedVectorResponse::from(vector))
+}
+
+#[get("/collections/{collection_name}/points/{id}/quantized_vector")]
+async fn get_point_quantized_vector(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    point: Path<PointPath>,
+    params: Query<QuantizedVectorParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    // Vectors are only read, nothing to verify
+    let pass = new_unchecked_verification_pass();
+
+    let Ok(point_id) = point.id.parse::<PointIdType>() else {
+        let err =
+            StorageError::bad_input(format!("Can not recognize \"{}\" as point id", point.id));
+        return process_response_error(err, Instant::now(), None);
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.collection_name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let res = do_get_point_quantized_vector(
+        dispatcher.toc(&auth, &pass),
+        &auth,
+        &collection.collection_name,
+        point_id,
+        params.using.as_deref().unwrap_or(DEFAULT_VECTOR_NAME),
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
 #[post("/collections/{collection_name}/points")]
 async fn get_points(
     dispatcher: web::Data<Dispatcher>,
@@ -182,6 +260,57 @@ async fn get_points(
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{collection_name}/points/exists")]
+async fn check_points_exist(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<PointsExistenceRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let pass = match check_strict_mode_timeout(
+        params.timeout_as_secs(),
+        &collection.collection_name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(p) => p,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let request = request.into_inner();
+
+    let shard_selection = match request.shard_key.clone() {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => ShardSelectorInternal::from(shard_keys),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.collection_name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let res = do_check_points_exist(
+        dispatcher.toc(&auth, &pass),
+        &collection.collection_name,
+        request,
+        params.consistency,
+        params.timeout(),
+        shard_selection,
+        auth,
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(res, timing, request_hw_counter.to_rest_api())
+}
+
 #[post("/collections/{collection_name}/points/scroll")]
 async fn scroll_points(
     dispatcher: web::Data<Dispatcher>,
@@ -194,6 +323,7 @@ async fn scroll_points(
     let ScrollRequest {
         scroll_request,
         shard_key,
+        score_with,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -222,18 +352,39 @@ async fn scroll_points(
     );
     let timing = Instant::now();
 
-    let res = dispatcher
-        .toc(&auth, &pass)
-        .scroll(
-            &collection.collection_name,
-            scroll_request,
-            params.consistency,
-            params.timeout(),
-            shard_selection,
-            auth,
-            request_hw_counter.get_counter(),
-        )
-        .await;
+    let toc = dispatcher.toc(&auth, &pass);
+
+    let res = async {
+        let mut result = toc
+            .scroll(
+                &collection.collection_name,
+                scroll_request,
+                params.consistency,
+                params.timeout(),
+                shard_selection.clone(),
+                auth.clone(),
+                request_hw_counter.get_counter(),
+            )
+            .await?;
+
+        if let Some(vector) = score_with {
+            do_score_records(
+                StrictModeCheckedTocProvider::new(&dispatcher),
+                &collection.collection_name,
+                &mut result.points,
+                vector,
+                params.consistency,
+                shard_selection,
+                auth,
+                params.timeout(),
+                request_hw_counter.get_counter(),
+            )
+            .await?;
+        }
+
+        Ok(result)
+    }
+    .await;
 
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
@@ -1,21 +1,30 @@
 use actix_web::{HttpResponse, Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
-use api::rest::{SearchMatrixOffsetsResponse, SearchMatrixPairsResponse, SearchMatrixRequest};
-use collection::collection::distance_matrix::CollectionSearchMatrixRequest;
+use api::rest::{
+    KDistanceRequest, KDistanceResponse, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
+    SearchMatrixRequest, SearchRequestInternal, SimilarityMatrixRequest, SimilarityMatrixResponse,
+};
+use collection::collection::distance_matrix::{
+    CollectionSearchMatrixRequest, CollectionSimilarityMatrixRequest,
+};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{
     CoreSearchRequest, SearchGroupsRequest, SearchRequest, SearchRequestBatch,
 };
 use itertools::Itertools;
-use storage::content_manager::collection_verification::check_strict_mode;
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::types::{WithPayloadInterface, WithVector};
+use storage::content_manager::collection_verification::{
+    check_strict_mode, check_strict_mode_timeout,
+};
 use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
 
 use super::CollectionPath;
-use super::read_params::ReadParams;
+use super::read_params::{ReadParams, ScoreParams};
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
-    get_request_hardware_counter, process_response, process_response_error,
+    get_request_hardware_counter, process_response, process_response_error, round_scores,
 };
 use crate::common::query::{
     do_core_search_points, do_search_batch_points, do_search_point_groups, do_search_points_matrix,
@@ -28,6 +37,7 @@ async fn search_points(
     collection: Path<CollectionPath>,
     request: Json<SearchRequest>,
     params: Query<ReadParams>,
+    score_params: Query<ScoreParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
@@ -75,10 +85,92 @@ async fn search_points(
     )
     .await
     .map(|scored_points| {
-        scored_points
+        let mut scored_points = scored_points
             .into_iter()
             .map(api::rest::ScoredPoint::from)
-            .collect_vec()
+            .collect_vec();
+        round_scores(&mut scored_points, score_params.score_precision);
+        scored_points
+    });
+
+    process_response(result, timing, request_hw_counter.to_rest_api())
+}
+
+#[post("/collections/{collection_name}/points/search/k_distance")]
+async fn search_k_distance(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<KDistanceRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> HttpResponse {
+    let KDistanceRequest {
+        vector,
+        k,
+        filter,
+        params: search_params,
+        with_id,
+        shard_key,
+    } = request.into_inner();
+
+    // The offset is applied after merging the results of the shards, so each shard still returns
+    // the scores of its `k` nearest points. Payloads and vectors are not fetched for any of them.
+    let search_request = SearchRequestInternal {
+        vector,
+        filter,
+        params: search_params,
+        limit: 1,
+        offset: Some(k - 1),
+        with_payload: Some(WithPayloadInterface::Bool(false)),
+        with_vector: Some(WithVector::Bool(false)),
+        score_threshold: None,
+    };
+
+    let pass = match check_strict_mode(
+        &search_request,
+        params.timeout_as_secs(),
+        &collection.collection_name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.collection_name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+
+    let timing = Instant::now();
+
+    let result = do_core_search_points(
+        dispatcher.toc(&auth, &pass),
+        &collection.collection_name,
+        search_request.into(),
+        params.consistency,
+        shard_selection,
+        auth,
+        params.timeout(),
+        request_hw_counter.get_counter(),
+    )
+    .await
+    .map(|scored_points| {
+        let nearest_k = scored_points.into_iter().next();
+        KDistanceResponse {
+            score: nearest_k.as_ref().map(|point| point.score),
+            id: nearest_k.filter(|_| with_id).map(|point| point.id),
+        }
     });
 
     process_response(result, timing, request_hw_counter.to_rest_api())
@@ -90,6 +182,7 @@ async fn batch_search_points(
     collection: Path<CollectionPath>,
     request: Json<SearchRequestBatch>,
     params: Query<ReadParams>,
+    score_params: Query<ScoreParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
@@ -148,10 +241,12 @@ async fn batch_search_points(
         batch_scored_points
             .into_iter()
             .map(|scored_points| {
-                scored_points
+                let mut scored_points = scored_points
                     .into_iter()
                     .map(api::rest::ScoredPoint::from)
-                    .collect_vec()
+                    .collect_vec();
+                round_scores(&mut scored_points, score_params.score_precision);
+                scored_points
             })
             .collect_vec()
     });
@@ -165,6 +260,7 @@ async fn search_point_groups(
     collection: Path<CollectionPath>,
     request: Json<SearchGroupsRequest>,
     params: Query<ReadParams>,
+    score_params: Query<ScoreParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
@@ -209,7 +305,12 @@ async fn search_point_groups(
         params.timeout(),
         request_hw_counter.get_counter(),
     )
-    .await;
+    .await
+    .map(|mut groups| {
+        let hits = groups.groups.iter_mut().flat_map(|group| &mut group.hits);
+        round_scores(hits, score_params.score_precision);
+        groups
+    });
 
     process_response(result, timing, request_hw_counter.to_rest_api())
 }
@@ -326,11 +427,75 @@ async fn search_points_matrix_offsets(
     process_response(response, timing, request_hw_counter.to_rest_api())
 }
 
+#[post("/collections/{collection_name}/points/similarity_matrix")]
+async fn similarity_matrix(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<SimilarityMatrixRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let SimilarityMatrixRequest {
+        ids,
+        using,
+        shard_key,
+    } = request.into_inner();
+
+    let pass = match check_strict_mode_timeout(
+        params.timeout_as_secs(),
+        &collection.collection_name,
+        &dispatcher,
+        &auth,
+    )
+    .await
+    {
+        Ok(pass) => pass,
+        Err(err) => return process_response_error(err, Instant::now(), None),
+    };
+
+    let shard_selection = match shard_key {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+
+    let request = CollectionSimilarityMatrixRequest {
+        ids,
+        using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+    };
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.collection_name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+    let timing = Instant::now();
+
+    let response = dispatcher
+        .toc(&auth, &pass)
+        .similarity_matrix(
+            &collection.collection_name,
+            request,
+            params.consistency,
+            shard_selection,
+            auth,
+            params.timeout(),
+            request_hw_counter.get_counter(),
+        )
+        .await
+        .map(SimilarityMatrixResponse::from);
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
 // Configure services
 pub fn config_search_api(cfg: &mut web::ServiceConfig) {
     cfg.service(search_points)
+        .service(search_k_distance)
         .service(batch_search_points)
         .service(search_point_groups)
         .service(search_points_matrix_pairs)
-        .service(search_points_matrix_offsets);
+        .service(search_points_matrix_offsets)
+        .service(similarity_matrix);
 }
@@ -175,6 +175,29 @@ async fn readyz(health_checker: web::Data<Option<Arc<health::HealthChecker>>>) -
         .body(body)
 }
 
+/// Readiness of this peer for load balancers: all shards loaded and optimized, none failed
+#[get("/readyz/indexes")]
+async fn readyz_indexes(
+    dispatcher: web::Data<Dispatcher>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let pass = new_unchecked_verification_pass();
+    let is_ready = health::check_local_replicas_ready(dispatcher.toc(&auth, &pass)).await;
+
+    let (status, body) = if is_ready {
+        (StatusCode::OK, "all indexes are ready")
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "some indexes are not ready",
+        )
+    };
+
+    HttpResponse::build(status)
+        .content_type(ContentType::plaintext())
+        .body(body)
+}
+
 /// Basic Kubernetes healthz endpoint
 fn kubernetes_healthz() -> impl Responder {
     HttpResponse::Ok()
@@ -265,6 +288,7 @@ pub fn config_service_api(cfg: &mut web::ServiceConfig) {
         .service(healthz)
         .service(livez)
         .service(readyz)
+        .service(readyz_indexes)
         .service(get_logger_config)
         .service(update_logger_config)
         .service(truncate_unapplied_wal);
@@ -24,9 +24,9 @@ use crate::common::update::*;
 use crate::settings::ServiceConfig;
 
 #[derive(Deserialize, Validate)]
-struct FieldPath {
+pub(super) struct FieldPath {
     #[serde(rename = "field_name")]
-    name: JsonPath,
+    pub(super) name: JsonPath,
 }
 
 #[put("/collections/{collection_name}/points")]
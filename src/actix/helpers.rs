@@ -1,13 +1,19 @@
 use std::fmt::Debug;
 use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
 
 use actix_web::http::header;
 use actix_web::http::header::HeaderMap;
 use actix_web::rt::time::Instant;
 use actix_web::{HttpResponse, ResponseError, http};
-use api::rest::models::{ApiResponse, ApiStatus, HardwareUsage, InferenceUsage, Usage};
+use api::rest::models::{
+    ApiError, ApiResponse, ApiStatus, ErrorCode, HardwareUsage, InferenceUsage, Usage,
+};
 use collection::operations::types::CollectionError;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use rand::{Rng, RngExt};
+use segment::types::ScoreType;
 use serde::Serialize;
 use storage::content_manager::errors::{StorageError, StorageResult};
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
@@ -88,6 +94,24 @@ where
     process_response_with_inference_usage(response, timing, hardware_usage, None)
 }
 
+/// Round the scores of the `points` to `precision` decimal places, to reduce the response size.
+///
+/// Scores are rounded after ranking, so the order of the points is preserved.
+/// Points with close scores may get equal scores.
+pub fn round_scores<'a>(
+    points: impl IntoIterator<Item = &'a mut api::rest::ScoredPoint>,
+    precision: Option<u8>,
+) {
+    let Some(precision) = precision else {
+        return;
+    };
+
+    let factor = 10f64.powi(i32::from(precision));
+    for point in points {
+        point.score = ((f64::from(point.score) * factor).round() / factor) as ScoreType;
+    }
+}
+
 pub fn process_response_error_with_inference_usage(
     err: StorageError,
     timing: Instant,
@@ -101,7 +125,10 @@ pub fn process_response_error_with_inference_usage(
     let headers = error.headers();
     let json_body = ApiResponse::<()> {
         result: None,
-        status: ApiStatus::Error(error.to_string()),
+        status: ApiStatus::ErrorWithCode(ApiError {
+            error: error.to_string(),
+            code: error.code(),
+        }),
         time: timing.elapsed().as_secs_f64(),
         usage: Some(Usage {
             hardware: hardware_usage,
@@ -199,6 +226,30 @@ fn log_service_error(err: &StorageError) {
     }
 }
 
+static RETRY_AFTER_MAX_JITTER_SEC: OnceLock<u32> = OnceLock::new();
+
+/// Set the max jitter of the `Retry-After` header of rate limited responses, from the config.
+/// Only the first call has effect.
+pub fn init_retry_after_max_jitter_sec(max_jitter_sec: u32) {
+    let _ = RETRY_AFTER_MAX_JITTER_SEC.set(max_jitter_sec);
+}
+
+fn retry_after_max_jitter_sec() -> u32 {
+    RETRY_AFTER_MAX_JITTER_SEC.get().copied().unwrap_or(0)
+}
+
+/// Value of the `Retry-After` header in seconds.
+///
+/// The `retry_after` is rounded up to the nearest second so clients don't retry too early.
+/// A random jitter of up to `max_jitter_sec` is added, so clients don't all retry at once.
+fn retry_after_secs(retry_after: Duration, max_jitter_sec: u32, rng: &mut impl Rng) -> u32 {
+    let retry_after_sec = retry_after.as_secs_f32().ceil() as u32;
+    if max_jitter_sec == 0 {
+        return retry_after_sec;
+    }
+    retry_after_sec.saturating_add(rng.random_range(0..=max_jitter_sec))
+}
+
 #[derive(Clone, Debug, thiserror::Error)]
 #[error("{0}")]
 pub struct HttpError(StorageError);
@@ -213,8 +264,11 @@ impl HttpError {
             } => {
                 if let Some(retry_after) = retry_after {
                     // Retry-After is expressed in seconds `https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Retry-After`
-                    // Ceil the value to the nearest second so clients don't retry too early
-                    let retry_after_sec = retry_after.as_secs_f32().ceil() as u32;
+                    let retry_after_sec = retry_after_secs(
+                        *retry_after,
+                        retry_after_max_jitter_sec(),
+                        &mut rand::rng(),
+                    );
                     headers.insert(
                         header::RETRY_AFTER,
                         header::HeaderValue::from(retry_after_sec),
@@ -234,9 +288,30 @@ impl HttpError {
             StorageError::InferenceError { .. } => {}
             StorageError::ShardUnavailable { .. } => {}
             StorageError::EmptyPartialSnapshot { .. } => {}
+            StorageError::Conflict { .. } => {}
         }
         headers
     }
+
+    fn code(&self) -> ErrorCode {
+        match &self.0 {
+            StorageError::BadInput { .. } => ErrorCode::BadInput,
+            StorageError::NotFound { .. } => ErrorCode::NotFound,
+            StorageError::ServiceError { .. } => ErrorCode::ServiceError,
+            StorageError::BadRequest { .. } => ErrorCode::BadRequest,
+            StorageError::Locked { .. } => ErrorCode::Locked,
+            StorageError::Timeout { .. } => ErrorCode::Timeout,
+            StorageError::AlreadyExists { .. } => ErrorCode::AlreadyExists,
+            StorageError::ChecksumMismatch { .. } => ErrorCode::ChecksumMismatch,
+            StorageError::Forbidden { .. } => ErrorCode::Forbidden,
+            StorageError::PreconditionFailed { .. } => ErrorCode::PreconditionFailed,
+            StorageError::InferenceError { .. } => ErrorCode::InferenceError,
+            StorageError::RateLimitExceeded { .. } => ErrorCode::RateLimitExceeded,
+            StorageError::ShardUnavailable { .. } => ErrorCode::ShardUnavailable,
+            StorageError::EmptyPartialSnapshot { .. } => ErrorCode::EmptyPartialSnapshot,
+            StorageError::Conflict { .. } => ErrorCode::Conflict,
+        }
+    }
 }
 
 impl ResponseError for HttpError {
@@ -256,6 +331,7 @@ impl ResponseError for HttpError {
             StorageError::RateLimitExceeded { .. } => http::StatusCode::TOO_MANY_REQUESTS,
             StorageError::ShardUnavailable { .. } => http::StatusCode::SERVICE_UNAVAILABLE,
             StorageError::EmptyPartialSnapshot { .. } => http::StatusCode::NOT_MODIFIED,
+            StorageError::Conflict { .. } => http::StatusCode::CONFLICT,
         }
     }
 }
@@ -277,3 +353,58 @@ impl From<std::io::Error> for HttpError {
         HttpError(err.into()) // TODO: Is this good enough?.. 🤔
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    #[test]
+    fn test_retry_after_secs() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // Without jitter, the value is only rounded up
+        assert_eq!(
+            retry_after_secs(Duration::from_millis(1500), 0, &mut rng),
+            2
+        );
+        assert_eq!(retry_after_secs(Duration::from_secs(3), 0, &mut rng), 3);
+        assert_eq!(retry_after_secs(Duration::ZERO, 0, &mut rng), 0);
+
+        // Jitter is bounded and never goes below the rounded value
+        let values: Vec<_> = (0..1000)
+            .map(|_| retry_after_secs(Duration::from_millis(1500), 3, &mut rng))
+            .collect();
+        assert!(values.iter().all(|value| (2..=5).contains(value)));
+        assert!(values.iter().any(|&value| value != values[0]));
+
+        // Same seed, same jitter
+        let jitter = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..10)
+                .map(|_| retry_after_secs(Duration::from_secs(1), 10, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(jitter(7), jitter(7));
+    }
+
+    #[test]
+    fn test_error_status_code() {
+        let error = HttpError::from(StorageError::bad_input("invalid vector"));
+        let status = ApiStatus::ErrorWithCode(ApiError {
+            error: error.to_string(),
+            code: error.code(),
+        });
+
+        // Description stays under `error`, as for errors without a code
+        assert_eq!(
+            serde_json::to_value(&status).unwrap(),
+            serde_json::json!({
+                "error": "Wrong input: invalid vector",
+                "code": "bad_input",
+            }),
+        );
+    }
+}
@@ -36,7 +36,9 @@ use crate::actix::api::local_shard_api::config_local_shard_api;
 use crate::actix::api::profiler_api::config_profiler_api;
 use crate::actix::api::query_api::config_query_api;
 use crate::actix::api::recommend_api::config_recommend_api;
-use crate::actix::api::retrieve_api::{get_point, get_points, scroll_points};
+use crate::actix::api::retrieve_api::{
+    check_points_exist, get_point, get_point_quantized_vector, get_points, scroll_points,
+};
 use crate::actix::api::search_api::config_search_api;
 use crate::actix::api::service_api::config_service_api;
 use crate::actix::api::shards_api::config_shards_api;
@@ -85,6 +87,9 @@ pub fn init(
         let health_checker = web::Data::new(health_checker);
         let web_ui_available = web_ui_folder(&settings);
         let service_config = web::Data::new(settings.service.clone());
+        helpers::init_retry_after_max_jitter_sec(
+            settings.service.retry_after_max_jitter_sec.unwrap_or(0),
+        );
         let audit_config_data = web::Data::new(settings.audit.clone());
 
         let mut api_key_whitelist = vec![
@@ -128,6 +133,7 @@ pub fn init(
                         .exclude("/telemetry")
                         .exclude("/healthz")
                         .exclude("/readyz")
+                        .exclude("/readyz/indexes")
                         .exclude("/livez"),
                 )
                 .wrap(actix_telemetry::ActixTelemetryTransform::new(
@@ -167,7 +173,9 @@ pub fn init(
                 // Ordering of services is important for correct path pattern matching
                 // See: <https://github.com/qdrant/qdrant/issues/3543>
                 .service(scroll_points)
+                .service(check_points_exist)
                 .service(count_points)
+                .service(get_point_quantized_vector)
                 .service(get_point)
                 .service(get_points);
 
@@ -19,8 +19,10 @@ use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::snapshot_ops::SnapshotDescription;
 use collection::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
+    FilterValidationResult, TokenizeResponse, WarmupRequest, WarmupResponse,
 };
 use collection::operations::verification::new_unchecked_verification_pass;
+use collection::profiling::index_advisor::IndexRecommendation;
 use collection::shards::replica_set;
 use collection::shards::replica_set::replica_set_state;
 use collection::shards::resharding::ReshardKey;
@@ -28,9 +30,13 @@ use collection::shards::shard::{PeerId, ShardId, ShardsPlacement};
 use collection::shards::transfer::{
     ShardTransfer, ShardTransferKey, ShardTransferMethod, ShardTransferRestart,
 };
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::populate_budget::PopulateBudget;
 use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use rand::seq::IteratorRandom;
+use segment::json_path::JsonPath;
+use segment::types::{Filter, PointIdType};
 use storage::content_manager::collection_meta_ops::ShardTransferOperations::{Abort, Start};
 #[cfg(feature = "staging")]
 use storage::content_manager::collection_meta_ops::TestSlowDown;
@@ -84,6 +90,98 @@ pub async fn do_get_collection(
     Ok(collection.info(&shard_selection).await?)
 }
 
+pub async fn do_validate_filter(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    filter: &Filter,
+) -> Result<FilterValidationResult, StorageError> {
+    let collection_pass =
+        auth.check_collection_access(name, AccessRequirements::new(), "validate_filter")?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    Ok(collection.validate_filter(filter))
+}
+
+pub async fn do_tokenize_text(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    key: &JsonPath,
+    text: &str,
+) -> Result<TokenizeResponse, StorageError> {
+    let collection_pass =
+        auth.check_collection_access(name, AccessRequirements::new(), "tokenize_text")?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    Ok(collection.tokenize_text(key, text)?)
+}
+
+pub async fn do_get_point_tokens(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    key: &JsonPath,
+    point_id: PointIdType,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<TokenizeResponse, StorageError> {
+    let collection_pass =
+        auth.check_collection_access(name, AccessRequirements::new(), "get_point_tokens")?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    let tokens = collection
+        .point_text_tokens(point_id, key, &hw_measurement_acc)
+        .await?
+        .ok_or_else(|| StorageError::bad_input(format!("Field `{key}` has no full-text index")))?;
+    Ok(TokenizeResponse { tokens })
+}
+
+pub async fn do_warmup_collection(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+    request: WarmupRequest,
+) -> Result<WarmupResponse, StorageError> {
+    let collection_pass = auth.check_collection_access(
+        name,
+        AccessRequirements::new().manage(),
+        "warmup_collection",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    let WarmupRequest {
+        budget_bytes,
+        fields,
+    } = request;
+
+    let budget = match budget_bytes {
+        Some(budget_bytes) => PopulateBudget::new(budget_bytes),
+        None => PopulateBudget::unlimited(),
+    };
+
+    Ok(collection.warmup_payload_indexes(fields, budget).await?)
+}
+
+pub async fn do_get_index_recommendations(
+    toc: &TableOfContent,
+    auth: &Auth,
+    name: &str,
+) -> Result<Vec<IndexRecommendation>, StorageError> {
+    let collection_pass = auth.check_collection_access(
+        name,
+        AccessRequirements::new().manage(),
+        "get_index_recommendations",
+    )?;
+
+    let collection = toc.get_collection(&collection_pass).await?;
+
+    Ok(collection.index_recommendations().await)
+}
+
 pub async fn do_list_collections(
     toc: &TableOfContent,
     auth: &Auth,
@@ -342,6 +342,31 @@ impl Task {
     }
 }
 
+/// Check if all collections on this peer have their shards loaded and optimized, without failed
+/// operations.
+///
+/// Unlike [`HealthChecker::check_ready`], this doesn't wait for consensus and is not sticky:
+/// the node becomes unready again while any of its shards is optimized, or if it fails.
+///
+/// Only the aggregated readiness is returned, so it is safe to report without authentication.
+pub async fn check_local_replicas_ready(toc: &TableOfContent) -> bool {
+    let collections = toc
+        .all_collections(&Access::full("For indexes readiness check"))
+        .await;
+
+    for collection_pass in &collections {
+        let Ok(collection) = toc.get_collection(collection_pass).await else {
+            continue;
+        };
+
+        if !collection.is_local_replicas_ready().await {
+            return false;
+        }
+    }
+
+    true
+}
+
 fn get_consensus_commit<'a>(
     transport_channel_pool: &'a TransportChannelPool,
     uri: &'a tonic::transport::Uri,
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use collection::operations::point_ops::WriteOrdering;
+use collection::operations::verification::new_unchecked_verification_pass;
+use collection::profiling::index_advisor::{IndexAdvisorConfig, schema_on_disk};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use storage::dispatcher::Dispatcher;
+use storage::rbac::{Access, Auth};
+
+use crate::common::update::{
+    CreateFieldIndex, InternalUpdateParams, UpdateParams, do_create_index,
+};
+
+/// Periodically creates the payload indexes recommended by the index advisor.
+///
+/// Created indexes are kept in RAM while their estimated size, together with the payload indexes
+/// already kept in RAM on this peer, fits into the memory budget. The rest are created on disk. At most `max_auto_created_indexes` are created in each
+/// collection, and reported in its telemetry.
+pub async fn auto_create_indexes(dispatcher: Arc<Dispatcher>, config: &'static IndexAdvisorConfig) {
+    let auth = Auth::new_internal(Access::full("Index advisor"));
+    let memory_budget = config.memory_budget_bytes();
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.auto_create_interval_sec));
+    loop {
+        interval.tick().await;
+
+        let toc = dispatcher
+            .toc(&auth, &new_unchecked_verification_pass())
+            .clone();
+        let collection_passes = toc.all_collections(auth.access("index_advisor")).await;
+
+        // Measure the payload indexes actually kept in RAM on this peer, so indexes created or
+        // dropped by users, and previously created indexes which got larger, count towards the
+        // budget. Indexes created within this tick are built in the background and are not
+        // measured yet, their estimated size is added instead.
+        let mut used_ram_bytes = 0usize;
+        for collection_pass in &collection_passes {
+            if let Ok(collection) = toc.get_collection(collection_pass).await {
+                used_ram_bytes += collection.payload_indexes_ram_bytes().await;
+            }
+        }
+
+        for collection_pass in collection_passes {
+            let collection_name = collection_pass.name().to_string();
+            let Ok(collection) = toc.get_collection(&collection_pass).await else {
+                continue;
+            };
+
+            let mut auto_created_count = collection.auto_created_indexes().len();
+            for recommendation in collection.index_recommendations().await {
+                if auto_created_count >= config.max_auto_created_indexes {
+                    log::debug!(
+                        "Index advisor: max number of automatically created indexes reached \
+                         in collection {collection_name}",
+                    );
+                    break;
+                }
+
+                let fits_in_ram = !recommendation.on_disk
+                    && used_ram_bytes.saturating_add(recommendation.estimated_ram_bytes)
+                        <= memory_budget;
+                let field_schema = if fits_in_ram || recommendation.on_disk {
+                    recommendation.field_schema
+                } else {
+                    schema_on_disk(&recommendation.field_schema)
+                };
+
+                log::info!(
+                    "Index advisor: creating {:?} index on field {} of collection \
+                     {collection_name}, on disk: {}",
+                    recommendation.index_type,
+                    recommendation.field_name,
+                    !fits_in_ram,
+                );
+
+                let operation = CreateFieldIndex {
+                    field_name: recommendation.field_name.clone(),
+                    field_schema: Some(field_schema),
+                };
+                let params = UpdateParams {
+                    wait: false,
+                    ordering: WriteOrdering::default(),
+                    timeout: None,
+                };
+                let hw_measurement_acc = HwMeasurementAcc::new_with_metrics_drain(
+                    dispatcher.get_collection_hw_metrics(collection_name.clone()),
+                );
+
+                let result = do_create_index(
+                    dispatcher.clone(),
+                    collection_name.clone(),
+                    operation,
+                    InternalUpdateParams::default(),
+                    params,
+                    auth.clone(),
+                    hw_measurement_acc,
+                )
+                .await;
+
+                match result {
+                    Ok(_) => {
+                        if fits_in_ram {
+                            used_ram_bytes += recommendation.estimated_ram_bytes;
+                        }
+                        collection.record_auto_created_index(&recommendation, !fits_in_ram);
+                        auto_created_count += 1;
+                    }
+                    Err(err) => log::warn!(
+                        "Index advisor: failed to create index on field {} of collection \
+                         {collection_name}: {err}",
+                        recommendation.field_name,
+                    ),
+                }
+            }
+        }
+    }
+}
@@ -112,7 +112,10 @@ fn collect_query(query: &Query, batch: &mut BatchAccum) {
         | Query::Fusion(_)
         | Query::Rrf(_)
         | Query::Formula(_)
-        | Query::Sample(_) => {}
+        | Query::Recency(_)
+        | Query::Proximity(_)
+        | Query::Sample(_)
+        | Query::WeightedSample(_) => {}
     }
 }
 
@@ -184,12 +187,21 @@ pub fn collect_query_request(request: &QueryRequestInternal) -> BatchAccum {
         using: _,
         filter: _,
         score_threshold: _,
+        apply_default_score_threshold: _,
         params: _,
         limit: _,
         offset: _,
         with_vector: _,
         with_payload: _,
         lookup_from: _,
+        facets: _,
+        apply_boost_rules: _,
+        with_provenance: _,
+        with_explanation: _,
+        with_stopwords: _,
+        soft_timeout_ms: _,
+        snippets: _,
+        neighbors: _,
     } = request;
 
     if let Some(query) = query {
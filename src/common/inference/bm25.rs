@@ -121,7 +121,8 @@ fn new_token_processor_from_config(value: TextPreprocessingConfig) -> TokensProc
     };
 
     let stopwords_config = match stopwords {
-        None => {
+        // Documents are processed one by one, there are no indexed texts to detect the language of
+        None | Some(StopwordsInterface::Auto(_)) => {
             // Try to create from the language
             Language::from_str(&language)
                 .ok()
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use api::conversions::json::json_path_from_proto;
 use api::grpc::qdrant::RecommendInput;
 use api::grpc::qdrant::query::Variant;
@@ -127,6 +129,10 @@ pub async fn convert_query_points_from_grpc(
         shard_key_selector: _,
         lookup_from,
         timeout: _,
+        facets: _,
+        apply_boost_rules,
+        apply_default_score_threshold,
+        soft_timeout_ms,
     } = query;
 
     let mut batch = BatchAccumGrpc::new();
@@ -177,6 +183,13 @@ pub async fn convert_query_points_from_grpc(
                 .transpose()?
                 .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
             lookup_from: lookup_from.map(LookupLocation::try_from).transpose()?,
+            apply_boost_rules: apply_boost_rules
+                .unwrap_or(CollectionQueryRequest::DEFAULT_APPLY_BOOST_RULES),
+            apply_default_score_threshold: apply_default_score_threshold
+                .unwrap_or(CollectionQueryRequest::DEFAULT_APPLY_DEFAULT_SCORE_THRESHOLD),
+            with_provenance: CollectionQueryRequest::DEFAULT_WITH_PROVENANCE,
+            with_explanation: CollectionQueryRequest::DEFAULT_WITH_EXPLANATION,
+            soft_timeout: soft_timeout_ms.map(Duration::from_millis),
         },
         usage.unwrap_or_default().into(),
     ))
@@ -295,7 +308,10 @@ fn convert_query_with_inferred(
         Variant::Fusion(fusion) => Query::Fusion(FusionInternal::try_from(fusion)?),
         Variant::Rrf(rrf) => Query::Fusion(FusionInternal::try_from(rrf)?),
         Variant::Formula(formula) => Query::Formula(FormulaInternal::try_from(formula)?),
+        Variant::Recency(recency) => Query::Formula(FormulaInternal::try_from(recency)?),
+        Variant::Proximity(proximity) => Query::Formula(FormulaInternal::try_from(proximity)?),
         Variant::Sample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
+        Variant::WeightedSample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
         Variant::NearestWithMmr(grpc::NearestInputWithMmr { nearest, mmr }) => {
             let nearest =
                 nearest.ok_or_else(|| Status::invalid_argument("nearest vector is missing"))?;
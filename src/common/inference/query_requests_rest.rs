@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use api::rest::models::InferenceUsage;
 use api::rest::schema as rest;
 use collection::lookup::WithLookup;
@@ -108,12 +110,21 @@ pub async fn convert_query_request_from_rest(
         using,
         filter,
         score_threshold,
+        apply_default_score_threshold,
         params,
         limit,
         offset,
         with_vector,
         with_payload,
         lookup_from,
+        facets: _, // Counted separately, see `do_query_facets`
+        apply_boost_rules,
+        with_provenance,
+        with_explanation,
+        with_stopwords: _, // Reported separately, see `do_query_text_stopwords`
+        soft_timeout_ms,
+        snippets: _, // Fetched for the returned points, see `TableOfContent::points_text_snippets`
+        neighbors: _, // Queried for the returned points, see `do_query_neighbors`
     } = request;
 
     let prefetch = prefetch
@@ -142,6 +153,14 @@ pub async fn convert_query_request_from_rest(
         with_vector: with_vector.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_VECTOR),
         with_payload: with_payload.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
         lookup_from,
+        apply_boost_rules: apply_boost_rules
+            .unwrap_or(CollectionQueryRequest::DEFAULT_APPLY_BOOST_RULES),
+        apply_default_score_threshold: apply_default_score_threshold
+            .unwrap_or(CollectionQueryRequest::DEFAULT_APPLY_DEFAULT_SCORE_THRESHOLD),
+        with_provenance: with_provenance.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PROVENANCE),
+        with_explanation: with_explanation
+            .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_EXPLANATION),
+        soft_timeout: soft_timeout_ms.map(Duration::from_millis),
     };
     Ok(CollectionQueryRequestWithUsage {
         request: collection_query_request,
@@ -272,7 +291,12 @@ fn convert_query_with_inferred(
         rest::Query::Fusion(fusion) => Ok(Query::Fusion(FusionInternal::from(fusion.fusion))),
         rest::Query::Rrf(rrf) => Ok(Query::Fusion(FusionInternal::from(rrf.rrf))),
         rest::Query::Formula(formula) => Ok(Query::Formula(FormulaInternal::from(formula))),
+        rest::Query::Recency(recency) => Ok(Query::Formula(FormulaInternal::from(recency))),
+        rest::Query::Proximity(proximity) => Ok(Query::Formula(FormulaInternal::from(proximity))),
         rest::Query::Sample(sample) => Ok(Query::Sample(SampleInternal::from(sample.sample))),
+        rest::Query::WeightedSample(sample) => {
+            Ok(Query::Sample(SampleInternal::from(sample.weighted_sample)))
+        }
         rest::Query::RelevanceFeedback(relevance_feedback) => {
             let rest::RelevanceFeedbackInput {
                 target,
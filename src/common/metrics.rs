@@ -16,6 +16,7 @@ use crate::common::telemetry_ops::cluster_telemetry::{ClusterStatusTelemetry, Cl
 use crate::common::telemetry_ops::collections_telemetry::{
     CollectionTelemetryEnum, CollectionsTelemetry,
 };
+use crate::common::telemetry_ops::field_index_builds::FieldIndexBuildsTelemetry;
 use crate::common::telemetry_ops::memory_telemetry::MemoryTelemetry;
 use crate::common::telemetry_ops::requests_telemetry::{
     GrpcTelemetry, RequestsTelemetry, WebApiTelemetry,
@@ -27,6 +28,7 @@ use crate::common::telemetry_ops::requests_telemetry::{
 ///
 /// This array *must* be sorted.
 const REST_ENDPOINT_WHITELIST: &[&str] = &[
+    "/collections/query/federated",
     "/collections/{collection_name}/index",
     "/collections/{collection_name}/points",
     "/collections/{collection_name}/points/batch",
@@ -82,6 +84,7 @@ const GRPC_ENDPOINT_WHITELIST: &[&str] = &[
     "/qdrant.Points/Search",
     "/qdrant.Points/SearchBatch",
     "/qdrant.Points/SearchGroups",
+    "/qdrant.Points/SearchStream",
     "/qdrant.Points/SetPayload",
     "/qdrant.Points/UpdateBatch",
     "/qdrant.Points/UpdateVectors",
@@ -150,6 +153,9 @@ impl MetricsProvider for TelemetryData {
         if let Some(mem) = &self.memory {
             mem.add_metrics(metrics, prefix);
         }
+        if let Some(field_index_builds) = &self.field_index_builds {
+            field_index_builds.add_metrics(metrics, prefix);
+        }
 
         #[cfg(target_os = "linux")]
         match procfs_metrics::ProcFsMetrics::collect() {
@@ -837,6 +843,32 @@ impl HardwareTelemetry {
     }
 }
 
+impl MetricsProvider for FieldIndexBuildsTelemetry {
+    fn add_metrics(&self, metrics: &mut MetricsData, prefix: Option<&str>) {
+        metrics.push_metric(metric_family(
+            "field_index_builds_running",
+            "number of payload field index builds in progress",
+            MetricType::GAUGE,
+            vec![gauge(self.running as f64, &[])],
+            prefix,
+        ));
+        metrics.push_metric(metric_family(
+            "field_index_builds_queued",
+            "number of payload field index builds waiting for the concurrency limit",
+            MetricType::GAUGE,
+            vec![gauge(self.queued as f64, &[])],
+            prefix,
+        ));
+        metrics.push_metric(metric_family(
+            "field_index_reconfigurations_running",
+            "number of in-place reconfigurations of payload field indexes in progress",
+            MetricType::GAUGE,
+            vec![gauge(self.reconfigurations.len() as f64, &[])],
+            prefix,
+        ));
+    }
+}
+
 impl MetricsProvider for HardwareTelemetry {
     fn add_metrics(&self, metrics: &mut MetricsData, prefix: Option<&str>) {
         // MetricType::COUNTER requires non-empty collection data.
@@ -6,6 +6,7 @@ pub mod error_reporting;
 pub mod health;
 pub mod helpers;
 pub mod http_client;
+pub mod index_advisor;
 pub mod inference;
 pub mod metrics;
 pub mod pyroscope_state;
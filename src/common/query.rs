@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
+use ahash::AHashSet;
 use api::rest::SearchGroupsRequestInternal;
 use collection::collection::distance_matrix::*;
 use collection::common::batching::batch_requests;
@@ -9,7 +11,14 @@ use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::universal_query::collection_query::*;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::types::ScoredPoint;
+use futures::future::try_join_all;
+use segment::data_types::facets::{FacetParams, FacetResponse};
+use segment::data_types::text_query::{QueryStopwords, TextCondition};
+use segment::json_path::JsonPath;
+use segment::types::{
+    Condition, Filter, HasIdCondition, PointIdType, ScoredPoint, SearchParams,
+    WithPayloadInterface, WithVector,
+};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
@@ -17,6 +26,8 @@ use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
 use storage::rbac::Auth;
 
+use crate::common::strict_mode::CheckedTocProvider;
+
 #[allow(clippy::too_many_arguments)]
 pub async fn do_core_search_points(
     toc: &TableOfContent,
@@ -47,6 +58,76 @@ pub async fn do_core_search_points(
         .ok_or_else(|| StorageError::service_error("Empty search result"))
 }
 
+/// Set the `score` of each of the `records` to its similarity to the `vector`.
+///
+/// Only the given records are scored, with exact search, and their order is preserved.
+/// Records without the vector are left without a score.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_score_records(
+    toc_provider: impl CheckedTocProvider,
+    collection_name: &str,
+    records: &mut [api::rest::Record],
+    vector: api::rest::NamedVectorStruct,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<(), StorageError> {
+    if records.is_empty() {
+        return Ok(());
+    }
+
+    let ids: AHashSet<_> = records.iter().map(|record| record.id).collect();
+    let request = api::rest::SearchRequestInternal {
+        vector,
+        filter: Some(Filter::new_must(Condition::HasId(HasIdCondition::from(
+            ids,
+        )))),
+        params: Some(SearchParams {
+            exact: true,
+            ..Default::default()
+        }),
+        limit: records.len(),
+        offset: None,
+        with_payload: Some(WithPayloadInterface::Bool(false)),
+        with_vector: Some(WithVector::Bool(false)),
+        score_threshold: None,
+    };
+
+    // Exact search is subject to strict mode, like any other search
+    let toc = toc_provider
+        .check_strict_mode(
+            &request,
+            collection_name,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &auth,
+        )
+        .await?;
+
+    let scored_points = do_core_search_points(
+        toc,
+        collection_name,
+        request.into(),
+        read_consistency,
+        shard_selection,
+        auth,
+        timeout,
+        hw_measurement_acc,
+    )
+    .await?;
+
+    let scores: HashMap<_, _> = scored_points
+        .into_iter()
+        .map(|point| (point.id, point.score))
+        .collect();
+    for record in records {
+        record.score = scores.get(&record.id).copied();
+    }
+
+    Ok(())
+}
+
 pub async fn do_search_batch_points(
     toc: &TableOfContent,
     collection_name: &str,
@@ -244,6 +325,32 @@ pub async fn do_get_points(
     .await
 }
 
+/// Whether each of the requested points exists, in the same order.
+///
+/// Only point versions are looked up, payloads and vectors are not read.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_check_points_exist(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: PointsExistenceRequest,
+    read_consistency: Option<ReadConsistency>,
+    timeout: Option<Duration>,
+    shard_selection: ShardSelectorInternal,
+    auth: Auth,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<Vec<PointExistence>, StorageError> {
+    toc.check_points_exist(
+        collection_name,
+        request,
+        read_consistency,
+        timeout,
+        shard_selection,
+        auth,
+        hw_measurement_acc,
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn do_scroll_points(
     toc: &TableOfContent,
@@ -295,6 +402,99 @@ pub async fn do_query_points(
         .ok_or_else(|| StorageError::service_error("Empty query result"))
 }
 
+/// Count the values of each facet of a query, independently of the query results.
+///
+/// Facets are counted concurrently, and returned in the order of the request.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_query_facets(
+    toc: &TableOfContent,
+    collection_name: &str,
+    facets: Vec<FacetParams>,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: ShardSelectorInternal,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<Vec<(JsonPath, FacetResponse)>, StorageError> {
+    let facets = facets.into_iter().map(|facet| {
+        let key = facet.key.clone();
+        let response = toc.facet(
+            collection_name,
+            facet,
+            shard_selection.clone(),
+            read_consistency,
+            auth.clone(),
+            timeout,
+            hw_measurement_acc.clone(),
+        );
+        async move { Ok((key, response.await?)) }
+    });
+    try_join_all(facets).await
+}
+
+/// Nearest neighbors of each of the `seeds`, by their stored vectors, in the order of the seeds.
+///
+/// Neighbors are searched in all selected shards concurrently, with the filter, the search
+/// params and the output options of the `request`.
+#[allow(clippy::too_many_arguments)]
+pub async fn do_query_neighbors(
+    toc: &TableOfContent,
+    collection_name: &str,
+    request: &CollectionQueryRequest,
+    seeds: &[PointIdType],
+    limit: usize,
+    read_consistency: Option<ReadConsistency>,
+    shard_selection: &ShardSelectorInternal,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<Vec<Vec<ScoredPoint>>, StorageError> {
+    if seeds.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let requests = seeds
+        .iter()
+        .map(|&seed| {
+            (
+                request.nearest_neighbors_of(seed, limit),
+                shard_selection.clone(),
+            )
+        })
+        .collect();
+    toc.query_batch(
+        collection_name,
+        requests,
+        read_consistency,
+        auth,
+        timeout,
+        hw_measurement_acc,
+    )
+    .await
+}
+
+/// Query tokens removed as stopwords from the full-text `conditions`.
+/// Conditions on fields without a full-text index are left out.
+pub async fn do_query_text_stopwords(
+    toc: &TableOfContent,
+    collection_name: &str,
+    conditions: Vec<TextCondition>,
+    shard_selection: &ShardSelectorInternal,
+    auth: &Auth,
+) -> Result<Vec<(TextCondition, QueryStopwords)>, StorageError> {
+    let stopwords = conditions.into_iter().map(|condition| async move {
+        let stopwords = toc
+            .text_query_stopwords(collection_name, &condition, shard_selection, auth)
+            .await?;
+        Ok::<_, StorageError>(stopwords.map(|stopwords| (condition, stopwords)))
+    });
+    Ok(try_join_all(stopwords)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn do_query_batch_points(
     toc: &TableOfContent,
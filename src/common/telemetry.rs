@@ -25,6 +25,7 @@ use crate::common::telemetry_ops::cluster_telemetry::ClusterTelemetry;
 use crate::common::telemetry_ops::collections_telemetry::{
     CollectionTelemetryEnum, CollectionsTelemetry,
 };
+use crate::common::telemetry_ops::field_index_builds::FieldIndexBuildsTelemetry;
 use crate::common::telemetry_ops::hardware::HardwareTelemetry;
 use crate::common::telemetry_ops::memory_telemetry::MemoryTelemetry;
 use crate::common::telemetry_ops::requests_telemetry::{
@@ -63,6 +64,8 @@ pub struct TelemetryData {
     pub(crate) hardware: Option<HardwareTelemetry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) search_pool: Option<SearchThreadPoolTelemetry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) field_index_builds: Option<FieldIndexBuildsTelemetry>,
 }
 
 impl TelemetryCollector {
@@ -154,6 +157,8 @@ impl TelemetryCollector {
                         .toc(auth, &new_unchecked_verification_pass()),
                 )
             }),
+            field_index_builds: (detail.level > DetailsLevel::Level0)
+                .then(FieldIndexBuildsTelemetry::collect),
         })
     }
 }
@@ -195,6 +200,7 @@ impl TryFrom<grpc::PeerTelemetry> for TelemetryData {
             memory: None,
             hardware: None,
             search_pool: None,
+            field_index_builds: None,
         })
     }
 }
@@ -212,6 +218,7 @@ impl TryFrom<TelemetryData> for grpc::PeerTelemetry {
             memory: _,
             hardware: _,
             search_pool: _,
+            field_index_builds: _,
         } = telemetry_data;
 
         let app = app.map(grpc::AppTelemetry::from);
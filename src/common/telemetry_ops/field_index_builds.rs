@@ -0,0 +1,60 @@
+use common::index_build_limit::{IndexBuildState, index_build_state};
+use common::index_reconfiguration_progress::{
+    IndexReconfigurationProgress, index_reconfigurations,
+};
+use schemars::JsonSchema;
+use segment::common::anonymize::Anonymize;
+use serde::Serialize;
+
+/// Payload field index builds of this peer, across all collections.
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+pub struct FieldIndexBuildsTelemetry {
+    /// Number of field index builds in progress.
+    #[anonymize(false)]
+    pub running: usize,
+    /// Number of field index builds waiting for the concurrency limit.
+    #[anonymize(false)]
+    pub queued: usize,
+    /// In-place reconfigurations of existing field indexes in progress, one per shard.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reconfigurations: Vec<FieldIndexReconfigurationTelemetry>,
+}
+
+/// Progress of the in-place reconfiguration of a field index in one shard.
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+pub struct FieldIndexReconfigurationTelemetry {
+    /// Field, which index is reconfigured.
+    pub field_name: String,
+    /// Number of segments of the shard, which are already processed.
+    #[anonymize(false)]
+    pub processed_segments: usize,
+    /// Number of segments of the shard.
+    #[anonymize(false)]
+    pub total_segments: usize,
+}
+
+impl FieldIndexBuildsTelemetry {
+    pub fn collect() -> Self {
+        let IndexBuildState { running, queued } = index_build_state();
+        let reconfigurations = index_reconfigurations()
+            .into_iter()
+            .map(|progress| {
+                let IndexReconfigurationProgress {
+                    field_name,
+                    processed_segments,
+                    total_segments,
+                } = progress;
+                FieldIndexReconfigurationTelemetry {
+                    field_name,
+                    processed_segments,
+                    total_segments,
+                }
+            })
+            .collect();
+        Self {
+            running,
+            queued,
+            reconfigurations,
+        }
+    }
+}
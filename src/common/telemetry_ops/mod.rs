@@ -3,6 +3,7 @@ pub mod cluster_telemetry;
 pub mod collections_telemetry;
 mod conversions;
 pub mod distributed_telemetry;
+pub mod field_index_builds;
 pub mod hardware;
 pub mod memory_telemetry;
 pub mod requests_telemetry;
@@ -331,56 +331,80 @@ pub async fn do_upsert_points(
         )
         .await?;
 
-    let (operation, shard_key, usage, update_filter, update_mode) = match operation {
-        PointInsertOperations::PointsBatch(batch) => {
-            let PointsBatch {
-                batch,
-                shard_key,
-                update_filter,
-                update_mode,
-            } = batch;
-            let (batch, usage) = convert_batch(batch, inference_params).await?;
-            let operation = PointInsertOperationsInternal::PointsBatch(batch);
-            let update_mode = update_mode.map(rest_update_mode_to_internal);
-            (operation, shard_key, usage, update_filter, update_mode)
-        }
-        PointInsertOperations::PointsList(list) => {
-            let PointsList {
-                points,
-                shard_key,
-                update_filter,
-                update_mode,
-            } = list;
-            let (list, usage) =
-                convert_point_struct(points, InferenceType::Update, inference_params).await?;
-            let operation = PointInsertOperationsInternal::PointsList(list);
-            let update_mode = update_mode.map(rest_update_mode_to_internal);
-            (operation, shard_key, usage, update_filter, update_mode)
-        }
-    };
+    let (operation, shard_key, usage, update_filter, update_mode, expected_version) =
+        match operation {
+            PointInsertOperations::PointsBatch(batch) => {
+                let PointsBatch {
+                    batch,
+                    shard_key,
+                    update_filter,
+                    update_mode,
+                    expected_version,
+                    duplicate_ids,
+                } = batch;
+                let (batch, usage) = convert_batch(batch, inference_params).await?;
+                let mut operation = PointInsertOperationsInternal::PointsBatch(batch);
+                resolve_duplicate_ids(&mut operation, duplicate_ids.unwrap_or_default())?;
+                let update_mode = update_mode.map(rest_update_mode_to_internal);
+                (
+                    operation,
+                    shard_key,
+                    usage,
+                    update_filter,
+                    update_mode,
+                    expected_version,
+                )
+            }
+            PointInsertOperations::PointsList(list) => {
+                let PointsList {
+                    points,
+                    shard_key,
+                    update_filter,
+                    update_mode,
+                    expected_version,
+                    duplicate_ids,
+                } = list;
+                let (list, usage) =
+                    convert_point_struct(points, InferenceType::Update, inference_params).await?;
+                let mut operation = PointInsertOperationsInternal::PointsList(list);
+                resolve_duplicate_ids(&mut operation, duplicate_ids.unwrap_or_default())?;
+                let update_mode = update_mode.map(rest_update_mode_to_internal);
+                (
+                    operation,
+                    shard_key,
+                    usage,
+                    update_filter,
+                    update_mode,
+                    expected_version,
+                )
+            }
+        };
 
-    // Decide which operation to use based on update_filter and update_mode
-    let operation = match (update_filter, update_mode) {
+    // Decide which operation to use based on update_filter, update_mode and expected_version
+    let operation = match (update_filter, update_mode, expected_version) {
         // If update_filter is provided, always use conditional upsert
-        (Some(condition), mode) => CollectionUpdateOperations::PointOperation(
+        (Some(condition), mode, expected_version) => CollectionUpdateOperations::PointOperation(
             PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
                 points_op: operation,
                 condition,
                 update_mode: mode,
+                expected_version,
             }),
         ),
-        // If update_mode is InsertOnly or UpdateOnly, use conditional upsert with empty filter
-        (None, Some(UpdateMode::InsertOnly)) | (None, Some(UpdateMode::UpdateOnly)) => {
+        // If update_mode is InsertOnly or UpdateOnly, or a version is expected,
+        // use conditional upsert with empty filter
+        (None, Some(UpdateMode::InsertOnly | UpdateMode::UpdateOnly), _) | (None, _, Some(_)) => {
             CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
                 ConditionalInsertOperationInternal {
                     points_op: operation,
                     condition: Filter::default(), // Empty filter matches all existing points
                     update_mode,
+                    expected_version,
                 },
             ))
         }
         // Default: regular upsert
-        (None, None) | (None, Some(UpdateMode::Upsert)) => {
+        (None, None | Some(UpdateMode::Upsert), None) => {
             CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(operation))
         }
     };
@@ -400,6 +424,25 @@ pub async fn do_upsert_points(
     Ok((result, usage))
 }
 
+/// Apply the duplicate ids `policy`, so each point id occurs in the `operation` only once
+fn resolve_duplicate_ids(
+    operation: &mut PointInsertOperationsInternal,
+    policy: DuplicateIdsPolicy,
+) -> Result<(), StorageError> {
+    match policy {
+        DuplicateIdsPolicy::LastWins => operation.dedup_point_ids(true),
+        DuplicateIdsPolicy::FirstWins => operation.dedup_point_ids(false),
+        DuplicateIdsPolicy::Error => {
+            if let Some(id) = operation.first_duplicate_id() {
+                return Err(StorageError::bad_input(format!(
+                    "Point id {id} occurs more than once in the request"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Convert REST UpdateMode to internal UpdateMode
 fn rest_update_mode_to_internal(mode: api::rest::schema::UpdateMode) -> point_ops::UpdateMode {
     match mode {
@@ -21,14 +21,18 @@ use ::common::budget::{ResourceBudget, get_io_budget};
 use ::common::cpu::get_cpu_budget;
 use ::common::flags::{feature_flags, init_feature_flags};
 use ::common::fs::{FsCheckResult, check_fs_info, check_mmap_functionality};
+use ::common::gridstore_index::init_gridstore_index_config;
+use ::common::index_build_limit::init_index_build_limit;
 use ::common::low_memory::init_low_memory_mode;
 use ::common::memory_usage::set_resident_bytes_reader;
 use ::common::mmap::MULTI_MMAP_SUPPORT_CHECK_RESULT;
 use ::common::mmap::advice::set_global;
 use ::tonic::transport::Uri;
-use api::grpc::transport_channel_pool::TransportChannelPool;
+use api::grpc::transport_channel_pool::{ShardUnavailableRetry, TransportChannelPool};
 use clap::Parser;
+use collection::profiling::index_advisor::{index_advisor_config, init_index_advisor};
 use collection::profiling::interface::init_requests_profile_collector;
+use collection::profiling::slow_query_log::init_slow_query_log;
 use collection::shards::channel_service::ChannelService;
 use collection::shards::shard::PeerId;
 use consensus::Consensus;
@@ -49,6 +53,7 @@ use storage::rbac::Access;
 use tikv_jemallocator::Jemalloc;
 
 use crate::common::helpers::load_tls_client_config;
+use crate::common::index_advisor::auto_create_indexes;
 use crate::common::inference::service::InferenceService;
 use crate::common::telemetry::TelemetryCollector;
 use crate::common::telemetry_reporting::TelemetryReporter;
@@ -304,12 +309,18 @@ fn init_channel_service(
 
         let tls_config = load_tls_client_config(settings)?;
 
+        let shard_unavailable_retry = ShardUnavailableRetry {
+            retries: settings.cluster.p2p.shard_unavailable_retries,
+            backoff: Duration::from_millis(settings.cluster.p2p.shard_unavailable_backoff_ms),
+        };
+
         channel_service.channel_pool = Arc::new(TransportChannelPool::new(
             p2p_grpc_timeout,
             connection_timeout,
             settings.cluster.p2p.connection_pool_size,
             tls_config,
             settings.service.api_key.clone(),
+            shard_unavailable_retry,
         ));
         channel_service.id_to_address = persistent_consensus_state.peer_address_by_id.clone();
         channel_service.id_to_metadata = persistent_consensus_state.peer_metadata_by_id.clone();
@@ -378,6 +389,17 @@ fn main() -> anyhow::Result<()> {
     // Set global low-memory mode, sourced from configuration
     init_low_memory_mode(settings.storage.low_memory_mode);
 
+    // Set global gridstore index write tuning, sourced from configuration
+    init_gridstore_index_config(settings.storage.performance.gridstore_index);
+
+    // Set global limit of concurrent field index builds, sourced from configuration
+    init_index_build_limit(
+        settings
+            .storage
+            .performance
+            .max_concurrent_field_index_builds,
+    );
+
     let reporting_enabled = !settings.telemetry_disabled && !args.disable_telemetry;
     let reporting_id = TelemetryCollector::generate_id();
 
@@ -689,6 +711,12 @@ fn main() -> anyhow::Result<()> {
     // Setup subscribers to listen for issue-able events
     issues_setup::setup_subscribers(&settings);
     init_requests_profile_collector(runtime_handle.clone());
+    init_slow_query_log(settings.service.slow_query_log.clone().unwrap_or_default());
+    init_index_advisor(settings.service.index_advisor.clone().unwrap_or_default());
+
+    if let Some(config) = index_advisor_config().filter(|config| config.auto_create) {
+        runtime_handle.spawn(auto_create_indexes(dispatcher_arc.clone(), config));
+    }
 
     // Helper to better log start errors
     let log_err_if_any = |server_name, result| match result {
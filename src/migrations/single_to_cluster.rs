@@ -2,11 +2,12 @@ use std::sync::Arc;
 
 use collection::collection_state::State;
 use collection::config::{CollectionConfigInternal, ShardingMethod};
+use collection::operations::boost_rules::BoostRulesDiff;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
 use collection::shards::shard::PeerId;
 use storage::content_manager::collection_meta_ops::{
     CollectionMetaOperations, CreateCollection, CreateCollectionOperation, CreateShardKey,
-    SetShardReplicaState,
+    SetShardReplicaState, UpdateCollectionOperation,
 };
 use storage::content_manager::consensus_manager::ConsensusStateRef;
 use storage::content_manager::shard_distribution::ShardDistributionProposal;
@@ -57,6 +58,7 @@ pub async fn handle_existing_collections(
             strict_mode_config,
             uuid,
             metadata,
+            boost_rules,
         } = config;
 
         let shards_number = params.shard_number.get();
@@ -133,6 +135,15 @@ pub async fn handle_existing_collections(
             }
         }
 
+        if !boost_rules.is_empty() {
+            let mut operation = UpdateCollectionOperation::new_empty(collection_name.clone());
+            operation.update_collection.boost_rules = Some(BoostRulesDiff {
+                upsert: boost_rules,
+                delete: Vec::new(),
+            });
+            consensus_operations.push(CollectionMetaOperations::UpdateCollection(operation));
+        }
+
         for operation in consensus_operations {
             let _res = dispatcher_arc
                 .submit_collection_meta_op(operation, full_auth.clone(), None)
@@ -1,11 +1,12 @@
 #![allow(dead_code)]
 
-use api::rest::models::{CollectionsResponse, ShardKeysResponse, Usage, VersionInfo};
+use api::rest::models::{CollectionsResponse, ErrorCode, ShardKeysResponse, Usage, VersionInfo};
 use api::rest::schema::PointInsertOperations;
 use api::rest::{
-    FacetRequest, FacetResponse, QueryGroupsRequest, QueryRequest, QueryRequestBatch,
+    FacetRequest, FacetResponse, FederatedQueryRequest, FederatedQueryResponse, KDistanceRequest,
+    KDistanceResponse, QuantilesRequest, QueryGroupsRequest, QueryRequest, QueryRequestBatch,
     QueryResponse, Record, ScoredPoint, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
-    SearchMatrixRequest, UpdateVectors,
+    SearchMatrixRequest, SimilarityMatrixRequest, SimilarityMatrixResponse, UpdateVectors,
 };
 use collection::operations::cluster_ops::ClusterOperations;
 use collection::operations::consistency_params::ReadConsistency;
@@ -17,11 +18,14 @@ use collection::operations::snapshot_ops::{
 use collection::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionExistence, CollectionInfo,
     CollectionsAliasesResponse, CountRequest, CountResult, DiscoverRequest, DiscoverRequestBatch,
-    GroupsResult, PointGroup, PointRequest, RecommendGroupsRequest, RecommendRequest,
+    FilterValidationResult, GroupsResult, PointExistence, PointGroup, PointRequest,
+    PointsExistenceRequest, QuantizedVectorResponse, RecommendGroupsRequest, RecommendRequest,
     RecommendRequestBatch, ScrollRequest, ScrollResult, SearchGroupsRequest, SearchRequest,
-    SearchRequestBatch, UpdateResult,
+    SearchRequestBatch, TokenizeRequest, TokenizeResponse, UpdateResult, ValidateFilterRequest,
+    WarmupRequest, WarmupResponse,
 };
 use collection::operations::vector_ops::DeleteVectors;
+use collection::profiling::index_advisor::IndexRecommendation;
 use schemars::JsonSchema;
 use schemars::r#gen::SchemaSettings;
 use serde::Serialize;
@@ -102,6 +106,25 @@ struct AllDefinitions {
     bp: OptimizationsResponse,
     bq: DistributedTelemetryData,
     br: segment::data_types::vector_name_config::VectorNameConfig,
+    bs: ValidateFilterRequest,
+    bt: FilterValidationResult,
+    bu: IndexRecommendation,
+    bv: PointsExistenceRequest,
+    bw: QuantilesRequest,
+    bx: segment::data_types::quantiles::QuantilesResponse,
+    by: SimilarityMatrixRequest,
+    bz: SimilarityMatrixResponse,
+    ca: KDistanceRequest,
+    cb: KDistanceResponse,
+    cc: FederatedQueryRequest,
+    cd: FederatedQueryResponse,
+    ce: TokenizeRequest,
+    cf: TokenizeResponse,
+    cg: ErrorCode,
+    ch: PointExistence,
+    ci: WarmupRequest,
+    cj: WarmupResponse,
+    ck: QuantizedVectorResponse,
 }
 
 fn save_schema<T: JsonSchema>() {
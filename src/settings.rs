@@ -2,9 +2,11 @@ use std::borrow::Cow;
 use std::{env, io};
 
 use api::grpc::transport_channel_pool::{
-    DEFAULT_CONNECT_TIMEOUT, DEFAULT_GRPC_TIMEOUT, DEFAULT_POOL_SIZE,
+    DEFAULT_CONNECT_TIMEOUT, DEFAULT_GRPC_TIMEOUT, DEFAULT_POOL_SIZE, ShardUnavailableRetry,
 };
 use collection::operations::validation;
+use collection::profiling::index_advisor::IndexAdvisorConfig;
+use collection::profiling::slow_query_log::SlowQueryLogConfig;
 use collection::shards::shard::PeerId;
 use common::flags::FeatureFlags;
 use config::{Config, ConfigError, Environment, File, FileFormat, Source};
@@ -73,6 +75,11 @@ pub struct ServiceConfig {
     #[serde(default)]
     pub enforce_internal_auth: Option<bool>,
 
+    /// gRPC methods, which bypass authentication, e.g. for readiness probes.
+    /// Default: `/qdrant.Qdrant/HealthCheck` and `/grpc.health.v1.Health/Check`.
+    #[serde(default)]
+    pub grpc_auth_bypass_paths: Option<Vec<String>>,
+
     #[serde(default)]
     pub hide_jwt_dashboard: Option<bool>,
 
@@ -89,10 +96,26 @@ pub struct ServiceConfig {
     /// How much time is considered too long for a query to execute.
     pub slow_query_secs: Option<f32>,
 
+    /// Structured logging of slow queries with their execution details.
+    #[serde(default)]
+    #[validate(nested)]
+    pub slow_query_log: Option<SlowQueryLogConfig>,
+
+    /// Recommendations, and optional automatic creation, of payload indexes for fields
+    /// frequently filtered without an index.
+    #[serde(default)]
+    #[validate(nested)]
+    pub index_advisor: Option<IndexAdvisorConfig>,
+
     /// Whether to enable reporting of measured hardware utilization in API responses.
     #[serde(default)]
     pub hardware_reporting: Option<bool>,
 
+    /// Max random jitter in seconds, added to the `Retry-After` header of rate limited REST
+    /// responses, so clients don't all retry at the same second. Default: 0, no jitter.
+    #[serde(default)]
+    pub retry_after_max_jitter_sec: Option<u32>,
+
     /// Global prefix for metrics.
     #[serde(default)]
     #[validate(custom(function = validate_metrics_prefix))]
@@ -143,6 +166,16 @@ pub struct P2pConfig {
     pub connection_pool_size: usize,
     #[serde(default)]
     pub enable_tls: bool,
+    /// Max number of extra retries of internal reads, that a peer rejected because the target
+    /// shard is temporarily unavailable there, e.g. during a failover. Afterwards such reads are
+    /// retried like any other unavailable peer. Updates are never retried.
+    /// Default: 0, which only makes the regular retries.
+    #[serde(default = "default_shard_unavailable_retries")]
+    pub shard_unavailable_retries: usize,
+    /// Backoff before the first retry of a shard unavailable rejection, doubled for each next one
+    #[serde(default = "default_shard_unavailable_backoff_ms")]
+    #[validate(range(min = 1))]
+    pub shard_unavailable_backoff_ms: u64,
 }
 
 impl Default for P2pConfig {
@@ -151,6 +184,8 @@ impl Default for P2pConfig {
             port: None,
             connection_pool_size: default_connection_pool_size(),
             enable_tls: false,
+            shard_unavailable_retries: default_shard_unavailable_retries(),
+            shard_unavailable_backoff_ms: default_shard_unavailable_backoff_ms(),
         }
     }
 }
@@ -473,6 +508,14 @@ const fn default_connection_pool_size() -> usize {
     DEFAULT_POOL_SIZE
 }
 
+fn default_shard_unavailable_retries() -> usize {
+    ShardUnavailableRetry::default().retries
+}
+
+fn default_shard_unavailable_backoff_ms() -> u64 {
+    ShardUnavailableRetry::default().backoff.as_millis() as u64
+}
+
 const fn default_message_timeout_tics() -> u64 {
     10
 }
@@ -1,3 +1,4 @@
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -12,12 +13,14 @@ use api::grpc::qdrant::{
     RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
     ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse,
     SearchMatrixOffsets, SearchMatrixOffsetsResponse, SearchMatrixPairs, SearchMatrixPairsResponse,
-    SearchMatrixPoints, SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints,
-    UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
+    SearchMatrixPoints, SearchPointGroups, SearchPoints, SearchResponse, SearchStreamResponse,
+    SearchStreamSummary, SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse,
+    UpdatePointVectors, UpsertPoints, search_stream_response,
 };
 use api::grpc::{PointsOperationResponseInternal, Usage};
 use collection::operations::types::CoreSearchRequest;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::{Stream, StreamExt as _};
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
 use storage::dispatcher::Dispatcher;
 use tonic::{Request, Response, Status};
@@ -401,6 +404,50 @@ impl Points for PointsService {
         Ok(res)
     }
 
+    type SearchStreamStream =
+        Pin<Box<dyn Stream<Item = Result<SearchStreamResponse, Status>> + Send>>;
+
+    async fn search_stream(
+        &self,
+        mut request: Request<SearchPoints>,
+    ) -> Result<Response<Self::SearchStreamStream>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+
+        let collection_name = request.get_ref().collection_name.clone();
+        let hw_metrics = self.get_request_collection_hw_usage_counter(collection_name, None);
+
+        // Points are ranked across all shards before the first one is sent,
+        // errors are returned before the stream starts
+        let (scored_points, time) = search_scored_points(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            None,
+            auth,
+            &hw_metrics,
+        )
+        .await?;
+
+        // Points are converted into gRPC messages only as the stream is consumed
+        let points = futures::stream::iter(scored_points).map(|point| SearchStreamResponse {
+            response: Some(search_stream_response::Response::Point(point.into())),
+        });
+        let summary = futures::stream::once(async move {
+            SearchStreamResponse {
+                response: Some(search_stream_response::Response::Summary(
+                    SearchStreamSummary {
+                        time,
+                        usage: Usage::from_hardware_usage(hw_metrics.to_grpc_api())
+                            .into_non_empty(),
+                    },
+                )),
+            }
+        });
+
+        let stream = points.chain(summary).map(Ok);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
     async fn search_batch(
         &self,
         mut request: Request<SearchBatchPoints>,
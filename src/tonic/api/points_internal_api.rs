@@ -10,11 +10,13 @@ use api::grpc::qdrant::{
     CreateFieldIndexCollectionInternal, CreateVectorNameInternal,
     DeleteFieldIndexCollectionInternal, DeletePayloadPointsInternal, DeletePointsInternal,
     DeleteVectorNameInternal, DeleteVectorsInternal, FacetCountsInternal, FacetResponseInternal,
-    GetPointsInternal, GetResponse, IntermediateResult, PointsOperationResponseInternal,
+    GetPointsInternal, GetResponse, IntermediateResult, PointVersionInternal,
+    PointVersionsInternal, PointVersionsResponseInternal, PointsOperationResponseInternal,
     QueryBatchPointsInternal, QueryBatchResponseInternal, QueryResultInternal, QueryShardPoints,
-    RecommendPointsInternal, RecommendResponse, ScrollPointsInternal, ScrollResponse,
+    QueryStats, RecommendPointsInternal, RecommendResponse, ScrollPointsInternal, ScrollResponse,
     SearchBatchResponse, SetPayloadPointsInternal, SyncPointsInternal, UpdateBatchInternal,
-    UpdateVectorsInternal, UpsertPointsInternal,
+    UpdateVectorsInternal, UpsertPointsInternal, ValueSamplesInternal,
+    ValueSamplesResponseInternal,
 };
 use api::grpc::update_operation::Update;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
@@ -422,13 +424,14 @@ pub async fn query_batch_internal(
         Some(shard_id) => ShardSelectorInternal::ShardId(shard_id),
     };
 
+    let hw_measurement_acc = request_hw_data.get_counter();
     let batch_response = toc
         .query_batch_internal(
             &collection_name,
             batch_requests,
             shard_selection,
             timeout,
-            request_hw_data.get_counter(),
+            hw_measurement_acc.clone(),
         )
         .await?;
 
@@ -447,6 +450,7 @@ pub async fn query_batch_internal(
         time: timing.elapsed().as_secs_f64(),
         hardware_usage: request_hw_data.to_grpc_api(),
         inference_usage: None, // No inference in internal API
+        stats: QueryStats::from(&hw_measurement_acc.query_stats()).into_non_empty(),
     };
 
     Ok(Response::new(response))
@@ -489,12 +493,90 @@ async fn facet_counts_internal(
         )
         .await?;
 
-    let FacetResponse { hits } = response;
+    let FacetResponse { hits, other_count } = response;
 
     let response = FacetResponseInternal {
         hits: hits.into_iter().map(From::from).collect_vec(),
         time: timing.elapsed().as_secs_f64(),
         usage: request_hw_data.to_grpc_api(),
+        other_count: other_count as u64,
+    };
+
+    Ok(Response::new(response))
+}
+
+async fn value_samples_internal(
+    toc: &TableOfContent,
+    request: ValueSamplesInternal,
+) -> Result<Response<ValueSamplesResponseInternal>, Status> {
+    let timing = Instant::now();
+
+    let ValueSamplesInternal {
+        collection_name,
+        key,
+        shard_id,
+        timeout,
+    } = request;
+
+    let key = JsonPath::from_str(&key)
+        .map_err(|_| Status::invalid_argument("Failed to parse value samples key"))?;
+
+    let samples = toc
+        .value_samples_internal(
+            &collection_name,
+            &key,
+            ShardSelectorInternal::ShardId(shard_id),
+            timeout.map(Duration::from_secs),
+        )
+        .await?;
+
+    let response = ValueSamplesResponseInternal {
+        samples: samples.samples.into_iter().map(From::from).collect_vec(),
+        time: timing.elapsed().as_secs_f64(),
+    };
+
+    Ok(Response::new(response))
+}
+
+async fn point_versions_internal(
+    toc: &TableOfContent,
+    request: PointVersionsInternal,
+    request_hw_data: RequestHwCounter,
+) -> Result<Response<PointVersionsResponseInternal>, Status> {
+    let timing = Instant::now();
+
+    let PointVersionsInternal {
+        collection_name,
+        ids,
+        shard_id,
+        timeout,
+    } = request;
+
+    let ids = ids
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect::<Result<_, Status>>()?;
+
+    let versions = toc
+        .point_versions_internal(
+            &collection_name,
+            ids,
+            shard_id,
+            timeout.map(Duration::from_secs),
+            request_hw_data.get_counter(),
+        )
+        .await?;
+
+    let response = PointVersionsResponseInternal {
+        versions: versions
+            .into_iter()
+            .map(|point| PointVersionInternal {
+                id: Some(point.id.into()),
+                version: point.version,
+            })
+            .collect_vec(),
+        time: timing.elapsed().as_secs_f64(),
+        usage: request_hw_data.to_grpc_api(),
     };
 
     Ok(Response::new(response))
@@ -1012,6 +1094,28 @@ impl PointsInternal for PointsInternalService {
         );
         facet_counts_internal(self.toc.as_ref(), request_inner, hw_data).await
     }
+
+    async fn value_samples(
+        &self,
+        request: Request<ValueSamplesInternal>,
+    ) -> Result<Response<ValueSamplesResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        value_samples_internal(self.toc.as_ref(), request.into_inner()).await
+    }
+
+    async fn point_versions(
+        &self,
+        request: Request<PointVersionsInternal>,
+    ) -> Result<Response<PointVersionsResponseInternal>, Status> {
+        validate_and_log(request.get_ref());
+
+        let request_inner = request.into_inner();
+        let hw_data = self.get_request_collection_hw_usage_counter_for_internal(
+            request_inner.collection_name.clone(),
+        );
+        point_versions_internal(self.toc.as_ref(), request_inner, hw_data).await
+    }
 }
 
 fn extract_internal_request<T>(request: Option<T>) -> Result<T, tonic::Status> {
@@ -4,11 +4,12 @@ use api::conversions::json::json_path_from_proto;
 use api::grpc::qdrant::{
     BatchResult, CoreSearchPoints, CountPoints, CountResponse, DiscoverBatchResponse,
     DiscoverPoints, DiscoverResponse, FacetCounts, FacetResponse, GetPoints, GetResponse,
-    GroupsResult, QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints,
-    QueryResponse, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints,
-    SearchParams, SearchPointGroups, SearchPoints, SearchResponse, WithVectorsSelector,
+    GroupsResult, QueryBatchResponse, QueryFacet, QueryFacetResult, QueryGroupsResponse,
+    QueryPointGroups, QueryPoints, QueryResponse, QueryStats,
+    ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse, RecommendGroupsResponse,
+    RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
+    SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints, SearchParams, SearchPointGroups,
+    SearchPoints, SearchResponse, WithVectorsSelector,
 };
 use api::grpc::{InferenceUsage, Usage};
 use collection::collection::distance_matrix::{
@@ -67,6 +68,35 @@ pub async fn search(
     auth: Auth,
     hw_measurement_acc: RequestHwCounter,
 ) -> Result<Response<SearchResponse>, Status> {
+    let (scored_points, time) = search_scored_points(
+        toc_provider,
+        search_points,
+        shard_selection,
+        auth,
+        &hw_measurement_acc,
+    )
+    .await?;
+
+    let response = SearchResponse {
+        result: scored_points.into_iter().map(ScoredPoint::into).collect(),
+        time,
+        usage: Usage::from_hardware_usage(hw_measurement_acc.to_grpc_api()).into_non_empty(),
+    };
+
+    Ok(Response::new(response))
+}
+
+/// Points found by the search, merged across shards in the final ranked order, and the time
+/// spent on the search, in seconds
+///
+/// Points are not converted into gRPC types yet, so the caller may stream them one by one.
+pub async fn search_scored_points(
+    toc_provider: impl CheckedTocProvider,
+    search_points: SearchPoints,
+    shard_selection: Option<ShardId>,
+    auth: Auth,
+    hw_measurement_acc: &RequestHwCounter,
+) -> Result<(Vec<ScoredPoint>, f64), Status> {
     let SearchPoints {
         collection_name,
         vector,
@@ -131,13 +161,7 @@ pub async fn search(
     )
     .await?;
 
-    let response = SearchResponse {
-        result: scored_points.into_iter().map(ScoredPoint::into).collect(),
-        time: timing.elapsed().as_secs_f64(),
-        usage: Usage::from_hardware_usage(hw_measurement_acc.to_grpc_api()).into_non_empty(),
-    };
-
-    Ok(Response::new(response))
+    Ok((scored_points, timing.elapsed().as_secs_f64()))
 }
 
 pub async fn core_search_batch(
@@ -771,9 +795,40 @@ pub async fn get(
     Ok(Response::new(response))
 }
 
+/// Convert facets of a query into facet requests, counted over the points matching `filter`
+fn convert_query_facets_from_grpc(
+    facets: Vec<QueryFacet>,
+    filter: Option<&api::grpc::qdrant::Filter>,
+) -> Result<Vec<FacetParams>, Status> {
+    if facets.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let filter: Option<segment::types::Filter> =
+        filter.cloned().map(TryInto::try_into).transpose()?;
+
+    facets
+        .into_iter()
+        .map(|facet| {
+            let QueryFacet { key, limit } = facet;
+            let limit = limit
+                .map(usize::try_from)
+                .transpose()
+                .map_err(|_| Status::invalid_argument("could not parse limit param into usize"))?
+                .unwrap_or(FacetParams::DEFAULT_LIMIT);
+            Ok(FacetParams {
+                key: json_path_from_proto(&key)?,
+                filter: filter.clone(),
+                limit,
+                exact: FacetParams::DEFAULT_EXACT,
+            })
+        })
+        .collect()
+}
+
 pub async fn query(
     toc_provider: impl CheckedTocProvider,
-    query_points: QueryPoints,
+    mut query_points: QueryPoints,
     shard_selection: Option<ShardId>,
     auth: Auth,
     request_hw_counter: RequestHwCounter,
@@ -788,6 +843,10 @@ pub async fn query(
         .transpose()?;
     let collection_name = query_points.collection_name.clone();
     let timeout = query_points.timeout;
+    let facets = convert_query_facets_from_grpc(
+        std::mem::take(&mut query_points.facets),
+        query_points.filter.as_ref(),
+    )?;
     let (request, inference_usage) =
         convert_query_points_from_grpc(query_points, inference_params).await?;
 
@@ -800,25 +859,64 @@ pub async fn query(
         )
         .await?;
 
+    for facet in &facets {
+        toc_provider
+            .check_strict_mode(facet, &collection_name, timeout.map(|i| i as usize), &auth)
+            .await?;
+    }
+
     let timeout = timeout.map(Duration::from_secs);
 
     let timing = Instant::now();
-    let scored_points = do_query_points(
+    let query_stats = request_hw_counter.get_counter().query_stats();
+    let points_response = do_query_points(
         toc,
         &collection_name,
         request,
         read_consistency,
-        shard_selector,
-        auth,
+        shard_selector.clone(),
+        auth.clone(),
         timeout,
         request_hw_counter.get_counter(),
-    )
-    .await?;
+    );
+
+    let facets_response = async {
+        if facets.is_empty() {
+            return Ok(Vec::new());
+        }
+        do_query_facets(
+            toc,
+            &collection_name,
+            facets,
+            read_consistency,
+            shard_selector.clone(),
+            auth.clone(),
+            timeout,
+            request_hw_counter.get_counter(),
+        )
+        .await
+    };
+
+    let (scored_points, facets) = tokio::try_join!(points_response, facets_response)?;
+
+    let facets = facets
+        .into_iter()
+        .map(|(key, facet_response)| {
+            let segment::data_types::facets::FacetResponse { hits, other_count } = facet_response;
+            QueryFacetResult {
+                key: key.to_string(),
+                hits: hits.into_iter().map(From::from).collect(),
+                other: other_count as u64,
+            }
+        })
+        .collect();
 
     let response = QueryResponse {
         result: scored_points.into_iter().map(ScoredPoint::into).collect(),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::new(request_hw_counter.to_grpc_api(), Some(inference_usage)).into_non_empty(),
+        facets,
+        stats: QueryStats::from(&query_stats).into_non_empty(),
     };
 
     Ok(Response::new(response))
@@ -840,6 +938,12 @@ pub async fn query_batch(
     let mut total_inference_usage = InferenceUsage::default();
 
     for query_points in points {
+        if !query_points.facets.is_empty() {
+            return Err(Status::invalid_argument(
+                "Facets are not supported in batch queries",
+            ));
+        }
+
         let shard_key_selector = query_points.shard_key_selector.clone();
         let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
         let (request, usage) =
@@ -999,12 +1103,13 @@ pub async fn facet(
         )
         .await?;
 
-    let segment::data_types::facets::FacetResponse { hits } = facet_response;
+    let segment::data_types::facets::FacetResponse { hits, other_count } = facet_response;
 
     let response = FacetResponse {
         hits: hits.into_iter().map(From::from).collect(),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+        other: other_count as u64,
     };
 
     Ok(Response::new(response))
@@ -46,6 +46,7 @@ fn test_storage_config(storage_path: &Path) -> StorageConfig {
             flush_interval_sec: 2,
             max_optimization_threads: Some(2),
             prevent_unoptimized: None,
+            requantization: None,
         },
         optimizers_overwrite: None,
         wal: WalConfig::default(),
@@ -60,6 +61,8 @@ fn test_storage_config(storage_path: &Path) -> StorageConfig {
             outgoing_shard_transfers_limit: Some(1),
             async_scorer: None,
             load_concurrency: LoadConcurrencyConfig::default(),
+            gridstore_index: Default::default(),
+            max_concurrent_field_index_builds: None,
         },
         hnsw_index: HnswConfig::default(),
         hnsw_global_config: HnswGlobalConfig::default(),
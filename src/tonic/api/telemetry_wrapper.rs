@@ -188,6 +188,18 @@ impl<T: Points> Points for PointsTelemetryWrapper<T> {
         Ok(resp)
     }
 
+    type SearchStreamStream = T::SearchStreamStream;
+
+    async fn search_stream(
+        &self,
+        request: Request<SearchPoints>,
+    ) -> Result<Response<Self::SearchStreamStream>, Status> {
+        let cn = request.get_ref().collection_name.clone();
+        let mut resp = self.inner.search_stream(request).await?;
+        resp.extensions_mut().insert(CollectionName(cn));
+        Ok(resp)
+    }
+
     async fn search_batch(
         &self,
         request: Request<SearchBatchPoints>,
@@ -483,6 +495,16 @@ mod tests {
                         Ok(Response::new(Default::default()))
                     }
                 )*
+
+                type SearchStreamStream =
+                    futures::stream::Empty<Result<SearchStreamResponse, Status>>;
+
+                async fn search_stream(
+                    &self,
+                    r: Request<SearchPoints>,
+                ) -> Result<Response<Self::SearchStreamStream>, Status> {
+                    Ok(Response::new(futures::stream::empty()))
+                }
             }
 
             $(
@@ -538,6 +560,22 @@ mod tests {
         search_matrix_offsets(SearchMatrixPoints) -> SearchMatrixOffsetsResponse,
     }
 
+    #[tokio::test]
+    async fn search_stream() {
+        let w = PointsTelemetryWrapper::new(MockPoints);
+        let r = w
+            .search_stream(Request::new(SearchPoints {
+                collection_name: "search_stream".into(),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            r.extensions().get::<CollectionName>().unwrap().0,
+            "search_stream",
+        );
+    }
+
     // Snapshots
     macro_rules! mock_and_test_snapshots {
         (
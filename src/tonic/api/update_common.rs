@@ -54,6 +54,8 @@ pub async fn upsert(
         update_filter,
         timeout,
         update_mode,
+        expected_version,
+        duplicate_ids,
     } = upsert_points;
 
     let points: Result<_, _> = points.into_iter().map(PointStruct::try_from).collect();
@@ -67,6 +69,8 @@ pub async fn upsert(
             .map(segment::types::Filter::try_from)
             .transpose()?,
         update_mode: update_mode.map(grpc_update_mode_to_rest),
+        expected_version,
+        duplicate_ids: duplicate_ids.map(grpc_duplicate_ids_policy_to_rest),
     });
 
     let timing = Instant::now();
@@ -100,6 +104,17 @@ fn grpc_update_mode_to_rest(mode: i32) -> api::rest::schema::UpdateMode {
     }
 }
 
+fn grpc_duplicate_ids_policy_to_rest(policy: i32) -> api::rest::schema::DuplicateIdsPolicy {
+    use api::grpc::qdrant::DuplicateIdsPolicy as GrpcPolicy;
+    use api::rest::schema::DuplicateIdsPolicy as RestPolicy;
+
+    match GrpcPolicy::try_from(policy) {
+        Ok(GrpcPolicy::FirstWins) => RestPolicy::FirstWins,
+        Ok(GrpcPolicy::Error) => RestPolicy::Error,
+        Ok(GrpcPolicy::LastWins) | Err(_) => RestPolicy::LastWins,
+    }
+}
+
 pub async fn delete(
     toc_provider: impl CheckedTocProvider,
     delete_points: DeletePoints,
@@ -460,6 +475,8 @@ pub async fn update_batch(
                 shard_key_selector,
                 update_filter,
                 update_mode,
+                expected_version,
+                duplicate_ids,
             }) => {
                 upsert(
                     StrictModeCheckedTocProvider::new(dispatcher),
@@ -472,6 +489,8 @@ pub async fn update_batch(
                         update_filter,
                         timeout,
                         update_mode,
+                        expected_version,
+                        duplicate_ids,
                     },
                     internal_params,
                     auth.clone(),
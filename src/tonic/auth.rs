@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
@@ -15,13 +16,51 @@ use crate::common::inference::api_keys::InferenceToken;
 type Request = tonic::codegen::http::Request<tonic::transport::Body>;
 type Response = tonic::codegen::http::Response<BoxBody>;
 
+/// gRPC methods, which bypass authentication if not configured otherwise, for health checks
+pub const DEFAULT_AUTH_BYPASS_PATHS: [&str; 2] =
+    ["/qdrant.Qdrant/HealthCheck", "/grpc.health.v1.Health/Check"];
+
 #[derive(Clone)]
 pub struct AuthMiddleware<S> {
     auth_keys: Arc<AuthKeys>,
+    bypass_paths: Arc<HashSet<String>>,
     service: S,
 }
 
-async fn check(auth_keys: Arc<AuthKeys>, mut req: Request) -> Result<Request, Status> {
+/// Give the request full access without authentication, if its gRPC method is one of
+/// `bypass_paths`.
+///
+/// Returns `false` if the request must be authenticated.
+fn try_bypass_auth(
+    bypass_paths: &HashSet<String>,
+    req: &mut Request,
+    remote: &Option<String>,
+    tracing_id: &Option<String>,
+) -> bool {
+    if !bypass_paths.contains(req.uri().path()) {
+        return false;
+    }
+
+    let auth = Auth::new(
+        Access::full("Auth bypass endpoints have full access without authentication"),
+        None,
+        remote.clone(),
+        AuthType::None,
+        tracing_id.clone(),
+    );
+    let inference_token = InferenceToken(None);
+
+    req.extensions_mut().insert(auth);
+    req.extensions_mut().insert(inference_token);
+
+    true
+}
+
+async fn check(
+    auth_keys: Arc<AuthKeys>,
+    bypass_paths: Arc<HashSet<String>>,
+    mut req: Request,
+) -> Result<Request, Status> {
     // When the audit logger trusts forwarded headers, prefer the raw
     // `X-Forwarded-For` value so audit entries record the real client address
     // rather than the proxy address.  Fall back to the TCP peer address.
@@ -44,25 +83,12 @@ async fn check(auth_keys: Arc<AuthKeys>, mut req: Request) -> Result<Request, St
             .map(str::to_string)
     });
 
-    // Allow health check endpoints to bypass authentication
-    let path = req.uri().path();
-    if path == "/qdrant.Qdrant/HealthCheck" || path == "/grpc.health.v1.Health/Check" {
-        // Set default full access for health check endpoints
-        let auth = Auth::new(
-            Access::full("Health check endpoints have full access without authentication"),
-            None,
-            remote,
-            AuthType::None,
-            tracing_id,
-        );
-        let inference_token = InferenceToken(None);
-
-        req.extensions_mut().insert(auth);
-        req.extensions_mut().insert(inference_token);
-
+    // Allow configured endpoints, health checks by default, to bypass authentication
+    if try_bypass_auth(&bypass_paths, &mut req, &remote, &tracing_id) {
         return Ok(req);
     }
 
+    let path = req.uri().path();
     let (access, inference_token, auth_type, subject) = auth_keys
         .validate_request(|key| req.headers().get(key).and_then(|val| val.to_str().ok()))
         .await
@@ -109,10 +135,11 @@ where
 
     fn call(&mut self, request: Request) -> Self::Future {
         let auth_keys = self.auth_keys.clone();
+        let bypass_paths = self.bypass_paths.clone();
         let mut service = self.service.clone();
 
         Box::pin(async move {
-            match check(auth_keys, request).await {
+            match check(auth_keys, bypass_paths, request).await {
                 Ok(req) => service.call(req).await,
                 Err(e) => Ok(e.to_http()),
             }
@@ -123,12 +150,20 @@ where
 #[derive(Clone)]
 pub struct AuthLayer {
     auth_keys: Arc<AuthKeys>,
+    bypass_paths: Arc<HashSet<String>>,
 }
 
 impl AuthLayer {
-    pub fn new(auth_keys: AuthKeys) -> Self {
+    /// Layer authenticating all requests, except for the gRPC methods in `bypass_paths`.
+    /// If `bypass_paths` is `None`, [`DEFAULT_AUTH_BYPASS_PATHS`] are used.
+    pub fn new(auth_keys: AuthKeys, bypass_paths: Option<Vec<String>>) -> Self {
+        let bypass_paths = match bypass_paths {
+            Some(paths) => paths.into_iter().collect(),
+            None => DEFAULT_AUTH_BYPASS_PATHS.map(str::to_string).into(),
+        };
         Self {
             auth_keys: Arc::new(auth_keys),
+            bypass_paths: Arc::new(bypass_paths),
         }
     }
 }
@@ -139,6 +174,7 @@ impl<S> Layer<S> for AuthLayer {
     fn layer(&self, service: S) -> Self::Service {
         Self::Service {
             auth_keys: self.auth_keys.clone(),
+            bypass_paths: self.bypass_paths.clone(),
             service,
         }
     }
@@ -164,3 +200,37 @@ pub fn extract_auth<R>(req: &mut tonic::Request<R>) -> Auth {
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(path: &str) -> Request {
+        tonic::codegen::http::Request::builder()
+            .uri(path)
+            .body(tonic::transport::Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_auth_bypass_paths() {
+        let default_paths: HashSet<_> = DEFAULT_AUTH_BYPASS_PATHS.map(str::to_string).into();
+        let custom_paths = HashSet::from(["/sidecar.Probe/Ready".to_string()]);
+
+        let mut req = request("/qdrant.Qdrant/HealthCheck");
+        assert!(try_bypass_auth(&default_paths, &mut req, &None, &None));
+        assert!(req.extensions().get::<Auth>().is_some());
+
+        let mut req = request("/sidecar.Probe/Ready");
+        assert!(!try_bypass_auth(&default_paths, &mut req, &None, &None));
+        assert!(try_bypass_auth(&custom_paths, &mut req, &None, &None));
+        assert!(req.extensions().get::<Auth>().is_some());
+
+        // Paths not in the set still require auth, including the default ones
+        for path in ["/qdrant.Points/Search", "/qdrant.Qdrant/HealthCheck"] {
+            let mut req = request(path);
+            assert!(!try_bypass_auth(&custom_paths, &mut req, &None, &None));
+            assert!(req.extensions().get::<Auth>().is_none());
+        }
+    }
+}
@@ -171,7 +171,10 @@ pub fn init(
                         .toc(&auth, &new_unchecked_verification_pass())
                         .clone(),
                 )
-                .map(auth::AuthLayer::new)
+                .map(|auth_keys| {
+                    let bypass_paths = settings.service.grpc_auth_bypass_paths.clone();
+                    auth::AuthLayer::new(auth_keys, bypass_paths)
+                })
             })
             .into_inner();
 
@@ -253,7 +256,10 @@ pub fn init_internal(
             // across a rolling upgrade while `enforce_internal_auth` is false.
             let internal_auth_layer = if settings.service.enforce_internal_auth.unwrap_or_default()
             {
-                AuthKeys::try_create(&settings.service, toc.clone()).map(auth::AuthLayer::new)
+                AuthKeys::try_create(&settings.service, toc.clone()).map(|auth_keys| {
+                    let bypass_paths = settings.service.grpc_auth_bypass_paths.clone();
+                    auth::AuthLayer::new(auth_keys, bypass_paths)
+                })
             } else {
                 None
             };
@@ -159,6 +159,12 @@ fi
   "limit": 3
 }' $QDRANT_HOST qdrant.Points/Search
 
+"${docker_grpcurl[@]}" -d '{
+  "collection_name": "test_collection",
+  "vector": [0.2,0.1,0.9,0.7],
+  "limit": 3
+}' $QDRANT_HOST qdrant.Points/SearchStream
+
 "${docker_grpcurl[@]}" -d '{
   "collection_name": "test_collection",
   "filter": {
@@ -241,6 +247,23 @@ fi
   "key": "city"
 }' $QDRANT_HOST qdrant.Points/Facet
 
+# query with city facet, counted over the points matching the filter
+response=$("${docker_grpcurl[@]}" -d '{
+  "collection_name": "test_collection",
+  "query": { "nearest": { "dense": { "data": [0.2,0.1,0.9,0.7] } } },
+  "filter": { "must": [{ "field": { "key": "city", "match": { "keyword": "London" } } }] },
+  "limit": 1,
+  "facets": [{ "key": "city" }]
+}' $QDRANT_HOST qdrant.Points/Query)
+
+points_count=$(echo "$response" | jq '.result | length')
+city_counts=$(echo "$response" | jq -c '.facets[0].hits | map({(.value.stringValue): (.count | tonumber)}) | add')
+
+if [[ "$points_count" != 1 || "$city_counts" != '{"London":2,"Berlin":1,"Moscow":1}' ]]; then
+  echo "Unexpected facets in query response: $response"
+  exit 1
+fi
+
 # create alias
 "${docker_grpcurl[@]}" -d '{
   "actions": [
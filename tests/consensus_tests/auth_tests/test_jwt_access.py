@@ -154,6 +154,12 @@ ACTION_ACCESS = {
         "GET /collections/{collection_name}/exists",
         "qdrant.Collections/CollectionExists",
     ),
+    "validate_filter": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/filter/validate",
+    ),
     "get_optimizations": EndpointAccess(
         True,
         True,
@@ -294,6 +300,30 @@ ACTION_ACCESS = {
         "qdrant.Points/DeleteFieldIndex",
         coll_prw=False,
     ),
+    "tokenize_text": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/index/{field_name}/tokenize",
+    ),
+    "get_point_tokens": EndpointAccess(
+        True,
+        True,
+        True,
+        "GET /collections/{collection_name}/index/{field_name}/points/{id}/tokens",
+    ),
+    "get_index_recommendations": EndpointAccess(
+        False,
+        False,
+        True,
+        "GET /collections/{collection_name}/index/recommendations",
+    ),
+    "warmup_collection": EndpointAccess(
+        False,
+        False,
+        True,
+        "POST /collections/{collection_name}/warmup",
+    ),
     "create_vector_name": EndpointAccess(
         False,
         True,
@@ -425,6 +455,12 @@ ACTION_ACCESS = {
         True,
         "GET /collections/{collection_name}/points/{id}",
     ),
+    "get_point_quantized_vector": EndpointAccess(
+        True,
+        True,
+        True,
+        "GET /collections/{collection_name}/points/{id}/quantized_vector",
+    ),
     "get_points": EndpointAccess(
         True,
         True,
@@ -433,6 +469,13 @@ ACTION_ACCESS = {
         "qdrant.Points/Get",
         coll_prw=True,
     ),
+    "check_points_exist": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/exists",
+        coll_prw=True,
+    ),
     "upsert_points": EndpointAccess(
         False,
         True,
@@ -521,6 +564,21 @@ ACTION_ACCESS = {
         "qdrant.Points/Search",
         coll_prw=True,
     ),
+    "search_k_distance": EndpointAccess(
+        True,
+        True,
+        True,
+        "POST /collections/{collection_name}/points/search/k_distance",
+        coll_prw=True,
+    ),
+    "search_points_stream": EndpointAccess(
+        True,
+        True,
+        True,
+        None,
+        "qdrant.Points/SearchStream",
+        coll_prw=True,
+    ),
     "search_points_batch": EndpointAccess(
         True,
         True,
@@ -593,6 +651,9 @@ ACTION_ACCESS = {
         "POST /collections/{collection_name}/points/query/groups",
         "qdrant.Points/QueryGroups",
     ),
+    "query_points_federated": EndpointAccess(
+        True, True, True, "POST /collections/query/federated"
+    ),
     "search_points_matrix_offsets": EndpointAccess(
         True,
         True,
@@ -606,12 +667,19 @@ ACTION_ACCESS = {
         True,
         "POST /collections/{collection_name}/points/search/matrix/pairs", "qdrant.Points/SearchMatrixPairs"
     ),
+    "similarity_matrix": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/points/similarity_matrix"
+    ),
     "facet": EndpointAccess(
         True, True, True, "POST /collections/{collection_name}/facet", "qdrant.Points/Facet"
     ),
+    "quantiles": EndpointAccess(
+        True, True, True, "POST /collections/{collection_name}/points/quantiles"
+    ),
     ### Service ###
     "root": EndpointAccess(True, True, True, "GET /", "qdrant.Qdrant/HealthCheck", everything=True),
     "readyz": EndpointAccess(True, True, True, "GET /readyz", "grpc.health.v1.Health/Check", everything=True),
+    "readyz_indexes": EndpointAccess(True, True, True, "GET /readyz/indexes", everything=True),
     "healthz": EndpointAccess(True, True, True, "GET /healthz", "grpc.health.v1.Health/Check", everything=True),
     "livez": EndpointAccess(True, True, True, "GET /livez", "grpc.health.v1.Health/Check", everything=True),
     "telemetry": EndpointAccess(True, True, True, "GET /telemetry"),
@@ -1056,6 +1124,14 @@ def test_collection_exists():
     )
 
 
+def test_validate_filter():
+    check_access(
+        "validate_filter",
+        rest_request={"filter": {"must": [{"key": FIELD_NAME, "match": {"value": "a"}}]}},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_get_optimizations():
     check_access(
         "get_optimizations",
@@ -1311,6 +1387,36 @@ def test_delete_index():
     )
 
 
+def test_tokenize_text():
+    check_access(
+        "tokenize_text",
+        rest_request={"text": "Hello world"},
+        path_params={"collection_name": COLL_NAME, "field_name": FIELD_NAME},
+    )
+
+
+def test_get_point_tokens():
+    check_access(
+        "get_point_tokens",
+        path_params={"collection_name": COLL_NAME, "field_name": FIELD_NAME, "id": 1},
+    )
+
+
+def test_get_index_recommendations():
+    check_access(
+        "get_index_recommendations",
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_warmup_collection():
+    check_access(
+        "warmup_collection",
+        rest_request={},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_create_vector_name():
     check_access(
         "create_vector_name",
@@ -1547,6 +1653,13 @@ def test_get_point():
     )
 
 
+def test_get_point_quantized_vector():
+    check_access(
+        "get_point_quantized_vector",
+        path_params={"collection_name": COLL_NAME, "id": 1},
+    )
+
+
 def test_get_points():
     check_access(
         "get_points",
@@ -1556,6 +1669,14 @@ def test_get_points():
     )
 
 
+def test_check_points_exist():
+    check_access(
+        "check_points_exist",
+        rest_request={"ids": [1], "with_version": True},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_upsert_points():
     check_access(
         "upsert_points",
@@ -1768,6 +1889,21 @@ def test_search_points():
     )
 
 
+def test_search_k_distance():
+    check_access(
+        "search_k_distance",
+        rest_request={"vector": [1, 2, 3, 4], "k": 3},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
+def test_search_points_stream():
+    check_access(
+        "search_points_stream",
+        grpc_request={"collection_name": COLL_NAME, "vector": [1, 2, 3, 4], "limit": 10},
+    )
+
+
 def test_search_points_batch():
     query = {"vector": [1, 2, 3, 4], "limit": 10}
     check_access(
@@ -1939,6 +2075,13 @@ def test_query_points_groups():
     )
 
 
+def test_query_points_federated():
+    check_access(
+        "query_points_federated",
+        rest_request={"collections": [COLL_NAME], "query": [0.1, 0.2, 0.3, 0.4]},
+    )
+
+
 def test_search_points_matrix_offsets():
     check_access(
         "search_points_matrix_offsets",
@@ -1957,6 +2100,14 @@ def test_search_points_matrix_pairs():
     )
 
 
+def test_similarity_matrix():
+    check_access(
+        "similarity_matrix",
+        rest_request={"ids": [1, 2]},
+        path_params={"collection_name": COLL_NAME},
+    )
+
+
 def test_facet():
     check_access(
         "facet",
@@ -1971,6 +2122,17 @@ def test_facet():
     )
 
 
+def test_quantiles():
+    check_access(
+        "quantiles",
+        path_params={"collection_name": COLL_NAME},
+        rest_request={
+            "key": FACET_KEY,
+            "buckets": 4,
+        },
+    )
+
+
 def test_root():
     check_access("root")
 
@@ -1979,6 +2141,10 @@ def test_readyz():
     check_access("readyz")
 
 
+def test_readyz_indexes():
+    check_access("readyz_indexes")
+
+
 def test_healthz():
     check_access("healthz")
 
@@ -83,6 +83,38 @@ def test_points_retrieve(collection_name):
     assert len(response.json()['result']['points']) == 2
 
 
+def test_points_exist(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/exists',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "ids": [2, 100, 1, 2]
+        }
+    )
+    assert response.ok
+    assert response.json()['result'] == [
+        {'id': 2, 'exists': True},
+        {'id': 100, 'exists': False},
+        {'id': 1, 'exists': True},
+        {'id': 2, 'exists': True},
+    ]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/exists',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "ids": [1, 100],
+            "with_version": True
+        }
+    )
+    assert response.ok
+    existing, missing = response.json()['result']
+    assert existing['exists'] and isinstance(existing['version'], int)
+    assert missing == {'id': 100, 'exists': False}
+
+
 def test_exclude_payload(collection_name):
     response = request_with_validation(
         api='/collections/{collection_name}/points/search',
@@ -148,6 +180,40 @@ def test_batch_search(collection_name):
     assert len(response.json()["result"]) == 0
 
 
+def test_search_k_distance(collection_name):
+    vector = [0.2, 0.1, 0.9, 0.7]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"vector": vector, "limit": 3}
+    )
+    assert response.ok
+    third = response.json()['result'][2]
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/k_distance',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"vector": vector, "k": 3, "with_id": True}
+    )
+    assert response.ok
+    result = response.json()['result']
+    assert result['score'] == pytest.approx(third['score'])
+    assert result['id'] == third['id']
+
+    # fewer than k points in the collection
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/search/k_distance',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"vector": vector, "k": 100}
+    )
+    assert response.ok
+    assert response.json()['result'] == {'score': None}
+
+
 def test_is_empty_condition(collection_name):
     response = request_with_validation(
         api='/collections/{collection_name}/points/search',
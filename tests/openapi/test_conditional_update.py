@@ -155,4 +155,134 @@ def test_conditional_update(collection_name):
         query_params={},
     )
     assert response.ok
-    assert response.json()["result"]["vector"][0] < 2.0
\ No newline at end of file
+    assert response.json()["result"]["vector"][0] < 2.0
+
+def get_point_version(collection_name, point_id):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/search",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "vector": [0.2, 0.1, 0.9, 0.7],
+            "filter": {"must": [{"has_id": [point_id]}]},
+            "limit": 1,
+        },
+    )
+    assert response.ok
+    return response.json()["result"][0]["version"]
+
+
+def test_upsert_expected_version(collection_name):
+    version = get_point_version(collection_name, 1)
+
+    # Version mismatch: whole operation is rejected with a conflict
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        body={
+            "points": [
+                {"id": 1, "vector": [9.0, 9.0, 9.0, 9.0], "payload": {"updated": True}},
+            ],
+            "expected_version": version + 100,
+        },
+        query_params={"wait": "true"},
+    )
+    assert response.status_code == 409
+
+    # Expected version of a missing point is a conflict too
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        body={
+            "points": [
+                {"id": 999, "vector": [1.0, 1.0, 1.0, 1.0]},
+            ],
+            "expected_version": version,
+        },
+        query_params={"wait": "true"},
+    )
+    assert response.status_code == 409
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/{id}",
+        method="GET",
+        path_params={"collection_name": collection_name, "id": 999},
+    )
+    assert response.status_code == 404
+
+    # Matching version: point is updated and gets a new version
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        body={
+            "points": [
+                {"id": 1, "vector": [9.0, 9.0, 9.0, 9.0], "payload": {"updated": True}},
+            ],
+            "expected_version": version,
+        },
+        query_params={"wait": "true"},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/{id}",
+        method="GET",
+        path_params={"collection_name": collection_name, "id": 1},
+    )
+    assert response.ok
+    assert response.json()["result"]["payload"]["updated"] is True
+    assert get_point_version(collection_name, 1) > version
+
+    # Stale version after the update is a conflict
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        body={
+            "points": [
+                {"id": 1, "vector": [8.0, 8.0, 8.0, 8.0]},
+            ],
+            "expected_version": version,
+        },
+        query_params={"wait": "true"},
+    )
+    assert response.status_code == 409
+
+
+def test_upsert_expected_version_replicated(collection_name):
+    version = get_point_version(collection_name, 1)
+
+    response = request_with_validation(
+        api="/collections/{collection_name}",
+        method="PATCH",
+        path_params={"collection_name": collection_name},
+        body={"params": {"replication_factor": 2}},
+    )
+    assert response.ok
+
+    # Versions differ between replicas, so they can't be expected
+    response = request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        body={
+            "points": [
+                {"id": 1, "vector": [9.0, 9.0, 9.0, 9.0], "payload": {"updated": True}},
+            ],
+            "expected_version": version,
+        },
+        query_params={"wait": "true"},
+    )
+    assert response.status_code == 400
+    assert "replication_factor" in response.json()["status"]["error"]
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/{id}",
+        method="GET",
+        path_params={"collection_name": collection_name, "id": 1},
+    )
+    assert response.ok
+    assert "updated" not in (response.json()["result"]["payload"] or {})
@@ -78,3 +78,30 @@ def test_search_matrix_offsets(collection_name):
         'ids': [1, 2, 3, 4, 5, 6, 7, 8]
     }
     assert search_result == expected_result
+
+
+def test_similarity_matrix(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/similarity_matrix",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            # point 100 doesn't exist and is left out
+            "ids": [1, 3, 4, 100],
+        },
+    )
+    assert response.ok, response.json()
+    result = response.json()["result"]
+    assert result["ids"] == [1, 3, 4]
+
+    scores = result["scores"]
+    assert len(scores) == 3
+    assert all(len(row) == 3 for row in scores)
+    for i in range(3):
+        for j in range(3):
+            assert scores[i][j] == pytest.approx(scores[j][i])
+
+    assert scores[0][1] == pytest.approx(1.4063001)
+    assert scores[0][2] == pytest.approx(1.2531)
+    assert scores[1][2] == pytest.approx(1.2218001)
+
@@ -0,0 +1,149 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+DUPLICATE_ID = 100
+
+
+@pytest.fixture(autouse=True)
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+
+    request_with_validation(
+        api="/collections/{collection_name}/index",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword",
+        },
+    ).raise_for_status()
+
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def upsert(collection_name, body):
+    return request_with_validation(
+        api="/collections/{collection_name}/points",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body=body,
+    )
+
+
+def points_list(duplicate_ids=None):
+    body = {
+        "points": [
+            {"id": DUPLICATE_ID, "vector": [0.1, 0.2, 0.3, 0.4], "payload": {"city": "Paris"}},
+            {"id": DUPLICATE_ID + 1, "vector": [0.4, 0.3, 0.2, 0.1], "payload": {"city": "Oslo"}},
+            {"id": DUPLICATE_ID, "vector": [0.5, 0.6, 0.7, 0.8], "payload": {"city": "Rome"}},
+        ]
+    }
+    if duplicate_ids is not None:
+        body["duplicate_ids"] = duplicate_ids
+    return body
+
+
+def get_city(collection_name, point_id):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/{id}",
+        method="GET",
+        path_params={"collection_name": collection_name, "id": point_id},
+    )
+    if response.status_code == 404:
+        return None
+    assert response.ok, response.text
+    return response.json()["result"]["payload"]["city"]
+
+
+def count_city(collection_name, city):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/count",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "filter": {"must": [{"key": "city", "match": {"value": city}}]},
+            "exact": True,
+        },
+    )
+    assert response.ok, response.text
+    return response.json()["result"]["count"]
+
+
+def test_duplicate_ids_last_wins_by_default(collection_name):
+    response = upsert(collection_name, points_list())
+    assert response.ok, response.text
+
+    assert get_city(collection_name, DUPLICATE_ID) == "Rome"
+    assert get_city(collection_name, DUPLICATE_ID + 1) == "Oslo"
+
+    # Payload index only contains the applied value
+    assert count_city(collection_name, "Rome") == 1
+    assert count_city(collection_name, "Paris") == 0
+
+
+def test_duplicate_ids_last_wins(collection_name):
+    response = upsert(collection_name, points_list("last_wins"))
+    assert response.ok, response.text
+
+    assert get_city(collection_name, DUPLICATE_ID) == "Rome"
+    assert count_city(collection_name, "Rome") == 1
+    assert count_city(collection_name, "Paris") == 0
+
+
+def test_duplicate_ids_first_wins(collection_name):
+    response = upsert(collection_name, points_list("first_wins"))
+    assert response.ok, response.text
+
+    assert get_city(collection_name, DUPLICATE_ID) == "Paris"
+    assert get_city(collection_name, DUPLICATE_ID + 1) == "Oslo"
+    assert count_city(collection_name, "Paris") == 1
+    assert count_city(collection_name, "Rome") == 0
+
+
+def test_duplicate_ids_error(collection_name):
+    response = upsert(collection_name, points_list("error"))
+    assert response.status_code == 400, response.text
+    assert f"Point id {DUPLICATE_ID} occurs more than once" in response.json()["status"]["error"]
+
+    # Nothing is applied
+    assert get_city(collection_name, DUPLICATE_ID) is None
+    assert get_city(collection_name, DUPLICATE_ID + 1) is None
+
+    # Requests without duplicates are accepted
+    body = points_list("error")
+    body["points"].pop()
+    response = upsert(collection_name, body)
+    assert response.ok, response.text
+    assert get_city(collection_name, DUPLICATE_ID) == "Paris"
+
+
+def test_duplicate_ids_batch(collection_name):
+    for policy, expected_city in [("last_wins", "Rome"), ("first_wins", "Paris")]:
+        response = upsert(collection_name, {
+            "batch": {
+                "ids": [DUPLICATE_ID, DUPLICATE_ID + 1, DUPLICATE_ID],
+                "vectors": [[0.1, 0.2, 0.3, 0.4], [0.4, 0.3, 0.2, 0.1], [0.5, 0.6, 0.7, 0.8]],
+                "payloads": [{"city": "Paris"}, {"city": "Oslo"}, {"city": "Rome"}],
+            },
+            "duplicate_ids": policy,
+        })
+        assert response.ok, response.text
+
+        # Vectors and payloads stay aligned with the remaining ids
+        assert get_city(collection_name, DUPLICATE_ID) == expected_city
+        assert get_city(collection_name, DUPLICATE_ID + 1) == "Oslo"
+        assert count_city(collection_name, expected_city) == 1
+
+    response = upsert(collection_name, {
+        "batch": {
+            "ids": [DUPLICATE_ID, DUPLICATE_ID],
+            "vectors": [[0.1, 0.2, 0.3, 0.4], [0.5, 0.6, 0.7, 0.8]],
+        },
+        "duplicate_ids": "error",
+    })
+    assert response.status_code == 400, response.text
@@ -1,7 +1,9 @@
 import pytest
+import requests
 
 from .helpers.collection_setup import basic_collection_setup, drop_collection
-from .helpers.helpers import request_with_validation
+from .helpers.helpers import qdrant_host_headers, request_with_validation
+from .helpers.settings import QDRANT_HOST
 
 
 @pytest.fixture(autouse=True, scope="module")
@@ -160,7 +162,8 @@ def test_basic_facet(collection_name):
             {"value": "Berlin", "count": 3},
             {"value": "London", "count": 2},
             {"value": "Moscow", "count": 2},
-        ]
+        ],
+        "other": 0,
     }
 
 
@@ -184,7 +187,33 @@ def test_integer_facet(collection_name):
             {"value": 0, "count": 2},
             {"value": 1, "count": 1},
             {"value": 2, "count": 1},
-        ]
+        ],
+        "other": 0,
+    }
+
+
+def test_facet_other(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/facet",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "city",
+            "limit": 2,
+        },
+    )
+
+    assert response.ok, response.json()
+
+    city_facet = response.json()["result"]
+    assert city_facet == {
+        "hits": [
+            {"value": "Berlin", "count": 3},
+            # Tie with "Moscow" is broken by value
+            {"value": "London", "count": 2},
+        ],
+        # Count of "Moscow", which is not returned
+        "other": 2,
     }
 
 
@@ -206,7 +235,8 @@ def test_uuid_facet(collection_name):
             {"value": "a64a8051-3ee7-4881-8bc6-691d25c70d54", "count": 5},
             {"value": "7cb46fb3-e348-4762-93ad-7fb983d2b85e", "count": 2},
             {"value": "2b016c50-282c-4d80-b784-cefead291180", "count": 1},
-        ]
+        ],
+        "other": 0,
     }
 
 
@@ -228,5 +258,151 @@ def test_boolean_facet(collection_name):
             # Sorted by count, then by value
             {"value": True, "count": 5},
             {"value": False, "count": 3},
-        ]
+        ],
+        "other": 0,
     }
+
+
+def test_integer_quantiles(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/quantiles",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "integer",
+            "buckets": 2,
+        },
+    )
+
+    assert response.ok, response.json()
+
+    buckets = response.json()["result"]["buckets"]
+    assert 1 <= len(buckets) <= 2
+    assert buckets[0]["from"] == 0
+    assert buckets[-1]["to"] == 3
+    assert sum(bucket["count"] for bucket in buckets) == 8
+    for prev, next in zip(buckets, buckets[1:]):
+        assert prev["to"] == next["from"]
+
+
+def test_quantiles_without_range_index(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/quantiles",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "key": "city",
+        },
+    )
+
+    assert response.status_code == 400, response.json()
+
+
+def test_query_with_facets(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.2, 0.1, 0.9, 0.7],
+            "limit": 1,
+            "facets": [{"key": "city"}, {"key": "integer", "limit": 2}],
+        },
+    )
+
+    assert response.ok, response.json()
+
+    result = response.json()["result"]
+    assert len(result["points"]) == 1
+    # Values are counted over all points, not only the returned ones
+    assert result["facets"] == [
+        {
+            "key": "city",
+            "hits": [
+                {"value": "Berlin", "count": 3},
+                {"value": "London", "count": 2},
+                {"value": "Moscow", "count": 2},
+            ],
+            "other": 0,
+        },
+        {
+            "key": "integer",
+            "hits": [
+                {"value": 3, "count": 4},
+                {"value": 0, "count": 2},
+            ],
+            "other": 2,
+        },
+    ]
+
+
+def test_query_with_filtered_facets(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.2, 0.1, 0.9, 0.7],
+            "limit": 1,
+            "filter": {"must": [{"key": "city", "match": {"value": "London"}}]},
+            "facets": [{"key": "city"}],
+        },
+    )
+
+    assert response.ok, response.json()
+
+    result = response.json()["result"]
+    assert len(result["points"]) == 1
+    # Values are counted over the points matching the filter
+    assert result["facets"] == [
+        {
+            "key": "city",
+            "hits": [
+                {"value": "London", "count": 2},
+                {"value": "Berlin", "count": 1},
+                {"value": "Moscow", "count": 1},
+            ],
+            "other": 0,
+        },
+    ]
+
+
+def test_query_without_facets(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.2, 0.1, 0.9, 0.7],
+            "limit": 1,
+        },
+    )
+
+    assert response.ok, response.json()
+    assert "facets" not in response.json()["result"]
+
+
+def test_query_facets_validation(collection_name):
+    # The number of values is capped
+    response = requests.post(f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={"query": [0.2, 0.1, 0.9, 0.7], "facets": [{"key": "city", "limit": 101}]},
+    )
+    assert response.status_code == 422, response.text
+
+    # Facets are not supported in batch requests
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query/batch",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "searches": [
+                {
+                    "query": [0.2, 0.1, 0.9, 0.7],
+                    "facets": [{"key": "city"}],
+                }
+            ]
+        },
+    )
+    assert response.status_code == 400, response.json()
@@ -0,0 +1,148 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+QUERY_VECTOR = [0.2, 0.1, 0.9, 0.7]
+
+
+@pytest.fixture(scope='module')
+def collection_name2(collection_name):
+    return f"{collection_name}_2"
+
+
+@pytest.fixture(scope='module')
+def collection_name3(collection_name):
+    return f"{collection_name}_3"
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors, collection_name, collection_name2, collection_name3):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    basic_collection_setup(collection_name=collection_name2, on_disk_vectors=on_disk_vectors)
+
+    drop_collection(collection_name=collection_name3)
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name3},
+        body={
+            "vectors": {
+                "size": 4,
+                "distance": "Euclid",
+                "on_disk": on_disk_vectors,
+            },
+        }
+    )
+    assert response.ok
+    yield
+    drop_collection(collection_name=collection_name)
+    drop_collection(collection_name=collection_name2)
+    drop_collection(collection_name=collection_name3)
+
+
+def query_federated(body):
+    return request_with_validation(
+        api='/collections/query/federated',
+        method="POST",
+        body=body,
+    )
+
+
+def test_federated_query(collection_name, collection_name2):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/query',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"query": QUERY_VECTOR, "limit": 3},
+    )
+    assert response.ok, response.text
+    single_ids = [point["id"] for point in response.json()["result"]["points"]]
+
+    response = query_federated({
+        "collections": [collection_name, collection_name2],
+        "query": QUERY_VECTOR,
+        "limit": 6,
+    })
+    assert response.ok, response.text
+    result = response.json()["result"]
+    points = result["points"]
+    assert "errors" not in result
+
+    # Both collections have the same points, so each point is returned from each of them
+    assert [point["id"] for point in points] == [id for id in single_ids for _ in range(2)]
+    scores = [point["score"] for point in points]
+    assert scores == sorted(scores, reverse=True)
+    for i in range(0, len(points), 2):
+        assert {points[i]["collection"], points[i + 1]["collection"]} == {collection_name, collection_name2}
+
+    response = query_federated({
+        "collections": [collection_name, collection_name2],
+        "query": QUERY_VECTOR,
+        "offset": 1,
+        "limit": 2,
+    })
+    assert response.ok, response.text
+    assert response.json()["result"]["points"] == points[1:3]
+
+
+def test_federated_query_incompatible(collection_name, collection_name3):
+    response = query_federated({
+        "collections": [collection_name, collection_name3],
+        "query": QUERY_VECTOR,
+    })
+    assert response.status_code == 400, response.text
+    assert "is not compatible" in response.json()["status"]["error"]
+
+    # Incompatible collections are rejected even if partial results are allowed
+    response = query_federated({
+        "collections": [collection_name, collection_name3],
+        "query": QUERY_VECTOR,
+        "allow_partial_results": True,
+    })
+    assert response.status_code == 400, response.text
+
+
+def test_federated_query_unsupported(collection_name, collection_name2):
+    response = query_federated({
+        "collections": [collection_name, collection_name2],
+        "query": {"order_by": "price"},
+    })
+    assert response.status_code == 400, response.text
+
+    response = query_federated({
+        "collections": [collection_name, collection_name2],
+        "query": QUERY_VECTOR,
+        "facets": [{"key": "city"}],
+    })
+    assert response.status_code == 400, response.text
+
+
+def test_federated_query_partial_results(collection_name):
+    missing_collection = f"{collection_name}_missing"
+
+    response = query_federated({
+        "collections": [collection_name, missing_collection],
+        "query": QUERY_VECTOR,
+    })
+    assert response.status_code == 404, response.text
+
+    response = query_federated({
+        "collections": [collection_name, missing_collection],
+        "query": QUERY_VECTOR,
+        "limit": 3,
+        "allow_partial_results": True,
+    })
+    assert response.ok, response.text
+    result = response.json()["result"]
+    assert len(result["points"]) == 3
+    assert all(point["collection"] == collection_name for point in result["points"])
+    assert [error["collection"] for error in result["errors"]] == [missing_collection]
+
+    # Fails if no collection responds
+    response = query_federated({
+        "collections": [missing_collection],
+        "query": QUERY_VECTOR,
+        "allow_partial_results": True,
+    })
+    assert response.status_code == 404, response.text
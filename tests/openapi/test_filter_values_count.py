@@ -89,3 +89,50 @@ def test_filter_values_count(collection_name):
     json = response.json()
     assert len(json['result']) == 3
     assert json['result'][0]['id'] == 1
+
+
+def test_filter_distinct_values_count(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {"categories": ["books", "music", "books", "books"]},
+            "points": [1],
+        }
+    )
+    assert response.ok, response.text
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/payload',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "payload": {"categories": ["books", "music", "films"]},
+            "points": [2],
+        }
+    )
+    assert response.ok, response.text
+
+    def count_points(values_count):
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/count',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "filter": {
+                    "must": [{"key": "categories", "values_count": values_count}]
+                },
+                "exact": True,
+            }
+        )
+        assert response.ok, response.text
+        return response.json()['result']['count']
+
+    assert count_points({"gte": 3}) == 2
+    assert count_points({"gte": 3, "distinct": False}) == 2
+    assert count_points({"gte": 3, "distinct": True}) == 1
+    assert count_points({"gt": 2, "distinct": True}) == 1
+    assert count_points({"gte": 2, "lte": 2, "distinct": True}) == 1
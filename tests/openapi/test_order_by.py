@@ -145,6 +145,94 @@ def test_order_by_int_descending(collection_name):
     assert result["next_page_offset"] == None
 
 
+def test_order_by_with_scores(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/scroll",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "order_by": {"key": "price", "direction": "desc"},
+            "limit": 5,
+            "score_with": [1.0, 1.0, 1.0, 1.0],
+        },
+    )
+    assert response.ok, response.json()
+
+    points = response.json()["result"]["points"]
+    # Scores don't affect the order, highest price is at the lowest id
+    assert [point["id"] for point in points] == [0, 1, 2, 3, 4]
+    for point in points:
+        # Dot product with the vector of the point, [0.1 * id] * 4
+        assert math.isclose(point["score"], 0.4 * point["id"], abs_tol=1e-5)
+
+    # Points without the vector have no score
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/scroll",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "order_by": {"key": "price", "direction": "desc"},
+            "limit": 5,
+            "score_with": {"name": "sparse-text", "vector": {"indices": [1], "values": [1.0]}},
+        },
+    )
+    assert response.ok, response.json()
+
+    points = response.json()["result"]["points"]
+    assert len(points) == 5
+    assert all("score" not in point for point in points)
+
+
+def test_order_by_query_using_named_vector(collection_name):
+    def query_ids(body):
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={"query": {"order_by": {"key": "price", "direction": "desc"}}, "limit": 5, **body},
+        )
+        assert response.ok, response.json()
+        return [point["id"] for point in response.json()["result"]["points"]]
+
+    # Without `using`, all points are ordered
+    assert query_ids({}) == [0, 1, 2, 3, 4]
+
+    # With a named vector in `using`, only the points having this vector are ordered
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/vectors",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={
+            "points": [
+                {"id": point_id, "vector": {"sparse-text": {"indices": [1], "values": [1.0]}}}
+                for point_id in [10, 20]
+            ]
+        },
+    )
+    assert response.ok, response.json()
+
+    try:
+        assert query_ids({"using": "sparse-text"}) == [10, 20]
+
+        # Same in prefetches
+        prefetch = {
+            "query": {"order_by": {"key": "price", "direction": "desc"}},
+            "using": "sparse-text",
+            "limit": 5,
+        }
+        assert query_ids({"prefetch": prefetch}) == [10, 20]
+    finally:
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/vectors/delete",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            query_params={"wait": "true"},
+            body={"points": [10, 20], "vector": ["sparse-text"]},
+        )
+        assert response.ok, response.json()
+
+
 def paginate_whole_collection(collection_name, key, direction, must=None):
     limit = 23
     pages = 0
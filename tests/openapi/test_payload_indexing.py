@@ -428,3 +428,79 @@ def test_payload_schemas(collection_name):
                     "field_name": f"field_{field_no:02d}",
                 },
             )
+
+
+def test_warmup_payload_indexes(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": {"type": "keyword", "on_disk": True},
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/warmup',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={},
+    )
+    assert response.ok
+    result = response.json()['result']
+    assert result['warmed_bytes'] == result['total_bytes']
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/warmup',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={"budget_bytes": 0, "fields": ["city", "price"]},
+    )
+    assert response.ok
+    result = response.json()['result']
+    assert result['warmed_bytes'] == 0
+
+
+def test_get_point_tokens(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": {"type": "text", "tokenizer": "word", "forward_index": True},
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index/{field_name}/points/{id}/tokens',
+        method="GET",
+        path_params={'collection_name': collection_name, 'field_name': "city", 'id': 2},
+    )
+    assert response.ok
+    assert sorted(response.json()['result']['tokens']) == ["berlin", "london"]
+
+    # Tokens are not stored without the forward index
+    response = request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": {"type": "text", "tokenizer": "word"},
+        }
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api='/collections/{collection_name}/index/{field_name}/points/{id}/tokens',
+        method="GET",
+        path_params={'collection_name': collection_name, 'field_name': "city", 'id': 2},
+    )
+    assert response.status_code == 400
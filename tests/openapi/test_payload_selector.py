@@ -313,3 +313,54 @@ def test_payload_selectors(collection_name):
             ],
         }
     }
+
+
+def test_payload_selector_array_index(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/scroll',
+        method="POST",
+        path_params={'collection_name': collection_name},
+        body={
+            "filter": {
+                "must": [
+                    {
+                        "key": "country.name",
+                        "match": {
+                            "value": "Germany",
+                        }
+                    }
+                ]
+            },
+            "limit": 3,
+            "with_payload": {
+                "include": [
+                    "country.name",
+                    "country.cities[1].location.lat",
+                    "country.cities[].sightseeing[0]",
+                    # Paths, which don't exist, are omitted
+                    "country.cities[5].name",
+                    "country.flag.colors",
+                ],
+            },
+        }
+    )
+    assert response.ok, response.text
+    assert response.json()['result']['points'][0]['payload'] == {
+        "country": {
+            "name": "Germany",
+            "cities": [
+                {
+                    "sightseeing": ["Brandenburg Gate"],
+                },
+                {
+                    "location": {
+                        "lat": 48.13743,
+                    },
+                    "sightseeing": ["Marienplatz"],
+                },
+                {
+                    "sightseeing": ["Reeperbahn"],
+                },
+            ],
+        }
+    }
@@ -0,0 +1,133 @@
+import random
+import time
+
+import pytest
+
+from .helpers.collection_setup import drop_collection
+from .helpers.helpers import request_with_validation
+
+VECTOR_DIM = 16
+NUM_POINTS = 200
+# Vectors are quantized once segments are indexed, 64 bytes per point exceed the threshold quickly
+INDEXING_THRESHOLD_KB = 1
+
+
+def wait_collection_green(collection_name, timeout=60):
+    start = time.time()
+    while time.time() - start < timeout:
+        response = request_with_validation(
+            api='/collections/{collection_name}',
+            method="GET",
+            path_params={'collection_name': collection_name},
+        )
+        assert response.ok
+        if response.json()['result']['status'] == 'green':
+            return
+        time.sleep(0.5)
+    raise TimeoutError("Collection did not become green")
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(collection_name):
+    drop_collection(collection_name=collection_name)
+
+    response = request_with_validation(
+        api='/collections/{collection_name}',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        body={
+            "vectors": {
+                "quantized": {
+                    "size": VECTOR_DIM,
+                    "distance": "Dot",
+                    "quantization_config": {
+                        "scalar": {
+                            "type": "int8",
+                            "always_ram": True,
+                        }
+                    },
+                },
+                "plain": {
+                    "size": VECTOR_DIM,
+                    "distance": "Dot",
+                },
+            },
+            "optimizers_config": {
+                "indexing_threshold": INDEXING_THRESHOLD_KB,
+            },
+        }
+    )
+    assert response.ok
+
+    rng = random.Random(42)
+    response = request_with_validation(
+        api='/collections/{collection_name}/points',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "points": [
+                {
+                    "id": point_id,
+                    "vector": {
+                        "quantized": [rng.uniform(-1.0, 1.0) for _ in range(VECTOR_DIM)],
+                        "plain": [rng.uniform(-1.0, 1.0) for _ in range(VECTOR_DIM)],
+                    },
+                }
+                for point_id in range(1, NUM_POINTS + 1)
+            ]
+        }
+    )
+    assert response.ok
+
+    wait_collection_green(collection_name)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def test_get_quantized_vector(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}/quantized_vector',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+        query_params={'using': 'quantized'},
+    )
+    assert response.ok, response.text
+
+    result = response.json()['result']
+    assert result['quantization']['scalar']['type'] == "int8"
+    assert result['dim'] == VECTOR_DIM
+    # `f32` offset correction, followed by a code per dimension
+    assert len(result['bytes']) == 4 + VECTOR_DIM
+    assert all(0 <= byte <= 255 for byte in result['bytes'])
+
+
+def test_get_not_quantized_vector(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}/quantized_vector',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+        query_params={'using': 'plain'},
+    )
+    assert response.status_code == 400
+    assert "is not quantized" in response.json()['status']['error']
+
+
+def test_get_quantized_vector_of_missing_point(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}/quantized_vector',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': NUM_POINTS + 1},
+        query_params={'using': 'quantized'},
+    )
+    assert response.status_code == 404
+
+
+def test_get_quantized_vector_of_missing_vector_name(collection_name):
+    response = request_with_validation(
+        api='/collections/{collection_name}/points/{id}/quantized_vector',
+        method="GET",
+        path_params={'collection_name': collection_name, 'id': 1},
+        query_params={'using': 'missing'},
+    )
+    assert response.status_code == 400
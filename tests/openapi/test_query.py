@@ -664,3 +664,404 @@ def test_score_threshold(body, collection_name):
     assert len(points) < 8
     for point in points:
         assert point["score"] >= score_threshold
+
+
+def test_default_score_threshold(on_disk_vectors, collection_name):
+    # Changes the collection parameters, so it goes to a collection of its own
+    threshold_collection_name = f'{collection_name}_default_threshold'
+    basic_collection_setup(collection_name=threshold_collection_name, on_disk_vectors=on_disk_vectors)
+
+    def query(body):
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": threshold_collection_name},
+            body={"query": [0.1, 0.2, 0.3, 0.4], **body},
+        )
+        assert response.ok, response.json()
+        return response.json()["result"]["points"]
+
+    try:
+        all_points = query({})
+        assert len(all_points) == 8
+        default_score_threshold = all_points[3]["score"]
+
+        response = request_with_validation(
+            api="/collections/{collection_name}",
+            method="PATCH",
+            path_params={"collection_name": threshold_collection_name},
+            body={"params": {"default_score_threshold": default_score_threshold}},
+        )
+        assert response.ok, response.json()
+
+        points = query({})
+        assert 0 < len(points) < 8
+        for point in points:
+            assert point["score"] >= default_score_threshold
+
+        # A threshold of the query takes precedence, also a lower one
+        assert len(query({"score_threshold": all_points[-1]["score"]})) == 8
+
+        # Queries can opt out of the default
+        assert len(query({"apply_default_score_threshold": False})) == 8
+
+        # Context scores are no similarities, the default doesn't apply to them
+        assert len(query({"query": {"context": [{"positive": 1, "negative": 2}]}})) > 0
+
+        # The default can be unset
+        response = request_with_validation(
+            api="/collections/{collection_name}",
+            method="PATCH",
+            path_params={"collection_name": threshold_collection_name},
+            body={"params": {"default_score_threshold": "Disabled"}},
+        )
+        assert response.ok, response.json()
+        assert len(query({})) == 8
+    finally:
+        drop_collection(collection_name=threshold_collection_name)
+
+def test_query_with_soft_timeout(collection_name):
+    def query(body):
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={"query": [0.05, 0.61, 0.76, 0.74], "limit": 3, **body},
+        )
+        assert response.ok, response.json()
+        return response.json()["result"]
+
+    expected = query({})
+    assert "stats" not in expected
+
+    # Enough time to search all segments, the results are complete
+    result = query({"soft_timeout_ms": 60_000})
+    assert result["points"] == expected["points"]
+    stats = result["stats"]
+    assert stats["partial"] is False
+    assert stats["total_segments"] > 0
+    assert stats["searched_segments"] == stats["total_segments"]
+
+    # raw query to bypass local validation
+    response = requests.post(f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={"query": [0.05, 0.61, 0.76, 0.74], "soft_timeout_ms": 0},
+    )
+    assert not response.ok, response.text
+    assert response.json()["status"]["error"] == ("Validation error in JSON body: [internal.soft_timeout_ms: value 0 invalid, must be 1 or larger]")
+
+
+def test_query_with_provenance(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.05, 0.61, 0.76, 0.74],
+            "limit": 3,
+            "with_provenance": True,
+        },
+    )
+    assert response.ok, response.json()
+    result = response.json()["result"]
+
+    point_ids = [point["id"] for point in result["points"]]
+    provenance = result["debug"]["provenance"]
+    assert [point["id"] for point in provenance] == point_ids
+    for point in provenance:
+        assert point["segment_uuid"]
+        assert "shard_id" in point
+        assert "peer_id" in point
+
+    # Debug info is only returned on request
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.05, 0.61, 0.76, 0.74],
+            "limit": 3,
+        },
+    )
+    assert response.ok, response.json()
+    assert "debug" not in response.json()["result"]
+
+
+def test_query_with_explanation(collection_name):
+    def query_explanation():
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "query": [0.05, 0.61, 0.76, 0.74],
+                "limit": 6,
+                "with_explanation": True,
+            },
+        )
+        assert response.ok, response.json()
+        result = response.json()["result"]
+        explanation = result["debug"]["explanation"]
+        assert [point["id"] for point in explanation] == [point["id"] for point in result["points"]]
+        return explanation
+
+    # Without boost rules, scores are the similarities to the query vector
+    for point in query_explanation():
+        assert [stage["kind"] for stage in point["stages"]] == ["vector"]
+        assert isclose(point["stages"][0]["score"], point["score"], rel_tol=1e-5)
+
+    response = request_with_validation(
+        api="/collections/{collection_name}",
+        method="PATCH",
+        path_params={"collection_name": collection_name},
+        body={
+            "boost_rules": {
+                "upsert": {
+                    "berlin": {
+                        "filter": {"must": [{"key": "city", "match": {"value": "Berlin"}}]},
+                        "multiplier": 2.0,
+                    },
+                },
+            },
+        },
+    )
+    assert response.ok, response.json()
+
+    try:
+        explanation = query_explanation()
+        assert any(len(point["stages"]) == 2 for point in explanation)
+        for point in explanation:
+            vector_stage, *boost_stages = point["stages"]
+            assert vector_stage["kind"] == "vector"
+            if boost_stages:
+                [boost_stage] = boost_stages
+                assert boost_stage["kind"] == "boost"
+                assert boost_stage["boost"] == {"rule": "berlin", "multiplier": 2.0}
+                assert isclose(vector_stage["score"] * 2.0, boost_stage["score"], rel_tol=1e-5)
+            # The last stage produces the returned score
+            assert isclose(point["stages"][-1]["score"], point["score"], rel_tol=1e-5)
+    finally:
+        response = request_with_validation(
+            api="/collections/{collection_name}",
+            method="PATCH",
+            path_params={"collection_name": collection_name},
+            body={"boost_rules": {"delete": ["berlin"]}},
+        )
+        assert response.ok, response.json()
+
+
+def test_query_explanation_of_fusion(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "prefetch": [
+                {"query": [0.1, 0.2, 0.3, 0.4]},
+                {"query": [0.5, 0.6, 0.7, 0.8]},
+            ],
+            "query": {"fusion": "rrf"},
+            "with_explanation": True,
+        },
+    )
+    assert response.ok, response.json()
+    result = response.json()["result"]
+    explanation = result["debug"]["explanation"]
+    assert len(explanation) == len(result["points"])
+    for point, point_explanation in zip(result["points"], explanation):
+        assert point_explanation["id"] == point["id"]
+        # Similarity in the prefetch, which found the point first, then the fused score
+        assert [stage["kind"] for stage in point_explanation["stages"]] == ["vector", "fusion"]
+        assert isclose(point_explanation["stages"][-1]["score"], point["score"], rel_tol=1e-5)
+
+
+def test_query_with_stopwords(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/index",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={
+            "field_name": "city",
+            "field_schema": {
+                "type": "text",
+                "tokenizer": "word",
+                "stopwords": "english",
+            },
+        },
+    )
+    assert response.ok, response.json()
+
+    try:
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "query": [0.05, 0.61, 0.76, 0.74],
+                "filter": {
+                    "should": [
+                        {"key": "city", "match": {"text": "the Berlin"}},
+                        {"key": "city", "match": {"text": "and the"}},
+                        {"key": "price", "range": {"gte": 10.0}},
+                    ]
+                },
+                "with_stopwords": True,
+            },
+        )
+        assert response.ok, response.json()
+        result = response.json()["result"]
+        assert len(result["points"]) > 0
+
+        first, second = result["debug"]["stopwords"]
+        assert first == {
+            "key": "city",
+            "text": "the Berlin",
+            "removed": ["the"],
+            "is_empty": False,
+        }
+        assert second["key"] == "city"
+        assert second["text"] == "and the"
+        assert second["removed"] == ["and", "the"]
+        assert second["is_empty"]
+        assert second["warning"]
+    finally:
+        response = request_with_validation(
+            api="/collections/{collection_name}/index/{field_name}",
+            method="DELETE",
+            path_params={"collection_name": collection_name, "field_name": "city"},
+            query_params={"wait": "true"},
+        )
+        assert response.ok, response.json()
+
+
+def test_query_with_snippets(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/index",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": "true"},
+        body={
+            "field_name": "city",
+            "field_schema": {"type": "text", "tokenizer": "word"},
+        },
+    )
+    assert response.ok, response.json()
+
+    try:
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "query": [0.05, 0.61, 0.76, 0.74],
+                "filter": {"must": [{"key": "city", "match": {"text": "Berlin"}}]},
+                "snippets": {"key": "city"},
+            },
+        )
+        assert response.ok, response.json()
+        result = response.json()["result"]
+
+        point_ids = [point["id"] for point in result["points"]]
+        assert sorted(point_ids) == [1, 2, 3]
+        assert [snippets["id"] for snippets in result["snippets"]] == point_ids
+
+        for point_snippets in result["snippets"]:
+            assert point_snippets["snippets"] == [
+                {
+                    "value_index": 0,
+                    "char_start": 0,
+                    "text": "Berlin",
+                    "highlights": [{"token": "berlin", "start": 0, "end": 6}],
+                }
+            ]
+
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "query": [0.05, 0.61, 0.76, 0.74],
+                "snippets": {"key": "city"},
+            },
+        )
+        assert response.status_code == 400, response.json()
+
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query/batch",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={
+                "searches": [
+                    {
+                        "query": [0.05, 0.61, 0.76, 0.74],
+                        "snippets": {"key": "city", "text": "Berlin"},
+                    }
+                ]
+            },
+        )
+        assert response.status_code == 400, response.json()
+    finally:
+        response = request_with_validation(
+            api="/collections/{collection_name}/index/{field_name}",
+            method="DELETE",
+            path_params={"collection_name": collection_name, "field_name": "city"},
+            query_params={"wait": "true"},
+        )
+        assert response.ok, response.json()
+
+
+def test_query_with_neighbors(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "query": [0.05, 0.61, 0.76, 0.74],
+            "limit": 5,
+            "neighbors": {"limit": 2, "seeds": 3},
+        },
+    )
+    assert response.ok, response.json()
+    result = response.json()["result"]
+
+    # Only the top points are expanded
+    point_ids = [point["id"] for point in result["points"]]
+    assert [neighbors["id"] for neighbors in result["neighbors"]] == point_ids[:3]
+
+    for point_neighbors in result["neighbors"]:
+        neighbor_ids = [neighbor["id"] for neighbor in point_neighbors["neighbors"]]
+        assert len(neighbor_ids) == 2
+        assert point_neighbors["id"] not in neighbor_ids
+
+        # Same as querying the nearest neighbors of the stored point
+        response = request_with_validation(
+            api="/collections/{collection_name}/points/query",
+            method="POST",
+            path_params={"collection_name": collection_name},
+            body={"query": point_neighbors["id"], "limit": 2},
+        )
+        assert response.ok, response.json()
+        assert neighbor_ids == [point["id"] for point in response.json()["result"]["points"]]
+
+    # The number of neighbors is capped
+    response = requests.post(f"{QDRANT_HOST}/collections/{collection_name}/points/query",
+        headers=qdrant_host_headers(),
+        json={"query": [0.05, 0.61, 0.76, 0.74], "neighbors": {"limit": 100}},
+    )
+    assert response.status_code == 422, response.text
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query/batch",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "searches": [
+                {
+                    "query": [0.05, 0.61, 0.76, 0.74],
+                    "neighbors": {"limit": 2},
+                }
+            ]
+        },
+    )
+    assert response.status_code == 400, response.json()
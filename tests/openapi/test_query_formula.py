@@ -160,3 +160,46 @@ def test_formula_with_score_threshold(collection_name):
         assert p.get("score") >= threshold - 1e-8, (
             f"Point {p.get('id')} with score {p.get('score')} is below threshold {threshold}"
         )
+
+
+@pytest.mark.parametrize(
+    "aggregation,expected_scores",
+    [
+        ("max", {1: 0.5, 2: 0.5, 3: 0.5, 4: 0.5}),
+        ("sum", {1: 0.5, 2: 1.0, 3: 0.5, 4: 0.5}),
+    ],
+)
+def test_formula_text_score(collection_name, aggregation, expected_scores):
+    response = request_with_validation(
+        api="/collections/{collection_name}/index",
+        method="PUT",
+        path_params={"collection_name": collection_name},
+        query_params={"wait": 'true'},
+        body={"field_name": "city", "field_schema": {"type": "text", "tokenizer": "word"}},
+    )
+    assert response.ok
+
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        body={
+            "prefetch": {"query": 8, "limit": 10},
+            "query": {
+                "formula": {
+                    "text_score": {
+                        "key": "city",
+                        "text": "Berlin London",
+                        "aggregation": aggregation,
+                    }
+                }
+            },
+            "limit": 10,
+        },
+    )
+    assert response.ok, response.json()
+
+    scores = {point["id"]: point["score"] for point in response.json()["result"]["points"]}
+    for point_id, score in scores.items():
+        # Each city of the array is scored separately
+        assert isclose(score, expected_scores.get(point_id, 0.0), rel_tol=1e-6)
@@ -0,0 +1,79 @@
+import pytest
+
+from .helpers.collection_setup import basic_collection_setup, drop_collection
+from .helpers.helpers import request_with_validation
+
+QUERY_VECTOR = [0.2, 0.1, 0.9, 0.7]
+
+
+@pytest.fixture(autouse=True, scope="module")
+def setup(on_disk_vectors, collection_name):
+    basic_collection_setup(collection_name=collection_name, on_disk_vectors=on_disk_vectors)
+    yield
+    drop_collection(collection_name=collection_name)
+
+
+def query(collection_name, api, body, score_precision=None):
+    query_params = {}
+    if score_precision is not None:
+        query_params["score_precision"] = score_precision
+    response = request_with_validation(
+        api=f"/collections/{{collection_name}}/points/{api}",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params=query_params,
+        body=body,
+    )
+    assert response.ok, response.text
+    return response.json()["result"]
+
+
+def assert_rounded(points, full_points, precision):
+    assert [point["id"] for point in points] == [point["id"] for point in full_points]
+    for point, full_point in zip(points, full_points):
+        assert point["score"] == pytest.approx(round(full_point["score"], precision), abs=1e-6)
+
+
+def test_score_precision_search(collection_name):
+    body = {"vector": QUERY_VECTOR, "limit": 5}
+    full_points = query(collection_name, "search", body)
+    points = query(collection_name, "search", body, score_precision=2)
+    assert_rounded(points, full_points, 2)
+
+    body = {"searches": [body, {"vector": QUERY_VECTOR[::-1], "limit": 3}]}
+    full_batch = query(collection_name, "search/batch", body)
+    batch = query(collection_name, "search/batch", body, score_precision=0)
+    for points, full_points in zip(batch, full_batch):
+        assert_rounded(points, full_points, 0)
+
+
+def test_score_precision_query(collection_name):
+    body = {"query": QUERY_VECTOR, "limit": 5}
+    full_points = query(collection_name, "query", body)["points"]
+    points = query(collection_name, "query", body, score_precision=3)["points"]
+    assert_rounded(points, full_points, 3)
+
+    body = {"searches": [body]}
+    full_batch = query(collection_name, "query/batch", body)
+    batch = query(collection_name, "query/batch", body, score_precision=1)
+    assert_rounded(batch[0]["points"], full_batch[0]["points"], 1)
+
+
+def test_score_precision_groups(collection_name):
+    body = {"query": QUERY_VECTOR, "group_by": "city", "limit": 3, "group_size": 2}
+    full_groups = query(collection_name, "query/groups", body)["groups"]
+    groups = query(collection_name, "query/groups", body, score_precision=2)["groups"]
+    assert [group["id"] for group in groups] == [group["id"] for group in full_groups]
+    for group, full_group in zip(groups, full_groups):
+        assert_rounded(group["hits"], full_group["hits"], 2)
+
+
+def test_score_precision_invalid(collection_name):
+    response = request_with_validation(
+        api="/collections/{collection_name}/points/query",
+        method="POST",
+        path_params={"collection_name": collection_name},
+        query_params={"score_precision": 8},
+        body={"query": QUERY_VECTOR},
+    )
+    assert response.status_code == 400, response.text
@@ -2604,4 +2604,64 @@ def test_strict_mode_payload_index_count(collection_name):
                 "type": "integer",
             }
         }
-    ).raise_for_status()
\ No newline at end of file
+    ).raise_for_status()
+
+def test_strict_mode_filter_max_selectivity(collection_name):
+    request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword",
+        }
+    ).raise_for_status()
+
+    def search_request(filter, params=None):
+        body = {
+            "vector": [0.2, 0.1, 0.9, 0.7],
+            "limit": 3,
+            "filter": filter,
+        }
+        if params is not None:
+            body["params"] = params
+        return request_with_validation(
+            api='/collections/{collection_name}/points/search',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body=body,
+        )
+
+    # 3 of 10 points are in Berlin, 8 of 10 are not in Moscow
+    selective_filter = {"must": [{"key": "city", "match": {"value": "Berlin"}}]}
+    broad_filter = {"must_not": [{"key": "city", "match": {"value": "Moscow"}}]}
+
+    search_request(broad_filter).raise_for_status()
+
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "filter_max_selectivity": 0.5,
+    })
+
+    search_request(selective_filter).raise_for_status()
+
+    response = search_request(broad_filter)
+    assert not response.ok
+    assert "Filter is not selective enough" in response.json()['status']['error']
+
+    # Requests without a filter match all points
+    response = search_request(None)
+    assert not response.ok
+    assert "Filter is not selective enough" in response.json()['status']['error']
+
+    # Filter is allowed, if the client acknowledges the full scan
+    search_request(broad_filter, params={"allow_full_scan": True}).raise_for_status()
+    search_request(None, params={"allow_full_scan": True}).raise_for_status()
+
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "filter_selectivity_warn_only": True,
+    })
+
+    search_request(broad_filter).raise_for_status()